@@ -28,7 +28,7 @@ CODE_GUARDS = [
     (Path("src-tauri/src/integration.rs"), "status:changed"),
     (Path("src-tauri/src/integration.rs"), "transcription:complete"),
     (Path("src-tauri/src/integration.rs"), "transcription:error"),
-    (Path("src-tauri/src/integration.rs"), "legacy string model state"),
+    (Path("src-tauri/src/integration/model_service.rs"), "legacy string model state"),
 ]
 
 MARKER_RE = re.compile(
@@ -0,0 +1,19 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+REPO_ROOT="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+BINDINGS_DIR="${REPO_ROOT}/src/bindings"
+
+echo "[TS_BINDINGS] Regenerating ${BINDINGS_DIR}..."
+rm -rf "${BINDINGS_DIR}"
+(cd "${REPO_ROOT}/src-tauri" && cargo test --lib export_bindings)
+
+if [ ! -f "${BINDINGS_DIR}/TranscriptEntry.ts" ]; then
+  echo "[TS_BINDINGS] ✗ ts-rs did not write bindings to ${BINDINGS_DIR}"
+  exit 1
+fi
+
+echo "[TS_BINDINGS] Type-checking frontend types against generated bindings..."
+(cd "${REPO_ROOT}" && npx --no-install tsc --noEmit -p tsconfig.bindings.json)
+
+echo "[TS_BINDINGS] ✓ frontend types accept backend payloads"
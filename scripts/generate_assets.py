@@ -4,7 +4,7 @@ Generate tray icons and audio cues for OpenVoicy.
 
 This script creates:
 - 6 tray icon states at multiple resolutions
-- 3 audio cue sounds (start, stop, error)
+- 4 audio cue sounds (start, stop, error, countdown tick)
 
 Run from project root:
     python scripts/generate_assets.py
@@ -236,6 +236,19 @@ def generate_sounds():
     write_wav(SOUNDS_DIR / "cue-error.wav", error_audio)
     print("Created cue-error.wav")
 
+    # Countdown tick: one short, quiet high note per second before recording
+    tick_audio = generate_sine_wave(1318.5, DURATION_SHORT / 2, 0.2)  # E6
+    write_wav(SOUNDS_DIR / "cue-tick.wav", tick_audio)
+    print("Created cue-tick.wav")
+
+    # Re-enable: two soft notes at one pitch, so it is not mistaken for the
+    # rising start tone (nothing is being recorded)
+    reenable_note = generate_sine_wave(784, DURATION_SHORT / 3, 0.2)  # G5
+    reenable_gap = b"\x00\x00" * int(SAMPLE_RATE * DURATION_SHORT / 6)
+    reenable_audio = reenable_note + reenable_gap + reenable_note
+    write_wav(SOUNDS_DIR / "cue-reenable.wav", reenable_audio)
+    print("Created cue-reenable.wav")
+
 
 def main():
     """Generate all assets."""
@@ -5,7 +5,7 @@ from pathlib import Path
 
 
 REPO_ROOT = Path(__file__).resolve().parents[2]
-INTEGRATION_RS = REPO_ROOT / "src-tauri" / "src" / "integration.rs"
+OVERLAY_SERVICE_RS = REPO_ROOT / "src-tauri" / "src" / "integration" / "overlay_service.rs"
 COMMANDS_RS = REPO_ROOT / "src-tauri" / "src" / "commands.rs"
 
 
@@ -13,7 +13,7 @@ def _extract_start_overlay_window_loop_block(source: str) -> str:
     start = source.find("fn start_overlay_window_loop(&self)")
     if start == -1:
         raise AssertionError("start_overlay_window_loop not found")
-    end = source.find("fn emit_app_error_event(", start)
+    end = source.find("fn start_focus_preview_loop(", start)
     if end == -1:
         raise AssertionError("end marker for start_overlay_window_loop not found")
     return source[start:end]
@@ -22,9 +22,9 @@ def _extract_start_overlay_window_loop_block(source: str) -> str:
 class OverlayConfigGateLoopTests(unittest.TestCase):
     @classmethod
     def setUpClass(cls) -> None:
-        cls.integration_text = INTEGRATION_RS.read_text(encoding="utf-8")
+        cls.overlay_service_text = OVERLAY_SERVICE_RS.read_text(encoding="utf-8")
         cls.commands_text = COMMANDS_RS.read_text(encoding="utf-8")
-        cls.overlay_loop_block = _extract_start_overlay_window_loop_block(cls.integration_text)
+        cls.overlay_loop_block = _extract_start_overlay_window_loop_block(cls.overlay_service_text)
 
     def test_overlay_loop_waits_on_notify_instead_of_periodic_interval(self) -> None:
         self.assertIn("overlay_config_notify.notified().await", self.overlay_loop_block)
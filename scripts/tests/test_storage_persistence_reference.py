@@ -74,15 +74,18 @@ class StoragePersistenceReferenceTests(unittest.TestCase):
         self.assertIn("Migration must be additive", self.reference_text)
 
     def test_runtime_config_implements_tmp_staging_and_atomic_replace(self) -> None:
-        self.assertIn('path.with_extension("json.tmp")', self.config_text)
+        self.assertIn('temp_extension.push(".tmp")', self.config_text)
         self.assertIn("replace_config_file(&temp, path)", self.config_text)
-        self.assertIn("fn replace_config_file(temp: &PathBuf, path: &PathBuf)", self.config_text)
+        self.assertIn("fn replace_config_file(temp: &Path, path: &Path)", self.config_text)
         self.assertIn("fs::rename(temp, path)", self.config_text)
 
     def test_runtime_config_implements_corrupt_backup_and_migration_entrypoint(self) -> None:
         self.assertIn('path.with_extension("json.corrupt")', self.config_text)
         self.assertIn("Failed to backup corrupt config", self.config_text)
-        self.assertIn("fn migrate_config(mut config: Value) -> AppConfig", self.config_text)
+        self.assertIn(
+            "fn migrate_config(mut config: Value) -> Result<AppConfig, serde_json::Error>",
+            self.config_text,
+        )
         self.assertIn("Future migrations go here", self.config_text)
 
     def test_reference_mentions_history_persistence_config_fields(self) -> None:
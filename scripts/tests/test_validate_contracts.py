@@ -498,6 +498,61 @@ listen('sidecar:status', () => {});
             errors = MODULE.validate_rust_event_payloads(root, events_contract)
             self.assertEqual(errors, [])
 
+    def test_validate_rust_event_payloads_scans_submodules_with_parent_constants(self) -> None:
+        """Submodules under src/<module>/ emit with constants defined in src/<module>.rs."""
+        with tempfile.TemporaryDirectory() as tmpdir:
+            root = Path(tmpdir)
+            src_dir = root / "src-tauri" / "src"
+            (src_dir / "integration").mkdir(parents=True, exist_ok=True)
+
+            (src_dir / "integration.rs").write_text(
+                "\n".join(
+                    [
+                        "mod audio_service;",
+                        'const EVENT_TRAY_UPDATE: &str = "tray:update";',
+                    ]
+                ),
+                encoding="utf-8",
+            )
+            (src_dir / "integration" / "audio_service.rs").write_text(
+                "\n".join(
+                    [
+                        "fn emit_device_count() {",
+                        "  emit_with_shared_seq(",
+                        "    handle,",
+                        "    &[EVENT_TRAY_UPDATE],",
+                        '    json!({ "reason": "device_list_changed", "device_count": 0 }),',
+                        "    &event_seq,",
+                        "  );",
+                        "}",
+                    ]
+                ),
+                encoding="utf-8",
+            )
+
+            events_contract = {
+                "items": [
+                    {
+                        "type": "event",
+                        "name": "tray:update",
+                        "payload_schema": {
+                            "type": "object",
+                            "required": ["reason", "device_count"],
+                            "properties": {
+                                "reason": {"type": "string"},
+                                "device_count": {"type": "integer"},
+                            },
+                            "additionalProperties": True,
+                        },
+                    }
+                ]
+            }
+
+            sites = MODULE.extract_rust_emission_sites(root, src_dir / "integration" / "audio_service.rs")
+            self.assertEqual([site.event_name for site in sites], ["tray:update"])
+            errors = MODULE.validate_rust_event_payloads(root, events_contract)
+            self.assertEqual(errors, [])
+
     def test_validate_rust_event_payloads_infers_shape_from_function_return_struct(self) -> None:
         """Regression (1e3m): infer payload shape for json!(identifier) from helper return struct."""
         with tempfile.TemporaryDirectory() as tmpdir:
@@ -559,6 +614,60 @@ listen('sidecar:status', () => {});
             errors = MODULE.validate_rust_event_payloads(root, events_contract)
             self.assertEqual(errors, [])
 
+    def test_validate_rust_event_payloads_infers_struct_literal_through_seq_wrapper(self) -> None:
+        with tempfile.TemporaryDirectory() as tmpdir:
+            root = Path(tmpdir)
+            rust_file = root / "src-tauri" / "src" / "commands.rs"
+            rust_file.parent.mkdir(parents=True, exist_ok=True)
+            rust_file.write_text(
+                "\n".join(
+                    [
+                        "use serde::Serialize;",
+                        'const EVENT_INJECTION_MODE_CHANGED: &str = "injection:mode_changed";',
+                        "#[derive(Serialize)]",
+                        "pub struct InjectionModeChangedPayload {",
+                        "  pub mode: String,",
+                        "}",
+                        "fn set_mode(app: &tauri::AppHandle) {",
+                        "  let payload = InjectionModeChangedPayload {",
+                        '    mode: "ask".to_string(),',
+                        "  };",
+                        "  let _ = app.emit(",
+                        "    EVENT_INJECTION_MODE_CHANGED,",
+                        "    crate::event_seq::payload_with_next_seq(serde_json::json!(payload)),",
+                        "  );",
+                        "}",
+                    ]
+                ),
+                encoding="utf-8",
+            )
+
+            events_contract = {
+                "items": [
+                    {
+                        "type": "event",
+                        "name": "injection:mode_changed",
+                        "payload_schema": {
+                            "type": "object",
+                            "required": ["seq", "mode"],
+                            "properties": {
+                                "seq": {"type": "integer"},
+                                "mode": {"type": "string"},
+                            },
+                            "additionalProperties": False,
+                        },
+                    }
+                ]
+            }
+
+            errors = MODULE.validate_rust_event_payloads(root, events_contract)
+            self.assertEqual(errors, [])
+
+    def test_rust_type_to_json_type_maps_sequences_to_array(self) -> None:
+        self.assertEqual(MODULE.rust_type_to_json_type("Vec<CapabilityIssue>"), "array")
+        self.assertEqual(MODULE.rust_type_to_json_type("Option<Vec<String>>"), "array")
+        self.assertEqual(MODULE.rust_type_to_json_type("PermissionStatus"), "object")
+
     def test_validate_tauri_event_payload_examples_reports_schema_violation(self) -> None:
         with tempfile.TemporaryDirectory() as tmpdir:
             root = Path(tmpdir)
@@ -57,6 +57,15 @@ class ValidateModelManifestTests(unittest.TestCase):
         errors = MODULE.validate_manifest_schema(manifest)
         self.assertTrue(any("Missing required field: model_family" in err for err in errors))
 
+    def test_validate_manifest_schema_requires_text_for_license_acceptance(self) -> None:
+        manifest = self._minimal_manifest()
+        manifest["license"]["requires_acceptance"] = True
+        errors = MODULE.validate_manifest_schema(manifest)
+        self.assertTrue(any("license.text is required" in err for err in errors))
+
+        manifest["license"]["text"] = "You must agree to these terms."
+        self.assertEqual(MODULE.validate_manifest_schema(manifest), [])
+
     def test_validate_ipc_model_ids_fails_on_mismatch(self) -> None:
         manifest = {"model_id": "parakeet-tdt-0.6b-v3"}
         with tempfile.TemporaryDirectory() as tmpdir:
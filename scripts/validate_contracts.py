@@ -653,6 +653,11 @@ def extract_rust_emission_sites(repo_root: Path, rust_file: Path) -> list[RustEm
 
     text = rust_file.read_text(encoding="utf-8")
     constants = parse_rust_event_constants(text)
+    # Submodules (src/foo/bar.rs) use the event constants of their parent (src/foo.rs).
+    parent_module = rust_file.parent.with_suffix(".rs")
+    if parent_module.is_file():
+        parent_constants = parse_rust_event_constants(parent_module.read_text(encoding="utf-8"))
+        constants = {**parent_constants, **constants}
     cfg_test_line = _find_cfg_test_line(text)
     sites: list[RustEmissionSite] = []
 
@@ -837,6 +842,8 @@ def rust_type_to_json_type(rust_type: str) -> str | None:
         return "number"
     if raw in {"Value", "serde_json::Value"}:
         return None
+    if raw.startswith("Vec<") or raw.startswith("VecDeque<") or raw.startswith("["):
+        return "array"
     return "object"
 
 
@@ -1037,6 +1044,11 @@ def infer_rust_payload_shape(
     # Use file-specific text for line-relative assignment lookups, global text for definitions
     local_text = site_file_text if site_file_text is not None else rust_text
     expr = site.payload_expr.strip()
+    # Commands stamp seq via event_seq::payload_with_next_seq(<payload>); infer the inner payload
+    seq_wrapper = re.fullmatch(r"(?:crate::)?(?:event_seq::)?payload_with_next_seq\((.*)\)", expr, re.S)
+    if seq_wrapper:
+        expr = seq_wrapper.group(1).strip()
+    expr = re.sub(r"^serde_json::json!", "json!", expr)
     fn_match = re.match(r"([A-Za-z_][A-Za-z0-9_]*)\s*\(", expr)
     if fn_match:
         fn_name = fn_match.group(1)
@@ -1070,6 +1082,11 @@ def infer_rust_payload_shape(
                     shape = infer_payload_shape_from_rust_struct(rust_text, "ModelProgress")
                     if shape is not None:
                         return shape
+                struct_literal = re.match(r"([A-Z][A-Za-z0-9_]*)\s*\{", rhs)
+                if struct_literal:
+                    shape = infer_payload_shape_from_rust_struct(rust_text, struct_literal.group(1))
+                    if shape is not None:
+                        return shape
 
     # Bare identifier: trace assignment in the same file and try to resolve the RHS
     if re.fullmatch(r"[A-Za-z_][A-Za-z0-9_]*", expr):
@@ -1095,7 +1112,7 @@ def validate_rust_event_payloads(repo_root: Path, events_contract: dict[str, Any
     if not rust_src_dir.is_dir():
         return [f"missing Rust source directory: {rust_src_dir.relative_to(repo_root)}"]
 
-    rust_files = sorted(rust_src_dir.glob("*.rs"))
+    rust_files = sorted(rust_src_dir.rglob("*.rs"))
     if not rust_files:
         return [f"{rust_src_dir.relative_to(repo_root)}: no Rust source files found"]
 
@@ -137,6 +137,12 @@ def validate_manifest_schema(manifest: dict[str, Any]) -> list[str]:
             for field in REQUIRED_LICENSE_FIELDS:
                 if field not in license_obj:
                     errors.append(f"Missing required license field: {field}")
+            if license_obj.get("requires_acceptance") is True:
+                text = license_obj.get("text")
+                if not isinstance(text, str) or not text.strip():
+                    errors.append(
+                        "license.text is required when license.requires_acceptance is true"
+                    )
 
     # Check files array
     if "files" in manifest:
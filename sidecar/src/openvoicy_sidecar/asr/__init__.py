@@ -55,6 +55,7 @@ __all__ = [
     "handle_asr_initialize",
     "handle_asr_transcribe",
     "handle_asr_status",
+    "handle_batch_compare_models",
     "create_backend",
 ]
 
@@ -88,6 +89,22 @@ def normalize_initialize_language(value: Any) -> Optional[str]:
     return normalized
 
 
+def normalize_initialize_beam_size(value: Any) -> Optional[int]:
+    """Normalize optional beam_size parameter for asr.initialize.
+
+    None means "use the catalog recommendation"; otherwise an integer 1-16.
+    """
+    if value is None:
+        return None
+
+    if isinstance(value, bool) or not isinstance(value, int) or not 1 <= value <= 16:
+        raise ASRError(
+            "Invalid beam_size: expected integer 1-16 or null",
+            code="E_INVALID_PARAMS",
+        )
+    return value
+
+
 def load_manifest(model_id: str) -> ModelManifest:
     """Load model manifest from shared directory.
 
@@ -179,6 +196,24 @@ def resolve_default_language(model_id: str) -> Optional[str]:
     return None
 
 
+def resolve_recommended_params(model_id: str) -> dict[str, Any]:
+    entry = get_catalog_entry(model_id)
+    if not entry:
+        return {}
+    params = entry.get("recommended_params")
+    return dict(params) if isinstance(params, dict) else {}
+
+
+def apply_beam_size(backend: Any, beam_size: Optional[int]) -> None:
+    """Configure decoder beam width on backends that support it."""
+    if beam_size is None:
+        return
+    if hasattr(backend, "set_beam_size"):
+        getattr(backend, "set_beam_size")(beam_size)
+    elif beam_size != 1:
+        log(f"Backend does not support beam_size={beam_size}; using its default decoding")
+
+
 def create_backend(
     family: str,
     config: Optional[dict[str, Any]] = None,
@@ -248,6 +283,7 @@ class ASREngine:
         device_pref: str = "auto",
         language: Optional[str] = None,
         progress_callback: Optional[ProgressCallback] = None,
+        beam_size: Optional[int] = None,
     ) -> dict[str, Any]:
         """Initialize ASR with the specified model.
 
@@ -258,12 +294,18 @@ class ASREngine:
             model_id: Model identifier (e.g., "parakeet-tdt-0.6b-v3")
             device_pref: Device preference ("auto", "cuda", "cpu")
             progress_callback: Optional callback for progress updates
+            beam_size: Decoder beam width; None applies the catalog recommendation
 
         Returns:
             Status dictionary with model_id, device, and status.
         """
         start_time = time.time()
         effective_language = language if language is not None else resolve_default_language(model_id)
+        effective_beam_size = (
+            beam_size
+            if beam_size is not None
+            else resolve_recommended_params(model_id).get("beam_size")
+        )
 
         # Fast path: already initialized with same model
         if (
@@ -281,6 +323,7 @@ class ASREngine:
                         f"Unsupported language '{requested}': {error}",
                         code="E_LANGUAGE_UNSUPPORTED",
                     ) from error
+            apply_beam_size(self._backend, effective_beam_size)
             elapsed = (time.time() - start_time) * 1000
             log(f"ASR already initialized (fast path: {elapsed:.1f}ms)")
             return {
@@ -306,6 +349,7 @@ class ASREngine:
                             f"Unsupported language '{requested}': {error}",
                             code="E_LANGUAGE_UNSUPPORTED",
                         ) from error
+                apply_beam_size(self._backend, effective_beam_size)
                 elapsed = (time.time() - start_time) * 1000
                 log(f"ASR already initialized (fast path after lock: {elapsed:.1f}ms)")
                 return {
@@ -346,7 +390,11 @@ class ASREngine:
 
             if progress_callback:
                 progress_callback(
-                    InitProgress(state="loading_model", detail="Loading model into memory...")
+                    InitProgress(
+                        state="loading_model",
+                        detail="Loading model into memory...",
+                        stage="weights",
+                    )
                 )
 
             family = resolve_model_family(model_id)
@@ -383,6 +431,7 @@ class ASREngine:
                     f"Backend family '{family}' does not support explicit language={effective_language}; ignoring"
                 )
 
+            apply_beam_size(backend, effective_beam_size)
             backend.initialize(model_path, device, progress_callback)
 
             # Unload previous backend if different model
@@ -466,6 +515,7 @@ def handle_asr_initialize(request: Request) -> dict[str, Any]:
         model_id: Model identifier (default: "parakeet-tdt-0.6b-v3")
         device_pref: Device preference - "auto", "cuda", or "cpu" (default: "auto")
         language: Optional language code, "auto", or null
+        beam_size: Optional decoder beam width; null applies the catalog recommendation
 
     Returns:
         { status: "ready", model_id: string, device: string }
@@ -474,6 +524,7 @@ def handle_asr_initialize(request: Request) -> dict[str, Any]:
     model_id = params.get("model_id", "parakeet-tdt-0.6b-v3")
     device_pref = params.get("device_pref", "auto")
     language = normalize_initialize_language(params.get("language"))
+    beam_size = normalize_initialize_beam_size(params.get("beam_size"))
 
     # Validate device_pref
     if device_pref not in ("auto", "cuda", "cpu"):
@@ -483,41 +534,32 @@ def handle_asr_initialize(request: Request) -> dict[str, Any]:
 
     # Progress callback that emits events
     def emit_progress(progress: InitProgress):
-        write_event("status_changed", progress.to_dict())
+        params = progress.to_dict()
+        # Loading stages also move the host's model status to "loading".
+        if progress.stage:
+            params["model"] = {"model_id": model_id, "status": "loading"}
+        write_event("status_changed", params)
 
     return engine.initialize(
         model_id,
         device_pref,
         language=language,
         progress_callback=emit_progress,
+        beam_size=beam_size,
     )
 
 
-def handle_asr_transcribe(request: Request) -> dict[str, Any]:
-    """Handle asr.transcribe request.
-
-    This is primarily for testing - normal flow uses recording.stop which
-    automatically transcribes.
-
-    Params:
-        audio_path: Path to audio file (WAV/FLAC)
+def load_audio_file(path: Path) -> tuple[Any, int]:
+    """Load a WAV file as mono float32 samples.
 
     Returns:
-        TranscriptionResult dict
+        Tuple of (audio, sample_rate).
     """
     import numpy as np
 
-    params = request.params
-    audio_path = params.get("audio_path")
-
-    if not audio_path:
-        raise ASRError("audio_path parameter required")
-
-    path = Path(audio_path)
     if not path.exists():
-        raise ASRError(f"Audio file not found: {audio_path}")
+        raise ASRError(f"Audio file not found: {path}")
 
-    # Load audio file
     try:
         from scipy.io import wavfile
 
@@ -538,11 +580,155 @@ def handle_asr_transcribe(request: Request) -> dict[str, Any]:
     except Exception as e:
         raise ASRError(f"Failed to load audio: {e}") from e
 
+    return audio, int(sample_rate)
+
+
+def handle_asr_transcribe(request: Request) -> dict[str, Any]:
+    """Handle asr.transcribe request.
+
+    This is primarily for testing - normal flow uses recording.stop which
+    automatically transcribes.
+
+    Params:
+        audio_path: Path to audio file (WAV/FLAC)
+
+    Returns:
+        TranscriptionResult dict
+    """
+    params = request.params
+    audio_path = params.get("audio_path")
+
+    if not audio_path:
+        raise ASRError("audio_path parameter required")
+
+    audio, sample_rate = load_audio_file(Path(audio_path))
+
     engine = get_engine()
     result = engine.transcribe(audio, sample_rate)
     return result.to_dict()
 
 
+def _compare_one_model(
+    engine: ASREngine,
+    model_id: str,
+    audio: Any,
+    sample_rate: int,
+    device_pref: str,
+    language: Optional[str],
+) -> dict[str, Any]:
+    entry: dict[str, Any] = {"model_id": model_id}
+    try:
+        manifest = load_manifest(model_id)
+        if not engine._cache_manager.check_cache(manifest):
+            raise ModelNotFoundError(f"Model {model_id} is not downloaded")
+
+        start_time = time.time()
+        engine.initialize(model_id, device_pref, language=language)
+        entry["load_ms"] = int((time.time() - start_time) * 1000)
+
+        start_time = time.time()
+        result = engine.transcribe(audio, sample_rate)
+        entry["transcribe_ms"] = int((time.time() - start_time) * 1000)
+        entry["text"] = result.text
+        if result.confidence is not None:
+            entry["confidence"] = result.confidence
+    except Exception as e:
+        entry["error"] = {"code": getattr(e, "code", "E_ASR"), "message": str(e)}
+        log(f"Model comparison failed for {model_id}: {entry['error']['code']}")
+    return entry
+
+
+def handle_batch_compare_models(request: Request) -> dict[str, Any]:
+    """Handle batch.compare_models request.
+
+    Transcribes one audio file with each requested model in turn. Runs in the
+    auxiliary sidecar, so swapping models never disturbs live dictation.
+    Models that are not downloaded are reported per entry instead of being
+    downloaded; a failing model does not abort the others.
+
+    Params:
+        audio_path: Path to audio file (WAV)
+        model_ids: Non-empty list of model IDs, in display order
+        device_pref: Optional device preference ("auto", "cuda", "cpu")
+        language: Optional language code
+
+    Returns:
+        audio_duration_ms: Length of the compared audio
+        results: One entry per model with text, load_ms and transcribe_ms,
+            or error {code, message}
+    """
+    params = request.params
+    audio_path = params.get("audio_path")
+    model_ids = params.get("model_ids")
+
+    if not audio_path:
+        raise ASRError("audio_path parameter required", code="E_INVALID_PARAMS")
+    if (
+        not isinstance(model_ids, list)
+        or not model_ids
+        or not all(isinstance(model_id, str) and model_id for model_id in model_ids)
+    ):
+        raise ASRError(
+            "model_ids must be a non-empty list of model IDs", code="E_INVALID_PARAMS"
+        )
+
+    device_pref = params.get("device_pref") or "auto"
+    language = normalize_initialize_language(params.get("language"))
+
+    audio, sample_rate = load_audio_file(Path(audio_path))
+    engine = get_engine()
+    results = [
+        _compare_one_model(engine, model_id, audio, sample_rate, device_pref, language)
+        for model_id in model_ids
+    ]
+
+    return {
+        "audio_duration_ms": int(len(audio) * 1000 / sample_rate) if sample_rate else 0,
+        "results": results,
+    }
+
+
+def handle_file_transcribe(request: Request) -> dict[str, Any]:
+    """Handle file.transcribe request.
+
+    Transcribes a dropped audio file with the configured model. Runs in the
+    auxiliary sidecar, which loads its own copy of the model, so a long file
+    never blocks the live sidecar's dispatch loop during dictation. A model
+    that is not downloaded is reported instead of being downloaded.
+
+    Params:
+        audio_path: Path to audio file (WAV)
+        model_id: Model to transcribe with
+        device_pref: Optional device preference ("auto", "cuda", "cpu")
+        language: Optional language code
+        beam_size: Optional beam size for models that support it
+
+    Returns:
+        TranscriptionResult dict
+    """
+    params = request.params
+    audio_path = params.get("audio_path")
+    model_id = params.get("model_id")
+
+    if not audio_path:
+        raise ASRError("audio_path parameter required", code="E_INVALID_PARAMS")
+    if not isinstance(model_id, str) or not model_id:
+        raise ASRError("model_id parameter required", code="E_INVALID_PARAMS")
+
+    device_pref = params.get("device_pref") or "auto"
+    language = normalize_initialize_language(params.get("language"))
+    beam_size = normalize_initialize_beam_size(params.get("beam_size"))
+
+    audio, sample_rate = load_audio_file(Path(audio_path))
+    engine = get_engine()
+    if not engine._cache_manager.check_cache(load_manifest(model_id)):
+        raise ModelNotFoundError(f"Model {model_id} is not downloaded")
+
+    engine.initialize(model_id, device_pref, language=language, beam_size=beam_size)
+    result = engine.transcribe(audio, sample_rate)
+    return result.to_dict()
+
+
 def handle_asr_status(request: Request) -> dict[str, Any]:
     """Handle asr.status request.
 
@@ -550,3 +736,15 @@ def handle_asr_status(request: Request) -> dict[str, Any]:
     """
     engine = get_engine()
     return engine.get_status()
+
+
+def handle_asr_unload(request: Request) -> dict[str, Any]:
+    """Handle asr.unload request.
+
+    Frees the loaded model while the host is fully paused; the host sends
+    asr.initialize to load it again. Returns the resulting engine status.
+    """
+    engine = get_engine()
+    engine.unload()
+    log("ASR model unloaded on host request")
+    return engine.get_status()
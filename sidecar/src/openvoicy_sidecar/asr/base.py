@@ -49,11 +49,19 @@ class TranscriptionResult:
 
 @dataclass
 class InitProgress:
-    """Progress during initialization."""
+    """Progress during initialization.
+
+    ``stage`` names the step of loading a model into memory: ``weights``
+    (reading the checkpoint), ``compile`` (preparing it for the device), or
+    ``warmup`` (first inference). ``percent`` is set only by steps that can
+    measure their progress.
+    """
 
     state: str
     detail: str = ""
     progress: Optional[dict[str, Any]] = None
+    stage: Optional[str] = None
+    percent: Optional[int] = None
 
     def to_dict(self) -> dict[str, Any]:
         """Convert to event format."""
@@ -62,6 +70,10 @@ class InitProgress:
             result["detail"] = self.detail
         if self.progress:
             result["progress"] = self.progress
+        if self.stage:
+            result["stage"] = self.stage
+        if self.percent is not None:
+            result["percent"] = self.percent
         return result
 
 
@@ -146,6 +146,7 @@ class ParakeetBackend(ASRBackend):
                 InitProgress(
                     state="loading_model",
                     detail=f"Loading model from {nemo_file.name}...",
+                    stage="weights",
                 )
             )
 
@@ -162,6 +163,15 @@ class ParakeetBackend(ASRBackend):
                 map_location=device,
             )
 
+            if progress_callback:
+                progress_callback(
+                    InitProgress(
+                        state="loading_model",
+                        detail=f"Preparing model on {device}...",
+                        stage="compile",
+                    )
+                )
+
             # Move to device and set to eval mode
             self._model = self._model.to(device)
             self._model.eval()
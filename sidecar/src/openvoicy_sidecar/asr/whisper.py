@@ -51,6 +51,7 @@ class WhisperBackend:
         self._state: ASRState = ASRState.UNINITIALIZED
         self._model_path: Optional[Path] = None
         self._language: Optional[str] = None  # None => auto-detect
+        self._beam_size: int = 5
 
     @staticmethod
     def is_available() -> bool:
@@ -86,6 +87,18 @@ class WhisperBackend:
         self._language = code
         log(f"Whisper language configured: {code}")
 
+    @property
+    def beam_size(self) -> int:
+        """Return the decoder beam width used for transcription."""
+        return self._beam_size
+
+    def set_beam_size(self, beam_size: int) -> None:
+        """Configure the decoder beam width (1 = greedy)."""
+        if beam_size < 1:
+            raise ValueError(f"Invalid beam_size {beam_size}; must be at least 1.")
+        self._beam_size = beam_size
+        log(f"Whisper beam size configured: {beam_size}")
+
     def initialize(
         self,
         model_path: Path,
@@ -112,7 +125,11 @@ class WhisperBackend:
 
         if progress_callback:
             progress_callback(
-                InitProgress(state="loading_model", detail="Loading Whisper model...")
+                InitProgress(
+                    state="loading_model",
+                    detail="Loading Whisper model...",
+                    stage="weights",
+                )
             )
 
         # Resolve device
@@ -188,7 +205,7 @@ class WhisperBackend:
             segments, info = self._model.transcribe(
                 audio,
                 language=self._language,
-                beam_size=5,
+                beam_size=self._beam_size,
             )
             text = " ".join(seg.text.strip() for seg in segments)
             duration_ms = int((time.time() - start) * 1000)
@@ -14,6 +14,11 @@ Edge Cases Handled:
 - Permission denied: E_MIC_PERMISSION error
 - Device not found: E_DEVICE_NOT_FOUND error
 - Hot-plug: Graceful handling when device disappears
+
+Input Gain:
+Quiet microphones can be boosted (or loud ones attenuated) with a software
+gain per device UID, applied to captured samples during recording and
+metering. Samples are clipped to [-1, 1] after the gain.
 """
 
 from __future__ import annotations
@@ -21,7 +26,7 @@ from __future__ import annotations
 import hashlib
 import sys
 from dataclasses import dataclass
-from typing import Any
+from typing import Any, Mapping
 
 from .protocol import (
     ERROR_DEVICE_NOT_FOUND,
@@ -34,6 +39,13 @@ from .protocol import (
 # Global state for selected device
 _active_device_uid: str | None = None
 
+# Input gain range in dB
+MIN_GAIN_DB = -20.0
+MAX_GAIN_DB = 30.0
+
+# Input gain in dB per device UID; devices without an entry are captured unchanged
+_device_gains_db: dict[str, float] = {}
+
 
 @dataclass
 class AudioDevice:
@@ -244,6 +256,68 @@ def get_active_device_uid() -> str | None:
     return _active_device_uid
 
 
+def clamp_gain_db(gain_db: float) -> float:
+    """Clamp an input gain to the supported range."""
+    return max(MIN_GAIN_DB, min(MAX_GAIN_DB, float(gain_db)))
+
+
+def get_device_gain_db(uid: str | None) -> float:
+    """Get the input gain of a device in dB (0.0 if none was set)."""
+    if uid is None:
+        return 0.0
+    return _device_gains_db.get(uid, 0.0)
+
+
+def set_device_gain_db(uid: str, gain_db: float) -> float:
+    """Set the input gain of a device, clamped to the supported range.
+
+    Takes effect immediately for recordings and meters using the device.
+
+    Returns:
+        The gain in effect.
+    """
+    clamped = clamp_gain_db(gain_db)
+    if clamped == 0.0:
+        _device_gains_db.pop(uid, None)
+    else:
+        _device_gains_db[uid] = clamped
+    return clamped
+
+
+def set_device_gains(gains: Mapping[str, Any]) -> None:
+    """Replace all input gains with a device UID to dB mapping.
+
+    Entries that are not numbers are ignored.
+    """
+    _device_gains_db.clear()
+    for uid, gain_db in gains.items():
+        if isinstance(gain_db, (int, float)) and not isinstance(gain_db, bool):
+            set_device_gain_db(str(uid), gain_db)
+
+
+def gain_factor(gain_db: float) -> float:
+    """Convert a gain in dB to a linear sample multiplier."""
+    return 10.0 ** (gain_db / 20.0)
+
+
+def apply_gain(samples: Any, gain_db: float) -> None:
+    """Apply a gain in dB to float samples in place, clipping to [-1, 1]."""
+    if gain_db == 0.0:
+        return
+    samples *= gain_factor(gain_db)
+    samples.clip(-1.0, 1.0, out=samples)
+
+
+def resolve_device_uid(uid: str | None) -> str | None:
+    """Resolve a device UID, or None for the active or default device."""
+    if uid is not None:
+        return uid
+    if _active_device_uid is not None:
+        return _active_device_uid
+    default = get_default_device()
+    return default.uid if default is not None else None
+
+
 # === JSON-RPC Handlers ===
 
 
@@ -290,6 +364,47 @@ def handle_audio_set_device(request: Request) -> dict[str, Any]:
         raise MicPermissionError("Microphone permission denied")
 
 
+def handle_audio_get_gain(request: Request) -> dict[str, Any]:
+    """Handle audio.get_gain request.
+
+    Params:
+        device_uid: Device UID, or null for the active device.
+
+    Returns:
+        device_uid: The resolved device UID (null if no device is available).
+        gain_db: Input gain of the device in dB.
+    """
+    device_uid = resolve_device_uid(request.params.get("device_uid"))
+    return {"device_uid": device_uid, "gain_db": get_device_gain_db(device_uid)}
+
+
+def handle_audio_set_gain(request: Request) -> dict[str, Any]:
+    """Handle audio.set_gain request.
+
+    Params:
+        device_uid: Device UID, or null for the active device.
+        gain_db: Input gain in dB, clamped to MIN_GAIN_DB..MAX_GAIN_DB.
+
+    Returns:
+        device_uid: The resolved device UID.
+        gain_db: The gain in effect after clamping.
+
+    Errors:
+        E_INVALID_PARAMS: gain_db missing or not a number.
+        E_DEVICE_NOT_FOUND: No device to apply the gain to.
+    """
+    gain_db = request.params.get("gain_db")
+    if not isinstance(gain_db, (int, float)) or isinstance(gain_db, bool):
+        raise InvalidGainError("gain_db must be a number")
+
+    requested_uid = request.params.get("device_uid")
+    device_uid = resolve_device_uid(requested_uid)
+    if device_uid is None:
+        raise DeviceNotFoundError("No audio input device available", requested_uid)
+
+    return {"device_uid": device_uid, "gain_db": set_device_gain_db(device_uid, gain_db)}
+
+
 # === Custom Exceptions for Error Handling ===
 
 
@@ -308,3 +423,11 @@ class DeviceNotFoundError(Exception):
         self.message = message
         self.device_uid = device_uid
         super().__init__(message)
+
+
+class InvalidGainError(Exception):
+    """Invalid input gain parameters."""
+
+    def __init__(self, message: str):
+        self.message = message
+        super().__init__(message)
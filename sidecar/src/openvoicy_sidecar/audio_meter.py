@@ -4,6 +4,9 @@ This module provides microphone level monitoring for:
 - Mic testing in settings (source="meter")
 - Visual feedback during recording (source="recording")
 
+During a mic test it can also run the VAD over the meter stream and emit its
+speech/silence decisions, so VAD timings can be tuned without dictating.
+
 The meter is low-CPU and doesn't require ASR model load.
 """
 
@@ -16,9 +19,22 @@ from typing import Any, Optional
 
 import numpy as np
 
-from .audio import find_device_by_uid, get_default_device
-from .notifications import calculate_audio_levels, emit_audio_level
+from .audio import (
+    apply_gain,
+    find_device_by_uid,
+    get_default_device,
+    get_device_gain_db,
+    set_device_gains,
+)
+from .notifications import calculate_audio_levels, emit_audio_level, emit_vad_preview
 from .protocol import Request, log
+from .vad import (
+    DEFAULT_MIN_SPEECH_MS,
+    DEFAULT_SILENCE_MS,
+    VadConfig,
+    VadState,
+    VoiceActivityDetector,
+)
 
 # === Constants ===
 
@@ -49,22 +65,36 @@ class AudioMeter:
         self._lock = threading.Lock()
         self._thread: Optional[threading.Thread] = None
         self._interval_ms = DEFAULT_INTERVAL_MS
+        # Device whose input gain applies to metered audio
+        self._gain_device_uid: Optional[str] = None
+        self._vad: Optional[VoiceActivityDetector] = None
+        self._vad_silence_ms = 0
+        # Samples not yet fed to the VAD; unlike the level buffer, every
+        # sample must be seen exactly once.
+        self._vad_pending: list[np.ndarray] = []
 
     @property
     def is_running(self) -> bool:
         """Check if meter is running."""
         return self._running
 
+    @property
+    def vad_preview(self) -> bool:
+        """Check if VAD decisions are being previewed."""
+        return self._vad is not None
+
     def start(
         self,
         device_uid: Optional[str] = None,
         interval_ms: int = DEFAULT_INTERVAL_MS,
+        vad: Optional[VadConfig] = None,
     ) -> None:
         """Start the audio meter.
 
         Args:
             device_uid: Device to monitor, or None for default
             interval_ms: Emission interval in ms (clamped to 30-250ms)
+            vad: Preview VAD decisions with this config, or None to skip
 
         Raises:
             RuntimeError: If meter already running
@@ -77,6 +107,12 @@ class AudioMeter:
 
             self._interval_ms = _clamp_interval(interval_ms)
             self._buffer.clear()
+            self._vad_pending.clear()
+            self._vad = None
+            if vad is not None:
+                vad.sample_rate = METER_SAMPLE_RATE
+                self._vad = VoiceActivityDetector(vad)
+                self._vad_silence_ms = vad.silence_ms
 
             # Resolve device
             device_index = None
@@ -85,6 +121,10 @@ class AudioMeter:
                 if device is None:
                     raise ValueError(f"Device not found: {device_uid}")
                 device_index = self._get_device_index(device_uid)
+                self._gain_device_uid = device_uid
+            else:
+                default = get_default_device()
+                self._gain_device_uid = default.uid if default is not None else None
 
             # Open audio stream
             try:
@@ -149,8 +189,11 @@ class AudioMeter:
                 return
 
             # Extract mono data
-            mono = indata[:, 0] if indata.ndim > 1 else indata.flatten()
+            mono = indata[:, 0].copy() if indata.ndim > 1 else indata.flatten()
+            apply_gain(mono, get_device_gain_db(self._gain_device_uid))
             self._buffer.extend(mono)
+            if self._vad is not None:
+                self._vad_pending.append(mono.copy())
 
     def _emit_loop(self) -> None:
         """Background loop that emits level events."""
@@ -167,11 +210,36 @@ class AudioMeter:
 
                 # Convert deque to array for analysis
                 audio = np.array(list(self._buffer), dtype=np.float32)
+                pending = self._vad_pending
+                self._vad_pending = []
 
             # Calculate and emit levels
             rms, peak = calculate_audio_levels(audio)
             emit_audio_level(rms=rms, peak=peak, source="meter")
 
+            if self._vad is not None and pending:
+                self._preview_vad(np.concatenate(pending))
+
+    def _preview_vad(self, chunk: np.ndarray) -> None:
+        """Feed new samples to the preview VAD and emit its decision.
+
+        The detector restarts after each auto-stop so the preview keeps
+        running for the rest of the mic test.
+        """
+        detector = self._vad
+        if detector is None:
+            return
+
+        state = detector.feed_audio(chunk)
+        emit_vad_preview(
+            state=state.value,
+            speech_ms=detector.speech_ms,
+            silence_ms=detector.silence_ms,
+            silence_threshold_ms=self._vad_silence_ms,
+        )
+        if state == VadState.AUTO_STOP:
+            detector.reset()
+
     def _get_device_index(self, device_uid: str) -> Optional[int]:
         """Get sounddevice device index from our UID."""
         try:
@@ -242,16 +310,41 @@ class MeterNotRunningError(MeterError):
         super().__init__(message, "E_METER_NOT_RUNNING")
 
 
+def _int_param(value: Any, default: int) -> int:
+    try:
+        return int(value)
+    except (TypeError, ValueError):
+        return default
+
+
+def _preview_vad_config(params: dict[str, Any]) -> Optional[VadConfig]:
+    """Build the preview VAD config when `vad_preview` is requested."""
+    if params.get("vad_preview") is not True:
+        return None
+
+    return VadConfig(
+        sample_rate=METER_SAMPLE_RATE,
+        silence_ms=_int_param(params.get("vad_silence_ms"), DEFAULT_SILENCE_MS),
+        min_speech_ms=_int_param(params.get("vad_min_speech_ms"), DEFAULT_MIN_SPEECH_MS),
+    )
+
+
 def handle_audio_meter_start(request: Request) -> dict[str, Any]:
     """Handle audio.meter_start request.
 
     Params:
         device_uid: Optional device UID to monitor
         interval_ms: Emission interval (30-250ms, default 80ms)
+        vad_preview: Also emit event.vad_preview decisions (default false)
+        vad_silence_ms: Preview silence threshold (default 1200)
+        vad_min_speech_ms: Preview minimum speech (default 250)
+        input_gains: Optional mapping of device UID to input gain in dB;
+            replaces the gains set via audio.set_gain
 
     Returns:
         running: True if meter started
         interval_ms: Actual interval being used (after clamping)
+        vad_preview: Whether VAD decisions are being previewed
 
     Errors:
         E_METER_RUNNING: Meter already running
@@ -260,14 +353,19 @@ def handle_audio_meter_start(request: Request) -> dict[str, Any]:
     """
     device_uid = request.params.get("device_uid")
     interval_ms = request.params.get("interval_ms", DEFAULT_INTERVAL_MS)
+    vad = _preview_vad_config(request.params)
+    input_gains = request.params.get("input_gains")
+    if isinstance(input_gains, dict):
+        set_device_gains(input_gains)
 
     meter = get_meter()
 
     try:
-        meter.start(device_uid, interval_ms)
+        meter.start(device_uid, interval_ms, vad)
         return {
             "running": True,
             "interval_ms": meter._interval_ms,
+            "vad_preview": meter.vad_preview,
         }
     except RuntimeError as e:
         raise MeterAlreadyRunningError(str(e))
@@ -59,6 +59,9 @@ NETWORK_RETRY_MAX_BACKOFF_SECONDS = 8.0
 
 TRUSTED_HF_HOSTS = ("huggingface.co", "hf.co")
 
+# Set by the host when offline mode is on; cached models keep working.
+OFFLINE_MODE_ENV = "OPENVOICY_OFFLINE"
+
 
 class ModelStatus(Enum):
     """Model download/verification status."""
@@ -104,6 +107,37 @@ class ModelFileInfo:
     mirror_urls: list[str] = field(default_factory=list)
 
 
+@dataclass
+class ModelLicense:
+    """License terms attached to a model manifest."""
+
+    spdx_id: str = ""
+    name: str = ""
+    url: str = ""
+    text: str = ""
+    requires_acceptance: bool = False
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> ModelLicense:
+        """Create from the manifest's license object."""
+        return cls(
+            spdx_id=str(data.get("spdx_id", "")),
+            name=str(data.get("name", "")),
+            url=str(data.get("url", "")),
+            text=str(data.get("text", "")),
+            requires_acceptance=data.get("requires_acceptance") is True,
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        """Convert to API response format."""
+        return {
+            "spdx_id": self.spdx_id,
+            "name": self.name,
+            "url": self.url,
+            "text": self.text,
+        }
+
+
 @dataclass
 class ModelManifest:
     """Model manifest containing file information."""
@@ -114,6 +148,7 @@ class ModelManifest:
     total_size_bytes: int
     files: list[ModelFileInfo]
     source_url: str = ""
+    license: ModelLicense | None = None
 
     @classmethod
     def from_dict(cls, data: dict[str, Any]) -> ModelManifest:
@@ -161,6 +196,10 @@ class ModelManifest:
                 )
             )
 
+        license_data = data.get("license")
+        model_license = (
+            ModelLicense.from_dict(license_data) if isinstance(license_data, dict) else None
+        )
         return cls(
             model_id=data.get("model_id", ""),
             revision=data.get("revision", ""),
@@ -168,6 +207,7 @@ class ModelManifest:
             total_size_bytes=data.get("total_size_bytes", 0),
             files=files,
             source_url=data.get("source_url", ""),
+            license=model_license,
         )
 
 
@@ -736,6 +776,11 @@ def build_download_headers(existing_size: int = 0, url: str = "") -> dict[str, s
     return headers
 
 
+def is_offline_mode() -> bool:
+    """Whether the host started the sidecar in offline mode."""
+    return os.environ.get(OFFLINE_MODE_ENV, "").strip() == "1"
+
+
 def download_with_mirrors(
     file_info: ModelFileInfo,
     dest_path: Path,
@@ -753,10 +798,15 @@ def download_with_mirrors(
 
     Raises:
         ModelCacheError: If all mirrors fail after retries.
+        NetworkError: Immediately, in offline mode.
     """
     urls = [file_info.primary_url] + file_info.mirror_urls
     urls = [u for u in urls if u]  # Filter empty URLs
 
+    if is_offline_mode():
+        log(f"Offline mode blocked model download of {file_info.path}")
+        raise NetworkError("Offline mode is on; model download is disabled", "")
+
     if not urls:
         raise NetworkError("No download URLs available", "")
 
@@ -1609,6 +1659,25 @@ def handle_model_get_status(request: Request) -> dict[str, Any]:
     return manager.get_status()
 
 
+def _license_required_response(request: Request, manifest: ModelManifest) -> dict[str, Any] | None:
+    """Return a license_required result if the manifest's license is not yet accepted.
+
+    The host shows the license text and retries with ``license_accepted: true``
+    once the user accepts it; nothing is downloaded until then.
+    """
+    model_license = manifest.license
+    if model_license is None or not model_license.requires_acceptance:
+        return None
+    if request.params.get("license_accepted") is True:
+        return None
+    return {
+        "model_id": manifest.model_id,
+        "revision": manifest.revision,
+        "status": "license_required",
+        "license": model_license.to_dict(),
+    }
+
+
 def handle_model_download(request: Request) -> dict[str, Any]:
     """Handle model.download request.
 
@@ -1626,6 +1695,10 @@ def handle_model_download(request: Request) -> dict[str, Any]:
         raise ModelCacheError("Model manifest not found")
 
     manifest = manager.load_manifest(manifest_path)
+    license_required = _license_required_response(request, manifest)
+    if license_required is not None:
+        return license_required
+
     progress_emitter = _ModelProgressEmitter(manifest.model_id)
 
     progress_emitter.emit(
@@ -1662,6 +1735,9 @@ def handle_model_install(request: Request) -> dict[str, Any]:
     manager = get_cache_manager()
     manifest_path = _resolve_manifest_path_for_model(model_id)
     manifest = manager.load_manifest(manifest_path)
+    license_required = _license_required_response(request, manifest)
+    if license_required is not None:
+        return license_required
 
     with _install_lock:
         if _install_thread is not None and _install_thread.is_alive():
@@ -4,11 +4,16 @@ This module provides:
 - Session tracking for exactly-once delivery semantics
 - Event emission helpers
 - Async transcription pipeline that emits results
+- Result cache for transcription.get_result and identical-audio reuse
+- Retained audio of failed sessions for transcription.retry
 
 Key Invariants:
 - Each session_id receives exactly ONE of: complete OR error (never both)
+- Continuous sessions may emit any number of utterance_complete events first,
+  in segment order, and always before the session's final result
 - Cancelled sessions receive NO notification
 - Session tracking is bounded (old sessions auto-expire)
+- Cached ASR output is reused only for byte-identical audio and the same model
 """
 
 from __future__ import annotations
@@ -16,6 +21,8 @@ from __future__ import annotations
 import hashlib
 import threading
 import time
+from collections import OrderedDict
+from concurrent.futures import ThreadPoolExecutor
 from dataclasses import dataclass, field
 from enum import Enum
 from typing import Any, Callable, Optional
@@ -174,6 +181,176 @@ def get_session_tracker() -> SessionTracker:
     return _session_tracker
 
 
+# === Transcription Result Cache ===
+
+
+@dataclass
+class CachedTranscription:
+    """Raw ASR output for one audio fingerprint."""
+
+    raw_text: str
+    confidence: Optional[float] = None
+    language: Optional[str] = None
+
+
+class TranscriptionResultCache:
+    """Bounded cache of transcription outcomes.
+
+    Keeps the emitted outcome per session so the host can fetch it with
+    ``transcription.get_result`` when a notification was lost or an RPC
+    timed out, and the raw ASR output per audio fingerprint so a retry with
+    identical audio skips the ASR step. Post-processing is not cached because
+    replacement rules may have changed in between.
+    """
+
+    def __init__(self, max_entries: int = 32, max_age_seconds: int = 300):
+        self._outcomes: OrderedDict[str, tuple[float, dict[str, Any]]] = OrderedDict()
+        self._asr: OrderedDict[str, tuple[float, CachedTranscription]] = OrderedDict()
+        self._lock = threading.Lock()
+        self._max_entries = max_entries
+        self._max_age = max_age_seconds
+
+    def record_result(self, session_id: str, params: dict[str, Any]) -> None:
+        """Remember the transcription_complete params emitted for a session."""
+        self._put(self._outcomes, session_id, {"result": dict(params)})
+
+    def record_error(self, session_id: str, kind: str, message: str) -> None:
+        """Remember the transcription_error emitted for a session."""
+        self._put(self._outcomes, session_id, {"error": {"kind": kind, "message": message}})
+
+    def get_outcome(self, session_id: str) -> Optional[dict[str, Any]]:
+        """Return ``{"result": ...}`` or ``{"error": ...}`` for a session."""
+        return self._get(self._outcomes, session_id)
+
+    def record_asr(self, fingerprint: str, transcription: CachedTranscription) -> None:
+        """Remember raw ASR output for an audio fingerprint."""
+        self._put(self._asr, fingerprint, transcription)
+
+    def get_asr(self, fingerprint: str) -> Optional[CachedTranscription]:
+        """Return cached ASR output for an audio fingerprint."""
+        return self._get(self._asr, fingerprint)
+
+    def _put(self, entries: OrderedDict, key: str, value: Any) -> None:
+        with self._lock:
+            self._cleanup_old(entries)
+            entries[key] = (time.monotonic(), value)
+            entries.move_to_end(key)
+            while len(entries) > self._max_entries:
+                entries.popitem(last=False)
+
+    def _get(self, entries: OrderedDict, key: str) -> Any:
+        with self._lock:
+            self._cleanup_old(entries)
+            entry = entries.get(key)
+            return entry[1] if entry else None
+
+    def _cleanup_old(self, entries: OrderedDict) -> None:
+        """Remove entries older than max_age (internal, called with lock)."""
+        now = time.monotonic()
+        while entries:
+            created_at, _ = next(iter(entries.values()))
+            if now - created_at <= self._max_age:
+                break
+            entries.popitem(last=False)
+
+
+# Global result cache
+_result_cache: Optional[TranscriptionResultCache] = None
+
+
+def get_result_cache() -> TranscriptionResultCache:
+    """Get the global transcription result cache."""
+    global _result_cache
+    if _result_cache is None:
+        _result_cache = TranscriptionResultCache()
+    return _result_cache
+
+
+def audio_fingerprint(audio: np.ndarray, model_id: Optional[str] = None) -> str:
+    """Hash audio samples together with the model that would transcribe them."""
+    digest = hashlib.sha256()
+    digest.update(str(model_id).encode("utf-8"))
+    digest.update(b"\0")
+    digest.update(np.ascontiguousarray(audio, dtype=np.float32).tobytes())
+    return digest.hexdigest()
+
+
+def get_transcription_result(session_id: str) -> dict[str, Any]:
+    """Describe what the sidecar knows about a session's transcription.
+
+    ``state`` is one of pending, completed, error, cancelled, or unknown
+    (never seen or expired). Completed and error states include the emitted
+    ``result`` or ``error`` while it is still cached.
+    """
+    state = get_session_tracker().get_state(session_id)
+    response: dict[str, Any] = {
+        "session_id": session_id,
+        "state": state.value if state is not None else "unknown",
+    }
+    if state in (SessionState.COMPLETED, SessionState.ERROR):
+        outcome = get_result_cache().get_outcome(session_id)
+        if outcome:
+            response.update(outcome)
+    return response
+
+
+# === Retry Audio Retention ===
+
+# Audio of the most recent failed sessions, kept so the host can ask for one
+# more attempt after a transient failure without re-recording.
+MAX_RETAINED_FAILED_SESSIONS = 2
+
+_failed_audio: OrderedDict[str, np.ndarray] = OrderedDict()
+_failed_audio_lock = threading.Lock()
+
+
+def _retain_failed_audio(session_id: str, audio: np.ndarray) -> None:
+    with _failed_audio_lock:
+        _failed_audio[session_id] = audio
+        _failed_audio.move_to_end(session_id)
+        while len(_failed_audio) > MAX_RETAINED_FAILED_SESSIONS:
+            _failed_audio.popitem(last=False)
+
+
+def retry_transcription(session_id: str, sample_rate: int) -> bool:
+    """Transcribe a failed session's retained audio once more.
+
+    The retained audio is consumed, so each failure gets at most one retry.
+    Returns False when no audio is retained for the session (never failed,
+    already retried, evicted, or lost in a sidecar restart).
+    """
+    with _failed_audio_lock:
+        audio = _failed_audio.pop(session_id, None)
+    if audio is None:
+        return False
+
+    log(f"Retrying transcription for session {session_id}")
+    transcribe_session_async(session_id, audio, sample_rate)
+    return True
+
+
+# Audio of the most recent sessions, kept so the host can export it for
+# model comparison after the fact.
+MAX_RETAINED_RECENT_SESSIONS = 2
+
+_recent_audio: OrderedDict[str, tuple[np.ndarray, int]] = OrderedDict()
+_recent_audio_lock = threading.Lock()
+
+
+def _retain_recent_audio(session_id: str, audio: np.ndarray, sample_rate: int) -> None:
+    with _recent_audio_lock:
+        _recent_audio[session_id] = (audio, sample_rate)
+        _recent_audio.move_to_end(session_id)
+        while len(_recent_audio) > MAX_RETAINED_RECENT_SESSIONS:
+            _recent_audio.popitem(last=False)
+
+
+def get_session_audio(session_id: str) -> Optional[tuple[np.ndarray, int]]:
+    """Return a recent session's preprocessed audio and sample rate, if retained."""
+    with _recent_audio_lock:
+        return _recent_audio.get(session_id)
+
+
 # === Event Emission Helpers ===
 
 
@@ -232,6 +409,32 @@ def emit_audio_level(
     write_notification(notification)
 
 
+def emit_vad_preview(
+    state: str,
+    speech_ms: float,
+    silence_ms: float,
+    silence_threshold_ms: int,
+) -> None:
+    """Emit a vad_preview event while the mic test previews VAD decisions.
+
+    Args:
+        state: VadState value (waiting_for_speech, speech, silence, auto_stop)
+        speech_ms: Speech accumulated since the last auto-stop
+        silence_ms: Trailing silence counted towards auto-stop
+        silence_threshold_ms: Configured silence before auto-stop
+    """
+    params: dict[str, Any] = {
+        "source": "meter",
+        "state": state,
+        "speech_ms": int(speech_ms),
+        "silence_ms": int(silence_ms),
+        "silence_threshold_ms": silence_threshold_ms,
+    }
+
+    notification = Notification(method="event.vad_preview", params=params)
+    write_notification(notification)
+
+
 def emit_model_progress(
     model_id: str,
     current: int,
@@ -321,6 +524,7 @@ def emit_transcription_complete(
     if language:
         params["language"] = language
 
+    get_result_cache().record_result(session_id, params)
     notification = Notification(method="event.transcription_complete", params=params)
     write_notification(notification)
     log(
@@ -330,6 +534,48 @@ def emit_transcription_complete(
     return True
 
 
+def emit_utterance_complete(
+    session_id: str,
+    segment_index: int,
+    text: str,
+    duration_ms: int,
+    audio_duration_ms: int,
+    confidence: Optional[float] = None,
+    language: Optional[str] = None,
+    raw_text: Optional[str] = None,
+) -> bool:
+    """Emit an utterance_complete event for one segment of a continuous session.
+
+    Unlike transcription_complete, a session may emit many of these; they stop
+    once the session is cancelled.
+
+    Returns True if emitted, False if the session was cancelled.
+    """
+    if get_session_tracker().get_state(session_id) == SessionState.CANCELLED:
+        log(f"Blocked utterance_complete for cancelled session {session_id}")
+        return False
+
+    params: dict[str, Any] = {
+        "session_id": session_id,
+        "segment_index": segment_index,
+        "text": text,
+        "raw_text": raw_text if raw_text is not None else text,
+        "duration_ms": duration_ms,
+        "audio_duration_ms": audio_duration_ms,
+    }
+    if confidence is not None:
+        params["confidence"] = round(confidence, 3)
+    if language:
+        params["language"] = language
+
+    write_notification(Notification(method="event.utterance_complete", params=params))
+    log(
+        "Event: utterance_complete "
+        f"session={session_id}, segment={segment_index}, text_len={len(text)}"
+    )
+    return True
+
+
 def emit_transcription_error(
     session_id: str,
     kind: str,
@@ -360,6 +606,7 @@ def emit_transcription_error(
         "message": message,
     }
 
+    get_result_cache().record_error(session_id, kind, message)
     notification = Notification(method="event.transcription_error", params=params)
     write_notification(notification)
     log(f"Event: transcription_error session={session_id}, kind={kind}")
@@ -399,33 +646,92 @@ def calculate_audio_levels(audio: np.ndarray) -> tuple[float, float]:
 
 # === Async Transcription Pipeline ===
 
+# Utterances of continuous sessions, and the final result that follows them,
+# run one at a time so their events leave in segment order.
+_utterance_executor: Optional[ThreadPoolExecutor] = None
+_utterance_executor_lock = threading.Lock()
+
+
+def _get_utterance_executor() -> ThreadPoolExecutor:
+    global _utterance_executor
+    with _utterance_executor_lock:
+        if _utterance_executor is None:
+            _utterance_executor = ThreadPoolExecutor(
+                max_workers=1, thread_name_prefix="utterance"
+            )
+        return _utterance_executor
+
+
+def _run_asr(session_id: str, audio: np.ndarray) -> tuple[CachedTranscription, str, int]:
+    """Transcribe preprocessed audio and post-process the text.
+
+    Audio identical to a recently transcribed session reuses the cached ASR
+    output instead of running the model again.
+
+    Returns the ASR output, the post-processed text, and compute time in ms.
+    """
+    # Import here to avoid circular imports
+    from .asr import get_engine, NotInitializedError
+    from .replacements import get_current_rules, process_text_with_full_stats
+
+    # Get ASR engine
+    engine = get_engine()
+    if not engine.is_ready():
+        raise NotInitializedError("ASR model not initialized")
+
+    # Transcribe, unless this exact audio was already transcribed
+    import time as time_module
+
+    cache = get_result_cache()
+    fingerprint = audio_fingerprint(audio, engine.get_status().get("model_id"))
+    start_time = time_module.time()
+    result = cache.get_asr(fingerprint)
+    if result is not None:
+        log(f"Reusing cached ASR result for session {session_id}")
+    else:
+        transcription = engine.transcribe(audio)
+        result = CachedTranscription(
+            raw_text=transcription.text,
+            confidence=transcription.confidence,
+            language=transcription.language,
+        )
+        cache.record_asr(fingerprint, result)
+    compute_ms = int((time_module.time() - start_time) * 1000)
+
+    # Post-process: use the exact same pipeline as replacements.preview
+    text, _, _, _ = process_text_with_full_stats(result.raw_text, rules=get_current_rules())
+    return result, text, compute_ms
+
 
 def transcribe_session_async(
     session_id: str,
     audio: np.ndarray,
     sample_rate: int,
+    after_utterances: bool = False,
 ) -> None:
     """Start async transcription for a session.
 
     This runs transcription in a background thread and emits
     either transcription_complete or transcription_error when done.
+    Audio identical to a recently transcribed session reuses the cached
+    ASR output instead of running the model again.
 
     Args:
         session_id: Session to transcribe
         audio: Preprocessed audio data as float32
         sample_rate: Sample rate of audio (kept for compatibility/telemetry)
+        after_utterances: Queue behind pending utterances of a continuous
+            session so the final result is emitted last
     """
     tracker = get_session_tracker()
     tracker.register(session_id)
+    if len(audio) > 0:
+        _retain_recent_audio(session_id, audio, sample_rate)
 
     def run_transcription():
         try:
             emit_status_changed("transcribing", "Processing audio...")
 
-            # Import here to avoid circular imports
-            from .asr import get_engine, NotInitializedError
-            from .replacements import get_current_rules, process_text_with_full_stats
-
             processed_audio = audio
 
             if len(processed_audio) == 0:
@@ -434,22 +740,7 @@ def transcribe_session_async(
                 emit_status_changed("idle")
                 return
 
-            # Get ASR engine
-            engine = get_engine()
-            if not engine.is_ready():
-                raise NotInitializedError("ASR model not initialized")
-
-            # Transcribe
-            import time as time_module
-
-            start_time = time_module.time()
-            result = engine.transcribe(processed_audio)
-            compute_ms = int((time_module.time() - start_time) * 1000)
-
-            # Post-process: use the exact same pipeline as replacements.preview
-            raw_text = result.text
-            rules = get_current_rules()
-            text, _, _, _ = process_text_with_full_stats(raw_text, rules=rules)
+            result, text, compute_ms = _run_asr(session_id, processed_audio)
 
             # Emit result
             emit_transcription_complete(
@@ -458,7 +749,7 @@ def transcribe_session_async(
                 duration_ms=compute_ms,
                 confidence=result.confidence,
                 language=result.language,
-                raw_text=raw_text,
+                raw_text=result.raw_text,
                 final_text=text,
             )
             emit_status_changed("idle")
@@ -469,9 +760,70 @@ def transcribe_session_async(
                 "Transcription error for session "
                 f"{session_id}: kind={error_kind}, error_len={len(str(e))}"
             )
+            if len(audio) > 0:
+                _retain_failed_audio(session_id, audio)
             emit_transcription_error(session_id, error_kind, str(e))
             emit_status_changed("error", str(e))
 
+    if after_utterances:
+        _get_utterance_executor().submit(run_transcription)
+        return
+
     # Start background thread
     thread = threading.Thread(target=run_transcription, daemon=True)
     thread.start()
+
+
+def transcribe_utterance_async(
+    session_id: str,
+    segment_index: int,
+    audio: np.ndarray,
+    audio_duration_ms: int,
+    preprocess: Callable[[np.ndarray], np.ndarray],
+) -> None:
+    """Queue one utterance of a continuous session for transcription.
+
+    Every segment index is emitted exactly once, with empty text when the
+    utterance was silence or failed to transcribe, so the host never waits
+    on a gap. Failures do not end the session.
+
+    Args:
+        session_id: Session the utterance belongs to
+        segment_index: Zero-based position of the utterance in the session
+        audio: Raw captured audio for the utterance
+        audio_duration_ms: Length of the captured audio
+        preprocess: Converts captured audio to ASR input
+    """
+
+    def run_utterance():
+        text = ""
+        raw_text = None
+        compute_ms = 0
+        confidence = None
+        language = None
+        try:
+            processed_audio = preprocess(audio)
+            if len(processed_audio) > 0:
+                result, text, compute_ms = _run_asr(session_id, processed_audio)
+                raw_text = result.raw_text
+                confidence = result.confidence
+                language = result.language
+        except Exception as e:
+            error_kind = getattr(e, "code", "E_TRANSCRIBE")
+            log(
+                "Utterance transcription error for session "
+                f"{session_id}: segment={segment_index}, kind={error_kind}, "
+                f"error_len={len(str(e))}"
+            )
+        emit_utterance_complete(
+            session_id=session_id,
+            segment_index=segment_index,
+            text=text,
+            duration_ms=compute_ms,
+            audio_duration_ms=audio_duration_ms,
+            confidence=confidence,
+            language=language,
+            raw_text=raw_text,
+        )
+
+    _get_utterance_executor().submit(run_utterance)
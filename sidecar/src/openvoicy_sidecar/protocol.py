@@ -3,13 +3,24 @@
 from __future__ import annotations
 
 import json
+import os
+import socket
 import sys
 from dataclasses import dataclass, field
-from typing import Any
+from typing import IO, Any
 
 # Maximum line length for incoming messages (1 MiB)
 MAX_LINE_LENGTH = 1024 * 1024
 
+# Set by the host when it offers a socket or named pipe instead of stdio:
+# "unix:<path>" or "pipe:<base name>" (the host writes requests to
+# "<base>-requests" and reads responses from "<base>-responses").
+ENDPOINT_ENV = "OPENVOICY_IPC_ENDPOINT"
+
+# Stream responses and notifications go to; stdout unless set_output() says
+# otherwise.
+_output: IO[str] | None = None
+
 # JSON-RPC 2.0 error codes
 ERROR_PARSE_ERROR = -32700
 ERROR_INVALID_REQUEST = -32600
@@ -167,18 +178,62 @@ def parse_line(line: str) -> Request | None:
     return Request.from_dict(data)
 
 
+def open_transport() -> tuple[IO[str], IO[str]]:
+    """Open the channel to the host as a (reader, writer) pair.
+
+    Connects to the endpoint named by OPENVOICY_IPC_ENDPOINT, so that stdout
+    stays free for debuggers or profilers wrapping the sidecar. Falls back to
+    stdin/stdout when unset or when the endpoint cannot be opened; the host
+    then talks over stdio too.
+    """
+    endpoint = os.environ.get(ENDPOINT_ENV, "").strip()
+    if not endpoint:
+        return sys.stdin, sys.stdout
+
+    kind, _, address = endpoint.partition(":")
+    try:
+        if kind == "unix" and address and hasattr(socket, "AF_UNIX"):
+            sock = socket.socket(socket.AF_UNIX, socket.SOCK_STREAM)
+            sock.connect(address)
+            reader = sock.makefile("r", encoding="utf-8", newline="\n")
+            writer = sock.makefile("w", encoding="utf-8", newline="\n")
+            return reader, writer
+        if kind == "pipe" and address:
+            reader = open(f"{address}-requests", encoding="utf-8", newline="\n")
+            writer = open(f"{address}-responses", "w", encoding="utf-8", newline="\n")
+            return reader, writer
+    except OSError as e:
+        log(f"Failed to open IPC endpoint {endpoint}: {e}; using stdio")
+        return sys.stdin, sys.stdout
+
+    log(f"Unsupported IPC endpoint {endpoint}; using stdio")
+    return sys.stdin, sys.stdout
+
+
+def set_output(stream: IO[str] | None) -> None:
+    """Send responses and notifications to stream (None restores stdout)."""
+    global _output
+    _output = stream
+
+
+def _out() -> IO[str]:
+    return _output if _output is not None else sys.stdout
+
+
 def write_response(response: Response) -> None:
-    """Write a response to stdout and flush."""
-    sys.stdout.write(response.to_json())
-    sys.stdout.write("\n")
-    sys.stdout.flush()
+    """Write a response to the host and flush."""
+    out = _out()
+    out.write(response.to_json())
+    out.write("\n")
+    out.flush()
 
 
 def write_notification(notification: Notification) -> None:
-    """Write a notification to stdout and flush."""
-    sys.stdout.write(notification.to_json())
-    sys.stdout.write("\n")
-    sys.stdout.flush()
+    """Write a notification to the host and flush."""
+    out = _out()
+    out.write(notification.to_json())
+    out.write("\n")
+    out.flush()
 
 
 def write_event(event_type: str, data: dict[str, Any]) -> None:
@@ -9,6 +9,9 @@ Key Features:
 - Thread-safe: Audio callback runs in separate thread
 - Session tracking: Each recording has a unique session_id
 - Clean cancellation: Discard buffered audio without processing
+- Continuous mode: VAD pauses cut utterances that are transcribed while the
+  microphone stays open; an optional flush interval also cuts at the next
+  short gap once that much speech is buffered
 
 Thread Safety:
 - sounddevice runs callbacks in a PortAudio thread
@@ -28,7 +31,14 @@ from typing import Any, Mapping
 
 import numpy as np
 
-from .audio import get_active_device_uid, find_device_by_uid, get_default_device
+from .audio import (
+    apply_gain,
+    find_device_by_uid,
+    get_active_device_uid,
+    get_default_device,
+    get_device_gain_db,
+    set_device_gains,
+)
 from .protocol import Request, log
 
 # === Constants ===
@@ -42,6 +52,9 @@ DEFAULT_MAX_DURATION_SEC = 120  # 2 minutes max recording
 # At 16kHz mono, 1024 samples = 64ms per chunk
 CHUNK_SIZE = 1024
 
+# Shortest accepted flush interval for time-boxed continuous recordings
+MIN_FLUSH_INTERVAL_MS = 1000
+
 # Audio level emission
 LEVEL_EMISSION_INTERVAL_MS = 80  # Emit levels every 80ms
 LEVEL_BUFFER_SIZE = 1600  # ~100ms of audio at 16kHz
@@ -176,6 +189,20 @@ class RecordingSession:
                 return np.array([], dtype=np.float32)
             return np.concatenate(list(self._buffer))
 
+    def take_audio(self) -> tuple[np.ndarray, int]:
+        """Remove all buffered audio and return it with its duration in ms.
+
+        Recording continues into the emptied buffer.
+        This operation is thread-safe.
+        """
+        with self._lock:
+            chunks = list(self._buffer)
+            self._buffer.clear()
+        if not chunks:
+            return np.array([], dtype=np.float32), 0
+        audio = np.concatenate(chunks)
+        return audio, int(len(audio) * 1000 / self.sample_rate)
+
     def get_duration_ms(self) -> int:
         """Get duration of buffered audio in milliseconds."""
         with self._lock:
@@ -222,8 +249,13 @@ class AudioRecorder:
         self._level_thread: threading.Thread | None = None
         self._emit_levels = False
         self._callback_error: str | None = None
+        # Device whose input gain applies to captured audio
+        self._gain_device_uid: str | None = None
         self._vad_detector: Any | None = None
         self._vad_auto_stop_triggered = False
+        self._continuous = False
+        self._flush_interval_ms = 0
+        self._utterance_count = 0
         self._preprocess_options: dict[str, Any] = _resolve_preprocess_options(None)
 
     @property
@@ -241,6 +273,16 @@ class AudioRecorder:
         """Get active VAD detector for current session, if configured."""
         return self._vad_detector
 
+    @property
+    def continuous(self) -> bool:
+        """Whether the current session segments speech into utterances."""
+        return self._continuous
+
+    @property
+    def has_utterances(self) -> bool:
+        """Whether the current session hands audio to transcription before stop."""
+        return self._continuous or self._utterance_count > 0
+
     @property
     def preprocess_options(self) -> dict[str, Any]:
         """Get active preprocessing options for current session."""
@@ -252,12 +294,18 @@ class AudioRecorder:
         session_id: str | None = None,
         vad: Mapping[str, Any] | None = None,
         preprocess: Mapping[str, Any] | None = None,
+        continuous: bool = False,
+        flush_interval_ms: int = 0,
     ) -> str:
         """Start a new recording session.
 
         Args:
             device_uid: Device to record from, or None for active/default device.
             session_id: Optional externally provided session ID.
+            continuous: Cut an utterance at every VAD pause instead of
+                auto-stopping; requires VAD to be enabled.
+            flush_interval_ms: In continuous mode, also cut at the first short
+                gap in speech once this much audio is buffered; 0 disables.
 
         Returns:
             Session ID for this recording.
@@ -272,7 +320,7 @@ class AudioRecorder:
                 raise RuntimeError("Recording already in progress")
 
             # Resolve device and capture format from selected/default input.
-            device_index, capture_sample_rate, capture_channels = (
+            device_index, capture_sample_rate, capture_channels, resolved_uid = (
                 self._resolve_capture_parameters(device_uid)
             )
 
@@ -304,8 +352,18 @@ class AudioRecorder:
                 self.sample_rate = capture_sample_rate
                 self.channels = capture_channels
                 self.max_samples = max_samples
+                self._gain_device_uid = resolved_uid
                 self._vad_detector = self._build_vad_detector(vad, capture_sample_rate)
                 self._vad_auto_stop_triggered = False
+                self._continuous = continuous and self._vad_detector is not None
+                self._flush_interval_ms = (
+                    max(flush_interval_ms, MIN_FLUSH_INTERVAL_MS)
+                    if self._continuous and flush_interval_ms > 0
+                    else 0
+                )
+                self._utterance_count = 0
+                if continuous and not self._continuous:
+                    log("Continuous mode needs VAD; recording a single utterance")
                 self._preprocess_options = _resolve_preprocess_options(preprocess)
                 self._state = RecordingState.RECORDING
 
@@ -324,6 +382,7 @@ class AudioRecorder:
                 self._stream = None
                 self._vad_detector = None
                 self._vad_auto_stop_triggered = False
+                self._continuous = False
                 log(f"Failed to start recording: {e}")
                 raise OSError(f"Failed to open audio device: {e}") from e
 
@@ -369,6 +428,7 @@ class AudioRecorder:
             self._session = None
             self._vad_detector = None
             self._vad_auto_stop_triggered = False
+            self._continuous = False
             self._state = RecordingState.IDLE
             self._callback_error = None
 
@@ -411,9 +471,66 @@ class AudioRecorder:
             self._session = None
             self._vad_detector = None
             self._vad_auto_stop_triggered = False
+            self._continuous = False
             self._state = RecordingState.IDLE
             self._callback_error = None
 
+    def split(self, session_id: str) -> tuple[int, int]:
+        """Hand the audio recorded so far to transcription and keep recording.
+
+        The audio becomes the next utterance of the session, so its result
+        arrives as an utterance_complete event ahead of the final result.
+
+        Args:
+            session_id: Session ID from start().
+
+        Returns:
+            Tuple of (segment_index, duration_ms) of the split-off audio.
+
+        Raises:
+            RuntimeError: If not recording or wrong session.
+        """
+        with self._lock:
+            if self._state != RecordingState.RECORDING:
+                raise RuntimeError("Not recording")
+
+            session = self._session
+            if session is None or session.session_id != session_id:
+                raise RuntimeError(f"Invalid session ID: {session_id}")
+
+            segment_index = self._utterance_count
+            self._utterance_count += 1
+            input_sample_rate = self.sample_rate
+            preprocess_options = self.preprocess_options
+            detector = self._vad_detector
+            continuous = self._continuous
+
+        audio_data, duration_ms = session.take_audio()
+        if detector is not None:
+            detector.reset()
+
+        from .notifications import get_session_tracker, transcribe_utterance_async
+
+        if segment_index == 0 and not continuous:
+            # Continuous sessions are tracked from the start; this one is
+            # tracked from its first split so a cancel drops its utterances.
+            get_session_tracker().register(session_id)
+
+        transcribe_utterance_async(
+            session_id,
+            segment_index,
+            audio_data,
+            duration_ms,
+            preprocess=lambda audio: _preprocess_for_asr(
+                audio, input_sample_rate, preprocess_options
+            ),
+        )
+        log(
+            f"Recording split: session={session_id}, segment={segment_index}, "
+            f"duration={duration_ms}ms"
+        )
+        return segment_index, duration_ms
+
     def get_status(self) -> dict[str, Any]:
         """Get current recording status."""
         with self._lock:
@@ -452,6 +569,7 @@ class AudioRecorder:
 
         # Preserve captured channel layout for preprocessing.
         chunk = indata.copy() if indata.ndim > 1 else indata.flatten().copy()
+        apply_gain(chunk, get_device_gain_db(self._gain_device_uid))
 
         # Add data outside main lock (session has its own lock)
         session.add_chunk(chunk)
@@ -473,8 +591,24 @@ class AudioRecorder:
                 self._vad_detector = None
             return
 
-        if getattr(vad_state, "value", "") == "auto_stop":
-            self._trigger_vad_auto_stop(session.session_id)
+        vad_value = getattr(vad_state, "value", "")
+        if vad_value == "auto_stop":
+            if self._continuous:
+                self._cut_utterance(session, detector)
+            else:
+                self._trigger_vad_auto_stop(session.session_id)
+        elif vad_value == "silence" and self._flush_due(session):
+            # Time-boxed flush: the gap is too short for a pause but avoids
+            # cutting a word in half.
+            self._cut_utterance(session, detector)
+
+    def _flush_due(self, session: RecordingSession) -> bool:
+        """Whether a time-boxed continuous session has buffered a full interval."""
+        return (
+            self._continuous
+            and self._flush_interval_ms > 0
+            and session.get_duration_ms() >= self._flush_interval_ms
+        )
 
     def _level_emit_loop(self) -> None:
         """Background loop that emits audio level events during recording."""
@@ -557,8 +691,10 @@ class AudioRecorder:
             log(f"Error getting device index: {e}")
             return None
 
-    def _resolve_capture_parameters(self, device_uid: str | None) -> tuple[int | None, int, int]:
-        """Resolve device index and native capture format."""
+    def _resolve_capture_parameters(
+        self, device_uid: str | None
+    ) -> tuple[int | None, int, int, str | None]:
+        """Resolve device index, native capture format, and device UID."""
         selected_uid = device_uid or get_active_device_uid()
         device = None
 
@@ -581,7 +717,8 @@ class AudioRecorder:
         # Ensure sane non-zero values.
         sample_rate = max(sample_rate, 1)
         channels = max(channels, 1)
-        return device_index, sample_rate, channels
+        resolved_uid = device.uid if device is not None else None
+        return device_index, sample_rate, channels, resolved_uid
 
     def _build_vad_detector(
         self,
@@ -641,6 +778,36 @@ class AudioRecorder:
         ).start()
 
 
+    def _cut_utterance(self, session: RecordingSession, detector: Any) -> None:
+        """Hand the speech buffered so far to transcription and keep recording."""
+        with self._lock:
+            if self._session is not session or self._state != RecordingState.RECORDING:
+                return
+            segment_index = self._utterance_count
+            self._utterance_count += 1
+            input_sample_rate = self.sample_rate
+            preprocess_options = self.preprocess_options
+
+        audio_data, duration_ms = session.take_audio()
+        detector.reset()
+
+        from .notifications import transcribe_utterance_async
+
+        transcribe_utterance_async(
+            session.session_id,
+            segment_index,
+            audio_data,
+            duration_ms,
+            preprocess=lambda audio: _preprocess_for_asr(
+                audio, input_sample_rate, preprocess_options
+            ),
+        )
+        log(
+            f"Utterance cut: session={session.session_id}, segment={segment_index}, "
+            f"duration={duration_ms}ms"
+        )
+
+
 # === Global Recorder Instance ===
 
 _recorder: AudioRecorder | None = None
@@ -693,6 +860,11 @@ def handle_recording_start(request: Request) -> dict[str, Any]:
     Params:
         device_uid: Optional device UID to record from.
         session_id: Optional externally provided session identifier.
+        continuous: Transcribe each utterance at VAD pauses until stopped.
+        flush_interval_ms: With continuous, also cut an utterance at the first
+            short gap after this many milliseconds of audio; 0 disables.
+        input_gains: Optional mapping of device UID to input gain in dB;
+            replaces the gains set via audio.set_gain.
 
     Returns:
         session_id: Unique session identifier.
@@ -705,6 +877,11 @@ def handle_recording_start(request: Request) -> dict[str, Any]:
     device_uid = request.params.get("device_uid")
     session_id = request.params.get("session_id")
     vad_params = _extract_vad_params(request.params)
+    continuous = _coerce_bool(request.params.get("continuous"), False)
+    flush_interval_ms = _coerce_int(request.params.get("flush_interval_ms"), 0)
+    input_gains = request.params.get("input_gains")
+    if isinstance(input_gains, Mapping):
+        set_device_gains(input_gains)
 
     recorder = get_recorder()
 
@@ -714,7 +891,15 @@ def handle_recording_start(request: Request) -> dict[str, Any]:
             session_id=session_id,
             vad=vad_params,
             preprocess=request.params,
+            continuous=continuous,
+            flush_interval_ms=flush_interval_ms,
         )
+        if continuous and recorder.continuous:
+            # Track the session from the start so a cancel also drops its
+            # pending utterances.
+            from .notifications import get_session_tracker
+
+            get_session_tracker().register(started_session_id)
 
         # Emit status change once recording has started successfully.
         from .notifications import emit_status_changed
@@ -755,8 +940,11 @@ def handle_recording_stop(request: Request) -> dict[str, Any]:
     recorder = get_recorder()
 
     try:
+        after_utterances = recorder.has_utterances
         audio_data, duration_ms = recorder.stop(session_id)
-        return _begin_transcription(recorder, session_id, audio_data, duration_ms)
+        return _begin_transcription(
+            recorder, session_id, audio_data, duration_ms, after_utterances=after_utterances
+        )
     except RuntimeError as e:
         error_msg = str(e).lower()
         if "not recording" in error_msg:
@@ -768,26 +956,81 @@ def handle_recording_stop(request: Request) -> dict[str, Any]:
         raise RecordingError(str(e), "E_AUDIO_IO")
 
 
-def _begin_transcription(
-    recorder: AudioRecorder,
-    session_id: str,
+def handle_recording_split(request: Request) -> dict[str, Any]:
+    """Handle recording.split request.
+
+    Transcribes the audio recorded so far as the next utterance of the
+    session while capture continues, so a long recording is delivered in
+    parts instead of being stopped. The final result of recording.stop is
+    emitted after every split-off utterance.
+
+    Params:
+        session_id: Session ID from recording.start.
+
+    Returns:
+        session_id: The session that was split.
+        segment_index: Utterance index of the split-off audio.
+        audio_duration_ms: Duration of the split-off audio.
+
+    Errors:
+        E_NOT_RECORDING: Not currently recording.
+        E_INVALID_SESSION: Session ID doesn't match.
+    """
+    session_id = request.params.get("session_id")
+
+    if not session_id:
+        raise InvalidSessionError("session_id is required")
+
+    try:
+        segment_index, duration_ms = get_recorder().split(session_id)
+    except RuntimeError as e:
+        error_msg = str(e).lower()
+        if "not recording" in error_msg:
+            raise NotRecordingError(str(e))
+        if "invalid session" in error_msg or "session id" in error_msg:
+            raise InvalidSessionError(str(e))
+        raise RecordingError(str(e))
+
+    return {
+        "session_id": session_id,
+        "segment_index": segment_index,
+        "audio_duration_ms": duration_ms,
+    }
+
+
+def _preprocess_for_asr(
     audio_data: np.ndarray,
-    duration_ms: int,
-) -> dict[str, Any]:
-    """Run preprocess + async transcription pipeline after recording stop."""
+    input_sample_rate: int,
+    preprocess_options: Mapping[str, Any],
+) -> np.ndarray:
+    """Convert captured audio to ASR input without mutating the source array."""
     from .preprocess import TARGET_SAMPLE_RATE, preprocess_audio
 
-    preprocess_options = recorder.preprocess_options
-    processed_audio = preprocess_audio(
+    return preprocess_audio(
         audio_data.copy(),
         {
-            "input_sample_rate": recorder.sample_rate,
+            "input_sample_rate": input_sample_rate,
             "target_sample_rate": TARGET_SAMPLE_RATE,
             "normalize": preprocess_options["normalize"],
             "audio": {"trim_silence": preprocess_options["audio"]["trim_silence"]},
         },
     )
 
+
+def _begin_transcription(
+    recorder: AudioRecorder,
+    session_id: str,
+    audio_data: np.ndarray,
+    duration_ms: int,
+    after_utterances: bool = False,
+) -> dict[str, Any]:
+    """Run preprocess + async transcription pipeline after recording stop."""
+    from .preprocess import TARGET_SAMPLE_RATE
+
+    processed_audio = _preprocess_for_asr(
+        audio_data, recorder.sample_rate, recorder.preprocess_options
+    )
+
     # Emit status change
     from .notifications import emit_status_changed
 
@@ -796,7 +1039,14 @@ def _begin_transcription(
     # Start async transcription (this returns immediately)
     from .notifications import transcribe_session_async
 
-    transcribe_session_async(session_id, processed_audio, TARGET_SAMPLE_RATE)
+    if after_utterances:
+        # The remainder of a continuous session is transcribed after its
+        # pending utterances so the final result arrives last.
+        transcribe_session_async(
+            session_id, processed_audio, TARGET_SAMPLE_RATE, after_utterances=True
+        )
+    else:
+        transcribe_session_async(session_id, processed_audio, TARGET_SAMPLE_RATE)
 
     return {
         "audio_duration_ms": duration_ms,
@@ -875,6 +1125,113 @@ def handle_recording_status(request: Request) -> dict[str, Any]:
     return recorder.get_status()
 
 
+def handle_transcription_get_result(request: Request) -> dict[str, Any]:
+    """Handle transcription.get_result request.
+
+    Lets the host recover a result after a lost notification or an RPC
+    timeout without transcribing the audio again.
+
+    Params:
+        session_id: Session ID from recording.start.
+
+    Returns:
+        session_id: Echoed session ID.
+        state: pending, completed, error, cancelled, or unknown.
+        result: transcription_complete params (completed sessions only).
+        error: {kind, message} (failed sessions only).
+
+    Errors:
+        E_INVALID_SESSION: Missing session ID.
+    """
+    session_id = request.params.get("session_id")
+
+    if not session_id:
+        raise InvalidSessionError("session_id is required")
+
+    from .notifications import get_transcription_result
+
+    return get_transcription_result(session_id)
+
+
+def handle_transcription_retry(request: Request) -> dict[str, Any]:
+    """Handle transcription.retry request.
+
+    Transcribes a failed session's audio once more, e.g. after a transient
+    error. The outcome arrives as a new transcription_complete or
+    transcription_error notification for the same session.
+
+    Params:
+        session_id: Session ID of the failed transcription.
+
+    Returns:
+        session_id: Echoed session ID.
+        retrying: False when the session's audio is no longer available.
+
+    Errors:
+        E_INVALID_SESSION: Missing session ID.
+    """
+    session_id = request.params.get("session_id")
+
+    if not session_id:
+        raise InvalidSessionError("session_id is required")
+
+    from .notifications import retry_transcription
+    from .preprocess import TARGET_SAMPLE_RATE
+
+    return {
+        "session_id": session_id,
+        "retrying": retry_transcription(session_id, TARGET_SAMPLE_RATE),
+    }
+
+
+def handle_recording_export_audio(request: Request) -> dict[str, Any]:
+    """Handle recording.export_audio request.
+
+    Writes a recent session's preprocessed audio to a 16-bit mono WAV file,
+    e.g. so the auxiliary sidecar can transcribe it with other models.
+
+    Params:
+        session_id: Session whose audio to export.
+        audio_path: Destination file path.
+
+    Returns:
+        session_id: Echoed session ID.
+        audio_path: Written file path.
+        duration_ms: Length of the exported audio.
+
+    Errors:
+        E_INVALID_SESSION: Missing session ID or audio no longer retained.
+    """
+    session_id = request.params.get("session_id")
+    audio_path = request.params.get("audio_path")
+
+    if not session_id:
+        raise InvalidSessionError("session_id is required")
+    if not audio_path:
+        raise RecordingError("audio_path is required", "E_INVALID_PARAMS")
+
+    from .notifications import get_session_audio
+
+    retained = get_session_audio(session_id)
+    if retained is None:
+        raise InvalidSessionError(f"Audio for session {session_id} is no longer available")
+    audio, sample_rate = retained
+
+    from scipy.io import wavfile
+
+    samples = (np.clip(audio, -1.0, 1.0) * 32767.0).astype(np.int16)
+    try:
+        wavfile.write(str(audio_path), sample_rate, samples)
+    except OSError as e:
+        raise RecordingError(f"Failed to write audio: {e}", "E_AUDIO_IO") from e
+
+    return {
+        "session_id": session_id,
+        "audio_path": str(audio_path),
+        "duration_ms": int(len(audio) * 1000 / sample_rate),
+    }
+
+
 # === Audio Storage for Transcription ===
 
 # Simple storage for audio data pending transcription
@@ -28,12 +28,18 @@ from .asr import (
     handle_asr_initialize,
     handle_asr_status,
     handle_asr_transcribe,
+    handle_asr_unload,
+    handle_batch_compare_models,
+    handle_file_transcribe,
 )
 from .audio import (
     DeviceNotFoundError,
+    InvalidGainError,
     MicPermissionError,
+    handle_audio_get_gain,
     handle_audio_list_devices,
     handle_audio_set_device,
+    handle_audio_set_gain,
 )
 from .audio_meter import (
     MeterAlreadyRunningError,
@@ -80,7 +86,9 @@ from .protocol import (
     log,
     make_error,
     make_success,
+    open_transport,
     parse_line,
+    set_output,
     write_response,
 )
 from .recording import (
@@ -90,9 +98,13 @@ from .recording import (
     RecordingError,
     get_recorder,
     handle_recording_cancel,
+    handle_recording_export_audio,
+    handle_recording_split,
     handle_recording_start,
     handle_recording_status,
     handle_recording_stop,
+    handle_transcription_get_result,
+    handle_transcription_retry,
 )
 from .replacements import (
     ReplacementError,
@@ -273,13 +285,19 @@ HANDLERS: dict[str, Any] = {
     "status.get": handle_status_get,
     "audio.list_devices": handle_audio_list_devices,
     "audio.set_device": handle_audio_set_device,
+    "audio.get_gain": handle_audio_get_gain,
+    "audio.set_gain": handle_audio_set_gain,
     "audio.meter_start": handle_audio_meter_start,
     "audio.meter_stop": handle_audio_meter_stop,
     "audio.meter_status": handle_audio_meter_status,
     "recording.start": handle_recording_start,
     "recording.stop": handle_recording_stop,
     "recording.cancel": handle_recording_cancel,
+    "recording.split": handle_recording_split,
     "recording.status": handle_recording_status,
+    "recording.export_audio": handle_recording_export_audio,
+    "transcription.get_result": handle_transcription_get_result,
+    "transcription.retry": handle_transcription_retry,
     "replacements.get_rules": handle_replacements_get_rules,
     "replacements.set_rules": handle_replacements_set_rules,
     "replacements.get_presets": handle_replacements_get_presets,
@@ -292,6 +310,9 @@ HANDLERS: dict[str, Any] = {
     "asr.initialize": handle_asr_initialize,
     "asr.status": handle_asr_status,
     "asr.transcribe": handle_asr_transcribe,
+    "asr.unload": handle_asr_unload,
+    "batch.compare_models": handle_batch_compare_models,
+    "file.transcribe": handle_file_transcribe,
 }
 
 
@@ -310,16 +331,19 @@ def dispatch(request: Request) -> dict[str, Any] | None:
 def run_server() -> None:
     """Run the main JSON-RPC server loop.
 
-    Reads NDJSON from stdin, processes requests, writes responses to stdout.
+    Reads NDJSON from stdin, processes requests, writes responses to stdout
+    (or a socket / named pipe the host offers, see open_transport()).
     Exits on EOF or shutdown request.
     """
     log(f"Sidecar starting (version {__version__}, protocol {PROTOCOL_VERSION})")
     load_startup_presets()
 
     shutdown_requested = False
+    reader, writer = open_transport()
+    set_output(writer)
 
     try:
-        for line in sys.stdin:
+        for line in reader:
             # Check line length limit
             if len(line) > MAX_LINE_LENGTH:
                 log(
@@ -406,6 +430,14 @@ def run_server() -> None:
                     "E_DEVICE_NOT_FOUND",
                     {"device_uid": e.device_uid} if e.device_uid else None,
                 )
+            except InvalidGainError as e:
+                log(f"Invalid gain: {e}")
+                response = make_error(
+                    request.id,
+                    ERROR_INVALID_PARAMS,
+                    str(e),
+                    "E_INVALID_PARAMS",
+                )
             except AlreadyRecordingError as e:
                 log(f"Already recording: {e}")
                 response = make_error(
@@ -24,9 +24,12 @@ from openvoicy_sidecar.asr import (
     NotInitializedError,
     TranscriptionError,
     TranscriptionResult,
+    apply_beam_size,
     handle_asr_initialize,
     handle_asr_status,
+    handle_asr_unload,
     load_manifest,
+    resolve_recommended_params,
 )
 from openvoicy_sidecar.asr.parakeet import ParakeetBackend, check_cuda_available, select_device
 from openvoicy_sidecar.protocol import Request
@@ -127,6 +130,16 @@ class TestInitProgress:
         assert d["state"] == "loading_model"
         assert d["detail"] == "Loading weights..."
         assert d["progress"]["current"] == 50
+        assert "stage" not in d
+        assert "percent" not in d
+
+    def test_to_dict_with_stage(self):
+        """Should include the loading stage and percent when known."""
+        progress = InitProgress(state="loading_model", stage="warmup", percent=0)
+        d = progress.to_dict()
+
+        assert d["stage"] == "warmup"
+        assert d["percent"] == 0
 
 
 # === Unit Tests: Device Selection ===
@@ -321,6 +334,14 @@ class TestHandlers:
         assert "state" in result
         assert "ready" in result
 
+    def test_asr_unload_handler(self):
+        """Should leave the engine uninitialized."""
+        request = Request(method="asr.unload", id=1)
+        result = handle_asr_unload(request)
+
+        assert result["state"] == "uninitialized"
+        assert result["ready"] is False
+
     def test_asr_initialize_invalid_device(self):
         """Should reject invalid device_pref."""
         request = Request(
@@ -360,6 +381,36 @@ class TestHandlers:
         assert call_kwargs["language"] == "en"
         assert callable(call_kwargs["progress_callback"])
 
+    def test_asr_initialize_reports_loading_stages_as_model_status(self):
+        """Staged progress should carry a loading model status for the host."""
+        request = Request(
+            method="asr.initialize",
+            id=1,
+            params={"model_id": "test-model", "device_pref": "cpu"},
+        )
+
+        def initialize(*args, progress_callback, **kwargs):
+            progress_callback(InitProgress(state="loading_model", detail="Checking..."))
+            progress_callback(
+                InitProgress(state="loading_model", stage="compile", percent=40)
+            )
+            return {"status": "ready", "model_id": "test-model", "device": "cpu"}
+
+        mock_engine = MagicMock()
+        mock_engine.initialize.side_effect = initialize
+
+        with (
+            patch("openvoicy_sidecar.asr.get_engine", return_value=mock_engine),
+            patch("openvoicy_sidecar.asr.write_event") as mock_write_event,
+        ):
+            handle_asr_initialize(request)
+
+        unstaged, staged = [call.args[1] for call in mock_write_event.call_args_list]
+        assert "model" not in unstaged
+        assert staged["stage"] == "compile"
+        assert staged["percent"] == 40
+        assert staged["model"] == {"model_id": "test-model", "status": "loading"}
+
     def test_asr_initialize_accepts_auto_and_null_language(self):
         """Should accept 'auto' and null language values."""
         mock_engine = MagicMock()
@@ -390,6 +441,60 @@ class TestHandlers:
         assert first_kwargs["language"] == "auto"
         assert second_kwargs["language"] is None
 
+    def test_asr_initialize_passes_beam_size_override_to_engine(self):
+        """Should pass beam_size through, with null meaning the catalog default."""
+        mock_engine = MagicMock()
+        mock_engine.initialize.return_value = {
+            "status": "ready",
+            "model_id": "test-model",
+            "device": "cpu",
+        }
+
+        with patch("openvoicy_sidecar.asr.get_engine", return_value=mock_engine):
+            handle_asr_initialize(
+                Request(
+                    method="asr.initialize",
+                    id=1,
+                    params={"model_id": "test-model", "device_pref": "cpu", "beam_size": 4},
+                )
+            )
+            handle_asr_initialize(
+                Request(
+                    method="asr.initialize",
+                    id=2,
+                    params={"model_id": "test-model", "device_pref": "cpu"},
+                )
+            )
+            with pytest.raises(ASRError):
+                handle_asr_initialize(
+                    Request(
+                        method="asr.initialize",
+                        id=3,
+                        params={"model_id": "test-model", "device_pref": "cpu", "beam_size": 0},
+                    )
+                )
+
+        first_kwargs = mock_engine.initialize.call_args_list[0].kwargs
+        second_kwargs = mock_engine.initialize.call_args_list[1].kwargs
+        assert first_kwargs["beam_size"] == 4
+        assert second_kwargs["beam_size"] is None
+
+    def test_recommended_params_come_from_catalog(self):
+        """Catalog recommended_params should resolve with or without namespace."""
+        params = resolve_recommended_params("parakeet-tdt-0.6b-v3")
+        assert params["beam_size"] == 1
+        assert resolve_recommended_params("nvidia/parakeet-tdt-0.6b-v3") == params
+        assert resolve_recommended_params("unknown-model") == {}
+
+    def test_apply_beam_size_only_configures_supporting_backends(self):
+        """Backends without set_beam_size are left on their default decoding."""
+        supporting = MagicMock()
+        apply_beam_size(supporting, 3)
+        supporting.set_beam_size.assert_called_once_with(3)
+
+        greedy_only = object()
+        apply_beam_size(greedy_only, 3)
+
     def test_asr_initialize_rejects_invalid_language_type(self):
         """Should reject non-string/non-null language values."""
         request = Request(
@@ -8,20 +8,28 @@ import sys
 from typing import Any
 from unittest.mock import MagicMock, patch
 
+import numpy as np
 import pytest
 
 from openvoicy_sidecar.audio import (
+    MAX_GAIN_DB,
     AudioDevice,
     DeviceNotFoundError,
+    InvalidGainError,
     MicPermissionError,
     _generate_stable_uid,
+    apply_gain,
     find_device_by_uid,
     get_active_device_uid,
     get_default_device,
+    get_device_gain_db,
+    handle_audio_get_gain,
     handle_audio_list_devices,
     handle_audio_set_device,
+    handle_audio_set_gain,
     list_audio_devices,
     set_active_device,
+    set_device_gains,
 )
 from openvoicy_sidecar.protocol import Request
 
@@ -73,6 +81,17 @@ def reset_active_device():
     audio_module._active_device_uid = original
 
 
+@pytest.fixture
+def reset_device_gains():
+    """Reset input gains after each test."""
+    import openvoicy_sidecar.audio as audio_module
+
+    original = dict(audio_module._device_gains_db)
+    yield
+    audio_module._device_gains_db.clear()
+    audio_module._device_gains_db.update(original)
+
+
 # === Unit Tests: list_audio_devices ===
 
 
@@ -462,6 +481,73 @@ class TestAudioHandlers:
                 handle_audio_set_device(request)
 
 
+# === Unit Tests: Input gain ===
+
+
+class TestInputGain:
+    """Tests for per-device input gain."""
+
+    def test_apply_gain_boosts_and_clips(self):
+        samples = np.array([0.1, -0.1, 0.5], dtype=np.float32)
+        apply_gain(samples, 20.0)
+        np.testing.assert_allclose(samples, [1.0, -1.0, 1.0], rtol=1e-5)
+
+        quiet = np.array([0.02, -0.04], dtype=np.float32)
+        apply_gain(quiet, 6.0)
+        np.testing.assert_allclose(quiet, [0.0399, -0.0798], rtol=1e-2)
+
+    def test_set_gain_for_active_device_is_clamped(self, reset_active_device, reset_device_gains):
+        set_device_gains({})
+        import openvoicy_sidecar.audio as audio_module
+
+        audio_module._active_device_uid = "dev-1"
+        result = handle_audio_set_gain(
+            Request(method="audio.set_gain", id=1, params={"gain_db": 99})
+        )
+        assert result == {"device_uid": "dev-1", "gain_db": MAX_GAIN_DB}
+
+        result = handle_audio_get_gain(
+            Request(method="audio.get_gain", id=2, params={"device_uid": "dev-2"})
+        )
+        assert result == {"device_uid": "dev-2", "gain_db": 0.0}
+
+    def test_set_gain_rejects_non_numbers(self, reset_device_gains):
+        for gain_db in (None, "6", True):
+            with pytest.raises(InvalidGainError):
+                handle_audio_set_gain(
+                    Request(
+                        method="audio.set_gain",
+                        id=3,
+                        params={"device_uid": "dev-1", "gain_db": gain_db},
+                    )
+                )
+
+    def test_set_gain_without_devices_raises_error(
+        self, reset_active_device, reset_device_gains
+    ):
+        import openvoicy_sidecar.audio as audio_module
+
+        audio_module._active_device_uid = None
+        mock_sd = MagicMock()
+        mock_sd.query_devices.return_value = []
+        mock_sd.query_hostapis.return_value = []
+        mock_sd.default.device = (None, None)
+
+        with patch.dict("sys.modules", {"sounddevice": mock_sd}):
+            with pytest.raises(DeviceNotFoundError):
+                handle_audio_set_gain(
+                    Request(method="audio.set_gain", id=4, params={"gain_db": 6})
+                )
+
+    def test_set_device_gains_replaces_all(self, reset_device_gains):
+        set_device_gains({"dev-1": 6.0, "dev-2": 3})
+        set_device_gains({"dev-2": 12, "dev-3": "loud"})
+        assert get_device_gain_db("dev-1") == 0.0
+        assert get_device_gain_db("dev-2") == 12.0
+        assert get_device_gain_db("dev-3") == 0.0
+        assert get_device_gain_db(None) == 0.0
+
+
 # === Integration Tests ===
 
 
@@ -27,6 +27,7 @@ from openvoicy_sidecar.audio_meter import (
     handle_audio_meter_stop,
 )
 from openvoicy_sidecar.protocol import Request
+from openvoicy_sidecar.vad import VadConfig
 
 
 # === Fixtures ===
@@ -154,6 +155,25 @@ class TestHandlers:
         # Clean up
         get_meter().stop()
 
+    def test_meter_start_with_vad_preview(self):
+        """Should enable the VAD preview only when requested."""
+        request = Request(
+            method="audio.meter_start",
+            id=1,
+            params={"vad_preview": True, "vad_silence_ms": 600, "vad_min_speech_ms": 150},
+        )
+
+        result = handle_audio_meter_start(request)
+
+        assert result["vad_preview"] is True
+        assert get_meter()._vad_silence_ms == 600
+
+        get_meter().stop()
+        result = handle_audio_meter_start(Request(method="audio.meter_start", id=2, params={}))
+        assert result["vad_preview"] is False
+
+        get_meter().stop()
+
     def test_meter_start_with_interval(self):
         """Should start meter with custom interval."""
         request = Request(
@@ -279,3 +299,25 @@ class TestMeterIntegration:
         assert len(meter._buffer) == 256
 
         meter.stop()
+
+    def test_vad_preview_emits_and_restarts_after_auto_stop(self):
+        """Should emit each VAD decision and reset the detector on auto-stop."""
+        meter = AudioMeter()
+        meter.start(vad=VadConfig(silence_ms=400, min_speech_ms=100))
+        emitted = []
+
+        with patch("openvoicy_sidecar.audio_meter.emit_vad_preview") as mock_emit:
+            mock_emit.side_effect = lambda **kwargs: emitted.append(kwargs)
+            with patch.object(meter._vad, "_detect_speech", side_effect=[True, False, False]):
+                speech = np.full(3200, 0.5, dtype=np.float32)
+                silence = np.zeros(3200, dtype=np.float32)
+                meter._preview_vad(speech)
+                meter._preview_vad(silence)
+                meter._preview_vad(silence)
+
+        meter.stop()
+
+        assert [event["state"] for event in emitted] == ["speech", "silence", "auto_stop"]
+        assert emitted[0]["speech_ms"] == pytest.approx(200)
+        assert emitted[-1]["silence_threshold_ms"] == 400
+        assert meter._vad.speech_ms == 0
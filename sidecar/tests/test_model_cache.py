@@ -765,6 +765,23 @@ class TestDownload:
         assert "network unavailable" in exc_info.value.message
         assert [c.args[0] for c in mock_sleep.call_args_list] == [0.1, 0.2]
 
+    def test_download_with_mirrors_refuses_in_offline_mode(self, tmp_path, monkeypatch):
+        """Offline mode should fail before any connection is attempted."""
+        monkeypatch.setenv(model_cache.OFFLINE_MODE_ENV, "1")
+        file_info = ModelFileInfo(
+            path="file.bin",
+            size_bytes=4,
+            sha256="",
+            primary_url="http://primary.example.com/file.bin",
+        )
+
+        with patch("openvoicy_sidecar.model_cache.download_file") as mock_download:
+            with pytest.raises(NetworkError) as exc_info:
+                download_with_mirrors(file_info, tmp_path / "downloaded.bin")
+
+        mock_download.assert_not_called()
+        assert "Offline mode" in exc_info.value.message
+
 
 # === Unit Tests: ModelCacheManager ===
 
@@ -0,0 +1,203 @@
+"""Tests for batch.compare_models, file.transcribe, recording.export_audio, and contract entries."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from types import SimpleNamespace
+from typing import Any
+
+import numpy as np
+import pytest
+from scipy.io import wavfile
+
+import openvoicy_sidecar.asr as asr
+import openvoicy_sidecar.notifications as notifications
+from openvoicy_sidecar.asr import (
+    ASRError,
+    ModelNotFoundError,
+    TranscriptionResult,
+    handle_batch_compare_models,
+    handle_file_transcribe,
+)
+from openvoicy_sidecar.protocol import Request
+from openvoicy_sidecar.recording import InvalidSessionError, handle_recording_export_audio
+from openvoicy_sidecar.server import HANDLERS
+
+
+@pytest.fixture(autouse=True)
+def reset_recent_audio():
+    notifications._recent_audio.clear()
+    yield
+    notifications._recent_audio.clear()
+
+
+@pytest.fixture
+def repo_root() -> Path:
+    return Path(__file__).resolve().parents[2]
+
+
+class FakeEngine:
+    def __init__(self, downloaded: set[str]):
+        self._cache_manager = SimpleNamespace(
+            check_cache=lambda manifest: manifest.model_id in downloaded
+        )
+        self.model_id: str | None = None
+        self.initialized: list[str] = []
+        self.beam_size: int | None = None
+
+    def initialize(
+        self, model_id: str, device_pref: str, language: Any = None, beam_size: Any = None
+    ) -> dict[str, Any]:
+        self.initialized.append(model_id)
+        self.model_id = model_id
+        self.beam_size = beam_size
+        return {"status": "ready", "model_id": model_id, "device": "cpu"}
+
+    def transcribe(self, audio: np.ndarray, sample_rate: int) -> TranscriptionResult:
+        return TranscriptionResult(text=f"text from {self.model_id}", confidence=0.9)
+
+
+@pytest.fixture
+def fake_engine(monkeypatch: pytest.MonkeyPatch) -> FakeEngine:
+    engine = FakeEngine(downloaded={"fast", "accurate"})
+    monkeypatch.setattr(asr, "get_engine", lambda: engine)
+    monkeypatch.setattr(asr, "load_manifest", lambda model_id: SimpleNamespace(model_id=model_id))
+    monkeypatch.setattr(
+        asr, "load_audio_file", lambda path: (np.zeros(32000, dtype=np.float32), 16000)
+    )
+    return engine
+
+
+def _compare_request(params: dict[str, Any]) -> Request:
+    return Request(method="batch.compare_models", id=1, params=params)
+
+
+def test_comparison_handlers_in_dispatch_table() -> None:
+    assert HANDLERS["batch.compare_models"] is handle_batch_compare_models
+    assert HANDLERS["file.transcribe"] is handle_file_transcribe
+    assert HANDLERS["recording.export_audio"] is handle_recording_export_audio
+
+
+@pytest.mark.parametrize(
+    "params",
+    [
+        {"model_ids": ["fast"]},
+        {"audio_path": "/tmp/a.wav"},
+        {"audio_path": "/tmp/a.wav", "model_ids": []},
+        {"audio_path": "/tmp/a.wav", "model_ids": ["fast", ""]},
+    ],
+)
+def test_compare_models_rejects_invalid_params(params: dict[str, Any]) -> None:
+    with pytest.raises(ASRError) as excinfo:
+        handle_batch_compare_models(_compare_request(params))
+
+    assert excinfo.value.code == "E_INVALID_PARAMS"
+
+
+def test_compare_models_runs_each_model_in_order(fake_engine: FakeEngine) -> None:
+    result = handle_batch_compare_models(
+        _compare_request(
+            {"audio_path": "/tmp/a.wav", "model_ids": ["accurate", "missing", "fast"]}
+        )
+    )
+
+    assert result["audio_duration_ms"] == 2000
+    assert [entry["model_id"] for entry in result["results"]] == ["accurate", "missing", "fast"]
+    assert result["results"][0]["text"] == "text from accurate"
+    assert "load_ms" in result["results"][0]
+    assert "transcribe_ms" in result["results"][0]
+    assert result["results"][1]["error"]["code"] == "E_MODEL_NOT_FOUND"
+    assert "text" not in result["results"][1]
+    assert result["results"][2]["text"] == "text from fast"
+    # The missing model is never initialized (which would download it).
+    assert fake_engine.initialized == ["accurate", "fast"]
+
+
+def test_file_transcribe_initializes_requested_model(fake_engine: FakeEngine) -> None:
+    result = handle_file_transcribe(
+        Request(
+            method="file.transcribe",
+            id=1,
+            params={"audio_path": "/tmp/a.wav", "model_id": "accurate", "beam_size": 4},
+        )
+    )
+
+    assert result["text"] == "text from accurate"
+    assert fake_engine.initialized == ["accurate"]
+    assert fake_engine.beam_size == 4
+
+
+def test_file_transcribe_never_downloads_missing_model(fake_engine: FakeEngine) -> None:
+    with pytest.raises(ModelNotFoundError):
+        handle_file_transcribe(
+            Request(
+                method="file.transcribe",
+                id=1,
+                params={"audio_path": "/tmp/a.wav", "model_id": "missing"},
+            )
+        )
+
+    assert fake_engine.initialized == []
+
+
+@pytest.mark.parametrize("params", [{"model_id": "fast"}, {"audio_path": "/tmp/a.wav"}])
+def test_file_transcribe_rejects_invalid_params(params: dict[str, Any]) -> None:
+    with pytest.raises(ASRError) as excinfo:
+        handle_file_transcribe(Request(method="file.transcribe", id=1, params=params))
+
+    assert excinfo.value.code == "E_INVALID_PARAMS"
+
+
+def test_export_audio_writes_retained_session(tmp_path: Path) -> None:
+    audio = np.full(8000, 0.5, dtype=np.float32)
+    notifications._retain_recent_audio("session-1", audio, 16000)
+    target = tmp_path / "session.wav"
+
+    result = handle_recording_export_audio(
+        Request(
+            method="recording.export_audio",
+            id=1,
+            params={"session_id": "session-1", "audio_path": str(target)},
+        )
+    )
+
+    assert result == {"session_id": "session-1", "audio_path": str(target), "duration_ms": 500}
+    sample_rate, samples = wavfile.read(str(target))
+    assert sample_rate == 16000
+    assert samples.dtype == np.int16
+    assert len(samples) == 8000
+
+
+def test_export_audio_without_retained_session(tmp_path: Path) -> None:
+    with pytest.raises(InvalidSessionError):
+        handle_recording_export_audio(
+            Request(
+                method="recording.export_audio",
+                id=1,
+                params={"session_id": "gone", "audio_path": str(tmp_path / "gone.wav")},
+            )
+        )
+
+
+def test_recent_audio_retention_is_bounded() -> None:
+    for index in range(notifications.MAX_RETAINED_RECENT_SESSIONS + 1):
+        notifications._retain_recent_audio(f"session-{index}", np.zeros(8, dtype=np.float32), 16000)
+
+    assert notifications.get_session_audio("session-0") is None
+    assert len(notifications._recent_audio) == notifications.MAX_RETAINED_RECENT_SESSIONS
+
+
+def test_comparison_optional_contract_entries(repo_root: Path) -> None:
+    contract_path = repo_root / "shared" / "contracts" / "sidecar.rpc.v1.json"
+    contract = json.loads(contract_path.read_text())
+    methods = {item.get("name"): item for item in contract["items"]}
+
+    assert methods["batch.compare_models"]["required"] is False
+    assert methods["batch.compare_models"]["params_schema"]["required"] == [
+        "audio_path",
+        "model_ids",
+    ]
+    assert methods["recording.export_audio"]["required"] is False
+    assert methods["file.transcribe"]["required"] is False
+    assert methods["file.transcribe"]["params_schema"]["required"] == ["audio_path", "model_id"]
@@ -418,6 +418,70 @@ class TestConcurrentInstalls:
         assert result["status"] == "installing"
         assert result["model_id"] == manifest.model_id
 
+    def test_handle_model_install_requires_license_acceptance(self) -> None:
+        manifest = ModelManifest.from_dict(
+            {
+                "model_id": "test-model-v1",
+                "revision": "v1",
+                "display_name": "Test Model",
+                "total_size_bytes": SAMPLE_SIZE,
+                "files": [],
+                "license": {
+                    "spdx_id": "LicenseRef-Test",
+                    "name": "Test Model License",
+                    "url": "https://example.com/license",
+                    "text": "You agree to the test terms.",
+                    "requires_acceptance": True,
+                },
+            }
+        )
+        manager = MagicMock()
+        manager.load_manifest.return_value = manifest
+
+        with (
+            patch(
+                "openvoicy_sidecar.model_cache._resolve_manifest_path_for_model",
+                return_value=Path("/tmp/manifest.json"),
+            ),
+            patch("openvoicy_sidecar.model_cache.get_cache_manager", return_value=manager),
+            patch("openvoicy_sidecar.model_cache.threading.Thread") as thread_cls,
+        ):
+            result = handle_model_install(
+                Request(method="model.install", id=1, params={"model_id": "test-model-v1"})
+            )
+            assert result["status"] == "license_required"
+            assert result["license"] == {
+                "spdx_id": "LicenseRef-Test",
+                "name": "Test Model License",
+                "url": "https://example.com/license",
+                "text": "You agree to the test terms.",
+            }
+            thread_cls.assert_not_called()
+
+            try:
+                result = handle_model_install(
+                    Request(
+                        method="model.install",
+                        id=2,
+                        params={"model_id": "test-model-v1", "license_accepted": True},
+                    )
+                )
+            finally:
+                model_cache._install_thread = None
+                model_cache._install_model_id = None
+                model_cache._install_revision = None
+
+        assert result["status"] == "installing"
+        thread_cls.return_value.start.assert_called_once()
+
+    def test_manifest_license_without_acceptance_flag_does_not_block(self) -> None:
+        manifest = ModelManifest.from_dict(
+            {"model_id": "m", "license": {"spdx_id": "CC-BY-4.0", "name": "CC BY 4.0"}}
+        )
+
+        assert manifest.license is not None
+        assert manifest.license.requires_acceptance is False
+
 # ── 6. CANCEL MID-DOWNLOAD ──────────────────────────────────────────
 
 
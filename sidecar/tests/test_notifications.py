@@ -12,6 +12,8 @@ import pytest
 from openvoicy_sidecar.notifications import (
     SessionState,
     SessionTracker,
+    TranscriptionResultCache,
+    audio_fingerprint,
     calculate_audio_levels,
     emit_audio_level,
     emit_model_progress,
@@ -19,7 +21,9 @@ from openvoicy_sidecar.notifications import (
     emit_transcription_complete,
     emit_transcription_error,
     get_session_tracker,
+    get_transcription_result,
     transcribe_session_async,
+    transcribe_utterance_async,
 )
 
 
@@ -33,8 +37,10 @@ def reset_tracker():
     import openvoicy_sidecar.notifications as notifications
 
     notifications._session_tracker = None
+    notifications._result_cache = None
     yield
     notifications._session_tracker = None
+    notifications._result_cache = None
 
 
 @pytest.fixture
@@ -470,3 +476,218 @@ class TestAsyncTranscriptionPipeline:
         emitted_text = complete_calls[0].args[0].params["text"]
         assert isinstance(emitted_text, str)
         assert emitted_text == "fixed text"
+
+    def test_identical_audio_reuses_cached_asr_result(self, mock_write_notification):
+        """A retry with byte-identical audio should skip the ASR step."""
+        audio = np.array([0.1, 0.2, 0.3], dtype=np.float32)
+
+        fake_engine = MagicMock()
+        fake_engine.is_ready.return_value = True
+        fake_engine.get_status.return_value = {"model_id": "parakeet"}
+        fake_engine.transcribe.return_value = MagicMock(
+            text="raw text", confidence=0.91, language="en"
+        )
+
+        with (
+            patch("openvoicy_sidecar.notifications.threading.Thread", ImmediateThread),
+            patch("openvoicy_sidecar.asr.get_engine", return_value=fake_engine, create=True),
+            patch("openvoicy_sidecar.replacements.get_current_rules", return_value=[]),
+            patch(
+                "openvoicy_sidecar.replacements.process_text_with_full_stats",
+                return_value=("fixed text", False, 0, []),
+            ),
+            patch("openvoicy_sidecar.notifications.emit_status_changed"),
+        ):
+            transcribe_session_async("session-1", audio, 16000)
+            transcribe_session_async("session-2", audio.copy(), 16000)
+            transcribe_session_async("session-3", audio * 0.5, 16000)
+
+        assert fake_engine.transcribe.call_count == 2
+        completed = {
+            call.args[0].params["session_id"]: call.args[0].params
+            for call in mock_write_notification.call_args_list
+            if call.args[0].method == "event.transcription_complete"
+        }
+        assert set(completed) == {"session-1", "session-2", "session-3"}
+        assert completed["session-2"]["raw_text"] == "raw text"
+        assert completed["session-2"]["text"] == "fixed text"
+        assert completed["session-2"]["language"] == "en"
+
+
+class ImmediateExecutor:
+    """Executor stub that runs submitted jobs immediately, in order."""
+
+    def submit(self, fn):
+        fn()
+
+
+class TestUtterancePipeline:
+    """Tests for continuous-session utterances."""
+
+    def _patches(self, fake_engine):
+        return (
+            patch(
+                "openvoicy_sidecar.notifications._get_utterance_executor",
+                return_value=ImmediateExecutor(),
+            ),
+            patch("openvoicy_sidecar.asr.get_engine", return_value=fake_engine, create=True),
+            patch("openvoicy_sidecar.replacements.get_current_rules", return_value=[]),
+            patch(
+                "openvoicy_sidecar.replacements.process_text_with_full_stats",
+                side_effect=lambda text, rules: (text.upper(), False, 0, []),
+            ),
+            patch("openvoicy_sidecar.notifications.emit_status_changed"),
+        )
+
+    def test_utterances_precede_final_result_in_order(self, mock_write_notification):
+        """Utterances are emitted by segment index before the session's final result."""
+        fake_engine = MagicMock()
+        fake_engine.is_ready.return_value = True
+        fake_engine.get_status.return_value = {"model_id": "parakeet"}
+        fake_engine.transcribe.side_effect = lambda audio: MagicMock(
+            text=f"part {len(audio)}", confidence=None, language=None
+        )
+
+        executor, engine, rules, process, status = self._patches(fake_engine)
+        with executor, engine, rules, process, status:
+            get_session_tracker().register("session-1")
+            for index, length in enumerate((1, 2)):
+                transcribe_utterance_async(
+                    "session-1",
+                    index,
+                    np.ones(length, dtype=np.float32),
+                    100,
+                    preprocess=lambda audio: audio,
+                )
+            transcribe_session_async(
+                "session-1", np.ones(3, dtype=np.float32), 16000, after_utterances=True
+            )
+
+        emitted = [call.args[0] for call in mock_write_notification.call_args_list]
+        assert [(n.method, n.params.get("segment_index")) for n in emitted] == [
+            ("event.utterance_complete", 0),
+            ("event.utterance_complete", 1),
+            ("event.transcription_complete", None),
+        ]
+        assert emitted[0].params["text"] == "PART 1"
+        assert emitted[0].params["raw_text"] == "part 1"
+        assert emitted[0].params["audio_duration_ms"] == 100
+        assert emitted[2].params["text"] == "PART 3"
+
+    def test_failed_or_silent_utterance_emits_empty_text(self, mock_write_notification):
+        """Every segment index is emitted so the host never waits on a gap."""
+        fake_engine = MagicMock()
+        fake_engine.is_ready.return_value = True
+        fake_engine.transcribe.side_effect = RuntimeError("boom")
+
+        executor, engine, rules, process, status = self._patches(fake_engine)
+        with executor, engine, rules, process, status:
+            transcribe_utterance_async(
+                "session-1",
+                0,
+                np.ones(4, dtype=np.float32),
+                250,
+                preprocess=lambda audio: audio[:0],
+            )
+            transcribe_utterance_async(
+                "session-1",
+                1,
+                np.ones(4, dtype=np.float32),
+                250,
+                preprocess=lambda audio: audio,
+            )
+
+        params = [call.args[0].params for call in mock_write_notification.call_args_list]
+        assert [(p["segment_index"], p["text"]) for p in params] == [(0, ""), (1, "")]
+
+    def test_cancelled_session_emits_no_utterances(self, mock_write_notification):
+        """Utterances still queued when a session is cancelled are dropped."""
+        tracker = get_session_tracker()
+        tracker.register("session-1")
+        tracker.mark_cancelled("session-1")
+
+        fake_engine = MagicMock()
+        fake_engine.is_ready.return_value = True
+        fake_engine.transcribe.return_value = MagicMock(
+            text="late words", confidence=None, language=None
+        )
+
+        executor, engine, rules, process, status = self._patches(fake_engine)
+        with executor, engine, rules, process, status:
+            transcribe_utterance_async(
+                "session-1",
+                0,
+                np.ones(4, dtype=np.float32),
+                250,
+                preprocess=lambda audio: audio,
+            )
+
+        mock_write_notification.assert_not_called()
+
+
+class TestTranscriptionResultCache:
+    """Tests for the transcription result cache and get_result lookups."""
+
+    def test_fingerprint_depends_on_audio_and_model(self):
+        """Fingerprints should match only for identical audio and model."""
+        audio = np.array([0.1, 0.2], dtype=np.float32)
+
+        assert audio_fingerprint(audio, "a") == audio_fingerprint(audio.copy(), "a")
+        assert audio_fingerprint(audio, "a") != audio_fingerprint(audio, "b")
+        assert audio_fingerprint(audio, "a") != audio_fingerprint(audio[:1], "a")
+
+    def test_cache_evicts_oldest_entries(self):
+        """Cache should stay bounded."""
+        cache = TranscriptionResultCache(max_entries=2)
+        cache.record_error("session-1", "E_TRANSCRIBE", "one")
+        cache.record_error("session-2", "E_TRANSCRIBE", "two")
+        cache.record_error("session-3", "E_TRANSCRIBE", "three")
+
+        assert cache.get_outcome("session-1") is None
+        assert cache.get_outcome("session-3") == {
+            "error": {"kind": "E_TRANSCRIBE", "message": "three"}
+        }
+
+    def test_cache_expires_old_entries(self):
+        """Entries older than max_age should be dropped."""
+        cache = TranscriptionResultCache(max_age_seconds=1)
+        with patch("openvoicy_sidecar.notifications.time.monotonic", return_value=100.0):
+            cache.record_error("session-1", "E_TRANSCRIBE", "boom")
+        with patch("openvoicy_sidecar.notifications.time.monotonic", return_value=102.0):
+            assert cache.get_outcome("session-1") is None
+
+    def test_get_result_reports_completed_session(self, mock_write_notification):
+        """Completed sessions should return the emitted params."""
+        get_session_tracker().register("session-1")
+        emit_transcription_complete("session-1", "Hello.", 120, raw_text="hello")
+
+        result = get_transcription_result("session-1")
+
+        assert result["state"] == "completed"
+        assert result["result"]["text"] == "Hello."
+        assert result["result"]["raw_text"] == "hello"
+        assert "error" not in result
+
+    def test_get_result_reports_error_session(self, mock_write_notification):
+        """Failed sessions should return the emitted error."""
+        get_session_tracker().register("session-1")
+        emit_transcription_error("session-1", "E_TRANSCRIBE", "boom")
+
+        result = get_transcription_result("session-1")
+
+        assert result == {
+            "session_id": "session-1",
+            "state": "error",
+            "error": {"kind": "E_TRANSCRIBE", "message": "boom"},
+        }
+
+    def test_get_result_reports_pending_cancelled_and_unknown(self):
+        """Sessions without an outcome should only report their state."""
+        tracker = get_session_tracker()
+        tracker.register("pending")
+        tracker.register("cancelled")
+        tracker.mark_cancelled("cancelled")
+
+        assert get_transcription_result("pending") == {"session_id": "pending", "state": "pending"}
+        assert get_transcription_result("cancelled")["state"] == "cancelled"
+        assert get_transcription_result("missing") == {"session_id": "missing", "state": "unknown"}
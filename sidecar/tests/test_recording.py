@@ -30,6 +30,7 @@ from openvoicy_sidecar.recording import (
     get_pending_audio,
     get_recorder,
     handle_recording_cancel,
+    handle_recording_split,
     handle_recording_start,
     handle_recording_status,
     handle_recording_stop,
@@ -147,6 +148,23 @@ class TestRecordingSession:
 
         np.testing.assert_array_almost_equal(audio, expected)
 
+    def test_take_audio_empties_buffer(self):
+        """Taking audio should return it with its duration and leave the buffer empty."""
+        session = RecordingSession(
+            session_id="test",
+            started_at=time.monotonic(),
+            sample_rate=16000,
+            channels=1,
+            max_samples=160000,
+        )
+        session.add_chunk(np.zeros(1600, dtype=np.float32))
+
+        audio, duration_ms = session.take_audio()
+        assert len(audio) == 1600
+        assert duration_ms == 100
+        assert len(session.get_audio()) == 0
+        assert session.take_audio()[1] == 0
+
     def test_get_duration_ms(self):
         """Should calculate duration correctly."""
         session = RecordingSession(
@@ -404,6 +422,182 @@ class TestAudioRecorder:
 
                 recorder.cancel(start_result["session_id"])
 
+    def test_continuous_mode_cuts_utterances_and_keeps_recording(
+        self, mock_sounddevice, reset_global_recorder
+    ):
+        """In continuous mode a VAD pause hands off an utterance without stopping."""
+        with patch.dict("sys.modules", {"sounddevice": mock_sounddevice}):
+            with (
+                patch(
+                    "openvoicy_sidecar.notifications.transcribe_utterance_async"
+                ) as mock_utterance,
+                patch("openvoicy_sidecar.notifications.transcribe_session_async") as mock_transcribe,
+                patch("openvoicy_sidecar.notifications.emit_status_changed"),
+            ):
+                start_result = handle_recording_start(
+                    Request(
+                        method="recording.start",
+                        id=1,
+                        params={
+                            "continuous": True,
+                            "vad_enabled": True,
+                            "vad_silence_ms": 400,
+                            "vad_min_speech_ms": 100,
+                        },
+                    )
+                )
+                session_id = start_result["session_id"]
+                recorder = get_recorder()
+                assert recorder.continuous
+
+                speech_chunk = np.full((CHUNK_SIZE, recorder.channels), 0.2, dtype=np.float32)
+                silence_chunk = np.zeros((CHUNK_SIZE, recorder.channels), dtype=np.float32)
+                for _ in range(2):
+                    for _ in range(6):
+                        recorder._audio_callback(speech_chunk, CHUNK_SIZE, None, None)
+                    for _ in range(30):
+                        recorder._audio_callback(silence_chunk, CHUNK_SIZE, None, None)
+
+                assert recorder.state == RecordingState.RECORDING
+                mock_transcribe.assert_not_called()
+                assert [call.args[:2] for call in mock_utterance.call_args_list] == [
+                    (session_id, 0),
+                    (session_id, 1),
+                ]
+                # Each cut empties the buffer, so no audio is transcribed twice.
+                cut_lengths = [len(call.args[2]) for call in mock_utterance.call_args_list]
+                assert sum(cut_lengths) <= 72 * CHUNK_SIZE
+                assert mock_utterance.call_args_list[0].args[3] > 0
+
+                handle_recording_stop(
+                    Request(method="recording.stop", id=2, params={"session_id": session_id})
+                )
+
+        _, transcribe_kwargs = mock_transcribe.call_args
+        assert transcribe_kwargs == {"after_utterances": True}
+        assert not recorder.continuous
+
+    def test_continuous_flush_interval_cuts_at_short_gap(
+        self, mock_sounddevice, reset_global_recorder
+    ):
+        """A flush interval cuts at the first short gap once enough audio is buffered."""
+        with patch.dict("sys.modules", {"sounddevice": mock_sounddevice}):
+            with (
+                patch(
+                    "openvoicy_sidecar.notifications.transcribe_utterance_async"
+                ) as mock_utterance,
+                patch("openvoicy_sidecar.notifications.transcribe_session_async"),
+                patch("openvoicy_sidecar.notifications.emit_status_changed"),
+            ):
+                start_result = handle_recording_start(
+                    Request(
+                        method="recording.start",
+                        id=1,
+                        params={
+                            "continuous": True,
+                            "flush_interval_ms": 1000,
+                            "vad_enabled": True,
+                            "vad_silence_ms": 2000,
+                            "vad_min_speech_ms": 100,
+                        },
+                    )
+                )
+                session_id = start_result["session_id"]
+                recorder = get_recorder()
+
+                speech_chunk = np.full((CHUNK_SIZE, recorder.channels), 0.2, dtype=np.float32)
+                silence_chunk = np.zeros((CHUNK_SIZE, recorder.channels), dtype=np.float32)
+
+                # A short gap before the interval has elapsed keeps buffering.
+                for _ in range(6):
+                    recorder._audio_callback(speech_chunk, CHUNK_SIZE, None, None)
+                for _ in range(3):
+                    recorder._audio_callback(silence_chunk, CHUNK_SIZE, None, None)
+                mock_utterance.assert_not_called()
+
+                # Continuous speech past the interval is never cut mid-word.
+                for _ in range(12):
+                    recorder._audio_callback(speech_chunk, CHUNK_SIZE, None, None)
+                mock_utterance.assert_not_called()
+
+                recorder._audio_callback(silence_chunk, CHUNK_SIZE, None, None)
+                assert [call.args[:2] for call in mock_utterance.call_args_list] == [
+                    (session_id, 0)
+                ]
+                assert recorder.state == RecordingState.RECORDING
+
+                recorder.cancel(session_id)
+
+    def test_flush_interval_is_ignored_without_continuous_mode(
+        self, recorder, mock_sounddevice
+    ):
+        """The flush interval only applies to continuous recordings."""
+        with patch.dict("sys.modules", {"sounddevice": mock_sounddevice}):
+            session_id = recorder.start(flush_interval_ms=5000)
+            assert recorder._flush_interval_ms == 0
+            recorder.cancel(session_id)
+
+    def test_continuous_mode_without_vad_records_single_utterance(
+        self, recorder, mock_sounddevice
+    ):
+        """Continuous mode needs a VAD detector to find utterance boundaries."""
+        with patch.dict("sys.modules", {"sounddevice": mock_sounddevice}):
+            session_id = recorder.start(continuous=True)
+            assert not recorder.continuous
+            recorder.cancel(session_id)
+
+    def test_split_hands_off_audio_and_keeps_recording(
+        self, mock_sounddevice, reset_global_recorder
+    ):
+        """A split transcribes the audio so far as an utterance; stop waits for it."""
+        with patch.dict("sys.modules", {"sounddevice": mock_sounddevice}):
+            with (
+                patch(
+                    "openvoicy_sidecar.notifications.transcribe_utterance_async"
+                ) as mock_utterance,
+                patch("openvoicy_sidecar.notifications.transcribe_session_async") as mock_transcribe,
+                patch("openvoicy_sidecar.notifications.emit_status_changed"),
+            ):
+                session_id = handle_recording_start(
+                    Request(method="recording.start", id=1, params={})
+                )["session_id"]
+                recorder = get_recorder()
+                assert not recorder.has_utterances
+                recorder._session.add_chunk(np.zeros(1600, dtype=np.float32))
+
+                result = handle_recording_split(
+                    Request(method="recording.split", id=2, params={"session_id": session_id})
+                )
+
+                assert result == {
+                    "session_id": session_id,
+                    "segment_index": 0,
+                    "audio_duration_ms": int(1600 * 1000 / recorder.sample_rate),
+                }
+                assert recorder.state == RecordingState.RECORDING
+                assert recorder._session.get_duration_ms() == 0
+                assert recorder.has_utterances
+                assert mock_utterance.call_args.args[:2] == (session_id, 0)
+
+                recorder._session.add_chunk(np.zeros(800, dtype=np.float32))
+                handle_recording_stop(
+                    Request(method="recording.stop", id=3, params={"session_id": session_id})
+                )
+
+        _, transcribe_kwargs = mock_transcribe.call_args
+        assert transcribe_kwargs == {"after_utterances": True}
+
+    def test_split_requires_the_active_session(self, recorder, mock_sounddevice):
+        """Splitting needs a recording with a matching session ID."""
+        with pytest.raises(RuntimeError, match="Not recording"):
+            recorder.split("missing")
+
+        with patch.dict("sys.modules", {"sounddevice": mock_sounddevice}):
+            session_id = recorder.start()
+            with pytest.raises(RuntimeError, match="Invalid session ID"):
+                recorder.split("other-session")
+            recorder.cancel(session_id)
+
     def test_stop_recording(self, recorder, mock_sounddevice):
         """Should stop recording and return audio data."""
         with patch.dict("sys.modules", {"sounddevice": mock_sounddevice}):
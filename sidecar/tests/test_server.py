@@ -4,6 +4,8 @@ from __future__ import annotations
 
 import io
 import json
+import os
+import socket
 import subprocess
 import sys
 from pathlib import Path
@@ -339,6 +341,64 @@ class TestServerIntegration:
         assert any("exiting" in l.lower() for l in stderr)
 
 
+class TestSocketTransport:
+    """Tests for talking to the host over a socket instead of stdio."""
+
+    def _spawn(self, endpoint: str) -> subprocess.Popen:
+        return subprocess.Popen(
+            [sys.executable, "-m", "openvoicy_sidecar"],
+            stdin=subprocess.PIPE,
+            stdout=subprocess.PIPE,
+            stderr=subprocess.PIPE,
+            text=True,
+            cwd=str(src_path.parent),
+            env={
+                **dict(os.environ),
+                "PYTHONPATH": str(src_path),
+                "OPENVOICY_IPC_ENDPOINT": endpoint,
+            },
+        )
+
+    @pytest.mark.skipif(not hasattr(socket, "AF_UNIX"), reason="needs Unix sockets")
+    def test_unix_socket_carries_protocol_and_frees_stdout(self, tmp_path):
+        """Requests and responses should use the socket; stdout stays empty."""
+        path = tmp_path / "sidecar.sock"
+        listener = socket.socket(socket.AF_UNIX, socket.SOCK_STREAM)
+        listener.bind(str(path))
+        listener.listen(1)
+        listener.settimeout(10.0)
+
+        proc = self._spawn(f"unix:{path}")
+        try:
+            conn, _ = listener.accept()
+            conn.settimeout(10.0)
+            with conn, conn.makefile("rw", encoding="utf-8", newline="\n") as stream:
+                stream.write('{"jsonrpc":"2.0","id":1,"method":"system.ping"}\n')
+                stream.flush()
+                response = json.loads(stream.readline())
+                assert response["id"] == 1
+                assert response["result"]["protocol"] == "v1"
+            stdout, _ = proc.communicate(timeout=10.0)
+        finally:
+            listener.close()
+            if proc.poll() is None:
+                proc.kill()
+                proc.communicate()
+
+        assert proc.returncode == 0
+        assert stdout == ""
+
+    def test_unusable_endpoint_falls_back_to_stdio(self):
+        """An endpoint that cannot be opened should leave stdio in use."""
+        proc = self._spawn("carrier-pigeon:coop")
+        stdout, stderr = proc.communicate(
+            '{"jsonrpc":"2.0","id":1,"method":"system.ping"}\n', timeout=10.0
+        )
+        responses = [json.loads(line) for line in stdout.splitlines() if line.strip()]
+        assert [r["id"] for r in responses] == [1]
+        assert "using stdio" in stderr
+
+
 class TestOversizedLine:
     """Tests for oversized line handling."""
 
@@ -0,0 +1,88 @@
+"""Compliance tests for transcription.get_result handler, docs, and contract entries."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+import pytest
+
+import openvoicy_sidecar.notifications as notifications
+from openvoicy_sidecar.protocol import Request
+from openvoicy_sidecar.recording import InvalidSessionError, handle_transcription_get_result
+from openvoicy_sidecar.server import HANDLERS
+
+
+@pytest.fixture(autouse=True)
+def reset_notification_state():
+    notifications._session_tracker = None
+    notifications._result_cache = None
+    yield
+    notifications._session_tracker = None
+    notifications._result_cache = None
+
+
+@pytest.fixture
+def repo_root() -> Path:
+    return Path(__file__).resolve().parents[2]
+
+
+def _request(params: dict[str, Any], req_id: int = 1) -> Request:
+    return Request(method="transcription.get_result", id=req_id, params=params)
+
+
+def test_transcription_get_result_handler_in_dispatch_table() -> None:
+    assert "transcription.get_result" in HANDLERS
+    assert HANDLERS["transcription.get_result"] is handle_transcription_get_result
+
+
+def test_transcription_get_result_requires_session_id() -> None:
+    with pytest.raises(InvalidSessionError):
+        handle_transcription_get_result(_request({}))
+
+
+def test_transcription_get_result_completed_shape(monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.setattr(notifications, "write_notification", lambda notification: None)
+    notifications.get_session_tracker().register("session-123")
+    notifications.emit_transcription_complete("session-123", "Hello.", 250, confidence=0.9)
+
+    result = handle_transcription_get_result(_request({"session_id": "session-123"}))
+
+    assert result["session_id"] == "session-123"
+    assert result["state"] == "completed"
+    assert result["result"]["text"] == "Hello."
+    assert result["result"]["duration_ms"] == 250
+
+
+def test_transcription_get_result_unknown_session() -> None:
+    result = handle_transcription_get_result(_request({"session_id": "missing"}))
+
+    assert result == {"session_id": "missing", "state": "unknown"}
+
+
+def test_transcription_get_result_optional_contract_entry(repo_root: Path) -> None:
+    contract_path = repo_root / "shared" / "contracts" / "sidecar.rpc.v1.json"
+    contract = json.loads(contract_path.read_text())
+    method = next(
+        item for item in contract["items"] if item.get("name") == "transcription.get_result"
+    )
+
+    assert method["required"] is False
+    assert method["params_schema"]["required"] == ["session_id"]
+    assert set(method["result_schema"]["required"]) == {"session_id", "state"}
+    assert set(method["result_schema"]["properties"]["state"]["enum"]) == {
+        "pending",
+        "completed",
+        "error",
+        "cancelled",
+        "unknown",
+    }
+
+
+def test_transcription_get_result_documented_in_ipc_protocol(repo_root: Path) -> None:
+    protocol_path = repo_root / "shared" / "ipc" / "IPC_PROTOCOL_V1.md"
+    protocol_text = protocol_path.read_text()
+
+    assert "#### `transcription.get_result`" in protocol_text
+    assert "| `transcription.get_result` *(optional)* | 2s | 1 retry |" in protocol_text
@@ -0,0 +1,109 @@
+"""Tests for transcription.retry handler, retained audio, and contract entries."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+import numpy as np
+import pytest
+
+import openvoicy_sidecar.notifications as notifications
+from openvoicy_sidecar.protocol import Request
+from openvoicy_sidecar.recording import InvalidSessionError, handle_transcription_retry
+from openvoicy_sidecar.server import HANDLERS
+
+
+@pytest.fixture(autouse=True)
+def reset_notification_state():
+    notifications._session_tracker = None
+    notifications._result_cache = None
+    notifications._failed_audio.clear()
+    yield
+    notifications._session_tracker = None
+    notifications._result_cache = None
+    notifications._failed_audio.clear()
+
+
+@pytest.fixture
+def repo_root() -> Path:
+    return Path(__file__).resolve().parents[2]
+
+
+def _request(params: dict[str, Any], req_id: int = 1) -> Request:
+    return Request(method="transcription.retry", id=req_id, params=params)
+
+
+def test_transcription_retry_handler_in_dispatch_table() -> None:
+    assert "transcription.retry" in HANDLERS
+    assert HANDLERS["transcription.retry"] is handle_transcription_retry
+
+
+def test_transcription_retry_requires_session_id() -> None:
+    with pytest.raises(InvalidSessionError):
+        handle_transcription_retry(_request({}))
+
+
+def test_transcription_retry_without_retained_audio() -> None:
+    result = handle_transcription_retry(_request({"session_id": "missing"}))
+
+    assert result == {"session_id": "missing", "retrying": False}
+
+
+def test_transcription_retry_reruns_failed_session_once(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    started: list[tuple[str, int]] = []
+    monkeypatch.setattr(
+        notifications,
+        "transcribe_session_async",
+        lambda session_id, audio, sample_rate: started.append((session_id, len(audio))),
+    )
+    notifications._retain_failed_audio("session-1", np.zeros(1600, dtype=np.float32))
+
+    first = handle_transcription_retry(_request({"session_id": "session-1"}))
+    second = handle_transcription_retry(_request({"session_id": "session-1"}))
+
+    assert first["retrying"] is True
+    assert second["retrying"] is False
+    assert started == [("session-1", 1600)]
+
+
+def test_failed_audio_retention_is_bounded() -> None:
+    for index in range(notifications.MAX_RETAINED_FAILED_SESSIONS + 1):
+        notifications._retain_failed_audio(f"session-{index}", np.zeros(8, dtype=np.float32))
+
+    assert "session-0" not in notifications._failed_audio
+    assert len(notifications._failed_audio) == notifications.MAX_RETAINED_FAILED_SESSIONS
+
+
+def test_transcription_retry_reopens_session_for_new_outcome(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    emitted: list[str] = []
+    monkeypatch.setattr(
+        notifications,
+        "write_notification",
+        lambda notification: emitted.append(notification.method),
+    )
+    tracker = notifications.get_session_tracker()
+    tracker.register("session-2")
+    notifications.emit_transcription_error("session-2", "E_TRANSCRIPTION_TIMEOUT", "timed out")
+
+    # transcribe_session_async re-registers the session before emitting.
+    tracker.register("session-2")
+    notifications.emit_transcription_complete("session-2", "Hello.", 120)
+
+    assert emitted == ["event.transcription_error", "event.transcription_complete"]
+    assert notifications.get_transcription_result("session-2")["state"] == "completed"
+
+
+def test_transcription_retry_optional_contract_entry(repo_root: Path) -> None:
+    contract_path = repo_root / "shared" / "contracts" / "sidecar.rpc.v1.json"
+    contract = json.loads(contract_path.read_text())
+    method = next(item for item in contract["items"] if item.get("name") == "transcription.retry")
+
+    assert method["required"] is False
+    assert method["params_schema"]["required"] == ["session_id"]
+    assert set(method["result_schema"]["required"]) == {"session_id", "retrying"}
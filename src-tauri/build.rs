@@ -1,3 +1,6 @@
 fn main() {
-    tauri_build::build()
+    // Tauri's codegen is only needed for the desktop app.
+    if std::env::var_os("CARGO_FEATURE_DESKTOP").is_some() {
+        tauri_build::build()
+    }
 }
@@ -0,0 +1,385 @@
+//! Screen-reader friendly state announcements.
+//!
+//! Speaks short lifecycle messages ("Recording started", "Transcription
+//! complete, 42 words") through the platform speech service so users who
+//! cannot see the tray icon or overlay still know what the app is doing.
+//! Announcements are off by default and gated per category via `ui.*`
+//! config flags.
+
+use std::sync::mpsc;
+use std::sync::Arc;
+
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::config::{self, UiConfig};
+use crate::recording::RecordingEvent;
+use crate::state::{AppState, StateEvent};
+
+/// Announcement category, each toggled by its own config flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnouncementCategory {
+    /// Recording started/stopped/cancelled.
+    Recording,
+    /// Transcription results.
+    Transcription,
+    /// Pause/resume and model loading.
+    Status,
+    /// Failures and timeouts.
+    Errors,
+}
+
+/// Effective announcement settings resolved from config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AnnouncementSettings {
+    pub enabled: bool,
+    pub recording: bool,
+    pub transcription: bool,
+    pub status: bool,
+    pub errors: bool,
+}
+
+impl AnnouncementSettings {
+    pub fn from_ui_config(ui: &UiConfig) -> Self {
+        Self {
+            enabled: ui.announcements_enabled,
+            recording: ui.announce_recording,
+            transcription: ui.announce_transcription,
+            status: ui.announce_status,
+            errors: ui.announce_errors,
+        }
+    }
+
+    /// Whether announcements in `category` should be spoken.
+    pub fn allows(&self, category: AnnouncementCategory) -> bool {
+        self.enabled
+            && match category {
+                AnnouncementCategory::Recording => self.recording,
+                AnnouncementCategory::Transcription => self.transcription,
+                AnnouncementCategory::Status => self.status,
+                AnnouncementCategory::Errors => self.errors,
+            }
+    }
+}
+
+/// A message to be spoken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Announcement {
+    pub category: AnnouncementCategory,
+    pub message: String,
+}
+
+impl Announcement {
+    fn new(category: AnnouncementCategory, message: impl Into<String>) -> Self {
+        Self {
+            category,
+            message: message.into(),
+        }
+    }
+}
+
+/// Map a recording lifecycle event to an announcement.
+pub fn announcement_for_recording_event(event: &RecordingEvent) -> Announcement {
+    use AnnouncementCategory::{Errors, Recording, Transcription};
+
+    match event {
+        RecordingEvent::Started { .. } => Announcement::new(Recording, "Recording started"),
+        RecordingEvent::Stopped { .. } => {
+            Announcement::new(Recording, "Recording stopped, transcribing")
+        }
+        RecordingEvent::TooShort { .. } => Announcement::new(Recording, "Recording too short"),
+        RecordingEvent::Cancelled { .. } => Announcement::new(Recording, "Recording cancelled"),
+        RecordingEvent::MaxDurationReached { .. } => {
+            Announcement::new(Recording, "Maximum recording length reached")
+        }
+        RecordingEvent::UtteranceComplete { text, .. }
+        | RecordingEvent::TranscriptionComplete { text, .. } => {
+            Announcement::new(Transcription, transcription_complete_message(text))
+        }
+        RecordingEvent::TranscriptionFailed { .. } => {
+            Announcement::new(Errors, "Transcription failed")
+        }
+        RecordingEvent::TranscriptionTimeout { .. } => {
+            Announcement::new(Errors, "Transcription timed out")
+        }
+    }
+}
+
+/// Map a state change to an announcement.
+///
+/// Recording and transcribing transitions are announced from recording
+/// events instead, so they are not repeated here.
+pub fn announcement_for_state_change(
+    previous: Option<&StateEvent>,
+    current: &StateEvent,
+) -> Option<Announcement> {
+    use AnnouncementCategory::{Errors, Status};
+
+    let previous_state = previous.map(|event| event.state);
+    if previous.is_some_and(|event| event.enabled != current.enabled) {
+        let message = if current.enabled {
+            "Dictation resumed"
+        } else {
+            "Dictation paused"
+        };
+        return Some(Announcement::new(Status, message));
+    }
+    if previous_state == Some(current.state) {
+        return None;
+    }
+
+    match current.state {
+        AppState::LoadingModel => Some(Announcement::new(Status, "Loading speech model")),
+        AppState::Idle if previous_state == Some(AppState::LoadingModel) => {
+            Some(Announcement::new(Status, "Speech model ready"))
+        }
+        AppState::Error => Some(Announcement::new(Errors, "Voice input error")),
+        _ => None,
+    }
+}
+
+fn transcription_complete_message(text: &str) -> String {
+    match text.split_whitespace().count() {
+        0 => "Transcription complete, no speech detected".to_string(),
+        1 => "Transcription complete, 1 word".to_string(),
+        words => format!("Transcription complete, {words} words"),
+    }
+}
+
+/// Speaks announcements.
+pub trait Announcer: Send + Sync {
+    fn announce(&self, message: &str);
+}
+
+/// Announcer backed by the platform speech service.
+///
+/// Messages are spoken one at a time on a worker thread so a burst of
+/// events does not talk over itself.
+pub struct SystemAnnouncer {
+    sender: mpsc::Sender<String>,
+}
+
+impl SystemAnnouncer {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel::<String>();
+        std::thread::spawn(move || {
+            for message in receiver {
+                if let Err(error) = speak(&message) {
+                    log::debug!("Screen-reader announcement failed: {}", error);
+                }
+            }
+        });
+        Self { sender }
+    }
+}
+
+impl Default for SystemAnnouncer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Announcer for SystemAnnouncer {
+    fn announce(&self, message: &str) {
+        // Never speak from unit tests.
+        if cfg!(test) {
+            return;
+        }
+        let _ = self.sender.send(message.to_string());
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn speak(message: &str) -> std::io::Result<()> {
+    std::process::Command::new("say")
+        .arg(message)
+        .status()
+        .map(|_| ())
+}
+
+#[cfg(target_os = "windows")]
+fn speak(message: &str) -> std::io::Result<()> {
+    let script = format!(
+        "Add-Type -AssemblyName System.Speech; \
+         (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak('{}')",
+        message.replace('\'', "''")
+    );
+    std::process::Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", &script])
+        .status()
+        .map(|_| ())
+}
+
+#[cfg(target_os = "linux")]
+fn speak(message: &str) -> std::io::Result<()> {
+    // speech-dispatcher is shared with Orca, so this queues with the screen reader.
+    std::process::Command::new("spd-say")
+        .args(["--wait", message])
+        .status()
+        .map(|_| ())
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+fn speak(_message: &str) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "speech announcements are not supported on this platform",
+    ))
+}
+
+/// Consume state and recording events and speak enabled announcements.
+///
+/// Config is re-read per announcement so toggles apply without restart.
+pub fn spawn_announcement_loop(
+    mut state_rx: broadcast::Receiver<StateEvent>,
+    mut recording_rx: broadcast::Receiver<RecordingEvent>,
+    announcer: Arc<dyn Announcer>,
+) {
+    tokio::spawn(async move {
+        log::info!("Announcement loop started");
+        let mut last_state: Option<StateEvent> = None;
+
+        loop {
+            let announcement = tokio::select! {
+                received = state_rx.recv() => match received {
+                    Ok(event) => {
+                        let announcement =
+                            announcement_for_state_change(last_state.as_ref(), &event);
+                        last_state = Some(event);
+                        announcement
+                    }
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                },
+                received = recording_rx.recv() => match received {
+                    Ok(event) => Some(announcement_for_recording_event(&event)),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                },
+            };
+
+            let Some(announcement) = announcement else {
+                continue;
+            };
+            let settings = AnnouncementSettings::from_ui_config(&config::load_config().ui);
+            if settings.allows(announcement.category) {
+                announcer.announce(&announcement.message);
+            }
+        }
+
+        log::info!("Announcement loop ended");
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording::CancelReason;
+    use crate::state::EnableLevel;
+
+    fn state_event(state: AppState, enabled: bool) -> StateEvent {
+        StateEvent {
+            state,
+            enabled,
+            level: if enabled {
+                EnableLevel::Enabled
+            } else {
+                EnableLevel::FullyPaused
+            },
+            detail: None,
+            timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_transcription_complete_announces_word_count() {
+        let announcement =
+            announcement_for_recording_event(&RecordingEvent::TranscriptionComplete {
+                session_id: "session-1".to_string(),
+                text: "hello there general kenobi".to_string(),
+                audio_duration_ms: 1000,
+                processing_duration_ms: 100,
+                timestamp: chrono::Utc::now(),
+            });
+
+        assert_eq!(announcement.category, AnnouncementCategory::Transcription);
+        assert_eq!(announcement.message, "Transcription complete, 4 words");
+        assert_eq!(
+            transcription_complete_message("one"),
+            "Transcription complete, 1 word"
+        );
+        assert_eq!(
+            transcription_complete_message("  "),
+            "Transcription complete, no speech detected"
+        );
+    }
+
+    #[test]
+    fn test_recording_events_map_to_categories() {
+        let now = chrono::Utc::now();
+
+        let started = announcement_for_recording_event(&RecordingEvent::Started {
+            session_id: "session-1".to_string(),
+            timestamp: now,
+        });
+        assert_eq!(started.category, AnnouncementCategory::Recording);
+        assert_eq!(started.message, "Recording started");
+
+        let cancelled = announcement_for_recording_event(&RecordingEvent::Cancelled {
+            session_id: "session-1".to_string(),
+            reason: CancelReason::EscapeKey,
+            timestamp: now,
+        });
+        assert_eq!(cancelled.message, "Recording cancelled");
+
+        let failed = announcement_for_recording_event(&RecordingEvent::TranscriptionFailed {
+            session_id: "session-1".to_string(),
+            error: "E_ASR: boom".to_string(),
+            timestamp: now,
+        });
+        assert_eq!(failed.category, AnnouncementCategory::Errors);
+    }
+
+    #[test]
+    fn test_state_changes_announce_pause_and_model_ready_only() {
+        let idle = state_event(AppState::Idle, true);
+        let paused = state_event(AppState::Idle, false);
+        let loading = state_event(AppState::LoadingModel, true);
+        let recording = state_event(AppState::Recording, true);
+
+        assert_eq!(
+            announcement_for_state_change(Some(&idle), &paused)
+                .unwrap()
+                .message,
+            "Dictation paused"
+        );
+        assert_eq!(
+            announcement_for_state_change(Some(&paused), &idle)
+                .unwrap()
+                .message,
+            "Dictation resumed"
+        );
+        assert_eq!(
+            announcement_for_state_change(Some(&loading), &idle)
+                .unwrap()
+                .message,
+            "Speech model ready"
+        );
+        assert!(announcement_for_state_change(Some(&idle), &recording).is_none());
+        assert!(announcement_for_state_change(Some(&recording), &idle).is_none());
+        assert!(announcement_for_state_change(Some(&idle), &idle).is_none());
+    }
+
+    #[test]
+    fn test_settings_gate_each_category() {
+        let mut ui = UiConfig::default();
+        assert!(!AnnouncementSettings::from_ui_config(&ui).allows(AnnouncementCategory::Recording));
+
+        ui.announcements_enabled = true;
+        ui.announce_transcription = false;
+        let settings = AnnouncementSettings::from_ui_config(&ui);
+        assert!(settings.allows(AnnouncementCategory::Recording));
+        assert!(!settings.allows(AnnouncementCategory::Transcription));
+        assert!(settings.allows(AnnouncementCategory::Status));
+        assert!(settings.allows(AnnouncementCategory::Errors));
+    }
+}
@@ -0,0 +1,301 @@
+//! Registry of user-invokable backend actions.
+//!
+//! `list_actions` returns every action with its metadata and whether it can
+//! run right now, so the UI can build a command palette (and automation can
+//! discover what the backend offers) without hard-coding the command list.
+//! `invoke_action` runs one by id after re-checking its availability.
+//!
+//! Availability is decided here from a snapshot of app state and
+//! capabilities; dispatching to the underlying commands lives in
+//! `commands::invoke_action`.
+
+use serde::Serialize;
+
+use crate::state::{AppState, CannotRecordReason};
+
+/// Every registered action, in palette order.
+pub const ACTIONS: [ActionId; 12] = [
+    ActionId::StartRecording,
+    ActionId::StopRecording,
+    ActionId::CancelRecording,
+    ActionId::ToggleEnabled,
+    ActionId::CopyLastTranscript,
+    ActionId::ClearHistory,
+    ActionId::SetInjectionMode,
+    ActionId::ClearTemporaryReplacements,
+    ActionId::DownloadModel,
+    ActionId::RestartSidecar,
+    ActionId::RunSelfCheck,
+    ActionId::CheckAppUpdate,
+];
+
+/// Stable identifier of an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionId {
+    StartRecording,
+    StopRecording,
+    CancelRecording,
+    ToggleEnabled,
+    CopyLastTranscript,
+    ClearHistory,
+    SetInjectionMode,
+    ClearTemporaryReplacements,
+    DownloadModel,
+    RestartSidecar,
+    RunSelfCheck,
+    CheckAppUpdate,
+}
+
+impl ActionId {
+    /// Id used on the wire.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::StartRecording => "recording.start",
+            Self::StopRecording => "recording.stop",
+            Self::CancelRecording => "recording.cancel",
+            Self::ToggleEnabled => "dictation.toggle_enabled",
+            Self::CopyLastTranscript => "history.copy_last",
+            Self::ClearHistory => "history.clear",
+            Self::SetInjectionMode => "injection.set_mode",
+            Self::ClearTemporaryReplacements => "replacements.clear_temporary",
+            Self::DownloadModel => "model.download",
+            Self::RestartSidecar => "sidecar.restart",
+            Self::RunSelfCheck => "diagnostics.self_check",
+            Self::CheckAppUpdate => "app.check_update",
+        }
+    }
+
+    pub fn parse(id: &str) -> Option<Self> {
+        ACTIONS.into_iter().find(|action| action.as_str() == id)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::StartRecording => "Start recording",
+            Self::StopRecording => "Stop recording",
+            Self::CancelRecording => "Cancel recording",
+            Self::ToggleEnabled => "Pause or resume dictation",
+            Self::CopyLastTranscript => "Copy last transcript",
+            Self::ClearHistory => "Clear transcript history",
+            Self::SetInjectionMode => "Set output mode",
+            Self::ClearTemporaryReplacements => "Clear temporary replacements",
+            Self::DownloadModel => "Download model",
+            Self::RestartSidecar => "Restart speech engine",
+            Self::RunSelfCheck => "Run self-check",
+            Self::CheckAppUpdate => "Check for updates",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            Self::StartRecording => "Open the microphone and start a dictation session.",
+            Self::StopRecording => "Stop the current recording and transcribe it.",
+            Self::CancelRecording => "Discard the current recording without transcribing.",
+            Self::ToggleEnabled => "Stop or resume listening for the recording hotkey.",
+            Self::CopyLastTranscript => "Copy the most recent transcript to the clipboard.",
+            Self::ClearHistory => "Delete all transcripts from the history.",
+            Self::SetInjectionMode => {
+                "Choose whether transcripts are typed, copied, or confirmed first."
+            }
+            Self::ClearTemporaryReplacements => "Drop replacement rules added for this session.",
+            Self::DownloadModel => "Download the configured speech model, or `model_id`.",
+            Self::RestartSidecar => "Restart the speech recognition process.",
+            Self::RunSelfCheck => "Check hotkey, injection, microphone, and model health.",
+            Self::CheckAppUpdate => "Look for a newer version of the app.",
+        }
+    }
+
+    /// Arguments the action reads from `args`; all are strings.
+    fn args(&self) -> &'static [ActionArg] {
+        match self {
+            Self::SetInjectionMode => &[ActionArg {
+                name: "mode",
+                required: true,
+                values: &["inject", "clipboard_only", "ask"],
+            }],
+            Self::DownloadModel => &[ActionArg {
+                name: "model_id",
+                required: false,
+                values: &[],
+            }],
+            _ => &[],
+        }
+    }
+
+    /// Why the action cannot run in `context`, as a stable code.
+    fn unavailable_reason(&self, context: &ActionContext) -> Option<&'static str> {
+        let busy = matches!(
+            context.state,
+            AppState::Recording | AppState::Transcribing | AppState::LoadingModel
+        );
+        match self {
+            Self::StartRecording => context.can_record.as_ref().err().map(cannot_record_code),
+            Self::StopRecording | Self::CancelRecording => {
+                (context.state != AppState::Recording).then_some("not_recording")
+            }
+            Self::CopyLastTranscript if !context.clipboard_available => {
+                Some("clipboard_unavailable")
+            }
+            Self::CopyLastTranscript | Self::ClearHistory => {
+                (context.history_len == 0).then_some("history_empty")
+            }
+            Self::DownloadModel | Self::RestartSidecar => busy.then_some("busy"),
+            Self::ToggleEnabled
+            | Self::SetInjectionMode
+            | Self::ClearTemporaryReplacements
+            | Self::RunSelfCheck
+            | Self::CheckAppUpdate => None,
+        }
+    }
+}
+
+/// Argument an action accepts in `invoke_action`'s `args` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ActionArg {
+    pub name: &'static str,
+    pub required: bool,
+    /// Accepted values; empty when any string is accepted.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub values: &'static [&'static str],
+}
+
+/// State that decides which actions can run.
+#[derive(Debug, Clone)]
+pub struct ActionContext {
+    pub state: AppState,
+    pub can_record: Result<(), CannotRecordReason>,
+    pub clipboard_available: bool,
+    pub history_len: usize,
+}
+
+/// Action metadata returned by `list_actions`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ActionInfo {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub args: &'static [ActionArg],
+    pub available: bool,
+    /// Stable code for why the action is unavailable.
+    pub unavailable_reason: Option<&'static str>,
+}
+
+impl ActionInfo {
+    pub fn new(action: ActionId, context: &ActionContext) -> Self {
+        let unavailable_reason = action.unavailable_reason(context);
+        Self {
+            id: action.as_str(),
+            name: action.name(),
+            description: action.description(),
+            args: action.args(),
+            available: unavailable_reason.is_none(),
+            unavailable_reason,
+        }
+    }
+}
+
+/// All actions with their availability in `context`.
+pub fn list_actions(context: &ActionContext) -> Vec<ActionInfo> {
+    ACTIONS
+        .into_iter()
+        .map(|action| ActionInfo::new(action, context))
+        .collect()
+}
+
+fn cannot_record_code(reason: &CannotRecordReason) -> &'static str {
+    match reason {
+        CannotRecordReason::Paused => "paused",
+        CannotRecordReason::ModelLoading => "model_loading",
+        CannotRecordReason::AlreadyRecording => "already_recording",
+        CannotRecordReason::StillTranscribing => "still_transcribing",
+        CannotRecordReason::InErrorState => "error_state",
+        CannotRecordReason::ScreenSharing => "screen_sharing",
+        CannotRecordReason::HotkeysPaused => "hotkeys_paused",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn idle() -> ActionContext {
+        ActionContext {
+            state: AppState::Idle,
+            can_record: Ok(()),
+            clipboard_available: true,
+            history_len: 2,
+        }
+    }
+
+    fn reason(context: &ActionContext, action: ActionId) -> Option<&'static str> {
+        ActionInfo::new(action, context).unavailable_reason
+    }
+
+    #[test]
+    fn test_ids_are_unique_and_round_trip() {
+        for action in ACTIONS {
+            assert_eq!(ActionId::parse(action.as_str()), Some(action));
+        }
+        let mut ids: Vec<_> = ACTIONS.iter().map(ActionId::as_str).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), ACTIONS.len());
+        assert_eq!(ActionId::parse("recording.explode"), None);
+    }
+
+    #[test]
+    fn test_recording_actions_follow_state() {
+        let context = idle();
+        assert_eq!(reason(&context, ActionId::StartRecording), None);
+        assert_eq!(
+            reason(&context, ActionId::StopRecording),
+            Some("not_recording")
+        );
+
+        let context = ActionContext {
+            state: AppState::Recording,
+            can_record: Err(CannotRecordReason::AlreadyRecording),
+            ..idle()
+        };
+        assert_eq!(
+            reason(&context, ActionId::StartRecording),
+            Some("already_recording")
+        );
+        assert_eq!(reason(&context, ActionId::CancelRecording), None);
+        assert_eq!(reason(&context, ActionId::RestartSidecar), Some("busy"));
+    }
+
+    #[test]
+    fn test_history_actions_need_history_and_clipboard() {
+        let context = ActionContext {
+            history_len: 0,
+            ..idle()
+        };
+        assert_eq!(
+            reason(&context, ActionId::ClearHistory),
+            Some("history_empty")
+        );
+
+        let context = ActionContext {
+            clipboard_available: false,
+            ..idle()
+        };
+        assert_eq!(
+            reason(&context, ActionId::CopyLastTranscript),
+            Some("clipboard_unavailable")
+        );
+        assert_eq!(reason(&context, ActionId::ClearHistory), None);
+    }
+
+    #[test]
+    fn test_list_serializes_metadata() {
+        let list = list_actions(&idle());
+        assert_eq!(list.len(), ACTIONS.len());
+        let set_mode = serde_json::to_value(&list[6]).unwrap();
+        assert_eq!(set_mode["id"], "injection.set_mode");
+        assert_eq!(set_mode["available"], true);
+        assert_eq!(set_mode["unavailable_reason"], serde_json::Value::Null);
+        assert_eq!(set_mode["args"][0]["name"], "mode");
+        assert_eq!(set_mode["args"][0]["values"][1], "clipboard_only");
+    }
+}
@@ -0,0 +1,465 @@
+//! Icons of the apps transcripts were dictated into, for the history view.
+//!
+//! History entries name their target app by its normalized id
+//! (`injection_target.app_id`); the UI asks for the icon with
+//! `get_app_icon`, which resolves it lazily and caches the result, misses
+//! included, for the rest of the session.
+//!
+//! At injection time the executable of the target window is remembered per
+//! app id. Linux matches desktop entries against the app id and executable
+//! name and reads their `Icon=` from the hicolor theme or pixmaps. macOS
+//! converts the icon of the app's bundle, found from the executable or by
+//! name in the Applications folders. Windows extracts the icon associated
+//! with the executable, so only apps dictated into since startup have one.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+use crate::focus::normalize_app_id;
+
+/// Icon files larger than this are not sent to the UI.
+const MAX_ICON_BYTES: u64 = 256 * 1024;
+
+/// Edge length icons are converted to where the platform needs converting.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+const ICON_SIZE_PX: u32 = 64;
+
+/// Resolved icons (and misses) kept before the cache starts over.
+const MAX_CACHED_ICONS: usize = 256;
+
+static EXECUTABLES: Lazy<Mutex<HashMap<String, PathBuf>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+static ICONS: Lazy<Mutex<HashMap<String, Option<AppIcon>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Icon of an app, ready for an `<img>`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct AppIcon {
+    pub app_id: String,
+    /// `data:` URL of a PNG or SVG image.
+    pub data_url: String,
+}
+
+/// Remember the executable of process `pid` as the source of `app_id`'s
+/// icon. Cheap enough for the injection path: no process is spawned.
+pub fn remember_executable(app_id: &str, pid: Option<u32>) {
+    let Some(path) = pid.and_then(executable_path) else {
+        return;
+    };
+    let mut executables = EXECUTABLES.lock().unwrap_or_else(|e| e.into_inner());
+    if executables.get(app_id) == Some(&path) {
+        return;
+    }
+    executables.insert(app_id.to_string(), path);
+    drop(executables);
+
+    // A miss cached before the executable was known may resolve now.
+    let mut icons = ICONS.lock().unwrap_or_else(|e| e.into_inner());
+    if matches!(icons.get(app_id), Some(None)) {
+        icons.remove(app_id);
+    }
+}
+
+/// Icon for `app_id`, resolved on first request. Blocks on file reads and,
+/// on macOS and Windows, a helper process.
+pub fn icon_for(app_id: &str) -> Option<AppIcon> {
+    // Normalized ids are `[a-z0-9-]` only, so they are safe in file names.
+    let app_id = normalize_app_id(app_id)?;
+    if let Some(cached) = ICONS.lock().unwrap_or_else(|e| e.into_inner()).get(&app_id) {
+        return cached.clone();
+    }
+
+    let executable = EXECUTABLES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&app_id)
+        .cloned();
+    let icon = resolve(&app_id, executable.as_deref()).map(|data_url| AppIcon {
+        app_id: app_id.clone(),
+        data_url,
+    });
+
+    let mut icons = ICONS.lock().unwrap_or_else(|e| e.into_inner());
+    if icons.len() >= MAX_CACHED_ICONS {
+        icons.clear();
+    }
+    icons.insert(app_id, icon.clone());
+    icon
+}
+
+fn data_url(mime: &str, bytes: &[u8]) -> String {
+    format!("data:{mime};base64,{}", BASE64_STANDARD.encode(bytes))
+}
+
+/// PNG or SVG icon file as a data URL, unless it is too large.
+#[cfg(any(target_os = "linux", target_os = "macos", test))]
+fn read_icon_file(path: &Path) -> Option<String> {
+    let mime = match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+        "png" => "image/png",
+        "svg" => "image/svg+xml",
+        _ => return None,
+    };
+    let metadata = std::fs::metadata(path).ok()?;
+    if !metadata.is_file() || metadata.len() > MAX_ICON_BYTES {
+        return None;
+    }
+    std::fs::read(path).ok().map(|bytes| data_url(mime, &bytes))
+}
+
+// === Executable lookup ===
+
+#[cfg(target_os = "linux")]
+fn executable_path(pid: u32) -> Option<PathBuf> {
+    std::fs::read_link(format!("/proc/{pid}/exe")).ok()
+}
+
+#[cfg(target_os = "macos")]
+fn executable_path(pid: u32) -> Option<PathBuf> {
+    use std::os::unix::ffi::OsStringExt;
+
+    // PROC_PIDPATHINFO_MAXSIZE
+    let mut buf = vec![0u8; 4096];
+    // SAFETY: the buffer is as large as the size passed.
+    let len = unsafe {
+        proc_pidpath(
+            i32::try_from(pid).ok()?,
+            buf.as_mut_ptr().cast(),
+            buf.len() as u32,
+        )
+    };
+    if len <= 0 {
+        return None;
+    }
+    buf.truncate(len as usize);
+    Some(PathBuf::from(std::ffi::OsString::from_vec(buf)))
+}
+
+#[cfg(target_os = "macos")]
+unsafe extern "C" {
+    fn proc_pidpath(pid: i32, buffer: *mut std::ffi::c_void, buffer_size: u32) -> i32;
+}
+
+#[cfg(target_os = "windows")]
+fn executable_path(pid: u32) -> Option<PathBuf> {
+    use std::os::windows::ffi::OsStringExt;
+
+    const PROCESS_QUERY_LIMITED_INFORMATION: u32 = 0x1000;
+
+    // SAFETY: the handle is checked and closed; the buffer is as large as
+    // the size passed.
+    unsafe {
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+        if process.is_null() {
+            return None;
+        }
+        let mut buf = vec![0u16; 1024];
+        let mut len = buf.len() as u32;
+        let ok = QueryFullProcessImageNameW(process, 0, buf.as_mut_ptr(), &mut len);
+        CloseHandle(process);
+        if ok == 0 {
+            return None;
+        }
+        buf.truncate(len as usize);
+        Some(PathBuf::from(std::ffi::OsString::from_wide(&buf)))
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "kernel32")]
+unsafe extern "system" {
+    fn OpenProcess(access: u32, inherit: i32, pid: u32) -> *mut std::ffi::c_void;
+    fn QueryFullProcessImageNameW(
+        process: *mut std::ffi::c_void,
+        flags: u32,
+        name: *mut u16,
+        size: *mut u32,
+    ) -> i32;
+    fn CloseHandle(handle: *mut std::ffi::c_void) -> i32;
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn executable_path(_pid: u32) -> Option<PathBuf> {
+    None
+}
+
+// === Linux: desktop entries ===
+
+#[cfg(target_os = "linux")]
+fn resolve(app_id: &str, executable: Option<&Path>) -> Option<String> {
+    let mut names = vec![app_id.to_string()];
+    if let Some(stem) = executable
+        .and_then(Path::file_name)
+        .and_then(|name| name.to_str())
+        .and_then(normalize_app_id)
+    {
+        if !names.contains(&stem) {
+            names.push(stem);
+        }
+    }
+
+    let data_dirs = xdg_data_dirs();
+    let icon = data_dirs.iter().find_map(|dir| {
+        let entries = std::fs::read_dir(dir.join("applications")).ok()?;
+        entries.flatten().find_map(|entry| {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("desktop") {
+                return None;
+            }
+            let stem = path.file_stem()?.to_str()?.to_string();
+            let contents = std::fs::read_to_string(&path).ok()?;
+            desktop_entry_icon(&contents, &stem, &names)
+        })
+    })?;
+    find_icon_file(&data_dirs, &icon)
+}
+
+/// `$XDG_DATA_HOME` followed by `$XDG_DATA_DIRS`, with the spec defaults.
+#[cfg(target_os = "linux")]
+fn xdg_data_dirs() -> Vec<PathBuf> {
+    let mut data_dirs = Vec::new();
+    match std::env::var_os("XDG_DATA_HOME").filter(|value| !value.is_empty()) {
+        Some(home) => data_dirs.push(PathBuf::from(home)),
+        None => data_dirs.extend(dirs::home_dir().map(|home| home.join(".local/share"))),
+    }
+    let system = std::env::var("XDG_DATA_DIRS")
+        .ok()
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| "/usr/local/share:/usr/share".to_string());
+    data_dirs.extend(
+        system
+            .split(':')
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from),
+    );
+    data_dirs
+}
+
+/// `Icon=` of a desktop entry that belongs to one of `names`: by file name
+/// (including the last part of reverse-DNS names like `org.gnome.Nautilus`),
+/// `StartupWMClass`, or the program in `Exec`.
+#[cfg(any(target_os = "linux", test))]
+fn desktop_entry_icon(contents: &str, file_stem: &str, names: &[String]) -> Option<String> {
+    let matches =
+        |candidate: &str| normalize_app_id(candidate).is_some_and(|id| names.contains(&id));
+
+    let mut in_entry = false;
+    let mut icon = None;
+    let mut matched = matches(file_stem) || file_stem.rsplit('.').next().is_some_and(matches);
+    for line in contents.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_entry = line == "[Desktop Entry]";
+            continue;
+        }
+        if !in_entry {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        match key.trim() {
+            "Icon" => icon = Some(value.trim().to_string()),
+            "StartupWMClass" => matched |= matches(value.trim()),
+            "Exec" => {
+                let program = value.split_whitespace().next().unwrap_or_default();
+                let program = program.trim_matches('"');
+                matched |= matches(program.rsplit('/').next().unwrap_or(program));
+            }
+            _ => {}
+        }
+    }
+    icon.filter(|icon| matched && !icon.is_empty())
+}
+
+/// File for icon `name` (or an absolute path) from the hicolor theme or
+/// pixmaps, preferring sizes near [`ICON_SIZE_PX`].
+#[cfg(target_os = "linux")]
+fn find_icon_file(data_dirs: &[PathBuf], name: &str) -> Option<String> {
+    if name.starts_with('/') {
+        return read_icon_file(Path::new(name));
+    }
+    if name.contains('/') {
+        return None;
+    }
+
+    let preferred = format!("{ICON_SIZE_PX}x{ICON_SIZE_PX}");
+    let sizes = [
+        preferred.as_str(),
+        "48x48",
+        "96x96",
+        "128x128",
+        "scalable",
+        "256x256",
+    ];
+    data_dirs.iter().find_map(|dir| {
+        let themed = sizes.iter().flat_map(|size| {
+            ["png", "svg"].map(|ext| {
+                dir.join("icons/hicolor")
+                    .join(size)
+                    .join("apps")
+                    .join(format!("{name}.{ext}"))
+            })
+        });
+        let pixmaps = ["png", "svg"].map(|ext| dir.join("pixmaps").join(format!("{name}.{ext}")));
+        themed.chain(pixmaps).find_map(|path| read_icon_file(&path))
+    })
+}
+
+// === macOS: application bundles ===
+
+#[cfg(target_os = "macos")]
+fn resolve(app_id: &str, executable: Option<&Path>) -> Option<String> {
+    use std::process::Command;
+
+    let bundle = executable
+        .and_then(|path| {
+            path.ancestors()
+                .find(|ancestor| ancestor.extension().and_then(|ext| ext.to_str()) == Some("app"))
+                .map(Path::to_path_buf)
+        })
+        .or_else(|| find_bundle(app_id))?;
+
+    let info = bundle.join("Contents/Info");
+    let icon_name = Command::new("defaults")
+        .arg("read")
+        .arg(&info)
+        .arg("CFBundleIconFile")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|name| !name.is_empty() && !name.contains('/'))
+        .unwrap_or_else(|| "AppIcon".to_string());
+    let mut icns = bundle.join("Contents/Resources").join(&icon_name);
+    if icns.extension().is_none() {
+        icns.set_extension("icns");
+    }
+
+    let png = std::env::temp_dir().join(format!("openvoicy-icon-{app_id}.png"));
+    let converted = Command::new("sips")
+        .args(["-s", "format", "png", "-Z", &ICON_SIZE_PX.to_string()])
+        .arg(&icns)
+        .arg("--out")
+        .arg(&png)
+        .output()
+        .is_ok_and(|output| output.status.success());
+    let icon = if converted {
+        read_icon_file(&png)
+    } else {
+        None
+    };
+    let _ = std::fs::remove_file(&png);
+    icon
+}
+
+/// Bundle in the Applications folders whose name normalizes to `app_id`.
+#[cfg(target_os = "macos")]
+fn find_bundle(app_id: &str) -> Option<PathBuf> {
+    let mut roots = vec![
+        PathBuf::from("/Applications"),
+        PathBuf::from("/Applications/Utilities"),
+        PathBuf::from("/System/Applications"),
+        PathBuf::from("/System/Applications/Utilities"),
+    ];
+    roots.extend(dirs::home_dir().map(|home| home.join("Applications")));
+    roots.iter().find_map(|root| {
+        std::fs::read_dir(root).ok()?.flatten().find_map(|entry| {
+            let path = entry.path();
+            let is_match = path.extension().and_then(|ext| ext.to_str()) == Some("app")
+                && path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .and_then(normalize_app_id)
+                    .is_some_and(|id| id == app_id);
+            is_match.then_some(path)
+        })
+    })
+}
+
+// === Windows: associated icon of the executable ===
+
+#[cfg(target_os = "windows")]
+fn resolve(_app_id: &str, executable: Option<&Path>) -> Option<String> {
+    let path = executable?.to_str()?.replace('\'', "''");
+    let script = format!(
+        "Add-Type -AssemblyName System.Drawing; \
+         $icon = [System.Drawing.Icon]::ExtractAssociatedIcon('{path}'); \
+         $stream = New-Object System.IO.MemoryStream; \
+         $icon.ToBitmap().Save($stream, [System.Drawing.Imaging.ImageFormat]::Png); \
+         [Convert]::ToBase64String($stream.ToArray())"
+    );
+    let encoded = crate::focus::run_powershell(&script)?;
+    let bytes = BASE64_STANDARD.decode(encoded).ok()?;
+    (bytes.len() as u64 <= MAX_ICON_BYTES).then(|| data_url("image/png", &bytes))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn resolve(_app_id: &str, _executable: Option<&Path>) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_desktop_entry_icon_matches_file_name_class_or_exec() {
+        let entry =
+            "[Desktop Entry]\nName=Files\nExec=nautilus --new-window %U\nIcon=org.gnome.Nautilus\n";
+        assert_eq!(
+            desktop_entry_icon(entry, "org.gnome.Nautilus", &names(&["nautilus"])).as_deref(),
+            Some("org.gnome.Nautilus")
+        );
+        assert_eq!(
+            desktop_entry_icon(entry, "files", &names(&["nautilus"])).as_deref(),
+            Some("org.gnome.Nautilus")
+        );
+
+        let code = "[Desktop Entry]\nExec=/usr/share/code/code --unity-launch %F\nIcon=vscode\nStartupWMClass=Code\n\n[Desktop Action new-empty-window]\nIcon=other\n";
+        assert_eq!(
+            desktop_entry_icon(code, "visual-studio-code", &names(&["code"])).as_deref(),
+            Some("vscode")
+        );
+        assert_eq!(
+            desktop_entry_icon(code, "visual-studio-code", &names(&["slack"])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_read_icon_file_accepts_small_png_and_svg_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let svg = dir.path().join("app.svg");
+        std::fs::write(&svg, "<svg/>").unwrap();
+        assert_eq!(
+            read_icon_file(&svg).as_deref(),
+            Some("data:image/svg+xml;base64,PHN2Zy8+")
+        );
+
+        let xpm = dir.path().join("app.xpm");
+        std::fs::write(&xpm, "/* XPM */").unwrap();
+        assert_eq!(read_icon_file(&xpm), None);
+
+        let large = dir.path().join("large.png");
+        std::fs::write(&large, vec![0u8; MAX_ICON_BYTES as usize + 1]).unwrap();
+        assert_eq!(read_icon_file(&large), None);
+    }
+
+    #[test]
+    fn test_icon_for_caches_misses_and_rejects_empty_ids() {
+        assert_eq!(icon_for("   "), None);
+        let app_id = "openvoicy-test-app-without-icon";
+        assert_eq!(icon_for(app_id), None);
+        assert!(ICONS.lock().unwrap().contains_key(app_id));
+    }
+}
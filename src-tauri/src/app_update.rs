@@ -0,0 +1,603 @@
+//! Application self-update with a staged rollout.
+//!
+//! A release manifest lists one installer per target triple and a
+//! `rollout_percentage`. Each install derives a stable bucket from a
+//! persisted random id and the release version, so a release reaches a
+//! consistent, growing share of users as the percentage is raised. Installers
+//! are downloaded and verified ahead of time ("staged") and only launched on
+//! request — never while a recording or transcription is in progress.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::config;
+use crate::network::NetworkPurpose;
+use crate::release_download::{self, DownloadError, ReleaseArtifact};
+use crate::sidecar::SidecarManager;
+use crate::state::AppState;
+
+/// App update progress event name.
+pub const EVENT_APP_UPDATE_PROGRESS: &str = "app:update_progress";
+
+/// Release manifest; debug builds can point at another with
+/// `OPENVOICY_APP_UPDATE_URL`.
+const DEFAULT_MANIFEST_URL: &str =
+    "https://github.com/urgood2/translatorvoiceinputtool/releases/latest/download/app-manifest.json";
+const MANIFEST_URL_ENV: &str = "OPENVOICY_APP_UPDATE_URL";
+
+/// Update state file and download directory inside the config directory.
+const STATE_FILE_NAME: &str = "app_update.json";
+const DOWNLOAD_DIR_NAME: &str = "updates";
+
+const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+static STORE: Lazy<AppUpdateStore> = Lazy::new(|| {
+    let dir = config::config_dir();
+    AppUpdateStore::new(dir.join(STATE_FILE_NAME), dir.join(DOWNLOAD_DIR_NAME))
+});
+
+/// App update errors.
+#[derive(Debug, Error)]
+pub enum AppUpdateError {
+    #[error("Invalid app release manifest: {0}")]
+    InvalidManifest(String),
+
+    #[error("No installer for {0} in the release manifest")]
+    NoArtifact(String),
+
+    #[error("The app is already up to date")]
+    UpToDate,
+
+    #[error("Version {0} has not been rolled out to this installation yet")]
+    NotInRollout(String),
+
+    #[error("No update has been downloaded")]
+    NothingStaged,
+
+    #[error("{0}")]
+    Busy(&'static str),
+
+    #[error("Failed to launch installer: {0}")]
+    Launch(String),
+
+    #[error(transparent)]
+    Download(#[from] DownloadError),
+
+    #[error("App update I/O error: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// Release manifest published alongside app installers.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AppReleaseManifest {
+    pub version: String,
+    #[serde(default)]
+    pub notes: Option<String>,
+    /// Share of installations (0-100) offered this release.
+    #[serde(default = "full_rollout")]
+    pub rollout_percentage: u8,
+    /// Installers keyed by Rust target triple.
+    pub platforms: BTreeMap<String, ReleaseArtifact>,
+}
+
+fn full_rollout() -> u8 {
+    100
+}
+
+/// Result of `check_app_update`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AppUpdateInfo {
+    pub current_version: String,
+    pub latest_version: String,
+    /// Newer and rolled out to this installation.
+    pub update_available: bool,
+    pub in_rollout: bool,
+    pub rollout_percentage: u8,
+    pub notes: Option<String>,
+    /// Version whose installer is downloaded and verified, if any.
+    pub staged_version: Option<String>,
+}
+
+/// Phase reported through `app:update_progress`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppUpdatePhase {
+    Downloading,
+    Verifying,
+    Staged,
+    Installing,
+    Failed,
+}
+
+/// A downloaded and verified installer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StagedUpdate {
+    pub version: String,
+    pub path: PathBuf,
+    /// Minisign signature from the manifest, checked again before launch.
+    #[serde(default)]
+    pub signature: Option<String>,
+}
+
+/// Contents of `app_update.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppUpdateState {
+    /// Random per-installation id used for rollout bucketing.
+    #[serde(default)]
+    pub install_id: String,
+    #[serde(default)]
+    pub staged: Option<StagedUpdate>,
+}
+
+/// What the app must do after an installer was launched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallAction {
+    /// The running binary was replaced in place; restart into it.
+    Restart,
+    /// An external installer is running; quit so it can replace the app.
+    Exit,
+}
+
+/// Rollout state and staged installers.
+pub struct AppUpdateStore {
+    state_path: PathBuf,
+    download_dir: PathBuf,
+}
+
+impl AppUpdateStore {
+    pub fn new(state_path: PathBuf, download_dir: PathBuf) -> Self {
+        Self {
+            state_path,
+            download_dir,
+        }
+    }
+
+    /// Load the state, creating and persisting an install id on first use.
+    pub fn load(&self) -> AppUpdateState {
+        let mut state: AppUpdateState = fs::read_to_string(&self.state_path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+        if state.install_id.is_empty() {
+            state.install_id = Uuid::new_v4().to_string();
+            if let Err(error) = self.save(&state) {
+                log::warn!("Failed to persist app update install id: {}", error);
+            }
+        }
+        state
+    }
+
+    /// Write the state through a temp file so a crash never leaves it torn.
+    fn save(&self, state: &AppUpdateState) -> io::Result<()> {
+        if let Some(parent) = self.state_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temp_path = self.state_path.with_extension("json.tmp");
+        fs::write(&temp_path, serde_json::to_string_pretty(state)?)?;
+        fs::rename(&temp_path, &self.state_path)
+    }
+
+    /// The staged installer, dropping it once that version (or a newer one)
+    /// is running or its file has disappeared.
+    pub fn staged(&self, current_version: &str) -> Option<StagedUpdate> {
+        let mut state = self.load();
+        let staged = state.staged.clone()?;
+        if release_download::is_newer(&staged.version, current_version) && staged.path.is_file() {
+            return Some(staged);
+        }
+        let _ = fs::remove_dir_all(self.download_dir.join(&staged.version));
+        state.staged = None;
+        if let Err(error) = self.save(&state) {
+            log::warn!("Failed to clear stale staged update: {}", error);
+        }
+        None
+    }
+
+    /// Record a verified installer, removing any previously staged one.
+    fn stage(&self, staged: StagedUpdate) -> io::Result<()> {
+        let mut state = self.load();
+        if let Some(previous) = state.staged.take() {
+            if previous.version != staged.version {
+                let _ = fs::remove_dir_all(self.download_dir.join(&previous.version));
+            }
+        }
+        state.staged = Some(staged);
+        self.save(&state)
+    }
+
+    /// Rollout bucket (0-99) of this installation for a release.
+    pub fn rollout_bucket(&self, version: &str) -> u8 {
+        rollout_bucket(&self.load().install_id, version)
+    }
+}
+
+/// Process-wide update store under the config directory.
+pub fn store() -> &'static AppUpdateStore {
+    &STORE
+}
+
+/// Version of the running app.
+pub fn current_version() -> &'static str {
+    CURRENT_VERSION
+}
+
+/// Stable bucket (0-99) for an install id and release version.
+///
+/// Hashing the version in as well means each release samples a different
+/// subset of installations first.
+pub fn rollout_bucket(install_id: &str, version: &str) -> u8 {
+    let digest = Sha256::digest(format!("{install_id}:{version}").as_bytes());
+    (u16::from_be_bytes([digest[0], digest[1]]) % 100) as u8
+}
+
+/// Whether a bucket falls inside the rollout percentage.
+pub fn in_rollout(bucket: u8, rollout_percentage: u8) -> bool {
+    bucket < rollout_percentage.min(100)
+}
+
+/// Why an update cannot be installed in this app state, if it cannot.
+pub fn install_blocked_reason(state: AppState) -> Option<&'static str> {
+    match state {
+        AppState::Recording => Some("Cannot install an update while recording"),
+        AppState::Transcribing => Some("Cannot install an update while transcribing"),
+        _ => None,
+    }
+}
+
+/// URL of the release manifest.
+///
+/// Release builds ignore `OPENVOICY_APP_UPDATE_URL` so the environment cannot
+/// redirect them to another feed.
+pub fn manifest_url() -> String {
+    env::var(MANIFEST_URL_ENV)
+        .ok()
+        .filter(|url| cfg!(debug_assertions) && !url.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_MANIFEST_URL.to_string())
+}
+
+/// Fetch and parse the release manifest.
+pub fn fetch_manifest() -> Result<AppReleaseManifest, AppUpdateError> {
+    parse_manifest(&release_download::fetch_bytes(
+        &manifest_url(),
+        "app release manifest",
+        NetworkPurpose::AppUpdate,
+    )?)
+}
+
+fn parse_manifest(bytes: &[u8]) -> Result<AppReleaseManifest, AppUpdateError> {
+    let manifest: AppReleaseManifest = serde_json::from_slice(bytes)
+        .map_err(|e| AppUpdateError::InvalidManifest(e.to_string()))?;
+    if release_download::parse_version(&manifest.version).is_none() {
+        return Err(AppUpdateError::InvalidManifest(format!(
+            "unparseable version '{}'",
+            manifest.version
+        )));
+    }
+    if manifest.rollout_percentage > 100 {
+        return Err(AppUpdateError::InvalidManifest(format!(
+            "rollout_percentage {} exceeds 100",
+            manifest.rollout_percentage
+        )));
+    }
+    Ok(manifest)
+}
+
+/// Compare the manifest against the running app and this installation's bucket.
+pub fn check_manifest(
+    manifest: &AppReleaseManifest,
+    current_version: &str,
+    bucket: u8,
+    staged_version: Option<String>,
+) -> AppUpdateInfo {
+    let eligible = in_rollout(bucket, manifest.rollout_percentage);
+    AppUpdateInfo {
+        current_version: current_version.to_string(),
+        latest_version: manifest.version.clone(),
+        update_available: eligible
+            && release_download::is_newer(&manifest.version, current_version),
+        in_rollout: eligible,
+        rollout_percentage: manifest.rollout_percentage,
+        notes: manifest.notes.clone(),
+        staged_version,
+    }
+}
+
+/// Payload for an `app:update_progress` event.
+pub fn progress_payload(
+    phase: AppUpdatePhase,
+    version: &str,
+    downloaded_bytes: Option<u64>,
+    total_bytes: Option<u64>,
+    message: Option<&str>,
+) -> Value {
+    let mut payload = json!({
+        "phase": phase,
+        "version": version,
+    });
+    if let Some(downloaded_bytes) = downloaded_bytes {
+        payload["downloaded_bytes"] = json!(downloaded_bytes);
+    }
+    if let Some(total_bytes) = total_bytes {
+        payload["total_bytes"] = json!(total_bytes);
+    }
+    if let Some(message) = message {
+        payload["message"] = json!(message);
+    }
+    payload
+}
+
+/// Installer file name taken from the artifact URL.
+fn installer_file_name(url: &str) -> String {
+    url.split(['?', '#'])
+        .next()
+        .and_then(|path| path.rsplit('/').next())
+        .filter(|name| !name.is_empty() && *name != "." && *name != "..")
+        .unwrap_or("openvoicy-installer")
+        .to_string()
+}
+
+/// Download and verify the manifest's installer for this platform.
+///
+/// `on_progress` receives the phase and, while downloading, the bytes
+/// downloaded so far and the expected total. An installer already staged for
+/// the same version is reused.
+pub fn download(
+    manifest: &AppReleaseManifest,
+    on_progress: impl Fn(AppUpdatePhase, Option<u64>, Option<u64>),
+) -> Result<StagedUpdate, AppUpdateError> {
+    let store = store();
+    if !release_download::is_newer(&manifest.version, CURRENT_VERSION) {
+        return Err(AppUpdateError::UpToDate);
+    }
+    if !in_rollout(
+        store.rollout_bucket(&manifest.version),
+        manifest.rollout_percentage,
+    ) {
+        return Err(AppUpdateError::NotInRollout(manifest.version.clone()));
+    }
+    if let Some(staged) = store.staged(CURRENT_VERSION) {
+        if staged.version == manifest.version {
+            return Ok(staged);
+        }
+    }
+
+    let target = SidecarManager::get_target_triple();
+    let artifact = manifest
+        .platforms
+        .get(&target)
+        .ok_or_else(|| AppUpdateError::NoArtifact(target.clone()))?;
+    let path = store
+        .download_dir
+        .join(&manifest.version)
+        .join(installer_file_name(&artifact.url));
+    let partial = path.with_extension("partial");
+
+    on_progress(AppUpdatePhase::Downloading, Some(0), artifact.size);
+    release_download::download_artifact(
+        artifact,
+        &partial,
+        "app installer",
+        NetworkPurpose::AppUpdate,
+        |bytes| on_progress(AppUpdatePhase::Downloading, Some(bytes), artifact.size),
+    )?;
+
+    on_progress(AppUpdatePhase::Verifying, None, None);
+    let signature = artifact.signature.as_deref();
+    let verified = release_download::verify_artifact(&partial, artifact, "app installer")
+        .and_then(|()| release_download::verify_signature(&partial, signature, "app installer"));
+    if let Err(error) = verified {
+        let _ = fs::remove_file(&partial);
+        return Err(error.into());
+    }
+    fs::rename(&partial, &path)?;
+
+    let staged = StagedUpdate {
+        version: manifest.version.clone(),
+        path,
+        signature: artifact.signature.clone(),
+    };
+    store.stage(staged.clone())?;
+    log::info!("Staged app update {} at {:?}", staged.version, staged.path);
+    Ok(staged)
+}
+
+/// Launch the staged installer.
+///
+/// The signature is checked again first, since the installer may have sat in
+/// the config directory since it was downloaded.
+pub fn install(staged: &StagedUpdate) -> Result<InstallAction, AppUpdateError> {
+    release_download::verify_signature(&staged.path, staged.signature.as_deref(), "app installer")?;
+    log::info!(
+        "Installing app update {} from {:?}",
+        staged.version,
+        staged.path
+    );
+    launch_installer(&staged.path)
+}
+
+#[cfg(target_os = "macos")]
+fn launch_installer(path: &Path) -> Result<InstallAction, AppUpdateError> {
+    // Opens the disk image or package installer UI.
+    spawn(Command::new("open").arg(path))?;
+    Ok(InstallAction::Exit)
+}
+
+#[cfg(target_os = "windows")]
+fn launch_installer(path: &Path) -> Result<InstallAction, AppUpdateError> {
+    let is_msi = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("msi"));
+    if is_msi {
+        spawn(Command::new("msiexec").arg("/i").arg(path))?;
+    } else {
+        spawn(&mut Command::new(path))?;
+    }
+    Ok(InstallAction::Exit)
+}
+
+#[cfg(target_os = "linux")]
+fn launch_installer(path: &Path) -> Result<InstallAction, AppUpdateError> {
+    // AppImages replace themselves in place; other formats go to the
+    // desktop's package installer.
+    if let Some(appimage) = env::var_os("APPIMAGE").map(PathBuf::from) {
+        let is_appimage = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("appimage"));
+        if is_appimage {
+            replace_appimage(path, &appimage)?;
+            return Ok(InstallAction::Restart);
+        }
+    }
+    spawn(Command::new("xdg-open").arg(path))?;
+    Ok(InstallAction::Exit)
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+fn launch_installer(_path: &Path) -> Result<InstallAction, AppUpdateError> {
+    Err(AppUpdateError::Launch(
+        "self-update is not supported on this platform".to_string(),
+    ))
+}
+
+#[cfg(any(target_os = "macos", target_os = "windows", target_os = "linux"))]
+fn spawn(command: &mut Command) -> Result<(), AppUpdateError> {
+    command
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| AppUpdateError::Launch(e.to_string()))
+}
+
+/// Copy the new AppImage next to the running one and rename it over it.
+#[cfg(target_os = "linux")]
+fn replace_appimage(new_image: &Path, current: &Path) -> Result<(), AppUpdateError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_path = current.with_extension("AppImage.new");
+    fs::copy(new_image, &temp_path)?;
+    fs::set_permissions(&temp_path, fs::Permissions::from_mode(0o755))?;
+    fs::rename(&temp_path, current)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn store_in(dir: &Path) -> AppUpdateStore {
+        AppUpdateStore::new(dir.join(STATE_FILE_NAME), dir.join(DOWNLOAD_DIR_NAME))
+    }
+
+    #[test]
+    fn test_rollout_bucket_is_stable_and_in_range() {
+        let bucket = rollout_bucket("install-a", "0.2.0");
+        assert!(bucket < 100);
+        assert_eq!(bucket, rollout_bucket("install-a", "0.2.0"));
+
+        let buckets: Vec<u8> = (0..200)
+            .map(|i| rollout_bucket(&format!("install-{i}"), "0.2.0"))
+            .collect();
+        assert!(buckets.iter().any(|&bucket| bucket < 50));
+        assert!(buckets.iter().any(|&bucket| bucket >= 50));
+    }
+
+    #[test]
+    fn test_rollout_percentage_bounds() {
+        assert!(!in_rollout(0, 0));
+        assert!(in_rollout(0, 1));
+        assert!(!in_rollout(10, 10));
+        assert!(in_rollout(99, 100));
+    }
+
+    #[test]
+    fn test_install_blocked_during_active_session() {
+        assert!(install_blocked_reason(AppState::Recording).is_some());
+        assert!(install_blocked_reason(AppState::Transcribing).is_some());
+        assert_eq!(install_blocked_reason(AppState::Idle), None);
+        assert_eq!(install_blocked_reason(AppState::Error), None);
+    }
+
+    #[test]
+    fn test_manifest_defaults_to_full_rollout() {
+        let manifest = parse_manifest(
+            br#"{"version": "0.2.0", "platforms": {
+                "x86_64-pc-windows-msvc": {"url": "https://example.com/a.msi", "sha256": "ab"}
+            }}"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.rollout_percentage, 100);
+
+        let info = check_manifest(&manifest, "0.1.0", 99, None);
+        assert!(info.update_available);
+        assert!(info.in_rollout);
+
+        assert!(matches!(
+            parse_manifest(br#"{"version": "0.2.0", "rollout_percentage": 101, "platforms": {}}"#),
+            Err(AppUpdateError::InvalidManifest(_))
+        ));
+    }
+
+    #[test]
+    fn test_update_outside_rollout_is_not_available() {
+        let manifest =
+            parse_manifest(br#"{"version": "0.2.0", "rollout_percentage": 25, "platforms": {}}"#)
+                .unwrap();
+        let info = check_manifest(&manifest, "0.1.0", 40, None);
+        assert!(!info.in_rollout);
+        assert!(!info.update_available);
+        assert!(check_manifest(&manifest, "0.1.0", 24, None).update_available);
+    }
+
+    #[test]
+    fn test_store_persists_install_id_and_drops_stale_staged_update() {
+        let dir = tempdir().unwrap();
+        let store = store_in(dir.path());
+        let install_id = store.load().install_id;
+        assert!(!install_id.is_empty());
+        assert_eq!(store.load().install_id, install_id);
+
+        let path = dir
+            .path()
+            .join(DOWNLOAD_DIR_NAME)
+            .join("0.2.0")
+            .join("app.msi");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, b"installer").unwrap();
+        store
+            .stage(StagedUpdate {
+                version: "0.2.0".to_string(),
+                path: path.clone(),
+                signature: None,
+            })
+            .unwrap();
+        assert!(store.staged("0.1.0").is_some());
+
+        // Once 0.2.0 is running the staged installer is obsolete.
+        assert_eq!(store.staged("0.2.0"), None);
+        assert!(!path.exists());
+        assert_eq!(store.load().staged, None);
+    }
+
+    #[test]
+    fn test_installer_file_name_from_url() {
+        assert_eq!(
+            installer_file_name("https://example.com/dl/OpenVoicy_0.2.0.msi?sig=1"),
+            "OpenVoicy_0.2.0.msi"
+        );
+        assert_eq!(
+            installer_file_name("https://example.com/"),
+            "openvoicy-installer"
+        );
+    }
+}
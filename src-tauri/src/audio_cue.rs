@@ -27,6 +27,10 @@ pub enum CueType {
     StopRecording,
     CancelRecording,
     Error,
+    /// One second of the countdown before capture starts.
+    CountdownTick,
+    /// Dictation re-enabled after idle auto-disable; nothing is recorded.
+    Reenabled,
 }
 
 #[derive(Debug, Clone)]
@@ -173,6 +177,8 @@ fn cue_candidates(cue: CueType) -> &'static [&'static str] {
         CueType::StopRecording => &["stop.wav", "cue-stop.wav"],
         CueType::CancelRecording => &["cancel.wav", "cue-cancel.wav"],
         CueType::Error => &["error.wav", "cue-error.wav"],
+        CueType::CountdownTick => &["tick.wav", "cue-tick.wav"],
+        CueType::Reenabled => &["reenable.wav", "cue-reenable.wav"],
     }
 }
 
@@ -183,6 +189,8 @@ fn load_all_cues(sounds_dir: &Path) -> HashMap<CueType, CueBuffer> {
         CueType::StopRecording,
         CueType::CancelRecording,
         CueType::Error,
+        CueType::CountdownTick,
+        CueType::Reenabled,
     ] {
         match load_cue(sounds_dir, cue) {
             Ok(Some(buffer)) => {
@@ -274,7 +282,9 @@ mod tests {
         assert!(manager.has_cue(CueType::StopRecording));
         assert!(manager.has_cue(CueType::Error));
         assert!(manager.has_cue(CueType::CancelRecording));
-        assert_eq!(manager.loaded_cue_count(), 4);
+        assert!(manager.has_cue(CueType::CountdownTick));
+        assert!(manager.has_cue(CueType::Reenabled));
+        assert_eq!(manager.loaded_cue_count(), 6);
     }
 
     #[test]
@@ -288,7 +298,14 @@ mod tests {
     #[test]
     fn supports_primary_filenames_without_cue_prefix() {
         let temp = TempDir::new().expect("tempdir");
-        for name in ["start.wav", "stop.wav", "cancel.wav", "error.wav"] {
+        for name in [
+            "start.wav",
+            "stop.wav",
+            "cancel.wav",
+            "error.wav",
+            "tick.wav",
+            "reenable.wav",
+        ] {
             write_test_wav(&temp.path().join(name));
         }
 
@@ -297,6 +314,8 @@ mod tests {
         assert!(manager.has_cue(CueType::StopRecording));
         assert!(manager.has_cue(CueType::CancelRecording));
         assert!(manager.has_cue(CueType::Error));
+        assert!(manager.has_cue(CueType::CountdownTick));
+        assert!(manager.has_cue(CueType::Reenabled));
     }
 
     #[test]
@@ -337,20 +356,38 @@ mod tests {
     #[test]
     fn project_cue_wav_files_are_valid_pcm16_mono_44100() {
         let sounds = default_sounds_dir();
-        for filename in ["cue-start.wav", "cue-stop.wav", "cue-cancel.wav", "cue-error.wav"] {
+        for filename in [
+            "cue-start.wav",
+            "cue-stop.wav",
+            "cue-cancel.wav",
+            "cue-error.wav",
+            "cue-tick.wav",
+            "cue-reenable.wav",
+        ] {
             let path = sounds.join(filename);
             assert!(path.exists(), "{} must exist", filename);
             let file = File::open(&path).expect("open cue file");
             let decoder = Decoder::new(BufReader::new(file)).expect("decode cue file");
             assert_eq!(decoder.channels(), 1, "{filename} should be mono");
-            assert_eq!(decoder.sample_rate(), 44100, "{filename} should be 44100 Hz");
+            assert_eq!(
+                decoder.sample_rate(),
+                44100,
+                "{filename} should be 44100 Hz"
+            );
         }
     }
 
     #[test]
     fn project_cue_wav_files_are_under_100kb() {
         let sounds = default_sounds_dir();
-        for filename in ["cue-start.wav", "cue-stop.wav", "cue-cancel.wav", "cue-error.wav"] {
+        for filename in [
+            "cue-start.wav",
+            "cue-stop.wav",
+            "cue-cancel.wav",
+            "cue-error.wav",
+            "cue-tick.wav",
+            "cue-reenable.wav",
+        ] {
             let path = sounds.join(filename);
             let size = std::fs::metadata(&path)
                 .unwrap_or_else(|_| panic!("{filename} metadata"))
@@ -369,6 +406,8 @@ mod tests {
             CueType::StopRecording,
             CueType::CancelRecording,
             CueType::Error,
+            CueType::CountdownTick,
+            CueType::Reenabled,
         ] {
             let candidates = cue_candidates(cue);
             assert!(
@@ -0,0 +1,294 @@
+//! Detection of active calls, to keep audio cues out of meetings.
+//!
+//! Start and stop beeps are picked up by the microphone of a conferencing app
+//! that is listening at the same time, so everyone on the call hears them.
+//! Cues are muted while `audio.in_meeting` is set by hand, or, with
+//! `audio.mute_cues_in_calls`, while a known conferencing app is capturing
+//! the microphone. With `audio.mute_cues_in_dnd` they are also muted while
+//! the OS is in Do-Not-Disturb (see [`crate::dnd`]). The overlay and tray
+//! indicators are unaffected.
+//!
+//! Linux asks PipeWire for running capture streams and reads the app that
+//! owns them. Windows reads the microphone consent store, which marks apps
+//! using the microphone right now. macOS offers no per-app microphone query
+//! without extra entitlements, so only the manual toggle applies there.
+//! Calls in a browser tab are not detected on any platform.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+use crate::config::AudioConfig;
+use crate::dnd;
+
+/// How long a detection result is reused; detection spawns a process.
+const DETECTION_TTL: Duration = Duration::from_secs(5);
+
+/// Executable or application names of conferencing apps, normalized to
+/// lowercase without `.exe`, with the app they belong to.
+#[cfg_attr(target_os = "macos", allow(dead_code))]
+const CALL_APPS: [(&str, &str); 12] = [
+    ("zoom", "zoom"),
+    ("zoom.us", "zoom"),
+    ("teams", "teams"),
+    ("ms-teams", "teams"),
+    ("msteams", "teams"),
+    ("teams-for-linux", "teams"),
+    ("slack", "slack"),
+    ("discord", "discord"),
+    ("skype", "skype"),
+    ("skypeforlinux", "skype"),
+    ("webex", "webex"),
+    ("ciscocollabhost", "webex"),
+];
+
+/// When the microphone was last checked, and the call app found then.
+type Detection = (Instant, Option<&'static str>);
+
+static CACHED_CALL: Lazy<Mutex<Option<Detection>>> = Lazy::new(|| Mutex::new(None));
+
+/// Why audio cues are muted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CueSuppressionReason {
+    /// The user marked themselves as in a meeting.
+    Manual,
+    /// A conferencing app is using the microphone.
+    Call,
+    /// The OS is in Do-Not-Disturb / Focus mode.
+    DoNotDisturb,
+}
+
+/// Whether audio cues play right now.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CueStatus {
+    pub suppressed: bool,
+    pub reason: Option<CueSuppressionReason>,
+    /// Conferencing app holding the microphone, for `Call`.
+    pub app: Option<String>,
+}
+
+impl CueStatus {
+    fn suppressed(reason: CueSuppressionReason, app: Option<&str>) -> Self {
+        Self {
+            suppressed: true,
+            reason: Some(reason),
+            app: app.map(str::to_string),
+        }
+    }
+}
+
+/// Cue status under `audio`, detecting calls and Do-Not-Disturb only when
+/// that can matter.
+pub fn cue_status(audio: &AudioConfig) -> CueStatus {
+    if audio.in_meeting {
+        return CueStatus::suppressed(CueSuppressionReason::Manual, None);
+    }
+    if !audio.audio_cues_enabled {
+        return CueStatus::default();
+    }
+    if audio.mute_cues_in_dnd && dnd::current().active {
+        return CueStatus::suppressed(CueSuppressionReason::DoNotDisturb, None);
+    }
+    if !audio.mute_cues_in_calls {
+        return CueStatus::default();
+    }
+    match current_call() {
+        Some(app) => CueStatus::suppressed(CueSuppressionReason::Call, Some(app)),
+        None => CueStatus::default(),
+    }
+}
+
+/// Conferencing app using the microphone, re-detected at most every few
+/// seconds.
+fn current_call() -> Option<&'static str> {
+    let mut cached = CACHED_CALL.lock().unwrap_or_else(|e| e.into_inner());
+    let now = Instant::now();
+    if let Some((detected_at, app)) = cached.as_ref() {
+        if now.saturating_duration_since(*detected_at) < DETECTION_TTL {
+            return *app;
+        }
+    }
+
+    let app = detect();
+    if cached.as_ref().map(|(_, previous)| *previous) != Some(app) {
+        log::info!("Call using the microphone: {:?}", app);
+    }
+    *cached = Some((now, app));
+    app
+}
+
+/// Conferencing app a process or application name belongs to.
+#[cfg_attr(target_os = "macos", allow(dead_code))]
+fn call_app(name: &str) -> Option<&'static str> {
+    let name = name.rsplit(['/', '\\', '#']).next().unwrap_or(name).trim();
+    let name = name.to_ascii_lowercase();
+    let name = name.strip_suffix(".exe").unwrap_or(&name);
+    // Packaged Windows apps carry a publisher suffix ("MSTeams_8wekyb3d8bbwe").
+    let name = name.split('_').next().unwrap_or(name);
+    CALL_APPS
+        .iter()
+        .find(|(process, _)| *process == name)
+        .map(|(_, app)| *app)
+}
+
+#[cfg(target_os = "linux")]
+fn detect() -> Option<&'static str> {
+    match std::process::Command::new("pw-dump").output() {
+        Ok(output) if output.status.success() => {
+            capturing_call_app(&String::from_utf8_lossy(&output.stdout))
+        }
+        _ => None,
+    }
+}
+
+/// Conferencing app owning a running capture stream in `pw-dump` output.
+#[cfg(any(target_os = "linux", test))]
+fn capturing_call_app(pw_dump: &str) -> Option<&'static str> {
+    let Ok(serde_json::Value::Array(objects)) = serde_json::from_str(pw_dump) else {
+        return None;
+    };
+    objects.iter().find_map(|object| {
+        let info = &object["info"];
+        let props = &info["props"];
+        let capturing = object["type"] == "PipeWire:Interface:Node"
+            && info["state"] == "running"
+            && props["media.class"] == "Stream/Input/Audio";
+        if !capturing {
+            return None;
+        }
+        ["application.process.binary", "application.name"]
+            .iter()
+            .filter_map(|key| props[*key].as_str())
+            .find_map(call_app)
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn detect() -> Option<&'static str> {
+    let Ok(output) = std::process::Command::new("reg")
+        .args([
+            "query",
+            r"HKCU\Software\Microsoft\Windows\CurrentVersion\CapabilityAccessManager\ConsentStore\microphone",
+            "/s",
+        ])
+        .output()
+    else {
+        return None;
+    };
+    microphone_call_app(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Conferencing app using the microphone in `reg query /s` output of the
+/// microphone consent store. An app is using it while its key has a
+/// `LastUsedTimeStop` of zero.
+#[cfg(any(target_os = "windows", test))]
+fn microphone_call_app(reg_output: &str) -> Option<&'static str> {
+    let mut key = "";
+    for line in reg_output.lines() {
+        let line = line.trim();
+        if line.starts_with("HKEY_") {
+            key = line;
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        if fields.next() == Some("LastUsedTimeStop") && fields.nth(1) == Some("0x0") {
+            if let Some(app) = call_app(key) {
+                return Some(app);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(target_os = "macos")]
+fn detect() -> Option<&'static str> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_meeting_suppresses_even_with_detection_off() {
+        let audio = AudioConfig {
+            in_meeting: true,
+            mute_cues_in_calls: false,
+            mute_cues_in_dnd: false,
+            ..AudioConfig::default()
+        };
+        assert_eq!(
+            cue_status(&audio),
+            CueStatus::suppressed(CueSuppressionReason::Manual, None)
+        );
+
+        let audio = AudioConfig {
+            mute_cues_in_calls: false,
+            mute_cues_in_dnd: false,
+            ..AudioConfig::default()
+        };
+        assert_eq!(cue_status(&audio), CueStatus::default());
+    }
+
+    #[test]
+    fn test_call_app_normalizes_paths_and_package_names() {
+        assert_eq!(call_app("Zoom.exe"), Some("zoom"));
+        assert_eq!(call_app("C:#Program Files#Zoom#bin#Zoom.exe"), Some("zoom"));
+        assert_eq!(call_app("MSTeams_8wekyb3d8bbwe"), Some("teams"));
+        assert_eq!(call_app("/usr/bin/teams-for-linux"), Some("teams"));
+        assert_eq!(call_app("firefox"), None);
+    }
+
+    #[test]
+    fn test_capturing_call_app_needs_a_running_capture_stream() {
+        let node = |class: &str, state: &str, binary: &str| {
+            serde_json::json!({
+                "type": "PipeWire:Interface:Node",
+                "info": {
+                    "state": state,
+                    "props": {
+                        "media.class": class,
+                        "application.process.binary": binary,
+                    },
+                },
+            })
+        };
+        let dump = |nodes: Vec<serde_json::Value>| serde_json::Value::Array(nodes).to_string();
+
+        assert_eq!(
+            capturing_call_app(&dump(vec![node("Stream/Input/Audio", "running", "zoom")])),
+            Some("zoom")
+        );
+        assert_eq!(
+            capturing_call_app(&dump(vec![
+                node("Stream/Output/Audio", "running", "zoom"),
+                node("Stream/Input/Audio", "suspended", "slack"),
+                node("Stream/Input/Audio", "running", "audacity"),
+            ])),
+            None
+        );
+        assert_eq!(capturing_call_app("not json"), None);
+    }
+
+    #[test]
+    fn test_microphone_call_app_reads_apps_in_use_from_consent_store() {
+        let output = r"
+HKEY_CURRENT_USER\Software\Microsoft\Windows\CurrentVersion\CapabilityAccessManager\ConsentStore\microphone\NonPackaged\C:#Program Files#Zoom#bin#Zoom.exe
+    LastUsedTimeStart    REG_QWORD    0x1da1c2b3c4d5e6f
+    LastUsedTimeStop    REG_QWORD    0x1da1c2b3c4d5f70
+
+HKEY_CURRENT_USER\Software\Microsoft\Windows\CurrentVersion\CapabilityAccessManager\ConsentStore\microphone\MSTeams_8wekyb3d8bbwe
+    Value    REG_SZ    Allow
+    LastUsedTimeStart    REG_QWORD    0x1da1c2b3c4d5e6f
+    LastUsedTimeStop    REG_QWORD    0x0
+";
+        assert_eq!(microphone_call_app(output), Some("teams"));
+        assert_eq!(
+            microphone_call_app(&output.replace("    0x0\n", "    0x1\n")),
+            None
+        );
+    }
+}
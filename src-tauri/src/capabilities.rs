@@ -15,11 +15,21 @@
 // Platform-conditional code paths mean some variants appear unused on any given platform
 #![allow(dead_code)]
 
+use once_cell::sync::Lazy;
 use serde::Serialize;
 use std::env;
+use std::sync::Mutex;
+
+use crate::display_strategy::{self, PasteStrategy, PlatformStrategies};
+use crate::dnd::{self, DndInfo};
+use crate::errors::SettingsPage;
+use crate::network::{self, NetworkStatus};
+use crate::screen_share::{self, ScreenShareInfo};
+use crate::session::{self, SessionInfo, SessionKind};
 
 /// Activation mode for voice recording.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "snake_case")]
 pub enum ActivationMode {
     /// Hold hotkey to record, release to transcribe.
@@ -30,16 +40,31 @@ pub enum ActivationMode {
 
 /// Method for injecting transcribed text.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "snake_case")]
 pub enum InjectionMethod {
     /// Inject keystrokes directly + clipboard fallback.
     ClipboardPaste,
     /// Copy to clipboard only (user pastes manually).
     ClipboardOnly,
+    /// Copy to clipboard and ask before pasting each transcript.
+    Ask,
+}
+
+impl InjectionMethod {
+    /// Map the `injection.mode` config value to the configured method.
+    pub fn from_config_mode(mode: &str) -> Self {
+        match mode {
+            "clipboard_only" => Self::ClipboardOnly,
+            "ask" => Self::Ask,
+            _ => Self::ClipboardPaste,
+        }
+    }
 }
 
 /// Permission state for a capability.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "snake_case")]
 pub enum PermissionState {
     /// Permission has been granted.
@@ -54,6 +79,7 @@ pub enum PermissionState {
 
 /// Display server / window system.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum DisplayServer {
     Windows,
@@ -62,6 +88,7 @@ pub enum DisplayServer {
     X11,
     Wayland {
         #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(test, ts(optional))]
         compositor: Option<String>,
     },
     Unknown,
@@ -69,7 +96,8 @@ pub enum DisplayServer {
 
 /// Effective mode with configured value, actual value, and reason.
 #[derive(Debug, Clone, Serialize)]
-pub struct EffectiveMode<T: Serialize + Clone> {
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct EffectiveMode<T> {
     /// What the user configured (or the default).
     pub configured: T,
     /// What will actually be used after platform constraints.
@@ -79,16 +107,20 @@ pub struct EffectiveMode<T: Serialize + Clone> {
 }
 
 /// Permission status for various capabilities.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 pub struct PermissionStatus {
     /// Microphone permission state.
     pub microphone: PermissionState,
     /// Accessibility permission state (macOS).
     pub accessibility: PermissionState,
+    /// Input Monitoring permission state (macOS).
+    pub input_monitoring: PermissionState,
 }
 
 /// Platform capabilities detection result.
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 pub struct Capabilities {
     /// Detected display server.
     pub display_server: DisplayServer,
@@ -100,12 +132,26 @@ pub struct Capabilities {
     pub keystroke_injection_available: bool,
     /// Whether clipboard access is available.
     pub clipboard_available: bool,
+    /// Whether transcripts can be put on the PRIMARY selection (Linux).
+    pub primary_selection_available: bool,
     /// Effective hotkey mode after platform constraints.
     pub hotkey_mode: EffectiveMode<ActivationMode>,
     /// Effective injection method after platform constraints.
     pub injection_method: EffectiveMode<InjectionMethod>,
     /// Permission status.
     pub permissions: PermissionStatus,
+    /// Focus and paste strategies selected for this session (Linux only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub strategies: Option<PlatformStrategies>,
+    /// Login session the app runs in (console, remote, or inactive).
+    pub session: SessionInfo,
+    /// Whether the screen is being shared right now.
+    pub screen_share: ScreenShareInfo,
+    /// Whether the OS is in Do-Not-Disturb / Focus mode right now.
+    pub dnd: DndInfo,
+    /// Offline mode and the network requests it refused.
+    pub network: NetworkStatus,
     /// Human-readable diagnostics text.
     pub diagnostics: String,
 }
@@ -126,6 +172,10 @@ impl Capabilities {
         configured_injection: InjectionMethod,
     ) -> Self {
         let display_server = detect_display_server();
+        let strategies = display_strategy::current();
+        let paste_keystroke = strategies
+            .as_ref()
+            .is_some_and(|strategies| strategies.paste != PasteStrategy::ClipboardOnly);
 
         // Determine capabilities based on platform
         let (hotkey_press, hotkey_release, keystroke_injection) = match &display_server {
@@ -136,11 +186,12 @@ impl Capabilities {
                 let has_accessibility = accessibility == PermissionState::Granted;
                 (has_accessibility, has_accessibility, has_accessibility)
             }
-            DisplayServer::X11 => (true, true, check_xdotool_available()),
+            DisplayServer::X11 => (true, true, paste_keystroke),
             DisplayServer::Wayland { .. } => {
-                // Wayland: press via portal, release is unreliable, no keystroke injection
+                // Wayland: press via portal, release is unreliable, keystrokes
+                // only through wtype or the RemoteDesktop portal
                 let portal_available = check_wayland_portal();
-                (portal_available, false, false)
+                (portal_available, false, paste_keystroke)
             }
             DisplayServer::Unknown => (false, false, false),
         };
@@ -156,6 +207,12 @@ impl Capabilities {
         let permissions = detect_permissions(&display_server);
 
         let clipboard_available = check_clipboard_available(&display_server);
+        let primary_selection_available = check_primary_selection_available(&display_server);
+
+        let session = session::current();
+        let screen_share = screen_share::current();
+        let dnd = dnd::current();
+        let network = network::status();
 
         // Generate diagnostics
         let diagnostics = generate_diagnostics(
@@ -164,7 +221,10 @@ impl Capabilities {
             hotkey_release,
             keystroke_injection,
             clipboard_available,
+            primary_selection_available,
             &permissions,
+            strategies.as_ref(),
+            &dnd,
         );
 
         Self {
@@ -173,9 +233,15 @@ impl Capabilities {
             hotkey_release_available: hotkey_release,
             keystroke_injection_available: keystroke_injection,
             clipboard_available,
+            primary_selection_available,
             hotkey_mode,
             injection_method,
             permissions,
+            strategies,
+            session,
+            screen_share,
+            dnd,
+            network,
             diagnostics,
         }
     }
@@ -194,6 +260,7 @@ impl Capabilities {
                 title: "Accessibility Permission Required".to_string(),
                 description: "Voice Input Tool needs accessibility permission to detect global hotkeys and type transcribed text.".to_string(),
                 remediation: Some("Open System Preferences → Security & Privacy → Privacy → Accessibility and enable Voice Input Tool.".to_string()),
+                deep_link: deep_link_for(SettingsPage::MacOSAccessibility),
             });
         }
 
@@ -205,6 +272,19 @@ impl Capabilities {
                 title: "Microphone Permission Denied".to_string(),
                 description: "Voice Input Tool cannot access the microphone.".to_string(),
                 remediation: Some("Grant microphone permission in system settings.".to_string()),
+                deep_link: deep_link_for(SettingsPage::MicrophonePermission),
+            });
+        }
+
+        // Input Monitoring is only needed for keyboard activity (typing suppression)
+        if self.permissions.input_monitoring == PermissionState::Denied {
+            issues.push(CapabilityIssue {
+                severity: IssueSeverity::Warning,
+                category: "permissions".to_string(),
+                title: "Input Monitoring Permission Denied".to_string(),
+                description: "Voice Input Tool cannot see keyboard activity, so hotkey suppression while typing is unavailable.".to_string(),
+                remediation: Some("Open System Settings → Privacy & Security → Input Monitoring and enable Voice Input Tool.".to_string()),
+                deep_link: deep_link_for(SettingsPage::MacOSInputMonitoring),
             });
         }
 
@@ -219,6 +299,7 @@ impl Capabilities {
                 remediation: Some(
                     "Using toggle mode and clipboard injection for best compatibility.".to_string(),
                 ),
+                deep_link: None,
             });
         }
 
@@ -233,6 +314,7 @@ impl Capabilities {
                 remediation: Some(
                     "Install xdotool: sudo apt install xdotool (Debian/Ubuntu)".to_string(),
                 ),
+                deep_link: None,
             });
         }
 
@@ -263,6 +345,72 @@ impl Capabilities {
                 description: "Clipboard operations are unavailable; text injection cannot run."
                     .to_string(),
                 remediation,
+                deep_link: None,
+            });
+        }
+
+        match self.session.kind {
+            SessionKind::Console => {}
+            SessionKind::Remote => issues.push(CapabilityIssue {
+                severity: IssueSeverity::Warning,
+                category: "platform".to_string(),
+                title: "Remote Session Detected".to_string(),
+                description: "Global hotkeys are paused in remote sessions so they cannot start a recording on the wrong desktop.".to_string(),
+                remediation: Some(
+                    "Start and stop recordings from the tray menu.".to_string(),
+                ),
+                deep_link: None,
+            }),
+            SessionKind::Inactive => issues.push(CapabilityIssue {
+                severity: IssueSeverity::Warning,
+                category: "platform".to_string(),
+                title: "Session Not Active".to_string(),
+                description: "Another session is using the display, so hotkeys are paused and transcripts are copied to the clipboard instead of typed.".to_string(),
+                remediation: Some("Switch back to this session to resume dictation.".to_string()),
+                deep_link: None,
+            }),
+        }
+
+        if self.screen_share.active {
+            issues.push(CapabilityIssue {
+                severity: IssueSeverity::Info,
+                category: "platform".to_string(),
+                title: "Screen Sharing Detected".to_string(),
+                description: "Viewers may see the recording overlay and dictated text while the screen is shared.".to_string(),
+                remediation: Some(
+                    "Choose what happens when dictating during a share in Settings.".to_string(),
+                ),
+                deep_link: None,
+            });
+        }
+
+        if self.dnd.active {
+            issues.push(CapabilityIssue {
+                severity: IssueSeverity::Info,
+                category: "platform".to_string(),
+                title: "Do Not Disturb Is On".to_string(),
+                description:
+                    "Audio cues are muted while the system is in Do Not Disturb or Focus mode."
+                        .to_string(),
+                remediation: Some(
+                    "Turn off \"Mute Cues in Do Not Disturb\" in Settings to keep hearing them."
+                        .to_string(),
+                ),
+                deep_link: None,
+            });
+        }
+
+        if self.network.offline_mode {
+            issues.push(CapabilityIssue {
+                severity: IssueSeverity::Info,
+                category: "network".to_string(),
+                title: "Offline Mode".to_string(),
+                description: format!(
+                    "Model downloads, update checks and settings sync are disabled ({} blocked requests).",
+                    self.network.blocked_count
+                ),
+                remediation: Some("Turn off offline mode in Settings to go online.".to_string()),
+                deep_link: None,
             });
         }
 
@@ -272,6 +420,7 @@ impl Capabilities {
 
 /// Issue severity level.
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "snake_case")]
 pub enum IssueSeverity {
     /// Issue prevents core functionality.
@@ -284,6 +433,7 @@ pub enum IssueSeverity {
 
 /// A capability issue that needs user attention.
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 pub struct CapabilityIssue {
     /// Severity of the issue.
     pub severity: IssueSeverity,
@@ -295,12 +445,62 @@ pub struct CapabilityIssue {
     pub description: String,
     /// How to fix the issue, if applicable.
     pub remediation: Option<String>,
+    /// OS settings URL that opens the exact pane needed to fix the issue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub deep_link: Option<String>,
+}
+
+/// Canonical `capabilities:issues` event payload from the permission
+/// pre-flight.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct CapabilitiesIssuesPayload {
+    pub permissions: PermissionStatus,
+    pub issues: Vec<CapabilityIssue>,
+}
+
+fn deep_link_for(page: SettingsPage) -> Option<String> {
+    page.deep_link().map(str::to_string)
+}
+
+/// Permission snapshot from the previous pre-flight check.
+static LAST_PREFLIGHT: Lazy<Mutex<PermissionPreflight>> =
+    Lazy::new(|| Mutex::new(PermissionPreflight::default()));
+
+/// Tracks permission changes between pre-flight checks.
+#[derive(Debug, Default)]
+pub struct PermissionPreflight {
+    last: Option<PermissionStatus>,
+}
+
+impl PermissionPreflight {
+    /// Record `status`, returning whether it differs from the previous check
+    /// (always true for the first check).
+    pub fn observe(&mut self, status: &PermissionStatus) -> bool {
+        let changed = self.last.as_ref() != Some(status);
+        self.last = Some(status.clone());
+        changed
+    }
+}
+
+/// Proactively check permissions (at startup and when the app regains focus).
+///
+/// Returns the detected capabilities when permissions changed since the last
+/// check, so callers only report issues that are new or resolved.
+pub fn permission_preflight() -> Option<Capabilities> {
+    let capabilities = Capabilities::detect();
+    let changed = LAST_PREFLIGHT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .observe(&capabilities.permissions);
+    changed.then_some(capabilities)
 }
 
 // === Platform Detection Functions ===
 
 /// Detect the display server / window system.
-fn detect_display_server() -> DisplayServer {
+pub(crate) fn detect_display_server() -> DisplayServer {
     #[cfg(target_os = "windows")]
     {
         DisplayServer::Windows
@@ -356,21 +556,6 @@ fn detect_wayland_compositor() -> Option<String> {
     None
 }
 
-/// Check if xdotool is available on the system.
-#[cfg(target_os = "linux")]
-fn check_xdotool_available() -> bool {
-    std::process::Command::new("which")
-        .arg("xdotool")
-        .output()
-        .map(|output| output.status.success())
-        .unwrap_or(false)
-}
-
-#[cfg(not(target_os = "linux"))]
-fn check_xdotool_available() -> bool {
-    false
-}
-
 #[cfg(target_os = "linux")]
 fn check_clipboard_available(display_server: &DisplayServer) -> bool {
     let has_wl_copy = check_command_available("wl-copy");
@@ -392,7 +577,7 @@ fn clipboard_available_from_probe(
 }
 
 #[cfg(target_os = "linux")]
-fn check_command_available(command: &str) -> bool {
+pub(crate) fn check_command_available(command: &str) -> bool {
     std::process::Command::new("which")
         .arg(command)
         .output()
@@ -424,14 +609,86 @@ fn check_clipboard_available(_: &DisplayServer) -> bool {
     false
 }
 
+/// Whether transcripts can be put on the PRIMARY selection.
+pub(crate) fn primary_selection_available() -> bool {
+    check_primary_selection_available(&detect_display_server())
+}
+
+#[cfg(target_os = "linux")]
+fn check_primary_selection_available(display_server: &DisplayServer) -> bool {
+    match display_server {
+        DisplayServer::X11 => check_command_available("xclip"),
+        DisplayServer::Wayland { .. } => {
+            check_command_available("wl-copy") && check_wayland_primary_selection()
+        }
+        _ => false,
+    }
+}
+
+/// Whether the compositor implements a primary-selection protocol. wl-paste
+/// exits non-zero for an empty selection too, so only its error text tells
+/// the two apart.
+#[cfg(target_os = "linux")]
+fn check_wayland_primary_selection() -> bool {
+    std::process::Command::new("wl-paste")
+        .args(["--primary", "--list-types"])
+        .output()
+        .map(|output| {
+            !wl_paste_reports_primary_unsupported(&String::from_utf8_lossy(&output.stderr))
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(target_os = "linux")]
+fn wl_paste_reports_primary_unsupported(stderr: &str) -> bool {
+    stderr.to_ascii_lowercase().contains("not supported")
+}
+
+#[cfg(not(target_os = "linux"))]
+fn check_primary_selection_available(_: &DisplayServer) -> bool {
+    false
+}
+
 /// Check if Wayland portal GlobalShortcuts is available.
 #[cfg(target_os = "linux")]
 fn check_wayland_portal() -> bool {
     query_wayland_global_shortcuts_portal().unwrap_or(false)
 }
 
+/// Whether this is a Wayland session.
+#[cfg(target_os = "linux")]
+pub(crate) fn is_wayland_session() -> bool {
+    matches!(detect_display_server(), DisplayServer::Wayland { .. })
+}
+
+/// Whether the GlobalShortcuts portal can register hotkeys.
+#[cfg(target_os = "linux")]
+pub(crate) fn wayland_portal_available() -> bool {
+    check_wayland_portal()
+}
+
+/// Whether the RemoteDesktop portal can deliver synthetic key presses.
+#[cfg(target_os = "linux")]
+pub(crate) fn remote_desktop_portal_available() -> bool {
+    introspect_portal()
+        .ok()
+        .flatten()
+        .is_some_and(|xml| portal_introspection_has_interface(&xml, REMOTE_DESKTOP_INTERFACE))
+}
+
 #[cfg(target_os = "linux")]
 fn query_wayland_global_shortcuts_portal() -> Result<bool, String> {
+    let xml = introspect_portal()?;
+    Ok(wayland_portal_support_from_probe(
+        xml.is_some(),
+        xml.as_deref(),
+    ))
+}
+
+/// Introspection XML of the desktop portal object, or None when no portal
+/// owns the bus name.
+#[cfg(target_os = "linux")]
+fn introspect_portal() -> Result<Option<String>, String> {
     use zbus::blocking::fdo::{DBusProxy, IntrospectableProxy};
     use zbus::blocking::Connection;
     use zbus::names::BusName;
@@ -448,7 +705,7 @@ fn query_wayland_global_shortcuts_portal() -> Result<bool, String> {
         .name_has_owner(portal_name)
         .map_err(|e| format!("name_has_owner call failed: {e}"))?;
     if !has_portal {
-        return Ok(wayland_portal_support_from_probe(false, None));
+        return Ok(None);
     }
 
     let introspectable = IntrospectableProxy::builder(&connection)
@@ -463,7 +720,7 @@ fn query_wayland_global_shortcuts_portal() -> Result<bool, String> {
         .introspect()
         .map_err(|e| format!("portal introspection failed: {e}"))?;
 
-    Ok(wayland_portal_support_from_probe(true, Some(&xml)))
+    Ok(Some(xml))
 }
 
 #[cfg(target_os = "linux")]
@@ -477,10 +734,18 @@ fn wayland_portal_support_from_probe(
             .unwrap_or(false)
 }
 
+#[cfg(target_os = "linux")]
+const GLOBAL_SHORTCUTS_INTERFACE: &str = "org.freedesktop.portal.GlobalShortcuts";
+#[cfg(target_os = "linux")]
+const REMOTE_DESKTOP_INTERFACE: &str = "org.freedesktop.portal.RemoteDesktop";
+
 #[cfg(target_os = "linux")]
 fn portal_introspection_has_global_shortcuts(introspection_xml: &str) -> bool {
-    const TARGET_INTERFACE: &str = "org.freedesktop.portal.GlobalShortcuts";
+    portal_introspection_has_interface(introspection_xml, GLOBAL_SHORTCUTS_INTERFACE)
+}
 
+#[cfg(target_os = "linux")]
+fn portal_introspection_has_interface(introspection_xml: &str, target_interface: &str) -> bool {
     let mut remaining = introspection_xml;
     while let Some(interface_pos) = remaining.find("<interface") {
         remaining = &remaining[interface_pos + "<interface".len()..];
@@ -490,7 +755,7 @@ fn portal_introspection_has_global_shortcuts(introspection_xml: &str) -> bool {
         };
 
         let interface_tag = &remaining[..tag_end];
-        if extract_xml_attribute(interface_tag, "name") == Some(TARGET_INTERFACE) {
+        if extract_xml_attribute(interface_tag, "name") == Some(target_interface) {
             return true;
         }
 
@@ -569,6 +834,88 @@ fn permission_from_accessibility_trust(is_trusted: bool) -> PermissionState {
     }
 }
 
+/// Check macOS microphone permission.
+///
+/// Uses `[AVCaptureDevice authorizationStatusForMediaType:AVMediaTypeAudio]`,
+/// which reads the TCC state without prompting.
+#[cfg(target_os = "macos")]
+fn check_macos_microphone() -> PermissionState {
+    use std::ffi::c_void;
+
+    type AuthorizationStatusFn =
+        unsafe extern "C" fn(*mut c_void, *mut c_void, *const c_void) -> isize;
+
+    unsafe {
+        let class = objc_getClass(c"AVCaptureDevice".as_ptr());
+        if class.is_null() {
+            return PermissionState::NotDetermined;
+        }
+        let selector = sel_registerName(c"authorizationStatusForMediaType:".as_ptr());
+        let send = std::mem::transmute::<*const c_void, AuthorizationStatusFn>(
+            objc_msgSend as *const c_void,
+        );
+        permission_from_av_authorization(send(class, selector, AVMediaTypeAudio))
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn check_macos_microphone() -> PermissionState {
+    PermissionState::NotApplicable
+}
+
+#[cfg(target_os = "macos")]
+#[link(name = "AVFoundation", kind = "framework")]
+unsafe extern "C" {
+    static AVMediaTypeAudio: *const std::ffi::c_void;
+}
+
+#[cfg(target_os = "macos")]
+#[link(name = "objc")]
+unsafe extern "C" {
+    fn objc_getClass(name: *const std::ffi::c_char) -> *mut std::ffi::c_void;
+    fn sel_registerName(name: *const std::ffi::c_char) -> *mut std::ffi::c_void;
+    fn objc_msgSend();
+}
+
+/// Map an `AVAuthorizationStatus` value to a permission state.
+fn permission_from_av_authorization(status: isize) -> PermissionState {
+    match status {
+        3 => PermissionState::Granted,
+        // Restricted (1) cannot be changed by the user, so treat it as denied.
+        1 | 2 => PermissionState::Denied,
+        _ => PermissionState::NotDetermined,
+    }
+}
+
+/// Check macOS Input Monitoring permission.
+///
+/// Uses IOHIDCheckAccess from IOKit, which does not prompt.
+#[cfg(target_os = "macos")]
+fn check_macos_input_monitoring() -> PermissionState {
+    // kIOHIDRequestTypeListenEvent
+    permission_from_hid_access(unsafe { IOHIDCheckAccess(1) })
+}
+
+#[cfg(not(target_os = "macos"))]
+fn check_macos_input_monitoring() -> PermissionState {
+    PermissionState::NotApplicable
+}
+
+#[cfg(target_os = "macos")]
+#[link(name = "IOKit", kind = "framework")]
+unsafe extern "C" {
+    fn IOHIDCheckAccess(request_type: u32) -> u32;
+}
+
+/// Map an `IOHIDAccessType` value to a permission state.
+fn permission_from_hid_access(access: u32) -> PermissionState {
+    match access {
+        0 => PermissionState::Granted,
+        1 => PermissionState::Denied,
+        _ => PermissionState::NotDetermined,
+    }
+}
+
 // === Mode Computation ===
 
 /// Compute the effective hotkey mode based on platform capabilities.
@@ -619,31 +966,41 @@ fn compute_effective_injection_method(
 
 /// Detect permission status for the platform.
 fn detect_permissions(display_server: &DisplayServer) -> PermissionStatus {
-    let accessibility = match display_server {
-        DisplayServer::MacOS => check_macos_accessibility(),
-        _ => PermissionState::NotApplicable,
+    let (accessibility, input_monitoring, microphone) = match display_server {
+        DisplayServer::MacOS => (
+            check_macos_accessibility(),
+            check_macos_input_monitoring(),
+            check_macos_microphone(),
+        ),
+        // Microphone permission elsewhere is checked on first use
+        _ => (
+            PermissionState::NotApplicable,
+            PermissionState::NotApplicable,
+            PermissionState::NotDetermined,
+        ),
     };
 
-    // Microphone permission would need platform-specific checks
-    // For now, return NotDetermined as we'll check on first use
-    let microphone = PermissionState::NotDetermined;
-
     PermissionStatus {
         microphone,
         accessibility,
+        input_monitoring,
     }
 }
 
 // === Diagnostics Generation ===
 
 /// Generate human-readable diagnostics text.
+#[allow(clippy::too_many_arguments)]
 fn generate_diagnostics(
     display_server: &DisplayServer,
     hotkey_press: bool,
     hotkey_release: bool,
     keystroke_injection: bool,
     clipboard_available: bool,
+    primary_selection_available: bool,
     permissions: &PermissionStatus,
+    strategies: Option<&PlatformStrategies>,
+    dnd: &DndInfo,
 ) -> String {
     let mut lines = Vec::new();
 
@@ -690,6 +1047,43 @@ fn generate_diagnostics(
     } else {
         lines.push("  ✗ Clipboard access NOT available".to_string());
     }
+    if primary_selection_available {
+        lines.push("  ✓ PRIMARY selection (middle-click paste) available".to_string());
+    }
+    if let Some(strategies) = strategies {
+        let forced = |overridden: bool| {
+            if overridden {
+                " (forced in config)"
+            } else {
+                ""
+            }
+        };
+        lines.push(format!(
+            "  • Focus strategy: {}{}",
+            strategies.focus.as_str(),
+            forced(strategies.focus_overridden)
+        ));
+        lines.push(format!(
+            "  • Paste strategy: {}{}",
+            strategies.paste.as_str(),
+            forced(strategies.paste_overridden)
+        ));
+    }
+    lines.push(String::new());
+
+    // Do-Not-Disturb
+    lines.push("Do Not Disturb:".to_string());
+    match (dnd.detectable, dnd.active) {
+        (false, _) => lines.push("  ? State not detectable on this system".to_string()),
+        (true, true) => lines.push(format!(
+            "  • On ({}), audio cues muted unless overridden",
+            dnd.source.as_deref().unwrap_or("unknown")
+        )),
+        (true, false) => lines.push(format!(
+            "  ✓ Off ({})",
+            dnd.source.as_deref().unwrap_or("unknown")
+        )),
+    }
     lines.push(String::new());
 
     // Permissions (if applicable)
@@ -715,14 +1109,32 @@ fn generate_diagnostics(
             }
             PermissionState::NotApplicable => {}
         }
+        match permissions.input_monitoring {
+            PermissionState::Granted => {
+                lines.push("  ✓ Input Monitoring permission granted".to_string())
+            }
+            PermissionState::Denied => {
+                lines.push("  ✗ Input Monitoring permission DENIED".to_string())
+            }
+            PermissionState::NotDetermined => {
+                lines.push("  ? Input Monitoring permission not yet requested".to_string())
+            }
+            PermissionState::NotApplicable => {}
+        }
     }
 
     // Wayland-specific notes
     if matches!(display_server, DisplayServer::Wayland { .. }) {
         lines.push("⚠ WAYLAND NOTES:".to_string());
         lines.push("  • Push-to-talk mode may not work reliably".to_string());
-        lines.push("  • Direct text injection is not supported".to_string());
-        lines.push("  • Text will be copied to clipboard; paste with Ctrl+V".to_string());
+        if keystroke_injection {
+            lines.push(
+                "  • Paste keystrokes go through wtype or the RemoteDesktop portal".to_string(),
+            );
+        } else {
+            lines.push("  • Direct text injection is not supported".to_string());
+            lines.push("  • Text will be copied to clipboard; paste with Ctrl+V".to_string());
+        }
     }
 
     lines.join("\n")
@@ -779,6 +1191,22 @@ mod tests {
         assert!(method.reason.contains("clipboard"));
     }
 
+    #[test]
+    fn test_injection_method_from_config_mode() {
+        assert_eq!(
+            InjectionMethod::from_config_mode("inject"),
+            InjectionMethod::ClipboardPaste
+        );
+        assert_eq!(
+            InjectionMethod::from_config_mode("clipboard_only"),
+            InjectionMethod::ClipboardOnly
+        );
+        assert_eq!(
+            InjectionMethod::from_config_mode("ask"),
+            InjectionMethod::Ask
+        );
+    }
+
     #[test]
     fn test_diagnostics_not_empty() {
         let caps = Capabilities::detect();
@@ -802,6 +1230,151 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_permission_from_av_authorization() {
+        assert_eq!(
+            permission_from_av_authorization(0),
+            PermissionState::NotDetermined
+        );
+        assert_eq!(permission_from_av_authorization(1), PermissionState::Denied);
+        assert_eq!(permission_from_av_authorization(2), PermissionState::Denied);
+        assert_eq!(
+            permission_from_av_authorization(3),
+            PermissionState::Granted
+        );
+    }
+
+    #[test]
+    fn test_permission_from_hid_access() {
+        assert_eq!(permission_from_hid_access(0), PermissionState::Granted);
+        assert_eq!(permission_from_hid_access(1), PermissionState::Denied);
+        assert_eq!(
+            permission_from_hid_access(2),
+            PermissionState::NotDetermined
+        );
+    }
+
+    #[test]
+    fn test_preflight_reports_only_permission_changes() {
+        let denied = PermissionStatus {
+            microphone: PermissionState::Granted,
+            accessibility: PermissionState::Denied,
+            input_monitoring: PermissionState::Granted,
+        };
+        let granted = PermissionStatus {
+            accessibility: PermissionState::Granted,
+            ..denied.clone()
+        };
+        let mut preflight = PermissionPreflight::default();
+
+        assert!(preflight.observe(&denied));
+        assert!(!preflight.observe(&denied));
+        assert!(preflight.observe(&granted));
+    }
+
+    #[test]
+    fn test_macos_permission_issues_link_to_settings_pane() {
+        let mut caps = Capabilities::detect();
+        caps.display_server = DisplayServer::MacOS;
+        caps.permissions = PermissionStatus {
+            microphone: PermissionState::Denied,
+            accessibility: PermissionState::Denied,
+            input_monitoring: PermissionState::Denied,
+        };
+
+        let issues = caps.issues();
+        let titles: Vec<&str> = issues.iter().map(|issue| issue.title.as_str()).collect();
+        assert!(titles.contains(&"Accessibility Permission Required"));
+        assert!(titles.contains(&"Microphone Permission Denied"));
+        assert!(titles.contains(&"Input Monitoring Permission Denied"));
+        if cfg!(target_os = "macos") {
+            assert!(issues
+                .iter()
+                .filter(|issue| issue.category == "permissions")
+                .all(|issue| issue.deep_link.is_some()));
+        }
+    }
+
+    #[test]
+    fn test_offline_mode_reports_info_issue() {
+        let mut caps = Capabilities::detect();
+        caps.network.offline_mode = false;
+        assert!(!caps
+            .issues()
+            .iter()
+            .any(|issue| issue.title == "Offline Mode"));
+
+        caps.network.offline_mode = true;
+        caps.network.blocked_count = 3;
+        let issue = caps
+            .issues()
+            .into_iter()
+            .find(|issue| issue.title == "Offline Mode")
+            .expect("offline mode issue");
+        assert!(matches!(issue.severity, IssueSeverity::Info));
+        assert!(issue.description.contains("3 blocked requests"));
+    }
+
+    #[test]
+    fn test_non_console_sessions_report_issue() {
+        let mut caps = Capabilities::detect();
+        let title_for = |caps: &Capabilities, title: &str| {
+            caps.issues().iter().any(|issue| issue.title == title)
+        };
+
+        caps.session = SessionInfo::console();
+        assert!(!title_for(&caps, "Remote Session Detected"));
+        assert!(!title_for(&caps, "Session Not Active"));
+
+        caps.session = SessionInfo {
+            kind: SessionKind::Remote,
+            remote_protocol: Some("rdp".to_string()),
+        };
+        assert!(title_for(&caps, "Remote Session Detected"));
+
+        caps.session.kind = SessionKind::Inactive;
+        assert!(title_for(&caps, "Session Not Active"));
+    }
+
+    #[test]
+    fn test_screen_share_reports_issue() {
+        let mut caps = Capabilities::detect();
+        caps.screen_share = ScreenShareInfo::default();
+        assert!(!caps
+            .issues()
+            .iter()
+            .any(|issue| issue.title == "Screen Sharing Detected"));
+
+        caps.screen_share = ScreenShareInfo {
+            active: true,
+            source: Some("zoom".to_string()),
+        };
+        assert!(caps
+            .issues()
+            .iter()
+            .any(|issue| issue.title == "Screen Sharing Detected"));
+    }
+
+    #[test]
+    fn test_dnd_reports_issue() {
+        let mut caps = Capabilities::detect();
+        caps.dnd = DndInfo::default();
+        assert!(!caps
+            .issues()
+            .iter()
+            .any(|issue| issue.title == "Do Not Disturb Is On"));
+
+        caps.dnd = DndInfo {
+            active: true,
+            detectable: true,
+            source: Some("gnome".to_string()),
+        };
+        assert!(caps
+            .issues()
+            .iter()
+            .any(|issue| issue.title == "Do Not Disturb Is On"));
+    }
+
     #[cfg(target_os = "macos")]
     #[test]
     fn test_macos_accessibility_check_is_not_not_determined() {
@@ -883,6 +1456,16 @@ mod tests {
         ));
     }
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_wl_paste_primary_probe_distinguishes_unsupported_from_empty() {
+        assert!(wl_paste_reports_primary_unsupported(
+            "wl-paste: Primary selection is not supported on this compositor\n"
+        ));
+        assert!(!wl_paste_reports_primary_unsupported("No selection\n"));
+        assert!(!wl_paste_reports_primary_unsupported(""));
+    }
+
     #[test]
     fn test_display_server_wire_snapshot_parity() {
         let snapshot: Value =
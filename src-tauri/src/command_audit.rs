@@ -0,0 +1,288 @@
+//! Audit log of the Tauri commands the frontend invokes.
+//!
+//! Commands take a [`CommandAudit`] argument. Extracting it records the
+//! command name, a summary of its arguments, and the window that invoked it;
+//! dropping it when the command returns records how long it took. A
+//! [`CommandError`](crate::commands::CommandError) serialized for the
+//! frontend right after marks the entry failed. The newest entries are kept
+//! in memory for `get_command_audit_log`, to debug reports like "I clicked
+//! download and nothing happened".
+//!
+//! Argument summaries show numbers, booleans, and identifier-like strings
+//! (`id`, `*Id`, `mode`, `name`); other strings are reduced to their
+//! length, and values under keys that look like credentials are redacted.
+
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use tauri::ipc::{CommandArg, CommandItem, InvokeBody, InvokeError};
+use tauri::Runtime;
+
+/// Maximum number of entries kept.
+const MAX_ENTRIES: usize = 200;
+
+/// Longest identifier-like string shown verbatim in an argument summary.
+const MAX_SHOWN_STRING_CHARS: usize = 64;
+
+static AUDIT_LOG: Lazy<Mutex<CommandAuditLog>> =
+    Lazy::new(|| Mutex::new(CommandAuditLog::new(MAX_ENTRIES)));
+
+thread_local! {
+    /// Entry of the command that last returned on this thread. Command
+    /// results are serialized on the thread that ran the command, right after
+    /// it returns.
+    static LAST_FINISHED: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Outcome of an audited command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommandAuditStatus {
+    /// Still running.
+    Pending,
+    Ok,
+    Error,
+}
+
+/// One command invocation.
+#[derive(Debug, Clone, Serialize)]
+pub struct CommandAuditEntry {
+    pub id: u64,
+    pub command: String,
+    /// Label of the window that invoked the command.
+    pub window: String,
+    /// Sanitized summary of the arguments.
+    pub args: String,
+    pub started_at: DateTime<Utc>,
+    /// Set once the command returns.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+    pub status: CommandAuditStatus,
+    /// Redacted message of the error returned to the frontend.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Bounded log of command invocations, oldest first.
+#[derive(Debug)]
+struct CommandAuditLog {
+    entries: VecDeque<CommandAuditEntry>,
+    max_entries: usize,
+    next_id: u64,
+}
+
+impl CommandAuditLog {
+    fn new(max_entries: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(max_entries),
+            max_entries,
+            next_id: 1,
+        }
+    }
+
+    fn begin(&mut self, command: &str, window: &str, args: String) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        if self.entries.len() >= self.max_entries {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(CommandAuditEntry {
+            id,
+            command: command.to_string(),
+            window: window.to_string(),
+            args,
+            started_at: Utc::now(),
+            duration_ms: None,
+            status: CommandAuditStatus::Pending,
+            error: None,
+        });
+        id
+    }
+
+    fn entry_mut(&mut self, id: u64) -> Option<&mut CommandAuditEntry> {
+        // Ids increase along the buffer, so the newest entries are at the back.
+        self.entries.iter_mut().rev().find(|entry| entry.id == id)
+    }
+
+    fn finish(&mut self, id: u64, duration: Duration) {
+        if let Some(entry) = self.entry_mut(id) {
+            entry.duration_ms = Some(duration.as_millis() as u64);
+            entry.status = CommandAuditStatus::Ok;
+        }
+    }
+
+    fn fail(&mut self, id: u64, message: &str) {
+        if let Some(entry) = self.entry_mut(id) {
+            entry.status = CommandAuditStatus::Error;
+            entry.error = Some(crate::log_buffer::redact_sensitive(message));
+        }
+    }
+
+    fn recent(&self, count: usize) -> Vec<CommandAuditEntry> {
+        let skip = self.entries.len().saturating_sub(count);
+        self.entries.iter().skip(skip).cloned().collect()
+    }
+}
+
+fn audit_log() -> std::sync::MutexGuard<'static, CommandAuditLog> {
+    AUDIT_LOG.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The newest `count` entries, oldest first.
+pub fn recent(count: usize) -> Vec<CommandAuditEntry> {
+    audit_log().recent(count)
+}
+
+/// Command argument recording its invocation in the audit log. The entry is
+/// completed when the command drops it on return.
+pub struct CommandAudit {
+    id: u64,
+    started: Instant,
+}
+
+impl CommandAudit {
+    fn begin(command: &str, caller: &str, args: String) -> Self {
+        Self {
+            id: audit_log().begin(command, caller, args),
+            started: Instant::now(),
+        }
+    }
+
+    /// Audit a command called from Rust rather than by a window, such as
+    /// from the tray menu; `caller` stands in for the window label.
+    pub fn internal(command: &str, caller: &str) -> Self {
+        Self::begin(command, caller, String::new())
+    }
+}
+
+impl<'de, R: Runtime> CommandArg<'de, R> for CommandAudit {
+    fn from_command(command: CommandItem<'de, R>) -> Result<Self, InvokeError> {
+        let window = command.message.webview_ref().window();
+        let args = summarize_args(command.message.payload());
+        Ok(Self::begin(command.name, window.label(), args))
+    }
+}
+
+impl Drop for CommandAudit {
+    fn drop(&mut self) {
+        audit_log().finish(self.id, self.started.elapsed());
+        LAST_FINISHED.with(|last| last.set(Some(self.id)));
+    }
+}
+
+/// Serialize a command error message, marking the command that just returned
+/// the error as failed.
+pub(crate) fn serialize_error_message<S: Serializer>(
+    message: &str,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if let Some(id) = LAST_FINISHED.with(Cell::take) {
+        audit_log().fail(id, message);
+    }
+    serializer.serialize_str(message)
+}
+
+fn summarize_args(payload: &InvokeBody) -> String {
+    match payload {
+        InvokeBody::Json(Value::Object(args)) => args
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, summarize_value(key, value)))
+            .collect::<Vec<_>>()
+            .join(", "),
+        InvokeBody::Json(Value::Null) => String::new(),
+        InvokeBody::Json(value) => summarize_value("", value),
+        InvokeBody::Raw(bytes) => format!("<{} bytes>", bytes.len()),
+    }
+}
+
+fn summarize_value(key: &str, value: &Value) -> String {
+    if is_sensitive_key(key) && !value.is_null() {
+        return "<redacted>".to_string();
+    }
+    match value {
+        Value::Null | Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::String(text)
+            if is_identifier_key(key) && text.chars().count() <= MAX_SHOWN_STRING_CHARS =>
+        {
+            value.to_string()
+        }
+        Value::String(text) => format!("<{} chars>", text.chars().count()),
+        Value::Array(items) => format!("[{} items]", items.len()),
+        Value::Object(fields) => format!("{{{} fields}}", fields.len()),
+    }
+}
+
+/// Tauri passes arguments with camelCase keys (`modelId`).
+fn is_identifier_key(key: &str) -> bool {
+    matches!(key, "id" | "mode" | "name") || key.ends_with("Id") || key.ends_with("_id")
+}
+
+fn is_sensitive_key(key: &str) -> bool {
+    let lower = key.to_ascii_lowercase();
+    key == "value"
+        || key.ends_with("Key")
+        || key.ends_with("_key")
+        || ["token", "secret", "password"]
+            .iter()
+            .any(|word| lower.contains(word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_log_keeps_newest_entries() {
+        let mut log = CommandAuditLog::new(2);
+        let first = log.begin("get_config", "main", String::new());
+        let second = log.begin("download_model", "main", String::new());
+        let third = log.begin("get_status", "overlay", String::new());
+        log.finish(first, Duration::from_millis(5));
+        log.finish(second, Duration::from_millis(1200));
+        log.fail(second, "Download failed: /home/alice/models is read-only");
+
+        let entries = log.recent(10);
+        assert_eq!(
+            entries.iter().map(|entry| entry.id).collect::<Vec<_>>(),
+            [second, third]
+        );
+        assert_eq!(entries[0].status, CommandAuditStatus::Error);
+        assert_eq!(entries[0].duration_ms, Some(1200));
+        assert!(!entries[0].error.as_ref().unwrap().contains("alice"));
+        assert_eq!(entries[1].status, CommandAuditStatus::Pending);
+        assert_eq!(entries[1].window, "overlay");
+        assert_eq!(log.recent(1)[0].id, third);
+    }
+
+    #[test]
+    fn test_summarize_args_hides_free_text_and_credentials() {
+        let payload = InvokeBody::Json(json!({
+            "modelId": "parakeet-tdt-0.6b-v2",
+            "force": true,
+            "count": 3,
+            "text": "dear diary",
+            "apiKey": "sk-123",
+            "terms": ["a", "b"],
+            "hotkey": "Ctrl+Shift+Space",
+        }));
+        let summary = summarize_args(&payload);
+        assert!(summary.contains("modelId=\"parakeet-tdt-0.6b-v2\""));
+        assert!(summary.contains("force=true"));
+        assert!(summary.contains("count=3"));
+        assert!(summary.contains("text=<10 chars>"));
+        assert!(summary.contains("apiKey=<redacted>"));
+        assert!(summary.contains("terms=[2 items]"));
+        assert!(summary.contains("hotkey=<16 chars>"));
+        assert!(!summary.contains("diary") && !summary.contains("sk-123"));
+
+        assert_eq!(summarize_args(&InvokeBody::Json(json!({}))), "");
+        assert_eq!(summarize_args(&InvokeBody::Raw(vec![0; 4])), "<4 bytes>");
+    }
+}
@@ -3,28 +3,77 @@
 //! This module provides the complete API surface between the React UI
 //! and the Rust backend via Tauri commands.
 
-use regex::{NoExpand, RegexBuilder};
+use chrono::{DateTime, Utc};
+use regex::NoExpand;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
 
 use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tauri::Emitter;
+use tauri::{Emitter, Manager};
 
-use crate::capabilities::{Capabilities, CapabilityIssue};
+use crate::actions::{self, ActionContext, ActionId, ActionInfo};
+use crate::app_icon::{self, AppIcon};
+use crate::app_update::{self, AppUpdateError, AppUpdateInfo, AppUpdatePhase, InstallAction};
+use crate::capabilities::{
+    self, ActivationMode, Capabilities, CapabilitiesIssuesPayload, CapabilityIssue, InjectionMethod,
+};
+use crate::command_audit::{self, CommandAudit, CommandAuditEntry};
 use crate::config::{self, AppConfig, ReplacementRule};
-use crate::history::{HistoryExportError, TranscriptEntry, TranscriptHistory};
+use crate::crash_reports::{self, CrashReport};
+use crate::errors::{AppError, IntegrationError};
+use crate::file_transcription::{self, AudioFileInfo, FileTranscriptionPhase};
+use crate::focus::{self, current_focus_info, FocusInfo, FocusSignature, InjectionTarget};
+use crate::history::{
+    HistoryClearFilter, HistoryExportError, HistoryInjectionResult, HistoryStats, SessionLabel,
+    TranscriptEntry, TranscriptHistory, DEFAULT_LOW_CONFIDENCE_THRESHOLD,
+};
+use crate::hotkey;
+use crate::hotkey_leak::{self, HotkeyLeakReport};
+use crate::hotkey_practice::{self, HotkeyPracticeReport};
+use crate::injection::{
+    inject_text, InjectionConfig, InjectionMode, InjectionModeChangedPayload, InjectionResult,
+};
 use crate::integration::{
-    SidecarAudioDevice, SidecarModelStatus, SidecarPresetInfo, SidecarReplacementPreviewResult,
+    transcript_complete_event_payload, InputGain, SidecarAudioDevice, SidecarModelStatus,
+    SidecarPresetInfo, SidecarReplacementPreviewResult,
 };
-use crate::model_defaults;
-use crate::state::{AppStateManager, CannotRecordReason, StateEvent};
+use crate::ipc::decode::{self, SidecarOutputStats};
+use crate::learned_dictionary::{self, LearnedWord};
+use crate::log_buffer::LogFilter;
+use crate::model_compare::{self, ComparisonAudio, ModelComparison};
+use crate::model_defaults::{self, RecommendedParams};
+use crate::model_license::{self, PendingLicense};
+use crate::overlay::{self, OverlayAppearance};
+use crate::paste_calibration::{self, AppPasteCalibration};
+use crate::pending_inserts::{PendingInsert, PendingInsertQueue, PendingInsertsChangedPayload};
+use crate::phrase_shortcuts::{self, PhraseShortcut, ShortcutError, ShortcutValue};
+use crate::policy::{self, PolicyViolation};
+use crate::power::{self, PowerStatus};
+use crate::release_download;
+use crate::replacement_rules::{self, RuleIssue};
+use crate::secrets::{self, SecretError, SecretStore};
+use crate::session_lifecycle::SessionSnapshot;
+use crate::share::{self, ShareError, ShareMethod};
+use crate::sidecar_update::{self, SidecarUpdateError, SidecarUpdateInfo, SidecarUpdatePhase};
+use crate::state::{AppState, AppStateManager, CannotRecordReason, EnableLevel, StateEvent};
+use crate::state_snapshot::{self, AppStateSnapshot, SnapshotError};
+use crate::sync::{self, SyncError, SyncReport};
+use crate::transcript_diff::{self, TranscriptDiff};
+use crate::typing_guard;
+use crate::vocabulary::{self, VocabularyError};
+use crate::watchdog::{WatchdogConfig, WatchdogSettings};
 use crate::IntegrationState;
 
 const MODEL_MANIFEST_PATH: &str = "shared/model/MODEL_MANIFEST.json";
 const MODEL_MANIFEST_JSON: &str = include_str!("../../shared/model/MODEL_MANIFEST.json");
 const EVENT_TRAY_UPDATE: &str = "tray:update";
+const EVENT_TRANSCRIPT_COMPLETE: &str = "transcript:complete";
+const EVENT_INJECTION_MODE_CHANGED: &str = "injection:mode_changed";
+const EVENT_PENDING_INSERTS_CHANGED: &str = "pending_inserts:changed";
+const EVENT_CAPABILITIES_ISSUES: &str = "capabilities:issues";
 
 fn emit_tray_update(app: &tauri::AppHandle, reason: &str) {
     let _ = app.emit(
@@ -36,35 +85,91 @@ fn emit_tray_update(app: &tauri::AppHandle, reason: &str) {
 }
 
 /// Command error types.
+///
+/// Serializing an error for the frontend also marks the command that returned
+/// it as failed in the command audit log.
 #[derive(Debug, Error, Serialize)]
 #[serde(rename_all = "snake_case", tag = "code")]
 pub enum CommandError {
     #[error("Config error: {message}")]
-    Config { message: String },
-
-    #[error("Audio error: {message}")]
-    Audio { message: String },
-
-    #[error("Sidecar IPC error: {message}")]
-    #[serde(rename = "E_SIDECAR_IPC")]
-    SidecarIpc { message: String },
-
-    #[error("Model error: {message}")]
-    Model { message: String },
+    Config {
+        #[serde(serialize_with = "command_audit::serialize_error_message")]
+        message: String,
+    },
+
+    /// Integration manager failure; `error` carries the stable error code,
+    /// recoverability, and diagnostics from the error taxonomy.
+    #[error("{message}")]
+    Integration {
+        #[serde(serialize_with = "command_audit::serialize_error_message")]
+        message: String,
+        title: String,
+        error: Box<AppError>,
+    },
+
+    /// The change touches settings locked by the managed policy.
+    #[error("{message}")]
+    Policy {
+        #[serde(serialize_with = "command_audit::serialize_error_message")]
+        message: String,
+        fields: Vec<String>,
+    },
 
     #[error("Clipboard error: {message}")]
-    Clipboard { message: String },
+    Clipboard {
+        #[serde(serialize_with = "command_audit::serialize_error_message")]
+        message: String,
+    },
+
+    /// The action cannot run in the current state; `reason` is the code
+    /// `list_actions` reports for it.
+    #[error("{message}")]
+    ActionUnavailable {
+        #[serde(serialize_with = "command_audit::serialize_error_message")]
+        message: String,
+        reason: String,
+    },
 
     #[allow(dead_code)]
     #[error("Hotkey error: {message}")]
-    Hotkey { message: String },
+    Hotkey {
+        #[serde(serialize_with = "command_audit::serialize_error_message")]
+        message: String,
+    },
 
     #[allow(dead_code)]
     #[error("Not implemented: {message}")]
-    NotImplemented { message: String },
+    NotImplemented {
+        #[serde(serialize_with = "command_audit::serialize_error_message")]
+        message: String,
+    },
 
     #[error("Internal error: {message}")]
-    Internal { message: String },
+    Internal {
+        #[serde(serialize_with = "command_audit::serialize_error_message")]
+        message: String,
+    },
+}
+
+impl From<SecretError> for CommandError {
+    fn from(e: SecretError) -> Self {
+        match e {
+            SecretError::InvalidName(_) | SecretError::EmptyValue => CommandError::Config {
+                message: e.to_string(),
+            },
+            SecretError::Keychain(_) => CommandError::Internal {
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+impl From<AppUpdateError> for CommandError {
+    fn from(e: AppUpdateError) -> Self {
+        CommandError::Internal {
+            message: e.to_string(),
+        }
+    }
 }
 
 impl From<config::ConfigError> for CommandError {
@@ -75,6 +180,25 @@ impl From<config::ConfigError> for CommandError {
     }
 }
 
+impl From<IntegrationError> for CommandError {
+    fn from(e: IntegrationError) -> Self {
+        CommandError::Integration {
+            message: e.to_string(),
+            title: e.to_user_error().title,
+            error: Box::new(AppError::from(&e)),
+        }
+    }
+}
+
+impl From<PolicyViolation> for CommandError {
+    fn from(e: PolicyViolation) -> Self {
+        CommandError::Policy {
+            message: e.to_string(),
+            fields: e.fields,
+        }
+    }
+}
+
 impl From<HistoryExportError> for CommandError {
     fn from(e: HistoryExportError) -> Self {
         CommandError::Internal {
@@ -83,31 +207,125 @@ impl From<HistoryExportError> for CommandError {
     }
 }
 
+impl From<SnapshotError> for CommandError {
+    fn from(e: SnapshotError) -> Self {
+        CommandError::Internal {
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<ShareError> for CommandError {
+    fn from(e: ShareError) -> Self {
+        CommandError::Clipboard {
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<SidecarUpdateError> for CommandError {
+    fn from(e: SidecarUpdateError) -> Self {
+        CommandError::Internal {
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<SyncError> for CommandError {
+    fn from(e: SyncError) -> Self {
+        match e {
+            SyncError::Secret(error) => error.into(),
+            SyncError::Transport(_) | SyncError::Io(_) => CommandError::Internal {
+                message: e.to_string(),
+            },
+            _ => CommandError::Config {
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+impl From<VocabularyError> for CommandError {
+    fn from(e: VocabularyError) -> Self {
+        CommandError::Config {
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<ShortcutError> for CommandError {
+    fn from(e: ShortcutError) -> Self {
+        match e {
+            ShortcutError::Secret(secret_error) => secret_error.into(),
+            ShortcutError::Io(_) => CommandError::Internal {
+                message: e.to_string(),
+            },
+            _ => CommandError::Config {
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
 // ============================================================================
 // STATE COMMANDS
 // ============================================================================
 
 /// Get current application state.
 #[tauri::command]
-pub fn get_app_state(state_manager: tauri::State<Arc<AppStateManager>>) -> StateEvent {
+pub fn get_app_state(
+    _audit: CommandAudit,
+    state_manager: tauri::State<Arc<AppStateManager>>,
+) -> StateEvent {
     state_manager.get_event()
 }
 
 /// Get platform capabilities.
 #[tauri::command]
-pub fn get_capabilities() -> Capabilities {
-    Capabilities::detect()
+pub fn get_capabilities(_audit: CommandAudit) -> Capabilities {
+    let mode = config::load_config().injection.mode;
+    Capabilities::detect_with_defaults(
+        ActivationMode::PushToTalk,
+        InjectionMethod::from_config_mode(&mode),
+    )
 }
 
 /// Get capability issues that need user attention.
 #[tauri::command]
-pub fn get_capability_issues() -> Vec<CapabilityIssue> {
+pub fn get_capability_issues(_audit: CommandAudit) -> Vec<CapabilityIssue> {
     Capabilities::detect().issues()
 }
 
+/// Re-check OS permissions in the background and emit `capabilities:issues`
+/// when they changed since the last check (always on the first check).
+///
+/// Runs at startup and whenever the main window regains focus, so granting a
+/// permission in System Settings clears the issue without a restart.
+pub fn run_permission_preflight(app: &tauri::AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let Some(capabilities) = capabilities::permission_preflight() else {
+            return;
+        };
+        let issues = capabilities.issues();
+        for issue in &issues {
+            log::warn!("Capability issue: {}", issue.title);
+        }
+        let payload = CapabilitiesIssuesPayload {
+            permissions: capabilities.permissions,
+            issues,
+        };
+        let _ = app.emit(
+            EVENT_CAPABILITIES_ISSUES,
+            crate::event_seq::payload_with_next_seq(serde_json::json!(payload)),
+        );
+    });
+}
+
 /// Check if recording can start.
 #[tauri::command]
 pub fn can_start_recording(
+    _audit: CommandAudit,
     state_manager: tauri::State<Arc<AppStateManager>>,
 ) -> Result<(), CannotRecordReason> {
     state_manager.can_start_recording()
@@ -141,7 +359,7 @@ pub enum CheckStatus {
 
 /// Run self-check for all subsystems.
 #[tauri::command]
-pub fn run_self_check() -> SelfCheckResult {
+pub fn run_self_check(_audit: CommandAudit) -> SelfCheckResult {
     let caps = Capabilities::detect();
 
     // Check injection capability
@@ -199,29 +417,73 @@ pub fn run_self_check() -> SelfCheckResult {
 // CONFIG COMMANDS
 // ============================================================================
 
-/// Get current configuration.
+/// Configuration as shown to the UI.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigView {
+    #[serde(flatten)]
+    pub config: AppConfig,
+    /// Dotted paths of settings locked by the managed policy.
+    pub managed_fields: Vec<String>,
+}
+
+/// Get current configuration, flagging fields managed by policy.
+#[tauri::command]
+pub fn get_config(_audit: CommandAudit) -> ConfigView {
+    ConfigView {
+        config: config::load_config(),
+        managed_fields: policy::current().managed_fields(),
+    }
+}
+
+/// Get the overlay appearance; later changes arrive as `overlay:config`.
 #[tauri::command]
-pub fn get_config() -> AppConfig {
-    config::load_config()
+pub fn get_overlay_config(_audit: CommandAudit) -> OverlayAppearance {
+    OverlayAppearance::from_config(&config::load_config().ui)
 }
 
 /// Update configuration.
 #[tauri::command]
 pub fn update_config(
+    _audit: CommandAudit,
     config: AppConfig,
     history: tauri::State<TranscriptHistory>,
     app: tauri::AppHandle,
     integration_state: tauri::State<'_, IntegrationState>,
 ) -> Result<(), CommandError> {
     let mut config = config;
+    if let Some(issue) = overlay::appearance_issue(&config.ui) {
+        return Err(CommandError::Config { message: issue });
+    }
+    let previous = config::load_config();
+    let preset = config.audio.vad_preset;
+    preset.apply_change(previous.audio.vad_preset, &mut config.audio);
     config.validate_and_clamp();
+    policy::current().check_update(&config)?;
+    let offline_mode_changed = previous.network.offline_mode != config.network.offline_mode;
+    let hotkeys_changed = previous.effective_hotkeys() != config.effective_hotkeys();
     config::save_config(&config)?;
     history.resize(config.history.max_entries as usize);
+    crate::history::request_prune();
     {
         let manager_state = Arc::clone(&integration_state.0);
+        let synced_config = config.clone();
         tauri::async_runtime::spawn(async move {
             let manager = manager_state.read().await;
             manager.notify_overlay_config_changed();
+            power::apply_config(&synced_config.power);
+            manager.apply_watchdog_config(&synced_config.supervisor);
+            // Group or profile changes alter the active rule set.
+            manager.sync_replacement_rules(&synced_config).await;
+            // The sidecar reads offline mode from its environment at startup.
+            if offline_mode_changed {
+                if let Err(error) = manager.restart_sidecar().await {
+                    log::warn!("Failed to restart sidecar for offline mode: {}", error);
+                }
+            }
+            // Switching profiles can switch hotkey sets.
+            if hotkeys_changed {
+                manager.reload_hotkeys().await;
+            }
         });
     }
     emit_tray_update(&app, "config_changed");
@@ -231,24 +493,100 @@ pub fn update_config(
 /// Reset configuration to defaults.
 #[tauri::command]
 pub fn reset_config_to_defaults(
+    _audit: CommandAudit,
     history: tauri::State<TranscriptHistory>,
     app: tauri::AppHandle,
     integration_state: tauri::State<'_, IntegrationState>,
 ) -> Result<AppConfig, CommandError> {
-    let config = AppConfig::default();
+    let mut config = AppConfig::default();
+    policy::current().enforce(&mut config);
+    let hotkeys_changed = config::load_config().effective_hotkeys() != config.effective_hotkeys();
     config::save_config(&config)?;
     history.resize(config.history.max_entries as usize);
+    crate::history::request_prune();
     {
         let manager_state = Arc::clone(&integration_state.0);
+        let synced_config = config.clone();
         tauri::async_runtime::spawn(async move {
             let manager = manager_state.read().await;
             manager.notify_overlay_config_changed();
+            power::apply_config(&synced_config.power);
+            manager.apply_watchdog_config(&synced_config.supervisor);
+            // Group or profile changes alter the active rule set.
+            manager.sync_replacement_rules(&synced_config).await;
+            if hotkeys_changed {
+                manager.reload_hotkeys().await;
+            }
         });
     }
     emit_tray_update(&app, "config_changed");
     Ok(config)
 }
 
+/// Sync rules, profiles and vocabulary with the configured sync endpoint now.
+#[tauri::command]
+pub async fn sync_now(
+    _audit: CommandAudit,
+    app: tauri::AppHandle,
+    integration_state: tauri::State<'_, IntegrationState>,
+) -> Result<SyncReport, CommandError> {
+    let (report, applied) =
+        tokio::task::spawn_blocking(sync::sync_now)
+            .await
+            .map_err(|error| CommandError::Internal {
+                message: format!("Sync task failed: {error}"),
+            })??;
+    if let Some(config) = applied {
+        let manager = integration_state.0.read().await;
+        manager.notify_overlay_config_changed();
+        manager.sync_replacement_rules(&config).await;
+        emit_tray_update(&app, "config_changed");
+    }
+    Ok(report)
+}
+
+// ============================================================================
+// SECRET COMMANDS
+// ============================================================================
+
+/// Run a keychain operation off the async runtime; keychain access can block
+/// on an OS unlock prompt.
+async fn run_secret_operation<T: Send + 'static>(
+    operation: impl FnOnce(&SecretStore) -> Result<T, SecretError> + Send + 'static,
+) -> Result<T, CommandError> {
+    tokio::task::spawn_blocking(move || operation(secrets::store()))
+        .await
+        .map_err(|error| CommandError::Internal {
+            message: format!("Keychain operation failed: {error}"),
+        })?
+        .map_err(CommandError::from)
+}
+
+/// Store a secret (e.g. a provider API key) in the OS keyring under `name`.
+///
+/// Config refers to the secret by `name`; the value is never written to disk
+/// by the app and cannot be read back through commands.
+#[tauri::command]
+pub async fn set_secret(
+    _audit: CommandAudit,
+    name: String,
+    value: String,
+) -> Result<(), CommandError> {
+    run_secret_operation(move |store| store.set(&name, &value)).await
+}
+
+/// Delete a stored secret. Returns whether it existed.
+#[tauri::command]
+pub async fn delete_secret(_audit: CommandAudit, name: String) -> Result<bool, CommandError> {
+    run_secret_operation(move |store| store.delete(&name)).await
+}
+
+/// Whether a secret is stored under `name`.
+#[tauri::command]
+pub async fn has_secret(_audit: CommandAudit, name: String) -> Result<bool, CommandError> {
+    run_secret_operation(move |store| store.has(&name)).await
+}
+
 // ============================================================================
 // AUDIO COMMANDS
 // ============================================================================
@@ -266,15 +604,13 @@ pub struct AudioDevice {
 /// List available audio input devices.
 #[tauri::command]
 pub async fn list_audio_devices(
+    _audit: CommandAudit,
     integration_state: tauri::State<'_, IntegrationState>,
     device_cache: tauri::State<'_, crate::tray::TrayDeviceCache>,
     app: tauri::AppHandle,
 ) -> Result<Vec<AudioDevice>, CommandError> {
     let manager = integration_state.0.read().await;
-    let devices = manager
-        .list_audio_devices()
-        .await
-        .map_err(|message| CommandError::Audio { message })?;
+    let devices = manager.list_audio_devices().await?;
 
     // Update the shared tray device cache so the tray menu stays current.
     device_cache.update(
@@ -309,15 +645,13 @@ pub async fn list_audio_devices(
 /// Set the audio input device.
 #[tauri::command]
 pub async fn set_audio_device(
+    _audit: CommandAudit,
     integration_state: tauri::State<'_, IntegrationState>,
     device_uid: Option<String>,
     app: tauri::AppHandle,
 ) -> Result<String, CommandError> {
     let manager = integration_state.0.read().await;
-    let active_device_uid = manager
-        .set_audio_device(device_uid.clone())
-        .await
-        .map_err(|message| CommandError::Audio { message })?;
+    let active_device_uid = manager.set_audio_device(device_uid.clone()).await?;
 
     // Persist the sidecar-confirmed device UID (which may differ from the request
     // if the sidecar fell back to a different device). Fall back to the requested
@@ -331,9 +665,54 @@ pub async fn set_audio_device(
     Ok(confirmed_uid.unwrap_or_else(|| "default".to_string()))
 }
 
+/// Get the input gain of a device (`None` = the active device).
+#[tauri::command]
+pub async fn get_input_gain(
+    _audit: CommandAudit,
+    integration_state: tauri::State<'_, IntegrationState>,
+    device_uid: Option<String>,
+) -> Result<InputGain, CommandError> {
+    let manager = integration_state.0.read().await;
+    manager
+        .get_input_gain(device_uid)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Set the input gain of a device (`None` = the active device). Applies
+/// immediately and is saved for future recordings from that device.
+#[tauri::command]
+pub async fn set_input_gain(
+    _audit: CommandAudit,
+    integration_state: tauri::State<'_, IntegrationState>,
+    device_uid: Option<String>,
+    gain_db: f64,
+) -> Result<InputGain, CommandError> {
+    let gain_db = gain_db.clamp(
+        *config::INPUT_GAIN_DB_RANGE.start(),
+        *config::INPUT_GAIN_DB_RANGE.end(),
+    );
+    let manager = integration_state.0.read().await;
+    let gain = manager.set_input_gain(device_uid, gain_db).await?;
+
+    // Persist under the UID the sidecar resolved, so a gain set for the
+    // default device follows that device rather than "default".
+    if let Some(uid) = gain.device_uid.clone() {
+        let mut app_config = config::load_config();
+        if gain.gain_db == 0.0 {
+            app_config.audio.input_gains.remove(&uid);
+        } else {
+            app_config.audio.input_gains.insert(uid, gain.gain_db);
+        }
+        config::save_config(&app_config)?;
+    }
+    Ok(gain)
+}
+
 /// Start microphone test (for level visualization).
 #[tauri::command]
 pub async fn start_mic_test(
+    _audit: CommandAudit,
     integration_state: tauri::State<'_, IntegrationState>,
 ) -> Result<(), CommandError> {
     let device_uid = config::load_config().audio.device_uid;
@@ -341,19 +720,17 @@ pub async fn start_mic_test(
     manager
         .start_mic_test(device_uid)
         .await
-        .map_err(|message| CommandError::Audio { message })
+        .map_err(CommandError::from)
 }
 
 /// Stop microphone test.
 #[tauri::command]
 pub async fn stop_mic_test(
+    _audit: CommandAudit,
     integration_state: tauri::State<'_, IntegrationState>,
 ) -> Result<(), CommandError> {
     let manager = integration_state.0.read().await;
-    manager
-        .stop_mic_test()
-        .await
-        .map_err(|message| CommandError::Audio { message })
+    manager.stop_mic_test().await.map_err(CommandError::from)
 }
 
 // ============================================================================
@@ -404,6 +781,9 @@ pub struct ModelCatalogEntry {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub license_spdx: Option<String>,
     pub manifest_path: String,
+    /// Parameters applied on initialization unless overridden in settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommended_params: Option<RecommendedParams>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -475,7 +855,6 @@ fn model_catalog_from_manifest_str(manifest_json: &str) -> Vec<ModelCatalogEntry
 
     vec![ModelCatalogEntry {
         family: derive_model_family(&model_id),
-        model_id,
         display_name,
         description,
         supported_languages,
@@ -483,20 +862,20 @@ fn model_catalog_from_manifest_str(manifest_json: &str) -> Vec<ModelCatalogEntry
         size_bytes: manifest.total_size_bytes,
         license_spdx: manifest.license.and_then(|license| license.spdx_id),
         manifest_path: MODEL_MANIFEST_PATH.to_string(),
+        recommended_params: model_defaults::recommended_params(&model_id),
+        model_id,
     }]
 }
 
 /// Get model status.
 #[tauri::command]
 pub async fn get_model_status(
+    _audit: CommandAudit,
     integration_state: tauri::State<'_, IntegrationState>,
     model_id: Option<String>,
 ) -> Result<ModelStatus, CommandError> {
     let manager = integration_state.0.read().await;
-    let status = manager
-        .query_model_status(model_id)
-        .await
-        .map_err(|message| CommandError::SidecarIpc { message })?;
+    let status = manager.query_model_status(model_id).await?;
 
     Ok(map_sidecar_model_status(status))
 }
@@ -530,7 +909,9 @@ fn map_sidecar_model_status(status: SidecarModelStatus) -> ModelStatus {
 
 /// Get available model catalog entries for model selection UI.
 #[tauri::command]
-pub async fn get_model_catalog() -> Result<Vec<ModelCatalogEntry>, CommandError> {
+pub async fn get_model_catalog(
+    _audit: CommandAudit,
+) -> Result<Vec<ModelCatalogEntry>, CommandError> {
     let _default_model_id = model_defaults::default_model_id();
     // Phase 4 catalog file is not yet available; fail-soft to manifest-backed single entry.
     Ok(model_catalog_from_manifest_str(MODEL_MANIFEST_JSON))
@@ -539,20 +920,30 @@ pub async fn get_model_catalog() -> Result<Vec<ModelCatalogEntry>, CommandError>
 /// Download the ASR model.
 #[tauri::command]
 pub async fn download_model(
+    _audit: CommandAudit,
     integration_state: tauri::State<'_, IntegrationState>,
     model_id: Option<String>,
     force: Option<bool>,
 ) -> Result<(), CommandError> {
+    if let Some(model_id) = model_id.as_deref() {
+        if !policy::current().is_model_allowed(model_id) {
+            return Err(PolicyViolation {
+                fields: vec!["model.model_id".to_string()],
+            }
+            .into());
+        }
+    }
     let manager = integration_state.0.read().await;
     manager
         .download_model(model_id, force)
         .await
-        .map_err(|message| CommandError::Model { message })
+        .map_err(CommandError::from)
 }
 
 /// Purge model cache.
 #[tauri::command]
 pub async fn purge_model_cache(
+    _audit: CommandAudit,
     integration_state: tauri::State<'_, IntegrationState>,
     model_id: Option<String>,
 ) -> Result<(), CommandError> {
@@ -560,19 +951,479 @@ pub async fn purge_model_cache(
     manager
         .purge_model_cache(model_id)
         .await
-        .map_err(map_purge_model_cache_error)
+        .map_err(CommandError::from)
 }
 
-/// Manually restart sidecar process.
+/// Transcribe one recording or WAV file with several downloaded models and
+/// return their texts, timings, and word differences side by side.
 #[tauri::command]
-pub async fn restart_sidecar(
+pub async fn compare_models(
+    _audit: CommandAudit,
     integration_state: tauri::State<'_, IntegrationState>,
+    session_id: Option<String>,
+    audio_path: Option<String>,
+    model_ids: Vec<String>,
+) -> Result<ModelComparison, CommandError> {
+    let audio = ComparisonAudio::from_args(session_id, audio_path)
+        .map_err(IntegrationError::InvalidInput)?;
+    let model_ids =
+        model_compare::normalize_model_ids(model_ids).map_err(IntegrationError::InvalidInput)?;
+    let manager = integration_state.0.read().await;
+    manager
+        .compare_models(audio, model_ids)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Transcribe a WAV file with the loaded model, copy the text to the
+/// clipboard, and add it to history marked with the file name.
+#[tauri::command]
+pub async fn transcribe_file(
+    _audit: CommandAudit,
+    app: tauri::AppHandle,
+    path: String,
+) -> Result<TranscriptEntry, CommandError> {
+    let path = PathBuf::from(path);
+    let info = match file_transcription::inspect_audio_file(&path) {
+        Ok(info) => info,
+        Err(message) => {
+            let file_name = file_transcription::file_name(&path);
+            emit_file_transcription_progress(
+                &app,
+                &file_name,
+                FileTranscriptionPhase::Failed,
+                None,
+                Some(&message),
+            );
+            return Err(IntegrationError::InvalidInput(message).into());
+        }
+    };
+    emit_file_transcription_progress(
+        &app,
+        &info.file_name,
+        FileTranscriptionPhase::Queued,
+        None,
+        None,
+    );
+    transcribe_audio_file(&app, &path, info).await
+}
+
+/// Transcribe files dropped onto the main window, one after another.
+///
+/// Every file is validated up front so rejected ones are reported at once
+/// instead of after the files queued before them.
+pub async fn transcribe_dropped_files(app: tauri::AppHandle, paths: Vec<PathBuf>) {
+    let mut accepted = Vec::with_capacity(paths.len());
+    for path in paths {
+        match file_transcription::inspect_audio_file(&path) {
+            Ok(info) => {
+                emit_file_transcription_progress(
+                    &app,
+                    &info.file_name,
+                    FileTranscriptionPhase::Queued,
+                    None,
+                    None,
+                );
+                accepted.push((path, info));
+            }
+            Err(message) => {
+                log::warn!("Rejected dropped file: {}", message);
+                emit_file_transcription_progress(
+                    &app,
+                    &file_transcription::file_name(&path),
+                    FileTranscriptionPhase::Failed,
+                    None,
+                    Some(&message),
+                );
+            }
+        }
+    }
+
+    for (path, info) in accepted {
+        if let Err(error) = transcribe_audio_file(&app, &path, info).await {
+            log::warn!("Dropped file transcription failed: {}", error);
+        }
+    }
+}
+
+async fn transcribe_audio_file(
+    app: &tauri::AppHandle,
+    path: &Path,
+    info: AudioFileInfo,
+) -> Result<TranscriptEntry, CommandError> {
+    let _slot = file_transcription::acquire_transcribe_slot().await;
+    emit_file_transcription_progress(
+        app,
+        &info.file_name,
+        FileTranscriptionPhase::Transcribing,
+        None,
+        None,
+    );
+
+    let result = {
+        let integration_state = app.state::<IntegrationState>();
+        let manager = integration_state.0.read().await;
+        manager.transcribe_file(path).await
+    };
+    let transcript = match result {
+        Ok(transcript) if transcript.final_text.trim().is_empty() => Err(
+            IntegrationError::InvalidInput(format!("{}: no speech found", info.file_name)),
+        ),
+        result => result,
+    };
+    let transcript = match transcript {
+        Ok(transcript) => transcript,
+        Err(error) => {
+            emit_file_transcription_progress(
+                app,
+                &info.file_name,
+                FileTranscriptionPhase::Failed,
+                None,
+                Some(&error.to_string()),
+            );
+            return Err(error.into());
+        }
+    };
+
+    let injection_result = match crate::injection::set_clipboard_public(&transcript.final_text) {
+        Ok(()) => HistoryInjectionResult::ClipboardOnly {
+            reason: "Transcribed from file".to_string(),
+        },
+        Err(message) => HistoryInjectionResult::Error { message },
+    };
+    let mut entry = TranscriptEntry::new(
+        transcript.final_text,
+        info.duration_ms,
+        transcript.transcription_ms,
+        injection_result,
+    )
+    .with_asr_metadata(transcript.language, transcript.confidence)
+    .with_source_file(info.file_name.clone())
+    .with_segments(transcript.segments)
+    .with_words(transcript.words)
+    .with_unfiltered_text(transcript.unfiltered_text);
+    entry.raw_text = transcript.raw_text;
+
+    app.state::<TranscriptHistory>().push(entry.clone());
+    let _ = app.emit(
+        EVENT_TRANSCRIPT_COMPLETE,
+        crate::event_seq::payload_with_next_seq(transcript_complete_event_payload(&entry)),
+    );
+    emit_tray_update(app, "history_changed");
+    emit_file_transcription_progress(
+        app,
+        &info.file_name,
+        FileTranscriptionPhase::Complete,
+        Some(entry.id),
+        None,
+    );
+    Ok(entry)
+}
+
+fn emit_file_transcription_progress(
+    app: &tauri::AppHandle,
+    file_name: &str,
+    phase: FileTranscriptionPhase,
+    entry_id: Option<Uuid>,
+    error: Option<&str>,
+) {
+    let _ = app.emit(
+        file_transcription::EVENT_FILE_TRANSCRIPTION_PROGRESS,
+        crate::event_seq::payload_with_next_seq(serde_json::json!(
+            file_transcription::progress_payload(file_name, phase, entry_id, error)
+        )),
+    );
+}
+
+/// Get the license blocking a model download, if any.
+#[tauri::command]
+pub fn get_pending_license(_audit: CommandAudit) -> Option<PendingLicense> {
+    model_license::global_licenses().pending()
+}
+
+/// Accept the pending license for `model_id` and continue the download.
+#[tauri::command]
+pub async fn accept_model_license(
+    _audit: CommandAudit,
+    integration_state: tauri::State<'_, IntegrationState>,
+    model_id: String,
 ) -> Result<(), CommandError> {
+    if !policy::current().is_model_allowed(&model_id) {
+        return Err(PolicyViolation {
+            fields: vec!["model.model_id".to_string()],
+        }
+        .into());
+    }
+    model_license::global_licenses()
+        .accept(&model_id)
+        .map_err(|e| CommandError::Config {
+            message: e.to_string(),
+        })?;
     let manager = integration_state.0.read().await;
     manager
-        .restart_sidecar()
+        .download_model(Some(model_id), None)
         .await
-        .map_err(|message| CommandError::SidecarIpc { message })
+        .map_err(CommandError::from)
+}
+
+/// Manually restart sidecar process.
+#[tauri::command]
+pub async fn restart_sidecar(
+    _audit: CommandAudit,
+    integration_state: tauri::State<'_, IntegrationState>,
+) -> Result<(), CommandError> {
+    let manager = integration_state.0.read().await;
+    manager.restart_sidecar().await.map_err(CommandError::from)
+}
+
+fn emit_sidecar_update_status(
+    app: &tauri::AppHandle,
+    phase: SidecarUpdatePhase,
+    version: &str,
+    message: Option<&str>,
+) {
+    let _ = app.emit(
+        sidecar_update::EVENT_SIDECAR_UPDATE_STATUS,
+        crate::event_seq::payload_with_next_seq(sidecar_update::update_status_payload(
+            phase, version, message,
+        )),
+    );
+}
+
+/// Check the sidecar release manifest for a newer build.
+#[tauri::command]
+pub async fn check_sidecar_update(_audit: CommandAudit) -> Result<SidecarUpdateInfo, CommandError> {
+    let info = tokio::task::spawn_blocking(|| -> Result<_, SidecarUpdateError> {
+        let manifest = sidecar_update::fetch_manifest()?;
+        let current_version = sidecar_update::store().current_version();
+        Ok(sidecar_update::check_manifest(&manifest, &current_version))
+    })
+    .await
+    .map_err(|error| CommandError::Internal {
+        message: format!("Sidecar update check failed: {error}"),
+    })??;
+    Ok(info)
+}
+
+/// Download, verify, and switch to the newest sidecar build, then restart
+/// the sidecar on it.
+///
+/// Progress is reported through `sidecar:update_status`. Returns the version
+/// now running; if the new build fails its first start the previous one is
+/// restored and an error is returned.
+#[tauri::command]
+pub async fn apply_sidecar_update(
+    _audit: CommandAudit,
+    app: tauri::AppHandle,
+    integration_state: tauri::State<'_, IntegrationState>,
+) -> Result<String, CommandError> {
+    let current_version = sidecar_update::store().current_version();
+    emit_sidecar_update_status(&app, SidecarUpdatePhase::Checking, &current_version, None);
+
+    let download_app = app.clone();
+    let installed = tokio::task::spawn_blocking(move || -> Result<_, SidecarUpdateError> {
+        let manifest = sidecar_update::fetch_manifest()?;
+        if !release_download::is_newer(&manifest.version, &current_version) {
+            return Ok(None);
+        }
+        sidecar_update::download_and_activate(&manifest, |phase| {
+            emit_sidecar_update_status(&download_app, phase, &manifest.version, None)
+        })
+        .map(Some)
+    })
+    .await
+    .map_err(|error| CommandError::Internal {
+        message: format!("Sidecar update failed: {error}"),
+    })?;
+
+    let installed = match installed {
+        Ok(Some(installed)) => installed,
+        Ok(None) => {
+            let version = sidecar_update::store().current_version();
+            emit_sidecar_update_status(
+                &app,
+                SidecarUpdatePhase::Ready,
+                &version,
+                Some("Sidecar is up to date"),
+            );
+            return Ok(version);
+        }
+        Err(error) => {
+            let message = error.to_string();
+            let version = sidecar_update::store().current_version();
+            emit_sidecar_update_status(&app, SidecarUpdatePhase::Failed, &version, Some(&message));
+            return Err(error.into());
+        }
+    };
+
+    emit_sidecar_update_status(
+        &app,
+        SidecarUpdatePhase::Restarting,
+        &installed.version,
+        None,
+    );
+    let manager = integration_state.0.read().await;
+    manager.restart_sidecar().await?;
+
+    let running_version = sidecar_update::store().current_version();
+    if running_version != installed.version {
+        return Err(CommandError::Internal {
+            message: format!(
+                "Sidecar {} failed to start; restored {}",
+                installed.version, running_version
+            ),
+        });
+    }
+    emit_sidecar_update_status(&app, SidecarUpdatePhase::Ready, &running_version, None);
+    Ok(running_version)
+}
+
+fn emit_app_update_progress(
+    app: &tauri::AppHandle,
+    phase: AppUpdatePhase,
+    version: &str,
+    downloaded_bytes: Option<u64>,
+    total_bytes: Option<u64>,
+    message: Option<&str>,
+) {
+    let _ = app.emit(
+        app_update::EVENT_APP_UPDATE_PROGRESS,
+        crate::event_seq::payload_with_next_seq(app_update::progress_payload(
+            phase,
+            version,
+            downloaded_bytes,
+            total_bytes,
+            message,
+        )),
+    );
+}
+
+/// Check the app release manifest for a newer version rolled out to this
+/// installation.
+#[tauri::command]
+pub async fn check_app_update(_audit: CommandAudit) -> Result<AppUpdateInfo, CommandError> {
+    let info = tokio::task::spawn_blocking(|| -> Result<_, AppUpdateError> {
+        let manifest = app_update::fetch_manifest()?;
+        let store = app_update::store();
+        let current_version = app_update::current_version();
+        let staged_version = store.staged(current_version).map(|staged| staged.version);
+        Ok(app_update::check_manifest(
+            &manifest,
+            current_version,
+            store.rollout_bucket(&manifest.version),
+            staged_version,
+        ))
+    })
+    .await
+    .map_err(|error| CommandError::Internal {
+        message: format!("App update check failed: {error}"),
+    })??;
+    Ok(info)
+}
+
+/// Download and verify the newest installer so it is ready to install.
+///
+/// Progress is reported through `app:update_progress`. Returns the staged
+/// version.
+#[tauri::command]
+pub async fn download_app_update(
+    _audit: CommandAudit,
+    app: tauri::AppHandle,
+) -> Result<String, CommandError> {
+    let progress_app = app.clone();
+    let result = tokio::task::spawn_blocking(move || -> Result<_, AppUpdateError> {
+        let manifest = app_update::fetch_manifest()?;
+        app_update::download(&manifest, |phase, downloaded_bytes, total_bytes| {
+            emit_app_update_progress(
+                &progress_app,
+                phase,
+                &manifest.version,
+                downloaded_bytes,
+                total_bytes,
+                None,
+            )
+        })
+    })
+    .await
+    .map_err(|error| CommandError::Internal {
+        message: format!("App update download failed: {error}"),
+    })?;
+
+    match result {
+        Ok(staged) => {
+            emit_app_update_progress(
+                &app,
+                AppUpdatePhase::Staged,
+                &staged.version,
+                None,
+                None,
+                None,
+            );
+            Ok(staged.version)
+        }
+        Err(error) => {
+            let message = error.to_string();
+            emit_app_update_progress(
+                &app,
+                AppUpdatePhase::Failed,
+                app_update::current_version(),
+                None,
+                None,
+                Some(&message),
+            );
+            Err(error.into())
+        }
+    }
+}
+
+/// Launch the staged installer and quit (or restart) the app.
+///
+/// Refused while a recording or transcription is in progress so no dictation
+/// is lost.
+#[tauri::command]
+pub fn install_app_update(
+    _audit: CommandAudit,
+    app: tauri::AppHandle,
+    state_manager: tauri::State<Arc<AppStateManager>>,
+) -> Result<(), CommandError> {
+    if let Some(reason) = app_update::install_blocked_reason(state_manager.get()) {
+        return Err(AppUpdateError::Busy(reason).into());
+    }
+    let staged = app_update::store()
+        .staged(app_update::current_version())
+        .ok_or(AppUpdateError::NothingStaged)?;
+
+    emit_app_update_progress(
+        &app,
+        AppUpdatePhase::Installing,
+        &staged.version,
+        None,
+        None,
+        None,
+    );
+    let action = match app_update::install(&staged) {
+        Ok(action) => action,
+        Err(error) => {
+            let message = error.to_string();
+            emit_app_update_progress(
+                &app,
+                AppUpdatePhase::Failed,
+                &staged.version,
+                None,
+                None,
+                Some(&message),
+            );
+            return Err(error.into());
+        }
+    };
+    // Restarting does not pass through the exit handler that writes a
+    // debounced config save.
+    config::flush_config()?;
+    match action {
+        InstallAction::Restart => app.restart(),
+        InstallAction::Exit => app.exit(0),
+    }
+    Ok(())
 }
 
 // ============================================================================
@@ -581,13 +1432,51 @@ pub async fn restart_sidecar(
 
 /// Get transcript history (newest first).
 #[tauri::command]
-pub fn get_transcript_history(history: tauri::State<TranscriptHistory>) -> Vec<TranscriptEntry> {
+pub fn get_transcript_history(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+) -> Vec<TranscriptEntry> {
     history.all()
 }
 
+/// Word counts and recorded audio time per day, derived from history.
+#[tauri::command]
+pub fn get_usage_stats(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+) -> crate::stats::UsageStats {
+    crate::stats::usage_stats(&history.all())
+}
+
+/// Entry counts and storage footprint of transcript history.
+#[tauri::command]
+pub fn get_history_stats(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+) -> HistoryStats {
+    history.stats()
+}
+
+/// Icon of the app a transcript went to, by `injection_target.app_id`.
+///
+/// Resolved on first request and cached; `None` when the platform has no
+/// icon for the app.
+#[tauri::command]
+pub async fn get_app_icon(
+    _audit: CommandAudit,
+    app_id: String,
+) -> Result<Option<AppIcon>, CommandError> {
+    tokio::task::spawn_blocking(move || app_icon::icon_for(&app_id))
+        .await
+        .map_err(|error| CommandError::Internal {
+            message: format!("Resolving the app icon failed: {error}"),
+        })
+}
+
 /// Copy a specific transcript to clipboard by ID.
 #[tauri::command]
 pub fn copy_transcript(
+    _audit: CommandAudit,
     history: tauri::State<TranscriptHistory>,
     id: String,
 ) -> Result<(), CommandError> {
@@ -607,6 +1496,7 @@ pub fn copy_transcript(
 /// Copy the most recent transcript to clipboard.
 #[tauri::command]
 pub fn copy_last_transcript(
+    _audit: CommandAudit,
     history: tauri::State<TranscriptHistory>,
 ) -> Result<Option<String>, CommandError> {
     if history.is_empty() {
@@ -623,14 +1513,57 @@ pub fn copy_last_transcript(
 
 /// Clear transcript history.
 #[tauri::command]
-pub fn clear_history(history: tauri::State<TranscriptHistory>, app: tauri::AppHandle) {
+pub fn clear_history(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+    app: tauri::AppHandle,
+) {
     history.clear();
     emit_tray_update(&app, "history_changed");
 }
 
+/// Remove the history entries matching `filter` (age, app, tag, pinned).
+///
+/// Returns the number of entries removed.
+#[tauri::command]
+pub fn clear_history_filtered(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+    app: tauri::AppHandle,
+    filter: HistoryClearFilter,
+) -> usize {
+    let removed = history.clear_matching(&filter, Utc::now());
+    if removed > 0 {
+        emit_tray_update(&app, "history_changed");
+    }
+    removed
+}
+
+/// Pin or unpin a transcript so filtered clears and retention keep it.
+#[tauri::command]
+pub fn set_transcript_pinned(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+    app: tauri::AppHandle,
+    entry_id: String,
+    pinned: bool,
+) -> Result<(), CommandError> {
+    let uuid = Uuid::parse_str(&entry_id).map_err(|_| CommandError::Internal {
+        message: "Invalid transcript ID".to_string(),
+    })?;
+    if !history.set_pinned(uuid, pinned) {
+        return Err(CommandError::Internal {
+            message: "Transcript not found".to_string(),
+        });
+    }
+    emit_tray_update(&app, "history_changed");
+    Ok(())
+}
+
 /// Export transcript history to Markdown or CSV.
 #[tauri::command]
 pub fn export_history(
+    _audit: CommandAudit,
     history: tauri::State<TranscriptHistory>,
     format: String,
 ) -> Result<String, CommandError> {
@@ -638,6 +1571,337 @@ pub fn export_history(
     Ok(output.to_string_lossy().to_string())
 }
 
+/// Export a single transcript as SRT or WebVTT subtitles.
+#[tauri::command]
+pub fn export_subtitles(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+    entry_id: String,
+    format: String,
+) -> Result<String, CommandError> {
+    let uuid = Uuid::parse_str(&entry_id).map_err(|_| CommandError::Internal {
+        message: "Invalid transcript ID".to_string(),
+    })?;
+    let output = history.export_subtitles(uuid, &format)?;
+    Ok(output.to_string_lossy().to_string())
+}
+
+/// Send a transcript to another app via the OS share sheet.
+///
+/// Falls back to an email draft, then the clipboard; returns the method used.
+#[tauri::command]
+pub async fn share_history_entry(
+    _audit: CommandAudit,
+    history: tauri::State<'_, TranscriptHistory>,
+    entry_id: String,
+) -> Result<ShareMethod, CommandError> {
+    let uuid = Uuid::parse_str(&entry_id).map_err(|_| CommandError::Internal {
+        message: "Invalid transcript ID".to_string(),
+    })?;
+    let text = history
+        .get(uuid)
+        .map(|entry| entry.text)
+        .ok_or_else(|| CommandError::Internal {
+            message: "Transcript not found".to_string(),
+        })?;
+
+    // The share helpers can take a few seconds to start.
+    tokio::task::spawn_blocking(move || share::share_text(&text))
+        .await
+        .map_err(|error| CommandError::Internal {
+            message: format!("Share task failed: {error}"),
+        })?
+        .map_err(CommandError::from)
+}
+
+/// Reveal the pre-filter text of a transcript, if it was kept.
+#[tauri::command]
+pub fn get_unfiltered_transcript(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+    entry_id: String,
+) -> Result<Option<String>, CommandError> {
+    let uuid = Uuid::parse_str(&entry_id).map_err(|_| CommandError::Internal {
+        message: "Invalid transcript ID".to_string(),
+    })?;
+    Ok(history.get(uuid).and_then(|entry| entry.unfiltered_text))
+}
+
+/// Show what post-processing changed in a transcript: diff spans from its
+/// raw ASR text to its final text, each naming the replacement rule or
+/// learned spelling responsible where that can be told.
+#[tauri::command]
+pub fn get_transcript_diff(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+    entry_id: String,
+) -> Result<TranscriptDiff, CommandError> {
+    let uuid = Uuid::parse_str(&entry_id).map_err(|_| CommandError::Internal {
+        message: "Invalid transcript ID".to_string(),
+    })?;
+    let entry = history.get(uuid).ok_or_else(|| CommandError::Internal {
+        message: "Transcript not found".to_string(),
+    })?;
+
+    let app_config = config::load_config();
+    let rules = replacement_rules::rules_for_sidecar(
+        &app_config.replacements,
+        &app_config.replacement_groups,
+    );
+    let learn = app_config.vocabulary.learn_capitalization;
+    Ok(transcript_diff::transcript_diff(
+        entry.id,
+        &entry.raw_text,
+        &entry.final_text,
+        &rules,
+        |text| {
+            if learn {
+                learned_dictionary::global_dictionary().apply(text)
+            } else {
+                text.to_string()
+            }
+        },
+    ))
+}
+
+/// Indices into a transcript's `words` whose confidence is below `threshold`
+/// (default [`DEFAULT_LOW_CONFIDENCE_THRESHOLD`]), for highlighting.
+#[tauri::command]
+pub fn get_low_confidence_words(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+    entry_id: String,
+    threshold: Option<f32>,
+) -> Result<Vec<usize>, CommandError> {
+    let uuid = Uuid::parse_str(&entry_id).map_err(|_| CommandError::Internal {
+        message: "Invalid transcript ID".to_string(),
+    })?;
+    let entry = history.get(uuid).ok_or_else(|| CommandError::Internal {
+        message: "Transcript not found".to_string(),
+    })?;
+    let threshold = threshold
+        .unwrap_or(DEFAULT_LOW_CONFIDENCE_THRESHOLD)
+        .clamp(0.0, 1.0);
+    Ok(entry.low_confidence_word_indices(threshold))
+}
+
+/// Replace a transcript's text with a user edit.
+///
+/// Capitalization fixes in the edit feed the learned dictionary.
+#[tauri::command]
+pub fn edit_transcript(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+    app: tauri::AppHandle,
+    entry_id: String,
+    text: String,
+) -> Result<(), CommandError> {
+    let uuid = Uuid::parse_str(&entry_id).map_err(|_| CommandError::Internal {
+        message: "Invalid transcript ID".to_string(),
+    })?;
+    let Some(previous) = history.update_text(uuid, text.clone()) else {
+        return Err(CommandError::Internal {
+            message: "Transcript not found".to_string(),
+        });
+    };
+
+    if config::load_config().vocabulary.learn_capitalization {
+        learned_dictionary::learn(learned_dictionary::global_dictionary(), &previous, &text);
+    }
+    emit_tray_update(&app, "history_changed");
+    Ok(())
+}
+
+/// Search transcript history (newest first).
+///
+/// `query` matches the transcript text and metadata case-insensitively;
+/// every tag in `tags` must be present on an entry.
+#[tauri::command]
+pub fn search_transcript_history(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+    query: Option<String>,
+    tags: Option<Vec<String>>,
+) -> Vec<TranscriptEntry> {
+    history.search(query.as_deref().unwrap_or(""), &tags.unwrap_or_default())
+}
+
+/// Set the name and tags of an existing transcript.
+///
+/// Returns the normalized label that was saved.
+#[tauri::command]
+pub fn set_transcript_label(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+    app: tauri::AppHandle,
+    entry_id: String,
+    name: Option<String>,
+    tags: Vec<String>,
+) -> Result<SessionLabel, CommandError> {
+    let uuid = Uuid::parse_str(&entry_id).map_err(|_| CommandError::Internal {
+        message: "Invalid transcript ID".to_string(),
+    })?;
+    let label = SessionLabel::normalized(name, tags);
+    if !history.update_label(uuid, label.clone()) {
+        return Err(CommandError::Internal {
+            message: "Transcript not found".to_string(),
+        });
+    }
+    emit_tray_update(&app, "history_changed");
+    Ok(label)
+}
+
+/// Get the label applied to upcoming dictation sessions.
+#[tauri::command]
+pub fn get_session_label(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+) -> SessionLabel {
+    history.active_label()
+}
+
+/// Set the name and tags applied to upcoming dictation sessions.
+///
+/// An empty name and tag list clears the label. Returns the normalized label.
+#[tauri::command]
+pub fn set_session_label(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+    name: Option<String>,
+    tags: Vec<String>,
+) -> SessionLabel {
+    let label = SessionLabel::normalized(name, tags);
+    history.set_active_label(label.clone());
+    label
+}
+
+/// Suggest recently used tags starting with `prefix`.
+#[tauri::command]
+pub fn get_tag_suggestions(
+    _audit: CommandAudit,
+    history: tauri::State<TranscriptHistory>,
+    prefix: Option<String>,
+    limit: Option<usize>,
+) -> Vec<String> {
+    history.tag_suggestions(prefix.as_deref().unwrap_or(""), limit)
+}
+
+// ============================================================================
+// VOCABULARY COMMANDS
+// ============================================================================
+
+/// Get the custom vocabulary (hotword) list.
+#[tauri::command]
+pub fn get_vocabulary(_audit: CommandAudit) -> Vec<String> {
+    config::load_config().vocabulary.terms
+}
+
+/// Replace the custom vocabulary list.
+///
+/// Returns the normalized list that was saved.
+#[tauri::command]
+pub fn set_vocabulary(
+    _audit: CommandAudit,
+    terms: Vec<String>,
+) -> Result<Vec<String>, CommandError> {
+    let normalized = vocabulary::normalize_terms(&terms)?;
+    let mut config = config::load_config();
+    config.vocabulary.terms = normalized.clone();
+    config::save_config(&config)?;
+    Ok(normalized)
+}
+
+/// Merge terms from a text file (one per line or comma-separated) into the vocabulary.
+/// Files over [`vocabulary::MAX_VOCABULARY_FILE_BYTES`] are refused unread.
+///
+/// Returns the normalized list that was saved.
+#[tauri::command]
+pub fn import_vocabulary(_audit: CommandAudit, path: String) -> Result<Vec<String>, CommandError> {
+    let read_error = |e: std::io::Error| CommandError::Config {
+        message: format!("Failed to read vocabulary file: {}", e),
+    };
+    vocabulary::check_file_size(std::fs::metadata(&path).map_err(read_error)?.len())?;
+    let contents = std::fs::read_to_string(&path).map_err(read_error)?;
+
+    let mut config = config::load_config();
+    let mut merged = config.vocabulary.terms.clone();
+    merged.extend(vocabulary::parse_vocabulary_file(&contents));
+    let normalized = vocabulary::normalize_terms(&merged)?;
+
+    config.vocabulary.terms = normalized.clone();
+    config::save_config(&config)?;
+    Ok(normalized)
+}
+
+/// Get words whose capitalization was learned from user corrections.
+#[tauri::command]
+pub fn get_learned_dictionary(_audit: CommandAudit) -> Vec<LearnedWord> {
+    learned_dictionary::global_dictionary().entries()
+}
+
+/// Forget a learned word so its capitalization is no longer applied.
+///
+/// Returns whether the word was in the dictionary.
+#[tauri::command]
+pub fn remove_learned_word(_audit: CommandAudit, word: String) -> Result<bool, CommandError> {
+    learned_dictionary::global_dictionary()
+        .remove(&word)
+        .map_err(|e| CommandError::Config {
+            message: format!("Failed to update learned dictionary: {}", e),
+        })
+}
+
+/// Get learned paste delays and their samples, sorted by app id.
+#[tauri::command]
+pub fn get_paste_calibration(_audit: CommandAudit) -> Vec<AppPasteCalibration> {
+    paste_calibration::global_calibration().entries()
+}
+
+/// Forget the learned paste delay of `app_id`, or of every app when omitted.
+///
+/// Returns how many apps were reset.
+#[tauri::command]
+pub fn reset_paste_calibration(
+    _audit: CommandAudit,
+    app_id: Option<String>,
+) -> Result<usize, CommandError> {
+    paste_calibration::global_calibration()
+        .reset(app_id.as_deref())
+        .map_err(|e| CommandError::Config {
+            message: format!("Failed to update paste calibration: {}", e),
+        })
+}
+
+/// Get exact-phrase shortcuts in creation order.
+#[tauri::command]
+pub fn get_phrase_shortcuts(_audit: CommandAudit) -> Vec<PhraseShortcut> {
+    phrase_shortcuts::global_shortcuts().entries()
+}
+
+/// Create a phrase shortcut, or update the one with `id`.
+///
+/// Secret values are referenced by the name given to `set_secret`; the value
+/// itself is only read from the keyring when the phrase is spoken.
+#[tauri::command]
+pub fn save_phrase_shortcut(
+    _audit: CommandAudit,
+    id: Option<Uuid>,
+    phrase: String,
+    value: ShortcutValue,
+    enabled: Option<bool>,
+) -> Result<PhraseShortcut, CommandError> {
+    let shortcuts = phrase_shortcuts::global_shortcuts();
+    Ok(shortcuts.save(id, &phrase, value, enabled.unwrap_or(true))?)
+}
+
+/// Delete a phrase shortcut. Returns whether it existed.
+///
+/// A referenced secret stays in the keyring; remove it with `delete_secret`.
+#[tauri::command]
+pub fn delete_phrase_shortcut(_audit: CommandAudit, id: Uuid) -> Result<bool, CommandError> {
+    Ok(phrase_shortcuts::global_shortcuts().remove(id)?)
+}
+
 // ============================================================================
 // HOTKEY COMMANDS
 // ============================================================================
@@ -649,32 +1913,95 @@ pub struct HotkeyStatus {
     pub copy_last: String,
     pub mode: String,
     pub registered: bool,
+    /// Hotkey presses ignored because the user was typing, since launch.
+    pub suppressed_while_typing: u64,
+    /// Active profile whose hotkeys are in effect, if it overrides the base set.
+    pub profile: Option<String>,
+    /// Bindings that share a key chord.
+    pub conflicts: Vec<String>,
 }
 
 /// Get current hotkey status.
 #[tauri::command]
-pub fn get_hotkey_status() -> HotkeyStatus {
+pub fn get_hotkey_status(_audit: CommandAudit) -> HotkeyStatus {
     let config = config::load_config();
+    let hotkeys = config.effective_hotkeys();
     HotkeyStatus {
-        primary: config.hotkeys.primary,
-        copy_last: config.hotkeys.copy_last,
-        mode: format!("{:?}", config.hotkeys.mode).to_lowercase(),
+        conflicts: hotkey::binding_conflicts(&hotkeys),
+        profile: config.hotkey_profile().map(str::to_string),
+        primary: hotkeys.primary,
+        copy_last: hotkeys.copy_last,
+        mode: format!("{:?}", hotkeys.mode).to_lowercase(),
         registered: false, // TODO: Track actual registration state
+        suppressed_while_typing: typing_guard::suppressed_activations(),
     }
 }
 
 /// Set hotkey bindings.
 #[tauri::command]
-pub fn set_hotkey(primary: String, copy_last: String) -> Result<(), CommandError> {
+pub fn set_hotkey(
+    _audit: CommandAudit,
+    primary: String,
+    copy_last: String,
+    integration_state: tauri::State<'_, IntegrationState>,
+) -> Result<(), CommandError> {
     let mut config = config::load_config();
     config.hotkeys.primary = primary;
     config.hotkeys.copy_last = copy_last;
     config::save_config(&config)?;
 
-    // TODO: Re-register hotkeys with the system
+    let manager_state = Arc::clone(&integration_state.0);
+    tauri::async_runtime::spawn(async move {
+        manager_state.read().await.reload_hotkeys().await;
+    });
     Ok(())
 }
 
+/// Check whether the recording hotkey leaks into the focused app.
+///
+/// Waits for the user to press the hotkey with the settings window focused;
+/// the press does not start a recording. The webview reports the keydown it
+/// receives through `report_hotkey_keydown`.
+#[tauri::command]
+pub async fn test_hotkey_leakage(_audit: CommandAudit) -> Result<HotkeyLeakReport, CommandError> {
+    let primary = config::load_config().effective_hotkeys().primary;
+    let hotkey = hotkey::parse_hotkey(&primary).map_err(|e| CommandError::Hotkey {
+        message: e.to_string(),
+    })?;
+    Ok(hotkey_leak::run_leak_test(&primary, hotkey.mods).await)
+}
+
+/// Report that the settings webview received the recording hotkey's keydown.
+#[tauri::command]
+pub fn report_hotkey_keydown(_audit: CommandAudit) {
+    hotkey_leak::observe_webview_keydown();
+}
+
+/// Practice run of the recording hotkey for onboarding.
+///
+/// Waits for the user to use the hotkey as the configured mode expects (hold
+/// and release, or press twice); the presses do not start a recording.
+#[tauri::command]
+pub async fn practice_hotkey(
+    _audit: CommandAudit,
+    state_manager: tauri::State<'_, Arc<AppStateManager>>,
+) -> Result<HotkeyPracticeReport, CommandError> {
+    if matches!(
+        state_manager.get(),
+        AppState::Recording | AppState::Transcribing
+    ) {
+        return Err(CommandError::ActionUnavailable {
+            message: "Finish the current dictation before practicing the hotkey".to_string(),
+            reason: "busy".to_string(),
+        });
+    }
+    let hotkeys = config::load_config().effective_hotkeys();
+    hotkey::parse_hotkey(&hotkeys.primary).map_err(|e| CommandError::Hotkey {
+        message: e.to_string(),
+    })?;
+    Ok(hotkey_practice::run_practice(&hotkeys.primary, hotkeys.mode).await)
+}
+
 // ============================================================================
 // REPLACEMENT COMMANDS
 // ============================================================================
@@ -707,22 +2034,7 @@ fn preview_replacement_local(
     let mut applied_rules_count = 0usize;
     for rule in rules {
         if rule.enabled {
-            let pattern = if rule.word_boundary {
-                if rule.kind == "regex" {
-                    format!(r"\b(?:{})\b", rule.pattern)
-                } else {
-                    format!(r"\b{}\b", regex::escape(&rule.pattern))
-                }
-            } else if rule.kind == "regex" {
-                rule.pattern.clone()
-            } else {
-                regex::escape(&rule.pattern)
-            };
-
-            match RegexBuilder::new(&pattern)
-                .case_insensitive(!rule.case_sensitive)
-                .build()
-            {
+            match replacement_rules::compile_rule(&rule) {
                 Ok(compiled) => {
                     let next = if rule.kind == "regex" {
                         compiled
@@ -757,31 +2069,121 @@ fn preview_replacement_local(
     }
 }
 
-#[allow(dead_code)]
-fn is_sidecar_unavailable_preview_error(message: &str) -> bool {
-    let normalized = message.to_ascii_lowercase();
-    normalized.contains("sidecar not connected") || normalized.contains("disconnected from sidecar")
+#[allow(dead_code)]
+fn is_sidecar_unavailable_preview_error(message: &str) -> bool {
+    let normalized = message.to_ascii_lowercase();
+    normalized.contains("sidecar not connected") || normalized.contains("disconnected from sidecar")
+}
+
+/// Get current replacement rules.
+#[tauri::command]
+pub fn get_replacement_rules(_audit: CommandAudit) -> Vec<ReplacementRule> {
+    let config = config::load_config();
+    config.replacements
+}
+
+/// Set replacement rules and push the active set to the sidecar.
+///
+/// Returns conflicting, shadowed, or otherwise invalid rules; they are
+/// saved regardless so the user can fix them in place.
+#[tauri::command]
+pub async fn set_replacement_rules(
+    _audit: CommandAudit,
+    integration_state: tauri::State<'_, IntegrationState>,
+    rules: Vec<ReplacementRule>,
+) -> Result<Vec<RuleIssue>, CommandError> {
+    let mut config = config::load_config();
+    config.replacements = rules;
+    config::save_config(&config)?;
+
+    let issues =
+        replacement_rules::validate_rules(&config.replacements, &config.replacement_groups);
+    let manager = integration_state.0.read().await;
+    manager.sync_replacement_rules(&config).await;
+    Ok(issues)
+}
+
+/// Temporary replacement rules and when they lapse.
+#[tauri::command]
+pub fn get_temporary_replacement_rules(_audit: CommandAudit) -> replacement_rules::TemporaryRules {
+    let config = config::load_config();
+    replacement_rules::temporary_rules().current(
+        config.replacement_groups.active_profile.as_deref(),
+        Utc::now(),
+    )
 }
 
-/// Get current replacement rules.
+/// Set session-scoped replacement rules that run ahead of the saved rules.
+///
+/// The rules are never written to config. They lapse when the app restarts,
+/// the active profile changes, or at `expires_at` (RFC 3339) if given.
 #[tauri::command]
-pub fn get_replacement_rules() -> Vec<ReplacementRule> {
+pub async fn set_temporary_replacement_rules(
+    _audit: CommandAudit,
+    integration_state: tauri::State<'_, IntegrationState>,
+    rules: Vec<ReplacementRule>,
+    expires_at: Option<String>,
+) -> Result<(), CommandError> {
+    let expires_at = expires_at
+        .map(|value| {
+            DateTime::parse_from_rfc3339(&value)
+                .map(|parsed| parsed.with_timezone(&Utc))
+                .map_err(|e| CommandError::Config {
+                    message: format!("Invalid expires_at '{}': {}", value, e),
+                })
+        })
+        .transpose()?;
+    if expires_at.is_some_and(|expires_at| expires_at <= Utc::now()) {
+        return Err(CommandError::Config {
+            message: "expires_at must be in the future".to_string(),
+        });
+    }
+    if let Some(issue) = replacement_rules::validate_rules(&rules, &Default::default())
+        .into_iter()
+        .find(|issue| issue.kind == replacement_rules::RuleIssueKind::InvalidPattern)
+    {
+        return Err(CommandError::Config {
+            message: issue.message,
+        });
+    }
+
     let config = config::load_config();
-    config.replacements
+    replacement_rules::temporary_rules().set(
+        rules,
+        expires_at,
+        config.replacement_groups.active_profile.clone(),
+    );
+    let manager = integration_state.0.read().await;
+    manager.sync_replacement_rules(&config).await;
+
+    if let Some(expires_at) = expires_at {
+        let manager_state = Arc::clone(&integration_state.0);
+        let delay = (expires_at - Utc::now()).to_std().unwrap_or_default();
+        tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let manager = manager_state.read().await;
+            manager.sync_replacement_rules(&config::load_config()).await;
+        });
+    }
+    Ok(())
 }
 
-/// Set replacement rules.
+/// Drop all temporary replacement rules.
 #[tauri::command]
-pub async fn set_replacement_rules(rules: Vec<ReplacementRule>) -> Result<(), CommandError> {
-    let mut config = config::load_config();
-    config.replacements = rules;
-    config::save_config(&config)?;
+pub async fn clear_temporary_replacement_rules(
+    _audit: CommandAudit,
+    integration_state: tauri::State<'_, IntegrationState>,
+) -> Result<(), CommandError> {
+    replacement_rules::temporary_rules().clear();
+    let manager = integration_state.0.read().await;
+    manager.sync_replacement_rules(&config::load_config()).await;
     Ok(())
 }
 
 /// Preview replacement result without saving.
 #[tauri::command]
 pub async fn preview_replacement(
+    _audit: CommandAudit,
     integration_state: tauri::State<'_, IntegrationState>,
     input: String,
     rules: Vec<ReplacementRule>,
@@ -802,20 +2204,18 @@ pub async fn preview_replacement(
             applied_rules_count: applied_rules_count.unwrap_or_default(),
             applied_presets: applied_presets.unwrap_or_default(),
         }),
-        Err(message) => Err(CommandError::SidecarIpc { message }),
+        Err(error) => Err(error.into()),
     }
 }
 
 /// Get available presets.
 #[tauri::command]
 pub async fn get_available_presets(
+    _audit: CommandAudit,
     integration_state: tauri::State<'_, IntegrationState>,
 ) -> Result<Vec<PresetInfo>, CommandError> {
     let manager = integration_state.0.read().await;
-    let presets = manager
-        .list_replacement_presets()
-        .await
-        .map_err(|message| CommandError::SidecarIpc { message })?;
+    let presets = manager.list_replacement_presets().await?;
 
     Ok(presets
         .into_iter()
@@ -862,6 +2262,7 @@ fn merge_preset_rules(
 /// Load a preset's rules.
 #[tauri::command]
 pub async fn load_preset(
+    _audit: CommandAudit,
     integration_state: tauri::State<'_, IntegrationState>,
     preset_id: String,
 ) -> Result<Vec<ReplacementRule>, CommandError> {
@@ -869,8 +2270,7 @@ pub async fn load_preset(
 
     let preset_rules = match manager
         .get_preset_replacement_rules(preset_id.clone())
-        .await
-        .map_err(|message| CommandError::SidecarIpc { message })?
+        .await?
     {
         Some(rules) => rules,
         None => {
@@ -890,19 +2290,19 @@ pub async fn load_preset(
         })
         .collect();
 
-    let active_rules = manager
-        .get_active_replacement_rules()
-        .await
-        .map_err(|message| CommandError::SidecarIpc { message })?;
-
-    let merged_rules = merge_preset_rules(active_rules, preset_rules.clone(), &preset_id);
+    // Merge into the configured rules rather than the sidecar's active set,
+    // which omits rules from disabled or inactive groups.
+    let mut app_config = config::load_config();
+    let merged_rules = merge_preset_rules(
+        app_config.replacements.clone(),
+        preset_rules.clone(),
+        &preset_id,
+    );
+    let active_rules =
+        replacement_rules::rules_for_sidecar(&merged_rules, &app_config.replacement_groups);
 
-    manager
-        .set_active_replacement_rules(merged_rules.clone())
-        .await
-        .map_err(|message| CommandError::SidecarIpc { message })?;
+    manager.set_active_replacement_rules(active_rules).await?;
 
-    let mut app_config = config::load_config();
     app_config.replacements = merged_rules;
     if !app_config
         .presets
@@ -917,118 +2317,275 @@ pub async fn load_preset(
     Ok(preset_rules)
 }
 
-fn map_start_recording_error(message: String) -> CommandError {
-    let lower = message.to_ascii_lowercase();
-
-    if lower.contains("sidecar") || lower.contains("recording.start") || lower.contains("session") {
-        return CommandError::SidecarIpc { message };
-    }
-    if lower.contains("model not ready") {
-        return CommandError::Model { message };
-    }
-    if lower.contains("disabled")
-        || lower.contains("already in progress")
-        || lower.contains("transcribing")
-    {
-        return CommandError::Audio { message };
-    }
-
-    CommandError::Internal { message }
+/// Start a new recording session.
+#[tauri::command]
+pub async fn start_recording(
+    _audit: CommandAudit,
+    integration_state: tauri::State<'_, IntegrationState>,
+) -> Result<(), CommandError> {
+    let manager = integration_state.0.read().await;
+    manager.start_recording().await.map_err(CommandError::from)
 }
 
-fn map_purge_model_cache_error(message: String) -> CommandError {
-    let lower = message.to_ascii_lowercase();
+/// Stop the current recording session and begin transcription.
+#[tauri::command]
+pub async fn stop_recording(
+    _audit: CommandAudit,
+    integration_state: tauri::State<'_, IntegrationState>,
+) -> Result<(), CommandError> {
+    let manager = integration_state.0.read().await;
+    manager.stop_recording().await.map_err(CommandError::from)
+}
 
-    if lower.contains("invalid model_id")
-        || lower.contains("cannot purge model while download or initialization is in progress")
-        || lower.contains("e_not_ready")
-        || lower.contains("e_not_initialized")
-        || lower.contains("e_model")
-        || lower.contains("e_cache_corrupt")
-        || lower.contains("e_network")
-        || lower.contains("e_disk_full")
-    {
-        return CommandError::Model { message };
-    }
-    if lower.contains("failed to purge cache") || lower.contains("sidecar not connected") {
-        return CommandError::SidecarIpc { message };
-    }
+/// Cancel the current recording session without transcription.
+#[tauri::command]
+pub async fn cancel_recording(
+    _audit: CommandAudit,
+    integration_state: tauri::State<'_, IntegrationState>,
+) -> Result<(), CommandError> {
+    let manager = integration_state.0.read().await;
+    manager.cancel_recording().await.map_err(CommandError::from)
+}
 
-    CommandError::Internal { message }
+/// Get the most recent dictation session, so a reloaded window can restore
+/// its UI from the full lifecycle snapshot. `None` before the first session.
+#[tauri::command]
+pub async fn get_active_session(
+    _audit: CommandAudit,
+    integration_state: tauri::State<'_, IntegrationState>,
+) -> Result<Option<SessionSnapshot>, CommandError> {
+    let manager = integration_state.0.read().await;
+    Ok(manager.active_session().await)
 }
 
-fn map_stop_recording_error(message: String) -> CommandError {
-    let lower = message.to_ascii_lowercase();
+// ============================================================================
+// INJECTION COMMANDS
+// ============================================================================
 
-    if lower.contains("sidecar")
-        || lower.contains("recording.stop")
-        || lower.contains("recording.cancel")
-    {
-        return CommandError::SidecarIpc { message };
-    }
-    if lower.contains("no recording in progress")
-        || lower.contains("not recording")
-        || lower.contains("transcribing")
-    {
-        return CommandError::Audio { message };
-    }
+/// Switch the global output mode: "inject", "clipboard_only", or "ask".
+///
+/// Returns the mode that was saved.
+#[tauri::command]
+pub fn set_injection_mode(
+    _audit: CommandAudit,
+    app: tauri::AppHandle,
+    mode: String,
+) -> Result<String, CommandError> {
+    let parsed = InjectionMode::parse(mode.trim()).ok_or_else(|| CommandError::Config {
+        message: format!(
+            "Invalid injection mode '{}'; expected inject, clipboard_only, or ask",
+            mode
+        ),
+    })?;
+
+    let mut config = config::load_config();
+    config.injection.mode = parsed.as_str().to_string();
+    config::save_config(&config)?;
 
-    CommandError::Internal { message }
+    let payload = InjectionModeChangedPayload {
+        mode: parsed.as_str().to_string(),
+    };
+    let _ = app.emit(
+        EVENT_INJECTION_MODE_CHANGED,
+        crate::event_seq::payload_with_next_seq(serde_json::json!(payload)),
+    );
+    emit_tray_update(&app, "injection_mode_changed");
+    Ok(parsed.as_str().to_string())
 }
 
-/// Start a new recording session.
+/// Paste a transcript held back by "ask" mode into the focused app.
 #[tauri::command]
-pub async fn start_recording(
-    integration_state: tauri::State<'_, IntegrationState>,
+pub async fn confirm_injection(
+    _audit: CommandAudit,
+    history: tauri::State<'_, TranscriptHistory>,
+    entry_id: String,
 ) -> Result<(), CommandError> {
-    let manager = integration_state.0.read().await;
-    manager
-        .start_recording()
-        .await
-        .map_err(map_start_recording_error)
+    let uuid = Uuid::parse_str(&entry_id).map_err(|_| CommandError::Internal {
+        message: "Invalid transcript ID".to_string(),
+    })?;
+    let entry = history.get(uuid).ok_or_else(|| CommandError::Internal {
+        message: "Transcript not found".to_string(),
+    })?;
+
+    let injection_config = InjectionConfig::from(&config::load_config().injection);
+    let _turn = crate::injection_order::global_dispatcher()
+        .acquire(None, false)
+        .await;
+    match inject_text(&entry.final_text, None, &injection_config).await {
+        InjectionResult::Failed { error, .. } => Err(CommandError::Internal { message: error }),
+        _ => Ok(()),
+    }
 }
 
-/// Stop the current recording session and begin transcription.
+/// Inject a transcript into the window it was originally dictated into.
+///
+/// The window is looked up among the open windows by app and title and
+/// focused first, so this does not depend on what has focus now. Fails when
+/// the entry has no recorded target or the window is no longer open.
 #[tauri::command]
-pub async fn stop_recording(
-    integration_state: tauri::State<'_, IntegrationState>,
+pub async fn reinject_to_original_target(
+    _audit: CommandAudit,
+    history: tauri::State<'_, TranscriptHistory>,
+    entry_id: String,
 ) -> Result<(), CommandError> {
-    let manager = integration_state.0.read().await;
-    manager
-        .stop_recording()
+    let uuid = Uuid::parse_str(&entry_id).map_err(|_| CommandError::Internal {
+        message: "Invalid transcript ID".to_string(),
+    })?;
+    let entry = history.get(uuid).ok_or_else(|| CommandError::Internal {
+        message: "Transcript not found".to_string(),
+    })?;
+    let target = entry
+        .injection_target
+        .clone()
+        .ok_or_else(|| CommandError::Internal {
+            message: "Transcript has no recorded target window".to_string(),
+        })?;
+
+    let injection_config = InjectionConfig::from(&config::load_config().injection);
+    let _turn = crate::injection_order::global_dispatcher()
+        .acquire(None, false)
+        .await;
+    let focused = tokio::task::spawn_blocking(move || focus_injection_target(&target))
         .await
-        .map_err(map_stop_recording_error)
+        .map_err(|error| CommandError::Internal {
+            message: format!("Focusing the target window failed: {error}"),
+        })?
+        .map_err(|message| CommandError::Internal { message })?;
+
+    match inject_text(&entry.final_text, Some(&focused), &injection_config).await {
+        InjectionResult::Injected { .. } => Ok(()),
+        InjectionResult::ClipboardOnly { reason, .. } => Err(CommandError::Clipboard {
+            message: format!("Transcript copied to the clipboard instead: {}", reason),
+        }),
+        InjectionResult::Failed { error, .. } => Err(CommandError::Internal { message: error }),
+    }
 }
 
-fn map_cancel_recording_error(message: String) -> CommandError {
-    let lower = message.to_ascii_lowercase();
+/// Focus the open window that is `target` and return its focus signature.
+fn focus_injection_target(target: &InjectionTarget) -> Result<FocusSignature, String> {
+    let window = focus::matching_window(focus::list_windows(), target)
+        .ok_or_else(|| "The original window is no longer open".to_string())?;
+    focus::activate_window(&window.handle)?;
 
-    if lower.contains("no recording in progress") || lower.contains("not recording") {
-        return CommandError::Audio { message };
+    // Window managers apply focus changes asynchronously.
+    for _ in 0..10 {
+        let current = focus::capture_focus();
+        if InjectionTarget::from_signature(&current).as_ref() == Some(target) {
+            return Ok(current);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
     }
+    Err("The original window could not be focused".to_string())
+}
 
-    CommandError::Internal { message }
+/// Cancel the chunked paste of a long transcript, if one is running.
+///
+/// Returns `false` when nothing was being injected. Text that was not yet
+/// pasted is left on the clipboard.
+#[tauri::command]
+pub fn cancel_injection(_audit: CommandAudit) -> bool {
+    crate::injection::cancel_active_injection()
 }
 
-/// Cancel the current recording session without transcription.
+/// List transcripts that fell back to clipboard-only and await insertion.
 #[tauri::command]
-pub async fn cancel_recording(
-    integration_state: tauri::State<'_, IntegrationState>,
+pub fn get_pending_inserts(
+    _audit: CommandAudit,
+    queue: tauri::State<'_, PendingInsertQueue>,
+) -> Vec<PendingInsert> {
+    queue.all()
+}
+
+/// Insert a queued transcript into the currently focused app.
+///
+/// The item leaves the queue once injected. If injection falls back to the
+/// clipboard again, the item stays queued and the reason is returned.
+#[tauri::command]
+pub async fn inject_pending_insert(
+    _audit: CommandAudit,
+    app: tauri::AppHandle,
+    entry_id: String,
 ) -> Result<(), CommandError> {
-    let manager = integration_state.0.read().await;
-    manager
-        .cancel_recording()
+    let uuid = Uuid::parse_str(&entry_id).map_err(|_| CommandError::Internal {
+        message: "Invalid transcript ID".to_string(),
+    })?;
+    // Look the item up once it is our turn: a transcript deferred for a busy
+    // app leaves the queue when its session injects it after all.
+    let _turn = crate::injection_order::global_dispatcher()
+        .acquire(None, false)
+        .await;
+    let queue = app.state::<PendingInsertQueue>();
+    let item = queue.get(uuid).ok_or_else(|| CommandError::Internal {
+        message: "Pending insert not found".to_string(),
+    })?;
+
+    let injection_config = InjectionConfig::from(&config::load_config().injection);
+    match inject_text(&item.text, None, &injection_config).await {
+        InjectionResult::Injected { .. } => {
+            queue.remove(uuid);
+            emit_pending_inserts_changed(&app);
+            Ok(())
+        }
+        InjectionResult::ClipboardOnly { reason, .. } => Err(CommandError::Clipboard {
+            message: format!("Transcript kept in pending queue: {}", reason),
+        }),
+        InjectionResult::Failed { error, .. } => Err(CommandError::Internal { message: error }),
+    }
+}
+
+/// Drop a queued transcript without inserting it.
+///
+/// Returns `false` when the transcript was not queued.
+#[tauri::command]
+pub fn dismiss_pending_insert(
+    _audit: CommandAudit,
+    app: tauri::AppHandle,
+    entry_id: String,
+) -> Result<bool, CommandError> {
+    let uuid = Uuid::parse_str(&entry_id).map_err(|_| CommandError::Internal {
+        message: "Invalid transcript ID".to_string(),
+    })?;
+    let removed = app.state::<PendingInsertQueue>().remove(uuid).is_some();
+    if removed {
+        emit_pending_inserts_changed(&app);
+    }
+    Ok(removed)
+}
+
+fn emit_pending_inserts_changed(app: &tauri::AppHandle) {
+    let payload = PendingInsertsChangedPayload {
+        count: app.state::<PendingInsertQueue>().len(),
+    };
+    let _ = app.emit(
+        EVENT_PENDING_INSERTS_CHANGED,
+        crate::event_seq::payload_with_next_seq(serde_json::json!(payload)),
+    );
+    emit_tray_update(app, "pending_inserts_changed");
+}
+
+/// Describe the window dictated text would currently be injected into.
+///
+/// The overlay pairs this with the debounced `focus:changed` event to show
+/// "Dictating into: ..." before recording starts.
+#[tauri::command]
+pub async fn get_current_focus_info(_audit: CommandAudit) -> Result<FocusInfo, CommandError> {
+    tokio::task::spawn_blocking(current_focus_info)
         .await
-        .map_err(map_cancel_recording_error)
+        .map_err(|error| CommandError::Internal {
+            message: format!("Focus capture failed: {error}"),
+        })
 }
 
 // ============================================================================
 // CONTROL COMMANDS
 // ============================================================================
 
-/// Toggle enabled state.
+/// Toggle between enabled and fully paused; hotkeys-paused resumes.
 #[tauri::command]
-pub fn toggle_enabled(state_manager: tauri::State<Arc<AppStateManager>>) -> bool {
+pub fn toggle_enabled(
+    _audit: CommandAudit,
+    state_manager: tauri::State<Arc<AppStateManager>>,
+) -> bool {
     let current = state_manager.is_enabled();
     state_manager.set_enabled(!current);
     !current
@@ -1036,16 +2593,164 @@ pub fn toggle_enabled(state_manager: tauri::State<Arc<AppStateManager>>) -> bool
 
 /// Check if enabled.
 #[tauri::command]
-pub fn is_enabled(state_manager: tauri::State<Arc<AppStateManager>>) -> bool {
+pub fn is_enabled(_audit: CommandAudit, state_manager: tauri::State<Arc<AppStateManager>>) -> bool {
     state_manager.is_enabled()
 }
 
 /// Set enabled state.
 #[tauri::command]
-pub fn set_enabled(state_manager: tauri::State<Arc<AppStateManager>>, enabled: bool) {
+pub fn set_enabled(
+    _audit: CommandAudit,
+    state_manager: tauri::State<Arc<AppStateManager>>,
+    enabled: bool,
+) {
     state_manager.set_enabled(enabled);
 }
 
+/// Get the current pause level.
+#[tauri::command]
+pub fn get_enable_level(
+    _audit: CommandAudit,
+    state_manager: tauri::State<Arc<AppStateManager>>,
+) -> EnableLevel {
+    state_manager.level()
+}
+
+/// Set the pause level: enabled, hotkeys_paused, fully_paused or stealth.
+#[tauri::command]
+pub fn set_enable_level(
+    _audit: CommandAudit,
+    state_manager: tauri::State<Arc<AppStateManager>>,
+    level: String,
+) -> Result<(), CommandError> {
+    let parsed = EnableLevel::parse(level.trim()).ok_or_else(|| CommandError::Config {
+        message: format!(
+            "Invalid enable level '{}'; expected enabled, hotkeys_paused, fully_paused, or stealth",
+            level
+        ),
+    })?;
+    state_manager.set_level(parsed);
+    Ok(())
+}
+
+// ============================================================================
+// ACTION COMMANDS
+// ============================================================================
+
+/// List every user-invokable backend action with whether it can run now, so
+/// the UI can build a command palette.
+#[tauri::command]
+pub async fn list_actions(
+    _audit: CommandAudit,
+    app: tauri::AppHandle,
+) -> Result<Vec<ActionInfo>, CommandError> {
+    Ok(actions::list_actions(&action_context(&app).await?))
+}
+
+/// Run an action from `list_actions` by id.
+///
+/// `args` holds the action's arguments by name. Returns what the underlying
+/// command returns, or `null` for commands without a result.
+#[tauri::command]
+pub async fn invoke_action(
+    _audit: CommandAudit,
+    app: tauri::AppHandle,
+    id: String,
+    args: Option<serde_json::Value>,
+) -> Result<serde_json::Value, CommandError> {
+    let action = ActionId::parse(&id).ok_or_else(|| CommandError::Config {
+        message: format!("Unknown action '{id}'"),
+    })?;
+    let info = ActionInfo::new(action, &action_context(&app).await?);
+    if let Some(reason) = info.unavailable_reason {
+        return Err(CommandError::ActionUnavailable {
+            message: format!("'{}' is not available right now", info.name),
+            reason: reason.to_string(),
+        });
+    }
+
+    let args = args.unwrap_or_default();
+    let arg = |name: &str| {
+        args.get(name)
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string)
+    };
+    let audit = |command: &str| CommandAudit::internal(command, "action");
+    log::info!("Invoking action {}", info.id);
+    match action {
+        ActionId::StartRecording => {
+            action_result(start_recording(audit("start_recording"), app.state()).await?)
+        }
+        ActionId::StopRecording => {
+            action_result(stop_recording(audit("stop_recording"), app.state()).await?)
+        }
+        ActionId::CancelRecording => {
+            action_result(cancel_recording(audit("cancel_recording"), app.state()).await?)
+        }
+        ActionId::ToggleEnabled => {
+            action_result(toggle_enabled(audit("toggle_enabled"), app.state()))
+        }
+        ActionId::CopyLastTranscript => action_result(copy_last_transcript(
+            audit("copy_last_transcript"),
+            app.state(),
+        )?),
+        ActionId::ClearHistory => {
+            clear_history(audit("clear_history"), app.state(), app.clone());
+            Ok(serde_json::Value::Null)
+        }
+        ActionId::SetInjectionMode => {
+            let mode = arg("mode").ok_or_else(|| CommandError::Config {
+                message: "Action 'injection.set_mode' needs a 'mode' argument".to_string(),
+            })?;
+            action_result(set_injection_mode(
+                audit("set_injection_mode"),
+                app.clone(),
+                mode,
+            )?)
+        }
+        ActionId::ClearTemporaryReplacements => action_result(
+            clear_temporary_replacement_rules(
+                audit("clear_temporary_replacement_rules"),
+                app.state(),
+            )
+            .await?,
+        ),
+        ActionId::DownloadModel => action_result(
+            download_model(audit("download_model"), app.state(), arg("model_id"), None).await?,
+        ),
+        ActionId::RestartSidecar => {
+            action_result(restart_sidecar(audit("restart_sidecar"), app.state()).await?)
+        }
+        ActionId::RunSelfCheck => action_result(run_self_check(audit("run_self_check"))),
+        ActionId::CheckAppUpdate => {
+            action_result(check_app_update(audit("check_app_update")).await?)
+        }
+    }
+}
+
+async fn action_context(app: &tauri::AppHandle) -> Result<ActionContext, CommandError> {
+    let state_manager = app.state::<Arc<AppStateManager>>();
+    // Capability detection probes for clipboard tools.
+    let clipboard_available =
+        tokio::task::spawn_blocking(|| Capabilities::detect().clipboard_available)
+            .await
+            .map_err(|error| CommandError::Internal {
+                message: format!("Capability detection failed: {error}"),
+            })?;
+    Ok(ActionContext {
+        state: state_manager.get(),
+        can_record: state_manager.can_start_recording(),
+        clipboard_available,
+        history_len: app.state::<TranscriptHistory>().len(),
+    })
+}
+
+fn action_result<T: Serialize>(value: T) -> Result<serde_json::Value, CommandError> {
+    serde_json::to_value(value).map_err(|error| CommandError::Internal {
+        message: format!("Action result not serializable: {error}"),
+    })
+}
+
 // ============================================================================
 // DIAGNOSTICS COMMANDS
 // ============================================================================
@@ -1061,33 +2766,57 @@ pub struct DiagnosticsReport {
     pub recent_logs: Vec<LogEntry>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub recent_sidecar_logs: Vec<String>,
+    /// Tail of the persisted session journal (JSONL lines, oldest first).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub session_journal: Vec<String>,
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     pub environment: BTreeMap<String, String>,
+    /// Sidecar watchdog timings in effect.
+    pub watchdog: WatchdogSettings,
+    /// Power source and the profile in effect.
+    pub power: PowerStatus,
+    /// Invalid UTF-8 bytes replaced in sidecar output this session.
+    pub sidecar_output: SidecarOutputStats,
 }
 
 // Re-export LogEntry from log_buffer for IPC
 pub use crate::log_buffer::LogEntry;
 
+/// Number of session journal lines included in diagnostics reports.
+const DIAGNOSTICS_JOURNAL_LINES: usize = 200;
+
 /// Generate diagnostics report.
 #[tauri::command]
 pub async fn generate_diagnostics(
+    _audit: CommandAudit,
     integration_state: tauri::State<'_, IntegrationState>,
 ) -> Result<DiagnosticsReport, CommandError> {
     let manager = integration_state.0.read().await;
     let recent_sidecar_logs = manager.recent_sidecar_logs(100).await;
-    Ok(diagnostics_report_with_sidecar_logs(recent_sidecar_logs))
+    let watchdog = manager.watchdog().config();
+    Ok(diagnostics_report_with_sidecar_logs(
+        recent_sidecar_logs,
+        &watchdog,
+    ))
 }
 
-fn diagnostics_report_with_sidecar_logs(recent_sidecar_logs: Vec<String>) -> DiagnosticsReport {
+fn diagnostics_report_with_sidecar_logs(
+    recent_sidecar_logs: Vec<String>,
+    watchdog: &WatchdogConfig,
+) -> DiagnosticsReport {
     DiagnosticsReport {
         version: env!("CARGO_PKG_VERSION").to_string(),
         platform: std::env::consts::OS.to_string(),
         capabilities: Capabilities::detect(),
         config: config::load_config(),
-        self_check: run_self_check(),
-        recent_logs: get_recent_logs(100),
+        self_check: run_self_check(CommandAudit::internal("run_self_check", "diagnostics")),
+        recent_logs: crate::log_buffer::global_buffer().recent(100, &LogFilter::default()),
         recent_sidecar_logs,
+        session_journal: crate::session_journal::global_journal().tail(DIAGNOSTICS_JOURNAL_LINES),
         environment: diagnostics_environment(),
+        watchdog: WatchdogSettings::from(watchdog),
+        power: power::status(),
+        sidecar_output: decode::output_stats(),
     }
 }
 
@@ -1131,19 +2860,143 @@ fn is_sensitive_env_key(upper_key: &str) -> bool {
         || upper_key.contains("PASSWORD")
 }
 
-/// Get recent log entries from the ring buffer.
+/// Get the newest `count` log entries from the ring buffer, oldest first.
+///
+/// `level` keeps entries at that severity or above ("warn" keeps warnings and
+/// errors), `module` matches part of the logger target, and `since` (RFC 3339)
+/// keeps entries recorded after that time.
 #[tauri::command]
-pub fn get_recent_logs(count: usize) -> Vec<LogEntry> {
-    let buffer = crate::log_buffer::global_buffer();
-    let entries = buffer.entries();
-    let len = entries.len();
+pub fn get_recent_logs(
+    _audit: CommandAudit,
+    count: usize,
+    level: Option<String>,
+    module: Option<String>,
+    since: Option<String>,
+) -> Result<Vec<LogEntry>, CommandError> {
+    let level = level
+        .map(|value| {
+            value
+                .trim()
+                .parse::<log::Level>()
+                .map_err(|_| CommandError::Config {
+                    message: format!(
+                        "Invalid log level '{}'; expected error, warn, info, debug, or trace",
+                        value
+                    ),
+                })
+        })
+        .transpose()?;
+    let since = since
+        .map(|value| {
+            DateTime::parse_from_rfc3339(&value)
+                .map(|parsed| parsed.with_timezone(&Utc))
+                .map_err(|e| CommandError::Config {
+                    message: format!("Invalid since '{}': {}", value, e),
+                })
+        })
+        .transpose()?;
+    let filter = LogFilter {
+        level,
+        module: module.filter(|module| !module.trim().is_empty()),
+        since,
+    };
+    Ok(crate::log_buffer::global_buffer().recent(count, &filter))
+}
 
-    // Return the last `count` entries (or all if count is larger)
-    if count >= len {
-        entries
-    } else {
-        entries.into_iter().skip(len - count).collect()
+/// Get the newest `count` entries of the command audit log, oldest first.
+///
+/// This command is not audited itself, so polling it does not push other
+/// commands out of the log.
+#[tauri::command]
+pub fn get_command_audit_log(count: usize) -> Vec<CommandAuditEntry> {
+    command_audit::recent(count)
+}
+
+/// Save a support snapshot of the app's state as JSON in the downloads
+/// folder and return its path.
+#[tauri::command]
+pub async fn export_app_state_snapshot(
+    _audit: CommandAudit,
+    state_manager: tauri::State<'_, Arc<AppStateManager>>,
+    integration_state: tauri::State<'_, IntegrationState>,
+) -> Result<String, CommandError> {
+    let manager = integration_state.0.read().await;
+    let snapshot = AppStateSnapshot {
+        snapshot_version: state_snapshot::SNAPSHOT_VERSION,
+        created_at: Utc::now(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        platform: std::env::consts::OS.to_string(),
+        app_state: state_manager.get_event(),
+        capabilities: serde_json::to_value(Capabilities::detect()).unwrap_or_default(),
+        model_status: manager.get_model_status().await,
+        config: state_snapshot::redact_config(&config::load_config()),
+        supervisor: manager.supervisor_snapshot().await,
+        recent_events: state_snapshot::recent_events(),
+    };
+    let path = state_snapshot::write_snapshot(&snapshot)?;
+    log::info!(
+        "Saved state snapshot with {} events",
+        snapshot.recent_events.len()
+    );
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Replay a support snapshot's events to the windows, to reproduce what the
+/// user saw. Returns the number of recorded events. Debug builds only.
+#[tauri::command]
+pub async fn replay_app_state_snapshot(
+    _audit: CommandAudit,
+    integration_state: tauri::State<'_, IntegrationState>,
+    path: String,
+) -> Result<usize, CommandError> {
+    if !cfg!(debug_assertions) {
+        return Err(CommandError::NotImplemented {
+            message: "Snapshot replay is only available in debug builds".to_string(),
+        });
+    }
+    let snapshot = state_snapshot::read_snapshot(Path::new(&path))?;
+    let events = snapshot.recent_events.len();
+    log::info!(
+        "Replaying state snapshot from {} ({}, {} events)",
+        snapshot.created_at,
+        snapshot.app_version,
+        events
+    );
+    if !integration_state
+        .0
+        .read()
+        .await
+        .replay_state_snapshot(snapshot)
+    {
+        return Err(CommandError::Internal {
+            message: "No windows to replay the snapshot to".to_string(),
+        });
     }
+    Ok(events)
+}
+
+/// Get the crash reports stored on this machine, oldest first.
+///
+/// Reports stay here until deleted, or until uploaded when
+/// `crash_reporting.upload_enabled` is on.
+#[tauri::command]
+pub fn get_pending_crash_reports(_audit: CommandAudit) -> Vec<CrashReport> {
+    crash_reports::global_store().pending()
+}
+
+/// Delete the crash reports in `ids`, or every report when omitted.
+///
+/// Returns how many reports were deleted.
+#[tauri::command]
+pub fn delete_crash_reports(
+    _audit: CommandAudit,
+    ids: Option<Vec<Uuid>>,
+) -> Result<usize, CommandError> {
+    crash_reports::global_store()
+        .delete(ids.as_deref())
+        .map_err(|e| CommandError::Internal {
+            message: format!("Failed to delete crash reports: {}", e),
+        })
 }
 
 // ============================================================================
@@ -1165,69 +3018,46 @@ mod tests {
     }
 
     #[test]
-    fn test_map_start_recording_error_sidecar_path() {
-        let mapped = map_start_recording_error("Sidecar not connected".to_string());
-        assert!(matches!(mapped, CommandError::SidecarIpc { .. }));
-    }
-
-    #[test]
-    fn test_map_start_recording_error_model_path() {
-        let mapped = map_start_recording_error("Model not ready".to_string());
-        assert!(matches!(mapped, CommandError::Model { .. }));
-    }
-
-    #[test]
-    fn test_map_purge_model_cache_error_sidecar_ipc_path() {
-        let mapped = map_purge_model_cache_error(
-            "Failed to purge cache: Remote error: E_INTERNAL - RPC transport dropped".to_string(),
-        );
-        assert!(matches!(mapped, CommandError::SidecarIpc { .. }));
-
-        let mapped = map_purge_model_cache_error("Sidecar not connected".to_string());
-        assert!(matches!(mapped, CommandError::SidecarIpc { .. }));
-    }
-
-    #[test]
-    fn test_map_purge_model_cache_error_model_path() {
-        let mapped =
-            map_purge_model_cache_error("Invalid model_id: blank or whitespace-only value".to_string());
-        assert!(matches!(mapped, CommandError::Model { .. }));
-
-        let mapped = map_purge_model_cache_error(
-            "Cannot purge model while download or initialization is in progress".to_string(),
-        );
-        assert!(matches!(mapped, CommandError::Model { .. }));
-
-        let mapped = map_purge_model_cache_error(
-            "Failed to purge cache: Remote error: E_NOT_READY - Model is currently in use"
-                .to_string(),
-        );
-        assert!(matches!(mapped, CommandError::Model { .. }));
-    }
-
-    #[test]
-    fn test_map_purge_model_cache_error_falls_back_to_internal() {
-        let mapped = map_purge_model_cache_error("Unexpected purge failure".to_string());
-        assert!(matches!(mapped, CommandError::Internal { .. }));
+    fn test_integration_error_serializes_with_app_error() {
+        let error = CommandError::from(IntegrationError::SidecarNotConnected);
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["code"], "integration");
+        assert_eq!(json["message"], "Sidecar not connected");
+        assert_eq!(json["title"], "Background Service Not Responding");
+        assert_eq!(json["error"]["code"], "E_SIDECAR_IPC");
+        assert_eq!(json["error"]["recoverable"], true);
     }
 
     #[test]
-    fn test_map_stop_recording_error_sidecar_path() {
-        let mapped =
-            map_stop_recording_error("Failed to call recording.stop RPC: disconnected".to_string());
-        assert!(matches!(mapped, CommandError::SidecarIpc { .. }));
+    fn test_integration_error_keeps_state_kind() {
+        let error = CommandError::from(IntegrationError::NotRecording);
+        let CommandError::Integration { error, .. } = error else {
+            panic!("expected integration error");
+        };
+        assert_eq!(error.code, "E_RECORDING_FAILED");
     }
 
     #[test]
-    fn test_map_stop_recording_error_no_session_path() {
-        let mapped = map_stop_recording_error("No recording in progress".to_string());
-        assert!(matches!(mapped, CommandError::Audio { .. }));
+    fn test_policy_violation_lists_managed_fields() {
+        let error = CommandError::from(PolicyViolation {
+            fields: vec!["history.persistence_mode".to_string()],
+        });
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["code"], "policy");
+        assert_eq!(json["fields"][0], "history.persistence_mode");
     }
 
     #[test]
-    fn test_map_cancel_recording_error_no_session_path() {
-        let mapped = map_cancel_recording_error("No recording in progress".to_string());
-        assert!(matches!(mapped, CommandError::Audio { .. }));
+    fn test_config_view_flattens_config() {
+        let view = ConfigView {
+            config: AppConfig::default(),
+            managed_fields: vec!["model.model_id".to_string()],
+        };
+        let json = serde_json::to_value(&view).unwrap();
+        assert!(json["history"].is_object());
+        assert_eq!(json["managed_fields"][0], "model.model_id");
+        // The UI sends the view back to update_config as a plain config.
+        assert!(serde_json::from_value::<AppConfig>(json).is_ok());
     }
 
     #[test]
@@ -1270,6 +3100,13 @@ mod tests {
         assert_eq!(entry.size_bytes, Some(2509371044));
         assert_eq!(entry.license_spdx.as_deref(), Some("CC-BY-4.0"));
         assert_eq!(entry.manifest_path, MODEL_MANIFEST_PATH);
+        assert_eq!(
+            entry
+                .recommended_params
+                .as_ref()
+                .and_then(|params| params.beam_size),
+            Some(1)
+        );
     }
 
     #[test]
@@ -1291,6 +3128,8 @@ mod tests {
                 case_sensitive: false,
                 description: None,
                 origin: None,
+                group: None,
+                order: 0,
             },
             ReplacementRule {
                 id: "rule-2".to_string(),
@@ -1302,6 +3141,8 @@ mod tests {
                 case_sensitive: false,
                 description: None,
                 origin: None,
+                group: None,
+                order: 0,
             },
             ReplacementRule {
                 id: "rule-3".to_string(),
@@ -1313,6 +3154,8 @@ mod tests {
                 case_sensitive: false,
                 description: None,
                 origin: None,
+                group: None,
+                order: 0,
             },
         ];
 
@@ -1337,6 +3180,8 @@ mod tests {
             case_sensitive: false,
             description: None,
             origin: None,
+            group: None,
+            order: 0,
         }];
 
         let result = preview_replacement_local("ASAPly ASAP".to_string(), rules);
@@ -1357,6 +3202,8 @@ mod tests {
             case_sensitive: true,
             description: None,
             origin: None,
+            group: None,
+            order: 0,
         }];
 
         let result = preview_replacement_local("Total: $42.50".to_string(), rules);
@@ -1398,6 +3245,8 @@ mod tests {
                 case_sensitive: false,
                 description: None,
                 origin: Some("user".to_string()),
+                group: None,
+                order: 0,
             },
             ReplacementRule {
                 id: "punctuation:period".to_string(),
@@ -1409,6 +3258,8 @@ mod tests {
                 case_sensitive: false,
                 description: None,
                 origin: Some("preset:punctuation".to_string()),
+                group: None,
+                order: 0,
             },
         ];
 
@@ -1422,6 +3273,8 @@ mod tests {
             case_sensitive: false,
             description: None,
             origin: Some("preset:punctuation".to_string()),
+            group: None,
+            order: 0,
         }];
 
         let merged = merge_preset_rules(active_rules, preset_rules, "punctuation");
@@ -1443,6 +3296,8 @@ mod tests {
                 case_sensitive: false,
                 description: None,
                 origin: Some("preset:coding-terms".to_string()),
+                group: None,
+                order: 0,
             },
             ReplacementRule {
                 id: "punctuation:period".to_string(),
@@ -1454,6 +3309,8 @@ mod tests {
                 case_sensitive: false,
                 description: None,
                 origin: Some("preset:punctuation".to_string()),
+                group: None,
+                order: 0,
             },
         ];
 
@@ -1467,6 +3324,8 @@ mod tests {
             case_sensitive: false,
             description: None,
             origin: Some("preset:punctuation".to_string()),
+            group: None,
+            order: 0,
         }];
 
         let merged = merge_preset_rules(active_rules, preset_rules, "punctuation");
@@ -1493,6 +3352,8 @@ mod tests {
                 case_sensitive: false,
                 description: None,
                 origin: Some("user".to_string()),
+                group: None,
+                order: 0,
             },
             ReplacementRule {
                 id: "a1b2c3d4-e5f6-7890-abcd-ef1234567890".to_string(),
@@ -1504,6 +3365,8 @@ mod tests {
                 case_sensitive: false,
                 description: None,
                 origin: Some("preset:punctuation".to_string()),
+                group: None,
+                order: 0,
             },
         ];
 
@@ -1517,6 +3380,8 @@ mod tests {
             case_sensitive: false,
             description: None,
             origin: Some("preset:punctuation".to_string()),
+            group: None,
+            order: 0,
         }];
 
         let merged = merge_preset_rules(active_rules, preset_rules, "punctuation");
@@ -1544,6 +3409,8 @@ mod tests {
             case_sensitive: false,
             description: None,
             origin: Some("preset".to_string()), // generic — legacy format
+            group: None,
+            order: 0,
         }];
 
         let preset_rules = vec![ReplacementRule {
@@ -1556,6 +3423,8 @@ mod tests {
             case_sensitive: false,
             description: None,
             origin: Some("preset:punctuation".to_string()),
+            group: None,
+            order: 0,
         }];
 
         let merged = merge_preset_rules(active_rules, preset_rules, "punctuation");
@@ -1567,9 +3436,11 @@ mod tests {
 
     #[test]
     fn test_diagnostics_report_serialization() {
-        let report = diagnostics_report_with_sidecar_logs(Vec::new());
+        let report = diagnostics_report_with_sidecar_logs(Vec::new(), &WatchdogConfig::default());
         let json = serde_json::to_string(&report).unwrap();
         assert!(json.contains("version"));
+        assert!(json.contains("\"ping_interval_ms\":10000"));
+        assert!(json.contains("\"rpc_bytes_replaced\""));
         assert!(json.contains("platform"));
         assert!(json.contains("capabilities"));
         assert!(json.contains("recent_logs"));
@@ -1581,7 +3452,10 @@ mod tests {
         buffer.clear();
         crate::log_buffer::log_to_buffer(log::Level::Info, "commands::tests", "diagnostics-log");
 
-        let report = diagnostics_report_with_sidecar_logs(vec!["sidecar-line".to_string()]);
+        let report = diagnostics_report_with_sidecar_logs(
+            vec!["sidecar-line".to_string()],
+            &WatchdogConfig::default(),
+        );
         assert!(report
             .recent_logs
             .iter()
@@ -1590,13 +3464,30 @@ mod tests {
         assert_eq!(report.recent_sidecar_logs, vec!["sidecar-line".to_string()]);
     }
 
+    #[test]
+    fn test_get_recent_logs_rejects_invalid_filters() {
+        let audit = || CommandAudit::internal("get_recent_logs", "test");
+        assert!(matches!(
+            get_recent_logs(audit(), 10, Some("loud".to_string()), None, None),
+            Err(CommandError::Config { .. })
+        ));
+        assert!(matches!(
+            get_recent_logs(audit(), 10, None, None, Some("yesterday".to_string())),
+            Err(CommandError::Config { .. })
+        ));
+        assert!(get_recent_logs(audit(), 10, Some("WARN".to_string()), None, None).is_ok());
+    }
+
     #[test]
     fn test_diagnostics_environment_redacts_sensitive_values() {
         let env = diagnostics_environment_from_iter(vec![
             ("HF_TOKEN".to_string(), "hf_secret_token".to_string()),
             ("SERVICE_API_KEY".to_string(), "api_secret".to_string()),
             ("SERVICE_KEY_ID".to_string(), "service-key-id".to_string()),
-            ("OPENVOICY_KEYCHAIN_PATH".to_string(), "/tmp/keychain".to_string()),
+            (
+                "OPENVOICY_KEYCHAIN_PATH".to_string(),
+                "/tmp/keychain".to_string(),
+            ),
             ("OPENVOICY_LOG_LEVEL".to_string(), "debug".to_string()),
             ("PATH".to_string(), "/usr/bin".to_string()),
         ]);
@@ -0,0 +1,536 @@
+//! Read-only status endpoint for companion devices on the LAN.
+//!
+//! With `companion.enabled` on, a small HTTP server on `companion.port`
+//! answers `GET /status` with the recording state and whether hotkeys are
+//! enabled, so a phone widget or StreamDeck plugin can show it. The service
+//! is advertised over mDNS as `_openvoicy._tcp` through the platform
+//! responder (`avahi-publish-service` on Linux, `dns-sd` on macOS and on
+//! Windows with Bonjour); without one the endpoint is still reachable by
+//! address. Transcripts are never exposed and every other request is
+//! refused. There is no remote control API yet, which the TXT record states
+//! as `control=none`.
+//!
+//! Web pages must not learn when the user is recording: no CORS header is
+//! sent, and requests a browser makes on a page's behalf (an `Origin`
+//! header, or `Sec-Fetch-Site` other than `none`) are refused. Opening the
+//! URL directly in a browser still works.
+//!
+//! The endpoint goes through the offline gate: in offline mode it is neither
+//! served nor advertised.
+
+use std::io::{self, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::config::{self, CompanionConfig};
+use crate::network::{self, NetworkPurpose};
+use crate::state::{AppState, AppStateManager, StateEvent};
+
+const APP_NAME: &str = "OpenVoicy";
+
+/// mDNS service type companions browse for.
+const SERVICE_TYPE: &str = "_openvoicy._tcp";
+
+const STATUS_PATH: &str = "/status";
+
+/// How often the loop picks up config changes.
+const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often the accept loop checks whether it should stop.
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Deadline for reading a whole request and writing the response, so a
+/// client trickling bytes cannot hold up the requests behind it.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Longest request head read; status requests are a few hundred bytes.
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// Body of `GET /status`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CompanionStatus {
+    pub app: &'static str,
+    pub version: &'static str,
+    pub state: AppState,
+    /// Whether hotkey listening is enabled (false = paused).
+    pub enabled: bool,
+    pub recording: bool,
+    /// When the state last changed.
+    pub since: DateTime<Utc>,
+}
+
+impl CompanionStatus {
+    fn from_event(event: &StateEvent) -> Self {
+        Self {
+            app: APP_NAME,
+            version: env!("CARGO_PKG_VERSION"),
+            state: event.state,
+            enabled: event.enabled,
+            recording: event.state == AppState::Recording,
+            since: event.timestamp,
+        }
+    }
+}
+
+type SharedStatus = Arc<Mutex<CompanionStatus>>;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Response {
+    code: u16,
+    reason: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn error(code: u16, reason: &'static str, error: &str) -> Self {
+        Self {
+            code,
+            reason,
+            body: serde_json::json!({ "error": error }).to_string(),
+        }
+    }
+
+    fn to_http(&self) -> String {
+        let allow = if self.code == 405 {
+            "Allow: GET\r\n"
+        } else {
+            ""
+        };
+        format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+             Cache-Control: no-store\r\n{}Connection: close\r\n\r\n{}",
+            self.code,
+            self.reason,
+            self.body.len(),
+            allow,
+            self.body
+        )
+    }
+}
+
+/// Whether a browser sent `request` on behalf of a web page. Browsers add
+/// `Origin` to cross-origin requests and `Sec-Fetch-Site` to all of them;
+/// it is `none` only when the user opened the URL themselves.
+fn from_web_page(request: &str) -> bool {
+    request.lines().skip(1).any(|line| {
+        let Some((name, value)) = line.split_once(':') else {
+            return false;
+        };
+        let name = name.trim();
+        name.eq_ignore_ascii_case("origin")
+            || (name.eq_ignore_ascii_case("sec-fetch-site")
+                && !value.trim().eq_ignore_ascii_case("none"))
+    })
+}
+
+/// Answer a request whose head is `request`.
+fn respond(request: &str, status: &CompanionStatus) -> Response {
+    let mut parts = request
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Response::error(400, "Bad Request", "bad_request");
+    };
+    if from_web_page(request) {
+        return Response::error(403, "Forbidden", "web_pages_refused");
+    }
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    if path != STATUS_PATH {
+        return Response::error(404, "Not Found", "not_found");
+    }
+    if method != "GET" {
+        return Response::error(405, "Method Not Allowed", "read_only");
+    }
+    match serde_json::to_string(status) {
+        Ok(body) => Response {
+            code: 200,
+            reason: "OK",
+            body,
+        },
+        Err(_) => Response::error(500, "Internal Server Error", "internal"),
+    }
+}
+
+/// Time left until `deadline`, or a timeout error once it has passed.
+fn remaining(deadline: Instant) -> io::Result<Duration> {
+    deadline
+        .checked_duration_since(Instant::now())
+        .filter(|left| !left.is_zero())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::TimedOut, "request deadline passed"))
+}
+
+fn handle_connection(
+    mut stream: TcpStream,
+    status: &SharedStatus,
+    timeout: Duration,
+) -> io::Result<()> {
+    let deadline = Instant::now() + timeout;
+    // Accepted sockets inherit non-blocking mode from the listener on some platforms.
+    stream.set_nonblocking(false)?;
+
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    while request.len() < MAX_REQUEST_BYTES && !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        stream.set_read_timeout(Some(remaining(deadline)?))?;
+        let read = stream.read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+        request.extend_from_slice(&buf[..read]);
+    }
+
+    let snapshot = status.lock().unwrap_or_else(|e| e.into_inner()).clone();
+    let response = respond(&String::from_utf8_lossy(&request), &snapshot);
+    stream.set_write_timeout(Some(remaining(deadline)?))?;
+    stream.write_all(response.to_http().as_bytes())
+}
+
+fn serve(listener: TcpListener, stop: Arc<AtomicBool>, status: SharedStatus) {
+    while !stop.load(Ordering::Acquire) {
+        match listener.accept() {
+            Ok((stream, peer)) => {
+                if let Err(error) = handle_connection(stream, &status, REQUEST_TIMEOUT) {
+                    log::debug!("Companion request from {} failed: {}", peer, error);
+                }
+            }
+            Err(error) if error.kind() == io::ErrorKind::WouldBlock => {
+                std::thread::sleep(ACCEPT_POLL_INTERVAL);
+            }
+            Err(error) => {
+                log::warn!("Companion status endpoint accept failed: {}", error);
+                std::thread::sleep(ACCEPT_POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+/// mDNS instance name, e.g. "OpenVoicy on studio-mac".
+fn instance_name() -> String {
+    let host = std::env::var("COMPUTERNAME")
+        .or_else(|_| std::env::var("HOSTNAME"))
+        .ok()
+        .or_else(|| {
+            Command::new("hostname")
+                .output()
+                .ok()
+                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        })
+        .filter(|host| !host.is_empty());
+    match host {
+        Some(host) => format!("{APP_NAME} on {host}"),
+        None => APP_NAME.to_string(),
+    }
+}
+
+/// Program and arguments that register the service with the mDNS responder
+/// for as long as the process runs.
+fn advertiser_invocation(instance: &str, port: u16) -> (&'static str, Vec<String>) {
+    let txt = [
+        format!("path={STATUS_PATH}"),
+        format!("version={}", env!("CARGO_PKG_VERSION")),
+        "control=none".to_string(),
+    ];
+    let mut args = Vec::new();
+    let program = if cfg!(target_os = "linux") {
+        args.extend([instance.to_string(), SERVICE_TYPE.to_string()]);
+        "avahi-publish-service"
+    } else {
+        args.extend([
+            "-R".to_string(),
+            instance.to_string(),
+            SERVICE_TYPE.to_string(),
+            "local".to_string(),
+        ]);
+        "dns-sd"
+    };
+    args.push(port.to_string());
+    args.extend(txt);
+    (program, args)
+}
+
+fn start_advertiser(port: u16) -> Option<Child> {
+    let (program, args) = advertiser_invocation(&instance_name(), port);
+    match Command::new(program)
+        .args(&args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => Some(child),
+        Err(error) => {
+            log::warn!(
+                "Companion status endpoint not advertised over mDNS ({} unavailable: {})",
+                program,
+                error
+            );
+            None
+        }
+    }
+}
+
+/// A running endpoint and its mDNS advertisement.
+struct Endpoint {
+    stop: Arc<AtomicBool>,
+    server: Option<JoinHandle<()>>,
+    advertiser: Option<Child>,
+}
+
+impl Endpoint {
+    fn start(port: u16, status: SharedStatus) -> io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::UNSPECIFIED, port))?;
+        listener.set_nonblocking(true)?;
+        let stop = Arc::new(AtomicBool::new(false));
+        let server_stop = Arc::clone(&stop);
+        let server = std::thread::Builder::new()
+            .name("companion-status".to_string())
+            .spawn(move || serve(listener, server_stop, status))?;
+        Ok(Self {
+            stop,
+            server: Some(server),
+            advertiser: start_advertiser(port),
+        })
+    }
+
+    /// Withdraw the advertisement and wait for the listener to close, so the
+    /// port can be bound again right away.
+    fn stop(mut self) {
+        if let Some(mut advertiser) = self.advertiser.take() {
+            let _ = advertiser.kill();
+            let _ = advertiser.wait();
+        }
+        self.stop.store(true, Ordering::Release);
+        if let Some(server) = self.server.take() {
+            let _ = server.join();
+        }
+    }
+}
+
+fn start_endpoint(config: &CompanionConfig, status: SharedStatus) -> Option<Endpoint> {
+    let target = format!("mdns://{}:{}", SERVICE_TYPE, config.port);
+    if let Err(error) = network::check(NetworkPurpose::CompanionStatus, &target) {
+        log::info!("{}", error);
+        return None;
+    }
+    match Endpoint::start(config.port, status) {
+        Ok(endpoint) => {
+            log::info!(
+                "Companion status endpoint listening on port {}",
+                config.port
+            );
+            Some(endpoint)
+        }
+        Err(error) => {
+            log::warn!(
+                "Companion status endpoint could not listen on port {}: {}",
+                config.port,
+                error
+            );
+            None
+        }
+    }
+}
+
+/// Keep the endpoint in line with `companion` and offline mode, and its
+/// status in line with the app state.
+pub fn start_companion_loop(state_manager: Arc<AppStateManager>) {
+    crate::runtime::spawn(async move {
+        let status: SharedStatus = Arc::new(Mutex::new(CompanionStatus::from_event(
+            &state_manager.get_event(),
+        )));
+        let mut receiver = state_manager.subscribe();
+        let mut poll = tokio::time::interval(CONFIG_POLL_INTERVAL);
+        poll.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut applied: Option<(CompanionConfig, bool)> = None;
+        let mut endpoint: Option<Endpoint> = None;
+
+        loop {
+            tokio::select! {
+                recv_result = receiver.recv() => {
+                    let event = match recv_result {
+                        Ok(event) => event,
+                        Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
+                            state_manager.get_event()
+                        }
+                        Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                    };
+                    *status.lock().unwrap_or_else(|e| e.into_inner()) =
+                        CompanionStatus::from_event(&event);
+                    continue;
+                }
+                _ = poll.tick() => {}
+            }
+
+            let config = config::load_config();
+            let wanted = (config.companion, config.network.offline_mode);
+            if applied.as_ref() == Some(&wanted) {
+                continue;
+            }
+            if let Some(running) = endpoint.take() {
+                running.stop();
+            }
+            if wanted.0.enabled {
+                endpoint = start_endpoint(&wanted.0, Arc::clone(&status));
+            }
+            applied = Some(wanted);
+        }
+
+        if let Some(running) = endpoint.take() {
+            running.stop();
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::EnableLevel;
+
+    fn status() -> CompanionStatus {
+        CompanionStatus::from_event(&StateEvent {
+            state: AppState::Recording,
+            enabled: true,
+            level: EnableLevel::Enabled,
+            detail: Some("not exposed".to_string()),
+            timestamp: Utc::now(),
+        })
+    }
+
+    #[test]
+    fn test_respond_serves_status() {
+        let response = respond(
+            "GET /status?t=1 HTTP/1.1\r\nHost: 192.168.1.5\r\n\r\n",
+            &status(),
+        );
+        assert_eq!(response.code, 200);
+        let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(body["state"], "recording");
+        assert_eq!(body["recording"], true);
+        assert_eq!(body["enabled"], true);
+        assert!(body.get("detail").is_none());
+    }
+
+    #[test]
+    fn test_respond_is_read_only() {
+        let response = respond("POST /status HTTP/1.1\r\n\r\n", &status());
+        assert_eq!(response.code, 405);
+        assert!(response.to_http().contains("Allow: GET\r\n"));
+
+        assert_eq!(respond("GET / HTTP/1.1\r\n\r\n", &status()).code, 404);
+        assert_eq!(respond("garbage", &status()).code, 400);
+    }
+
+    #[test]
+    fn test_respond_refuses_web_pages() {
+        for header in [
+            "Origin: https://example.com",
+            "origin: null",
+            "Sec-Fetch-Site: cross-site",
+            "Sec-Fetch-Site: same-origin",
+        ] {
+            let request = format!("GET /status HTTP/1.1\r\nHost: localhost\r\n{header}\r\n\r\n");
+            assert_eq!(respond(&request, &status()).code, 403, "{header}");
+        }
+
+        // Typed into the address bar.
+        let request = "GET /status HTTP/1.1\r\nHost: localhost\r\nSec-Fetch-Site: none\r\n\r\n";
+        assert_eq!(respond(request, &status()).code, 200);
+    }
+
+    #[test]
+    fn test_response_has_no_cors_header() {
+        for request in [
+            "GET /status HTTP/1.1\r\n\r\n",
+            "GET /status HTTP/1.1\r\nOrigin: https://example.com\r\n\r\n",
+        ] {
+            let http = respond(request, &status()).to_http().to_ascii_lowercase();
+            assert!(!http.contains("access-control-allow-origin"), "{request}");
+        }
+    }
+
+    #[test]
+    fn test_response_sets_content_length() {
+        let response = respond("GET /status HTTP/1.1\r\n\r\n", &status());
+        let http = response.to_http();
+        let (head, body) = http.split_once("\r\n\r\n").unwrap();
+        assert!(head.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(head.contains(&format!("Content-Length: {}", body.len())));
+    }
+
+    #[test]
+    fn test_advertiser_invocation_publishes_service_and_txt() {
+        let (program, args) = advertiser_invocation("OpenVoicy on host", 47830);
+        if cfg!(target_os = "linux") {
+            assert_eq!(program, "avahi-publish-service");
+        } else {
+            assert_eq!(program, "dns-sd");
+            assert_eq!(args[0], "-R");
+        }
+        assert!(args.contains(&"OpenVoicy on host".to_string()));
+        assert!(args.contains(&SERVICE_TYPE.to_string()));
+        assert!(args.contains(&"47830".to_string()));
+        assert!(args.contains(&"path=/status".to_string()));
+        assert!(args.contains(&"control=none".to_string()));
+    }
+
+    #[test]
+    fn test_endpoint_serves_over_tcp_and_stops() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        listener.set_nonblocking(true).unwrap();
+        let stop = Arc::new(AtomicBool::new(false));
+        let shared = Arc::new(Mutex::new(status()));
+        let server = {
+            let stop = Arc::clone(&stop);
+            std::thread::spawn(move || serve(listener, stop, shared))
+        };
+
+        let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port)).unwrap();
+        stream
+            .write_all(b"GET /status HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("\"state\":\"recording\""));
+
+        stop.store(true, Ordering::Release);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_trickling_client_is_cut_off_at_request_deadline() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port)).unwrap();
+            // One byte well inside each read timeout, never finishing the head.
+            for _ in 0..40 {
+                if stream.write_all(b"G").is_err() {
+                    break;
+                }
+                std::thread::sleep(Duration::from_millis(50));
+            }
+        });
+
+        let (stream, _) = listener.accept().unwrap();
+        let started = Instant::now();
+        let result = handle_connection(
+            stream,
+            &Arc::new(Mutex::new(status())),
+            Duration::from_millis(300),
+        );
+        assert!(result.is_err());
+        assert!(started.elapsed() < Duration::from_secs(1));
+        client.join().unwrap();
+    }
+}
@@ -1,8 +1,11 @@
 //! Configuration persistence with atomic writes and migrations.
 //!
 //! Stores application configuration in a JSON file with:
-//! - Atomic writes (write temp, rename)
-//! - Corruption fallback (regenerate defaults if parse fails)
+//! - Debounced writes on a dedicated writer thread, so callers on hot paths
+//!   (device hot-swap) never wait on disk
+//! - Atomic writes (write and sync temp, rename)
+//! - A checksum and last-good backup beside the file; a config that no longer
+//!   parses is restored from the backup, or regenerated from defaults
 //! - Schema versioning with migration support
 //! - Platform-specific config paths
 
@@ -12,8 +15,13 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::fs;
-use std::io;
-use std::path::PathBuf;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 /// Current schema version.
@@ -24,45 +32,104 @@ const CONFIG_DIR_NAME: &str = "OpenVoicy";
 
 /// Config file name.
 const CONFIG_FILE_NAME: &str = "config.json";
+
+/// Quiet time after the last save before the config is written.
+const SAVE_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Longest a save waits while further saves keep arriving.
+const MAX_SAVE_DELAY: Duration = Duration::from_secs(2);
 const SENSITIVE_FIELD_KEYWORDS: [&str; 4] = ["token", "key", "secret", "password"];
 
-const ROOT_CONFIG_FIELDS: [&str; 10] = [
+const ROOT_CONFIG_FIELDS: [&str; 18] = [
     "schema_version",
     "audio",
     "hotkeys",
     "injection",
     "model",
     "replacements",
+    "replacement_groups",
     "ui",
     "history",
     "presets",
     "supervisor",
+    "vocabulary",
+    "word_filter",
+    "sync",
+    "network",
+    "companion",
+    "crash_reporting",
+    "power",
 ];
 
-const AUDIO_CONFIG_FIELDS: [&str; 6] = [
+const AUDIO_CONFIG_FIELDS: [&str; 17] = [
     "device_uid",
     "audio_cues_enabled",
     "trim_silence",
     "vad_enabled",
+    "vad_preset",
     "vad_silence_ms",
     "vad_min_speech_ms",
+    "diarization_enabled",
+    "auto_punctuation",
+    "continuous_dictation",
+    "live_flush_secs",
+    "countdown_secs",
+    "mute_cues_in_calls",
+    "mute_cues_in_dnd",
+    "in_meeting",
+    "auto_split_recordings",
+    "input_gains",
 ];
 
-const HOTKEY_CONFIG_FIELDS: [&str; 3] = ["primary", "copy_last", "mode"];
+const HOTKEY_CONFIG_FIELDS: [&str; 8] = [
+    "primary",
+    "copy_last",
+    "dictate_raw",
+    "cancel",
+    "mode",
+    "idle_auto_disable_minutes",
+    "typing_suppression_ms",
+    "profiles",
+];
 
-const INJECTION_CONFIG_FIELDS: [&str; 5] = [
+const INJECTION_CONFIG_FIELDS: [&str; 17] = [
     "paste_delay_ms",
     "restore_clipboard",
     "suffix",
     "focus_guard_enabled",
     "app_overrides",
+    "mode",
+    "chunk_threshold_chars",
+    "chunk_size_chars",
+    "focus_strategy",
+    "paste_strategy",
+    "smart_spacing",
+    "verify_paste",
+    "calibrate_paste_delay",
+    "confirm_above_chars",
+    "confirm_above_minutes",
+    "primary_selection",
+    "busy_target_wait_secs",
 ];
 
-const APP_OVERRIDE_FIELDS: [&str; 2] = ["paste_delay_ms", "use_clipboard_only"];
+const APP_OVERRIDE_FIELDS: [&str; 3] = [
+    "paste_delay_ms",
+    "use_clipboard_only",
+    "word_filter_enabled",
+];
 
-const MODEL_CONFIG_FIELDS: [&str; 4] = ["model_id", "device", "preferred_device", "language"];
+const MODEL_CONFIG_FIELDS: [&str; 8] = [
+    "model_id",
+    "device",
+    "preferred_device",
+    "language",
+    "beam_size",
+    "preload_on_start",
+    "preload_delay_ms",
+    "asr_backend",
+];
 
-const REPLACEMENT_RULE_FIELDS: [&str; 9] = [
+const REPLACEMENT_RULE_FIELDS: [&str; 11] = [
     "id",
     "kind",
     "pattern",
@@ -72,24 +139,90 @@ const REPLACEMENT_RULE_FIELDS: [&str; 9] = [
     "case_sensitive",
     "description",
     "origin",
+    "group",
+    "order",
 ];
 
-const UI_CONFIG_FIELDS: [&str; 8] = [
+const REPLACEMENT_GROUPS_CONFIG_FIELDS: [&str; 2] = ["groups", "active_profile"];
+
+const REPLACEMENT_GROUP_FIELDS: [&str; 5] = ["id", "name", "enabled", "order", "profiles"];
+
+const UI_CONFIG_FIELDS: [&str; 20] = [
     "show_on_startup",
     "window_width",
     "window_height",
     "theme",
     "onboarding_completed",
     "overlay_enabled",
+    "overlay_theme",
+    "overlay_scale",
+    "overlay_opacity",
+    "overlay_show_waveform",
+    "overlay_monitor",
     "locale",
     "reduce_motion",
+    "announcements_enabled",
+    "announce_recording",
+    "announce_transcription",
+    "announce_status",
+    "announce_errors",
+    "always_visible_recording_indicator",
+    "screen_share_policy",
 ];
 
-const HISTORY_CONFIG_FIELDS: [&str; 3] = ["persistence_mode", "max_entries", "encrypt_at_rest"];
+const HISTORY_CONFIG_FIELDS: [&str; 8] = [
+    "persistence_mode",
+    "max_entries",
+    "encrypt_at_rest",
+    "capture_window_context",
+    "sanitize_window_title",
+    "window_title_max_chars",
+    "retention_days",
+    "max_storage_mb",
+];
 
 const PRESETS_CONFIG_FIELDS: [&str; 1] = ["enabled_presets"];
 
-const SUPERVISOR_CONFIG_FIELDS: [&str; 1] = ["captured_log_max_lines"];
+const SUPERVISOR_CONFIG_FIELDS: [&str; 9] = [
+    "captured_log_max_lines",
+    "protocol_error_tolerance",
+    "watchdog_ping_interval_secs",
+    "watchdog_ping_timeout_secs",
+    "watchdog_hang_threshold_secs",
+    "warm_spare_restarts",
+    "error_auto_recovery",
+    "error_recovery_backoff_secs",
+    "error_recovery_max_attempts",
+];
+
+const VOCABULARY_CONFIG_FIELDS: [&str; 2] = ["terms", "learn_capitalization"];
+
+const WORD_FILTER_CONFIG_FIELDS: [&str; 6] = [
+    "enabled",
+    "mode",
+    "use_profanity_preset",
+    "words",
+    "keep_unfiltered",
+    "profiles",
+];
+
+const SYNC_CONFIG_FIELDS: [&str; 7] = [
+    "enabled",
+    "backend",
+    "url",
+    "s3_region",
+    "credentials_name",
+    "passphrase_name",
+    "interval_minutes",
+];
+
+const NETWORK_CONFIG_FIELDS: [&str; 1] = ["offline_mode"];
+
+const COMPANION_CONFIG_FIELDS: [&str; 2] = ["enabled", "port"];
+
+const CRASH_REPORTING_CONFIG_FIELDS: [&str; 2] = ["upload_enabled", "endpoint"];
+
+const POWER_CONFIG_FIELDS: [&str; 1] = ["low_power_on_battery"];
 
 /// Root application configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -118,6 +251,10 @@ pub struct AppConfig {
     #[serde(default)]
     pub replacements: Vec<ReplacementRule>,
 
+    /// Replacement rule groups and the active rule profile.
+    #[serde(default)]
+    pub replacement_groups: ReplacementGroupsConfig,
+
     /// UI settings.
     #[serde(default)]
     pub ui: UiConfig,
@@ -133,6 +270,34 @@ pub struct AppConfig {
     /// Supervisor settings (sidecar lifecycle management).
     #[serde(default)]
     pub supervisor: SupervisorConfig,
+
+    /// Custom vocabulary (hotword boost list).
+    #[serde(default)]
+    pub vocabulary: VocabularyConfig,
+
+    /// Profanity / sensitive-word filter applied before injection and history.
+    #[serde(default)]
+    pub word_filter: WordFilterConfig,
+
+    /// Encrypted settings sync across machines.
+    #[serde(default)]
+    pub sync: SyncConfig,
+
+    /// Network access (offline mode).
+    #[serde(default)]
+    pub network: NetworkConfig,
+
+    /// LAN status endpoint for companion devices.
+    #[serde(default)]
+    pub companion: CompanionConfig,
+
+    /// Opt-in upload of crash reports.
+    #[serde(default)]
+    pub crash_reporting: CrashReportingConfig,
+
+    /// Energy saving on battery power.
+    #[serde(default)]
+    pub power: PowerConfig,
 }
 
 impl Default for AppConfig {
@@ -144,10 +309,18 @@ impl Default for AppConfig {
             injection: InjectionConfig::default(),
             model: None, // Use defaults from sidecar
             replacements: Vec::new(),
+            replacement_groups: ReplacementGroupsConfig::default(),
             ui: UiConfig::default(),
             history: HistoryConfig::default(),
             presets: PresetsConfig::default(),
             supervisor: SupervisorConfig::default(),
+            vocabulary: VocabularyConfig::default(),
+            word_filter: WordFilterConfig::default(),
+            sync: SyncConfig::default(),
+            network: NetworkConfig::default(),
+            companion: CompanionConfig::default(),
+            crash_reporting: CrashReportingConfig::default(),
+            power: PowerConfig::default(),
         }
     }
 }
@@ -167,6 +340,33 @@ pub struct ModelConfig {
     /// ASR language hint: None for sidecar default, "auto" for autodetect, or ISO 639-1 code.
     #[serde(default)]
     pub language: Option<String>,
+    /// Decoder beam width override; None applies the catalog recommendation.
+    #[serde(default)]
+    pub beam_size: Option<u32>,
+    /// Load the model into memory as soon as the sidecar is ready instead of
+    /// on first use.
+    #[serde(default)]
+    pub preload_on_start: bool,
+    /// Delay before the startup preload, to keep launch responsive.
+    #[serde(default)]
+    pub preload_delay_ms: u32,
+    /// Engine that transcribes dictation.
+    #[serde(default)]
+    pub asr_backend: AsrBackend,
+}
+
+/// Speech recognition engine selection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AsrBackend {
+    /// The sidecar, falling back to the embedded engine when it keeps
+    /// failing to start.
+    #[default]
+    Auto,
+    /// The sidecar only.
+    Sidecar,
+    /// The embedded whisper.cpp engine only.
+    Embedded,
 }
 
 impl ModelConfig {
@@ -183,6 +383,39 @@ impl ModelConfig {
 }
 
 impl AppConfig {
+    /// Hotkeys in effect for the active profile.
+    pub fn effective_hotkeys(&self) -> HotkeyConfig {
+        self.hotkeys
+            .for_profile(self.replacement_groups.active_profile.as_deref())
+    }
+
+    /// Whether the word filter runs for the active profile, before per-app
+    /// overrides.
+    pub fn word_filter_enabled(&self) -> bool {
+        self.replacement_groups
+            .active_profile
+            .as_deref()
+            .and_then(|profile| self.word_filter.profiles.get(profile))
+            .copied()
+            .unwrap_or(self.word_filter.enabled)
+    }
+
+    /// The active profile, when it has its own hotkeys.
+    pub fn hotkey_profile(&self) -> Option<&str> {
+        self.replacement_groups
+            .active_profile
+            .as_deref()
+            .filter(|profile| self.hotkeys.profiles.contains_key(*profile))
+    }
+
+    /// Configured ASR backend.
+    pub fn asr_backend(&self) -> AsrBackend {
+        self.model
+            .as_ref()
+            .map(|model| model.asr_backend)
+            .unwrap_or_default()
+    }
+
     /// Resolve effective sidecar device preference for model initialization.
     pub fn effective_model_device_pref(&self) -> String {
         self.model
@@ -203,6 +436,38 @@ impl AppConfig {
             );
         }
 
+        let original_chunk_size_chars = self.injection.chunk_size_chars;
+        self.injection.chunk_size_chars = self.injection.chunk_size_chars.clamp(100, 10_000);
+        if self.injection.chunk_size_chars != original_chunk_size_chars {
+            log::warn!(
+                "injection.chunk_size_chars clamped from {} to {}",
+                original_chunk_size_chars,
+                self.injection.chunk_size_chars
+            );
+        }
+
+        let original_confirm_minutes = self.injection.confirm_above_minutes;
+        self.injection.confirm_above_minutes = self.injection.confirm_above_minutes.min(1440);
+        if self.injection.confirm_above_minutes != original_confirm_minutes {
+            log::warn!(
+                "injection.confirm_above_minutes clamped from {} to {}",
+                original_confirm_minutes,
+                self.injection.confirm_above_minutes
+            );
+        }
+
+        // Below the injection dispatcher's 90 s hold timeout, after which later
+        // sessions stop waiting for the deferred one.
+        let original_busy_wait = self.injection.busy_target_wait_secs;
+        self.injection.busy_target_wait_secs = self.injection.busy_target_wait_secs.min(60);
+        if self.injection.busy_target_wait_secs != original_busy_wait {
+            log::warn!(
+                "injection.busy_target_wait_secs clamped from {} to {}",
+                original_busy_wait,
+                self.injection.busy_target_wait_secs
+            );
+        }
+
         let invalid_override_keys: Vec<String> = self
             .injection
             .app_overrides
@@ -250,6 +515,52 @@ impl AppConfig {
             );
         }
 
+        let original_live_flush_secs = self.audio.live_flush_secs;
+        if self.audio.live_flush_secs != 0 {
+            self.audio.live_flush_secs = self.audio.live_flush_secs.clamp(3, 60);
+        }
+        if self.audio.live_flush_secs != original_live_flush_secs {
+            log::warn!(
+                "audio.live_flush_secs clamped from {} to {}",
+                original_live_flush_secs,
+                self.audio.live_flush_secs
+            );
+        }
+
+        let original_countdown_secs = self.audio.countdown_secs;
+        self.audio.countdown_secs = self.audio.countdown_secs.min(10);
+        if self.audio.countdown_secs != original_countdown_secs {
+            log::warn!(
+                "audio.countdown_secs clamped from {} to {}",
+                original_countdown_secs,
+                self.audio.countdown_secs
+            );
+        }
+
+        for (device_uid, gain_db) in &mut self.audio.input_gains {
+            let clamped = clamp_finite(*gain_db, &INPUT_GAIN_DB_RANGE, 0.0);
+            if clamped != *gain_db {
+                log::warn!(
+                    "audio.input_gains['{}'] clamped from {} to {}",
+                    device_uid,
+                    gain_db,
+                    clamped
+                );
+                *gain_db = clamped;
+            }
+        }
+
+        // Timings set directly win over a named preset they no longer match.
+        if self.audio.vad_preset.timings().is_some_and(|timings| {
+            timings != (self.audio.vad_silence_ms, self.audio.vad_min_speech_ms)
+        }) {
+            log::info!(
+                "audio.vad_preset {:?} does not match the configured timings; using custom",
+                self.audio.vad_preset
+            );
+            self.audio.vad_preset = VadPreset::Custom;
+        }
+
         // Validate hotkey format (basic check - ensure non-empty)
         if self.hotkeys.primary.is_empty() {
             log::warn!(
@@ -265,6 +576,45 @@ impl AppConfig {
             );
             self.hotkeys.copy_last = HotkeyConfig::default().copy_last;
         }
+        self.hotkeys
+            .profiles
+            .retain(|profile, _| !profile.trim().is_empty());
+        for (profile, overrides) in &mut self.hotkeys.profiles {
+            for (field, binding) in [
+                ("primary", &mut overrides.primary),
+                ("copy_last", &mut overrides.copy_last),
+            ] {
+                if binding
+                    .as_deref()
+                    .is_some_and(|value| value.trim().is_empty())
+                {
+                    log::warn!(
+                        "hotkeys.profiles.{}.{} is empty; using the base binding",
+                        profile,
+                        field
+                    );
+                    *binding = None;
+                }
+            }
+        }
+        let original_idle_minutes = self.hotkeys.idle_auto_disable_minutes;
+        self.hotkeys.idle_auto_disable_minutes = self.hotkeys.idle_auto_disable_minutes.min(1440);
+        if self.hotkeys.idle_auto_disable_minutes != original_idle_minutes {
+            log::warn!(
+                "hotkeys.idle_auto_disable_minutes clamped from {} to {}",
+                original_idle_minutes,
+                self.hotkeys.idle_auto_disable_minutes
+            );
+        }
+        let original_typing_suppression_ms = self.hotkeys.typing_suppression_ms;
+        self.hotkeys.typing_suppression_ms = self.hotkeys.typing_suppression_ms.min(2000);
+        if self.hotkeys.typing_suppression_ms != original_typing_suppression_ms {
+            log::warn!(
+                "hotkeys.typing_suppression_ms clamped from {} to {}",
+                original_typing_suppression_ms,
+                self.hotkeys.typing_suppression_ms
+            );
+        }
 
         // Validate window dimensions (minimum 200x200)
         let original_window_width = self.ui.window_width;
@@ -306,6 +656,24 @@ impl AppConfig {
                     model.language = None;
                 }
             }
+
+            if let Some(beam_size) = model.beam_size {
+                let clamped = beam_size.clamp(1, 16);
+                if clamped != beam_size {
+                    log::warn!("model.beam_size clamped from {} to {}", beam_size, clamped);
+                    model.beam_size = Some(clamped);
+                }
+            }
+
+            let original_preload_delay_ms = model.preload_delay_ms;
+            model.preload_delay_ms = model.preload_delay_ms.min(60_000);
+            if model.preload_delay_ms != original_preload_delay_ms {
+                log::warn!(
+                    "model.preload_delay_ms clamped from {} to {}",
+                    original_preload_delay_ms,
+                    model.preload_delay_ms
+                );
+            }
         }
 
         let effective_model_device_pref = self.effective_model_device_pref();
@@ -337,6 +705,33 @@ impl AppConfig {
             }
         }
 
+        let mut seen_group_ids = std::collections::HashSet::new();
+        self.replacement_groups.groups.retain(|group| {
+            if !is_valid_replacement_rule_id(&group.id) {
+                log::warn!(
+                    "replacement group has invalid id '{}'; dropping group",
+                    group.id
+                );
+                return false;
+            }
+            if !seen_group_ids.insert(group.id.clone()) {
+                log::warn!(
+                    "replacement group '{}' is defined more than once; dropping duplicate",
+                    group.id
+                );
+                return false;
+            }
+            true
+        });
+        if self
+            .replacement_groups
+            .active_profile
+            .as_deref()
+            .is_some_and(|profile| profile.trim().is_empty())
+        {
+            self.replacement_groups.active_profile = None;
+        }
+
         // Validate theme selection
         if !matches!(self.ui.theme.as_str(), "system" | "light" | "dark") {
             log::warn!(
@@ -347,6 +742,73 @@ impl AppConfig {
             self.ui.theme = default_theme();
         }
 
+        // Validate overlay appearance
+        if !matches!(self.ui.overlay_theme.as_str(), "system" | "light" | "dark") {
+            log::warn!(
+                "Invalid ui.overlay_theme value '{}', resetting to '{}'",
+                self.ui.overlay_theme,
+                default_theme()
+            );
+            self.ui.overlay_theme = default_theme();
+        }
+        self.ui.overlay_scale = clamp_finite(
+            self.ui.overlay_scale,
+            &crate::overlay::OVERLAY_SCALE_RANGE,
+            default_overlay_scale(),
+        );
+        self.ui.overlay_opacity = clamp_finite(
+            self.ui.overlay_opacity,
+            &crate::overlay::OVERLAY_OPACITY_RANGE,
+            default_overlay_opacity(),
+        );
+        if self
+            .ui
+            .overlay_monitor
+            .as_deref()
+            .is_some_and(|name| name.trim().is_empty())
+        {
+            self.ui.overlay_monitor = None;
+        }
+
+        // Validate global injection mode
+        if crate::injection::InjectionMode::parse(&self.injection.mode).is_none() {
+            log::warn!(
+                "Invalid injection.mode value '{}', resetting to '{}'",
+                self.injection.mode,
+                default_injection_mode()
+            );
+            self.injection.mode = default_injection_mode();
+        }
+
+        if crate::injection::PrimarySelectionMode::parse(&self.injection.primary_selection)
+            .is_none()
+        {
+            log::warn!(
+                "Invalid injection.primary_selection value '{}', resetting to '{}'",
+                self.injection.primary_selection,
+                default_primary_selection()
+            );
+            self.injection.primary_selection = default_primary_selection();
+        }
+
+        // Validate Linux focus/paste strategy overrides
+        if !crate::display_strategy::is_valid_focus_strategy(&self.injection.focus_strategy) {
+            log::warn!(
+                "Invalid injection.focus_strategy value '{}', resetting to '{}'",
+                self.injection.focus_strategy,
+                default_platform_strategy()
+            );
+            self.injection.focus_strategy = default_platform_strategy();
+        }
+        if !crate::display_strategy::is_valid_paste_strategy(&self.injection.paste_strategy) {
+            log::warn!(
+                "Invalid injection.paste_strategy value '{}', resetting to '{}'",
+                self.injection.paste_strategy,
+                default_platform_strategy()
+            );
+            self.injection.paste_strategy = default_platform_strategy();
+        }
+
         // Validate history persistence mode
         if !matches!(self.history.persistence_mode.as_str(), "memory" | "disk") {
             log::warn!(
@@ -367,14 +829,128 @@ impl AppConfig {
             );
         }
 
+        let original_title_max_chars = self.history.window_title_max_chars;
+        self.history.window_title_max_chars = self.history.window_title_max_chars.min(512);
+        if self.history.window_title_max_chars != original_title_max_chars {
+            log::warn!(
+                "history.window_title_max_chars clamped from {} to {}",
+                original_title_max_chars,
+                self.history.window_title_max_chars
+            );
+        }
+
+        let original_retention_days = self.history.retention_days;
+        self.history.retention_days = self.history.retention_days.min(3650);
+        if self.history.retention_days != original_retention_days {
+            log::warn!(
+                "history.retention_days clamped from {} to {}",
+                original_retention_days,
+                self.history.retention_days
+            );
+        }
+
+        let original_max_storage_mb = self.history.max_storage_mb;
+        self.history.max_storage_mb = self.history.max_storage_mb.min(1024);
+        if self.history.max_storage_mb != original_max_storage_mb {
+            log::warn!(
+                "history.max_storage_mb clamped from {} to {}",
+                original_max_storage_mb,
+                self.history.max_storage_mb
+            );
+        }
+
         if self.history.persistence_mode == "disk" && !self.history.encrypt_at_rest {
             log::warn!(
                 "history.encrypt_at_rest is disabled while persistence_mode is 'disk'; leaving explicit user setting"
             );
         }
+
+        let original_vocabulary_len = self.vocabulary.terms.len();
+        let clamped_terms = crate::vocabulary::clamp_terms(&self.vocabulary.terms);
+        if clamped_terms != self.vocabulary.terms {
+            log::warn!(
+                "vocabulary.terms normalized from {} to {} entries",
+                original_vocabulary_len,
+                clamped_terms.len()
+            );
+            self.vocabulary.terms = clamped_terms;
+        }
+
+        // Validate word filter mode
+        if crate::word_filter::FilterMode::parse(&self.word_filter.mode).is_none() {
+            log::warn!(
+                "Invalid word_filter.mode value '{}', resetting to 'mask'",
+                self.word_filter.mode
+            );
+            self.word_filter.mode = "mask".to_string();
+        }
+
+        // Validate sync settings
+        if !matches!(self.sync.backend.as_str(), "webdav" | "s3") {
+            log::warn!(
+                "Invalid sync.backend value '{}', resetting to 'webdav'",
+                self.sync.backend
+            );
+            self.sync.backend = "webdav".to_string();
+        }
+        let default_sync = SyncConfig::default();
+        if !crate::secrets::is_valid_secret_name(&self.sync.credentials_name) {
+            self.sync.credentials_name = default_sync.credentials_name;
+        }
+        if !crate::secrets::is_valid_secret_name(&self.sync.passphrase_name) {
+            self.sync.passphrase_name = default_sync.passphrase_name;
+        }
+        // 0 means manual sync only; otherwise every 5 minutes to once a day
+        if self.sync.interval_minutes != 0 {
+            self.sync.interval_minutes = self.sync.interval_minutes.clamp(5, 1440);
+        }
+        if self.companion.port < 1024 {
+            log::warn!(
+                "companion.port {} is reserved, resetting to {}",
+                self.companion.port,
+                DEFAULT_COMPANION_PORT
+            );
+            self.companion.port = DEFAULT_COMPANION_PORT;
+        }
+
+        // A ping must finish before the next one is due, and a single missed
+        // ping must not count as a hang.
+        let supervisor = &mut self.supervisor;
+        let original_watchdog = (
+            supervisor.watchdog_ping_interval_secs,
+            supervisor.watchdog_ping_timeout_secs,
+            supervisor.watchdog_hang_threshold_secs,
+        );
+        supervisor.watchdog_ping_interval_secs =
+            supervisor.watchdog_ping_interval_secs.clamp(2, 300);
+        supervisor.watchdog_ping_timeout_secs = supervisor
+            .watchdog_ping_timeout_secs
+            .clamp(1, supervisor.watchdog_ping_interval_secs);
+        supervisor.watchdog_hang_threshold_secs = supervisor
+            .watchdog_hang_threshold_secs
+            .clamp(supervisor.watchdog_ping_interval_secs * 2, 3600);
+        let clamped_watchdog = (
+            supervisor.watchdog_ping_interval_secs,
+            supervisor.watchdog_ping_timeout_secs,
+            supervisor.watchdog_hang_threshold_secs,
+        );
+        if clamped_watchdog != original_watchdog {
+            log::warn!(
+                "supervisor watchdog (interval, timeout, hang) secs clamped from {:?} to {:?}",
+                original_watchdog,
+                clamped_watchdog
+            );
+        }
+        supervisor.error_recovery_backoff_secs =
+            supervisor.error_recovery_backoff_secs.clamp(1, 300);
+        supervisor.error_recovery_max_attempts =
+            supervisor.error_recovery_max_attempts.clamp(1, 20);
     }
 }
 
+/// Supported input gain in dB; the sidecar clamps to the same range.
+pub const INPUT_GAIN_DB_RANGE: std::ops::RangeInclusive<f64> = -20.0..=30.0;
+
 /// Audio configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -389,12 +965,51 @@ pub struct AudioConfig {
     /// Whether Voice Activity Detection auto-stop is enabled.
     #[serde(default)]
     pub vad_enabled: bool,
+    /// Named VAD timing preset; anything but `custom` overrides the timings below.
+    #[serde(default)]
+    pub vad_preset: VadPreset,
     /// Silence duration threshold before VAD auto-stop triggers.
     #[serde(default = "default_vad_silence_ms")]
     pub vad_silence_ms: u32,
     /// Minimum speech duration before VAD can auto-stop.
     #[serde(default = "default_vad_min_speech_ms")]
     pub vad_min_speech_ms: u32,
+    /// Whether the sidecar should label transcript segments by speaker, for
+    /// recordings made while `in_meeting` is set and for transcribed files.
+    #[serde(default)]
+    pub diarization_enabled: bool,
+    /// Whether the ASR backend restores punctuation and capitalization.
+    #[serde(default = "default_true")]
+    pub auto_punctuation: bool,
+    /// Keep recording after each pause and transcribe utterances as they end.
+    #[serde(default)]
+    pub continuous_dictation: bool,
+    /// In continuous dictation, deliver speech at the next short gap once this
+    /// many seconds have been buffered, so text appears while talking without
+    /// a full pause. 0 = only at pauses.
+    #[serde(default)]
+    pub live_flush_secs: u32,
+    /// Seconds counted down between the hotkey press and audio capture;
+    /// pressing the hotkey again cancels. 0 = start immediately.
+    #[serde(default)]
+    pub countdown_secs: u32,
+    /// Mute audio cues while a conferencing app is using the microphone.
+    #[serde(default = "default_true")]
+    pub mute_cues_in_calls: bool,
+    /// Mute audio cues while the OS is in Do-Not-Disturb / Focus mode.
+    #[serde(default = "default_true")]
+    pub mute_cues_in_dnd: bool,
+    /// Mute audio cues until turned off again ("in a meeting").
+    #[serde(default)]
+    pub in_meeting: bool,
+    /// At the maximum recording length, transcribe what was said so far and
+    /// keep recording instead of stopping.
+    #[serde(default)]
+    pub auto_split_recordings: bool,
+    /// Software input gain in dB per device UID, applied by the sidecar to
+    /// captured audio. Devices without an entry are captured unchanged.
+    #[serde(default)]
+    pub input_gains: HashMap<String, f64>,
 }
 
 impl Default for AudioConfig {
@@ -404,10 +1019,70 @@ impl Default for AudioConfig {
             audio_cues_enabled: true,
             trim_silence: true,
             vad_enabled: false,
+            vad_preset: VadPreset::default(),
             vad_silence_ms: default_vad_silence_ms(),
             vad_min_speech_ms: default_vad_min_speech_ms(),
+            diarization_enabled: false,
+            auto_punctuation: true,
+            continuous_dictation: false,
+            live_flush_secs: 0,
+            countdown_secs: 0,
+            mute_cues_in_calls: true,
+            mute_cues_in_dnd: true,
+            in_meeting: false,
+            auto_split_recordings: false,
+            input_gains: HashMap::new(),
+        }
+    }
+}
+
+/// Named VAD sensitivity preset.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VadPreset {
+    /// Stops soon after a short pause; suits quick commands.
+    Aggressive,
+    /// The default timings.
+    #[default]
+    Balanced,
+    /// Tolerates long pauses; suits thinking aloud.
+    Relaxed,
+    /// Uses `vad_silence_ms` and `vad_min_speech_ms` as configured.
+    Custom,
+}
+
+impl VadPreset {
+    const NAMED: [VadPreset; 3] = [Self::Aggressive, Self::Balanced, Self::Relaxed];
+
+    /// `(silence_ms, min_speech_ms)` for named presets; `None` for custom.
+    pub fn timings(self) -> Option<(u32, u32)> {
+        match self {
+            Self::Aggressive => Some((600, 150)),
+            Self::Balanced => Some((default_vad_silence_ms(), default_vad_min_speech_ms())),
+            Self::Relaxed => Some((2500, 400)),
+            Self::Custom => None,
         }
     }
+
+    /// Switch `audio` to this preset's timings when it differs from
+    /// `previous`; picking a named preset is what applies its timings.
+    pub fn apply_change(self, previous: VadPreset, audio: &mut AudioConfig) {
+        if self == previous {
+            return;
+        }
+        if let Some((silence_ms, min_speech_ms)) = self.timings() {
+            audio.vad_silence_ms = silence_ms;
+            audio.vad_min_speech_ms = min_speech_ms;
+        }
+    }
+
+    /// The named preset with exactly these timings, or `Custom`.
+    pub fn matching(silence_ms: u32, min_speech_ms: u32) -> Self {
+        Self::NAMED
+            .into_iter()
+            .find(|preset| preset.timings() == Some((silence_ms, min_speech_ms)))
+            .unwrap_or(Self::Custom)
+    }
 }
 
 /// Hotkey mode.
@@ -422,15 +1097,74 @@ pub enum HotkeyMode {
 }
 
 /// Hotkey configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct HotkeyConfig {
     /// Primary hotkey for recording.
     pub primary: String,
     /// Hotkey to copy last transcript.
     pub copy_last: String,
+    /// Hotkey that records with auto punctuation off for one dictation ("dictate raw").
+    /// Empty disables it.
+    pub dictate_raw: String,
+    /// Hotkey that discards the current recording without transcribing it.
+    /// Only registered while recording, so a bare key like Escape is safe.
+    /// Empty disables it.
+    pub cancel: String,
     /// Hotkey mode (hold vs toggle).
     pub mode: HotkeyMode,
+    /// Pause hotkey listening after this many minutes without a dictation.
+    /// The next hotkey press re-enables it. 0 disables the idle timer.
+    pub idle_auto_disable_minutes: u32,
+    /// Ignore the recording hotkey if another key was pressed within this many
+    /// milliseconds (key rollover while typing). 0 disables the guard.
+    pub typing_suppression_ms: u32,
+    /// Bindings that replace the ones above while a profile is active, keyed
+    /// by profile name (`replacement_groups.active_profile`).
+    #[serde(default)]
+    pub profiles: HashMap<String, ProfileHotkeys>,
+}
+
+impl HotkeyConfig {
+    /// These hotkeys with the overrides of `profile` applied.
+    pub fn for_profile(&self, profile: Option<&str>) -> HotkeyConfig {
+        let mut effective = self.clone();
+        let Some(overrides) = profile.and_then(|profile| self.profiles.get(profile)) else {
+            return effective;
+        };
+        for (binding, value) in [
+            (&mut effective.primary, &overrides.primary),
+            (&mut effective.copy_last, &overrides.copy_last),
+            (&mut effective.dictate_raw, &overrides.dictate_raw),
+            (&mut effective.cancel, &overrides.cancel),
+        ] {
+            if let Some(value) = value {
+                binding.clone_from(value);
+            }
+        }
+        if let Some(mode) = overrides.mode {
+            effective.mode = mode;
+        }
+        effective
+    }
+}
+
+/// Per-profile hotkey overrides; unset fields keep the base binding.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProfileHotkeys {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copy_last: Option<String>,
+    /// Empty disables dictate-raw in this profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dictate_raw: Option<String>,
+    /// Empty disables the cancel hotkey in this profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<HotkeyMode>,
 }
 
 impl Default for HotkeyConfig {
@@ -438,7 +1172,12 @@ impl Default for HotkeyConfig {
         Self {
             primary: "Ctrl+Shift+Space".to_string(),
             copy_last: "Ctrl+Shift+V".to_string(),
+            dictate_raw: String::new(),
+            cancel: "Escape".to_string(),
             mode: HotkeyMode::Hold,
+            idle_auto_disable_minutes: 0,
+            typing_suppression_ms: 0,
+            profiles: HashMap::new(),
         }
     }
 }
@@ -458,6 +1197,50 @@ pub struct InjectionConfig {
     /// Per-application overrides keyed by app identifier.
     #[serde(default)]
     pub app_overrides: HashMap<String, AppOverride>,
+    /// Global output mode: "inject", "clipboard_only", or "ask".
+    #[serde(default = "default_injection_mode")]
+    pub mode: String,
+    /// Texts longer than this many characters are pasted in chunks; 0 disables chunking.
+    #[serde(default = "default_chunk_threshold_chars")]
+    pub chunk_threshold_chars: u32,
+    /// Characters per pasted chunk. Clamped to 100-10000.
+    #[serde(default = "default_chunk_size_chars")]
+    pub chunk_size_chars: u32,
+    /// Linux focus capture strategy: "auto", "xdotool", "sway", "hyprland", or "unavailable".
+    #[serde(default = "default_platform_strategy")]
+    pub focus_strategy: String,
+    /// Linux paste keystroke strategy: "auto", "xdotool", "wtype", "portal", or "clipboard_only".
+    #[serde(default = "default_platform_strategy")]
+    pub paste_strategy: String,
+    /// Adapt leading spacing and capitalization to the text before the caret,
+    /// where the platform accessibility API exposes it.
+    #[serde(default)]
+    pub smart_spacing: bool,
+    /// Read the target field back after a paste and retype it with
+    /// keystrokes once if the text did not arrive.
+    #[serde(default)]
+    pub verify_paste: bool,
+    /// Measure each paste and learn a per-app delay that replaces
+    /// `paste_delay_ms` for apps without an override.
+    #[serde(default)]
+    pub calibrate_paste_delay: bool,
+    /// Hold back transcripts longer than this many characters until the
+    /// user confirms them; 0 disables the guard.
+    #[serde(default)]
+    pub confirm_above_chars: u32,
+    /// Hold back transcripts of recordings longer than this many minutes
+    /// until the user confirms them; 0 disables the guard.
+    #[serde(default)]
+    pub confirm_above_minutes: u32,
+    /// Linux PRIMARY selection (middle-click paste): "off", "also" (in
+    /// addition to the clipboard), or "instead" (in place of the clipboard).
+    #[serde(default = "default_primary_selection")]
+    pub primary_selection: String,
+    /// How long a transcript waits in the pending insert queue for a busy
+    /// (not responding) target app before falling back to the clipboard;
+    /// 0 falls back right away.
+    #[serde(default = "default_busy_target_wait_secs")]
+    pub busy_target_wait_secs: u32,
 }
 
 /// Per-application injection override.
@@ -469,6 +1252,9 @@ pub struct AppOverride {
     /// Whether clipboard-only injection should be used for this app.
     #[serde(default)]
     pub use_clipboard_only: Option<bool>,
+    /// Whether the word filter runs for this app (inherits `word_filter.enabled` when unset).
+    #[serde(default)]
+    pub word_filter_enabled: Option<bool>,
 }
 
 impl Default for InjectionConfig {
@@ -479,6 +1265,18 @@ impl Default for InjectionConfig {
             suffix: " ".to_string(), // Single space
             focus_guard_enabled: true,
             app_overrides: HashMap::new(),
+            mode: default_injection_mode(),
+            chunk_threshold_chars: default_chunk_threshold_chars(),
+            chunk_size_chars: default_chunk_size_chars(),
+            focus_strategy: default_platform_strategy(),
+            paste_strategy: default_platform_strategy(),
+            smart_spacing: false,
+            verify_paste: false,
+            calibrate_paste_delay: false,
+            confirm_above_chars: 0,
+            confirm_above_minutes: 0,
+            primary_selection: default_primary_selection(),
+            busy_target_wait_secs: default_busy_target_wait_secs(),
         }
     }
 }
@@ -510,6 +1308,41 @@ pub struct ReplacementRule {
     /// Optional origin identifier (e.g., "user" or "preset:punctuation").
     #[serde(default)]
     pub origin: Option<String>,
+    /// Optional group ID from `replacement_groups.groups`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Position within the rule's group; lower runs first.
+    #[serde(default)]
+    pub order: i32,
+}
+
+/// Replacement rule groups configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReplacementGroupsConfig {
+    /// Named groups that rules can be assigned to.
+    pub groups: Vec<ReplacementGroup>,
+    /// Active rule profile; groups restricted to other profiles are skipped.
+    pub active_profile: Option<String>,
+}
+
+/// A named group of replacement rules.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplacementGroup {
+    /// Group identifier referenced by `ReplacementRule::group`.
+    pub id: String,
+    /// Display name.
+    #[serde(default)]
+    pub name: String,
+    /// Whether the group's rules are applied.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Position among groups; lower runs first.
+    #[serde(default)]
+    pub order: i32,
+    /// Profiles the group is active in; empty means all profiles.
+    #[serde(default)]
+    pub profiles: Vec<String>,
 }
 
 /// UI configuration.
@@ -531,12 +1364,67 @@ pub struct UiConfig {
     /// Whether overlay UI is enabled.
     #[serde(default = "default_overlay_enabled")]
     pub overlay_enabled: bool,
+    /// Overlay theme ("system", "light", "dark").
+    #[serde(default = "default_theme")]
+    pub overlay_theme: String,
+    /// Overlay size multiplier (0.5-2.0).
+    #[serde(default = "default_overlay_scale")]
+    pub overlay_scale: f64,
+    /// Overlay opacity (0.2-1.0).
+    #[serde(default = "default_overlay_opacity")]
+    pub overlay_opacity: f64,
+    /// Whether the overlay shows the live waveform.
+    #[serde(default = "default_true")]
+    pub overlay_show_waveform: bool,
+    /// Name of the monitor the overlay is pinned to, or None to show it on
+    /// the monitor of the focused app. A disconnected monitor falls back to
+    /// following focus.
+    #[serde(default)]
+    pub overlay_monitor: Option<String>,
     /// Preferred UI locale (e.g., "en-US"), or None for system locale.
     #[serde(default)]
     pub locale: Option<String>,
     /// Whether reduced-motion mode is enabled.
     #[serde(default)]
     pub reduce_motion: bool,
+    /// Speak state announcements through the platform speech service.
+    #[serde(default)]
+    pub announcements_enabled: bool,
+    /// Announce recording started/stopped/cancelled.
+    #[serde(default = "default_true")]
+    pub announce_recording: bool,
+    /// Announce completed transcriptions with their word count.
+    #[serde(default = "default_true")]
+    pub announce_transcription: bool,
+    /// Announce pause/resume and model loading.
+    #[serde(default = "default_true")]
+    pub announce_status: bool,
+    /// Announce transcription failures and errors.
+    #[serde(default = "default_true")]
+    pub announce_errors: bool,
+    /// Pin a recording badge to every monitor and flash the tray icon while
+    /// recording, regardless of the overlay setting.
+    #[serde(default)]
+    pub always_visible_recording_indicator: bool,
+    /// What happens when a recording starts while the screen is shared.
+    #[serde(default)]
+    pub screen_share_policy: ScreenSharePolicy,
+}
+
+/// Response to a recording started while the screen is shared.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScreenSharePolicy {
+    /// Record as usual.
+    Off,
+    /// Record, and tell the user the share may show their dictation.
+    #[default]
+    Warn,
+    /// Record with the overlay shown even when it is disabled, so viewers
+    /// can see the microphone is live.
+    ShowOverlay,
+    /// Refuse to record until the share ends.
+    DisableDictation,
 }
 
 impl Default for UiConfig {
@@ -548,8 +1436,20 @@ impl Default for UiConfig {
             theme: default_theme(),
             onboarding_completed: default_onboarding_completed(),
             overlay_enabled: default_overlay_enabled(),
+            overlay_theme: default_theme(),
+            overlay_scale: default_overlay_scale(),
+            overlay_opacity: default_overlay_opacity(),
+            overlay_show_waveform: default_true(),
+            overlay_monitor: None,
             locale: None,
             reduce_motion: false,
+            announcements_enabled: false,
+            announce_recording: default_true(),
+            announce_transcription: default_true(),
+            announce_status: default_true(),
+            announce_errors: default_true(),
+            always_visible_recording_indicator: false,
+            screen_share_policy: ScreenSharePolicy::default(),
         }
     }
 }
@@ -567,6 +1467,23 @@ pub struct HistoryConfig {
     /// Whether disk-persisted history is encrypted at rest.
     #[serde(default = "default_true")]
     pub encrypt_at_rest: bool,
+    /// Record the focused app name and window title with each transcript.
+    /// Opt-in, since titles often name documents and chat partners.
+    #[serde(default)]
+    pub capture_window_context: bool,
+    /// Mask email addresses and long digit runs in captured window titles.
+    #[serde(default = "default_true")]
+    pub sanitize_window_title: bool,
+    /// Maximum captured window title length; 0 keeps only the app name.
+    #[serde(default = "default_window_title_max_chars")]
+    pub window_title_max_chars: u32,
+    /// Delete unpinned history entries older than this many days; 0 keeps them.
+    #[serde(default)]
+    pub retention_days: u32,
+    /// Drop the oldest unpinned entries while persisted history is larger
+    /// than this many MiB; 0 = no limit.
+    #[serde(default)]
+    pub max_storage_mb: u32,
 }
 
 impl Default for HistoryConfig {
@@ -575,10 +1492,19 @@ impl Default for HistoryConfig {
             persistence_mode: default_persistence_mode(),
             max_entries: default_history_max_entries(),
             encrypt_at_rest: default_true(),
+            capture_window_context: false,
+            sanitize_window_title: default_true(),
+            window_title_max_chars: default_window_title_max_chars(),
+            retention_days: 0,
+            max_storage_mb: 0,
         }
     }
 }
 
+fn default_window_title_max_chars() -> u32 {
+    120
+}
+
 fn default_theme() -> String {
     "system".to_string()
 }
@@ -591,10 +1517,51 @@ fn default_overlay_enabled() -> bool {
     true
 }
 
+/// Clamp `value` into `range`, falling back to `default` for NaN/infinity.
+fn clamp_finite(value: f64, range: &std::ops::RangeInclusive<f64>, default: f64) -> f64 {
+    if value.is_finite() {
+        value.clamp(*range.start(), *range.end())
+    } else {
+        default
+    }
+}
+
+fn default_overlay_scale() -> f64 {
+    1.0
+}
+
+fn default_overlay_opacity() -> f64 {
+    1.0
+}
+
 fn default_persistence_mode() -> String {
     "memory".to_string()
 }
 
+fn default_injection_mode() -> String {
+    "inject".to_string()
+}
+
+fn default_primary_selection() -> String {
+    "off".to_string()
+}
+
+fn default_busy_target_wait_secs() -> u32 {
+    10
+}
+
+fn default_chunk_threshold_chars() -> u32 {
+    2000
+}
+
+fn default_chunk_size_chars() -> u32 {
+    800
+}
+
+fn default_platform_strategy() -> String {
+    "auto".to_string()
+}
+
 fn default_history_max_entries() -> u32 {
     100
 }
@@ -686,22 +1653,219 @@ pub struct PresetsConfig {
 /// Supervisor settings exposed to the user config file.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
-pub struct SupervisorConfig {
-    /// Maximum sidecar log lines retained in supervisor memory.
-    #[serde(default = "default_captured_log_max_lines")]
-    pub captured_log_max_lines: usize,
+pub struct SupervisorConfig {
+    /// Maximum sidecar log lines retained in supervisor memory.
+    #[serde(default = "default_captured_log_max_lines")]
+    pub captured_log_max_lines: usize,
+    /// Consecutive unparseable sidecar stdout lines skipped before the
+    /// connection is treated as dead.
+    #[serde(default = "default_protocol_error_tolerance")]
+    pub protocol_error_tolerance: u32,
+    /// Seconds between watchdog pings of an idle sidecar.
+    #[serde(default = "default_watchdog_ping_interval_secs")]
+    pub watchdog_ping_interval_secs: u64,
+    /// Seconds a ping may take before it counts as failed.
+    #[serde(default = "default_watchdog_ping_timeout_secs")]
+    pub watchdog_ping_timeout_secs: u64,
+    /// Seconds without a successful ping before the sidecar is declared hung
+    /// and restarted.
+    #[serde(default = "default_watchdog_hang_threshold_secs")]
+    pub watchdog_hang_threshold_secs: u64,
+    /// On manual restarts and switches to a cached model, start the new
+    /// sidecar and load its model before stopping the old one, so dictation
+    /// keeps working meanwhile. Needs memory for two loaded models.
+    #[serde(default)]
+    pub warm_spare_restarts: bool,
+    /// Leave a recoverable Error state (sidecar, audio device, model,
+    /// transcription) on its own once revalidation passes.
+    #[serde(default = "default_true")]
+    pub error_auto_recovery: bool,
+    /// Seconds before the first recovery attempt; doubles after each failed
+    /// one, up to five minutes.
+    #[serde(default = "default_error_recovery_backoff_secs")]
+    pub error_recovery_backoff_secs: u64,
+    /// Failed recovery attempts after which the error waits for the user.
+    #[serde(default = "default_error_recovery_max_attempts")]
+    pub error_recovery_max_attempts: u32,
+}
+
+impl Default for SupervisorConfig {
+    fn default() -> Self {
+        Self {
+            captured_log_max_lines: default_captured_log_max_lines(),
+            protocol_error_tolerance: default_protocol_error_tolerance(),
+            watchdog_ping_interval_secs: default_watchdog_ping_interval_secs(),
+            watchdog_ping_timeout_secs: default_watchdog_ping_timeout_secs(),
+            watchdog_hang_threshold_secs: default_watchdog_hang_threshold_secs(),
+            warm_spare_restarts: false,
+            error_auto_recovery: true,
+            error_recovery_backoff_secs: default_error_recovery_backoff_secs(),
+            error_recovery_max_attempts: default_error_recovery_max_attempts(),
+        }
+    }
+}
+
+fn default_captured_log_max_lines() -> usize {
+    1000
+}
+
+fn default_protocol_error_tolerance() -> u32 {
+    10
+}
+
+fn default_watchdog_ping_interval_secs() -> u64 {
+    10
+}
+
+fn default_watchdog_ping_timeout_secs() -> u64 {
+    5
+}
+
+fn default_watchdog_hang_threshold_secs() -> u64 {
+    30
+}
+
+fn default_error_recovery_backoff_secs() -> u64 {
+    5
+}
+
+fn default_error_recovery_max_attempts() -> u32 {
+    6
+}
+
+/// Custom vocabulary configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VocabularyConfig {
+    /// Domain terms, names, and acronyms boosted during recognition.
+    pub terms: Vec<String>,
+    /// Learn capitalization the user repeatedly corrects and apply it to new transcripts.
+    pub learn_capitalization: bool,
+}
+
+impl Default for VocabularyConfig {
+    fn default() -> Self {
+        Self {
+            terms: Vec::new(),
+            learn_capitalization: true,
+        }
+    }
+}
+
+/// Word filter configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WordFilterConfig {
+    /// Whether the filter runs on transcripts.
+    pub enabled: bool,
+    /// How matched words are treated: "mask" or "remove".
+    pub mode: String,
+    /// Include the built-in profanity list.
+    pub use_profanity_preset: bool,
+    /// User-defined words to filter.
+    pub words: Vec<String>,
+    /// Keep the unfiltered text in history so it can be revealed on demand.
+    pub keep_unfiltered: bool,
+    /// Whether the filter runs while a profile is active, keyed by profile
+    /// name (`replacement_groups.active_profile`). Unlisted profiles use
+    /// `enabled`.
+    pub profiles: HashMap<String, bool>,
+}
+
+impl Default for WordFilterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mode: "mask".to_string(),
+            use_profanity_preset: true,
+            words: Vec::new(),
+            keep_unfiltered: false,
+            profiles: HashMap::new(),
+        }
+    }
+}
+
+/// Settings sync configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyncConfig {
+    /// Whether settings are synced with the endpoint.
+    pub enabled: bool,
+    /// Endpoint type: "webdav" or "s3".
+    pub backend: String,
+    /// URL of the sync document (a WebDAV file or an S3 object).
+    pub url: String,
+    /// Region used to sign S3 requests.
+    pub s3_region: String,
+    /// Secret holding "user:password" (WebDAV) or "ACCESS_KEY_ID:SECRET_ACCESS_KEY" (S3).
+    pub credentials_name: String,
+    /// Secret holding the passphrase the sync document is encrypted with.
+    pub passphrase_name: String,
+    /// Minutes between background syncs; 0 syncs only on demand.
+    pub interval_minutes: u32,
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            backend: "webdav".to_string(),
+            url: String::new(),
+            s3_region: "us-east-1".to_string(),
+            credentials_name: "sync.credentials".to_string(),
+            passphrase_name: "sync.passphrase".to_string(),
+            interval_minutes: 30,
+        }
+    }
+}
+
+/// Network access configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NetworkConfig {
+    /// Block all network activity (downloads, update checks, sync, providers).
+    pub offline_mode: bool,
+}
+
+/// Default port of the companion status endpoint.
+pub const DEFAULT_COMPANION_PORT: u16 = 47_830;
+
+/// Companion status endpoint configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompanionConfig {
+    /// Serve recording state on the LAN and advertise it over mDNS.
+    pub enabled: bool,
+    /// TCP port of the status endpoint.
+    pub port: u16,
 }
 
-impl Default for SupervisorConfig {
+impl Default for CompanionConfig {
     fn default() -> Self {
         Self {
-            captured_log_max_lines: default_captured_log_max_lines(),
+            enabled: false,
+            port: DEFAULT_COMPANION_PORT,
         }
     }
 }
 
-fn default_captured_log_max_lines() -> usize {
-    1000
+/// Crash report upload configuration. Reports are always kept locally.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CrashReportingConfig {
+    /// Send queued crash reports to `endpoint`.
+    pub upload_enabled: bool,
+    /// HTTPS URL crash reports are POSTed to.
+    pub endpoint: String,
+}
+
+/// Power configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PowerConfig {
+    /// On battery, ping the sidecar less often, skip the model preload,
+    /// lower the audio level event rate, and unload the model sooner when idle.
+    pub low_power_on_battery: bool,
 }
 
 fn default_schema_version() -> u32 {
@@ -746,32 +1910,48 @@ pub fn config_path() -> PathBuf {
     config_dir().join(CONFIG_FILE_NAME)
 }
 
+static CONFIG_WRITER: Lazy<Arc<ConfigWriter>> =
+    Lazy::new(|| ConfigWriter::new(config_path(), SAVE_DEBOUNCE));
+
 /// Load configuration from disk.
 ///
-/// If the config file doesn't exist or is corrupted, returns defaults.
-/// Corrupted files are backed up for debugging.
+/// A save still waiting for the writer thread is returned as is. If the
+/// config file doesn't exist, returns defaults; if it is corrupted, it is
+/// backed up for debugging and replaced by the last-good backup, or by
+/// defaults. Values locked by the managed policy replace the user's settings.
 pub fn load_config() -> AppConfig {
-    load_config_from_path(&config_path())
+    let mut config = match CONFIG_WRITER.pending() {
+        Some(mut pending) => {
+            pending.validate_and_clamp();
+            pending
+        }
+        None => load_config_from_path(&config_path()),
+    };
+    crate::policy::current().enforce(&mut config);
+    config
 }
 
 /// Load configuration from a specific path (for testing).
-pub fn load_config_from_path(path: &PathBuf) -> AppConfig {
+pub fn load_config_from_path(path: &Path) -> AppConfig {
     match fs::read_to_string(path) {
-        Ok(content) => match serde_json::from_str::<Value>(&content) {
-            Ok(mut value) => {
-                reject_sensitive_unknown_fields(&mut value);
-                let mut config = migrate_config(value);
-                config.validate_and_clamp();
+        Ok(content) => match parse_config(&content) {
+            Ok(config) => {
+                if !matches_checksum(path, &content) {
+                    log::info!("Config file was changed outside the app");
+                }
                 config
             }
             Err(e) => {
-                log::error!("Config parse error, using defaults: {}", e);
+                log::error!("Config parse error: {}", e);
                 // Backup corrupt file for debugging
                 let backup = path.with_extension("json.corrupt");
                 if let Err(backup_err) = fs::rename(path, &backup) {
                     log::warn!("Failed to backup corrupt config: {}", backup_err);
                 }
-                AppConfig::default()
+                restore_last_good(path).unwrap_or_else(|| {
+                    log::warn!("No usable config backup, using defaults");
+                    AppConfig::default()
+                })
             }
         },
         Err(e) if e.kind() == io::ErrorKind::NotFound => {
@@ -785,32 +1965,101 @@ pub fn load_config_from_path(path: &PathBuf) -> AppConfig {
     }
 }
 
-/// Save configuration to disk with atomic replacement where platform permits.
+fn parse_config(content: &str) -> Result<AppConfig, serde_json::Error> {
+    let mut value = serde_json::from_str::<Value>(content)?;
+    reject_sensitive_unknown_fields(&mut value);
+    let mut config = migrate_config(value)?;
+    config.validate_and_clamp();
+    Ok(config)
+}
+
+/// Put the last-good backup back in place of a corrupt config. The backup is
+/// only trusted while it matches the checksum written with it.
+fn restore_last_good(path: &Path) -> Option<AppConfig> {
+    let content = fs::read_to_string(backup_path(path)).ok()?;
+    if !matches_checksum(path, &content) {
+        log::warn!("Config backup does not match its checksum, not restoring it");
+        return None;
+    }
+    let config = parse_config(&content).ok()?;
+    match write_atomically(path, content.as_bytes()) {
+        Ok(()) => log::warn!("Restored config from the last-good backup"),
+        Err(e) => log::warn!("Failed to rewrite config from backup: {}", e),
+    }
+    Some(config)
+}
+
+/// Save configuration without waiting for the disk.
 ///
-/// Writes to a temp file first, then replaces the final path.
+/// The config is handed to the writer thread, which writes it once saves
+/// have been quiet for a moment; [`load_config`] sees it right away.
+/// Serialization errors are still reported here. Call [`flush_config`]
+/// before exiting.
 pub fn save_config(config: &AppConfig) -> Result<(), ConfigError> {
-    save_config_to_path(config, &config_path())
+    serde_json::to_value(config)?;
+    CONFIG_WRITER.save(config.clone());
+    Ok(())
 }
 
-/// Save configuration to a specific path (for testing).
-pub fn save_config_to_path(config: &AppConfig, path: &PathBuf) -> Result<(), ConfigError> {
-    let temp = path.with_extension("json.tmp");
+/// Write a save still waiting for the writer thread.
+pub fn flush_config() -> Result<(), ConfigError> {
+    CONFIG_WRITER.flush()
+}
 
+/// Save configuration to a specific path, synchronously.
+///
+/// Writes the config, its checksum, and the last-good backup, each through a
+/// synced temp file that then replaces the final path.
+pub fn save_config_to_path(config: &AppConfig, path: &Path) -> Result<(), ConfigError> {
     // Ensure parent directory exists
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
 
-    // Write to temp file
     let json = serde_json::to_string_pretty(config)?;
-    fs::write(&temp, &json)?;
-
-    replace_config_file(&temp, path)?;
+    write_atomically(path, json.as_bytes())?;
+    write_atomically(&checksum_path(path), checksum(json.as_bytes()).as_bytes())?;
+    write_atomically(&backup_path(path), json.as_bytes())?;
 
     Ok(())
 }
 
-fn replace_config_file(temp: &PathBuf, path: &PathBuf) -> io::Result<()> {
+fn checksum_path(path: &Path) -> PathBuf {
+    path.with_extension("json.sha256")
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    path.with_extension("json.bak")
+}
+
+fn checksum(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+/// Whether `content` is what the app last wrote to `path`.
+fn matches_checksum(path: &Path, content: &str) -> bool {
+    fs::read_to_string(checksum_path(path))
+        .map(|expected| expected.trim() == checksum(content.as_bytes()))
+        .unwrap_or(false)
+}
+
+fn write_atomically(path: &Path, content: &[u8]) -> io::Result<()> {
+    let mut temp_extension = path
+        .extension()
+        .map(|extension| extension.to_os_string())
+        .unwrap_or_default();
+    temp_extension.push(".tmp");
+    let temp = path.with_extension(temp_extension);
+
+    let mut file = fs::File::create(&temp)?;
+    file.write_all(content)?;
+    file.sync_all()?;
+    drop(file);
+
+    replace_config_file(&temp, path)
+}
+
+fn replace_config_file(temp: &Path, path: &Path) -> io::Result<()> {
     match fs::rename(temp, path) {
         Ok(()) => Ok(()),
         Err(rename_err) => {
@@ -833,8 +2082,127 @@ fn replace_config_file(temp: &PathBuf, path: &PathBuf) -> io::Result<()> {
     }
 }
 
+/// Writes saved configs to one path on a dedicated thread, debounced.
+struct ConfigWriter {
+    path: PathBuf,
+    debounce: Duration,
+    state: Mutex<WriterState>,
+    wake: Condvar,
+    /// Held while writing, so writes land in the order they were taken.
+    write_lock: Mutex<()>,
+}
+
+#[derive(Default)]
+struct WriterState {
+    /// Newest saved config not yet on disk.
+    pending: Option<AppConfig>,
+    /// Bumped by every save, so the writer can tell a burst from quiet.
+    revision: u64,
+    /// A save arrived since the writer last started a write.
+    dirty: bool,
+    thread_started: bool,
+}
+
+impl ConfigWriter {
+    fn new(path: PathBuf, debounce: Duration) -> Arc<Self> {
+        Arc::new(Self {
+            path,
+            debounce,
+            state: Mutex::new(WriterState::default()),
+            wake: Condvar::new(),
+            write_lock: Mutex::new(()),
+        })
+    }
+
+    fn state(&self) -> MutexGuard<'_, WriterState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn pending(&self) -> Option<AppConfig> {
+        self.state().pending.clone()
+    }
+
+    fn save(self: &Arc<Self>, config: AppConfig) {
+        let mut state = self.state();
+        state.pending = Some(config);
+        state.revision += 1;
+        state.dirty = true;
+        if !state.thread_started {
+            let writer = Arc::clone(self);
+            match std::thread::Builder::new()
+                .name("config-writer".to_string())
+                .spawn(move || writer.run())
+            {
+                Ok(_) => state.thread_started = true,
+                Err(e) => {
+                    // Without the thread, write on the caller's thread.
+                    log::error!("Failed to start config writer: {}", e);
+                    drop(state);
+                    if let Err(e) = self.write_pending() {
+                        log::error!("Failed to save config: {}", e);
+                    }
+                    return;
+                }
+            }
+        }
+        self.wake.notify_all();
+    }
+
+    fn flush(&self) -> Result<(), ConfigError> {
+        self.write_pending()
+    }
+
+    fn run(&self) {
+        loop {
+            let mut state = self.state();
+            while !state.dirty {
+                state = self.wake.wait(state).unwrap_or_else(|e| e.into_inner());
+            }
+            // Wait for saves to go quiet, but not forever.
+            let first_save = Instant::now();
+            loop {
+                let revision = state.revision;
+                state = self
+                    .wake
+                    .wait_timeout(state, self.debounce)
+                    .unwrap_or_else(|e| e.into_inner())
+                    .0;
+                if state.revision == revision || first_save.elapsed() >= MAX_SAVE_DELAY {
+                    break;
+                }
+            }
+            state.dirty = false;
+            drop(state);
+
+            if let Err(e) = self.write_pending() {
+                log::error!("Failed to save config: {}", e);
+            }
+        }
+    }
+
+    /// Write the pending config, if any. It stays pending until it is on
+    /// disk, so reads never see an older config in between, and a failed
+    /// write is retried by the next save or flush.
+    fn write_pending(&self) -> Result<(), ConfigError> {
+        let _write = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        let (config, revision) = {
+            let state = self.state();
+            match &state.pending {
+                Some(config) => (config.clone(), state.revision),
+                None => return Ok(()),
+            }
+        };
+        save_config_to_path(&config, &self.path)?;
+        let mut state = self.state();
+        if state.revision == revision {
+            state.pending = None;
+        }
+        Ok(())
+    }
+}
+
 /// Migrate configuration from older schema versions.
-fn migrate_config(mut config: Value) -> AppConfig {
+fn migrate_config(mut config: Value) -> Result<AppConfig, serde_json::Error> {
     let version = config["schema_version"].as_u64().unwrap_or(0) as u32;
 
     // Migration v0 → v1: add focus_guard_enabled
@@ -868,16 +2236,41 @@ fn migrate_config(mut config: Value) -> AppConfig {
         }
     }
 
+    infer_vad_preset(&mut config);
     sanitize_invalid_boolean_fields(&mut config);
     ensure_replacement_rule_ids(&mut config);
 
     // Future migrations go here:
     // if version < 2 { ... }
 
-    serde_json::from_value(config).unwrap_or_else(|e| {
-        log::error!("Config migration failed, using defaults: {}", e);
-        AppConfig::default()
-    })
+    serde_json::from_value(config)
+}
+
+/// Configs saved before VAD presets keep their timings: a missing or unknown
+/// `audio.vad_preset` becomes the preset matching the saved values, or custom.
+fn infer_vad_preset(config: &mut Value) {
+    let Some(audio) = config.get_mut("audio").and_then(Value::as_object_mut) else {
+        return;
+    };
+    let known = audio
+        .get("vad_preset")
+        .is_some_and(|preset| serde_json::from_value::<VadPreset>(preset.clone()).is_ok());
+    if known {
+        return;
+    }
+
+    let timing = |key: &str, default: u32| {
+        audio
+            .get(key)
+            .and_then(Value::as_u64)
+            .and_then(|value| u32::try_from(value).ok())
+            .unwrap_or(default)
+    };
+    let preset = VadPreset::matching(
+        timing("vad_silence_ms", default_vad_silence_ms()),
+        timing("vad_min_speech_ms", default_vad_min_speech_ms()),
+    );
+    audio.insert("vad_preset".to_string(), serde_json::json!(preset));
 }
 
 fn sanitize_invalid_boolean_fields(config: &mut Value) {
@@ -920,6 +2313,33 @@ fn sanitize_invalid_boolean_fields(config: &mut Value) {
         );
         sanitize_bool_field(audio, "trim_silence", true, "audio.trim_silence");
         sanitize_bool_field(audio, "vad_enabled", false, "audio.vad_enabled");
+        sanitize_bool_field(
+            audio,
+            "diarization_enabled",
+            false,
+            "audio.diarization_enabled",
+        );
+        sanitize_bool_field(audio, "auto_punctuation", true, "audio.auto_punctuation");
+        sanitize_bool_field(
+            audio,
+            "continuous_dictation",
+            false,
+            "audio.continuous_dictation",
+        );
+        sanitize_bool_field(
+            audio,
+            "mute_cues_in_calls",
+            true,
+            "audio.mute_cues_in_calls",
+        );
+        sanitize_bool_field(audio, "mute_cues_in_dnd", true, "audio.mute_cues_in_dnd");
+        sanitize_bool_field(audio, "in_meeting", false, "audio.in_meeting");
+        sanitize_bool_field(
+            audio,
+            "auto_split_recordings",
+            false,
+            "audio.auto_split_recordings",
+        );
     }
 
     if let Some(injection) = config.get_mut("injection").and_then(Value::as_object_mut) {
@@ -935,6 +2355,14 @@ fn sanitize_invalid_boolean_fields(config: &mut Value) {
             true,
             "injection.focus_guard_enabled",
         );
+        sanitize_bool_field(injection, "smart_spacing", false, "injection.smart_spacing");
+        sanitize_bool_field(injection, "verify_paste", false, "injection.verify_paste");
+        sanitize_bool_field(
+            injection,
+            "calibrate_paste_delay",
+            false,
+            "injection.calibrate_paste_delay",
+        );
 
         if let Some(app_overrides) = injection
             .get_mut("app_overrides")
@@ -947,6 +2375,11 @@ fn sanitize_invalid_boolean_fields(config: &mut Value) {
                         "use_clipboard_only",
                         &format!("injection.app_overrides.{}.use_clipboard_only", app_id),
                     );
+                    sanitize_optional_bool_field(
+                        override_obj,
+                        "word_filter_enabled",
+                        &format!("injection.app_overrides.{}.word_filter_enabled", app_id),
+                    );
                 }
             }
         }
@@ -956,11 +2389,127 @@ fn sanitize_invalid_boolean_fields(config: &mut Value) {
         sanitize_bool_field(ui, "show_on_startup", true, "ui.show_on_startup");
         sanitize_bool_field(ui, "onboarding_completed", false, "ui.onboarding_completed");
         sanitize_bool_field(ui, "overlay_enabled", true, "ui.overlay_enabled");
+        sanitize_bool_field(
+            ui,
+            "overlay_show_waveform",
+            true,
+            "ui.overlay_show_waveform",
+        );
         sanitize_bool_field(ui, "reduce_motion", false, "ui.reduce_motion");
+        sanitize_bool_field(
+            ui,
+            "announcements_enabled",
+            false,
+            "ui.announcements_enabled",
+        );
+        sanitize_bool_field(ui, "announce_recording", true, "ui.announce_recording");
+        sanitize_bool_field(
+            ui,
+            "announce_transcription",
+            true,
+            "ui.announce_transcription",
+        );
+        sanitize_bool_field(ui, "announce_status", true, "ui.announce_status");
+        sanitize_bool_field(ui, "announce_errors", true, "ui.announce_errors");
+        sanitize_bool_field(
+            ui,
+            "always_visible_recording_indicator",
+            false,
+            "ui.always_visible_recording_indicator",
+        );
     }
 
     if let Some(history) = config.get_mut("history").and_then(Value::as_object_mut) {
         sanitize_bool_field(history, "encrypt_at_rest", true, "history.encrypt_at_rest");
+        sanitize_bool_field(
+            history,
+            "capture_window_context",
+            true,
+            "history.capture_window_context",
+        );
+        sanitize_bool_field(
+            history,
+            "sanitize_window_title",
+            true,
+            "history.sanitize_window_title",
+        );
+    }
+
+    if let Some(vocabulary) = config.get_mut("vocabulary").and_then(Value::as_object_mut) {
+        sanitize_bool_field(
+            vocabulary,
+            "learn_capitalization",
+            true,
+            "vocabulary.learn_capitalization",
+        );
+    }
+
+    if let Some(word_filter) = config.get_mut("word_filter").and_then(Value::as_object_mut) {
+        sanitize_bool_field(word_filter, "enabled", false, "word_filter.enabled");
+        sanitize_bool_field(
+            word_filter,
+            "use_profanity_preset",
+            true,
+            "word_filter.use_profanity_preset",
+        );
+        sanitize_bool_field(
+            word_filter,
+            "keep_unfiltered",
+            false,
+            "word_filter.keep_unfiltered",
+        );
+        if let Some(profiles) = word_filter
+            .get_mut("profiles")
+            .and_then(Value::as_object_mut)
+        {
+            profiles.retain(|profile, value| {
+                if value.is_boolean() {
+                    return true;
+                }
+                log::warn!(
+                    "Invalid boolean config value at 'word_filter.profiles.{}'; removing invalid value",
+                    profile
+                );
+                false
+            });
+        }
+    }
+
+    if let Some(sync) = config.get_mut("sync").and_then(Value::as_object_mut) {
+        sanitize_bool_field(sync, "enabled", false, "sync.enabled");
+    }
+
+    if let Some(network) = config.get_mut("network").and_then(Value::as_object_mut) {
+        sanitize_bool_field(network, "offline_mode", false, "network.offline_mode");
+    }
+
+    if let Some(companion) = config.get_mut("companion").and_then(Value::as_object_mut) {
+        sanitize_bool_field(companion, "enabled", false, "companion.enabled");
+    }
+
+    if let Some(crash_reporting) = config
+        .get_mut("crash_reporting")
+        .and_then(Value::as_object_mut)
+    {
+        sanitize_bool_field(
+            crash_reporting,
+            "upload_enabled",
+            false,
+            "crash_reporting.upload_enabled",
+        );
+    }
+
+    if let Some(power) = config.get_mut("power").and_then(Value::as_object_mut) {
+        sanitize_bool_field(
+            power,
+            "low_power_on_battery",
+            false,
+            "power.low_power_on_battery",
+        );
+    }
+
+    if let Some(model) = config.get_mut("model").and_then(Value::as_object_mut) {
+        sanitize_bool_field(model, "preload_on_start", false, "model.preload_on_start");
     }
 
     if let Some(replacements) = config.get_mut("replacements").and_then(Value::as_array_mut) {
@@ -987,6 +2536,23 @@ fn sanitize_invalid_boolean_fields(config: &mut Value) {
             }
         }
     }
+
+    if let Some(groups) = config
+        .get_mut("replacement_groups")
+        .and_then(|replacement_groups| replacement_groups.get_mut("groups"))
+        .and_then(Value::as_array_mut)
+    {
+        for (index, group) in groups.iter_mut().enumerate() {
+            if let Some(group) = group.as_object_mut() {
+                sanitize_bool_field(
+                    group,
+                    "enabled",
+                    true,
+                    &format!("replacement_groups.groups[{}].enabled", index),
+                );
+            }
+        }
+    }
 }
 
 fn ensure_replacement_rule_ids(config: &mut Value) {
@@ -1130,6 +2696,27 @@ fn sensitive_unknown_config_fields(config: &Value) -> Vec<String> {
             }
         }
     }
+    if let Some(replacement_groups) = root.get("replacement_groups").and_then(Value::as_object) {
+        collect_sensitive_unknown_keys(
+            replacement_groups,
+            "replacement_groups",
+            &REPLACEMENT_GROUPS_CONFIG_FIELDS,
+            &mut fields,
+        );
+        if let Some(groups) = replacement_groups.get("groups").and_then(Value::as_array) {
+            for (idx, group) in groups.iter().enumerate() {
+                if let Some(group_obj) = group.as_object() {
+                    let path_prefix = format!("replacement_groups.groups[{}]", idx);
+                    collect_sensitive_unknown_keys(
+                        group_obj,
+                        &path_prefix,
+                        &REPLACEMENT_GROUP_FIELDS,
+                        &mut fields,
+                    );
+                }
+            }
+        }
+    }
     if let Some(ui) = root.get("ui").and_then(Value::as_object) {
         collect_sensitive_unknown_keys(ui, "ui", &UI_CONFIG_FIELDS, &mut fields);
     }
@@ -1147,6 +2734,47 @@ fn sensitive_unknown_config_fields(config: &Value) -> Vec<String> {
             &mut fields,
         );
     }
+    if let Some(vocabulary) = root.get("vocabulary").and_then(Value::as_object) {
+        collect_sensitive_unknown_keys(
+            vocabulary,
+            "vocabulary",
+            &VOCABULARY_CONFIG_FIELDS,
+            &mut fields,
+        );
+    }
+    if let Some(word_filter) = root.get("word_filter").and_then(Value::as_object) {
+        collect_sensitive_unknown_keys(
+            word_filter,
+            "word_filter",
+            &WORD_FILTER_CONFIG_FIELDS,
+            &mut fields,
+        );
+    }
+    if let Some(sync) = root.get("sync").and_then(Value::as_object) {
+        collect_sensitive_unknown_keys(sync, "sync", &SYNC_CONFIG_FIELDS, &mut fields);
+    }
+    if let Some(network) = root.get("network").and_then(Value::as_object) {
+        collect_sensitive_unknown_keys(network, "network", &NETWORK_CONFIG_FIELDS, &mut fields);
+    }
+    if let Some(companion) = root.get("companion").and_then(Value::as_object) {
+        collect_sensitive_unknown_keys(
+            companion,
+            "companion",
+            &COMPANION_CONFIG_FIELDS,
+            &mut fields,
+        );
+    }
+    if let Some(crash_reporting) = root.get("crash_reporting").and_then(Value::as_object) {
+        collect_sensitive_unknown_keys(
+            crash_reporting,
+            "crash_reporting",
+            &CRASH_REPORTING_CONFIG_FIELDS,
+            &mut fields,
+        );
+    }
+    if let Some(power) = root.get("power").and_then(Value::as_object) {
+        collect_sensitive_unknown_keys(power, "power", &POWER_CONFIG_FIELDS, &mut fields);
+    }
 
     fields.sort();
     fields.dedup();
@@ -1235,13 +2863,39 @@ mod tests {
         assert!(!config.audio.vad_enabled);
         assert_eq!(config.audio.vad_silence_ms, 1200);
         assert_eq!(config.audio.vad_min_speech_ms, 250);
+        assert!(!config.audio.diarization_enabled);
+        assert!(config.audio.auto_punctuation);
+        assert!(!config.audio.continuous_dictation);
+        assert_eq!(config.audio.live_flush_secs, 0);
+        assert_eq!(config.audio.countdown_secs, 0);
+        assert!(config.audio.mute_cues_in_calls);
+        assert!(config.audio.mute_cues_in_dnd);
+        assert!(!config.audio.in_meeting);
+        assert!(!config.audio.auto_split_recordings);
+        assert!(config.audio.input_gains.is_empty());
+        assert!(!config.network.offline_mode);
+        assert!(!config.companion.enabled);
+        assert!(!config.crash_reporting.upload_enabled);
+        assert!(config.crash_reporting.endpoint.is_empty());
+        assert!(!config.power.low_power_on_battery);
+        assert_eq!(config.companion.port, DEFAULT_COMPANION_PORT);
         assert_eq!(config.hotkeys.primary, "Ctrl+Shift+Space");
+        assert_eq!(config.hotkeys.cancel, "Escape");
         assert_eq!(config.hotkeys.mode, HotkeyMode::Hold);
         assert_eq!(config.injection.paste_delay_ms, 40);
         assert!(config.injection.restore_clipboard);
         assert_eq!(config.injection.suffix, " ");
         assert!(config.injection.focus_guard_enabled);
         assert!(config.injection.app_overrides.is_empty());
+        assert_eq!(config.injection.chunk_threshold_chars, 2000);
+        assert_eq!(config.injection.chunk_size_chars, 800);
+        assert!(!config.injection.smart_spacing);
+        assert!(!config.injection.verify_paste);
+        assert!(!config.injection.calibrate_paste_delay);
+        assert_eq!(config.injection.confirm_above_chars, 0);
+        assert_eq!(config.injection.confirm_above_minutes, 0);
+        assert_eq!(config.injection.primary_selection, "off");
+        assert_eq!(config.injection.busy_target_wait_secs, 10);
         assert!(config.model.is_none());
         assert!(config.replacements.is_empty());
         assert!(config.ui.show_on_startup);
@@ -1250,10 +2904,14 @@ mod tests {
         assert!(config.ui.overlay_enabled);
         assert_eq!(config.ui.locale, None);
         assert!(!config.ui.reduce_motion);
+        assert!(!config.ui.announcements_enabled);
+        assert!(config.ui.announce_recording);
+        assert!(config.ui.announce_transcription);
         assert_eq!(config.history.persistence_mode, "memory");
         assert_eq!(config.history.max_entries, 100);
         assert!(config.history.encrypt_at_rest);
         assert_eq!(config.supervisor.captured_log_max_lines, 1000);
+        assert!(config.vocabulary.learn_capitalization);
     }
 
     #[test]
@@ -1274,6 +2932,7 @@ mod tests {
             AppOverride {
                 paste_delay_ms: Some(120),
                 use_clipboard_only: Some(true),
+                word_filter_enabled: None,
             },
         );
         config.replacements = vec![ReplacementRule {
@@ -1286,12 +2945,18 @@ mod tests {
             case_sensitive: false,
             description: Some("Expand common abbreviation".to_string()),
             origin: Some("user".to_string()),
+            group: None,
+            order: 0,
         }];
         config.model = Some(ModelConfig {
             model_id: Some("nvidia/parakeet-tdt-0.6b-v2".to_string()),
             device: Some("cuda".to_string()),
             preferred_device: "gpu".to_string(),
             language: Some("auto".to_string()),
+            beam_size: None,
+            preload_on_start: false,
+            preload_delay_ms: 0,
+            asr_backend: AsrBackend::default(),
         });
         config.ui.locale = Some("en-US".to_string());
         config.ui.reduce_motion = true;
@@ -1391,44 +3056,141 @@ mod tests {
 
         let loaded = load_config_from_path(&config_path);
         assert_eq!(loaded.hotkeys.primary, "Alt+Shift+V");
-
-        let temp_path = config_path.with_extension("json.tmp");
-        assert!(!temp_path.exists());
+
+        let temp_path = config_path.with_extension("json.tmp");
+        assert!(!temp_path.exists());
+    }
+
+    #[test]
+    fn test_corrupt_json_fallback() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        // Write invalid JSON
+        fs::write(&config_path, "{ invalid json }").unwrap();
+
+        // Load should return defaults
+        let config = load_config_from_path(&config_path);
+        assert_eq!(config.schema_version, CURRENT_SCHEMA_VERSION);
+
+        // Corrupt file should be backed up
+        let backup_path = config_path.with_extension("json.corrupt");
+        assert!(backup_path.exists());
+        assert!(!config_path.exists());
+    }
+
+    #[test]
+    fn test_valid_json_wrong_schema_fallback() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        // Write valid JSON with wrong types
+        fs::write(
+            &config_path,
+            r#"{"schema_version": "not_a_number", "audio": "wrong"}"#,
+        )
+        .unwrap();
+
+        // Load should return defaults after migration fails
+        let config = load_config_from_path(&config_path);
+        assert_eq!(config.schema_version, CURRENT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_corrupt_config_restored_from_last_good_backup() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        let mut saved = AppConfig::default();
+        saved.hotkeys.primary = "Alt+Shift+V".to_string();
+        save_config_to_path(&saved, &config_path).unwrap();
+        assert!(checksum_path(&config_path).exists());
+        assert!(backup_path(&config_path).exists());
+
+        // A truncated write leaves the main file unreadable.
+        fs::write(&config_path, "{ \"hotkeys\": ").unwrap();
+
+        let loaded = load_config_from_path(&config_path);
+        assert_eq!(loaded.hotkeys.primary, "Alt+Shift+V");
+        assert!(config_path.with_extension("json.corrupt").exists());
+        // The restored file is back in place and matches the checksum.
+        let restored = fs::read_to_string(&config_path).unwrap();
+        assert!(matches_checksum(&config_path, &restored));
+    }
+
+    #[test]
+    fn test_backup_not_matching_checksum_is_not_restored() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        let mut saved = AppConfig::default();
+        saved.hotkeys.primary = "Alt+Shift+V".to_string();
+        save_config_to_path(&saved, &config_path).unwrap();
+        fs::write(checksum_path(&config_path), "0000").unwrap();
+        fs::write(&config_path, "{ invalid json }").unwrap();
+
+        let loaded = load_config_from_path(&config_path);
+        assert_eq!(loaded.hotkeys.primary, AppConfig::default().hotkeys.primary);
+        assert!(!config_path.exists());
+    }
+
+    #[test]
+    fn test_hand_edited_config_is_kept() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        save_config_to_path(&AppConfig::default(), &config_path).unwrap();
+        let mut edited: Value =
+            serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        edited["hotkeys"]["primary"] = serde_json::json!("Alt+Shift+V");
+        fs::write(&config_path, edited.to_string()).unwrap();
+
+        let loaded = load_config_from_path(&config_path);
+        assert_eq!(loaded.hotkeys.primary, "Alt+Shift+V");
     }
 
     #[test]
-    fn test_corrupt_json_fallback() {
+    fn test_config_writer_debounces_saves() {
         let temp_dir = TempDir::new().unwrap();
         let config_path = temp_dir.path().join("config.json");
+        let writer = ConfigWriter::new(config_path.clone(), Duration::from_millis(20));
 
-        // Write invalid JSON
-        fs::write(&config_path, "{ invalid json }").unwrap();
-
-        // Load should return defaults
-        let config = load_config_from_path(&config_path);
-        assert_eq!(config.schema_version, CURRENT_SCHEMA_VERSION);
+        for primary in ["Ctrl+Shift+Space", "Alt+Shift+V"] {
+            let mut config = AppConfig::default();
+            config.hotkeys.primary = primary.to_string();
+            writer.save(config);
+        }
+        // Reads see the newest save before it reaches the disk.
+        assert_eq!(writer.pending().unwrap().hotkeys.primary, "Alt+Shift+V");
 
-        // Corrupt file should be backed up
-        let backup_path = config_path.with_extension("json.corrupt");
-        assert!(backup_path.exists());
-        assert!(!config_path.exists());
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while writer.pending().is_some() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert!(writer.pending().is_none());
+        assert_eq!(
+            load_config_from_path(&config_path).hotkeys.primary,
+            "Alt+Shift+V"
+        );
     }
 
     #[test]
-    fn test_valid_json_wrong_schema_fallback() {
+    fn test_config_writer_flush_writes_immediately() {
         let temp_dir = TempDir::new().unwrap();
         let config_path = temp_dir.path().join("config.json");
+        let writer = ConfigWriter::new(config_path.clone(), Duration::from_secs(60));
 
-        // Write valid JSON with wrong types
-        fs::write(
-            &config_path,
-            r#"{"schema_version": "not_a_number", "audio": "wrong"}"#,
-        )
-        .unwrap();
+        let mut config = AppConfig::default();
+        config.hotkeys.primary = "Alt+Shift+V".to_string();
+        writer.save(config);
+        assert!(!config_path.exists());
 
-        // Load should return defaults after migration fails
-        let config = load_config_from_path(&config_path);
-        assert_eq!(config.schema_version, CURRENT_SCHEMA_VERSION);
+        writer.flush().unwrap();
+        assert!(writer.pending().is_none());
+        assert_eq!(
+            load_config_from_path(&config_path).hotkeys.primary,
+            "Alt+Shift+V"
+        );
     }
 
     #[test]
@@ -1745,6 +3507,355 @@ mod tests {
         assert_eq!(loaded.history.max_entries, 2000);
     }
 
+    #[test]
+    fn test_validate_and_clamp_normalizes_vocabulary_terms() {
+        let mut config = AppConfig::default();
+        config.vocabulary.terms = vec![
+            " OpenVoicy ".to_string(),
+            "openvoicy".to_string(),
+            String::new(),
+            "x".repeat(crate::vocabulary::MAX_VOCABULARY_TERM_CHARS + 1),
+            "Tauri".to_string(),
+        ];
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.vocabulary.terms, vec!["OpenVoicy", "Tauri"]);
+    }
+
+    #[test]
+    fn test_vad_preset_timings_apply_only_when_the_preset_changes() {
+        let mut config = AppConfig::default();
+        VadPreset::Relaxed.apply_change(VadPreset::Balanced, &mut config.audio);
+        config.audio.vad_preset = VadPreset::Relaxed;
+        config.validate_and_clamp();
+        assert_eq!(config.audio.vad_silence_ms, 2500);
+        assert_eq!(config.audio.vad_min_speech_ms, 400);
+        assert_eq!(config.audio.vad_preset, VadPreset::Relaxed);
+
+        // Re-saving the same preset keeps timings edited directly.
+        config.audio.vad_silence_ms = 900;
+        VadPreset::Relaxed.apply_change(VadPreset::Relaxed, &mut config.audio);
+        config.validate_and_clamp();
+        assert_eq!(config.audio.vad_silence_ms, 900);
+        assert_eq!(config.audio.vad_min_speech_ms, 400);
+        assert_eq!(config.audio.vad_preset, VadPreset::Custom);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_bounds_live_flush_secs_but_keeps_off() {
+        let mut config = AppConfig::default();
+        config.validate_and_clamp();
+        assert_eq!(config.audio.live_flush_secs, 0);
+
+        config.audio.live_flush_secs = 1;
+        config.validate_and_clamp();
+        assert_eq!(config.audio.live_flush_secs, 3);
+
+        config.audio.live_flush_secs = 600;
+        config.validate_and_clamp();
+        assert_eq!(config.audio.live_flush_secs, 60);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_caps_countdown_secs() {
+        let mut config = AppConfig::default();
+        config.audio.countdown_secs = 3;
+        config.validate_and_clamp();
+        assert_eq!(config.audio.countdown_secs, 3);
+
+        config.audio.countdown_secs = 90;
+        config.validate_and_clamp();
+        assert_eq!(config.audio.countdown_secs, 10);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_caps_input_gains() {
+        let mut config = AppConfig::default();
+        config.audio.input_gains.insert("usb-mic".to_string(), 12.0);
+        config
+            .audio
+            .input_gains
+            .insert("loud-mic".to_string(), -45.0);
+        config
+            .audio
+            .input_gains
+            .insert("quiet-mic".to_string(), 60.0);
+        config.validate_and_clamp();
+        assert_eq!(config.audio.input_gains["usb-mic"], 12.0);
+        assert_eq!(config.audio.input_gains["loud-mic"], -20.0);
+        assert_eq!(config.audio.input_gains["quiet-mic"], 30.0);
+    }
+
+    #[test]
+    fn test_missing_vad_preset_is_inferred_from_saved_timings() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        fs::write(
+            &config_path,
+            r#"{"audio": {"vad_silence_ms": 1500, "vad_min_speech_ms": 300}}"#,
+        )
+        .unwrap();
+        let loaded = load_config_from_path(&config_path);
+        assert_eq!(loaded.audio.vad_preset, VadPreset::Custom);
+        assert_eq!(loaded.audio.vad_silence_ms, 1500);
+
+        fs::write(
+            &config_path,
+            r#"{"audio": {"vad_preset": "bogus", "vad_silence_ms": 600, "vad_min_speech_ms": 150}}"#,
+        )
+        .unwrap();
+        let loaded = load_config_from_path(&config_path);
+        assert_eq!(loaded.audio.vad_preset, VadPreset::Aggressive);
+
+        fs::write(&config_path, r#"{"audio": {"device_uid": "mic"}}"#).unwrap();
+        let loaded = load_config_from_path(&config_path);
+        assert_eq!(loaded.audio.vad_preset, VadPreset::Balanced);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_drops_invalid_and_duplicate_replacement_groups() {
+        let mut config: AppConfig = serde_json::from_value(serde_json::json!({
+            "replacement_groups": {
+                "groups": [
+                    { "id": "email", "order": 2, "profiles": ["work"] },
+                    { "id": "" },
+                    { "id": "email", "name": "Duplicate" },
+                    { "id": "chat", "enabled": false }
+                ],
+                "active_profile": "  "
+            }
+        }))
+        .unwrap();
+
+        config.validate_and_clamp();
+
+        let groups = &config.replacement_groups.groups;
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].id, "email");
+        assert_eq!(groups[0].order, 2);
+        assert!(groups[0].enabled);
+        assert_eq!(groups[0].profiles, vec!["work"]);
+        assert_eq!(groups[1].id, "chat");
+        assert!(!groups[1].enabled);
+        assert_eq!(config.replacement_groups.active_profile, None);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_resets_invalid_injection_mode() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.injection.mode, "inject");
+        config.injection.mode = "paste".to_string();
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.injection.mode, "inject");
+    }
+
+    #[test]
+    fn test_validate_and_clamp_resets_invalid_primary_selection() {
+        let mut config = AppConfig::default();
+        config.injection.primary_selection = "replace".to_string();
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.injection.primary_selection, "off");
+    }
+
+    #[test]
+    fn test_validate_and_clamp_resets_invalid_platform_strategies() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.injection.focus_strategy, "auto");
+        assert_eq!(config.injection.paste_strategy, "auto");
+        config.injection.focus_strategy = "wmctrl".to_string();
+        config.injection.paste_strategy = "wtype".to_string();
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.injection.focus_strategy, "auto");
+        assert_eq!(config.injection.paste_strategy, "wtype");
+    }
+
+    #[test]
+    fn test_effective_hotkeys_apply_active_profile_overrides() {
+        let mut config = AppConfig::default();
+        config.hotkeys.profiles.insert(
+            "meeting".to_string(),
+            ProfileHotkeys {
+                primary: Some("F13".to_string()),
+                cancel: Some(String::new()),
+                mode: Some(HotkeyMode::Toggle),
+                ..ProfileHotkeys::default()
+            },
+        );
+
+        assert_eq!(config.effective_hotkeys().primary, "Ctrl+Shift+Space");
+
+        config.replacement_groups.active_profile = Some("meeting".to_string());
+        let effective = config.effective_hotkeys();
+        assert_eq!(effective.primary, "F13");
+        assert_eq!(effective.copy_last, "Ctrl+Shift+V");
+        assert_eq!(effective.cancel, "");
+        assert_eq!(effective.mode, HotkeyMode::Toggle);
+
+        config.replacement_groups.active_profile = Some("unknown".to_string());
+        assert_eq!(config.effective_hotkeys().primary, "Ctrl+Shift+Space");
+    }
+
+    #[test]
+    fn test_validate_and_clamp_drops_empty_profile_hotkeys() {
+        let mut config = AppConfig::default();
+        config.hotkeys.profiles.insert(
+            "work".to_string(),
+            ProfileHotkeys {
+                primary: Some("  ".to_string()),
+                dictate_raw: Some(String::new()),
+                ..ProfileHotkeys::default()
+            },
+        );
+        config
+            .hotkeys
+            .profiles
+            .insert(" ".to_string(), ProfileHotkeys::default());
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.hotkeys.profiles.len(), 1);
+        let work = &config.hotkeys.profiles["work"];
+        assert_eq!(work.primary, None);
+        assert_eq!(work.dictate_raw.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_validate_and_clamp_caps_idle_auto_disable_minutes() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.hotkeys.idle_auto_disable_minutes, 0);
+        config.hotkeys.idle_auto_disable_minutes = 100_000;
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.hotkeys.idle_auto_disable_minutes, 1440);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_caps_confirm_above_minutes() {
+        let mut config = AppConfig::default();
+        config.injection.confirm_above_minutes = 10_000;
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.injection.confirm_above_minutes, 1440);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_caps_busy_target_wait_secs() {
+        let mut config = AppConfig::default();
+        config.injection.busy_target_wait_secs = 3600;
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.injection.busy_target_wait_secs, 60);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_caps_typing_suppression_ms() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.hotkeys.typing_suppression_ms, 0);
+        config.hotkeys.typing_suppression_ms = 60_000;
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.hotkeys.typing_suppression_ms, 2000);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_caps_window_title_max_chars() {
+        let mut config = AppConfig::default();
+        assert!(!config.history.capture_window_context);
+        assert_eq!(config.history.window_title_max_chars, 120);
+        config.history.window_title_max_chars = 10_000;
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.history.window_title_max_chars, 512);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_caps_retention_days() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.history.retention_days, 0);
+        config.history.retention_days = 100_000;
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.history.retention_days, 3650);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_caps_max_storage_mb() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.history.max_storage_mb, 0);
+        config.history.max_storage_mb = 50_000;
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.history.max_storage_mb, 1024);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_clamps_chunk_size_chars() {
+        let mut config = AppConfig::default();
+        config.injection.chunk_size_chars = 5;
+        config.validate_and_clamp();
+        assert_eq!(config.injection.chunk_size_chars, 100);
+
+        config.injection.chunk_size_chars = 50_000;
+        config.validate_and_clamp();
+        assert_eq!(config.injection.chunk_size_chars, 10_000);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_resets_invalid_word_filter_mode() {
+        let mut config = AppConfig::default();
+        config.word_filter.mode = "bleep".to_string();
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.word_filter.mode, "mask");
+    }
+
+    #[test]
+    fn test_validate_and_clamp_sync_settings() {
+        let mut config = AppConfig::default();
+        config.sync.backend = "ftp".to_string();
+        config.sync.passphrase_name = "Not A Secret Name".to_string();
+        config.sync.interval_minutes = 1;
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.sync.backend, "webdav");
+        assert_eq!(config.sync.passphrase_name, "sync.passphrase");
+        assert_eq!(config.sync.interval_minutes, 5);
+
+        // Zero keeps sync manual-only.
+        config.sync.interval_minutes = 0;
+        config.validate_and_clamp();
+        assert_eq!(config.sync.interval_minutes, 0);
+    }
+
+    #[test]
+    fn test_validate_and_clamp_resets_reserved_companion_port() {
+        let mut config = AppConfig::default();
+        config.companion.port = 80;
+        config.validate_and_clamp();
+        assert_eq!(config.companion.port, DEFAULT_COMPANION_PORT);
+
+        config.companion.port = 8080;
+        config.validate_and_clamp();
+        assert_eq!(config.companion.port, 8080);
+    }
+
     #[test]
     fn test_invalid_boolean_types_fall_back_to_per_field_defaults() {
         let temp_dir = TempDir::new().unwrap();
@@ -1758,7 +3869,11 @@ mod tests {
                     "audio_cues_enabled": "yes",
                     "trim_silence": "no",
                     "vad_enabled": "false",
-                    "vad_silence_ms": 1600
+                    "vad_silence_ms": 1600,
+                    "diarization_enabled": "on",
+                    "auto_punctuation": "off",
+                    "continuous_dictation": "yes",
+                    "in_meeting": "maybe"
                 },
                 "hotkeys": {
                     "primary": "Alt+Space",
@@ -1769,6 +3884,8 @@ mod tests {
                     "paste_delay_ms": 55,
                     "restore_clipboard": "true",
                     "focus_guard_enabled": 1,
+                    "smart_spacing": "yes",
+                    "verify_paste": 1,
                     "app_overrides": {
                         "slack": {
                             "paste_delay_ms": 45,
@@ -1781,11 +3898,32 @@ mod tests {
                     "window_width": 777,
                     "window_height": 555,
                     "overlay_enabled": "nope",
-                    "reduce_motion": "0"
+                    "overlay_show_waveform": "hidden",
+                    "reduce_motion": "0",
+                    "announcements_enabled": "yes",
+                    "announce_errors": 0,
+                    "always_visible_recording_indicator": "on"
                 },
                 "history": {
                     "max_entries": 321,
                     "encrypt_at_rest": "true"
+                },
+                "vocabulary": {
+                    "learn_capitalization": "always"
+                },
+                "word_filter": {
+                    "profiles": { "work": true, "home": "off" }
+                },
+                "sync": {
+                    "enabled": "yes",
+                    "interval_minutes": 60
+                },
+                "network": {
+                    "offline_mode": "on"
+                },
+                "model": {
+                    "model_id": "nvidia/parakeet-tdt-0.6b-v2",
+                    "preload_on_start": "yes"
                 }
             }"#,
         )
@@ -1806,12 +3944,36 @@ mod tests {
         assert!(loaded.audio.audio_cues_enabled);
         assert!(loaded.audio.trim_silence);
         assert!(!loaded.audio.vad_enabled);
+        assert!(!loaded.audio.diarization_enabled);
+        assert!(loaded.audio.auto_punctuation);
+        assert!(!loaded.audio.continuous_dictation);
+        assert!(!loaded.audio.in_meeting);
         assert!(loaded.injection.restore_clipboard);
         assert!(loaded.injection.focus_guard_enabled);
+        assert!(!loaded.injection.smart_spacing);
+        assert!(!loaded.injection.verify_paste);
         assert!(loaded.ui.show_on_startup);
         assert!(loaded.ui.overlay_enabled);
+        assert!(loaded.ui.overlay_show_waveform);
         assert!(!loaded.ui.reduce_motion);
+        assert!(!loaded.ui.announcements_enabled);
+        assert!(loaded.ui.announce_errors);
+        assert!(!loaded.ui.always_visible_recording_indicator);
+        assert!(loaded.vocabulary.learn_capitalization);
+        assert_eq!(
+            loaded.word_filter.profiles,
+            HashMap::from([("work".to_string(), true)])
+        );
         assert!(loaded.history.encrypt_at_rest);
+        assert!(!loaded.sync.enabled);
+        assert_eq!(loaded.sync.interval_minutes, 60);
+        assert!(!loaded.network.offline_mode);
+        let model = loaded.model.as_ref().expect("model section should be kept");
+        assert_eq!(
+            model.model_id.as_deref(),
+            Some("nvidia/parakeet-tdt-0.6b-v2")
+        );
+        assert!(!model.preload_on_start);
 
         // Invalid optional bool should be dropped.
         assert_eq!(
@@ -2084,6 +4246,7 @@ mod tests {
             AppOverride {
                 paste_delay_ms: Some(700),
                 use_clipboard_only: Some(false),
+                word_filter_enabled: None,
             },
         );
 
@@ -2106,6 +4269,7 @@ mod tests {
             AppOverride {
                 paste_delay_ms: Some(120),
                 use_clipboard_only: Some(true),
+                word_filter_enabled: None,
             },
         );
 
@@ -2238,6 +4402,20 @@ mod tests {
         assert_eq!(config.ui.theme, "system");
     }
 
+    #[test]
+    fn test_overlay_appearance_is_validated_and_clamped() {
+        let mut config = AppConfig::default();
+        config.ui.overlay_theme = "neon".to_string();
+        config.ui.overlay_scale = 5.0;
+        config.ui.overlay_opacity = f64::NAN;
+
+        config.validate_and_clamp();
+
+        assert_eq!(config.ui.overlay_theme, "system");
+        assert_eq!(config.ui.overlay_scale, 2.0);
+        assert_eq!(config.ui.overlay_opacity, 1.0);
+    }
+
     #[test]
     fn test_invalid_history_persistence_mode_gets_default() {
         let mut config = AppConfig::default();
@@ -2284,6 +4462,7 @@ mod tests {
         assert!(config.ui.overlay_enabled);
         assert_eq!(config.ui.locale, None);
         assert!(!config.ui.reduce_motion);
+        assert_eq!(config.ui.screen_share_policy, ScreenSharePolicy::Warn);
     }
 
     #[test]
@@ -2446,11 +4625,25 @@ mod tests {
             device: Some("cuda".to_string()),
             preferred_device: "cpu".to_string(),
             language: None,
+            beam_size: None,
+            preload_on_start: false,
+            preload_delay_ms: 0,
+            asr_backend: AsrBackend::default(),
         });
 
         assert_eq!(config.effective_model_device_pref(), "cuda");
     }
 
+    #[test]
+    fn test_asr_backend_defaults_to_auto() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.asr_backend(), AsrBackend::Auto);
+
+        config.model =
+            Some(serde_json::from_value(serde_json::json!({ "asr_backend": "embedded" })).unwrap());
+        assert_eq!(config.asr_backend(), AsrBackend::Embedded);
+    }
+
     #[test]
     fn test_effective_model_device_pref_maps_preferred_device_for_non_concrete_device() {
         let mut config = AppConfig::default();
@@ -2459,6 +4652,10 @@ mod tests {
             device: Some("auto".to_string()),
             preferred_device: "cpu".to_string(),
             language: None,
+            beam_size: None,
+            preload_on_start: false,
+            preload_delay_ms: 0,
+            asr_backend: AsrBackend::default(),
         });
         assert_eq!(config.effective_model_device_pref(), "cpu");
 
@@ -2467,6 +4664,10 @@ mod tests {
             device: Some("cpu".to_string()),
             preferred_device: "gpu".to_string(),
             language: None,
+            beam_size: None,
+            preload_on_start: false,
+            preload_delay_ms: 0,
+            asr_backend: AsrBackend::default(),
         });
         assert_eq!(config.effective_model_device_pref(), "cpu");
     }
@@ -2554,6 +4755,10 @@ mod tests {
                     device: case.device.map(std::string::ToString::to_string),
                     preferred_device: case.preferred_device.unwrap_or("auto").to_string(),
                     language: None,
+                    beam_size: None,
+                    preload_on_start: false,
+                    preload_delay_ms: 0,
+                    asr_backend: AsrBackend::default(),
                 });
             }
 
@@ -2578,6 +4783,10 @@ mod tests {
             device: Some("auto".to_string()),
             preferred_device: "tpu".to_string(),
             language: None,
+            beam_size: None,
+            preload_on_start: false,
+            preload_delay_ms: 0,
+            asr_backend: AsrBackend::default(),
         });
 
         config.validate_and_clamp();
@@ -2624,6 +4833,10 @@ mod tests {
                 device: Some("auto".to_string()),
                 preferred_device: "auto".to_string(),
                 language: language.map(std::string::ToString::to_string),
+                beam_size: None,
+                preload_on_start: false,
+                preload_delay_ms: 0,
+                asr_backend: AsrBackend::default(),
             });
 
             save_config_to_path(&config, &config_path).unwrap();
@@ -2639,6 +4852,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validate_and_clamp_clamps_model_beam_size_and_preload_delay() {
+        let mut config = AppConfig {
+            model: Some(ModelConfig {
+                model_id: None,
+                device: None,
+                preferred_device: "auto".to_string(),
+                language: None,
+                beam_size: Some(64),
+                preload_on_start: true,
+                preload_delay_ms: 120_000,
+                asr_backend: AsrBackend::default(),
+            }),
+            ..AppConfig::default()
+        };
+
+        config.validate_and_clamp();
+
+        let model = config.model.as_ref().unwrap();
+        assert_eq!(model.beam_size, Some(16));
+        assert!(model.preload_on_start);
+        assert_eq!(model.preload_delay_ms, 60_000);
+    }
+
     #[test]
     fn test_validate_and_clamp_resets_unknown_model_language_value_to_null() {
         let mut config = AppConfig::default();
@@ -2647,6 +4884,10 @@ mod tests {
             device: Some("auto".to_string()),
             preferred_device: "auto".to_string(),
             language: Some("english".to_string()),
+            beam_size: None,
+            preload_on_start: false,
+            preload_delay_ms: 0,
+            asr_backend: AsrBackend::default(),
         });
 
         config.validate_and_clamp();
@@ -2673,6 +4914,8 @@ mod tests {
             case_sensitive: true,
             description: None,
             origin: None,
+            group: None,
+            order: 0,
         }];
 
         config.validate_and_clamp();
@@ -2692,6 +4935,8 @@ mod tests {
             case_sensitive: true,
             description: None,
             origin: Some("system".to_string()),
+            group: None,
+            order: 0,
         }];
 
         config.validate_and_clamp();
@@ -2802,6 +5047,34 @@ mod tests {
 
         let loaded = load_config_from_path(&config_path);
         assert_eq!(loaded.supervisor.captured_log_max_lines, 1000);
+        assert_eq!(loaded.supervisor.protocol_error_tolerance, 10);
+        assert_eq!(loaded.supervisor.watchdog_ping_interval_secs, 10);
+        assert_eq!(loaded.supervisor.watchdog_ping_timeout_secs, 5);
+        assert_eq!(loaded.supervisor.watchdog_hang_threshold_secs, 30);
+        assert!(!loaded.supervisor.warm_spare_restarts);
+        assert!(loaded.supervisor.error_auto_recovery);
+        assert_eq!(loaded.supervisor.error_recovery_backoff_secs, 5);
+        assert_eq!(loaded.supervisor.error_recovery_max_attempts, 6);
+    }
+
+    #[test]
+    fn test_supervisor_watchdog_timings_clamped_against_each_other() {
+        let mut config = AppConfig::default();
+        config.supervisor.watchdog_ping_interval_secs = 1;
+        config.supervisor.watchdog_ping_timeout_secs = 9;
+        config.supervisor.watchdog_hang_threshold_secs = 0;
+        config.validate_and_clamp();
+        assert_eq!(config.supervisor.watchdog_ping_interval_secs, 2);
+        assert_eq!(config.supervisor.watchdog_ping_timeout_secs, 2);
+        assert_eq!(config.supervisor.watchdog_hang_threshold_secs, 4);
+
+        config.supervisor.watchdog_ping_interval_secs = 60;
+        config.supervisor.watchdog_ping_timeout_secs = 20;
+        config.supervisor.watchdog_hang_threshold_secs = 90;
+        config.validate_and_clamp();
+        assert_eq!(config.supervisor.watchdog_ping_interval_secs, 60);
+        assert_eq!(config.supervisor.watchdog_ping_timeout_secs, 20);
+        assert_eq!(config.supervisor.watchdog_hang_threshold_secs, 120);
     }
 
     #[test]
@@ -6,6 +6,16 @@ use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 // TauriCommand local definitions
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriCommandDefActionInfo {
+    pub args: Vec<serde_json::Value>,
+    pub available: bool,
+    pub description: String,
+    pub id: String,
+    pub name: String,
+    pub unavailable_reason: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct TauriCommandDefAudioDevice {
     pub channels: i64,
@@ -23,6 +33,20 @@ pub struct TauriCommandDefCheckItem {
     pub status: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriCommandDefCommandAuditEntry {
+    pub args: String,
+    pub command: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub id: i64,
+    pub started_at: String,
+    pub status: String,
+    pub window: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct TauriCommandDefDiagnosticsReport {
     pub capabilities: TauriCommandDefOpenObject,
@@ -30,15 +54,26 @@ pub struct TauriCommandDefDiagnosticsReport {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub environment: Option<BTreeMap<String, String>>,
     pub platform: String,
+    pub power: serde_json::Value,
     pub recent_logs: Vec<TauriCommandDefLogEntry>,
     pub self_check: TauriCommandDefOpenObject,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_journal: Option<Vec<String>>,
+    pub sidecar_output: serde_json::Value,
     pub version: String,
+    pub watchdog: serde_json::Value,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct TauriCommandDefEmptyParams {
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriCommandDefInputGain {
+    pub device_uid: Option<String>,
+    pub gain_db: f64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct TauriCommandDefLogEntry {
     pub level: String,
@@ -58,6 +93,8 @@ pub struct TauriCommandDefModelCatalogEntry {
     pub manifest_path: String,
     pub model_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recommended_params: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub size_bytes: Option<i64>,
     pub supported_languages: Vec<String>,
 }
@@ -82,6 +119,16 @@ pub struct TauriCommandDefOpenObject {
     pub extra: BTreeMap<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriCommandDefPhraseShortcut {
+    pub enabled: bool,
+    pub id: String,
+    pub phrase: String,
+    pub value: TauriCommandDefPhraseShortcutValue,
+}
+
+pub type TauriCommandDefPhraseShortcutValue = serde_json::Value;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct TauriCommandDefPresetInfo {
     pub description: String,
@@ -90,6 +137,20 @@ pub struct TauriCommandDefPresetInfo {
     pub rule_count: i64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriCommandDefSessionSnapshot {
+    pub audio_ms: Option<i64>,
+    pub cancel_reason: Option<String>,
+    pub created_at: String,
+    pub error: Option<String>,
+    pub phase: String,
+    pub revision: i64,
+    pub session_id: String,
+    pub started_at: Option<String>,
+    pub text: Option<String>,
+    pub updated_at: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct TauriCommandDefStateEvent {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -102,6 +163,23 @@ pub struct TauriCommandDefStateEvent {
     pub extra: BTreeMap<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriCommandDefTranscriptDiff {
+    pub entry_id: String,
+    pub final_text: String,
+    pub raw_text: String,
+    pub spans: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriCommandDefUsageTotals {
+    pub audio_ms: i64,
+    pub transcripts: i64,
+    pub words: i64,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
 pub type TauriCommandDefVoidResult = Option<BTreeMap<String, serde_json::Value>>;
 
 // TauriEvent local definitions
@@ -124,8 +202,38 @@ pub struct TauriEventDefAppErrorPayload {
     pub seq: i64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefAppNavigatePayload {
+    pub seq: i64,
+    pub tab: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefAppRecoveryPayload {
+    pub attempt: i64,
+    pub category: String,
+    pub delay_ms: Option<i64>,
+    pub failed_checks: Vec<String>,
+    pub max_attempts: i64,
+    pub phase: String,
+    pub seq: i64,
+}
+
 pub type TauriEventDefAppState = String;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefAppUpdateProgressPayload {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub downloaded_bytes: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub phase: String,
+    pub seq: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_bytes: Option<i64>,
+    pub version: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct TauriEventDefAudioLevelPayload {
     pub peak: f64,
@@ -136,6 +244,83 @@ pub struct TauriEventDefAudioLevelPayload {
     pub source: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefCapabilitiesIssuesPayload {
+    pub issues: Vec<BTreeMap<String, serde_json::Value>>,
+    pub permissions: serde_json::Value,
+    pub seq: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefCuesStatusPayload {
+    pub app: Option<String>,
+    pub reason: String,
+    pub seq: i64,
+    pub suppressed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefFileTranscriptionProgressPayload {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entry_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub file_name: String,
+    pub phase: String,
+    pub seq: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefFocusChangedPayload {
+    pub app_name: String,
+    pub is_self: bool,
+    pub seq: i64,
+    pub window_id: String,
+    pub window_title: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefInjectionConfirmRequestedPayload {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_duration_ms: Option<i64>,
+    pub entry_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guard: Option<String>,
+    pub seq: i64,
+    pub text_length: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefInjectionDeferredPayload {
+    pub entry_id: String,
+    pub max_wait_ms: i64,
+    pub phase: String,
+    pub seq: i64,
+    pub session_id: String,
+    pub waited_ms: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefInjectionModeChangedPayload {
+    pub mode: String,
+    pub seq: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefInjectionProgressPayload {
+    pub chars_injected: i64,
+    pub chunk_count: i64,
+    pub chunks_done: i64,
+    pub seq: i64,
+    pub total_chars: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefInjectionQueueChangedPayload {
+    pub depth: i64,
+    pub seq: i64,
+}
+
 pub type TauriEventDefInjectionResult = serde_json::Value;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -163,12 +348,18 @@ pub struct TauriEventDefModelStatusPayload {
     pub cache_path: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     pub model_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub percent: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub progress: Option<TauriEventDefModelStatusProgress>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub revision: Option<String>,
     pub seq: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage: Option<String>,
     pub status: String,
 }
 
@@ -180,17 +371,58 @@ pub struct TauriEventDefModelStatusProgress {
     pub unit: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefOverlayCancelledPayload {
+    pub reason: String,
+    pub seq: i64,
+    pub session_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefOverlayConfigPayload {
+    pub opacity: f64,
+    pub scale: f64,
+    pub seq: i64,
+    pub show_waveform: bool,
+    pub theme: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefOverlayCountdownPayload {
+    pub cancelled: bool,
+    pub remaining_secs: i64,
+    pub seq: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct TauriEventDefOverlayTogglePayload {
     pub enabled: bool,
     pub seq: i64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefOverlayTranscribingPayload {
+    pub audio_ms: i64,
+    pub elapsed_ms: i64,
+    pub seq: i64,
+    pub session_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefPendingInsertsChangedPayload {
+    pub count: i64,
+    pub seq: i64,
+}
+
+pub type TauriEventDefPermissionState = String;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct TauriEventDefRecordingStatusPayload {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub audio_ms: Option<i64>,
     pub phase: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub screen_sharing: Option<bool>,
     pub seq: i64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub session_id: Option<String>,
@@ -198,6 +430,21 @@ pub struct TauriEventDefRecordingStatusPayload {
     pub started_at: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefSessionChangedPayload {
+    pub audio_ms: Option<i64>,
+    pub cancel_reason: Option<String>,
+    pub created_at: String,
+    pub error: Option<String>,
+    pub phase: String,
+    pub revision: i64,
+    pub seq: i64,
+    pub session_id: String,
+    pub started_at: Option<String>,
+    pub text: Option<String>,
+    pub updated_at: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct TauriEventDefSidecarStatusPayload {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -207,11 +454,22 @@ pub struct TauriEventDefSidecarStatusPayload {
     pub state: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefSidecarUpdateStatusPayload {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub phase: String,
+    pub seq: i64,
+    pub version: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct TauriEventDefStateChangedPayload {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub detail: Option<String>,
     pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
     pub seq: i64,
     pub state: TauriEventDefAppState,
     pub timestamp: String,
@@ -225,24 +483,52 @@ pub struct TauriEventDefTranscriptCompletePayload {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct TauriEventDefTranscriptEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_name: Option<String>,
     pub audio_duration_ms: i64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_punctuation: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub confidence: Option<f64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub final_text: Option<String>,
     pub id: String,
     pub injection_result: TauriEventDefInjectionResult,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub injection_target: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub language: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paste_verification: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub raw_text: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub segments: Option<Vec<serde_json::Value>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub session_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_file: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
     pub text: String,
     pub timestamp: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timings: Option<TauriEventDefTranscriptTimings>,
     pub transcription_duration_ms: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transcription_retries: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unfiltered_text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window_title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub words: Option<Vec<serde_json::Value>>,
     #[serde(flatten)]
     pub extra: BTreeMap<String, serde_json::Value>,
 }
@@ -275,6 +561,15 @@ pub struct TauriEventDefTranscriptTimings {
     pub transcribe_ms: Option<i64>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TauriEventDefVadPreviewPayload {
+    pub seq: i64,
+    pub silence_ms: f64,
+    pub silence_threshold_ms: i64,
+    pub speech_ms: f64,
+    pub state: String,
+}
+
 // SidecarRpc local definitions
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct SidecarRpcDefReplacementRule {
@@ -294,96 +589,302 @@ pub struct SidecarRpcDefReplacementRule {
 }
 
 // Tauri command constants and payload types
+pub const CMD_ACCEPT_MODEL_LICENSE: &str = "accept_model_license";
+pub const CMD_APPLY_SIDECAR_UPDATE: &str = "apply_sidecar_update";
 pub const CMD_CAN_START_RECORDING: &str = "can_start_recording";
+pub const CMD_CANCEL_INJECTION: &str = "cancel_injection";
 pub const CMD_CANCEL_RECORDING: &str = "cancel_recording";
+pub const CMD_CHECK_APP_UPDATE: &str = "check_app_update";
+pub const CMD_CHECK_SIDECAR_UPDATE: &str = "check_sidecar_update";
 pub const CMD_CLEAR_HISTORY: &str = "clear_history";
+pub const CMD_CLEAR_HISTORY_FILTERED: &str = "clear_history_filtered";
+pub const CMD_CLEAR_TEMPORARY_REPLACEMENT_RULES: &str = "clear_temporary_replacement_rules";
+pub const CMD_COMPARE_MODELS: &str = "compare_models";
+pub const CMD_CONFIRM_INJECTION: &str = "confirm_injection";
 pub const CMD_COPY_LAST_TRANSCRIPT: &str = "copy_last_transcript";
 pub const CMD_COPY_TRANSCRIPT: &str = "copy_transcript";
+pub const CMD_DELETE_CRASH_REPORTS: &str = "delete_crash_reports";
+pub const CMD_DELETE_PHRASE_SHORTCUT: &str = "delete_phrase_shortcut";
+pub const CMD_DELETE_SECRET: &str = "delete_secret";
+pub const CMD_DISMISS_PENDING_INSERT: &str = "dismiss_pending_insert";
+pub const CMD_DOWNLOAD_APP_UPDATE: &str = "download_app_update";
 pub const CMD_DOWNLOAD_MODEL: &str = "download_model";
+pub const CMD_EDIT_TRANSCRIPT: &str = "edit_transcript";
+pub const CMD_EXPORT_APP_STATE_SNAPSHOT: &str = "export_app_state_snapshot";
 pub const CMD_EXPORT_HISTORY: &str = "export_history";
+pub const CMD_EXPORT_SUBTITLES: &str = "export_subtitles";
 pub const CMD_GENERATE_DIAGNOSTICS: &str = "generate_diagnostics";
+pub const CMD_GET_ACTIVE_SESSION: &str = "get_active_session";
+pub const CMD_GET_APP_ICON: &str = "get_app_icon";
 pub const CMD_GET_APP_STATE: &str = "get_app_state";
 pub const CMD_GET_AVAILABLE_PRESETS: &str = "get_available_presets";
 pub const CMD_GET_CAPABILITIES: &str = "get_capabilities";
 pub const CMD_GET_CAPABILITY_ISSUES: &str = "get_capability_issues";
+pub const CMD_GET_COMMAND_AUDIT_LOG: &str = "get_command_audit_log";
 pub const CMD_GET_CONFIG: &str = "get_config";
+pub const CMD_GET_CURRENT_FOCUS_INFO: &str = "get_current_focus_info";
+pub const CMD_GET_ENABLE_LEVEL: &str = "get_enable_level";
+pub const CMD_GET_HISTORY_STATS: &str = "get_history_stats";
 pub const CMD_GET_HOTKEY_STATUS: &str = "get_hotkey_status";
+pub const CMD_GET_INPUT_GAIN: &str = "get_input_gain";
+pub const CMD_GET_LEARNED_DICTIONARY: &str = "get_learned_dictionary";
+pub const CMD_GET_LOW_CONFIDENCE_WORDS: &str = "get_low_confidence_words";
 pub const CMD_GET_MODEL_CATALOG: &str = "get_model_catalog";
 pub const CMD_GET_MODEL_STATUS: &str = "get_model_status";
+pub const CMD_GET_OVERLAY_CONFIG: &str = "get_overlay_config";
+pub const CMD_GET_PASTE_CALIBRATION: &str = "get_paste_calibration";
+pub const CMD_GET_PENDING_CRASH_REPORTS: &str = "get_pending_crash_reports";
+pub const CMD_GET_PENDING_INSERTS: &str = "get_pending_inserts";
+pub const CMD_GET_PENDING_LICENSE: &str = "get_pending_license";
+pub const CMD_GET_PHRASE_SHORTCUTS: &str = "get_phrase_shortcuts";
 pub const CMD_GET_RECENT_LOGS: &str = "get_recent_logs";
 pub const CMD_GET_REPLACEMENT_RULES: &str = "get_replacement_rules";
+pub const CMD_GET_SESSION_LABEL: &str = "get_session_label";
+pub const CMD_GET_TAG_SUGGESTIONS: &str = "get_tag_suggestions";
+pub const CMD_GET_TEMPORARY_REPLACEMENT_RULES: &str = "get_temporary_replacement_rules";
+pub const CMD_GET_TRANSCRIPT_DIFF: &str = "get_transcript_diff";
 pub const CMD_GET_TRANSCRIPT_HISTORY: &str = "get_transcript_history";
+pub const CMD_GET_UNFILTERED_TRANSCRIPT: &str = "get_unfiltered_transcript";
+pub const CMD_GET_USAGE_STATS: &str = "get_usage_stats";
+pub const CMD_GET_VOCABULARY: &str = "get_vocabulary";
+pub const CMD_HAS_SECRET: &str = "has_secret";
+pub const CMD_IMPORT_VOCABULARY: &str = "import_vocabulary";
+pub const CMD_INJECT_PENDING_INSERT: &str = "inject_pending_insert";
+pub const CMD_INSTALL_APP_UPDATE: &str = "install_app_update";
+pub const CMD_INVOKE_ACTION: &str = "invoke_action";
 pub const CMD_IS_ENABLED: &str = "is_enabled";
+pub const CMD_LIST_ACTIONS: &str = "list_actions";
 pub const CMD_LIST_AUDIO_DEVICES: &str = "list_audio_devices";
 pub const CMD_LOAD_PRESET: &str = "load_preset";
+pub const CMD_PRACTICE_HOTKEY: &str = "practice_hotkey";
 pub const CMD_PREVIEW_REPLACEMENT: &str = "preview_replacement";
 pub const CMD_PURGE_MODEL_CACHE: &str = "purge_model_cache";
+pub const CMD_REINJECT_TO_ORIGINAL_TARGET: &str = "reinject_to_original_target";
+pub const CMD_REMOVE_LEARNED_WORD: &str = "remove_learned_word";
+pub const CMD_REPLAY_APP_STATE_SNAPSHOT: &str = "replay_app_state_snapshot";
+pub const CMD_REPORT_HOTKEY_KEYDOWN: &str = "report_hotkey_keydown";
 pub const CMD_RESET_CONFIG_TO_DEFAULTS: &str = "reset_config_to_defaults";
+pub const CMD_RESET_PASTE_CALIBRATION: &str = "reset_paste_calibration";
 pub const CMD_RESTART_SIDECAR: &str = "restart_sidecar";
 pub const CMD_RUN_SELF_CHECK: &str = "run_self_check";
+pub const CMD_SAVE_PHRASE_SHORTCUT: &str = "save_phrase_shortcut";
+pub const CMD_SEARCH_TRANSCRIPT_HISTORY: &str = "search_transcript_history";
 pub const CMD_SET_AUDIO_DEVICE: &str = "set_audio_device";
+pub const CMD_SET_ENABLE_LEVEL: &str = "set_enable_level";
 pub const CMD_SET_ENABLED: &str = "set_enabled";
 pub const CMD_SET_HOTKEY: &str = "set_hotkey";
+pub const CMD_SET_INJECTION_MODE: &str = "set_injection_mode";
+pub const CMD_SET_INPUT_GAIN: &str = "set_input_gain";
 pub const CMD_SET_REPLACEMENT_RULES: &str = "set_replacement_rules";
+pub const CMD_SET_SECRET: &str = "set_secret";
+pub const CMD_SET_SESSION_LABEL: &str = "set_session_label";
+pub const CMD_SET_TEMPORARY_REPLACEMENT_RULES: &str = "set_temporary_replacement_rules";
+pub const CMD_SET_TRANSCRIPT_LABEL: &str = "set_transcript_label";
+pub const CMD_SET_TRANSCRIPT_PINNED: &str = "set_transcript_pinned";
+pub const CMD_SET_VOCABULARY: &str = "set_vocabulary";
+pub const CMD_SHARE_HISTORY_ENTRY: &str = "share_history_entry";
 pub const CMD_START_MIC_TEST: &str = "start_mic_test";
 pub const CMD_START_RECORDING: &str = "start_recording";
 pub const CMD_STOP_MIC_TEST: &str = "stop_mic_test";
 pub const CMD_STOP_RECORDING: &str = "stop_recording";
+pub const CMD_SYNC_NOW: &str = "sync_now";
+pub const CMD_TEST_HOTKEY_LEAKAGE: &str = "test_hotkey_leakage";
 pub const CMD_TOGGLE_ENABLED: &str = "toggle_enabled";
+pub const CMD_TRANSCRIBE_FILE: &str = "transcribe_file";
 pub const CMD_UPDATE_CONFIG: &str = "update_config";
 
 pub const TAURI_COMMAND_NAMES: &[&str] = &[
+    "accept_model_license",
+    "apply_sidecar_update",
     "can_start_recording",
+    "cancel_injection",
     "cancel_recording",
+    "check_app_update",
+    "check_sidecar_update",
     "clear_history",
+    "clear_history_filtered",
+    "clear_temporary_replacement_rules",
+    "compare_models",
+    "confirm_injection",
     "copy_last_transcript",
     "copy_transcript",
+    "delete_crash_reports",
+    "delete_phrase_shortcut",
+    "delete_secret",
+    "dismiss_pending_insert",
+    "download_app_update",
     "download_model",
+    "edit_transcript",
+    "export_app_state_snapshot",
     "export_history",
+    "export_subtitles",
     "generate_diagnostics",
+    "get_active_session",
+    "get_app_icon",
     "get_app_state",
     "get_available_presets",
     "get_capabilities",
     "get_capability_issues",
+    "get_command_audit_log",
     "get_config",
+    "get_current_focus_info",
+    "get_enable_level",
+    "get_history_stats",
     "get_hotkey_status",
+    "get_input_gain",
+    "get_learned_dictionary",
+    "get_low_confidence_words",
     "get_model_catalog",
     "get_model_status",
+    "get_overlay_config",
+    "get_paste_calibration",
+    "get_pending_crash_reports",
+    "get_pending_inserts",
+    "get_pending_license",
+    "get_phrase_shortcuts",
     "get_recent_logs",
     "get_replacement_rules",
+    "get_session_label",
+    "get_tag_suggestions",
+    "get_temporary_replacement_rules",
+    "get_transcript_diff",
     "get_transcript_history",
+    "get_unfiltered_transcript",
+    "get_usage_stats",
+    "get_vocabulary",
+    "has_secret",
+    "import_vocabulary",
+    "inject_pending_insert",
+    "install_app_update",
+    "invoke_action",
     "is_enabled",
+    "list_actions",
     "list_audio_devices",
     "load_preset",
+    "practice_hotkey",
     "preview_replacement",
     "purge_model_cache",
+    "reinject_to_original_target",
+    "remove_learned_word",
+    "replay_app_state_snapshot",
+    "report_hotkey_keydown",
     "reset_config_to_defaults",
+    "reset_paste_calibration",
     "restart_sidecar",
     "run_self_check",
+    "save_phrase_shortcut",
+    "search_transcript_history",
     "set_audio_device",
+    "set_enable_level",
     "set_enabled",
     "set_hotkey",
+    "set_injection_mode",
+    "set_input_gain",
     "set_replacement_rules",
+    "set_secret",
+    "set_session_label",
+    "set_temporary_replacement_rules",
+    "set_transcript_label",
+    "set_transcript_pinned",
+    "set_vocabulary",
+    "share_history_entry",
     "start_mic_test",
     "start_recording",
     "stop_mic_test",
     "stop_recording",
+    "sync_now",
+    "test_hotkey_leakage",
     "toggle_enabled",
+    "transcribe_file",
     "update_config",
 ];
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandAcceptModelLicenseParams {
+    #[serde(rename = "modelId")]
+    pub model_id: String,
+}
+
+pub type CommandAcceptModelLicenseResult = TauriCommandDefVoidResult;
+
+pub type CommandApplySidecarUpdateParams = TauriCommandDefEmptyParams;
+
+pub type CommandApplySidecarUpdateResult = String;
+
 pub type CommandCanStartRecordingParams = TauriCommandDefEmptyParams;
 
 pub type CommandCanStartRecordingResult = TauriCommandDefVoidResult;
 
+pub type CommandCancelInjectionParams = TauriCommandDefEmptyParams;
+
+pub type CommandCancelInjectionResult = bool;
+
 pub type CommandCancelRecordingParams = TauriCommandDefEmptyParams;
 
 pub type CommandCancelRecordingResult = TauriCommandDefOpenObject;
 
+pub type CommandCheckAppUpdateParams = TauriCommandDefEmptyParams;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandCheckAppUpdateResult {
+    pub current_version: String,
+    pub in_rollout: bool,
+    pub latest_version: String,
+    pub notes: Option<String>,
+    pub rollout_percentage: i64,
+    pub staged_version: Option<String>,
+    pub update_available: bool,
+}
+
+pub type CommandCheckSidecarUpdateParams = TauriCommandDefEmptyParams;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandCheckSidecarUpdateResult {
+    pub current_version: String,
+    pub latest_version: String,
+    pub notes: Option<String>,
+    pub update_available: bool,
+}
+
 pub type CommandClearHistoryParams = TauriCommandDefEmptyParams;
 
 pub type CommandClearHistoryResult = TauriCommandDefVoidResult;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandClearHistoryFilteredParams {
+    pub filter: serde_json::Value,
+}
+
+pub type CommandClearHistoryFilteredResult = i64;
+
+pub type CommandClearTemporaryReplacementRulesParams = TauriCommandDefEmptyParams;
+
+pub type CommandClearTemporaryReplacementRulesResult = TauriCommandDefVoidResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandCompareModelsParams {
+    #[serde(rename = "audioPath", default, skip_serializing_if = "Option::is_none")]
+    pub audio_path: Option<String>,
+    #[serde(rename = "modelIds")]
+    pub model_ids: Vec<String>,
+    #[serde(rename = "sessionId", default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandCompareModelsResult {
+    pub audio_duration_ms: i64,
+    pub baseline_model_id: Option<String>,
+    pub results: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandConfirmInjectionParams {
+    pub entry_id: String,
+}
+
+pub type CommandConfirmInjectionResult = TauriCommandDefVoidResult;
+
 pub type CommandCopyLastTranscriptParams = TauriCommandDefEmptyParams;
 
 pub type CommandCopyLastTranscriptResult = Option<String>;
@@ -395,6 +896,39 @@ pub struct CommandCopyTranscriptParams {
 
 pub type CommandCopyTranscriptResult = TauriCommandDefVoidResult;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandDeleteCrashReportsParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ids: Option<Vec<String>>,
+}
+
+pub type CommandDeleteCrashReportsResult = i64;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandDeletePhraseShortcutParams {
+    pub id: String,
+}
+
+pub type CommandDeletePhraseShortcutResult = bool;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandDeleteSecretParams {
+    pub name: String,
+}
+
+pub type CommandDeleteSecretResult = bool;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandDismissPendingInsertParams {
+    pub entry_id: String,
+}
+
+pub type CommandDismissPendingInsertResult = bool;
+
+pub type CommandDownloadAppUpdateParams = TauriCommandDefEmptyParams;
+
+pub type CommandDownloadAppUpdateResult = String;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct CommandDownloadModelParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -407,6 +941,18 @@ pub struct CommandDownloadModelParams {
 
 pub type CommandDownloadModelResult = TauriCommandDefVoidResult;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandEditTranscriptParams {
+    pub entry_id: String,
+    pub text: String,
+}
+
+pub type CommandEditTranscriptResult = TauriCommandDefVoidResult;
+
+pub type CommandExportAppStateSnapshotParams = TauriCommandDefEmptyParams;
+
+pub type CommandExportAppStateSnapshotResult = String;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct CommandExportHistoryParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -417,10 +963,29 @@ pub struct CommandExportHistoryParams {
 
 pub type CommandExportHistoryResult = TauriCommandDefOpenObject;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandExportSubtitlesParams {
+    pub entry_id: String,
+    pub format: String,
+}
+
+pub type CommandExportSubtitlesResult = String;
+
 pub type CommandGenerateDiagnosticsParams = TauriCommandDefEmptyParams;
 
 pub type CommandGenerateDiagnosticsResult = TauriCommandDefDiagnosticsReport;
 
+pub type CommandGetActiveSessionParams = TauriCommandDefEmptyParams;
+
+pub type CommandGetActiveSessionResult = Option<TauriCommandDefSessionSnapshot>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetAppIconParams {
+    pub app_id: String,
+}
+
+pub type CommandGetAppIconResult = Option<serde_json::Value>;
+
 pub type CommandGetAppStateParams = TauriCommandDefEmptyParams;
 
 pub type CommandGetAppStateResult = TauriCommandDefStateEvent;
@@ -437,20 +1002,84 @@ pub type CommandGetCapabilityIssuesParams = TauriCommandDefEmptyParams;
 
 pub type CommandGetCapabilityIssuesResult = Vec<TauriCommandDefOpenObject>;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetCommandAuditLogParams {
+    pub count: i64,
+}
+
+pub type CommandGetCommandAuditLogResult = Vec<TauriCommandDefCommandAuditEntry>;
+
 pub type CommandGetConfigParams = TauriCommandDefEmptyParams;
 
 pub type CommandGetConfigResult = TauriCommandDefOpenObject;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetCurrentFocusInfoParams {
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetCurrentFocusInfoResult {
+    pub app_name: String,
+    pub is_self: bool,
+    pub window_id: String,
+    pub window_title: Option<String>,
+}
+
+pub type CommandGetEnableLevelParams = TauriCommandDefEmptyParams;
+
+pub type CommandGetEnableLevelResult = String;
+
+pub type CommandGetHistoryStatsParams = TauriCommandDefEmptyParams;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetHistoryStatsResult {
+    pub entries: i64,
+    pub last_pruned_at: Option<String>,
+    pub max_entries: i64,
+    pub memory_bytes: i64,
+    pub newest_at: Option<String>,
+    pub oldest_at: Option<String>,
+    pub persistence: String,
+    pub pinned: i64,
+    pub storage_bytes: i64,
+}
+
 pub type CommandGetHotkeyStatusParams = TauriCommandDefEmptyParams;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct CommandGetHotkeyStatusResult {
+    pub conflicts: Vec<String>,
     pub copy_last: String,
     pub mode: String,
     pub primary: String,
+    pub profile: Option<String>,
     pub registered: bool,
+    pub suppressed_while_typing: i64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetInputGainParams {
+    #[serde(rename = "deviceUid", default, skip_serializing_if = "Option::is_none")]
+    pub device_uid: Option<String>,
+    #[serde(rename = "device_uid", default, skip_serializing_if = "Option::is_none")]
+    pub device_uid_2: Option<String>,
+}
+
+pub type CommandGetInputGainResult = TauriCommandDefInputGain;
+
+pub type CommandGetLearnedDictionaryParams = TauriCommandDefEmptyParams;
+
+pub type CommandGetLearnedDictionaryResult = Vec<serde_json::Value>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetLowConfidenceWordsParams {
+    pub entry_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<f64>,
+}
+
+pub type CommandGetLowConfidenceWordsResult = Vec<i64>;
+
 pub type CommandGetModelCatalogParams = TauriCommandDefEmptyParams;
 
 pub type CommandGetModelCatalogResult = Vec<TauriCommandDefModelCatalogEntry>;
@@ -465,9 +1094,54 @@ pub struct CommandGetModelStatusParams {
 
 pub type CommandGetModelStatusResult = TauriCommandDefModelStatus;
 
+pub type CommandGetOverlayConfigParams = TauriCommandDefEmptyParams;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetOverlayConfigResult {
+    pub opacity: f64,
+    pub scale: f64,
+    pub show_waveform: bool,
+    pub theme: String,
+}
+
+pub type CommandGetPasteCalibrationParams = TauriCommandDefEmptyParams;
+
+pub type CommandGetPasteCalibrationResult = Vec<serde_json::Value>;
+
+pub type CommandGetPendingCrashReportsParams = TauriCommandDefEmptyParams;
+
+pub type CommandGetPendingCrashReportsResult = Vec<TauriCommandDefOpenObject>;
+
+pub type CommandGetPendingInsertsParams = TauriCommandDefEmptyParams;
+
+pub type CommandGetPendingInsertsResult = Vec<serde_json::Value>;
+
+pub type CommandGetPendingLicenseParams = TauriCommandDefEmptyParams;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetPendingLicenseResult {
+    pub model_id: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    pub spdx_id: String,
+    pub text: String,
+    pub url: String,
+}
+
+pub type CommandGetPhraseShortcutsParams = TauriCommandDefEmptyParams;
+
+pub type CommandGetPhraseShortcutsResult = Vec<TauriCommandDefPhraseShortcut>;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct CommandGetRecentLogsParams {
     pub count: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub module: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
 }
 
 pub type CommandGetRecentLogsResult = Vec<TauriCommandDefLogEntry>;
@@ -476,14 +1150,109 @@ pub type CommandGetReplacementRulesParams = TauriCommandDefEmptyParams;
 
 pub type CommandGetReplacementRulesResult = Vec<TauriCommandDefOpenObject>;
 
+pub type CommandGetSessionLabelParams = TauriCommandDefEmptyParams;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetSessionLabelResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetTagSuggestionsParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+}
+
+pub type CommandGetTagSuggestionsResult = Vec<String>;
+
+pub type CommandGetTemporaryReplacementRulesParams = TauriCommandDefEmptyParams;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetTemporaryReplacementRulesResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    pub rules: Vec<TauriCommandDefOpenObject>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetTranscriptDiffParams {
+    pub entry_id: String,
+}
+
+pub type CommandGetTranscriptDiffResult = TauriCommandDefTranscriptDiff;
+
 pub type CommandGetTranscriptHistoryParams = TauriCommandDefEmptyParams;
 
 pub type CommandGetTranscriptHistoryResult = Vec<TauriCommandDefOpenObject>;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetUnfilteredTranscriptParams {
+    pub entry_id: String,
+}
+
+pub type CommandGetUnfilteredTranscriptResult = Option<String>;
+
+pub type CommandGetUsageStatsParams = TauriCommandDefEmptyParams;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandGetUsageStatsResult {
+    pub all_time: TauriCommandDefUsageTotals,
+    pub days: Vec<serde_json::Value>,
+    pub today: TauriCommandDefUsageTotals,
+}
+
+pub type CommandGetVocabularyParams = TauriCommandDefEmptyParams;
+
+pub type CommandGetVocabularyResult = Vec<String>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandHasSecretParams {
+    pub name: String,
+}
+
+pub type CommandHasSecretResult = bool;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandImportVocabularyParams {
+    pub path: String,
+}
+
+pub type CommandImportVocabularyResult = Vec<String>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandInjectPendingInsertParams {
+    pub entry_id: String,
+}
+
+pub type CommandInjectPendingInsertResult = TauriCommandDefVoidResult;
+
+pub type CommandInstallAppUpdateParams = TauriCommandDefEmptyParams;
+
+pub type CommandInstallAppUpdateResult = TauriCommandDefVoidResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandInvokeActionParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub args: Option<BTreeMap<String, String>>,
+    pub id: String,
+}
+
+pub type CommandInvokeActionResult = serde_json::Value;
+
 pub type CommandIsEnabledParams = TauriCommandDefEmptyParams;
 
 pub type CommandIsEnabledResult = bool;
 
+pub type CommandListActionsParams = TauriCommandDefEmptyParams;
+
+pub type CommandListActionsResult = Vec<TauriCommandDefActionInfo>;
+
 pub type CommandListAudioDevicesParams = TauriCommandDefEmptyParams;
 
 pub type CommandListAudioDevicesResult = Vec<TauriCommandDefAudioDevice>;
@@ -498,6 +1267,23 @@ pub struct CommandLoadPresetParams {
 
 pub type CommandLoadPresetResult = Vec<TauriCommandDefOpenObject>;
 
+pub type CommandPracticeHotkeyParams = TauriCommandDefEmptyParams;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandPracticeHotkeyResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hold_ms: Option<i64>,
+    pub hotkey: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<i64>,
+    pub mode: String,
+    pub passed: bool,
+    pub presses: i64,
+    pub releases: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct CommandPreviewReplacementParams {
     pub input: String,
@@ -521,10 +1307,43 @@ pub struct CommandPurgeModelCacheParams {
 
 pub type CommandPurgeModelCacheResult = TauriCommandDefVoidResult;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandReinjectToOriginalTargetParams {
+    pub entry_id: String,
+}
+
+pub type CommandReinjectToOriginalTargetResult = TauriCommandDefVoidResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandRemoveLearnedWordParams {
+    pub word: String,
+}
+
+pub type CommandRemoveLearnedWordResult = bool;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandReplayAppStateSnapshotParams {
+    pub path: String,
+}
+
+pub type CommandReplayAppStateSnapshotResult = i64;
+
+pub type CommandReportHotkeyKeydownParams = TauriCommandDefEmptyParams;
+
+pub type CommandReportHotkeyKeydownResult = TauriCommandDefVoidResult;
+
 pub type CommandResetConfigToDefaultsParams = TauriCommandDefEmptyParams;
 
 pub type CommandResetConfigToDefaultsResult = TauriCommandDefOpenObject;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandResetPasteCalibrationParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<String>,
+}
+
+pub type CommandResetPasteCalibrationResult = i64;
+
 pub type CommandRestartSidecarParams = TauriCommandDefEmptyParams;
 
 pub type CommandRestartSidecarResult = TauriCommandDefOpenObject;
@@ -540,6 +1359,28 @@ pub struct CommandRunSelfCheckResult {
     pub sidecar: TauriCommandDefCheckItem,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSavePhraseShortcutParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub phrase: String,
+    pub value: TauriCommandDefPhraseShortcutValue,
+}
+
+pub type CommandSavePhraseShortcutResult = TauriCommandDefPhraseShortcut;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSearchTranscriptHistoryParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+pub type CommandSearchTranscriptHistoryResult = Vec<TauriCommandDefOpenObject>;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct CommandSetAudioDeviceParams {
     #[serde(rename = "deviceUid", default, skip_serializing_if = "Option::is_none")]
@@ -550,6 +1391,13 @@ pub struct CommandSetAudioDeviceParams {
 
 pub type CommandSetAudioDeviceResult = String;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSetEnableLevelParams {
+    pub level: String,
+}
+
+pub type CommandSetEnableLevelResult = TauriCommandDefVoidResult;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct CommandSetEnabledParams {
     pub enabled: bool,
@@ -568,12 +1416,101 @@ pub struct CommandSetHotkeyParams {
 
 pub type CommandSetHotkeyResult = TauriCommandDefVoidResult;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSetInjectionModeParams {
+    pub mode: String,
+}
+
+pub type CommandSetInjectionModeResult = String;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSetInputGainParams {
+    #[serde(rename = "deviceUid", default, skip_serializing_if = "Option::is_none")]
+    pub device_uid: Option<String>,
+    #[serde(rename = "device_uid", default, skip_serializing_if = "Option::is_none")]
+    pub device_uid_2: Option<String>,
+    #[serde(rename = "gainDb")]
+    pub gain_db: f64,
+}
+
+pub type CommandSetInputGainResult = TauriCommandDefInputGain;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct CommandSetReplacementRulesParams {
     pub rules: Vec<TauriCommandDefOpenObject>,
 }
 
-pub type CommandSetReplacementRulesResult = TauriCommandDefVoidResult;
+pub type CommandSetReplacementRulesResult = Vec<serde_json::Value>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSetSecretParams {
+    pub name: String,
+    pub value: String,
+}
+
+pub type CommandSetSecretResult = TauriCommandDefVoidResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSetSessionLabelParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSetSessionLabelResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSetTemporaryReplacementRulesParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    pub rules: Vec<TauriCommandDefOpenObject>,
+}
+
+pub type CommandSetTemporaryReplacementRulesResult = TauriCommandDefVoidResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSetTranscriptLabelParams {
+    #[serde(rename = "entryId")]
+    pub entry_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSetTranscriptLabelResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSetTranscriptPinnedParams {
+    #[serde(rename = "entryId")]
+    pub entry_id: String,
+    pub pinned: bool,
+}
+
+pub type CommandSetTranscriptPinnedResult = TauriCommandDefVoidResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSetVocabularyParams {
+    pub terms: Vec<String>,
+}
+
+pub type CommandSetVocabularyResult = Vec<String>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandShareHistoryEntryParams {
+    pub entry_id: String,
+}
+
+pub type CommandShareHistoryEntryResult = String;
 
 pub type CommandStartMicTestParams = TauriCommandDefEmptyParams;
 
@@ -591,10 +1528,39 @@ pub type CommandStopRecordingParams = TauriCommandDefEmptyParams;
 
 pub type CommandStopRecordingResult = TauriCommandDefOpenObject;
 
+pub type CommandSyncNowParams = TauriCommandDefEmptyParams;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandSyncNowResult {
+    pub conflicts: Vec<serde_json::Value>,
+    pub pulled: i64,
+    pub pushed: i64,
+    pub synced_at: String,
+}
+
+pub type CommandTestHotkeyLeakageParams = TauriCommandDefEmptyParams;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandTestHotkeyLeakageResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    pub hotkey: String,
+    pub hotkey_detected: bool,
+    pub leaked: bool,
+    pub suppression: String,
+}
+
 pub type CommandToggleEnabledParams = TauriCommandDefEmptyParams;
 
 pub type CommandToggleEnabledResult = bool;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandTranscribeFileParams {
+    pub path: String,
+}
+
+pub type CommandTranscribeFileResult = TauriCommandDefOpenObject;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct CommandUpdateConfigParams {
     pub config: TauriCommandDefOpenObject,
@@ -604,63 +1570,150 @@ pub type CommandUpdateConfigResult = TauriCommandDefVoidResult;
 
 // Tauri event constants and payload types
 pub const EVENT_APP_ERROR: &str = "app:error";
+pub const EVENT_APP_NAVIGATE: &str = "app:navigate";
+pub const EVENT_APP_RECOVERY: &str = "app:recovery";
+pub const EVENT_APP_UPDATE_PROGRESS: &str = "app:update_progress";
 pub const EVENT_AUDIO_LEVEL: &str = "audio:level";
+pub const EVENT_CAPABILITIES_ISSUES: &str = "capabilities:issues";
+pub const EVENT_CUES_STATUS: &str = "cues:status";
+pub const EVENT_FILE_TRANSCRIPTION_PROGRESS: &str = "file_transcription:progress";
+pub const EVENT_FOCUS_CHANGED: &str = "focus:changed";
+pub const EVENT_INJECTION_CONFIRM_REQUESTED: &str = "injection:confirm_requested";
+pub const EVENT_INJECTION_DEFERRED: &str = "injection:deferred";
+pub const EVENT_INJECTION_MODE_CHANGED: &str = "injection:mode_changed";
+pub const EVENT_INJECTION_PROGRESS: &str = "injection:progress";
+pub const EVENT_INJECTION_QUEUE_CHANGED: &str = "injection:queue_changed";
 pub const EVENT_MODEL_PROGRESS: &str = "model:progress";
 pub const EVENT_MODEL_STATUS: &str = "model:status";
+pub const EVENT_OVERLAY_CANCELLED: &str = "overlay:cancelled";
+pub const EVENT_OVERLAY_CONFIG: &str = "overlay:config";
+pub const EVENT_OVERLAY_COUNTDOWN: &str = "overlay:countdown";
 pub const EVENT_OVERLAY_TOGGLE: &str = "overlay:toggle";
+pub const EVENT_OVERLAY_TRANSCRIBING: &str = "overlay:transcribing";
+pub const EVENT_PENDING_INSERTS_CHANGED: &str = "pending_inserts:changed";
 pub const EVENT_RECORDING_STATUS: &str = "recording:status";
+pub const EVENT_SESSION_CHANGED: &str = "session:changed";
 pub const EVENT_SIDECAR_STATUS: &str = "sidecar:status";
+pub const EVENT_SIDECAR_UPDATE_STATUS: &str = "sidecar:update_status";
 pub const EVENT_STATE_CHANGED: &str = "state:changed";
 pub const EVENT_TRANSCRIPT_COMPLETE: &str = "transcript:complete";
 pub const EVENT_TRANSCRIPT_ERROR: &str = "transcript:error";
+pub const EVENT_VAD_PREVIEW: &str = "vad:preview";
 
 pub const TAURI_EVENT_NAMES: &[&str] = &[
     "app:error",
+    "app:navigate",
+    "app:recovery",
+    "app:update_progress",
     "audio:level",
+    "capabilities:issues",
+    "cues:status",
+    "file_transcription:progress",
+    "focus:changed",
+    "injection:confirm_requested",
+    "injection:deferred",
+    "injection:mode_changed",
+    "injection:progress",
+    "injection:queue_changed",
     "model:progress",
     "model:status",
+    "overlay:cancelled",
+    "overlay:config",
+    "overlay:countdown",
     "overlay:toggle",
+    "overlay:transcribing",
+    "pending_inserts:changed",
     "recording:status",
+    "session:changed",
     "sidecar:status",
+    "sidecar:update_status",
     "state:changed",
     "transcript:complete",
     "transcript:error",
+    "vad:preview",
 ];
 
 pub type EventAppErrorPayload = TauriEventDefAppErrorPayload;
 
+pub type EventAppNavigatePayload = TauriEventDefAppNavigatePayload;
+
+pub type EventAppRecoveryPayload = TauriEventDefAppRecoveryPayload;
+
+pub type EventAppUpdateProgressPayload = TauriEventDefAppUpdateProgressPayload;
+
 pub type EventAudioLevelPayload = TauriEventDefAudioLevelPayload;
 
+pub type EventCapabilitiesIssuesPayload = TauriEventDefCapabilitiesIssuesPayload;
+
+pub type EventCuesStatusPayload = TauriEventDefCuesStatusPayload;
+
+pub type EventFileTranscriptionProgressPayload = TauriEventDefFileTranscriptionProgressPayload;
+
+pub type EventFocusChangedPayload = TauriEventDefFocusChangedPayload;
+
+pub type EventInjectionConfirmRequestedPayload = TauriEventDefInjectionConfirmRequestedPayload;
+
+pub type EventInjectionDeferredPayload = TauriEventDefInjectionDeferredPayload;
+
+pub type EventInjectionModeChangedPayload = TauriEventDefInjectionModeChangedPayload;
+
+pub type EventInjectionProgressPayload = TauriEventDefInjectionProgressPayload;
+
+pub type EventInjectionQueueChangedPayload = TauriEventDefInjectionQueueChangedPayload;
+
 pub type EventModelProgressPayload = TauriEventDefModelProgressPayload;
 
 pub type EventModelStatusPayload = TauriEventDefModelStatusPayload;
 
+pub type EventOverlayCancelledPayload = TauriEventDefOverlayCancelledPayload;
+
+pub type EventOverlayConfigPayload = TauriEventDefOverlayConfigPayload;
+
+pub type EventOverlayCountdownPayload = TauriEventDefOverlayCountdownPayload;
+
 pub type EventOverlayTogglePayload = TauriEventDefOverlayTogglePayload;
 
+pub type EventOverlayTranscribingPayload = TauriEventDefOverlayTranscribingPayload;
+
+pub type EventPendingInsertsChangedPayload = TauriEventDefPendingInsertsChangedPayload;
+
 pub type EventRecordingStatusPayload = TauriEventDefRecordingStatusPayload;
 
+pub type EventSessionChangedPayload = TauriEventDefSessionChangedPayload;
+
 pub type EventSidecarStatusPayload = TauriEventDefSidecarStatusPayload;
 
+pub type EventSidecarUpdateStatusPayload = TauriEventDefSidecarUpdateStatusPayload;
+
 pub type EventStateChangedPayload = TauriEventDefStateChangedPayload;
 
 pub type EventTranscriptCompletePayload = TauriEventDefTranscriptCompletePayload;
 
 pub type EventTranscriptErrorPayload = TauriEventDefTranscriptErrorPayload;
 
+pub type EventVadPreviewPayload = TauriEventDefVadPreviewPayload;
+
 // Sidecar RPC method constants and payload types
 pub const RPC_ASR_INITIALIZE: &str = "asr.initialize";
 pub const RPC_ASR_STATUS: &str = "asr.status";
 pub const RPC_ASR_TRANSCRIBE: &str = "asr.transcribe";
+pub const RPC_ASR_UNLOAD: &str = "asr.unload";
+pub const RPC_AUDIO_GET_GAIN: &str = "audio.get_gain";
 pub const RPC_AUDIO_LIST_DEVICES: &str = "audio.list_devices";
 pub const RPC_AUDIO_METER_START: &str = "audio.meter_start";
 pub const RPC_AUDIO_METER_STATUS: &str = "audio.meter_status";
 pub const RPC_AUDIO_METER_STOP: &str = "audio.meter_stop";
 pub const RPC_AUDIO_SET_DEVICE: &str = "audio.set_device";
+pub const RPC_AUDIO_SET_GAIN: &str = "audio.set_gain";
+pub const RPC_BATCH_COMPARE_MODELS: &str = "batch.compare_models";
+pub const RPC_FILE_TRANSCRIBE: &str = "file.transcribe";
 pub const RPC_MODEL_DOWNLOAD: &str = "model.download";
 pub const RPC_MODEL_GET_STATUS: &str = "model.get_status";
 pub const RPC_MODEL_INSTALL: &str = "model.install";
 pub const RPC_MODEL_PURGE_CACHE: &str = "model.purge_cache";
 pub const RPC_RECORDING_CANCEL: &str = "recording.cancel";
+pub const RPC_RECORDING_EXPORT_AUDIO: &str = "recording.export_audio";
+pub const RPC_RECORDING_SPLIT: &str = "recording.split";
 pub const RPC_RECORDING_START: &str = "recording.start";
 pub const RPC_RECORDING_STATUS: &str = "recording.status";
 pub const RPC_RECORDING_STOP: &str = "recording.stop";
@@ -673,21 +1726,30 @@ pub const RPC_STATUS_GET: &str = "status.get";
 pub const RPC_SYSTEM_INFO: &str = "system.info";
 pub const RPC_SYSTEM_PING: &str = "system.ping";
 pub const RPC_SYSTEM_SHUTDOWN: &str = "system.shutdown";
+pub const RPC_TRANSCRIPTION_GET_RESULT: &str = "transcription.get_result";
+pub const RPC_TRANSCRIPTION_RETRY: &str = "transcription.retry";
 
 pub const SIDECAR_RPC_METHOD_NAMES: &[&str] = &[
     "asr.initialize",
     "asr.status",
     "asr.transcribe",
+    "asr.unload",
+    "audio.get_gain",
     "audio.list_devices",
     "audio.meter_start",
     "audio.meter_status",
     "audio.meter_stop",
     "audio.set_device",
+    "audio.set_gain",
+    "batch.compare_models",
+    "file.transcribe",
     "model.download",
     "model.get_status",
     "model.install",
     "model.purge_cache",
     "recording.cancel",
+    "recording.export_audio",
+    "recording.split",
     "recording.start",
     "recording.status",
     "recording.stop",
@@ -700,6 +1762,8 @@ pub const SIDECAR_RPC_METHOD_NAMES: &[&str] = &[
     "system.info",
     "system.ping",
     "system.shutdown",
+    "transcription.get_result",
+    "transcription.retry",
 ];
 
 pub const SIDECAR_RPC_REQUIRED_METHOD_NAMES: &[&str] = &[
@@ -725,16 +1789,27 @@ pub const SIDECAR_RPC_REQUIRED_METHOD_NAMES: &[&str] = &[
 pub const SIDECAR_RPC_OPTIONAL_METHOD_NAMES: &[&str] = &[
     "asr.status",
     "asr.transcribe",
+    "asr.unload",
+    "audio.get_gain",
     "audio.meter_status",
+    "audio.set_gain",
+    "batch.compare_models",
+    "file.transcribe",
     "model.download",
     "model.install",
+    "recording.export_audio",
+    "recording.split",
     "recording.status",
     "replacements.get_preset_rules",
     "replacements.preview",
+    "transcription.get_result",
+    "transcription.retry",
 ];
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct RpcAsrInitializeParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beam_size: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub device_pref: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -792,6 +1867,32 @@ pub struct RpcAsrTranscribeResult {
     pub extra: BTreeMap<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcAsrUnloadParams {
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcAsrUnloadResult {
+    pub ready: bool,
+    pub state: String,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcAudioGetGainParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_uid: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcAudioGetGainResult {
+    pub device_uid: Option<String>,
+    pub gain_db: f64,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct RpcAudioListDevicesParams {
 }
@@ -808,13 +1909,23 @@ pub struct RpcAudioMeterStartParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub device_uid: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_gains: Option<BTreeMap<String, f64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub interval_ms: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vad_min_speech_ms: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vad_preview: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vad_silence_ms: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct RpcAudioMeterStartResult {
     pub interval_ms: i64,
     pub running: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vad_preview: Option<bool>,
     #[serde(flatten)]
     pub extra: BTreeMap<String, serde_json::Value>,
 }
@@ -856,8 +1967,74 @@ pub struct RpcAudioSetDeviceResult {
     pub extra: BTreeMap<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcAudioSetGainParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_uid: Option<String>,
+    pub gain_db: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcAudioSetGainResult {
+    pub device_uid: String,
+    pub gain_db: f64,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcBatchCompareModelsParams {
+    pub audio_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_pref: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    pub model_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcBatchCompareModelsResult {
+    pub audio_duration_ms: i64,
+    pub results: Vec<serde_json::Value>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcFileTranscribeParams {
+    pub audio_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beam_size: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_pref: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diarize: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    pub model_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcFileTranscribeResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub segments: Option<Vec<serde_json::Value>>,
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub words: Option<Vec<serde_json::Value>>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct RpcModelDownloadParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license_accepted: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub model_id: Option<String>,
     #[serde(flatten)]
@@ -871,6 +2048,8 @@ pub struct RpcModelDownloadResult {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub model_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub progress: Option<BTreeMap<String, serde_json::Value>>,
@@ -904,6 +2083,8 @@ pub struct RpcModelGetStatusResult {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct RpcModelInstallParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license_accepted: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub model_id: Option<String>,
     #[serde(flatten)]
@@ -917,6 +2098,8 @@ pub struct RpcModelInstallResult {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub model_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub progress: Option<BTreeMap<String, serde_json::Value>>,
@@ -953,11 +2136,52 @@ pub struct RpcRecordingCancelResult {
     pub extra: BTreeMap<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcRecordingExportAudioParams {
+    pub audio_path: String,
+    pub session_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcRecordingExportAudioResult {
+    pub audio_path: String,
+    pub duration_ms: i64,
+    pub session_id: String,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcRecordingSplitParams {
+    pub session_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcRecordingSplitResult {
+    pub audio_duration_ms: i64,
+    pub segment_index: i64,
+    pub session_id: String,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct RpcRecordingStartParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub continuous: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub device_uid: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diarize: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flush_interval_ms: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hotwords: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_gains: Option<BTreeMap<String, f64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub punctuate: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub session_id: Option<String>,
     #[serde(flatten)]
     pub extra: BTreeMap<String, serde_json::Value>,
@@ -1124,12 +2348,44 @@ pub struct RpcSystemShutdownResult {
     pub extra: BTreeMap<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcTranscriptionGetResultParams {
+    pub session_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcTranscriptionGetResultResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    pub session_id: String,
+    pub state: String,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcTranscriptionRetryParams {
+    pub session_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcTranscriptionRetryResult {
+    pub retrying: bool,
+    pub session_id: String,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
 // Sidecar RPC notification constants and payload types
 pub const RPC_NOTIFY_EVENT_AUDIO_LEVEL: &str = "event.audio_level";
 pub const RPC_NOTIFY_EVENT_MODEL_PROGRESS: &str = "event.model_progress";
 pub const RPC_NOTIFY_EVENT_STATUS_CHANGED: &str = "event.status_changed";
 pub const RPC_NOTIFY_EVENT_TRANSCRIPTION_COMPLETE: &str = "event.transcription_complete";
 pub const RPC_NOTIFY_EVENT_TRANSCRIPTION_ERROR: &str = "event.transcription_error";
+pub const RPC_NOTIFY_EVENT_UTTERANCE_COMPLETE: &str = "event.utterance_complete";
+pub const RPC_NOTIFY_EVENT_VAD_PREVIEW: &str = "event.vad_preview";
 
 pub const SIDECAR_RPC_NOTIFICATION_NAMES: &[&str] = &[
     "event.audio_level",
@@ -1137,6 +2393,8 @@ pub const SIDECAR_RPC_NOTIFICATION_NAMES: &[&str] = &[
     "event.status_changed",
     "event.transcription_complete",
     "event.transcription_error",
+    "event.utterance_complete",
+    "event.vad_preview",
 ];
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -1176,7 +2434,11 @@ pub struct RpcNotificationEventStatusChangedParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub model: Option<BTreeMap<String, serde_json::Value>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub percent: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub progress: Option<BTreeMap<String, serde_json::Value>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage: Option<String>,
     pub state: String,
     #[serde(flatten)]
     pub extra: BTreeMap<String, serde_json::Value>,
@@ -1187,8 +2449,12 @@ pub struct RpcNotificationEventTranscriptionCompleteParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub confidence: Option<f64>,
     pub duration_ms: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub segments: Option<Vec<serde_json::Value>>,
     pub session_id: String,
     pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub words: Option<Vec<serde_json::Value>>,
     #[serde(flatten)]
     pub extra: BTreeMap<String, serde_json::Value>,
 }
@@ -1201,3 +2467,32 @@ pub struct RpcNotificationEventTranscriptionErrorParams {
     #[serde(flatten)]
     pub extra: BTreeMap<String, serde_json::Value>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcNotificationEventUtteranceCompleteParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_duration_ms: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    pub duration_ms: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_text: Option<String>,
+    pub segment_index: i64,
+    pub session_id: String,
+    pub text: String,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RpcNotificationEventVadPreviewParams {
+    pub silence_ms: f64,
+    pub silence_threshold_ms: i64,
+    pub source: String,
+    pub speech_ms: f64,
+    pub state: String,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
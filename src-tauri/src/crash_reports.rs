@@ -0,0 +1,562 @@
+//! Crash and panic reports with an opt-in upload queue.
+//!
+//! Panics (through a panic hook) and sidecar crashes (classified from the
+//! sidecar's last log lines) are written as JSON reports to `crash-reports/`
+//! in the config directory. Reports are anonymous: they carry the app
+//! version, OS, architecture, and a redacted message and log excerpt, but no
+//! user, machine, or transcript data. They stay on disk until the user
+//! deletes them, unless `crash_reporting.upload_enabled` is turned on, in
+//! which case they are sent to `crash_reporting.endpoint` and removed once
+//! accepted. Failed uploads back off exponentially. As with other network
+//! requests, uploads go through `curl` and are refused in offline mode.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::config;
+use crate::log_buffer;
+use crate::network::{self, NetworkPurpose};
+
+/// Report directory inside the config directory, shared with startup
+/// failure reports.
+const CRASH_REPORT_DIR_NAME: &str = "crash-reports";
+
+/// File name prefix of queued reports.
+const REPORT_FILE_PREFIX: &str = "crash-";
+
+/// Log lines kept in a report.
+const REPORT_LOG_LINES: usize = 40;
+
+/// How often the upload loop looks for reports that are due.
+const UPLOAD_POLL_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Delay before the first retry of a failed upload; doubled per attempt.
+const UPLOAD_BACKOFF_BASE_SECS: i64 = 60;
+
+/// Longest delay between upload attempts.
+const UPLOAD_BACKOFF_MAX_SECS: i64 = 24 * 60 * 60;
+
+const UPLOAD_TIMEOUT_SECS: &str = "30";
+
+/// What crashed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrashKind {
+    /// A panic in the app.
+    Panic,
+    /// The sidecar process exited unexpectedly.
+    SidecarCrash,
+}
+
+/// Likely cause of a sidecar crash, read from its last log lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SidecarCrashCategory {
+    OutOfMemory,
+    /// A Python module or native library failed to import.
+    MissingDependency,
+    /// Segfault, abort, or another fatal signal in native code.
+    NativeCrash,
+    /// An uncaught Python exception.
+    PythonException,
+    Unknown,
+}
+
+impl SidecarCrashCategory {
+    /// Classify a crash from the sidecar's last log lines. The most specific
+    /// match wins: an out-of-memory error is usually followed by a traceback.
+    pub fn classify(log_lines: &[String]) -> Self {
+        let text = log_lines.join("\n").to_lowercase();
+        let contains_any = |needles: &[&str]| needles.iter().any(|needle| text.contains(needle));
+
+        if contains_any(&[
+            "memoryerror",
+            "out of memory",
+            "cannot allocate memory",
+            "oom-kill",
+        ]) {
+            Self::OutOfMemory
+        } else if contains_any(&[
+            "modulenotfounderror",
+            "importerror",
+            "no module named",
+            "cannot open shared object",
+            "library not loaded",
+            "dll load failed",
+        ]) {
+            Self::MissingDependency
+        } else if contains_any(&[
+            "segmentation fault",
+            "sigsegv",
+            "sigabrt",
+            "fatal python error",
+            "illegal instruction",
+            "bus error",
+        ]) {
+            Self::NativeCrash
+        } else if contains_any(&["traceback (most recent call last)"]) {
+            Self::PythonException
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+/// A redacted crash report.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrashReport {
+    pub id: Uuid,
+    pub kind: CrashKind,
+    pub created_at: DateTime<Utc>,
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    /// Panic message or sidecar failure summary, redacted.
+    pub message: String,
+    /// Source location of a panic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sidecar_category: Option<SidecarCrashCategory>,
+    /// Last log lines before the crash, redacted.
+    #[serde(default)]
+    pub recent_logs: Vec<String>,
+    /// Failed upload attempts so far.
+    #[serde(default)]
+    pub upload_attempts: u32,
+    /// Earliest time of the next upload attempt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_upload_at: Option<DateTime<Utc>>,
+}
+
+impl CrashReport {
+    fn new(kind: CrashKind, message: &str, recent_logs: Vec<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            kind,
+            created_at: Utc::now(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            message: log_buffer::redact_sensitive(message),
+            location: None,
+            sidecar_category: None,
+            recent_logs: recent_logs
+                .iter()
+                .map(|line| log_buffer::redact_sensitive(line))
+                .collect(),
+            upload_attempts: 0,
+            next_upload_at: None,
+        }
+    }
+
+    /// Report of a panic with `message` at `location`.
+    pub fn panic(message: &str, location: Option<String>) -> Self {
+        let recent_logs = log_buffer::global_buffer().try_recent_lines(REPORT_LOG_LINES);
+        Self {
+            location,
+            ..Self::new(CrashKind::Panic, message, recent_logs)
+        }
+    }
+
+    /// Report of a sidecar crash, classified from its last log lines.
+    pub fn sidecar_crash(restart_count: u32, sidecar_logs: &[String]) -> Self {
+        let category = SidecarCrashCategory::classify(sidecar_logs);
+        let skip = sidecar_logs.len().saturating_sub(REPORT_LOG_LINES);
+        let message = format!(
+            "sidecar crashed ({:?}) after {} restarts",
+            category, restart_count
+        );
+        Self {
+            sidecar_category: Some(category),
+            ..Self::new(
+                CrashKind::SidecarCrash,
+                &message,
+                sidecar_logs[skip..].to_vec(),
+            )
+        }
+    }
+
+    /// Whether an upload may be attempted at `now`.
+    fn upload_due(&self, now: DateTime<Utc>) -> bool {
+        self.next_upload_at.is_none_or(|due| due <= now)
+    }
+
+    /// Record a failed upload at `now` and schedule the next attempt.
+    fn upload_failed(&mut self, now: DateTime<Utc>) {
+        self.upload_attempts = self.upload_attempts.saturating_add(1);
+        let exponent = self.upload_attempts.saturating_sub(1).min(16);
+        let delay = UPLOAD_BACKOFF_BASE_SECS
+            .saturating_mul(1 << exponent)
+            .min(UPLOAD_BACKOFF_MAX_SECS);
+        self.next_upload_at = Some(now + chrono::Duration::seconds(delay));
+    }
+}
+
+/// Crash reports stored as one JSON file each.
+#[derive(Debug, Clone)]
+pub struct CrashReportStore {
+    dir: PathBuf,
+}
+
+impl CrashReportStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn path(&self, id: Uuid) -> PathBuf {
+        self.dir.join(format!("{}{}.json", REPORT_FILE_PREFIX, id))
+    }
+
+    /// Write `report`, replacing an earlier version of it.
+    pub fn save(&self, report: &CrashReport) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path(report.id);
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, serde_json::to_vec_pretty(report)?)?;
+        fs::rename(&temp_path, &path)
+    }
+
+    /// Stored reports, oldest first. Unreadable files are skipped.
+    pub fn pending(&self) -> Vec<CrashReport> {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        let mut reports: Vec<CrashReport> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| is_report_file(path))
+            .filter_map(|path| {
+                let contents = fs::read(&path).ok()?;
+                serde_json::from_slice(&contents)
+                    .map_err(|error| {
+                        log::warn!(
+                            "Skipping unreadable crash report {}: {}",
+                            path.display(),
+                            error
+                        )
+                    })
+                    .ok()
+            })
+            .collect();
+        reports.sort_by_key(|report| report.created_at);
+        reports
+    }
+
+    /// Delete the reports in `ids`, or every report when `None`.
+    /// Returns how many were deleted.
+    pub fn delete(&self, ids: Option<&[Uuid]>) -> io::Result<usize> {
+        let targets: Vec<Uuid> = match ids {
+            Some(ids) => ids.to_vec(),
+            None => self.pending().into_iter().map(|report| report.id).collect(),
+        };
+        let mut deleted = 0;
+        for id in targets {
+            match fs::remove_file(self.path(id)) {
+                Ok(()) => deleted += 1,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(deleted)
+    }
+}
+
+fn is_report_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "json")
+        && path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(REPORT_FILE_PREFIX))
+}
+
+/// The store in the config directory.
+pub fn global_store() -> CrashReportStore {
+    CrashReportStore::new(config::config_dir().join(CRASH_REPORT_DIR_NAME))
+}
+
+/// Save `report` to `store` (usually [`global_store`]), logging (not
+/// propagating) failures.
+pub fn record(store: &CrashReportStore, report: CrashReport) {
+    match store.save(&report) {
+        Ok(()) => log::info!("Saved {:?} crash report {}", report.kind, report.id),
+        Err(error) => log::warn!("Failed to save crash report: {}", error),
+    }
+}
+
+/// Save a report for every panic, then run the previous hook.
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panic with a non-string payload".to_string());
+        let location = info
+            .location()
+            .map(|location| format!("{}:{}", location.file(), location.line()));
+        record(&global_store(), CrashReport::panic(&message, location));
+        previous(info);
+    }));
+}
+
+/// Upload due reports every [`UPLOAD_POLL_INTERVAL`] while
+/// `crash_reporting.upload_enabled` is on.
+pub fn start_upload_loop() {
+    crate::runtime::spawn(async move {
+        let mut poll = tokio::time::interval(UPLOAD_POLL_INTERVAL);
+        poll.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            poll.tick().await;
+            let settings = config::load_config().crash_reporting;
+            if !settings.upload_enabled || settings.endpoint.trim().is_empty() {
+                continue;
+            }
+            let endpoint = settings.endpoint.trim().to_string();
+            let result =
+                tokio::task::spawn_blocking(move || upload_due(&global_store(), &endpoint)).await;
+            if let Ok(Err(error)) = result {
+                log::warn!("Crash report upload stopped: {}", error);
+            }
+        }
+    });
+}
+
+/// Upload every due report in `store` to `endpoint`, deleting accepted ones
+/// and rescheduling failures. Returns how many were uploaded.
+fn upload_due(store: &CrashReportStore, endpoint: &str) -> Result<usize, String> {
+    if !crate::sync::is_allowed_url(endpoint) {
+        return Err(format!(
+            "endpoint must use https (or http on localhost): {}",
+            endpoint
+        ));
+    }
+    network::check(NetworkPurpose::CrashReport, endpoint).map_err(|error| error.to_string())?;
+
+    let mut uploaded = 0;
+    for mut report in store.pending() {
+        let now = Utc::now();
+        if !report.upload_due(now) {
+            continue;
+        }
+        match post_report(endpoint, &report) {
+            Ok(()) => {
+                store
+                    .delete(Some(&[report.id]))
+                    .map_err(|error| error.to_string())?;
+                uploaded += 1;
+            }
+            Err(error) => {
+                log::warn!("Failed to upload crash report {}: {}", report.id, error);
+                report.upload_failed(now);
+                store.save(&report).map_err(|error| error.to_string())?;
+            }
+        }
+    }
+    Ok(uploaded)
+}
+
+/// POST `report` as JSON; any 2xx response counts as accepted.
+fn post_report(endpoint: &str, report: &CrashReport) -> Result<(), String> {
+    let body = serde_json::to_vec(&UploadedReport::from(report)).map_err(|e| e.to_string())?;
+    let mut child = Command::new("curl")
+        .args([
+            "-sS",
+            "--max-time",
+            UPLOAD_TIMEOUT_SECS,
+            "-o",
+            if cfg!(windows) { "NUL" } else { "/dev/null" },
+            "-w",
+            "%{http_code}",
+            "-H",
+            "Content-Type: application/json",
+            "--data-binary",
+            "@-",
+        ])
+        .arg(endpoint)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("could not run curl: {e}"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(&body).map_err(|e| e.to_string())?;
+    }
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    match String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse::<u16>()
+    {
+        Ok(200..=299) => Ok(()),
+        Ok(status) => Err(format!("endpoint returned HTTP {}", status)),
+        Err(_) => Err("curl did not report an HTTP status".to_string()),
+    }
+}
+
+/// The part of a report that is uploaded; queue bookkeeping stays local.
+#[derive(Serialize)]
+struct UploadedReport<'a> {
+    id: Uuid,
+    kind: CrashKind,
+    created_at: DateTime<Utc>,
+    app_version: &'a str,
+    os: &'a str,
+    arch: &'a str,
+    message: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sidecar_category: Option<SidecarCrashCategory>,
+    recent_logs: &'a [String],
+}
+
+impl<'a> From<&'a CrashReport> for UploadedReport<'a> {
+    fn from(report: &'a CrashReport) -> Self {
+        Self {
+            id: report.id,
+            kind: report.kind,
+            created_at: report.created_at,
+            app_version: &report.app_version,
+            os: &report.os,
+            arch: &report.arch,
+            message: &report.message,
+            location: report.location.as_deref(),
+            sidecar_category: report.sidecar_category,
+            recent_logs: &report.recent_logs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_sidecar_crash_taxonomy() {
+        let classify = |text: &str| SidecarCrashCategory::classify(&lines(text));
+        assert_eq!(
+            classify("Traceback (most recent call last):\n  File \"x.py\"\nMemoryError"),
+            SidecarCrashCategory::OutOfMemory
+        );
+        assert_eq!(
+            classify("ModuleNotFoundError: No module named 'sounddevice'"),
+            SidecarCrashCategory::MissingDependency
+        );
+        assert_eq!(
+            classify("Fatal Python error: Segmentation fault"),
+            SidecarCrashCategory::NativeCrash
+        );
+        assert_eq!(
+            classify("Traceback (most recent call last):\nValueError: bad"),
+            SidecarCrashCategory::PythonException
+        );
+        assert_eq!(classify(""), SidecarCrashCategory::Unknown);
+    }
+
+    #[test]
+    fn test_reports_are_redacted() {
+        let report = CrashReport::sidecar_crash(
+            2,
+            &lines("loading /home/alice/models\nHF_TOKEN=hf_abc123\nSegmentation fault"),
+        );
+        assert_eq!(report.kind, CrashKind::SidecarCrash);
+        assert_eq!(
+            report.sidecar_category,
+            Some(SidecarCrashCategory::NativeCrash)
+        );
+        let logs = report.recent_logs.join("\n");
+        assert!(!logs.contains("alice"));
+        assert!(!logs.contains("hf_abc123"));
+
+        let panic = CrashReport::panic(
+            "failed to open /home/alice/notes.txt",
+            Some("src/x.rs:1".into()),
+        );
+        assert!(!panic.message.contains("alice"));
+        assert_eq!(panic.location.as_deref(), Some("src/x.rs:1"));
+    }
+
+    #[test]
+    fn test_store_lists_and_deletes_reports() {
+        let dir = tempdir().unwrap();
+        let store = CrashReportStore::new(dir.path().to_path_buf());
+        let first = CrashReport::panic("first", None);
+        let second = CrashReport::sidecar_crash(0, &[]);
+        store.save(&first).unwrap();
+        store.save(&second).unwrap();
+        // Startup failure reports share the directory but are not queued.
+        fs::write(dir.path().join("startup-1.json"), "{}").unwrap();
+
+        let pending = store.pending();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[0].id, first.id);
+
+        assert_eq!(store.delete(Some(&[first.id, Uuid::new_v4()])).unwrap(), 1);
+        assert_eq!(store.pending().len(), 1);
+        assert_eq!(store.delete(None).unwrap(), 1);
+        assert!(store.pending().is_empty());
+        assert!(dir.path().join("startup-1.json").exists());
+    }
+
+    #[test]
+    fn test_record_saves_to_the_given_store() {
+        let dir = tempdir().unwrap();
+        let store = CrashReportStore::new(dir.path().to_path_buf());
+        let report = CrashReport::panic("boom", None);
+
+        record(&store, report.clone());
+
+        let pending = store.pending();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, report.id);
+    }
+
+    #[test]
+    fn test_failed_uploads_back_off() {
+        let mut report = CrashReport::panic("boom", None);
+        let now = Utc::now();
+        assert!(report.upload_due(now));
+
+        report.upload_failed(now);
+        assert_eq!(report.upload_attempts, 1);
+        assert!(!report.upload_due(now));
+        assert!(report.upload_due(now + chrono::Duration::seconds(60)));
+
+        report.upload_failed(now);
+        assert!(!report.upload_due(now + chrono::Duration::seconds(60)));
+        assert!(report.upload_due(now + chrono::Duration::seconds(120)));
+
+        for _ in 0..30 {
+            report.upload_failed(now);
+        }
+        assert_eq!(
+            report.next_upload_at,
+            Some(now + chrono::Duration::seconds(UPLOAD_BACKOFF_MAX_SECS))
+        );
+    }
+
+    #[test]
+    fn test_upload_requires_secure_endpoint() {
+        let dir = tempdir().unwrap();
+        let store = CrashReportStore::new(dir.path().to_path_buf());
+        assert!(upload_due(&store, "http://crash.example.com/reports").is_err());
+    }
+}
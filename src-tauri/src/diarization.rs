@@ -0,0 +1,124 @@
+//! Speaker diarization helpers.
+//!
+//! The sidecar tags transcript segments with opaque speaker ids when
+//! diarization is enabled. This module maps those ids to stable display
+//! labels ("Speaker 1", "Speaker 2", ...) and renders a merged, speaker-
+//! prefixed transcript.
+
+use std::collections::HashMap;
+
+use crate::history::TranscriptSegment;
+
+/// Whether any segment carries a speaker id.
+pub fn has_speakers(segments: &[TranscriptSegment]) -> bool {
+    segments.iter().any(|segment| segment.speaker.is_some())
+}
+
+/// Map raw speaker ids to display labels in order of first appearance by
+/// start time, whatever order the segments arrive in.
+pub fn speaker_labels(segments: &[TranscriptSegment]) -> HashMap<String, String> {
+    let mut labels = HashMap::new();
+    for speaker in by_start_time(segments)
+        .into_iter()
+        .filter_map(|segment| segment.speaker.as_ref())
+    {
+        let next_index = labels.len() + 1;
+        labels
+            .entry(speaker.clone())
+            .or_insert_with(|| format!("Speaker {next_index}"));
+    }
+    labels
+}
+
+fn by_start_time(segments: &[TranscriptSegment]) -> Vec<&TranscriptSegment> {
+    let mut ordered: Vec<&TranscriptSegment> = segments.iter().collect();
+    ordered.sort_by_key(|segment| segment.start_ms);
+    ordered
+}
+
+/// Render segments as "Speaker N: ..." lines, merging consecutive segments
+/// from the same speaker.
+///
+/// Returns `None` when no segment carries a speaker id.
+pub fn format_speaker_transcript(segments: &[TranscriptSegment]) -> Option<String> {
+    if !has_speakers(segments) {
+        return None;
+    }
+
+    let labels = speaker_labels(segments);
+    let mut turns: Vec<(Option<&str>, String)> = Vec::new();
+    for segment in by_start_time(segments) {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let label = segment
+            .speaker
+            .as_ref()
+            .and_then(|speaker| labels.get(speaker))
+            .map(String::as_str);
+
+        match turns.last_mut() {
+            Some((last_label, last_text)) if *last_label == label => {
+                last_text.push(' ');
+                last_text.push_str(text);
+            }
+            _ => turns.push((label, text.to_string())),
+        }
+    }
+
+    let lines: Vec<String> = turns
+        .into_iter()
+        .map(|(label, text)| match label {
+            Some(label) => format!("{label}: {text}"),
+            None => text,
+        })
+        .collect();
+    Some(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start_ms: u64, speaker: Option<&str>, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            start_ms,
+            end_ms: start_ms + 500,
+            text: text.to_string(),
+            speaker: speaker.map(ToString::to_string),
+        }
+    }
+
+    #[test]
+    fn test_format_returns_none_without_speakers() {
+        let segments = vec![segment(0, None, "hello")];
+        assert!(format_speaker_transcript(&segments).is_none());
+    }
+
+    #[test]
+    fn test_labels_follow_first_appearance() {
+        let segments = vec![
+            segment(0, Some("SPK_7"), "a"),
+            segment(500, Some("SPK_2"), "b"),
+            segment(1000, Some("SPK_7"), "c"),
+        ];
+        let labels = speaker_labels(&segments);
+        assert_eq!(labels["SPK_7"], "Speaker 1");
+        assert_eq!(labels["SPK_2"], "Speaker 2");
+    }
+
+    #[test]
+    fn test_format_merges_consecutive_turns() {
+        let segments = vec![
+            segment(1000, Some("b"), "Fine, thanks."),
+            segment(0, Some("a"), "Hi there."),
+            segment(500, Some("a"), "How are you?"),
+            segment(1500, None, "[music]"),
+        ];
+        assert_eq!(
+            format_speaker_transcript(&segments).unwrap(),
+            "Speaker 1: Hi there. How are you?\nSpeaker 2: Fine, thanks.\n[music]"
+        );
+    }
+}
@@ -0,0 +1,312 @@
+//! Runtime focus and paste strategy selection for Linux display servers.
+//!
+//! X11 sessions use xdotool (XTEST) for both focus capture and the paste
+//! keystroke. Wayland has no single equivalent: wlroots compositors expose
+//! their own IPC for the focused window (`swaymsg`, `hyprctl`) and accept
+//! synthetic input through the virtual-keyboard protocol (`wtype`), while
+//! GNOME and KDE only allow input through the RemoteDesktop portal. The
+//! strategies are picked from the detected display server and installed
+//! tools, and `injection.focus_strategy` / `injection.paste_strategy` can
+//! force a specific one for troubleshooting.
+
+// Selection only runs on Linux; other platforms report no strategies
+#![cfg_attr(not(target_os = "linux"), allow(dead_code))]
+
+use serde::Serialize;
+
+use crate::capabilities::DisplayServer;
+
+/// How the focused window is identified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum FocusStrategy {
+    /// `xdotool getactivewindow` (X11 / XWayland).
+    Xdotool,
+    /// `swaymsg -t get_tree` on Sway.
+    Sway,
+    /// `hyprctl activewindow -j` on Hyprland.
+    Hyprland,
+    /// No focus source; Focus Guard cannot compare windows.
+    Unavailable,
+}
+
+impl FocusStrategy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "xdotool" => Some(Self::Xdotool),
+            "sway" => Some(Self::Sway),
+            "hyprland" => Some(Self::Hyprland),
+            "unavailable" => Some(Self::Unavailable),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Xdotool => "xdotool",
+            Self::Sway => "sway",
+            Self::Hyprland => "hyprland",
+            Self::Unavailable => "unavailable",
+        }
+    }
+}
+
+/// How the paste keystroke is delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum PasteStrategy {
+    /// `xdotool key ctrl+v` through XTEST.
+    Xdotool,
+    /// `wtype` through the wlroots virtual-keyboard protocol.
+    Wtype,
+    /// The XDG RemoteDesktop portal.
+    Portal,
+    /// No keystroke; text is left on the clipboard.
+    ClipboardOnly,
+}
+
+impl PasteStrategy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "xdotool" => Some(Self::Xdotool),
+            "wtype" => Some(Self::Wtype),
+            "portal" => Some(Self::Portal),
+            "clipboard_only" => Some(Self::ClipboardOnly),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Xdotool => "xdotool",
+            Self::Wtype => "wtype",
+            Self::Portal => "portal",
+            Self::ClipboardOnly => "clipboard_only",
+        }
+    }
+}
+
+/// Strategies in effect, reported by `get_capabilities`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct PlatformStrategies {
+    pub focus: FocusStrategy,
+    pub paste: PasteStrategy,
+    /// Whether `focus` was forced by config rather than auto-selected.
+    pub focus_overridden: bool,
+    /// Whether `paste` was forced by config rather than auto-selected.
+    pub paste_overridden: bool,
+}
+
+/// Tools and protocols found on this system.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StrategyProbe {
+    pub xdotool: bool,
+    pub wtype: bool,
+    /// Sway IPC socket and `swaymsg` are present.
+    pub sway_ipc: bool,
+    /// Hyprland instance and `hyprctl` are present.
+    pub hyprland_ipc: bool,
+    /// The compositor implements the wlroots virtual-keyboard protocol.
+    pub virtual_keyboard: bool,
+    /// The RemoteDesktop portal interface is exported.
+    pub remote_desktop_portal: bool,
+}
+
+/// Pick the focus strategy; a configured value other than "auto" wins.
+pub fn select_focus_strategy(
+    display_server: &DisplayServer,
+    probe: &StrategyProbe,
+    configured: &str,
+) -> (FocusStrategy, bool) {
+    if let Some(strategy) = FocusStrategy::parse(configured) {
+        return (strategy, true);
+    }
+
+    let strategy = match display_server {
+        DisplayServer::X11 if probe.xdotool => FocusStrategy::Xdotool,
+        DisplayServer::Wayland { .. } if probe.sway_ipc => FocusStrategy::Sway,
+        DisplayServer::Wayland { .. } if probe.hyprland_ipc => FocusStrategy::Hyprland,
+        _ => FocusStrategy::Unavailable,
+    };
+    (strategy, false)
+}
+
+/// Pick the paste strategy; a configured value other than "auto" wins.
+pub fn select_paste_strategy(
+    display_server: &DisplayServer,
+    probe: &StrategyProbe,
+    configured: &str,
+) -> (PasteStrategy, bool) {
+    if let Some(strategy) = PasteStrategy::parse(configured) {
+        return (strategy, true);
+    }
+
+    let strategy = match display_server {
+        DisplayServer::X11 if probe.xdotool => PasteStrategy::Xdotool,
+        DisplayServer::Wayland { .. } if probe.wtype && probe.virtual_keyboard => {
+            PasteStrategy::Wtype
+        }
+        DisplayServer::Wayland { .. } if probe.remote_desktop_portal => PasteStrategy::Portal,
+        _ => PasteStrategy::ClipboardOnly,
+    };
+    (strategy, false)
+}
+
+/// Whether a configured strategy value is valid ("auto" or a known strategy).
+pub fn is_valid_focus_strategy(value: &str) -> bool {
+    value == "auto" || FocusStrategy::parse(value).is_some()
+}
+
+/// Whether a configured strategy value is valid ("auto" or a known strategy).
+pub fn is_valid_paste_strategy(value: &str) -> bool {
+    value == "auto" || PasteStrategy::parse(value).is_some()
+}
+
+/// Strategies for the running session, or None off Linux.
+pub fn current() -> Option<PlatformStrategies> {
+    #[cfg(target_os = "linux")]
+    {
+        let display_server = crate::capabilities::detect_display_server();
+        let injection = crate::config::load_config().injection;
+        let probe = *linux::PROBE;
+        let (focus, focus_overridden) =
+            select_focus_strategy(&display_server, &probe, &injection.focus_strategy);
+        let (paste, paste_overridden) =
+            select_paste_strategy(&display_server, &probe, &injection.paste_strategy);
+        Some(PlatformStrategies {
+            focus,
+            paste,
+            focus_overridden,
+            paste_overridden,
+        })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::env;
+
+    use once_cell::sync::Lazy;
+
+    use super::StrategyProbe;
+    use crate::capabilities;
+
+    /// Probed once; installed tools do not change while the app runs.
+    pub(super) static PROBE: Lazy<StrategyProbe> = Lazy::new(|| {
+        let has = capabilities::check_command_available;
+        StrategyProbe {
+            xdotool: has("xdotool"),
+            wtype: has("wtype"),
+            sway_ipc: env::var("SWAYSOCK").is_ok() && has("swaymsg"),
+            hyprland_ipc: env::var("HYPRLAND_INSTANCE_SIGNATURE").is_ok() && has("hyprctl"),
+            virtual_keyboard: !desktop_lacks_virtual_keyboard(
+                env::var("XDG_CURRENT_DESKTOP").ok().as_deref(),
+            ),
+            remote_desktop_portal: capabilities::remote_desktop_portal_available(),
+        }
+    });
+
+    /// GNOME (Mutter) and KDE (KWin) do not implement the wlroots
+    /// virtual-keyboard protocol.
+    pub(super) fn desktop_lacks_virtual_keyboard(desktop: Option<&str>) -> bool {
+        desktop.is_some_and(|desktop| {
+            desktop
+                .split(':')
+                .any(|name| matches!(name.to_ascii_lowercase().as_str(), "gnome" | "kde"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wayland() -> DisplayServer {
+        DisplayServer::Wayland { compositor: None }
+    }
+
+    #[test]
+    fn test_x11_uses_xdotool_when_installed() {
+        let probe = StrategyProbe {
+            xdotool: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            select_focus_strategy(&DisplayServer::X11, &probe, "auto"),
+            (FocusStrategy::Xdotool, false)
+        );
+        assert_eq!(
+            select_paste_strategy(&DisplayServer::X11, &probe, "auto"),
+            (PasteStrategy::Xdotool, false)
+        );
+        assert_eq!(
+            select_paste_strategy(&DisplayServer::X11, &StrategyProbe::default(), "auto"),
+            (PasteStrategy::ClipboardOnly, false)
+        );
+    }
+
+    #[test]
+    fn test_wayland_prefers_virtual_keyboard_then_portal() {
+        let mut probe = StrategyProbe {
+            wtype: true,
+            virtual_keyboard: true,
+            remote_desktop_portal: true,
+            sway_ipc: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            select_paste_strategy(&wayland(), &probe, "auto").0,
+            PasteStrategy::Wtype
+        );
+        assert_eq!(
+            select_focus_strategy(&wayland(), &probe, "auto").0,
+            FocusStrategy::Sway
+        );
+
+        probe.virtual_keyboard = false;
+        assert_eq!(
+            select_paste_strategy(&wayland(), &probe, "auto").0,
+            PasteStrategy::Portal
+        );
+
+        probe.remote_desktop_portal = false;
+        assert_eq!(
+            select_paste_strategy(&wayland(), &probe, "auto").0,
+            PasteStrategy::ClipboardOnly
+        );
+    }
+
+    #[test]
+    fn test_configured_strategy_overrides_detection() {
+        let probe = StrategyProbe::default();
+        assert_eq!(
+            select_paste_strategy(&wayland(), &probe, "xdotool"),
+            (PasteStrategy::Xdotool, true)
+        );
+        assert_eq!(
+            select_focus_strategy(&DisplayServer::X11, &probe, "hyprland"),
+            (FocusStrategy::Hyprland, true)
+        );
+        assert!(is_valid_paste_strategy("auto"));
+        assert!(is_valid_paste_strategy("portal"));
+        assert!(!is_valid_paste_strategy("ydotool"));
+        assert!(!is_valid_focus_strategy("wtype"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_gnome_and_kde_lack_virtual_keyboard() {
+        assert!(linux::desktop_lacks_virtual_keyboard(Some("ubuntu:GNOME")));
+        assert!(linux::desktop_lacks_virtual_keyboard(Some("KDE")));
+        assert!(!linux::desktop_lacks_virtual_keyboard(Some("sway")));
+        assert!(!linux::desktop_lacks_virtual_keyboard(None));
+    }
+}
@@ -0,0 +1,214 @@
+//! Detection of the OS Do-Not-Disturb / Focus state.
+//!
+//! While the user has asked the OS to keep quiet, start and stop beeps are
+//! muted too (`audio.mute_cues_in_dnd`, on by default). The app posts no OS
+//! notifications of its own, so audio cues are all there is to silence. The
+//! overlay and tray indicators are unaffected.
+//!
+//! Linux reads the `Inhibited` property of the freedesktop notification
+//! server (KDE Plasma, and others that implement it) and falls back to
+//! GNOME's `show-banners` setting. macOS reads the Focus assertion store,
+//! which lists manually enabled Focus modes but not scheduled ones, and may
+//! be unreadable without Full Disk Access. Windows asks the shell whether it
+//! is holding back notifications (quiet hours, presentation mode, full-screen
+//! apps); Focus Assist itself has no public API.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+/// How long a detection result is reused; detection spawns a process.
+const DETECTION_TTL: Duration = Duration::from_secs(5);
+
+static CACHED_DND: Lazy<Mutex<Option<(Instant, DndInfo)>>> = Lazy::new(|| Mutex::new(None));
+
+/// Whether the OS is in Do-Not-Disturb / Focus mode.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct DndInfo {
+    pub active: bool,
+    /// Whether the state could be read at all on this system.
+    pub detectable: bool,
+    /// Where the state was read from ("freedesktop", "gnome", "focus",
+    /// "shell").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub source: Option<String>,
+}
+
+impl DndInfo {
+    fn detected(active: bool, source: &str) -> Self {
+        Self {
+            active,
+            detectable: true,
+            source: Some(source.to_string()),
+        }
+    }
+}
+
+/// The current Do-Not-Disturb state, re-detected at most every few seconds.
+pub fn current() -> DndInfo {
+    let mut cached = CACHED_DND.lock().unwrap_or_else(|e| e.into_inner());
+    let now = Instant::now();
+    if let Some((detected_at, info)) = cached.as_ref() {
+        if now.saturating_duration_since(*detected_at) < DETECTION_TTL {
+            return info.clone();
+        }
+    }
+
+    let info = detect();
+    if cached.as_ref().map(|(_, previous)| previous) != Some(&info) {
+        log::info!(
+            "Do-Not-Disturb active: {} (source: {:?})",
+            info.active,
+            info.source
+        );
+    }
+    *cached = Some((now, info.clone()));
+    info
+}
+
+#[cfg(target_os = "linux")]
+fn detect() -> DndInfo {
+    let inhibited = std::process::Command::new("gdbus")
+        .args([
+            "call",
+            "--session",
+            "--dest",
+            "org.freedesktop.Notifications",
+            "--object-path",
+            "/org/freedesktop/Notifications",
+            "--method",
+            "org.freedesktop.DBus.Properties.Get",
+            "org.freedesktop.Notifications",
+            "Inhibited",
+        ])
+        .output();
+    if let Ok(output) = inhibited {
+        if output.status.success() {
+            if let Some(active) = parse_gdbus_bool(&String::from_utf8_lossy(&output.stdout)) {
+                return DndInfo::detected(active, "freedesktop");
+            }
+        }
+    }
+
+    let gnome = std::env::var("XDG_CURRENT_DESKTOP")
+        .map(|desktop| desktop.to_ascii_lowercase().contains("gnome"))
+        .unwrap_or(false);
+    if !gnome {
+        return DndInfo::default();
+    }
+    match std::process::Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.notifications", "show-banners"])
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            match String::from_utf8_lossy(&output.stdout).trim() {
+                "false" => DndInfo::detected(true, "gnome"),
+                "true" => DndInfo::detected(false, "gnome"),
+                _ => DndInfo::default(),
+            }
+        }
+        _ => DndInfo::default(),
+    }
+}
+
+/// Boolean from a `gdbus call` property reply (`(<true>,)`).
+#[cfg(any(target_os = "linux", test))]
+fn parse_gdbus_bool(reply: &str) -> Option<bool> {
+    match reply.trim() {
+        "(<true>,)" => Some(true),
+        "(<false>,)" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn detect() -> DndInfo {
+    let Some(home) = std::env::var_os("HOME") else {
+        return DndInfo::default();
+    };
+    let path = std::path::Path::new(&home).join("Library/DoNotDisturb/DB/Assertions.json");
+    match std::fs::read_to_string(path) {
+        Ok(contents) => focus_asserted(&contents)
+            .map(|active| DndInfo::detected(active, "focus"))
+            .unwrap_or_default(),
+        Err(_) => DndInfo::default(),
+    }
+}
+
+/// Whether the macOS Focus assertion store holds an active assertion.
+/// `None` when the file is not in the expected shape.
+#[cfg(any(target_os = "macos", test))]
+fn focus_asserted(assertions_json: &str) -> Option<bool> {
+    let value: serde_json::Value = serde_json::from_str(assertions_json).ok()?;
+    let data = value["data"].as_array()?;
+    Some(data.iter().any(|entry| {
+        entry["storeAssertionRecords"]
+            .as_array()
+            .is_some_and(|records| !records.is_empty())
+    }))
+}
+
+#[cfg(target_os = "windows")]
+fn detect() -> DndInfo {
+    let mut state = 0i32;
+    // SAFETY: the call only writes the out-parameter.
+    let hr = unsafe { SHQueryUserNotificationState(&mut state) };
+    if hr != 0 {
+        return DndInfo::default();
+    }
+    DndInfo::detected(notification_state_is_quiet(state), "shell")
+}
+
+/// Whether a `QUERY_USER_NOTIFICATION_STATE` means the shell is holding back
+/// notifications: busy or full-screen apps (2, 3), presentation mode (4) and
+/// quiet time (6).
+#[cfg(any(target_os = "windows", test))]
+fn notification_state_is_quiet(state: i32) -> bool {
+    matches!(state, 2 | 3 | 4 | 6)
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "shell32")]
+unsafe extern "system" {
+    fn SHQueryUserNotificationState(state: *mut i32) -> i32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gdbus_bool_reads_property_reply() {
+        assert_eq!(parse_gdbus_bool("(<true>,)\n"), Some(true));
+        assert_eq!(parse_gdbus_bool("(<false>,)\n"), Some(false));
+        assert_eq!(parse_gdbus_bool("Error: GDBus.Error"), None);
+    }
+
+    #[test]
+    fn test_focus_asserted_needs_a_store_assertion() {
+        let with = |records: serde_json::Value| {
+            serde_json::json!({ "data": [{ "storeAssertionRecords": records }] }).to_string()
+        };
+        assert_eq!(
+            focus_asserted(&with(serde_json::json!([{
+                "assertionDetails": { "assertionDetailsModeIdentifier": "com.apple.donotdisturb.mode.default" }
+            }]))),
+            Some(true)
+        );
+        assert_eq!(focus_asserted(&with(serde_json::json!([]))), Some(false));
+        assert_eq!(focus_asserted(r#"{"data":[{}]}"#), Some(false));
+        assert_eq!(focus_asserted("not json"), None);
+    }
+
+    #[test]
+    fn test_notification_state_is_quiet() {
+        assert!(notification_state_is_quiet(4));
+        assert!(notification_state_is_quiet(6));
+        assert!(!notification_state_is_quiet(5));
+        assert!(!notification_state_is_quiet(1));
+    }
+}
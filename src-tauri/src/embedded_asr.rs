@@ -0,0 +1,438 @@
+//! Embedded whisper.cpp engine used when the sidecar is unavailable.
+//!
+//! When the Python sidecar keeps failing to start (the supervisor's circuit
+//! breaker opens) and `model.asr_backend` is `auto`, dictation switches to a
+//! tiny English Whisper model run in-process through whisper-rs, with audio
+//! captured by cpal. `embedded` uses this engine from startup. Accuracy is
+//! below the sidecar models and there are no partial transcripts, but basic
+//! dictation keeps working.
+//!
+//! The engine is compiled only with the `embedded-asr` feature; without it
+//! every call reports [`EmbeddedAsrError::NotBuilt`]. The ggml model is not
+//! downloaded: it is looked up in the bundled resources and then in the
+//! config directory.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use once_cell::sync::Lazy;
+use thiserror::Error;
+
+use crate::config;
+
+/// Model ID reported in `model:status` while the embedded engine is active.
+pub const EMBEDDED_MODEL_ID: &str = "ggml/whisper-tiny.en";
+
+/// File name of the ggml model the engine loads.
+pub const EMBEDDED_MODEL_FILE: &str = "ggml-tiny.en.bin";
+
+/// Sample rate whisper.cpp expects.
+pub const WHISPER_SAMPLE_RATE: u32 = 16_000;
+
+/// Longest recording buffered in memory, in seconds.
+#[cfg_attr(not(feature = "embedded-asr"), allow(dead_code))]
+const MAX_CAPTURE_SECS: usize = 600;
+
+static ENGINE: Lazy<Mutex<EmbeddedAsr>> = Lazy::new(|| Mutex::new(EmbeddedAsr::default()));
+
+/// Errors from the embedded engine.
+#[derive(Debug, Error)]
+pub enum EmbeddedAsrError {
+    #[error("this build does not include the embedded ASR engine")]
+    NotBuilt,
+    #[error("embedded model {EMBEDDED_MODEL_FILE} not found")]
+    ModelMissing,
+    #[error("embedded ASR engine is not active")]
+    NotActive,
+    #[error("no embedded recording for session {0}")]
+    NotRecording(String),
+    #[error("audio capture failed: {0}")]
+    #[cfg_attr(not(feature = "embedded-asr"), allow(dead_code))]
+    Capture(String),
+    #[error("embedded transcription failed: {0}")]
+    #[cfg_attr(not(feature = "embedded-asr"), allow(dead_code))]
+    Transcription(String),
+}
+
+#[derive(Default)]
+struct EmbeddedAsr {
+    model: Option<Arc<backend::Model>>,
+    capture: Option<(String, backend::Capture)>,
+}
+
+fn engine() -> MutexGuard<'static, EmbeddedAsr> {
+    ENGINE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Where the model is looked for, in order.
+pub fn model_candidates(resource_dir: Option<&Path>) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    if let Some(dir) = resource_dir {
+        candidates.push(dir.join("models").join(EMBEDDED_MODEL_FILE));
+    }
+    candidates.push(
+        config::config_dir()
+            .join("models")
+            .join(EMBEDDED_MODEL_FILE),
+    );
+    candidates
+}
+
+/// Load the model and route dictation to the embedded engine.
+///
+/// Blocking: loading takes a moment, so call it off the async runtime.
+pub fn activate(resource_dir: Option<&Path>) -> Result<PathBuf, EmbeddedAsrError> {
+    if !backend::AVAILABLE {
+        return Err(EmbeddedAsrError::NotBuilt);
+    }
+    let path = model_candidates(resource_dir)
+        .into_iter()
+        .find(|path| path.is_file())
+        .ok_or(EmbeddedAsrError::ModelMissing)?;
+    if !is_active() {
+        let model = backend::Model::load(&path)?;
+        engine().model = Some(Arc::new(model));
+    }
+    Ok(path)
+}
+
+/// Unload the model, dropping any recording in progress.
+pub fn deactivate() {
+    let mut engine = engine();
+    engine.capture = None;
+    engine.model = None;
+}
+
+/// Whether dictation is currently routed to the embedded engine.
+pub fn is_active() -> bool {
+    engine().model.is_some()
+}
+
+/// Start capturing from the default input device for `session_id`.
+pub fn start_capture(session_id: &str) -> Result<(), EmbeddedAsrError> {
+    let mut engine = engine();
+    if engine.model.is_none() {
+        return Err(EmbeddedAsrError::NotActive);
+    }
+    // Replacing a previous capture drops it, which stops its stream.
+    let capture = backend::Capture::start()?;
+    engine.capture = Some((session_id.to_string(), capture));
+    Ok(())
+}
+
+/// Stop capturing and return the recording as 16 kHz mono samples.
+pub fn stop_capture(session_id: &str) -> Result<Vec<f32>, EmbeddedAsrError> {
+    let capture = {
+        let mut engine = engine();
+        match engine.capture.take() {
+            Some((id, capture)) if id == session_id => capture,
+            other => {
+                engine.capture = other;
+                return Err(EmbeddedAsrError::NotRecording(session_id.to_string()));
+            }
+        }
+    };
+    capture.finish()
+}
+
+/// Discard the recording in progress, if any.
+pub fn cancel_capture() {
+    engine().capture = None;
+}
+
+/// Transcribe 16 kHz mono samples in `language`, an ISO 639-1 code, or
+/// `None` to let whisper detect it.
+///
+/// Blocking: run it on a blocking thread.
+pub fn transcribe(audio: &[f32], language: Option<&str>) -> Result<String, EmbeddedAsrError> {
+    let model = engine().model.clone().ok_or(EmbeddedAsrError::NotActive)?;
+    model.transcribe(audio, language)
+}
+
+/// Map `model.language` to the language passed to whisper: `auto` and an
+/// unset language both mean detection.
+pub fn whisper_language(configured: Option<&str>) -> Option<&str> {
+    configured.filter(|language| *language != "auto")
+}
+
+/// Length of 16 kHz samples in milliseconds.
+pub fn duration_ms(audio: &[f32]) -> u64 {
+    audio.len() as u64 * 1000 / u64::from(WHISPER_SAMPLE_RATE)
+}
+
+/// Downmix interleaved samples to mono and resample them linearly to 16 kHz.
+#[cfg_attr(not(feature = "embedded-asr"), allow(dead_code))]
+pub fn to_whisper_input(samples: &[f32], channels: u16, sample_rate: u32) -> Vec<f32> {
+    let channels = usize::from(channels.max(1));
+    let mono: Vec<f32> = samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect();
+    if sample_rate == WHISPER_SAMPLE_RATE || sample_rate == 0 || mono.is_empty() {
+        return mono;
+    }
+
+    let step = f64::from(sample_rate) / f64::from(WHISPER_SAMPLE_RATE);
+    let out_len = (mono.len() as f64 / step) as usize;
+    (0..out_len)
+        .map(|index| {
+            let position = index as f64 * step;
+            let before = position as usize;
+            let fraction = (position - before as f64) as f32;
+            let current = mono[before];
+            let next = mono.get(before + 1).copied().unwrap_or(current);
+            current + (next - current) * fraction
+        })
+        .collect()
+}
+
+#[cfg(feature = "embedded-asr")]
+mod backend {
+    use std::path::Path;
+    use std::sync::{mpsc, Arc, Mutex};
+    use std::thread::JoinHandle;
+
+    use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+    use whisper_rs::{
+        FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters, WhisperError,
+    };
+
+    use super::{to_whisper_input, EmbeddedAsrError, MAX_CAPTURE_SECS};
+
+    pub const AVAILABLE: bool = true;
+
+    fn transcription_error(error: WhisperError) -> EmbeddedAsrError {
+        EmbeddedAsrError::Transcription(error.to_string())
+    }
+
+    fn capture_error(error: impl std::fmt::Display) -> EmbeddedAsrError {
+        EmbeddedAsrError::Capture(error.to_string())
+    }
+
+    pub struct Model {
+        context: WhisperContext,
+    }
+
+    impl Model {
+        pub fn load(path: &Path) -> Result<Self, EmbeddedAsrError> {
+            let path = path.to_str().ok_or_else(|| {
+                EmbeddedAsrError::Transcription("model path is not valid UTF-8".to_string())
+            })?;
+            let context =
+                WhisperContext::new_with_params(path, WhisperContextParameters::default())
+                    .map_err(transcription_error)?;
+            Ok(Self { context })
+        }
+
+        pub fn transcribe(
+            &self,
+            audio: &[f32],
+            language: Option<&str>,
+        ) -> Result<String, EmbeddedAsrError> {
+            let mut state = self.context.create_state().map_err(transcription_error)?;
+            let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+            params.set_language(language);
+            params.set_no_context(true);
+            params.set_print_progress(false);
+            params.set_print_realtime(false);
+            params.set_print_special(false);
+            params.set_print_timestamps(false);
+            state.full(params, audio).map_err(transcription_error)?;
+
+            let mut text = String::new();
+            for segment in 0..state.full_n_segments().map_err(transcription_error)? {
+                text.push_str(
+                    &state
+                        .full_get_segment_text(segment)
+                        .map_err(transcription_error)?,
+                );
+            }
+            Ok(text.trim().to_string())
+        }
+    }
+
+    /// A recording on a dedicated thread; cpal streams cannot leave the
+    /// thread that built them. Dropping it stops the stream.
+    pub struct Capture {
+        stop: mpsc::Sender<()>,
+        thread: JoinHandle<Vec<f32>>,
+    }
+
+    impl Capture {
+        pub fn start() -> Result<Self, EmbeddedAsrError> {
+            let (stop, stop_rx) = mpsc::channel::<()>();
+            let (ready_tx, ready_rx) = mpsc::channel();
+            let thread = std::thread::spawn(move || {
+                let samples = Arc::new(Mutex::new(Vec::new()));
+                let (stream, channels, sample_rate) = match open_stream(Arc::clone(&samples)) {
+                    Ok(opened) => {
+                        let _ = ready_tx.send(Ok(()));
+                        opened
+                    }
+                    Err(error) => {
+                        let _ = ready_tx.send(Err(error));
+                        return Vec::new();
+                    }
+                };
+                // Returns on finish() or when the Capture is dropped.
+                let _ = stop_rx.recv();
+                drop(stream);
+                let samples =
+                    std::mem::take(&mut *samples.lock().unwrap_or_else(|e| e.into_inner()));
+                to_whisper_input(&samples, channels, sample_rate)
+            });
+            ready_rx
+                .recv()
+                .map_err(|_| capture_error("capture thread exited"))??;
+            Ok(Self { stop, thread })
+        }
+
+        pub fn finish(self) -> Result<Vec<f32>, EmbeddedAsrError> {
+            let _ = self.stop.send(());
+            self.thread
+                .join()
+                .map_err(|_| capture_error("capture thread panicked"))
+        }
+    }
+
+    fn open_stream(
+        samples: Arc<Mutex<Vec<f32>>>,
+    ) -> Result<(cpal::Stream, u16, u32), EmbeddedAsrError> {
+        let device = cpal::default_host()
+            .default_input_device()
+            .ok_or_else(|| capture_error("no input device"))?;
+        let supported = device.default_input_config().map_err(capture_error)?;
+        let channels = supported.channels();
+        let sample_rate = supported.sample_rate().0;
+        let limit = MAX_CAPTURE_SECS * sample_rate as usize * usize::from(channels);
+        let config = supported.config();
+        let on_error =
+            |error: cpal::StreamError| log::warn!("Embedded ASR capture error: {}", error);
+
+        let stream = match supported.sample_format() {
+            cpal::SampleFormat::F32 => device.build_input_stream(
+                &config,
+                move |data: &[f32], _| append(&samples, data.iter().copied(), limit),
+                on_error,
+                None,
+            ),
+            cpal::SampleFormat::I16 => device.build_input_stream(
+                &config,
+                move |data: &[i16], _| {
+                    append(
+                        &samples,
+                        data.iter().map(|sample| f32::from(*sample) / 32768.0),
+                        limit,
+                    )
+                },
+                on_error,
+                None,
+            ),
+            other => {
+                return Err(capture_error(format!(
+                    "unsupported sample format {other:?}"
+                )))
+            }
+        }
+        .map_err(capture_error)?;
+        stream.play().map_err(capture_error)?;
+        Ok((stream, channels, sample_rate))
+    }
+
+    fn append(samples: &Mutex<Vec<f32>>, data: impl Iterator<Item = f32>, limit: usize) {
+        let mut samples = samples.lock().unwrap_or_else(|e| e.into_inner());
+        let room = limit.saturating_sub(samples.len());
+        samples.extend(data.take(room));
+    }
+}
+
+#[cfg(not(feature = "embedded-asr"))]
+mod backend {
+    use std::path::Path;
+
+    use super::EmbeddedAsrError;
+
+    pub const AVAILABLE: bool = false;
+
+    pub struct Model;
+
+    impl Model {
+        pub fn load(_path: &Path) -> Result<Self, EmbeddedAsrError> {
+            Err(EmbeddedAsrError::NotBuilt)
+        }
+
+        pub fn transcribe(
+            &self,
+            _audio: &[f32],
+            _language: Option<&str>,
+        ) -> Result<String, EmbeddedAsrError> {
+            Err(EmbeddedAsrError::NotBuilt)
+        }
+    }
+
+    pub struct Capture;
+
+    impl Capture {
+        pub fn start() -> Result<Self, EmbeddedAsrError> {
+            Err(EmbeddedAsrError::NotBuilt)
+        }
+
+        pub fn finish(self) -> Result<Vec<f32>, EmbeddedAsrError> {
+            Err(EmbeddedAsrError::NotBuilt)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_whisper_input_downmixes_and_resamples() {
+        assert_eq!(
+            to_whisper_input(&[1.0, 0.0, 0.5, 0.5], 2, WHISPER_SAMPLE_RATE),
+            vec![0.5, 0.5]
+        );
+
+        let ramp: Vec<f32> = (0..48).map(|index| index as f32).collect();
+        let resampled = to_whisper_input(&ramp, 1, 48_000);
+        assert_eq!(resampled.len(), 16);
+        assert_eq!(resampled[1], 3.0);
+        assert_eq!(resampled[15], 45.0);
+
+        let upsampled = to_whisper_input(&[0.0, 1.0], 1, 8_000);
+        assert_eq!(upsampled, vec![0.0, 0.5, 1.0, 1.0]);
+        assert!(to_whisper_input(&[], 1, 44_100).is_empty());
+    }
+
+    #[test]
+    fn test_model_candidates_prefer_bundled_resources() {
+        let candidates = model_candidates(Some(Path::new("/opt/app/resources")));
+        assert_eq!(
+            candidates[0],
+            Path::new("/opt/app/resources/models").join(EMBEDDED_MODEL_FILE)
+        );
+        assert_eq!(
+            candidates[1],
+            config::config_dir()
+                .join("models")
+                .join(EMBEDDED_MODEL_FILE)
+        );
+    }
+
+    #[test]
+    fn test_stop_capture_requires_matching_session() {
+        assert!(matches!(
+            stop_capture("not-recording"),
+            Err(EmbeddedAsrError::NotRecording(_))
+        ));
+        assert_eq!(duration_ms(&[0.0; 8_000]), 500);
+    }
+
+    #[test]
+    fn test_whisper_language_detects_unless_a_code_is_configured() {
+        assert_eq!(whisper_language(None), None);
+        assert_eq!(whisper_language(Some("auto")), None);
+        assert_eq!(whisper_language(Some("ja")), Some("ja"));
+    }
+}
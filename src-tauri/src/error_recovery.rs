@@ -0,0 +1,196 @@
+//! Automatic recovery from the Error state.
+//!
+//! When the app enters Error for a recoverable [`ErrorCategory`], the
+//! integration layer waits a backoff, revalidates what failed (sidecar ping,
+//! input devices, model status) and returns to Idle once every check passes.
+//! Each failed attempt doubles the wait, up to [`MAX_RECOVERY_BACKOFF`];
+//! after `supervisor.error_recovery_max_attempts` the error waits for the
+//! user. Every step is published as an `app:recovery` event.
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::config::SupervisorConfig;
+use crate::state::ErrorCategory;
+
+/// Longest wait between two recovery attempts.
+pub const MAX_RECOVERY_BACKOFF: Duration = Duration::from_secs(300);
+
+/// When and how often to try leaving the Error state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecoveryPolicy {
+    pub enabled: bool,
+    /// Wait before the first attempt.
+    pub initial_backoff: Duration,
+    pub max_attempts: u32,
+}
+
+impl RecoveryPolicy {
+    /// Policy from the user's `supervisor` settings.
+    pub fn from_supervisor(supervisor: &SupervisorConfig) -> Self {
+        Self {
+            enabled: supervisor.error_auto_recovery,
+            initial_backoff: Duration::from_secs(supervisor.error_recovery_backoff_secs),
+            max_attempts: supervisor.error_recovery_max_attempts,
+        }
+    }
+
+    /// Whether errors of `category` are recovered from automatically.
+    pub fn applies_to(&self, category: ErrorCategory) -> bool {
+        self.enabled && category.auto_recoverable()
+    }
+
+    /// Wait before `attempt` (1-based).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(MAX_RECOVERY_BACKOFF)
+    }
+}
+
+/// A revalidation run before leaving the Error state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryCheck {
+    /// The sidecar answers `system.ping`.
+    SidecarPing,
+    /// An input device is present, including the configured one.
+    AudioDevices,
+    /// The model reports ready.
+    ModelStatus,
+}
+
+impl RecoveryCheck {
+    /// Sidecar method the check calls.
+    pub fn rpc_method(self) -> &'static str {
+        match self {
+            RecoveryCheck::SidecarPing => "system.ping",
+            RecoveryCheck::AudioDevices => "audio.list_devices",
+            RecoveryCheck::ModelStatus => "model.get_status",
+        }
+    }
+
+    /// Checks that must pass before an error of `category` clears.
+    pub fn for_category(category: ErrorCategory) -> &'static [RecoveryCheck] {
+        match category {
+            ErrorCategory::Sidecar => &[RecoveryCheck::SidecarPing],
+            ErrorCategory::AudioDevice => {
+                &[RecoveryCheck::SidecarPing, RecoveryCheck::AudioDevices]
+            }
+            ErrorCategory::Model | ErrorCategory::Transcription => {
+                &[RecoveryCheck::SidecarPing, RecoveryCheck::ModelStatus]
+            }
+            ErrorCategory::Other => &[],
+        }
+    }
+}
+
+/// Step of a recovery, as reported in `app:recovery`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryPhase {
+    /// An attempt will run after `delay_ms`.
+    Scheduled,
+    /// The checks are running.
+    Checking,
+    /// Some checks failed; another attempt follows unless attempts ran out.
+    Failed,
+    /// The checks passed and the app is back to Idle.
+    Recovered,
+    /// The app left this error some other way, or a newer error replaced it.
+    Superseded,
+    /// Every attempt failed; the error waits for the user.
+    GaveUp,
+}
+
+/// One step of a recovery, published as `app:recovery`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveryEvent {
+    pub phase: RecoveryPhase,
+    pub category: ErrorCategory,
+    /// 1-based attempt the step belongs to.
+    pub attempt: u32,
+    pub max_attempts: u32,
+    /// Wait before the attempt (`scheduled` only).
+    pub delay_ms: Option<u64>,
+    /// Checks that did not pass (`failed` only).
+    pub failed_checks: Vec<RecoveryCheck>,
+}
+
+impl RecoveryEvent {
+    pub fn new(
+        phase: RecoveryPhase,
+        category: ErrorCategory,
+        attempt: u32,
+        policy: &RecoveryPolicy,
+    ) -> Self {
+        Self {
+            phase,
+            category,
+            attempt,
+            max_attempts: policy.max_attempts,
+            delay_ms: None,
+            failed_checks: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(initial_secs: u64) -> RecoveryPolicy {
+        RecoveryPolicy {
+            enabled: true,
+            initial_backoff: Duration::from_secs(initial_secs),
+            max_attempts: 6,
+        }
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_cap() {
+        let policy = policy(5);
+        assert_eq!(policy.backoff(1), Duration::from_secs(5));
+        assert_eq!(policy.backoff(2), Duration::from_secs(10));
+        assert_eq!(policy.backoff(4), Duration::from_secs(40));
+        assert_eq!(policy.backoff(7), MAX_RECOVERY_BACKOFF);
+        assert_eq!(policy.backoff(u32::MAX), MAX_RECOVERY_BACKOFF);
+    }
+
+    #[test]
+    fn test_policy_skips_other_errors_and_disabled_recovery() {
+        let enabled = policy(5);
+        assert!(enabled.applies_to(ErrorCategory::Sidecar));
+        assert!(enabled.applies_to(ErrorCategory::Transcription));
+        assert!(!enabled.applies_to(ErrorCategory::Other));
+
+        let disabled = RecoveryPolicy {
+            enabled: false,
+            ..enabled
+        };
+        assert!(!disabled.applies_to(ErrorCategory::Sidecar));
+    }
+
+    #[test]
+    fn test_policy_from_supervisor_defaults() {
+        let policy = RecoveryPolicy::from_supervisor(&SupervisorConfig::default());
+        assert!(policy.enabled);
+        assert_eq!(policy.initial_backoff, Duration::from_secs(5));
+        assert_eq!(policy.max_attempts, 6);
+    }
+
+    #[test]
+    fn test_checks_match_the_failed_subsystem() {
+        assert_eq!(
+            RecoveryCheck::for_category(ErrorCategory::AudioDevice),
+            &[RecoveryCheck::SidecarPing, RecoveryCheck::AudioDevices]
+        );
+        assert_eq!(
+            RecoveryCheck::for_category(ErrorCategory::Transcription),
+            &[RecoveryCheck::SidecarPing, RecoveryCheck::ModelStatus]
+        );
+        assert!(RecoveryCheck::for_category(ErrorCategory::Other).is_empty());
+    }
+}
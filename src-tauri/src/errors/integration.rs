@@ -0,0 +1,240 @@
+//! Typed failures from `IntegrationManager` operations.
+//!
+//! Each variant keeps enough structure (the sidecar error kind, the reason a
+//! recording could not start) to map onto the [`AppErrorKind`] taxonomy, so
+//! commands can hand the UI a stable code and remediation instead of a bare
+//! message.
+
+use serde_json::json;
+use thiserror::Error;
+
+use super::{AppError, AppErrorKind, ErrorKind, UserError};
+use crate::ipc::RpcError;
+use crate::sidecar::incompatible_environment_reason;
+use crate::state::CannotRecordReason;
+
+/// Integration manager operation errors.
+#[derive(Debug, Error)]
+pub enum IntegrationError {
+    /// No RPC client; the sidecar is stopped or still starting.
+    #[error("Sidecar not connected")]
+    SidecarNotConnected,
+
+    /// An RPC call to the sidecar failed.
+    #[error("Failed to {action}: {source}")]
+    Rpc {
+        action: String,
+        #[source]
+        source: RpcError,
+    },
+
+    /// The sidecar answered with a payload the host cannot use.
+    #[error("{0}")]
+    Protocol(String),
+
+    /// The supervisor could not bring the sidecar to a ready state.
+    #[error("{message}")]
+    Supervisor { kind: ErrorKind, message: String },
+
+    /// A model download, install, or initialization failed.
+    #[error("{message}")]
+    Model { kind: ErrorKind, message: String },
+
+    /// The model cache is in use by a download or initialization.
+    #[error("Cannot purge model while download or initialization is in progress")]
+    ModelBusy,
+
+    /// The app state does not allow a recording to start.
+    #[error("{}", cannot_record_reason_message(.0))]
+    CannotRecord(CannotRecordReason),
+
+    /// Stop or cancel was requested without an active session.
+    #[error("No recording in progress")]
+    NotRecording,
+
+    /// The host recording controller failed.
+    #[error("{0}")]
+    Recording(String),
+
+    /// A caller passed an invalid argument.
+    #[error("{0}")]
+    InvalidInput(String),
+}
+
+impl IntegrationError {
+    /// Wrap an RPC failure; `action` completes "Failed to ...".
+    pub fn rpc(action: impl Into<String>, source: RpcError) -> Self {
+        Self::Rpc {
+            action: action.into(),
+            source,
+        }
+    }
+
+    /// Position in the application error taxonomy.
+    pub fn app_error_kind(&self) -> AppErrorKind {
+        match self {
+            Self::SidecarNotConnected => AppErrorKind::SidecarHang,
+            Self::Rpc {
+                source: RpcError::Remote { kind, message, .. },
+                ..
+            } => super::from_sidecar_error(kind, message, None),
+            Self::Rpc { .. } => AppErrorKind::SidecarHang,
+            Self::Protocol(message) | Self::InvalidInput(message) => AppErrorKind::Internal {
+                message: message.clone(),
+            },
+            Self::Supervisor { kind, message } => match kind {
+                ErrorKind::SidecarCircuitBreaker => {
+                    AppErrorKind::SidecarMaxRetries { retry_count: 0 }
+                }
+                ErrorKind::SidecarSpawn => match incompatible_environment_reason(message) {
+                    Some(reason) => AppErrorKind::SidecarIncompatible {
+                        reason: reason.to_string(),
+                    },
+                    None => AppErrorKind::SidecarNotFound,
+                },
+                _ => AppErrorKind::SidecarCrash { restart_count: 0 },
+            },
+            Self::Model { kind, message } => {
+                super::from_sidecar_error(kind.to_sidecar(), message, None)
+            }
+            Self::ModelBusy => AppErrorKind::ModelPurgeRejected,
+            Self::CannotRecord(CannotRecordReason::AlreadyRecording) => {
+                AppErrorKind::AlreadyRecording
+            }
+            Self::CannotRecord(CannotRecordReason::ModelLoading) => {
+                AppErrorKind::ModelNotInitialized
+            }
+            Self::CannotRecord(reason) => AppErrorKind::RecordingBlocked {
+                message: cannot_record_reason_message(reason).to_string(),
+            },
+            Self::NotRecording => AppErrorKind::NotRecording,
+            Self::Recording(message) => AppErrorKind::AudioIO {
+                message: message.clone(),
+            },
+        }
+    }
+
+    /// Convert to a user-facing error, keeping this error's text as details.
+    pub fn to_user_error(&self) -> UserError {
+        self.app_error_kind()
+            .to_user_error()
+            .with_details(self.to_string())
+    }
+}
+
+impl From<&IntegrationError> for AppError {
+    fn from(error: &IntegrationError) -> Self {
+        let app_error = AppError::from_kind_with_details(
+            &error.app_error_kind(),
+            Some(json!({ "cause": error.to_string() })),
+        );
+        let correlation_id = match error {
+            IntegrationError::Rpc { source, .. } => source.correlation_id(),
+            _ => None,
+        };
+        match correlation_id {
+            Some(correlation_id) => app_error.with_correlation_id(correlation_id),
+            None => app_error,
+        }
+    }
+}
+
+fn cannot_record_reason_message(reason: &CannotRecordReason) -> &'static str {
+    match reason {
+        CannotRecordReason::Paused => "Recording disabled (paused)",
+        CannotRecordReason::ModelLoading => "Model not ready",
+        CannotRecordReason::AlreadyRecording => "Recording already in progress",
+        CannotRecordReason::StillTranscribing => "Cannot start recording while transcribing",
+        CannotRecordReason::InErrorState => "Cannot start recording while app is in error state",
+        CannotRecordReason::ScreenSharing => "Dictation is disabled while the screen is shared",
+        CannotRecordReason::HotkeysPaused => "Hotkeys paused; start recording from the tray",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::Remediation;
+
+    #[test]
+    fn test_remote_rpc_error_keeps_sidecar_kind_and_correlation_id() {
+        let error = IntegrationError::rpc(
+            "call recording.start RPC",
+            RpcError::Remote {
+                code: -32020,
+                message: "permission denied".to_string(),
+                kind: "E_MIC_PERMISSION".to_string(),
+                correlation_id: Some("req-7".to_string()),
+            },
+        );
+        assert!(error
+            .to_string()
+            .starts_with("Failed to call recording.start RPC: Remote error: E_MIC_PERMISSION"));
+
+        let app_error = AppError::from(&error);
+        assert_eq!(app_error.code, "E_MIC_PERMISSION");
+        assert_eq!(
+            app_error.details.as_ref().unwrap()["correlation_id"],
+            "req-7"
+        );
+        assert!(matches!(
+            error.to_user_error().remediation,
+            Some(Remediation::OpenSettings(_))
+        ));
+    }
+
+    #[test]
+    fn test_cannot_record_reasons_map_to_recording_kinds() {
+        let error = IntegrationError::CannotRecord(CannotRecordReason::StillTranscribing);
+        assert_eq!(
+            error.to_string(),
+            "Cannot start recording while transcribing"
+        );
+        assert_eq!(AppError::from(&error).code, "E_RECORDING_FAILED");
+
+        let error = IntegrationError::CannotRecord(CannotRecordReason::ModelLoading);
+        assert_eq!(AppError::from(&error).code, "E_MODEL_NOT_READY");
+    }
+
+    #[test]
+    fn test_sidecar_not_connected_suggests_restart() {
+        let user_error = IntegrationError::SidecarNotConnected.to_user_error();
+        assert_eq!(user_error.error_kind, Some(ErrorKind::SidecarIpc));
+        assert_eq!(user_error.remediation, Some(Remediation::RestartSidecar));
+        assert_eq!(user_error.details.as_deref(), Some("Sidecar not connected"));
+    }
+
+    #[test]
+    fn test_supervisor_kind_selects_sidecar_error() {
+        let error = IntegrationError::Supervisor {
+            kind: ErrorKind::SidecarCircuitBreaker,
+            message: "circuit breaker open".to_string(),
+        };
+        assert_eq!(AppError::from(&error).code, "E_SIDECAR_CIRCUIT_BREAKER");
+    }
+
+    #[test]
+    fn test_incompatible_python_environment_is_not_reported_as_missing_files() {
+        let error = IntegrationError::Supervisor {
+            kind: ErrorKind::SidecarSpawn,
+            message: "Failed to spawn Python sidecar: incompatible sidecar environment: 'python3' cannot import numpy: No module named 'numpy'.".to_string(),
+        };
+        assert!(matches!(
+            error.app_error_kind(),
+            AppErrorKind::SidecarIncompatible { reason }
+                if reason == "'python3' cannot import numpy: No module named 'numpy'."
+        ));
+        let user_error = error.to_user_error();
+        assert_eq!(user_error.error_kind, Some(ErrorKind::SidecarSpawn));
+        assert_eq!(user_error.remediation, Some(Remediation::RestartSidecar));
+
+        let error = IntegrationError::Supervisor {
+            kind: ErrorKind::SidecarSpawn,
+            message: "Failed to spawn bundled sidecar: not found".to_string(),
+        };
+        assert!(matches!(
+            error.app_error_kind(),
+            AppErrorKind::SidecarNotFound
+        ));
+    }
+}
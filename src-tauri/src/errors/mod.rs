@@ -15,15 +15,19 @@
 //! | Transcription  | E_TRANSCRIPTION_FAILED, E_TRANSCRIPTION_TIMEOUT          | Retry               |
 //! | Injection/UI   | E_INJECTION_FAILED, E_OVERLAY_FAILED                    | Fallback UX         |
 
+mod integration;
 mod kinds;
 mod remediation;
 
+pub use integration::IntegrationError;
 pub use kinds::ErrorKind;
 pub use remediation::{Remediation, SettingsPage};
 
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
+use crate::ipc::RpcError;
+
 /// User-facing error with actionable information.
 ///
 /// This struct is designed to be directly useful to the UI layer:
@@ -100,6 +104,7 @@ impl UserError {
 ///
 /// This is the canonical error shape emitted to frontend events.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 pub struct AppError {
     /// Stable identifier (for metrics/handling), e.g. "E_MIC_PERMISSION".
     pub code: String,
@@ -107,6 +112,7 @@ pub struct AppError {
     pub message: String,
     /// Optional structured diagnostics payload.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
     pub details: Option<Value>,
     /// Whether retry/recovery can happen without destructive action.
     pub recoverable: bool,
@@ -165,6 +171,50 @@ impl AppError {
             recoverable,
         }
     }
+
+    /// Attach an RPC correlation id to the diagnostics payload.
+    pub fn with_correlation_id(mut self, correlation_id: impl Into<String>) -> Self {
+        let correlation_id = Value::String(correlation_id.into());
+        self.details = match self.details.take() {
+            Some(Value::Object(mut map)) => {
+                map.insert("correlation_id".to_string(), correlation_id);
+                Some(Value::Object(map))
+            }
+            Some(other) => Some(json!({
+                "details": other,
+                "correlation_id": correlation_id
+            })),
+            None => Some(json!({ "correlation_id": correlation_id })),
+        };
+        self
+    }
+}
+
+impl From<&RpcError> for AppError {
+    fn from(error: &RpcError) -> Self {
+        let app_error = match error {
+            RpcError::Remote { kind, message, .. } => {
+                let error_kind = ErrorKind::from_sidecar(kind).unwrap_or(ErrorKind::SidecarIpc);
+                AppError::new(
+                    error_kind.to_sidecar(),
+                    message.clone(),
+                    Some(json!({ "sidecar_kind": kind })),
+                    error_kind.is_recoverable(),
+                )
+            }
+            other => AppError::new(
+                ErrorKind::SidecarIpc.to_sidecar(),
+                other.to_string(),
+                None,
+                ErrorKind::SidecarIpc.is_recoverable(),
+            ),
+        };
+
+        match error.correlation_id() {
+            Some(correlation_id) => app_error.with_correlation_id(correlation_id),
+            None => app_error,
+        }
+    }
 }
 
 /// Application-level errors that can be converted to user errors.
@@ -191,6 +241,9 @@ pub enum AppErrorKind {
     SidecarMaxRetries { retry_count: u32 },
     /// Sidecar executable not found.
     SidecarNotFound,
+    /// Python environment cannot run the sidecar (dev mode): too old an
+    /// interpreter or missing dependencies.
+    SidecarIncompatible { reason: String },
     /// Sidecar blocked by OS (macOS quarantine).
     SidecarQuarantined,
 
@@ -220,6 +273,8 @@ pub enum AppErrorKind {
     AlreadyRecording,
     /// Not recording (stop called when not recording).
     NotRecording,
+    /// Recording cannot start in the current app state.
+    RecordingBlocked { message: String },
     /// Invalid session ID.
     InvalidSession { expected: String, actual: String },
     /// Recording max duration reached.
@@ -374,6 +429,17 @@ fn map_error_to_user_message(error: &AppErrorKind) -> UserError {
             None,
         ),
 
+        AppErrorKind::SidecarIncompatible { reason } => UserError::new(
+            "Background Service Cannot Start",
+            format!(
+                "The Python environment cannot run the background service: {} Fix it, then restart the service.",
+                reason
+            ),
+            Some(ErrorKind::SidecarSpawn),
+            Some(Remediation::RestartSidecar),
+            None,
+        ),
+
         AppErrorKind::SidecarQuarantined => UserError::new(
             "Background Service Blocked",
             "Background service is blocked by macOS Gatekeeper. Open Security settings to allow.",
@@ -477,6 +543,14 @@ fn map_error_to_user_message(error: &AppErrorKind) -> UserError {
             None,
         ),
 
+        AppErrorKind::RecordingBlocked { message } => UserError::new(
+            "Cannot Record",
+            "Recording cannot start right now. Wait for the current step to finish or resume.",
+            Some(ErrorKind::RecordingFailed),
+            None,
+            Some(message.clone()),
+        ),
+
         AppErrorKind::InvalidSession { expected, actual } => UserError::new(
             "Session Mismatch",
             "Recording session mismatch. Please try again.",
@@ -546,8 +620,8 @@ fn map_error_to_user_message(error: &AppErrorKind) -> UserError {
         ),
 
         AppErrorKind::SelfInjectionPrevented => UserError::new(
-            "Settings Window Focused",
-            "Settings window was focused. Text copied to clipboard instead.",
+            "OpenVoicy Window Focused",
+            "An OpenVoicy window was focused. Text copied to clipboard instead.",
             None, // Not an error, safety behavior
             None,
             None,
@@ -607,7 +681,6 @@ fn map_error_to_user_message(error: &AppErrorKind) -> UserError {
 /// Map a sidecar error kind string to an AppError.
 ///
 /// This function is used when receiving errors from the sidecar via JSON-RPC.
-#[allow(dead_code)]
 pub fn from_sidecar_error(kind: &str, message: &str, details: Option<&str>) -> AppErrorKind {
     match kind {
         // Canonical catalog
@@ -716,6 +789,9 @@ mod tests {
             AppErrorKind::SidecarHang,
             AppErrorKind::SidecarMaxRetries { retry_count: 5 },
             AppErrorKind::SidecarNotFound,
+            AppErrorKind::SidecarIncompatible {
+                reason: "test".to_string(),
+            },
             AppErrorKind::SidecarQuarantined,
             AppErrorKind::ModelDownloadNetwork { url: None },
             AppErrorKind::ModelDownloadDiskFull {
@@ -734,6 +810,9 @@ mod tests {
             AppErrorKind::RecordingTooShort { duration_ms: 100 },
             AppErrorKind::AlreadyRecording,
             AppErrorKind::NotRecording,
+            AppErrorKind::RecordingBlocked {
+                message: "paused".to_string(),
+            },
             AppErrorKind::InvalidSession {
                 expected: "a".to_string(),
                 actual: "b".to_string(),
@@ -833,6 +912,42 @@ mod tests {
         assert!(!payload.message.is_empty());
     }
 
+    #[test]
+    fn test_app_error_from_rpc_error_carries_correlation_id() {
+        let error = RpcError::Remote {
+            code: -32011,
+            message: "download failed".to_string(),
+            kind: "E_NETWORK".to_string(),
+            correlation_id: Some("a1b2c3d4-12".to_string()),
+        };
+
+        let app_error = AppError::from(&error);
+        assert_eq!(app_error.code, "E_NETWORK");
+        assert_eq!(app_error.message, "download failed");
+        let details = app_error.details.expect("details");
+        assert_eq!(details["correlation_id"], "a1b2c3d4-12");
+        assert_eq!(details["sidecar_kind"], "E_NETWORK");
+    }
+
+    #[test]
+    fn test_app_error_from_rpc_timeout_maps_to_sidecar_ipc() {
+        let error = RpcError::Timeout {
+            method: "asr.transcribe".to_string(),
+            correlation_id: Some("a1b2c3d4-3".to_string()),
+        };
+
+        let app_error = AppError::from(&error);
+        assert_eq!(app_error.code, "E_SIDECAR_IPC");
+        assert_eq!(
+            app_error.details,
+            Some(json!({ "correlation_id": "a1b2c3d4-3" }))
+        );
+
+        let app_error = AppError::from(&RpcError::Disconnected);
+        assert_eq!(app_error.code, "E_SIDECAR_IPC");
+        assert!(app_error.details.is_none());
+    }
+
     #[test]
     fn test_from_sidecar_error() {
         let error = from_sidecar_error("E_MIC_PERMISSION", "Permission denied", None);
@@ -20,6 +20,8 @@ pub enum SettingsPage {
     MicrophonePermission,
     /// macOS Accessibility permission.
     MacOSAccessibility,
+    /// macOS Input Monitoring permission.
+    MacOSInputMonitoring,
     /// macOS Security & Privacy settings.
     MacOSSecurity,
 }
@@ -40,6 +42,10 @@ impl SettingsPage {
                 "x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility",
             ),
             #[cfg(target_os = "macos")]
+            Self::MacOSInputMonitoring => {
+                Some("x-apple.systempreferences:com.apple.preference.security?Privacy_ListenEvent")
+            }
+            #[cfg(target_os = "macos")]
             Self::MacOSSecurity => {
                 Some("x-apple.systempreferences:com.apple.preference.security?General")
             }
@@ -72,6 +78,10 @@ impl SettingsPage {
                 "Open System Preferences → Security & Privacy → Privacy → Accessibility"
             }
             #[cfg(target_os = "macos")]
+            Self::MacOSInputMonitoring => {
+                "Open System Settings → Privacy & Security → Input Monitoring"
+            }
+            #[cfg(target_os = "macos")]
             Self::MacOSSecurity => "Open System Preferences → Security & Privacy → General",
             #[cfg(target_os = "windows")]
             Self::MicrophonePermission => "Open Settings → Privacy → Microphone",
@@ -80,7 +90,9 @@ impl SettingsPage {
             #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
             Self::MicrophonePermission => "Check your system's privacy settings",
             #[cfg(not(target_os = "macos"))]
-            Self::MacOSAccessibility | Self::MacOSSecurity => "N/A on this platform",
+            Self::MacOSAccessibility | Self::MacOSInputMonitoring | Self::MacOSSecurity => {
+                "N/A on this platform"
+            }
         }
     }
 }
@@ -153,6 +165,7 @@ mod tests {
             SettingsPage::AudioDevice,
             SettingsPage::MicrophonePermission,
             SettingsPage::MacOSAccessibility,
+            SettingsPage::MacOSInputMonitoring,
             SettingsPage::MacOSSecurity,
         ];
 
@@ -0,0 +1,283 @@
+//! Transcription of audio files dropped onto the main window.
+//!
+//! Dropped files are checked here before anything reaches the sidecar: only
+//! WAV files (the format the sidecar decodes) up to [`MAX_AUDIO_FILE_BYTES`]
+//! are accepted, and the header must carry a format and a data chunk.
+//! Files are transcribed one at a time by the auxiliary sidecar with the
+//! configured model, so live dictation keeps running meanwhile. Transcripts
+//! then go through the same replacement rules, learned spellings and word
+//! filter as dictation and land in history marked with their source file.
+//!
+//! Only the main window takes drops: tray icons cannot receive them on any
+//! platform, and the overlay ignores the cursor.
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Progress of one file through transcription.
+pub const EVENT_FILE_TRANSCRIPTION_PROGRESS: &str = "file_transcription:progress";
+
+/// Largest file accepted, about 20 minutes of 16-bit stereo audio at 44.1 kHz.
+pub const MAX_AUDIO_FILE_BYTES: u64 = 200 * 1024 * 1024;
+
+/// File extensions the sidecar can decode.
+const AUDIO_EXTENSIONS: [&str; 2] = ["wav", "wave"];
+
+/// Held while a file is being transcribed, so drops queue behind each other.
+static TRANSCRIBE_LOCK: Lazy<tokio::sync::Mutex<()>> = Lazy::new(|| tokio::sync::Mutex::new(()));
+
+/// Step a dropped file has reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum FileTranscriptionPhase {
+    /// Accepted and waiting for earlier files.
+    Queued,
+    Transcribing,
+    /// Transcribed and added to history.
+    Complete,
+    Failed,
+}
+
+/// What a valid audio file looks like before transcription.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioFileInfo {
+    pub file_name: String,
+    pub duration_ms: u32,
+}
+
+/// Display name of `path`, falling back to the whole path.
+pub fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+/// Check that `path` is a WAV file the sidecar can transcribe and read its
+/// duration from the header.
+pub fn inspect_audio_file(path: &Path) -> Result<AudioFileInfo, String> {
+    let name = file_name(path);
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_ascii_lowercase());
+    if !extension.is_some_and(|extension| AUDIO_EXTENSIONS.contains(&extension.as_str())) {
+        return Err(format!("{}: only WAV audio files can be transcribed", name));
+    }
+
+    let metadata = std::fs::metadata(path)
+        .map_err(|error| format!("{}: cannot read file: {}", name, error))?;
+    if !metadata.is_file() {
+        return Err(format!("{}: not a file", name));
+    }
+    if metadata.len() > MAX_AUDIO_FILE_BYTES {
+        return Err(format!(
+            "{}: file is larger than {} MB",
+            name,
+            MAX_AUDIO_FILE_BYTES / (1024 * 1024)
+        ));
+    }
+
+    let mut file =
+        File::open(path).map_err(|error| format!("{}: cannot open file: {}", name, error))?;
+    let duration_ms =
+        wav_duration_ms(&mut file).map_err(|reason| format!("{}: {}", name, reason))?;
+    Ok(AudioFileInfo {
+        file_name: name,
+        duration_ms,
+    })
+}
+
+/// Duration of the WAV audio in `reader`, from its `fmt ` and `data` chunks.
+fn wav_duration_ms(reader: &mut (impl Read + Seek)) -> Result<u32, String> {
+    const NOT_WAV: &str = "not a valid WAV file";
+
+    let mut header = [0u8; 12];
+    reader.read_exact(&mut header).map_err(|_| NOT_WAV)?;
+    if &header[0..4] != b"RIFF" || &header[8..12] != b"WAVE" {
+        return Err(NOT_WAV.to_string());
+    }
+
+    let mut byte_rate: Option<u32> = None;
+    loop {
+        let mut chunk = [0u8; 8];
+        if reader.read_exact(&mut chunk).is_err() {
+            return Err(format!("{}: no audio data", NOT_WAV));
+        }
+        let size = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+        match &chunk[0..4] {
+            b"fmt " => {
+                let mut fmt = [0u8; 12];
+                reader.read_exact(&mut fmt).map_err(|_| NOT_WAV)?;
+                byte_rate = Some(u32::from_le_bytes([fmt[8], fmt[9], fmt[10], fmt[11]]));
+                skip(reader, u64::from(size).saturating_sub(12))?;
+            }
+            b"data" => {
+                let byte_rate = byte_rate
+                    .filter(|rate| *rate > 0)
+                    .ok_or_else(|| format!("{}: missing audio format", NOT_WAV))?;
+                if size == 0 {
+                    return Err("file contains no audio".to_string());
+                }
+                let duration_ms = u64::from(size) * 1000 / u64::from(byte_rate);
+                return Ok(u32::try_from(duration_ms).unwrap_or(u32::MAX));
+            }
+            _ => skip(reader, u64::from(size))?,
+        }
+        // Chunks are padded to an even length.
+        if size % 2 == 1 {
+            skip(reader, 1)?;
+        }
+    }
+}
+
+fn skip(reader: &mut impl Seek, bytes: u64) -> Result<(), String> {
+    let offset = i64::try_from(bytes).map_err(|_| "not a valid WAV file".to_string())?;
+    reader
+        .seek(SeekFrom::Current(offset))
+        .map(|_| ())
+        .map_err(|_| "not a valid WAV file".to_string())
+}
+
+/// Wait until no other file is being transcribed.
+pub async fn acquire_transcribe_slot() -> tokio::sync::MutexGuard<'static, ()> {
+    TRANSCRIBE_LOCK.lock().await
+}
+
+/// Payload of [`EVENT_FILE_TRANSCRIPTION_PROGRESS`].
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct FileTranscriptionProgressPayload {
+    pub file_name: String,
+    pub phase: FileTranscriptionPhase,
+    /// History entry created for the file, once complete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub entry_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub error: Option<String>,
+}
+
+/// Progress of `file_name`, with the history entry once it completes.
+pub fn progress_payload(
+    file_name: &str,
+    phase: FileTranscriptionPhase,
+    entry_id: Option<Uuid>,
+    error: Option<&str>,
+) -> FileTranscriptionProgressPayload {
+    FileTranscriptionProgressPayload {
+        file_name: file_name.to_string(),
+        phase,
+        entry_id: entry_id.map(|id| id.to_string()),
+        error: error.map(ToString::to_string),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::io::{Cursor, Write};
+
+    /// A 16 kHz mono 16-bit WAV with an extra chunk before `data`.
+    fn wav_bytes(data_len: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + 10 + data_len).to_le_bytes());
+        bytes.extend_from_slice(b"WAVE");
+        bytes.extend_from_slice(b"fmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&16_000u32.to_le_bytes());
+        bytes.extend_from_slice(&32_000u32.to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"LIST");
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&[0, 0]);
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&data_len.to_le_bytes());
+        bytes.resize(bytes.len() + data_len as usize, 0);
+        bytes
+    }
+
+    #[test]
+    fn test_wav_duration_skips_unknown_chunks() {
+        let mut reader = Cursor::new(wav_bytes(48_000));
+        assert_eq!(wav_duration_ms(&mut reader), Ok(1_500));
+    }
+
+    #[test]
+    fn test_wav_duration_rejects_bad_headers() {
+        assert!(wav_duration_ms(&mut Cursor::new(b"ID3\x04 not audio".to_vec())).is_err());
+        assert_eq!(
+            wav_duration_ms(&mut Cursor::new(wav_bytes(0))),
+            Err("file contains no audio".to_string())
+        );
+
+        let mut no_fmt = wav_bytes(100);
+        no_fmt[12..16].copy_from_slice(b"junk");
+        assert!(wav_duration_ms(&mut Cursor::new(no_fmt))
+            .unwrap_err()
+            .contains("missing audio format"));
+    }
+
+    #[test]
+    fn test_inspect_checks_extension_and_contents() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let wav = dir.path().join("Meeting.WAV");
+        File::create(&wav)
+            .unwrap()
+            .write_all(&wav_bytes(64_000))
+            .unwrap();
+        assert_eq!(
+            inspect_audio_file(&wav),
+            Ok(AudioFileInfo {
+                file_name: "Meeting.WAV".to_string(),
+                duration_ms: 2_000,
+            })
+        );
+
+        let mp3 = dir.path().join("song.mp3");
+        File::create(&mp3).unwrap();
+        assert!(inspect_audio_file(&mp3)
+            .unwrap_err()
+            .contains("only WAV audio files"));
+
+        let fake = dir.path().join("notes.wav");
+        File::create(&fake)
+            .unwrap()
+            .write_all(b"plain text")
+            .unwrap();
+        assert!(inspect_audio_file(&fake)
+            .unwrap_err()
+            .starts_with("notes.wav: not a valid WAV file"));
+    }
+
+    #[test]
+    fn test_progress_payload_omits_unset_fields() {
+        let payload = json!(progress_payload(
+            "a.wav",
+            FileTranscriptionPhase::Queued,
+            None,
+            None
+        ));
+        assert_eq!(payload, json!({ "file_name": "a.wav", "phase": "queued" }));
+
+        let payload = json!(progress_payload(
+            "a.wav",
+            FileTranscriptionPhase::Failed,
+            None,
+            Some("no model"),
+        ));
+        assert_eq!(payload["phase"], "failed");
+        assert_eq!(payload["error"], "no model");
+    }
+}
@@ -8,12 +8,31 @@
 //! 1. When recording stops, capture the current focus signature
 //! 2. When transcription completes, validate focus before injecting
 //! 3. If focus changed or OpenVoicy is focused, use clipboard-only mode
+//!
+//! Any window of this process counts as OpenVoicy (settings, overlay, ...),
+//! matched by process id where the platform reports one and by process name
+//! otherwise.
+//!
+//! History entries also keep an [`InjectionTarget`] for the window they were
+//! dictated into, so a transcript can later be injected there again: the
+//! open windows are listed, the matching one is focused, and injection runs
+//! against it.
 
 #![allow(dead_code)] // Module under construction
 
 use chrono::{DateTime, Utc};
-use serde::Serialize;
-use std::time::Instant;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::time::{Duration, Instant};
+
+use crate::config::HistoryConfig;
+
+static TITLE_EMAIL_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[\w.+-]+@[\w-]+(?:\.[\w-]+)+").expect("valid email pattern"));
+static TITLE_DIGIT_RUN_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\d{6,}").expect("valid digit-run pattern"));
 
 /// Focus signature capturing foreground window information.
 #[derive(Debug, Clone, Serialize)]
@@ -29,6 +48,25 @@ pub struct FocusSignature {
     pub captured_at: Instant,
     /// Timestamp for serialization.
     pub timestamp: DateTime<Utc>,
+    /// Frame of the focused window, where the platform reports it.
+    #[serde(skip)]
+    pub window_bounds: Option<WindowBounds>,
+    /// Process owning the window, where the platform reports it (for
+    /// self-injection detection).
+    #[serde(skip)]
+    pub pid: Option<u32>,
+}
+
+/// Window frame in screen coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowBounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    /// The coordinates are logical points (macOS, Wayland compositors)
+    /// rather than physical pixels (Windows, X11).
+    pub logical: bool,
 }
 
 /// Result of focus validation.
@@ -58,7 +96,7 @@ impl FocusValidation {
             FocusValidation::Changed { from_app, to_app } => {
                 Some(format!("Focus changed from {} to {}", from_app, to_app))
             }
-            FocusValidation::SelfFocused => Some("OpenVoicy settings window focused".to_string()),
+            FocusValidation::SelfFocused => Some("OpenVoicy window focused".to_string()),
             FocusValidation::Unavailable => {
                 Some("Focus detection unavailable on this platform".to_string())
             }
@@ -91,6 +129,8 @@ pub fn capture_focus() -> FocusSignature {
             app_name: "Unknown".to_string(),
             captured_at: Instant::now(),
             timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
         }
     }
 }
@@ -115,8 +155,19 @@ pub fn validate_focus(expected: &FocusSignature) -> FocusValidation {
     }
 }
 
-/// Check if OpenVoicy itself is focused.
+/// Check if a window of OpenVoicy itself is focused, so dictation must not
+/// be injected into it.
 pub fn is_self_focused(sig: &FocusSignature) -> bool {
+    is_own_window(sig, std::process::id())
+}
+
+/// Whether the window belongs to this process (`own_pid`), or to another
+/// OpenVoicy instance going by its process name.
+fn is_own_window(sig: &FocusSignature, own_pid: u32) -> bool {
+    if sig.pid == Some(own_pid) {
+        return true;
+    }
+
     let process_lower = sig.process_name.to_lowercase();
 
     // Check various patterns for our app name
@@ -126,6 +177,271 @@ pub fn is_self_focused(sig: &FocusSignature) -> bool {
         || process_lower.contains("voiceinputtool")
 }
 
+/// Frontend-facing view of the would-be injection target.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FocusInfo {
+    /// Application name (process name with platform suffixes stripped).
+    pub app_name: String,
+    /// Window title, when it carries more than the application name.
+    pub window_title: Option<String>,
+    /// Platform-specific window identifier.
+    pub window_id: String,
+    /// OpenVoicy itself is focused, so dictation would not be injected.
+    pub is_self: bool,
+}
+
+impl From<&FocusSignature> for FocusInfo {
+    fn from(sig: &FocusSignature) -> Self {
+        let process = sig.process_name.trim();
+        let app_name = if process.is_empty() || process.eq_ignore_ascii_case("unknown") {
+            sig.app_name.trim().to_string()
+        } else {
+            process.strip_suffix(".exe").unwrap_or(process).to_string()
+        };
+        let window_title = Some(sig.app_name.trim())
+            .filter(|title| !title.is_empty() && !title.eq_ignore_ascii_case(&app_name))
+            .map(ToString::to_string);
+
+        Self {
+            app_name,
+            window_title,
+            window_id: sig.window_id.clone(),
+            is_self: is_self_focused(sig),
+        }
+    }
+}
+
+/// Capture the current focus as a [`FocusInfo`].
+pub fn current_focus_info() -> FocusInfo {
+    FocusInfo::from(&capture_focus())
+}
+
+/// Clean up a window title for storage in history.
+///
+/// Control characters become spaces and whitespace is collapsed. When
+/// `redact` is set, email addresses and digit runs of six or more (account,
+/// phone, or card numbers) are masked. The result is truncated to `max_chars` with an
+/// ellipsis; returns `None` when nothing is left to keep.
+pub fn sanitize_window_title(title: &str, redact: bool, max_chars: usize) -> Option<String> {
+    let collapsed = title
+        .chars()
+        .map(|ch| if ch.is_control() { ' ' } else { ch })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let cleaned = if redact {
+        let masked = TITLE_EMAIL_PATTERN.replace_all(&collapsed, "[email]");
+        TITLE_DIGIT_RUN_PATTERN
+            .replace_all(&masked, "[number]")
+            .into_owned()
+    } else {
+        collapsed
+    };
+
+    if cleaned.is_empty() || max_chars == 0 {
+        return None;
+    }
+    if cleaned.chars().count() <= max_chars {
+        return Some(cleaned);
+    }
+    let mut truncated: String = cleaned.chars().take(max_chars.saturating_sub(1)).collect();
+    truncated.push('…');
+    Some(truncated)
+}
+
+/// App name and window title to record with a transcript, honoring the
+/// `history` privacy settings.
+///
+/// Returns `None` when window context capture is disabled.
+pub fn history_window_context(
+    config: &HistoryConfig,
+    sig: &FocusSignature,
+) -> Option<(String, Option<String>)> {
+    if !config.capture_window_context {
+        return None;
+    }
+    let info = FocusInfo::from(sig);
+    let window_title = info.window_title.as_deref().and_then(|title| {
+        sanitize_window_title(
+            title,
+            config.sanitize_window_title,
+            config.window_title_max_chars as usize,
+        )
+    });
+    Some((info.app_name, window_title))
+}
+
+/// Window a transcript was dictated into, stored with its history entry.
+///
+/// Holds the normalized app id and a SHA-256 hash of the window title rather
+/// than the title itself, so the entry can find the window again without
+/// keeping its title.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct InjectionTarget {
+    /// Application id, normalized with [`normalize_app_id`].
+    pub app_id: String,
+    /// Hex SHA-256 of the window title; absent when the window has no title
+    /// beyond the application name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub window_title_hash: Option<String>,
+}
+
+impl InjectionTarget {
+    /// Target for the window in `sig`, or `None` when the application is
+    /// unknown or is OpenVoicy itself.
+    pub fn from_signature(sig: &FocusSignature) -> Option<Self> {
+        if is_self_focused(sig) {
+            return None;
+        }
+        let info = FocusInfo::from(sig);
+        let app_id = normalize_app_id(&info.app_name).filter(|id| id != "unknown")?;
+        Some(Self {
+            app_id,
+            window_title_hash: info
+                .window_title
+                .map(|title| format!("{:x}", Sha256::digest(title.as_bytes()))),
+        })
+    }
+}
+
+/// An open top-level window that [`activate_window`] can focus.
+#[derive(Debug, Clone)]
+pub struct OpenWindow {
+    /// Platform handle for [`activate_window`].
+    pub handle: String,
+    /// What [`capture_focus`] would report with this window focused.
+    pub signature: FocusSignature,
+}
+
+/// The first of `windows` that is `target`.
+pub fn matching_window(windows: Vec<OpenWindow>, target: &InjectionTarget) -> Option<OpenWindow> {
+    windows
+        .into_iter()
+        .find(|window| InjectionTarget::from_signature(&window.signature).as_ref() == Some(target))
+}
+
+/// List the open top-level windows.
+///
+/// Empty where windows cannot be enumerated (Wayland without compositor
+/// IPC, unsupported platforms).
+pub fn list_windows() -> Vec<OpenWindow> {
+    #[cfg(target_os = "linux")]
+    {
+        list_windows_linux()
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        list_windows_macos()
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        list_windows_windows()
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    {
+        Vec::new()
+    }
+}
+
+/// Bring the window with `handle` (from [`list_windows`]) to the front.
+pub fn activate_window(handle: &str) -> Result<(), String> {
+    #[cfg(target_os = "linux")]
+    {
+        activate_window_linux(handle)
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        activate_window_macos(handle)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        activate_window_windows(handle)
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    {
+        let _ = handle;
+        Err("Focusing windows is not supported on this platform".to_string())
+    }
+}
+
+/// Run `program` and report a non-zero exit as an error.
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+fn run_checked(program: &str, args: &[&str]) -> Result<(), String> {
+    use std::process::{Command, Stdio};
+
+    let output = Command::new(program)
+        .args(args)
+        .stdout(Stdio::null())
+        .output()
+        .map_err(|error| format!("Failed to run {}: {}", program, error))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} failed: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// Debounces focus observations so transient switches (alt-tab, popups) are
+/// not reported.
+///
+/// A new target is reported only after it has been observed continuously for
+/// the debounce window.
+#[derive(Debug)]
+pub struct FocusChangeDebouncer {
+    debounce: Duration,
+    reported: Option<FocusInfo>,
+    pending: Option<(FocusInfo, Instant)>,
+}
+
+impl FocusChangeDebouncer {
+    pub fn new(debounce: Duration) -> Self {
+        Self {
+            debounce,
+            reported: None,
+            pending: None,
+        }
+    }
+
+    /// Feed an observation; returns the focus to report once it has settled.
+    pub fn observe(&mut self, info: FocusInfo, now: Instant) -> Option<FocusInfo> {
+        if self.reported.as_ref() == Some(&info) {
+            self.pending = None;
+            return None;
+        }
+
+        match self.pending.as_ref() {
+            Some((pending, since)) if *pending == info => {
+                if now.duration_since(*since) < self.debounce {
+                    return None;
+                }
+            }
+            _ => {
+                self.pending = Some((info, now));
+                if !self.debounce.is_zero() {
+                    return None;
+                }
+            }
+        }
+
+        let (settled, _) = self.pending.take()?;
+        self.reported = Some(settled.clone());
+        Some(settled)
+    }
+}
+
 /// Normalize an app identifier for config matching.
 ///
 /// This allows case-insensitive matching and tolerates minor formatting
@@ -252,12 +568,49 @@ fn compose_window_id(
 
 #[cfg(target_os = "linux")]
 fn capture_focus_linux() -> FocusSignature {
-    // Try using xdotool to get active window info
-    let window_id = get_active_window_id_linux();
-    let (process_name, app_name) = if window_id != "unknown" {
-        get_window_info_linux(&window_id)
-    } else {
-        ("unknown".to_string(), "Unknown".to_string())
+    use crate::display_strategy::{self, FocusStrategy};
+
+    let strategy = display_strategy::current()
+        .map(|strategies| strategies.focus)
+        .unwrap_or(FocusStrategy::Unavailable);
+    let (window_id, pid, process_name, app_name, window_bounds) = match strategy {
+        FocusStrategy::Xdotool => {
+            let window_id = get_active_window_id_linux();
+            if window_id != "unknown" {
+                let (pid, process_name, app_name) = get_window_info_linux(&window_id);
+                let window_bounds = get_window_geometry_linux(&window_id);
+                (window_id, pid, process_name, app_name, window_bounds)
+            } else {
+                (
+                    window_id,
+                    None,
+                    "unknown".to_string(),
+                    "Unknown".to_string(),
+                    None,
+                )
+            }
+        }
+        FocusStrategy::Sway => {
+            compositor_focus_linux("swaymsg", &["-t", "get_tree"], sway_focused_window)
+        }
+        FocusStrategy::Hyprland => {
+            compositor_focus_linux("hyprctl", &["activewindow", "-j"], hyprland_active_window)
+        }
+        FocusStrategy::Unavailable => {
+            // Wayland without compositor IPC can't report the focused window
+            let window_id = if std::env::var("WAYLAND_DISPLAY").is_ok() {
+                "wayland-unavailable"
+            } else {
+                "unknown"
+            };
+            (
+                window_id.to_string(),
+                None,
+                "unknown".to_string(),
+                "Unknown".to_string(),
+                None,
+            )
+        }
     };
 
     FocusSignature {
@@ -266,6 +619,8 @@ fn capture_focus_linux() -> FocusSignature {
         app_name,
         captured_at: Instant::now(),
         timestamp: Utc::now(),
+        window_bounds,
+        pid,
     }
 }
 
@@ -273,11 +628,6 @@ fn capture_focus_linux() -> FocusSignature {
 fn get_active_window_id_linux() -> String {
     use std::process::{Command, Stdio};
 
-    // Check if we're on Wayland (can't get window info)
-    if std::env::var("WAYLAND_DISPLAY").is_ok() {
-        return "wayland-unavailable".to_string();
-    }
-
     // Use xdotool to get active window
     let output = Command::new("xdotool")
         .args(["getactivewindow"])
@@ -291,7 +641,7 @@ fn get_active_window_id_linux() -> String {
 }
 
 #[cfg(target_os = "linux")]
-fn get_window_info_linux(window_id: &str) -> (String, String) {
+fn get_window_info_linux(window_id: &str) -> (Option<u32>, String, String) {
     use std::process::{Command, Stdio};
 
     // Get process ID for the window
@@ -303,37 +653,314 @@ fn get_window_info_linux(window_id: &str) -> (String, String) {
         .filter(|o| o.status.success())
         .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
 
-    // Get process name from PID
-    let process_name = pid
-        .as_ref()
-        .and_then(|p| {
-            std::fs::read_to_string(format!("/proc/{}/comm", p))
-                .ok()
-                .map(|s| s.trim().to_string())
-        })
-        .unwrap_or_else(|| "unknown".to_string());
+    let process_name = process_name_linux(pid.as_deref());
 
     // Get window name
-    let app_name = Command::new("xdotool")
+    let title = Command::new("xdotool")
         .args(["getwindowname", window_id])
         .stderr(Stdio::null())
         .output()
         .ok()
         .filter(|o| o.status.success())
-        .map(|o| {
-            let name = String::from_utf8_lossy(&o.stdout).trim().to_string();
-            // Truncate long window names
-            if name.len() > 50 {
-                format!("{}...", &name[..47])
-            } else if name.is_empty() {
-                process_name.clone()
-            } else {
-                name
-            }
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
+    let app_name = linux_app_name(title.as_deref(), &process_name);
+
+    (pid.and_then(|pid| pid.parse().ok()), process_name, app_name)
+}
+
+#[cfg(target_os = "linux")]
+fn get_window_geometry_linux(window_id: &str) -> Option<WindowBounds> {
+    use std::process::{Command, Stdio};
+
+    let output = Command::new("xdotool")
+        .args(["getwindowgeometry", "--shell", window_id])
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|o| o.status.success())?;
+    parse_xdotool_geometry(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Bounds from the `X=`, `Y=`, `WIDTH=` and `HEIGHT=` lines of
+/// `xdotool getwindowgeometry --shell`.
+fn parse_xdotool_geometry(output: &str) -> Option<WindowBounds> {
+    let value = |key: &str| {
+        output.lines().find_map(|line| {
+            line.trim()
+                .strip_prefix(key)?
+                .strip_prefix('=')?
+                .parse::<i64>()
+                .ok()
         })
-        .unwrap_or_else(|| process_name.clone());
+    };
+    Some(WindowBounds {
+        x: i32::try_from(value("X")?).ok()?,
+        y: i32::try_from(value("Y")?).ok()?,
+        width: u32::try_from(value("WIDTH")?).ok()?,
+        height: u32::try_from(value("HEIGHT")?).ok()?,
+        logical: false,
+    })
+}
+
+/// Focused window through a compositor IPC command that prints JSON.
+#[cfg(target_os = "linux")]
+fn compositor_focus_linux(
+    program: &str,
+    args: &[&str],
+    parse: fn(&serde_json::Value) -> Option<CompositorWindow>,
+) -> (String, Option<u32>, String, String, Option<WindowBounds>) {
+    use std::process::{Command, Stdio};
 
-    (process_name, app_name)
+    let window = Command::new(program)
+        .args(args)
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| serde_json::from_slice::<serde_json::Value>(&o.stdout).ok())
+        .as_ref()
+        .and_then(parse);
+
+    match window {
+        Some(window) => {
+            let pid = window.pid.map(|pid| pid.to_string());
+            let process_name = process_name_linux(pid.as_deref());
+            let app_name = linux_app_name(window.title.as_deref(), &process_name);
+            (window.id, window.pid, process_name, app_name, window.bounds)
+        }
+        None => (
+            "unknown".to_string(),
+            None,
+            "unknown".to_string(),
+            "Unknown".to_string(),
+            None,
+        ),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn list_windows_linux() -> Vec<OpenWindow> {
+    use crate::display_strategy::{self, FocusStrategy};
+    use std::process::{Command, Stdio};
+
+    let strategy = display_strategy::current()
+        .map(|strategies| strategies.focus)
+        .unwrap_or(FocusStrategy::Unavailable);
+    let json_output = |program: &str, args: &[&str]| {
+        Command::new(program)
+            .args(args)
+            .stderr(Stdio::null())
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .and_then(|o| serde_json::from_slice::<serde_json::Value>(&o.stdout).ok())
+    };
+    let windows = match strategy {
+        FocusStrategy::Xdotool => {
+            let ids = Command::new("xdotool")
+                .args(["search", "--onlyvisible", "--name", "."])
+                .stderr(Stdio::null())
+                .output()
+                .ok()
+                .filter(|o| o.status.success())
+                .map(|o| String::from_utf8_lossy(&o.stdout).into_owned())
+                .unwrap_or_default();
+            return ids
+                .lines()
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .map(|id| {
+                    let (pid, process_name, app_name) = get_window_info_linux(id);
+                    linux_open_window(id.to_string(), pid, process_name, app_name)
+                })
+                .collect();
+        }
+        FocusStrategy::Sway => json_output("swaymsg", &["-t", "get_tree"])
+            .map(|tree| sway_windows(&tree))
+            .unwrap_or_default(),
+        FocusStrategy::Hyprland => json_output("hyprctl", &["clients", "-j"])
+            .and_then(|clients| clients.as_array().cloned())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(hyprland_active_window)
+            .collect(),
+        FocusStrategy::Unavailable => Vec::new(),
+    };
+
+    windows
+        .into_iter()
+        .map(|window| {
+            let pid = window.pid.map(|pid| pid.to_string());
+            let process_name = process_name_linux(pid.as_deref());
+            let app_name = linux_app_name(window.title.as_deref(), &process_name);
+            linux_open_window(window.id, window.pid, process_name, app_name)
+        })
+        .collect()
+}
+
+/// Window ids on Linux are also the handles used to focus them.
+#[cfg(target_os = "linux")]
+fn linux_open_window(
+    window_id: String,
+    pid: Option<u32>,
+    process_name: String,
+    app_name: String,
+) -> OpenWindow {
+    OpenWindow {
+        handle: window_id.clone(),
+        signature: FocusSignature {
+            window_id,
+            process_name,
+            app_name,
+            captured_at: Instant::now(),
+            timestamp: Utc::now(),
+            window_bounds: None,
+            pid,
+        },
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn activate_window_linux(handle: &str) -> Result<(), String> {
+    if let Some(con_id) = handle.strip_prefix("sway:") {
+        run_checked("swaymsg", &[&format!("[con_id={}]", con_id), "focus"])
+    } else if let Some(address) = handle.strip_prefix("hyprland:") {
+        run_checked(
+            "hyprctl",
+            &["dispatch", "focuswindow", &format!("address:{}", address)],
+        )
+    } else {
+        run_checked("xdotool", &["windowactivate", "--sync", handle])
+    }
+}
+
+/// Process name from `/proc/<pid>/comm`.
+#[cfg(target_os = "linux")]
+fn process_name_linux(pid: Option<&str>) -> String {
+    pid.and_then(|p| {
+        std::fs::read_to_string(format!("/proc/{}/comm", p))
+            .ok()
+            .map(|s| s.trim().to_string())
+    })
+    .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Display name from a window title, falling back to the process name.
+fn linux_app_name(title: Option<&str>, process_name: &str) -> String {
+    match title.map(str::trim) {
+        // Truncate long window names
+        Some(name) if name.len() > 50 => {
+            let cut = name
+                .char_indices()
+                .map(|(index, _)| index)
+                .take_while(|index| *index <= 47)
+                .last()
+                .unwrap_or(0);
+            format!("{}...", &name[..cut])
+        }
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => process_name.to_string(),
+    }
+}
+
+/// Focused window reported by a Wayland compositor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CompositorWindow {
+    id: String,
+    pid: Option<u32>,
+    title: Option<String>,
+    bounds: Option<WindowBounds>,
+}
+
+/// Focused window in `swaymsg -t get_tree` output.
+fn sway_focused_window(node: &serde_json::Value) -> Option<CompositorWindow> {
+    if node.get("focused").and_then(|f| f.as_bool()) == Some(true) {
+        if let Some(window) = sway_window(node) {
+            return Some(window);
+        }
+    }
+
+    sway_children(node).find_map(sway_focused_window)
+}
+
+/// Every window in `swaymsg -t get_tree` output.
+fn sway_windows(node: &serde_json::Value) -> Vec<CompositorWindow> {
+    let mut windows: Vec<CompositorWindow> = sway_window(node).into_iter().collect();
+    windows.extend(sway_children(node).flat_map(sway_windows));
+    windows
+}
+
+/// `node` as a window; containers and workspaces carry no `pid`.
+fn sway_window(node: &serde_json::Value) -> Option<CompositorWindow> {
+    node.get("pid")?;
+    Some(CompositorWindow {
+        id: format!("sway:{}", node.get("id")?.as_i64()?),
+        pid: node
+            .get("pid")
+            .and_then(|pid| pid.as_u64())
+            .and_then(|pid| u32::try_from(pid).ok()),
+        title: node
+            .get("name")
+            .and_then(|name| name.as_str())
+            .map(str::to_string),
+        bounds: node.get("rect").and_then(|rect| {
+            logical_bounds(
+                rect.get("x")?,
+                rect.get("y")?,
+                rect.get("width")?,
+                rect.get("height")?,
+            )
+        }),
+    })
+}
+
+fn sway_children(node: &serde_json::Value) -> impl Iterator<Item = &serde_json::Value> {
+    ["nodes", "floating_nodes"]
+        .into_iter()
+        .filter_map(|key| node.get(key).and_then(|children| children.as_array()))
+        .flatten()
+}
+
+/// Window in `hyprctl activewindow -j` output, or one entry of
+/// `hyprctl clients -j`.
+fn hyprland_active_window(window: &serde_json::Value) -> Option<CompositorWindow> {
+    let address = window.get("address")?.as_str()?;
+    if address.is_empty() {
+        return None;
+    }
+    Some(CompositorWindow {
+        id: format!("hyprland:{address}"),
+        pid: window
+            .get("pid")
+            .and_then(|pid| pid.as_u64())
+            .and_then(|pid| u32::try_from(pid).ok()),
+        title: window
+            .get("title")
+            .and_then(|title| title.as_str())
+            .map(str::to_string),
+        bounds: window
+            .get("at")
+            .zip(window.get("size"))
+            .and_then(|(at, size)| {
+                logical_bounds(at.get(0)?, at.get(1)?, size.get(0)?, size.get(1)?)
+            }),
+    })
+}
+
+/// Bounds from compositor JSON numbers, in layout (logical) coordinates.
+fn logical_bounds(
+    x: &serde_json::Value,
+    y: &serde_json::Value,
+    width: &serde_json::Value,
+    height: &serde_json::Value,
+) -> Option<WindowBounds> {
+    Some(WindowBounds {
+        x: i32::try_from(x.as_i64()?).ok()?,
+        y: i32::try_from(y.as_i64()?).ok()?,
+        width: u32::try_from(width.as_u64()?).ok()?,
+        height: u32::try_from(height.as_u64()?).ok()?,
+        logical: true,
+    })
 }
 
 // === macOS Implementation (placeholder) ===
@@ -341,7 +968,9 @@ fn get_window_info_linux(window_id: &str) -> (String, String) {
 #[cfg(target_os = "macos")]
 fn capture_focus_macos() -> FocusSignature {
     let (pid, app_name_raw) = get_frontmost_app_macos();
-    let window_title = get_front_window_title_macos();
+    let (window_title, window_bounds) = run_osascript(FRONT_WINDOW_SCRIPT_MACOS)
+        .map(|output| parse_front_window_macos(&output))
+        .unwrap_or((None, None));
 
     let app_name = if app_name_raw.trim().is_empty() {
         "Unknown (macOS)".to_string()
@@ -365,6 +994,8 @@ fn capture_focus_macos() -> FocusSignature {
         app_name: display_name,
         captured_at: Instant::now(),
         timestamp: Utc::now(),
+        window_bounds,
+        pid: pid.and_then(|pid| pid.parse().ok()),
     }
 }
 
@@ -404,10 +1035,108 @@ fn get_frontmost_app_macos() -> (Option<String>, String) {
     (None, "Unknown (macOS)".to_string())
 }
 
+/// Prints `x,y,width,height<TAB>title` of the frontmost app's front window.
+#[cfg(target_os = "macos")]
+const FRONT_WINDOW_SCRIPT_MACOS: &str = r#"tell application "System Events"
+    tell first application process whose frontmost is true
+        if (count of windows) is 0 then return ""
+        tell front window
+            set {x, y} to position
+            set {w, h} to size
+            return (x as string) & "," & (y as string) & "," & (w as string) & "," & (h as string) & tab & name
+        end tell
+    end tell
+end tell"#;
+
+/// Title and frame (in points) from [`FRONT_WINDOW_SCRIPT_MACOS`] output.
+fn parse_front_window_macos(output: &str) -> (Option<String>, Option<WindowBounds>) {
+    let (frame, title) = output.split_once('\t').unwrap_or((output, ""));
+    let title = Some(title.trim())
+        .filter(|title| !title.is_empty())
+        .map(ToString::to_string);
+    let numbers: Vec<i64> = frame
+        .split(',')
+        .filter_map(|part| part.trim().parse().ok())
+        .collect();
+    let bounds = match numbers[..] {
+        [x, y, width, height] => Some(WindowBounds {
+            x: i32::try_from(x).unwrap_or_default(),
+            y: i32::try_from(y).unwrap_or_default(),
+            width: u32::try_from(width).unwrap_or_default(),
+            height: u32::try_from(height).unwrap_or_default(),
+            logical: true,
+        }),
+        _ => None,
+    };
+    (title, bounds)
+}
+
+#[cfg(target_os = "macos")]
+fn list_windows_macos() -> Vec<OpenWindow> {
+    let script = r#"set out to ""
+tell application "System Events"
+    repeat with proc in (every application process whose background only is false)
+        set procId to unix id of proc
+        set procName to name of proc
+        set windowIndex to 0
+        repeat with win in windows of proc
+            set windowIndex to windowIndex + 1
+            set out to out & procId & tab & windowIndex & tab & procName & tab & (name of win as string) & linefeed
+        end repeat
+    end repeat
+end tell
+return out"#;
+    let Some(output) = run_osascript(script) else {
+        return Vec::new();
+    };
+
+    let mut process_names = std::collections::HashMap::new();
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(4, '\t');
+            let pid = parts.next()?.trim().to_string();
+            let index = parts.next()?.trim();
+            let app_name = parts.next()?.trim().to_string();
+            let title = parts
+                .next()
+                .map(str::trim)
+                .filter(|title| !title.is_empty() && *title != "missing value");
+            let process_name = process_names
+                .entry(pid.clone())
+                .or_insert_with(|| get_process_name_macos(&pid).unwrap_or_else(|| app_name.clone()))
+                .clone();
+            Some(OpenWindow {
+                handle: format!("{}:{}", pid, index),
+                signature: FocusSignature {
+                    window_id: compose_window_id("macos", Some(&pid), title, &app_name),
+                    process_name,
+                    app_name: title.map(ToString::to_string).unwrap_or(app_name),
+                    captured_at: Instant::now(),
+                    timestamp: Utc::now(),
+                    window_bounds: None,
+                    pid: pid.parse().ok(),
+                },
+            })
+        })
+        .collect()
+}
+
 #[cfg(target_os = "macos")]
-fn get_front_window_title_macos() -> Option<String> {
-    let script = r#"tell application "System Events" to tell first application process whose frontmost is true to if (count of windows) > 0 then return name of front window else return """#;
-    run_osascript(script)
+fn activate_window_macos(handle: &str) -> Result<(), String> {
+    let (pid, index) = handle
+        .split_once(':')
+        .filter(|(pid, index)| {
+            pid.chars().all(|ch| ch.is_ascii_digit()) && index.chars().all(|ch| ch.is_ascii_digit())
+        })
+        .ok_or_else(|| format!("Invalid window handle: {}", handle))?;
+    let script = format!(
+        r#"tell application "System Events" to tell (first application process whose unix id is {pid})
+    set frontmost to true
+    perform action "AXRaise" of window {index}
+end tell"#
+    );
+    run_checked("osascript", &["-e", &script])
 }
 
 #[cfg(target_os = "macos")]
@@ -461,11 +1190,49 @@ fn capture_focus_windows() -> FocusSignature {
         app_name: display_name,
         captured_at: Instant::now(),
         timestamp: Utc::now(),
+        window_bounds: foreground_window_bounds_windows(),
+        pid: pid.and_then(|pid| pid.parse().ok()),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn foreground_window_bounds_windows() -> Option<WindowBounds> {
+    let window = unsafe { GetForegroundWindow() };
+    if window.is_null() {
+        return None;
+    }
+    let mut rect = WindowRect::default();
+    if unsafe { GetWindowRect(window, &mut rect) } == 0 {
+        return None;
     }
+    Some(WindowBounds {
+        x: rect.left,
+        y: rect.top,
+        width: u32::try_from(rect.right - rect.left).unwrap_or_default(),
+        height: u32::try_from(rect.bottom - rect.top).unwrap_or_default(),
+        logical: false,
+    })
+}
+
+#[cfg(target_os = "windows")]
+#[repr(C)]
+#[derive(Default)]
+struct WindowRect {
+    left: i32,
+    top: i32,
+    right: i32,
+    bottom: i32,
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "user32")]
+unsafe extern "system" {
+    fn GetForegroundWindow() -> *mut std::ffi::c_void;
+    fn GetWindowRect(window: *mut std::ffi::c_void, rect: *mut WindowRect) -> i32;
 }
 
 #[cfg(target_os = "windows")]
-fn run_powershell(script: &str) -> Option<String> {
+pub(crate) fn run_powershell(script: &str) -> Option<String> {
     use std::process::Command;
 
     let output = Command::new("powershell")
@@ -481,6 +1248,51 @@ fn run_powershell(script: &str) -> Option<String> {
     }
 }
 
+#[cfg(target_os = "windows")]
+fn list_windows_windows() -> Vec<OpenWindow> {
+    let script = r#"$ErrorActionPreference='SilentlyContinue'; Get-Process | Where-Object { $_.MainWindowHandle -ne 0 -and $_.MainWindowTitle } | ForEach-Object { Write-Output ($_.Id.ToString() + "`t" + $_.MainWindowHandle.ToString() + "`t" + $_.ProcessName + "`t" + $_.MainWindowTitle) }"#;
+    let Some(output) = run_powershell(script) else {
+        return Vec::new();
+    };
+
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(4, '\t');
+            let pid = parts.next()?.trim();
+            let handle = parts.next()?.trim().to_string();
+            let process_name = parts.next()?.trim().to_string();
+            let title = parts.next().map(str::trim).filter(|t| !t.is_empty())?;
+            Some(OpenWindow {
+                handle,
+                signature: FocusSignature {
+                    window_id: compose_window_id("windows", Some(pid), Some(title), title),
+                    process_name,
+                    app_name: title.to_string(),
+                    captured_at: Instant::now(),
+                    timestamp: Utc::now(),
+                    window_bounds: None,
+                    pid: pid.parse().ok(),
+                },
+            })
+        })
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn activate_window_windows(handle: &str) -> Result<(), String> {
+    if handle.is_empty() || !handle.chars().all(|ch| ch.is_ascii_digit()) {
+        return Err(format!("Invalid window handle: {}", handle));
+    }
+    let script = format!(
+        r#"Add-Type -Namespace Win32 -Name Window -MemberDefinition '[DllImport("user32.dll")] public static extern bool SetForegroundWindow(System.IntPtr hWnd); [DllImport("user32.dll")] public static extern bool ShowWindow(System.IntPtr hWnd, int nCmdShow); [DllImport("user32.dll")] public static extern bool IsIconic(System.IntPtr hWnd);'; $h=[System.IntPtr]{handle}; if ([Win32.Window]::IsIconic($h)) {{ [Win32.Window]::ShowWindow($h, 9) | Out-Null }}; if (-not [Win32.Window]::SetForegroundWindow($h)) {{ exit 1 }}"#
+    );
+    run_checked(
+        "powershell",
+        &["-NoProfile", "-NonInteractive", "-Command", &script],
+    )
+}
+
 #[cfg(target_os = "windows")]
 fn get_foreground_window_info_windows() -> (Option<String>, String, Option<String>) {
     let script = r#"$ErrorActionPreference='SilentlyContinue'; Add-Type -Namespace Win32 -Name User32 -MemberDefinition '[DllImport("user32.dll")] public static extern System.IntPtr GetForegroundWindow(); [DllImport("user32.dll")] public static extern uint GetWindowThreadProcessId(System.IntPtr hWnd, out uint lpdwProcessId); [DllImport("user32.dll", CharSet=CharSet.Unicode)] public static extern int GetWindowText(System.IntPtr hWnd, System.Text.StringBuilder text, int count);'; $h=[Win32.User32]::GetForegroundWindow(); if ($h -eq [System.IntPtr]::Zero) { return }; $pid=0; [Win32.User32]::GetWindowThreadProcessId($h, [ref]$pid) | Out-Null; $p=Get-Process -Id $pid -ErrorAction SilentlyContinue; $name=if ($p) { $p.ProcessName } else { 'unknown' }; $sb=New-Object System.Text.StringBuilder 1024; [Win32.User32]::GetWindowText($h, $sb, $sb.Capacity) | Out-Null; $title=$sb.ToString(); Write-Output ($pid.ToString() + \"`t\" + $name + \"`t\" + $title)"#;
@@ -523,6 +1335,8 @@ mod tests {
             app_name: "OpenVoicy".to_string(),
             captured_at: Instant::now(),
             timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
         }));
 
         assert!(is_self_focused(&FocusSignature {
@@ -531,6 +1345,8 @@ mod tests {
             app_name: "Voice Input Tool".to_string(),
             captured_at: Instant::now(),
             timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
         }));
 
         assert!(is_self_focused(&FocusSignature {
@@ -539,6 +1355,8 @@ mod tests {
             app_name: "App".to_string(),
             captured_at: Instant::now(),
             timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
         }));
 
         // Case insensitive
@@ -548,6 +1366,8 @@ mod tests {
             app_name: "App".to_string(),
             captured_at: Instant::now(),
             timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
         }));
     }
 
@@ -559,6 +1379,8 @@ mod tests {
             app_name: "Firefox".to_string(),
             captured_at: Instant::now(),
             timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
         }));
 
         assert!(!is_self_focused(&FocusSignature {
@@ -567,9 +1389,28 @@ mod tests {
             app_name: "Visual Studio Code".to_string(),
             captured_at: Instant::now(),
             timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
         }));
     }
 
+    #[test]
+    fn test_own_window_matches_process_id() {
+        let window = |pid: Option<u32>, title: &str| FocusSignature {
+            window_id: "1".to_string(),
+            process_name: "renamed-build".to_string(),
+            app_name: title.to_string(),
+            captured_at: Instant::now(),
+            timestamp: Utc::now(),
+            window_bounds: None,
+            pid,
+        };
+
+        assert!(is_own_window(&window(Some(42), "Overlay"), 42));
+        assert!(!is_own_window(&window(Some(7), "Overlay"), 42));
+        assert!(!is_own_window(&window(None, "Overlay"), 42));
+    }
+
     #[test]
     fn test_normalize_app_id() {
         assert_eq!(normalize_app_id(" Slack "), Some("slack".to_string()));
@@ -589,6 +1430,8 @@ mod tests {
             app_name: "general - Slack".to_string(),
             captured_at: Instant::now(),
             timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
         };
 
         let candidates = app_override_candidates(&sig);
@@ -604,6 +1447,8 @@ mod tests {
             app_name: "Firefox".to_string(),
             captured_at: Instant::now(),
             timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
         };
 
         // Create a validation result for same focus
@@ -652,6 +1497,247 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_focus_info_splits_app_name_and_window_title() {
+        let info = FocusInfo::from(&FocusSignature {
+            window_id: "1".to_string(),
+            process_name: "Slack.exe".to_string(),
+            app_name: "#general - Slack".to_string(),
+            captured_at: Instant::now(),
+            timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
+        });
+        assert_eq!(info.app_name, "Slack");
+        assert_eq!(info.window_title.as_deref(), Some("#general - Slack"));
+        assert!(!info.is_self);
+
+        let bare = FocusInfo::from(&FocusSignature {
+            window_id: "2".to_string(),
+            process_name: "firefox".to_string(),
+            app_name: "Firefox".to_string(),
+            captured_at: Instant::now(),
+            timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
+        });
+        assert_eq!(bare.window_title, None);
+    }
+
+    #[test]
+    fn test_focus_debouncer_reports_only_settled_changes() {
+        let info = |id: &str| FocusInfo {
+            app_name: id.to_string(),
+            window_title: None,
+            window_id: id.to_string(),
+            is_self: false,
+        };
+        let start = Instant::now();
+        let mut debouncer = FocusChangeDebouncer::new(Duration::from_millis(300));
+
+        assert_eq!(debouncer.observe(info("a"), start), None);
+        assert_eq!(
+            debouncer.observe(info("a"), start + Duration::from_millis(300)),
+            Some(info("a"))
+        );
+        assert_eq!(
+            debouncer.observe(info("a"), start + Duration::from_millis(600)),
+            None
+        );
+
+        // A transient switch that reverts before settling is not reported.
+        assert_eq!(
+            debouncer.observe(info("b"), start + Duration::from_millis(700)),
+            None
+        );
+        assert_eq!(
+            debouncer.observe(info("a"), start + Duration::from_millis(800)),
+            None
+        );
+        assert_eq!(
+            debouncer.observe(info("b"), start + Duration::from_millis(1200)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_sanitize_window_title_redacts_and_truncates() {
+        assert_eq!(
+            sanitize_window_title("Inbox\t- jane.doe@example.com  - Mail", true, 120).as_deref(),
+            Some("Inbox - [email] - Mail")
+        );
+        assert_eq!(
+            sanitize_window_title("Account 12345678 - Bank", true, 120).as_deref(),
+            Some("Account [number] - Bank")
+        );
+        assert_eq!(
+            sanitize_window_title("Account 12345678 - Bank", false, 120).as_deref(),
+            Some("Account 12345678 - Bank")
+        );
+        assert_eq!(
+            sanitize_window_title("PROJ-123 Fix login flow", true, 8).as_deref(),
+            Some("PROJ-12…")
+        );
+        assert_eq!(sanitize_window_title("anything", true, 0), None);
+        assert_eq!(sanitize_window_title("   ", true, 120), None);
+    }
+
+    #[test]
+    fn test_history_window_context_respects_capture_switch() {
+        let sig = FocusSignature {
+            window_id: "1".to_string(),
+            process_name: "jira".to_string(),
+            app_name: "PROJ-42 Checkout bug - Jira".to_string(),
+            captured_at: Instant::now(),
+            timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
+        };
+        let mut config = HistoryConfig::default();
+        assert!(history_window_context(&config, &sig).is_none());
+
+        config.capture_window_context = true;
+        let (app_name, window_title) = history_window_context(&config, &sig).unwrap();
+        assert_eq!(app_name, "jira");
+        assert_eq!(window_title.as_deref(), Some("PROJ-42 Checkout bug - Jira"));
+    }
+
+    #[test]
+    fn test_sway_focused_window_walks_tree() {
+        let tree = serde_json::json!({
+            "id": 1,
+            "focused": false,
+            "nodes": [{
+                "id": 4,
+                "focused": false,
+                "nodes": [
+                    { "id": 7, "pid": 100, "focused": false, "name": "Terminal" },
+                    { "id": 9, "pid": 200, "focused": true, "name": "Notes - Editor" }
+                ],
+                "floating_nodes": []
+            }]
+        });
+
+        let window = sway_focused_window(&tree).expect("focused window");
+
+        assert_eq!(window.id, "sway:9");
+        assert_eq!(window.pid, Some(200));
+        assert_eq!(window.title.as_deref(), Some("Notes - Editor"));
+    }
+
+    #[test]
+    fn test_sway_windows_lists_every_window() {
+        let tree = serde_json::json!({
+            "id": 1,
+            "nodes": [{
+                "id": 4,
+                "nodes": [{ "id": 7, "pid": 100, "name": "Terminal" }],
+                "floating_nodes": [{ "id": 9, "pid": 200, "name": "Notes - Editor" }]
+            }]
+        });
+
+        let ids: Vec<_> = sway_windows(&tree).into_iter().map(|w| w.id).collect();
+        assert_eq!(ids, vec!["sway:7", "sway:9"]);
+    }
+
+    #[test]
+    fn test_injection_target_hashes_title_and_matches_windows() {
+        let sig = |window_id: &str, process: &str, title: &str| FocusSignature {
+            window_id: window_id.to_string(),
+            process_name: process.to_string(),
+            app_name: title.to_string(),
+            captured_at: Instant::now(),
+            timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
+        };
+        let target = InjectionTarget::from_signature(&sig("1", "Code.exe", "notes.md - Code"))
+            .expect("target");
+        assert_eq!(target.app_id, "code");
+        let hash = target.window_title_hash.as_deref().expect("title hash");
+        assert_eq!(hash.len(), 64);
+        assert!(!hash.contains("notes"));
+
+        assert!(InjectionTarget::from_signature(&sig("2", "openvoicy", "Settings")).is_none());
+        assert!(InjectionTarget::from_signature(&sig("3", "unknown", "Unknown")).is_none());
+
+        let window = |handle: &str, title: &str| OpenWindow {
+            handle: handle.to_string(),
+            signature: sig(handle, "code", title),
+        };
+        let found = matching_window(
+            vec![
+                window("a", "todo.md - Code"),
+                window("b", "notes.md - Code"),
+            ],
+            &target,
+        );
+        assert_eq!(found.map(|w| w.handle).as_deref(), Some("b"));
+        assert!(matching_window(vec![window("a", "todo.md - Code")], &target).is_none());
+    }
+
+    #[test]
+    fn test_hyprland_active_window_requires_address() {
+        let active = serde_json::json!({
+            "address": "0x5581d0b0",
+            "pid": 321,
+            "class": "firefox",
+            "title": "Mozilla Firefox"
+        });
+        let window = hyprland_active_window(&active).expect("active window");
+        assert_eq!(window.id, "hyprland:0x5581d0b0");
+        assert_eq!(window.pid, Some(321));
+
+        assert!(hyprland_active_window(&serde_json::json!({ "address": "" })).is_none());
+    }
+
+    #[test]
+    fn test_window_bounds_from_platform_output() {
+        let xdotool = "WINDOW=62914566\nX=1940\nY=120\nWIDTH=1280\nHEIGHT=800\nSCREEN=0\n";
+        assert_eq!(
+            parse_xdotool_geometry(xdotool),
+            Some(WindowBounds {
+                x: 1940,
+                y: 120,
+                width: 1280,
+                height: 800,
+                logical: false,
+            })
+        );
+        assert_eq!(parse_xdotool_geometry("WINDOW=1\n"), None);
+
+        let (title, bounds) = parse_front_window_macos("-1440,25,900,600\tNotes");
+        assert_eq!(title.as_deref(), Some("Notes"));
+        assert_eq!(
+            bounds.map(|b| (b.x, b.width, b.logical)),
+            Some((-1440, 900, true))
+        );
+
+        let node = serde_json::json!({
+            "id": 9,
+            "pid": 200,
+            "rect": { "x": 1920, "y": 0, "width": 960, "height": 1080 }
+        });
+        assert_eq!(sway_window(&node).unwrap().bounds.map(|b| b.x), Some(1920));
+
+        let hyprland = serde_json::json!({
+            "address": "0x5581d0b0",
+            "at": [2560, 40],
+            "size": [1200, 900]
+        });
+        let bounds = hyprland_active_window(&hyprland).unwrap().bounds.unwrap();
+        assert_eq!((bounds.x, bounds.y, bounds.height), (2560, 40, 900));
+    }
+
+    #[test]
+    fn test_linux_app_name_truncates_and_falls_back() {
+        assert_eq!(linux_app_name(Some("Editor"), "code"), "Editor");
+        assert_eq!(linux_app_name(Some("  "), "code"), "code");
+        assert_eq!(linux_app_name(None, "code"), "code");
+        let long = "x".repeat(80);
+        assert_eq!(linux_app_name(Some(&long), "code").len(), 50);
+    }
+
     #[test]
     fn test_compose_window_id_falls_back_to_app_name() {
         assert_eq!(
@@ -0,0 +1,106 @@
+//! Public API for embedding the dictation engine without Tauri.
+//!
+//! [`Engine`] wires up the same integration layer the desktop app runs —
+//! hotkeys, sidecar supervision, recording sessions, transcript history, and
+//! text injection — and sends every event the app would emit to windows to an
+//! [`AppEventBroadcaster`] instead. Config is read from and written to the
+//! usual config directory. Call it from inside a Tokio runtime.
+//!
+//! ```no_run
+//! use translator_voice_input_tool_lib::headless::{
+//!     AppEventBroadcaster, Engine, IntegrationError,
+//! };
+//!
+//! struct PrintEvents;
+//!
+//! impl AppEventBroadcaster for PrintEvents {
+//!     fn emit_all(&self, event: &str, payload: serde_json::Value) {
+//!         println!("{event}: {payload}");
+//!     }
+//! }
+//!
+//! # async fn run() -> Result<(), IntegrationError> {
+//! let engine = Engine::new(PrintEvents);
+//! engine.initialize().await?;
+//! engine.manager().read().await.start_recording().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+pub use crate::errors::IntegrationError;
+pub use crate::history::{HistoryClearFilter, TranscriptEntry, TranscriptHistory};
+pub use crate::injection::{inject_text, InjectionConfig, InjectionResult};
+pub use crate::integration::IntegrationManager;
+pub use crate::ipc::{RpcClient, RpcError};
+pub use crate::runtime::{AppEventBroadcaster, AppHandle, Manager};
+pub use crate::state::{AppState, AppStateManager, StateEvent};
+
+use crate::config;
+use crate::pending_inserts::PendingInsertQueue;
+
+/// The dictation engine and the host state it runs against.
+pub struct Engine {
+    app_handle: AppHandle,
+    manager: Arc<RwLock<IntegrationManager>>,
+}
+
+impl Engine {
+    /// Create the engine, sending its events to `broadcaster`.
+    #[cfg(not(feature = "desktop"))]
+    pub fn new(broadcaster: impl AppEventBroadcaster + 'static) -> Self {
+        Self::with_app_handle(AppHandle::new(broadcaster))
+    }
+
+    /// Create the engine on an existing host handle. State the engine needs
+    /// (app state, transcript history, pending inserts) is registered on it
+    /// unless the host already manages it.
+    pub fn with_app_handle(app_handle: AppHandle) -> Self {
+        let state_manager = Arc::new(AppStateManager::new());
+        let mut manager = IntegrationManager::new(Arc::clone(&state_manager));
+        manager.set_app_handle(app_handle.clone());
+
+        app_handle.manage(state_manager);
+        app_handle.manage(crate::load_transcript_history(&config::load_config()));
+        app_handle.manage(PendingInsertQueue::new());
+
+        Self {
+            app_handle,
+            manager: Arc::new(RwLock::new(manager)),
+        }
+    }
+
+    /// Start hotkeys, the sidecar, and the event loops.
+    pub async fn initialize(&self) -> Result<(), IntegrationError> {
+        crate::history::start_prune_loop(self.app_handle.clone());
+        crate::crash_reports::start_upload_loop();
+        self.manager.read().await.initialize().await
+    }
+
+    /// Stop the sidecar and every background loop, and write a pending
+    /// config save.
+    pub async fn shutdown(&self) {
+        self.manager.read().await.shutdown().await;
+        if let Err(e) = crate::config::flush_config() {
+            log::error!("Failed to save config on shutdown: {}", e);
+        }
+    }
+
+    /// The integration manager, for recording, model, and sidecar control.
+    pub fn manager(&self) -> &Arc<RwLock<IntegrationManager>> {
+        &self.manager
+    }
+
+    /// Transcripts delivered so far.
+    pub fn history(&self) -> impl std::ops::Deref<Target = TranscriptHistory> + '_ {
+        self.app_handle.state::<TranscriptHistory>()
+    }
+
+    /// The host handle events and managed state go through.
+    pub fn app_handle(&self) -> &AppHandle {
+        &self.app_handle
+    }
+}
@@ -6,20 +6,41 @@
 #![allow(dead_code)] // Module under construction
 
 use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::RwLock;
 use thiserror::Error;
+use tokio::sync::Notify;
 use uuid::Uuid;
 
-use crate::history_persistence::HistoryPersistence;
+use crate::config::{self, HistoryConfig};
+use crate::diarization;
+use crate::focus::InjectionTarget;
+use crate::history_persistence::{HistoryPersistence, HistoryPersistencePolicy};
+use crate::injection::PasteVerification;
+use crate::runtime::{AppEventBroadcaster, AppHandle, Manager};
+use crate::subtitles::{self, SubtitleFormat};
 
 /// Default maximum history size.
 const DEFAULT_MAX_SIZE: usize = 100;
+/// Session label limits; longer names and tags are truncated.
+const MAX_SESSION_NAME_CHARS: usize = 100;
+const MAX_TAG_CHARS: usize = 32;
+const MAX_TAGS: usize = 10;
+/// Default number of tag suggestions.
+const DEFAULT_TAG_SUGGESTIONS: usize = 10;
 const CSV_UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+/// How often history is pruned by age and size.
+const PRUNE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+/// Event telling the tray and UI that history changed.
+const EVENT_TRAY_UPDATE: &str = "tray:update";
+
+/// Wakes the pruning loop ahead of its interval, e.g. after a config change.
+static PRUNE_REQUESTED: Lazy<Notify> = Lazy::new(Notify::new);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum HistoryExportFormat {
@@ -52,12 +73,15 @@ pub enum HistoryExportError {
     InvalidFormat { value: String },
     #[error("failed to resolve export directory")]
     MissingExportDirectory,
+    #[error("transcript entry not found: {id}")]
+    EntryNotFound { id: Uuid },
     #[error("failed to export history: {0}")]
     Io(#[from] std::io::Error),
 }
 
 /// Result of text injection for a transcript entry.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "snake_case", tag = "status")]
 pub enum HistoryInjectionResult {
     /// Text was successfully injected via paste.
@@ -87,28 +111,93 @@ impl HistoryInjectionResult {
     }
 }
 
+/// Where the audio of a transcript entry came from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptSource {
+    /// Dictated through the microphone.
+    #[default]
+    Microphone,
+    /// Transcribed from an audio file.
+    File,
+}
+
+impl TranscriptSource {
+    fn is_microphone(&self) -> bool {
+        *self == Self::Microphone
+    }
+}
+
 /// Timing breakdown for the stop -> injection pipeline.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 pub struct TranscriptTimings {
     /// Time from stop request until recording.stop RPC returns.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional, type = "number"))]
     pub ipc_ms: Option<u64>,
     /// Time from stop RPC return until transcription_complete is received.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional, type = "number"))]
     pub transcribe_ms: Option<u64>,
     /// Time spent in host-side post-processing before injection.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional, type = "number"))]
     pub postprocess_ms: Option<u64>,
     /// Time spent injecting text.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional, type = "number"))]
     pub inject_ms: Option<u64>,
     /// End-to-end stop -> injection total.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional, type = "number"))]
     pub total_ms: Option<u64>,
 }
 
+/// Timestamped transcript segment reported by the sidecar.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct TranscriptSegment {
+    /// Segment start offset from the beginning of the recording.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub start_ms: u64,
+    /// Segment end offset from the beginning of the recording.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub end_ms: u64,
+    /// Segment text.
+    pub text: String,
+    /// Opaque speaker id when diarization is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub speaker: Option<String>,
+}
+
+/// Confidence below which a word is highlighted when the caller does not
+/// pick a threshold.
+pub const DEFAULT_LOW_CONFIDENCE_THRESHOLD: f32 = 0.6;
+
+/// Word-level timestamp and confidence reported by the sidecar.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct TranscriptWord {
+    /// The recognized word as emitted by the ASR backend.
+    pub word: String,
+    /// Word start offset from the beginning of the recording.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub start_ms: u64,
+    /// Word end offset from the beginning of the recording.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub end_ms: u64,
+    /// Optional per-word confidence in [0.0, 1.0].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub confidence: Option<f32>,
+}
+
 /// A single transcript entry in the history.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 pub struct TranscriptEntry {
     /// Unique identifier for this entry.
     pub id: Uuid,
@@ -128,18 +217,199 @@ pub struct TranscriptEntry {
     pub transcription_duration_ms: u32,
     /// Recording session ID correlated to the sidecar session, if available.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
     pub session_id: Option<Uuid>,
     /// Optional detected language code (for example, "en").
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
     pub language: Option<String>,
     /// Optional confidence score in [0.0, 1.0].
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
     pub confidence: Option<f32>,
     /// Result of injection attempt.
     pub injection_result: HistoryInjectionResult,
+    /// Whether the pasted text was confirmed in the target field, when
+    /// `injection.verify_paste` is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub paste_verification: Option<PasteVerification>,
+    /// Transcription attempts repeated after transient sidecar errors.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    #[cfg_attr(test, ts(as = "Option<u32>", optional))]
+    pub transcription_retries: u32,
     /// Optional stop -> injection timing breakdown.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
     pub timings: Option<TranscriptTimings>,
+    /// Segment timestamps, when the sidecar provides them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<TranscriptSegment>>", optional))]
+    pub segments: Vec<TranscriptSegment>,
+    /// Word timestamps and confidence, when the sidecar provides them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<TranscriptWord>>", optional))]
+    pub words: Vec<TranscriptWord>,
+    /// Pre-filter text, kept only when the word filter changed the transcript
+    /// and `word_filter.keep_unfiltered` is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub unfiltered_text: Option<String>,
+    /// Effective auto punctuation setting for this recording.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub auto_punctuation: Option<bool>,
+    /// Application that was focused when recording stopped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub app_name: Option<String>,
+    /// Sanitized window title of the focused application.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub window_title: Option<String>,
+    /// Window the transcript was dictated into, for re-injecting it there.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub injection_target: Option<InjectionTarget>,
+    /// User-assigned name for the dictation session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub session_name: Option<String>,
+    /// User-assigned tags, e.g. project names.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<String>>", optional))]
+    pub tags: Vec<String>,
+    /// Where the audio came from; omitted for microphone dictation.
+    #[serde(default, skip_serializing_if = "TranscriptSource::is_microphone")]
+    #[cfg_attr(test, ts(as = "Option<TranscriptSource>", optional))]
+    pub source: TranscriptSource,
+    /// Name of the transcribed file, for `source: file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub source_file: Option<String>,
+    /// Pinned entries are kept by filtered clears with `unpinned_only` and by
+    /// the retention policy.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[cfg_attr(test, ts(as = "Option<bool>", optional))]
+    pub pinned: bool,
+}
+
+/// Name and tags assigned to a dictation session.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionLabel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl SessionLabel {
+    /// Build a label from user input: trims and truncates the name, and
+    /// dedupes tags case-insensitively (dropping a leading `#`).
+    pub fn normalized(name: Option<String>, tags: Vec<String>) -> Self {
+        let name = name
+            .map(|name| truncate_chars(&collapse_whitespace(&name), MAX_SESSION_NAME_CHARS))
+            .filter(|name| !name.is_empty());
+
+        let mut normalized_tags: Vec<String> = Vec::new();
+        for tag in tags {
+            let tag = collapse_whitespace(tag.trim().trim_start_matches('#'));
+            let tag = truncate_chars(&tag, MAX_TAG_CHARS);
+            if tag.is_empty()
+                || normalized_tags
+                    .iter()
+                    .any(|existing| existing.eq_ignore_ascii_case(&tag))
+            {
+                continue;
+            }
+            normalized_tags.push(tag);
+            if normalized_tags.len() == MAX_TAGS {
+                break;
+            }
+        }
+
+        Self {
+            name,
+            tags: normalized_tags,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.tags.is_empty()
+    }
+}
+
+/// Which entries a filtered clear removes. Every criterion that is set must
+/// match; an empty filter matches every entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HistoryClearFilter {
+    /// Only entries older than this many days.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub older_than_days: Option<u32>,
+    /// Only entries dictated into this application (case-insensitive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_name: Option<String>,
+    /// Only entries carrying this tag (case-insensitive, leading `#` ignored).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// Keep pinned entries.
+    pub unpinned_only: bool,
+}
+
+impl HistoryClearFilter {
+    /// Filter of the retention policy: unpinned entries older than `days`.
+    pub fn retention(days: u32) -> Self {
+        Self {
+            older_than_days: Some(days),
+            unpinned_only: true,
+            ..Self::default()
+        }
+    }
+
+    /// Whether `entry` should be removed, as of `now`.
+    pub fn matches(&self, entry: &TranscriptEntry, now: DateTime<Utc>) -> bool {
+        if self.unpinned_only && entry.pinned {
+            return false;
+        }
+        if let Some(days) = self.older_than_days {
+            if entry.timestamp > now - chrono::Duration::days(i64::from(days)) {
+                return false;
+            }
+        }
+        if let Some(app_name) = self.app_name.as_deref().map(str::trim) {
+            if !entry
+                .app_name
+                .as_deref()
+                .is_some_and(|name| name.trim().eq_ignore_ascii_case(app_name))
+            {
+                return false;
+            }
+        }
+        if let Some(tag) = &self.tag {
+            if !entry.has_tags(&[tag.trim_start_matches('#').to_string()]) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+fn collapse_whitespace(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn truncate_chars(value: &str, max_chars: usize) -> String {
+    value
+        .chars()
+        .take(max_chars)
+        .collect::<String>()
+        .trim_end()
+        .to_string()
 }
 
 impl TranscriptEntry {
@@ -164,7 +434,21 @@ impl TranscriptEntry {
             language: None,
             confidence: None,
             injection_result,
+            paste_verification: None,
+            transcription_retries: 0,
             timings: None,
+            segments: Vec::new(),
+            words: Vec::new(),
+            unfiltered_text: None,
+            auto_punctuation: None,
+            app_name: None,
+            window_title: None,
+            injection_target: None,
+            session_name: None,
+            tags: Vec::new(),
+            source: TranscriptSource::Microphone,
+            source_file: None,
+            pinned: false,
         }
     }
 
@@ -181,11 +465,169 @@ impl TranscriptEntry {
         self
     }
 
+    /// Record the paste verification outcome.
+    pub fn with_paste_verification(
+        mut self,
+        paste_verification: Option<PasteVerification>,
+    ) -> Self {
+        self.paste_verification = paste_verification;
+        self
+    }
+
+    /// Record how many times transcription was retried for this entry.
+    pub fn with_transcription_retries(mut self, transcription_retries: u32) -> Self {
+        self.transcription_retries = transcription_retries;
+        self
+    }
+
     /// Attach pipeline timings.
     pub fn with_timings(mut self, timings: TranscriptTimings) -> Self {
         self.timings = Some(timings);
         self
     }
+
+    /// Attach sidecar segment timestamps.
+    pub fn with_segments(mut self, segments: Vec<TranscriptSegment>) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// Attach sidecar word timestamps.
+    pub fn with_words(mut self, words: Vec<TranscriptWord>) -> Self {
+        self.words = words;
+        self
+    }
+
+    /// Attach the pre-filter transcript text.
+    pub fn with_unfiltered_text(mut self, unfiltered_text: Option<String>) -> Self {
+        self.unfiltered_text = unfiltered_text;
+        self
+    }
+
+    /// Record whether auto punctuation was applied to this recording.
+    pub fn with_auto_punctuation(mut self, auto_punctuation: Option<bool>) -> Self {
+        self.auto_punctuation = auto_punctuation;
+        self
+    }
+
+    /// Attach the focused app name and window title captured for this entry.
+    pub fn with_window_context(
+        mut self,
+        app_name: Option<String>,
+        window_title: Option<String>,
+    ) -> Self {
+        self.app_name = app_name;
+        self.window_title = window_title;
+        self
+    }
+
+    /// Attach the window the transcript was dictated into.
+    pub fn with_injection_target(mut self, target: Option<InjectionTarget>) -> Self {
+        self.injection_target = target;
+        self
+    }
+
+    /// Attach a session name and tags.
+    pub fn with_label(mut self, label: SessionLabel) -> Self {
+        self.session_name = label.name;
+        self.tags = label.tags;
+        self
+    }
+
+    /// Mark the entry as transcribed from the audio file `file_name`.
+    pub fn with_source_file(mut self, file_name: String) -> Self {
+        self.source = TranscriptSource::File;
+        self.source_file = Some(file_name);
+        self
+    }
+
+    /// Whether `query` (lowercase) appears in the text or metadata shown in history.
+    fn matches_query(&self, query: &str) -> bool {
+        let fields = [
+            Some(self.text.as_str()),
+            Some(self.final_text.as_str()),
+            self.language.as_deref(),
+            self.app_name.as_deref(),
+            self.window_title.as_deref(),
+            self.session_name.as_deref(),
+            self.source_file.as_deref(),
+        ];
+        fields
+            .into_iter()
+            .flatten()
+            .chain(self.tags.iter().map(String::as_str))
+            .any(|field| field.to_lowercase().contains(query))
+    }
+
+    /// Whether the entry carries every tag in `tags` (case-insensitive).
+    fn has_tags(&self, tags: &[String]) -> bool {
+        tags.iter().all(|wanted| {
+            self.tags
+                .iter()
+                .any(|tag| tag.eq_ignore_ascii_case(wanted.trim()))
+        })
+    }
+
+    /// Indices of words whose confidence is below `threshold`.
+    ///
+    /// Words without a confidence value are never reported.
+    pub fn low_confidence_word_indices(&self, threshold: f32) -> Vec<usize> {
+        self.words
+            .iter()
+            .enumerate()
+            .filter(|(_, word)| word.confidence.is_some_and(|value| value < threshold))
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+/// Limits enforced by a pruning pass; zero disables a limit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryPruneLimits {
+    /// Remove unpinned entries older than this many days.
+    pub retention_days: u32,
+    /// Remove the oldest unpinned entries while the serialized history is
+    /// larger than this.
+    pub max_storage_bytes: u64,
+}
+
+impl HistoryPruneLimits {
+    pub fn from_config(config: &HistoryConfig) -> Self {
+        Self {
+            retention_days: config.retention_days,
+            max_storage_bytes: u64::from(config.max_storage_mb) * 1024 * 1024,
+        }
+    }
+}
+
+/// Entries removed by a pruning pass.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryPruneOutcome {
+    pub removed_by_age: usize,
+    pub removed_by_size: usize,
+}
+
+impl HistoryPruneOutcome {
+    pub fn removed(&self) -> usize {
+        self.removed_by_age + self.removed_by_size
+    }
+}
+
+/// Entry counts and storage footprint returned by `get_history_stats`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HistoryStats {
+    pub entries: usize,
+    pub pinned: usize,
+    pub max_entries: usize,
+    pub persistence: HistoryPersistencePolicy,
+    /// Size of the history file; 0 for memory-only history.
+    pub storage_bytes: u64,
+    /// Serialized size of the entries held in memory.
+    pub memory_bytes: u64,
+    pub oldest_at: Option<DateTime<Utc>>,
+    pub newest_at: Option<DateTime<Utc>>,
+    /// When the background pruning pass last ran.
+    pub last_pruned_at: Option<DateTime<Utc>>,
 }
 
 /// Thread-safe transcript history ring buffer.
@@ -193,6 +635,12 @@ pub struct TranscriptHistory {
     entries: RwLock<VecDeque<TranscriptEntry>>,
     max_size: AtomicUsize,
     persistence: Option<Box<dyn HistoryPersistence>>,
+    /// Label applied to new entries until it is changed or cleared.
+    active_label: RwLock<SessionLabel>,
+    /// Entries were dropped in memory without rewriting persistence; the
+    /// next pruning pass writes the snapshot.
+    persist_pending: AtomicBool,
+    last_pruned_at: RwLock<Option<DateTime<Utc>>>,
 }
 
 impl Default for TranscriptHistory {
@@ -242,6 +690,9 @@ impl TranscriptHistory {
             entries: RwLock::new(entries),
             max_size: AtomicUsize::new(max_size),
             persistence,
+            active_label: RwLock::new(SessionLabel::default()),
+            persist_pending: AtomicBool::new(false),
+            last_pruned_at: RwLock::new(None),
         }
     }
 
@@ -279,31 +730,103 @@ impl TranscriptHistory {
 
     /// Resize the maximum retained entries.
     ///
-    /// When shrinking, oldest entries are dropped first.
+    /// When shrinking, oldest entries are dropped first. Persistence is not
+    /// rewritten here; the next [`prune`](Self::prune) pass or push does it,
+    /// so callers on a command thread never wait for disk I/O.
     pub fn resize(&self, new_max_size: usize) {
         let new_max_size = new_max_size.max(1);
         let previous_max = self.max_size.swap(new_max_size, Ordering::Relaxed);
         if previous_max == new_max_size {
             return;
         }
+        if let Some(persistence) = self.persistence.as_ref() {
+            persistence.set_max_entries(new_max_size);
+        }
 
-        let (removed, snapshot) = {
+        let removed = {
             let mut entries = self.entries.write().unwrap();
             let before = entries.len();
             while entries.len() > new_max_size {
                 entries.pop_front();
             }
-            let removed = before.saturating_sub(entries.len());
-            let snapshot = entries.iter().cloned().collect::<Vec<_>>();
-            (removed, snapshot)
+            before.saturating_sub(entries.len())
         };
+        if removed > 0 {
+            self.persist_pending.store(true, Ordering::Relaxed);
+        }
         log::info!(
             "Resized transcript history max entries: {} -> {} (removed {} old entries)",
             previous_max,
             new_max_size,
             removed
         );
-        self.persist_snapshot(&snapshot);
+    }
+
+    /// Remove unpinned entries past `limits` and write any pending
+    /// snapshot. Meant for the background pruning loop.
+    pub fn prune(&self, limits: HistoryPruneLimits, now: DateTime<Utc>) -> HistoryPruneOutcome {
+        let (outcome, snapshot) = {
+            let mut entries = self.entries.write().unwrap();
+            let mut outcome = HistoryPruneOutcome::default();
+            if limits.retention_days > 0 {
+                let filter = HistoryClearFilter::retention(limits.retention_days);
+                let before = entries.len();
+                entries.retain(|entry| !filter.matches(entry, now));
+                outcome.removed_by_age = before - entries.len();
+            }
+            if limits.max_storage_bytes > 0 {
+                outcome.removed_by_size =
+                    drop_oldest_over_size(&mut entries, limits.max_storage_bytes);
+            }
+            (outcome, entries.iter().cloned().collect::<Vec<_>>())
+        };
+        *self.last_pruned_at.write().unwrap() = Some(now);
+
+        let pending = self.persist_pending.swap(false, Ordering::Relaxed);
+        if outcome.removed() == 0 && !pending {
+            return outcome;
+        }
+        if snapshot.is_empty() {
+            self.purge_persistence();
+        } else {
+            self.persist_snapshot(&snapshot);
+        }
+        outcome
+    }
+
+    /// Entry counts and storage footprint.
+    pub fn stats(&self) -> HistoryStats {
+        let (entries, pinned, memory_bytes, oldest_at, newest_at) = {
+            let entries = self.entries.read().unwrap();
+            (
+                entries.len(),
+                entries.iter().filter(|entry| entry.pinned).count(),
+                entries.iter().map(serialized_len).sum(),
+                entries.front().map(|entry| entry.timestamp),
+                entries.back().map(|entry| entry.timestamp),
+            )
+        };
+        let (persistence, storage_bytes) = match self.persistence.as_ref() {
+            Some(persistence) => (
+                persistence.policy(),
+                persistence.storage_bytes().unwrap_or_else(|error| {
+                    log::warn!("Failed to read transcript history size: {error}");
+                    0
+                }),
+            ),
+            None => (HistoryPersistencePolicy::MemoryOnly, 0),
+        };
+        HistoryStats {
+            entries,
+            pinned,
+            max_entries: self.max_size(),
+            persistence,
+            storage_bytes,
+            memory_bytes,
+            oldest_at,
+            newest_at,
+            last_pruned_at: *self.last_pruned_at.read().unwrap(),
+        }
     }
 
     /// Current configured max entries.
@@ -340,6 +863,130 @@ impl TranscriptHistory {
         self.len() == 0
     }
 
+    /// Replace a transcript's text with a user-edited version.
+    ///
+    /// Returns the previous text, or None if the entry was not found.
+    pub fn update_text(&self, id: Uuid, text: String) -> Option<String> {
+        let (previous, snapshot) = {
+            let mut entries = self.entries.write().unwrap();
+            let entry = entries.iter_mut().find(|e| e.id == id)?;
+            let previous = std::mem::replace(&mut entry.text, text);
+            (previous, entries.iter().cloned().collect::<Vec<_>>())
+        };
+        self.persist_snapshot(&snapshot);
+        Some(previous)
+    }
+
+    /// Set the name and tags of an existing entry.
+    ///
+    /// Returns false if the entry was not found.
+    pub fn update_label(&self, id: Uuid, label: SessionLabel) -> bool {
+        let snapshot = {
+            let mut entries = self.entries.write().unwrap();
+            let Some(entry) = entries.iter_mut().find(|e| e.id == id) else {
+                return false;
+            };
+            entry.session_name = label.name;
+            entry.tags = label.tags;
+            entries.iter().cloned().collect::<Vec<_>>()
+        };
+        self.persist_snapshot(&snapshot);
+        true
+    }
+
+    /// Label applied to sessions recorded from now on.
+    pub fn active_label(&self) -> SessionLabel {
+        self.active_label.read().unwrap().clone()
+    }
+
+    /// Set (or clear, with an empty label) the label for upcoming sessions.
+    pub fn set_active_label(&self, label: SessionLabel) {
+        *self.active_label.write().unwrap() = label;
+    }
+
+    /// Entries matching `query` and carrying all of `tags`, newest first.
+    pub fn search(&self, query: &str, tags: &[String]) -> Vec<TranscriptEntry> {
+        let query = query.trim().to_lowercase();
+        let entries = self.entries.read().unwrap();
+        entries
+            .iter()
+            .rev()
+            .filter(|entry| query.is_empty() || entry.matches_query(&query))
+            .filter(|entry| entry.has_tags(tags))
+            .cloned()
+            .collect()
+    }
+
+    /// Recently used tags starting with `prefix`, most recent first.
+    ///
+    /// Tags of the active label come before those found in history.
+    pub fn tag_suggestions(&self, prefix: &str, limit: Option<usize>) -> Vec<String> {
+        let prefix = prefix.trim().trim_start_matches('#').to_lowercase();
+        let limit = limit.unwrap_or(DEFAULT_TAG_SUGGESTIONS);
+        let active = self.active_label();
+        let entries = self.entries.read().unwrap();
+
+        let mut suggestions: Vec<String> = Vec::new();
+        let candidates = active
+            .tags
+            .iter()
+            .chain(entries.iter().rev().flat_map(|entry| entry.tags.iter()));
+        for tag in candidates {
+            if suggestions.len() >= limit {
+                break;
+            }
+            if !tag.to_lowercase().starts_with(&prefix)
+                || suggestions
+                    .iter()
+                    .any(|existing| existing.eq_ignore_ascii_case(tag))
+            {
+                continue;
+            }
+            suggestions.push(tag.clone());
+        }
+        suggestions
+    }
+
+    /// Pin or unpin an existing entry.
+    ///
+    /// Returns false if the entry was not found.
+    pub fn set_pinned(&self, id: Uuid, pinned: bool) -> bool {
+        let snapshot = {
+            let mut entries = self.entries.write().unwrap();
+            let Some(entry) = entries.iter_mut().find(|e| e.id == id) else {
+                return false;
+            };
+            entry.pinned = pinned;
+            entries.iter().cloned().collect::<Vec<_>>()
+        };
+        self.persist_snapshot(&snapshot);
+        true
+    }
+
+    /// Remove the entries matching `filter` as of `now`.
+    ///
+    /// Returns how many entries were removed.
+    pub fn clear_matching(&self, filter: &HistoryClearFilter, now: DateTime<Utc>) -> usize {
+        let (removed, snapshot) = {
+            let mut entries = self.entries.write().unwrap();
+            let before = entries.len();
+            entries.retain(|entry| !filter.matches(entry, now));
+            (
+                before - entries.len(),
+                entries.iter().cloned().collect::<Vec<_>>(),
+            )
+        };
+        if removed == 0 {
+            return 0;
+        }
+        if snapshot.is_empty() {
+            self.purge_persistence();
+        } else {
+            self.persist_snapshot(&snapshot);
+        }
+        removed
+    }
+
     /// Clear all entries from the history.
     pub fn clear(&self) {
         {
@@ -390,6 +1037,39 @@ impl TranscriptHistory {
         self.export_to_dir(export_format, &export_dir)
     }
 
+    /// Export a single entry as SRT or WebVTT subtitles.
+    pub fn export_subtitles(&self, id: Uuid, format: &str) -> Result<PathBuf, HistoryExportError> {
+        let subtitle_format = SubtitleFormat::parse(format)?;
+        let export_dir = resolve_export_directory()?;
+        self.export_subtitles_to_dir(id, subtitle_format, &export_dir)
+    }
+
+    fn export_subtitles_to_dir(
+        &self,
+        id: Uuid,
+        subtitle_format: SubtitleFormat,
+        export_dir: &Path,
+    ) -> Result<PathBuf, HistoryExportError> {
+        let entry = self
+            .get(id)
+            .ok_or(HistoryExportError::EntryNotFound { id })?;
+        fs::create_dir_all(export_dir)?;
+
+        let filename = format!(
+            "openvoicy-transcript-{}-{}.{}",
+            entry.timestamp.format("%Y%m%d-%H%M%S"),
+            &id.simple().to_string()[..8],
+            subtitle_format.extension()
+        );
+        let output_path = export_dir.join(filename);
+        fs::write(
+            &output_path,
+            subtitles::render_entry(&entry, subtitle_format),
+        )?;
+
+        Ok(output_path)
+    }
+
     fn export_to_dir(
         &self,
         export_format: HistoryExportFormat,
@@ -464,6 +1144,13 @@ fn render_markdown_export(entries: &[TranscriptEntry]) -> String {
         });
         out.push_str("\n```\n\n");
 
+        if let Some(speaker_text) = diarization::format_speaker_transcript(&entry.segments) {
+            out.push_str("### Speakers\n");
+            out.push_str("```\n");
+            out.push_str(&speaker_text);
+            out.push_str("\n```\n\n");
+        }
+
         out.push_str("### Metadata\n");
         out.push_str(&format!("- ID: `{}`\n", entry.id));
         out.push_str(&format!(
@@ -485,6 +1172,18 @@ fn render_markdown_export(entries: &[TranscriptEntry]) -> String {
             "- Transcription Duration (ms): `{}`\n",
             entry.transcription_duration_ms
         ));
+        if let Some(app_name) = entry.app_name.as_deref() {
+            out.push_str(&format!("- Application: `{}`\n", app_name));
+        }
+        if let Some(window_title) = entry.window_title.as_deref() {
+            out.push_str(&format!("- Window Title: `{}`\n", window_title));
+        }
+        if let Some(session_name) = entry.session_name.as_deref() {
+            out.push_str(&format!("- Session Name: `{}`\n", session_name));
+        }
+        if !entry.tags.is_empty() {
+            out.push_str(&format!("- Tags: `{}`\n", entry.tags.join(", ")));
+        }
         out.push_str(&format!(
             "- Language: `{}`\n",
             entry.language.as_deref().unwrap_or("n/a")
@@ -569,6 +1268,86 @@ fn csv_escape(value: &str) -> String {
     }
 }
 
+/// Serialized size of an entry as one persisted JSONL line.
+fn serialized_len(entry: &TranscriptEntry) -> u64 {
+    serde_json::to_string(entry).map_or(0, |line| line.len() as u64 + 1)
+}
+
+/// Drop the oldest unpinned entries until the serialized history fits in
+/// `max_bytes`. Returns the number removed.
+fn drop_oldest_over_size(entries: &mut VecDeque<TranscriptEntry>, max_bytes: u64) -> usize {
+    let sizes: Vec<u64> = entries.iter().map(serialized_len).collect();
+    let mut total: u64 = sizes.iter().sum();
+    let mut dropped = vec![false; entries.len()];
+    for (index, entry) in entries.iter().enumerate() {
+        if total <= max_bytes {
+            break;
+        }
+        if !entry.pinned {
+            dropped[index] = true;
+            total -= sizes[index];
+        }
+    }
+    let before = entries.len();
+    let mut index = 0;
+    entries.retain(|_| {
+        let keep = !dropped[index];
+        index += 1;
+        keep
+    });
+    before - entries.len()
+}
+
+/// Ask the pruning loop to run now, e.g. after history limits changed.
+pub fn request_prune() {
+    PRUNE_REQUESTED.notify_one();
+}
+
+/// Prune history by `history.retention_days` and `history.max_storage_mb`
+/// at startup, every [`PRUNE_INTERVAL`], and on [`request_prune`]. Each
+/// pass runs on a blocking thread so persistence rewrites stay off the
+/// async runtime and the recording path.
+pub fn start_prune_loop(app: AppHandle) {
+    crate::runtime::spawn(async move {
+        let mut interval = tokio::time::interval(PRUNE_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = PRUNE_REQUESTED.notified() => {}
+            }
+            let limits = HistoryPruneLimits::from_config(&config::load_config().history);
+            let pass_app = app.clone();
+            let outcome = match tokio::task::spawn_blocking(move || {
+                pass_app
+                    .state::<TranscriptHistory>()
+                    .prune(limits, Utc::now())
+            })
+            .await
+            {
+                Ok(outcome) => outcome,
+                Err(error) => {
+                    log::warn!("History pruning pass failed: {error}");
+                    continue;
+                }
+            };
+            if outcome.removed() > 0 {
+                log::info!(
+                    "History pruning removed {} entries by age and {} by size",
+                    outcome.removed_by_age,
+                    outcome.removed_by_size
+                );
+                app.emit_all(
+                    EVENT_TRAY_UPDATE,
+                    crate::event_seq::payload_with_next_seq(serde_json::json!({
+                        "reason": "history_changed",
+                    })),
+                );
+            }
+        }
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -778,6 +1557,283 @@ mod tests {
         assert!(history.is_empty());
     }
 
+    #[test]
+    fn test_clear_matching_combines_filters() {
+        let core = Arc::new(MockPersistenceCore::with_loaded_entries(Vec::new()));
+        let history = TranscriptHistory::with_capacity_and_persistence(
+            10,
+            Some(Box::new(SharedMockPersistence(Arc::clone(&core)))),
+        );
+        let now = Utc::now();
+        let aged = |text: &str, days: i64, app: &str, tags: &[&str]| {
+            let mut entry = labeled_entry(text, tags);
+            entry.timestamp = now - chrono::Duration::days(days);
+            entry.app_name = Some(app.to_string());
+            entry
+        };
+        history.push(aged("old slack", 40, "Slack", &["acme"]));
+        history.push(aged("old code", 40, "Code", &[]));
+        history.push(aged("new slack", 1, "Slack", &["globex"]));
+        let mut pinned = aged("old pinned", 40, "Slack", &["acme"]);
+        pinned.pinned = true;
+        history.push(pinned);
+
+        let slack_tagged = HistoryClearFilter {
+            app_name: Some("slack".to_string()),
+            tag: Some("#ACME".to_string()),
+            unpinned_only: true,
+            ..HistoryClearFilter::default()
+        };
+        assert_eq!(history.clear_matching(&slack_tagged, now), 1);
+        assert_eq!(history.clear_matching(&slack_tagged, now), 0);
+
+        assert_eq!(
+            history.clear_matching(&HistoryClearFilter::retention(30), now),
+            1
+        );
+        let texts: Vec<String> = history.all().into_iter().map(|e| e.text).collect();
+        assert_eq!(texts, vec!["old pinned", "new slack"]);
+        assert_eq!(core.purge_calls.load(AtomicOrdering::Relaxed), 0);
+
+        // Clearing everything purges persistence like `clear`.
+        assert_eq!(
+            history.clear_matching(&HistoryClearFilter::default(), now),
+            2
+        );
+        assert!(history.is_empty());
+        assert_eq!(core.purge_calls.load(AtomicOrdering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_prune_by_age_and_size_keeps_pinned() {
+        let history = TranscriptHistory::with_capacity(10);
+        let now = Utc::now();
+        let mut old = labeled_entry("old", &[]);
+        old.timestamp = now - chrono::Duration::days(40);
+        history.push(old);
+        let mut pinned = labeled_entry("pinned", &[]);
+        pinned.pinned = true;
+        history.push(pinned);
+        history.push(labeled_entry("middle", &[]));
+        history.push(labeled_entry("newest", &[]));
+
+        let outcome = history.prune(
+            HistoryPruneLimits {
+                retention_days: 30,
+                max_storage_bytes: 0,
+            },
+            now,
+        );
+        assert_eq!(outcome.removed_by_age, 1);
+        assert_eq!(outcome.removed_by_size, 0);
+
+        let all = history.all();
+        let outcome = history.prune(
+            HistoryPruneLimits {
+                retention_days: 0,
+                max_storage_bytes: serialized_len(&all[0]) + serialized_len(&all[2]),
+            },
+            now,
+        );
+        assert_eq!(outcome.removed_by_size, 1);
+        let texts: Vec<String> = history.all().into_iter().map(|e| e.text).collect();
+        assert_eq!(texts, vec!["newest", "pinned"]);
+        assert_eq!(history.stats().last_pruned_at, Some(now));
+    }
+
+    #[test]
+    fn test_resize_persists_on_next_prune() {
+        let core = Arc::new(MockPersistenceCore::with_loaded_entries(Vec::new()));
+        let history = TranscriptHistory::with_capacity_and_persistence(
+            5,
+            Some(Box::new(SharedMockPersistence(Arc::clone(&core)))),
+        );
+        for i in 0..3 {
+            history.push(labeled_entry(&format!("Entry {i}"), &[]));
+        }
+        let saves = || core.saved_snapshots.lock().unwrap().len();
+        assert_eq!(saves(), 3);
+
+        history.resize(2);
+        assert_eq!(history.len(), 2);
+        assert_eq!(saves(), 3);
+
+        history.prune(HistoryPruneLimits::default(), Utc::now());
+        assert_eq!(saves(), 4);
+        assert_eq!(core.saved_snapshots.lock().unwrap()[3].len(), 2);
+
+        history.prune(HistoryPruneLimits::default(), Utc::now());
+        assert_eq!(saves(), 4);
+    }
+
+    #[test]
+    fn test_stats_counts_entries() {
+        let history = TranscriptHistory::with_capacity(4);
+        let stats = history.stats();
+        assert_eq!(stats.entries, 0);
+        assert_eq!(stats.max_entries, 4);
+        assert_eq!(stats.persistence, HistoryPersistencePolicy::MemoryOnly);
+        assert_eq!(stats.memory_bytes, 0);
+        assert!(stats.oldest_at.is_none());
+
+        let mut pinned = labeled_entry("pinned", &[]);
+        pinned.pinned = true;
+        history.push(pinned);
+        history.push(labeled_entry("other", &[]));
+        let stats = history.stats();
+        assert_eq!(stats.entries, 2);
+        assert_eq!(stats.pinned, 1);
+        assert!(stats.memory_bytes > 0);
+        assert_eq!(stats.storage_bytes, 0);
+        assert!(stats.oldest_at <= stats.newest_at);
+    }
+
+    #[test]
+    fn test_set_pinned() {
+        let history = TranscriptHistory::new();
+        let entry = labeled_entry("keep me", &[]);
+        let id = entry.id;
+        history.push(entry);
+
+        assert!(history.set_pinned(id, true));
+        assert!(history.get(id).unwrap().pinned);
+        let json = serde_json::to_value(history.get(id).unwrap()).unwrap();
+        assert_eq!(json["pinned"], true);
+        assert!(history.set_pinned(id, false));
+        let json = serde_json::to_value(history.get(id).unwrap()).unwrap();
+        assert!(json.get("pinned").is_none());
+        assert!(!history.set_pinned(Uuid::new_v4(), true));
+    }
+
+    #[test]
+    fn test_update_text_returns_previous_text() {
+        let history = TranscriptHistory::new();
+        let entry = TranscriptEntry::new(
+            "ask kubernetes".to_string(),
+            1000,
+            200,
+            HistoryInjectionResult::Injected,
+        );
+        let id = entry.id;
+        history.push(entry);
+
+        assert_eq!(
+            history.update_text(id, "ask Kubernetes".to_string()),
+            Some("ask kubernetes".to_string())
+        );
+        assert_eq!(history.get(id).unwrap().text, "ask Kubernetes");
+        assert_eq!(
+            history.update_text(Uuid::new_v4(), "missing".to_string()),
+            None
+        );
+    }
+
+    fn labeled_entry(text: &str, tags: &[&str]) -> TranscriptEntry {
+        TranscriptEntry::new(
+            text.to_string(),
+            1000,
+            100,
+            HistoryInjectionResult::Injected,
+        )
+        .with_label(SessionLabel::normalized(
+            None,
+            tags.iter().map(|tag| tag.to_string()).collect(),
+        ))
+    }
+
+    #[test]
+    fn test_session_label_normalization() {
+        let label = SessionLabel::normalized(
+            Some("  Sprint   planning ".to_string()),
+            vec![
+                "#Acme".to_string(),
+                "acme".to_string(),
+                "  ".to_string(),
+                "client  notes".to_string(),
+                "x".repeat(40),
+            ],
+        );
+        assert_eq!(label.name.as_deref(), Some("Sprint planning"));
+        assert_eq!(
+            label.tags,
+            vec!["Acme", "client notes", "x".repeat(32).as_str()]
+        );
+
+        assert!(SessionLabel::normalized(Some("   ".to_string()), Vec::new()).is_empty());
+        let many: Vec<String> = (0..20).map(|i| format!("tag{i}")).collect();
+        assert_eq!(SessionLabel::normalized(None, many).tags.len(), MAX_TAGS);
+    }
+
+    #[test]
+    fn test_search_filters_by_query_and_tags() {
+        let history = TranscriptHistory::new();
+        history.push(labeled_entry("acme roadmap draft", &["acme", "planning"]));
+        history.push(labeled_entry("globex invoice reply", &["globex"]));
+        history.push(labeled_entry("acme bug triage", &["Acme"]));
+
+        let acme = history.search("", &["ACME".to_string()]);
+        assert_eq!(acme.len(), 2);
+        assert_eq!(acme[0].text, "acme bug triage", "newest first");
+
+        let planning = history.search("roadmap", &["acme".to_string()]);
+        assert_eq!(planning.len(), 1);
+        assert!(history.search("invoice", &["acme".to_string()]).is_empty());
+
+        // Tags are searchable text too.
+        assert_eq!(history.search("planning", &[]).len(), 1);
+        assert_eq!(history.search("", &[]).len(), 3);
+    }
+
+    #[test]
+    fn test_update_label_and_active_label() {
+        let history = TranscriptHistory::new();
+        let entry = labeled_entry("notes", &[]);
+        let id = entry.id;
+        history.push(entry);
+
+        let label = SessionLabel::normalized(Some("Standup".to_string()), vec!["team".to_string()]);
+        assert!(history.update_label(id, label));
+        let updated = history.get(id).unwrap();
+        assert_eq!(updated.session_name.as_deref(), Some("Standup"));
+        assert_eq!(updated.tags, vec!["team"]);
+        assert!(!history.update_label(Uuid::new_v4(), SessionLabel::default()));
+
+        assert!(history.active_label().is_empty());
+        history.set_active_label(SessionLabel::normalized(None, vec!["acme".to_string()]));
+        assert_eq!(history.active_label().tags, vec!["acme"]);
+    }
+
+    #[test]
+    fn test_tag_suggestions_prefer_recent_and_active_tags() {
+        let history = TranscriptHistory::new();
+        history.push(labeled_entry("one", &["alpha", "beta"]));
+        history.push(labeled_entry("two", &["apollo"]));
+        history.set_active_label(SessionLabel::normalized(None, vec!["atlas".to_string()]));
+
+        assert_eq!(
+            history.tag_suggestions("", None),
+            vec!["atlas", "apollo", "alpha", "beta"]
+        );
+        assert_eq!(
+            history.tag_suggestions("#a", Some(2)),
+            vec!["atlas", "apollo"]
+        );
+        assert!(history.tag_suggestions("zeta", None).is_empty());
+    }
+
+    #[test]
+    fn test_entry_label_round_trip() {
+        let entry = labeled_entry("notes", &["acme"]);
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["tags"], serde_json::json!(["acme"]));
+        assert!(json.get("session_name").is_none());
+
+        let unlabeled = serde_json::to_value(labeled_entry("notes", &[])).unwrap();
+        assert!(unlabeled.get("tags").is_none());
+        let decoded: TranscriptEntry = serde_json::from_value(unlabeled).unwrap();
+        assert!(decoded.tags.is_empty());
+    }
+
     #[test]
     fn test_last_text() {
         let history = TranscriptHistory::new();
@@ -908,6 +1964,156 @@ mod tests {
         assert!(value.get("language").is_none());
         assert!(value.get("confidence").is_none());
         assert!(value.get("timings").is_none());
+        assert!(value.get("source").is_none());
+        assert!(value.get("source_file").is_none());
+    }
+
+    #[test]
+    fn test_entry_source_file_serialization() {
+        let entry = TranscriptEntry::new(
+            "From a file".to_string(),
+            2000,
+            350,
+            HistoryInjectionResult::Injected,
+        )
+        .with_source_file("interview.wav".to_string());
+
+        let value = serde_json::to_value(&entry).unwrap();
+        assert_eq!(value["source"], "file");
+        assert_eq!(value["source_file"], "interview.wav");
+
+        let restored: TranscriptEntry = serde_json::from_value(value).unwrap();
+        assert_eq!(restored.source, TranscriptSource::File);
+        assert!(restored.matches_query("interview"));
+    }
+
+    #[test]
+    fn test_entry_words_round_trip_and_low_confidence_lookup() {
+        let entry = TranscriptEntry::new(
+            "hello world".to_string(),
+            1000,
+            100,
+            HistoryInjectionResult::Injected,
+        )
+        .with_words(vec![
+            TranscriptWord {
+                word: "hello".to_string(),
+                start_ms: 0,
+                end_ms: 400,
+                confidence: Some(0.98),
+            },
+            TranscriptWord {
+                word: "world".to_string(),
+                start_ms: 450,
+                end_ms: 900,
+                confidence: Some(0.41),
+            },
+        ]);
+
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["words"][1]["word"], "world");
+        assert_eq!(json["words"][1]["end_ms"], 900);
+
+        let decoded: TranscriptEntry = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.words, entry.words);
+        assert_eq!(decoded.low_confidence_word_indices(0.6), vec![1]);
+    }
+
+    #[test]
+    fn test_entry_without_words_omits_field() {
+        let entry = TranscriptEntry::new(
+            "plain".to_string(),
+            1000,
+            100,
+            HistoryInjectionResult::Injected,
+        );
+        let json = serde_json::to_value(&entry).unwrap();
+        assert!(json.get("words").is_none());
+        assert!(json.get("segments").is_none());
+        assert!(json.get("unfiltered_text").is_none());
+    }
+
+    #[test]
+    fn test_entry_unfiltered_text_round_trip() {
+        let entry = TranscriptEntry::new(
+            "oh c***".to_string(),
+            1000,
+            100,
+            HistoryInjectionResult::Injected,
+        )
+        .with_unfiltered_text(Some("oh crap".to_string()));
+
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["unfiltered_text"], "oh crap");
+
+        let decoded: TranscriptEntry = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.unfiltered_text.as_deref(), Some("oh crap"));
+    }
+
+    #[test]
+    fn test_entry_window_context_round_trip_and_markdown() {
+        let entry = TranscriptEntry::new(
+            "ship it".to_string(),
+            1000,
+            100,
+            HistoryInjectionResult::Injected,
+        )
+        .with_window_context(
+            Some("jira".to_string()),
+            Some("PROJ-42 Checkout bug".to_string()),
+        );
+
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["app_name"], "jira");
+        assert_eq!(json["window_title"], "PROJ-42 Checkout bug");
+
+        let decoded: TranscriptEntry = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            decoded.window_title.as_deref(),
+            Some("PROJ-42 Checkout bug")
+        );
+
+        let markdown = render_markdown_export(&[entry]);
+        assert!(markdown.contains("- Application: `jira`"));
+        assert!(markdown.contains("- Window Title: `PROJ-42 Checkout bug`"));
+    }
+
+    #[test]
+    fn test_entry_paste_verification_round_trip() {
+        let entry = TranscriptEntry::new(
+            "ship it".to_string(),
+            1000,
+            100,
+            HistoryInjectionResult::Injected,
+        )
+        .with_paste_verification(Some(PasteVerification::RetriedWithKeystrokes));
+
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["paste_verification"], "retried_with_keystrokes");
+
+        let decoded: TranscriptEntry = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            decoded.paste_verification,
+            Some(PasteVerification::RetriedWithKeystrokes)
+        );
+    }
+
+    #[test]
+    fn test_entry_transcription_retries_round_trip() {
+        let entry = TranscriptEntry::new(
+            "ship it".to_string(),
+            1000,
+            100,
+            HistoryInjectionResult::Injected,
+        );
+        let json = serde_json::to_value(&entry).unwrap();
+        assert!(json.get("transcription_retries").is_none());
+
+        let json = serde_json::to_value(entry.with_transcription_retries(1)).unwrap();
+        assert_eq!(json["transcription_retries"], 1);
+
+        let decoded: TranscriptEntry = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.transcription_retries, 1);
     }
 
     #[test]
@@ -992,6 +2198,34 @@ mod tests {
         assert!(content.contains("focus changed"));
     }
 
+    #[test]
+    fn test_export_markdown_includes_speaker_turns_when_diarized() {
+        let entry = TranscriptEntry::new(
+            "hi hello".to_string(),
+            1000,
+            300,
+            HistoryInjectionResult::Injected,
+        )
+        .with_segments(vec![
+            TranscriptSegment {
+                start_ms: 0,
+                end_ms: 400,
+                text: "hi".to_string(),
+                speaker: Some("spk_0".to_string()),
+            },
+            TranscriptSegment {
+                start_ms: 400,
+                end_ms: 900,
+                text: "hello".to_string(),
+                speaker: Some("spk_1".to_string()),
+            },
+        ]);
+
+        let rendered = render_markdown_export(&[entry]);
+        assert!(rendered.contains("### Speakers"));
+        assert!(rendered.contains("Speaker 1: hi\nSpeaker 2: hello"));
+    }
+
     #[test]
     fn test_export_csv_empty_history_writes_bom_and_headers() {
         let history = TranscriptHistory::new();
@@ -1284,6 +2518,55 @@ mod tests {
         assert!(csv_path.file_name().unwrap().to_str().unwrap().starts_with("openvoicy-history-"));
     }
 
+    #[test]
+    fn test_export_subtitles_writes_segment_cues() {
+        let history = TranscriptHistory::new();
+        let entry = TranscriptEntry::new(
+            "hello world again".to_string(),
+            4_000,
+            100,
+            HistoryInjectionResult::Injected,
+        )
+        .with_segments(vec![
+            TranscriptSegment {
+                start_ms: 0,
+                end_ms: 1_500,
+                text: "hello world".to_string(),
+                speaker: None,
+            },
+            TranscriptSegment {
+                start_ms: 1_500,
+                end_ms: 4_000,
+                text: "again".to_string(),
+                speaker: None,
+            },
+        ]);
+        let id = entry.id;
+        history.push(entry);
+        let dir = tempdir().expect("temp dir should be available");
+
+        let path = history
+            .export_subtitles_to_dir(id, SubtitleFormat::Vtt, dir.path())
+            .expect("subtitle export should succeed");
+        assert_eq!(path.extension().and_then(|e| e.to_str()), Some("vtt"));
+        let content = fs::read_to_string(path).expect("subtitle file should be readable");
+        assert!(content.starts_with("WEBVTT\n\n"));
+        assert!(content.contains("00:00:00.000 --> 00:00:01.500\nhello world"));
+        assert!(content.contains("00:00:01.500 --> 00:00:04.000\nagain"));
+    }
+
+    #[test]
+    fn test_export_subtitles_rejects_unknown_entry() {
+        let history = TranscriptHistory::new();
+        let dir = tempdir().expect("temp dir should be available");
+        let result =
+            history.export_subtitles_to_dir(Uuid::new_v4(), SubtitleFormat::Srt, dir.path());
+        assert!(matches!(
+            result,
+            Err(HistoryExportError::EntryNotFound { .. })
+        ));
+    }
+
     #[test]
     fn test_export_csv_multiple_entries_preserves_order() {
         let history = TranscriptHistory::new();
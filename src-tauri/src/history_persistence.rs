@@ -7,6 +7,7 @@
 use std::fs;
 use std::io::{self, ErrorKind};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Mutex;
 
 use aes_gcm::aead::Aead;
@@ -15,6 +16,7 @@ use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use base64::Engine;
 use keyring::Error as KeyringError;
 use rand::RngCore;
+use serde::Serialize;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -51,10 +53,24 @@ pub trait HistoryPersistence: Send + Sync {
     fn load(&self) -> Result<Vec<TranscriptEntry>, PersistenceError>;
     fn purge(&self) -> Result<(), PersistenceError>;
     fn entry_count(&self) -> Result<usize, PersistenceError>;
+
+    /// Change the number of entries kept on disk.
+    fn set_max_entries(&self, _max_entries: usize) {}
+
+    /// Bytes the persisted history takes on disk.
+    fn storage_bytes(&self) -> Result<u64, PersistenceError> {
+        Ok(0)
+    }
+
+    /// Where and how entries are stored.
+    fn policy(&self) -> HistoryPersistencePolicy {
+        HistoryPersistencePolicy::MemoryOnly
+    }
 }
 
 /// Effective persistence policy after applying config gates.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum HistoryPersistencePolicy {
     MemoryOnly,
     DiskPlaintext,
@@ -205,7 +221,7 @@ impl EncryptionProvider {
 pub struct JsonlPersistence {
     path: PathBuf,
     encryption: Option<EncryptionProvider>,
-    max_entries: usize,
+    max_entries: AtomicUsize,
     save_state: Mutex<JsonlSaveState>,
 }
 
@@ -220,7 +236,7 @@ impl JsonlPersistence {
         Self {
             path,
             encryption,
-            max_entries: max_entries.max(1),
+            max_entries: AtomicUsize::new(max_entries.max(1)),
             save_state: Mutex::new(JsonlSaveState::default()),
         }
     }
@@ -231,8 +247,12 @@ impl JsonlPersistence {
         PathBuf::from(os_path)
     }
 
+    fn max_entries(&self) -> usize {
+        self.max_entries.load(Ordering::Relaxed)
+    }
+
     fn normalize_entries(&self, mut entries: Vec<TranscriptEntry>) -> Vec<TranscriptEntry> {
-        let keep = self.max_entries;
+        let keep = self.max_entries();
         if entries.len() > keep {
             entries = entries.split_off(entries.len() - keep);
         }
@@ -268,7 +288,7 @@ impl JsonlPersistence {
     }
 
     fn rewrite_snapshot(&self, entries: &[TranscriptEntry]) -> Result<(), PersistenceError> {
-        let start = entries.len().saturating_sub(self.max_entries);
+        let start = entries.len().saturating_sub(self.max_entries());
         let mut payload = String::new();
         for entry in entries.iter().skip(start) {
             payload.push_str(&serde_json::to_string(entry)?);
@@ -329,7 +349,7 @@ impl JsonlPersistence {
 
 impl HistoryPersistence for JsonlPersistence {
     fn save(&self, entries: &[TranscriptEntry]) -> Result<(), PersistenceError> {
-        let start = entries.len().saturating_sub(self.max_entries);
+        let start = entries.len().saturating_sub(self.max_entries());
         let snapshot = &entries[start..];
 
         if self.encryption.is_some() {
@@ -420,6 +440,27 @@ impl HistoryPersistence for JsonlPersistence {
     fn entry_count(&self) -> Result<usize, PersistenceError> {
         Ok(self.load()?.len())
     }
+
+    fn set_max_entries(&self, max_entries: usize) {
+        self.max_entries
+            .store(max_entries.max(1), Ordering::Relaxed);
+    }
+
+    fn storage_bytes(&self) -> Result<u64, PersistenceError> {
+        match fs::metadata(&self.path) {
+            Ok(metadata) => Ok(metadata.len()),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(0),
+            Err(error) => Err(PersistenceError::Io(error)),
+        }
+    }
+
+    fn policy(&self) -> HistoryPersistencePolicy {
+        if self.encryption.is_some() {
+            HistoryPersistencePolicy::DiskEncrypted
+        } else {
+            HistoryPersistencePolicy::DiskPlaintext
+        }
+    }
 }
 
 /// Memory-only persistence backend.
@@ -636,6 +677,25 @@ mod tests {
         assert_eq!(loaded[1].text, "entry-3");
     }
 
+    #[test]
+    fn jsonl_persistence_max_entries_can_grow() {
+        let dir = tempdir().expect("temp dir should be available");
+        let path = dir.path().join("history.jsonl");
+        let persistence = JsonlPersistence::new(path, None, 1);
+        let entries = vec![sample_entry("entry-1"), sample_entry("entry-2")];
+        assert_eq!(persistence.storage_bytes().expect("stat should work"), 0);
+
+        persistence.set_max_entries(5);
+        persistence.save(&entries).expect("save should succeed");
+
+        assert_eq!(persistence.load().expect("load should succeed").len(), 2);
+        assert!(persistence.storage_bytes().expect("stat should work") > 0);
+        assert_eq!(
+            persistence.policy(),
+            HistoryPersistencePolicy::DiskPlaintext
+        );
+    }
+
     #[test]
     fn jsonl_persistence_purge_removes_file() {
         let dir = tempdir().expect("temp dir should be available");
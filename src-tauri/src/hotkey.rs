@@ -6,6 +6,8 @@
 //! - Auto-repeat debouncing
 //! - Audio cues for start/stop/error
 //! - Copy last transcript hotkey
+//! - Cancel hotkey, registered only while recording
+//! - Modifier masking so the chord does not leak into the focused app (see `hotkey_leak`)
 
 #![allow(dead_code)] // Module under construction
 
@@ -19,9 +21,15 @@ use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::mpsc;
 
+#[cfg(target_os = "linux")]
+use crate::capabilities;
 use crate::config::{self, HotkeyMode};
 use crate::history::TranscriptHistory;
-use crate::state::{AppState, AppStateManager};
+use crate::hotkey_leak;
+use crate::hotkey_practice;
+use crate::state::{AppState, AppStateManager, CannotRecordReason};
+#[cfg(target_os = "linux")]
+use crate::wayland_shortcuts::{self, PortalShortcut, PortalShortcuts};
 
 /// Sound types for audio cues.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -40,6 +48,10 @@ pub enum HotkeyAction {
     PrimaryUp,
     /// Copy last transcript hotkey pressed.
     CopyLast,
+    /// Dictate-raw hotkey pressed (like `PrimaryDown`, with auto punctuation off).
+    DictateRawDown,
+    /// Cancel hotkey pressed (discard the recording without transcribing).
+    Cancel,
 }
 
 /// Hotkey registration errors.
@@ -65,14 +77,26 @@ pub struct HotkeyStatus {
     pub primary: String,
     /// Copy last hotkey string.
     pub copy_last: String,
+    /// Dictate-raw hotkey string (empty when disabled).
+    pub dictate_raw: String,
+    /// Cancel hotkey string (empty when disabled).
+    pub cancel: String,
     /// Current mode (hold or toggle).
     pub mode: String,
     /// Whether primary hotkey is registered.
     pub primary_registered: bool,
     /// Whether copy last hotkey is registered.
     pub copy_last_registered: bool,
+    /// Whether dictate-raw hotkey is registered.
+    pub dictate_raw_registered: bool,
+    /// Whether the cancel hotkey is valid; it is only registered while recording.
+    pub cancel_registered: bool,
     /// Registration error if any.
     pub error: Option<String>,
+    /// Profile whose hotkeys are registered, if it overrides the base set.
+    pub profile: Option<String>,
+    /// Bindings that share a key chord (see `binding_conflicts`).
+    pub conflicts: Vec<String>,
 }
 
 /// Internal state for hotkey handling.
@@ -107,12 +131,23 @@ pub struct HotkeyManager {
     copy_last_id: Option<u32>,
     /// Exact copy-last hotkey value registered with the OS.
     copy_last_hotkey: Option<HotKey>,
+    /// Dictate-raw hotkey ID.
+    dictate_raw_id: Option<u32>,
+    /// Exact dictate-raw hotkey value registered with the OS.
+    dictate_raw_hotkey: Option<HotKey>,
+    /// Parsed cancel hotkey, registered with the OS only while recording.
+    cancel_hotkey: Option<HotKey>,
+    /// Cancel hotkey ID while it is registered.
+    cancel_id: Option<u32>,
     /// Internal state.
     state: Arc<HotkeyState>,
     /// Event sender for hotkey actions.
     action_tx: mpsc::Sender<HotkeyAction>,
     /// Event receiver for hotkey actions.
     action_rx: Option<mpsc::Receiver<HotkeyAction>>,
+    /// GlobalShortcuts portal session used instead of `manager` on Wayland.
+    #[cfg(target_os = "linux")]
+    portal: Option<PortalShortcuts>,
 }
 
 impl HotkeyManager {
@@ -127,12 +162,18 @@ impl HotkeyManager {
             primary_hotkey: None,
             copy_last_id: None,
             copy_last_hotkey: None,
+            dictate_raw_id: None,
+            dictate_raw_hotkey: None,
+            cancel_hotkey: None,
+            cancel_id: None,
             state: Arc::new(HotkeyState::new(
-                config.hotkeys.mode,
+                config.effective_hotkeys().mode,
                 config.audio.audio_cues_enabled,
             )),
             action_tx,
             action_rx: Some(action_rx),
+            #[cfg(target_os = "linux")]
+            portal: None,
         }
     }
 
@@ -141,19 +182,38 @@ impl HotkeyManager {
         self.action_rx.take()
     }
 
-    /// Initialize and register hotkeys.
+    /// Initialize and register hotkeys for the active profile.
+    ///
+    /// Safe to call again at runtime (e.g. on a profile switch): previous
+    /// registrations are released first, and a held primary key or an armed
+    /// cancel hotkey carries over to the new bindings.
     pub fn initialize(&mut self) -> Result<HotkeyStatus, HotkeyError> {
+        let config = config::load_config();
+        let hotkeys = config.effective_hotkeys();
+        let profile = config.hotkey_profile().map(str::to_string);
+        let conflicts = binding_conflicts(&hotkeys);
+        for conflict in &conflicts {
+            log::warn!("Hotkey conflict: {}", conflict);
+        }
+
+        let cancel_was_armed = self.cancel_armed();
+        let key_was_down = self.state.key_is_down.load(Ordering::SeqCst);
+        self.release_bindings();
+
+        #[cfg(target_os = "linux")]
+        if let Some(mut status) = self.initialize_portal(&hotkeys, config.audio.audio_cues_enabled)
+        {
+            self.state.key_is_down.store(key_was_down, Ordering::SeqCst);
+            status.profile = profile;
+            status.conflicts = conflicts;
+            return Ok(status);
+        }
+
         let manager =
             GlobalHotKeyManager::new().map_err(|e| HotkeyError::PlatformError(e.to_string()))?;
 
-        let config = config::load_config();
-        self.primary_id = None;
-        self.primary_hotkey = None;
-        self.copy_last_id = None;
-        self.copy_last_hotkey = None;
-
         // Parse and register primary hotkey
-        let (primary_registered, primary_error) = match parse_hotkey(&config.hotkeys.primary) {
+        let (primary_registered, primary_error) = match parse_hotkey(&hotkeys.primary) {
             Ok(hk) => match manager.register(hk) {
                 Ok(()) => {
                     self.primary_id = Some(hk.id());
@@ -166,8 +226,7 @@ impl HotkeyManager {
         };
 
         // Parse and register copy last hotkey
-        let (copy_last_registered, copy_last_error) = match parse_hotkey(&config.hotkeys.copy_last)
-        {
+        let (copy_last_registered, copy_last_error) = match parse_hotkey(&hotkeys.copy_last) {
             Ok(hk) => match manager.register(hk) {
                 Ok(()) => {
                     self.copy_last_id = Some(hk.id());
@@ -179,28 +238,165 @@ impl HotkeyManager {
             Err(e) => (false, Some(e.to_string())),
         };
 
+        // Parse and register the optional dictate-raw hotkey
+        let (dictate_raw_registered, dictate_raw_error) = if hotkeys.dictate_raw.is_empty() {
+            (false, None)
+        } else {
+            match parse_hotkey(&hotkeys.dictate_raw) {
+                Ok(hk) => match manager.register(hk) {
+                    Ok(()) => {
+                        self.dictate_raw_id = Some(hk.id());
+                        self.dictate_raw_hotkey = Some(hk);
+                        (true, None)
+                    }
+                    Err(e) => (false, Some(e.to_string())),
+                },
+                Err(e) => (false, Some(e.to_string())),
+            }
+        };
+
+        // The cancel hotkey is only parsed here; `set_cancel_armed` registers it
+        // while a recording is in progress.
+        let (cancel_registered, cancel_error) = if hotkeys.cancel.is_empty() {
+            (false, None)
+        } else {
+            match parse_hotkey(&hotkeys.cancel) {
+                Ok(hk) => {
+                    self.cancel_hotkey = Some(hk);
+                    (true, None)
+                }
+                Err(e) => (false, Some(e.to_string())),
+            }
+        };
+
         self.manager = Some(manager);
+        self.set_cancel_armed(cancel_was_armed);
 
         // Update state
         self.state = Arc::new(HotkeyState::new(
-            config.hotkeys.mode,
+            hotkeys.mode,
             config.audio.audio_cues_enabled,
         ));
-
-        let error = primary_error.or(copy_last_error);
+        self.state.key_is_down.store(key_was_down, Ordering::SeqCst);
+
+        let error = primary_error
+            .or(copy_last_error)
+            .or(dictate_raw_error)
+            .or(cancel_error);
+        #[cfg(target_os = "linux")]
+        let error = error.or_else(|| {
+            capabilities::is_wayland_session().then(|| {
+                "GlobalShortcuts portal unavailable; hotkeys only work in XWayland windows"
+                    .to_string()
+            })
+        });
 
         Ok(HotkeyStatus {
-            primary: config.hotkeys.primary,
-            copy_last: config.hotkeys.copy_last,
-            mode: format!("{:?}", config.hotkeys.mode).to_lowercase(),
+            primary: hotkeys.primary,
+            copy_last: hotkeys.copy_last,
+            dictate_raw: hotkeys.dictate_raw,
+            cancel: hotkeys.cancel,
+            mode: format!("{:?}", hotkeys.mode).to_lowercase(),
             primary_registered,
             copy_last_registered,
+            dictate_raw_registered,
+            cancel_registered,
             error,
+            profile,
+            conflicts,
         })
     }
 
+    /// Register hotkeys with the GlobalShortcuts portal on Wayland.
+    ///
+    /// Returns None when not on Wayland or the portal cannot be used, so the
+    /// caller falls back to `global-hotkey`.
+    #[cfg(target_os = "linux")]
+    fn initialize_portal(
+        &mut self,
+        hotkeys: &config::HotkeyConfig,
+        audio_cues_enabled: bool,
+    ) -> Option<HotkeyStatus> {
+        if !capabilities::is_wayland_session() || !capabilities::wayland_portal_available() {
+            return None;
+        }
+
+        let mut shortcuts = vec![
+            PortalShortcut {
+                id: wayland_shortcuts::PRIMARY_SHORTCUT_ID,
+                description: "Start or stop dictation",
+                hotkey: hotkeys.primary.clone(),
+            },
+            PortalShortcut {
+                id: wayland_shortcuts::COPY_LAST_SHORTCUT_ID,
+                description: "Copy last transcript",
+                hotkey: hotkeys.copy_last.clone(),
+            },
+        ];
+        if !hotkeys.dictate_raw.is_empty() {
+            shortcuts.push(PortalShortcut {
+                id: wayland_shortcuts::DICTATE_RAW_SHORTCUT_ID,
+                description: "Dictate without auto punctuation",
+                hotkey: hotkeys.dictate_raw.clone(),
+            });
+        }
+        // Portal shortcuts stay bound for the whole session, so a bare key like
+        // Escape would be taken from every app; only chords are registered.
+        let cancel_portal_bindable = portal_bindable_cancel(&hotkeys.cancel);
+        if cancel_portal_bindable {
+            shortcuts.push(PortalShortcut {
+                id: wayland_shortcuts::CANCEL_SHORTCUT_ID,
+                description: "Cancel the current dictation",
+                hotkey: hotkeys.cancel.clone(),
+            });
+        }
+
+        match PortalShortcuts::start(shortcuts, self.action_tx.clone()) {
+            Ok(portal) => {
+                self.portal = Some(portal);
+                self.state = Arc::new(HotkeyState::new(hotkeys.mode, audio_cues_enabled));
+                Some(HotkeyStatus {
+                    primary: hotkeys.primary.clone(),
+                    copy_last: hotkeys.copy_last.clone(),
+                    dictate_raw: hotkeys.dictate_raw.clone(),
+                    cancel: hotkeys.cancel.clone(),
+                    mode: format!("{:?}", hotkeys.mode).to_lowercase(),
+                    primary_registered: true,
+                    copy_last_registered: true,
+                    dictate_raw_registered: !hotkeys.dictate_raw.is_empty(),
+                    cancel_registered: cancel_portal_bindable,
+                    error: None,
+                    profile: None,
+                    conflicts: Vec::new(),
+                })
+            }
+            Err(error) => {
+                log::warn!(
+                    "GlobalShortcuts portal registration failed, falling back: {}",
+                    error
+                );
+                None
+            }
+        }
+    }
+
     /// Process a hotkey event from the global event channel.
     pub fn process_event(&self, event: GlobalHotKeyEvent) {
+        if event.state == HotKeyState::Pressed {
+            let pressed = [
+                self.primary_hotkey,
+                self.copy_last_hotkey,
+                self.dictate_raw_hotkey,
+                self.cancel_id.and(self.cancel_hotkey),
+            ]
+            .into_iter()
+            .flatten()
+            .find(|hotkey| hotkey.id() == event.id);
+            if let Some(hotkey) = pressed {
+                hotkey_leak::mask_modifier_release(hotkey.mods);
+            }
+        }
+
         let action = if Some(event.id) == self.primary_id {
             match event.state {
                 HotKeyState::Pressed => Some(HotkeyAction::PrimaryDown),
@@ -211,11 +407,28 @@ impl HotkeyManager {
                 HotKeyState::Pressed => Some(HotkeyAction::CopyLast),
                 HotKeyState::Released => None, // Ignore release for copy last
             }
+        } else if Some(event.id) == self.dictate_raw_id {
+            match event.state {
+                HotKeyState::Pressed => Some(HotkeyAction::DictateRawDown),
+                // Release stops a hold-mode recording exactly like the primary key.
+                HotKeyState::Released => Some(HotkeyAction::PrimaryUp),
+            }
+        } else if Some(event.id) == self.cancel_id {
+            match event.state {
+                HotKeyState::Pressed => Some(HotkeyAction::Cancel),
+                HotKeyState::Released => None,
+            }
         } else {
             None
         };
 
         if let Some(action) = action {
+            if matches!(
+                action,
+                HotkeyAction::PrimaryDown | HotkeyAction::DictateRawDown
+            ) {
+                hotkey_practice::observe_hotkey_dispatch();
+            }
             let _ = self.action_tx.try_send(action);
         }
     }
@@ -225,7 +438,10 @@ impl HotkeyManager {
     /// Returns true if recording should start.
     pub fn handle_primary_down(&self, state_manager: &AppStateManager) -> Option<RecordingAction> {
         // Check if enabled
-        if !state_manager.is_enabled() {
+        if matches!(
+            state_manager.can_start_recording_from_hotkey(),
+            Err(CannotRecordReason::Paused | CannotRecordReason::HotkeysPaused)
+        ) {
             return None; // Paused, ignore
         }
 
@@ -322,6 +538,31 @@ impl HotkeyManager {
         }
     }
 
+    /// Whether the cancel hotkey is currently registered with the OS.
+    pub fn cancel_armed(&self) -> bool {
+        self.cancel_id.is_some()
+    }
+
+    /// Register the cancel hotkey while recording and release it otherwise,
+    /// so keys like Escape keep working in other apps between dictations.
+    pub fn set_cancel_armed(&mut self, armed: bool) {
+        if armed == self.cancel_armed() {
+            return;
+        }
+        let (Some(manager), Some(hotkey)) = (&self.manager, self.cancel_hotkey) else {
+            return;
+        };
+        if armed {
+            match manager.register(hotkey) {
+                Ok(()) => self.cancel_id = Some(hotkey.id()),
+                Err(error) => log::warn!("Failed to register cancel hotkey: {}", error),
+            }
+        } else {
+            let _ = manager.unregister(hotkey);
+            self.cancel_id = None;
+        }
+    }
+
     /// Update audio cues setting.
     pub fn set_audio_cues_enabled(&self, enabled: bool) {
         self.state
@@ -331,6 +572,13 @@ impl HotkeyManager {
 
     /// Unregister hotkeys.
     pub fn shutdown(&mut self) {
+        self.release_bindings();
+    }
+
+    /// Unregister every hotkey and close the portal session.
+    fn release_bindings(&mut self) {
+        self.set_cancel_armed(false);
+        self.cancel_hotkey = None;
         let (primary_hotkey, copy_last_hotkey) = self.take_registered_hotkeys();
         if let Some(manager) = &self.manager {
             if let Some(hk) = primary_hotkey {
@@ -339,8 +587,16 @@ impl HotkeyManager {
             if let Some(hk) = copy_last_hotkey {
                 let _ = manager.unregister(hk);
             }
+            if let Some(hk) = self.dictate_raw_hotkey.take() {
+                let _ = manager.unregister(hk);
+            }
         }
+        self.dictate_raw_id = None;
         self.manager = None;
+        #[cfg(target_os = "linux")]
+        if let Some(portal) = self.portal.take() {
+            portal.close();
+        }
     }
 
     fn take_registered_hotkeys(&mut self) -> (Option<HotKey>, Option<HotKey>) {
@@ -374,6 +630,39 @@ pub enum CopyLastResult {
     ClipboardError,
 }
 
+/// Whether `hotkey` can be bound for the whole session through the Wayland
+/// portal without taking a plain key away from other apps.
+fn portal_bindable_cancel(hotkey: &str) -> bool {
+    parse_hotkey(hotkey).is_ok_and(|hk| !hk.mods.is_empty())
+}
+
+/// Bindings in `hotkeys` that resolve to the same key chord, as
+/// "primary and cancel both use Ctrl+Space". Only the first of two clashing
+/// bindings would fire.
+pub fn binding_conflicts(hotkeys: &config::HotkeyConfig) -> Vec<String> {
+    let bindings = [
+        ("primary", &hotkeys.primary),
+        ("copy_last", &hotkeys.copy_last),
+        ("dictate_raw", &hotkeys.dictate_raw),
+        ("cancel", &hotkeys.cancel),
+    ];
+    let parsed: Vec<(&str, &String, HotKey)> = bindings
+        .into_iter()
+        .filter(|(_, value)| !value.is_empty())
+        .filter_map(|(name, value)| parse_hotkey(value).ok().map(|hk| (name, value, hk)))
+        .collect();
+
+    let mut conflicts = Vec::new();
+    for (index, (name, value, hotkey)) in parsed.iter().enumerate() {
+        for (other, _, other_hotkey) in &parsed[index + 1..] {
+            if hotkey.id() == other_hotkey.id() {
+                conflicts.push(format!("{name} and {other} both use {value}"));
+            }
+        }
+    }
+    conflicts
+}
+
 /// Parse a hotkey string like "Ctrl+Shift+Space" into a HotKey.
 pub fn parse_hotkey(s: &str) -> Result<HotKey, HotkeyError> {
     let parts: Vec<&str> = s.split('+').map(|p| p.trim()).collect();
@@ -633,7 +922,13 @@ mod tests {
             mode: "hold".to_string(),
             primary_registered: true,
             copy_last_registered: true,
+            dictate_raw: String::new(),
+            dictate_raw_registered: false,
+            cancel: "Escape".to_string(),
+            cancel_registered: true,
             error: None,
+            profile: None,
+            conflicts: Vec::new(),
         };
         let json = serde_json::to_string(&status).unwrap();
         assert!(json.contains("Ctrl+Shift+Space"));
@@ -670,6 +965,8 @@ mod tests {
 
         manager.primary_id = Some(10);
         manager.copy_last_id = Some(20);
+        manager.dictate_raw_id = Some(30);
+        manager.cancel_id = Some(40);
 
         manager.process_event(GlobalHotKeyEvent {
             id: 10,
@@ -687,10 +984,73 @@ mod tests {
             id: 20,
             state: HotKeyState::Released,
         });
+        manager.process_event(GlobalHotKeyEvent {
+            id: 30,
+            state: HotKeyState::Pressed,
+        });
+        manager.process_event(GlobalHotKeyEvent {
+            id: 30,
+            state: HotKeyState::Released,
+        });
+        manager.process_event(GlobalHotKeyEvent {
+            id: 40,
+            state: HotKeyState::Pressed,
+        });
+        manager.process_event(GlobalHotKeyEvent {
+            id: 40,
+            state: HotKeyState::Released,
+        });
 
         assert!(matches!(rx.try_recv(), Ok(HotkeyAction::PrimaryDown)));
         assert!(matches!(rx.try_recv(), Ok(HotkeyAction::PrimaryUp)));
         assert!(matches!(rx.try_recv(), Ok(HotkeyAction::CopyLast)));
+        assert!(matches!(rx.try_recv(), Ok(HotkeyAction::DictateRawDown)));
+        assert!(matches!(rx.try_recv(), Ok(HotkeyAction::PrimaryUp)));
+        assert!(matches!(rx.try_recv(), Ok(HotkeyAction::Cancel)));
         assert!(rx.try_recv().is_err());
     }
+
+    #[test]
+    fn test_cancel_hotkey_is_not_armed_without_registration() {
+        let mut manager = HotkeyManager::new();
+        manager.cancel_hotkey = Some(parse_hotkey("Escape").unwrap());
+        assert!(!manager.cancel_armed());
+
+        // Without an OS manager there is nothing to register with.
+        manager.set_cancel_armed(true);
+        assert!(!manager.cancel_armed());
+    }
+
+    #[test]
+    fn test_portal_only_binds_cancel_chords() {
+        assert!(!portal_bindable_cancel("Escape"));
+        assert!(!portal_bindable_cancel(""));
+        assert!(portal_bindable_cancel("Ctrl+Shift+Escape"));
+    }
+
+    #[test]
+    fn test_binding_conflicts_reports_shared_chords() {
+        let mut hotkeys = config::HotkeyConfig::default();
+        assert!(binding_conflicts(&hotkeys).is_empty());
+
+        hotkeys.dictate_raw = "ctrl+shift+space".to_string();
+        hotkeys.cancel = String::new();
+        assert_eq!(
+            binding_conflicts(&hotkeys),
+            vec!["primary and dictate_raw both use Ctrl+Shift+Space"]
+        );
+    }
+
+    #[test]
+    fn test_release_bindings_keeps_held_key_state() {
+        let mut manager = HotkeyManager::new();
+        manager.primary_id = Some(1);
+        manager.cancel_hotkey = Some(parse_hotkey("Escape").unwrap());
+        manager.state.key_is_down.store(true, Ordering::SeqCst);
+
+        manager.release_bindings();
+        assert!(manager.primary_id.is_none());
+        assert!(manager.cancel_hotkey.is_none());
+        assert!(manager.state.key_is_down.load(Ordering::SeqCst));
+    }
 }
@@ -0,0 +1,292 @@
+//! Keeping hotkey keystrokes out of the focused app, and detecting when they leak.
+//!
+//! The OS-level registration (`RegisterHotKey` on Windows, Carbon hot keys on
+//! macOS, `XGrabKey` on X11, the GlobalShortcuts portal on Wayland) already
+//! consumes the chord itself. What still leaks on Windows is the modifier: a
+//! lone Alt or Win release after the chord opens the window menu or the Start
+//! menu, so a masking keystroke is sent while the chord is held. On Wayland
+//! without the portal the X11 grab only covers XWayland windows, and native
+//! Wayland apps receive the keys.
+//!
+//! To detect leakage, the hotkey settings arm a leak test and ask the user to
+//! press the recording hotkey with the settings window focused. The press is
+//! swallowed instead of starting a recording; if the webview also receives
+//! the keydown, the keystroke reached the focused app.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use global_hotkey::hotkey::Modifiers;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+/// How long an armed leak test waits for the hotkey press.
+const LEAK_TEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long after the hotkey fires the webview may still report the keydown.
+const LEAK_REPORT_GRACE: Duration = Duration::from_millis(300);
+
+const LEAK_TEST_POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+/// Unassigned virtual key used to mask modifier releases (as AutoHotkey does).
+#[cfg(target_os = "windows")]
+const MASK_VKEY: u8 = 0xE8;
+
+#[cfg(target_os = "windows")]
+const KEYEVENTF_KEYUP: u32 = 0x0002;
+
+static LEAK_PROBE: Lazy<Mutex<LeakProbe>> = Lazy::new(|| Mutex::new(LeakProbe::default()));
+
+/// How the hotkey chord is kept from reaching the focused app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuppressionMethod {
+    /// The OS hotkey registration consumes the chord.
+    OsGrab,
+    /// OS registration plus a masking keystroke for the Alt/Win release.
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    OsGrabWithModifierMask,
+    /// The Wayland GlobalShortcuts portal consumes the chord.
+    Portal,
+    /// The chord is only grabbed in some windows and may reach the focused app.
+    Unsuppressed,
+}
+
+/// Result of a hotkey leak test.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HotkeyLeakReport {
+    pub hotkey: String,
+    /// Whether the global hotkey fired during the test.
+    pub hotkey_detected: bool,
+    /// Whether the keystroke also reached the focused settings window.
+    pub leaked: bool,
+    pub suppression: SuppressionMethod,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
+/// State of the leak test in progress, if any.
+#[derive(Debug, Default)]
+struct LeakProbe {
+    armed_at: Option<Instant>,
+    hotkey_seen: bool,
+    keydown_seen: bool,
+}
+
+impl LeakProbe {
+    fn arm(&mut self, now: Instant) {
+        *self = Self {
+            armed_at: Some(now),
+            ..Self::default()
+        };
+    }
+
+    fn is_armed(&self, now: Instant) -> bool {
+        self.armed_at.is_some_and(|armed_at| {
+            now.saturating_duration_since(armed_at) < LEAK_TEST_TIMEOUT + LEAK_REPORT_GRACE
+        })
+    }
+
+    /// Record a hotkey press; true if it belongs to the test and must not act.
+    fn observe_hotkey(&mut self, now: Instant) -> bool {
+        let armed = self.is_armed(now);
+        if armed {
+            self.hotkey_seen = true;
+        }
+        armed
+    }
+
+    fn observe_keydown(&mut self, now: Instant) {
+        if self.is_armed(now) {
+            self.keydown_seen = true;
+        }
+    }
+
+    /// Disarm and return `(hotkey_seen, keydown_seen)`.
+    fn finish(&mut self) -> (bool, bool) {
+        let outcome = (self.hotkey_seen, self.keydown_seen);
+        *self = Self::default();
+        outcome
+    }
+}
+
+fn probe() -> std::sync::MutexGuard<'static, LeakProbe> {
+    LEAK_PROBE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Record a recording-hotkey press. Returns true while a leak test is armed,
+/// in which case the press must not start or stop a recording.
+pub fn observe_hotkey_press() -> bool {
+    probe().observe_hotkey(Instant::now())
+}
+
+/// Record that the settings webview received the hotkey's keydown.
+pub fn observe_webview_keydown() {
+    probe().observe_keydown(Instant::now());
+}
+
+/// Arm a leak test, wait for the hotkey press, and report whether it leaked.
+pub async fn run_leak_test(hotkey: &str, mods: Modifiers) -> HotkeyLeakReport {
+    probe().arm(Instant::now());
+
+    let deadline = Instant::now() + LEAK_TEST_TIMEOUT;
+    while Instant::now() < deadline && !probe().hotkey_seen {
+        tokio::time::sleep(LEAK_TEST_POLL_INTERVAL).await;
+    }
+    // The webview's keydown can arrive after the global hotkey event.
+    tokio::time::sleep(LEAK_REPORT_GRACE).await;
+
+    let (hotkey_detected, leaked) = probe().finish();
+    let suppression = suppression_method(mods);
+    if leaked {
+        log::warn!(
+            "Hotkey {} leaked into the focused app (suppression: {:?})",
+            hotkey,
+            suppression
+        );
+    }
+    HotkeyLeakReport {
+        hotkey: hotkey.to_string(),
+        hotkey_detected,
+        leaked,
+        suppression,
+        hint: leak_hint(hotkey_detected, leaked, suppression),
+    }
+}
+
+/// How this platform keeps a hotkey with `mods` from reaching the focused app.
+pub fn suppression_method(mods: Modifiers) -> SuppressionMethod {
+    #[cfg(target_os = "windows")]
+    {
+        if needs_modifier_mask(mods) {
+            SuppressionMethod::OsGrabWithModifierMask
+        } else {
+            SuppressionMethod::OsGrab
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let _ = mods;
+        if !crate::capabilities::is_wayland_session() {
+            SuppressionMethod::OsGrab
+        } else if crate::capabilities::wayland_portal_available() {
+            SuppressionMethod::Portal
+        } else {
+            SuppressionMethod::Unsuppressed
+        }
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    {
+        let _ = mods;
+        SuppressionMethod::OsGrab
+    }
+}
+
+/// Advice for the user when the test did not pass cleanly.
+fn leak_hint(
+    hotkey_detected: bool,
+    leaked: bool,
+    suppression: SuppressionMethod,
+) -> Option<String> {
+    let hint = match (hotkey_detected, leaked) {
+        (true, false) => return None,
+        (false, false) => "The hotkey was not pressed during the test.",
+        (false, true) => {
+            "The hotkey is not registered, so the keys go to the focused app. \
+             Another app may already use this shortcut; choose a different one."
+        }
+        (true, true) if suppression == SuppressionMethod::Unsuppressed => {
+            "Hotkeys are only grabbed in XWayland windows on this desktop. \
+             Enable the GlobalShortcuts portal or use a shortcut apps ignore."
+        }
+        (true, true) => {
+            "The shortcut also reached the focused app. \
+             Choose a combination with Ctrl or Alt that apps do not type."
+        }
+    };
+    Some(hint.to_string())
+}
+
+/// Whether releasing this chord would leave a lone Alt/Win release that
+/// Windows turns into menu or Start menu activation.
+#[cfg(any(target_os = "windows", test))]
+fn needs_modifier_mask(mods: Modifiers) -> bool {
+    mods.intersects(Modifiers::ALT | Modifiers::META | Modifiers::SUPER)
+}
+
+/// Send a masking keystroke while a hotkey chord with `mods` is held, so the
+/// modifier release is not taken as a menu or Start menu shortcut.
+#[cfg(target_os = "windows")]
+pub fn mask_modifier_release(mods: Modifiers) {
+    if !needs_modifier_mask(mods) {
+        return;
+    }
+    unsafe {
+        keybd_event(MASK_VKEY, 0, 0, 0);
+        keybd_event(MASK_VKEY, 0, KEYEVENTF_KEYUP, 0);
+    }
+}
+
+/// Other platforms' hotkey registrations consume modifiers too.
+#[cfg(not(target_os = "windows"))]
+pub fn mask_modifier_release(_mods: Modifiers) {}
+
+#[cfg(target_os = "windows")]
+#[link(name = "user32")]
+unsafe extern "system" {
+    fn keybd_event(vkey: u8, scan: u8, flags: u32, extra_info: usize);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probe_swallows_presses_only_while_armed() {
+        let now = Instant::now();
+        let mut probe = LeakProbe::default();
+        assert!(!probe.observe_hotkey(now));
+
+        probe.arm(now);
+        assert!(probe.observe_hotkey(now + Duration::from_secs(1)));
+        probe.observe_keydown(now + Duration::from_secs(1));
+        assert_eq!(probe.finish(), (true, true));
+
+        // Finishing disarms the probe.
+        assert!(!probe.observe_hotkey(now + Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_probe_expires_after_timeout() {
+        let now = Instant::now();
+        let mut probe = LeakProbe::default();
+        probe.arm(now);
+        let late = now + LEAK_TEST_TIMEOUT + LEAK_REPORT_GRACE;
+        assert!(!probe.observe_hotkey(late));
+        probe.observe_keydown(late);
+        assert_eq!(probe.finish(), (false, false));
+    }
+
+    #[test]
+    fn test_needs_modifier_mask_for_alt_and_win() {
+        assert!(needs_modifier_mask(Modifiers::ALT | Modifiers::SHIFT));
+        assert!(needs_modifier_mask(Modifiers::META));
+        assert!(!needs_modifier_mask(Modifiers::CONTROL | Modifiers::SHIFT));
+    }
+
+    #[test]
+    fn test_leak_hint_only_when_test_did_not_pass() {
+        assert_eq!(leak_hint(true, false, SuppressionMethod::OsGrab), None);
+        assert!(leak_hint(false, false, SuppressionMethod::OsGrab)
+            .unwrap()
+            .contains("not pressed"));
+        assert!(leak_hint(true, true, SuppressionMethod::Unsuppressed)
+            .unwrap()
+            .contains("XWayland"));
+        assert!(leak_hint(false, true, SuppressionMethod::OsGrab)
+            .unwrap()
+            .contains("not registered"));
+    }
+}
@@ -0,0 +1,320 @@
+//! Hotkey practice mode for onboarding.
+//!
+//! New users confirm their recording hotkey works before their first real
+//! dictation. While a practice run is armed, recording-hotkey presses and
+//! releases are swallowed instead of starting or stopping a recording; the
+//! run records them, checks they match the configured hold or toggle mode,
+//! and measures how long a press takes to get from the hotkey event to the
+//! point where a recording would start.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+use crate::config::HotkeyMode;
+
+/// How long an armed practice run waits for the user to finish.
+const PRACTICE_TIMEOUT: Duration = Duration::from_secs(15);
+
+const PRACTICE_POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+/// Shorter holds are taps: in hold mode they would record nothing useful.
+const MIN_HOLD: Duration = Duration::from_millis(200);
+
+/// Press-to-start latency above this is reported as sluggish.
+const SLOW_LATENCY: Duration = Duration::from_millis(150);
+
+static PRACTICE_PROBE: Lazy<Mutex<PracticeProbe>> =
+    Lazy::new(|| Mutex::new(PracticeProbe::default()));
+
+/// Result of a hotkey practice run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HotkeyPracticeReport {
+    pub hotkey: String,
+    pub mode: HotkeyMode,
+    /// Whether the presses and releases matched what `mode` needs.
+    pub passed: bool,
+    pub presses: u32,
+    pub releases: u32,
+    /// How long the first press was held, when its release was seen.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hold_ms: Option<u64>,
+    /// Time from the first press's hotkey event to the point where a
+    /// recording would start.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
+/// State of the practice run in progress, if any.
+#[derive(Debug, Default)]
+struct PracticeProbe {
+    armed_at: Option<Instant>,
+    mode: HotkeyMode,
+    /// When the pending press's hotkey event reached the app.
+    dispatched_at: Option<Instant>,
+    held: bool,
+    presses: u32,
+    releases: u32,
+    first_press_at: Option<Instant>,
+    first_release_at: Option<Instant>,
+    latency: Option<Duration>,
+}
+
+impl PracticeProbe {
+    fn arm(&mut self, mode: HotkeyMode, now: Instant) {
+        *self = Self {
+            armed_at: Some(now),
+            mode,
+            ..Self::default()
+        };
+    }
+
+    fn is_armed(&self, now: Instant) -> bool {
+        self.armed_at
+            .is_some_and(|armed_at| now.saturating_duration_since(armed_at) < PRACTICE_TIMEOUT)
+    }
+
+    fn observe_dispatch(&mut self, now: Instant) {
+        if self.is_armed(now) && self.dispatched_at.is_none() {
+            self.dispatched_at = Some(now);
+        }
+    }
+
+    /// Record a press; true if it belongs to the run and must not act.
+    fn observe_press(&mut self, now: Instant) -> bool {
+        if !self.is_armed(now) {
+            return false;
+        }
+        let dispatched_at = self.dispatched_at.take();
+        if self.held {
+            // Key auto-repeat while the hotkey is held down.
+            return true;
+        }
+        self.held = true;
+        self.presses += 1;
+        if self.first_press_at.is_none() {
+            self.first_press_at = Some(now);
+            self.latency = dispatched_at.map(|at| now.saturating_duration_since(at));
+        }
+        true
+    }
+
+    /// Record a release; true if it belongs to the run and must not act.
+    fn observe_release(&mut self, now: Instant) -> bool {
+        if !self.is_armed(now) {
+            return false;
+        }
+        if self.held {
+            self.held = false;
+            self.releases += 1;
+            if self.first_release_at.is_none() {
+                self.first_release_at = Some(now);
+            }
+        }
+        true
+    }
+
+    /// Whether the user has done everything the mode needs.
+    fn is_complete(&self) -> bool {
+        match self.mode {
+            HotkeyMode::Hold => self.releases >= 1,
+            HotkeyMode::Toggle => self.presses >= 2,
+        }
+    }
+
+    /// Disarm and build the report.
+    fn finish(&mut self, hotkey: &str) -> HotkeyPracticeReport {
+        let hold = self
+            .first_press_at
+            .zip(self.first_release_at)
+            .map(|(pressed, released)| released.saturating_duration_since(pressed));
+        let (passed, hint) = evaluate(self.mode, self.presses, self.releases, hold, self.latency);
+        let report = HotkeyPracticeReport {
+            hotkey: hotkey.to_string(),
+            mode: self.mode,
+            passed,
+            presses: self.presses,
+            releases: self.releases,
+            hold_ms: hold.map(|hold| hold.as_millis() as u64),
+            latency_ms: self.latency.map(|latency| latency.as_millis() as u64),
+            hint,
+        };
+        *self = Self::default();
+        report
+    }
+}
+
+fn probe() -> std::sync::MutexGuard<'static, PracticeProbe> {
+    PRACTICE_PROBE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Note that a recording-hotkey press event reached the app, for the
+/// press-to-start latency of a practice run.
+pub fn observe_hotkey_dispatch() {
+    probe().observe_dispatch(Instant::now());
+}
+
+/// Record a recording-hotkey press. Returns true while a practice run is
+/// armed, in which case the press must not start or stop a recording.
+pub fn observe_hotkey_press() -> bool {
+    probe().observe_press(Instant::now())
+}
+
+/// Record a recording-hotkey release. Returns true while a practice run is
+/// armed, in which case the release must not stop a recording.
+pub fn observe_hotkey_release() -> bool {
+    probe().observe_release(Instant::now())
+}
+
+/// Arm a practice run for `mode`, wait for the user to use the hotkey, and
+/// report whether it behaved as that mode expects.
+pub async fn run_practice(hotkey: &str, mode: HotkeyMode) -> HotkeyPracticeReport {
+    probe().arm(mode, Instant::now());
+
+    let deadline = Instant::now() + PRACTICE_TIMEOUT;
+    while Instant::now() < deadline && !probe().is_complete() {
+        tokio::time::sleep(PRACTICE_POLL_INTERVAL).await;
+    }
+
+    let report = probe().finish(hotkey);
+    log::info!(
+        "Hotkey practice for {} ({:?}): passed={}, presses={}, releases={}, latency={:?}ms",
+        hotkey,
+        mode,
+        report.passed,
+        report.presses,
+        report.releases,
+        report.latency_ms
+    );
+    report
+}
+
+/// Whether a run passed, and advice for the user when it did not (or when
+/// the hotkey is slow to respond).
+fn evaluate(
+    mode: HotkeyMode,
+    presses: u32,
+    releases: u32,
+    hold: Option<Duration>,
+    latency: Option<Duration>,
+) -> (bool, Option<String>) {
+    if presses == 0 {
+        return (
+            false,
+            Some(
+                "The hotkey was not detected. Another app may already use this shortcut; \
+                 choose a different one."
+                    .to_string(),
+            ),
+        );
+    }
+    let failure = match mode {
+        HotkeyMode::Hold if releases == 0 => Some(
+            "The hotkey release was not detected, so holding it would never stop a \
+             recording. Switch to toggle mode.",
+        ),
+        HotkeyMode::Hold if hold.is_some_and(|hold| hold < MIN_HOLD) => Some(
+            "The hotkey was tapped. In hold mode, keep it held down while you speak, \
+             or switch to toggle mode.",
+        ),
+        HotkeyMode::Toggle if presses < 2 => {
+            Some("Press the hotkey a second time to stop the recording.")
+        }
+        _ => None,
+    };
+    if let Some(failure) = failure {
+        return (false, Some(failure.to_string()));
+    }
+    let hint = latency
+        .filter(|latency| *latency > SLOW_LATENCY)
+        .map(|latency| {
+            format!(
+                "The hotkey took {} ms to respond. The first words of a dictation may be cut off; \
+             wait a moment after pressing it before you speak.",
+                latency.as_millis()
+            )
+        });
+    (true, hint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hold_practice_records_hold_and_latency() {
+        let now = Instant::now();
+        let mut probe = PracticeProbe::default();
+        assert!(!probe.observe_press(now));
+
+        probe.arm(HotkeyMode::Hold, now);
+        probe.observe_dispatch(now + Duration::from_millis(100));
+        assert!(probe.observe_press(now + Duration::from_millis(130)));
+        // Auto-repeat presses while held are swallowed but not counted.
+        assert!(probe.observe_press(now + Duration::from_millis(400)));
+        assert!(!probe.is_complete());
+        assert!(probe.observe_release(now + Duration::from_millis(900)));
+        assert!(probe.is_complete());
+
+        let report = probe.finish("Ctrl+Shift+Space");
+        assert!(report.passed);
+        assert_eq!((report.presses, report.releases), (1, 1));
+        assert_eq!(report.hold_ms, Some(770));
+        assert_eq!(report.latency_ms, Some(30));
+        assert_eq!(report.hint, None);
+
+        // Finishing disarms the probe.
+        assert!(!probe.observe_release(now + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_toggle_practice_needs_two_presses() {
+        let now = Instant::now();
+        let mut probe = PracticeProbe::default();
+        probe.arm(HotkeyMode::Toggle, now);
+        probe.observe_press(now + Duration::from_millis(100));
+        probe.observe_release(now + Duration::from_millis(150));
+        assert!(!probe.is_complete());
+        probe.observe_press(now + Duration::from_secs(2));
+        assert!(probe.is_complete());
+        assert!(probe.finish("F9").passed);
+    }
+
+    #[test]
+    fn test_probe_expires_after_timeout() {
+        let now = Instant::now();
+        let mut probe = PracticeProbe::default();
+        probe.arm(HotkeyMode::Hold, now);
+        assert!(!probe.observe_press(now + PRACTICE_TIMEOUT));
+        assert_eq!(probe.finish("F9").presses, 0);
+    }
+
+    #[test]
+    fn test_evaluate_explains_mode_mismatches() {
+        let (passed, hint) = evaluate(HotkeyMode::Hold, 0, 0, None, None);
+        assert!(!passed);
+        assert!(hint.unwrap().contains("not detected"));
+
+        let (passed, hint) = evaluate(HotkeyMode::Hold, 1, 0, None, None);
+        assert!(!passed);
+        assert!(hint.unwrap().contains("toggle mode"));
+
+        let tap = Some(Duration::from_millis(50));
+        let (passed, hint) = evaluate(HotkeyMode::Hold, 1, 1, tap, None);
+        assert!(!passed);
+        assert!(hint.unwrap().contains("tapped"));
+
+        let (passed, hint) = evaluate(HotkeyMode::Toggle, 1, 1, tap, None);
+        assert!(!passed);
+        assert!(hint.unwrap().contains("second time"));
+
+        let slow = Some(Duration::from_millis(400));
+        let (passed, hint) = evaluate(HotkeyMode::Toggle, 2, 1, tap, slow);
+        assert!(passed);
+        assert!(hint.unwrap().contains("400 ms"));
+    }
+}
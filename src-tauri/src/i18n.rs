@@ -0,0 +1,346 @@
+//! Localization catalog for user-facing strings rendered by the host.
+//!
+//! Strings are looked up by key in a per-language table; anything missing
+//! from a translation falls back to English, and an unknown key falls back
+//! to the key itself so a gap is visible rather than blank. The UI language
+//! comes from `ui.locale`, or the system locale when unset.
+
+use once_cell::sync::Lazy;
+use phf::phf_map;
+
+/// Language used when no translation is available.
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+static EN: phf::Map<&'static str, &'static str> = phf_map! {
+    "tray.tooltip.paused" => "OpenVoicy - Paused",
+    "tray.tooltip.hotkeys_paused" => "OpenVoicy - Hotkeys Paused",
+    "tray.tooltip.stealth" => "OpenVoicy - Stealth",
+    "tray.tooltip.ready" => "OpenVoicy - Ready",
+    "tray.tooltip.recording" => "OpenVoicy - Recording...",
+    "tray.tooltip.processing" => "OpenVoicy - Processing...",
+    "tray.tooltip.loading_model" => "OpenVoicy - Loading model...",
+    "tray.tooltip.error" => "OpenVoicy - Error",
+    "tray.enable" => "Enable OpenVoicy",
+    "tray.disable" => "Disable OpenVoicy",
+    "tray.enable_level" => "Pause Mode",
+    "tray.enable_level.enabled" => "Active",
+    "tray.enable_level.hotkeys_paused" => "Pause Hotkeys Only",
+    "tray.enable_level.fully_paused" => "Pause Completely",
+    "tray.enable_level.stealth" => "Stealth (No Indicators)",
+    "tray.processing" => "Processing...",
+    "tray.start_recording" => "Start Recording",
+    "tray.start_recording_with_hotkey" => "Start Recording ({hotkey})",
+    "tray.stop_recording" => "Stop Recording",
+    "tray.stop_recording_with_hotkey" => "Stop Recording ({hotkey})",
+    "tray.cancel_recording" => "Cancel Recording",
+    "tray.pending_insert" => "Pending Insert ({count})",
+    "tray.mode" => "Mode: {mode}",
+    "tray.mode.hold" => "Hold",
+    "tray.mode.toggle" => "Toggle",
+    "tray.language" => "Language: {language}",
+    "tray.language.auto" => "Language: Auto",
+    "tray.mic" => "Mic: {device}",
+    "tray.mic.default" => "Mic: System Default",
+    "tray.mic.empty" => "No microphone devices",
+    "tray.output" => "Output: {mode}",
+    "tray.output.inject" => "Paste into App",
+    "tray.output.clipboard_only" => "Clipboard Only",
+    "tray.output.ask" => "Ask Each Time",
+    "tray.recent" => "Recent",
+    "tray.recent.empty" => "No recent transcripts",
+    "tray.stats.today" => "Today: {words} words, {minutes} min audio",
+    "number.group_separator" => ",",
+    "tray.show_overlay" => "Show Overlay",
+    "tray.model" => "Model: {status}",
+    "tray.sidecar" => "Sidecar: {status}",
+    "tray.status.ready" => "ready",
+    "tray.status.loading" => "loading",
+    "tray.status.paused" => "paused",
+    "tray.status.error" => "error",
+    "tray.status.failed" => "failed",
+    "tray.show_window" => "Show Window",
+    "tray.hide_window" => "Hide Window",
+    "tray.quit" => "Quit",
+};
+
+static DE: phf::Map<&'static str, &'static str> = phf_map! {
+    "tray.tooltip.paused" => "OpenVoicy - Pausiert",
+    "tray.tooltip.hotkeys_paused" => "OpenVoicy - Tastenkürzel pausiert",
+    "tray.tooltip.stealth" => "OpenVoicy - Tarnmodus",
+    "tray.tooltip.ready" => "OpenVoicy - Bereit",
+    "tray.tooltip.recording" => "OpenVoicy - Aufnahme...",
+    "tray.tooltip.processing" => "OpenVoicy - Verarbeitung...",
+    "tray.tooltip.loading_model" => "OpenVoicy - Modell wird geladen...",
+    "tray.tooltip.error" => "OpenVoicy - Fehler",
+    "tray.enable" => "OpenVoicy aktivieren",
+    "tray.disable" => "OpenVoicy deaktivieren",
+    "tray.enable_level" => "Pausenmodus",
+    "tray.enable_level.enabled" => "Aktiv",
+    "tray.enable_level.hotkeys_paused" => "Nur Tastenkürzel pausieren",
+    "tray.enable_level.fully_paused" => "Vollständig pausieren",
+    "tray.enable_level.stealth" => "Tarnmodus (keine Anzeigen)",
+    "tray.processing" => "Verarbeitung...",
+    "tray.start_recording" => "Aufnahme starten",
+    "tray.start_recording_with_hotkey" => "Aufnahme starten ({hotkey})",
+    "tray.stop_recording" => "Aufnahme beenden",
+    "tray.stop_recording_with_hotkey" => "Aufnahme beenden ({hotkey})",
+    "tray.cancel_recording" => "Aufnahme abbrechen",
+    "tray.pending_insert" => "Ausstehende Einfügungen ({count})",
+    "tray.mode" => "Modus: {mode}",
+    "tray.mode.hold" => "Halten",
+    "tray.mode.toggle" => "Umschalten",
+    "tray.language" => "Sprache: {language}",
+    "tray.language.auto" => "Sprache: Automatisch",
+    "tray.mic" => "Mikrofon: {device}",
+    "tray.mic.default" => "Mikrofon: Systemstandard",
+    "tray.mic.empty" => "Keine Mikrofone gefunden",
+    "tray.output" => "Ausgabe: {mode}",
+    "tray.output.inject" => "In App einfügen",
+    "tray.output.clipboard_only" => "Nur Zwischenablage",
+    "tray.output.ask" => "Jedes Mal fragen",
+    "tray.recent" => "Zuletzt",
+    "tray.recent.empty" => "Keine letzten Transkripte",
+    "tray.stats.today" => "Heute: {words} Wörter, {minutes} Min. Audio",
+    "number.group_separator" => ".",
+    "tray.show_overlay" => "Overlay anzeigen",
+    "tray.model" => "Modell: {status}",
+    "tray.sidecar" => "Sidecar: {status}",
+    "tray.status.ready" => "bereit",
+    "tray.status.loading" => "lädt",
+    "tray.status.paused" => "pausiert",
+    "tray.status.error" => "Fehler",
+    "tray.status.failed" => "fehlgeschlagen",
+    "tray.show_window" => "Fenster anzeigen",
+    "tray.hide_window" => "Fenster ausblenden",
+    "tray.quit" => "Beenden",
+};
+
+static ES: phf::Map<&'static str, &'static str> = phf_map! {
+    "tray.tooltip.paused" => "OpenVoicy - En pausa",
+    "tray.tooltip.hotkeys_paused" => "OpenVoicy - Atajos en pausa",
+    "tray.tooltip.stealth" => "OpenVoicy - Modo discreto",
+    "tray.tooltip.ready" => "OpenVoicy - Listo",
+    "tray.tooltip.recording" => "OpenVoicy - Grabando...",
+    "tray.tooltip.processing" => "OpenVoicy - Procesando...",
+    "tray.tooltip.loading_model" => "OpenVoicy - Cargando modelo...",
+    "tray.tooltip.error" => "OpenVoicy - Error",
+    "tray.enable" => "Activar OpenVoicy",
+    "tray.disable" => "Desactivar OpenVoicy",
+    "tray.enable_level" => "Modo de pausa",
+    "tray.enable_level.enabled" => "Activo",
+    "tray.enable_level.hotkeys_paused" => "Pausar solo los atajos",
+    "tray.enable_level.fully_paused" => "Pausar por completo",
+    "tray.enable_level.stealth" => "Discreto (sin indicadores)",
+    "tray.processing" => "Procesando...",
+    "tray.start_recording" => "Iniciar grabación",
+    "tray.start_recording_with_hotkey" => "Iniciar grabación ({hotkey})",
+    "tray.stop_recording" => "Detener grabación",
+    "tray.stop_recording_with_hotkey" => "Detener grabación ({hotkey})",
+    "tray.cancel_recording" => "Cancelar grabación",
+    "tray.pending_insert" => "Inserciones pendientes ({count})",
+    "tray.mode" => "Modo: {mode}",
+    "tray.mode.hold" => "Mantener",
+    "tray.mode.toggle" => "Alternar",
+    "tray.language" => "Idioma: {language}",
+    "tray.language.auto" => "Idioma: Automático",
+    "tray.mic" => "Micrófono: {device}",
+    "tray.mic.default" => "Micrófono: Predeterminado del sistema",
+    "tray.mic.empty" => "No hay micrófonos",
+    "tray.output" => "Salida: {mode}",
+    "tray.output.inject" => "Pegar en la aplicación",
+    "tray.output.clipboard_only" => "Solo portapapeles",
+    "tray.output.ask" => "Preguntar cada vez",
+    "tray.recent" => "Recientes",
+    "tray.recent.empty" => "No hay transcripciones recientes",
+    "tray.stats.today" => "Hoy: {words} palabras, {minutes} min de audio",
+    "number.group_separator" => ".",
+    "tray.show_overlay" => "Mostrar superposición",
+    "tray.model" => "Modelo: {status}",
+    "tray.sidecar" => "Sidecar: {status}",
+    "tray.status.ready" => "listo",
+    "tray.status.loading" => "cargando",
+    "tray.status.paused" => "en pausa",
+    "tray.status.error" => "error",
+    "tray.status.failed" => "falló",
+    "tray.show_window" => "Mostrar ventana",
+    "tray.hide_window" => "Ocultar ventana",
+    "tray.quit" => "Salir",
+};
+
+static FR: phf::Map<&'static str, &'static str> = phf_map! {
+    "tray.tooltip.paused" => "OpenVoicy - En pause",
+    "tray.tooltip.hotkeys_paused" => "OpenVoicy - Raccourcis en pause",
+    "tray.tooltip.stealth" => "OpenVoicy - Mode discret",
+    "tray.tooltip.ready" => "OpenVoicy - Prêt",
+    "tray.tooltip.recording" => "OpenVoicy - Enregistrement...",
+    "tray.tooltip.processing" => "OpenVoicy - Traitement...",
+    "tray.tooltip.loading_model" => "OpenVoicy - Chargement du modèle...",
+    "tray.tooltip.error" => "OpenVoicy - Erreur",
+    "tray.enable" => "Activer OpenVoicy",
+    "tray.disable" => "Désactiver OpenVoicy",
+    "tray.enable_level" => "Mode pause",
+    "tray.enable_level.enabled" => "Actif",
+    "tray.enable_level.hotkeys_paused" => "Mettre en pause les raccourcis",
+    "tray.enable_level.fully_paused" => "Mettre entièrement en pause",
+    "tray.enable_level.stealth" => "Discret (sans indicateurs)",
+    "tray.processing" => "Traitement...",
+    "tray.start_recording" => "Démarrer l'enregistrement",
+    "tray.start_recording_with_hotkey" => "Démarrer l'enregistrement ({hotkey})",
+    "tray.stop_recording" => "Arrêter l'enregistrement",
+    "tray.stop_recording_with_hotkey" => "Arrêter l'enregistrement ({hotkey})",
+    "tray.cancel_recording" => "Annuler l'enregistrement",
+    "tray.pending_insert" => "Insertions en attente ({count})",
+    "tray.mode" => "Mode : {mode}",
+    "tray.mode.hold" => "Maintenir",
+    "tray.mode.toggle" => "Basculer",
+    "tray.language" => "Langue : {language}",
+    "tray.language.auto" => "Langue : Automatique",
+    "tray.mic" => "Micro : {device}",
+    "tray.mic.default" => "Micro : Par défaut du système",
+    "tray.mic.empty" => "Aucun micro",
+    "tray.output" => "Sortie : {mode}",
+    "tray.output.inject" => "Coller dans l'application",
+    "tray.output.clipboard_only" => "Presse-papiers uniquement",
+    "tray.output.ask" => "Demander à chaque fois",
+    "tray.recent" => "Récents",
+    "tray.recent.empty" => "Aucune transcription récente",
+    "tray.stats.today" => "Aujourd'hui : {words} mots, {minutes} min d'audio",
+    "number.group_separator" => "\u{202f}",
+    "tray.show_overlay" => "Afficher la superposition",
+    "tray.model" => "Modèle : {status}",
+    "tray.sidecar" => "Sidecar : {status}",
+    "tray.status.ready" => "prêt",
+    "tray.status.loading" => "chargement",
+    "tray.status.paused" => "en pause",
+    "tray.status.error" => "erreur",
+    "tray.status.failed" => "échec",
+    "tray.show_window" => "Afficher la fenêtre",
+    "tray.hide_window" => "Masquer la fenêtre",
+    "tray.quit" => "Quitter",
+};
+
+static CATALOG: phf::Map<&'static str, &'static phf::Map<&'static str, &'static str>> = phf_map! {
+    "en" => &EN,
+    "de" => &DE,
+    "es" => &ES,
+    "fr" => &FR,
+};
+
+/// System locale, read once; GUI launches do not change it mid-session.
+static SYSTEM_LANGUAGE: Lazy<&'static str> = Lazy::new(|| {
+    system_locale()
+        .and_then(|locale| supported_language(&locale))
+        .unwrap_or(DEFAULT_LANGUAGE)
+});
+
+/// Resolve the UI language from the configured locale (e.g. "de-DE"),
+/// falling back to the system locale and then English.
+pub fn resolve_language(configured: Option<&str>) -> &'static str {
+    match configured
+        .map(str::trim)
+        .filter(|locale| !locale.is_empty())
+    {
+        Some(locale) => supported_language(locale).unwrap_or(DEFAULT_LANGUAGE),
+        None => *SYSTEM_LANGUAGE,
+    }
+}
+
+/// Look up `key` for `language`.
+pub fn text(language: &str, key: &'static str) -> &'static str {
+    CATALOG
+        .get(language)
+        .and_then(|strings| strings.get(key))
+        .or_else(|| EN.get(key))
+        .copied()
+        .unwrap_or(key)
+}
+
+/// Look up `key` and substitute `{name}` placeholders.
+pub fn format_text(language: &str, key: &'static str, args: &[(&str, &str)]) -> String {
+    args.iter()
+        .fold(text(language, key).to_string(), |out, (name, value)| {
+            out.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+/// Map a locale such as "pt_BR.UTF-8" or "fr-CA" to a catalog language.
+fn supported_language(locale: &str) -> Option<&'static str> {
+    let language = locale
+        .split(['-', '_', '.', '@'])
+        .next()?
+        .to_ascii_lowercase();
+    CATALOG.get_key(language.as_str()).copied()
+}
+
+#[cfg(target_os = "macos")]
+fn system_locale() -> Option<String> {
+    let output = std::process::Command::new("defaults")
+        .args(["read", "-g", "AppleLocale"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(target_os = "windows")]
+fn system_locale() -> Option<String> {
+    let output = std::process::Command::new("powershell")
+        .args(["-NoProfile", "-Command", "(Get-Culture).Name"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn system_locale() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty() && value != "C" && value != "POSIX")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_language_uses_configured_locale() {
+        assert_eq!(resolve_language(Some("de-DE")), "de");
+        assert_eq!(resolve_language(Some("fr_CA.UTF-8")), "fr");
+        assert_eq!(resolve_language(Some("ES")), "es");
+        assert_eq!(resolve_language(Some("ja-JP")), DEFAULT_LANGUAGE);
+    }
+
+    #[test]
+    fn test_text_falls_back_to_english_then_key() {
+        assert_eq!(text("de", "tray.quit"), "Beenden");
+        assert_eq!(text("xx", "tray.quit"), "Quit");
+        assert_eq!(text("de", "tray.missing"), "tray.missing");
+    }
+
+    #[test]
+    fn test_format_substitutes_placeholders() {
+        assert_eq!(
+            format_text(
+                "en",
+                "tray.start_recording_with_hotkey",
+                &[("hotkey", "Ctrl+Alt+Space")]
+            ),
+            "Start Recording (Ctrl+Alt+Space)"
+        );
+    }
+
+    #[test]
+    fn test_every_translation_key_exists_in_english() {
+        for (language, strings) in CATALOG.entries() {
+            for key in strings.keys() {
+                assert!(EN.contains_key(key), "{language} has unknown key {key}");
+            }
+        }
+    }
+}
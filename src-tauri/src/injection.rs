@@ -9,15 +9,23 @@
 //! - Self-injection prevention: never injects into OpenVoicy itself
 //! - Injection serialization: concurrent injections are queued
 //! - Clipboard restore: optionally restores previous clipboard contents
+//! - Chunked paste: long texts are pasted in blocks with adaptive delays,
+//!   progress reporting, and cancellation
+//! - Paste verification: optionally reads the target field back after a paste
+//!   and retypes the text with keystrokes once if it did not arrive
+//! - Paste delay calibration: optionally measures each paste and learns a
+//!   per-app delay (see `paste_calibration`)
 
 #![allow(dead_code)] // Module under construction
 
 use chrono::{DateTime, Utc};
 use once_cell::sync::Lazy;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::future::Future;
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::sync::Mutex;
 use tokio::time::sleep;
@@ -25,10 +33,36 @@ use tokio::time::sleep;
 use crate::focus::{
     app_override_candidates, capture_focus, normalize_app_id, validate_focus, FocusSignature,
 };
+use crate::paste_calibration::{self, PasteCalibration, PasteSample};
 
 /// Global injection mutex to serialize injections.
 static INJECTION_MUTEX: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
 
+/// Cancellation flag of the chunked injection in progress, if any.
+static ACTIVE_CHUNKED_INJECTION: Lazy<std::sync::Mutex<Option<Arc<AtomicBool>>>> =
+    Lazy::new(|| std::sync::Mutex::new(None));
+
+/// Lower bound for the adaptive delay between pasted chunks.
+const MIN_CHUNK_DELAY: Duration = Duration::from_millis(20);
+
+/// Upper bound for the adaptive delay between pasted chunks.
+const MAX_CHUNK_DELAY: Duration = Duration::from_millis(1000);
+
+/// How long to keep reading the target field back before a paste counts as lost.
+const PASTE_VERIFY_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// Delay between readbacks while verifying a paste.
+const PASTE_VERIFY_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Trailing characters of the injected text that must appear before the caret.
+const PASTE_VERIFY_TAIL_CHARS: usize = 32;
+
+/// How long calibration waits for the clipboard or the field to show a paste.
+const CALIBRATION_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Delay between readbacks while calibrating, fine enough to time fast apps.
+const CALIBRATION_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
 async fn with_injection_lock<T, F, Fut>(operation: F) -> T
 where
     F: FnOnce() -> Fut,
@@ -38,15 +72,27 @@ where
     operation().await
 }
 
-async fn set_clipboard_serialized(text: &str) -> Result<(), InjectionError> {
-    with_injection_lock(|| async { set_clipboard(text) }).await
-}
-
-async fn clipboard_only_result(text: &str, suffix: &str, reason: String) -> InjectionResult {
+async fn clipboard_only_result(
+    text: &str,
+    suffix: &str,
+    primary_selection: PrimarySelectionMode,
+    reason: String,
+) -> InjectionResult {
     let text_with_suffix = format!("{}{}", text, suffix);
-    if let Err(e) = set_clipboard_serialized(&text_with_suffix).await {
+    let primary_selection = available_primary_selection(primary_selection);
+    let copied = with_injection_lock(|| async {
+        let on_primary = primary_selection != PrimarySelectionMode::Off
+            && copy_to_primary_selection(&text_with_suffix);
+        if on_primary && primary_selection == PrimarySelectionMode::Instead {
+            return Ok(());
+        }
+        set_clipboard(&text_with_suffix)
+    })
+    .await;
+    if let Err(e) = copied {
         return InjectionResult::Failed {
             error: format!("Clipboard error: {}", e),
+            paste_verification: None,
             timestamp: Utc::now(),
         };
     }
@@ -72,6 +118,90 @@ pub struct InjectionConfig {
     pub focus_guard_enabled: bool,
     /// Per-application behavior overrides.
     pub app_overrides: HashMap<String, AppOverride>,
+    /// Texts longer than this many characters are pasted in chunks (0 = never).
+    pub chunk_threshold_chars: usize,
+    /// Maximum characters per pasted chunk.
+    pub chunk_size_chars: usize,
+    /// Whether to adapt leading spacing and capitalization to the text before the caret.
+    pub smart_spacing: bool,
+    /// Whether to read the target field back after a paste and retype on a miss.
+    pub verify_paste: bool,
+    /// Whether to measure each paste and learn a per-app delay.
+    pub calibrate_paste_delay: bool,
+    /// Learned delays keyed by app id; used for apps without a delay override.
+    pub learned_paste_delays: HashMap<String, u32>,
+    /// Whether transcripts also go to the PRIMARY selection (Linux).
+    pub primary_selection: PrimarySelectionMode,
+}
+
+/// Global output mode selected by the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InjectionMode {
+    /// Paste into the focused app (default).
+    Inject,
+    /// Copy to clipboard only; the user pastes manually.
+    ClipboardOnly,
+    /// Copy to clipboard and ask before pasting each transcript.
+    Ask,
+}
+
+impl InjectionMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "inject" => Some(Self::Inject),
+            "clipboard_only" => Some(Self::ClipboardOnly),
+            "ask" => Some(Self::Ask),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Inject => "inject",
+            Self::ClipboardOnly => "clipboard_only",
+            Self::Ask => "ask",
+        }
+    }
+}
+
+/// Canonical `injection:mode_changed` event payload.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct InjectionModeChangedPayload {
+    pub mode: String,
+}
+
+/// Whether transcripts are put on the X11/Wayland PRIMARY selection, which
+/// pastes with a middle click.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PrimarySelectionMode {
+    /// Clipboard only (default).
+    #[default]
+    Off,
+    /// PRIMARY selection in addition to the clipboard.
+    Also,
+    /// PRIMARY selection in place of the clipboard. Pasting into the focused
+    /// app still goes through the clipboard, which is restored afterwards.
+    Instead,
+}
+
+impl PrimarySelectionMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "off" => Some(Self::Off),
+            "also" => Some(Self::Also),
+            "instead" => Some(Self::Instead),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Also => "also",
+            Self::Instead => "instead",
+        }
+    }
 }
 
 /// Per-app injection override.
@@ -89,6 +219,13 @@ struct EffectiveInjectionConfig {
     focus_guard_enabled: bool,
     use_clipboard_only: bool,
     matched_override: Option<String>,
+    chunk_threshold_chars: usize,
+    chunk_size_chars: usize,
+    smart_spacing: bool,
+    verify_paste: bool,
+    /// App id to record a calibration sample for after the paste.
+    calibration_app: Option<String>,
+    primary_selection: PrimarySelectionMode,
 }
 
 impl Default for InjectionConfig {
@@ -99,11 +236,58 @@ impl Default for InjectionConfig {
             suffix: " ".to_string(),
             focus_guard_enabled: true,
             app_overrides: HashMap::new(),
+            chunk_threshold_chars: 2000,
+            chunk_size_chars: 800,
+            smart_spacing: false,
+            verify_paste: false,
+            calibrate_paste_delay: false,
+            learned_paste_delays: HashMap::new(),
+            primary_selection: PrimarySelectionMode::Off,
         }
     }
 }
 
+impl From<&crate::config::InjectionConfig> for InjectionConfig {
+    fn from(config: &crate::config::InjectionConfig) -> Self {
+        Self::with_calibration(config, paste_calibration::global_calibration())
+    }
+}
+
 impl InjectionConfig {
+    /// Build from the app config, taking learned paste delays from `calibration`.
+    pub fn with_calibration(
+        config: &crate::config::InjectionConfig,
+        calibration: &PasteCalibration,
+    ) -> Self {
+        Self {
+            paste_delay_ms: config.paste_delay_ms,
+            restore_clipboard: config.restore_clipboard,
+            suffix: config.suffix.clone(),
+            focus_guard_enabled: config.focus_guard_enabled,
+            app_overrides: config
+                .app_overrides
+                .iter()
+                .map(|(app_id, ov)| {
+                    (
+                        app_id.clone(),
+                        AppOverride {
+                            paste_delay_ms: ov.paste_delay_ms,
+                            use_clipboard_only: ov.use_clipboard_only,
+                        },
+                    )
+                })
+                .collect(),
+            chunk_threshold_chars: config.chunk_threshold_chars as usize,
+            chunk_size_chars: config.chunk_size_chars as usize,
+            smart_spacing: config.smart_spacing,
+            verify_paste: config.verify_paste,
+            calibrate_paste_delay: config.calibrate_paste_delay,
+            learned_paste_delays: calibration.learned_delays(),
+            primary_selection: PrimarySelectionMode::parse(&config.primary_selection)
+                .unwrap_or_default(),
+        }
+    }
+
     /// Clamp paste delay to valid range.
     pub fn clamped_delay(&self) -> Duration {
         let ms = self.paste_delay_ms.clamp(10, 500);
@@ -140,11 +324,19 @@ impl InjectionConfig {
             focus_guard_enabled: self.focus_guard_enabled,
             use_clipboard_only: false,
             matched_override: None,
+            chunk_threshold_chars: self.chunk_threshold_chars,
+            chunk_size_chars: self.chunk_size_chars,
+            smart_spacing: self.smart_spacing,
+            verify_paste: self.verify_paste,
+            calibration_app: None,
+            primary_selection: self.primary_selection,
         };
 
+        let mut delay_overridden = false;
         if let Some((matched_key, app_override)) = self.resolve_override(focus) {
             if let Some(delay) = app_override.paste_delay_ms {
                 effective.paste_delay_ms = delay;
+                delay_overridden = true;
             }
             if let Some(clipboard_only) = app_override.use_clipboard_only {
                 effective.use_clipboard_only = clipboard_only;
@@ -152,6 +344,19 @@ impl InjectionConfig {
             effective.matched_override = Some(matched_key);
         }
 
+        let candidates = app_override_candidates(focus);
+        if !delay_overridden {
+            if let Some(delay) = candidates
+                .iter()
+                .find_map(|candidate| self.learned_paste_delays.get(candidate))
+            {
+                effective.paste_delay_ms = *delay;
+            }
+        }
+        if self.calibrate_paste_delay {
+            effective.calibration_app = candidates.into_iter().next();
+        }
+
         effective
     }
 }
@@ -161,6 +366,134 @@ impl EffectiveInjectionConfig {
         let ms = self.paste_delay_ms.clamp(10, 500);
         Duration::from_millis(ms as u64)
     }
+
+    fn should_chunk(&self, text: &str) -> bool {
+        self.chunk_threshold_chars > 0 && text.chars().count() > self.chunk_threshold_chars
+    }
+}
+
+/// Progress of a chunked injection, reported after each pasted chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct InjectionProgress {
+    /// Number of chunks pasted so far.
+    pub chunks_done: usize,
+    /// Total number of chunks.
+    pub chunk_count: usize,
+    /// Characters pasted so far.
+    pub chars_injected: usize,
+    /// Total characters to paste (including suffix).
+    pub total_chars: usize,
+}
+
+/// Request cancellation of the chunked injection in progress.
+///
+/// Returns `false` when no chunked injection is running. The current chunk
+/// finishes; the remaining text is left on the clipboard.
+pub fn cancel_active_injection() -> bool {
+    let active = ACTIVE_CHUNKED_INJECTION
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match active.as_ref() {
+        Some(cancelled) => {
+            cancelled.store(true, Ordering::SeqCst);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Registers the running chunked injection for cancellation; clears it on drop.
+struct ChunkedInjectionGuard {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl ChunkedInjectionGuard {
+    fn register() -> Self {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        *ACTIVE_CHUNKED_INJECTION
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Arc::clone(&cancelled));
+        Self { cancelled }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for ChunkedInjectionGuard {
+    fn drop(&mut self) {
+        *ACTIVE_CHUNKED_INJECTION
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+    }
+}
+
+/// Delay between pasted chunks, backing off when the target app is slow.
+///
+/// A paste shortcut that takes long to dispatch means the target's event
+/// loop is busy; overwriting the clipboard before it reads the previous
+/// chunk would paste the wrong text.
+#[derive(Debug, Clone, Copy)]
+struct AdaptiveChunkDelay {
+    base: Duration,
+    current: Duration,
+}
+
+impl AdaptiveChunkDelay {
+    fn new(base: Duration) -> Self {
+        let base = base.clamp(MIN_CHUNK_DELAY, MAX_CHUNK_DELAY);
+        Self {
+            base,
+            current: base,
+        }
+    }
+
+    fn current(&self) -> Duration {
+        self.current
+    }
+
+    /// Update the delay from how long the last paste took to dispatch.
+    fn observe(&mut self, paste_elapsed: Duration) {
+        let target = (self.base + paste_elapsed * 2).clamp(MIN_CHUNK_DELAY, MAX_CHUNK_DELAY);
+        // Smooth so a single slow paste does not stall the remaining chunks.
+        self.current = ((self.current * 3 + target) / 4).clamp(MIN_CHUNK_DELAY, MAX_CHUNK_DELAY);
+    }
+}
+
+/// Split `text` into chunks of at most `max_chars` characters.
+///
+/// Breaks after the last newline in the window when possible, then after the
+/// last whitespace, so words and paragraphs stay intact.
+fn split_into_chunks(text: &str, max_chars: usize) -> Vec<&str> {
+    fn last_break(window: &str, is_break: impl Fn(char) -> bool) -> Option<usize> {
+        window
+            .char_indices()
+            .rev()
+            .find(|(_, c)| is_break(*c))
+            .map(|(index, c)| index + c.len_utf8())
+    }
+
+    let max_chars = max_chars.max(1);
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let Some((hard_end, _)) = rest.char_indices().nth(max_chars) else {
+            chunks.push(rest);
+            break;
+        };
+        let window = &rest[..hard_end];
+        // Avoid tiny chunks when the only break is near the start of the window.
+        let min_split = hard_end / 2;
+        let split_at = last_break(window, |c| c == '\n')
+            .filter(|&index| index > min_split)
+            .or_else(|| last_break(window, char::is_whitespace).filter(|&index| index > min_split))
+            .unwrap_or(hard_end);
+        let (chunk, remainder) = rest.split_at(split_at);
+        chunks.push(chunk);
+        rest = remainder;
+    }
+    chunks
 }
 
 /// Result of an injection attempt.
@@ -170,6 +503,8 @@ pub enum InjectionResult {
     /// Text was injected via paste.
     Injected {
         text_length: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        paste_verification: Option<PasteVerification>,
         timestamp: DateTime<Utc>,
     },
     /// Text copied to clipboard only (paste not performed).
@@ -181,10 +516,27 @@ pub enum InjectionResult {
     /// Injection failed.
     Failed {
         error: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        paste_verification: Option<PasteVerification>,
         timestamp: DateTime<Utc>,
     },
 }
 
+/// Outcome of reading the target field back after a paste.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum PasteVerification {
+    /// The pasted text was found before the caret.
+    Verified,
+    /// The paste did not arrive; the text was retyped with keystrokes.
+    RetriedWithKeystrokes,
+    /// Neither the paste nor the keystroke retry arrived.
+    Failed,
+    /// The target field cannot be read back, so the paste was not checked.
+    Unverifiable,
+}
+
 impl InjectionResult {
     /// Check if the injection was successful (either injected or clipboard).
     pub fn is_success(&self) -> bool {
@@ -193,6 +545,19 @@ impl InjectionResult {
             InjectionResult::Injected { .. } | InjectionResult::ClipboardOnly { .. }
         )
     }
+
+    /// Paste verification outcome, when verification ran.
+    pub fn paste_verification(&self) -> Option<PasteVerification> {
+        match self {
+            InjectionResult::Injected {
+                paste_verification, ..
+            }
+            | InjectionResult::Failed {
+                paste_verification, ..
+            } => *paste_verification,
+            InjectionResult::ClipboardOnly { .. } => None,
+        }
+    }
 }
 
 /// Injection errors.
@@ -213,15 +578,27 @@ pub enum InjectionError {
 /// This is the main entry point for text injection. It:
 /// 1. Validates focus (if Focus Guard enabled)
 /// 2. Serializes with other injections
-/// 3. Sets clipboard text (with suffix)
+/// 3. Sets clipboard text (with suffix, and smart spacing when enabled)
 /// 4. Sends paste shortcut (if focus validated)
-/// 5. Optionally restores clipboard
+/// 5. Optionally verifies the paste, retyping with keystrokes once on a miss
+/// 6. Optionally restores clipboard
 ///
 /// Returns the injection result with details.
 pub async fn inject_text(
     text: &str,
     expected_focus: Option<&FocusSignature>,
     config: &InjectionConfig,
+) -> InjectionResult {
+    inject_text_with_progress(text, expected_focus, config, &|_| {}).await
+}
+
+/// [`inject_text`] that reports per-chunk progress when the text is long
+/// enough to be pasted in chunks.
+pub async fn inject_text_with_progress(
+    text: &str,
+    expected_focus: Option<&FocusSignature>,
+    config: &InjectionConfig,
+    on_progress: &(dyn Fn(InjectionProgress) + Send + Sync),
 ) -> InjectionResult {
     // Use the currently focused app to resolve per-app overrides.
     let current_focus = capture_focus();
@@ -234,22 +611,35 @@ pub async fn inject_text(
             "App override clipboard-only mode".to_string()
         };
 
-        return clipboard_only_result(text, &effective.suffix, reason).await;
+        return clipboard_only_result(text, &effective.suffix, effective.primary_selection, reason)
+            .await;
+    }
+
+    // A background session has no focused app; keystrokes would go nowhere.
+    if let Some(reason) = crate::session::current().injection_block_reason() {
+        log::info!("Clipboard-only mode: {}", reason);
+        return clipboard_only_result(text, &effective.suffix, effective.primary_selection, reason)
+            .await;
     }
 
     // Validate focus if Focus Guard is enabled and we have an expected signature
-    if effective.focus_guard_enabled {
-        if let Some(expected) = expected_focus {
-            let validation = validate_focus(expected);
-            if !validation.should_inject() {
-                // Clipboard-only mode
-                let reason = validation
-                    .clipboard_only_reason()
-                    .unwrap_or_else(|| "Focus validation failed".to_string());
-
-                log::info!("Clipboard-only mode: {}", reason);
-                return clipboard_only_result(text, &effective.suffix, reason).await;
-            }
+    let guarded_focus = expected_focus.filter(|_| effective.focus_guard_enabled);
+    if let Some(expected) = guarded_focus {
+        let validation = validate_focus(expected);
+        if !validation.should_inject() {
+            // Clipboard-only mode
+            let reason = validation
+                .clipboard_only_reason()
+                .unwrap_or_else(|| "Focus validation failed".to_string());
+
+            log::info!("Clipboard-only mode: {}", reason);
+            return clipboard_only_result(
+                text,
+                &effective.suffix,
+                effective.primary_selection,
+                reason,
+            )
+            .await;
         }
     }
 
@@ -258,41 +648,113 @@ pub async fn inject_text(
         return clipboard_only_result(
             text,
             &effective.suffix,
-            "OpenVoicy settings window focused".to_string(),
+            effective.primary_selection,
+            "OpenVoicy window focused".to_string(),
         )
         .await;
     }
 
+    if effective.smart_spacing {
+        if let Some(before_caret) = crate::smart_spacing::text_before_caret() {
+            let adjusted = crate::smart_spacing::apply(text, &before_caret);
+            return perform_injection(&adjusted, &effective, guarded_focus, on_progress).await;
+        }
+        log::debug!("Smart spacing unavailable for focused field; using static suffix");
+    }
+
     // Perform injection (serialized)
-    perform_injection(text, &effective).await
+    perform_injection(text, &effective, guarded_focus, on_progress).await
 }
 
 /// Perform the actual injection (clipboard + paste).
-async fn perform_injection(text: &str, config: &EffectiveInjectionConfig) -> InjectionResult {
+///
+/// `guarded_focus` is re-checked before each chunk of a chunked paste.
+async fn perform_injection(
+    text: &str,
+    config: &EffectiveInjectionConfig,
+    guarded_focus: Option<&FocusSignature>,
+    on_progress: &(dyn Fn(InjectionProgress) + Send + Sync),
+) -> InjectionResult {
     with_injection_lock(|| async {
         let text_with_suffix = format!("{}{}", text, config.suffix);
 
-        // Save previous clipboard if needed
-        let previous_clipboard = if config.restore_clipboard {
-            get_clipboard().ok()
-        } else {
-            None
-        };
+        let primary_selection = available_primary_selection(config.primary_selection);
+        if primary_selection != PrimarySelectionMode::Off {
+            copy_to_primary_selection(&text_with_suffix);
+        }
+
+        // Save previous clipboard if needed; with the PRIMARY selection in
+        // place of the clipboard, the clipboard only carries the paste.
+        let previous_clipboard =
+            if config.restore_clipboard || primary_selection == PrimarySelectionMode::Instead {
+                get_clipboard().ok()
+            } else {
+                None
+            };
+
+        if config.should_chunk(&text_with_suffix) {
+            let result = paste_chunks(
+                text.len(),
+                &text_with_suffix,
+                config,
+                guarded_focus,
+                on_progress,
+            )
+            .await;
+            // Keep leftover text on the clipboard after a cancel or paste failure.
+            if let (InjectionResult::Injected { .. }, Some(prev)) = (&result, previous_clipboard) {
+                sleep(Duration::from_millis(50)).await;
+                let _ = set_clipboard(&prev);
+            }
+            return result;
+        }
 
         // Set clipboard
         if let Err(e) = set_clipboard(&text_with_suffix) {
             return InjectionResult::Failed {
                 error: format!("Clipboard error: {}", e),
+                paste_verification: None,
                 timestamp: Utc::now(),
             };
         }
 
+        let clipboard_settle = match config.calibration_app {
+            Some(_) => Some(clipboard_settle_time(&text_with_suffix).await),
+            None => None,
+        };
+
         // Wait before paste
-        sleep(config.clamped_delay()).await;
+        let delay = config.clamped_delay();
+        sleep(delay.saturating_sub(clipboard_settle.unwrap_or_default())).await;
 
         // Synthesize paste shortcut
+        let paste_started = Instant::now();
         match synthesize_paste() {
             Ok(()) => {
+                if let (Some(app_id), Some(clipboard_settle)) =
+                    (&config.calibration_app, clipboard_settle)
+                {
+                    let key_dispatch = paste_started.elapsed();
+                    let paste_ready = paste_ready_time(&text_with_suffix, paste_started).await;
+                    paste_calibration::record(
+                        paste_calibration::global_calibration(),
+                        app_id,
+                        PasteSample {
+                            delay_ms: duration_ms(delay),
+                            clipboard_settle_ms: duration_ms(clipboard_settle),
+                            key_dispatch_ms: duration_ms(key_dispatch),
+                            paste_ready_ms: paste_ready.flatten().map(duration_ms),
+                            arrived: paste_ready.map(|ready| ready.is_some()),
+                        },
+                    );
+                }
+
+                let paste_verification = if config.verify_paste {
+                    Some(verify_paste_or_retype(&text_with_suffix).await)
+                } else {
+                    None
+                };
+
                 // Restore clipboard if needed
                 if let Some(prev) = previous_clipboard {
                     // Small delay to let paste complete
@@ -300,8 +762,17 @@ async fn perform_injection(text: &str, config: &EffectiveInjectionConfig) -> Inj
                     let _ = set_clipboard(&prev);
                 }
 
+                if paste_verification == Some(PasteVerification::Failed) {
+                    return InjectionResult::Failed {
+                        error: "Pasted text did not arrive and the keystroke retry failed"
+                            .to_string(),
+                        paste_verification,
+                        timestamp: Utc::now(),
+                    };
+                }
                 InjectionResult::Injected {
                     text_length: text.len(),
+                    paste_verification,
                     timestamp: Utc::now(),
                 }
             }
@@ -318,6 +789,231 @@ async fn perform_injection(text: &str, config: &EffectiveInjectionConfig) -> Inj
     .await
 }
 
+/// Time until the clipboard reads back `text`, capped at the calibration timeout.
+async fn clipboard_settle_time(text: &str) -> Duration {
+    let started = Instant::now();
+    loop {
+        let elapsed = started.elapsed();
+        if elapsed >= CALIBRATION_TIMEOUT || get_clipboard().is_ok_and(|current| current == text) {
+            return elapsed;
+        }
+        sleep(CALIBRATION_POLL_INTERVAL).await;
+    }
+}
+
+/// Time from `paste_started` until `text` appears before the caret. `None`
+/// when the field cannot be read back, `Some(None)` when the text did not
+/// arrive in time.
+async fn paste_ready_time(text: &str, paste_started: Instant) -> Option<Option<Duration>> {
+    loop {
+        let before_caret = crate::smart_spacing::text_before_caret()?;
+        let elapsed = paste_started.elapsed();
+        if readback_matches(text, &before_caret) {
+            return Some(Some(elapsed));
+        }
+        if elapsed >= CALIBRATION_TIMEOUT {
+            return Some(None);
+        }
+        sleep(CALIBRATION_POLL_INTERVAL).await;
+    }
+}
+
+fn duration_ms(duration: Duration) -> u32 {
+    u32::try_from(duration.as_millis()).unwrap_or(u32::MAX)
+}
+
+/// Check that a paste arrived and retype `text` with keystrokes once if not.
+/// Must run under the injection lock.
+async fn verify_paste_or_retype(text: &str) -> PasteVerification {
+    match read_back_paste(text).await {
+        None => PasteVerification::Unverifiable,
+        Some(true) => PasteVerification::Verified,
+        Some(false) => {
+            log::warn!("Pasted text not found in the target field; retrying with keystrokes");
+            if let Err(e) = type_text(text) {
+                log::warn!("Keystroke retry failed: {}", e);
+                return PasteVerification::Failed;
+            }
+            match read_back_paste(text).await {
+                Some(false) => PasteVerification::Failed,
+                _ => PasteVerification::RetriedWithKeystrokes,
+            }
+        }
+    }
+}
+
+/// Poll the text before the caret until it ends with `text` or the timeout
+/// passes. None when the target field cannot be read back.
+async fn read_back_paste(text: &str) -> Option<bool> {
+    let deadline = Instant::now() + PASTE_VERIFY_TIMEOUT;
+    loop {
+        let before_caret = crate::smart_spacing::text_before_caret()?;
+        if readback_matches(text, &before_caret) {
+            return Some(true);
+        }
+        if Instant::now() >= deadline {
+            return Some(false);
+        }
+        sleep(PASTE_VERIFY_POLL_INTERVAL).await;
+    }
+}
+
+/// Whether `before_caret` ends with the tail of the injected `text`.
+///
+/// Trailing whitespace is ignored on both sides, since fields may trim or
+/// reflow a pasted suffix.
+fn readback_matches(text: &str, before_caret: &str) -> bool {
+    let expected = text.trim_end();
+    let skip = expected
+        .chars()
+        .count()
+        .saturating_sub(PASTE_VERIFY_TAIL_CHARS);
+    let tail: String = expected.chars().skip(skip).collect();
+    before_caret.trim_end().ends_with(&tail)
+}
+
+/// Paste `text_with_suffix` chunk by chunk. Must run under the injection lock.
+async fn paste_chunks(
+    text_length: usize,
+    text_with_suffix: &str,
+    config: &EffectiveInjectionConfig,
+    guarded_focus: Option<&FocusSignature>,
+    on_progress: &(dyn Fn(InjectionProgress) + Send + Sync),
+) -> InjectionResult {
+    let chunks = split_into_chunks(text_with_suffix, config.chunk_size_chars);
+    let chunk_count = chunks.len();
+    let total_chars = text_with_suffix.chars().count();
+    let guard = ChunkedInjectionGuard::register();
+    let mut chunk_delay = AdaptiveChunkDelay::new(config.clamped_delay());
+    let mut chars_injected = 0;
+
+    log::info!(
+        "Chunked injection: {} chars in {} chunks",
+        total_chars,
+        chunk_count
+    );
+
+    for (index, chunk) in chunks.iter().enumerate() {
+        if index > 0 {
+            sleep(chunk_delay.current()).await;
+        }
+
+        if guard.is_cancelled() {
+            let reason = format!(
+                "Injection cancelled after {} of {} chunks",
+                index, chunk_count
+            );
+            return leave_remaining_on_clipboard(&chunks[index..], reason, text_length);
+        }
+
+        // A long paste outlasts the initial check; stop if focus moved since.
+        if let Some(expected) = guarded_focus.filter(|_| index > 0) {
+            if let Some(changed) = validate_focus(expected).clipboard_only_reason() {
+                let reason = format!("{} after {} of {} chunks", changed, index, chunk_count);
+                log::info!("Chunked injection stopped: {}", reason);
+                return leave_remaining_on_clipboard(&chunks[index..], reason, text_length);
+            }
+        }
+
+        if let Err(e) = set_clipboard(chunk) {
+            return InjectionResult::Failed {
+                error: format!(
+                    "Clipboard error after {} of {} chunks: {}",
+                    index, chunk_count, e
+                ),
+                paste_verification: None,
+                timestamp: Utc::now(),
+            };
+        }
+        sleep(config.clamped_delay()).await;
+
+        let paste_started = Instant::now();
+        if let Err(e) = synthesize_paste() {
+            let reason = format!(
+                "Paste synthesis failed after {} of {} chunks: {}",
+                index, chunk_count, e
+            );
+            return leave_remaining_on_clipboard(&chunks[index..], reason, text_length);
+        }
+        chunk_delay.observe(paste_started.elapsed());
+
+        chars_injected += chunk.chars().count();
+        on_progress(InjectionProgress {
+            chunks_done: index + 1,
+            chunk_count,
+            chars_injected,
+            total_chars,
+        });
+    }
+
+    InjectionResult::Injected {
+        text_length,
+        paste_verification: None,
+        timestamp: Utc::now(),
+    }
+}
+
+/// Put the chunks that were not pasted on the clipboard for manual paste.
+fn leave_remaining_on_clipboard(
+    remaining: &[&str],
+    reason: String,
+    text_length: usize,
+) -> InjectionResult {
+    match set_clipboard(&remaining.concat()) {
+        Ok(()) => InjectionResult::ClipboardOnly {
+            reason: format!("{}; remaining text copied to clipboard", reason),
+            text_length,
+            timestamp: Utc::now(),
+        },
+        Err(e) => InjectionResult::Failed {
+            error: format!("{}; clipboard error: {}", reason, e),
+            paste_verification: None,
+            timestamp: Utc::now(),
+        },
+    }
+}
+
+/// `mode`, or [`PrimarySelectionMode::Off`] when the display server has no
+/// usable PRIMARY selection.
+fn available_primary_selection(mode: PrimarySelectionMode) -> PrimarySelectionMode {
+    if mode == PrimarySelectionMode::Off || crate::capabilities::primary_selection_available() {
+        mode
+    } else {
+        log::debug!(
+            "PRIMARY selection unavailable; ignoring injection.primary_selection '{}'",
+            mode.as_str()
+        );
+        PrimarySelectionMode::Off
+    }
+}
+
+/// Put `text` on the PRIMARY selection. Failures are logged, not fatal.
+fn copy_to_primary_selection(text: &str) -> bool {
+    match set_primary_selection(text) {
+        Ok(()) => true,
+        Err(e) => {
+            log::warn!("Failed to set PRIMARY selection: {}", e);
+            false
+        }
+    }
+}
+
+/// Set text to the PRIMARY selection (middle-click paste).
+fn set_primary_selection(text: &str) -> Result<(), InjectionError> {
+    #[cfg(target_os = "linux")]
+    {
+        set_primary_selection_linux(text)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = text;
+        Err(InjectionError::UnsupportedPlatform(
+            "PRIMARY selection not supported".to_string(),
+        ))
+    }
+}
+
 /// Set text to clipboard (public API for other modules).
 pub fn set_clipboard_public(text: &str) -> Result<(), String> {
     set_clipboard(text).map_err(|e| e.to_string())
@@ -398,6 +1094,31 @@ fn synthesize_paste() -> Result<(), InjectionError> {
     }
 }
 
+/// Type `text` as keystrokes into the focused field, bypassing the clipboard.
+fn type_text(text: &str) -> Result<(), InjectionError> {
+    #[cfg(target_os = "linux")]
+    {
+        type_text_linux(text)
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        type_text_macos(text)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        type_text_windows(text)
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    {
+        Err(InjectionError::UnsupportedPlatform(
+            "Keystroke typing not supported".to_string(),
+        ))
+    }
+}
+
 // === Linux Implementation ===
 
 #[cfg(target_os = "linux")]
@@ -448,6 +1169,37 @@ fn set_clipboard_linux(text: &str) -> Result<(), InjectionError> {
     Ok(())
 }
 
+#[cfg(target_os = "linux")]
+fn set_primary_selection_linux(text: &str) -> Result<(), InjectionError> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let (program, args): (&str, &[&str]) = if std::env::var("WAYLAND_DISPLAY").is_ok() {
+        ("wl-copy", &["--primary"])
+    } else {
+        ("xclip", &["-selection", "primary"])
+    };
+
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| InjectionError::Clipboard(format!("{} failed: {}", program, e)))?;
+
+    if let Some(stdin) = child.stdin.as_mut() {
+        stdin
+            .write_all(text.as_bytes())
+            .map_err(|e| InjectionError::Clipboard(e.to_string()))?;
+    }
+
+    child
+        .wait()
+        .map_err(|e| InjectionError::Clipboard(e.to_string()))?;
+
+    Ok(())
+}
+
 #[cfg(target_os = "linux")]
 fn get_clipboard_linux() -> Result<String, InjectionError> {
     use std::process::{Command, Stdio};
@@ -478,30 +1230,64 @@ fn get_clipboard_linux() -> Result<String, InjectionError> {
 
 #[cfg(target_os = "linux")]
 fn synthesize_paste_linux() -> Result<(), InjectionError> {
-    use std::process::{Command, Stdio};
-
-    // Check if we're on Wayland
-    if std::env::var("WAYLAND_DISPLAY").is_ok() {
-        // Wayland doesn't support keystroke synthesis
-        // This will result in clipboard-only mode
-        return Err(InjectionError::UnsupportedPlatform(
-            "Wayland does not support keystroke injection".to_string(),
-        ));
+    use crate::display_strategy::{self, PasteStrategy};
+
+    let strategy = display_strategy::current()
+        .map(|strategies| strategies.paste)
+        .unwrap_or(PasteStrategy::ClipboardOnly);
+    match strategy {
+        // XTEST through xdotool (X11 / XWayland)
+        PasteStrategy::Xdotool => run_paste_command_linux("xdotool", &["key", "ctrl+v"]),
+        // wlroots virtual-keyboard protocol
+        PasteStrategy::Wtype => {
+            run_paste_command_linux("wtype", &["-M", "ctrl", "-k", "v", "-m", "ctrl"])
+        }
+        PasteStrategy::Portal => crate::xdg_portal::remote_desktop_paste()
+            .map_err(|e| InjectionError::PasteFailed(format!("RemoteDesktop portal: {}", e))),
+        // No keystroke source; this will result in clipboard-only mode
+        PasteStrategy::ClipboardOnly => Err(InjectionError::UnsupportedPlatform(
+            "No keystroke injection strategy available on this display server".to_string(),
+        )),
     }
+}
+
+#[cfg(target_os = "linux")]
+fn run_paste_command_linux(program: &str, args: &[&str]) -> Result<(), InjectionError> {
+    use std::process::{Command, Stdio};
 
-    // Use xdotool for X11
-    let status = Command::new("xdotool")
-        .args(["key", "ctrl+v"])
+    let status = Command::new(program)
+        .args(args)
         .stderr(Stdio::null())
         .status()
-        .map_err(|e| InjectionError::PasteFailed(format!("xdotool failed: {}", e)))?;
+        .map_err(|e| InjectionError::PasteFailed(format!("{} failed: {}", program, e)))?;
 
     if status.success() {
         Ok(())
     } else {
-        Err(InjectionError::PasteFailed(
-            "xdotool returned non-zero exit code".to_string(),
-        ))
+        Err(InjectionError::PasteFailed(format!(
+            "{} returned non-zero exit code",
+            program
+        )))
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn type_text_linux(text: &str) -> Result<(), InjectionError> {
+    use crate::display_strategy::{self, PasteStrategy};
+
+    let strategy = display_strategy::current()
+        .map(|strategies| strategies.paste)
+        .unwrap_or(PasteStrategy::ClipboardOnly);
+    match strategy {
+        PasteStrategy::Xdotool => {
+            run_paste_command_linux("xdotool", &["type", "--clearmodifiers", "--", text])
+        }
+        PasteStrategy::Wtype => run_paste_command_linux("wtype", &["--", text]),
+        PasteStrategy::Portal | PasteStrategy::ClipboardOnly => {
+            Err(InjectionError::UnsupportedPlatform(
+                "No keystroke typing strategy available on this display server".to_string(),
+            ))
+        }
     }
 }
 
@@ -564,6 +1350,34 @@ fn synthesize_paste_macos() -> Result<(), InjectionError> {
     }
 }
 
+#[cfg(target_os = "macos")]
+fn type_text_macos(text: &str) -> Result<(), InjectionError> {
+    use std::process::Command;
+
+    let script = format!(
+        "tell application \"System Events\" to keystroke {}",
+        applescript_string_literal(text)
+    );
+    let status = Command::new("osascript")
+        .args(["-e", &script])
+        .status()
+        .map_err(|e| InjectionError::PasteFailed(format!("osascript failed: {}", e)))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(InjectionError::PasteFailed(
+            "osascript keystroke returned non-zero exit code".to_string(),
+        ))
+    }
+}
+
+/// Quote `value` as an AppleScript string literal.
+#[cfg(any(target_os = "macos", test))]
+fn applescript_string_literal(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 // === Windows Implementation ===
 
 #[cfg(target_os = "windows")]
@@ -651,6 +1465,50 @@ fn synthesize_paste_windows() -> Result<(), InjectionError> {
     }
 }
 
+#[cfg(target_os = "windows")]
+fn type_text_windows(text: &str) -> Result<(), InjectionError> {
+    use std::process::Command;
+
+    // The escaped text is passed through the environment to avoid quoting.
+    let status = Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-NonInteractive",
+            "-Command",
+            "$wshell = New-Object -ComObject WScript.Shell; $null = $wshell.SendKeys($env:OPENVOICY_KEYSTROKE_TEXT)",
+        ])
+        .env("OPENVOICY_KEYSTROKE_TEXT", escape_send_keys(text))
+        .status()
+        .map_err(|e| InjectionError::PasteFailed(format!("powershell SendKeys failed: {}", e)))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(InjectionError::PasteFailed(
+            "Windows SendKeys typing returned non-zero exit code".to_string(),
+        ))
+    }
+}
+
+/// Escape `text` so `SendKeys` types it literally.
+#[cfg(any(target_os = "windows", test))]
+fn escape_send_keys(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '+' | '^' | '%' | '~' | '(' | ')' | '{' | '}' | '[' | ']' => {
+                escaped.push('{');
+                escaped.push(ch);
+                escaped.push('}');
+            }
+            '\n' => escaped.push_str("{ENTER}"),
+            '\r' => {}
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -664,6 +1522,44 @@ mod tests {
         assert_eq!(config.suffix, " ");
         assert!(config.focus_guard_enabled);
         assert!(config.app_overrides.is_empty());
+        assert_eq!(config.chunk_threshold_chars, 2000);
+        assert_eq!(config.chunk_size_chars, 800);
+        assert!(!config.verify_paste);
+    }
+
+    #[test]
+    fn test_injection_mode_round_trips_config_values() {
+        for mode in [
+            InjectionMode::Inject,
+            InjectionMode::ClipboardOnly,
+            InjectionMode::Ask,
+        ] {
+            assert_eq!(InjectionMode::parse(mode.as_str()), Some(mode));
+        }
+        assert_eq!(InjectionMode::parse("paste"), None);
+    }
+
+    #[test]
+    fn test_primary_selection_mode_round_trips_config_values() {
+        for mode in [
+            PrimarySelectionMode::Off,
+            PrimarySelectionMode::Also,
+            PrimarySelectionMode::Instead,
+        ] {
+            assert_eq!(PrimarySelectionMode::parse(mode.as_str()), Some(mode));
+        }
+        assert_eq!(PrimarySelectionMode::parse("primary"), None);
+
+        let config = crate::config::InjectionConfig {
+            primary_selection: "instead".to_string(),
+            ..Default::default()
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let calibration = PasteCalibration::new(dir.path().join("calibration.json"));
+        assert_eq!(
+            InjectionConfig::with_calibration(&config, &calibration).primary_selection,
+            PrimarySelectionMode::Instead
+        );
     }
 
     #[test]
@@ -691,6 +1587,7 @@ mod tests {
     fn test_injection_result_success() {
         let result = InjectionResult::Injected {
             text_length: 10,
+            paste_verification: None,
             timestamp: Utc::now(),
         };
         assert!(result.is_success());
@@ -704,11 +1601,63 @@ mod tests {
 
         let result = InjectionResult::Failed {
             error: "test error".to_string(),
+            paste_verification: None,
             timestamp: Utc::now(),
         };
         assert!(!result.is_success());
     }
 
+    #[test]
+    fn test_injection_result_reports_paste_verification() {
+        let result = InjectionResult::Injected {
+            text_length: 5,
+            paste_verification: Some(PasteVerification::RetriedWithKeystrokes),
+            timestamp: Utc::now(),
+        };
+        assert_eq!(
+            result.paste_verification(),
+            Some(PasteVerification::RetriedWithKeystrokes)
+        );
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["paste_verification"], "retried_with_keystrokes");
+
+        let result = InjectionResult::Injected {
+            text_length: 5,
+            paste_verification: None,
+            timestamp: Utc::now(),
+        };
+        let json = serde_json::to_value(&result).unwrap();
+        assert!(json.get("paste_verification").is_none());
+    }
+
+    #[test]
+    fn test_readback_matches_tail_of_injected_text() {
+        assert!(readback_matches("hello world ", "Notes: hello world"));
+        assert!(readback_matches("hello world", "hello world "));
+        assert!(!readback_matches("hello world ", "Notes: hello"));
+        assert!(!readback_matches("hello world", ""));
+
+        // Only the tail needs to be visible in the truncated readback.
+        let long = "word ".repeat(40);
+        let before_caret: String = long.chars().skip(long.len() - 40).collect();
+        assert!(readback_matches(&long, &before_caret));
+    }
+
+    #[test]
+    fn test_escape_send_keys_brackets_special_characters() {
+        assert_eq!(escape_send_keys("a+b (50%)"), "a{+}b {(}50{%}{)}");
+        assert_eq!(escape_send_keys("{x}~^"), "{{}x{}}{~}{^}");
+        assert_eq!(escape_send_keys("one\r\ntwo"), "one{ENTER}two");
+    }
+
+    #[test]
+    fn test_applescript_string_literal_escapes_quotes() {
+        assert_eq!(
+            applescript_string_literal(r#"say "hi" \ bye"#),
+            r#""say \"hi\" \\ bye""#
+        );
+    }
+
     #[tokio::test]
     async fn test_injection_serialization() {
         // Test that injections are serialized (not interleaved)
@@ -785,6 +1734,8 @@ mod tests {
             app_name: "general - Slack".to_string(),
             captured_at: Instant::now(),
             timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
         };
 
         let resolved = config.resolve_override(&focus);
@@ -813,6 +1764,8 @@ mod tests {
             app_name: "Discord".to_string(),
             captured_at: Instant::now(),
             timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
         };
 
         let effective = config.effective_for_focus(&focus);
@@ -821,6 +1774,162 @@ mod tests {
         assert_eq!(effective.matched_override.as_deref(), Some("discord"));
     }
 
+    #[test]
+    fn test_learned_delay_applies_unless_overridden() {
+        let mut config = InjectionConfig {
+            calibrate_paste_delay: true,
+            ..Default::default()
+        };
+        config.learned_paste_delays.insert("code".to_string(), 15);
+        config.learned_paste_delays.insert("slack".to_string(), 180);
+        config.app_overrides.insert(
+            "slack".to_string(),
+            AppOverride {
+                paste_delay_ms: Some(90),
+                use_clipboard_only: None,
+            },
+        );
+        let focus = |process: &str| FocusSignature {
+            window_id: "4".to_string(),
+            process_name: process.to_string(),
+            app_name: "Window".to_string(),
+            captured_at: Instant::now(),
+            timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
+        };
+
+        let code = config.effective_for_focus(&focus("Code.exe"));
+        assert_eq!(code.paste_delay_ms, 15);
+        assert_eq!(code.calibration_app.as_deref(), Some("code"));
+
+        assert_eq!(
+            config.effective_for_focus(&focus("slack")).paste_delay_ms,
+            90
+        );
+        assert_eq!(
+            config
+                .effective_for_focus(&focus("terminal"))
+                .paste_delay_ms,
+            40
+        );
+
+        config.calibrate_paste_delay = false;
+        assert!(config
+            .effective_for_focus(&focus("code"))
+            .calibration_app
+            .is_none());
+    }
+
+    #[test]
+    fn test_learned_delays_come_from_the_given_calibration() {
+        let dir = tempfile::tempdir().unwrap();
+        let calibration = PasteCalibration::new(dir.path().join("calibration.json"));
+        let config = crate::config::InjectionConfig::default();
+        assert!(InjectionConfig::with_calibration(&config, &calibration)
+            .learned_paste_delays
+            .is_empty());
+
+        let sample = PasteSample {
+            delay_ms: 40,
+            clipboard_settle_ms: 4,
+            key_dispatch_ms: 3,
+            paste_ready_ms: Some(12),
+            arrived: Some(true),
+        };
+        for _ in 0..paste_calibration::MIN_SAMPLES {
+            paste_calibration::record(&calibration, "code", sample);
+        }
+        let learned = InjectionConfig::with_calibration(&config, &calibration).learned_paste_delays;
+        assert_eq!(learned.get("code"), Some(&20));
+    }
+
+    #[test]
+    fn test_should_chunk_respects_threshold() {
+        let focus = FocusSignature {
+            window_id: "3".to_string(),
+            process_name: "editor".to_string(),
+            app_name: "Editor".to_string(),
+            captured_at: Instant::now(),
+            timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
+        };
+        let config = InjectionConfig {
+            chunk_threshold_chars: 10,
+            ..Default::default()
+        };
+        let effective = config.effective_for_focus(&focus);
+        assert!(!effective.should_chunk("short text"));
+        assert!(effective.should_chunk("longer than ten"));
+
+        let disabled = InjectionConfig {
+            chunk_threshold_chars: 0,
+            ..Default::default()
+        }
+        .effective_for_focus(&focus);
+        assert!(!disabled.should_chunk(&"x".repeat(10_000)));
+    }
+
+    #[test]
+    fn test_split_into_chunks_prefers_paragraph_then_word_breaks() {
+        let text = "first paragraph here\nsecond paragraph that keeps going on";
+        let chunks = split_into_chunks(text, 30);
+        assert_eq!(chunks[0], "first paragraph here\n");
+        assert_eq!(chunks.concat(), text);
+        assert!(chunks.iter().all(|chunk| chunk.chars().count() <= 30));
+        assert!(chunks[1].ends_with(' '));
+
+        let unbroken = "a".repeat(25);
+        let chunks = split_into_chunks(&unbroken, 10);
+        assert_eq!(chunks, vec!["aaaaaaaaaa", "aaaaaaaaaa", "aaaaa"]);
+    }
+
+    #[test]
+    fn test_split_into_chunks_is_char_boundary_safe() {
+        let text = "héllo wörld ünïcode ☃☃☃☃☃ text";
+        let chunks = split_into_chunks(text, 7);
+        assert_eq!(chunks.concat(), text);
+        assert!(chunks.iter().all(|chunk| chunk.chars().count() <= 7));
+        assert!(split_into_chunks("", 7).is_empty());
+    }
+
+    #[test]
+    fn test_adaptive_chunk_delay_backs_off_for_slow_pastes() {
+        let mut delay = AdaptiveChunkDelay::new(Duration::from_millis(40));
+        delay.observe(Duration::ZERO);
+        assert_eq!(delay.current(), Duration::from_millis(40));
+
+        for _ in 0..10 {
+            delay.observe(Duration::from_millis(200));
+        }
+        assert!(delay.current() > Duration::from_millis(300));
+
+        for _ in 0..20 {
+            delay.observe(Duration::from_secs(5));
+        }
+        assert!(delay.current() > Duration::from_millis(990));
+        assert!(delay.current() <= MAX_CHUNK_DELAY);
+
+        for _ in 0..40 {
+            delay.observe(Duration::ZERO);
+        }
+        assert!(delay.current() < Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_cancel_active_injection_only_while_registered() {
+        assert!(!cancel_active_injection());
+
+        let guard = ChunkedInjectionGuard::register();
+        assert!(!guard.is_cancelled());
+        assert!(cancel_active_injection());
+        assert!(guard.is_cancelled());
+
+        drop(guard);
+        assert!(!cancel_active_injection());
+    }
+
     #[cfg(target_os = "linux")]
     #[test]
     fn test_clipboard_operations() {
@@ -0,0 +1,433 @@
+//! Ordered dispatch of text injections.
+//!
+//! Transcriptions can finish out of order: a short dictation started right
+//! after a long one may come back first, and the utterances of a continuous
+//! session race the final result of the session before it. Injecting results
+//! as they arrive would interleave text in the target app.
+//!
+//! Each recording reserves a place when it starts. A result waits until every
+//! session that started earlier has finished injecting or ended without text,
+//! and only one injection runs at a time. Manual injections (pasting a held
+//! transcript) skip the session order but still wait for the injection in
+//! progress.
+
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use tokio::sync::{watch, Notify};
+use tokio::time::Instant;
+
+/// How long a result waits for earlier sessions before they are presumed
+/// abandoned (longer than the transcription timeout).
+const HOLD_TIMEOUT: Duration = Duration::from_secs(90);
+
+static INJECTION_DISPATCHER: Lazy<InjectionDispatcher> = Lazy::new(InjectionDispatcher::new);
+
+/// Get the process-wide injection dispatcher.
+pub fn global_dispatcher() -> &'static InjectionDispatcher {
+    &INJECTION_DISPATCHER
+}
+
+/// A session that may still inject text.
+#[derive(Debug)]
+struct Slot {
+    session_id: String,
+    started_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Default)]
+struct DispatchState {
+    /// Reserved sessions, oldest first.
+    slots: Vec<Slot>,
+    /// Whether an injection is in progress.
+    busy: bool,
+    /// Results waiting for their turn.
+    waiting: usize,
+    /// How often waiters checked for their turn.
+    #[cfg(test)]
+    turn_checks: usize,
+}
+
+impl DispatchState {
+    /// Whether `session_id` may inject now.
+    fn is_turn(&self, session_id: Option<&str>) -> bool {
+        if self.busy {
+            return false;
+        }
+        match session_id {
+            Some(id) => self
+                .slots
+                .iter()
+                .position(|slot| slot.session_id == id)
+                .is_none_or(|position| position == 0),
+            None => true,
+        }
+    }
+
+    /// Drop the sessions ahead of `session_id`; returns how many were dropped.
+    fn skip_ahead_of(&mut self, session_id: &str) -> usize {
+        let position = self
+            .slots
+            .iter()
+            .position(|slot| slot.session_id == session_id)
+            .unwrap_or(0);
+        self.slots.drain(..position).count()
+    }
+
+    fn depth(&self) -> usize {
+        self.waiting + usize::from(self.busy)
+    }
+}
+
+/// Serializes injections in session start order.
+#[derive(Debug)]
+pub struct InjectionDispatcher {
+    state: Mutex<DispatchState>,
+    notify: Notify,
+    depth: watch::Sender<usize>,
+    hold_timeout: Duration,
+}
+
+impl Default for InjectionDispatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InjectionDispatcher {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(DispatchState::default()),
+            notify: Notify::new(),
+            depth: watch::Sender::new(0),
+            hold_timeout: HOLD_TIMEOUT,
+        }
+    }
+
+    #[cfg(test)]
+    fn with_hold_timeout(hold_timeout: Duration) -> Self {
+        Self {
+            hold_timeout,
+            ..Self::new()
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, DispatchState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn publish_depth(&self, state: &DispatchState) {
+        self.depth.send_if_modified(|depth| {
+            let changed = *depth != state.depth();
+            *depth = state.depth();
+            changed
+        });
+    }
+
+    /// Reserve a place for a session that started at `started_at`.
+    pub fn reserve(&self, session_id: &str, started_at: DateTime<Utc>) {
+        let mut state = self.lock();
+        if state.slots.iter().any(|slot| slot.session_id == session_id) {
+            return;
+        }
+        let position = state
+            .slots
+            .partition_point(|slot| slot.started_at <= started_at);
+        state.slots.insert(
+            position,
+            Slot {
+                session_id: session_id.to_string(),
+                started_at,
+            },
+        );
+    }
+
+    /// Drop a session that will inject nothing more, letting later ones go.
+    pub fn release(&self, session_id: &str) {
+        let mut state = self.lock();
+        let before = state.slots.len();
+        state.slots.retain(|slot| slot.session_id != session_id);
+        if state.slots.len() != before {
+            drop(state);
+            self.notify.notify_waiters();
+        }
+    }
+
+    /// Wait for the turn of `session_id`, or of a manual injection when
+    /// `None`.
+    ///
+    /// With `finishes_session`, the session's place is released when the
+    /// turn ends; utterances of a continuous session keep it for the next.
+    pub async fn acquire(
+        &self,
+        session_id: Option<&str>,
+        finishes_session: bool,
+    ) -> InjectionTurn<'_> {
+        self.wait_for_turn(session_id).await;
+        InjectionTurn {
+            dispatcher: self,
+            session_id: session_id.map(str::to_string),
+            finishes_session,
+            holding: true,
+        }
+    }
+
+    /// Wait until `session_id` may inject, then mark an injection in progress.
+    async fn wait_for_turn(&self, session_id: Option<&str>) {
+        let mut deadline = Instant::now() + self.hold_timeout;
+        {
+            let mut state = self.lock();
+            state.waiting += 1;
+            self.publish_depth(&state);
+        }
+
+        loop {
+            // Registered before checking so a release in between is not missed.
+            let notified = self.notify.notified();
+            {
+                let mut state = self.lock();
+                #[cfg(test)]
+                {
+                    state.turn_checks += 1;
+                }
+                if state.is_turn(session_id) {
+                    state.waiting -= 1;
+                    state.busy = true;
+                    self.publish_depth(&state);
+                    return;
+                }
+            }
+            if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                // The turn holder may legitimately outlive the timeout (a long
+                // chunked paste, a deferral); keep waiting without spinning.
+                deadline = Instant::now() + self.hold_timeout;
+                if let Some(id) = session_id {
+                    let skipped = self.lock().skip_ahead_of(id);
+                    if skipped > 0 {
+                        log::warn!(
+                            "Injection for session {} waited {:?}; skipping {} earlier session(s)",
+                            id,
+                            self.hold_timeout,
+                            skipped
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// End the injection in progress, releasing the place of
+    /// `finished_session`.
+    fn end_turn(&self, finished_session: Option<&str>) {
+        let mut state = self.lock();
+        state.busy = false;
+        if let Some(session_id) = finished_session {
+            state.slots.retain(|slot| slot.session_id != session_id);
+        }
+        self.publish_depth(&state);
+        drop(state);
+        self.notify.notify_waiters();
+    }
+
+    /// Results waiting or being injected.
+    #[cfg(test)]
+    pub fn depth(&self) -> usize {
+        self.lock().depth()
+    }
+
+    /// Watch the number of results waiting or being injected.
+    pub fn subscribe_depth(&self) -> watch::Receiver<usize> {
+        self.depth.subscribe()
+    }
+}
+
+/// Permission to inject; the next result may go once this is dropped.
+#[derive(Debug)]
+pub struct InjectionTurn<'a> {
+    dispatcher: &'a InjectionDispatcher,
+    /// Session the turn belongs to; `None` for a manual injection.
+    session_id: Option<String>,
+    /// Whether the session's place is released with the turn.
+    finishes_session: bool,
+    /// False while the turn is lent out by [`InjectionTurn::yield_during`].
+    holding: bool,
+}
+
+impl InjectionTurn<'_> {
+    /// Let other injections run while `during` runs, such as a wait for a busy
+    /// target app, then wait for the turn again. The session keeps its place,
+    /// so later sessions still wait for it; manual injections may go.
+    pub async fn yield_during<T>(&mut self, during: impl Future<Output = T>) -> T {
+        self.dispatcher.end_turn(None);
+        self.holding = false;
+        let output = during.await;
+        self.dispatcher
+            .wait_for_turn(self.session_id.as_deref())
+            .await;
+        self.holding = true;
+        output
+    }
+}
+
+impl Drop for InjectionTurn<'_> {
+    fn drop(&mut self) {
+        let finished_session = self.session_id.as_deref().filter(|_| self.finishes_session);
+        if self.holding {
+            self.dispatcher.end_turn(finished_session);
+        } else if let Some(session_id) = finished_session {
+            self.dispatcher.release(session_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000 + seconds, 0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_later_session_waits_for_earlier_one() {
+        let dispatcher = Arc::new(InjectionDispatcher::new());
+        dispatcher.reserve("first", at(0));
+        dispatcher.reserve("second", at(5));
+
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let second = {
+            let dispatcher = Arc::clone(&dispatcher);
+            let order = Arc::clone(&order);
+            tokio::spawn(async move {
+                let _turn = dispatcher.acquire(Some("second"), true).await;
+                order.lock().unwrap().push("second");
+            })
+        };
+        tokio::task::yield_now().await;
+        assert!(order.lock().unwrap().is_empty());
+        assert_eq!(dispatcher.depth(), 1);
+
+        {
+            let _turn = dispatcher.acquire(Some("first"), true).await;
+            order.lock().unwrap().push("first");
+        }
+        second.await.unwrap();
+
+        assert_eq!(*order.lock().unwrap(), vec!["first", "second"]);
+        assert_eq!(dispatcher.depth(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_reserve_orders_by_start_time() {
+        let dispatcher = InjectionDispatcher::new();
+        dispatcher.reserve("late", at(10));
+        dispatcher.reserve("early", at(1));
+
+        let state = dispatcher.lock();
+        assert!(state.is_turn(Some("early")));
+        assert!(!state.is_turn(Some("late")));
+    }
+
+    #[tokio::test]
+    async fn test_release_unblocks_later_sessions() {
+        let dispatcher = InjectionDispatcher::new();
+        dispatcher.reserve("cancelled", at(0));
+        dispatcher.reserve("next", at(1));
+        assert!(!dispatcher.lock().is_turn(Some("next")));
+
+        dispatcher.release("cancelled");
+        let _turn = dispatcher.acquire(Some("next"), true).await;
+    }
+
+    #[tokio::test]
+    async fn test_utterance_turns_keep_the_session_place() {
+        let dispatcher = InjectionDispatcher::new();
+        dispatcher.reserve("continuous", at(0));
+        dispatcher.reserve("next", at(30));
+
+        drop(dispatcher.acquire(Some("continuous"), false).await);
+        assert!(!dispatcher.lock().is_turn(Some("next")));
+
+        drop(dispatcher.acquire(Some("continuous"), true).await);
+        assert!(dispatcher.lock().is_turn(Some("next")));
+    }
+
+    #[tokio::test]
+    async fn test_only_one_injection_at_a_time() {
+        let dispatcher = InjectionDispatcher::new();
+        let turn = dispatcher.acquire(None, false).await;
+        assert!(!dispatcher.lock().is_turn(None));
+        assert_eq!(*dispatcher.subscribe_depth().borrow(), 1);
+
+        drop(turn);
+        assert!(dispatcher.lock().is_turn(None));
+        assert_eq!(*dispatcher.subscribe_depth().borrow(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_yielded_turn_lets_manual_injections_but_not_later_sessions_go() {
+        let dispatcher = InjectionDispatcher::new();
+        dispatcher.reserve("deferred", at(0));
+        dispatcher.reserve("later", at(5));
+        let mut turn = dispatcher.acquire(Some("deferred"), true).await;
+
+        let depth_during_wait = turn
+            .yield_during(async {
+                // The user inserts the queued transcript by hand meanwhile.
+                drop(dispatcher.acquire(None, false).await);
+                assert!(!dispatcher.lock().is_turn(Some("later")));
+                dispatcher.depth()
+            })
+            .await;
+        assert_eq!(depth_during_wait, 0);
+        assert!(!dispatcher.lock().is_turn(None), "turn held again");
+
+        drop(turn);
+        assert!(dispatcher.lock().is_turn(Some("later")));
+        assert_eq!(dispatcher.depth(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_waiter_does_not_spin_when_the_turn_outlives_the_hold_timeout() {
+        let hold_timeout = Duration::from_millis(20);
+        let dispatcher = Arc::new(InjectionDispatcher::with_hold_timeout(hold_timeout));
+        dispatcher.reserve("lost", at(0));
+        dispatcher.reserve("waiting", at(1));
+        let long_paste = dispatcher.acquire(None, false).await;
+
+        let waiter = {
+            let dispatcher = Arc::clone(&dispatcher);
+            tokio::spawn(async move {
+                drop(dispatcher.acquire(Some("waiting"), true).await);
+            })
+        };
+        tokio::time::sleep(hold_timeout * 10).await;
+        assert!(!waiter.is_finished());
+        {
+            let state = dispatcher.lock();
+            assert_eq!(state.slots.len(), 1, "earlier session skipped");
+            // One check per hold timeout, plus the first one.
+            assert!(state.turn_checks <= 20, "{} turn checks", state.turn_checks);
+        }
+
+        drop(long_paste);
+        waiter.await.unwrap();
+    }
+
+    #[test]
+    fn test_skip_ahead_drops_only_earlier_sessions() {
+        let dispatcher = InjectionDispatcher::new();
+        dispatcher.reserve("lost", at(0));
+        dispatcher.reserve("waiting", at(1));
+        dispatcher.reserve("later", at(2));
+
+        let mut state = dispatcher.lock();
+        assert_eq!(state.skip_ahead_of("waiting"), 1);
+        assert!(state.is_turn(Some("waiting")));
+        assert_eq!(state.slots.len(), 2);
+    }
+}
@@ -7,45 +7,66 @@
 //! - Text injection (from injection.rs)
 //!
 //! The IntegrationManager is the central coordinator that handles the
-//! event-driven flow across all these components.
-
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+//! event-driven flow across all these components. It composes one service per
+//! concern, each owning its state and event loops:
+//! - `ModelService` (model_service.rs): model check, download, purge, embedded ASR
+//! - `AudioService` (audio_service.rs): devices, mic test, device hot-swap
+//! - `SessionService` (session_service.rs): recording sessions through injection
+//! - `OverlayService` (overlay_service.rs): overlay window and focus preview
+
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use std::{cell::RefCell, thread_local};
 
-use global_hotkey::GlobalHotKeyEvent;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::{json, Value};
-use sha2::{Digest, Sha256};
-use tauri::{AppHandle, Emitter, Manager};
-use tokio::sync::{Mutex, Notify, RwLock};
+use tokio::sync::{Mutex, OwnedRwLockWriteGuard, RwLock};
 use uuid::Uuid;
 
+use crate::a11y;
 use crate::audio_cue::{AudioCueManager, CueType};
-use crate::config::{self, HotkeyMode, ReplacementRule};
-use crate::errors::{AppError, ErrorKind};
-use crate::focus::{capture_focus, FocusSignature};
-use crate::history::{
-    HistoryInjectionResult, TranscriptEntry, TranscriptHistory, TranscriptTimings,
-};
-use crate::hotkey::{HotkeyAction, HotkeyManager, RecordingAction};
-use crate::injection::{inject_text, InjectionConfig, InjectionResult};
+use crate::call_detect::{self, CueStatus};
+use crate::config::{self, AsrBackend, ReplacementRule, ScreenSharePolicy};
+use crate::error_recovery::RecoveryCheck;
+use crate::errors::{AppError, ErrorKind, IntegrationError};
+use crate::focus::FocusSignature;
+use crate::history::{TranscriptEntry, TranscriptSegment, TranscriptTimings, TranscriptWord};
+use crate::injection::{InjectionProgress, InjectionResult};
 use crate::ipc::{NotificationEvent, RpcClient, RpcError};
+use crate::model_compare::{ComparisonAudio, ModelComparison, ModelComparisonResult};
 use crate::model_defaults;
-use crate::overlay::{
-    FileOverlayConfigStore, OverlayManager, OverlayWindowBackend, TauriOverlayWindowBackend,
-    OVERLAY_WINDOW_LABEL,
-};
-use crate::recording::{
-    CancelReason, RecordingController, RecordingEvent, StopResult, TranscriptionResult,
-};
+use crate::model_license::ModelLicense;
+use crate::notification_throttle::{self, NotificationThrottle};
+use crate::overlay::{OverlayAppearance, OVERLAY_TIMER_MAX_HZ};
+use crate::power;
+use crate::recording::{CancelReason, RecordingController, RecordingEvent, StopResult};
+use crate::runtime::{AppEventBroadcaster, AppHandle};
+use crate::session_journal::JournalEvent;
+use crate::session_lifecycle::SessionSnapshot;
 use crate::sidecar::SidecarManager;
-use crate::state::{AppState, AppStateManager, CannotRecordReason, StateEvent};
+use crate::sidecar_update::{self, SidecarUpdatePhase};
+use crate::state::{AppState, AppStateManager, ErrorCategory, StateEvent};
+use crate::state_snapshot::{self, AppStateSnapshot, SupervisorSnapshot};
 use crate::supervisor::{
-    SidecarState as SupervisorState, SidecarSupervisor, SidecarSupervisorConfig,
+    SidecarRole, SidecarState as SupervisorState, SidecarSupervisor, SidecarSupervisorConfig,
+    SupervisorRegistry,
 };
-use crate::watchdog::{self, PingCallback, Watchdog, WatchdogConfig, WatchdogEvent};
+use crate::watchdog::{Watchdog, WatchdogConfig};
+
+mod audio_service;
+mod hotkey_service;
+mod model_service;
+mod overlay_service;
+mod session_service;
+mod watchdog_service;
+
+use audio_service::AudioService;
+use hotkey_service::HotkeyService;
+use model_service::ModelService;
+use overlay_service::OverlayService;
+use session_service::SessionService;
+use watchdog_service::WatchdogService;
 
 /// Tray icon event name.
 const EVENT_TRAY_UPDATE: &str = "tray:update";
@@ -62,13 +83,49 @@ const EVENT_MODEL_STATUS: &str = "model:status";
 const EVENT_SIDECAR_STATUS: &str = "sidecar:status";
 /// Canonical recording phase event name.
 const EVENT_RECORDING_STATUS: &str = "recording:status";
+/// Overlay progress while the sidecar transcribes a stopped recording.
+const EVENT_OVERLAY_TRANSCRIBING: &str = "overlay:transcribing";
+/// Overlay appearance (theme, scale, opacity, waveform) changed.
+const EVENT_OVERLAY_CONFIG: &str = "overlay:config";
+/// Recording discarded; the overlay plays its cancel animation.
+const EVENT_OVERLAY_CANCELLED: &str = "overlay:cancelled";
+/// Seconds left before a hotkey-started recording begins capturing.
+const EVENT_OVERLAY_COUNTDOWN: &str = "overlay:countdown";
+/// Time between countdown ticks.
+const RECORDING_COUNTDOWN_TICK: Duration = Duration::from_secs(1);
+/// Whether audio cues are muted for a meeting, and why.
+const EVENT_CUES_STATUS: &str = "cues:status";
+const CUE_STATUS_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// How long the overlay stays up after a cancel so the animation can finish.
+const OVERLAY_CANCEL_ANIMATION: Duration = Duration::from_millis(600);
+const OVERLAY_TRANSCRIBING_INTERVAL: Duration = Duration::from_millis(1_000 / OVERLAY_TIMER_MAX_HZ);
 const DEVICE_HOT_SWAP_POLL_INTERVAL: Duration = Duration::from_millis(1200);
 const DEVICE_HOT_SWAP_DEBOUNCE: Duration = Duration::from_millis(750);
+/// Would-be injection target changed (debounced), for the overlay focus preview.
+const EVENT_FOCUS_CHANGED: &str = "focus:changed";
+/// Step of automatic recovery from the Error state.
+const EVENT_APP_RECOVERY: &str = "app:recovery";
+const FOCUS_PREVIEW_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const FOCUS_PREVIEW_DEBOUNCE: Duration = Duration::from_millis(400);
+const IDLE_AUTO_DISABLE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+/// How long a warm spare sidecar waits for dictation to finish before the
+/// switch is given up.
+const WARM_SPARE_SWITCH_TIMEOUT: Duration = Duration::from_secs(60);
+const WARM_SPARE_SWITCH_POLL_INTERVAL: Duration = Duration::from_millis(250);
 const DEVICE_REMOVED_CLIPBOARD_REASON: &str =
     "Audio device disconnected during transcription; transcript copied to clipboard.";
+const GLOBAL_CLIPBOARD_ONLY_REASON: &str =
+    "Global clipboard-only mode; transcript copied to clipboard.";
+const ASK_MODE_CLIPBOARD_REASON: &str =
+    "Ask mode; transcript copied to clipboard pending confirmation.";
+const INJECTION_GUARD_REASON_PREFIX: &str = "Injection guard";
+const BUSY_TARGET_PENDING_REASON: &str =
+    "Target app is not responding; waiting to insert the transcript.";
+const BUSY_TARGET_DISMISSED_REASON: &str =
+    "Pending insert dismissed while the target app was busy; transcript copied to clipboard.";
 
 /// Model status tracking.
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ModelStatus {
     /// Model status unknown (not yet queried).
@@ -115,25 +172,47 @@ pub struct ModelProgress {
 
 /// Canonical progress payload for model status events.
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 pub struct ModelStatusProgress {
+    #[cfg_attr(test, ts(type = "number"))]
     pub current: u64,
+    #[cfg_attr(test, ts(type = "number | null"))]
     pub total: Option<u64>,
     pub unit: String,
 }
 
 /// Canonical model status event payload.
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 pub struct ModelStatusPayload {
     pub model_id: String,
     pub status: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
     pub revision: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
     pub cache_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
     pub progress: Option<ModelStatusProgress>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
     pub error: Option<String>,
+    /// Engine serving the model: "embedded" for the in-process fallback,
+    /// omitted for the sidecar.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub kind: Option<String>,
+    /// Step of loading the model into memory ("weights", "compile",
+    /// "warmup") while `status` is "loading".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub stage: Option<String>,
+    /// Completion of `stage`, when the sidecar can measure it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub percent: Option<u8>,
 }
 
 /// Sidecar `model.get_status` payload.
@@ -151,6 +230,10 @@ pub struct SidecarModelStatus {
     pub error: Option<String>,
     #[serde(default)]
     pub error_message: Option<String>,
+    /// License to accept before the download can continue
+    /// (`status == "license_required"`).
+    #[serde(default)]
+    pub license: Option<ModelLicense>,
 }
 
 /// Sidecar model download/verification progress payload.
@@ -181,8 +264,25 @@ const EVENT_TRANSCRIPT_ERROR: &str = "transcript:error";
 
 /// Application error event name (legacy + structured compatibility payload).
 const EVENT_APP_ERROR: &str = "app:error";
-const AUDIO_LEVEL_METER_MIN_INTERVAL_MS: u64 = 34; // <=30Hz
-const AUDIO_LEVEL_NON_METER_MIN_INTERVAL_MS: u64 = 67; // <=15Hz
+
+/// Ask-mode prompt: a transcript is waiting for the user to confirm injection.
+const EVENT_INJECTION_CONFIRM_REQUESTED: &str = "injection:confirm_requested";
+
+/// Chunked injection progress for long transcripts.
+const EVENT_INJECTION_PROGRESS: &str = "injection:progress";
+
+/// Number of transcription results waiting for or holding the injection turn.
+const EVENT_INJECTION_QUEUE_CHANGED: &str = "injection:queue_changed";
+
+/// A transcript is held back, or stopped being held back, because the target
+/// app is not responding.
+const EVENT_INJECTION_DEFERRED: &str = "injection:deferred";
+
+/// The queue of clipboard-only transcripts awaiting insertion changed.
+const EVENT_PENDING_INSERTS_CHANGED: &str = "pending_inserts:changed";
+
+/// The current dictation session moved to a new lifecycle phase.
+const EVENT_SESSION_CHANGED: &str = "session:changed";
 
 fn status_progress_from_parts(
     current: u64,
@@ -197,6 +297,7 @@ fn status_progress_from_parts(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn model_progress_from_parts(
     model_id: Option<String>,
     current: u64,
@@ -286,7 +387,11 @@ fn normalized_purged_model_ids(purged_model_ids: &[String]) -> Vec<String> {
     resolved_ids
 }
 
-fn model_download_params(model_id: Option<String>, force: Option<bool>) -> Option<Value> {
+fn model_download_params(
+    model_id: Option<String>,
+    force: Option<bool>,
+    license_accepted: bool,
+) -> Option<Value> {
     let mut params = serde_json::Map::new();
     if let Some(model_id) = model_id {
         let trimmed = model_id.trim();
@@ -297,6 +402,9 @@ fn model_download_params(model_id: Option<String>, force: Option<bool>) -> Optio
     if let Some(force) = force {
         params.insert("force".to_string(), json!(force));
     }
+    if license_accepted {
+        params.insert("license_accepted".to_string(), json!(true));
+    }
 
     if params.is_empty() {
         None
@@ -342,7 +450,27 @@ fn configured_model_language_hint(config: &config::AppConfig) -> Option<String>
         })
 }
 
-fn asr_initialize_params(model_id: &str, device_pref: &str, language: Option<&str>) -> Value {
+fn configured_model_beam_size(config: &config::AppConfig) -> Option<u32> {
+    config.model.as_ref().and_then(|model| model.beam_size)
+}
+
+/// Delay before the startup preload, or None when `model.preload_on_start` is off.
+fn model_preload_delay(config: &config::AppConfig) -> Option<Duration> {
+    config
+        .model
+        .as_ref()
+        .filter(|model| model.preload_on_start)
+        .map(|model| Duration::from_millis(u64::from(model.preload_delay_ms)))
+}
+
+/// Build `asr.initialize` params. Parameters left unset here fall back to the
+/// model's catalog recommendations on the sidecar side.
+fn asr_initialize_params(
+    model_id: &str,
+    device_pref: &str,
+    language: Option<&str>,
+    beam_size: Option<u32>,
+) -> Value {
     let mut params = serde_json::Map::new();
     params.insert("model_id".to_string(), json!(model_id));
     params.insert("device_pref".to_string(), json!(device_pref));
@@ -357,6 +485,9 @@ fn asr_initialize_params(model_id: &str, device_pref: &str, language: Option<&st
     }) {
         params.insert("language".to_string(), json!(language));
     }
+    if let Some(beam_size) = beam_size {
+        params.insert("beam_size".to_string(), json!(beam_size));
+    }
 
     Value::Object(params)
 }
@@ -404,8 +535,9 @@ async fn call_asr_initialize_with_language_fallback(
     model_id: &str,
     device_pref: &str,
     language: Option<String>,
+    beam_size: Option<u32>,
 ) -> Result<AsrInitializeResult, RpcError> {
-    let params = asr_initialize_params(model_id, device_pref, language.as_deref());
+    let params = asr_initialize_params(model_id, device_pref, language.as_deref(), beam_size);
 
     match client
         .call::<AsrInitializeResult>("asr.initialize", Some(params))
@@ -426,7 +558,7 @@ async fn call_asr_initialize_with_language_fallback(
                 requested_language
             );
 
-            let fallback_params = asr_initialize_params(model_id, device_pref, None);
+            let fallback_params = asr_initialize_params(model_id, device_pref, None, beam_size);
             client
                 .call::<AsrInitializeResult>("asr.initialize", Some(fallback_params))
                 .await
@@ -434,50 +566,82 @@ async fn call_asr_initialize_with_language_fallback(
     }
 }
 
-fn map_download_response_status(status: &SidecarModelStatus) -> ModelStatus {
-    match status.status.as_str() {
-        "missing" => ModelStatus::Missing,
-        "downloading" => ModelStatus::Downloading,
-        "loading" | "verifying" | "installing" => ModelStatus::Loading,
-        "ready" => ModelStatus::Ready,
-        "error" => ModelStatus::Error(
-            status
-                .error
-                .clone()
-                .or_else(|| status.error_message.clone())
-                .unwrap_or_else(|| "model download failed".to_string()),
-        ),
-        _ => ModelStatus::Unknown,
+/// Classify a supervisor failure message for error events and typed errors.
+fn supervisor_failure_kind(message: &str) -> ErrorKind {
+    let lower = message.to_ascii_lowercase();
+    if lower.contains("circuit breaker") {
+        ErrorKind::SidecarCircuitBreaker
+    } else if lower.contains("spawn") {
+        ErrorKind::SidecarSpawn
+    } else {
+        ErrorKind::SidecarCrash
+    }
+}
+
+/// Shut down a sidecar that no longer serves the app.
+async fn retire_sidecar(
+    client: Option<RpcClient>,
+    mut supervisor: SidecarSupervisor<SidecarManager>,
+) {
+    if let Some(client) = client {
+        let _: Result<Value, RpcError> = client.call("system.shutdown", None).await;
+        client.shutdown().await;
     }
+    let _ = supervisor.stop().await;
 }
 
-fn model_download_method_unsupported_message() -> String {
-    "E_METHOD_NOT_FOUND: Sidecar does not support model.download or model.install".to_string()
+fn supervisor_failure_error(message: String) -> IntegrationError {
+    IntegrationError::Supervisor {
+        kind: supervisor_failure_kind(&message),
+        message,
+    }
 }
 
-fn map_model_download_rpc_error(error: RpcError) -> String {
-    match error {
+fn model_download_method_unsupported_error() -> IntegrationError {
+    IntegrationError::Model {
+        kind: ErrorKind::MethodNotFound,
+        message: "E_METHOD_NOT_FOUND: Sidecar does not support model.download or model.install"
+            .to_string(),
+    }
+}
+
+fn map_model_download_rpc_error(error: RpcError) -> IntegrationError {
+    let (kind, message) = match error {
         RpcError::Remote { kind, message, .. } => match kind.as_str() {
-            "E_NETWORK" => {
-                format!("E_NETWORK: {message}. Check your network connection and retry.")
-            }
-            "E_DISK_FULL" => {
-                format!("E_DISK_FULL: {message}. Free disk space and retry the download.")
-            }
-            "E_CACHE_CORRUPT" => {
-                format!("E_CACHE_CORRUPT: {message}. Purge model cache, then retry.")
-            }
-            "E_METHOD_NOT_FOUND" => model_download_method_unsupported_message(),
-            _ if !kind.is_empty() => format!("{kind}: {message}"),
-            _ => format!("E_MODEL_DOWNLOAD: {message}"),
+            "E_NETWORK" => (
+                ErrorKind::Network,
+                format!("E_NETWORK: {message}. Check your network connection and retry."),
+            ),
+            "E_DISK_FULL" => (
+                ErrorKind::DiskFull,
+                format!("E_DISK_FULL: {message}. Free disk space and retry the download."),
+            ),
+            "E_CACHE_CORRUPT" => (
+                ErrorKind::CacheCorrupt,
+                format!("E_CACHE_CORRUPT: {message}. Purge model cache, then retry."),
+            ),
+            "E_METHOD_NOT_FOUND" => return model_download_method_unsupported_error(),
+            _ if !kind.is_empty() => (
+                ErrorKind::from_sidecar(&kind).unwrap_or(ErrorKind::ModelDownload),
+                format!("{kind}: {message}"),
+            ),
+            _ => (ErrorKind::ModelDownload, format!("E_MODEL_DOWNLOAD: {message}")),
         },
-        RpcError::Timeout { .. } => {
+        RpcError::Timeout { .. } => (
+            ErrorKind::ModelDownload,
             "E_MODEL_DOWNLOAD: Model download timed out. Retry and keep the app running while the model installs."
-                .to_string()
-        }
-        RpcError::Disconnected => "E_SIDECAR_IPC: Sidecar not connected".to_string(),
-        other => format!("E_MODEL_DOWNLOAD: Failed to download model: {other}"),
-    }
+                .to_string(),
+        ),
+        RpcError::Disconnected => (
+            ErrorKind::SidecarIpc,
+            "E_SIDECAR_IPC: Sidecar not connected".to_string(),
+        ),
+        other => (
+            ErrorKind::ModelDownload,
+            format!("E_MODEL_DOWNLOAD: Failed to download model: {other}"),
+        ),
+    };
+    IntegrationError::Model { kind, message }
 }
 
 fn model_status_to_event_fields(status: ModelStatus) -> (String, Option<String>) {
@@ -506,9 +670,28 @@ fn model_status_event_payload(
         cache_path,
         progress,
         error,
+        kind: None,
+        stage: None,
+        percent: None,
     }
 }
 
+/// Attach the sidecar's initialization step to a `loading` payload; other
+/// statuses have no stage.
+fn with_loading_stage(
+    mut payload: ModelStatusPayload,
+    stage: Option<String>,
+    percent: Option<f64>,
+) -> ModelStatusPayload {
+    if payload.status == "loading" {
+        payload.stage = stage.filter(|stage| !stage.is_empty());
+        payload.percent = percent
+            .filter(|percent| percent.is_finite())
+            .map(|percent| percent.clamp(0.0, 100.0).round() as u8);
+    }
+    payload
+}
+
 #[derive(Debug, Deserialize)]
 struct AudioDeviceSummary {
     uid: String,
@@ -533,6 +716,15 @@ pub struct SidecarAudioDevice {
     pub channels: u32,
 }
 
+/// Input gain of a device, as reported by sidecar `audio.get_gain` and
+/// `audio.set_gain`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputGain {
+    /// Resolved device UID; None when no input device is available.
+    pub device_uid: Option<String>,
+    pub gain_db: f64,
+}
+
 /// Preset metadata payload returned by sidecar replacements APIs.
 #[derive(Debug, Clone, Deserialize)]
 pub struct SidecarPresetInfo {
@@ -553,6 +745,100 @@ pub struct SidecarReplacementPreviewResult {
     pub applied_presets: Option<Vec<String>>,
 }
 
+/// Text of an audio file transcribed by [`IntegrationManager::transcribe_file`].
+#[derive(Debug, Clone)]
+pub struct FileTranscript {
+    pub raw_text: String,
+    pub final_text: String,
+    /// Text before the word filter, kept when `word_filter.keep_unfiltered` is set.
+    pub unfiltered_text: Option<String>,
+    pub language: Option<String>,
+    pub confidence: Option<f32>,
+    pub transcription_ms: u32,
+    /// Segment timestamps, with speaker ids when diarization was requested.
+    pub segments: Vec<TranscriptSegment>,
+    /// Word timestamps and confidence, when the backend reports them.
+    pub words: Vec<TranscriptWord>,
+}
+
+/// `file.transcribe` params for `path`: the configured model, plus speaker
+/// labels when diarization is enabled.
+fn file_transcribe_params(config: &config::AppConfig, path: &std::path::Path) -> Value {
+    let language = configured_model_language_hint(config);
+    let mut params = asr_initialize_params(
+        &configured_model_id(),
+        &config.effective_model_device_pref(),
+        language.as_deref(),
+        configured_model_beam_size(config),
+    );
+    params["audio_path"] = json!(path.to_string_lossy());
+    // Only request diarization when enabled so older sidecars see unchanged params.
+    if config.audio.diarization_enabled {
+        params["diarize"] = json!(true);
+    }
+    params
+}
+
+/// Apply learned spellings and the word filter to a file transcript, in the
+/// same order as dictation. No window is focused for a dropped file, so the
+/// global word filter setting applies. Segments and words are filtered in
+/// place. Returns `(raw, final, unfiltered)`.
+fn postprocess_file_transcript(
+    config: &config::AppConfig,
+    dictionary: &crate::learned_dictionary::LearnedDictionary,
+    raw_text: String,
+    final_text: String,
+    segments: &mut [TranscriptSegment],
+    words: &mut Vec<TranscriptWord>,
+) -> (String, String, Option<String>) {
+    let final_text = if config.vocabulary.learn_capitalization {
+        crate::learned_dictionary::learn(dictionary, &raw_text, &final_text);
+        dictionary.apply(&final_text)
+    } else {
+        final_text
+    };
+
+    if !crate::word_filter::filter_enabled_for_focus(config, None) {
+        return (raw_text, final_text, None);
+    }
+    let filter = &config.word_filter;
+    let filtered = crate::word_filter::apply(filter, &final_text);
+    let unfiltered_text = (filtered != final_text && filter.keep_unfiltered).then_some(final_text);
+    crate::word_filter::apply_to_segments(filter, segments);
+    crate::word_filter::apply_to_words(filter, words);
+    (
+        crate::word_filter::apply(filter, &raw_text),
+        filtered,
+        unfiltered_text,
+    )
+}
+
+/// Whether the sidecar's answer to `check` lets the error clear.
+fn recovery_check_passed(
+    check: RecoveryCheck,
+    result: &Result<Value, RpcError>,
+    configured_device_uid: Option<&str>,
+) -> bool {
+    #[derive(Deserialize)]
+    struct StatusResult {
+        status: String,
+    }
+
+    let Ok(value) = result else {
+        return false;
+    };
+    match check {
+        RecoveryCheck::SidecarPing => true,
+        RecoveryCheck::ModelStatus => serde_json::from_value::<StatusResult>(value.clone())
+            .is_ok_and(|result| result.status == "ready"),
+        RecoveryCheck::AudioDevices => serde_json::from_value::<AudioListResult>(value.clone())
+            .is_ok_and(|result| {
+                !result.devices.is_empty()
+                    && is_configured_device_available(configured_device_uid, &result.devices)
+            }),
+    }
+}
+
 fn is_configured_device_available(
     configured_device_uid: Option<&str>,
     devices: &[AudioDeviceSummary],
@@ -641,65 +927,16 @@ fn device_removed_app_error(
     )
 }
 
-async fn cleanup_active_session_for_no_audio_devices(
-    manager: &IntegrationManager,
-    state_manager: &Arc<AppStateManager>,
-    recording_context: &Arc<RwLock<Option<RecordingContext>>>,
-    current_session_id: &Arc<RwLock<Option<String>>>,
-) {
-    if current_session_id.read().await.is_none() {
-        return;
-    }
-
-    if state_manager.get() == AppState::Transcribing {
-        let mut context = recording_context.write().await;
-        if let Some(context) = context.as_mut() {
-            context.force_clipboard_only = true;
-            context.force_clipboard_reason = Some(DEVICE_REMOVED_CLIPBOARD_REASON.to_string());
-        }
-    }
-
-    if let Err(stop_error) = manager.stop_recording().await {
-        log::warn!(
-            "Failed to stop active session during no-audio-device transition: {}",
-            stop_error
-        );
-        if let Err(cancel_error) = manager.cancel_recording().await {
-            log::warn!(
-                "Failed to cancel active session during no-audio-device transition: {}",
-                cancel_error
-            );
-        }
-    }
-}
-
-fn no_audio_device_app_error() -> AppError {
-    AppError::new(
-        ErrorKind::NoAudioDevice.to_sidecar(),
-        "No audio input device is available. Connect a microphone and try again.",
-        Some(json!({
-            "reason": "no_available_input_device"
-        })),
-        true,
-    )
-}
-
-fn device_uid_snapshot(devices: &[SidecarAudioDevice]) -> Vec<String> {
-    let mut snapshot: Vec<String> = devices.iter().map(|device| device.uid.clone()).collect();
-    snapshot.sort();
-    snapshot
-}
-
 fn add_seq_to_payload(payload: Value, seq: u64) -> Value {
     crate::event_seq::add_seq_to_payload(payload, seq)
 }
 
-fn sha256_prefix(input: &str) -> String {
-    let mut hasher = Sha256::new();
-    hasher.update(input.as_bytes());
-    let digest = hasher.finalize();
-    let hex = format!("{:x}", digest);
-    hex[..8].to_string()
+/// Sleep until the throttle's next held-back notification is due, or forever if none.
+async fn wait_for_throttle_deadline(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(tokio::time::Instant::from_std(deadline)).await,
+        None => std::future::pending().await,
+    }
 }
 
 fn extract_session_id(params: &Value) -> Option<&str> {
@@ -774,12 +1011,21 @@ fn overlay_recording_state_for_event(
     overlay_enabled: bool,
     event: &RecordingEvent,
 ) -> Option<bool> {
-    if !overlay_enabled {
+    // An utterance of a continuous session leaves the recording running.
+    if !overlay_enabled || matches!(event, RecordingEvent::UtteranceComplete { .. }) {
         return None;
     }
     Some(is_overlay_recording_active(event))
 }
 
+/// Delay before hiding the overlay after `event` ends a recording.
+fn overlay_hide_delay_for_event(event: &RecordingEvent) -> Duration {
+    match event {
+        RecordingEvent::Cancelled { .. } => OVERLAY_CANCEL_ANIMATION,
+        _ => Duration::ZERO,
+    }
+}
+
 fn recording_event_audio_cue(event: &RecordingEvent) -> Option<CueType> {
     match event {
         // StartRecording cue is played pre-roll in start_recording_flow
@@ -792,25 +1038,202 @@ fn recording_event_audio_cue(event: &RecordingEvent) -> Option<CueType> {
     }
 }
 
+/// Dictation activity tracker backing `hotkeys.idle_auto_disable_minutes`.
+#[derive(Debug)]
+struct IdleActivityTimer {
+    last_activity: Instant,
+    /// Set when the timer (not the user) paused hotkey listening.
+    auto_disabled: bool,
+}
+
+impl IdleActivityTimer {
+    fn new(now: Instant) -> Self {
+        Self {
+            last_activity: now,
+            auto_disabled: false,
+        }
+    }
+
+    fn record_activity(&mut self, now: Instant) {
+        self.last_activity = now;
+    }
+
+    /// Time since the last dictation.
+    fn idle_for(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.last_activity)
+    }
+
+    /// Returns true when listening should be paused for inactivity.
+    ///
+    /// Never fires while a session is active, when the limit is zero, or when
+    /// the user already paused listening themselves. Time spent in a session
+    /// counts as activity, so a long dictation does not trip the timer as soon
+    /// as it ends.
+    fn check_idle(
+        &mut self,
+        now: Instant,
+        idle_limit: Duration,
+        enabled: bool,
+        state: AppState,
+    ) -> bool {
+        if enabled && self.auto_disabled {
+            // Re-enabled from the tray or settings rather than by hotkey.
+            self.auto_disabled = false;
+            self.last_activity = now;
+        }
+        if state != AppState::Idle {
+            self.last_activity = now;
+            return false;
+        }
+        if idle_limit.is_zero() || !enabled {
+            return false;
+        }
+        if now.duration_since(self.last_activity) < idle_limit {
+            return false;
+        }
+        self.auto_disabled = true;
+        true
+    }
+
+    /// Consume the auto-disabled marker on a hotkey press.
+    ///
+    /// Returns true when the press should re-enable listening instead of
+    /// starting a recording.
+    fn take_auto_disabled(&mut self, now: Instant) -> bool {
+        let was_auto_disabled = std::mem::take(&mut self.auto_disabled);
+        if was_auto_disabled {
+            self.last_activity = now;
+        }
+        was_auto_disabled
+    }
+}
+
 thread_local! {
     static AUDIO_CUE_MANAGER: RefCell<Option<AudioCueManager>> = const { RefCell::new(None) };
 }
 
+fn recording_event_journal_record(
+    event: &RecordingEvent,
+) -> Option<(JournalEvent, Option<&str>, Option<Value>)> {
+    match event {
+        RecordingEvent::Started { session_id, .. } => Some((
+            JournalEvent::SessionStarted,
+            Some(session_id.as_str()),
+            None,
+        )),
+        RecordingEvent::Stopped {
+            session_id,
+            duration_ms,
+            ..
+        } => Some((
+            JournalEvent::SessionStopped,
+            Some(session_id.as_str()),
+            Some(json!({ "duration_ms": duration_ms })),
+        )),
+        RecordingEvent::TooShort {
+            session_id,
+            duration_ms,
+            ..
+        } => Some((
+            JournalEvent::SessionTooShort,
+            Some(session_id.as_str()),
+            Some(json!({ "duration_ms": duration_ms })),
+        )),
+        RecordingEvent::Cancelled {
+            session_id, reason, ..
+        } => Some((
+            JournalEvent::SessionCancelled,
+            Some(session_id.as_str()),
+            Some(json!({ "reason": reason })),
+        )),
+        RecordingEvent::UtteranceComplete {
+            session_id,
+            segment_index,
+            text,
+            audio_duration_ms,
+            processing_duration_ms,
+            ..
+        } => Some((
+            JournalEvent::Transcribed,
+            Some(session_id.as_str()),
+            Some(json!({
+                "segment_index": segment_index,
+                "text_length": text.len(),
+                "audio_duration_ms": audio_duration_ms,
+                "processing_duration_ms": processing_duration_ms,
+            })),
+        )),
+        RecordingEvent::TranscriptionComplete {
+            session_id,
+            text,
+            audio_duration_ms,
+            processing_duration_ms,
+            ..
+        } => Some((
+            JournalEvent::Transcribed,
+            Some(session_id.as_str()),
+            Some(json!({
+                "text_length": text.len(),
+                "audio_duration_ms": audio_duration_ms,
+                "processing_duration_ms": processing_duration_ms,
+            })),
+        )),
+        RecordingEvent::TranscriptionFailed {
+            session_id, error, ..
+        } => Some((
+            JournalEvent::TranscriptionFailed,
+            Some(session_id.as_str()),
+            Some(json!({ "error_kind": parse_sidecar_transcription_error(error).0 })),
+        )),
+        RecordingEvent::TranscriptionTimeout { session_id, .. } => Some((
+            JournalEvent::TranscriptionTimeout,
+            Some(session_id.as_str()),
+            None,
+        )),
+        RecordingEvent::MaxDurationReached { .. } => None,
+    }
+}
+
+fn injection_result_journal_detail(result: &InjectionResult, entry_id: Uuid) -> Value {
+    let (outcome, text_length) = match result {
+        InjectionResult::Injected { text_length, .. } => ("injected", Some(*text_length)),
+        InjectionResult::ClipboardOnly { text_length, .. } => {
+            ("clipboard_only", Some(*text_length))
+        }
+        InjectionResult::Failed { .. } => ("failed", None),
+    };
+    json!({
+        "outcome": outcome,
+        "text_length": text_length,
+        "entry_id": entry_id,
+    })
+}
+
 fn should_play_lifecycle_audio_cues() -> bool {
     !cfg!(test)
 }
 
-fn play_lifecycle_audio_cue(cue: CueType) {
+fn play_lifecycle_audio_cue(state_manager: &AppStateManager, cue: CueType) {
     if !should_play_lifecycle_audio_cues() {
         return;
     }
+    if !state_manager.level().shows_indicators() {
+        log::debug!("Audio cue {:?} muted: stealth", cue);
+        return;
+    }
+
+    // Re-read config so toggling audio_cues_enabled takes effect
+    // immediately without requiring a restart.
+    let cfg = config::load_config();
+    let status = call_detect::cue_status(&cfg.audio);
+    if status.suppressed {
+        log::debug!("Audio cue {:?} muted: {:?}", cue, status.reason);
+        return;
+    }
 
     AUDIO_CUE_MANAGER.with(|slot| {
         let mut slot = slot.borrow_mut();
         let manager = slot.get_or_insert_with(AudioCueManager::new);
-        // Re-read config so toggling audio_cues_enabled takes effect
-        // immediately without requiring a restart.
-        let cfg = config::load_config();
         manager.set_enabled(cfg.audio.audio_cues_enabled);
         manager.play_cue(cue);
     });
@@ -826,6 +1249,45 @@ fn has_transcription_timed_out(
         .unwrap_or(false)
 }
 
+/// How long a transcription retry waits for the sidecar to become reachable.
+const TRANSCRIPTION_RETRY_RECOVERY_TIMEOUT: Duration = Duration::from_secs(15);
+const TRANSCRIPTION_RETRY_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Transcription errors caused by the sidecar rather than the audio, which
+/// are worth one more attempt once the sidecar is healthy again.
+fn is_transient_transcription_error(kind: &str) -> bool {
+    matches!(
+        ErrorKind::from_sidecar(&canonical_transcription_error_kind(kind)),
+        Some(ErrorKind::TranscriptionTimeout | ErrorKind::SidecarCrash | ErrorKind::SidecarIpc)
+    )
+}
+
+/// Response of the sidecar's `transcription.get_result` method.
+#[derive(Debug, Deserialize)]
+struct TranscriptionLookup {
+    state: String,
+    #[serde(default)]
+    result: Option<Value>,
+    #[serde(default)]
+    error: Option<TranscriptionLookupError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranscriptionLookupError {
+    kind: String,
+    message: String,
+}
+
+/// A finished outcome from a lookup: the `transcription_complete` params, or
+/// the error as delivered by `event.transcription_error`.
+fn recovered_transcription_outcome(lookup: TranscriptionLookup) -> Option<Result<Value, String>> {
+    match (lookup.state.as_str(), lookup.result, lookup.error) {
+        ("completed", Some(result), _) => Some(Ok(result)),
+        ("error", _, Some(error)) => Some(Err(format!("{}: {}", error.kind, error.message))),
+        _ => None,
+    }
+}
+
 fn startup_model_status_requires_loading_state(status: &str) -> bool {
     matches!(status, "downloading" | "loading" | "verifying")
 }
@@ -932,7 +1394,7 @@ fn app_error_event_payload(app_error: &AppError) -> Value {
     })
 }
 
-fn transcript_complete_event_payload(entry: &TranscriptEntry) -> Value {
+pub(crate) fn transcript_complete_event_payload(entry: &TranscriptEntry) -> Value {
     json!({
         "entry": entry
     })
@@ -942,49 +1404,145 @@ fn state_changed_event_payload(event: &StateEvent) -> Value {
     json!({
         "state": event.state,
         "enabled": event.enabled,
+        "level": event.level,
         "detail": event.detail,
         "timestamp": event.timestamp.to_rfc3339(),
     })
 }
 
+fn injection_progress_event_payload(progress: &InjectionProgress) -> Value {
+    json!({
+        "chunks_done": progress.chunks_done,
+        "chunk_count": progress.chunk_count,
+        "chars_injected": progress.chars_injected,
+        "total_chars": progress.total_chars,
+    })
+}
+
+fn injection_queue_changed_payload(depth: usize) -> Value {
+    json!({ "depth": depth })
+}
+
+fn busy_target_clipboard_reason(waited: Duration) -> String {
+    format!(
+        "Target app did not respond for {} s; transcript copied to clipboard.",
+        waited.as_secs()
+    )
+}
+
+/// Canonical `recording:status` event payload.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct RecordingStatusPayload {
+    pub phase: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub session_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub started_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional, type = "number"))]
+    pub audio_ms: Option<u64>,
+    /// Set when the recording started while the screen was shared under the
+    /// `warn` screen share policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub screen_sharing: Option<bool>,
+}
+
 fn recording_status_event_payload(
     phase: &str,
     session_id: Option<&str>,
     started_at: Option<String>,
     audio_ms: Option<u64>,
 ) -> Value {
-    let mut payload = json!({ "phase": phase });
-    if let Some(session_id) = session_id {
-        if let Some(object) = payload.as_object_mut() {
-            object.insert("session_id".to_string(), json!(session_id));
-        }
-    }
-    if let Some(started_at) = started_at {
-        if let Some(object) = payload.as_object_mut() {
-            object.insert("started_at".to_string(), json!(started_at));
-        }
-    }
-    if let Some(audio_ms) = audio_ms {
-        if let Some(object) = payload.as_object_mut() {
-            object.insert("audio_ms".to_string(), json!(audio_ms));
-        }
-    }
-    payload
+    let payload = RecordingStatusPayload {
+        phase: phase.to_string(),
+        session_id: session_id.map(ToString::to_string),
+        started_at,
+        audio_ms,
+        screen_sharing: None,
+    };
+    serde_json::to_value(payload).unwrap_or_else(|_| json!({ "phase": phase }))
 }
 
-fn should_emit_audio_level(
-    now: Instant,
-    last_emitted_at: &mut Option<Instant>,
-    min_interval: Duration,
-) -> bool {
-    if let Some(last) = *last_emitted_at {
-        if now.duration_since(last) < min_interval {
-            return false;
-        }
-    }
+/// `recording:status` payload for a started recording, flagged when the
+/// screen share policy asks to warn about it.
+fn recording_started_event_payload(
+    session_id: &str,
+    started_at: String,
+    share_policy: Option<ScreenSharePolicy>,
+) -> Value {
+    let payload = RecordingStatusPayload {
+        phase: "recording".to_string(),
+        session_id: Some(session_id.to_string()),
+        started_at: Some(started_at),
+        audio_ms: None,
+        screen_sharing: (share_policy == Some(ScreenSharePolicy::Warn)).then_some(true),
+    };
+    serde_json::to_value(payload).unwrap_or_else(|_| json!({ "phase": "recording" }))
+}
+
+/// `session:changed` payload; the same shape `get_active_session` returns.
+fn session_changed_payload(snapshot: &SessionSnapshot) -> Value {
+    json!({
+        "session_id": snapshot.session_id,
+        "phase": snapshot.phase,
+        "revision": snapshot.revision,
+        "created_at": snapshot.created_at,
+        "updated_at": snapshot.updated_at,
+        "started_at": snapshot.started_at,
+        "audio_ms": snapshot.audio_ms,
+        "text": snapshot.text,
+        "error": snapshot.error,
+        "cancel_reason": snapshot.cancel_reason,
+    })
+}
+
+/// `overlay:transcribing` payload for the gap between stop and inject.
+fn overlay_transcribing_payload(session_id: &str, elapsed_ms: u64, audio_ms: u64) -> Value {
+    json!({
+        "session_id": session_id,
+        "elapsed_ms": elapsed_ms,
+        "audio_ms": audio_ms,
+    })
+}
+
+/// `overlay:config` payload with the overlay appearance settings.
+fn overlay_config_payload(appearance: &OverlayAppearance) -> Value {
+    json!({
+        "theme": appearance.theme,
+        "scale": appearance.scale,
+        "opacity": appearance.opacity,
+        "show_waveform": appearance.show_waveform,
+    })
+}
+
+/// `overlay:cancelled` payload for a discarded recording.
+fn overlay_cancelled_payload(session_id: &str, reason: &CancelReason) -> Value {
+    json!({
+        "session_id": session_id,
+        "reason": reason.as_str(),
+    })
+}
+
+/// `overlay:countdown` payload; `remaining_secs` of 0 ends the countdown,
+/// either as capture starts or, when `cancelled`, without a recording.
+fn overlay_countdown_payload(remaining_secs: u32, cancelled: bool) -> Value {
+    json!({
+        "remaining_secs": remaining_secs,
+        "cancelled": cancelled,
+    })
+}
 
-    *last_emitted_at = Some(now);
-    true
+/// `cues:status` payload.
+fn cues_status_payload(status: &CueStatus) -> Value {
+    json!({
+        "suppressed": status.suppressed,
+        "reason": status.reason,
+        "app": status.app,
+    })
 }
 
 fn canonical_transcription_error_kind(sidecar_kind: &str) -> String {
@@ -1068,26 +1626,126 @@ fn injection_method_attempted(reason: &str) -> &'static str {
 }
 
 fn clipboard_only_requires_app_error(reason: &str) -> bool {
-    !reason
-        .to_ascii_lowercase()
-        .starts_with("app override clipboard-only mode")
+    let normalized = reason.to_ascii_lowercase();
+    // User-selected clipboard-only outcomes are not failures.
+    ![
+        "app override clipboard-only mode",
+        "global clipboard-only mode",
+        "ask mode",
+        "injection guard",
+        "pending insert dismissed",
+    ]
+    .iter()
+    .any(|prefix| normalized.starts_with(prefix))
 }
 
-fn injection_failure_app_error(reason: &str, text_length: usize) -> AppError {
-    AppError::new(
-        ErrorKind::InjectionFailed.to_sidecar(),
-        "Automatic text injection failed. Transcript preserved in history.",
-        Some(json!({
-            "method_attempted": injection_method_attempted(reason),
-            "reason": reason,
-            "text_length": text_length
-        })),
-        true,
-    )
+/// A limit that holds a transcript back until the user confirms it, so a
+/// forgotten open mic does not paste a wall of text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InjectionGuard {
+    /// The transcript is longer than `injection.confirm_above_chars`.
+    TextLength { chars: usize, limit: u32 },
+    /// The recording ran longer than `injection.confirm_above_minutes`.
+    AudioDuration { minutes: u64, limit: u32 },
 }
 
-fn validate_recording_start_response(
-    expected_session_id: &str,
+impl InjectionGuard {
+    fn check(config: &config::InjectionConfig, text: &str, audio_duration_ms: u64) -> Option<Self> {
+        let chars = text.chars().count();
+        if config.confirm_above_chars > 0 && chars > config.confirm_above_chars as usize {
+            return Some(Self::TextLength {
+                chars,
+                limit: config.confirm_above_chars,
+            });
+        }
+        let limit_ms = u64::from(config.confirm_above_minutes) * 60_000;
+        if config.confirm_above_minutes > 0 && audio_duration_ms > limit_ms {
+            return Some(Self::AudioDuration {
+                minutes: audio_duration_ms / 60_000,
+                limit: config.confirm_above_minutes,
+            });
+        }
+        None
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::TextLength { .. } => "text_length",
+            Self::AudioDuration { .. } => "audio_duration",
+        }
+    }
+
+    fn clipboard_reason(&self) -> String {
+        let detail = match self {
+            Self::TextLength { chars, limit } => {
+                format!("transcript has {chars} characters (limit {limit})")
+            }
+            Self::AudioDuration { minutes, limit } => {
+                format!("recording ran {minutes} minutes (limit {limit})")
+            }
+        };
+        format!(
+            "{INJECTION_GUARD_REASON_PREFIX}: {detail}; transcript copied to clipboard pending confirmation."
+        )
+    }
+}
+
+/// Canonical `injection:confirm_requested` event payload.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct InjectionConfirmRequestedPayload {
+    pub entry_id: String,
+    pub text_length: usize,
+    /// Limit that held the transcript back; omitted in "ask" mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub guard: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional, type = "number"))]
+    pub audio_duration_ms: Option<u64>,
+}
+
+fn injection_confirm_requested_payload(
+    entry: &TranscriptEntry,
+    guard: Option<InjectionGuard>,
+    audio_duration_ms: u64,
+) -> InjectionConfirmRequestedPayload {
+    InjectionConfirmRequestedPayload {
+        entry_id: entry.id.to_string(),
+        text_length: entry.final_text.len(),
+        guard: guard.map(|guard| guard.as_str().to_string()),
+        audio_duration_ms: guard.map(|_| audio_duration_ms),
+    }
+}
+
+/// Reason to queue a transcript for later insertion, if injection fell back to
+/// the clipboard without the user asking for it.
+fn pending_insert_reason(result: &InjectionResult) -> Option<&str> {
+    match result {
+        InjectionResult::ClipboardOnly { reason, .. }
+            if clipboard_only_requires_app_error(reason) =>
+        {
+            Some(reason.as_str())
+        }
+        _ => None,
+    }
+}
+
+fn injection_failure_app_error(reason: &str, text_length: usize) -> AppError {
+    AppError::new(
+        ErrorKind::InjectionFailed.to_sidecar(),
+        "Automatic text injection failed. Transcript preserved in history.",
+        Some(json!({
+            "method_attempted": injection_method_attempted(reason),
+            "reason": reason,
+            "text_length": text_length
+        })),
+        true,
+    )
+}
+
+fn validate_recording_start_response(
+    expected_session_id: &str,
     response: &Value,
 ) -> Result<(), String> {
     #[derive(Deserialize)]
@@ -1107,25 +1765,43 @@ fn validate_recording_start_response(
     }
 }
 
-fn cannot_record_reason_message(reason: CannotRecordReason) -> &'static str {
-    match reason {
-        CannotRecordReason::Paused => "Recording disabled (paused)",
-        CannotRecordReason::ModelLoading => "Model not ready",
-        CannotRecordReason::AlreadyRecording => "Recording already in progress",
-        CannotRecordReason::StillTranscribing => "Cannot start recording while transcribing",
-        CannotRecordReason::InErrorState => "Cannot start recording while app is in error state",
-    }
-}
-
-fn recording_start_params(session_id: &str, app_config: &config::AppConfig) -> Value {
-    json!({
+fn recording_start_params(
+    session_id: &str,
+    app_config: &config::AppConfig,
+    auto_punctuation: bool,
+) -> Value {
+    let continuous = app_config.audio.continuous_dictation;
+    let mut params = json!({
         "session_id": session_id,
         "device_uid": app_config.audio.device_uid,
         "trim_silence": app_config.audio.trim_silence,
-        "vad_enabled": app_config.audio.vad_enabled,
+        // Continuous dictation finds utterance boundaries with the VAD.
+        "vad_enabled": app_config.audio.vad_enabled || continuous,
         "vad_silence_ms": app_config.audio.vad_silence_ms,
         "vad_min_speech_ms": app_config.audio.vad_min_speech_ms
-    })
+    });
+    // Diarization is for meetings, not ordinary dictation. Only request it when
+    // enabled so older sidecars see unchanged params.
+    if app_config.audio.diarization_enabled && app_config.audio.in_meeting {
+        params["diarize"] = json!(true);
+    }
+    if continuous {
+        params["continuous"] = json!(true);
+        if app_config.audio.live_flush_secs > 0 {
+            params["flush_interval_ms"] = json!(app_config.audio.live_flush_secs * 1000);
+        }
+    }
+    if !app_config.vocabulary.terms.is_empty() {
+        params["hotwords"] = json!(app_config.vocabulary.terms);
+    }
+    if !app_config.audio.input_gains.is_empty() {
+        params["input_gains"] = json!(app_config.audio.input_gains);
+    }
+    // Punctuation is on by default in the sidecar; only send the opt-out.
+    if !auto_punctuation {
+        params["punctuate"] = json!(false);
+    }
+    params
 }
 
 #[derive(Debug, Clone, Default)]
@@ -1170,39 +1846,15 @@ fn pipeline_timings_from_marks(marks: &PipelineTimingMarks) -> Option<Transcript
     }
 }
 
-fn log_pipeline_timings(timings: &TranscriptTimings) {
-    let fmt = |v: Option<u64>| match v {
-        Some(ms) => format!("{}ms", ms),
-        None => "n/a".to_string(),
-    };
-
-    log::info!(
-        "Pipeline: total={} (ipc={}, transcribe={}, postprocess={}, inject={})",
-        fmt(timings.total_ms),
-        fmt(timings.ipc_ms),
-        fmt(timings.transcribe_ms),
-        fmt(timings.postprocess_ms),
-        fmt(timings.inject_ms),
-    );
-}
-
-trait AppEventBroadcaster {
-    fn emit_all(&self, event: &str, payload: Value);
-}
-
-impl AppEventBroadcaster for AppHandle {
-    fn emit_all(&self, event: &str, payload: Value) {
-        let _ = self.emit(event, payload);
-    }
-}
-
 fn emit_with_existing_seq_to_all_windows<B: AppEventBroadcaster>(
     broadcaster: &B,
     event: &str,
     payload: Value,
     seq: u64,
 ) {
-    broadcaster.emit_all(event, add_seq_to_payload(payload, seq));
+    let payload = add_seq_to_payload(payload, seq);
+    state_snapshot::record_event(event, &payload);
+    broadcaster.emit_all(event, payload);
 }
 
 fn emit_with_shared_seq_for_broadcaster<B: AppEventBroadcaster>(
@@ -1268,12 +1920,22 @@ struct RecordingContext {
     language: Option<String>,
     /// Optional confidence reported by sidecar transcription.
     confidence: Option<f32>,
+    /// Segment timestamps reported by sidecar transcription.
+    segments: Vec<TranscriptSegment>,
+    /// Word timestamps and confidence reported by sidecar transcription.
+    words: Vec<TranscriptWord>,
+    /// Effective auto punctuation setting sent with `recording.start`.
+    auto_punctuation: bool,
     /// When true, skip direct injection and force clipboard preservation.
     force_clipboard_only: bool,
     /// Optional reason associated with forced clipboard preservation.
     force_clipboard_reason: Option<String>,
     /// Pipeline timing marks for stop -> injection latency tracking.
     timing_marks: PipelineTimingMarks,
+    /// Transcription retries requested after transient sidecar errors.
+    transcription_retries: u32,
+    /// When the last retry was requested; restarts the transcription timeout.
+    retry_requested_at: Option<Instant>,
 }
 
 /// Central integration manager that wires everything together.
@@ -1281,81 +1943,134 @@ struct RecordingContext {
 pub struct IntegrationManager {
     /// Application state manager.
     state_manager: Arc<AppStateManager>,
-    /// Recording controller.
-    recording_controller: Arc<RecordingController>,
-    /// Hotkey manager.
-    hotkey_manager: Arc<RwLock<HotkeyManager>>,
     /// RPC client (if sidecar is connected).
     rpc_client: Arc<RwLock<Option<RpcClient>>>,
-    /// Sidecar lifecycle supervisor.
+    /// Sidecar lifecycle supervisor for the live (dictation) sidecar.
     supervisor: Arc<Mutex<SidecarSupervisor<SidecarManager>>>,
+    /// Supervisors for every sidecar role, including `supervisor` as `Live`.
+    supervisors: Arc<SupervisorRegistry>,
+    /// RPC client for the auxiliary sidecar, connected on first auxiliary call.
+    auxiliary_rpc_client: Arc<RwLock<Option<RpcClient>>>,
     /// Tauri app handle.
     app_handle: Option<AppHandle>,
-    /// Focus context for current recording.
-    recording_context: Arc<RwLock<Option<RecordingContext>>>,
-    /// Current active recording/transcription session for correlation.
-    current_session_id: Arc<RwLock<Option<String>>>,
     /// Configuration.
     config: IntegrationConfig,
-    /// Current model status.
-    model_status: Arc<RwLock<ModelStatus>>,
-    /// Current model download progress.
-    model_progress: Arc<RwLock<Option<ModelProgress>>>,
-    /// Whether model initialization has been attempted.
-    model_init_attempted: Arc<AtomicBool>,
     /// Watchdog for sidecar health monitoring.
     watchdog: Arc<Watchdog>,
     /// Monotonic event sequence counter for frontend events.
     event_seq: Arc<AtomicU64>,
-    /// Overlay window lifecycle manager.
-    overlay_manager: Arc<Mutex<OverlayManager>>,
-    /// Notification channel for overlay config changes (event-driven, no polling).
-    overlay_config_notify: Arc<Notify>,
-    /// Cached overlay-enabled flag so disabled mode avoids per-event config reads.
-    overlay_enabled: Arc<AtomicBool>,
+    /// ASR model lifecycle.
+    model: ModelService,
+    /// Audio devices and the mic test meter.
+    audio: AudioService,
+    /// Recording sessions and transcript delivery.
+    session: SessionService,
+    /// Overlay window lifecycle.
+    overlay: OverlayService,
+    /// Global hotkeys driving the session.
+    hotkeys: HotkeyService,
+    /// Watchdog and error recovery loops.
+    health: WatchdogService,
 }
 
 impl IntegrationManager {
     /// Create a new integration manager.
     pub fn new(state_manager: Arc<AppStateManager>) -> Self {
-        let recording_controller = Arc::new(RecordingController::new(Arc::clone(&state_manager)));
-        let watchdog = Arc::new(Watchdog::with_config(WatchdogConfig::default()));
         let config = IntegrationConfig::default();
         let app_config = config::load_config();
-        let mut sidecar_manager = SidecarManager::new();
-        sidecar_manager.set_python_mode(config.python_path.clone(), config.sidecar_module.clone());
-        let supervisor = Arc::new(Mutex::new(SidecarSupervisor::new(
-            sidecar_manager,
-            SidecarSupervisorConfig {
-                captured_log_max_lines: app_config.supervisor.captured_log_max_lines,
-                ..SidecarSupervisorConfig::default()
-            },
+        let watchdog = Arc::new(Watchdog::with_config(WatchdogConfig::from_supervisor(
+            &app_config.supervisor,
         )));
+        let supervisor_config = SidecarSupervisorConfig {
+            captured_log_max_lines: app_config.supervisor.captured_log_max_lines,
+            ..SidecarSupervisorConfig::default()
+        };
+        let new_sidecar_manager = || {
+            let mut sidecar_manager = SidecarManager::new();
+            sidecar_manager
+                .set_python_mode(config.python_path.clone(), config.sidecar_module.clone());
+            sidecar_manager
+        };
+        // The auxiliary supervisor never gets an app handle: its status must not
+        // drive the live sidecar indicators in the UI.
+        let supervisors = Arc::new(
+            SupervisorRegistry::new()
+                .with_supervisor(
+                    SidecarRole::Live,
+                    SidecarSupervisor::new(new_sidecar_manager(), supervisor_config.clone()),
+                )
+                .with_supervisor(
+                    SidecarRole::Auxiliary,
+                    SidecarSupervisor::new(new_sidecar_manager(), supervisor_config),
+                ),
+        );
+        let supervisor = supervisors
+            .get(SidecarRole::Live)
+            .expect("live sidecar supervisor is always registered");
+
+        let rpc_client = Arc::new(RwLock::new(None));
+        let event_seq = Arc::new(AtomicU64::new(1));
+        let overlay = OverlayService::new(Arc::clone(&event_seq), app_config.ui.overlay_enabled);
+        let session = SessionService::new(
+            Arc::clone(&state_manager),
+            Arc::clone(&rpc_client),
+            Arc::clone(&event_seq),
+            overlay.clone(),
+        );
+        let model = ModelService::new(
+            Arc::clone(&state_manager),
+            Arc::clone(&session.recording_controller),
+            Arc::clone(&rpc_client),
+            Arc::clone(&event_seq),
+        );
+        let audio = AudioService::new(
+            Arc::clone(&rpc_client),
+            Arc::clone(&state_manager),
+            Arc::clone(&event_seq),
+            session.clone(),
+        );
+        let health = WatchdogService::new(
+            Arc::clone(&watchdog),
+            Arc::clone(&state_manager),
+            Arc::clone(&rpc_client),
+            Arc::clone(&event_seq),
+            Arc::clone(&model.status),
+            Arc::clone(&session.recording_controller),
+        );
+        let hotkeys = HotkeyService::new(
+            Arc::clone(&state_manager),
+            Arc::clone(&event_seq),
+            session.clone(),
+        );
 
         Self {
             state_manager,
-            recording_controller,
-            hotkey_manager: Arc::new(RwLock::new(HotkeyManager::new())),
-            rpc_client: Arc::new(RwLock::new(None)),
+            rpc_client,
             supervisor,
+            supervisors,
+            auxiliary_rpc_client: Arc::new(RwLock::new(None)),
             app_handle: None,
-            recording_context: Arc::new(RwLock::new(None)),
-            current_session_id: Arc::new(RwLock::new(None)),
             config,
-            model_status: Arc::new(RwLock::new(ModelStatus::Unknown)),
-            model_progress: Arc::new(RwLock::new(None)),
-            model_init_attempted: Arc::new(AtomicBool::new(false)),
             watchdog,
-            event_seq: Arc::new(AtomicU64::new(1)),
-            overlay_manager: Arc::new(Mutex::new(OverlayManager::new())),
-            overlay_config_notify: Arc::new(Notify::new()),
-            overlay_enabled: Arc::new(AtomicBool::new(app_config.ui.overlay_enabled)),
+            event_seq,
+            model,
+            audio,
+            session,
+            overlay,
+            hotkeys,
+            health,
         }
     }
 
     /// Set the Tauri app handle.
     pub fn set_app_handle(&mut self, handle: AppHandle) {
         self.app_handle = Some(handle.clone());
+        self.model.set_app_handle(handle.clone());
+        self.audio.set_app_handle(handle.clone());
+        self.session.set_app_handle(handle.clone());
+        self.overlay.set_app_handle(handle.clone());
+        self.hotkeys.set_app_handle(handle.clone());
+        self.health.set_app_handle(handle.clone());
         if let Ok(mut supervisor) = self.supervisor.try_lock() {
             supervisor.set_app_handle(handle);
         }
@@ -1368,30 +2083,20 @@ impl IntegrationManager {
 
     /// Get the recording controller.
     pub fn recording_controller(&self) -> &Arc<RecordingController> {
-        &self.recording_controller
+        &self.session.recording_controller
+    }
+
+    /// Re-register hotkeys after the bindings in effect changed, such as on a
+    /// profile switch.
+    pub async fn reload_hotkeys(&self) {
+        self.hotkeys.reload().await;
     }
 
     /// Initialize and start all components.
-    pub async fn initialize(&self) -> Result<(), String> {
+    pub async fn initialize(&self) -> Result<(), IntegrationError> {
         log::info!("Initializing integration manager");
 
-        // Initialize hotkey manager
-        {
-            let mut hotkey_manager = self.hotkey_manager.write().await;
-            match hotkey_manager.initialize() {
-                Ok(status) => {
-                    log::info!(
-                        "Hotkey registered: primary={}, mode={}",
-                        status.primary,
-                        status.mode
-                    );
-                }
-                Err(e) => {
-                    log::warn!("Failed to register hotkeys: {}", e);
-                    // Continue anyway - user can fix in settings
-                }
-            }
-        }
+        self.hotkeys.register().await;
 
         // Start sidecar if configured
         if self.config.auto_start_sidecar {
@@ -1399,755 +2104,52 @@ impl IntegrationManager {
         }
 
         // Start event loops
-        self.start_hotkey_loop();
-        self.start_state_loop();
-        self.start_recording_event_loop();
-        self.start_overlay_window_loop();
-        self.start_device_hot_swap_loop();
+        self.hotkeys.start_loop();
+        self.session.start_state_loop();
+        self.audio.start_cue_status_loop();
+        self.session.start_recording_event_loop();
+        self.overlay.start_overlay_window_loop();
+        self.audio.start_device_hot_swap_loop();
+        self.overlay.start_focus_preview_loop();
+        self.session.start_idle_auto_disable_loop();
+        self.model.start_full_pause_loop();
+        self.model.start_idle_suspend_loop(&self.session);
+        self.start_announcement_loop();
+        self.session.start_injection_queue_loop();
 
         // Start watchdog loop
-        self.start_watchdog_loop();
+        self.health.start_loop(self.clone());
+        self.health.start_power_profile_loop();
+        self.health.start_error_recovery_loop();
 
         log::info!("Integration manager initialized");
         Ok(())
     }
 
-    /// Start overlay config-gate loop.
-    ///
-    /// Ensures the overlay window is pre-created when `ui.overlay_enabled=true`
-    /// and destroyed when disabled. The actual show/hide is driven by recording
-    /// events in `start_recording_event_loop`.
-    fn start_overlay_window_loop(&self) {
-        let app_handle = self.app_handle.clone();
-        let overlay_manager = Arc::clone(&self.overlay_manager);
-        let overlay_config_notify = Arc::clone(&self.overlay_config_notify);
-        let overlay_enabled = Arc::clone(&self.overlay_enabled);
-
-        tokio::spawn(async move {
-            let Some(handle) = app_handle else {
-                log::warn!("Overlay window loop skipped: app handle missing");
-                return;
-            };
-
-            let config_store = FileOverlayConfigStore;
-            let mut last_enabled: Option<bool> = None;
-
-            log::info!("Overlay config-gate loop started");
-
-            loop {
-                let enabled = config::load_config().ui.overlay_enabled;
-                overlay_enabled.store(enabled, Ordering::Release);
-                if !should_apply_overlay_config_change(last_enabled, enabled) {
-                    overlay_config_notify.notified().await;
-                    continue;
-                }
-
-                let backend = TauriOverlayWindowBackend::new(&handle);
-
-                if !enabled {
-                    // Disabled: hide and destroy any existing window.
-                    let mut manager = overlay_manager.lock().await;
-                    if let Err(error) = manager.hide(&config_store, &backend) {
-                        log::warn!("Overlay hide on disable failed: {error}");
-                    }
-                } else {
-                    // Enabled: pre-create the window (hidden) so show is fast.
-                    let backend_ref = &backend;
-                    if !backend_ref.window_exists(OVERLAY_WINDOW_LABEL) {
-                        if let Err(error) = backend_ref.create_window(OVERLAY_WINDOW_LABEL) {
-                            log::warn!("Overlay window pre-creation failed: {error}");
-                        }
-                    }
-                }
-
-                last_enabled = Some(enabled);
-                overlay_config_notify.notified().await;
-            }
-        });
-    }
-
     pub fn notify_overlay_config_changed(&self) {
-        self.overlay_config_notify.notify_waiters();
-    }
-
-    fn emit_app_error_event(
-        app_handle: &Option<AppHandle>,
-        event_seq: &Arc<AtomicU64>,
-        app_error: &AppError,
-    ) {
-        if let Some(ref handle) = app_handle {
-            emit_with_shared_seq(
-                handle,
-                &[EVENT_APP_ERROR],
-                app_error_event_payload(app_error),
-                event_seq,
-            );
-        }
-    }
-
-    fn persist_default_audio_device_selection() -> Result<(), String> {
-        let mut app_config = config::load_config();
-        app_config.audio.device_uid = None;
-        config::save_config(&app_config).map_err(|error| format!("Failed to save config: {error}"))
-    }
-
-    /// Start device hot-swap monitor loop.
-    ///
-    /// Polls audio.list_devices and handles selected-device disappearance with:
-    /// - stop active recording/transcription handling
-    /// - default device fallback
-    /// - structured, recoverable app:error emission when active session is impacted
-    fn start_device_hot_swap_loop(&self) {
-        let manager = self.clone();
-        let state_manager = Arc::clone(&self.state_manager);
-        let recording_context = Arc::clone(&self.recording_context);
-        let current_session_id = Arc::clone(&self.current_session_id);
-        let app_handle = self.app_handle.clone();
-        let event_seq = Arc::clone(&self.event_seq);
-
-        tokio::spawn(async move {
-            let mut tick = tokio::time::interval(DEVICE_HOT_SWAP_POLL_INTERVAL);
-            tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-
-            let mut previous_devices: Option<Vec<SidecarAudioDevice>> = None;
-            let mut last_change_handled_at: Option<Instant> = None;
-            let mut last_device_list_error_at: Option<Instant> = None;
-
-            loop {
-                tick.tick().await;
-
-                let devices = match manager.list_audio_devices().await {
-                    Ok(devices) => devices,
-                    Err(error) => {
-                        let now = Instant::now();
-                        let should_log = last_device_list_error_at
-                            .is_none_or(|last| now.duration_since(last) >= Duration::from_secs(5));
-                        if should_log {
-                            log::debug!("Device hot-swap poll skipped: {}", error);
-                            last_device_list_error_at = Some(now);
-                        }
-                        continue;
-                    }
-                };
-
-                // Keep the tray device cache up to date on every successful poll.
-                if let Some(ref handle) = app_handle {
-                    let cache = handle.state::<crate::tray::TrayDeviceCache>();
-                    cache.update(
-                        &devices
-                            .iter()
-                            .map(|d| (d.uid.clone(), d.name.clone()))
-                            .collect::<Vec<_>>(),
-                    );
-                }
-
-                let Some(previous) = previous_devices.as_ref() else {
-                    previous_devices = Some(devices);
-                    continue;
-                };
-
-                if device_uid_snapshot(previous) == device_uid_snapshot(&devices) {
-                    continue;
-                }
-
-                let now = Instant::now();
-                if last_change_handled_at
-                    .is_some_and(|last| now.duration_since(last) < DEVICE_HOT_SWAP_DEBOUNCE)
-                {
-                    previous_devices = Some(devices);
-                    continue;
-                }
-                last_change_handled_at = Some(now);
-
-                if let Some(ref handle) = app_handle {
-                    emit_with_shared_seq(
-                        handle,
-                        &[EVENT_TRAY_UPDATE],
-                        json!({
-                            "reason": "device_list_changed",
-                            "device_count": devices.len(),
-                        }),
-                        &event_seq,
-                    );
-                }
-
-                if devices.is_empty() {
-                    cleanup_active_session_for_no_audio_devices(
-                        &manager,
-                        &state_manager,
-                        &recording_context,
-                        &current_session_id,
-                    )
-                    .await;
-                    state_manager.transition_to_error(
-                        "No audio input device available. Connect a microphone and try again."
-                            .to_string(),
-                    );
-                    let app_error = no_audio_device_app_error();
-                    Self::emit_app_error_event(&app_handle, &event_seq, &app_error);
-                    previous_devices = Some(devices);
-                    continue;
-                }
-
-                let configured_device_uid = config::load_config().audio.device_uid;
-                let state = state_manager.get();
-                let decision = decide_device_hot_swap(
-                    state,
-                    configured_device_uid.as_deref(),
-                    previous,
-                    &devices,
-                );
-
-                if let Some(removed_uid) = decision.removed_device_uid.clone() {
-                    let removed_name = decision
-                        .removed_device_name
-                        .clone()
-                        .unwrap_or_else(|| removed_uid.clone());
-                    let had_active_recording =
-                        decision.should_emit_error || current_session_id.read().await.is_some();
-                    let mut transcript_preserved = false;
-
-                    if decision.should_stop_recording {
-                        if let Err(error) = manager.stop_recording().await {
-                            log::warn!(
-                                "Failed to stop recording after device removal (partial audio may be lost): {}",
-                                error
-                            );
-                        }
-                    }
-
-                    if decision.should_force_clipboard_only {
-                        let mut context = recording_context.write().await;
-                        if let Some(context) = context.as_mut() {
-                            context.force_clipboard_only = true;
-                            context.force_clipboard_reason =
-                                Some(DEVICE_REMOVED_CLIPBOARD_REASON.to_string());
-                            transcript_preserved = true;
-                        }
-                    }
-
-                    let fallback_device_uid = match manager.set_audio_device(None).await {
-                        Ok(uid) => uid,
-                        Err(error) => {
-                            log::warn!("Failed to switch to default audio device: {}", error);
-                            None
-                        }
-                    };
-
-                    if let Err(error) = Self::persist_default_audio_device_selection() {
-                        log::warn!(
-                            "Failed to persist default audio device after hot-swap: {}",
-                            error
-                        );
-                    }
-
-                    if let Some(ref handle) = app_handle {
-                        emit_with_shared_seq(
-                            handle,
-                            &[EVENT_TRAY_UPDATE],
-                            json!({
-                                "reason": "device_changed",
-                                "removed_device_uid": removed_uid,
-                                "active_device_uid": fallback_device_uid,
-                            }),
-                            &event_seq,
-                        );
-                    }
-
-                    if decision.should_emit_error {
-                        let app_error = device_removed_app_error(
-                            removed_uid,
-                            removed_name,
-                            fallback_device_uid,
-                            had_active_recording,
-                            transcript_preserved,
-                        );
-                        Self::emit_app_error_event(&app_handle, &event_seq, &app_error);
-                    } else {
-                        log::info!(
-                            "Configured audio device removed while idle; switched to default device"
-                        );
-                    }
-                }
-
-                previous_devices = Some(devices);
-            }
-        });
+        self.overlay.notify_config_changed();
     }
 
-    /// Start the watchdog monitoring loop.
-    fn start_watchdog_loop(&self) {
-        let rpc_client = Arc::clone(&self.rpc_client);
-        let watchdog = Arc::clone(&self.watchdog);
-        let state_manager = Arc::clone(&self.state_manager);
-        let recording_controller = Arc::clone(&self.recording_controller);
-        let model_status = Arc::clone(&self.model_status);
-        let app_handle = self.app_handle.clone();
-        let event_seq = Arc::clone(&self.event_seq);
-        let manager = self.clone();
-
-        // Create ping adapter
-        let pinger = Arc::new(RpcPinger {
-            rpc_client: Arc::clone(&rpc_client),
-        });
-
-        // Start the watchdog loop
-        watchdog.start_loop(pinger);
-
-        // Start platform power listener (if available) and feed events into watchdog.
-        if let Some(mut power_rx) = watchdog::platform::start_power_listener() {
-            let watchdog_for_power = Arc::clone(&watchdog);
-            tokio::spawn(async move {
-                log::info!("Power event listener started");
-                while let Some(event) = power_rx.recv().await {
-                    watchdog_for_power.on_power_event(event).await;
-                }
-                log::info!("Power event listener ended");
-            });
-        } else {
-            log::info!(
-                "Power event listener unavailable; watchdog will infer resume via loop-gap fallback"
-            );
-        }
-
-        // Start event handler loop
-        let watchdog_for_events = Arc::clone(&watchdog);
-        let mut event_rx = watchdog.subscribe();
-        tokio::spawn(async move {
-            log::info!("Watchdog event handler started");
-
-            while let Ok(event) = event_rx.recv().await {
-                match event {
-                    WatchdogEvent::HealthCheck { status } => {
-                        log::debug!("Watchdog health check: {:?}", status);
-                        // Could emit event to frontend for status display
-                    }
-                    WatchdogEvent::SidecarRecoveryRequested { reason } => {
-                        log::error!(
-                            "Watchdog requested supervisor recovery for sidecar: {}",
-                            reason
-                        );
-                        // Transition to error state
-                        state_manager
-                            .transition_to_error("Sidecar hung, restarting...".to_string());
-
-                        // Keep legacy event for frontend compatibility while recovery moves through
-                        // supervisor policy.
-                        if let Some(ref handle) = app_handle {
-                            emit_with_shared_seq(
-                                handle,
-                                &["sidecar:restart"],
-                                serde_json::json!({ "reason": reason }),
-                                &event_seq,
-                            );
-                        }
-
-                        if let Err(err) = manager.recover_sidecar_from_watchdog().await {
-                            log::error!("Watchdog recovery via supervisor failed: {}", err);
-                        }
-                    }
-                    WatchdogEvent::SidecarHung => {
-                        // Legacy event retained in watchdog for observability.
-                        // Recovery is handled by the SidecarRecoveryRequested branch above;
-                        // calling recover here would trigger a duplicate attempt.
-                        log::warn!("Watchdog: legacy SidecarHung event received (no-op)");
-                    }
-                    WatchdogEvent::SystemResumed => {
-                        log::info!("System resumed from suspend, triggering revalidation");
-                    }
-                    WatchdogEvent::RevalidationNeeded => {
-                        log::info!("Revalidation needed after resume");
-
-                        // Revalidate sidecar connection
-                        let client = rpc_client.read().await;
-                        if let Some(ref c) = *client {
-                            #[derive(serde::Deserialize)]
-                            struct PingResult {
-                                #[allow(dead_code)]
-                                version: String,
-                            }
-
-                            match c.call::<PingResult>("system.ping", None).await {
-                                Ok(_) => {
-                                    log::info!("Sidecar responsive after resume");
-                                }
-                                Err(e) => {
-                                    log::warn!("Sidecar unresponsive after resume: {}", e);
-                                    state_manager.transition_to_error(
-                                        "Sidecar unresponsive after resume".to_string(),
-                                    );
-                                }
-                            }
-                        }
-
-                        // Revalidate model status
-                        #[derive(serde::Deserialize)]
-                        struct StatusResult {
-                            status: String,
-                        }
-
-                        let client = rpc_client.read().await;
-                        if let Some(ref c) = *client {
-                            match c.call::<StatusResult>("model.get_status", None).await {
-                                Ok(result) => {
-                                    log::info!("Model status after resume: {}", result.status);
-                                    match result.status.as_str() {
-                                        "ready" => {
-                                            recording_controller.set_model_ready(true).await;
-                                            *model_status.write().await = ModelStatus::Ready;
-                                        }
-                                        "downloading" => {
-                                            recording_controller.set_model_ready(false).await;
-                                            *model_status.write().await = ModelStatus::Downloading;
-                                        }
-                                        "loading" | "verifying" => {
-                                            recording_controller.set_model_ready(false).await;
-                                            *model_status.write().await = ModelStatus::Loading;
-                                        }
-                                        _ => {
-                                            recording_controller.set_model_ready(false).await;
-                                            *model_status.write().await = ModelStatus::Missing;
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    log::warn!("Failed to get model status after resume: {}", e);
-                                }
-                            }
-                        }
-
-                        // Revalidate available input devices and configured device presence.
-                        let client = rpc_client.read().await;
-                        if let Some(ref c) = *client {
-                            match c.call::<AudioListResult>("audio.list_devices", None).await {
-                                Ok(result) => {
-                                    let configured_uid = config::load_config().audio.device_uid;
-                                    if !is_configured_device_available(
-                                        configured_uid.as_deref(),
-                                        &result.devices,
-                                    ) {
-                                        if let Some(uid) = configured_uid {
-                                            log::warn!(
-                                                "Configured audio device missing after resume: {}",
-                                                uid
-                                            );
-                                            state_manager.transition_to_error(format!(
-                                                "Configured audio device unavailable after resume: {}",
-                                                uid
-                                            ));
-                                        }
-                                    } else {
-                                        log::info!(
-                                            "Audio devices revalidated after resume ({} devices)",
-                                            result.devices.len()
-                                        );
-                                    }
-
-                                    if let Some(ref handle) = app_handle {
-                                        emit_with_shared_seq(
-                                            handle,
-                                            &[EVENT_TRAY_UPDATE],
-                                            json!({
-                                                "reason": "device_list_changed",
-                                                "device_count": result.devices.len(),
-                                            }),
-                                            &event_seq,
-                                        );
-                                    }
-                                }
-                                Err(e) => {
-                                    log::warn!("Failed to list audio devices after resume: {}", e);
-                                }
-                            }
-                        }
-
-                        watchdog_for_events.clear_revalidation_pending().await;
-                    }
-                }
-            }
-
-            log::info!("Watchdog event handler ended");
-        });
-    }
-
-    /// Spawn model check and initialization in background.
-    fn spawn_model_check(&self) {
-        let rpc_client = Arc::clone(&self.rpc_client);
-        let state_manager = Arc::clone(&self.state_manager);
-        let recording_controller = Arc::clone(&self.recording_controller);
-        let model_status = Arc::clone(&self.model_status);
-        let model_init_attempted = Arc::clone(&self.model_init_attempted);
-        let app_handle = self.app_handle.clone();
-        let event_seq = Arc::clone(&self.event_seq);
-
-        tokio::spawn(async move {
-            // Check if already attempted
-            if model_init_attempted.swap(true, Ordering::SeqCst) {
-                log::debug!("Model initialization already attempted");
-                return;
-            }
-
-            // Query model status from sidecar
-            let client = rpc_client.read().await;
-            let client = match client.as_ref() {
-                Some(c) => c,
-                None => {
-                    log::warn!("Cannot check model status: sidecar not connected");
-                    return;
-                }
-            };
-
-            log::info!("Checking model status on startup");
-
-            #[derive(Deserialize, Debug)]
-            struct StatusResult {
-                status: String,
-                #[serde(default)]
-                model_id: Option<String>,
-                #[serde(default)]
-                revision: Option<String>,
-                #[serde(default)]
-                cache_path: Option<String>,
-                #[serde(default)]
-                progress: Option<ProgressResult>,
-            }
-
-            #[derive(Deserialize, Debug)]
-            struct ProgressResult {
-                current: u64,
-                #[serde(default)]
-                total: Option<u64>,
-                #[serde(default)]
-                unit: Option<String>,
-                #[serde(default)]
-                stage: Option<String>,
-            }
-
-            match client.call::<StatusResult>("model.get_status", None).await {
-                Ok(result) => {
-                    log::info!("Model status: {:?}", result);
-                    let status_progress = result.progress.as_ref().map(|progress| {
-                        status_progress_from_parts(
-                            progress.current,
-                            progress.total,
-                            progress.unit.clone(),
-                            progress.stage.clone(),
-                        )
-                    });
-
-                    match result.status.as_str() {
-                        "ready" => {
-                            *model_status.write().await = ModelStatus::Ready;
-                            recording_controller.set_model_ready(true).await;
-                            let _ = state_manager.transition(AppState::Idle);
-                            Self::emit_model_status_with_details(
-                                &app_handle,
-                                ModelStatus::Ready,
-                                &event_seq,
-                                result.model_id.clone(),
-                                result.revision.clone(),
-                                result.cache_path.clone(),
-                                status_progress.clone(),
-                            );
-                            log::info!("Model ready for transcription");
-                        }
-                        "missing" | "not_found" | "error" => {
-                            log::info!(
-                                "Model not ready ({}), triggering initialization",
-                                result.status
-                            );
-                            *model_status.write().await = ModelStatus::Missing;
-                            Self::emit_model_status_with_details(
-                                &app_handle,
-                                ModelStatus::Missing,
-                                &event_seq,
-                                result.model_id.clone(),
-                                result.revision.clone(),
-                                result.cache_path.clone(),
-                                status_progress.clone(),
-                            );
-
-                            // Trigger model initialization
-                            Self::trigger_model_init(
-                                client,
-                                &state_manager,
-                                &recording_controller,
-                                &model_status,
-                                &app_handle,
-                                &event_seq,
-                            )
-                            .await;
-                        }
-                        "downloading" | "loading" | "verifying" => {
-                            // Already in progress (maybe from another session)
-                            debug_assert!(startup_model_status_requires_loading_state(
-                                result.status.as_str()
-                            ));
-                            let _ = state_manager.transition(AppState::LoadingModel);
-                            recording_controller.set_model_ready(false).await;
-                            let status = if result.status == "downloading" {
-                                ModelStatus::Downloading
-                            } else {
-                                ModelStatus::Loading
-                            };
-                            *model_status.write().await = status.clone();
-                            Self::emit_model_status_with_details(
-                                &app_handle,
-                                status,
-                                &event_seq,
-                                result.model_id.clone(),
-                                result.revision.clone(),
-                                result.cache_path.clone(),
-                                status_progress.clone(),
-                            );
-                            log::info!(
-                                "Model {} in progress; app state set to loading_model",
-                                result.status
-                            );
-                        }
-                        _ => {
-                            log::warn!("Unknown model status: {}", result.status);
-                            *model_status.write().await = ModelStatus::Unknown;
-                            Self::emit_model_status_with_details(
-                                &app_handle,
-                                ModelStatus::Unknown,
-                                &event_seq,
-                                result.model_id.clone(),
-                                result.revision.clone(),
-                                result.cache_path.clone(),
-                                status_progress.clone(),
-                            );
-                        }
-                    }
-                }
-                Err(e) => {
-                    log::warn!("Failed to get model status: {}", e);
-                    // Don't block on this - user can trigger manually
-                }
-            }
-        });
-    }
-
-    /// Trigger model initialization via sidecar.
-    async fn trigger_model_init(
-        client: &RpcClient,
-        state_manager: &Arc<AppStateManager>,
-        recording_controller: &Arc<RecordingController>,
-        model_status: &Arc<RwLock<ModelStatus>>,
-        app_handle: &Option<AppHandle>,
-        event_seq: &Arc<AtomicU64>,
-    ) {
-        // Transition to loading state
-        let _ = state_manager.transition(AppState::LoadingModel);
-        *model_status.write().await = ModelStatus::Downloading;
-        Self::emit_model_status(app_handle, ModelStatus::Downloading, event_seq);
-
-        // Get configured model or use default
-        let config = config::load_config();
-        let model_id = config
-            .model
-            .as_ref()
-            .and_then(|m| m.model_id.clone())
-            .unwrap_or_else(|| model_defaults::default_model_id().to_string());
-
-        let raw_device = config.model.as_ref().and_then(|m| m.device.as_deref());
-        let raw_preferred = config
-            .model
-            .as_ref()
-            .map(|m| m.preferred_device.as_str())
-            .unwrap_or("auto");
-        let device_pref = config.effective_model_device_pref();
-
-        log::info!(
-            "Resolved model device preference: model.device={:?}, model.preferred_device='{}', effective='{}'",
-            raw_device,
-            raw_preferred,
-            device_pref
-        );
-
-        log::info!(
-            "Initializing ASR model: model={}, device={}, language={:?}",
-            model_id,
-            device_pref,
-            configured_model_language_hint(&config)
-        );
-        let language = configured_model_language_hint(&config);
-
-        match call_asr_initialize_with_language_fallback(client, &model_id, &device_pref, language)
-            .await
-        {
-            Ok(result) => {
-                log::info!("ASR initialization complete: status={}", result.status);
-                *model_status.write().await = ModelStatus::Ready;
-                recording_controller.set_model_ready(true).await;
-                let _ = state_manager.transition(AppState::Idle);
-                Self::emit_model_status(app_handle, ModelStatus::Ready, event_seq);
-            }
-            Err(e) => {
-                log::error!("ASR initialization failed: {}", e);
-                let error_msg = format!("Model initialization failed: {}", e);
-                *model_status.write().await = ModelStatus::Error(error_msg.clone());
-                state_manager.transition_to_error(error_msg.clone());
-                Self::emit_model_status(app_handle, ModelStatus::Error(error_msg), event_seq);
-            }
-        }
-    }
-
-    /// Emit model status event to frontend.
-    fn emit_model_status(
-        app_handle: &Option<AppHandle>,
-        status: ModelStatus,
-        seq_counter: &Arc<AtomicU64>,
-    ) {
-        Self::emit_model_status_with_details(
-            app_handle,
-            status,
-            seq_counter,
-            None,
-            None,
-            None,
-            None,
+    /// Start screen-reader announcement loop (gated by `ui.announcements_enabled`).
+    fn start_announcement_loop(&self) {
+        a11y::spawn_announcement_loop(
+            self.state_manager.subscribe(),
+            self.session.recording_controller.subscribe(),
+            Arc::new(a11y::SystemAnnouncer::new()),
         );
     }
 
-    /// Emit canonical model status event with optional metadata.
-    fn emit_model_status_with_details(
-        app_handle: &Option<AppHandle>,
-        status: ModelStatus,
-        seq_counter: &Arc<AtomicU64>,
-        model_id: Option<String>,
-        revision: Option<String>,
-        cache_path: Option<String>,
-        progress: Option<ModelStatusProgress>,
-    ) {
-        if let Some(ref handle) = app_handle {
-            let payload =
-                model_status_event_payload(status, model_id, revision, cache_path, progress);
-
-            emit_with_shared_seq(handle, &[EVENT_MODEL_STATUS], json!(payload), seq_counter);
-        }
-    }
-
     /// Get current model status.
     pub async fn get_model_status(&self) -> ModelStatus {
-        self.model_status.read().await.clone()
+        self.model.get_status().await
     }
 
     /// Query live model status from sidecar RPC.
     pub async fn query_model_status(
         &self,
         model_id: Option<String>,
-    ) -> Result<SidecarModelStatus, String> {
-        let client = self.rpc_client.read().await;
-        let client = client
-            .as_ref()
-            .ok_or_else(|| "E_SIDECAR_IPC: Sidecar not connected".to_string())?;
-
-        let params = model_id.map(|id| json!({ "model_id": id }));
-        client
-            .call::<SidecarModelStatus>("model.get_status", params)
-            .await
-            .map_err(|e| format!("E_SIDECAR_IPC: Failed to query model status: {}", e))
+    ) -> Result<SidecarModelStatus, IntegrationError> {
+        self.model.query_status(model_id).await
     }
 
     /// Manually trigger model download.
@@ -2155,441 +2157,101 @@ impl IntegrationManager {
         &self,
         model_id: Option<String>,
         force: Option<bool>,
-    ) -> Result<(), String> {
-        let client = self.rpc_client.read().await;
-        let client = client
-            .as_ref()
-            .ok_or_else(|| "E_SIDECAR_IPC: Sidecar not connected".to_string())?;
-
-        let _ = self.state_manager.transition(AppState::LoadingModel);
-        *self.model_status.write().await = ModelStatus::Downloading;
-        self.recording_controller.set_model_ready(false).await;
-        Self::emit_model_status(&self.app_handle, ModelStatus::Downloading, &self.event_seq);
-
-        let params = model_download_params(model_id, force);
-        let resolved_model_id = resolve_model_id(
-            params
-                .as_ref()
-                .and_then(|value| value.get("model_id"))
-                .and_then(Value::as_str)
-                .map(ToString::to_string),
-        );
-        let status_result = match client
-            .call::<SidecarModelStatus>("model.install", params.clone())
-            .await
-        {
-            Ok(status) => Ok(status),
-            Err(RpcError::Remote { kind, .. }) if kind == "E_METHOD_NOT_FOUND" => {
-                log::info!(
-                    "model.install unsupported by sidecar; falling back to legacy model.download + asr.initialize"
-                );
-                match client
-                    .call::<SidecarModelStatus>("model.download", params.clone())
-                    .await
-                {
-                    Ok(mut status) => {
-                        let config = config::load_config();
-                        let device_pref = config.effective_model_device_pref();
-                        let language = configured_model_language_hint(&config);
-                        match call_asr_initialize_with_language_fallback(
-                            client,
-                            &resolved_model_id,
-                            &device_pref,
-                            language,
-                        )
-                        .await
-                        {
-                            Ok(result) => {
-                                log::info!(
-                                    "Legacy fallback asr.initialize complete: model={}, status={}",
-                                    resolved_model_id,
-                                    result.status
-                                );
-                                status.status = "ready".to_string();
-                                Ok(status)
-                            }
-                            Err(error) => Err(format!(
-                                "E_MODEL_DOWNLOAD: Legacy sidecar fallback failed during asr.initialize: {}",
-                                error
-                            )),
-                        }
-                    }
-                    Err(RpcError::Remote { kind, .. }) if kind == "E_METHOD_NOT_FOUND" => {
-                        Err(model_download_method_unsupported_message())
-                    }
-                    Err(err) => Err(map_model_download_rpc_error(err)),
-                }
-            }
-            Err(err) => Err(map_model_download_rpc_error(err)),
-        };
-
-        match status_result {
-            Ok(status) => {
-                let mapped_status = map_download_response_status(&status);
-                *self.model_status.write().await = mapped_status.clone();
-                self.recording_controller
-                    .set_model_ready(matches!(mapped_status, ModelStatus::Ready))
-                    .await;
-
-                if matches!(mapped_status, ModelStatus::Ready) {
-                    let _ = self.state_manager.transition(AppState::Idle);
-                }
-
-                let status_progress = status.progress.as_ref().map(|progress| {
-                    status_progress_from_parts(
-                        progress.current,
-                        progress.total,
-                        progress.unit.clone(),
-                        None,
-                    )
-                });
-
-                Self::emit_model_status_with_details(
-                    &self.app_handle,
-                    mapped_status,
-                    &self.event_seq,
-                    Some(resolve_model_id(Some(status.model_id.clone()))),
-                    status.revision.clone(),
-                    status.cache_path.clone(),
-                    status_progress.clone(),
-                );
-
-                if let (Some(progress), Some(handle)) = (status.progress, self.app_handle.as_ref())
-                {
-                    let model_progress_data = model_progress_from_parts(
-                        Some(resolve_model_id(Some(status.model_id.clone()))),
-                        progress.current,
-                        progress.total,
-                        progress.unit,
-                        progress.stage,
-                        progress.current_file,
-                        progress.files_completed,
-                        progress.files_total,
-                    );
-                    *self.model_progress.write().await = Some(model_progress_data.clone());
-                    emit_with_shared_seq(
-                        handle,
-                        &[EVENT_MODEL_PROGRESS],
-                        json!(model_progress_data),
-                        &self.event_seq,
-                    );
+    ) -> Result<(), IntegrationError> {
+        if force != Some(true) && self.warm_spare_enabled().await {
+            let resolved_model_id = resolve_model_id(model_id.clone());
+            let cached = self
+                .model
+                .query_status(Some(resolved_model_id.clone()))
+                .await
+                .is_ok_and(|status| status.status == "ready");
+            // Models that still need downloading install in the live sidecar,
+            // which reports their progress.
+            if cached {
+                match self.restart_with_warm_spare(&resolved_model_id).await {
+                    Ok(()) => return Ok(()),
+                    Err(error) => log::warn!(
+                        "Warm spare switch to model {} failed; loading it in place: {}",
+                        resolved_model_id,
+                        error
+                    ),
                 }
-
-                Ok(())
-            }
-            Err(message) => {
-                let error_status = ModelStatus::Error(message.clone());
-                *self.model_status.write().await = error_status.clone();
-                self.recording_controller.set_model_ready(false).await;
-                self.state_manager.transition_to_error(message.clone());
-                Self::emit_model_status(&self.app_handle, error_status, &self.event_seq);
-                Err(message)
             }
         }
+        self.model.download(model_id, force).await
     }
 
     /// Purge model cache.
-    pub async fn purge_model_cache(&self, model_id: Option<String>) -> Result<(), String> {
-        let current_status = self.model_status.read().await.clone();
-        if current_status == ModelStatus::Downloading || current_status == ModelStatus::Loading {
-            return Err(
-                "Cannot purge model while download or initialization is in progress".to_string(),
-            );
-        }
-
-        let purge_model_id = match model_id {
-            Some(id) => {
-                let trimmed = id.trim().to_string();
-                if trimmed.is_empty() {
-                    return Err(
-                        "Invalid model_id: blank or whitespace-only value".to_string(),
-                    );
-                }
-                Some(trimmed)
-            }
-            None => None,
-        };
-
-        let client = self.rpc_client.read().await;
-        let client = client
-            .as_ref()
-            .ok_or_else(|| "Sidecar not connected".to_string())?;
-
-        #[derive(Deserialize)]
-        struct PurgeResult {
-            #[allow(dead_code)]
-            purged: bool,
-            #[serde(default)]
-            purged_model_ids: Vec<String>,
-        }
-
-        let params = purge_model_id
-            .as_ref()
-            .map(|requested_model_id| json!({ "model_id": requested_model_id }));
-
-        let result = client
-            .call::<PurgeResult>("model.purge_cache", params)
-            .await
-            .map_err(|e| format!("Failed to purge cache: {}", e))?;
-
-        let configured_model_id = configured_model_id();
-        let reported_purged_model_ids = normalized_purged_model_ids(&result.purged_model_ids);
-        let status_model_ids = purge_status_model_ids(
-            purge_model_id.as_deref(),
-            configured_model_id.as_str(),
-            &reported_purged_model_ids,
-        );
-        let removed_model_count = reported_purged_model_ids.len();
-        let affects_configured_model =
-            purge_affects_configured_model(configured_model_id.as_str(), &status_model_ids);
-
-        if affects_configured_model {
-            *self.model_status.write().await = ModelStatus::Missing;
-            self.recording_controller.set_model_ready(false).await;
-        }
-
-        // Emit model:status Missing for each purged model so UI consumers
-        // can track cache state for all models, not just the configured one.
-        if let Some(app_handle) = &self.app_handle {
-            emit_missing_model_status_for_purged_models_with_broadcaster(
-                app_handle,
-                &self.event_seq,
-                &status_model_ids,
-            );
-        }
-
-        log::info!(
-            "Model cache purged{} ({} models removed)",
-            purge_model_id
-                .as_ref()
-                .map(|id| format!(" for model {}", id))
-                .unwrap_or_default(),
-            removed_model_count,
-        );
-        Ok(())
+    pub async fn purge_model_cache(
+        &self,
+        model_id: Option<String>,
+    ) -> Result<(), IntegrationError> {
+        self.model.purge_cache(model_id).await
     }
 
-    async fn start_recording_flow(
-        state_manager: &Arc<AppStateManager>,
-        recording_controller: &Arc<RecordingController>,
-        rpc_client: &Arc<RwLock<Option<RpcClient>>>,
-        recording_context: &Arc<RwLock<Option<RecordingContext>>>,
-        current_session_id: &Arc<RwLock<Option<String>>>,
-    ) -> Result<(), String> {
-        if current_session_id.read().await.is_some() {
-            return Err("Recording already in progress".to_string());
-        }
-
-        state_manager
-            .can_start_recording()
-            .map_err(cannot_record_reason_message)
-            .map_err(ToString::to_string)?;
-
-        if !recording_controller.is_model_ready().await {
-            return Err("Model not ready".to_string());
-        }
-
-        let session_id = Uuid::new_v4().to_string();
-        let focus = capture_focus();
-        let app_config = config::load_config();
-        let params = recording_start_params(session_id.as_str(), &app_config);
-
-        // Play start cue BEFORE mic capture begins and wait for the pre-roll
-        // delay so the beep is less likely to be picked up by the microphone.
-        play_lifecycle_audio_cue(CueType::StartRecording);
-        tokio::time::sleep(crate::audio_cue::START_CUE_PRE_ROLL).await;
-
-        let start_response: Value = {
-            let client_guard = rpc_client.read().await;
-            let client = client_guard
-                .as_ref()
-                .ok_or_else(|| "Sidecar not connected".to_string())?;
-
-            client
-                .call("recording.start", Some(params))
-                .await
-                .map_err(|err| format!("Failed to call recording.start RPC: {}", err))?
-        };
-
-        if let Err(mismatch) =
-            validate_recording_start_response(session_id.as_str(), &start_response)
-        {
-            if let Some(client) = rpc_client.read().await.as_ref() {
-                let cancel_session_id = start_response
-                    .get("session_id")
-                    .and_then(Value::as_str)
-                    .unwrap_or(session_id.as_str())
-                    .to_string();
-                let cancel_result: Result<Value, _> = client
-                    .call(
-                        "recording.cancel",
-                        Some(json!({ "session_id": cancel_session_id })),
-                    )
-                    .await;
-                if let Err(err) = cancel_result {
-                    log::warn!(
-                        "Failed to roll back recording.start via recording.cancel: {}",
-                        err
-                    );
-                }
-            }
-            return Err(mismatch);
-        }
-
-        if let Err(err) = recording_controller
-            .start_with_session_id(session_id.clone())
-            .await
-        {
-            if let Some(client) = rpc_client.read().await.as_ref() {
-                let cancel_result: Result<Value, _> = client
-                    .call(
-                        "recording.cancel",
-                        Some(json!({ "session_id": session_id })),
-                    )
-                    .await;
-                if let Err(cancel_err) = cancel_result {
-                    log::warn!(
-                        "Failed to cancel sidecar session after host start failure: {}",
-                        cancel_err
-                    );
-                }
-            }
-            return Err(format!("Failed to start recording: {}", err));
-        }
-
-        *recording_context.write().await = Some(RecordingContext {
-            focus_before: focus,
-            session_id: session_id.clone(),
-            audio_duration_ms: None,
-            raw_text: None,
-            final_text: None,
-            language: None,
-            confidence: None,
-            force_clipboard_only: false,
-            force_clipboard_reason: None,
-            timing_marks: PipelineTimingMarks::default(),
-        });
-        *current_session_id.write().await = Some(session_id);
-
-        Ok(())
+    /// Unified recording start entry point for commands/UI/hotkey/tray/overlay.
+    pub async fn start_recording(&self) -> Result<(), IntegrationError> {
+        self.session.start(false).await
     }
 
-    /// Unified recording start entry point for commands/UI/hotkey/tray/overlay.
-    pub async fn start_recording(&self) -> Result<(), String> {
-        Self::start_recording_flow(
-            &self.state_manager,
-            &self.recording_controller,
-            &self.rpc_client,
-            &self.recording_context,
-            &self.current_session_id,
-        )
-        .await
+    /// Snapshot of the most recent dictation session, if any.
+    pub async fn active_session(&self) -> Option<SessionSnapshot> {
+        self.session.snapshot().await
     }
 
     /// Unified recording stop entry point for commands/UI/hotkey/tray/overlay.
-    pub async fn stop_recording(&self) -> Result<(), String> {
-        Self::stop_recording_flow(
-            &self.recording_controller,
-            &self.rpc_client,
-            &self.recording_context,
-            &self.current_session_id,
-        )
-        .await
+    pub async fn stop_recording(&self) -> Result<(), IntegrationError> {
+        self.session.stop().await
     }
 
     /// Unified recording cancel entry point for commands/UI/hotkey/tray/overlay.
-    pub async fn cancel_recording(&self) -> Result<(), String> {
-        Self::cancel_recording_flow(
-            &self.recording_controller,
-            &self.rpc_client,
-            &self.recording_context,
-            &self.current_session_id,
-        )
-        .await
+    pub async fn cancel_recording(&self) -> Result<(), IntegrationError> {
+        self.session.cancel(CancelReason::UserButton).await
     }
 
     /// Start microphone level meter via sidecar.
-    pub async fn start_mic_test(&self, device_uid: Option<String>) -> Result<(), String> {
-        let client = self.rpc_client.read().await;
-        let client = client
-            .as_ref()
-            .ok_or_else(|| "Sidecar not connected".to_string())?;
-
-        #[derive(Deserialize)]
-        struct MeterStartResult {
-            #[allow(dead_code)]
-            running: bool,
-            #[allow(dead_code)]
-            interval_ms: u64,
-        }
-
-        let params = json!({
-            "device_uid": device_uid,
-            "interval_ms": 80u64
-        });
-
-        client
-            .call::<MeterStartResult>("audio.meter_start", Some(params))
-            .await
-            .map_err(|e| format!("Failed to start mic test: {}", e))?;
-        Ok(())
+    pub async fn start_mic_test(&self, device_uid: Option<String>) -> Result<(), IntegrationError> {
+        self.audio.start_mic_test(device_uid).await
     }
 
     /// List available audio input devices via sidecar.
-    pub async fn list_audio_devices(&self) -> Result<Vec<SidecarAudioDevice>, String> {
-        let client = self.rpc_client.read().await;
-        let client = client
-            .as_ref()
-            .ok_or_else(|| "Sidecar not connected".to_string())?;
-
-        #[derive(Deserialize)]
-        struct ListDevicesResult {
-            #[serde(default)]
-            devices: Vec<SidecarAudioDevice>,
-        }
-
-        let result = client
-            .call::<ListDevicesResult>("audio.list_devices", None)
-            .await
-            .map_err(|e| format!("Failed to list audio devices: {}", e))?;
-
-        Ok(result.devices)
+    pub async fn list_audio_devices(&self) -> Result<Vec<SidecarAudioDevice>, IntegrationError> {
+        self.audio.list_devices().await
     }
 
     /// Set active audio input device via sidecar.
     pub async fn set_audio_device(
-        &self,
-        device_uid: Option<String>,
-    ) -> Result<Option<String>, String> {
-        let client = self.rpc_client.read().await;
-        let client = client
-            .as_ref()
-            .ok_or_else(|| "Sidecar not connected".to_string())?;
-
-        #[derive(Deserialize)]
-        struct SetDeviceResult {
-            #[serde(default)]
-            active_device_uid: Option<String>,
-        }
-
-        let params = json!({
-            "device_uid": device_uid
-        });
+        &self,
+        device_uid: Option<String>,
+    ) -> Result<Option<String>, IntegrationError> {
+        self.audio.set_device(device_uid).await
+    }
 
-        let result = client
-            .call::<SetDeviceResult>("audio.set_device", Some(params))
-            .await
-            .map_err(|e| format!("Failed to set audio device: {}", e))?;
+    /// Get the input gain of a device (`None` = the active device).
+    pub async fn get_input_gain(
+        &self,
+        device_uid: Option<String>,
+    ) -> Result<InputGain, IntegrationError> {
+        self.audio.get_gain(device_uid).await
+    }
 
-        Ok(result.active_device_uid)
+    /// Set the input gain of a device (`None` = the active device) via sidecar.
+    pub async fn set_input_gain(
+        &self,
+        device_uid: Option<String>,
+        gain_db: f64,
+    ) -> Result<InputGain, IntegrationError> {
+        self.audio.set_gain(device_uid, gain_db).await
     }
 
     /// List available replacement presets via sidecar.
-    pub async fn list_replacement_presets(&self) -> Result<Vec<SidecarPresetInfo>, String> {
+    pub async fn list_replacement_presets(
+        &self,
+    ) -> Result<Vec<SidecarPresetInfo>, IntegrationError> {
         let client = self.rpc_client.read().await;
         let client = client
             .as_ref()
-            .ok_or_else(|| "Sidecar not connected".to_string())?;
+            .ok_or(IntegrationError::SidecarNotConnected)?;
 
         #[derive(Deserialize)]
         struct GetPresetsResult {
@@ -2600,7 +2262,7 @@ impl IntegrationManager {
         let result = client
             .call::<GetPresetsResult>("replacements.get_presets", None)
             .await
-            .map_err(|e| format!("Failed to list presets: {}", e))?;
+            .map_err(|e| IntegrationError::rpc("list presets", e))?;
 
         Ok(result.presets)
     }
@@ -2611,11 +2273,11 @@ impl IntegrationManager {
     pub async fn get_preset_replacement_rules(
         &self,
         preset_id: String,
-    ) -> Result<Option<Vec<ReplacementRule>>, String> {
+    ) -> Result<Option<Vec<ReplacementRule>>, IntegrationError> {
         let client = self.rpc_client.read().await;
         let client = client
             .as_ref()
-            .ok_or_else(|| "Sidecar not connected".to_string())?;
+            .ok_or(IntegrationError::SidecarNotConnected)?;
 
         #[derive(Deserialize)]
         struct GetPresetRulesResult {
@@ -2633,16 +2295,18 @@ impl IntegrationManager {
         {
             Ok(result) => Ok(Some(result.rules)),
             Err(RpcError::Remote { kind, .. }) if kind == "E_NOT_FOUND" => Ok(None),
-            Err(e) => Err(format!("Failed to load preset rules: {}", e)),
+            Err(e) => Err(IntegrationError::rpc("load preset rules", e)),
         }
     }
 
     /// Get current active replacement rules from sidecar.
-    pub async fn get_active_replacement_rules(&self) -> Result<Vec<ReplacementRule>, String> {
+    pub async fn get_active_replacement_rules(
+        &self,
+    ) -> Result<Vec<ReplacementRule>, IntegrationError> {
         let client = self.rpc_client.read().await;
         let client = client
             .as_ref()
-            .ok_or_else(|| "Sidecar not connected".to_string())?;
+            .ok_or(IntegrationError::SidecarNotConnected)?;
 
         #[derive(Deserialize)]
         struct GetRulesResult {
@@ -2653,7 +2317,7 @@ impl IntegrationManager {
         let result = client
             .call::<GetRulesResult>("replacements.get_rules", None)
             .await
-            .map_err(|e| format!("Failed to get active replacement rules: {}", e))?;
+            .map_err(|e| IntegrationError::rpc("get active replacement rules", e))?;
 
         Ok(result.rules)
     }
@@ -2662,11 +2326,11 @@ impl IntegrationManager {
     pub async fn set_active_replacement_rules(
         &self,
         rules: Vec<ReplacementRule>,
-    ) -> Result<(), String> {
+    ) -> Result<(), IntegrationError> {
         let client = self.rpc_client.read().await;
         let client = client
             .as_ref()
-            .ok_or_else(|| "Sidecar not connected".to_string())?;
+            .ok_or(IntegrationError::SidecarNotConnected)?;
 
         #[derive(Deserialize)]
         struct SetRulesResult {
@@ -2678,21 +2342,37 @@ impl IntegrationManager {
         client
             .call::<SetRulesResult>("replacements.set_rules", Some(params))
             .await
-            .map_err(|e| format!("Failed to set active replacement rules: {}", e))?;
+            .map_err(|e| IntegrationError::rpc("set active replacement rules", e))?;
 
         Ok(())
     }
 
+    /// Push the configured and temporary replacement rules to the sidecar in
+    /// resolved order.
+    ///
+    /// Failures are logged; the sidecar keeps its previous rules.
+    pub async fn sync_replacement_rules(&self, app_config: &config::AppConfig) {
+        let rules = crate::replacement_rules::rules_for_sidecar(
+            &app_config.replacements,
+            &app_config.replacement_groups,
+        );
+        let count = rules.len();
+        match self.set_active_replacement_rules(rules).await {
+            Ok(()) => log::info!("Synced {} active replacement rules to sidecar", count),
+            Err(error) => log::warn!("Replacement rule sync skipped: {}", error),
+        }
+    }
+
     /// Preview text using sidecar's replacements pipeline.
     pub async fn preview_replacement(
         &self,
         text: String,
         rules: Vec<ReplacementRule>,
-    ) -> Result<SidecarReplacementPreviewResult, String> {
+    ) -> Result<SidecarReplacementPreviewResult, IntegrationError> {
         let client = self.rpc_client.read().await;
         let client = client
             .as_ref()
-            .ok_or_else(|| "Sidecar not connected".to_string())?;
+            .ok_or(IntegrationError::SidecarNotConnected)?;
 
         let params = json!({
             "text": text,
@@ -2702,27 +2382,12 @@ impl IntegrationManager {
         client
             .call::<SidecarReplacementPreviewResult>("replacements.preview", Some(params))
             .await
-            .map_err(|e| format!("Failed to preview replacements: {}", e))
+            .map_err(|e| IntegrationError::rpc("preview replacements", e))
     }
 
     /// Stop microphone level meter via sidecar.
-    pub async fn stop_mic_test(&self) -> Result<(), String> {
-        let client = self.rpc_client.read().await;
-        let client = client
-            .as_ref()
-            .ok_or_else(|| "Sidecar not connected".to_string())?;
-
-        #[derive(Deserialize)]
-        struct MeterStopResult {
-            #[allow(dead_code)]
-            stopped: bool,
-        }
-
-        client
-            .call::<MeterStopResult>("audio.meter_stop", None)
-            .await
-            .map_err(|e| format!("Failed to stop mic test: {}", e))?;
-        Ok(())
+    pub async fn stop_mic_test(&self) -> Result<(), IntegrationError> {
+        self.audio.stop_mic_test().await
     }
 
     async fn apply_supervisor_runtime_config(&self) {
@@ -2753,30 +2418,37 @@ impl IntegrationManager {
             let supervisor = self.supervisor.lock().await;
             supervisor.controller().clone()
         };
-        let rpc_client = RpcClient::new_with_sidecar_manager(sidecar);
+        let rpc_client = RpcClient::new_with_sidecar_manager(
+            sidecar,
+            config::load_config().supervisor.protocol_error_tolerance,
+        );
         self.start_notification_loop(rpc_client.subscribe());
         *self.rpc_client.write().await = Some(rpc_client);
+        // The sidecar starts with no rules; restore the configured set.
+        self.sync_replacement_rules(&config::load_config()).await;
     }
 
     async fn emit_supervisor_failure(&self, message: String, restart_count: u32) {
-        self.state_manager.transition_to_error(message.clone());
-        self.recording_controller.set_model_ready(false).await;
+        let error_kind = supervisor_failure_kind(&message);
+        // An open circuit breaker waits for the user to restart the sidecar.
+        let category = if error_kind == ErrorKind::SidecarCircuitBreaker {
+            ErrorCategory::Other
+        } else {
+            ErrorCategory::Sidecar
+        };
+        self.state_manager
+            .transition_to_error(category, message.clone());
+        self.session
+            .recording_controller
+            .set_model_ready(false)
+            .await;
 
         if let Some(ref handle) = self.app_handle {
-            let lower = message.to_ascii_lowercase();
-            let error_kind = if lower.contains("circuit breaker") {
-                ErrorKind::SidecarCircuitBreaker.to_sidecar()
-            } else if lower.contains("spawn") {
-                ErrorKind::SidecarSpawn.to_sidecar()
-            } else {
-                ErrorKind::SidecarCrash.to_sidecar()
-            };
-
             let app_error = AppError::new(
-                error_kind,
+                error_kind.to_sidecar(),
                 message.clone(),
                 Some(json!({ "restart_count": restart_count })),
-                !lower.contains("circuit breaker"),
+                error_kind != ErrorKind::SidecarCircuitBreaker,
             );
             emit_with_shared_seq(
                 handle,
@@ -2785,10 +2457,47 @@ impl IntegrationManager {
                 &self.event_seq,
             );
         }
+
+        // The breaker only opens after repeated start failures; keep
+        // dictation alive in process unless the sidecar was requested.
+        if error_kind == ErrorKind::SidecarCircuitBreaker
+            && config::load_config().asr_backend() != AsrBackend::Sidecar
+        {
+            self.model.activate_embedded_asr().await;
+        }
+    }
+
+    /// Restore the previous sidecar build when a just-activated update fails
+    /// its first start, and try once more. Returns None when no update was
+    /// pending.
+    async fn retry_after_sidecar_update_rollback(
+        &self,
+    ) -> Option<(Result<(), String>, SupervisorState, u32)> {
+        let version = sidecar_update::rollback_pending_update()?;
+        log::warn!(
+            "Sidecar update {} failed its first start; restored previous build",
+            version
+        );
+        if let Some(ref handle) = self.app_handle {
+            emit_with_shared_seq(
+                handle,
+                &[sidecar_update::EVENT_SIDECAR_UPDATE_STATUS],
+                sidecar_update::update_status_payload(
+                    SidecarUpdatePhase::RolledBack,
+                    &version,
+                    Some("Update failed to start; restored the previous sidecar"),
+                ),
+                &self.event_seq,
+            );
+        }
+
+        let mut supervisor = self.supervisor.lock().await;
+        let result = supervisor.restart().await;
+        Some((result, supervisor.state(), supervisor.restart_count()))
     }
 
     /// Start the sidecar process through supervisor and connect RPC client.
-    pub async fn start_sidecar(&self) -> Result<(), String> {
+    pub async fn start_sidecar(&self) -> Result<(), IntegrationError> {
         log::info!("Starting sidecar process via supervisor");
         self.reset_rpc_client(false).await;
         self.apply_supervisor_runtime_config().await;
@@ -2798,11 +2507,20 @@ impl IntegrationManager {
             let result = supervisor.start().await;
             (result, supervisor.state(), supervisor.restart_count())
         };
+        let (result, state, restart_count) = if result.is_ok() && state == SupervisorState::Ready {
+            (result, state, restart_count)
+        } else {
+            match self.retry_after_sidecar_update_rollback().await {
+                Some(retried) => retried,
+                None => (result, state, restart_count),
+            }
+        };
 
         match result {
             Ok(()) if state == SupervisorState::Ready => {
+                sidecar_update::confirm_pending_update();
                 self.attach_rpc_client_to_supervisor_sidecar().await;
-                self.spawn_model_check();
+                self.model.spawn_check();
                 log::info!("Sidecar connected via supervisor");
                 Ok(())
             }
@@ -2811,13 +2529,13 @@ impl IntegrationManager {
                 self.emit_supervisor_failure(message.clone(), restart_count)
                     .await;
                 self.watchdog.mark_not_running().await;
-                Err(message)
+                Err(supervisor_failure_error(message))
             }
             Err(err) => {
                 self.emit_supervisor_failure(err.clone(), restart_count)
                     .await;
                 self.watchdog.mark_not_running().await;
-                Err(err)
+                Err(supervisor_failure_error(err))
             }
         }
     }
@@ -2825,8 +2543,20 @@ impl IntegrationManager {
     /// Manually restart the sidecar process.
     ///
     /// This path is used for user-initiated recovery after failures.
-    pub async fn restart_sidecar(&self) -> Result<(), String> {
+    pub async fn restart_sidecar(&self) -> Result<(), IntegrationError> {
         log::info!("Manual sidecar restart requested");
+        if self.warm_spare_enabled().await {
+            match self.restart_with_warm_spare(&configured_model_id()).await {
+                Ok(()) => {
+                    sidecar_update::confirm_pending_update();
+                    log::info!("Sidecar restarted onto a warm spare");
+                    return Ok(());
+                }
+                Err(error) => {
+                    log::warn!("Warm spare restart failed; restarting in place: {}", error)
+                }
+            }
+        }
         self.reset_rpc_client(false).await;
         self.apply_supervisor_runtime_config().await;
 
@@ -2835,11 +2565,20 @@ impl IntegrationManager {
             let result = supervisor.restart().await;
             (result, supervisor.state(), supervisor.restart_count())
         };
+        let (result, state, restart_count) = if result.is_ok() && state == SupervisorState::Ready {
+            (result, state, restart_count)
+        } else {
+            match self.retry_after_sidecar_update_rollback().await {
+                Some(retried) => retried,
+                None => (result, state, restart_count),
+            }
+        };
 
         match result {
             Ok(()) if state == SupervisorState::Ready => {
+                sidecar_update::confirm_pending_update();
                 self.attach_rpc_client_to_supervisor_sidecar().await;
-                self.spawn_model_check();
+                self.model.spawn_check();
                 log::info!("Sidecar restarted via supervisor");
                 Ok(())
             }
@@ -2848,18 +2587,143 @@ impl IntegrationManager {
                 self.emit_supervisor_failure(message.clone(), restart_count)
                     .await;
                 self.watchdog.mark_not_running().await;
-                Err(message)
+                Err(supervisor_failure_error(message))
             }
             Err(err) => {
                 self.emit_supervisor_failure(err.clone(), restart_count)
                     .await;
                 self.watchdog.mark_not_running().await;
-                Err(err)
+                Err(supervisor_failure_error(err))
+            }
+        }
+    }
+
+    /// Whether restarts and model switches go through a warm spare: enabled
+    /// in the config, the sidecar serves dictation, and it is connected (a
+    /// broken sidecar is restarted in place).
+    async fn warm_spare_enabled(&self) -> bool {
+        let app_config = config::load_config();
+        app_config.supervisor.warm_spare_restarts
+            && app_config.asr_backend() != AsrBackend::Embedded
+            && self
+                .rpc_client
+                .read()
+                .await
+                .as_ref()
+                .is_some_and(RpcClient::is_connected)
+    }
+
+    /// Replace the live sidecar without downtime: start a new one next to
+    /// it, load `model_id` there, and switch over once it is ready and no
+    /// dictation is in progress. The current sidecar keeps serving until the
+    /// switch and is left running when the spare fails to come up.
+    async fn restart_with_warm_spare(&self, model_id: &str) -> Result<(), IntegrationError> {
+        let app_config = config::load_config();
+        let mut controller = SidecarManager::new();
+        controller.set_python_mode(
+            self.config.python_path.clone(),
+            self.config.sidecar_module.clone(),
+        );
+        // No app handle until the switch: the spare's status must not drive
+        // the live sidecar indicators.
+        let mut spare = SidecarSupervisor::new(
+            controller,
+            SidecarSupervisorConfig {
+                captured_log_max_lines: app_config.supervisor.captured_log_max_lines,
+                ..SidecarSupervisorConfig::default()
+            },
+        );
+        log::info!("Starting warm spare sidecar for model {}", model_id);
+        spare.start().await.map_err(|error| {
+            supervisor_failure_error(format!("Failed to start warm spare sidecar: {}", error))
+        })?;
+        let spare_client = RpcClient::new_with_sidecar_manager(
+            spare.controller().clone(),
+            app_config.supervisor.protocol_error_tolerance,
+        );
+
+        let initialized = call_asr_initialize_with_language_fallback(
+            &spare_client,
+            model_id,
+            &app_config.effective_model_device_pref(),
+            configured_model_language_hint(&app_config),
+            configured_model_beam_size(&app_config),
+        )
+        .await;
+        if let Err(error) = initialized {
+            retire_sidecar(Some(spare_client), spare).await;
+            return Err(IntegrationError::rpc(
+                "initialize ASR in warm spare sidecar",
+                error,
+            ));
+        }
+
+        let recording_blocked = if self.wait_for_dictation_to_finish().await {
+            self.block_recording_for_switch().await
+        } else {
+            None
+        };
+        let Some(recording_blocked) = recording_blocked else {
+            retire_sidecar(Some(spare_client), spare).await;
+            return Err(supervisor_failure_error(
+                "Dictation still in progress; warm spare sidecar discarded".to_string(),
+            ));
+        };
+
+        self.start_notification_loop(spare_client.subscribe());
+        let retired_client = {
+            // Waits for in-flight calls on the current client.
+            let mut rpc_client = self.rpc_client.write().await;
+            let mut live = self.supervisor.lock().await;
+            std::mem::swap(&mut *live, &mut spare);
+            if let Some(handle) = spare.take_app_handle() {
+                live.set_app_handle(handle);
+            }
+            rpc_client.replace(spare_client)
+        };
+        drop(recording_blocked);
+        retire_sidecar(retired_client, spare).await;
+        log::info!("Switched to warm spare sidecar with model {}", model_id);
+
+        // The new sidecar starts with no rules; restore the configured set.
+        self.sync_replacement_rules(&app_config).await;
+        self.model.mark_ready(model_id).await;
+        Ok(())
+    }
+
+    /// Wait until no recording or transcription is in progress. False when
+    /// [`WARM_SPARE_SWITCH_TIMEOUT`] passes first.
+    async fn wait_for_dictation_to_finish(&self) -> bool {
+        let deadline = Instant::now() + WARM_SPARE_SWITCH_TIMEOUT;
+        loop {
+            if !matches!(
+                self.state_manager.get(),
+                AppState::Recording | AppState::Transcribing
+            ) {
+                return true;
+            }
+            if Instant::now() >= deadline {
+                return false;
             }
+            tokio::time::sleep(WARM_SPARE_SWITCH_POLL_INTERVAL).await;
         }
     }
 
-    async fn recover_sidecar_from_watchdog(&self) -> Result<(), String> {
+    /// Keep recordings from starting for a sidecar switch. A hotkey recording
+    /// may have started after [`Self::wait_for_dictation_to_finish`] returned;
+    /// None when dictation is active once it has reached `Recording`.
+    async fn block_recording_for_switch(&self) -> Option<OwnedRwLockWriteGuard<()>> {
+        let blocked = self.session.block_recording_start().await;
+        if matches!(
+            self.state_manager.get(),
+            AppState::Recording | AppState::Transcribing
+        ) {
+            return None;
+        }
+        Some(blocked)
+    }
+
+    async fn recover_sidecar_from_watchdog(&self) -> Result<(), IntegrationError> {
         log::warn!("Watchdog requested sidecar recovery via supervisor");
         self.reset_rpc_client(false).await;
         self.apply_supervisor_runtime_config().await;
@@ -2873,7 +2737,7 @@ impl IntegrationManager {
         match result {
             Ok(()) if state == SupervisorState::Ready => {
                 self.attach_rpc_client_to_supervisor_sidecar().await;
-                self.spawn_model_check();
+                self.model.spawn_check();
                 Ok(())
             }
             Ok(()) => {
@@ -2884,14 +2748,14 @@ impl IntegrationManager {
                 self.emit_supervisor_failure(message.clone(), restart_count)
                     .await;
                 self.watchdog.mark_not_running().await;
-                Err(message)
+                Err(supervisor_failure_error(message))
             }
             Err(err) => {
                 let message = format!("Watchdog recovery failed: {}", err);
                 self.emit_supervisor_failure(message.clone(), restart_count)
                     .await;
                 self.watchdog.mark_not_running().await;
-                Err(message)
+                Err(supervisor_failure_error(message))
             }
         }
     }
@@ -2925,820 +2789,291 @@ impl IntegrationManager {
 
     async fn stop_sidecar_runtime(&self) {
         self.reset_rpc_client(true).await;
-        let mut supervisor = self.supervisor.lock().await;
-        let _ = supervisor.stop().await;
-
-        self.watchdog.mark_not_running().await;
-    }
-
-    /// Initialize ASR model via sidecar.
-    pub async fn initialize_asr(&self, model_id: &str, device: &str) -> Result<(), String> {
-        let client = self.rpc_client.read().await;
-        let client = client
-            .as_ref()
-            .ok_or_else(|| "Sidecar not connected".to_string())?;
-
-        // Transition to loading state
-        let _ = self.state_manager.transition(AppState::LoadingModel);
-        let config = config::load_config();
-        let language = configured_model_language_hint(&config);
-
-        match call_asr_initialize_with_language_fallback(client, model_id, device, language).await {
-            Ok(result) => {
-                log::info!("ASR initialized: status={}", result.status);
-                // Mark model ready
-                self.recording_controller.set_model_ready(true).await;
-                // Transition to idle
-                let _ = self.state_manager.transition(AppState::Idle);
-                Ok(())
-            }
-            Err(e) => {
-                log::error!("ASR initialization failed: {}", e);
-                self.state_manager
-                    .transition_to_error(format!("Model initialization failed: {}", e));
-                Err(format!("ASR initialization failed: {}", e))
-            }
-        }
-    }
-
-    /// Start hotkey event processing loop.
-    fn start_hotkey_loop(&self) {
-        let hotkey_manager = Arc::clone(&self.hotkey_manager);
-        let state_manager = Arc::clone(&self.state_manager);
-        let recording_controller = Arc::clone(&self.recording_controller);
-        let rpc_client = Arc::clone(&self.rpc_client);
-        let recording_context = Arc::clone(&self.recording_context);
-        let current_session_id = Arc::clone(&self.current_session_id);
-        let app_handle = self.app_handle.clone();
-
-        tokio::spawn(async move {
-            // Take the receiver from hotkey manager
-            let mut receiver = {
-                let mut hk = hotkey_manager.write().await;
-                match hk.take_action_receiver() {
-                    Some(rx) => rx,
-                    None => {
-                        log::warn!("Hotkey receiver already taken");
-                        return;
-                    }
-                }
-            };
-
-            log::info!("Hotkey event loop started");
-
-            loop {
-                // Drain global hotkey events and forward them into the hotkey action channel.
-                {
-                    let hk = hotkey_manager.read().await;
-                    while let Ok(event) = GlobalHotKeyEvent::receiver().try_recv() {
-                        hk.process_event(event);
-                    }
-                }
-
-                Self::enforce_runtime_limits(
-                    &state_manager,
-                    &recording_controller,
-                    &rpc_client,
-                    &recording_context,
-                    &current_session_id,
-                )
-                .await;
-
-                let action =
-                    match tokio::time::timeout(Duration::from_millis(25), receiver.recv()).await {
-                        Ok(Some(action)) => action,
-                        Ok(None) => break,
-                        Err(_) => continue,
-                    };
-
-                let config = config::load_config();
-
-                match action {
-                    HotkeyAction::PrimaryDown => {
-                        // Handle based on mode
-                        let hk = hotkey_manager.read().await;
-                        let recording_action = hk.handle_primary_down(&state_manager);
-
-                        if let Some(RecordingAction::Start) = recording_action {
-                            if let Err(err) = Self::start_recording_flow(
-                                &state_manager,
-                                &recording_controller,
-                                &rpc_client,
-                                &recording_context,
-                                &current_session_id,
-                            )
-                            .await
-                            {
-                                log::warn!("Failed to start recording: {}", err);
-                                *current_session_id.write().await = None;
-                            }
-                        } else if let Some(RecordingAction::Stop) = recording_action {
-                            // Toggle mode: stop recording
-                            if let Err(err) = Self::stop_recording_flow(
-                                &recording_controller,
-                                &rpc_client,
-                                &recording_context,
-                                &current_session_id,
-                            )
-                            .await
-                            {
-                                log::warn!("Failed to stop recording: {}", err);
-                            }
-                        }
-                    }
-                    HotkeyAction::PrimaryUp => {
-                        // Only relevant for hold mode
-                        if config.hotkeys.mode == HotkeyMode::Hold {
-                            let hk = hotkey_manager.read().await;
-                            if let Some(RecordingAction::Stop) =
-                                hk.handle_primary_up(&state_manager)
-                            {
-                                if let Err(err) = Self::stop_recording_flow(
-                                    &recording_controller,
-                                    &rpc_client,
-                                    &recording_context,
-                                    &current_session_id,
-                                )
-                                .await
-                                {
-                                    log::warn!("Failed to stop recording: {}", err);
-                                }
-                            }
-                        }
-                    }
-                    HotkeyAction::CopyLast => {
-                        // Copy last transcript
-                        if let Some(ref handle) = app_handle {
-                            let history = handle.state::<TranscriptHistory>();
-                            let hk = hotkey_manager.read().await;
-                            let result = hk.handle_copy_last(&history);
-                            log::debug!("Copy last result: {:?}", result);
-                        }
-                    }
-                }
-            }
-
-            log::info!("Hotkey event loop ended");
-        });
-    }
-
-    /// Stop recording and trigger transcription flow.
-    async fn stop_recording_flow(
-        recording_controller: &Arc<RecordingController>,
-        rpc_client: &Arc<RwLock<Option<RpcClient>>>,
-        recording_context: &Arc<RwLock<Option<RecordingContext>>>,
-        current_session_id: &Arc<RwLock<Option<String>>>,
-    ) -> Result<(), String> {
-        if current_session_id.read().await.is_none() {
-            return Err("No recording in progress".to_string());
-        }
-
-        let result = recording_controller
-            .stop()
-            .await
-            .map_err(|e| format!("Failed to stop recording: {}", e))?;
-        log::info!("Recording stopped: {:?}", result);
-        Self::complete_stop_recording_flow(
-            result,
-            rpc_client,
-            recording_context,
-            current_session_id,
-        )
-        .await
-    }
-
-    /// Cancel recording and discard audio without transcription.
-    async fn cancel_recording_flow(
-        recording_controller: &Arc<RecordingController>,
-        rpc_client: &Arc<RwLock<Option<RpcClient>>>,
-        recording_context: &Arc<RwLock<Option<RecordingContext>>>,
-        current_session_id: &Arc<RwLock<Option<String>>>,
-    ) -> Result<(), String> {
-        let session_id = current_session_id
-            .read()
-            .await
-            .clone()
-            .ok_or_else(|| "No recording in progress".to_string())?;
-
-        recording_controller
-            .cancel(CancelReason::UserButton)
-            .await
-            .map_err(|err| match err {
-                crate::recording::RecordingError::NotRecording => {
-                    "No recording in progress".to_string()
-                }
-                other => format!("Failed to cancel recording: {}", other),
-            })?;
-
-        // Clear host correlation state immediately so any late sidecar notifications are stale.
-        *current_session_id.write().await = None;
-        *recording_context.write().await = None;
-
-        let params = json!({ "session_id": session_id });
-        if let Some(client) = rpc_client.read().await.as_ref() {
-            let cancel_result: Result<Value, RpcError> =
-                client.call("recording.cancel", Some(params.clone())).await;
-            match cancel_result {
-                Ok(_) => {}
-                Err(RpcError::Remote { kind, .. }) if kind == "E_METHOD_NOT_FOUND" => {
-                    log::warn!(
-                        "recording.cancel not supported; falling back to recording.stop and ignoring transcription result"
-                    );
-                    let fallback: Result<Value, RpcError> =
-                        client.call("recording.stop", Some(params)).await;
-                    if let Err(err) = fallback {
-                        log::error!("Fallback recording.stop after cancel failed: {}", err);
-                    }
-                }
-                Err(err) => {
-                    log::error!("Failed to call recording.cancel RPC: {}", err);
-                }
-            }
-        } else {
-            log::warn!("Sidecar not connected during cancel; completed host cancellation only");
+        {
+            let mut supervisor = self.supervisor.lock().await;
+            let _ = supervisor.stop().await;
         }
+        self.stop_auxiliary_sidecar().await;
 
-        Ok(())
+        self.watchdog.mark_not_running().await;
     }
 
-    async fn enforce_runtime_limits(
-        state_manager: &Arc<AppStateManager>,
-        recording_controller: &Arc<RecordingController>,
-        rpc_client: &Arc<RwLock<Option<RpcClient>>>,
-        recording_context: &Arc<RwLock<Option<RecordingContext>>>,
-        current_session_id: &Arc<RwLock<Option<String>>>,
-    ) {
-        if state_manager.get() == AppState::Recording {
-            if let Some(stop_result) = recording_controller.check_max_duration().await {
-                log::info!("Max recording duration reached; stopping recording");
-                if let Err(err) = Self::complete_stop_recording_flow(
-                    stop_result,
-                    rpc_client,
-                    recording_context,
-                    current_session_id,
-                )
-                .await
-                {
-                    log::warn!("Failed to complete max-duration stop flow: {}", err);
-                }
+    /// Call an RPC method on the sidecar that serves its [`SidecarRole`].
+    ///
+    /// Auxiliary methods (batch/file transcription, translation) run in a
+    /// second sidecar process, started on first use, so long jobs never block
+    /// live dictation.
+    pub async fn call_routed<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Option<Value>,
+    ) -> Result<T, IntegrationError> {
+        let role = SidecarRole::for_method(method);
+        let rpc_client = match role {
+            SidecarRole::Live => &self.rpc_client,
+            SidecarRole::Auxiliary => {
+                self.ensure_auxiliary_sidecar().await?;
+                &self.auxiliary_rpc_client
             }
-            return;
-        }
-
-        if state_manager.get() != AppState::Transcribing {
-            return;
-        }
-
-        let transcription_timeout = recording_controller
-            .get_config()
-            .await
-            .transcription_timeout;
-        let timed_out = {
-            let ctx = recording_context.read().await;
-            let stop_called_at = ctx.as_ref().and_then(|ctx| ctx.timing_marks.t0_stop_called);
-            has_transcription_timed_out(stop_called_at, transcription_timeout, Instant::now())
         };
 
-        if timed_out {
-            log::warn!(
-                "Transcription timed out after {:?}; transitioning to error",
-                transcription_timeout
-            );
-            recording_controller.on_transcription_timeout().await;
-            *current_session_id.write().await = None;
-            *recording_context.write().await = None;
-        }
+        let client_guard = rpc_client.read().await;
+        let client = client_guard
+            .as_ref()
+            .ok_or(IntegrationError::SidecarNotConnected)?;
+        client
+            .call(method, params)
+            .await
+            .map_err(|error| IntegrationError::rpc(format!("call {} RPC", method), error))
     }
 
-    async fn complete_stop_recording_flow(
-        result: StopResult,
-        rpc_client: &Arc<RwLock<Option<RpcClient>>>,
-        recording_context: &Arc<RwLock<Option<RecordingContext>>>,
-        current_session_id: &Arc<RwLock<Option<String>>>,
-    ) -> Result<(), String> {
-        let stop_rpc_method = stop_rpc_method_for_result(&result);
-        let too_short = matches!(result, StopResult::TooShort);
-
-        // Tell sidecar to stop/cancel recording.
-        if let Some(client) = rpc_client.read().await.as_ref() {
-            let stop_called_at = Instant::now();
-            let session_id = {
-                let mut ctx = recording_context.write().await;
-                if let Some(ctx) = ctx.as_mut() {
-                    if !too_short {
-                        ctx.timing_marks.t0_stop_called = Some(stop_called_at);
-                    }
-                    Some(ctx.session_id.clone())
-                } else {
-                    None
-                }
-            };
-
-            let session_id =
-                session_id.ok_or_else(|| "No recording context for active session".to_string())?;
-            let params = json!({
-                "session_id": session_id
+    /// Start (or restart after a crash) the auxiliary sidecar and connect its RPC client.
+    async fn ensure_auxiliary_sidecar(&self) -> Result<(), IntegrationError> {
+        let Some(supervisor) = self.supervisors.get(SidecarRole::Auxiliary) else {
+            return Err(IntegrationError::Supervisor {
+                kind: ErrorKind::SidecarSpawn,
+                message: "Auxiliary sidecar is not configured".to_string(),
             });
-            if stop_rpc_method == "recording.stop" {
-                #[derive(Deserialize)]
-                struct StopResultPayload {
-                    audio_duration_ms: u64,
-                }
-
-                let stop_result: Result<StopResultPayload, _> =
-                    client.call(stop_rpc_method, Some(params)).await;
+        };
+        // Hold the supervisor lock so concurrent callers start only one process.
+        let mut supervisor = supervisor.lock().await;
 
-                let stop_rpc_returned_at = Instant::now();
-                let mut ctx = recording_context.write().await;
-                if let Some(ctx) = ctx.as_mut() {
-                    if ctx.session_id == session_id {
-                        ctx.timing_marks.t1_stop_rpc_returned = Some(stop_rpc_returned_at);
-                        if let Ok(stop_payload) = &stop_result {
-                            ctx.audio_duration_ms = Some(stop_payload.audio_duration_ms);
-                        }
-                    }
-                }
-                if let Err(err) = stop_result {
-                    return Err(format!("Failed to call {} RPC: {}", stop_rpc_method, err));
-                }
-            } else {
-                let cancel_result: Result<Value, _> =
-                    client.call(stop_rpc_method, Some(params)).await;
-                if let Err(err) = cancel_result {
-                    return Err(format!("Failed to call {} RPC: {}", stop_rpc_method, err));
-                }
-            }
-        } else if !too_short {
-            return Err("Sidecar not connected".to_string());
+        let mut rpc_client = self.auxiliary_rpc_client.write().await;
+        if rpc_client.as_ref().is_some_and(RpcClient::is_connected) {
+            return Ok(());
         }
-
-        // Too-short recordings don't produce transcription and should clear session context.
-        if too_short {
-            *current_session_id.write().await = None;
-            *recording_context.write().await = None;
+        if let Some(stale) = rpc_client.take() {
+            stale.shutdown().await;
         }
 
-        Ok(())
-    }
-
-    /// Start state change event loop (for tray updates).
-    fn start_state_loop(&self) {
-        let state_manager = Arc::clone(&self.state_manager);
-        let app_handle = self.app_handle.clone();
-        let event_seq = Arc::clone(&self.event_seq);
-
-        tokio::spawn(async move {
-            let mut receiver = state_manager.subscribe();
-
-            log::info!("State event loop started");
-
-            while let Ok(event) = receiver.recv().await {
-                log::debug!("State changed: {:?}", event.state);
-
-                // Emit tray update
-                if let Some(ref handle) = app_handle {
-                    let icon = match event.state {
-                        AppState::Idle => "tray-idle",
-                        AppState::Recording => "tray-recording",
-                        AppState::Transcribing => "tray-transcribing",
-                        AppState::LoadingModel => "tray-loading",
-                        AppState::Error => "tray-error",
-                    };
-
-                    emit_with_shared_seq(
-                        handle,
-                        &[EVENT_TRAY_UPDATE],
-                        json!({
-                            "icon": icon,
-                            "state": event.state,
-                            "enabled": event.enabled,
-                            "detail": event.detail,
-                        }),
-                        &event_seq,
-                    );
-
-                    emit_with_shared_seq(
-                        handle,
-                        &[EVENT_STATE_CHANGED],
-                        state_changed_event_payload(&event),
-                        &event_seq,
-                    );
-                }
-            }
-
-            log::info!("State event loop ended");
-        });
-    }
-
-    /// Start recording event loop (for transcription results).
-    fn start_recording_event_loop(&self) {
-        let recording_controller = Arc::clone(&self.recording_controller);
-        let recording_context = Arc::clone(&self.recording_context);
-        let current_session_id = Arc::clone(&self.current_session_id);
-        let app_handle = self.app_handle.clone();
-        let event_seq = Arc::clone(&self.event_seq);
-        let overlay_manager = Arc::clone(&self.overlay_manager);
-        let overlay_enabled = Arc::clone(&self.overlay_enabled);
-
-        tokio::spawn(async move {
-            let mut receiver = recording_controller.subscribe();
-
-            log::info!("Recording event loop started");
-
-            while let Ok(event) = receiver.recv().await {
-                if let Some(cue) = recording_event_audio_cue(&event) {
-                    play_lifecycle_audio_cue(cue);
-                }
-
-                // Drive overlay show/hide based on recording lifecycle.
-                let overlay_enabled = overlay_enabled.load(Ordering::Acquire);
-                let recording_active =
-                    overlay_recording_state_for_event(overlay_enabled, &event);
-                if let (Some(recording_active), Some(ref handle)) = (recording_active, &app_handle)
-                {
-                    let config_store = FileOverlayConfigStore;
-                    let backend = TauriOverlayWindowBackend::new(handle);
-                    let mut manager = overlay_manager.lock().await;
-                    if let Err(error) =
-                        manager.handle_recording_state(recording_active, &config_store, &backend)
-                    {
-                        log::debug!("Overlay recording state transition failed: {error}");
-                    }
-                }
-
-                match event {
-                    RecordingEvent::Started {
-                        session_id,
-                        timestamp,
-                    } => {
-                        if let Some(ref handle) = app_handle {
-                            emit_with_shared_seq(
-                                handle,
-                                &[EVENT_RECORDING_STATUS],
-                                recording_status_event_payload(
-                                    "recording",
-                                    Some(session_id.as_str()),
-                                    Some(timestamp.to_rfc3339()),
-                                    None,
-                                ),
-                                &event_seq,
-                            );
-                        }
-                    }
-                    RecordingEvent::Stopped {
-                        session_id,
-                        duration_ms,
-                        ..
-                    } => {
-                        if let Some(ref handle) = app_handle {
-                            emit_with_shared_seq(
-                                handle,
-                                &[EVENT_RECORDING_STATUS],
-                                recording_status_event_payload(
-                                    "transcribing",
-                                    Some(session_id.as_str()),
-                                    None,
-                                    Some(duration_ms),
-                                ),
-                                &event_seq,
-                            );
-                        }
-                    }
-                    RecordingEvent::TooShort { .. } => {
-                        if let Some(ref handle) = app_handle {
-                            emit_with_shared_seq(
-                                handle,
-                                &[EVENT_RECORDING_STATUS],
-                                recording_status_event_payload("idle", None, None, None),
-                                &event_seq,
-                            );
-                        }
-                    }
-                    RecordingEvent::TranscriptionComplete {
-                        session_id,
-                        text: sidecar_text,
-                        audio_duration_ms,
-                        processing_duration_ms,
-                        timestamp: _,
-                    } => {
-                        if let Some(ref handle) = app_handle {
-                            emit_with_shared_seq(
-                                handle,
-                                &[EVENT_RECORDING_STATUS],
-                                recording_status_event_payload(
-                                    "idle",
-                                    Some(session_id.as_str()),
-                                    None,
-                                    None,
-                                ),
-                                &event_seq,
-                            );
-                        }
+        supervisor.controller_mut().set_python_mode(
+            self.config.python_path.clone(),
+            self.config.sidecar_module.clone(),
+        );
+        let result = if supervisor.state() == SupervisorState::Stopped {
+            supervisor.start().await
+        } else {
+            supervisor.handle_crash().await
+        };
+        result.map_err(|error| {
+            supervisor_failure_error(format!("Failed to start auxiliary sidecar: {}", error))
+        })?;
+        if supervisor.state() != SupervisorState::Ready {
+            return Err(supervisor_failure_error(format!(
+                "Auxiliary sidecar not ready: {:?}",
+                supervisor.state()
+            )));
+        }
 
-                        // Snapshot focus context and timing marks for this session.
-                        let (
-                            focus_before,
-                            mut timing_marks,
-                            raw_text,
-                            final_text,
-                            language,
-                            confidence,
-                            force_clipboard_only,
-                            force_clipboard_reason,
-                        ) = {
-                            let ctx = recording_context.read().await;
-                            if let Some(ctx) = ctx.as_ref() {
-                                (
-                                    Some(ctx.focus_before.clone()),
-                                    ctx.timing_marks.clone(),
-                                    ctx.raw_text.clone(),
-                                    ctx.final_text.clone(),
-                                    ctx.language.clone(),
-                                    ctx.confidence,
-                                    ctx.force_clipboard_only,
-                                    ctx.force_clipboard_reason.clone(),
-                                )
-                            } else {
-                                (
-                                    None,
-                                    PipelineTimingMarks::default(),
-                                    None,
-                                    None,
-                                    None,
-                                    None,
-                                    false,
-                                    None,
-                                )
-                            }
-                        };
-                        let (raw_text, final_text) = resolve_transcript_texts(
-                            &sidecar_text,
-                            raw_text.as_deref(),
-                            final_text.as_deref(),
-                        );
-                        log::info!(
-                            "Transcription complete: session={}, text_len={}, text_sha256_prefix={}, audio={}ms, processing={}ms",
-                            session_id,
-                            final_text.len(),
-                            sha256_prefix(&final_text),
-                            audio_duration_ms,
-                            processing_duration_ms
-                        );
-
-                        if log::log_enabled!(log::Level::Debug) {
-                            log::debug!("Transcription text (debug): {}", final_text);
-                        }
-                        if timing_marks.t2_transcription_received.is_none() {
-                            timing_marks.t2_transcription_received = Some(Instant::now());
-                        }
-                        let expected_focus = focus_before.as_ref();
+        log::info!("Auxiliary sidecar connected");
+        *rpc_client = Some(RpcClient::new_with_sidecar_manager(
+            supervisor.controller().clone(),
+            config::load_config().supervisor.protocol_error_tolerance,
+        ));
+        Ok(())
+    }
 
-                        // Sidecar output is already fully transformed (normalize/macros/replacements).
-                        // Never apply replacements again on the Rust side.
-                        if final_text.trim().is_empty() {
-                            log::info!("Empty transcription, skipping injection");
-                            continue;
-                        }
+    async fn stop_auxiliary_sidecar(&self) {
+        if let Some(client) = self.auxiliary_rpc_client.write().await.take() {
+            let _: Result<Value, RpcError> = client.call("system.shutdown", None).await;
+            client.shutdown().await;
+        }
+        if let Some(supervisor) = self.supervisors.get(SidecarRole::Auxiliary) {
+            let mut supervisor = supervisor.lock().await;
+            if supervisor.state() != SupervisorState::Stopped {
+                let _ = supervisor.stop().await;
+            }
+        }
+    }
 
-                        // Load injection config
-                        let config = config::load_config();
-                        let injection_config = InjectionConfig {
-                            paste_delay_ms: config.injection.paste_delay_ms,
-                            restore_clipboard: config.injection.restore_clipboard,
-                            suffix: config.injection.suffix.clone(),
-                            focus_guard_enabled: config.injection.focus_guard_enabled,
-                            app_overrides: config
-                                .injection
-                                .app_overrides
-                                .iter()
-                                .map(|(app_id, ov)| {
-                                    (
-                                        app_id.clone(),
-                                        crate::injection::AppOverride {
-                                            paste_delay_ms: ov.paste_delay_ms,
-                                            use_clipboard_only: ov.use_clipboard_only,
-                                        },
-                                    )
-                                })
-                                .collect(),
-                        };
-
-                        timing_marks.t3_postprocess_completed = Some(Instant::now());
-
-                        let mut result = if force_clipboard_only {
-                            let text_with_suffix =
-                                format!("{}{}", final_text, injection_config.suffix);
-                            let forced_reason = force_clipboard_reason
-                                .unwrap_or_else(|| DEVICE_REMOVED_CLIPBOARD_REASON.to_string());
-                            let fallback_reason =
-                                match crate::injection::set_clipboard_public(&text_with_suffix) {
-                                    Ok(()) => forced_reason,
-                                    Err(clipboard_error) => format!(
-                                        "{}; clipboard fallback failed: {}; transcript preserved in history",
-                                        forced_reason, clipboard_error
-                                    ),
-                                };
-
-                            InjectionResult::ClipboardOnly {
-                                reason: fallback_reason,
-                                text_length: final_text.len(),
-                                timestamp: chrono::Utc::now(),
-                            }
-                        } else {
-                            inject_text(&final_text, expected_focus, &injection_config).await
-                        };
-                        timing_marks.t4_injection_completed = Some(Instant::now());
-
-                        let pipeline_timings = pipeline_timings_from_marks(&timing_marks);
-                        if let Some(timings) = pipeline_timings.as_ref() {
-                            log_pipeline_timings(timings);
-                        }
+    /// Transcribe the same audio with each of `model_ids` and compare the
+    /// results side by side.
+    ///
+    /// Session audio is exported to a temporary WAV by the live sidecar; the
+    /// models themselves run in the auxiliary sidecar so loading them never
+    /// evicts the model used for dictation.
+    pub async fn compare_models(
+        &self,
+        audio: ComparisonAudio,
+        model_ids: Vec<String>,
+    ) -> Result<ModelComparison, IntegrationError> {
+        let (audio_path, temporary) = match audio {
+            ComparisonAudio::File(path) => {
+                if !path.is_file() {
+                    return Err(IntegrationError::InvalidInput(format!(
+                        "Audio file not found: {}",
+                        path.display()
+                    )));
+                }
+                (path, false)
+            }
+            ComparisonAudio::Session(session_id) => {
+                let path =
+                    std::env::temp_dir().join(format!("openvoicy-compare-{}.wav", Uuid::new_v4()));
+                self.export_session_audio(&session_id, &path).await?;
+                (path, true)
+            }
+        };
 
-                        let mut injection_app_error: Option<AppError> = None;
-                        if let InjectionResult::Failed { error, .. } = result.clone() {
-                            let text_with_suffix =
-                                format!("{}{}", final_text, injection_config.suffix);
-                            let fallback_reason =
-                                match crate::injection::set_clipboard_public(&text_with_suffix) {
-                                    Ok(()) => format!(
-                                        "{}; transcript copied to clipboard for manual paste",
-                                        error
-                                    ),
-                                Err(clipboard_error) => format!(
-                                    "{}; clipboard fallback failed: {}; transcript preserved in history",
-                                    error, clipboard_error
-                                ),
-                            };
-
-                            result = InjectionResult::ClipboardOnly {
-                                reason: fallback_reason.clone(),
-                                text_length: final_text.len(),
-                                timestamp: chrono::Utc::now(),
-                            };
-                            injection_app_error = Some(injection_failure_app_error(
-                                &fallback_reason,
-                                final_text.len(),
-                            ));
-                        }
+        let config = config::load_config();
+        let params = json!({
+            "audio_path": audio_path.to_string_lossy(),
+            "model_ids": model_ids,
+            "device_pref": config.effective_model_device_pref(),
+            "language": configured_model_language_hint(&config),
+        });
 
-                        match &result {
-                            InjectionResult::Injected { text_length, .. } => {
-                                log::info!("Text injected: {} chars", text_length);
-                            }
-                            InjectionResult::ClipboardOnly {
-                                reason,
-                                text_length,
-                                ..
-                            } => {
-                                log::info!("Clipboard-only mode: {}", reason);
-                                if clipboard_only_requires_app_error(reason)
-                                    && injection_app_error.is_none()
-                                {
-                                    injection_app_error =
-                                        Some(injection_failure_app_error(reason, *text_length));
-                                }
-                            }
-                            InjectionResult::Failed { error, .. } => {
-                                log::error!("Injection failed: {}", error);
-                            }
-                        }
+        #[derive(Deserialize)]
+        struct CompareModelsResult {
+            audio_duration_ms: u64,
+            results: Vec<ModelComparisonResult>,
+        }
 
-                        // Add to history and emit a shared transcript payload.
-                        let mut transcript_entry = TranscriptEntry::new(
-                            final_text.clone(),
-                            audio_duration_ms as u32,
-                            processing_duration_ms as u32,
-                            HistoryInjectionResult::from_injection_result(&result),
-                        )
-                        .with_session_id(Uuid::parse_str(&session_id).ok())
-                        .with_asr_metadata(language, confidence);
-                        transcript_entry.raw_text = raw_text;
-                        transcript_entry.final_text = final_text.clone();
-                        transcript_entry.text = final_text;
-                        if let Some(timings) = pipeline_timings.clone() {
-                            transcript_entry = transcript_entry.with_timings(timings);
-                        }
+        let result = self
+            .call_routed::<CompareModelsResult>("batch.compare_models", Some(params))
+            .await;
+        if temporary {
+            let _ = std::fs::remove_file(&audio_path);
+        }
 
-                        // Add to history
-                        if let Some(ref handle) = app_handle {
-                            let history = handle.state::<TranscriptHistory>();
-                            history.push(transcript_entry.clone());
-                            emit_with_shared_seq(
-                                handle,
-                                &[EVENT_TRAY_UPDATE],
-                                json!({
-                                    "reason": "history_changed",
-                                    "entry_id": transcript_entry.id,
-                                }),
-                                &event_seq,
-                            );
-                        }
+        match result {
+            Ok(result) => Ok(ModelComparison::new(
+                result.audio_duration_ms,
+                result.results,
+            )),
+            Err(IntegrationError::Rpc {
+                source: RpcError::Remote { kind, .. },
+                ..
+            }) if kind == "E_METHOD_NOT_FOUND" => Err(IntegrationError::InvalidInput(
+                "The sidecar does not support model comparison".to_string(),
+            )),
+            Err(error) => Err(error),
+        }
+    }
 
-                        // Emit canonical transcript event payload with shared seq.
-                        if let Some(ref handle) = app_handle {
-                            emit_with_shared_seq(
-                                handle,
-                                &[EVENT_TRANSCRIPT_COMPLETE],
-                                transcript_complete_event_payload(&transcript_entry),
-                                &event_seq,
-                            );
-                            if let Some(app_error) = injection_app_error {
-                                emit_with_shared_seq(
-                                    handle,
-                                    &[EVENT_APP_ERROR],
-                                    app_error_event_payload(&app_error),
-                                    &event_seq,
-                                );
-                            }
-                        }
+    /// Transcribe an audio file with the configured model and post-process it
+    /// as dictation would: active replacement rules, learned spellings and the
+    /// word filter.
+    ///
+    /// The file runs in the auxiliary sidecar, which loads its own copy of the
+    /// model, so a long file never blocks the live sidecar's dispatch loop.
+    pub async fn transcribe_file(
+        &self,
+        path: &std::path::Path,
+    ) -> Result<FileTranscript, IntegrationError> {
+        #[derive(Deserialize)]
+        struct TranscribeResult {
+            text: String,
+            language: Option<String>,
+            confidence: Option<f32>,
+            #[serde(default)]
+            segments: Vec<TranscriptSegment>,
+            #[serde(default)]
+            words: Vec<TranscriptWord>,
+        }
 
-                        // Clear context
-                        *recording_context.write().await = None;
-                        *current_session_id.write().await = None;
-                    }
-                    RecordingEvent::TranscriptionFailed {
-                        session_id, error, ..
-                    } => {
-                        if let Some(ref handle) = app_handle {
-                            emit_with_shared_seq(
-                                handle,
-                                &[EVENT_RECORDING_STATUS],
-                                recording_status_event_payload(
-                                    "idle",
-                                    Some(session_id.as_str()),
-                                    None,
-                                    None,
-                                ),
-                                &event_seq,
-                            );
-                        }
+        let config = config::load_config();
+        let params = file_transcribe_params(&config, path);
 
-                        log::error!(
-                            "Transcription failed: session={}, error_len={}, error_sha256_prefix={}",
-                            session_id,
-                            error.len(),
-                            sha256_prefix(&error),
-                        );
-                        if log::log_enabled!(log::Level::Debug) {
-                            log::debug!("Transcription error message (debug): {}", error);
-                        }
+        let started_at = Instant::now();
+        let result = match self
+            .call_routed::<TranscribeResult>("file.transcribe", Some(params))
+            .await
+        {
+            Ok(result) => result,
+            Err(IntegrationError::Rpc {
+                source: RpcError::Remote { kind, .. },
+                ..
+            }) if kind == "E_METHOD_NOT_FOUND" => {
+                return Err(IntegrationError::InvalidInput(
+                    "The sidecar does not support file transcription".to_string(),
+                ))
+            }
+            Err(error) => return Err(error),
+        };
+        let transcription_ms = started_at.elapsed().as_millis();
+
+        let raw_text = result.text.trim().to_string();
+        // The sidecar applies its synced active rules when none are passed.
+        let final_text = match self
+            .call_routed::<SidecarReplacementPreviewResult>(
+                "replacements.preview",
+                Some(json!({ "text": raw_text })),
+            )
+            .await
+        {
+            Ok(preview) => preview.result,
+            Err(error) => {
+                log::warn!("Replacements skipped for file transcript: {}", error);
+                raw_text.clone()
+            }
+        };
 
-                        if let Some(ref handle) = app_handle {
-                            let app_error =
-                                transcription_failure_app_error(&session_id, error.as_str());
-                            emit_with_shared_seq(
-                                handle,
-                                &[EVENT_TRANSCRIPT_ERROR],
-                                transcription_error_event_payload(&session_id, &app_error),
-                                &event_seq,
-                            );
-                            emit_with_shared_seq(
-                                handle,
-                                &[EVENT_APP_ERROR],
-                                app_error_event_payload(&app_error),
-                                &event_seq,
-                            );
-                        }
+        let mut segments = result.segments;
+        let mut words = result.words;
+        let (raw_text, final_text, unfiltered_text) = postprocess_file_transcript(
+            &config,
+            crate::learned_dictionary::global_dictionary(),
+            raw_text,
+            final_text,
+            &mut segments,
+            &mut words,
+        );
+        Ok(FileTranscript {
+            raw_text,
+            final_text,
+            unfiltered_text,
+            language: result.language,
+            confidence: result.confidence,
+            transcription_ms: u32::try_from(transcription_ms).unwrap_or(u32::MAX),
+            segments,
+            words,
+        })
+    }
 
-                        // Clear context
-                        *recording_context.write().await = None;
-                        *current_session_id.write().await = None;
-                    }
-                    RecordingEvent::TranscriptionTimeout { session_id, .. } => {
-                        if let Some(ref handle) = app_handle {
-                            emit_with_shared_seq(
-                                handle,
-                                &[EVENT_RECORDING_STATUS],
-                                recording_status_event_payload(
-                                    "idle",
-                                    Some(session_id.as_str()),
-                                    None,
-                                    None,
-                                ),
-                                &event_seq,
-                            );
-                        }
-                        *recording_context.write().await = None;
-                        *current_session_id.write().await = None;
-                    }
-                    RecordingEvent::Cancelled { .. } => {
-                        if let Some(ref handle) = app_handle {
-                            emit_with_shared_seq(
-                                handle,
-                                &[EVENT_RECORDING_STATUS],
-                                recording_status_event_payload("idle", None, None, None),
-                                &event_seq,
-                            );
-                        }
-                        *recording_context.write().await = None;
-                        *current_session_id.write().await = None;
-                    }
-                    _ => {}
-                }
+    async fn export_session_audio(
+        &self,
+        session_id: &str,
+        path: &std::path::Path,
+    ) -> Result<(), IntegrationError> {
+        let client = self.rpc_client.read().await;
+        let client = client
+            .as_ref()
+            .ok_or(IntegrationError::SidecarNotConnected)?;
+
+        match client
+            .call::<Value>(
+                "recording.export_audio",
+                Some(json!({
+                    "session_id": session_id,
+                    "audio_path": path.to_string_lossy(),
+                })),
+            )
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(RpcError::Remote { kind, .. }) if kind == "E_INVALID_SESSION" => {
+                Err(IntegrationError::InvalidInput(format!(
+                    "Audio for session {} is no longer available",
+                    session_id
+                )))
+            }
+            Err(RpcError::Remote { kind, .. }) if kind == "E_METHOD_NOT_FOUND" => {
+                Err(IntegrationError::InvalidInput(
+                    "The sidecar does not support model comparison".to_string(),
+                ))
             }
+            Err(error) => Err(IntegrationError::rpc("export session audio", error)),
+        }
+    }
 
-            log::info!("Recording event loop ended");
-        });
+    /// Initialize ASR model via sidecar.
+    pub async fn initialize_asr(
+        &self,
+        model_id: &str,
+        device: &str,
+    ) -> Result<(), IntegrationError> {
+        self.model.initialize_asr(model_id, device).await
     }
 
     /// Start sidecar notification processing loop.
@@ -3746,372 +3081,74 @@ impl IntegrationManager {
         &self,
         mut receiver: tokio::sync::broadcast::Receiver<NotificationEvent>,
     ) {
-        let recording_controller = Arc::clone(&self.recording_controller);
-        let model_status = Arc::clone(&self.model_status);
-        let model_progress = Arc::clone(&self.model_progress);
-        let app_handle = self.app_handle.clone();
+        let model = self.model.clone();
+        let audio = self.audio.clone();
+        let session = self.session.clone();
         let watchdog = Arc::clone(&self.watchdog);
-        let recording_context = Arc::clone(&self.recording_context);
-        let current_session_id = Arc::clone(&self.current_session_id);
-        let event_seq = Arc::clone(&self.event_seq);
 
         tokio::spawn(async move {
             log::info!("Notification loop started");
-            let mut last_meter_audio_emit_at: Option<Instant> = None;
-            let mut last_non_meter_audio_emit_at: Option<Instant> = None;
-
-            while let Ok(event) = receiver.recv().await {
-                // Any notification means the sidecar is alive
-                watchdog.mark_activity().await;
-
-                log::debug!("Sidecar notification: method={}", event.method);
+            let mut profile = power::current_profile();
+            let mut throttle =
+                NotificationThrottle::new(notification_throttle::notification_rules(profile));
 
-                match event.method.as_str() {
-                    "event.transcription_complete" => {
-                        let incoming_session_id = extract_session_id(&event.params);
-                        let active_session_id = current_session_id.read().await.clone();
-                        if is_stale_session(incoming_session_id, active_session_id.as_deref()) {
+            loop {
+                let event = tokio::select! {
+                    received = receiver.recv() => match received {
+                        Ok(event) => {
+                            // Any notification means the sidecar is alive
+                            watchdog.mark_activity().await;
+                            if power::current_profile() != profile {
+                                profile = power::current_profile();
+                                throttle = NotificationThrottle::new(
+                                    notification_throttle::notification_rules(profile),
+                                );
+                            }
+                            match throttle.admit(event, Instant::now()) {
+                                Some(event) => event,
+                                None => continue,
+                            }
+                        }
+                        Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
                             log::warn!(
-                                "{}",
-                                stale_notification_message(
-                                    incoming_session_id,
-                                    active_session_id.as_deref()
-                                )
+                                "Notification loop lagged; skipped {} notifications",
+                                skipped
                             );
                             continue;
                         }
-
-                        // Parse transcription result
-                        #[derive(Deserialize)]
-                        struct TranscriptionParams {
-                            session_id: String,
-                            text: String,
-                            duration_ms: u64,
-                            #[serde(default)]
-                            confidence: Option<f64>,
-                            #[serde(default)]
-                            language: Option<String>,
-                            #[serde(default)]
-                            raw_text: Option<String>,
-                            #[serde(default)]
-                            final_text: Option<String>,
-                        }
-
-                        if let Ok(params) =
-                            serde_json::from_value::<TranscriptionParams>(event.params)
-                        {
-                            let (raw_text, final_text) = resolve_transcript_texts(
-                                &params.text,
-                                params.raw_text.as_deref(),
-                                params.final_text.as_deref(),
-                            );
-                            let (audio_duration_ms, processing_duration_ms) = {
-                                let mut stop_audio_duration_ms = None;
-                                let mut ctx = recording_context.write().await;
-                                if let Some(ctx) = ctx.as_mut() {
-                                    if ctx.session_id == params.session_id {
-                                        ctx.timing_marks.t2_transcription_received =
-                                            Some(Instant::now());
-                                        stop_audio_duration_ms = ctx.audio_duration_ms;
-                                        ctx.raw_text = Some(raw_text.clone());
-                                        ctx.final_text = Some(final_text.clone());
-                                        ctx.language = params
-                                            .language
-                                            .as_deref()
-                                            .map(str::trim)
-                                            .filter(|value| !value.is_empty())
-                                            .map(ToString::to_string);
-                                        ctx.confidence = params.confidence.map(|v| v as f32);
-                                    }
-                                }
-                                map_transcription_complete_durations(
-                                    params.duration_ms,
-                                    stop_audio_duration_ms,
-                                )
-                            };
-
-                            let result = TranscriptionResult {
-                                session_id: params.session_id,
-                                // Sidecar text is authoritative and already post-processed.
-                                text: final_text,
-                                audio_duration_ms,
-                                processing_duration_ms,
-                            };
-
-                            // Deliver to recording controller (validates session ID)
-                            recording_controller.on_transcription_result(result).await;
+                        Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                    },
+                    _ = wait_for_throttle_deadline(throttle.next_deadline()) => {
+                        match throttle.take_due(Instant::now()) {
+                            Some(event) => event,
+                            None => continue,
                         }
                     }
-                    "event.transcription_error" => {
-                        let incoming_session_id = extract_session_id(&event.params);
-                        let active_session_id = current_session_id.read().await.clone();
-                        if is_stale_session(incoming_session_id, active_session_id.as_deref()) {
-                            log::warn!(
-                                "{}",
-                                stale_notification_message(
-                                    incoming_session_id,
-                                    active_session_id.as_deref()
-                                )
-                            );
-                            continue;
-                        }
+                };
 
-                        #[derive(Deserialize)]
-                        struct ErrorParams {
-                            session_id: String,
-                            kind: String,
-                            message: String,
-                        }
+                log::debug!("Sidecar notification: method={}", event.method);
 
-                        if let Ok(params) = serde_json::from_value::<ErrorParams>(event.params) {
-                            recording_controller
-                                .on_transcription_error(
-                                    params.session_id,
-                                    format!("{}: {}", params.kind, params.message),
-                                )
-                                .await;
-                        }
+                match event.method.as_str() {
+                    "event.transcription_complete" => {
+                        session.handle_transcription_complete(event.params).await;
+                    }
+                    "event.utterance_complete" => {
+                        session.handle_utterance_complete(event.params).await;
+                    }
+                    "event.transcription_error" => {
+                        session.handle_transcription_error(event.params).await;
                     }
                     "event.status_changed" => {
-                        // Handle model progress updates
-                        #[derive(Deserialize)]
-                        struct StatusParams {
-                            #[allow(dead_code)]
-                            #[serde(default)]
-                            state: Option<String>,
-                            #[allow(dead_code)]
-                            #[serde(default)]
-                            detail: Option<String>,
-                            #[serde(default)]
-                            model: Option<Value>,
-                            #[serde(default)]
-                            progress: Option<ProgressParams>,
-                            #[serde(default)]
-                            restart_count: Option<u32>,
-                        }
-
-                        #[derive(Deserialize)]
-                        struct ModelParams {
-                            #[allow(dead_code)]
-                            #[serde(default)]
-                            model_id: Option<String>,
-                            status: String,
-                            #[serde(default)]
-                            revision: Option<String>,
-                            #[serde(default)]
-                            cache_path: Option<String>,
-                        }
-
-                        #[derive(Deserialize)]
-                        struct ProgressParams {
-                            current: u64,
-                            #[serde(default)]
-                            total: Option<u64>,
-                            #[serde(default)]
-                            unit: Option<String>,
-                            #[serde(default)]
-                            stage: Option<String>,
-                            #[serde(default)]
-                            current_file: Option<String>,
-                            #[serde(default)]
-                            files_completed: Option<u64>,
-                            #[serde(default)]
-                            files_total: Option<u64>,
-                        }
-
-                        let mut canonical_sidecar_payload =
-                            sidecar_status_payload_from_status_event(None, None, None);
-
-                        if let Ok(params) =
-                            serde_json::from_value::<StatusParams>(event.params.clone())
-                        {
-                            let parsed_model = params.model.as_ref().and_then(|model| {
-                                serde_json::from_value::<ModelParams>(model.clone()).ok()
-                            });
-
-                            // Support both spec-compliant model object and legacy string model state.
-                            let model_state = parsed_model
-                                .as_ref()
-                                .map(|parsed| parsed.status.clone())
-                                .or_else(|| {
-                                    params
-                                        .model
-                                        .as_ref()
-                                        .and_then(|model| model.as_str().map(ToOwned::to_owned))
-                                });
-
-                            let status_progress = params.progress.as_ref().map(|progress| {
-                                status_progress_from_parts(
-                                    progress.current,
-                                    progress.total,
-                                    progress.unit.clone(),
-                                    progress.stage.clone(),
-                                )
-                            });
-
-                            // Update model status if provided
-                            if let Some(model_state) = model_state {
-                                let new_status = map_status_event_model_state(
-                                    model_state.as_str(),
-                                    params.detail.clone(),
-                                );
-                                *model_status.write().await = new_status.clone();
-
-                                if new_status == ModelStatus::Ready {
-                                    recording_controller.set_model_ready(true).await;
-                                } else {
-                                    recording_controller.set_model_ready(false).await;
-                                }
-
-                                Self::emit_model_status_with_details(
-                                    &app_handle,
-                                    new_status,
-                                    &event_seq,
-                                    parsed_model.as_ref().and_then(|m| m.model_id.clone()),
-                                    parsed_model.as_ref().and_then(|m| m.revision.clone()),
-                                    parsed_model.as_ref().and_then(|m| m.cache_path.clone()),
-                                    status_progress.clone(),
-                                );
-                            }
-
-                            // Update and emit progress if provided
-                            if let Some(ref progress) = params.progress {
-                                let model_progress_data = model_progress_from_parts(
-                                    parsed_model.as_ref().and_then(|m| m.model_id.clone()),
-                                    progress.current,
-                                    progress.total,
-                                    progress.unit.clone(),
-                                    progress.stage.clone(),
-                                    progress.current_file.clone(),
-                                    progress.files_completed,
-                                    progress.files_total,
-                                );
-                                *model_progress.write().await = Some(model_progress_data.clone());
-
-                                if let Some(ref handle) = app_handle {
-                                    emit_with_shared_seq(
-                                        handle,
-                                        &[EVENT_MODEL_PROGRESS],
-                                        json!(model_progress_data),
-                                        &event_seq,
-                                    );
-                                }
-                            }
-
-                            canonical_sidecar_payload = sidecar_status_payload_from_status_event(
-                                params.state.as_deref(),
-                                params.detail.clone(),
-                                params.restart_count,
-                            );
-                        }
-
-                        // Emit canonical sidecar status payload with shared seq.
-                        if let Some(ref handle) = app_handle {
-                            let seq = next_seq(&event_seq);
-                            emit_with_existing_seq_to_all_windows(
-                                handle,
-                                EVENT_SIDECAR_STATUS,
-                                canonical_sidecar_payload,
-                                seq,
-                            );
-                        }
+                        model.handle_status_changed(event.params).await;
                     }
                     "event.model_progress" => {
-                        if let Ok(params) =
-                            serde_json::from_value::<SidecarModelProgress>(event.params)
-                        {
-                            let model_progress_data = model_progress_from_parts(
-                                params.model_id,
-                                params.current,
-                                params.total,
-                                params.unit,
-                                params.stage,
-                                params.current_file,
-                                params.files_completed,
-                                params.files_total,
-                            );
-                            *model_progress.write().await = Some(model_progress_data.clone());
-
-                            if let Some(ref handle) = app_handle {
-                                emit_with_shared_seq(
-                                    handle,
-                                    &[EVENT_MODEL_PROGRESS],
-                                    json!(model_progress_data),
-                                    &event_seq,
-                                );
-                            }
-                        }
+                        model.handle_model_progress(event.params).await;
                     }
                     "event.audio_level" => {
-                        #[derive(Deserialize)]
-                        struct AudioLevelParams {
-                            source: String,
-                            rms: f64,
-                            peak: f64,
-                            #[serde(default)]
-                            session_id: Option<String>,
-                        }
-
-                        if let Ok(params) = serde_json::from_value::<AudioLevelParams>(event.params)
-                        {
-                            // recording source is session-scoped and should include session_id.
-                            if params.source == "recording" && params.session_id.is_none() {
-                                log::warn!("Ignoring invalid audio_level event: missing session_id for recording source");
-                                continue;
-                            }
-
-                            if params.source == "recording" {
-                                let active_session_id = current_session_id.read().await.clone();
-                                let incoming_session_id = params.session_id.as_deref();
-                                if is_stale_session(
-                                    incoming_session_id,
-                                    active_session_id.as_deref(),
-                                ) {
-                                    log::warn!(
-                                        "{}",
-                                        stale_notification_message(
-                                            incoming_session_id,
-                                            active_session_id.as_deref()
-                                        )
-                                    );
-                                    continue;
-                                }
-                            }
-
-                            let now = Instant::now();
-                            let should_emit = if params.source == "meter" {
-                                should_emit_audio_level(
-                                    now,
-                                    &mut last_meter_audio_emit_at,
-                                    Duration::from_millis(AUDIO_LEVEL_METER_MIN_INTERVAL_MS),
-                                )
-                            } else {
-                                should_emit_audio_level(
-                                    now,
-                                    &mut last_non_meter_audio_emit_at,
-                                    Duration::from_millis(AUDIO_LEVEL_NON_METER_MIN_INTERVAL_MS),
-                                )
-                            };
-                            if !should_emit {
-                                continue;
-                            }
-
-                            if let Some(ref handle) = app_handle {
-                                emit_with_shared_seq(
-                                    handle,
-                                    &["audio:level"],
-                                    json!({
-                                        "source": params.source,
-                                        "rms": params.rms,
-                                        "peak": params.peak,
-                                        "session_id": params.session_id,
-                                    }),
-                                    &event_seq,
-                                );
-                            }
-                        } else {
-                            log::warn!("Ignoring invalid audio_level payload");
-                        }
+                        audio.handle_audio_level(event.params).await;
+                    }
+                    "event.vad_preview" => {
+                        audio.handle_vad_preview(event.params);
                     }
                     _ => {
                         log::debug!("Unhandled notification: {}", event.method);
@@ -4133,11 +3170,7 @@ impl IntegrationManager {
         // Shutdown sidecar
         self.stop_sidecar_runtime().await;
 
-        // Shutdown hotkey manager
-        {
-            let mut hk = self.hotkey_manager.write().await;
-            hk.shutdown();
-        }
+        self.hotkeys.shutdown().await;
 
         log::info!("Integration manager shutdown complete");
     }
@@ -4148,41 +3181,48 @@ impl IntegrationManager {
         supervisor.recent_captured_log_lines(count)
     }
 
+    /// State of the live sidecar's supervisor, for support snapshots.
+    pub async fn supervisor_snapshot(&self) -> SupervisorSnapshot {
+        let supervisor = self.supervisor.lock().await;
+        SupervisorSnapshot {
+            state: supervisor.state(),
+            restart_count: supervisor.restart_count(),
+            circuit_breaker_open: supervisor.circuit_breaker_open(),
+        }
+    }
+
+    /// Send a support snapshot's events to the windows in the background.
+    /// Returns false when there are no windows to send them to.
+    pub fn replay_state_snapshot(&self, snapshot: AppStateSnapshot) -> bool {
+        let Some(app_handle) = self.app_handle.clone() else {
+            return false;
+        };
+        let event_seq = Arc::clone(&self.event_seq);
+        crate::runtime::spawn(async move {
+            state_snapshot::replay(&snapshot, &app_handle, || next_seq(&event_seq)).await;
+            log::info!("Finished replaying state snapshot");
+        });
+        true
+    }
+
     /// Get the watchdog for external monitoring.
     pub fn watchdog(&self) -> &Arc<Watchdog> {
         &self.watchdog
     }
-}
-
-/// RPC ping adapter for watchdog.
-struct RpcPinger {
-    rpc_client: Arc<RwLock<Option<RpcClient>>>,
-}
-
-impl PingCallback for RpcPinger {
-    async fn ping(&self) -> Result<(), String> {
-        let client = self.rpc_client.read().await;
-        let client = client
-            .as_ref()
-            .ok_or_else(|| "Sidecar not connected".to_string())?;
 
-        #[derive(serde::Deserialize)]
-        struct PingResult {
-            #[allow(dead_code)]
-            version: String,
-        }
-
-        client
-            .call::<PingResult>("system.ping", None)
-            .await
-            .map(|_| ())
-            .map_err(|e| format!("Ping failed: {}", e))
+    /// Apply changed watchdog timings from the `supervisor` settings and the
+    /// power profile without restarting the watchdog.
+    pub fn apply_watchdog_config(&self, supervisor: &config::SupervisorConfig) {
+        self.health.apply_config(supervisor);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::focus::capture_focus;
+    use crate::history::HistoryInjectionResult;
+    use crate::state::EnableLevel;
     use regex::Regex;
     use std::collections::HashMap;
     use std::fs;
@@ -4270,10 +3310,8 @@ mod tests {
         fn install_with_missing_sounds(base_dir: &Path) -> Self {
             let missing_sounds_dir = base_dir.join("missing-audio-cues");
             AUDIO_CUE_MANAGER.with(|slot| {
-                *slot.borrow_mut() = Some(AudioCueManager::with_sounds_dir(
-                    missing_sounds_dir,
-                    true,
-                ));
+                *slot.borrow_mut() =
+                    Some(AudioCueManager::with_sounds_dir(missing_sounds_dir, true));
             });
             Self
         }
@@ -4695,7 +3733,8 @@ for raw in sys.stdin:
                     println!(
                         "[RECORDING_FLOW][EVENT] {} {}",
                         chrono::Utc::now().to_rfc3339(),
-                        serde_json::to_string(&event).unwrap_or_else(|_| "<serialize-error>".into())
+                        serde_json::to_string(&event)
+                            .unwrap_or_else(|_| "<serialize-error>".into())
                     );
                     if predicate(&event) {
                         return event;
@@ -4902,6 +3941,7 @@ for raw in sys.stdin:
         let event = StateEvent {
             state: AppState::Recording,
             enabled: true,
+            level: EnableLevel::Enabled,
             detail: Some("capturing".to_string()),
             timestamp: chrono::Utc::now(),
         };
@@ -4916,10 +3956,7 @@ for raw in sys.stdin:
         println!("[EVENT_TEST] state event emitted with seq={emitted_seq}");
 
         let main_events = broadcaster.received_event_names("main");
-        assert_eq!(
-            main_events,
-            vec![EVENT_STATE_CHANGED.to_string()]
-        );
+        assert_eq!(main_events, vec![EVENT_STATE_CHANGED.to_string()]);
         assert!(
             !main_events.contains(&"state_changed".to_string()),
             "legacy state_changed alias must not be emitted"
@@ -5119,17 +4156,28 @@ for raw in sys.stdin:
 
     #[test]
     fn test_no_window_specific_emit_calls_for_app_events() {
-        let source = include_str!("integration.rs");
-        let runtime_source = source.split("#[cfg(test)]").next().unwrap_or(source);
-        let forbidden_emit_to = Regex::new(r"\.emit_to\s*\(").expect("valid emit_to detector regex");
-        assert!(
-            !forbidden_emit_to.is_match(runtime_source),
-            "window-specific emit_to usage is forbidden for app events"
-        );
+        let sources = [
+            include_str!("integration.rs"),
+            include_str!("integration/audio_service.rs"),
+            include_str!("integration/model_service.rs"),
+            include_str!("integration/overlay_service.rs"),
+            include_str!("integration/session_service.rs"),
+        ];
+        let forbidden_emit_to =
+            Regex::new(r"\.emit_to\s*\(").expect("valid emit_to detector regex");
+        for source in sources {
+            let runtime_source = source.split("#[cfg(test)]").next().unwrap_or(source);
+            assert!(
+                !forbidden_emit_to.is_match(runtime_source),
+                "window-specific emit_to usage is forbidden for app events"
+            );
+        }
 
         // Regression: whitespace/newline variants must still be detected.
         assert!(forbidden_emit_to.is_match("window.emit_to (\"main\", \"state:changed\", payload)"));
-        assert!(forbidden_emit_to.is_match("window.emit_to\n(\"main\", \"state:changed\", payload)"));
+        assert!(
+            forbidden_emit_to.is_match("window.emit_to\n(\"main\", \"state:changed\", payload)")
+        );
     }
 
     #[test]
@@ -5382,15 +4430,25 @@ for raw in sys.stdin:
 
     #[test]
     fn test_model_download_params_omits_empty_model_id_and_optional_force() {
-        assert!(model_download_params(None, None).is_none());
-        assert_eq!(model_download_params(Some("   ".to_string()), None), None);
+        assert!(model_download_params(None, None, false).is_none());
         assert_eq!(
-            model_download_params(Some("parakeet".to_string()), Some(true)),
+            model_download_params(Some("   ".to_string()), None, false),
+            None
+        );
+        assert_eq!(
+            model_download_params(Some("parakeet".to_string()), Some(true), false),
             Some(json!({
                 "model_id": "parakeet",
                 "force": true
             }))
         );
+        assert_eq!(
+            model_download_params(Some("parakeet".to_string()), None, true),
+            Some(json!({
+                "model_id": "parakeet",
+                "license_accepted": true
+            }))
+        );
     }
 
     #[test]
@@ -5401,6 +4459,10 @@ for raw in sys.stdin:
             device: None,
             preferred_device: "auto".to_string(),
             language: Some(" ja ".to_string()),
+            beam_size: None,
+            preload_on_start: false,
+            preload_delay_ms: 0,
+            asr_backend: config::AsrBackend::default(),
         });
 
         assert_eq!(
@@ -5417,37 +4479,173 @@ for raw in sys.stdin:
             device: None,
             preferred_device: "auto".to_string(),
             language: Some("   ".to_string()),
+            beam_size: None,
+            preload_on_start: false,
+            preload_delay_ms: 0,
+            asr_backend: config::AsrBackend::default(),
         });
 
         assert_eq!(configured_model_language_hint(&config), None);
     }
 
     #[test]
-    fn test_asr_initialize_params_includes_optional_language_when_present() {
-        let params = asr_initialize_params("parakeet", "cuda", Some("en"));
+    fn test_asr_initialize_params_includes_optional_language_when_present() {
+        let params = asr_initialize_params("parakeet", "cuda", Some("en"), None);
+        assert_eq!(
+            params,
+            json!({
+                "model_id": "parakeet",
+                "device_pref": "cuda",
+                "language": "en"
+            })
+        );
+    }
+
+    #[test]
+    fn test_asr_initialize_params_omits_language_when_absent_or_blank() {
+        let no_language = asr_initialize_params("parakeet", "auto", None, None);
+        assert_eq!(
+            no_language,
+            json!({
+                "model_id": "parakeet",
+                "device_pref": "auto"
+            })
+        );
+
+        let blank_language = asr_initialize_params("parakeet", "auto", Some("   "), None);
+        assert_eq!(blank_language, no_language);
+    }
+
+    #[test]
+    fn test_model_preload_delay_requires_toggle() {
+        let mut config = config::AppConfig::default();
+        assert_eq!(model_preload_delay(&config), None);
+
+        config.model = Some(config::ModelConfig {
+            model_id: None,
+            device: None,
+            preferred_device: "auto".to_string(),
+            language: None,
+            beam_size: None,
+            preload_on_start: false,
+            preload_delay_ms: 1500,
+            asr_backend: config::AsrBackend::default(),
+        });
+        assert_eq!(model_preload_delay(&config), None);
+
+        config.model.as_mut().unwrap().preload_on_start = true;
+        assert_eq!(
+            model_preload_delay(&config),
+            Some(Duration::from_millis(1500))
+        );
+    }
+
+    #[test]
+    fn test_asr_initialize_params_includes_beam_size_override_only_when_set() {
+        let params = asr_initialize_params("parakeet", "auto", None, Some(4));
+        assert_eq!(params["beam_size"], 4);
+
+        let mut config = config::AppConfig::default();
+        assert_eq!(configured_model_beam_size(&config), None);
+        config.model = Some(config::ModelConfig {
+            model_id: None,
+            device: None,
+            preferred_device: "auto".to_string(),
+            language: None,
+            beam_size: Some(4),
+            preload_on_start: false,
+            preload_delay_ms: 0,
+            asr_backend: config::AsrBackend::default(),
+        });
+        assert_eq!(configured_model_beam_size(&config), Some(4));
+    }
+
+    #[test]
+    fn test_postprocess_file_transcript_applies_word_filter_like_dictation() {
+        let dir = tempfile::tempdir().unwrap();
+        let dictionary =
+            crate::learned_dictionary::LearnedDictionary::new(dir.path().join("learned.json"));
+        let mut config = config::AppConfig::default();
+        config.vocabulary.learn_capitalization = false;
+        let mut segments = vec![TranscriptSegment {
+            start_ms: 0,
+            end_ms: 900,
+            text: "Oh crap".to_string(),
+            speaker: Some("spk_0".to_string()),
+        }];
+        let mut words = vec![TranscriptWord {
+            word: "crap".to_string(),
+            start_ms: 300,
+            end_ms: 900,
+            confidence: None,
+        }];
+        let (raw, final_text, unfiltered) = postprocess_file_transcript(
+            &config,
+            &dictionary,
+            "oh crap".to_string(),
+            "Oh crap".to_string(),
+            &mut segments,
+            &mut words,
+        );
+        assert_eq!((raw.as_str(), final_text.as_str()), ("oh crap", "Oh crap"));
+        assert_eq!(unfiltered, None);
+        assert_eq!(segments[0].text, "Oh crap");
+
+        config.word_filter.enabled = true;
+        config.word_filter.keep_unfiltered = true;
+        let (raw, final_text, unfiltered) = postprocess_file_transcript(
+            &config,
+            &dictionary,
+            "oh crap".to_string(),
+            "Oh crap".to_string(),
+            &mut segments,
+            &mut words,
+        );
+        assert_eq!(raw, "oh c***");
+        assert_eq!(final_text, "Oh c***");
+        assert_eq!(unfiltered.as_deref(), Some("Oh crap"));
+        assert_eq!(segments[0].text, "Oh c***");
+        assert_eq!(segments[0].speaker.as_deref(), Some("spk_0"));
+        assert_eq!(words[0].word, "c***");
+    }
+
+    #[test]
+    fn test_postprocess_file_transcript_learns_capitalization() {
+        let dir = tempfile::tempdir().unwrap();
+        let dictionary =
+            crate::learned_dictionary::LearnedDictionary::new(dir.path().join("learned.json"));
+        let config = config::AppConfig::default();
+        assert!(config.vocabulary.learn_capitalization);
+        let postprocess = |raw: &str, final_text: &str| {
+            postprocess_file_transcript(
+                &config,
+                &dictionary,
+                raw.to_string(),
+                final_text.to_string(),
+                &mut [],
+                &mut Vec::new(),
+            )
+            .1
+        };
+
+        assert_eq!(postprocess("use github", "use GitHub"), "use GitHub");
         assert_eq!(
-            params,
-            json!({
-                "model_id": "parakeet",
-                "device_pref": "cuda",
-                "language": "en"
-            })
+            postprocess("push to github", "push to GitHub"),
+            "push to GitHub"
         );
+        assert_eq!(postprocess("open github", "open github"), "open GitHub");
     }
 
     #[test]
-    fn test_asr_initialize_params_omits_language_when_absent_or_blank() {
-        let no_language = asr_initialize_params("parakeet", "auto", None);
-        assert_eq!(
-            no_language,
-            json!({
-                "model_id": "parakeet",
-                "device_pref": "auto"
-            })
-        );
+    fn test_file_transcribe_params_request_diarization_when_enabled() {
+        let mut config = config::AppConfig::default();
+        let path = std::path::Path::new("/tmp/meeting.wav");
+        let params = file_transcribe_params(&config, path);
+        assert_eq!(params["audio_path"], "/tmp/meeting.wav");
+        assert!(params.get("diarize").is_none());
 
-        let blank_language = asr_initialize_params("parakeet", "auto", Some("   "));
-        assert_eq!(blank_language, no_language);
+        config.audio.diarization_enabled = true;
+        assert_eq!(file_transcribe_params(&config, path)["diarize"], true);
     }
 
     #[test]
@@ -5456,6 +4654,7 @@ for raw in sys.stdin:
             code: -32602,
             kind: "E_INVALID_PARAMS".to_string(),
             message: "Invalid params: unknown field 'language'".to_string(),
+            correlation_id: None,
         };
 
         assert!(asr_initialize_language_rejected(&error));
@@ -5467,6 +4666,7 @@ for raw in sys.stdin:
             code: -32601,
             kind: "E_METHOD_NOT_FOUND".to_string(),
             message: "Method not found".to_string(),
+            correlation_id: None,
         };
 
         assert!(asr_initialize_language_rejected(&error));
@@ -5478,6 +4678,7 @@ for raw in sys.stdin:
             code: -32001,
             kind: "E_NOT_READY".to_string(),
             message: "ASR backend not initialized".to_string(),
+            correlation_id: None,
         };
 
         assert!(!asr_initialize_language_rejected(&error));
@@ -5489,11 +4690,19 @@ for raw in sys.stdin:
             code: -32011,
             message: "download failed".to_string(),
             kind: "E_NETWORK".to_string(),
+            correlation_id: None,
         };
 
         let mapped = map_model_download_rpc_error(error);
-        assert!(mapped.contains("E_NETWORK"));
-        assert!(mapped.contains("network connection"));
+        assert!(matches!(
+            mapped,
+            IntegrationError::Model {
+                kind: ErrorKind::Network,
+                ..
+            }
+        ));
+        assert!(mapped.to_string().contains("E_NETWORK"));
+        assert!(mapped.to_string().contains("network connection"));
     }
 
     #[test]
@@ -5502,11 +4711,12 @@ for raw in sys.stdin:
             code: -32013,
             message: "not enough disk".to_string(),
             kind: "E_DISK_FULL".to_string(),
+            correlation_id: None,
         };
 
         let mapped = map_model_download_rpc_error(error);
-        assert!(mapped.contains("E_DISK_FULL"));
-        assert!(mapped.contains("Free disk space"));
+        assert!(mapped.to_string().contains("E_DISK_FULL"));
+        assert!(mapped.to_string().contains("Free disk space"));
     }
 
     #[test]
@@ -5582,6 +4792,28 @@ for raw in sys.stdin:
         assert_eq!(payload.model_id, configured_model_id());
         assert_eq!(payload.status, "ready");
         assert!(payload.error.is_none());
+        // Sidecar models omit the engine kind; only the embedded fallback sets it.
+        assert!(json!(payload).get("kind").is_none());
+    }
+
+    #[test]
+    fn test_loading_stage_only_attaches_to_loading_payloads() {
+        let loading = model_status_event_payload(ModelStatus::Loading, None, None, None, None);
+        let payload = with_loading_stage(loading, Some("compile".to_string()), Some(42.4));
+        assert_eq!(payload.stage.as_deref(), Some("compile"));
+        assert_eq!(payload.percent, Some(42));
+        let json = json!(payload);
+        assert_eq!(json["stage"], "compile");
+        assert_eq!(json["percent"], 42);
+
+        let loading = model_status_event_payload(ModelStatus::Loading, None, None, None, None);
+        let payload = with_loading_stage(loading, Some("warmup".to_string()), Some(250.0));
+        assert_eq!(payload.percent, Some(100));
+
+        let ready = model_status_event_payload(ModelStatus::Ready, None, None, None, None);
+        let payload = with_loading_stage(ready, Some("warmup".to_string()), Some(100.0));
+        assert!(payload.stage.is_none());
+        assert!(json!(payload).get("percent").is_none());
     }
 
     #[test]
@@ -5683,7 +4915,8 @@ for raw in sys.stdin:
     #[test]
     fn test_targeted_purge_with_no_reported_ids_does_not_affect_configured_model() {
         let configured = "nvidia/parakeet-tdt-0.6b-v3";
-        let status_model_ids = purge_status_model_ids(Some("openai/whisper-large"), configured, &[]);
+        let status_model_ids =
+            purge_status_model_ids(Some("openai/whisper-large"), configured, &[]);
         assert!(status_model_ids.is_empty());
         assert!(!purge_affects_configured_model(
             configured,
@@ -5798,7 +5031,10 @@ for raw in sys.stdin:
             payloads[0].get("model_id").and_then(Value::as_str),
             Some("openai/whisper-large")
         );
-        assert_eq!(payloads[0].get("status").and_then(Value::as_str), Some("missing"));
+        assert_eq!(
+            payloads[0].get("status").and_then(Value::as_str),
+            Some("missing")
+        );
         assert_eq!(payloads[0].get("seq").and_then(Value::as_u64), Some(1));
     }
 
@@ -5896,6 +5132,7 @@ for raw in sys.stdin:
 
         assert_eq!(
             recording_event_audio_cue(&RecordingEvent::TooShort {
+                session_id: "session-1".to_string(),
                 duration_ms: 100,
                 timestamp: now,
             }),
@@ -5911,6 +5148,61 @@ for raw in sys.stdin:
         );
     }
 
+    #[test]
+    fn test_recording_event_journal_record_omits_transcript_text() {
+        let now = chrono::Utc::now();
+
+        let complete = RecordingEvent::TranscriptionComplete {
+            session_id: "session-1".to_string(),
+            text: "secret dictation".to_string(),
+            audio_duration_ms: 1500,
+            processing_duration_ms: 300,
+            timestamp: now,
+        };
+        let (event, session_id, detail) =
+            recording_event_journal_record(&complete).expect("transcription is journaled");
+        assert_eq!(event, JournalEvent::Transcribed);
+        assert_eq!(session_id, Some("session-1"));
+        let detail = detail.expect("detail");
+        assert_eq!(detail["text_length"], 16);
+        assert!(!detail.to_string().contains("secret dictation"));
+
+        let (event, _, detail) = recording_event_journal_record(&RecordingEvent::Cancelled {
+            session_id: "session-1".to_string(),
+            reason: CancelReason::DoubleTap,
+            timestamp: now,
+        })
+        .expect("cancel is journaled");
+        assert_eq!(event, JournalEvent::SessionCancelled);
+        assert_eq!(detail, Some(json!({ "reason": "double_tap" })));
+
+        assert!(
+            recording_event_journal_record(&RecordingEvent::MaxDurationReached {
+                session_id: "session-1".to_string(),
+                duration_ms: 60_000,
+                timestamp: now,
+            })
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_injection_result_journal_detail_records_outcome() {
+        let entry_id = Uuid::new_v4();
+        let detail = injection_result_journal_detail(
+            &InjectionResult::ClipboardOnly {
+                reason: "focus changed".to_string(),
+                text_length: 42,
+                timestamp: chrono::Utc::now(),
+            },
+            entry_id,
+        );
+
+        assert_eq!(detail["outcome"], "clipboard_only");
+        assert_eq!(detail["text_length"], 42);
+        assert_eq!(detail["entry_id"], entry_id.to_string());
+    }
+
     #[test]
     fn test_lifecycle_audio_cues_disabled_in_test_builds() {
         assert!(!should_play_lifecycle_audio_cues());
@@ -5965,6 +5257,7 @@ for raw in sys.stdin:
         }));
 
         assert!(!is_overlay_recording_active(&RecordingEvent::TooShort {
+            session_id: "session-1".to_string(),
             duration_ms: 50,
             timestamp: now,
         }));
@@ -6035,6 +5328,24 @@ for raw in sys.stdin:
         );
     }
 
+    #[test]
+    fn test_overlay_stays_up_for_continuous_utterances() {
+        assert_eq!(
+            overlay_recording_state_for_event(
+                true,
+                &RecordingEvent::UtteranceComplete {
+                    session_id: "session-1".to_string(),
+                    segment_index: 0,
+                    text: "first sentence".to_string(),
+                    audio_duration_ms: 1800,
+                    processing_duration_ms: 120,
+                    timestamp: chrono::Utc::now(),
+                }
+            ),
+            None
+        );
+    }
+
     #[test]
     fn test_has_transcription_timed_out_when_elapsed_reaches_timeout() {
         let base = Instant::now();
@@ -6055,6 +5366,113 @@ for raw in sys.stdin:
         ));
     }
 
+    #[test]
+    fn test_is_transient_transcription_error_kinds() {
+        assert!(is_transient_transcription_error("E_TRANSCRIPTION_TIMEOUT"));
+        assert!(is_transient_transcription_error("E_SIDECAR_RESTARTING"));
+        assert!(is_transient_transcription_error("E_SIDECAR_CRASH"));
+        assert!(is_transient_transcription_error("asr-timeout"));
+        assert!(!is_transient_transcription_error("E_TRANSCRIBE"));
+        assert!(!is_transient_transcription_error("E_MODEL_NOT_READY"));
+        assert!(!is_transient_transcription_error(""));
+    }
+
+    fn retry_test_context(session_id: &str) -> RecordingContext {
+        RecordingContext {
+            focus_before: capture_focus(),
+            session_id: session_id.to_string(),
+            audio_duration_ms: None,
+            raw_text: None,
+            final_text: None,
+            language: None,
+            confidence: None,
+            segments: Vec::new(),
+            words: Vec::new(),
+            auto_punctuation: true,
+            force_clipboard_only: false,
+            force_clipboard_reason: None,
+            timing_marks: PipelineTimingMarks::default(),
+            transcription_retries: 0,
+            retry_requested_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_claim_transcription_retry_once_per_session() {
+        let manager = IntegrationManager::new(Arc::new(AppStateManager::new()));
+        let session = &manager.session;
+        *session.recording_context.write().await = Some(retry_test_context("session-1"));
+
+        assert!(
+            !session
+                .claim_transcription_retry("session-1", "E_TRANSCRIBE")
+                .await
+        );
+        assert!(
+            !session
+                .claim_transcription_retry("session-other", "E_TRANSCRIPTION_TIMEOUT")
+                .await
+        );
+        assert!(
+            session
+                .claim_transcription_retry("session-1", "E_TRANSCRIPTION_TIMEOUT")
+                .await
+        );
+        assert!(
+            !session
+                .claim_transcription_retry("session-1", "E_SIDECAR_RESTARTING")
+                .await
+        );
+
+        let ctx = session.recording_context.read().await;
+        let ctx = ctx.as_ref().expect("context");
+        assert_eq!(ctx.transcription_retries, 1);
+        assert!(ctx.retry_requested_at.is_some());
+    }
+
+    fn transcription_lookup(value: Value) -> TranscriptionLookup {
+        serde_json::from_value(value).expect("lookup payload should parse")
+    }
+
+    #[test]
+    fn test_recovered_transcription_outcome_returns_completed_result() {
+        let outcome = recovered_transcription_outcome(transcription_lookup(json!({
+            "session_id": "session-1",
+            "state": "completed",
+            "result": { "session_id": "session-1", "text": "hello", "duration_ms": 12 }
+        })));
+        assert_eq!(
+            outcome,
+            Some(Ok(
+                json!({ "session_id": "session-1", "text": "hello", "duration_ms": 12 })
+            ))
+        );
+    }
+
+    #[test]
+    fn test_recovered_transcription_outcome_formats_error_like_notification() {
+        let outcome = recovered_transcription_outcome(transcription_lookup(json!({
+            "session_id": "session-1",
+            "state": "error",
+            "error": { "kind": "E_TRANSCRIBE", "message": "model crashed" }
+        })));
+        assert_eq!(
+            outcome,
+            Some(Err("E_TRANSCRIBE: model crashed".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_recovered_transcription_outcome_ignores_unfinished_sessions() {
+        for state in ["pending", "cancelled", "unknown", "completed"] {
+            let outcome = recovered_transcription_outcome(transcription_lookup(json!({
+                "session_id": "session-1",
+                "state": state
+            })));
+            assert_eq!(outcome, None, "state {state} should not recover a result");
+        }
+    }
+
     #[test]
     fn test_startup_model_status_requires_loading_state_for_downloading_and_loading() {
         assert!(startup_model_status_requires_loading_state("downloading"));
@@ -6152,7 +5570,11 @@ for raw in sys.stdin:
 
         let event_names = broadcaster.received_event_names("main");
         let payloads = broadcaster.received_payloads("main");
-        assert_eq!(event_names.len(), 1, "Bridge must emit exactly 1 canonical event");
+        assert_eq!(
+            event_names.len(),
+            1,
+            "Bridge must emit exactly 1 canonical event"
+        );
 
         assert_eq!(event_names[0], "sidecar:status");
         assert_eq!(
@@ -6317,7 +5739,9 @@ for raw in sys.stdin:
             Some(false)
         );
         assert_eq!(
-            payload.pointer("/error/details/reason").and_then(Value::as_str),
+            payload
+                .pointer("/error/details/reason")
+                .and_then(Value::as_str),
             Some("os_denied")
         );
     }
@@ -6394,11 +5818,141 @@ for raw in sys.stdin:
         assert!(!clipboard_only_requires_app_error(
             "App override clipboard-only mode (slack)"
         ));
+        assert!(!clipboard_only_requires_app_error(
+            GLOBAL_CLIPBOARD_ONLY_REASON
+        ));
+        assert!(!clipboard_only_requires_app_error(
+            ASK_MODE_CLIPBOARD_REASON
+        ));
+        assert!(!clipboard_only_requires_app_error(
+            &InjectionGuard::TextLength {
+                chars: 5000,
+                limit: 2000
+            }
+            .clipboard_reason()
+        ));
         assert!(clipboard_only_requires_app_error(
             "Focus changed from Terminal to Browser"
         ));
     }
 
+    #[test]
+    fn test_injection_guard_checks_text_length_then_audio_duration() {
+        let mut injection = config::InjectionConfig::default();
+        assert_eq!(
+            InjectionGuard::check(&injection, &"a".repeat(10_000), 3_600_000),
+            None
+        );
+
+        injection.confirm_above_chars = 10;
+        injection.confirm_above_minutes = 5;
+        assert_eq!(
+            InjectionGuard::check(&injection, "0123456789", 300_000),
+            None
+        );
+        // Characters, not bytes.
+        assert_eq!(InjectionGuard::check(&injection, "ééééééééé", 0), None);
+        assert_eq!(
+            InjectionGuard::check(&injection, "0123456789!", 0),
+            Some(InjectionGuard::TextLength {
+                chars: 11,
+                limit: 10
+            })
+        );
+        let guard = InjectionGuard::check(&injection, "short", 420_000).unwrap();
+        assert_eq!(
+            guard,
+            InjectionGuard::AudioDuration {
+                minutes: 7,
+                limit: 5
+            }
+        );
+        assert_eq!(guard.as_str(), "audio_duration");
+        assert_eq!(
+            guard.clipboard_reason(),
+            "Injection guard: recording ran 7 minutes (limit 5); transcript copied to clipboard pending confirmation."
+        );
+    }
+
+    #[test]
+    fn test_injection_confirm_requested_payload_reports_guard_only_when_held() {
+        let entry = TranscriptEntry::new(
+            "hello".to_string(),
+            420_000,
+            340,
+            HistoryInjectionResult::Injected,
+        );
+        let guard = InjectionGuard::AudioDuration {
+            minutes: 7,
+            limit: 5,
+        };
+
+        let held = json!(injection_confirm_requested_payload(
+            &entry,
+            Some(guard),
+            420_000
+        ));
+        assert_eq!(
+            held,
+            json!({
+                "entry_id": entry.id.to_string(),
+                "text_length": 5,
+                "guard": "audio_duration",
+                "audio_duration_ms": 420_000,
+            })
+        );
+
+        let asked = json!(injection_confirm_requested_payload(&entry, None, 420_000));
+        assert_eq!(
+            asked,
+            json!({
+                "entry_id": entry.id.to_string(),
+                "text_length": 5,
+            })
+        );
+    }
+
+    #[test]
+    fn test_pending_insert_reason_only_for_unrequested_clipboard_fallbacks() {
+        let clipboard_only = |reason: &str| InjectionResult::ClipboardOnly {
+            reason: reason.to_string(),
+            text_length: 5,
+            timestamp: chrono::Utc::now(),
+        };
+
+        assert_eq!(
+            pending_insert_reason(&clipboard_only("Focus changed from Terminal to Browser")),
+            Some("Focus changed from Terminal to Browser")
+        );
+        assert_eq!(
+            pending_insert_reason(&clipboard_only(GLOBAL_CLIPBOARD_ONLY_REASON)),
+            None
+        );
+        assert_eq!(
+            pending_insert_reason(&clipboard_only(ASK_MODE_CLIPBOARD_REASON)),
+            None
+        );
+        // A busy target that never recovered stays queued; one the user
+        // dismissed while waiting does not come back.
+        let expired = busy_target_clipboard_reason(Duration::from_secs(10));
+        assert_eq!(
+            pending_insert_reason(&clipboard_only(&expired)),
+            Some(expired.as_str())
+        );
+        assert_eq!(
+            pending_insert_reason(&clipboard_only(BUSY_TARGET_DISMISSED_REASON)),
+            None
+        );
+        assert_eq!(
+            pending_insert_reason(&InjectionResult::Injected {
+                text_length: 5,
+                paste_verification: None,
+                timestamp: chrono::Utc::now(),
+            }),
+            None
+        );
+    }
+
     #[test]
     fn test_injection_method_attempted_classifies_reasons() {
         assert_eq!(
@@ -6516,6 +6070,7 @@ for raw in sys.stdin:
         let event = StateEvent {
             state: AppState::Idle,
             enabled: true,
+            level: EnableLevel::Stealth,
             detail: None,
             timestamp: chrono::Utc::now(),
         };
@@ -6523,6 +6078,10 @@ for raw in sys.stdin:
         let payload = state_changed_event_payload(&event);
         assert_eq!(payload.get("state").and_then(Value::as_str), Some("idle"));
         assert_eq!(payload.get("enabled").and_then(Value::as_bool), Some(true));
+        assert_eq!(
+            payload.get("level").and_then(Value::as_str),
+            Some("stealth")
+        );
         assert!(payload.get("detail").is_some());
         assert!(payload.get("timestamp").and_then(Value::as_str).is_some());
     }
@@ -6559,18 +6118,162 @@ for raw in sys.stdin:
         );
 
         assert_eq!(
-            payload.get("phase").and_then(Value::as_str),
-            Some("recording")
+            payload.get("phase").and_then(Value::as_str),
+            Some("recording")
+        );
+        assert_eq!(
+            payload.get("session_id").and_then(Value::as_str),
+            Some("session-1")
+        );
+        assert_eq!(
+            payload.get("started_at").and_then(Value::as_str),
+            Some("2026-02-19T03:00:00Z")
+        );
+        assert!(payload.get("audio_ms").is_none());
+    }
+
+    #[test]
+    fn test_recording_started_event_payload_flags_screen_sharing_only_when_warning() {
+        let started_at = "2026-02-19T03:00:00Z".to_string();
+        let payload = recording_started_event_payload(
+            "session-1",
+            started_at.clone(),
+            Some(ScreenSharePolicy::Warn),
+        );
+        assert_eq!(
+            payload.get("phase").and_then(Value::as_str),
+            Some("recording")
+        );
+        assert_eq!(
+            payload.get("screen_sharing").and_then(Value::as_bool),
+            Some(true)
+        );
+
+        let payload = recording_started_event_payload(
+            "session-1",
+            started_at.clone(),
+            Some(ScreenSharePolicy::ShowOverlay),
+        );
+        assert!(payload.get("screen_sharing").is_none());
+        let payload = recording_started_event_payload("session-1", started_at, None);
+        assert!(payload.get("screen_sharing").is_none());
+    }
+
+    #[test]
+    fn test_session_changed_payload_matches_active_session_snapshot() {
+        let snapshot = SessionSnapshot::created("session-5".to_string(), chrono::Utc::now())
+            .fail("mic unplugged".to_string(), chrono::Utc::now())
+            .expect("created session can fail");
+
+        let payload = session_changed_payload(&snapshot);
+
+        assert_eq!(payload, serde_json::to_value(&snapshot).unwrap());
+        assert_eq!(payload.get("phase").and_then(Value::as_str), Some("failed"));
+        assert_eq!(payload.get("revision").and_then(Value::as_u64), Some(2));
+    }
+
+    #[test]
+    fn test_overlay_transcribing_payload_reports_elapsed_and_audio_duration() {
+        let payload = overlay_transcribing_payload("session-3", 1500, 4200);
+
+        assert_eq!(
+            payload.get("session_id").and_then(Value::as_str),
+            Some("session-3")
+        );
+        assert_eq!(
+            payload.get("elapsed_ms").and_then(Value::as_u64),
+            Some(1500)
+        );
+        assert_eq!(payload.get("audio_ms").and_then(Value::as_u64), Some(4200));
+    }
+
+    #[test]
+    fn test_overlay_cancelled_payload_reports_reason() {
+        let payload = overlay_cancelled_payload("session-4", &CancelReason::EscapeKey);
+
+        assert_eq!(
+            payload.get("session_id").and_then(Value::as_str),
+            Some("session-4")
+        );
+        assert_eq!(
+            payload.get("reason").and_then(Value::as_str),
+            Some("escape_key")
+        );
+    }
+
+    #[test]
+    fn test_overlay_countdown_payload_reports_remaining_and_cancellation() {
+        let payload = overlay_countdown_payload(3, false);
+        assert_eq!(
+            payload.get("remaining_secs").and_then(Value::as_u64),
+            Some(3)
+        );
+        assert_eq!(payload.get("cancelled"), Some(&Value::Bool(false)));
+
+        let payload = overlay_countdown_payload(0, true);
+        assert_eq!(
+            payload.get("remaining_secs").and_then(Value::as_u64),
+            Some(0)
+        );
+        assert_eq!(payload.get("cancelled"), Some(&Value::Bool(true)));
+    }
+
+    #[test]
+    fn test_cues_status_payload_reports_reason_and_app() {
+        let payload = cues_status_payload(&CueStatus::default());
+        assert_eq!(payload.get("suppressed"), Some(&Value::Bool(false)));
+        assert_eq!(payload.get("reason"), Some(&Value::Null));
+        assert_eq!(payload.get("app"), Some(&Value::Null));
+
+        let status = call_detect::cue_status(&crate::config::AudioConfig {
+            in_meeting: true,
+            ..Default::default()
+        });
+        let payload = cues_status_payload(&status);
+        assert_eq!(payload.get("suppressed"), Some(&Value::Bool(true)));
+        assert_eq!(
+            payload.get("reason").and_then(Value::as_str),
+            Some("manual")
+        );
+    }
+
+    #[test]
+    fn test_overlay_hide_delay_only_for_cancel() {
+        let now = chrono::Utc::now();
+        assert_eq!(
+            overlay_hide_delay_for_event(&RecordingEvent::Cancelled {
+                session_id: "session-1".to_string(),
+                reason: CancelReason::EscapeKey,
+                timestamp: now,
+            }),
+            OVERLAY_CANCEL_ANIMATION
         );
         assert_eq!(
-            payload.get("session_id").and_then(Value::as_str),
-            Some("session-1")
+            overlay_hide_delay_for_event(&RecordingEvent::TooShort {
+                session_id: "session-1".to_string(),
+                duration_ms: 50,
+                timestamp: now,
+            }),
+            Duration::ZERO
         );
+    }
+
+    #[test]
+    fn test_overlay_config_payload_carries_appearance() {
+        let payload = overlay_config_payload(&OverlayAppearance {
+            theme: "dark".to_string(),
+            scale: 1.5,
+            opacity: 0.8,
+            show_waveform: false,
+        });
+
+        assert_eq!(payload.get("theme").and_then(Value::as_str), Some("dark"));
+        assert_eq!(payload.get("scale").and_then(Value::as_f64), Some(1.5));
+        assert_eq!(payload.get("opacity").and_then(Value::as_f64), Some(0.8));
         assert_eq!(
-            payload.get("started_at").and_then(Value::as_str),
-            Some("2026-02-19T03:00:00Z")
+            payload.get("show_waveform").and_then(Value::as_bool),
+            Some(false)
         );
-        assert!(payload.get("audio_ms").is_none());
     }
 
     #[test]
@@ -6613,6 +6316,26 @@ for raw in sys.stdin:
         assert!(payload.get("audio_ms").is_none());
     }
 
+    #[test]
+    fn test_injection_progress_event_payload_reports_chunk_counts() {
+        let payload = injection_progress_event_payload(&InjectionProgress {
+            chunks_done: 2,
+            chunk_count: 5,
+            chars_injected: 1600,
+            total_chars: 3900,
+        });
+
+        assert_eq!(payload["chunks_done"], 2);
+        assert_eq!(payload["chunk_count"], 5);
+        assert_eq!(payload["chars_injected"], 1600);
+        assert_eq!(payload["total_chars"], 3900);
+    }
+
+    #[test]
+    fn test_injection_queue_changed_payload_reports_depth() {
+        assert_eq!(injection_queue_changed_payload(2), json!({ "depth": 2 }));
+    }
+
     #[test]
     fn test_transcription_failure_app_error_preserves_sidecar_error_kind() {
         let app_error =
@@ -6706,6 +6429,48 @@ for raw in sys.stdin:
         assert!(!is_configured_device_available(Some("mic-z"), &devices));
     }
 
+    #[test]
+    fn test_recovery_checks_need_a_usable_answer() {
+        let ok = |value: Value| -> Result<Value, RpcError> { Ok(value) };
+        let devices = ok(json!({ "devices": [{ "uid": "mic-a" }] }));
+
+        assert!(recovery_check_passed(
+            RecoveryCheck::SidecarPing,
+            &ok(json!({ "version": "1" })),
+            None
+        ));
+        assert!(!recovery_check_passed(
+            RecoveryCheck::SidecarPing,
+            &Err(RpcError::Disconnected),
+            None
+        ));
+        assert!(recovery_check_passed(
+            RecoveryCheck::ModelStatus,
+            &ok(json!({ "status": "ready" })),
+            None
+        ));
+        assert!(!recovery_check_passed(
+            RecoveryCheck::ModelStatus,
+            &ok(json!({ "status": "loading" })),
+            None
+        ));
+        assert!(recovery_check_passed(
+            RecoveryCheck::AudioDevices,
+            &devices,
+            Some("mic-a")
+        ));
+        assert!(!recovery_check_passed(
+            RecoveryCheck::AudioDevices,
+            &devices,
+            Some("mic-z")
+        ));
+        assert!(!recovery_check_passed(
+            RecoveryCheck::AudioDevices,
+            &ok(json!({ "devices": [] })),
+            None
+        ));
+    }
+
     fn test_device(uid: &str, name: &str) -> SidecarAudioDevice {
         SidecarAudioDevice {
             uid: uid.to_string(),
@@ -6809,44 +6574,6 @@ for raw in sys.stdin:
         );
     }
 
-    #[test]
-    fn test_should_emit_audio_level_throttles_meter_source() {
-        let min_interval = Duration::from_millis(AUDIO_LEVEL_METER_MIN_INTERVAL_MS);
-        let start = Instant::now();
-        let mut last = None;
-
-        assert!(should_emit_audio_level(start, &mut last, min_interval));
-        assert!(!should_emit_audio_level(
-            start + Duration::from_millis(5),
-            &mut last,
-            min_interval
-        ));
-        assert!(should_emit_audio_level(
-            start + Duration::from_millis(40),
-            &mut last,
-            min_interval
-        ));
-    }
-
-    #[test]
-    fn test_should_emit_audio_level_throttles_non_meter_sources_to_15hz() {
-        let min_interval = Duration::from_millis(AUDIO_LEVEL_NON_METER_MIN_INTERVAL_MS);
-        let start = Instant::now();
-        let mut last = None;
-
-        assert!(should_emit_audio_level(start, &mut last, min_interval));
-        assert!(!should_emit_audio_level(
-            start + Duration::from_millis(40),
-            &mut last,
-            min_interval
-        ));
-        assert!(should_emit_audio_level(
-            start + Duration::from_millis(90),
-            &mut last,
-            min_interval
-        ));
-    }
-
     #[tokio::test]
     async fn test_integration_manager_creation() {
         let state_manager = Arc::new(AppStateManager::new());
@@ -6865,6 +6592,78 @@ for raw in sys.stdin:
         assert_eq!(status, crate::watchdog::HealthStatus::NotRunning);
     }
 
+    #[tokio::test]
+    async fn test_sidecar_switch_aborts_for_recording_started_after_idle_check() {
+        let state_manager = Arc::new(AppStateManager::new());
+        let manager = IntegrationManager::new(Arc::clone(&state_manager));
+        assert!(manager.wait_for_dictation_to_finish().await);
+
+        // A hotkey recording is between recording.start and `Recording`.
+        let starting = Arc::clone(&manager.session.capture_gate).read_owned().await;
+        let switch = manager.block_recording_for_switch();
+        tokio::pin!(switch);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), &mut switch)
+                .await
+                .is_err(),
+            "the switch must wait for the starting recording"
+        );
+
+        state_manager.transition(AppState::Recording).unwrap();
+        drop(starting);
+        assert!(switch.await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sidecar_switch_blocks_recording_start_until_done() {
+        let manager = IntegrationManager::new(Arc::new(AppStateManager::new()));
+
+        let blocked = manager
+            .block_recording_for_switch()
+            .await
+            .expect("no dictation in progress");
+        assert!(tokio::time::timeout(
+            Duration::from_millis(50),
+            manager.session.capture_gate.read()
+        )
+        .await
+        .is_err());
+
+        drop(blocked);
+        assert!(manager.session.capture_gate.try_read().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_auxiliary_call_spawn_failure_leaves_live_sidecar_untouched() {
+        let state_manager = Arc::new(AppStateManager::new());
+        let mut manager = IntegrationManager::new(state_manager);
+        manager.config.python_path = "__missing_python_binary__".to_string();
+        manager.config.sidecar_module = "openvoicy_sidecar".to_string();
+
+        let error = manager
+            .call_routed::<Value>("batch.transcribe", None)
+            .await
+            .expect_err("auxiliary call should fail when sidecar cannot spawn");
+        assert!(error
+            .to_string()
+            .contains("Failed to start auxiliary sidecar"));
+        assert!(manager.auxiliary_rpc_client.read().await.is_none());
+        assert_eq!(
+            manager.supervisor.lock().await.state(),
+            SupervisorState::Stopped
+        );
+        assert_eq!(
+            manager
+                .supervisors
+                .get(SidecarRole::Auxiliary)
+                .unwrap()
+                .lock()
+                .await
+                .state(),
+            SupervisorState::Failed
+        );
+    }
+
     #[tokio::test]
     async fn test_restart_sidecar_reports_spawn_failure_and_clears_runtime_handles() {
         let state_manager = Arc::new(AppStateManager::new());
@@ -6876,7 +6675,7 @@ for raw in sys.stdin:
             .restart_sidecar()
             .await
             .expect_err("restart_sidecar should fail when sidecar cannot spawn");
-        assert!(error.contains("Failed to spawn"));
+        assert!(error.to_string().contains("Failed to spawn"));
         assert!(manager.rpc_client.read().await.is_none());
         assert_eq!(
             manager.supervisor.lock().await.state(),
@@ -6897,7 +6696,106 @@ for raw in sys.stdin:
             .start_mic_test(Some("device-1".to_string()))
             .await
             .expect_err("start_mic_test should fail without sidecar");
-        assert!(error.contains("Sidecar not connected"));
+        assert!(matches!(error, IntegrationError::SidecarNotConnected));
+    }
+
+    #[test]
+    fn test_idle_activity_timer_fires_once_after_limit() {
+        let start = Instant::now();
+        let limit = Duration::from_secs(600);
+        let mut timer = IdleActivityTimer::new(start);
+
+        assert!(!timer.check_idle(
+            start + Duration::from_secs(599),
+            limit,
+            true,
+            AppState::Idle
+        ));
+        assert!(timer.check_idle(start + limit, limit, true, AppState::Idle));
+        // Already paused: no repeat firing.
+        assert!(!timer.check_idle(start + limit * 2, limit, false, AppState::Idle));
+
+        assert!(timer.take_auto_disabled(start + limit * 2));
+        assert!(!timer.take_auto_disabled(start + limit * 2));
+        assert!(!timer.check_idle(
+            start + limit * 2 + Duration::from_secs(1),
+            limit,
+            true,
+            AppState::Idle
+        ));
+    }
+
+    #[test]
+    fn test_idle_activity_timer_reports_time_since_last_dictation() {
+        let start = Instant::now();
+        let mut timer = IdleActivityTimer::new(start);
+        assert_eq!(
+            timer.idle_for(start + Duration::from_secs(90)),
+            Duration::from_secs(90)
+        );
+
+        timer.record_activity(start + Duration::from_secs(60));
+        assert_eq!(
+            timer.idle_for(start + Duration::from_secs(90)),
+            Duration::from_secs(30)
+        );
+        // Active sessions keep the timer at zero.
+        timer.check_idle(
+            start + Duration::from_secs(120),
+            Duration::ZERO,
+            true,
+            AppState::Recording,
+        );
+        assert_eq!(
+            timer.idle_for(start + Duration::from_secs(120)),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_idle_activity_timer_skips_active_sessions_and_zero_limit() {
+        let start = Instant::now();
+        let later = start + Duration::from_secs(3600);
+        let mut timer = IdleActivityTimer::new(start);
+
+        assert!(!timer.check_idle(later, Duration::ZERO, true, AppState::Idle));
+        assert!(!timer.check_idle(later, Duration::from_secs(60), true, AppState::Recording));
+        // The session just ended: the idle period starts now, not at its start.
+        assert!(!timer.check_idle(
+            later + Duration::from_secs(30),
+            Duration::from_secs(60),
+            true,
+            AppState::Idle
+        ));
+
+        timer.record_activity(later + Duration::from_secs(90));
+        assert!(!timer.check_idle(
+            later + Duration::from_secs(120),
+            Duration::from_secs(60),
+            true,
+            AppState::Idle
+        ));
+    }
+
+    #[test]
+    fn test_idle_activity_timer_ignores_user_pause_and_manual_reenable() {
+        let start = Instant::now();
+        let limit = Duration::from_secs(60);
+        let mut timer = IdleActivityTimer::new(start);
+
+        // User paused listening themselves: the hotkey must not re-arm it.
+        assert!(!timer.check_idle(start + limit, limit, false, AppState::Idle));
+        assert!(!timer.take_auto_disabled(start + limit));
+
+        // Auto-disabled, then re-enabled from the tray: timer restarts.
+        assert!(timer.check_idle(start + limit, limit, true, AppState::Idle));
+        assert!(!timer.check_idle(
+            start + limit * 2 - Duration::from_secs(1),
+            limit,
+            true,
+            AppState::Idle
+        ));
+        assert!(timer.check_idle(start + limit * 3, limit, true, AppState::Idle));
     }
 
     #[test]
@@ -6909,7 +6807,7 @@ for raw in sys.stdin:
         app_config.audio.vad_silence_ms = 1500;
         app_config.audio.vad_min_speech_ms = 350;
 
-        let params = recording_start_params("session-1", &app_config);
+        let params = recording_start_params("session-1", &app_config, true);
 
         assert_eq!(params["session_id"], "session-1");
         assert_eq!(params["device_uid"], "mic-1");
@@ -6917,28 +6815,156 @@ for raw in sys.stdin:
         assert_eq!(params["vad_enabled"], true);
         assert_eq!(params["vad_silence_ms"], 1500);
         assert_eq!(params["vad_min_speech_ms"], 350);
+        assert!(params.get("diarize").is_none());
+        assert!(params.get("continuous").is_none());
+    }
+
+    #[test]
+    fn test_recording_start_params_continuous_dictation_enables_vad() {
+        let mut app_config = config::AppConfig::default();
+        app_config.audio.continuous_dictation = true;
+        assert!(!app_config.audio.vad_enabled);
+
+        let params = recording_start_params("session-1", &app_config, true);
+
+        assert_eq!(params["continuous"], true);
+        assert_eq!(params["vad_enabled"], true);
+        assert!(params.get("flush_interval_ms").is_none());
+    }
+
+    #[test]
+    fn test_recording_start_params_send_flush_interval_only_when_continuous() {
+        let mut app_config = config::AppConfig::default();
+        app_config.audio.live_flush_secs = 10;
+        assert!(recording_start_params("session-1", &app_config, true)
+            .get("flush_interval_ms")
+            .is_none());
+
+        app_config.audio.continuous_dictation = true;
+        let params = recording_start_params("session-1", &app_config, true);
+        assert_eq!(params["flush_interval_ms"], 10_000);
+    }
+
+    #[test]
+    fn test_recording_start_params_request_diarization_only_in_meetings() {
+        let mut app_config = config::AppConfig::default();
+        app_config.audio.diarization_enabled = true;
+        assert!(recording_start_params("session-1", &app_config, true)
+            .get("diarize")
+            .is_none());
+
+        app_config.audio.in_meeting = true;
+        let params = recording_start_params("session-1", &app_config, true);
+
+        assert_eq!(params["diarize"], true);
+    }
+
+    #[test]
+    fn test_recording_start_params_include_vocabulary_hotwords_when_set() {
+        let mut app_config = config::AppConfig::default();
+        assert!(recording_start_params("session-1", &app_config, true)
+            .get("hotwords")
+            .is_none());
+
+        app_config.vocabulary.terms = vec!["OpenVoicy".to_string(), "Tauri".to_string()];
+        let params = recording_start_params("session-1", &app_config, true);
+
+        assert_eq!(params["hotwords"], json!(["OpenVoicy", "Tauri"]));
+    }
+
+    #[test]
+    fn test_recording_start_params_include_input_gains_when_set() {
+        let mut app_config = config::AppConfig::default();
+        assert!(recording_start_params("session-1", &app_config, true)
+            .get("input_gains")
+            .is_none());
+
+        app_config
+            .audio
+            .input_gains
+            .insert("linux:abc123".to_string(), 9.0);
+        let params = recording_start_params("session-1", &app_config, true);
+
+        assert_eq!(params["input_gains"], json!({ "linux:abc123": 9.0 }));
+    }
+
+    #[test]
+    fn test_recording_start_params_only_send_punctuation_opt_out() {
+        let app_config = config::AppConfig::default();
+        assert!(recording_start_params("session-1", &app_config, true)
+            .get("punctuate")
+            .is_none());
+
+        let params = recording_start_params("session-1", &app_config, false);
+        assert_eq!(params["punctuate"], false);
     }
 
     #[tokio::test]
     async fn test_start_recording_requires_sidecar_connection_without_state_transition() {
         let state_manager = Arc::new(AppStateManager::new());
         let manager = IntegrationManager::new(Arc::clone(&state_manager));
-        manager.recording_controller.set_model_ready(true).await;
+        manager
+            .session
+            .recording_controller
+            .set_model_ready(true)
+            .await;
 
         let error = manager
             .start_recording()
             .await
             .expect_err("start_recording should fail without sidecar");
-        assert!(error.contains("Sidecar not connected"));
+        assert!(matches!(error, IntegrationError::SidecarNotConnected));
         assert_eq!(state_manager.get(), AppState::Idle);
-        assert!(manager.current_session_id.read().await.is_none());
+        assert!(manager.session.current_session_id.read().await.is_none());
         assert!(manager
+            .session
             .recording_controller
             .current_session_id()
             .await
             .is_none());
     }
 
+    #[tokio::test]
+    async fn test_countdown_is_refused_while_the_model_loads() {
+        let state_manager = Arc::new(AppStateManager::new());
+        let manager = IntegrationManager::new(Arc::clone(&state_manager));
+
+        let error = manager
+            .session
+            .start_after_countdown(false, 3)
+            .await
+            .expect_err("countdown should not start without a ready model");
+        assert!(matches!(
+            error,
+            IntegrationError::CannotRecord(crate::state::CannotRecordReason::ModelLoading)
+        ));
+        assert!(!manager.session.cancel_countdown().await);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_countdown_never_starts_recording() {
+        let state_manager = Arc::new(AppStateManager::new());
+        let manager = IntegrationManager::new(Arc::clone(&state_manager));
+        manager
+            .session
+            .recording_controller
+            .set_model_ready(true)
+            .await;
+
+        manager
+            .session
+            .start_after_countdown(false, 3)
+            .await
+            .expect("countdown should start");
+        assert!(manager.session.cancel_countdown().await);
+        assert!(!manager.session.cancel_countdown().await);
+
+        tokio::time::sleep(OVERLAY_CANCEL_ANIMATION + Duration::from_millis(50)).await;
+        assert_eq!(state_manager.get(), AppState::Idle);
+        assert!(manager.session.current_session_id.read().await.is_none());
+        assert!(manager.active_session().await.is_none());
+    }
+
     #[tokio::test]
     async fn test_stop_recording_requires_active_session() {
         let state_manager = Arc::new(AppStateManager::new());
@@ -6948,9 +6974,9 @@ for raw in sys.stdin:
             .stop_recording()
             .await
             .expect_err("stop_recording should fail without an active session");
-        assert!(error.contains("No recording in progress"));
+        assert!(matches!(error, IntegrationError::NotRecording));
         assert_eq!(state_manager.get(), AppState::Idle);
-        assert!(manager.current_session_id.read().await.is_none());
+        assert!(manager.session.current_session_id.read().await.is_none());
     }
 
     #[tokio::test]
@@ -6962,26 +6988,31 @@ for raw in sys.stdin:
             .cancel_recording()
             .await
             .expect_err("cancel_recording should fail without an active session");
-        assert!(error.contains("No recording in progress"));
+        assert!(matches!(error, IntegrationError::NotRecording));
         assert_eq!(state_manager.get(), AppState::Idle);
-        assert!(manager.current_session_id.read().await.is_none());
+        assert!(manager.session.current_session_id.read().await.is_none());
     }
 
     #[tokio::test]
     async fn test_cancel_recording_without_sidecar_is_best_effort_and_clears_state() {
         let state_manager = Arc::new(AppStateManager::new());
         let manager = IntegrationManager::new(Arc::clone(&state_manager));
-        manager.recording_controller.set_model_ready(true).await;
+        manager
+            .session
+            .recording_controller
+            .set_model_ready(true)
+            .await;
 
         let session_id = "session-cancel-1".to_string();
         manager
+            .session
             .recording_controller
             .start_with_session_id(session_id.clone())
             .await
             .expect("host recording should start for cancel flow test");
 
-        *manager.current_session_id.write().await = Some(session_id.clone());
-        *manager.recording_context.write().await = Some(RecordingContext {
+        *manager.session.current_session_id.write().await = Some(session_id.clone());
+        *manager.session.recording_context.write().await = Some(RecordingContext {
             focus_before: capture_focus(),
             session_id,
             audio_duration_ms: None,
@@ -6989,9 +7020,14 @@ for raw in sys.stdin:
             final_text: None,
             language: None,
             confidence: None,
+            segments: Vec::new(),
+            words: Vec::new(),
+            auto_punctuation: true,
             force_clipboard_only: false,
             force_clipboard_reason: None,
             timing_marks: PipelineTimingMarks::default(),
+            transcription_retries: 0,
+            retry_requested_at: None,
         });
 
         manager
@@ -7000,9 +7036,10 @@ for raw in sys.stdin:
             .expect("cancel_recording should succeed even without sidecar connection");
 
         assert_eq!(state_manager.get(), AppState::Idle);
-        assert!(manager.current_session_id.read().await.is_none());
-        assert!(manager.recording_context.read().await.is_none());
+        assert!(manager.session.current_session_id.read().await.is_none());
+        assert!(manager.session.recording_context.read().await.is_none());
         assert!(manager
+            .session
             .recording_controller
             .current_session_id()
             .await
@@ -7013,16 +7050,21 @@ for raw in sys.stdin:
     async fn test_no_audio_device_cleanup_clears_active_recording_session() {
         let state_manager = Arc::new(AppStateManager::new());
         let manager = IntegrationManager::new(Arc::clone(&state_manager));
-        manager.recording_controller.set_model_ready(true).await;
+        manager
+            .session
+            .recording_controller
+            .set_model_ready(true)
+            .await;
 
         let session_id = "session-no-device-recording".to_string();
         manager
+            .session
             .recording_controller
             .start_with_session_id(session_id.clone())
             .await
             .expect("recording controller should accept test session");
-        *manager.current_session_id.write().await = Some(session_id.clone());
-        *manager.recording_context.write().await = Some(RecordingContext {
+        *manager.session.current_session_id.write().await = Some(session_id.clone());
+        *manager.session.recording_context.write().await = Some(RecordingContext {
             focus_before: capture_focus(),
             session_id,
             audio_duration_ms: None,
@@ -7030,22 +7072,21 @@ for raw in sys.stdin:
             final_text: None,
             language: None,
             confidence: None,
+            segments: Vec::new(),
+            words: Vec::new(),
+            auto_punctuation: true,
             force_clipboard_only: false,
             force_clipboard_reason: None,
             timing_marks: PipelineTimingMarks::default(),
+            transcription_retries: 0,
+            retry_requested_at: None,
         });
         let _ = state_manager.transition(AppState::Recording);
 
-        cleanup_active_session_for_no_audio_devices(
-            &manager,
-            &state_manager,
-            &manager.recording_context,
-            &manager.current_session_id,
-        )
-        .await;
+        manager.session.end_for_no_audio_devices().await;
 
-        assert!(manager.current_session_id.read().await.is_none());
-        assert!(manager.recording_context.read().await.is_none());
+        assert!(manager.session.current_session_id.read().await.is_none());
+        assert!(manager.session.recording_context.read().await.is_none());
         assert_eq!(state_manager.get(), AppState::Idle);
     }
 
@@ -7053,16 +7094,21 @@ for raw in sys.stdin:
     async fn test_no_audio_device_cleanup_clears_active_transcribing_session() {
         let state_manager = Arc::new(AppStateManager::new());
         let manager = IntegrationManager::new(Arc::clone(&state_manager));
-        manager.recording_controller.set_model_ready(true).await;
+        manager
+            .session
+            .recording_controller
+            .set_model_ready(true)
+            .await;
 
         let session_id = "session-no-device-transcribing".to_string();
         manager
+            .session
             .recording_controller
             .start_with_session_id(session_id.clone())
             .await
             .expect("recording controller should accept test session");
-        *manager.current_session_id.write().await = Some(session_id.clone());
-        *manager.recording_context.write().await = Some(RecordingContext {
+        *manager.session.current_session_id.write().await = Some(session_id.clone());
+        *manager.session.recording_context.write().await = Some(RecordingContext {
             focus_before: capture_focus(),
             session_id,
             audio_duration_ms: Some(1200),
@@ -7070,22 +7116,21 @@ for raw in sys.stdin:
             final_text: Some("partial".to_string()),
             language: None,
             confidence: None,
+            segments: Vec::new(),
+            words: Vec::new(),
+            auto_punctuation: true,
             force_clipboard_only: false,
             force_clipboard_reason: None,
             timing_marks: PipelineTimingMarks::default(),
+            transcription_retries: 0,
+            retry_requested_at: None,
         });
         let _ = state_manager.transition(AppState::Transcribing);
 
-        cleanup_active_session_for_no_audio_devices(
-            &manager,
-            &state_manager,
-            &manager.recording_context,
-            &manager.current_session_id,
-        )
-        .await;
+        manager.session.end_for_no_audio_devices().await;
 
-        assert!(manager.current_session_id.read().await.is_none());
-        assert!(manager.recording_context.read().await.is_none());
+        assert!(manager.session.current_session_id.read().await.is_none());
+        assert!(manager.session.recording_context.read().await.is_none());
         assert_eq!(state_manager.get(), AppState::Idle);
     }
 
@@ -7115,16 +7160,21 @@ for raw in sys.stdin:
 
         let rpc_client = RpcClient::new(stdin, stdout);
         manager.start_notification_loop(rpc_client.subscribe());
-        manager.start_recording_event_loop();
+        manager.session.start_recording_event_loop();
         *manager.rpc_client.write().await = Some(rpc_client);
-        manager.recording_controller.set_model_ready(true).await;
-        let mut recording_config = manager.recording_controller.get_config().await;
+        manager
+            .session
+            .recording_controller
+            .set_model_ready(true)
+            .await;
+        let mut recording_config = manager.session.recording_controller.get_config().await;
         recording_config.too_short_threshold = Duration::from_millis(0);
         manager
+            .session
             .recording_controller
             .set_config(recording_config)
             .await;
-        let mut recording_events = manager.recording_controller.subscribe();
+        let mut recording_events = manager.session.recording_controller.subscribe();
         let mut state_events = state_manager.subscribe();
 
         println!(
@@ -7136,6 +7186,7 @@ for raw in sys.stdin:
             .await
             .expect("start_recording should succeed");
         let first_session_id = manager
+            .session
             .current_session_id
             .read()
             .await
@@ -7156,13 +7207,14 @@ for raw in sys.stdin:
         })
         .await;
 
-        let started = wait_for_recording_event(&mut recording_events, Duration::from_secs(2), |event| {
-            matches!(
-                event,
-                RecordingEvent::Started { session_id, .. } if session_id == &first_session_id
-            )
-        })
-        .await;
+        let started =
+            wait_for_recording_event(&mut recording_events, Duration::from_secs(2), |event| {
+                matches!(
+                    event,
+                    RecordingEvent::Started { session_id, .. } if session_id == &first_session_id
+                )
+            })
+            .await;
         assert!(matches!(started, RecordingEvent::Started { .. }));
 
         wait_until(Duration::from_secs(2), || {
@@ -7218,13 +7270,14 @@ for raw in sys.stdin:
             .stop_recording()
             .await
             .expect("stop_recording should succeed");
-        let stopped = wait_for_recording_event(&mut recording_events, Duration::from_secs(2), |event| {
-            matches!(
-                event,
-                RecordingEvent::Stopped { session_id, .. } if session_id == &first_session_id
-            )
-        })
-        .await;
+        let stopped =
+            wait_for_recording_event(&mut recording_events, Duration::from_secs(2), |event| {
+                matches!(
+                    event,
+                    RecordingEvent::Stopped { session_id, .. } if session_id == &first_session_id
+                )
+            })
+            .await;
         assert!(matches!(stopped, RecordingEvent::Stopped { .. }));
         wait_for_state_event(&mut state_events, Duration::from_secs(2), |event| {
             event.state == AppState::Transcribing
@@ -7278,19 +7331,20 @@ for raw in sys.stdin:
         );
         wait_until(Duration::from_secs(2), || {
             manager
+                .session
                 .current_session_id
                 .try_read()
                 .map(|guard| guard.is_none())
                 .unwrap_or(false)
         })
         .await;
-        if manager.current_session_id.read().await.is_some() {
+        if manager.session.current_session_id.read().await.is_some() {
             println!(
                 "[RECORDING_FLOW][STATE] {} forcing session cleanup for next branch",
                 chrono::Utc::now().to_rfc3339()
             );
-            *manager.current_session_id.write().await = None;
-            *manager.recording_context.write().await = None;
+            *manager.session.current_session_id.write().await = None;
+            *manager.session.recording_context.write().await = None;
         }
         println!(
             "[RECORDING_FLOW][STATE] {} start second recording for cancel branch",
@@ -7301,6 +7355,7 @@ for raw in sys.stdin:
             .await
             .expect("second start_recording should succeed");
         let cancel_session_id = manager
+            .session
             .current_session_id
             .read()
             .await
@@ -7329,7 +7384,7 @@ for raw in sys.stdin:
         })
         .await;
         assert_eq!(state_manager.get(), AppState::Idle);
-        assert!(manager.current_session_id.read().await.is_none());
+        assert!(manager.session.current_session_id.read().await.is_none());
         wait_for_state_event(&mut state_events, Duration::from_secs(2), |event| {
             event.state == AppState::Idle
         })
@@ -7344,7 +7399,7 @@ for raw in sys.stdin:
             })
             .await;
         assert!(matches!(cancelled, RecordingEvent::Cancelled { .. }));
-        assert!(manager.recording_context.read().await.is_none());
+        assert!(manager.session.recording_context.read().await.is_none());
 
         let calls_after_cancel = read_mock_call_log(&call_log_path);
         let cancel_call = calls_after_cancel
@@ -7394,8 +7449,8 @@ for raw in sys.stdin:
 
         let no_transcription_deadline = tokio::time::Instant::now() + Duration::from_millis(300);
         while tokio::time::Instant::now() < no_transcription_deadline {
-            let remaining = no_transcription_deadline
-                .saturating_duration_since(tokio::time::Instant::now());
+            let remaining =
+                no_transcription_deadline.saturating_duration_since(tokio::time::Instant::now());
             match tokio::time::timeout(remaining, recording_events.recv()).await {
                 Ok(Ok(RecordingEvent::TranscriptionComplete { session_id, .. }))
                     if session_id == cancel_session_id =>
@@ -7434,7 +7489,7 @@ for raw in sys.stdin:
             .list_audio_devices()
             .await
             .expect_err("list_audio_devices should fail without sidecar");
-        assert!(error.contains("Sidecar not connected"));
+        assert!(matches!(error, IntegrationError::SidecarNotConnected));
     }
 
     #[tokio::test]
@@ -7446,7 +7501,7 @@ for raw in sys.stdin:
             .set_audio_device(Some("device-1".to_string()))
             .await
             .expect_err("set_audio_device should fail without sidecar");
-        assert!(error.contains("Sidecar not connected"));
+        assert!(matches!(error, IntegrationError::SidecarNotConnected));
     }
 
     #[tokio::test]
@@ -7458,7 +7513,7 @@ for raw in sys.stdin:
             .stop_mic_test()
             .await
             .expect_err("stop_mic_test should fail without sidecar");
-        assert!(error.contains("Sidecar not connected"));
+        assert!(matches!(error, IntegrationError::SidecarNotConnected));
     }
 
     #[tokio::test]
@@ -7470,7 +7525,7 @@ for raw in sys.stdin:
             .query_model_status(None)
             .await
             .expect_err("query_model_status should fail without sidecar");
-        assert!(error.contains("E_SIDECAR_IPC"));
+        assert!(matches!(error, IntegrationError::SidecarNotConnected));
     }
 
     #[tokio::test]
@@ -7482,13 +7537,15 @@ for raw in sys.stdin:
             .download_model(None, None)
             .await
             .expect_err("download_model should fail without sidecar");
-        assert!(error.contains("E_SIDECAR_IPC"));
+        assert!(matches!(error, IntegrationError::SidecarNotConnected));
     }
 
     #[tokio::test]
     async fn test_download_model_falls_back_to_legacy_methods_when_model_install_is_unsupported() {
         let temp_dir = tempfile::TempDir::new().expect("temp dir should be created");
-        let call_log_path = temp_dir.path().join("mock_model_install_fallback_calls.jsonl");
+        let call_log_path = temp_dir
+            .path()
+            .join("mock_model_install_fallback_calls.jsonl");
         fs::write(&call_log_path, "").expect("call log file should be initialized");
 
         let mut mock_sidecar = spawn_mock_sidecar_model_install_fallback_process(&call_log_path);
@@ -7513,7 +7570,10 @@ for raw in sys.stdin:
             .await
             .expect("download_model should succeed via legacy fallback path");
 
-        wait_until(Duration::from_secs(2), || read_mock_call_log(&call_log_path).len() >= 3).await;
+        wait_until(Duration::from_secs(2), || {
+            read_mock_call_log(&call_log_path).len() >= 3
+        })
+        .await;
 
         let calls = read_mock_call_log(&call_log_path);
         let methods: Vec<String> = calls
@@ -7542,7 +7602,7 @@ for raw in sys.stdin:
         );
 
         assert_eq!(manager.get_model_status().await, ModelStatus::Ready);
-        assert!(manager.recording_controller.is_model_ready().await);
+        assert!(manager.session.recording_controller.is_model_ready().await);
         assert_eq!(state_manager.get(), AppState::Idle);
 
         if let Some(client) = manager.rpc_client.write().await.take() {
@@ -7578,12 +7638,16 @@ for raw in sys.stdin:
             "openai/whisper-small",
             "cpu",
             Some("en".to_string()),
+            None,
         )
         .await
         .expect("fallback initialize should succeed");
         assert_eq!(result.status, "ready");
 
-        wait_until(Duration::from_secs(2), || read_mock_call_log(&call_log_path).len() >= 2).await;
+        wait_until(Duration::from_secs(2), || {
+            read_mock_call_log(&call_log_path).len() >= 2
+        })
+        .await;
 
         let asr_calls: Vec<Value> = read_mock_call_log(&call_log_path)
             .into_iter()
@@ -7637,18 +7701,26 @@ for raw in sys.stdin:
             "openai/whisper-small",
             "cpu",
             Some("de".to_string()),
+            None,
         )
         .await
         .expect("fallback initialize should succeed on method-not-found");
         assert_eq!(result.status, "ready");
 
-        wait_until(Duration::from_secs(2), || read_mock_call_log(&call_log_path).len() >= 2).await;
+        wait_until(Duration::from_secs(2), || {
+            read_mock_call_log(&call_log_path).len() >= 2
+        })
+        .await;
 
         let asr_calls: Vec<Value> = read_mock_call_log(&call_log_path)
             .into_iter()
             .filter(|call| call.get("method").and_then(Value::as_str) == Some("asr.initialize"))
             .collect();
-        assert_eq!(asr_calls.len(), 2, "host should retry after method-not-found");
+        assert_eq!(
+            asr_calls.len(),
+            2,
+            "host should retry after method-not-found"
+        );
         assert_eq!(
             asr_calls[0]
                 .get("params")
@@ -7672,7 +7744,9 @@ for raw in sys.stdin:
     #[tokio::test]
     async fn test_asr_initialize_language_fallback_surfaces_whisper_unavailable_message() {
         let temp_dir = tempfile::TempDir::new().expect("temp dir should be created");
-        let call_log_path = temp_dir.path().join("mock_asr_whisper_unavailable_calls.jsonl");
+        let call_log_path = temp_dir
+            .path()
+            .join("mock_asr_whisper_unavailable_calls.jsonl");
         fs::write(&call_log_path, "").expect("call log file should be initialized");
 
         let mut mock_sidecar = spawn_mock_sidecar_asr_initialize_error_process(
@@ -7695,6 +7769,7 @@ for raw in sys.stdin:
             "openai/whisper-small",
             "cpu",
             Some("en".to_string()),
+            None,
         )
         .await
         .expect_err("initialize should fail when whisper backend is unavailable");
@@ -7717,22 +7792,20 @@ for raw in sys.stdin:
             .purge_model_cache(None)
             .await
             .expect_err("purge_model_cache should fail without sidecar");
-        assert!(error.contains("Sidecar not connected"));
+        assert!(matches!(error, IntegrationError::SidecarNotConnected));
     }
 
     #[tokio::test]
     async fn test_purge_model_cache_rejected_while_loading_or_downloading() {
         let state_manager = Arc::new(AppStateManager::new());
         let manager = IntegrationManager::new(state_manager);
-        *manager.model_status.write().await = ModelStatus::Downloading;
+        *manager.model.status.write().await = ModelStatus::Downloading;
 
         let error = manager
             .purge_model_cache(None)
             .await
             .expect_err("purge_model_cache should reject while downloading");
-        assert!(
-            error.contains("Cannot purge model while download or initialization is in progress")
-        );
+        assert!(matches!(error, IntegrationError::ModelBusy));
     }
 
     #[tokio::test]
@@ -7749,7 +7822,7 @@ for raw in sys.stdin:
                     blank
                 ));
             assert!(
-                error.contains("Invalid model_id"),
+                error.to_string().contains("Invalid model_id"),
                 "expected validation error for blank model_id {:?}, got: {}",
                 blank,
                 error,
@@ -7766,7 +7839,7 @@ for raw in sys.stdin:
             .list_replacement_presets()
             .await
             .expect_err("list_replacement_presets should fail without sidecar");
-        assert!(error.contains("Sidecar not connected"));
+        assert!(matches!(error, IntegrationError::SidecarNotConnected));
     }
 
     #[tokio::test]
@@ -7778,7 +7851,7 @@ for raw in sys.stdin:
             .get_preset_replacement_rules("punctuation".to_string())
             .await
             .expect_err("get_preset_replacement_rules should fail without sidecar");
-        assert!(error.contains("Sidecar not connected"));
+        assert!(matches!(error, IntegrationError::SidecarNotConnected));
     }
 
     #[tokio::test]
@@ -7790,7 +7863,7 @@ for raw in sys.stdin:
             .get_active_replacement_rules()
             .await
             .expect_err("get_active_replacement_rules should fail without sidecar");
-        assert!(error.contains("Sidecar not connected"));
+        assert!(matches!(error, IntegrationError::SidecarNotConnected));
     }
 
     #[tokio::test]
@@ -7802,7 +7875,7 @@ for raw in sys.stdin:
             .set_active_replacement_rules(Vec::new())
             .await
             .expect_err("set_active_replacement_rules should fail without sidecar");
-        assert!(error.contains("Sidecar not connected"));
+        assert!(matches!(error, IntegrationError::SidecarNotConnected));
     }
 
     #[tokio::test]
@@ -7814,6 +7887,6 @@ for raw in sys.stdin:
             .preview_replacement("hello".to_string(), Vec::new())
             .await
             .expect_err("preview_replacement should fail without sidecar");
-        assert!(error.contains("Sidecar not connected"));
+        assert!(matches!(error, IntegrationError::SidecarNotConnected));
     }
 }
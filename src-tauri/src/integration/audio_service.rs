@@ -0,0 +1,567 @@
+//! Audio input devices: listing and selection, the mic test meter, and
+//! recovery when the selected device disappears.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::sync::RwLock;
+
+use crate::call_detect::{self, CueStatus};
+use crate::config;
+use crate::errors::{AppError, ErrorKind, IntegrationError};
+use crate::ipc::{RpcClient, RpcError};
+use crate::runtime::AppHandle;
+#[cfg(feature = "desktop")]
+use crate::runtime::Manager;
+use crate::state::{AppStateManager, ErrorCategory};
+
+use super::session_service::SessionService;
+use super::{
+    app_error_event_payload, cues_status_payload, decide_device_hot_swap, device_removed_app_error,
+    emit_with_shared_seq, is_stale_session, stale_notification_message, InputGain,
+    SidecarAudioDevice, CUE_STATUS_POLL_INTERVAL, DEVICE_HOT_SWAP_DEBOUNCE,
+    DEVICE_HOT_SWAP_POLL_INTERVAL, DEVICE_REMOVED_CLIPBOARD_REASON, EVENT_APP_ERROR,
+    EVENT_CUES_STATUS, EVENT_TRAY_UPDATE,
+};
+
+/// Talks to the sidecar's audio methods and forwards its level meter events.
+#[derive(Clone)]
+pub(super) struct AudioService {
+    /// RPC client of the live sidecar (if connected).
+    rpc_client: Arc<RwLock<Option<RpcClient>>>,
+    /// Application state manager.
+    state_manager: Arc<AppStateManager>,
+    /// Tauri app handle.
+    app_handle: Option<AppHandle>,
+    /// Monotonic event sequence counter shared with the other services.
+    event_seq: Arc<AtomicU64>,
+    /// Session stopped or cancelled when its input device goes away.
+    session: SessionService,
+}
+
+impl AudioService {
+    pub(super) fn new(
+        rpc_client: Arc<RwLock<Option<RpcClient>>>,
+        state_manager: Arc<AppStateManager>,
+        event_seq: Arc<AtomicU64>,
+        session: SessionService,
+    ) -> Self {
+        Self {
+            rpc_client,
+            state_manager,
+            app_handle: None,
+            event_seq,
+            session,
+        }
+    }
+
+    pub(super) fn set_app_handle(&mut self, handle: AppHandle) {
+        self.session.set_app_handle(handle.clone());
+        self.app_handle = Some(handle);
+    }
+
+    /// Start microphone level meter via sidecar.
+    pub(super) async fn start_mic_test(
+        &self,
+        device_uid: Option<String>,
+    ) -> Result<(), IntegrationError> {
+        let client = self.rpc_client.read().await;
+        let client = client
+            .as_ref()
+            .ok_or(IntegrationError::SidecarNotConnected)?;
+
+        #[derive(Deserialize)]
+        struct MeterStartResult {
+            #[allow(dead_code)]
+            running: bool,
+            #[allow(dead_code)]
+            interval_ms: u64,
+        }
+
+        // Preview the configured VAD timings so presets can be compared live.
+        let audio_config = config::load_config().audio;
+        let mut params = json!({
+            "device_uid": device_uid,
+            "interval_ms": 80u64,
+            "vad_preview": true,
+            "vad_silence_ms": audio_config.vad_silence_ms,
+            "vad_min_speech_ms": audio_config.vad_min_speech_ms,
+        });
+        if !audio_config.input_gains.is_empty() {
+            params["input_gains"] = json!(audio_config.input_gains);
+        }
+
+        client
+            .call::<MeterStartResult>("audio.meter_start", Some(params))
+            .await
+            .map_err(|e| IntegrationError::rpc("start mic test", e))?;
+        Ok(())
+    }
+
+    /// Stop microphone level meter via sidecar.
+    pub(super) async fn stop_mic_test(&self) -> Result<(), IntegrationError> {
+        let client = self.rpc_client.read().await;
+        let client = client
+            .as_ref()
+            .ok_or(IntegrationError::SidecarNotConnected)?;
+
+        #[derive(Deserialize)]
+        struct MeterStopResult {
+            #[allow(dead_code)]
+            stopped: bool,
+        }
+
+        client
+            .call::<MeterStopResult>("audio.meter_stop", None)
+            .await
+            .map_err(|e| IntegrationError::rpc("stop mic test", e))?;
+        Ok(())
+    }
+
+    /// List available audio input devices via sidecar.
+    pub(super) async fn list_devices(&self) -> Result<Vec<SidecarAudioDevice>, IntegrationError> {
+        let client = self.rpc_client.read().await;
+        let client = client
+            .as_ref()
+            .ok_or(IntegrationError::SidecarNotConnected)?;
+
+        #[derive(Deserialize)]
+        struct ListDevicesResult {
+            #[serde(default)]
+            devices: Vec<SidecarAudioDevice>,
+        }
+
+        let result = client
+            .call::<ListDevicesResult>("audio.list_devices", None)
+            .await
+            .map_err(|e| IntegrationError::rpc("list audio devices", e))?;
+
+        Ok(result.devices)
+    }
+
+    /// Set active audio input device via sidecar.
+    pub(super) async fn set_device(
+        &self,
+        device_uid: Option<String>,
+    ) -> Result<Option<String>, IntegrationError> {
+        let client = self.rpc_client.read().await;
+        let client = client
+            .as_ref()
+            .ok_or(IntegrationError::SidecarNotConnected)?;
+
+        #[derive(Deserialize)]
+        struct SetDeviceResult {
+            #[serde(default)]
+            active_device_uid: Option<String>,
+        }
+
+        let params = json!({
+            "device_uid": device_uid
+        });
+
+        let result = client
+            .call::<SetDeviceResult>("audio.set_device", Some(params))
+            .await
+            .map_err(|e| IntegrationError::rpc("set audio device", e))?;
+
+        Ok(result.active_device_uid)
+    }
+
+    /// Resolve `device_uid` (None = the active device) via sidecar and report
+    /// its configured input gain. The config is authoritative: the sidecar
+    /// only knows the gains set or sent with a capture since it started.
+    pub(super) async fn get_gain(
+        &self,
+        device_uid: Option<String>,
+    ) -> Result<InputGain, IntegrationError> {
+        let client = self.rpc_client.read().await;
+        let client = client
+            .as_ref()
+            .ok_or(IntegrationError::SidecarNotConnected)?;
+
+        let params = json!({ "device_uid": device_uid });
+        let resolved_uid = match client
+            .call::<InputGain>("audio.get_gain", Some(params))
+            .await
+        {
+            Ok(gain) => gain.device_uid,
+            // Older sidecars cannot resolve the active device.
+            Err(RpcError::Remote { kind, .. }) if kind == "E_METHOD_NOT_FOUND" => device_uid,
+            Err(e) => return Err(IntegrationError::rpc("get input gain", e)),
+        };
+        let gain_db = resolved_uid
+            .as_ref()
+            .and_then(|uid| config::load_config().audio.input_gains.get(uid).copied())
+            .unwrap_or(0.0);
+        Ok(InputGain {
+            device_uid: resolved_uid,
+            gain_db,
+        })
+    }
+
+    /// Set the input gain of a device via sidecar; it applies immediately,
+    /// including to a running mic test.
+    pub(super) async fn set_gain(
+        &self,
+        device_uid: Option<String>,
+        gain_db: f64,
+    ) -> Result<InputGain, IntegrationError> {
+        let client = self.rpc_client.read().await;
+        let client = client
+            .as_ref()
+            .ok_or(IntegrationError::SidecarNotConnected)?;
+
+        let params = json!({
+            "device_uid": device_uid,
+            "gain_db": gain_db,
+        });
+        client
+            .call::<InputGain>("audio.set_gain", Some(params))
+            .await
+            .map_err(|e| IntegrationError::rpc("set input gain", e))
+    }
+
+    fn persist_default_device_selection() -> Result<(), String> {
+        let mut app_config = config::load_config();
+        app_config.audio.device_uid = None;
+        config::save_config(&app_config).map_err(|error| format!("Failed to save config: {error}"))
+    }
+
+    /// Start the loop that publishes `cues:status` whenever audio cues get
+    /// muted or unmuted for a meeting.
+    pub(super) fn start_cue_status_loop(&self) {
+        let Some(handle) = self.app_handle.clone() else {
+            return;
+        };
+        let event_seq = Arc::clone(&self.event_seq);
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(CUE_STATUS_POLL_INTERVAL);
+            let mut last: Option<CueStatus> = None;
+            loop {
+                interval.tick().await;
+                // Call detection runs a process; keep it off the runtime.
+                let status = match tokio::task::spawn_blocking(|| {
+                    call_detect::cue_status(&config::load_config().audio)
+                })
+                .await
+                {
+                    Ok(status) => status,
+                    Err(e) => {
+                        log::warn!("Cue status check failed: {}", e);
+                        continue;
+                    }
+                };
+                if last.as_ref() != Some(&status) {
+                    emit_with_shared_seq(
+                        &handle,
+                        &[EVENT_CUES_STATUS],
+                        cues_status_payload(&status),
+                        &event_seq,
+                    );
+                    last = Some(status);
+                }
+            }
+        });
+    }
+
+    /// Start device hot-swap monitor loop.
+    ///
+    /// Polls audio.list_devices and handles selected-device disappearance with:
+    /// - stop active recording/transcription handling
+    /// - default device fallback
+    /// - structured, recoverable app:error emission when active session is impacted
+    pub(super) fn start_device_hot_swap_loop(&self) {
+        let audio = self.clone();
+        let session = self.session.clone();
+        let state_manager = Arc::clone(&self.state_manager);
+        let app_handle = self.app_handle.clone();
+        let event_seq = Arc::clone(&self.event_seq);
+
+        tokio::spawn(async move {
+            let mut tick = tokio::time::interval(DEVICE_HOT_SWAP_POLL_INTERVAL);
+            tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+            let mut previous_devices: Option<Vec<SidecarAudioDevice>> = None;
+            let mut last_change_handled_at: Option<Instant> = None;
+            let mut last_device_list_error_at: Option<Instant> = None;
+
+            loop {
+                tick.tick().await;
+
+                let devices = match audio.list_devices().await {
+                    Ok(devices) => devices,
+                    Err(error) => {
+                        let now = Instant::now();
+                        let should_log = last_device_list_error_at
+                            .is_none_or(|last| now.duration_since(last) >= Duration::from_secs(5));
+                        if should_log {
+                            log::debug!("Device hot-swap poll skipped: {}", error);
+                            last_device_list_error_at = Some(now);
+                        }
+                        continue;
+                    }
+                };
+
+                // Keep the tray device cache up to date on every successful poll.
+                #[cfg(feature = "desktop")]
+                if let Some(ref handle) = app_handle {
+                    let cache = handle.state::<crate::tray::TrayDeviceCache>();
+                    cache.update(
+                        &devices
+                            .iter()
+                            .map(|d| (d.uid.clone(), d.name.clone()))
+                            .collect::<Vec<_>>(),
+                    );
+                }
+
+                let Some(previous) = previous_devices.as_ref() else {
+                    previous_devices = Some(devices);
+                    continue;
+                };
+
+                if device_uid_snapshot(previous) == device_uid_snapshot(&devices) {
+                    continue;
+                }
+
+                let now = Instant::now();
+                if last_change_handled_at
+                    .is_some_and(|last| now.duration_since(last) < DEVICE_HOT_SWAP_DEBOUNCE)
+                {
+                    previous_devices = Some(devices);
+                    continue;
+                }
+                last_change_handled_at = Some(now);
+
+                if let Some(ref handle) = app_handle {
+                    emit_with_shared_seq(
+                        handle,
+                        &[EVENT_TRAY_UPDATE],
+                        json!({
+                            "reason": "device_list_changed",
+                            "device_count": devices.len(),
+                        }),
+                        &event_seq,
+                    );
+                }
+
+                if devices.is_empty() {
+                    session.end_for_no_audio_devices().await;
+                    state_manager.transition_to_error(
+                        ErrorCategory::AudioDevice,
+                        "No audio input device available. Connect a microphone and try again."
+                            .to_string(),
+                    );
+                    let app_error = no_audio_device_app_error();
+                    emit_app_error_event(&app_handle, &event_seq, &app_error);
+                    previous_devices = Some(devices);
+                    continue;
+                }
+
+                let configured_device_uid = config::load_config().audio.device_uid;
+                let state = state_manager.get();
+                let decision = decide_device_hot_swap(
+                    state,
+                    configured_device_uid.as_deref(),
+                    previous,
+                    &devices,
+                );
+
+                if let Some(removed_uid) = decision.removed_device_uid.clone() {
+                    let removed_name = decision
+                        .removed_device_name
+                        .clone()
+                        .unwrap_or_else(|| removed_uid.clone());
+                    let had_active_recording = decision.should_emit_error
+                        || session.current_session_id.read().await.is_some();
+                    let mut transcript_preserved = false;
+
+                    if decision.should_stop_recording {
+                        if let Err(error) = session.stop().await {
+                            log::warn!(
+                                "Failed to stop recording after device removal (partial audio may be lost): {}",
+                                error
+                            );
+                        }
+                    }
+
+                    if decision.should_force_clipboard_only {
+                        let mut context = session.recording_context.write().await;
+                        if let Some(context) = context.as_mut() {
+                            context.force_clipboard_only = true;
+                            context.force_clipboard_reason =
+                                Some(DEVICE_REMOVED_CLIPBOARD_REASON.to_string());
+                            transcript_preserved = true;
+                        }
+                    }
+
+                    let fallback_device_uid = match audio.set_device(None).await {
+                        Ok(uid) => uid,
+                        Err(error) => {
+                            log::warn!("Failed to switch to default audio device: {}", error);
+                            None
+                        }
+                    };
+
+                    if let Err(error) = Self::persist_default_device_selection() {
+                        log::warn!(
+                            "Failed to persist default audio device after hot-swap: {}",
+                            error
+                        );
+                    }
+
+                    if let Some(ref handle) = app_handle {
+                        emit_with_shared_seq(
+                            handle,
+                            &[EVENT_TRAY_UPDATE],
+                            json!({
+                                "reason": "device_changed",
+                                "removed_device_uid": removed_uid,
+                                "active_device_uid": fallback_device_uid,
+                            }),
+                            &event_seq,
+                        );
+                    }
+
+                    if decision.should_emit_error {
+                        let app_error = device_removed_app_error(
+                            removed_uid,
+                            removed_name,
+                            fallback_device_uid,
+                            had_active_recording,
+                            transcript_preserved,
+                        );
+                        emit_app_error_event(&app_handle, &event_seq, &app_error);
+                    } else {
+                        log::info!(
+                            "Configured audio device removed while idle; switched to default device"
+                        );
+                    }
+                }
+
+                previous_devices = Some(devices);
+            }
+        });
+    }
+
+    /// Handle an `event.audio_level` notification.
+    ///
+    /// Recording levels are session-scoped: they must carry the active
+    /// session's id or they are dropped.
+    pub(super) async fn handle_audio_level(&self, params: Value) {
+        #[derive(Deserialize)]
+        struct AudioLevelParams {
+            source: String,
+            rms: f64,
+            peak: f64,
+            #[serde(default)]
+            session_id: Option<String>,
+        }
+
+        let Ok(params) = serde_json::from_value::<AudioLevelParams>(params) else {
+            log::warn!("Ignoring invalid audio_level payload");
+            return;
+        };
+
+        // recording source is session-scoped and should include session_id.
+        if params.source == "recording" && params.session_id.is_none() {
+            log::warn!(
+                "Ignoring invalid audio_level event: missing session_id for recording source"
+            );
+            return;
+        }
+
+        if params.source == "recording" {
+            let active_session_id = self.session.current_session_id.read().await.clone();
+            let incoming_session_id = params.session_id.as_deref();
+            if is_stale_session(incoming_session_id, active_session_id.as_deref()) {
+                log::warn!(
+                    "{}",
+                    stale_notification_message(incoming_session_id, active_session_id.as_deref())
+                );
+                return;
+            }
+        }
+
+        if let Some(ref handle) = self.app_handle {
+            emit_with_shared_seq(
+                handle,
+                &["audio:level"],
+                json!({
+                    "source": params.source,
+                    "rms": params.rms,
+                    "peak": params.peak,
+                    "session_id": params.session_id,
+                }),
+                &self.event_seq,
+            );
+        }
+    }
+
+    /// Handle an `event.vad_preview` notification from the mic test meter.
+    pub(super) fn handle_vad_preview(&self, params: Value) {
+        #[derive(Deserialize)]
+        struct VadPreviewParams {
+            state: String,
+            speech_ms: f64,
+            silence_ms: f64,
+            silence_threshold_ms: u64,
+        }
+
+        match serde_json::from_value::<VadPreviewParams>(params) {
+            Ok(params) => {
+                if let Some(ref handle) = self.app_handle {
+                    emit_with_shared_seq(
+                        handle,
+                        &["vad:preview"],
+                        json!({
+                            "state": params.state,
+                            "speech_ms": params.speech_ms,
+                            "silence_ms": params.silence_ms,
+                            "silence_threshold_ms": params.silence_threshold_ms,
+                        }),
+                        &self.event_seq,
+                    );
+                }
+            }
+            Err(e) => {
+                log::warn!("Ignoring invalid vad_preview payload: {}", e);
+            }
+        }
+    }
+}
+
+fn emit_app_error_event(
+    app_handle: &Option<AppHandle>,
+    event_seq: &Arc<AtomicU64>,
+    app_error: &AppError,
+) {
+    if let Some(ref handle) = app_handle {
+        emit_with_shared_seq(
+            handle,
+            &[EVENT_APP_ERROR],
+            app_error_event_payload(app_error),
+            event_seq,
+        );
+    }
+}
+
+fn no_audio_device_app_error() -> AppError {
+    AppError::new(
+        ErrorKind::NoAudioDevice.to_sidecar(),
+        "No audio input device is available. Connect a microphone and try again.",
+        Some(json!({
+            "reason": "no_available_input_device"
+        })),
+        true,
+    )
+}
+
+fn device_uid_snapshot(devices: &[SidecarAudioDevice]) -> Vec<String> {
+    let mut snapshot: Vec<String> = devices.iter().map(|device| device.uid.clone()).collect();
+    snapshot.sort();
+    snapshot
+}
@@ -0,0 +1,279 @@
+//! Global hotkeys: registration, and the loop that turns hotkey presses into
+//! recording starts and stops, transcript copies and cancels.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use global_hotkey::GlobalHotKeyEvent;
+use serde_json::json;
+use tokio::sync::RwLock;
+
+use crate::audio_cue::CueType;
+use crate::config::{self, HotkeyMode};
+use crate::history::TranscriptHistory;
+use crate::hotkey::{HotkeyAction, HotkeyManager, RecordingAction};
+use crate::hotkey_leak;
+use crate::hotkey_practice;
+use crate::recording::CancelReason;
+use crate::runtime::{AppHandle, Manager};
+use crate::session;
+use crate::state::{AppState, AppStateManager};
+use crate::typing_guard;
+
+use super::session_service::SessionService;
+use super::{emit_with_shared_seq, play_lifecycle_audio_cue, EVENT_TRAY_UPDATE};
+
+/// Registers the global hotkeys and acts on their presses.
+#[derive(Clone)]
+pub(super) struct HotkeyService {
+    /// Registered hotkeys and the channel their actions arrive on.
+    hotkey_manager: Arc<RwLock<HotkeyManager>>,
+    /// Application state manager.
+    state_manager: Arc<AppStateManager>,
+    /// Tauri app handle.
+    app_handle: Option<AppHandle>,
+    /// Monotonic event sequence counter shared with the other services.
+    event_seq: Arc<AtomicU64>,
+    /// Session started, stopped and cancelled by the hotkeys.
+    session: SessionService,
+}
+
+impl HotkeyService {
+    pub(super) fn new(
+        state_manager: Arc<AppStateManager>,
+        event_seq: Arc<AtomicU64>,
+        session: SessionService,
+    ) -> Self {
+        Self {
+            hotkey_manager: Arc::new(RwLock::new(HotkeyManager::new())),
+            state_manager,
+            app_handle: None,
+            event_seq,
+            session,
+        }
+    }
+
+    pub(super) fn set_app_handle(&mut self, handle: AppHandle) {
+        self.session.set_app_handle(handle.clone());
+        self.app_handle = Some(handle);
+    }
+
+    /// Register the configured hotkeys at startup.
+    pub(super) async fn register(&self) {
+        let mut hotkey_manager = self.hotkey_manager.write().await;
+        match hotkey_manager.initialize() {
+            Ok(status) => {
+                log::info!(
+                    "Hotkey registered: primary={}, mode={}",
+                    status.primary,
+                    status.mode
+                );
+            }
+            Err(e) => {
+                log::warn!("Failed to register hotkeys: {}", e);
+                // Continue anyway - user can fix in settings
+            }
+        }
+    }
+
+    /// Re-register hotkeys after the bindings in effect changed, such as on a
+    /// profile switch.
+    pub(super) async fn reload(&self) {
+        let mut hotkey_manager = self.hotkey_manager.write().await;
+        match hotkey_manager.initialize() {
+            Ok(status) => {
+                log::info!(
+                    "Hotkeys re-registered: primary={}, mode={}, profile={:?}",
+                    status.primary,
+                    status.mode,
+                    status.profile
+                );
+                if let Some(error) = status.error {
+                    log::warn!("Hotkey registration incomplete: {}", error);
+                }
+            }
+            Err(e) => log::warn!("Failed to re-register hotkeys: {}", e),
+        }
+    }
+
+    /// Start hotkey event processing loop.
+    pub(super) fn start_loop(&self) {
+        let hotkey_manager = Arc::clone(&self.hotkey_manager);
+        let state_manager = Arc::clone(&self.state_manager);
+        let session = self.session.clone();
+        let app_handle = self.app_handle.clone();
+        let event_seq = Arc::clone(&self.event_seq);
+
+        if config::load_config().hotkeys.typing_suppression_ms > 0 {
+            typing_guard::start_monitor();
+        }
+
+        tokio::spawn(async move {
+            // Take the receiver from hotkey manager
+            let mut receiver = {
+                let mut hk = hotkey_manager.write().await;
+                match hk.take_action_receiver() {
+                    Some(rx) => rx,
+                    None => {
+                        log::warn!("Hotkey receiver already taken");
+                        return;
+                    }
+                }
+            };
+
+            log::info!("Hotkey event loop started");
+
+            loop {
+                // Drain global hotkey events and forward them into the hotkey action channel.
+                {
+                    let hk = hotkey_manager.read().await;
+                    while let Ok(event) = GlobalHotKeyEvent::receiver().try_recv() {
+                        hk.process_event(event);
+                    }
+                }
+
+                // Hold the cancel hotkey only while recording.
+                let recording = state_manager.get() == AppState::Recording;
+                if hotkey_manager.read().await.cancel_armed() != recording {
+                    hotkey_manager.write().await.set_cancel_armed(recording);
+                }
+
+                session.enforce_runtime_limits().await;
+
+                let action =
+                    match tokio::time::timeout(Duration::from_millis(25), receiver.recv()).await {
+                        Ok(Some(action)) => action,
+                        Ok(None) => break,
+                        Err(_) => continue,
+                    };
+
+                let config = config::load_config();
+
+                match action {
+                    HotkeyAction::PrimaryDown | HotkeyAction::DictateRawDown => {
+                        if hotkey_leak::observe_hotkey_press() {
+                            // Test press from the hotkey settings, not a dictation.
+                            log::info!("Hotkey press captured by leak test");
+                            continue;
+                        }
+                        if hotkey_practice::observe_hotkey_press() {
+                            // Onboarding practice press, not a dictation.
+                            continue;
+                        }
+                        if session.cancel_countdown().await {
+                            // Second press during the start countdown.
+                            continue;
+                        }
+                        let now = Instant::now();
+                        let rearm = !state_manager.is_enabled()
+                            && session.take_idle_auto_disabled(now).await;
+                        if rearm {
+                            // Idle auto-disable: this press only re-arms, confirmed by a cue.
+                            log::info!("Hotkey pressed after idle auto-disable; re-enabling");
+                            state_manager.set_enabled(true);
+                            play_lifecycle_audio_cue(&state_manager, CueType::Reenabled);
+                            if let Some(ref handle) = app_handle {
+                                emit_with_shared_seq(
+                                    handle,
+                                    &[EVENT_TRAY_UPDATE],
+                                    json!({ "reason": "idle_auto_reenabled" }),
+                                    &event_seq,
+                                );
+                            }
+                            continue;
+                        }
+                        if state_manager.get() != AppState::Recording
+                            && typing_guard::should_suppress(config.hotkeys.typing_suppression_ms)
+                        {
+                            // Key rollover while typing, not an intentional dictation.
+                            log::info!(
+                                "Ignoring hotkey press within {}ms of typing",
+                                config.hotkeys.typing_suppression_ms
+                            );
+                            continue;
+                        }
+                        if state_manager.get() != AppState::Recording
+                            && session::current().suppresses_hotkeys()
+                        {
+                            // Remote or background session: the press belongs to
+                            // whichever session the user is actually looking at.
+                            log::info!("Ignoring hotkey press in a non-console session");
+                            continue;
+                        }
+
+                        // Handle based on mode
+                        let dictate_raw = matches!(action, HotkeyAction::DictateRawDown);
+                        let hk = hotkey_manager.read().await;
+                        let recording_action = hk.handle_primary_down(&state_manager);
+
+                        if let Some(RecordingAction::Start) = recording_action {
+                            let countdown_secs = config.audio.countdown_secs;
+                            let started = if countdown_secs > 0 {
+                                session
+                                    .start_after_countdown(dictate_raw, countdown_secs)
+                                    .await
+                            } else {
+                                session.start(dictate_raw).await
+                            };
+                            if let Err(err) = started {
+                                log::warn!("Failed to start recording: {}", err);
+                                *session.current_session_id.write().await = None;
+                            }
+                        } else if let Some(RecordingAction::Stop) = recording_action {
+                            // Toggle mode: stop recording
+                            if let Err(err) = session.stop().await {
+                                log::warn!("Failed to stop recording: {}", err);
+                            }
+                        }
+                    }
+                    HotkeyAction::PrimaryUp => {
+                        if hotkey_practice::observe_hotkey_release() {
+                            continue;
+                        }
+                        // Only relevant for hold mode
+                        if config.effective_hotkeys().mode == HotkeyMode::Hold {
+                            if session.cancel_countdown().await {
+                                // Released before the countdown ran out.
+                                continue;
+                            }
+                            let hk = hotkey_manager.read().await;
+                            if let Some(RecordingAction::Stop) =
+                                hk.handle_primary_up(&state_manager)
+                            {
+                                if let Err(err) = session.stop().await {
+                                    log::warn!("Failed to stop recording: {}", err);
+                                }
+                            }
+                        }
+                    }
+                    HotkeyAction::CopyLast => {
+                        // Copy last transcript
+                        if let Some(ref handle) = app_handle {
+                            let history = handle.state::<TranscriptHistory>();
+                            let hk = hotkey_manager.read().await;
+                            let result = hk.handle_copy_last(&history);
+                            log::debug!("Copy last result: {:?}", result);
+                        }
+                    }
+                    HotkeyAction::Cancel => {
+                        // Portal-bound cancel chords fire outside recordings too.
+                        if state_manager.get() != AppState::Recording {
+                            continue;
+                        }
+                        if let Err(err) = session.cancel(CancelReason::EscapeKey).await {
+                            log::warn!("Failed to cancel recording: {}", err);
+                        }
+                    }
+                }
+            }
+
+            log::info!("Hotkey event loop ended");
+        });
+    }
+
+    /// Unregister the hotkeys.
+    pub(super) async fn shutdown(&self) {
+        self.hotkey_manager.write().await.shutdown();
+    }
+}
@@ -0,0 +1,1099 @@
+//! ASR model lifecycle: startup check, preload, download, purge, unloading
+//! while paused or idle on battery, the embedded fallback engine and the
+//! sidecar's model status notifications.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::sync::{broadcast, RwLock};
+
+use crate::config::{self, AsrBackend};
+use crate::embedded_asr;
+use crate::errors::{ErrorKind, IntegrationError};
+use crate::ipc::{RpcClient, RpcError};
+use crate::model_defaults;
+use crate::model_license::{self, PendingLicense};
+use crate::network::{self, NetworkPurpose};
+use crate::power::{self, PowerProfile};
+use crate::recording::RecordingController;
+use crate::runtime::{AppHandle, Manager};
+use crate::state::{AppState, AppStateManager, EnableLevel, ErrorCategory};
+
+use super::session_service::SessionService;
+use super::{
+    call_asr_initialize_with_language_fallback, configured_model_beam_size, configured_model_id,
+    configured_model_language_hint, emit_missing_model_status_for_purged_models_with_broadcaster,
+    emit_with_existing_seq_to_all_windows, emit_with_shared_seq, map_model_download_rpc_error,
+    map_status_event_model_state, model_download_method_unsupported_error, model_download_params,
+    model_preload_delay, model_progress_from_parts, model_status_event_payload, next_seq,
+    normalized_purged_model_ids, purge_affects_configured_model, purge_status_model_ids,
+    resolve_model_id, sidecar_status_payload_from_status_event,
+    startup_model_status_requires_loading_state, status_progress_from_parts, with_loading_stage,
+    ModelProgress, ModelStatus, ModelStatusPayload, ModelStatusProgress, SidecarModelProgress,
+    SidecarModelStatus, EVENT_MODEL_PROGRESS, EVENT_MODEL_STATUS, EVENT_SIDECAR_STATUS,
+    IDLE_AUTO_DISABLE_CHECK_INTERVAL,
+};
+
+/// Tracks the ASR model and drives its initialization through the sidecar
+/// or the embedded engine.
+#[derive(Clone)]
+pub(super) struct ModelService {
+    /// Application state manager.
+    state_manager: Arc<AppStateManager>,
+    /// Recording controller, told whether the model is ready.
+    recording_controller: Arc<RecordingController>,
+    /// RPC client of the live sidecar (if connected).
+    rpc_client: Arc<RwLock<Option<RpcClient>>>,
+    /// Tauri app handle.
+    app_handle: Option<AppHandle>,
+    /// Monotonic event sequence counter shared with the other services.
+    event_seq: Arc<AtomicU64>,
+    /// Current model status.
+    pub(super) status: Arc<RwLock<ModelStatus>>,
+    /// Current model download progress.
+    progress: Arc<RwLock<Option<ModelProgress>>>,
+    /// Whether model initialization has been attempted.
+    init_attempted: Arc<AtomicBool>,
+}
+
+impl ModelService {
+    pub(super) fn new(
+        state_manager: Arc<AppStateManager>,
+        recording_controller: Arc<RecordingController>,
+        rpc_client: Arc<RwLock<Option<RpcClient>>>,
+        event_seq: Arc<AtomicU64>,
+    ) -> Self {
+        Self {
+            state_manager,
+            recording_controller,
+            rpc_client,
+            app_handle: None,
+            event_seq,
+            status: Arc::new(RwLock::new(ModelStatus::Unknown)),
+            progress: Arc::new(RwLock::new(None)),
+            init_attempted: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub(super) fn set_app_handle(&mut self, handle: AppHandle) {
+        self.app_handle = Some(handle);
+    }
+
+    /// Spawn model check and initialization in background.
+    pub(super) fn spawn_check(&self) {
+        let model = self.clone();
+
+        tokio::spawn(async move {
+            // Check if already attempted
+            if model.init_attempted.swap(true, Ordering::SeqCst) {
+                log::debug!("Model initialization already attempted");
+                return;
+            }
+
+            if config::load_config().asr_backend() == AsrBackend::Embedded
+                && model.activate_embedded_asr().await
+            {
+                return;
+            }
+
+            // Query model status from sidecar
+            let client = model.rpc_client.read().await;
+            let client = match client.as_ref() {
+                Some(c) => c,
+                None => {
+                    log::warn!("Cannot check model status: sidecar not connected");
+                    return;
+                }
+            };
+
+            log::info!("Checking model status on startup");
+
+            #[derive(Deserialize, Debug)]
+            struct StatusResult {
+                status: String,
+                #[serde(default)]
+                model_id: Option<String>,
+                #[serde(default)]
+                revision: Option<String>,
+                #[serde(default)]
+                cache_path: Option<String>,
+                #[serde(default)]
+                progress: Option<ProgressResult>,
+            }
+
+            #[derive(Deserialize, Debug)]
+            struct ProgressResult {
+                current: u64,
+                #[serde(default)]
+                total: Option<u64>,
+                #[serde(default)]
+                unit: Option<String>,
+                #[serde(default)]
+                stage: Option<String>,
+            }
+
+            match client.call::<StatusResult>("model.get_status", None).await {
+                Ok(result) => {
+                    log::info!("Model status: {:?}", result);
+                    let status_progress = result.progress.as_ref().map(|progress| {
+                        status_progress_from_parts(
+                            progress.current,
+                            progress.total,
+                            progress.unit.clone(),
+                            progress.stage.clone(),
+                        )
+                    });
+
+                    match result.status.as_str() {
+                        "ready" => {
+                            *model.status.write().await = ModelStatus::Ready;
+                            model.recording_controller.set_model_ready(true).await;
+                            let _ = model.state_manager.transition(AppState::Idle);
+                            model.emit_status_with_details(
+                                ModelStatus::Ready,
+                                result.model_id.clone(),
+                                result.revision.clone(),
+                                result.cache_path.clone(),
+                                status_progress.clone(),
+                            );
+                            log::info!("Model ready for transcription");
+
+                            if let Some(delay) = model_preload_delay(&config::load_config()) {
+                                model.spawn_preload(delay);
+                            }
+                        }
+                        "missing" | "not_found" | "error" => {
+                            log::info!(
+                                "Model not ready ({}), triggering initialization",
+                                result.status
+                            );
+                            *model.status.write().await = ModelStatus::Missing;
+                            model.emit_status_with_details(
+                                ModelStatus::Missing,
+                                result.model_id.clone(),
+                                result.revision.clone(),
+                                result.cache_path.clone(),
+                                status_progress.clone(),
+                            );
+
+                            // Trigger model initialization
+                            model.trigger_init(client).await;
+                        }
+                        "downloading" | "loading" | "verifying" => {
+                            // Already in progress (maybe from another session)
+                            debug_assert!(startup_model_status_requires_loading_state(
+                                result.status.as_str()
+                            ));
+                            let _ = model.state_manager.transition(AppState::LoadingModel);
+                            model.recording_controller.set_model_ready(false).await;
+                            let status = if result.status == "downloading" {
+                                ModelStatus::Downloading
+                            } else {
+                                ModelStatus::Loading
+                            };
+                            *model.status.write().await = status.clone();
+                            model.emit_status_with_details(
+                                status,
+                                result.model_id.clone(),
+                                result.revision.clone(),
+                                result.cache_path.clone(),
+                                status_progress.clone(),
+                            );
+                            log::info!(
+                                "Model {} in progress; app state set to loading_model",
+                                result.status
+                            );
+                        }
+                        _ => {
+                            log::warn!("Unknown model status: {}", result.status);
+                            *model.status.write().await = ModelStatus::Unknown;
+                            model.emit_status_with_details(
+                                ModelStatus::Unknown,
+                                result.model_id.clone(),
+                                result.revision.clone(),
+                                result.cache_path.clone(),
+                                status_progress.clone(),
+                            );
+                        }
+                    }
+                }
+                Err(e) => {
+                    log::warn!("Failed to get model status: {}", e);
+                    // Don't block on this - user can trigger manually
+                }
+            }
+        });
+    }
+
+    /// Load the cached model into memory after `delay`, so the first
+    /// dictation does not pay for it. Skipped if the app is busy by then.
+    fn spawn_preload(&self, delay: Duration) {
+        let model = self.clone();
+
+        tokio::spawn(async move {
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+
+            let client = model.rpc_client.read().await;
+            let Some(client) = client.as_ref() else {
+                log::debug!("Model preload skipped: sidecar not connected");
+                return;
+            };
+            let app_state = model.state_manager.get();
+            if app_state != AppState::Idle {
+                log::info!("Model preload skipped: app is {:?}", app_state);
+                return;
+            }
+            if power::current_profile() == PowerProfile::LowPower {
+                log::info!("Model preload skipped: low-power profile on battery");
+                return;
+            }
+
+            model.load_cached(client).await;
+        });
+    }
+
+    /// Load the configured model, already on disk, into the sidecar.
+    async fn load_cached(&self, client: &RpcClient) {
+        let config = config::load_config();
+        let model_id = config
+            .model
+            .as_ref()
+            .and_then(|m| m.model_id.clone())
+            .unwrap_or_else(|| model_defaults::default_model_id().to_string());
+        let device_pref = config.effective_model_device_pref();
+        log::info!(
+            "Loading cached ASR model: model={}, device={}",
+            model_id,
+            device_pref
+        );
+
+        let _ = self.state_manager.transition(AppState::LoadingModel);
+        *self.status.write().await = ModelStatus::Loading;
+        self.emit_status(ModelStatus::Loading);
+
+        match call_asr_initialize_with_language_fallback(
+            client,
+            &model_id,
+            &device_pref,
+            configured_model_language_hint(&config),
+            configured_model_beam_size(&config),
+        )
+        .await
+        {
+            Ok(result) => log::info!("Cached model loaded: status={}", result.status),
+            // The files are cached, so the model can still load on first use.
+            Err(error) => log::warn!("Cached model load failed: {}", error),
+        }
+
+        Self::release_embedded_asr();
+        *self.status.write().await = ModelStatus::Ready;
+        self.recording_controller.set_model_ready(true).await;
+        let _ = self.state_manager.transition(AppState::Idle);
+        self.emit_status(ModelStatus::Ready);
+    }
+
+    /// Unload the sidecar model while dictation is fully paused, and load it
+    /// again once resumed.
+    pub(super) fn start_full_pause_loop(&self) {
+        let model = self.clone();
+
+        tokio::spawn(async move {
+            let mut receiver = model.state_manager.subscribe();
+            let mut unloaded = false;
+
+            loop {
+                if let Err(broadcast::error::RecvError::Closed) = receiver.recv().await {
+                    break;
+                }
+
+                let paused = model.state_manager.level() == EnableLevel::FullyPaused;
+                if paused && !unloaded && model.state_manager.get() == AppState::Idle {
+                    // Waiting for Idle lets a running transcription finish first.
+                    unloaded = model.unload("Dictation fully paused").await;
+                } else if !paused && unloaded {
+                    unloaded = false;
+                    model.reload("pause").await;
+                }
+            }
+        });
+    }
+
+    /// Unload the sidecar model after the power profile's idle time without
+    /// a dictation, and load it again on the next recording attempt or once
+    /// the profile keeps it loaded.
+    pub(super) fn start_idle_suspend_loop(&self, session: &SessionService) {
+        let model = self.clone();
+        let idle_activity = Arc::clone(&session.idle_activity);
+        let wake = Arc::clone(&session.model_wake);
+
+        tokio::spawn(async move {
+            let mut tick = tokio::time::interval(IDLE_AUTO_DISABLE_CHECK_INTERVAL);
+            tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            let mut suspended = false;
+
+            loop {
+                let woken = tokio::select! {
+                    _ = tick.tick() => false,
+                    _ = wake.notified() => true,
+                };
+                let suspend_after = power::sidecar_idle_suspend_after(power::current_profile());
+
+                if suspended {
+                    if model.recording_controller.is_model_ready().await {
+                        // Loaded again by another path, e.g. resuming from a full pause.
+                        suspended = false;
+                    } else if woken || suspend_after.is_none() {
+                        suspended = false;
+                        // Restart the idle clock so the model is not dropped again
+                        // before the user retries.
+                        idle_activity.lock().await.record_activity(Instant::now());
+                        model.reload("idle suspension").await;
+                    }
+                    continue;
+                }
+
+                let Some(limit) = suspend_after else {
+                    continue;
+                };
+                if model.state_manager.get() != AppState::Idle
+                    || model.state_manager.level() == EnableLevel::FullyPaused
+                    || !model.recording_controller.is_model_ready().await
+                    || idle_activity.lock().await.idle_for(Instant::now()) < limit
+                {
+                    continue;
+                }
+                suspended = model.unload("Idle on battery").await;
+            }
+        });
+    }
+
+    /// Load the cached model again after it was unloaded for `reason`.
+    async fn reload(&self, reason: &str) {
+        let client = self.rpc_client.read().await;
+        match client.as_ref() {
+            Some(client) => self.load_cached(client).await,
+            None => log::warn!(
+                "Cannot reload model after {}: sidecar not connected",
+                reason
+            ),
+        }
+    }
+
+    /// Ask the sidecar to drop its model for `reason`; true once it did.
+    async fn unload(&self, reason: &str) -> bool {
+        if embedded_asr::is_active() {
+            return false;
+        }
+        let client = self.rpc_client.read().await;
+        let Some(client) = client.as_ref() else {
+            return false;
+        };
+        match client.call::<Value>("asr.unload", None).await {
+            Ok(_) => {
+                log::info!("{}; sidecar model unloaded", reason);
+                self.recording_controller.set_model_ready(false).await;
+                true
+            }
+            Err(RpcError::Remote { kind, .. }) if kind == "E_METHOD_NOT_FOUND" => {
+                log::info!("asr.unload unsupported by sidecar; model stays loaded");
+                false
+            }
+            Err(error) => {
+                log::warn!("Failed to unload model ({}): {}", reason, error);
+                false
+            }
+        }
+    }
+
+    /// Route dictation to the embedded engine and announce it as the ready
+    /// model. Returns false when this build lacks the engine or its model.
+    pub(super) async fn activate_embedded_asr(&self) -> bool {
+        let resource_dir = self
+            .app_handle
+            .as_ref()
+            .and_then(|handle| handle.path().resource_dir().ok());
+        let activated =
+            tokio::task::spawn_blocking(move || embedded_asr::activate(resource_dir.as_deref()))
+                .await;
+        match activated {
+            Ok(Ok(path)) => {
+                log::warn!("Dictation using embedded ASR engine: {}", path.display());
+                *self.status.write().await = ModelStatus::Ready;
+                self.recording_controller.set_model_ready(true).await;
+                let _ = self.state_manager.transition(AppState::Idle);
+                if let Some(ref handle) = self.app_handle {
+                    let mut payload = model_status_event_payload(
+                        ModelStatus::Ready,
+                        Some(embedded_asr::EMBEDDED_MODEL_ID.to_string()),
+                        None,
+                        None,
+                        None,
+                    );
+                    payload.kind = Some("embedded".to_string());
+                    emit_with_shared_seq(
+                        handle,
+                        &[EVENT_MODEL_STATUS],
+                        json!(payload),
+                        &self.event_seq,
+                    );
+                }
+                true
+            }
+            Ok(Err(err)) => {
+                log::warn!("Embedded ASR engine unavailable: {}", err);
+                false
+            }
+            Err(err) => {
+                log::error!("Embedded ASR activation task failed: {}", err);
+                false
+            }
+        }
+    }
+
+    /// Hand dictation back to the sidecar once its model is ready, unless the
+    /// embedded engine was requested outright.
+    fn release_embedded_asr() {
+        if embedded_asr::is_active() && config::load_config().asr_backend() != AsrBackend::Embedded
+        {
+            log::info!("Sidecar model ready; leaving embedded ASR engine");
+            embedded_asr::deactivate();
+        }
+    }
+
+    /// Trigger model initialization via sidecar.
+    async fn trigger_init(&self, client: &RpcClient) {
+        // Transition to loading state
+        let _ = self.state_manager.transition(AppState::LoadingModel);
+        *self.status.write().await = ModelStatus::Downloading;
+        self.emit_status(ModelStatus::Downloading);
+
+        // Get configured model or use default
+        let config = config::load_config();
+        let model_id = config
+            .model
+            .as_ref()
+            .and_then(|m| m.model_id.clone())
+            .unwrap_or_else(|| model_defaults::default_model_id().to_string());
+
+        let raw_device = config.model.as_ref().and_then(|m| m.device.as_deref());
+        let raw_preferred = config
+            .model
+            .as_ref()
+            .map(|m| m.preferred_device.as_str())
+            .unwrap_or("auto");
+        let device_pref = config.effective_model_device_pref();
+
+        log::info!(
+            "Resolved model device preference: model.device={:?}, model.preferred_device='{}', effective='{}'",
+            raw_device,
+            raw_preferred,
+            device_pref
+        );
+
+        log::info!(
+            "Initializing ASR model: model={}, device={}, language={:?}",
+            model_id,
+            device_pref,
+            configured_model_language_hint(&config)
+        );
+        let language = configured_model_language_hint(&config);
+        let beam_size = configured_model_beam_size(&config);
+
+        match call_asr_initialize_with_language_fallback(
+            client,
+            &model_id,
+            &device_pref,
+            language,
+            beam_size,
+        )
+        .await
+        {
+            Ok(result) => {
+                log::info!("ASR initialization complete: status={}", result.status);
+                Self::release_embedded_asr();
+                *self.status.write().await = ModelStatus::Ready;
+                self.recording_controller.set_model_ready(true).await;
+                let _ = self.state_manager.transition(AppState::Idle);
+                self.emit_status(ModelStatus::Ready);
+            }
+            Err(e) => {
+                log::error!("ASR initialization failed: {}", e);
+                let error_msg = format!("Model initialization failed: {}", e);
+                *self.status.write().await = ModelStatus::Error(error_msg.clone());
+                self.state_manager
+                    .transition_to_error(ErrorCategory::Model, error_msg.clone());
+                self.emit_status(ModelStatus::Error(error_msg));
+            }
+        }
+    }
+
+    /// Announce `model_id` as the ready model after it was loaded outside
+    /// the usual initialization, into a warm spare sidecar.
+    pub(super) async fn mark_ready(&self, model_id: &str) {
+        Self::release_embedded_asr();
+        *self.status.write().await = ModelStatus::Ready;
+        self.recording_controller.set_model_ready(true).await;
+        if matches!(
+            self.state_manager.get(),
+            AppState::LoadingModel | AppState::Error
+        ) {
+            let _ = self.state_manager.transition(AppState::Idle);
+        }
+        self.emit_status_with_details(
+            ModelStatus::Ready,
+            Some(model_id.to_string()),
+            None,
+            None,
+            None,
+        );
+    }
+
+    /// Emit model status event to frontend.
+    fn emit_status(&self, status: ModelStatus) {
+        self.emit_status_with_details(status, None, None, None, None);
+    }
+
+    /// Emit canonical model status event with optional metadata.
+    fn emit_status_with_details(
+        &self,
+        status: ModelStatus,
+        model_id: Option<String>,
+        revision: Option<String>,
+        cache_path: Option<String>,
+        progress: Option<ModelStatusProgress>,
+    ) {
+        self.emit_status_payload(model_status_event_payload(
+            status, model_id, revision, cache_path, progress,
+        ));
+    }
+
+    fn emit_status_payload(&self, payload: ModelStatusPayload) {
+        if let Some(ref handle) = self.app_handle {
+            emit_with_shared_seq(
+                handle,
+                &[EVENT_MODEL_STATUS],
+                json!(payload),
+                &self.event_seq,
+            );
+        }
+    }
+
+    /// Get current model status.
+    pub(super) async fn get_status(&self) -> ModelStatus {
+        self.status.read().await.clone()
+    }
+
+    /// Query live model status from sidecar RPC.
+    pub(super) async fn query_status(
+        &self,
+        model_id: Option<String>,
+    ) -> Result<SidecarModelStatus, IntegrationError> {
+        let client = self.rpc_client.read().await;
+        let client = client
+            .as_ref()
+            .ok_or(IntegrationError::SidecarNotConnected)?;
+
+        let params = model_id.map(|id| json!({ "model_id": id }));
+        client
+            .call::<SidecarModelStatus>("model.get_status", params)
+            .await
+            .map_err(|e| IntegrationError::rpc("query model status", e))
+    }
+
+    /// Manually trigger model download.
+    pub(super) async fn download(
+        &self,
+        model_id: Option<String>,
+        force: Option<bool>,
+    ) -> Result<(), IntegrationError> {
+        let client = self.rpc_client.read().await;
+        let client = client
+            .as_ref()
+            .ok_or(IntegrationError::SidecarNotConnected)?;
+
+        let resolved_model_id = resolve_model_id(model_id.clone());
+        if network::is_offline() {
+            // A cached model installs without network access.
+            let cached = client
+                .call::<SidecarModelStatus>(
+                    "model.get_status",
+                    Some(json!({ "model_id": resolved_model_id })),
+                )
+                .await
+                .is_ok_and(|status| status.status == "ready");
+            if !cached {
+                network::check(NetworkPurpose::ModelDownload, &resolved_model_id).map_err(
+                    |blocked| IntegrationError::Model {
+                        kind: ErrorKind::Network,
+                        message: format!(
+                            "E_NETWORK: {blocked}. Turn off offline mode to download models."
+                        ),
+                    },
+                )?;
+            }
+        }
+
+        let _ = self.state_manager.transition(AppState::LoadingModel);
+        *self.status.write().await = ModelStatus::Downloading;
+        self.recording_controller.set_model_ready(false).await;
+        self.emit_status(ModelStatus::Downloading);
+
+        let license_accepted = model_license::global_licenses().is_accepted(&resolved_model_id);
+        let params = model_download_params(model_id, force, license_accepted);
+        let status_result = match client
+            .call::<SidecarModelStatus>("model.install", params.clone())
+            .await
+        {
+            Ok(status) => Ok(status),
+            Err(RpcError::Remote { kind, .. }) if kind == "E_METHOD_NOT_FOUND" => {
+                log::info!(
+                    "model.install unsupported by sidecar; falling back to legacy model.download + asr.initialize"
+                );
+                match client
+                    .call::<SidecarModelStatus>("model.download", params.clone())
+                    .await
+                {
+                    Ok(mut status) => {
+                        let config = config::load_config();
+                        let device_pref = config.effective_model_device_pref();
+                        let language = configured_model_language_hint(&config);
+                        match call_asr_initialize_with_language_fallback(
+                            client,
+                            &resolved_model_id,
+                            &device_pref,
+                            language,
+                            configured_model_beam_size(&config),
+                        )
+                        .await
+                        {
+                            Ok(result) => {
+                                log::info!(
+                                    "Legacy fallback asr.initialize complete: model={}, status={}",
+                                    resolved_model_id,
+                                    result.status
+                                );
+                                status.status = "ready".to_string();
+                                Ok(status)
+                            }
+                            Err(error) => Err(IntegrationError::Model {
+                                kind: ErrorKind::ModelDownload,
+                                message: format!(
+                                    "E_MODEL_DOWNLOAD: Legacy sidecar fallback failed during asr.initialize: {}",
+                                    error
+                                ),
+                            }),
+                        }
+                    }
+                    Err(RpcError::Remote { kind, .. }) if kind == "E_METHOD_NOT_FOUND" => {
+                        Err(model_download_method_unsupported_error())
+                    }
+                    Err(err) => Err(map_model_download_rpc_error(err)),
+                }
+            }
+            Err(err) => Err(map_model_download_rpc_error(err)),
+        };
+
+        match status_result {
+            Ok(status) => {
+                let mapped_status = map_download_response_status(&status);
+                *self.status.write().await = mapped_status.clone();
+                self.recording_controller
+                    .set_model_ready(matches!(mapped_status, ModelStatus::Ready))
+                    .await;
+
+                let pending_license = (status.status == "license_required")
+                    .then(|| status.license.clone())
+                    .flatten();
+                if let Some(license) = pending_license {
+                    // Nothing is downloaded until the user accepts the license.
+                    log::info!(
+                        "Model '{}' requires license acceptance before download",
+                        resolved_model_id
+                    );
+                    model_license::global_licenses().set_pending(PendingLicense {
+                        model_id: resolved_model_id.clone(),
+                        revision: status.revision.clone(),
+                        license,
+                    });
+                    let _ = self.state_manager.transition(AppState::Idle);
+                }
+
+                if matches!(mapped_status, ModelStatus::Ready) {
+                    let _ = self.state_manager.transition(AppState::Idle);
+                }
+
+                let status_progress = status.progress.as_ref().map(|progress| {
+                    status_progress_from_parts(
+                        progress.current,
+                        progress.total,
+                        progress.unit.clone(),
+                        None,
+                    )
+                });
+
+                self.emit_status_with_details(
+                    mapped_status,
+                    Some(resolve_model_id(Some(status.model_id.clone()))),
+                    status.revision.clone(),
+                    status.cache_path.clone(),
+                    status_progress.clone(),
+                );
+
+                if let (Some(progress), Some(handle)) = (status.progress, self.app_handle.as_ref())
+                {
+                    let model_progress_data = model_progress_from_parts(
+                        Some(resolve_model_id(Some(status.model_id.clone()))),
+                        progress.current,
+                        progress.total,
+                        progress.unit,
+                        progress.stage,
+                        progress.current_file,
+                        progress.files_completed,
+                        progress.files_total,
+                    );
+                    *self.progress.write().await = Some(model_progress_data.clone());
+                    emit_with_shared_seq(
+                        handle,
+                        &[EVENT_MODEL_PROGRESS],
+                        json!(model_progress_data),
+                        &self.event_seq,
+                    );
+                }
+
+                Ok(())
+            }
+            Err(error) => {
+                let message = error.to_string();
+                let error_status = ModelStatus::Error(message.clone());
+                *self.status.write().await = error_status.clone();
+                self.recording_controller.set_model_ready(false).await;
+                self.state_manager
+                    .transition_to_error(ErrorCategory::Model, message);
+                self.emit_status(error_status);
+                Err(error)
+            }
+        }
+    }
+
+    /// Purge model cache.
+    pub(super) async fn purge_cache(
+        &self,
+        model_id: Option<String>,
+    ) -> Result<(), IntegrationError> {
+        let current_status = self.status.read().await.clone();
+        if current_status == ModelStatus::Downloading || current_status == ModelStatus::Loading {
+            return Err(IntegrationError::ModelBusy);
+        }
+
+        let purge_model_id = match model_id {
+            Some(id) => {
+                let trimmed = id.trim().to_string();
+                if trimmed.is_empty() {
+                    return Err(IntegrationError::InvalidInput(
+                        "Invalid model_id: blank or whitespace-only value".to_string(),
+                    ));
+                }
+                Some(trimmed)
+            }
+            None => None,
+        };
+
+        let client = self.rpc_client.read().await;
+        let client = client
+            .as_ref()
+            .ok_or(IntegrationError::SidecarNotConnected)?;
+
+        #[derive(Deserialize)]
+        struct PurgeResult {
+            #[allow(dead_code)]
+            purged: bool,
+            #[serde(default)]
+            purged_model_ids: Vec<String>,
+        }
+
+        let params = purge_model_id
+            .as_ref()
+            .map(|requested_model_id| json!({ "model_id": requested_model_id }));
+
+        let result = client
+            .call::<PurgeResult>("model.purge_cache", params)
+            .await
+            .map_err(|e| IntegrationError::rpc("purge cache", e))?;
+
+        let configured_model_id = configured_model_id();
+        let reported_purged_model_ids = normalized_purged_model_ids(&result.purged_model_ids);
+        let status_model_ids = purge_status_model_ids(
+            purge_model_id.as_deref(),
+            configured_model_id.as_str(),
+            &reported_purged_model_ids,
+        );
+        let removed_model_count = reported_purged_model_ids.len();
+        let affects_configured_model =
+            purge_affects_configured_model(configured_model_id.as_str(), &status_model_ids);
+
+        if affects_configured_model {
+            *self.status.write().await = ModelStatus::Missing;
+            self.recording_controller.set_model_ready(false).await;
+        }
+
+        // Emit model:status Missing for each purged model so UI consumers
+        // can track cache state for all models, not just the configured one.
+        if let Some(app_handle) = &self.app_handle {
+            emit_missing_model_status_for_purged_models_with_broadcaster(
+                app_handle,
+                &self.event_seq,
+                &status_model_ids,
+            );
+        }
+
+        log::info!(
+            "Model cache purged{} ({} models removed)",
+            purge_model_id
+                .as_ref()
+                .map(|id| format!(" for model {}", id))
+                .unwrap_or_default(),
+            removed_model_count,
+        );
+        Ok(())
+    }
+
+    /// Initialize ASR model via sidecar.
+    pub(super) async fn initialize_asr(
+        &self,
+        model_id: &str,
+        device: &str,
+    ) -> Result<(), IntegrationError> {
+        let client = self.rpc_client.read().await;
+        let client = client
+            .as_ref()
+            .ok_or(IntegrationError::SidecarNotConnected)?;
+
+        // Transition to loading state
+        let _ = self.state_manager.transition(AppState::LoadingModel);
+        let config = config::load_config();
+        let language = configured_model_language_hint(&config);
+        let beam_size = configured_model_beam_size(&config);
+
+        match call_asr_initialize_with_language_fallback(
+            client, model_id, device, language, beam_size,
+        )
+        .await
+        {
+            Ok(result) => {
+                log::info!("ASR initialized: status={}", result.status);
+                // Mark model ready
+                self.recording_controller.set_model_ready(true).await;
+                // Transition to idle
+                let _ = self.state_manager.transition(AppState::Idle);
+                Ok(())
+            }
+            Err(e) => {
+                log::error!("ASR initialization failed: {}", e);
+                self.state_manager.transition_to_error(
+                    ErrorCategory::Model,
+                    format!("Model initialization failed: {}", e),
+                );
+                Err(IntegrationError::rpc("initialize ASR", e))
+            }
+        }
+    }
+
+    /// Handle an `event.status_changed` notification: update the model
+    /// status and progress, and re-emit the sidecar state as `sidecar:status`.
+    pub(super) async fn handle_status_changed(&self, params: Value) {
+        #[derive(Deserialize)]
+        struct StatusParams {
+            #[allow(dead_code)]
+            #[serde(default)]
+            state: Option<String>,
+            #[allow(dead_code)]
+            #[serde(default)]
+            detail: Option<String>,
+            #[serde(default)]
+            model: Option<Value>,
+            #[serde(default)]
+            progress: Option<ProgressParams>,
+            #[serde(default)]
+            restart_count: Option<u32>,
+            /// Initialization step while the model loads.
+            #[serde(default)]
+            stage: Option<String>,
+            #[serde(default)]
+            percent: Option<f64>,
+        }
+
+        #[derive(Deserialize)]
+        struct ModelParams {
+            #[allow(dead_code)]
+            #[serde(default)]
+            model_id: Option<String>,
+            status: String,
+            #[serde(default)]
+            revision: Option<String>,
+            #[serde(default)]
+            cache_path: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        struct ProgressParams {
+            current: u64,
+            #[serde(default)]
+            total: Option<u64>,
+            #[serde(default)]
+            unit: Option<String>,
+            #[serde(default)]
+            stage: Option<String>,
+            #[serde(default)]
+            current_file: Option<String>,
+            #[serde(default)]
+            files_completed: Option<u64>,
+            #[serde(default)]
+            files_total: Option<u64>,
+        }
+
+        let mut canonical_sidecar_payload =
+            sidecar_status_payload_from_status_event(None, None, None);
+
+        if let Ok(params) = serde_json::from_value::<StatusParams>(params) {
+            let parsed_model = params
+                .model
+                .as_ref()
+                .and_then(|model| serde_json::from_value::<ModelParams>(model.clone()).ok());
+
+            // Support both spec-compliant model object and legacy string model state.
+            let model_state = parsed_model
+                .as_ref()
+                .map(|parsed| parsed.status.clone())
+                .or_else(|| {
+                    params
+                        .model
+                        .as_ref()
+                        .and_then(|model| model.as_str().map(ToOwned::to_owned))
+                });
+
+            let status_progress = params.progress.as_ref().map(|progress| {
+                status_progress_from_parts(
+                    progress.current,
+                    progress.total,
+                    progress.unit.clone(),
+                    progress.stage.clone(),
+                )
+            });
+
+            // Update model status if provided
+            if let Some(model_state) = model_state {
+                let new_status =
+                    map_status_event_model_state(model_state.as_str(), params.detail.clone());
+                *self.status.write().await = new_status.clone();
+
+                if new_status == ModelStatus::Ready {
+                    self.recording_controller.set_model_ready(true).await;
+                } else {
+                    self.recording_controller.set_model_ready(false).await;
+                }
+
+                let payload = model_status_event_payload(
+                    new_status,
+                    parsed_model.as_ref().and_then(|m| m.model_id.clone()),
+                    parsed_model.as_ref().and_then(|m| m.revision.clone()),
+                    parsed_model.as_ref().and_then(|m| m.cache_path.clone()),
+                    status_progress.clone(),
+                );
+                self.emit_status_payload(with_loading_stage(
+                    payload,
+                    params.stage.clone(),
+                    params.percent,
+                ));
+            }
+
+            // Update and emit progress if provided
+            if let Some(ref progress) = params.progress {
+                let model_progress_data = model_progress_from_parts(
+                    parsed_model.as_ref().and_then(|m| m.model_id.clone()),
+                    progress.current,
+                    progress.total,
+                    progress.unit.clone(),
+                    progress.stage.clone(),
+                    progress.current_file.clone(),
+                    progress.files_completed,
+                    progress.files_total,
+                );
+                *self.progress.write().await = Some(model_progress_data.clone());
+
+                if let Some(ref handle) = self.app_handle {
+                    emit_with_shared_seq(
+                        handle,
+                        &[EVENT_MODEL_PROGRESS],
+                        json!(model_progress_data),
+                        &self.event_seq,
+                    );
+                }
+            }
+
+            canonical_sidecar_payload = sidecar_status_payload_from_status_event(
+                params.state.as_deref(),
+                params.detail.clone(),
+                params.restart_count,
+            );
+        }
+
+        // Emit canonical sidecar status payload with shared seq.
+        if let Some(ref handle) = self.app_handle {
+            let seq = next_seq(&self.event_seq);
+            emit_with_existing_seq_to_all_windows(
+                handle,
+                EVENT_SIDECAR_STATUS,
+                canonical_sidecar_payload,
+                seq,
+            );
+        }
+    }
+
+    /// Handle an `event.model_progress` notification.
+    pub(super) async fn handle_model_progress(&self, params: Value) {
+        if let Ok(params) = serde_json::from_value::<SidecarModelProgress>(params) {
+            let model_progress_data = model_progress_from_parts(
+                params.model_id,
+                params.current,
+                params.total,
+                params.unit,
+                params.stage,
+                params.current_file,
+                params.files_completed,
+                params.files_total,
+            );
+            *self.progress.write().await = Some(model_progress_data.clone());
+
+            if let Some(ref handle) = self.app_handle {
+                emit_with_shared_seq(
+                    handle,
+                    &[EVENT_MODEL_PROGRESS],
+                    json!(model_progress_data),
+                    &self.event_seq,
+                );
+            }
+        }
+    }
+}
+
+fn map_download_response_status(status: &SidecarModelStatus) -> ModelStatus {
+    match status.status.as_str() {
+        "missing" | "license_required" => ModelStatus::Missing,
+        "downloading" => ModelStatus::Downloading,
+        "loading" | "verifying" | "installing" => ModelStatus::Loading,
+        "ready" => ModelStatus::Ready,
+        "error" => ModelStatus::Error(
+            status
+                .error
+                .clone()
+                .or_else(|| status.error_message.clone())
+                .unwrap_or_else(|| "model download failed".to_string()),
+        ),
+        _ => ModelStatus::Unknown,
+    }
+}
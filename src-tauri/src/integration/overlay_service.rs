@@ -0,0 +1,255 @@
+//! Overlay window lifecycle: config gate, recording show/hide and the focus
+//! preview shown while the overlay is up for a recording.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use serde_json::json;
+use tokio::sync::{Mutex, Notify};
+
+use crate::config;
+#[cfg(feature = "desktop")]
+use crate::focus::capture_focus;
+use crate::focus::{current_focus_info, FocusChangeDebouncer};
+#[cfg(feature = "desktop")]
+use crate::overlay::{
+    FileOverlayConfigStore, ForcedOverlayConfigStore, OverlayWindowBackend,
+    TauriOverlayWindowBackend, OVERLAY_WINDOW_LABEL,
+};
+use crate::overlay::{OverlayAppearance, OverlayManager};
+use crate::runtime::AppHandle;
+
+use super::{
+    emit_with_shared_seq, overlay_config_payload, should_apply_overlay_config_change,
+    EVENT_FOCUS_CHANGED, EVENT_OVERLAY_CONFIG, FOCUS_PREVIEW_DEBOUNCE, FOCUS_PREVIEW_POLL_INTERVAL,
+};
+
+/// Owns the overlay window and keeps it in step with `ui.overlay_*` config.
+#[derive(Clone)]
+pub(super) struct OverlayService {
+    /// Tauri app handle.
+    app_handle: Option<AppHandle>,
+    /// Monotonic event sequence counter shared with the other services.
+    event_seq: Arc<AtomicU64>,
+    /// Overlay window lifecycle manager.
+    overlay_manager: Arc<Mutex<OverlayManager>>,
+    /// Notification channel for overlay config changes (event-driven, no polling).
+    overlay_config_notify: Arc<Notify>,
+    /// Cached overlay-enabled flag so disabled mode avoids per-event config reads.
+    overlay_enabled: Arc<AtomicBool>,
+    /// Whether a recording is in progress, so the focus preview is wanted.
+    recording_active: Arc<AtomicBool>,
+    /// Wakes the idle focus preview loop when recording or the overlay starts.
+    focus_preview_notify: Arc<Notify>,
+}
+
+impl OverlayService {
+    pub(super) fn new(event_seq: Arc<AtomicU64>, overlay_enabled: bool) -> Self {
+        Self {
+            app_handle: None,
+            event_seq,
+            overlay_manager: Arc::new(Mutex::new(OverlayManager::new())),
+            overlay_config_notify: Arc::new(Notify::new()),
+            overlay_enabled: Arc::new(AtomicBool::new(overlay_enabled)),
+            recording_active: Arc::new(AtomicBool::new(false)),
+            focus_preview_notify: Arc::new(Notify::new()),
+        }
+    }
+
+    pub(super) fn set_app_handle(&mut self, handle: AppHandle) {
+        self.app_handle = Some(handle);
+    }
+
+    /// Whether the overlay is enabled, as of the last config the gate loop read.
+    pub(super) fn is_enabled(&self) -> bool {
+        self.overlay_enabled.load(Ordering::Acquire)
+    }
+
+    /// Wake the config-gate loop after the overlay config changed.
+    pub(super) fn notify_config_changed(&self) {
+        self.overlay_config_notify.notify_waiters();
+    }
+
+    /// Start overlay config-gate loop.
+    ///
+    /// Ensures the overlay window is pre-created when `ui.overlay_enabled=true`
+    /// and destroyed when disabled, and emits `overlay:config` whenever the
+    /// overlay appearance changes. The actual show/hide is driven by recording
+    /// events through `apply_recording_state`.
+    pub(super) fn start_overlay_window_loop(&self) {
+        let app_handle = self.app_handle.clone();
+        #[cfg(feature = "desktop")]
+        let overlay_manager = Arc::clone(&self.overlay_manager);
+        let overlay_config_notify = Arc::clone(&self.overlay_config_notify);
+        let overlay_enabled = Arc::clone(&self.overlay_enabled);
+        let focus_preview_notify = Arc::clone(&self.focus_preview_notify);
+        let event_seq = Arc::clone(&self.event_seq);
+
+        tokio::spawn(async move {
+            let Some(handle) = app_handle else {
+                log::warn!("Overlay window loop skipped: app handle missing");
+                return;
+            };
+
+            let mut last_enabled: Option<bool> = None;
+            let mut last_appearance: Option<OverlayAppearance> = None;
+
+            log::info!("Overlay config-gate loop started");
+
+            loop {
+                let ui_config = config::load_config().ui;
+                let enabled = ui_config.overlay_enabled;
+                overlay_enabled.store(enabled, Ordering::Release);
+                focus_preview_notify.notify_one();
+
+                let appearance = OverlayAppearance::from_config(&ui_config);
+                if last_appearance.as_ref() != Some(&appearance) {
+                    emit_with_shared_seq(
+                        &handle,
+                        &[EVENT_OVERLAY_CONFIG],
+                        overlay_config_payload(&appearance),
+                        &event_seq,
+                    );
+                    last_appearance = Some(appearance);
+                }
+
+                if !should_apply_overlay_config_change(last_enabled, enabled) {
+                    overlay_config_notify.notified().await;
+                    continue;
+                }
+
+                // Headless hosts have no overlay window to manage.
+                #[cfg(feature = "desktop")]
+                {
+                    let backend = TauriOverlayWindowBackend::new(&handle);
+
+                    if !enabled {
+                        // Disabled: hide and destroy any existing window.
+                        let mut manager = overlay_manager.lock().await;
+                        if let Err(error) = manager.hide(&FileOverlayConfigStore, &backend) {
+                            log::warn!("Overlay hide on disable failed: {error}");
+                        }
+                    } else {
+                        // Enabled: pre-create the window (hidden) so show is fast.
+                        let backend_ref = &backend;
+                        if !backend_ref.window_exists(OVERLAY_WINDOW_LABEL) {
+                            if let Err(error) = backend_ref.create_window(OVERLAY_WINDOW_LABEL) {
+                                log::warn!("Overlay window pre-creation failed: {error}");
+                            }
+                        }
+                    }
+                }
+
+                last_enabled = Some(enabled);
+                overlay_config_notify.notified().await;
+            }
+        });
+    }
+
+    /// Start focus preview loop.
+    ///
+    /// While the overlay is enabled and a recording is in progress, polls the
+    /// foreground window and emits a debounced `focus:changed` event so the
+    /// overlay can show where dictated text will land. Otherwise it sleeps
+    /// until woken, since each poll shells out on some platforms.
+    pub(super) fn start_focus_preview_loop(&self) {
+        let app_handle = self.app_handle.clone();
+        let overlay_enabled = Arc::clone(&self.overlay_enabled);
+        let recording_active = Arc::clone(&self.recording_active);
+        let focus_preview_notify = Arc::clone(&self.focus_preview_notify);
+        let event_seq = Arc::clone(&self.event_seq);
+
+        tokio::spawn(async move {
+            let Some(handle) = app_handle else {
+                log::warn!("Focus preview loop skipped: app handle missing");
+                return;
+            };
+
+            let mut tick = tokio::time::interval(FOCUS_PREVIEW_POLL_INTERVAL);
+            tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            let mut debouncer = FocusChangeDebouncer::new(FOCUS_PREVIEW_DEBOUNCE);
+
+            loop {
+                if !overlay_enabled.load(Ordering::Acquire)
+                    || !recording_active.load(Ordering::Acquire)
+                {
+                    focus_preview_notify.notified().await;
+                    continue;
+                }
+                tick.tick().await;
+
+                // Focus capture shells out on some platforms; keep it off the runtime.
+                let info = match tokio::task::spawn_blocking(current_focus_info).await {
+                    Ok(info) => info,
+                    Err(error) => {
+                        log::debug!("Focus preview capture failed: {}", error);
+                        continue;
+                    }
+                };
+
+                if let Some(settled) = debouncer.observe(info, Instant::now()) {
+                    emit_with_shared_seq(
+                        &handle,
+                        &[EVENT_FOCUS_CHANGED],
+                        json!({
+                            "app_name": settled.app_name,
+                            "window_title": settled.window_title,
+                            "window_id": settled.window_id,
+                            "is_self": settled.is_self,
+                        }),
+                        &event_seq,
+                    );
+                }
+            }
+        });
+    }
+
+    /// Show or hide the overlay window for the recording lifecycle; `forced`
+    /// shows it even when the overlay is disabled. Shown, it goes to the
+    /// monitor of the focused app unless `ui.overlay_monitor` pins it.
+    #[cfg(feature = "desktop")]
+    pub(super) async fn apply_recording_state(&self, recording_active: bool, forced: bool) {
+        self.set_focus_preview_active(recording_active);
+        let Some(ref handle) = self.app_handle else {
+            return;
+        };
+        // Focus capture shells out on some platforms; keep it off the runtime.
+        let target_window = if recording_active {
+            tokio::task::spawn_blocking(capture_focus)
+                .await
+                .ok()
+                .and_then(|focus| focus.window_bounds)
+        } else {
+            None
+        };
+        let backend = TauriOverlayWindowBackend::new(handle);
+        let mut manager = self.overlay_manager.lock().await;
+        if recording_active {
+            manager.set_target_window(target_window);
+            manager.set_fixed_monitor(config::load_config().ui.overlay_monitor);
+        }
+        let result = if forced {
+            manager.handle_recording_state(recording_active, &ForcedOverlayConfigStore, &backend)
+        } else {
+            manager.handle_recording_state(recording_active, &FileOverlayConfigStore, &backend)
+        };
+        if let Err(error) = result {
+            log::debug!("Overlay recording state transition failed: {error}");
+        }
+    }
+
+    /// Headless hosts have no overlay window.
+    #[cfg(not(feature = "desktop"))]
+    pub(super) async fn apply_recording_state(&self, recording_active: bool, _forced: bool) {
+        self.set_focus_preview_active(recording_active);
+    }
+
+    fn set_focus_preview_active(&self, recording_active: bool) {
+        self.recording_active
+            .store(recording_active, Ordering::Release);
+        if recording_active {
+            self.focus_preview_notify.notify_one();
+        }
+    }
+}
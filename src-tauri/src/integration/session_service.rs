@@ -0,0 +1,2119 @@
+//! Recording sessions: start/stop/cancel, transcription delivery and retry,
+//! and the injection pipeline that runs once a transcript arrives.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use tokio::sync::{Mutex, Notify, OwnedRwLockWriteGuard, RwLock};
+use uuid::Uuid;
+
+use crate::audio_cue::CueType;
+use crate::config::{self, ScreenSharePolicy};
+use crate::embedded_asr;
+use crate::errors::{AppError, IntegrationError};
+use crate::focus::capture_focus;
+use crate::history::{
+    HistoryInjectionResult, TranscriptEntry, TranscriptHistory, TranscriptSegment,
+    TranscriptTimings, TranscriptWord,
+};
+use crate::injection::{
+    inject_text_with_progress, InjectionConfig, InjectionMode, InjectionProgress, InjectionResult,
+};
+use crate::injection_order;
+use crate::ipc::{RpcClient, RpcError};
+use crate::live_flush::{self, SentenceCarry};
+use crate::pending_inserts::{PendingInsert, PendingInsertQueue, PendingInsertsChangedPayload};
+use crate::recording::{
+    CancelReason, RecordingController, RecordingEvent, StopResult, TranscriptionResult,
+    UtteranceResult,
+};
+use crate::runtime::{AppHandle, Manager};
+use crate::screen_share;
+use crate::session_journal::{self, JournalEvent};
+use crate::session_lifecycle::{apply_recording_event, SessionSnapshot};
+use crate::state::{AppState, AppStateManager, CannotRecordReason, EnableLevel};
+use crate::target_responsiveness::{self, BusyWaitOutcome, Responsiveness};
+
+use super::overlay_service::OverlayService;
+use super::{
+    app_error_event_payload, busy_target_clipboard_reason, canonical_transcription_error_kind,
+    clipboard_only_requires_app_error, emit_with_shared_seq, extract_session_id,
+    has_transcription_timed_out, injection_confirm_requested_payload, injection_failure_app_error,
+    injection_progress_event_payload, injection_queue_changed_payload,
+    injection_result_journal_detail, is_stale_session, is_transient_transcription_error,
+    map_transcription_complete_durations, overlay_cancelled_payload, overlay_countdown_payload,
+    overlay_hide_delay_for_event, overlay_recording_state_for_event, overlay_transcribing_payload,
+    pending_insert_reason, pipeline_timings_from_marks, play_lifecycle_audio_cue,
+    recording_event_audio_cue, recording_event_journal_record, recording_start_params,
+    recording_started_event_payload, recording_status_event_payload,
+    recovered_transcription_outcome, resolve_transcript_texts, session_changed_payload,
+    stale_notification_message, state_changed_event_payload, stop_rpc_method_for_result,
+    transcript_complete_event_payload, transcription_error_event_payload,
+    transcription_failure_app_error, validate_recording_start_response, IdleActivityTimer,
+    InjectionGuard, PipelineTimingMarks, RecordingContext, TranscriptionLookup,
+    ASK_MODE_CLIPBOARD_REASON, BUSY_TARGET_DISMISSED_REASON, BUSY_TARGET_PENDING_REASON,
+    DEVICE_REMOVED_CLIPBOARD_REASON, EVENT_APP_ERROR, EVENT_INJECTION_CONFIRM_REQUESTED,
+    EVENT_INJECTION_DEFERRED, EVENT_INJECTION_PROGRESS, EVENT_INJECTION_QUEUE_CHANGED,
+    EVENT_OVERLAY_CANCELLED, EVENT_OVERLAY_COUNTDOWN, EVENT_OVERLAY_TRANSCRIBING,
+    EVENT_PENDING_INSERTS_CHANGED, EVENT_RECORDING_STATUS, EVENT_SESSION_CHANGED,
+    EVENT_STATE_CHANGED, EVENT_TRANSCRIPT_COMPLETE, EVENT_TRANSCRIPT_ERROR, EVENT_TRAY_UPDATE,
+    GLOBAL_CLIPBOARD_ONLY_REASON, IDLE_AUTO_DISABLE_CHECK_INTERVAL, OVERLAY_CANCEL_ANIMATION,
+    OVERLAY_TRANSCRIBING_INTERVAL, RECORDING_COUNTDOWN_TICK, TRANSCRIPTION_RETRY_POLL_INTERVAL,
+    TRANSCRIPTION_RETRY_RECOVERY_TIMEOUT,
+};
+
+/// Owns the active recording session, from start through transcription to
+/// injection of the transcript.
+#[derive(Clone)]
+pub(super) struct SessionService {
+    /// Application state manager.
+    state_manager: Arc<AppStateManager>,
+    /// Recording controller.
+    pub(super) recording_controller: Arc<RecordingController>,
+    /// RPC client of the live sidecar (if connected).
+    rpc_client: Arc<RwLock<Option<RpcClient>>>,
+    /// Tauri app handle.
+    app_handle: Option<AppHandle>,
+    /// Monotonic event sequence counter shared with the other services.
+    event_seq: Arc<AtomicU64>,
+    /// Focus context for current recording.
+    pub(super) recording_context: Arc<RwLock<Option<RecordingContext>>>,
+    /// Current active recording/transcription session for correlation.
+    pub(super) current_session_id: Arc<RwLock<Option<String>>>,
+    /// Lifecycle snapshot of the most recent session, kept after it ends.
+    active_session: Arc<RwLock<Option<SessionSnapshot>>>,
+    /// Cancel signal of the countdown before a hotkey-started recording,
+    /// while one is running.
+    countdown: Arc<Mutex<Option<Arc<Notify>>>>,
+    /// Dictation activity timer for idle auto-disable and the sidecar's
+    /// idle suspension.
+    pub(super) idle_activity: Arc<Mutex<IdleActivityTimer>>,
+    /// Signalled when a recording cannot start because the model is not
+    /// loaded, so a model unloaded for idleness loads again.
+    pub(super) model_wake: Arc<Notify>,
+    /// Overlay shown and hidden with the recording lifecycle.
+    overlay: OverlayService,
+    /// Held for reading while a recording starts, from `recording.start`
+    /// until the app is in `Recording`; a sidecar switch holds it for writing.
+    pub(super) capture_gate: Arc<RwLock<()>>,
+}
+
+impl SessionService {
+    pub(super) fn new(
+        state_manager: Arc<AppStateManager>,
+        rpc_client: Arc<RwLock<Option<RpcClient>>>,
+        event_seq: Arc<AtomicU64>,
+        overlay: OverlayService,
+    ) -> Self {
+        let recording_controller = Arc::new(RecordingController::new(Arc::clone(&state_manager)));
+        Self {
+            state_manager,
+            recording_controller,
+            rpc_client,
+            app_handle: None,
+            event_seq,
+            recording_context: Arc::new(RwLock::new(None)),
+            current_session_id: Arc::new(RwLock::new(None)),
+            active_session: Arc::new(RwLock::new(None)),
+            countdown: Arc::new(Mutex::new(None)),
+            idle_activity: Arc::new(Mutex::new(IdleActivityTimer::new(Instant::now()))),
+            model_wake: Arc::new(Notify::new()),
+            overlay,
+            capture_gate: Arc::new(RwLock::new(())),
+        }
+    }
+
+    /// Keep recordings from starting until the guard is dropped. Waits for a
+    /// recording that is already starting to reach the `Recording` state.
+    pub(super) async fn block_recording_start(&self) -> OwnedRwLockWriteGuard<()> {
+        Arc::clone(&self.capture_gate).write_owned().await
+    }
+
+    pub(super) fn set_app_handle(&mut self, handle: AppHandle) {
+        self.overlay.set_app_handle(handle.clone());
+        self.app_handle = Some(handle);
+    }
+
+    /// Note a dictation for the idle auto-disable timer.
+    async fn record_activity(&self, now: Instant) {
+        self.idle_activity.lock().await.record_activity(now);
+    }
+
+    /// Refuse to record until the model is loaded, waking one that was
+    /// unloaded for idleness.
+    async fn require_model_ready(&self) -> Result<(), IntegrationError> {
+        if self.recording_controller.is_model_ready().await {
+            return Ok(());
+        }
+        self.model_wake.notify_one();
+        Err(IntegrationError::CannotRecord(
+            CannotRecordReason::ModelLoading,
+        ))
+    }
+
+    /// Whether listening was paused by idle auto-disable; a true result is
+    /// consumed, so only the first hotkey press re-arms.
+    pub(super) async fn take_idle_auto_disabled(&self, now: Instant) -> bool {
+        self.idle_activity.lock().await.take_auto_disabled(now)
+    }
+
+    /// Lifecycle snapshot of the most recent session.
+    pub(super) async fn snapshot(&self) -> Option<SessionSnapshot> {
+        self.active_session.read().await.clone()
+    }
+
+    /// Start a recording session; `dictate_raw` turns auto punctuation off.
+    pub(super) async fn start(&self, dictate_raw: bool) -> Result<(), IntegrationError> {
+        if self.current_session_id.read().await.is_some() {
+            return Err(IntegrationError::CannotRecord(
+                CannotRecordReason::AlreadyRecording,
+            ));
+        }
+
+        self.state_manager
+            .can_start_recording()
+            .map_err(IntegrationError::CannotRecord)?;
+
+        self.require_model_ready().await?;
+
+        let app_config = config::load_config();
+        if screen_share::enforced_policy(app_config.ui.screen_share_policy)
+            == Some(ScreenSharePolicy::DisableDictation)
+        {
+            return Err(IntegrationError::CannotRecord(
+                CannotRecordReason::ScreenSharing,
+            ));
+        }
+
+        let session_id = Uuid::new_v4().to_string();
+        let focus = capture_focus();
+        let auto_punctuation = app_config.audio.auto_punctuation && !dictate_raw;
+        let params = recording_start_params(session_id.as_str(), &app_config, auto_punctuation);
+
+        publish_session_snapshot(
+            &self.active_session,
+            self.app_handle.as_ref(),
+            &self.event_seq,
+            SessionSnapshot::created(session_id.clone(), Utc::now()),
+        )
+        .await;
+        if let Err(err) = self.begin_capture(&session_id, params).await {
+            let failed = self
+                .active_session
+                .read()
+                .await
+                .as_ref()
+                .filter(|snapshot| snapshot.session_id == session_id)
+                .and_then(|snapshot| snapshot.fail(err.to_string(), Utc::now()));
+            if let Some(failed) = failed {
+                publish_session_snapshot(
+                    &self.active_session,
+                    self.app_handle.as_ref(),
+                    &self.event_seq,
+                    failed,
+                )
+                .await;
+            }
+            return Err(err);
+        }
+
+        *self.recording_context.write().await = Some(RecordingContext {
+            focus_before: focus,
+            session_id: session_id.clone(),
+            audio_duration_ms: None,
+            raw_text: None,
+            final_text: None,
+            language: None,
+            confidence: None,
+            segments: Vec::new(),
+            words: Vec::new(),
+            auto_punctuation,
+            force_clipboard_only: false,
+            force_clipboard_reason: None,
+            timing_marks: PipelineTimingMarks::default(),
+            transcription_retries: 0,
+            retry_requested_at: None,
+        });
+        *self.current_session_id.write().await = Some(session_id);
+        // Any dictation counts, whether it came from the hotkey, tray,
+        // command or action.
+        self.record_activity(Instant::now()).await;
+
+        Ok(())
+    }
+
+    /// Start recording after counting down `secs` seconds on the overlay, with
+    /// a tick cue per second. Returns once the countdown runs; the recording
+    /// starts from a background task unless `cancel_countdown` comes first.
+    pub(super) async fn start_after_countdown(
+        &self,
+        dictate_raw: bool,
+        secs: u32,
+    ) -> Result<(), IntegrationError> {
+        // Refuse up front instead of after making the user wait.
+        self.state_manager
+            .can_start_recording()
+            .map_err(IntegrationError::CannotRecord)?;
+        self.require_model_ready().await?;
+
+        let cancel = Arc::new(Notify::new());
+        *self.countdown.lock().await = Some(Arc::clone(&cancel));
+        let overlay_shown =
+            self.overlay.is_enabled() && self.state_manager.level().shows_indicators();
+        if overlay_shown {
+            self.overlay.apply_recording_state(true, false).await;
+        }
+
+        let service = self.clone();
+        tokio::spawn(async move {
+            for remaining_secs in (1..=secs).rev() {
+                service.emit_countdown(remaining_secs, false);
+                play_lifecycle_audio_cue(&service.state_manager, CueType::CountdownTick);
+                tokio::select! {
+                    _ = tokio::time::sleep(RECORDING_COUNTDOWN_TICK) => {}
+                    _ = cancel.notified() => break,
+                }
+            }
+
+            // Still registered means nobody cancelled, even in a race with
+            // the last tick.
+            let finished = {
+                let mut countdown = service.countdown.lock().await;
+                let ours = countdown
+                    .as_ref()
+                    .is_some_and(|pending| Arc::ptr_eq(pending, &cancel));
+                if ours {
+                    *countdown = None;
+                }
+                ours
+            };
+            if !finished {
+                log::info!("Recording countdown cancelled");
+                service.emit_countdown(0, true);
+                play_lifecycle_audio_cue(&service.state_manager, CueType::CancelRecording);
+                if overlay_shown {
+                    tokio::time::sleep(OVERLAY_CANCEL_ANIMATION).await;
+                    if service.state_manager.get() != AppState::Recording {
+                        service.overlay.apply_recording_state(false, false).await;
+                    }
+                }
+                return;
+            }
+
+            service.emit_countdown(0, false);
+            if let Err(err) = service.start(dictate_raw).await {
+                log::warn!("Failed to start recording after countdown: {}", err);
+                if overlay_shown {
+                    service.overlay.apply_recording_state(false, false).await;
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// Cancel the countdown before a recording; false when none is running.
+    pub(super) async fn cancel_countdown(&self) -> bool {
+        let Some(cancel) = self.countdown.lock().await.take() else {
+            return false;
+        };
+        cancel.notify_one();
+        true
+    }
+
+    fn emit_countdown(&self, remaining_secs: u32, cancelled: bool) {
+        if let Some(ref handle) = self.app_handle {
+            emit_with_shared_seq(
+                handle,
+                &[EVENT_OVERLAY_COUNTDOWN],
+                overlay_countdown_payload(remaining_secs, cancelled),
+                &self.event_seq,
+            );
+        }
+    }
+
+    /// Play the start cue and begin audio capture for `session_id`, on the
+    /// sidecar or the embedded engine.
+    async fn begin_capture(&self, session_id: &str, params: Value) -> Result<(), IntegrationError> {
+        // Play start cue BEFORE mic capture begins and wait for the pre-roll
+        // delay so the beep is less likely to be picked up by the microphone.
+        play_lifecycle_audio_cue(&self.state_manager, CueType::StartRecording);
+        tokio::time::sleep(crate::audio_cue::START_CUE_PRE_ROLL).await;
+
+        // A sidecar switch must not retire the sidecar this recording starts on.
+        let _capture_gate = self.capture_gate.read().await;
+        if embedded_asr::is_active() {
+            embedded_asr::start_capture(session_id).map_err(|err| {
+                IntegrationError::Recording(format!("Failed to start recording: {}", err))
+            })?;
+        } else {
+            self.start_sidecar_recording(session_id, params).await?;
+        }
+
+        if let Err(err) = self
+            .recording_controller
+            .start_with_session_id(session_id.to_string())
+            .await
+        {
+            if embedded_asr::is_active() {
+                embedded_asr::cancel_capture();
+            } else if let Some(client) = self.rpc_client.read().await.as_ref() {
+                let cancel_result: Result<Value, _> = client
+                    .call(
+                        "recording.cancel",
+                        Some(json!({ "session_id": session_id })),
+                    )
+                    .await;
+                if let Err(cancel_err) = cancel_result {
+                    log::warn!(
+                        "Failed to cancel sidecar session after host start failure: {}",
+                        cancel_err
+                    );
+                }
+            }
+            return Err(IntegrationError::Recording(format!(
+                "Failed to start recording: {}",
+                err
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Start the sidecar's capture for `session_id`, rolling it back if the
+    /// sidecar answers for a different session.
+    async fn start_sidecar_recording(
+        &self,
+        session_id: &str,
+        params: Value,
+    ) -> Result<(), IntegrationError> {
+        let start_response: Value = {
+            let client_guard = self.rpc_client.read().await;
+            let client = client_guard
+                .as_ref()
+                .ok_or(IntegrationError::SidecarNotConnected)?;
+
+            client
+                .call("recording.start", Some(params))
+                .await
+                .map_err(|err| IntegrationError::rpc("call recording.start RPC", err))?
+        };
+
+        if let Err(mismatch) = validate_recording_start_response(session_id, &start_response) {
+            if let Some(client) = self.rpc_client.read().await.as_ref() {
+                let cancel_session_id = start_response
+                    .get("session_id")
+                    .and_then(Value::as_str)
+                    .unwrap_or(session_id)
+                    .to_string();
+                let cancel_result: Result<Value, _> = client
+                    .call(
+                        "recording.cancel",
+                        Some(json!({ "session_id": cancel_session_id })),
+                    )
+                    .await;
+                if let Err(err) = cancel_result {
+                    log::warn!(
+                        "Failed to roll back recording.start via recording.cancel: {}",
+                        err
+                    );
+                }
+            }
+            return Err(IntegrationError::Protocol(mismatch));
+        }
+
+        Ok(())
+    }
+
+    /// Stop recording and trigger transcription flow.
+    pub(super) async fn stop(&self) -> Result<(), IntegrationError> {
+        if self.current_session_id.read().await.is_none() {
+            return Err(IntegrationError::NotRecording);
+        }
+
+        let result =
+            self.recording_controller.stop().await.map_err(|e| {
+                IntegrationError::Recording(format!("Failed to stop recording: {}", e))
+            })?;
+        log::info!("Recording stopped: {:?}", result);
+        self.complete_stop(result).await
+    }
+
+    /// Cancel recording and discard audio without transcription.
+    pub(super) async fn cancel(&self, reason: CancelReason) -> Result<(), IntegrationError> {
+        let session_id = self
+            .current_session_id
+            .read()
+            .await
+            .clone()
+            .ok_or(IntegrationError::NotRecording)?;
+
+        self.recording_controller
+            .cancel(reason)
+            .await
+            .map_err(|err| match err {
+                crate::recording::RecordingError::NotRecording => IntegrationError::NotRecording,
+                other => {
+                    IntegrationError::Recording(format!("Failed to cancel recording: {}", other))
+                }
+            })?;
+
+        // Clear host correlation state immediately so any late sidecar notifications are stale.
+        *self.current_session_id.write().await = None;
+        *self.recording_context.write().await = None;
+
+        if embedded_asr::is_active() {
+            embedded_asr::cancel_capture();
+            return Ok(());
+        }
+
+        let params = json!({ "session_id": session_id });
+        if let Some(client) = self.rpc_client.read().await.as_ref() {
+            let cancel_result: Result<Value, RpcError> =
+                client.call("recording.cancel", Some(params.clone())).await;
+            match cancel_result {
+                Ok(_) => {}
+                Err(RpcError::Remote { kind, .. }) if kind == "E_METHOD_NOT_FOUND" => {
+                    log::warn!(
+                        "recording.cancel not supported; falling back to recording.stop and ignoring transcription result"
+                    );
+                    let fallback: Result<Value, RpcError> =
+                        client.call("recording.stop", Some(params)).await;
+                    if let Err(err) = fallback {
+                        log::error!("Fallback recording.stop after cancel failed: {}", err);
+                    }
+                }
+                Err(err) => {
+                    log::error!("Failed to call recording.cancel RPC: {}", err);
+                }
+            }
+        } else {
+            log::warn!("Sidecar not connected during cancel; completed host cancellation only");
+        }
+
+        Ok(())
+    }
+
+    /// End the active session after the last input device disappeared,
+    /// keeping a transcript that is already on its way.
+    pub(super) async fn end_for_no_audio_devices(&self) {
+        if self.current_session_id.read().await.is_none() {
+            return;
+        }
+
+        if self.state_manager.get() == AppState::Transcribing {
+            let mut context = self.recording_context.write().await;
+            if let Some(context) = context.as_mut() {
+                context.force_clipboard_only = true;
+                context.force_clipboard_reason = Some(DEVICE_REMOVED_CLIPBOARD_REASON.to_string());
+            }
+        }
+
+        if let Err(stop_error) = self.stop().await {
+            log::warn!(
+                "Failed to stop active session during no-audio-device transition: {}",
+                stop_error
+            );
+            if let Err(cancel_error) = self.cancel(CancelReason::UserButton).await {
+                log::warn!(
+                    "Failed to cancel active session during no-audio-device transition: {}",
+                    cancel_error
+                );
+            }
+        }
+    }
+
+    /// Stop (or, with `audio.auto_split_recordings`, split) recordings that
+    /// hit the maximum duration and time out transcriptions that never came
+    /// back.
+    pub(super) async fn enforce_runtime_limits(&self) {
+        if self.state_manager.get() == AppState::Recording {
+            if config::load_config().audio.auto_split_recordings && !embedded_asr::is_active() {
+                self.split_at_max_duration().await;
+                return;
+            }
+            if let Some(stop_result) = self.recording_controller.check_max_duration().await {
+                log::info!("Max recording duration reached; stopping recording");
+                if let Err(err) = self.complete_stop(stop_result).await {
+                    log::warn!("Failed to complete max-duration stop flow: {}", err);
+                }
+            }
+            return;
+        }
+
+        if self.state_manager.get() != AppState::Transcribing {
+            return;
+        }
+
+        let transcription_timeout = self
+            .recording_controller
+            .get_config()
+            .await
+            .transcription_timeout;
+        let timed_out = {
+            let ctx = self.recording_context.read().await;
+            let stop_called_at = ctx
+                .as_ref()
+                .and_then(|ctx| ctx.retry_requested_at.or(ctx.timing_marks.t0_stop_called));
+            has_transcription_timed_out(stop_called_at, transcription_timeout, Instant::now())
+        };
+
+        if timed_out {
+            // The result may have been produced but its notification lost;
+            // recover it instead of discarding the transcription.
+            let session_id = self.current_session_id.read().await.clone();
+            if let Some(session_id) = session_id {
+                let lookup = self.lookup_transcription_result(&session_id).await;
+                match lookup.and_then(recovered_transcription_outcome) {
+                    Some(Ok(result)) => {
+                        log::info!(
+                            "Recovered transcription result for session {} after timeout",
+                            session_id
+                        );
+                        self.deliver_transcription_complete(result).await;
+                        return;
+                    }
+                    Some(Err(error)) => {
+                        self.recording_controller
+                            .on_transcription_error(session_id, error)
+                            .await;
+                        return;
+                    }
+                    None => {}
+                }
+            }
+
+            log::warn!(
+                "Transcription timed out after {:?}; transitioning to error",
+                transcription_timeout
+            );
+            self.recording_controller.on_transcription_timeout().await;
+            *self.current_session_id.write().await = None;
+            *self.recording_context.write().await = None;
+        }
+    }
+
+    /// Whether a notification belongs to a session other than the active
+    /// one; such notifications are logged and dropped.
+    async fn is_stale_notification(&self, params: &Value) -> bool {
+        let incoming_session_id = extract_session_id(params);
+        let active_session_id = self.current_session_id.read().await.clone();
+        if is_stale_session(incoming_session_id, active_session_id.as_deref()) {
+            log::warn!(
+                "{}",
+                stale_notification_message(incoming_session_id, active_session_id.as_deref())
+            );
+            return true;
+        }
+        false
+    }
+
+    /// Handle an `event.transcription_complete` notification.
+    pub(super) async fn handle_transcription_complete(&self, params: Value) {
+        if self.is_stale_notification(&params).await {
+            return;
+        }
+        self.deliver_transcription_complete(params).await;
+    }
+
+    /// Handle an `event.utterance_complete` notification.
+    pub(super) async fn handle_utterance_complete(&self, params: Value) {
+        if self.is_stale_notification(&params).await {
+            return;
+        }
+        self.deliver_utterance_complete(params).await;
+    }
+
+    /// Handle an `event.transcription_error` notification, retrying the
+    /// transcription once when the error is transient.
+    pub(super) async fn handle_transcription_error(&self, params: Value) {
+        if self.is_stale_notification(&params).await {
+            return;
+        }
+
+        #[derive(Deserialize)]
+        struct ErrorParams {
+            session_id: String,
+            kind: String,
+            message: String,
+        }
+
+        if let Ok(params) = serde_json::from_value::<ErrorParams>(params) {
+            let error = format!("{}: {}", params.kind, params.message);
+            if self
+                .claim_transcription_retry(&params.session_id, &params.kind)
+                .await
+            {
+                let session = self.clone();
+                tokio::spawn(async move {
+                    if !session
+                        .request_transcription_retry(&params.session_id)
+                        .await
+                    {
+                        session
+                            .recording_controller
+                            .on_transcription_error(params.session_id, error)
+                            .await;
+                    }
+                });
+                return;
+            }
+
+            self.recording_controller
+                .on_transcription_error(params.session_id, error)
+                .await;
+        }
+    }
+
+    /// Hand a `transcription_complete` payload to the recording controller.
+    async fn deliver_transcription_complete(&self, params: Value) {
+        // Parse transcription result
+        #[derive(Deserialize)]
+        struct TranscriptionParams {
+            session_id: String,
+            text: String,
+            duration_ms: u64,
+            #[serde(default)]
+            confidence: Option<f64>,
+            #[serde(default)]
+            language: Option<String>,
+            #[serde(default)]
+            raw_text: Option<String>,
+            #[serde(default)]
+            final_text: Option<String>,
+            #[serde(default)]
+            segments: Vec<TranscriptSegment>,
+            #[serde(default)]
+            words: Vec<TranscriptWord>,
+        }
+
+        if let Ok(params) = serde_json::from_value::<TranscriptionParams>(params) {
+            let (raw_text, final_text) = resolve_transcript_texts(
+                &params.text,
+                params.raw_text.as_deref(),
+                params.final_text.as_deref(),
+            );
+            let (audio_duration_ms, processing_duration_ms) = {
+                let mut stop_audio_duration_ms = None;
+                let mut ctx = self.recording_context.write().await;
+                if let Some(ctx) = ctx.as_mut() {
+                    if ctx.session_id == params.session_id {
+                        ctx.timing_marks.t2_transcription_received = Some(Instant::now());
+                        stop_audio_duration_ms = ctx.audio_duration_ms;
+                        ctx.raw_text = Some(raw_text.clone());
+                        ctx.final_text = Some(final_text.clone());
+                        ctx.language = params
+                            .language
+                            .as_deref()
+                            .map(str::trim)
+                            .filter(|value| !value.is_empty())
+                            .map(ToString::to_string);
+                        ctx.confidence = params.confidence.map(|v| v as f32);
+                        ctx.segments = params.segments.clone();
+                        ctx.words = params.words.clone();
+                    }
+                }
+                map_transcription_complete_durations(params.duration_ms, stop_audio_duration_ms)
+            };
+
+            let result = TranscriptionResult {
+                session_id: params.session_id,
+                // Sidecar text is authoritative and already post-processed.
+                text: final_text,
+                audio_duration_ms,
+                processing_duration_ms,
+            };
+
+            // Deliver to recording controller (validates session ID)
+            self.recording_controller
+                .on_transcription_result(result)
+                .await;
+        }
+    }
+
+    /// Hand an `utterance_complete` payload to the recording controller.
+    async fn deliver_utterance_complete(&self, params: Value) {
+        #[derive(Deserialize)]
+        struct UtteranceParams {
+            session_id: String,
+            segment_index: u32,
+            text: String,
+            duration_ms: u64,
+            #[serde(default)]
+            audio_duration_ms: u64,
+        }
+
+        if let Ok(params) = serde_json::from_value::<UtteranceParams>(params) {
+            // Delivered in segment order (validates session ID)
+            self.recording_controller
+                .on_utterance_result(UtteranceResult {
+                    session_id: params.session_id,
+                    segment_index: params.segment_index,
+                    text: params.text,
+                    audio_duration_ms: params.audio_duration_ms,
+                    processing_duration_ms: params.duration_ms,
+                })
+                .await;
+        }
+    }
+
+    /// Decide whether a failed transcription gets its one automatic retry.
+    ///
+    /// Only transient errors of the active session qualify. Claiming the
+    /// retry is recorded on the recording context and in the session journal.
+    pub(super) async fn claim_transcription_retry(&self, session_id: &str, kind: &str) -> bool {
+        if !is_transient_transcription_error(kind) {
+            return false;
+        }
+
+        let mut ctx = self.recording_context.write().await;
+        let Some(ctx) = ctx
+            .as_mut()
+            .filter(|ctx| ctx.session_id == session_id && ctx.transcription_retries == 0)
+        else {
+            return false;
+        };
+        ctx.transcription_retries += 1;
+        ctx.retry_requested_at = Some(Instant::now());
+
+        log::info!(
+            "Retrying transcription for session {} after transient error",
+            session_id
+        );
+        session_journal::record(
+            session_journal::global_journal(),
+            JournalEvent::TranscriptionRetried,
+            Some(session_id),
+            Some(json!({ "error_kind": canonical_transcription_error_kind(kind) })),
+        );
+        true
+    }
+
+    /// Ask the sidecar to transcribe a failed session again, waiting for it
+    /// to become reachable first.
+    ///
+    /// Returns false when the sidecar no longer has the session's audio,
+    /// predates `transcription.retry`, or stays unreachable.
+    async fn request_transcription_retry(&self, session_id: &str) -> bool {
+        let deadline = Instant::now() + TRANSCRIPTION_RETRY_RECOVERY_TIMEOUT;
+        loop {
+            let outcome = {
+                let client = self.rpc_client.read().await;
+                match client.as_ref() {
+                    Some(client) => Some(
+                        client
+                            .call::<TranscriptionRetry>(
+                                "transcription.retry",
+                                Some(json!({ "session_id": session_id })),
+                            )
+                            .await,
+                    ),
+                    None => None,
+                }
+            };
+
+            match outcome {
+                Some(Ok(retry)) => return retry.retrying,
+                Some(Err(RpcError::Remote { kind, .. })) if kind == "E_METHOD_NOT_FOUND" => {
+                    return false;
+                }
+                Some(Err(err)) => {
+                    log::debug!("Transcription retry not accepted yet: {}", err);
+                }
+                None => {}
+            }
+
+            if Instant::now() + TRANSCRIPTION_RETRY_POLL_INTERVAL >= deadline {
+                log::warn!(
+                    "Sidecar did not recover in time to retry transcription for session {}",
+                    session_id
+                );
+                return false;
+            }
+            tokio::time::sleep(TRANSCRIPTION_RETRY_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Ask the sidecar what became of a session's transcription.
+    ///
+    /// Returns `None` when the sidecar is not connected, predates
+    /// `transcription.get_result`, or the call fails.
+    async fn lookup_transcription_result(&self, session_id: &str) -> Option<TranscriptionLookup> {
+        let client = self.rpc_client.read().await;
+        let lookup: Result<TranscriptionLookup, RpcError> = client
+            .as_ref()?
+            .call(
+                "transcription.get_result",
+                Some(json!({ "session_id": session_id })),
+            )
+            .await;
+        match lookup {
+            Ok(lookup) => Some(lookup),
+            Err(RpcError::Remote { kind, .. }) if kind == "E_METHOD_NOT_FOUND" => None,
+            Err(err) => {
+                log::warn!("Failed to look up transcription result: {}", err);
+                None
+            }
+        }
+    }
+
+    /// Hand the audio recorded so far to the sidecar as the next utterance
+    /// and keep recording. The part arrives through the utterance path, so
+    /// it is injected and added to history like a continuous-dictation
+    /// utterance. Stops the recording when the sidecar cannot split.
+    async fn split_at_max_duration(&self) {
+        let Some((session_id, duration_ms)) =
+            self.recording_controller.split_at_max_duration().await
+        else {
+            return;
+        };
+
+        let split_result: Result<Value, RpcError> = match self.rpc_client.read().await.as_ref() {
+            Some(client) => {
+                client
+                    .call("recording.split", Some(json!({ "session_id": session_id })))
+                    .await
+            }
+            None => Err(RpcError::Disconnected),
+        };
+        match split_result {
+            Ok(result) => {
+                log::info!(
+                    "Max recording duration reached; split segment {} of session {}",
+                    result
+                        .get("segment_index")
+                        .and_then(Value::as_u64)
+                        .unwrap_or_default(),
+                    session_id
+                );
+            }
+            Err(err) => {
+                log::warn!(
+                    "recording.split failed ({}); stopping recording at max duration",
+                    err
+                );
+                if let Some(stop_result) = self
+                    .recording_controller
+                    .stop_at_max_duration(session_id, duration_ms)
+                    .await
+                {
+                    if let Err(err) = self.complete_stop(stop_result).await {
+                        log::warn!("Failed to complete max-duration stop flow: {}", err);
+                    }
+                }
+            }
+        }
+    }
+
+    async fn complete_stop(&self, result: StopResult) -> Result<(), IntegrationError> {
+        if embedded_asr::is_active() {
+            return self.complete_embedded_stop(result).await;
+        }
+
+        let stop_rpc_method = stop_rpc_method_for_result(&result);
+        let too_short = matches!(result, StopResult::TooShort);
+
+        // Tell sidecar to stop/cancel recording.
+        if let Some(client) = self.rpc_client.read().await.as_ref() {
+            let stop_called_at = Instant::now();
+            let session_id = {
+                let mut ctx = self.recording_context.write().await;
+                if let Some(ctx) = ctx.as_mut() {
+                    if !too_short {
+                        ctx.timing_marks.t0_stop_called = Some(stop_called_at);
+                    }
+                    Some(ctx.session_id.clone())
+                } else {
+                    None
+                }
+            };
+
+            let session_id = session_id.ok_or_else(|| {
+                IntegrationError::Recording("No recording context for active session".to_string())
+            })?;
+            let params = json!({
+                "session_id": session_id
+            });
+            if stop_rpc_method == "recording.stop" {
+                #[derive(Deserialize)]
+                struct StopResultPayload {
+                    audio_duration_ms: u64,
+                }
+
+                let stop_result: Result<StopResultPayload, _> =
+                    client.call(stop_rpc_method, Some(params)).await;
+
+                let stop_rpc_returned_at = Instant::now();
+                let mut ctx = self.recording_context.write().await;
+                if let Some(ctx) = ctx.as_mut() {
+                    if ctx.session_id == session_id {
+                        ctx.timing_marks.t1_stop_rpc_returned = Some(stop_rpc_returned_at);
+                        if let Ok(stop_payload) = &stop_result {
+                            ctx.audio_duration_ms = Some(stop_payload.audio_duration_ms);
+                        }
+                    }
+                }
+                if let Err(err) = stop_result {
+                    return Err(IntegrationError::rpc(
+                        format!("call {} RPC", stop_rpc_method),
+                        err,
+                    ));
+                }
+            } else {
+                let cancel_result: Result<Value, _> =
+                    client.call(stop_rpc_method, Some(params)).await;
+                if let Err(err) = cancel_result {
+                    return Err(IntegrationError::rpc(
+                        format!("call {} RPC", stop_rpc_method),
+                        err,
+                    ));
+                }
+            }
+        } else if !too_short {
+            return Err(IntegrationError::SidecarNotConnected);
+        }
+
+        // Too-short recordings don't produce transcription and should clear session context.
+        if too_short {
+            *self.current_session_id.write().await = None;
+            *self.recording_context.write().await = None;
+        }
+
+        Ok(())
+    }
+
+    /// Stop the embedded engine's capture and transcribe it in process.
+    async fn complete_embedded_stop(&self, result: StopResult) -> Result<(), IntegrationError> {
+        if matches!(result, StopResult::TooShort) {
+            embedded_asr::cancel_capture();
+            *self.current_session_id.write().await = None;
+            *self.recording_context.write().await = None;
+            return Ok(());
+        }
+
+        let session_id = {
+            let mut ctx = self.recording_context.write().await;
+            let ctx = ctx.as_mut().ok_or_else(|| {
+                IntegrationError::Recording("No recording context for active session".to_string())
+            })?;
+            ctx.timing_marks.t0_stop_called = Some(Instant::now());
+            ctx.session_id.clone()
+        };
+
+        let capture_session_id = session_id.clone();
+        let audio =
+            tokio::task::spawn_blocking(move || embedded_asr::stop_capture(&capture_session_id))
+                .await
+                .map_err(|err| {
+                    IntegrationError::Recording(format!("Embedded capture task failed: {}", err))
+                })?
+                .map_err(|err| {
+                    IntegrationError::Recording(format!("Failed to stop recording: {}", err))
+                })?;
+        let duration_ms = embedded_asr::duration_ms(&audio);
+        if let Some(ctx) = self.recording_context.write().await.as_mut() {
+            if ctx.session_id == session_id {
+                ctx.timing_marks.t1_stop_rpc_returned = Some(Instant::now());
+                ctx.audio_duration_ms = Some(duration_ms);
+            }
+        }
+
+        let language = config::load_config().model.and_then(|model| model.language);
+        let session = self.clone();
+        tokio::spawn(async move {
+            match tokio::task::spawn_blocking(move || {
+                embedded_asr::transcribe(
+                    &audio,
+                    embedded_asr::whisper_language(language.as_deref()),
+                )
+            })
+            .await
+            {
+                Ok(Ok(text)) => {
+                    session
+                        .deliver_transcription_complete(json!({
+                            "session_id": session_id,
+                            "text": text,
+                            "duration_ms": duration_ms,
+                        }))
+                        .await;
+                }
+                Ok(Err(err)) => {
+                    session
+                        .recording_controller
+                        .on_transcription_error(session_id, err.to_string())
+                        .await;
+                }
+                Err(err) => {
+                    session
+                        .recording_controller
+                        .on_transcription_error(
+                            session_id,
+                            format!("Embedded transcription task failed: {}", err),
+                        )
+                        .await;
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Start state change event loop (for tray updates).
+    pub(super) fn start_state_loop(&self) {
+        let state_manager = Arc::clone(&self.state_manager);
+        let app_handle = self.app_handle.clone();
+        let event_seq = Arc::clone(&self.event_seq);
+
+        tokio::spawn(async move {
+            let mut receiver = state_manager.subscribe();
+
+            log::info!("State event loop started");
+
+            while let Ok(event) = receiver.recv().await {
+                log::debug!("State changed: {:?}", event.state);
+
+                // Emit tray update
+                if let Some(ref handle) = app_handle {
+                    let icon = match event.state {
+                        AppState::Idle => "tray-idle",
+                        AppState::Recording => "tray-recording",
+                        AppState::Transcribing => "tray-transcribing",
+                        AppState::LoadingModel => "tray-loading",
+                        AppState::Error => "tray-error",
+                    };
+
+                    emit_with_shared_seq(
+                        handle,
+                        &[EVENT_TRAY_UPDATE],
+                        json!({
+                            "icon": icon,
+                            "state": event.state,
+                            "enabled": event.enabled,
+                            "level": event.level,
+                            "detail": event.detail,
+                        }),
+                        &event_seq,
+                    );
+
+                    emit_with_shared_seq(
+                        handle,
+                        &[EVENT_STATE_CHANGED],
+                        state_changed_event_payload(&event),
+                        &event_seq,
+                    );
+                }
+            }
+
+            log::info!("State event loop ended");
+        });
+    }
+
+    /// Start idle auto-disable loop.
+    ///
+    /// Pauses hotkey listening after `hotkeys.idle_auto_disable_minutes`
+    /// without a dictation so a forgotten, armed tool does not record by
+    /// accident. The next hotkey press re-enables it (see `start_hotkey_loop`).
+    pub(super) fn start_idle_auto_disable_loop(&self) {
+        let state_manager = Arc::clone(&self.state_manager);
+        let idle_activity = Arc::clone(&self.idle_activity);
+        let app_handle = self.app_handle.clone();
+        let event_seq = Arc::clone(&self.event_seq);
+
+        tokio::spawn(async move {
+            let mut tick = tokio::time::interval(IDLE_AUTO_DISABLE_CHECK_INTERVAL);
+            tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+            loop {
+                tick.tick().await;
+
+                let idle_minutes = config::load_config().hotkeys.idle_auto_disable_minutes;
+                let idle_limit = Duration::from_secs(u64::from(idle_minutes) * 60);
+                let should_disable = idle_activity.lock().await.check_idle(
+                    Instant::now(),
+                    idle_limit,
+                    state_manager.is_enabled(),
+                    state_manager.get(),
+                );
+                if !should_disable {
+                    continue;
+                }
+
+                log::info!(
+                    "No dictation for {} minutes; pausing hotkey listening until the next hotkey press",
+                    idle_minutes
+                );
+                state_manager.set_level(EnableLevel::HotkeysPaused);
+                if let Some(ref handle) = app_handle {
+                    emit_with_shared_seq(
+                        handle,
+                        &[EVENT_TRAY_UPDATE],
+                        json!({ "reason": "idle_auto_disabled" }),
+                        &event_seq,
+                    );
+                }
+            }
+        });
+    }
+
+    /// Forward injection queue depth changes to the frontend.
+    pub(super) fn start_injection_queue_loop(&self) {
+        let Some(handle) = self.app_handle.clone() else {
+            return;
+        };
+        let event_seq = Arc::clone(&self.event_seq);
+        let mut depth = injection_order::global_dispatcher().subscribe_depth();
+
+        tokio::spawn(async move {
+            while depth.changed().await.is_ok() {
+                let current = *depth.borrow_and_update();
+                emit_with_shared_seq(
+                    &handle,
+                    &[EVENT_INJECTION_QUEUE_CHANGED],
+                    injection_queue_changed_payload(current),
+                    &event_seq,
+                );
+            }
+        });
+    }
+
+    /// Start recording event loop (for transcription results).
+    pub(super) fn start_recording_event_loop(&self) {
+        let recording_controller = Arc::clone(&self.recording_controller);
+        let state_manager = Arc::clone(&self.state_manager);
+        let recording_context = Arc::clone(&self.recording_context);
+        let current_session_id = Arc::clone(&self.current_session_id);
+        let active_session = Arc::clone(&self.active_session);
+        let app_handle = self.app_handle.clone();
+        let event_seq = Arc::clone(&self.event_seq);
+        let overlay = self.overlay.clone();
+
+        tokio::spawn(async move {
+            let mut receiver = recording_controller.subscribe();
+            // Screen share policy in force for the current recording.
+            let mut share_policy: Option<ScreenSharePolicy> = None;
+            // Unfinished sentences of live continuous sessions.
+            let mut sentence_carry = SentenceCarry::default();
+
+            log::info!("Recording event loop started");
+
+            while let Ok(event) = receiver.recv().await {
+                if let RecordingEvent::Started { session_id, .. } = &event {
+                    let app_config = config::load_config();
+                    share_policy = screen_share::enforced_policy(app_config.ui.screen_share_policy);
+                    if share_policy.is_some() {
+                        log::warn!("Recording started while the screen is shared");
+                    }
+                    if app_config.audio.continuous_dictation && app_config.audio.live_flush_secs > 0
+                    {
+                        sentence_carry.begin(session_id);
+                    }
+                }
+                if let Some(cue) = recording_event_audio_cue(&event) {
+                    play_lifecycle_audio_cue(&state_manager, cue);
+                }
+                if let Some((journal_event, session_id, detail)) =
+                    recording_event_journal_record(&event)
+                {
+                    session_journal::record(
+                        session_journal::global_journal(),
+                        journal_event,
+                        session_id,
+                        detail,
+                    );
+                }
+                update_injection_order(&event);
+                sentence_carry.discard_ended(&event);
+                let next_snapshot =
+                    apply_recording_event(active_session.read().await.as_ref(), &event);
+                if let Some(snapshot) = next_snapshot {
+                    publish_session_snapshot(
+                        &active_session,
+                        app_handle.as_ref(),
+                        &event_seq,
+                        snapshot,
+                    )
+                    .await;
+                }
+
+                // Drive overlay show/hide based on recording lifecycle.
+                let overlay_forced = share_policy == Some(ScreenSharePolicy::ShowOverlay);
+                let overlay_enabled = overlay.is_enabled() || overlay_forced;
+                let recording_active = overlay_recording_state_for_event(overlay_enabled, &event)
+                    // Stealth never shows the overlay, but still hides one shown before.
+                    .filter(|active| {
+                        !active || overlay_forced || state_manager.level().shows_indicators()
+                    });
+                if let Some(recording_active) = recording_active {
+                    let hide_delay = overlay_hide_delay_for_event(&event);
+                    if !recording_active && !hide_delay.is_zero() {
+                        // Keep the overlay up while it plays the cancel animation.
+                        let overlay = overlay.clone();
+                        let state_manager = Arc::clone(&state_manager);
+                        tokio::spawn(async move {
+                            tokio::time::sleep(hide_delay).await;
+                            if state_manager.get() != AppState::Recording {
+                                overlay.apply_recording_state(false, false).await;
+                            }
+                        });
+                    } else {
+                        overlay
+                            .apply_recording_state(recording_active, overlay_forced)
+                            .await;
+                    }
+                }
+
+                let utterance = matches!(event, RecordingEvent::UtteranceComplete { .. });
+                match event {
+                    RecordingEvent::Started {
+                        session_id,
+                        timestamp,
+                    } => {
+                        if let Some(ref handle) = app_handle {
+                            emit_with_shared_seq(
+                                handle,
+                                &[EVENT_RECORDING_STATUS],
+                                recording_started_event_payload(
+                                    session_id.as_str(),
+                                    timestamp.to_rfc3339(),
+                                    share_policy,
+                                ),
+                                &event_seq,
+                            );
+                        }
+                    }
+                    RecordingEvent::Stopped {
+                        session_id,
+                        duration_ms,
+                        ..
+                    } => {
+                        if let Some(ref handle) = app_handle {
+                            emit_with_shared_seq(
+                                handle,
+                                &[EVENT_RECORDING_STATUS],
+                                recording_status_event_payload(
+                                    "transcribing",
+                                    Some(session_id.as_str()),
+                                    None,
+                                    Some(duration_ms),
+                                ),
+                                &event_seq,
+                            );
+                            if overlay_enabled {
+                                spawn_overlay_transcribing_ticker(
+                                    handle.clone(),
+                                    Arc::clone(&state_manager),
+                                    Arc::clone(&current_session_id),
+                                    Arc::clone(&event_seq),
+                                    session_id.clone(),
+                                    duration_ms,
+                                );
+                            }
+                        }
+                    }
+                    RecordingEvent::TooShort { .. } => {
+                        if let Some(ref handle) = app_handle {
+                            emit_with_shared_seq(
+                                handle,
+                                &[EVENT_RECORDING_STATUS],
+                                recording_status_event_payload("idle", None, None, None),
+                                &event_seq,
+                            );
+                        }
+                    }
+                    RecordingEvent::TranscriptionComplete {
+                        session_id,
+                        text: sidecar_text,
+                        audio_duration_ms,
+                        processing_duration_ms,
+                        ..
+                    }
+                    | RecordingEvent::UtteranceComplete {
+                        session_id,
+                        text: sidecar_text,
+                        audio_duration_ms,
+                        processing_duration_ms,
+                        ..
+                    } => {
+                        // An utterance leaves the session recording.
+                        if let Some(handle) = app_handle.as_ref().filter(|_| !utterance) {
+                            emit_with_shared_seq(
+                                handle,
+                                &[EVENT_RECORDING_STATUS],
+                                recording_status_event_payload(
+                                    "idle",
+                                    Some(session_id.as_str()),
+                                    None,
+                                    None,
+                                ),
+                                &event_seq,
+                            );
+                        }
+
+                        // Snapshot focus context and timing marks for this session.
+                        let (
+                            focus_before,
+                            mut timing_marks,
+                            mut raw_text,
+                            mut final_text,
+                            mut language,
+                            mut confidence,
+                            mut segments,
+                            mut words,
+                            auto_punctuation,
+                            force_clipboard_only,
+                            force_clipboard_reason,
+                            mut transcription_retries,
+                        ) = {
+                            let ctx = recording_context.read().await;
+                            if let Some(ctx) = ctx.as_ref() {
+                                (
+                                    Some(ctx.focus_before.clone()),
+                                    ctx.timing_marks.clone(),
+                                    ctx.raw_text.clone(),
+                                    ctx.final_text.clone(),
+                                    ctx.language.clone(),
+                                    ctx.confidence,
+                                    ctx.segments.clone(),
+                                    ctx.words.clone(),
+                                    Some(ctx.auto_punctuation),
+                                    ctx.force_clipboard_only,
+                                    ctx.force_clipboard_reason.clone(),
+                                    ctx.transcription_retries,
+                                )
+                            } else {
+                                (
+                                    None,
+                                    PipelineTimingMarks::default(),
+                                    None,
+                                    None,
+                                    None,
+                                    None,
+                                    Vec::new(),
+                                    Vec::new(),
+                                    None,
+                                    false,
+                                    None,
+                                    0,
+                                )
+                            }
+                        };
+                        if utterance {
+                            // The context describes the whole session; an
+                            // utterance carries only its own text.
+                            timing_marks = PipelineTimingMarks::default();
+                            (raw_text, final_text, language, confidence) = (None, None, None, None);
+                            segments.clear();
+                            words.clear();
+                            transcription_retries = 0;
+                        }
+                        // Live sessions inject only whole sentences of an
+                        // utterance; the tail goes out with the next one.
+                        // Without punctuation there are no boundaries to
+                        // wait for.
+                        let sidecar_text = if utterance && auto_punctuation != Some(false) {
+                            sentence_carry.release(&session_id, &sidecar_text)
+                        } else {
+                            sidecar_text
+                        };
+                        if !utterance {
+                            if let Some(held) = sentence_carry.take_held(&session_id) {
+                                final_text = Some(live_flush::join(
+                                    &held,
+                                    final_text.as_deref().unwrap_or(&sidecar_text),
+                                ));
+                                raw_text = raw_text.map(|raw| live_flush::join(&held, &raw));
+                            }
+                        }
+                        // The snapshot is taken; clear the context once the
+                        // session's final result is in, unless a newer session
+                        // has already replaced it.
+                        if !utterance {
+                            let mut ctx = recording_context.write().await;
+                            if ctx.as_ref().is_some_and(|ctx| ctx.session_id == session_id) {
+                                *ctx = None;
+                            }
+                            drop(ctx);
+                            let mut current = current_session_id.write().await;
+                            if current.as_deref() == Some(session_id.as_str()) {
+                                *current = None;
+                            }
+                        }
+
+                        // Inject off the event loop so a result can wait for
+                        // sessions that started earlier without blocking the
+                        // events that complete them.
+                        let app_handle = app_handle.clone();
+                        let event_seq = Arc::clone(&event_seq);
+                        tokio::spawn(async move {
+                            let (mut raw_text, final_text) = resolve_transcript_texts(
+                                &sidecar_text,
+                                raw_text.as_deref(),
+                                final_text.as_deref(),
+                            );
+                            log::info!(
+                                "Transcription complete: session={}, text_len={}, text_sha256_prefix={}, audio={}ms, processing={}ms",
+                                session_id,
+                                final_text.len(),
+                                sha256_prefix(&final_text),
+                                audio_duration_ms,
+                                processing_duration_ms
+                            );
+
+                            if log::log_enabled!(log::Level::Debug) {
+                                log::debug!("Transcription text (debug): {}", final_text);
+                            }
+                            if timing_marks.t2_transcription_received.is_none() {
+                                timing_marks.t2_transcription_received = Some(Instant::now());
+                            }
+                            let expected_focus = focus_before.as_ref();
+
+                            // Sidecar output is already fully transformed (normalize/macros/replacements).
+                            // Never apply replacements again on the Rust side.
+                            if final_text.trim().is_empty() {
+                                log::info!("Empty transcription, skipping injection");
+                                if !utterance {
+                                    injection_order::global_dispatcher().release(&session_id);
+                                }
+                                return;
+                            }
+
+                            // Wait for sessions that started earlier, and for
+                            // any injection in progress, so text never interleaves.
+                            let mut turn = injection_order::global_dispatcher()
+                                .acquire(Some(&session_id), !utterance)
+                                .await;
+
+                            // Load injection config
+                            let config = config::load_config();
+                            let injection_config = InjectionConfig::from(&config.injection);
+
+                            // An utterance that is exactly a shortcut phrase is
+                            // replaced by the shortcut's value below.
+                            let shortcut = crate::phrase_shortcuts::expand(&final_text);
+
+                            // Learn capitalization from replacement hits, then apply learned spellings.
+                            let final_text = if config.vocabulary.learn_capitalization {
+                                let dictionary = crate::learned_dictionary::global_dictionary();
+                                crate::learned_dictionary::learn(
+                                    dictionary,
+                                    &raw_text,
+                                    &final_text,
+                                );
+                                dictionary.apply(&final_text)
+                            } else {
+                                final_text
+                            };
+
+                            // Filter before the text is injected or stored in history.
+                            let mut unfiltered_text = None;
+                            let final_text = if crate::word_filter::filter_enabled_for_focus(
+                                &config,
+                                expected_focus,
+                            ) {
+                                let filter = &config.word_filter;
+                                let filtered = crate::word_filter::apply(filter, &final_text);
+                                if filtered != final_text && filter.keep_unfiltered {
+                                    unfiltered_text = Some(final_text.clone());
+                                }
+                                raw_text = crate::word_filter::apply(filter, &raw_text);
+                                crate::word_filter::apply_to_segments(filter, &mut segments);
+                                crate::word_filter::apply_to_words(filter, &mut words);
+                                filtered
+                            } else {
+                                final_text
+                            };
+
+                            // Secret values are injected but history keeps the
+                            // spoken phrase.
+                            let mut history_text = None;
+                            let final_text = match shortcut {
+                                Some(expansion) => {
+                                    log::info!(
+                                        "Phrase shortcut matched: id={}, secret={}",
+                                        expansion.shortcut_id,
+                                        expansion.secret
+                                    );
+                                    if expansion.secret {
+                                        history_text = Some(final_text);
+                                    }
+                                    expansion.text
+                                }
+                                None => final_text,
+                            };
+
+                            timing_marks.t3_postprocess_completed = Some(Instant::now());
+
+                            let window_context = expected_focus.and_then(|focus| {
+                                crate::focus::history_window_context(&config.history, focus)
+                            });
+                            let (window_app_name, window_title) = match window_context {
+                                Some((app_name, window_title)) => (Some(app_name), window_title),
+                                None => (None, None),
+                            };
+                            let injection_target = expected_focus
+                                .filter(|_| config.history.capture_window_context)
+                                .and_then(crate::focus::InjectionTarget::from_signature);
+                            if let (Some(target), Some(focus)) = (&injection_target, expected_focus)
+                            {
+                                crate::app_icon::remember_executable(&target.app_id, focus.pid);
+                            }
+
+                            let injection_mode = InjectionMode::parse(&config.injection.mode)
+                                .unwrap_or(InjectionMode::Inject);
+                            let injection_guard = if injection_mode == InjectionMode::Inject
+                                && !force_clipboard_only
+                            {
+                                InjectionGuard::check(
+                                    &config.injection,
+                                    &final_text,
+                                    audio_duration_ms,
+                                )
+                            } else {
+                                None
+                            };
+                            let clipboard_only_reason =
+                                if force_clipboard_only {
+                                    Some(force_clipboard_reason.unwrap_or_else(|| {
+                                        DEVICE_REMOVED_CLIPBOARD_REASON.to_string()
+                                    }))
+                                } else {
+                                    match injection_mode {
+                                        InjectionMode::Inject => injection_guard
+                                            .as_ref()
+                                            .map(InjectionGuard::clipboard_reason),
+                                        InjectionMode::ClipboardOnly => {
+                                            Some(GLOBAL_CLIPBOARD_ONLY_REASON.to_string())
+                                        }
+                                        InjectionMode::Ask => {
+                                            Some(ASK_MODE_CLIPBOARD_REASON.to_string())
+                                        }
+                                    }
+                                };
+
+                            // Wait for a busy target app instead of typing into it.
+                            // The turn is lent out meanwhile so the user can insert
+                            // the queued transcript by hand.
+                            let entry_id = Uuid::new_v4();
+                            let clipboard_only_reason = match clipboard_only_reason {
+                                Some(reason) => Some(reason),
+                                None => {
+                                    turn.yield_during(defer_while_target_busy(
+                                        app_handle.as_ref(),
+                                        &event_seq,
+                                        &session_id,
+                                        entry_id,
+                                        history_text.as_deref().unwrap_or(&final_text),
+                                        Duration::from_secs(u64::from(
+                                            config.injection.busy_target_wait_secs,
+                                        )),
+                                    ))
+                                    .await
+                                }
+                            };
+
+                            let mut result = if let Some(forced_reason) = clipboard_only_reason {
+                                let text_with_suffix =
+                                    format!("{}{}", final_text, injection_config.suffix);
+                                let fallback_reason =
+                                    match crate::injection::set_clipboard_public(&text_with_suffix) {
+                                        Ok(()) => forced_reason,
+                                        Err(clipboard_error) => format!(
+                                            "{}; clipboard fallback failed: {}; transcript preserved in history",
+                                            forced_reason, clipboard_error
+                                        ),
+                                    };
+
+                                InjectionResult::ClipboardOnly {
+                                    reason: fallback_reason,
+                                    text_length: final_text.len(),
+                                    timestamp: chrono::Utc::now(),
+                                }
+                            } else {
+                                let progress_handle = app_handle.clone();
+                                let progress_seq = Arc::clone(&event_seq);
+                                let on_progress = move |progress: InjectionProgress| {
+                                    if let Some(ref handle) = progress_handle {
+                                        emit_with_shared_seq(
+                                            handle,
+                                            &[EVENT_INJECTION_PROGRESS],
+                                            injection_progress_event_payload(&progress),
+                                            &progress_seq,
+                                        );
+                                    }
+                                };
+                                inject_text_with_progress(
+                                    &final_text,
+                                    expected_focus,
+                                    &injection_config,
+                                    &on_progress,
+                                )
+                                .await
+                            };
+                            timing_marks.t4_injection_completed = Some(Instant::now());
+                            let paste_verification = result.paste_verification();
+
+                            let pipeline_timings = pipeline_timings_from_marks(&timing_marks);
+                            if let Some(timings) = pipeline_timings.as_ref() {
+                                log_pipeline_timings(timings);
+                            }
+
+                            let mut injection_app_error: Option<AppError> = None;
+                            if let InjectionResult::Failed { error, .. } = result.clone() {
+                                let text_with_suffix =
+                                    format!("{}{}", final_text, injection_config.suffix);
+                                let fallback_reason =
+                                    match crate::injection::set_clipboard_public(&text_with_suffix) {
+                                        Ok(()) => format!(
+                                            "{}; transcript copied to clipboard for manual paste",
+                                            error
+                                        ),
+                                    Err(clipboard_error) => format!(
+                                        "{}; clipboard fallback failed: {}; transcript preserved in history",
+                                        error, clipboard_error
+                                    ),
+                                };
+
+                                result = InjectionResult::ClipboardOnly {
+                                    reason: fallback_reason.clone(),
+                                    text_length: final_text.len(),
+                                    timestamp: chrono::Utc::now(),
+                                };
+                                injection_app_error = Some(injection_failure_app_error(
+                                    &fallback_reason,
+                                    final_text.len(),
+                                ));
+                            }
+
+                            match &result {
+                                InjectionResult::Injected { text_length, .. } => {
+                                    log::info!("Text injected: {} chars", text_length);
+                                }
+                                InjectionResult::ClipboardOnly {
+                                    reason,
+                                    text_length,
+                                    ..
+                                } => {
+                                    log::info!("Clipboard-only mode: {}", reason);
+                                    if clipboard_only_requires_app_error(reason)
+                                        && injection_app_error.is_none()
+                                    {
+                                        injection_app_error =
+                                            Some(injection_failure_app_error(reason, *text_length));
+                                    }
+                                }
+                                InjectionResult::Failed { error, .. } => {
+                                    log::error!("Injection failed: {}", error);
+                                }
+                            }
+
+                            // Add to history and emit a shared transcript payload.
+                            let final_text = history_text.unwrap_or(final_text);
+                            let mut transcript_entry = TranscriptEntry::new(
+                                final_text.clone(),
+                                audio_duration_ms as u32,
+                                processing_duration_ms as u32,
+                                HistoryInjectionResult::from_injection_result(&result),
+                            )
+                            .with_session_id(Uuid::parse_str(&session_id).ok())
+                            .with_asr_metadata(language, confidence)
+                            .with_segments(segments)
+                            .with_words(words)
+                            .with_unfiltered_text(unfiltered_text)
+                            .with_auto_punctuation(auto_punctuation)
+                            .with_window_context(window_app_name, window_title)
+                            .with_injection_target(injection_target)
+                            .with_paste_verification(paste_verification)
+                            .with_transcription_retries(transcription_retries);
+                            // Keep the id the deferral events and pending insert used.
+                            transcript_entry.id = entry_id;
+                            transcript_entry.raw_text = raw_text;
+                            transcript_entry.final_text = final_text.clone();
+                            transcript_entry.text = final_text;
+                            if let Some(timings) = pipeline_timings.clone() {
+                                transcript_entry = transcript_entry.with_timings(timings);
+                            }
+
+                            session_journal::record(
+                                session_journal::global_journal(),
+                                JournalEvent::Injected,
+                                Some(session_id.as_str()),
+                                Some(injection_result_journal_detail(
+                                    &result,
+                                    transcript_entry.id,
+                                )),
+                            );
+
+                            // Add to history
+                            if let Some(ref handle) = app_handle {
+                                let history = handle.state::<TranscriptHistory>();
+                                transcript_entry =
+                                    transcript_entry.with_label(history.active_label());
+                                history.push(transcript_entry.clone());
+                                if let Some(reason) = pending_insert_reason(&result) {
+                                    let queue = handle.state::<PendingInsertQueue>();
+                                    queue.push(PendingInsert::new(
+                                        transcript_entry.id,
+                                        transcript_entry.final_text.clone(),
+                                        reason.to_string(),
+                                    ));
+                                    let payload =
+                                        PendingInsertsChangedPayload { count: queue.len() };
+                                    emit_with_shared_seq(
+                                        handle,
+                                        &[EVENT_PENDING_INSERTS_CHANGED],
+                                        json!(payload),
+                                        &event_seq,
+                                    );
+                                }
+                                emit_with_shared_seq(
+                                    handle,
+                                    &[EVENT_TRAY_UPDATE],
+                                    json!({
+                                        "reason": "history_changed",
+                                        "entry_id": transcript_entry.id,
+                                    }),
+                                    &event_seq,
+                                );
+                            }
+
+                            // Emit canonical transcript event payload with shared seq.
+                            if let Some(ref handle) = app_handle {
+                                emit_with_shared_seq(
+                                    handle,
+                                    &[EVENT_TRANSCRIPT_COMPLETE],
+                                    transcript_complete_event_payload(&transcript_entry),
+                                    &event_seq,
+                                );
+                                if let Some(app_error) = injection_app_error {
+                                    emit_with_shared_seq(
+                                        handle,
+                                        &[EVENT_APP_ERROR],
+                                        app_error_event_payload(&app_error),
+                                        &event_seq,
+                                    );
+                                }
+                                if let Some(guard) = injection_guard {
+                                    log::info!(
+                                        "Injection held for confirmation: {}",
+                                        guard.as_str()
+                                    );
+                                    let payload = injection_confirm_requested_payload(
+                                        &transcript_entry,
+                                        Some(guard),
+                                        audio_duration_ms,
+                                    );
+                                    emit_with_shared_seq(
+                                        handle,
+                                        &[EVENT_INJECTION_CONFIRM_REQUESTED],
+                                        json!(payload),
+                                        &event_seq,
+                                    );
+                                } else if injection_mode == InjectionMode::Ask
+                                    && !force_clipboard_only
+                                {
+                                    let payload = injection_confirm_requested_payload(
+                                        &transcript_entry,
+                                        None,
+                                        audio_duration_ms,
+                                    );
+                                    emit_with_shared_seq(
+                                        handle,
+                                        &[EVENT_INJECTION_CONFIRM_REQUESTED],
+                                        json!(payload),
+                                        &event_seq,
+                                    );
+                                }
+                            }
+                        });
+                    }
+                    RecordingEvent::TranscriptionFailed {
+                        session_id, error, ..
+                    } => {
+                        if let Some(ref handle) = app_handle {
+                            emit_with_shared_seq(
+                                handle,
+                                &[EVENT_RECORDING_STATUS],
+                                recording_status_event_payload(
+                                    "idle",
+                                    Some(session_id.as_str()),
+                                    None,
+                                    None,
+                                ),
+                                &event_seq,
+                            );
+                        }
+
+                        log::error!(
+                            "Transcription failed: session={}, error_len={}, error_sha256_prefix={}",
+                            session_id,
+                            error.len(),
+                            sha256_prefix(&error),
+                        );
+                        if log::log_enabled!(log::Level::Debug) {
+                            log::debug!("Transcription error message (debug): {}", error);
+                        }
+
+                        if let Some(ref handle) = app_handle {
+                            let app_error =
+                                transcription_failure_app_error(&session_id, error.as_str());
+                            emit_with_shared_seq(
+                                handle,
+                                &[EVENT_TRANSCRIPT_ERROR],
+                                transcription_error_event_payload(&session_id, &app_error),
+                                &event_seq,
+                            );
+                            emit_with_shared_seq(
+                                handle,
+                                &[EVENT_APP_ERROR],
+                                app_error_event_payload(&app_error),
+                                &event_seq,
+                            );
+                        }
+
+                        // Clear context
+                        *recording_context.write().await = None;
+                        *current_session_id.write().await = None;
+                    }
+                    RecordingEvent::TranscriptionTimeout { session_id, .. } => {
+                        if let Some(ref handle) = app_handle {
+                            emit_with_shared_seq(
+                                handle,
+                                &[EVENT_RECORDING_STATUS],
+                                recording_status_event_payload(
+                                    "idle",
+                                    Some(session_id.as_str()),
+                                    None,
+                                    None,
+                                ),
+                                &event_seq,
+                            );
+                        }
+                        *recording_context.write().await = None;
+                        *current_session_id.write().await = None;
+                    }
+                    RecordingEvent::Cancelled {
+                        session_id, reason, ..
+                    } => {
+                        if let Some(ref handle) = app_handle {
+                            emit_with_shared_seq(
+                                handle,
+                                &[EVENT_RECORDING_STATUS],
+                                recording_status_event_payload("idle", None, None, None),
+                                &event_seq,
+                            );
+                            if overlay_enabled {
+                                emit_with_shared_seq(
+                                    handle,
+                                    &[EVENT_OVERLAY_CANCELLED],
+                                    overlay_cancelled_payload(&session_id, &reason),
+                                    &event_seq,
+                                );
+                            }
+                        }
+                        *recording_context.write().await = None;
+                        *current_session_id.write().await = None;
+                    }
+                    _ => {}
+                }
+            }
+
+            log::info!("Recording event loop ended");
+        });
+    }
+}
+
+/// Store `snapshot` as the current session and emit `session:changed`.
+/// Hold a transcript in the pending insert queue while the target app is not
+/// responding, for up to `max_wait`. Returns the clipboard-only reason to use
+/// instead of injecting when the app did not recover in time or the user
+/// dismissed the queued transcript.
+async fn defer_while_target_busy(
+    app_handle: Option<&AppHandle>,
+    event_seq: &Arc<AtomicU64>,
+    session_id: &str,
+    entry_id: Uuid,
+    text: &str,
+    max_wait: Duration,
+) -> Option<String> {
+    if max_wait.is_zero() || target_responsiveness::check().await != Responsiveness::Busy {
+        return None;
+    }
+    log::info!(
+        "Target app not responding; deferring injection for up to {} s",
+        max_wait.as_secs()
+    );
+    let emit = |event: &str, payload: Value| {
+        if let Some(handle) = app_handle {
+            emit_with_shared_seq(handle, &[event], payload, event_seq);
+        }
+    };
+    let queue = app_handle.map(|handle| handle.state::<PendingInsertQueue>());
+    if let Some(queue) = queue.as_deref() {
+        queue.push(PendingInsert::new(
+            entry_id,
+            text.to_string(),
+            BUSY_TARGET_PENDING_REASON.to_string(),
+        ));
+        emit(
+            EVENT_PENDING_INSERTS_CHANGED,
+            json!(PendingInsertsChangedPayload { count: queue.len() }),
+        );
+    }
+    emit(
+        EVENT_INJECTION_DEFERRED,
+        injection_deferred_payload(entry_id, session_id, "waiting", Duration::ZERO, max_wait),
+    );
+
+    // Inserting or dismissing the transcript from the queue ends the wait.
+    let started = Instant::now();
+    let outcome = target_responsiveness::wait_until_responsive(max_wait, || {
+        queue
+            .as_deref()
+            .is_none_or(|queue| queue.get(entry_id).is_some())
+    })
+    .await;
+    let waited = started.elapsed();
+
+    let (phase, clipboard_reason) = match outcome {
+        BusyWaitOutcome::Responsive => {
+            if let Some(queue) = queue.as_deref() {
+                queue.remove(entry_id);
+                emit(
+                    EVENT_PENDING_INSERTS_CHANGED,
+                    json!(PendingInsertsChangedPayload { count: queue.len() }),
+                );
+            }
+            ("resumed", None)
+        }
+        // Stays queued; the clipboard fallback updates its reason.
+        BusyWaitOutcome::Expired => ("expired", Some(busy_target_clipboard_reason(waited))),
+        BusyWaitOutcome::Abandoned => ("dismissed", Some(BUSY_TARGET_DISMISSED_REASON.to_string())),
+    };
+    log::info!(
+        "Deferred injection {} after {} ms",
+        phase,
+        waited.as_millis()
+    );
+    emit(
+        EVENT_INJECTION_DEFERRED,
+        injection_deferred_payload(entry_id, session_id, phase, waited, max_wait),
+    );
+    clipboard_reason
+}
+
+async fn publish_session_snapshot(
+    active_session: &RwLock<Option<SessionSnapshot>>,
+    app_handle: Option<&AppHandle>,
+    event_seq: &Arc<AtomicU64>,
+    snapshot: SessionSnapshot,
+) {
+    let payload = session_changed_payload(&snapshot);
+    *active_session.write().await = Some(snapshot);
+    if let Some(handle) = app_handle {
+        emit_with_shared_seq(handle, &[EVENT_SESSION_CHANGED], payload, event_seq);
+    }
+}
+
+fn sha256_prefix(input: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(input.as_bytes());
+    let digest = hasher.finalize();
+    let hex = format!("{:x}", digest);
+    hex[..8].to_string()
+}
+
+/// Reserve an injection turn when a session starts and give it up when the
+/// session ends without a transcript.
+fn update_injection_order(event: &RecordingEvent) {
+    let dispatcher = injection_order::global_dispatcher();
+    match event {
+        RecordingEvent::Started {
+            session_id,
+            timestamp,
+        } => dispatcher.reserve(session_id, *timestamp),
+        RecordingEvent::TooShort { session_id, .. }
+        | RecordingEvent::Cancelled { session_id, .. }
+        | RecordingEvent::TranscriptionFailed { session_id, .. }
+        | RecordingEvent::TranscriptionTimeout { session_id, .. } => dispatcher.release(session_id),
+        _ => {}
+    }
+}
+
+/// `phase` is "waiting" when the deferral starts, then "resumed", "expired",
+/// or "dismissed".
+fn injection_deferred_payload(
+    entry_id: Uuid,
+    session_id: &str,
+    phase: &str,
+    waited: Duration,
+    max_wait: Duration,
+) -> Value {
+    json!({
+        "entry_id": entry_id,
+        "session_id": session_id,
+        "phase": phase,
+        "waited_ms": waited.as_millis() as u64,
+        "max_wait_ms": max_wait.as_millis() as u64,
+    })
+}
+
+/// Emit `overlay:transcribing` at the overlay timer rate until the session
+/// leaves the Transcribing state.
+fn spawn_overlay_transcribing_ticker(
+    handle: AppHandle,
+    state_manager: Arc<AppStateManager>,
+    current_session_id: Arc<RwLock<Option<String>>>,
+    event_seq: Arc<AtomicU64>,
+    session_id: String,
+    audio_ms: u64,
+) {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        let mut tick = tokio::time::interval(OVERLAY_TRANSCRIBING_INTERVAL);
+        tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tick.tick().await;
+            let still_transcribing = state_manager.get() == AppState::Transcribing
+                && current_session_id.read().await.as_deref() == Some(session_id.as_str());
+            if !still_transcribing {
+                break;
+            }
+
+            let elapsed_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+            emit_with_shared_seq(
+                &handle,
+                &[EVENT_OVERLAY_TRANSCRIBING],
+                overlay_transcribing_payload(&session_id, elapsed_ms, audio_ms),
+                &event_seq,
+            );
+        }
+    });
+}
+
+fn log_pipeline_timings(timings: &TranscriptTimings) {
+    let fmt = |v: Option<u64>| match v {
+        Some(ms) => format!("{}ms", ms),
+        None => "n/a".to_string(),
+    };
+
+    log::info!(
+        "Pipeline: total={} (ipc={}, transcribe={}, postprocess={}, inject={})",
+        fmt(timings.total_ms),
+        fmt(timings.ipc_ms),
+        fmt(timings.transcribe_ms),
+        fmt(timings.postprocess_ms),
+        fmt(timings.inject_ms),
+    );
+}
+
+/// Response of the sidecar's `transcription.retry` method.
+#[derive(Debug, Deserialize)]
+struct TranscriptionRetry {
+    retrying: bool,
+}
@@ -0,0 +1,511 @@
+//! Sidecar health: the watchdog's ping and power event loops, recovery of a
+//! hung sidecar, revalidation after resume, and the loop that leaves
+//! recoverable Error states on its own.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+use tokio::sync::{broadcast, RwLock};
+
+use crate::config;
+use crate::error_recovery::{RecoveryCheck, RecoveryEvent, RecoveryPhase, RecoveryPolicy};
+use crate::ipc::{decode_result, RpcClient};
+use crate::power;
+use crate::recording::RecordingController;
+use crate::runtime::AppHandle;
+use crate::state::{AppStateManager, ErrorCategory, StateEvent};
+use crate::watchdog::{self, PingCallback, Watchdog, WatchdogConfig, WatchdogEvent};
+
+use super::{
+    emit_with_shared_seq, is_configured_device_available, recovery_check_passed, AudioListResult,
+    IntegrationManager, ModelStatus, EVENT_APP_RECOVERY, EVENT_TRAY_UPDATE,
+};
+
+/// Runs the watchdog against the live sidecar and recovers from the errors
+/// it and the other services report.
+#[derive(Clone)]
+pub(super) struct WatchdogService {
+    /// Sidecar health monitor, shared with the manager.
+    watchdog: Arc<Watchdog>,
+    /// Application state manager.
+    state_manager: Arc<AppStateManager>,
+    /// RPC client of the live sidecar (if connected).
+    rpc_client: Arc<RwLock<Option<RpcClient>>>,
+    /// Tauri app handle.
+    app_handle: Option<AppHandle>,
+    /// Monotonic event sequence counter shared with the other services.
+    event_seq: Arc<AtomicU64>,
+    /// Model status, revalidated after resume and recovery.
+    model_status: Arc<RwLock<ModelStatus>>,
+    /// Recording controller, told whether the model is ready.
+    recording_controller: Arc<RecordingController>,
+}
+
+impl WatchdogService {
+    pub(super) fn new(
+        watchdog: Arc<Watchdog>,
+        state_manager: Arc<AppStateManager>,
+        rpc_client: Arc<RwLock<Option<RpcClient>>>,
+        event_seq: Arc<AtomicU64>,
+        model_status: Arc<RwLock<ModelStatus>>,
+        recording_controller: Arc<RecordingController>,
+    ) -> Self {
+        Self {
+            watchdog,
+            state_manager,
+            rpc_client,
+            app_handle: None,
+            event_seq,
+            model_status,
+            recording_controller,
+        }
+    }
+
+    pub(super) fn set_app_handle(&mut self, handle: AppHandle) {
+        self.app_handle = Some(handle);
+    }
+
+    /// Apply changed watchdog timings from the `supervisor` settings and the
+    /// power profile without restarting the watchdog.
+    pub(super) fn apply_config(&self, supervisor: &config::SupervisorConfig) {
+        let watchdog_config =
+            WatchdogConfig::from_supervisor(supervisor).for_power_profile(power::current_profile());
+        if watchdog_config != self.watchdog.config() {
+            self.watchdog.reconfigure(watchdog_config);
+        }
+    }
+
+    /// Track the power source and apply the power profile's watchdog timings
+    /// when it changes.
+    pub(super) fn start_power_profile_loop(&self) {
+        let service = self.clone();
+
+        tokio::spawn(async move {
+            let mut tick = tokio::time::interval(power::POWER_POLL_INTERVAL);
+            tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+            loop {
+                tick.tick().await;
+                let config = config::load_config();
+                if power::refresh(&config.power).is_some() {
+                    service.apply_config(&config.supervisor);
+                }
+            }
+        });
+    }
+
+    /// Start the watchdog monitoring loop. Hung sidecars are restarted
+    /// through `manager`'s supervisor.
+    pub(super) fn start_loop(&self, manager: IntegrationManager) {
+        let rpc_client = Arc::clone(&self.rpc_client);
+        let watchdog = Arc::clone(&self.watchdog);
+        let state_manager = Arc::clone(&self.state_manager);
+        let recording_controller = Arc::clone(&self.recording_controller);
+        let model_status = Arc::clone(&self.model_status);
+        let app_handle = self.app_handle.clone();
+        let event_seq = Arc::clone(&self.event_seq);
+
+        // Create ping adapter
+        let pinger = Arc::new(RpcPinger {
+            rpc_client: Arc::clone(&rpc_client),
+        });
+
+        // Start the watchdog loop
+        watchdog.start_loop(pinger);
+
+        // Start platform power listener (if available) and feed events into watchdog.
+        if let Some(mut power_rx) = watchdog::platform::start_power_listener() {
+            let watchdog_for_power = Arc::clone(&watchdog);
+            tokio::spawn(async move {
+                log::info!("Power event listener started");
+                while let Some(event) = power_rx.recv().await {
+                    watchdog_for_power.on_power_event(event).await;
+                }
+                log::info!("Power event listener ended");
+            });
+        } else {
+            log::info!(
+                "Power event listener unavailable; watchdog will infer resume via loop-gap fallback"
+            );
+        }
+
+        // Start event handler loop
+        let watchdog_for_events = Arc::clone(&watchdog);
+        let mut event_rx = watchdog.subscribe();
+        tokio::spawn(async move {
+            log::info!("Watchdog event handler started");
+
+            while let Ok(event) = event_rx.recv().await {
+                match event {
+                    WatchdogEvent::HealthCheck { status } => {
+                        log::debug!("Watchdog health check: {:?}", status);
+                        // Could emit event to frontend for status display
+                    }
+                    WatchdogEvent::SidecarRecoveryRequested { reason } => {
+                        log::error!(
+                            "Watchdog requested supervisor recovery for sidecar: {}",
+                            reason
+                        );
+                        // Transition to error state
+                        state_manager.transition_to_error(
+                            ErrorCategory::Sidecar,
+                            "Sidecar hung, restarting...".to_string(),
+                        );
+
+                        // Keep legacy event for frontend compatibility while recovery moves through
+                        // supervisor policy.
+                        if let Some(ref handle) = app_handle {
+                            emit_with_shared_seq(
+                                handle,
+                                &["sidecar:restart"],
+                                serde_json::json!({ "reason": reason }),
+                                &event_seq,
+                            );
+                        }
+
+                        if let Err(err) = manager.recover_sidecar_from_watchdog().await {
+                            log::error!("Watchdog recovery via supervisor failed: {}", err);
+                        }
+                    }
+                    WatchdogEvent::SidecarHung => {
+                        // Legacy event retained in watchdog for observability.
+                        // Recovery is handled by the SidecarRecoveryRequested branch above;
+                        // calling recover here would trigger a duplicate attempt.
+                        log::warn!("Watchdog: legacy SidecarHung event received (no-op)");
+                    }
+                    WatchdogEvent::SystemResumed => {
+                        log::info!("System resumed from suspend, triggering revalidation");
+                    }
+                    WatchdogEvent::RevalidationNeeded => {
+                        log::info!("Revalidation needed after resume");
+
+                        // Revalidate the connection, model status, and input devices
+                        // in one pipelined batch.
+                        let client = rpc_client.read().await;
+                        if let Some(ref c) = *client {
+                            #[derive(serde::Deserialize)]
+                            struct PingResult {
+                                #[allow(dead_code)]
+                                version: String,
+                            }
+
+                            #[derive(serde::Deserialize)]
+                            struct StatusResult {
+                                status: String,
+                            }
+
+                            let [ping, status, devices] = <[_; 3]>::try_from(
+                                c.call_batch(vec![
+                                    ("system.ping", None),
+                                    ("model.get_status", None),
+                                    ("audio.list_devices", None),
+                                ])
+                                .await,
+                            )
+                            .expect("call_batch returns one result per call");
+
+                            match decode_result::<PingResult>(ping) {
+                                Ok(_) => {
+                                    log::info!("Sidecar responsive after resume");
+                                }
+                                Err(e) => {
+                                    log::warn!("Sidecar unresponsive after resume: {}", e);
+                                    state_manager.transition_to_error(
+                                        ErrorCategory::Sidecar,
+                                        "Sidecar unresponsive after resume".to_string(),
+                                    );
+                                }
+                            }
+
+                            match decode_result::<StatusResult>(status) {
+                                Ok(result) => {
+                                    log::info!("Model status after resume: {}", result.status);
+                                    match result.status.as_str() {
+                                        "ready" => {
+                                            recording_controller.set_model_ready(true).await;
+                                            *model_status.write().await = ModelStatus::Ready;
+                                        }
+                                        "downloading" => {
+                                            recording_controller.set_model_ready(false).await;
+                                            *model_status.write().await = ModelStatus::Downloading;
+                                        }
+                                        "loading" | "verifying" => {
+                                            recording_controller.set_model_ready(false).await;
+                                            *model_status.write().await = ModelStatus::Loading;
+                                        }
+                                        _ => {
+                                            recording_controller.set_model_ready(false).await;
+                                            *model_status.write().await = ModelStatus::Missing;
+                                        }
+                                    }
+                                }
+                                Err(e) => {
+                                    log::warn!("Failed to get model status after resume: {}", e);
+                                }
+                            }
+
+                            // Check the configured device is still present.
+                            match decode_result::<AudioListResult>(devices) {
+                                Ok(result) => {
+                                    let configured_uid = config::load_config().audio.device_uid;
+                                    if !is_configured_device_available(
+                                        configured_uid.as_deref(),
+                                        &result.devices,
+                                    ) {
+                                        if let Some(uid) = configured_uid {
+                                            log::warn!(
+                                                "Configured audio device missing after resume: {}",
+                                                uid
+                                            );
+                                            state_manager.transition_to_error(
+                                                ErrorCategory::AudioDevice,
+                                                format!(
+                                                    "Configured audio device unavailable after resume: {}",
+                                                    uid
+                                                ),
+                                            );
+                                        }
+                                    } else {
+                                        log::info!(
+                                            "Audio devices revalidated after resume ({} devices)",
+                                            result.devices.len()
+                                        );
+                                    }
+
+                                    if let Some(ref handle) = app_handle {
+                                        emit_with_shared_seq(
+                                            handle,
+                                            &[EVENT_TRAY_UPDATE],
+                                            json!({
+                                                "reason": "device_list_changed",
+                                                "device_count": result.devices.len(),
+                                            }),
+                                            &event_seq,
+                                        );
+                                    }
+                                }
+                                Err(e) => {
+                                    log::warn!("Failed to list audio devices after resume: {}", e);
+                                }
+                            }
+                        }
+
+                        watchdog_for_events.clear_revalidation_pending().await;
+                    }
+                }
+            }
+
+            log::info!("Watchdog event handler ended");
+        });
+    }
+
+    /// Start the loop that leaves recoverable Error states on its own.
+    ///
+    /// Each such error is revalidated after a backoff (see
+    /// [`crate::error_recovery`]); once its checks pass the app returns to
+    /// Idle. Every step is published as `app:recovery`.
+    pub(super) fn start_error_recovery_loop(&self) {
+        let state_manager = Arc::clone(&self.state_manager);
+        let rpc_client = Arc::clone(&self.rpc_client);
+        let recording_controller = Arc::clone(&self.recording_controller);
+        let model_status = Arc::clone(&self.model_status);
+        let app_handle = self.app_handle.clone();
+        let event_seq = Arc::clone(&self.event_seq);
+
+        tokio::spawn(async move {
+            let mut receiver = state_manager.subscribe();
+            let mut handled_generation = None;
+            let emit = |event: RecoveryEvent| {
+                if let Some(ref handle) = app_handle {
+                    emit_with_shared_seq(
+                        handle,
+                        &[EVENT_APP_RECOVERY],
+                        app_recovery_event_payload(&event),
+                        &event_seq,
+                    );
+                }
+            };
+
+            'errors: loop {
+                let error = match state_manager.current_error() {
+                    Some(error) if handled_generation != Some(error.generation) => error,
+                    _ => match receiver.recv().await {
+                        Err(broadcast::error::RecvError::Closed) => break,
+                        _ => continue,
+                    },
+                };
+                handled_generation = Some(error.generation);
+
+                let policy = RecoveryPolicy::from_supervisor(&config::load_config().supervisor);
+                if !policy.applies_to(error.category) {
+                    continue;
+                }
+                let checks = RecoveryCheck::for_category(error.category);
+
+                for attempt in 1..=policy.max_attempts {
+                    let delay = policy.backoff(attempt);
+                    emit(RecoveryEvent {
+                        delay_ms: Some(delay.as_millis() as u64),
+                        ..RecoveryEvent::new(
+                            RecoveryPhase::Scheduled,
+                            error.category,
+                            attempt,
+                            &policy,
+                        )
+                    });
+                    tokio::select! {
+                        _ = tokio::time::sleep(delay) => {}
+                        _ = error_left(&mut receiver, &state_manager, error.generation) => {
+                            emit(RecoveryEvent::new(
+                                RecoveryPhase::Superseded,
+                                error.category,
+                                attempt,
+                                &policy,
+                            ));
+                            continue 'errors;
+                        }
+                    }
+
+                    emit(RecoveryEvent::new(
+                        RecoveryPhase::Checking,
+                        error.category,
+                        attempt,
+                        &policy,
+                    ));
+                    let failed_checks = failed_recovery_checks(&rpc_client, checks).await;
+                    if failed_checks.is_empty() {
+                        if checks.contains(&RecoveryCheck::ModelStatus) {
+                            *model_status.write().await = ModelStatus::Ready;
+                            recording_controller.set_model_ready(true).await;
+                        }
+                        let phase = if state_manager.recover_from_error(error.generation) {
+                            log::info!(
+                                "Recovered from {:?} error after {} attempt(s)",
+                                error.category,
+                                attempt
+                            );
+                            RecoveryPhase::Recovered
+                        } else {
+                            RecoveryPhase::Superseded
+                        };
+                        emit(RecoveryEvent::new(phase, error.category, attempt, &policy));
+                        continue 'errors;
+                    }
+
+                    log::info!(
+                        "Recovery attempt {}/{} from {:?} error failed: {:?}",
+                        attempt,
+                        policy.max_attempts,
+                        error.category,
+                        failed_checks
+                    );
+                    emit(RecoveryEvent {
+                        failed_checks,
+                        ..RecoveryEvent::new(
+                            RecoveryPhase::Failed,
+                            error.category,
+                            attempt,
+                            &policy,
+                        )
+                    });
+                }
+
+                log::warn!(
+                    "Giving up recovery from {:?} error after {} attempts",
+                    error.category,
+                    policy.max_attempts
+                );
+                emit(RecoveryEvent::new(
+                    RecoveryPhase::GaveUp,
+                    error.category,
+                    policy.max_attempts,
+                    &policy,
+                ));
+            }
+        });
+    }
+}
+
+/// Resolves once the app is no longer in the error `generation`: it left
+/// the Error state, or a newer error replaced it.
+async fn error_left(
+    receiver: &mut broadcast::Receiver<StateEvent>,
+    state_manager: &AppStateManager,
+    generation: u64,
+) {
+    loop {
+        if state_manager.current_error().map(|error| error.generation) != Some(generation) {
+            return;
+        }
+        if let Err(broadcast::error::RecvError::Closed) = receiver.recv().await {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+/// The `checks` that do not pass, run as one batch against the sidecar.
+async fn failed_recovery_checks(
+    rpc_client: &RwLock<Option<RpcClient>>,
+    checks: &[RecoveryCheck],
+) -> Vec<RecoveryCheck> {
+    let client = rpc_client.read().await;
+    let Some(client) = client.as_ref() else {
+        return checks.to_vec();
+    };
+    let results = client
+        .call_batch(
+            checks
+                .iter()
+                .map(|check| (check.rpc_method(), None))
+                .collect(),
+        )
+        .await;
+    let configured_uid = config::load_config().audio.device_uid;
+    checks
+        .iter()
+        .zip(results)
+        .filter(|(check, result)| {
+            !recovery_check_passed(**check, result, configured_uid.as_deref())
+        })
+        .map(|(check, _)| *check)
+        .collect()
+}
+
+/// RPC ping adapter for watchdog.
+struct RpcPinger {
+    rpc_client: Arc<RwLock<Option<RpcClient>>>,
+}
+
+impl PingCallback for RpcPinger {
+    async fn ping(&self) -> Result<(), String> {
+        let client = self.rpc_client.read().await;
+        let client = client
+            .as_ref()
+            .ok_or_else(|| "Sidecar not connected".to_string())?;
+
+        #[derive(serde::Deserialize)]
+        struct PingResult {
+            #[allow(dead_code)]
+            version: String,
+        }
+
+        client
+            .call::<PingResult>("system.ping", None)
+            .await
+            .map(|_| ())
+            .map_err(|e| format!("Ping failed: {}", e))
+    }
+}
+
+/// `app:recovery` payload.
+fn app_recovery_event_payload(event: &RecoveryEvent) -> Value {
+    json!({
+        "phase": event.phase,
+        "category": event.category,
+        "attempt": event.attempt,
+        "max_attempts": event.max_attempts,
+        "delay_ms": event.delay_ms,
+        "failed_checks": event.failed_checks,
+    })
+}
@@ -0,0 +1,103 @@
+//! Lossy decoding of sidecar output.
+//!
+//! Python formats some errors in the locale's encoding, so a line from the
+//! sidecar may not be valid UTF-8. Lines are decoded with U+FFFD in place of
+//! each invalid sequence instead of failing the read, and the bytes replaced
+//! are counted per channel for the diagnostics report.
+
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::Serialize;
+
+static RPC_BYTES_REPLACED: AtomicU64 = AtomicU64::new(0);
+static LOG_BYTES_REPLACED: AtomicU64 = AtomicU64::new(0);
+
+/// Where a decoded line came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputChannel {
+    /// The JSON-RPC line protocol.
+    Rpc,
+    /// Captured stdout/stderr logging.
+    Log,
+}
+
+impl OutputChannel {
+    fn counter(self) -> &'static AtomicU64 {
+        match self {
+            OutputChannel::Rpc => &RPC_BYTES_REPLACED,
+            OutputChannel::Log => &LOG_BYTES_REPLACED,
+        }
+    }
+}
+
+/// Bytes replaced while decoding sidecar output, as reported in diagnostics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SidecarOutputStats {
+    pub rpc_bytes_replaced: u64,
+    pub log_bytes_replaced: u64,
+}
+
+/// Decode a line read from `channel`, replacing invalid UTF-8.
+pub fn decode_line(channel: OutputChannel, bytes: &[u8]) -> Cow<'_, str> {
+    let replaced = invalid_byte_count(bytes);
+    if replaced > 0 {
+        channel.counter().fetch_add(replaced, Ordering::Relaxed);
+        log::debug!(
+            "Replaced {} invalid UTF-8 byte(s) in sidecar {:?} output",
+            replaced,
+            channel
+        );
+    }
+    String::from_utf8_lossy(bytes)
+}
+
+/// Replacement counts since startup.
+pub fn output_stats() -> SidecarOutputStats {
+    SidecarOutputStats {
+        rpc_bytes_replaced: RPC_BYTES_REPLACED.load(Ordering::Relaxed),
+        log_bytes_replaced: LOG_BYTES_REPLACED.load(Ordering::Relaxed),
+    }
+}
+
+/// Number of bytes that are not part of a valid UTF-8 sequence.
+fn invalid_byte_count(mut bytes: &[u8]) -> u64 {
+    let mut invalid = 0u64;
+    loop {
+        match std::str::from_utf8(bytes) {
+            Ok(_) => return invalid,
+            Err(e) => {
+                let skipped = e.error_len().unwrap_or(bytes.len() - e.valid_up_to());
+                invalid += skipped as u64;
+                bytes = &bytes[e.valid_up_to() + skipped..];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_byte_count() {
+        assert_eq!(invalid_byte_count(b"plain ascii"), 0);
+        assert_eq!(invalid_byte_count("caf\u{e9}".as_bytes()), 0);
+        // Latin-1 "café" and a truncated multi-byte sequence at the end.
+        assert_eq!(invalid_byte_count(b"caf\xe9 \xff\xfe"), 3);
+        assert_eq!(invalid_byte_count(b"ok \xe2\x82"), 2);
+    }
+
+    #[test]
+    fn test_decode_line_replaces_and_counts() {
+        let before = output_stats().log_bytes_replaced;
+        let line = decode_line(OutputChannel::Log, b"Erreur: fichier \xe9chou\xe9\n");
+        assert_eq!(line, "Erreur: fichier \u{fffd}chou\u{fffd}\n");
+        assert!(output_stats().log_bytes_replaced >= before + 2);
+
+        assert!(matches!(
+            decode_line(OutputChannel::Rpc, b"{\"jsonrpc\":\"2.0\"}"),
+            Cow::Borrowed(_)
+        ));
+    }
+}
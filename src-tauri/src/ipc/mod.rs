@@ -2,48 +2,81 @@
 //!
 //! This module provides an async RPC client that handles:
 //! - Request/response correlation
+//! - Per-call correlation ids and debug-level call tracing
 //! - Per-method timeouts
+//! - Pipelined batches of calls
 //! - Notification broadcasting
 //! - Line buffering and oversized line detection
+//! - Resynchronization after garbage on stdout
+//! - Lossy UTF-8 decoding of sidecar output (see [`decode`])
+//! - Stdio or socket/named-pipe transports (see [`transport`])
 
 #![allow(dead_code)] // Client will be used when integrated with SidecarManager
 
 /// JSON-RPC 2.0 message types for IPC communication.
 pub mod types;
 
+/// Stdio and socket/named-pipe channels carrying the line protocol.
+pub mod transport;
+
+/// Lossy UTF-8 decoding of sidecar output, with replacement counters.
+pub mod decode;
+
+#[cfg(test)]
+mod property_tests;
+
 use std::collections::HashMap;
-use std::io::{BufRead, BufReader, Write};
+use std::io::{BufRead, BufReader, ErrorKind, Write};
 use std::process::{ChildStdin, ChildStdout};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 use thiserror::Error;
 use tokio::sync::{broadcast, mpsc, oneshot, Mutex};
-use tokio::time::timeout;
+use tokio::time::timeout_at;
 
+use crate::session_journal::{self, JournalEvent, SessionJournal};
 use crate::sidecar::SidecarManager;
 
 pub use types::*;
 
-/// Maximum line length (1 MiB). Lines exceeding this cause a fatal error.
+/// Maximum line length (1 MiB). Longer lines are discarded unread.
 const MAX_LINE_LENGTH: usize = 1024 * 1024;
 
+/// Consecutive malformed lines tolerated when no tolerance is configured.
+pub const DEFAULT_PROTOCOL_ERROR_TOLERANCE: u32 = 10;
+
+/// How many `{` positions are tried when looking for a message inside a
+/// garbled line.
+const MAX_RESYNC_ATTEMPTS: usize = 16;
+
 /// RPC client errors.
 #[derive(Debug, Error)]
 pub enum RpcError {
-    #[error("Timeout waiting for response to {method}")]
-    Timeout { method: String },
+    #[error(
+        "Timeout waiting for response to {method}{}",
+        correlation_suffix(.correlation_id.as_deref())
+    )]
+    Timeout {
+        method: String,
+        correlation_id: Option<String>,
+    },
 
     #[error("Protocol error: {0}")]
     Protocol(String),
 
-    #[error("Remote error: {kind} - {message}")]
+    #[error(
+        "Remote error: {kind} - {message}{}",
+        correlation_suffix(.correlation_id.as_deref())
+    )]
     Remote {
         code: i32,
         message: String,
         kind: String,
+        correlation_id: Option<String>,
     },
 
     #[error("Disconnected from sidecar")]
@@ -59,6 +92,24 @@ pub enum RpcError {
     Channel(String),
 }
 
+impl RpcError {
+    /// Correlation id of the call that produced this error, if known.
+    pub fn correlation_id(&self) -> Option<&str> {
+        match self {
+            Self::Timeout { correlation_id, .. } | Self::Remote { correlation_id, .. } => {
+                correlation_id.as_deref()
+            }
+            _ => None,
+        }
+    }
+}
+
+fn correlation_suffix(correlation_id: Option<&str>) -> String {
+    correlation_id
+        .map(|id| format!(" (correlation_id={id})"))
+        .unwrap_or_default()
+}
+
 /// Notification event from the sidecar.
 #[derive(Debug, Clone)]
 pub struct NotificationEvent {
@@ -75,6 +126,186 @@ fn parse_notification_event(line: &str) -> Option<NotificationEvent> {
         })
 }
 
+/// Read one line into `buf`, keeping at most `MAX_LINE_LENGTH + 1` bytes so an
+/// oversized line cannot exhaust memory; the rest of it is discarded.
+///
+/// Returns the full length of the line in the stream, or 0 at end of stream.
+pub(crate) fn read_line_bounded(
+    reader: &mut impl BufRead,
+    buf: &mut Vec<u8>,
+) -> std::io::Result<usize> {
+    buf.clear();
+    let mut total = 0;
+    loop {
+        let available = match reader.fill_buf() {
+            Ok(available) => available,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        if available.is_empty() {
+            return Ok(total);
+        }
+        let (chunk, line_done) = match available.iter().position(|&byte| byte == b'\n') {
+            Some(end) => (&available[..=end], true),
+            None => (available, false),
+        };
+        let room = (MAX_LINE_LENGTH + 1).saturating_sub(buf.len());
+        buf.extend_from_slice(&chunk[..chunk.len().min(room)]);
+        let used = chunk.len();
+        reader.consume(used);
+        total += used;
+        if line_done {
+            return Ok(total);
+        }
+    }
+}
+
+/// What the reader does with one line of sidecar stdout.
+#[derive(Debug)]
+enum LineVerdict<'a> {
+    /// A message, with the JSON text it was parsed from.
+    Message(IncomingMessage, &'a str),
+    /// Nothing to deliver; keep reading.
+    Skip,
+    /// Too many malformed lines in a row; the stream is unusable.
+    Fatal,
+}
+
+/// Recovers from garbage on the sidecar's stdout.
+///
+/// A stray print from a native library, a truncated write, or an oversized
+/// line is skipped, and a message glued to garbage on the same line is still
+/// delivered. Only more than `tolerance` malformed lines in a row end the
+/// connection. Each skip is logged and recorded in `journal`.
+#[derive(Debug)]
+struct ProtocolResync<'j> {
+    tolerance: u32,
+    consecutive_errors: u32,
+    journal: &'j SessionJournal,
+}
+
+impl<'j> ProtocolResync<'j> {
+    fn new(tolerance: u32, journal: &'j SessionJournal) -> Self {
+        Self {
+            tolerance,
+            consecutive_errors: 0,
+            journal,
+        }
+    }
+
+    fn check<'a>(&mut self, line: &'a str) -> LineVerdict<'a> {
+        if line.trim().is_empty() {
+            return LineVerdict::Skip;
+        }
+        if line.len() > MAX_LINE_LENGTH {
+            return self.malformed(
+                format!("line exceeds maximum length of {} bytes", MAX_LINE_LENGTH),
+                line.len(),
+            );
+        }
+
+        let Some((start, raw, message)) = find_message(line) else {
+            return self.malformed("no JSON-RPC message found".to_string(), line.len());
+        };
+        let skipped = line[..start].trim().len() + line[start + raw.len()..].trim().len();
+        if skipped > 0 {
+            self.warn(
+                &format!("resynchronized after {} bytes of garbage", skipped),
+                skipped,
+            );
+        }
+        self.consecutive_errors = 0;
+        LineVerdict::Message(message, raw)
+    }
+
+    fn malformed(&mut self, reason: String, bytes: usize) -> LineVerdict<'static> {
+        self.consecutive_errors += 1;
+        if self.consecutive_errors > self.tolerance {
+            log::error!(
+                "Sidecar protocol lost after {} malformed lines in a row (last: {})",
+                self.consecutive_errors,
+                reason
+            );
+            return LineVerdict::Fatal;
+        }
+        self.warn(&reason, bytes);
+        LineVerdict::Skip
+    }
+
+    fn warn(&self, reason: &str, bytes: usize) {
+        log::warn!(
+            "Skipping malformed sidecar output ({}, {} bytes); {}/{} before disconnect",
+            reason,
+            bytes,
+            self.consecutive_errors,
+            self.tolerance
+        );
+        session_journal::record(
+            self.journal,
+            JournalEvent::ProtocolWarning,
+            None,
+            Some(serde_json::json!({
+                "reason": reason,
+                "bytes": bytes,
+                "consecutive_errors": self.consecutive_errors,
+                "tolerance": self.tolerance,
+            })),
+        );
+    }
+}
+
+/// Find the first JSON-RPC message in `line`, trying each `{` as its start
+/// and ignoring anything after it.
+fn find_message(line: &str) -> Option<(usize, &str, IncomingMessage)> {
+    line.match_indices('{')
+        .take(MAX_RESYNC_ATTEMPTS)
+        .find_map(|(start, _)| {
+            let rest = &line[start..];
+            let mut stream =
+                serde_json::Deserializer::from_str(rest).into_iter::<IncomingMessage>();
+            match stream.next() {
+                Some(Ok(message)) => Some((start, &rest[..stream.byte_offset()], message)),
+                _ => None,
+            }
+        })
+}
+
+/// Deliver one message from the sidecar: a response to the call waiting on
+/// its id, a notification to subscribers. `raw` is the JSON text the message
+/// was parsed from.
+fn dispatch_message(
+    message: IncomingMessage,
+    raw: &str,
+    pending: &Mutex<HashMap<u64, PendingRequest>>,
+    notification_tx: &broadcast::Sender<NotificationEvent>,
+) {
+    match message {
+        IncomingMessage::Response(response) => {
+            if let Some(RequestId::Number(id)) = response.id {
+                // Correlate with pending request
+                let mut pending_guard = pending.blocking_lock();
+                if let Some(request) = pending_guard.remove(&id) {
+                    let _ = request.sender.send(Ok(response));
+                } else {
+                    log::warn!("Received response for unknown request id: {}", id);
+                }
+            } else if let Some(event) = parse_notification_event(raw) {
+                // Untagged enum parsing may classify notifications as Response(id=None).
+                // Recover by parsing notification shape directly and broadcasting it.
+                let _ = notification_tx.send(event);
+            }
+        }
+        IncomingMessage::Notification(notif) => {
+            // Broadcast notification
+            let event = NotificationEvent {
+                method: notif.method,
+                params: notif.params,
+            };
+            let _ = notification_tx.send(event);
+        }
+    }
+}
+
 /// Internal command for the writer task.
 enum WriterCommand {
     Send(String),
@@ -102,11 +333,24 @@ pub struct RpcClient {
 
     /// Flag indicating if the client is connected.
     connected: Arc<std::sync::atomic::AtomicBool>,
+
+    /// Per-client prefix so correlation ids stay unique across sidecar restarts.
+    trace_prefix: String,
 }
 
 impl RpcClient {
     /// Create a new RPC client connected to the given stdin/stdout.
     pub fn new(stdin: ChildStdin, stdout: ChildStdout) -> Self {
+        Self::with_protocol_error_tolerance(stdin, stdout, DEFAULT_PROTOCOL_ERROR_TOLERANCE)
+    }
+
+    /// Create a new RPC client that gives up after more than `tolerance`
+    /// malformed stdout lines in a row.
+    pub fn with_protocol_error_tolerance(
+        stdin: ChildStdin,
+        stdout: ChildStdout,
+        tolerance: u32,
+    ) -> Self {
         let (writer_tx, writer_rx) = mpsc::channel::<WriterCommand>(32);
         let (notification_tx, _) = broadcast::channel::<NotificationEvent>(64);
 
@@ -132,6 +376,7 @@ impl RpcClient {
                 pending_clone,
                 notification_tx_clone,
                 connected_clone,
+                tolerance,
             );
         });
 
@@ -141,11 +386,18 @@ impl RpcClient {
             pending,
             notification_tx,
             connected,
+            trace_prefix: new_trace_prefix(),
         }
     }
 
     /// Create a new RPC client backed by `SidecarManager` read/write primitives.
-    pub fn new_with_sidecar_manager(sidecar: SidecarManager) -> Self {
+    ///
+    /// The connection is dropped after more than `protocol_error_tolerance`
+    /// malformed stdout lines in a row.
+    pub fn new_with_sidecar_manager(
+        sidecar: SidecarManager,
+        protocol_error_tolerance: u32,
+    ) -> Self {
         let (writer_tx, writer_rx) = mpsc::channel::<WriterCommand>(32);
         let (notification_tx, _) = broadcast::channel::<NotificationEvent>(64);
 
@@ -170,6 +422,7 @@ impl RpcClient {
                 pending_clone,
                 notification_tx_clone,
                 connected_clone,
+                protocol_error_tolerance,
             );
         });
 
@@ -179,9 +432,15 @@ impl RpcClient {
             pending,
             notification_tx,
             connected,
+            trace_prefix: new_trace_prefix(),
         }
     }
 
+    /// Correlation id logged for request `id`; matches the JSON-RPC id the sidecar logs.
+    fn correlation_id(&self, id: u64) -> String {
+        format!("{}-{}", self.trace_prefix, id)
+    }
+
     /// Check if the client is connected.
     pub fn is_connected(&self) -> bool {
         self.connected.load(Ordering::SeqCst)
@@ -193,6 +452,9 @@ impl RpcClient {
     }
 
     /// Call an RPC method and wait for the response.
+    ///
+    /// Each call is traced at debug level with its correlation id, duration,
+    /// and outcome.
     pub async fn call<T: DeserializeOwned>(
         &self,
         method: &str,
@@ -204,7 +466,89 @@ impl RpcClient {
 
         // Generate request ID
         let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let correlation_id = self.correlation_id(id);
+        let started_at = Instant::now();
+        log::debug!("rpc {} -> {}", correlation_id, method);
+
+        let result = self.call_inner(id, &correlation_id, method, params).await;
+        log::debug!(
+            "rpc {} <- {} outcome={} duration_ms={}",
+            correlation_id,
+            method,
+            call_outcome(&result),
+            started_at.elapsed().as_millis()
+        );
+        result
+    }
+
+    async fn call_inner<T: DeserializeOwned>(
+        &self,
+        id: u64,
+        correlation_id: &str,
+        method: &str,
+        params: Option<Value>,
+    ) -> Result<T, RpcError> {
+        let deadline = tokio::time::Instant::now() + TimeoutConfig::get(method);
+        let rx = self.send_request(id, method, params).await?;
+        let result = self
+            .wait_response(id, correlation_id, method, rx, deadline)
+            .await?;
+        serde_json::from_value(result).map_err(RpcError::from)
+    }
+
+    /// Send several calls back to back, then collect their results in order.
+    ///
+    /// The sidecar reads each request as soon as it arrives, so a batch costs
+    /// one round trip instead of one per call. Results are independent: a
+    /// failed call does not fail the others. Decode each with
+    /// [`decode_result`].
+    pub async fn call_batch(
+        &self,
+        calls: Vec<(&str, Option<Value>)>,
+    ) -> Vec<Result<Value, RpcError>> {
+        if !self.is_connected() {
+            return calls.iter().map(|_| Err(RpcError::Disconnected)).collect();
+        }
+
+        let started_at = Instant::now();
+        let mut in_flight = Vec::with_capacity(calls.len());
+        for (method, params) in calls {
+            let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+            let correlation_id = self.correlation_id(id);
+            log::debug!("rpc {} -> {} (batched)", correlation_id, method);
+            let deadline = tokio::time::Instant::now() + TimeoutConfig::get(method);
+            let sent = self.send_request(id, method, params).await;
+            in_flight.push((id, correlation_id, method, deadline, sent));
+        }
 
+        let mut results = Vec::with_capacity(in_flight.len());
+        for (id, correlation_id, method, deadline, sent) in in_flight {
+            let result = match sent {
+                Ok(rx) => {
+                    self.wait_response(id, &correlation_id, method, rx, deadline)
+                        .await
+                }
+                Err(error) => Err(error),
+            };
+            log::debug!(
+                "rpc {} <- {} outcome={} duration_ms={}",
+                correlation_id,
+                method,
+                call_outcome(&result),
+                started_at.elapsed().as_millis()
+            );
+            results.push(result);
+        }
+        results
+    }
+
+    /// Register request `id` and hand it to the writer.
+    async fn send_request(
+        &self,
+        id: u64,
+        method: &str,
+        params: Option<Value>,
+    ) -> Result<oneshot::Receiver<Result<Response, RpcError>>, RpcError> {
         // Create request
         let request = Request::new(id, method, params);
         let request_json = serde_json::to_string(&request)?;
@@ -229,9 +573,19 @@ impl RpcClient {
             return Err(RpcError::Disconnected);
         }
 
-        // Wait for response with timeout
-        let method_timeout = TimeoutConfig::get(method);
-        let response = match timeout(method_timeout, rx).await {
+        Ok(rx)
+    }
+
+    /// Wait until `deadline` for the response to request `id`.
+    async fn wait_response(
+        &self,
+        id: u64,
+        correlation_id: &str,
+        method: &str,
+        rx: oneshot::Receiver<Result<Response, RpcError>>,
+        deadline: tokio::time::Instant,
+    ) -> Result<Value, RpcError> {
+        let response = match timeout_at(deadline, rx).await {
             Ok(Ok(result)) => result,
             Ok(Err(_)) => {
                 // Channel was closed
@@ -243,6 +597,7 @@ impl RpcClient {
                 self.cleanup_pending(id).await;
                 return Err(RpcError::Timeout {
                     method: method.to_string(),
+                    correlation_id: Some(correlation_id.to_string()),
                 });
             }
         }?;
@@ -253,15 +608,14 @@ impl RpcClient {
                 code: err.code,
                 message: err.message,
                 kind: err.data.map(|d| d.kind).unwrap_or_default(),
+                correlation_id: Some(correlation_id.to_string()),
             });
         }
 
         // Parse result
-        let result = response
+        response
             .result
-            .ok_or_else(|| RpcError::Protocol("Missing result in response".to_string()))?;
-
-        serde_json::from_value(result).map_err(RpcError::from)
+            .ok_or_else(|| RpcError::Protocol("Missing result in response".to_string()))
     }
 
     /// Clean up a pending request.
@@ -328,69 +682,35 @@ impl RpcClient {
         pending: Arc<Mutex<HashMap<u64, PendingRequest>>>,
         notification_tx: broadcast::Sender<NotificationEvent>,
         connected: Arc<std::sync::atomic::AtomicBool>,
+        tolerance: u32,
     ) {
-        let reader = BufReader::new(stdout);
-
-        for line in reader.lines() {
-            let line = match line {
-                Ok(l) => l,
+        let mut reader = BufReader::new(stdout);
+        let mut resync = ProtocolResync::new(tolerance, session_journal::global_journal());
+        let mut buf = Vec::new();
+
+        loop {
+            match read_line_bounded(&mut reader, &mut buf) {
+                Ok(0) => break,
+                Ok(_) => {}
                 Err(e) => {
                     log::error!("Error reading from sidecar: {}", e);
                     connected.store(false, Ordering::SeqCst);
                     break;
                 }
-            };
-
-            // Check line length
-            if line.len() > MAX_LINE_LENGTH {
-                log::error!(
-                    "Line exceeds maximum length ({} > {}), fatal",
-                    line.len(),
-                    MAX_LINE_LENGTH
-                );
-                connected.store(false, Ordering::SeqCst);
-                break;
-            }
-
-            // Skip empty lines
-            if line.trim().is_empty() {
-                continue;
             }
+            // Binary garbage becomes replacement characters and fails to parse.
+            let line = decode::decode_line(decode::OutputChannel::Rpc, &buf);
 
-            // Parse message
-            let message: IncomingMessage = match serde_json::from_str(&line) {
-                Ok(m) => m,
-                Err(e) => {
-                    log::warn!("Failed to parse message from sidecar: {}", e);
-                    continue;
+            let (message, line) = match resync.check(&line) {
+                LineVerdict::Message(message, raw) => (message, raw),
+                LineVerdict::Skip => continue,
+                LineVerdict::Fatal => {
+                    connected.store(false, Ordering::SeqCst);
+                    break;
                 }
             };
 
-            match message {
-                IncomingMessage::Response(response) => {
-                    if let Some(RequestId::Number(id)) = response.id {
-                        // Correlate with pending request
-                        let mut pending_guard = pending.blocking_lock();
-                        if let Some(request) = pending_guard.remove(&id) {
-                            let _ = request.sender.send(Ok(response));
-                        } else {
-                            log::warn!("Received response for unknown request id: {}", id);
-                        }
-                    } else if let Some(event) = parse_notification_event(&line) {
-                        // Untagged enum parsing may classify notifications as Response(id=None).
-                        // Recover by parsing notification shape directly and broadcasting it.
-                        let _ = notification_tx.send(event);
-                    }
-                }
-                IncomingMessage::Notification(notif) => {
-                    // Broadcast notification
-                    let event = NotificationEvent {
-                        method: notif.method,
-                        params: notif.params,
-                    };
-                    let _ = notification_tx.send(event);
-                }
-            }
+            dispatch_message(message, line, &pending, &notification_tx);
         }
 
         log::info!("Reader loop ended");
@@ -409,7 +729,10 @@ impl RpcClient {
         pending: Arc<Mutex<HashMap<u64, PendingRequest>>>,
         notification_tx: broadcast::Sender<NotificationEvent>,
         connected: Arc<std::sync::atomic::AtomicBool>,
+        tolerance: u32,
     ) {
+        let mut resync = ProtocolResync::new(tolerance, session_journal::global_journal());
+
         while connected.load(Ordering::SeqCst) {
             let line = match sidecar.read_line() {
                 Ok(l) => l,
@@ -420,56 +743,16 @@ impl RpcClient {
                 }
             };
 
-            // Check line length.
-            if line.len() > MAX_LINE_LENGTH {
-                log::error!(
-                    "Line exceeds maximum length ({} > {}), fatal",
-                    line.len(),
-                    MAX_LINE_LENGTH
-                );
-                connected.store(false, Ordering::SeqCst);
-                break;
-            }
-
-            // Skip empty lines.
-            if line.trim().is_empty() {
-                continue;
-            }
-
-            // Parse message.
-            let message: IncomingMessage = match serde_json::from_str(&line) {
-                Ok(m) => m,
-                Err(e) => {
-                    log::warn!("Failed to parse message from sidecar: {}", e);
-                    continue;
+            let (message, line) = match resync.check(&line) {
+                LineVerdict::Message(message, raw) => (message, raw),
+                LineVerdict::Skip => continue,
+                LineVerdict::Fatal => {
+                    connected.store(false, Ordering::SeqCst);
+                    break;
                 }
             };
 
-            match message {
-                IncomingMessage::Response(response) => {
-                    if let Some(RequestId::Number(id)) = response.id {
-                        // Correlate with pending request.
-                        let mut pending_guard = pending.blocking_lock();
-                        if let Some(request) = pending_guard.remove(&id) {
-                            let _ = request.sender.send(Ok(response));
-                        } else {
-                            log::warn!("Received response for unknown request id: {}", id);
-                        }
-                    } else if let Some(event) = parse_notification_event(&line) {
-                        // Untagged enum parsing may classify notifications as Response(id=None).
-                        // Recover by parsing notification shape directly and broadcasting it.
-                        let _ = notification_tx.send(event);
-                    }
-                }
-                IncomingMessage::Notification(notif) => {
-                    // Broadcast notification.
-                    let event = NotificationEvent {
-                        method: notif.method,
-                        params: notif.params,
-                    };
-                    let _ = notification_tx.send(event);
-                }
-            }
+            dispatch_message(message, line, &pending, &notification_tx);
         }
 
         log::info!("Reader loop ended");
@@ -489,14 +772,39 @@ impl RpcClient {
     }
 }
 
+/// Decode one result of [`RpcClient::call_batch`].
+pub fn decode_result<T: DeserializeOwned>(result: Result<Value, RpcError>) -> Result<T, RpcError> {
+    result.and_then(|value| serde_json::from_value(value).map_err(RpcError::from))
+}
+
+fn new_trace_prefix() -> String {
+    uuid::Uuid::new_v4().simple().to_string()[..8].to_string()
+}
+
+fn call_outcome<T>(result: &Result<T, RpcError>) -> String {
+    match result {
+        Ok(_) => "ok".to_string(),
+        Err(RpcError::Remote { kind, .. }) if !kind.is_empty() => format!("remote:{kind}"),
+        Err(RpcError::Remote { .. }) => "remote".to_string(),
+        Err(RpcError::Timeout { .. }) => "timeout".to_string(),
+        Err(RpcError::Disconnected) => "disconnected".to_string(),
+        Err(RpcError::Protocol(_)) => "protocol_error".to_string(),
+        Err(RpcError::Serialization(_)) => "serialization_error".to_string(),
+        Err(RpcError::Io(_)) => "io_error".to_string(),
+        Err(RpcError::Channel(_)) => "channel_error".to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::session_journal::{temp_journal, JournalRecord};
 
     #[test]
     fn test_rpc_error_display() {
         let err = RpcError::Timeout {
             method: "test".to_string(),
+            correlation_id: None,
         };
         assert!(err.to_string().contains("Timeout"));
 
@@ -504,10 +812,63 @@ mod tests {
             code: -32601,
             message: "Method not found".to_string(),
             kind: "E_METHOD_NOT_FOUND".to_string(),
+            correlation_id: None,
         };
         assert!(err.to_string().contains("E_METHOD_NOT_FOUND"));
     }
 
+    #[test]
+    fn test_rpc_error_display_includes_correlation_id() {
+        let err = RpcError::Timeout {
+            method: "asr.transcribe".to_string(),
+            correlation_id: Some("a1b2c3d4-7".to_string()),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Timeout waiting for response to asr.transcribe (correlation_id=a1b2c3d4-7)"
+        );
+        assert_eq!(err.correlation_id(), Some("a1b2c3d4-7"));
+        assert_eq!(RpcError::Disconnected.correlation_id(), None);
+    }
+
+    #[test]
+    fn test_call_outcome_labels() {
+        assert_eq!(call_outcome::<()>(&Ok(())), "ok");
+        assert_eq!(
+            call_outcome::<()>(&Err(RpcError::Remote {
+                code: -32601,
+                message: "Method not found".to_string(),
+                kind: "E_METHOD_NOT_FOUND".to_string(),
+                correlation_id: None,
+            })),
+            "remote:E_METHOD_NOT_FOUND"
+        );
+        assert_eq!(
+            call_outcome::<()>(&Err(RpcError::Disconnected)),
+            "disconnected"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_correlation_ids_are_unique_per_client() {
+        let (first, _) = mpsc::channel::<WriterCommand>(1);
+        let (second, _) = mpsc::channel::<WriterCommand>(1);
+        let client = |writer_tx| RpcClient {
+            next_id: AtomicU64::new(1),
+            writer_tx,
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            notification_tx: broadcast::channel(1).0,
+            connected: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            trace_prefix: new_trace_prefix(),
+        };
+        let first = client(first);
+        let second = client(second);
+
+        assert_eq!(first.trace_prefix.len(), 8);
+        assert!(first.correlation_id(3).ends_with("-3"));
+        assert_ne!(first.correlation_id(3), second.correlation_id(3));
+    }
+
     #[test]
     fn test_max_line_length_constant() {
         // Ensure MAX_LINE_LENGTH is 1 MiB
@@ -567,6 +928,75 @@ mod tests {
         assert!(large_line.len() > MAX_LINE_LENGTH);
     }
 
+    #[test]
+    fn test_read_line_bounded_discards_oversized_line() {
+        let mut input = "x".repeat(MAX_LINE_LENGTH + 10);
+        input.push('\n');
+        input.push_str("{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":{}}\n");
+        let mut reader = std::io::Cursor::new(input.into_bytes());
+        let mut buf = Vec::new();
+
+        let total = read_line_bounded(&mut reader, &mut buf).unwrap();
+        assert_eq!(total, MAX_LINE_LENGTH + 11);
+        assert_eq!(buf.len(), MAX_LINE_LENGTH + 1);
+
+        read_line_bounded(&mut reader, &mut buf).unwrap();
+        assert!(buf.starts_with(b"{\"jsonrpc\""));
+        assert_eq!(read_line_bounded(&mut reader, &mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_resync_recovers_message_glued_to_garbage() {
+        let (_dir, journal) = temp_journal();
+        let mut resync = ProtocolResync::new(3, &journal);
+        let line = r#"Warning: fp16 not supported{"jsonrpc":"2.0","id":7,"result":{}}trailing"#;
+
+        match resync.check(line) {
+            LineVerdict::Message(message, raw) => {
+                assert!(message.has_id());
+                assert_eq!(raw, r#"{"jsonrpc":"2.0","id":7,"result":{}}"#);
+            }
+            other => panic!("Expected message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_resync_tolerates_garbage_until_limit() {
+        let (_dir, journal) = temp_journal();
+        let mut resync = ProtocolResync::new(2, &journal);
+        let valid = r#"{"jsonrpc":"2.0","method":"audio:level","params":{}}"#;
+
+        assert!(matches!(
+            resync.check("\u{fffd}\u{fffd}binary"),
+            LineVerdict::Skip
+        ));
+        assert!(matches!(resync.check("{ not json"), LineVerdict::Skip));
+        // A good line resets the count.
+        assert!(matches!(resync.check(valid), LineVerdict::Message(..)));
+        assert!(matches!(resync.check("noise"), LineVerdict::Skip));
+        assert!(matches!(resync.check("noise"), LineVerdict::Skip));
+        assert!(matches!(resync.check("noise"), LineVerdict::Fatal));
+
+        // Every skipped line is journaled; the fatal one is only logged.
+        let events: Vec<JournalEvent> = journal
+            .tail(10)
+            .iter()
+            .map(|line| serde_json::from_str::<JournalRecord>(line).unwrap().event)
+            .collect();
+        assert_eq!(events, vec![JournalEvent::ProtocolWarning; 4]);
+    }
+
+    #[test]
+    fn test_resync_skips_blank_and_oversized_lines() {
+        let (_dir, journal) = temp_journal();
+        let mut resync = ProtocolResync::new(0, &journal);
+        assert!(matches!(resync.check("   "), LineVerdict::Skip));
+        assert!(matches!(
+            resync.check(&"x".repeat(MAX_LINE_LENGTH + 1)),
+            LineVerdict::Fatal
+        ));
+    }
+
     #[test]
     fn test_incoming_message_response_parsing() {
         let json = r#"{"jsonrpc":"2.0","id":42,"result":{"status":"ok"}}"#;
@@ -674,6 +1104,7 @@ mod tests {
             pending: Arc::clone(&pending),
             notification_tx,
             connected: Arc::new(std::sync::atomic::AtomicBool::new(true)),
+            trace_prefix: new_trace_prefix(),
         };
 
         let result: Result<Value, RpcError> = client.call("system.ping", None).await;
@@ -681,6 +1112,83 @@ mod tests {
         assert!(pending.lock().await.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_call_batch_sends_every_request_before_waiting() {
+        let (writer_tx, mut writer_rx) = mpsc::channel::<WriterCommand>(8);
+        let pending = Arc::new(Mutex::new(HashMap::new()));
+        let client = RpcClient {
+            next_id: AtomicU64::new(1),
+            writer_tx,
+            pending: Arc::clone(&pending),
+            notification_tx: broadcast::channel(1).0,
+            connected: Arc::new(std::sync::atomic::AtomicBool::new(true)),
+            trace_prefix: new_trace_prefix(),
+        };
+
+        // Fake sidecar: read all three requests, then answer newest first.
+        let sidecar = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            while requests.len() < 3 {
+                if let Some(WriterCommand::Send(line)) = writer_rx.recv().await {
+                    requests.push(serde_json::from_str::<Value>(&line).unwrap());
+                }
+            }
+            for request in requests.iter().rev() {
+                let id = request["id"].as_u64().unwrap();
+                let response: Response = serde_json::from_value(if request["method"] == "fail" {
+                    serde_json::json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "error": {
+                            "code": -32601,
+                            "message": "nope",
+                            "data": {"kind": "E_METHOD_NOT_FOUND"},
+                        },
+                    })
+                } else {
+                    serde_json::json!({"jsonrpc": "2.0", "id": id, "result": request["method"]})
+                })
+                .unwrap();
+                let request = pending.lock().await.remove(&id).unwrap();
+                let _ = request.sender.send(Ok(response));
+            }
+        });
+
+        let results = client
+            .call_batch(vec![
+                ("system.ping", None),
+                ("fail", None),
+                ("model.get_status", None),
+            ])
+            .await;
+        sidecar.await.unwrap();
+
+        assert_eq!(results.len(), 3);
+        let ping = results[0].as_ref().unwrap().clone();
+        assert_eq!(decode_result::<String>(Ok(ping)).unwrap(), "system.ping");
+        assert!(matches!(results[1], Err(RpcError::Remote { .. })));
+        assert_eq!(results[2].as_ref().unwrap(), "model.get_status");
+    }
+
+    #[tokio::test]
+    async fn test_call_batch_when_disconnected() {
+        let client = RpcClient {
+            next_id: AtomicU64::new(1),
+            writer_tx: mpsc::channel(1).0,
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            notification_tx: broadcast::channel(1).0,
+            connected: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            trace_prefix: new_trace_prefix(),
+        };
+        let results = client
+            .call_batch(vec![("system.ping", None), ("audio.list_devices", None)])
+            .await;
+        assert_eq!(results.len(), 2);
+        assert!(results
+            .iter()
+            .all(|result| matches!(result, Err(RpcError::Disconnected))));
+    }
+
     #[test]
     fn test_pending_mutex_blocking_lock_from_std_thread() {
         use std::collections::HashMap;
@@ -0,0 +1,465 @@
+//! Property tests for sidecar wire handling: message parsing, resync after
+//! garbage, oversized lines, and request/response correlation.
+//!
+//! Inputs come from proptest strategies. A failing case is shrunk to a
+//! minimal input and its seed is saved under `proptest-regressions/`, so it
+//! is replayed first on later runs. Set `PROPTEST_CASES` to run more cases.
+
+use std::collections::HashMap;
+use std::io::{BufReader, Cursor};
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::{select, subsequence, Index};
+use serde_json::{json, Value};
+use tokio::sync::{broadcast, oneshot, Mutex};
+
+use super::*;
+use crate::session_journal::temp_journal;
+
+const CASES: u32 = 256;
+
+/// Characters that can never start a message, for garbage before a frame.
+const GARBAGE_CHARS: &[char] = &[
+    'a', 'Z', ' ', '\t', '0', '9', ':', ',', '"', '\\', '[', ']', '}', '\u{fffd}', 'é', '语',
+];
+
+/// Characters for strings inside frames, including JSON escapes and control
+/// characters.
+const STRING_CHARS: &[char] = &[
+    'a', 'b', 'z', 'A', '0', '7', ' ', '.', ':', '{', '}', '[', ']', '"', '\\', '\n', '\t',
+    '\u{0}', '\u{7f}', 'é', 'ß', '语', '😀',
+];
+
+fn arb_string(max_len: usize) -> impl Strategy<Value = String> {
+    vec(select(STRING_CHARS), 0..=max_len).prop_map(String::from_iter)
+}
+
+fn arb_garbage(max_len: usize) -> impl Strategy<Value = String> {
+    vec(select(GARBAGE_CHARS), 0..=max_len).prop_map(String::from_iter)
+}
+
+fn arb_json() -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::Bool),
+        any::<i64>().prop_map(|number| json!(number)),
+        any::<u64>().prop_map(|number| json!(number)),
+        // Quarters stay exact through a text round trip.
+        (-4000..4000i32).prop_map(|quarters| json!(f64::from(quarters) / 4.0)),
+        arb_string(16).prop_map(Value::String),
+    ];
+    leaf.prop_recursive(3, 32, 4, |inner| {
+        prop_oneof![
+            vec(inner.clone(), 0..4).prop_map(Value::Array),
+            vec((arb_string(8), inner), 0..4)
+                .prop_map(|entries| Value::Object(entries.into_iter().collect())),
+        ]
+    })
+}
+
+/// One well-formed line the sidecar may write.
+#[derive(Debug, Clone)]
+enum Frame {
+    Result {
+        id: u64,
+        result: Value,
+    },
+    Error {
+        id: u64,
+        code: i32,
+        kind: String,
+    },
+    /// A response whose id is a string, which no call is waiting on.
+    StringId {
+        id: String,
+    },
+    Notification {
+        method: String,
+        params: Value,
+    },
+}
+
+impl Frame {
+    fn to_line(&self) -> String {
+        let value = match self {
+            Self::Result { id, result } => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+            Self::Error { id, code, kind } => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": {"code": code, "message": "failed", "data": {"kind": kind}},
+            }),
+            Self::StringId { id } => json!({"jsonrpc": "2.0", "id": id, "result": {}}),
+            Self::Notification { method, params } => {
+                json!({"jsonrpc": "2.0", "method": method, "params": params})
+            }
+        };
+        value.to_string()
+    }
+}
+
+fn arb_frame(max_id: u64) -> impl Strategy<Value = Frame> {
+    prop_oneof![
+        (0..=max_id, arb_json()).prop_map(|(id, result)| Frame::Result { id, result }),
+        (0..=max_id, any::<i32>(), arb_string(12)).prop_map(|(id, code, kind)| Frame::Error {
+            id,
+            code,
+            kind
+        }),
+        arb_string(12).prop_map(|id| Frame::StringId { id }),
+        (arb_string(16), arb_json())
+            .prop_map(|(method, params)| Frame::Notification { method, params }),
+    ]
+}
+
+/// Damage a truncated write or a stray print can do to a line.
+#[derive(Debug, Clone)]
+enum Mutation {
+    Truncate(Index),
+    Insert(Index, Vec<u8>),
+    Remove(Index),
+    RepeatTail(Index),
+}
+
+fn arb_mutation() -> impl Strategy<Value = Mutation> {
+    prop_oneof![
+        any::<Index>().prop_map(Mutation::Truncate),
+        (any::<Index>(), vec(any::<u8>(), 1..8))
+            .prop_map(|(at, noise)| Mutation::Insert(at, noise)),
+        any::<Index>().prop_map(Mutation::Remove),
+        any::<Index>().prop_map(Mutation::RepeatTail),
+    ]
+}
+
+fn mutate(line: &str, mutations: &[Mutation]) -> String {
+    let mut bytes = line.as_bytes().to_vec();
+    for mutation in mutations {
+        // Positions range over the line including its end.
+        let at = |index: &Index| index.index(bytes.len() + 1);
+        match mutation {
+            Mutation::Truncate(index) => bytes.truncate(at(index)),
+            Mutation::Insert(index, noise) => {
+                let at = at(index);
+                bytes.splice(at..at, noise.iter().copied());
+            }
+            Mutation::Remove(index) => {
+                let at = at(index);
+                if at < bytes.len() {
+                    bytes.remove(at);
+                }
+            }
+            Mutation::RepeatTail(index) => {
+                let copy = bytes[at(index)..].to_vec();
+                bytes.extend(copy);
+            }
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Garbage before a frame with up to `MAX_RESYNC_ATTEMPTS - 1` stray braces,
+/// each of which costs one resync attempt.
+fn arb_prefix() -> impl Strategy<Value = String> {
+    (arb_garbage(40), vec(any::<Index>(), 0..MAX_RESYNC_ATTEMPTS)).prop_map(
+        |(mut prefix, braces)| {
+            for index in braces {
+                let boundaries: Vec<usize> = prefix
+                    .char_indices()
+                    .map(|(at, _)| at)
+                    .chain([prefix.len()])
+                    .collect();
+                prefix.insert(*index.get(&boundaries), '{');
+            }
+            prefix
+        },
+    )
+}
+
+/// One line of a stream for the tolerance property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineKind {
+    Valid,
+    Garbage,
+    Blank,
+}
+
+fn arb_line_kind() -> impl Strategy<Value = LineKind> {
+    prop_oneof![
+        2 => Just(LineKind::Valid),
+        2 => Just(LineKind::Garbage),
+        1 => Just(LineKind::Blank),
+    ]
+}
+
+/// Lengths right around the limit, or anywhere up to twice it.
+fn arb_oversized_len() -> impl Strategy<Value = usize> {
+    prop_oneof![
+        select(vec![
+            MAX_LINE_LENGTH - 2,
+            MAX_LINE_LENGTH - 1,
+            MAX_LINE_LENGTH,
+            MAX_LINE_LENGTH + 1,
+        ]),
+        64..MAX_LINE_LENGTH * 2,
+    ]
+}
+
+fn padded_notification(len: usize) -> String {
+    let empty = r#"{"jsonrpc":"2.0","method":"pad","params":""}"#;
+    let padding = len.saturating_sub(empty.len());
+    format!(
+        r#"{{"jsonrpc":"2.0","method":"pad","params":"{}"}}"#,
+        "x".repeat(padding)
+    )
+}
+
+/// Calls waiting on responses, and what the sidecar sends meanwhile.
+#[derive(Debug)]
+struct CorrelationCase {
+    pending_ids: Vec<u64>,
+    frames: Vec<Frame>,
+}
+
+fn arb_correlation_case() -> impl Strategy<Value = CorrelationCase> {
+    let pending_ids = subsequence((1..=24).collect::<Vec<u64>>(), 0..12).prop_shuffle();
+    // Ids up to 32 include duplicates and ids no call is waiting on. Results
+    // carry their position so each call can tell which response it got.
+    let frames = vec(arb_frame(32), 0..48).prop_map(|frames| {
+        frames
+            .into_iter()
+            .enumerate()
+            .map(|(seq, frame)| match frame {
+                Frame::Result { id, .. } => Frame::Result {
+                    id,
+                    result: json!({ "seq": seq }),
+                },
+                frame => frame,
+            })
+            .collect()
+    });
+    (pending_ids, frames).prop_map(|(pending_ids, frames)| CorrelationCase {
+        pending_ids,
+        frames,
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    #[test]
+    fn prop_frames_round_trip_through_incoming_message(frame in arb_frame(u64::MAX)) {
+        let line = frame.to_line();
+        let message: IncomingMessage = serde_json::from_str(&line)
+            .map_err(|e| TestCaseError::fail(format!("parse failed: {e}")))?;
+        match (frame, message) {
+            (Frame::Result { id, result }, IncomingMessage::Response(response)) => {
+                prop_assert_eq!(response.id, Some(RequestId::Number(id)));
+                // A null result reads as no result at all.
+                prop_assert_eq!(response.result.unwrap_or(Value::Null), result);
+            }
+            (Frame::Error { id, code, kind }, IncomingMessage::Response(response)) => {
+                prop_assert_eq!(&response.id, &Some(RequestId::Number(id)));
+                prop_assert!(!response.is_success(), "error read as success");
+                prop_assert_eq!(response.error_kind(), Some(kind.as_str()));
+                prop_assert_eq!(response.error.map(|e| e.code), Some(code));
+            }
+            (Frame::StringId { id }, IncomingMessage::Response(response)) => {
+                prop_assert_eq!(response.id, Some(RequestId::String(id)));
+            }
+            (Frame::Notification { method, params }, message) => {
+                prop_assert!(!message.has_id(), "notification has an id");
+                let event = parse_notification_event(&line)
+                    .ok_or_else(|| TestCaseError::fail("not parsed as a notification"))?;
+                prop_assert_eq!(event.method, method);
+                prop_assert_eq!(event.params, params);
+            }
+            (_, message) => prop_assert!(false, "parsed as {:?}", message),
+        }
+    }
+
+    #[test]
+    fn prop_resync_only_delivers_complete_messages_from_malformed_frames(
+        frame in arb_frame(64),
+        mutations in vec(arb_mutation(), 1..4),
+    ) {
+        let line = mutate(&frame.to_line(), &mutations);
+        let (_dir, journal) = temp_journal();
+        let mut resync = ProtocolResync::new(u32::MAX, &journal);
+        match resync.check(&line) {
+            LineVerdict::Message(_, raw) => {
+                prop_assert!(line.contains(raw), "raw text not from the line");
+                prop_assert!(
+                    serde_json::from_str::<IncomingMessage>(raw).is_ok(),
+                    "raw text {:?} is not a message on its own",
+                    raw
+                );
+            }
+            LineVerdict::Skip => {}
+            LineVerdict::Fatal => prop_assert!(false, "fatal below tolerance"),
+        }
+    }
+
+    #[test]
+    fn prop_resync_recovers_frame_between_garbage(
+        prefix in arb_prefix(),
+        frame in arb_frame(64),
+        suffix in arb_string(40),
+    ) {
+        let expected = frame.to_line();
+        let line = format!("{prefix}{expected}{suffix}");
+        let (_dir, journal) = temp_journal();
+        match ProtocolResync::new(0, &journal).check(&line) {
+            LineVerdict::Message(_, raw) => prop_assert_eq!(raw, expected.as_str()),
+            other => prop_assert!(false, "verdict {:?}", other),
+        }
+    }
+
+    #[test]
+    fn prop_resync_fatal_only_after_tolerance_exceeded(
+        tolerance in 0u32..5,
+        lines in vec(arb_line_kind(), 0..40),
+    ) {
+        let valid = r#"{"jsonrpc":"2.0","method":"audio:level","params":{}}"#;
+        let (_dir, journal) = temp_journal();
+        let mut resync = ProtocolResync::new(tolerance, &journal);
+        let mut consecutive = 0;
+        for (index, kind) in lines.iter().enumerate() {
+            let verdict = match kind {
+                LineKind::Valid => resync.check(valid),
+                LineKind::Garbage => resync.check("Warning: fp16 not supported"),
+                LineKind::Blank => resync.check("  \r\n"),
+            };
+            let expected_fatal = match kind {
+                LineKind::Valid => {
+                    consecutive = 0;
+                    false
+                }
+                LineKind::Garbage => {
+                    consecutive += 1;
+                    consecutive > tolerance
+                }
+                LineKind::Blank => false,
+            };
+            let ok = match verdict {
+                LineVerdict::Message(..) => *kind == LineKind::Valid,
+                LineVerdict::Skip => *kind != LineKind::Valid && !expected_fatal,
+                LineVerdict::Fatal => expected_fatal,
+            };
+            prop_assert!(ok, "line {} ({:?}) gave {:?}", index, kind, verdict);
+            if expected_fatal {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn prop_responses_resolve_the_call_with_their_id_once(case in arb_correlation_case()) {
+        let pending = Mutex::new(HashMap::new());
+        let mut receivers = HashMap::new();
+        for &id in &case.pending_ids {
+            let (sender, receiver) = oneshot::channel();
+            pending
+                .blocking_lock()
+                .insert(id, PendingRequest { sender });
+            receivers.insert(id, receiver);
+        }
+        let (notification_tx, mut notification_rx) =
+            broadcast::channel(case.frames.len().max(1));
+
+        for frame in &case.frames {
+            let line = frame.to_line();
+            let message = serde_json::from_str::<IncomingMessage>(&line)
+                .map_err(|e| TestCaseError::fail(format!("parse failed: {e}")))?;
+            dispatch_message(message, &line, &pending, &notification_tx);
+        }
+
+        // Each call gets the first response with its id; later ones are dropped.
+        let first_response = |id: u64| {
+            case.frames.iter().find(|frame| {
+                matches!(frame, Frame::Result { id: got, .. } | Frame::Error { id: got, .. } if *got == id)
+            })
+        };
+        let still_pending = pending.blocking_lock();
+        for (id, mut receiver) in receivers {
+            match (first_response(id), receiver.try_recv()) {
+                (Some(Frame::Result { result, .. }), Ok(Ok(response))) => {
+                    prop_assert_eq!(response.result.as_ref(), Some(result), "call {}", id);
+                }
+                (Some(Frame::Error { kind, .. }), Ok(Ok(response))) => {
+                    prop_assert_eq!(response.error_kind(), Some(kind.as_str()), "call {}", id);
+                }
+                (None, Err(oneshot::error::TryRecvError::Empty)) => {
+                    prop_assert!(
+                        still_pending.contains_key(&id),
+                        "call {} dropped without a response",
+                        id
+                    );
+                    continue;
+                }
+                (expected, got) => {
+                    prop_assert!(false, "call {}: expected {:?}, got {:?}", id, expected, got)
+                }
+            }
+            prop_assert!(
+                !still_pending.contains_key(&id),
+                "call {} still pending after its response",
+                id
+            );
+        }
+
+        // Notifications reach subscribers in order, responses never do.
+        for frame in &case.frames {
+            if let Frame::Notification { method, params } = frame {
+                let event = notification_rx.try_recv().map_err(|e| {
+                    TestCaseError::fail(format!("missing notification {method:?}: {e}"))
+                })?;
+                prop_assert_eq!(&event.method, method, "out of order");
+                prop_assert_eq!(&event.params, params, "out of order");
+            }
+        }
+        prop_assert!(
+            notification_rx.try_recv().is_err(),
+            "response broadcast as a notification"
+        );
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn prop_oversized_lines_are_bounded_and_dropped(
+        len in arb_oversized_len(),
+        chunk in 1..=64 * 1024usize,
+    ) {
+        let line = padded_notification(len);
+        let next = r#"{"jsonrpc":"2.0","id":1,"result":{}}"#;
+        let input = format!("{line}\n{next}\n");
+        let mut reader = BufReader::with_capacity(chunk, Cursor::new(input.into_bytes()));
+        let mut buf = Vec::new();
+
+        let total = read_line_bounded(&mut reader, &mut buf)?;
+        prop_assert_eq!(total, line.len() + 1);
+        prop_assert_eq!(buf.len(), total.min(MAX_LINE_LENGTH + 1));
+
+        // The newline counts toward the limit.
+        let fits = line.len() < MAX_LINE_LENGTH;
+        let read = String::from_utf8_lossy(&buf);
+        let (_dir, journal) = temp_journal();
+        let verdict = ProtocolResync::new(1, &journal).check(&read);
+        prop_assert_eq!(
+            matches!(verdict, LineVerdict::Message(..)),
+            fits,
+            "{} byte line gave {:?}",
+            line.len(),
+            verdict
+        );
+
+        // The next line is read intact.
+        read_line_bounded(&mut reader, &mut buf)?;
+        let next_line = format!("{next}\n");
+        prop_assert_eq!(buf.as_slice(), next_line.as_bytes(), "next line damaged");
+        let eof = read_line_bounded(&mut reader, &mut buf)?;
+        prop_assert_eq!(eof, 0, "missing end of stream");
+    }
+}
@@ -0,0 +1,462 @@
+//! Byte channels that carry the sidecar line protocol.
+//!
+//! By default the sidecar speaks NDJSON over its stdin and stdout. A debugger
+//! or profiler wrapping the sidecar may print to stdout itself, which
+//! corrupts that stream. With `OPENVOICY_SIDECAR_TRANSPORT=socket` the host
+//! instead listens on a Unix domain socket (on Windows, a pair of named
+//! pipes, one per direction) and passes its address to the sidecar in
+//! `OPENVOICY_IPC_ENDPOINT`. The sidecar connects back and uses it, leaving
+//! stdout to whatever wraps it. A sidecar that does not connect in time is
+//! talked to over stdio as before. Framing and messages are the same on
+//! every transport.
+
+use std::io::{self, BufReader, ErrorKind, Read, Write};
+use std::process::{ChildStdin, ChildStdout};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::read_line_bounded;
+
+/// Host-side selection of the transport for new sidecar processes.
+pub const TRANSPORT_ENV: &str = "OPENVOICY_SIDECAR_TRANSPORT";
+
+/// Address the sidecar connects back to: `unix:<path>` or `pipe:<name>`.
+pub const ENDPOINT_ENV: &str = "OPENVOICY_IPC_ENDPOINT";
+
+/// How often a pending connection is polled.
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// A line-oriented, full-duplex channel to the sidecar. One thread reads
+/// while another writes.
+pub trait Transport: Send + Sync {
+    /// Short name for logs ("stdio", "unix_socket", "named_pipe").
+    fn name(&self) -> &'static str;
+
+    /// Write one message and flush it; the newline is added here.
+    fn write_line(&self, line: &str) -> io::Result<()>;
+
+    /// Read one line into `buf` like [`read_line_bounded`]. Returns 0 at
+    /// end of stream.
+    fn read_line(&self, buf: &mut Vec<u8>) -> io::Result<usize>;
+
+    /// Close the sending half so the sidecar sees end of input.
+    fn close_write(&self);
+}
+
+/// Transport requested for new sidecar processes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TransportKind {
+    #[default]
+    Stdio,
+    Socket,
+}
+
+impl TransportKind {
+    /// The transport named by [`TRANSPORT_ENV`].
+    pub fn from_env() -> Self {
+        Self::parse(std::env::var(TRANSPORT_ENV).ok().as_deref())
+    }
+
+    fn parse(value: Option<&str>) -> Self {
+        let Some(raw) = value else {
+            return Self::Stdio;
+        };
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "" | "stdio" => Self::Stdio,
+            "socket" | "unix" | "pipe" => Self::Socket,
+            _ => {
+                log::warn!("Unknown {} '{}', using stdio", TRANSPORT_ENV, raw);
+                Self::Stdio
+            }
+        }
+    }
+}
+
+/// Writer half that can be closed while the reader half stays open.
+pub trait HalfClose: Write {
+    /// Signal end of input to the peer. Dropping the writer afterwards must
+    /// be enough for writers without a separate shutdown.
+    fn close_write(&mut self) {}
+}
+
+impl HalfClose for ChildStdin {}
+
+impl HalfClose for std::fs::File {}
+
+#[cfg(unix)]
+impl HalfClose for std::os::unix::net::UnixStream {
+    fn close_write(&mut self) {
+        let _ = self.shutdown(std::net::Shutdown::Write);
+    }
+}
+
+/// A transport over a separate reader and writer.
+pub struct StreamTransport<R, W> {
+    name: &'static str,
+    reader: Mutex<BufReader<R>>,
+    writer: Mutex<Option<W>>,
+}
+
+impl<R: Read, W: HalfClose> StreamTransport<R, W> {
+    pub fn new(name: &'static str, reader: R, writer: W) -> Self {
+        Self {
+            name,
+            reader: Mutex::new(BufReader::new(reader)),
+            writer: Mutex::new(Some(writer)),
+        }
+    }
+}
+
+impl<R: Read + Send, W: HalfClose + Send> Transport for StreamTransport<R, W> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn write_line(&self, line: &str) -> io::Result<()> {
+        let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        let writer = writer
+            .as_mut()
+            .ok_or_else(|| io::Error::new(ErrorKind::BrokenPipe, "transport closed"))?;
+        writeln!(writer, "{}", line)?;
+        writer.flush()
+    }
+
+    fn read_line(&self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        let mut reader = self.reader.lock().unwrap_or_else(|e| e.into_inner());
+        read_line_bounded(&mut *reader, buf)
+    }
+
+    fn close_write(&self) {
+        let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(mut writer) = writer.take() {
+            writer.close_write();
+        }
+    }
+}
+
+/// The sidecar's stdin and stdout.
+pub fn stdio(stdin: ChildStdin, stdout: ChildStdout) -> StreamTransport<ChildStdout, ChildStdin> {
+    StreamTransport::new("stdio", stdout, stdin)
+}
+
+/// Where a sidecar about to be spawned connects back to.
+///
+/// On Unix this is a socket in a private (0700) directory, removed on drop.
+/// On Windows it is two named pipes with unguessable names that accept only
+/// local clients.
+pub struct SocketListener {
+    endpoint: String,
+    #[cfg(unix)]
+    listener: std::os::unix::net::UnixListener,
+    #[cfg(unix)]
+    dir: std::path::PathBuf,
+    #[cfg(windows)]
+    requests: std::os::windows::io::OwnedHandle,
+    #[cfg(windows)]
+    responses: std::os::windows::io::OwnedHandle,
+}
+
+impl SocketListener {
+    /// Value for [`ENDPOINT_ENV`] in the sidecar's environment.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Wait up to `timeout` for the sidecar to connect, giving up early
+    /// once `alive` reports that it exited.
+    pub fn accept(
+        self,
+        timeout: Duration,
+        mut alive: impl FnMut() -> bool,
+    ) -> io::Result<Box<dyn Transport>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(transport) = self.try_accept()? {
+                return Ok(transport);
+            }
+            if Instant::now() >= deadline {
+                return Err(io::Error::new(
+                    ErrorKind::TimedOut,
+                    "sidecar did not connect in time",
+                ));
+            }
+            if !alive() {
+                return Err(io::Error::new(
+                    ErrorKind::ConnectionAborted,
+                    "sidecar exited before connecting",
+                ));
+            }
+            std::thread::sleep(ACCEPT_POLL_INTERVAL);
+        }
+    }
+}
+
+#[cfg(unix)]
+impl SocketListener {
+    pub fn bind() -> io::Result<Self> {
+        use std::os::unix::fs::DirBuilderExt;
+
+        // The runtime dir is private to the user; the temp dir may not be.
+        let base = std::env::var_os("XDG_RUNTIME_DIR")
+            .map(std::path::PathBuf::from)
+            .filter(|dir| dir.is_dir())
+            .unwrap_or_else(std::env::temp_dir);
+        let dir = base.join(format!(
+            "openvoicy-ipc-{}-{}",
+            std::process::id(),
+            &uuid::Uuid::new_v4().simple().to_string()[..8]
+        ));
+        std::fs::DirBuilder::new().mode(0o700).create(&dir)?;
+        let path = dir.join("sidecar.sock");
+        let listener = match std::os::unix::net::UnixListener::bind(&path) {
+            Ok(listener) => listener,
+            Err(error) => {
+                let _ = std::fs::remove_dir_all(&dir);
+                return Err(error);
+            }
+        };
+        listener.set_nonblocking(true)?;
+        Ok(Self {
+            endpoint: format!("unix:{}", path.display()),
+            listener,
+            dir,
+        })
+    }
+
+    fn try_accept(&self) -> io::Result<Option<Box<dyn Transport>>> {
+        match self.listener.accept() {
+            Ok((stream, _)) => {
+                stream.set_nonblocking(false)?;
+                let reader = stream.try_clone()?;
+                Ok(Some(Box::new(StreamTransport::new(
+                    "unix_socket",
+                    reader,
+                    stream,
+                ))))
+            }
+            Err(error) if error.kind() == ErrorKind::WouldBlock => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for SocketListener {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[cfg(windows)]
+impl SocketListener {
+    pub fn bind() -> io::Result<Self> {
+        let base = format!(
+            r"\\.\pipe\openvoicy-ipc-{}-{}",
+            std::process::id(),
+            uuid::Uuid::new_v4().simple()
+        );
+        Ok(Self {
+            requests: create_pipe(&format!("{base}-requests"), PIPE_ACCESS_OUTBOUND)?,
+            responses: create_pipe(&format!("{base}-responses"), PIPE_ACCESS_INBOUND)?,
+            endpoint: format!("pipe:{base}"),
+        })
+    }
+
+    fn try_accept(&self) -> io::Result<Option<Box<dyn Transport>>> {
+        // Each call re-checks both pipes; a pipe that is already connected
+        // reports so again.
+        if !pipe_connected(&self.requests)? || !pipe_connected(&self.responses)? {
+            return Ok(None);
+        }
+        let requests = self.requests.try_clone()?;
+        let responses = self.responses.try_clone()?;
+        set_blocking(&requests)?;
+        set_blocking(&responses)?;
+        Ok(Some(Box::new(StreamTransport::new(
+            "named_pipe",
+            std::fs::File::from(responses),
+            std::fs::File::from(requests),
+        ))))
+    }
+}
+
+#[cfg(windows)]
+const PIPE_ACCESS_INBOUND: u32 = 0x1;
+#[cfg(windows)]
+const PIPE_ACCESS_OUTBOUND: u32 = 0x2;
+#[cfg(windows)]
+const FILE_FLAG_FIRST_PIPE_INSTANCE: u32 = 0x0008_0000;
+#[cfg(windows)]
+const PIPE_NOWAIT: u32 = 0x1;
+#[cfg(windows)]
+const PIPE_WAIT: u32 = 0x0;
+#[cfg(windows)]
+const PIPE_REJECT_REMOTE_CLIENTS: u32 = 0x8;
+#[cfg(windows)]
+const ERROR_PIPE_CONNECTED: i32 = 535;
+#[cfg(windows)]
+const ERROR_PIPE_LISTENING: i32 = 536;
+#[cfg(windows)]
+const PIPE_BUFFER_BYTES: u32 = 64 * 1024;
+
+/// A single-instance, non-blocking byte pipe; switched to blocking once
+/// the sidecar connects.
+#[cfg(windows)]
+fn create_pipe(name: &str, access: u32) -> io::Result<std::os::windows::io::OwnedHandle> {
+    use std::os::windows::ffi::OsStrExt;
+    use std::os::windows::io::{FromRawHandle, RawHandle};
+
+    let wide: Vec<u16> = std::ffi::OsStr::new(name)
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+    // SAFETY: `wide` is NUL-terminated and outlives the call.
+    let handle = unsafe {
+        CreateNamedPipeW(
+            wide.as_ptr(),
+            access | FILE_FLAG_FIRST_PIPE_INSTANCE,
+            PIPE_NOWAIT | PIPE_REJECT_REMOTE_CLIENTS,
+            1,
+            PIPE_BUFFER_BYTES,
+            PIPE_BUFFER_BYTES,
+            0,
+            std::ptr::null_mut(),
+        )
+    };
+    if handle as isize == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    // SAFETY: the handle was just created and is owned by nobody else.
+    Ok(unsafe { std::os::windows::io::OwnedHandle::from_raw_handle(handle as RawHandle) })
+}
+
+#[cfg(windows)]
+fn pipe_connected(pipe: &std::os::windows::io::OwnedHandle) -> io::Result<bool> {
+    use std::os::windows::io::AsRawHandle;
+
+    // SAFETY: the handle is a valid pipe for the duration of the call.
+    if unsafe { ConnectNamedPipe(pipe.as_raw_handle(), std::ptr::null_mut()) } != 0 {
+        return Ok(false);
+    }
+    let error = io::Error::last_os_error();
+    match error.raw_os_error() {
+        Some(ERROR_PIPE_CONNECTED) => Ok(true),
+        Some(ERROR_PIPE_LISTENING) => Ok(false),
+        _ => Err(error),
+    }
+}
+
+#[cfg(windows)]
+fn set_blocking(pipe: &std::os::windows::io::OwnedHandle) -> io::Result<()> {
+    use std::os::windows::io::AsRawHandle;
+
+    let mut mode = PIPE_WAIT;
+    // SAFETY: `mode` outlives the call; the other settings are left alone.
+    let ok = unsafe {
+        SetNamedPipeHandleState(
+            pipe.as_raw_handle(),
+            &mut mode,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    if ok == 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+#[link(name = "kernel32")]
+unsafe extern "system" {
+    fn CreateNamedPipeW(
+        name: *const u16,
+        open_mode: u32,
+        pipe_mode: u32,
+        max_instances: u32,
+        out_buffer_size: u32,
+        in_buffer_size: u32,
+        default_timeout: u32,
+        security_attributes: *mut std::ffi::c_void,
+    ) -> *mut std::ffi::c_void;
+    fn ConnectNamedPipe(pipe: *mut std::ffi::c_void, overlapped: *mut std::ffi::c_void) -> i32;
+    fn SetNamedPipeHandleState(
+        pipe: *mut std::ffi::c_void,
+        mode: *mut u32,
+        max_collection_count: *mut u32,
+        collect_data_timeout: *mut u32,
+    ) -> i32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transport_kind_parse() {
+        assert_eq!(TransportKind::parse(None), TransportKind::Stdio);
+        assert_eq!(TransportKind::parse(Some("")), TransportKind::Stdio);
+        assert_eq!(
+            TransportKind::parse(Some(" Socket ")),
+            TransportKind::Socket
+        );
+        assert_eq!(TransportKind::parse(Some("pipe")), TransportKind::Socket);
+        assert_eq!(
+            TransportKind::parse(Some("carrier-pigeon")),
+            TransportKind::Stdio
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_socket_listener_round_trips_lines() {
+        use std::io::BufRead;
+        use std::os::unix::net::UnixStream;
+
+        let listener = SocketListener::bind().expect("bind should succeed");
+        let path = listener
+            .endpoint()
+            .strip_prefix("unix:")
+            .expect("unix endpoint")
+            .to_string();
+        let mut sidecar = UnixStream::connect(&path).expect("connect should succeed");
+        let transport = listener
+            .accept(Duration::from_secs(5), || true)
+            .expect("accept should succeed");
+        assert_eq!(transport.name(), "unix_socket");
+        assert!(!std::path::Path::new(&path).exists());
+
+        transport
+            .write_line(r#"{"jsonrpc":"2.0","id":1,"method":"system.ping"}"#)
+            .unwrap();
+        let mut request = String::new();
+        std::io::BufReader::new(sidecar.try_clone().unwrap())
+            .read_line(&mut request)
+            .unwrap();
+        assert!(request.ends_with("\"system.ping\"}\n"));
+
+        sidecar
+            .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":{}}\n")
+            .unwrap();
+        let mut buf = Vec::new();
+        assert!(transport.read_line(&mut buf).unwrap() > 0);
+        assert_eq!(buf, b"{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":{}}\n");
+
+        transport.close_write();
+        let mut rest = Vec::new();
+        sidecar.read_to_end(&mut rest).unwrap();
+        assert!(rest.is_empty());
+        assert!(transport.write_line("{}").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_socket_listener_gives_up_when_sidecar_exits() {
+        let listener = SocketListener::bind().expect("bind should succeed");
+        let error = match listener.accept(Duration::from_secs(5), || false) {
+            Ok(_) => panic!("nothing connected"),
+            Err(error) => error,
+        };
+        assert_eq!(error.kind(), ErrorKind::ConnectionAborted);
+    }
+}
@@ -140,6 +140,8 @@ impl TimeoutConfig {
             "system.shutdown" => 2,
             "audio.list_devices" => 2,
             "audio.set_device" => 2,
+            "audio.get_gain" => 2,
+            "audio.set_gain" => 2,
             "audio.meter_start" => 2,
             "audio.meter_stop" => 2,
             "model.get_status" => 2,
@@ -147,9 +149,16 @@ impl TimeoutConfig {
             "model.install" => 1200,  // legacy alias compatibility
             "model.purge_cache" => 10,
             "asr.initialize" => 1200, // 20 minutes for first-run download
+            "asr.unload" => 10, // waits for an in-flight initialize
+            "batch.compare_models" => 600, // loads every compared model in turn
+            "file.transcribe" => 600, // dropped files can be long recordings
             "recording.start" => 2,
             "recording.stop" => 2,
             "recording.cancel" => 2,
+            "recording.split" => 2,
+            "recording.export_audio" => 5,
+            "transcription.get_result" => 2,
+            "transcription.retry" => 2,
             "replacements.set_rules" => 2,
             "status.get" => 2,
         };
@@ -0,0 +1,403 @@
+//! Learned capitalization dictionary for proper nouns.
+//!
+//! Watches for words whose capitalization the user keeps correcting — by
+//! editing history entries, or through replacement rules rewriting the raw
+//! transcript — and, once a spelling has been corrected often enough, applies
+//! it to new transcripts automatically. Entries are stored next to the config
+//! and can be reviewed and pruned from settings.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+
+use crate::config;
+
+/// Dictionary file name inside the config directory.
+const DICTIONARY_FILE_NAME: &str = "learned_dictionary.json";
+
+/// Corrections needed before a spelling is applied to new transcripts.
+pub const LEARN_THRESHOLD: u32 = 2;
+
+/// Maximum learned words; the least recently corrected is evicted when full.
+const MAX_LEARNED_WORDS: usize = 1000;
+
+static WORD_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\w[\w'\-]*").unwrap());
+
+static LEARNED_DICTIONARY: Lazy<LearnedDictionary> =
+    Lazy::new(|| LearnedDictionary::new(config::config_dir().join(DICTIONARY_FILE_NAME)));
+
+/// Get the process-wide learned dictionary.
+pub fn global_dictionary() -> &'static LearnedDictionary {
+    &LEARNED_DICTIONARY
+}
+
+/// Learn from a correction in `dictionary` (usually [`global_dictionary`]),
+/// logging (not propagating) write failures.
+pub fn learn(dictionary: &LearnedDictionary, original: &str, corrected: &str) {
+    if let Err(error) = dictionary.observe(original, corrected) {
+        log::warn!("Failed to update learned dictionary: {}", error);
+    }
+}
+
+/// A word whose capitalization the user has corrected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LearnedWord {
+    /// Preferred spelling, e.g. "PostgreSQL".
+    pub word: String,
+    /// Times the user corrected a transcript to this spelling.
+    pub corrections: u32,
+    /// Whether the spelling is applied to new transcripts.
+    pub active: bool,
+    /// When the spelling was last corrected.
+    pub last_corrected: DateTime<Utc>,
+}
+
+/// JSON-backed dictionary of learned spellings, keyed by lowercase word.
+#[derive(Debug)]
+pub struct LearnedDictionary {
+    path: PathBuf,
+    /// Learned words; loaded from disk on first use.
+    words: Mutex<Option<BTreeMap<String, LearnedWord>>>,
+}
+
+impl LearnedDictionary {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            words: Mutex::new(None),
+        }
+    }
+
+    /// Record capitalization corrections between `original` and `corrected`.
+    ///
+    /// Returns the entries that were created or updated.
+    pub fn observe(&self, original: &str, corrected: &str) -> io::Result<Vec<LearnedWord>> {
+        let corrections = capitalization_corrections(original, corrected);
+        if corrections.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.with_words(|words| {
+            let now = Utc::now();
+            let mut updated = Vec::with_capacity(corrections.len());
+            for spelling in corrections {
+                let entry = words
+                    .entry(spelling.to_lowercase())
+                    .or_insert_with(|| LearnedWord {
+                        word: spelling.clone(),
+                        corrections: 0,
+                        active: false,
+                        last_corrected: now,
+                    });
+                // A different spelling of the same word starts the count over.
+                if entry.word != spelling {
+                    entry.word = spelling;
+                    entry.corrections = 0;
+                }
+                entry.corrections = entry.corrections.saturating_add(1);
+                entry.active = entry.corrections >= LEARN_THRESHOLD;
+                entry.last_corrected = now;
+                updated.push(entry.clone());
+            }
+
+            while words.len() > MAX_LEARNED_WORDS {
+                let Some(oldest) = words
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_corrected)
+                    .map(|(key, _)| key.clone())
+                else {
+                    break;
+                };
+                words.remove(&oldest);
+            }
+
+            Ok(updated)
+        })
+    }
+
+    /// Rewrite active learned words in `text` to their preferred spelling.
+    pub fn apply(&self, text: &str) -> String {
+        let spellings = self
+            .with_words(|words| {
+                Ok::<_, io::Error>(
+                    words
+                        .iter()
+                        .filter(|(_, entry)| entry.active)
+                        .map(|(key, entry)| (key.clone(), entry.word.clone()))
+                        .collect::<HashMap<_, _>>(),
+                )
+            })
+            .unwrap_or_default();
+        if spellings.is_empty() {
+            return text.to_string();
+        }
+
+        let mut keys: Vec<&String> = spellings.keys().collect();
+        // Longest first so a hyphenated entry wins over a learned word it starts with.
+        keys.sort_by_key(|key| std::cmp::Reverse(key.len()));
+        let alternation = keys
+            .iter()
+            .map(|key| regex::escape(key))
+            .collect::<Vec<_>>()
+            .join("|");
+        let Ok(pattern) = Regex::new(&format!(r"(?i)\b(?:{alternation})\b")) else {
+            return text.to_string();
+        };
+
+        pattern
+            .replace_all(text, |captures: &Captures| {
+                let matched = &captures[0];
+                spellings
+                    .get(&matched.to_lowercase())
+                    .cloned()
+                    .unwrap_or_else(|| matched.to_string())
+            })
+            .into_owned()
+    }
+
+    /// All learned words, sorted alphabetically.
+    pub fn entries(&self) -> Vec<LearnedWord> {
+        self.with_words(|words| Ok::<_, io::Error>(words.values().cloned().collect()))
+            .unwrap_or_default()
+    }
+
+    /// Forget a learned word (case-insensitive). Returns whether it existed.
+    pub fn remove(&self, word: &str) -> io::Result<bool> {
+        let key = word.trim().to_lowercase();
+        self.with_words(|words| Ok(words.remove(&key).is_some()))
+    }
+
+    /// Replace all learned words, e.g. with the result of a settings sync.
+    pub fn replace_all(&self, entries: Vec<LearnedWord>) -> io::Result<()> {
+        self.with_words(|words| {
+            *words = entries
+                .into_iter()
+                .map(|entry| (entry.word.to_lowercase(), entry))
+                .collect();
+            Ok(())
+        })
+    }
+
+    /// Run `f` against the loaded words, saving afterwards if they changed.
+    fn with_words<T>(
+        &self,
+        f: impl FnOnce(&mut BTreeMap<String, LearnedWord>) -> io::Result<T>,
+    ) -> io::Result<T> {
+        let mut guard = self
+            .words
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let words = guard.get_or_insert_with(|| self.load());
+        let before = words.clone();
+        let result = f(words)?;
+        if *words != before {
+            self.save(words)?;
+        }
+        Ok(result)
+    }
+
+    fn load(&self) -> BTreeMap<String, LearnedWord> {
+        let Ok(contents) = fs::read_to_string(&self.path) else {
+            return BTreeMap::new();
+        };
+        match serde_json::from_str::<Vec<LearnedWord>>(&contents) {
+            Ok(entries) => entries
+                .into_iter()
+                .map(|entry| (entry.word.to_lowercase(), entry))
+                .collect(),
+            Err(error) => {
+                log::warn!("Ignoring unreadable learned dictionary: {}", error);
+                BTreeMap::new()
+            }
+        }
+    }
+
+    fn save(&self, words: &BTreeMap<String, LearnedWord>) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let entries: Vec<&LearnedWord> = words.values().collect();
+        let contents = serde_json::to_string_pretty(&entries)?;
+        let temp_path = self.path.with_extension("json.tmp");
+        fs::write(&temp_path, contents)?;
+        fs::rename(&temp_path, &self.path)
+    }
+}
+
+/// Words in `corrected` that appear in `original` with different capitalization.
+///
+/// Only changes that add capitals are learned, and capitalizing the first
+/// letter of a sentence is ignored since it says nothing about the word.
+fn capitalization_corrections(original: &str, corrected: &str) -> Vec<String> {
+    let mut original_spellings: HashMap<String, Vec<&str>> = HashMap::new();
+    for token in WORD_RE.find_iter(original) {
+        original_spellings
+            .entry(token.as_str().to_lowercase())
+            .or_default()
+            .push(token.as_str());
+    }
+
+    let mut corrections: Vec<String> = Vec::new();
+    for token in WORD_RE.find_iter(corrected) {
+        let spelling = token.as_str();
+        let lowercase = spelling.to_lowercase();
+        if spelling.chars().count() < 2 || spelling == lowercase {
+            continue;
+        }
+        let Some(previous) = original_spellings.get(&lowercase) else {
+            continue;
+        };
+        if previous.contains(&spelling) {
+            continue;
+        }
+        if is_sentence_start(corrected, token.start())
+            && previous
+                .iter()
+                .any(|before| differs_only_in_first_letter(before, spelling))
+        {
+            continue;
+        }
+        if !corrections.iter().any(|existing| existing == spelling) {
+            corrections.push(spelling.to_string());
+        }
+    }
+    corrections
+}
+
+fn is_sentence_start(text: &str, position: usize) -> bool {
+    let before = text[..position].trim_end_matches([' ', '\t']);
+    before.is_empty() || before.ends_with(['.', '!', '?', '\n'])
+}
+
+fn differs_only_in_first_letter(before: &str, after: &str) -> bool {
+    let mut before_chars = before.chars();
+    let mut after_chars = after.chars();
+    before_chars.next();
+    after_chars.next();
+    before_chars.eq(after_chars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn dictionary() -> (tempfile::TempDir, LearnedDictionary) {
+        let dir = tempdir().unwrap();
+        let dictionary = LearnedDictionary::new(dir.path().join("learned.json"));
+        (dir, dictionary)
+    }
+
+    #[test]
+    fn test_capitalization_corrections_ignore_sentence_starts_and_spelling_changes() {
+        assert_eq!(
+            capitalization_corrections(
+                "ask kubernetes about postgresql",
+                "ask Kubernetes about PostgreSQL"
+            ),
+            vec!["Kubernetes", "PostgreSQL"]
+        );
+        assert!(capitalization_corrections("hello there. okay", "Hello there. Okay").is_empty());
+        assert!(capitalization_corrections("teh cat", "The cat").is_empty());
+        assert!(capitalization_corrections("Paris is big", "paris is big").is_empty());
+        assert_eq!(
+            capitalization_corrections("iphone sales", "IPhone sales"),
+            vec!["IPhone"]
+        );
+    }
+
+    #[test]
+    fn test_word_is_applied_after_threshold() {
+        let (_dir, dictionary) = dictionary();
+
+        let first = dictionary
+            .observe("call kubernetes", "call Kubernetes")
+            .unwrap();
+        assert_eq!(first.len(), 1);
+        assert!(!first[0].active);
+        assert_eq!(dictionary.apply("deploy kubernetes"), "deploy kubernetes");
+
+        let second = dictionary
+            .observe("use kubernetes", "use Kubernetes")
+            .unwrap();
+        assert!(second[0].active);
+        assert_eq!(
+            dictionary.apply("KUBERNETES and kubernetes-native"),
+            "Kubernetes and Kubernetes-native"
+        );
+    }
+
+    #[test]
+    fn test_learn_writes_to_the_given_dictionary() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("learned.json");
+        let dictionary = LearnedDictionary::new(path.clone());
+
+        learn(&dictionary, "ask kubernetes", "ask Kubernetes");
+        learn(&dictionary, "no change", "no change");
+
+        let entries = LearnedDictionary::new(path).entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].word, "Kubernetes");
+    }
+
+    #[test]
+    fn test_new_spelling_resets_count() {
+        let (_dir, dictionary) = dictionary();
+        dictionary.observe("openai", "Openai").unwrap();
+        dictionary.observe("openai", "Openai").unwrap();
+
+        let updated = dictionary.observe("openai", "OpenAI").unwrap();
+
+        assert_eq!(updated[0].word, "OpenAI");
+        assert_eq!(updated[0].corrections, 1);
+        assert!(!updated[0].active);
+    }
+
+    #[test]
+    fn test_entries_persist_and_can_be_removed() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("learned.json");
+        let dictionary = LearnedDictionary::new(path.clone());
+        dictionary.observe("github repo", "GitHub repo").unwrap();
+        dictionary.observe("github repo", "GitHub repo").unwrap();
+
+        let reopened = LearnedDictionary::new(path);
+        let entries = reopened.entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].word, "GitHub");
+        assert_eq!(entries[0].corrections, 2);
+
+        assert!(reopened.remove("GITHUB").unwrap());
+        assert!(!reopened.remove("github").unwrap());
+        assert!(reopened.entries().is_empty());
+        assert_eq!(reopened.apply("github"), "github");
+    }
+
+    #[test]
+    fn test_replace_all_swaps_entries() {
+        let (_dir, dictionary) = dictionary();
+        dictionary.observe("github repo", "GitHub repo").unwrap();
+
+        dictionary
+            .replace_all(vec![LearnedWord {
+                word: "PostgreSQL".to_string(),
+                corrections: 3,
+                active: true,
+                last_corrected: Utc::now(),
+            }])
+            .unwrap();
+
+        let entries = dictionary.entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].word, "PostgreSQL");
+        assert_eq!(dictionary.apply("use postgresql"), "use PostgreSQL");
+    }
+}
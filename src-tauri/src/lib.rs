@@ -2,49 +2,146 @@
 //!
 //! This library provides the core functionality for the Voice Input Tool,
 //! a desktop application that transcribes speech to text using local ASR.
+//!
+//! The `desktop` feature (on by default) builds the Tauri app. The
+//! `headless` feature exposes the engine to other Rust applications through
+//! [`headless`]; without `desktop` it builds without the Tauri runtime.
+
+// Without the desktop command layer, much of the engine has no caller.
+#![cfg_attr(not(feature = "desktop"), allow(dead_code))]
 
+#[cfg(feature = "desktop")]
 use std::sync::Arc;
 
+#[cfg(feature = "desktop")]
 use tauri::Manager;
+#[cfg(feature = "desktop")]
 use tokio::sync::RwLock;
 
+mod a11y;
+mod actions;
+mod app_icon;
+mod app_update;
 mod audio_cue;
+mod call_detect;
 mod capabilities;
+#[cfg(feature = "desktop")]
+mod command_audit;
+#[cfg(feature = "desktop")]
 mod commands;
+mod companion;
 mod config;
 pub mod contracts;
+mod crash_reports;
+mod diarization;
+mod display_strategy;
+mod dnd;
+mod embedded_asr;
+mod error_recovery;
 mod errors;
 mod event_seq;
+mod file_transcription;
 mod focus;
+#[cfg(feature = "headless")]
+pub mod headless;
 mod history;
 mod history_persistence;
 mod hotkey;
+mod hotkey_leak;
+mod hotkey_practice;
+mod i18n;
 mod injection;
+mod injection_order;
 mod integration;
 pub mod ipc;
+mod learned_dictionary;
+mod live_flush;
 mod log_buffer;
+mod model_compare;
 mod model_defaults;
+mod model_license;
+mod network;
+mod notification_throttle;
 mod overlay;
+mod paste_calibration;
+mod pending_inserts;
+mod phrase_shortcuts;
+mod policy;
+mod power;
 mod recording;
+#[cfg(feature = "desktop")]
+mod recording_indicator;
+mod release_download;
+mod replacement_rules;
+mod runtime;
+mod screen_share;
+mod secrets;
+mod session;
+mod session_journal;
+mod session_lifecycle;
+mod share;
 mod sidecar;
+mod sidecar_update;
+mod smart_spacing;
+#[cfg(feature = "desktop")]
+mod startup_failure;
 mod state;
+mod state_snapshot;
+mod stats;
+mod subtitles;
 mod supervisor;
+mod sync;
+mod target_responsiveness;
+mod transcript_diff;
+#[cfg(feature = "desktop")]
 mod tray;
+mod typing_guard;
+mod vocabulary;
 mod watchdog;
+#[cfg(target_os = "linux")]
+mod wayland_shortcuts;
+mod word_filter;
+#[cfg(target_os = "linux")]
+mod xdg_portal;
 
 use history::TranscriptHistory;
 use history_persistence::build_history_persistence;
+#[cfg(feature = "desktop")]
 use integration::IntegrationManager;
+#[cfg(feature = "desktop")]
 use state::AppStateManager;
 
+/// Transcript history backed by the history file, as configured by `config`.
+fn load_transcript_history(config: &config::AppConfig) -> TranscriptHistory {
+    let history_max_entries = config.history.max_entries as usize;
+    let history_file = config::config_dir().join("history.jsonl");
+    let history_persistence = build_history_persistence(
+        history_file,
+        history_max_entries,
+        &config.history.persistence_mode,
+        config.history.encrypt_at_rest,
+    );
+    TranscriptHistory::with_capacity_and_persistence(history_max_entries, Some(history_persistence))
+}
+
 /// Integration manager wrapper for Tauri state.
+#[cfg(feature = "desktop")]
 pub struct IntegrationState(pub Arc<RwLock<IntegrationManager>>);
 
 /// Configure and run the Tauri application
+#[cfg(feature = "desktop")]
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // Initialize logging with diagnostics ring-buffer capture.
     log_buffer::init_buffer_logger(log::Level::Info);
+    crash_reports::install_panic_hook();
+
+    // Refuse to start on an unreadable config or beside another instance
+    if let Err(failure) = startup_failure::check_config_file(&config::config_path())
+        .and_then(|()| startup_failure::acquire_instance_lock(&config::config_dir()))
+    {
+        startup_failure::handle(failure);
+    }
 
     // Create shared state manager
     let state_manager = Arc::new(AppStateManager::new());
@@ -53,27 +150,17 @@ pub fn run() {
     let integration_manager = Arc::new(RwLock::new(IntegrationManager::new(Arc::clone(
         &state_manager,
     ))));
-    let initial_config = config::load_config();
-    let history_max_entries = initial_config.history.max_entries as usize;
-    let history_file = config::config_dir().join("history.jsonl");
-    let history_persistence = build_history_persistence(
-        history_file,
-        history_max_entries,
-        &initial_config.history.persistence_mode,
-        initial_config.history.encrypt_at_rest,
-    );
-    let transcript_history = TranscriptHistory::with_capacity_and_persistence(
-        history_max_entries,
-        Some(history_persistence),
-    );
+    let transcript_history = load_transcript_history(&config::load_config());
 
-    tauri::Builder::default()
+    let result = tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         // Manage state components
         .manage(IntegrationState(Arc::clone(&integration_manager)))
         .manage(state_manager)
         .manage(transcript_history)
+        .manage(pending_inserts::PendingInsertQueue::new())
         .manage(tray::TrayDeviceCache::new())
+        .manage(tray::TrayStatsCache::new())
         .invoke_handler(tauri::generate_handler![
             // State commands
             commands::get_app_state,
@@ -83,11 +170,19 @@ pub fn run() {
             commands::run_self_check,
             // Config commands
             commands::get_config,
+            commands::get_overlay_config,
             commands::update_config,
             commands::reset_config_to_defaults,
+            commands::sync_now,
+            // Secret commands
+            commands::set_secret,
+            commands::delete_secret,
+            commands::has_secret,
             // Audio commands
             commands::list_audio_devices,
             commands::set_audio_device,
+            commands::get_input_gain,
+            commands::set_input_gain,
             commands::start_mic_test,
             commands::stop_mic_test,
             // Model commands
@@ -95,19 +190,61 @@ pub fn run() {
             commands::get_model_catalog,
             commands::download_model,
             commands::purge_model_cache,
+            commands::compare_models,
+            commands::transcribe_file,
+            commands::get_pending_license,
+            commands::accept_model_license,
             commands::restart_sidecar,
+            commands::check_sidecar_update,
+            commands::apply_sidecar_update,
+            commands::check_app_update,
+            commands::download_app_update,
+            commands::install_app_update,
             // History commands
             commands::get_transcript_history,
+            commands::get_usage_stats,
+            commands::get_history_stats,
+            commands::get_app_icon,
             commands::copy_transcript,
             commands::copy_last_transcript,
             commands::clear_history,
+            commands::clear_history_filtered,
+            commands::set_transcript_pinned,
             commands::export_history,
+            commands::export_subtitles,
+            commands::share_history_entry,
+            commands::get_unfiltered_transcript,
+            commands::get_transcript_diff,
+            commands::get_low_confidence_words,
+            commands::edit_transcript,
+            commands::search_transcript_history,
+            commands::set_transcript_label,
+            commands::get_session_label,
+            commands::set_session_label,
+            commands::get_tag_suggestions,
+            // Vocabulary commands
+            commands::get_vocabulary,
+            commands::set_vocabulary,
+            commands::import_vocabulary,
+            commands::get_learned_dictionary,
+            commands::remove_learned_word,
+            commands::get_paste_calibration,
+            commands::reset_paste_calibration,
+            commands::get_phrase_shortcuts,
+            commands::save_phrase_shortcut,
+            commands::delete_phrase_shortcut,
             // Hotkey commands
             commands::get_hotkey_status,
             commands::set_hotkey,
+            commands::test_hotkey_leakage,
+            commands::report_hotkey_keydown,
+            commands::practice_hotkey,
             // Replacement commands
             commands::get_replacement_rules,
             commands::set_replacement_rules,
+            commands::get_temporary_replacement_rules,
+            commands::set_temporary_replacement_rules,
+            commands::clear_temporary_replacement_rules,
             commands::preview_replacement,
             commands::get_available_presets,
             commands::load_preset,
@@ -115,13 +252,54 @@ pub fn run() {
             commands::start_recording,
             commands::stop_recording,
             commands::cancel_recording,
+            commands::get_active_session,
             commands::toggle_enabled,
             commands::is_enabled,
             commands::set_enabled,
+            commands::get_enable_level,
+            commands::set_enable_level,
+            // Action commands
+            commands::list_actions,
+            commands::invoke_action,
+            // Injection commands
+            commands::set_injection_mode,
+            commands::confirm_injection,
+            commands::reinject_to_original_target,
+            commands::cancel_injection,
+            commands::get_pending_inserts,
+            commands::inject_pending_insert,
+            commands::dismiss_pending_insert,
+            commands::get_current_focus_info,
             // Diagnostics commands
             commands::generate_diagnostics,
             commands::get_recent_logs,
+            commands::get_command_audit_log,
+            commands::export_app_state_snapshot,
+            commands::replay_app_state_snapshot,
+            commands::get_pending_crash_reports,
+            commands::delete_crash_reports,
         ])
+        .on_window_event(|window, event| {
+            // Permissions are usually granted in System Settings, so re-check on return
+            if window.label() == "main" && matches!(event, tauri::WindowEvent::Focused(true)) {
+                commands::run_permission_preflight(window.app_handle());
+            }
+            // Audio files dropped on the main window are transcribed into history
+            if let tauri::WindowEvent::DragDrop(tauri::DragDropEvent::Drop { paths, .. }) = event {
+                if window.label() == "main" && !paths.is_empty() {
+                    tauri::async_runtime::spawn(commands::transcribe_dropped_files(
+                        window.app_handle().clone(),
+                        paths.clone(),
+                    ));
+                }
+            }
+            // Recording indicators must not be dismissable while recording
+            if recording_indicator::is_indicator_label(window.label()) {
+                if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                    api.prevent_close();
+                }
+            }
+        })
         .setup(|app| {
             // Configure sidecar path for development
             #[cfg(debug_assertions)]
@@ -149,13 +327,43 @@ pub fn run() {
             let tray_manager = Arc::new(RwLock::new(tray_manager));
 
             // Start tray update loop
-            tray::start_tray_loop(app_handle_for_tray, state_manager_for_tray, tray_manager);
+            tray::start_tray_loop(
+                app_handle_for_tray.clone(),
+                Arc::clone(&state_manager_for_tray),
+                tray_manager,
+            );
+
+            // Send queued crash reports when the user opted in
+            crash_reports::start_upload_loop();
+
+            // Drop history entries past the retention policy
+            history::start_prune_loop(app.handle().clone());
+
+            // Serve recording state to companion devices when enabled
+            companion::start_companion_loop(Arc::clone(&state_manager_for_tray));
+
+            // Keep recording badges on every monitor when required by config/policy
+            recording_indicator::start_indicator_loop(app_handle_for_tray, state_manager_for_tray);
+
+            // Check OS permissions up front instead of waiting for injection to fail
+            commands::run_permission_preflight(app.handle());
 
             // Set up integration manager with app handle and initialize
             let integration_state = app.state::<IntegrationState>();
             let integration_manager = Arc::clone(&integration_state.0);
             let app_handle = app.handle().clone();
 
+            // Sync settings with the user's endpoint in the background when enabled
+            let sync_manager = Arc::clone(&integration_manager);
+            sync::start_sync_loop(move |config| {
+                let manager_state = Arc::clone(&sync_manager);
+                tauri::async_runtime::spawn(async move {
+                    let manager = manager_state.read().await;
+                    manager.notify_overlay_config_changed();
+                    manager.sync_replacement_rules(&config).await;
+                });
+            });
+
             // Initialize integration manager in async context
             tauri::async_runtime::spawn(async move {
                 {
@@ -184,6 +392,18 @@ pub fn run() {
             log::info!("Voice Input Tool starting");
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!());
+    match result {
+        Ok(app) => app.run(|_, event| {
+            if let tauri::RunEvent::Exit = event {
+                // Saves are debounced; write the last one before exiting.
+                if let Err(e) = config::flush_config() {
+                    log::error!("Failed to save config on exit: {}", e);
+                }
+            }
+        }),
+        Err(error) => {
+            startup_failure::handle(startup_failure::StartupFailure::classify(&error));
+        }
+    }
 }
@@ -0,0 +1,184 @@
+//! Sentence boundaries for live continuous dictation.
+//!
+//! With `audio.live_flush_secs` set, the sidecar cuts a continuous recording
+//! at the first short gap once enough speech is buffered, so an utterance can
+//! end mid-sentence. Only complete sentences of each utterance are injected;
+//! the unfinished tail is held and placed in front of the next utterance, and
+//! whatever is still held when the session's final result arrives goes out
+//! with it. Text is therefore injected once, in order, and never retracted.
+
+use std::collections::HashMap;
+
+use crate::recording::RecordingEvent;
+
+/// Characters that end a sentence when followed by whitespace or the end of
+/// the text.
+const SENTENCE_END_CHARS: &[char] = &['.', '!', '?', '\u{2026}'];
+
+/// Full-width terminators end a sentence even without a following space.
+const CJK_SENTENCE_END_CHARS: &[char] = &['\u{3002}', '\u{FF01}', '\u{FF1F}'];
+
+/// Closing quotes and brackets that belong to the sentence they follow.
+const CLOSING_CHARS: &[char] = &[
+    '"', '\'', ')', ']', '}', '\u{201D}', '\u{2019}', '\u{300D}', '\u{300F}', '\u{FF09}',
+];
+
+/// Held text longer than this is released as is; someone talking without a
+/// sentence end (or with punctuation off) should still see their words.
+const MAX_HELD_CHARS: usize = 300;
+
+/// Unfinished sentence tails of live continuous sessions.
+#[derive(Debug, Default)]
+pub struct SentenceCarry {
+    held: HashMap<String, String>,
+}
+
+impl SentenceCarry {
+    /// Start holding sentence tails for `session_id`.
+    pub fn begin(&mut self, session_id: &str) {
+        self.held.insert(session_id.to_string(), String::new());
+    }
+
+    /// Text of an utterance that is ready to inject: the held tail plus the
+    /// complete sentences of `text`. The rest of `text` is held. Sessions
+    /// that were not begun pass `text` through unchanged.
+    pub fn release(&mut self, session_id: &str, text: &str) -> String {
+        let Some(held) = self.held.get_mut(session_id) else {
+            return text.to_string();
+        };
+        let combined = join(held, text);
+        let (ready, rest) = combined.split_at(complete_sentences_len(&combined));
+        let rest = rest.trim();
+        if rest.chars().count() > MAX_HELD_CHARS {
+            held.clear();
+            return combined;
+        }
+        let ready = ready.trim_end().to_string();
+        *held = rest.to_string();
+        ready
+    }
+
+    /// Stop tracking `session_id`, returning any text still held.
+    pub fn take_held(&mut self, session_id: &str) -> Option<String> {
+        self.held.remove(session_id).filter(|held| !held.is_empty())
+    }
+
+    /// Drop the held text of a session that ended without a final result.
+    pub fn discard_ended(&mut self, event: &RecordingEvent) {
+        match event {
+            RecordingEvent::Cancelled { session_id, .. }
+            | RecordingEvent::TooShort { session_id, .. }
+            | RecordingEvent::TranscriptionFailed { session_id, .. }
+            | RecordingEvent::TranscriptionTimeout { session_id, .. } => {
+                self.held.remove(session_id);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Join held text and the text that follows it with a single space.
+pub fn join(held: &str, text: &str) -> String {
+    let (held, text) = (held.trim(), text.trim());
+    match (held.is_empty(), text.is_empty()) {
+        (true, _) => text.to_string(),
+        (false, true) => held.to_string(),
+        (false, false) => format!("{held} {text}"),
+    }
+}
+
+/// Byte length of the prefix of `text` that ends on a sentence boundary.
+fn complete_sentences_len(text: &str) -> usize {
+    let mut boundary = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((_, ch)) = chars.next() {
+        let cjk = CJK_SENTENCE_END_CHARS.contains(&ch);
+        if !cjk && !SENTENCE_END_CHARS.contains(&ch) {
+            continue;
+        }
+        while chars
+            .peek()
+            .is_some_and(|(_, next)| CLOSING_CHARS.contains(next))
+        {
+            chars.next();
+        }
+        match chars.peek() {
+            None => boundary = text.len(),
+            Some(&(index, next)) if cjk || next.is_whitespace() => boundary = index,
+            // "3.5", "e.g" and the like.
+            Some(_) => {}
+        }
+    }
+    boundary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_release_holds_unfinished_sentence_for_next_utterance() {
+        let mut carry = SentenceCarry::default();
+        carry.begin("s1");
+
+        assert_eq!(
+            carry.release("s1", "First sentence. Second one is"),
+            "First sentence."
+        );
+        assert_eq!(
+            carry.release("s1", "still going! And a third"),
+            "Second one is still going!"
+        );
+        assert_eq!(carry.take_held("s1").as_deref(), Some("And a third"));
+        assert!(carry.take_held("s1").is_none());
+    }
+
+    #[test]
+    fn test_release_passes_through_sessions_that_were_not_begun() {
+        let mut carry = SentenceCarry::default();
+        assert_eq!(carry.release("s1", "no boundary here"), "no boundary here");
+        assert!(carry.take_held("s1").is_none());
+    }
+
+    #[test]
+    fn test_sentence_boundaries_skip_decimals_and_keep_closing_quotes() {
+        assert_eq!(complete_sentences_len("Pi is 3.14 roughly"), 0);
+        let text = "He said \"stop.\" Then";
+        assert_eq!(&text[..complete_sentences_len(text)], "He said \"stop.\"");
+        let cjk = "你好。我们";
+        assert_eq!(&cjk[..complete_sentences_len(cjk)], "你好。");
+        assert_eq!(complete_sentences_len("Done?"), "Done?".len());
+    }
+
+    #[test]
+    fn test_long_held_text_is_released_without_boundary() {
+        let mut carry = SentenceCarry::default();
+        carry.begin("s1");
+        let run_on = "word ".repeat(MAX_HELD_CHARS / 4);
+
+        assert_eq!(carry.release("s1", &run_on), run_on.trim());
+        assert!(carry.take_held("s1").is_none());
+    }
+
+    #[test]
+    fn test_discard_ended_drops_held_text() {
+        let mut carry = SentenceCarry::default();
+        carry.begin("s1");
+        carry.release("s1", "unfinished");
+
+        carry.discard_ended(&RecordingEvent::TranscriptionTimeout {
+            session_id: "s1".to_string(),
+            timestamp: Utc::now(),
+        });
+
+        assert_eq!(carry.release("s1", "later"), "later");
+    }
+
+    #[test]
+    fn test_join_uses_single_space() {
+        assert_eq!(join("", " text "), "text");
+        assert_eq!(join("held ", ""), "held");
+        assert_eq!(join("held", "text"), "held text");
+    }
+}
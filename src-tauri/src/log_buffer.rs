@@ -3,6 +3,7 @@
 //! Provides bounded log storage that can be included in bug reports.
 //! Features:
 //! - Bounded by line count (default 500) and byte size (default 256 KiB)
+//! - Filtering by level, module, and time for the diagnostics view
 //! - Thread-safe access
 //! - Automatic redaction of sensitive data (paths, transcripts)
 //! - Integration with the `log` crate via custom subscriber
@@ -44,6 +45,9 @@ pub struct LogEntry {
     pub target: String,
     /// Log message (redacted if necessary).
     pub message: String,
+    /// Parsed level used for filtering.
+    #[serde(skip)]
+    severity: Level,
     /// Approximate byte size of this entry.
     #[serde(skip)]
     byte_size: usize,
@@ -60,6 +64,7 @@ impl LogEntry {
             level: level.to_string(),
             target: target.to_string(),
             message: redacted_message,
+            severity: level,
             byte_size,
         }
     }
@@ -77,6 +82,31 @@ impl LogEntry {
     }
 }
 
+/// Criteria for selecting log entries; unset fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct LogFilter {
+    /// Most verbose level to include (`Warn` keeps warnings and errors).
+    pub level: Option<Level>,
+    /// Case-insensitive substring of the target, e.g. `sidecar`.
+    pub module: Option<String>,
+    /// Only entries recorded after this time.
+    pub since: Option<DateTime<Utc>>,
+}
+
+impl LogFilter {
+    /// Whether `entry` passes every set criterion.
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        self.level.is_none_or(|level| entry.severity <= level)
+            && self.module.as_deref().is_none_or(|module| {
+                entry
+                    .target
+                    .to_ascii_lowercase()
+                    .contains(&module.to_ascii_lowercase())
+            })
+            && self.since.is_none_or(|since| entry.timestamp > since)
+    }
+}
+
 /// Patterns for redacting sensitive data.
 static REDACTION_PATTERNS: Lazy<Vec<(Regex, &'static str)>> = Lazy::new(|| {
     vec![
@@ -127,7 +157,7 @@ static REDACTION_PATTERNS: Lazy<Vec<(Regex, &'static str)>> = Lazy::new(|| {
 });
 
 /// Redact sensitive information from a log message.
-fn redact_sensitive(message: &str) -> String {
+pub fn redact_sensitive(message: &str) -> String {
     let mut result = message.to_string();
 
     for (pattern, replacement) in REDACTION_PATTERNS.iter() {
@@ -181,6 +211,34 @@ impl LogRingBuffer {
         self.entries.read().unwrap().iter().cloned().collect()
     }
 
+    /// Formatted lines of the newest `count` entries, oldest first; empty
+    /// while the buffer is locked, so it is safe to call from a panic hook.
+    pub fn try_recent_lines(&self, count: usize) -> Vec<String> {
+        let Ok(entries) = self.entries.try_read() else {
+            return Vec::new();
+        };
+        let skip = entries.len().saturating_sub(count);
+        entries
+            .iter()
+            .skip(skip)
+            .map(LogEntry::format_line)
+            .collect()
+    }
+
+    /// Get the newest `count` entries passing `filter`, oldest first.
+    pub fn recent(&self, count: usize, filter: &LogFilter) -> Vec<LogEntry> {
+        let entries = self.entries.read().unwrap();
+        let mut recent: Vec<LogEntry> = entries
+            .iter()
+            .rev()
+            .filter(|entry| filter.matches(entry))
+            .take(count)
+            .cloned()
+            .collect();
+        recent.reverse();
+        recent
+    }
+
     /// Get entry count.
     pub fn len(&self) -> usize {
         self.entries.read().unwrap().len()
@@ -484,6 +542,56 @@ mod tests {
         assert!(buffer.byte_size() <= 200 + 100); // Allow some slack for last entry
     }
 
+    #[test]
+    fn test_recent_filters_by_level_and_module() {
+        let buffer = LogRingBuffer::new(100, 10000);
+
+        buffer.push(LogEntry::new(Level::Debug, "app::sidecar", "Spawned"));
+        buffer.push(LogEntry::new(Level::Warn, "app::sidecar", "Slow ping"));
+        buffer.push(LogEntry::new(Level::Error, "app::hotkey", "Grab failed"));
+        buffer.push(LogEntry::new(Level::Info, "app::Sidecar", "Ready"));
+
+        let filter = LogFilter {
+            level: Some(Level::Warn),
+            ..LogFilter::default()
+        };
+        let messages: Vec<String> = buffer
+            .recent(10, &filter)
+            .into_iter()
+            .map(|entry| entry.message)
+            .collect();
+        assert_eq!(messages, vec!["Slow ping", "Grab failed"]);
+
+        let filter = LogFilter {
+            module: Some("SIDECAR".to_string()),
+            ..LogFilter::default()
+        };
+        let messages: Vec<String> = buffer
+            .recent(2, &filter)
+            .into_iter()
+            .map(|entry| entry.message)
+            .collect();
+        assert_eq!(messages, vec!["Slow ping", "Ready"]);
+    }
+
+    #[test]
+    fn test_recent_filters_by_since() {
+        let buffer = LogRingBuffer::new(100, 10000);
+
+        let mut old = LogEntry::new(Level::Info, "test", "Old");
+        old.timestamp = Utc::now() - chrono::Duration::minutes(5);
+        buffer.push(old);
+        buffer.push(LogEntry::new(Level::Info, "test", "New"));
+
+        let filter = LogFilter {
+            since: Some(Utc::now() - chrono::Duration::minutes(1)),
+            ..LogFilter::default()
+        };
+        let entries = buffer.recent(10, &filter);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].message, "New");
+    }
+
     #[test]
     fn test_buffer_clear() {
         let buffer = LogRingBuffer::new(100, 10000);
@@ -0,0 +1,336 @@
+//! Side-by-side model comparison results.
+//!
+//! The auxiliary sidecar transcribes the same audio with each requested model;
+//! this module turns its per-model output into a comparison against the first
+//! model that succeeded, with word-level differences and real-time factors.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Most models one comparison may load, to bound its run time.
+pub const MAX_COMPARED_MODELS: usize = 4;
+
+/// Above this many word pairs the diff falls back to a whole replacement
+/// instead of building the LCS table.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// Audio to compare models on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComparisonAudio {
+    /// A recent session's recording, still retained by the live sidecar.
+    Session(String),
+    /// A WAV file on disk.
+    File(PathBuf),
+}
+
+impl ComparisonAudio {
+    /// Pick the audio source from command arguments; exactly one must be set.
+    pub fn from_args(
+        session_id: Option<String>,
+        audio_path: Option<String>,
+    ) -> Result<Self, String> {
+        let session_id = session_id.filter(|id| !id.trim().is_empty());
+        let audio_path = audio_path.filter(|path| !path.trim().is_empty());
+        match (session_id, audio_path) {
+            (Some(session_id), None) => Ok(Self::Session(session_id)),
+            (None, Some(path)) => Ok(Self::File(PathBuf::from(path))),
+            _ => Err("Provide either a session ID or an audio file path".to_string()),
+        }
+    }
+}
+
+/// Trim and de-duplicate requested model IDs, keeping request order.
+pub fn normalize_model_ids(model_ids: Vec<String>) -> Result<Vec<String>, String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(model_ids.len());
+    for model_id in model_ids {
+        let model_id = model_id.trim();
+        if !model_id.is_empty() && !normalized.iter().any(|existing| existing == model_id) {
+            normalized.push(model_id.to_string());
+        }
+    }
+    if normalized.is_empty() {
+        return Err("Select at least one model to compare".to_string());
+    }
+    if normalized.len() > MAX_COMPARED_MODELS {
+        return Err(format!(
+            "Compare at most {} models at a time",
+            MAX_COMPARED_MODELS
+        ));
+    }
+    Ok(normalized)
+}
+
+/// Why a single model could not be compared.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelComparisonError {
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffOp {
+    Equal,
+    /// Words only in this model's text.
+    Insert,
+    /// Words only in the baseline text.
+    Delete,
+}
+
+/// A run of consecutive words with the same diff operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffSegment {
+    pub op: DiffOp,
+    pub text: String,
+}
+
+/// One model's output in a comparison.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelComparisonResult {
+    pub model_id: String,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub confidence: Option<f32>,
+    /// Time to load the model into memory.
+    #[serde(default)]
+    pub load_ms: Option<u64>,
+    /// Inference time only.
+    #[serde(default)]
+    pub transcribe_ms: Option<u64>,
+    /// Inference time divided by audio length; below 1.0 is faster than real time.
+    #[serde(default)]
+    pub real_time_factor: Option<f64>,
+    #[serde(default)]
+    pub error: Option<ModelComparisonError>,
+    /// Differences from the baseline text; empty for the baseline itself.
+    #[serde(default, skip_deserializing)]
+    pub diff: Vec<DiffSegment>,
+    /// Inserted plus deleted words relative to the baseline.
+    #[serde(default, skip_deserializing)]
+    pub changed_words: u64,
+}
+
+/// Result of the `compare_models` command.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ModelComparison {
+    pub audio_duration_ms: u64,
+    /// First model, in request order, that produced text.
+    pub baseline_model_id: Option<String>,
+    pub results: Vec<ModelComparisonResult>,
+}
+
+impl ModelComparison {
+    /// Build a comparison from per-model results in request order.
+    pub fn new(audio_duration_ms: u64, mut results: Vec<ModelComparisonResult>) -> Self {
+        let baseline = results
+            .iter()
+            .find(|result| result.error.is_none() && result.text.is_some())
+            .map(|result| {
+                (
+                    result.model_id.clone(),
+                    result.text.clone().unwrap_or_default(),
+                )
+            });
+
+        for result in &mut results {
+            result.real_time_factor = match (result.transcribe_ms, audio_duration_ms) {
+                (Some(transcribe_ms), duration) if duration > 0 => {
+                    Some(transcribe_ms as f64 / duration as f64)
+                }
+                _ => None,
+            };
+            let (Some((baseline_id, baseline_text)), Some(text)) = (&baseline, &result.text) else {
+                continue;
+            };
+            if &result.model_id == baseline_id {
+                continue;
+            }
+            result.diff = word_diff(baseline_text, text);
+            result.changed_words = result
+                .diff
+                .iter()
+                .filter(|segment| segment.op != DiffOp::Equal)
+                .map(|segment| segment.text.split_whitespace().count() as u64)
+                .sum();
+        }
+
+        Self {
+            audio_duration_ms,
+            baseline_model_id: baseline.map(|(model_id, _)| model_id),
+            results,
+        }
+    }
+}
+
+/// Word-level diff of `candidate` against `baseline`.
+///
+/// Words are compared exactly, so casing and punctuation differences count.
+pub fn word_diff(baseline: &str, candidate: &str) -> Vec<DiffSegment> {
+    let old: Vec<&str> = baseline.split_whitespace().collect();
+    let new: Vec<&str> = candidate.split_whitespace().collect();
+
+    let mut ops: Vec<(DiffOp, &str)> = Vec::with_capacity(old.len() + new.len());
+    if (old.len() + 1).saturating_mul(new.len() + 1) > MAX_DIFF_CELLS {
+        ops.extend(old.iter().map(|word| (DiffOp::Delete, *word)));
+        ops.extend(new.iter().map(|word| (DiffOp::Insert, *word)));
+        return merge_ops(ops);
+    }
+
+    // lcs[i][j] = longest common subsequence of old[i..] and new[j..].
+    let width = new.len() + 1;
+    let mut lcs = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i * width + j] = if old[i] == new[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            ops.push((DiffOp::Equal, old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+            ops.push((DiffOp::Delete, old[i]));
+            i += 1;
+        } else {
+            ops.push((DiffOp::Insert, new[j]));
+            j += 1;
+        }
+    }
+    ops.extend(old[i..].iter().map(|word| (DiffOp::Delete, *word)));
+    ops.extend(new[j..].iter().map(|word| (DiffOp::Insert, *word)));
+    merge_ops(ops)
+}
+
+fn merge_ops(ops: Vec<(DiffOp, &str)>) -> Vec<DiffSegment> {
+    let mut segments: Vec<DiffSegment> = Vec::new();
+    for (op, word) in ops {
+        match segments.last_mut() {
+            Some(last) if last.op == op => {
+                last.text.push(' ');
+                last.text.push_str(word);
+            }
+            _ => segments.push(DiffSegment {
+                op,
+                text: word.to_string(),
+            }),
+        }
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(op: DiffOp, text: &str) -> DiffSegment {
+        DiffSegment {
+            op,
+            text: text.to_string(),
+        }
+    }
+
+    fn result(model_id: &str, text: Option<&str>, transcribe_ms: u64) -> ModelComparisonResult {
+        ModelComparisonResult {
+            model_id: model_id.to_string(),
+            text: text.map(str::to_string),
+            confidence: None,
+            load_ms: Some(1000),
+            transcribe_ms: Some(transcribe_ms),
+            real_time_factor: None,
+            error: None,
+            diff: Vec::new(),
+            changed_words: 0,
+        }
+    }
+
+    #[test]
+    fn test_comparison_audio_requires_exactly_one_source() {
+        assert_eq!(
+            ComparisonAudio::from_args(Some("session-1".to_string()), None),
+            Ok(ComparisonAudio::Session("session-1".to_string()))
+        );
+        assert_eq!(
+            ComparisonAudio::from_args(Some(" ".to_string()), Some("/tmp/a.wav".to_string())),
+            Ok(ComparisonAudio::File(PathBuf::from("/tmp/a.wav")))
+        );
+        assert!(ComparisonAudio::from_args(None, None).is_err());
+        assert!(
+            ComparisonAudio::from_args(Some("s".to_string()), Some("/tmp/a.wav".to_string()))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_normalize_model_ids() {
+        assert_eq!(
+            normalize_model_ids(vec![
+                " base ".to_string(),
+                "large".to_string(),
+                "base".to_string(),
+                String::new(),
+            ]),
+            Ok(vec!["base".to_string(), "large".to_string()])
+        );
+        assert!(normalize_model_ids(vec![" ".to_string()]).is_err());
+        assert!(normalize_model_ids(
+            (0..=MAX_COMPARED_MODELS)
+                .map(|index| format!("model-{index}"))
+                .collect()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_word_diff_marks_substitutions_and_additions() {
+        assert_eq!(
+            word_diff("send the report today", "send a report today please"),
+            vec![
+                segment(DiffOp::Equal, "send"),
+                segment(DiffOp::Delete, "the"),
+                segment(DiffOp::Insert, "a"),
+                segment(DiffOp::Equal, "report today"),
+                segment(DiffOp::Insert, "please"),
+            ]
+        );
+        assert_eq!(
+            word_diff("same words", "same  words"),
+            vec![segment(DiffOp::Equal, "same words")]
+        );
+        assert!(word_diff("", "").is_empty());
+    }
+
+    #[test]
+    fn test_comparison_diffs_against_first_successful_model() {
+        let mut failed = result("missing", None, 0);
+        failed.transcribe_ms = None;
+        failed.error = Some(ModelComparisonError {
+            code: "E_MODEL_NOT_FOUND".to_string(),
+            message: "Model missing is not downloaded".to_string(),
+        });
+        let comparison = ModelComparison::new(
+            4000,
+            vec![
+                failed,
+                result("fast", Some("hello world"), 400),
+                result("accurate", Some("Hello, world"), 2000),
+            ],
+        );
+
+        assert_eq!(comparison.baseline_model_id.as_deref(), Some("fast"));
+        assert!(comparison.results[0].diff.is_empty());
+        assert_eq!(comparison.results[0].real_time_factor, None);
+        assert!(comparison.results[1].diff.is_empty());
+        assert_eq!(comparison.results[1].real_time_factor, Some(0.1));
+        assert_eq!(comparison.results[2].changed_words, 2);
+        assert_eq!(comparison.results[2].real_time_factor, Some(0.5));
+    }
+}
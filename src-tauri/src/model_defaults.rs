@@ -1,7 +1,7 @@
 //! Shared model default resolution for Rust-side components.
 
 use once_cell::sync::Lazy;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Fallback model ID if manifest parsing fails.
 const FALLBACK_MODEL_ID: &str = "parakeet-tdt-0.6b-v3";
@@ -9,6 +9,37 @@ const FALLBACK_MODEL_ID: &str = "parakeet-tdt-0.6b-v3";
 /// Canonical manifest path shared with sidecar and docs.
 const MODEL_MANIFEST_JSON: &str = include_str!("../../shared/model/MODEL_MANIFEST.json");
 
+/// Model catalog carrying per-model recommended parameters.
+const MODEL_CATALOG_JSON: &str = include_str!("../../shared/model/MODEL_CATALOG.json");
+
+/// Parameters the catalog recommends for a model. The sidecar applies them on
+/// `asr.initialize` unless the user overrides them; the UI shows them when
+/// choosing a model.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecommendedParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beam_size: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vad_silence_ms: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vad_min_speech_ms: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_ram_mb: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CatalogDocument {
+    #[serde(default)]
+    models: Vec<CatalogModel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CatalogModel {
+    model_id: String,
+    #[serde(default)]
+    recommended_params: Option<RecommendedParams>,
+}
+
 #[derive(Debug, Deserialize)]
 struct ManifestModelDefaults {
     model_id: String,
@@ -40,17 +71,73 @@ static DEFAULT_MODEL_ID: Lazy<String> =
         },
     );
 
+static CATALOG_MODELS: Lazy<Vec<CatalogModel>> =
+    Lazy::new(|| catalog_models_from_str(MODEL_CATALOG_JSON));
+
 /// Return the canonical default model ID for Rust-side flows.
 pub fn default_model_id() -> &'static str {
     DEFAULT_MODEL_ID.as_str()
 }
 
+/// Recommended parameters for `model_id`, if the catalog lists any.
+pub fn recommended_params(model_id: &str) -> Option<RecommendedParams> {
+    CATALOG_MODELS
+        .iter()
+        .find(|entry| model_id_matches(&entry.model_id, model_id))
+        .and_then(|entry| entry.recommended_params.clone())
+}
+
+fn catalog_models_from_str(catalog_json: &str) -> Vec<CatalogModel> {
+    match serde_json::from_str::<CatalogDocument>(catalog_json) {
+        Ok(catalog) => catalog.models,
+        Err(error) => {
+            log::warn!("Failed to parse MODEL_CATALOG.json: {}", error);
+            Vec::new()
+        }
+    }
+}
+
+/// Catalog IDs carry a namespace ("nvidia/parakeet-...") that manifests and
+/// configs may omit, so compare the bare names when only one side has it.
+fn model_id_matches(candidate: &str, model_id: &str) -> bool {
+    let candidate = candidate.trim();
+    let model_id = model_id.trim();
+    if candidate == model_id {
+        return true;
+    }
+    match (candidate.rsplit_once('/'), model_id.rsplit_once('/')) {
+        (Some((_, name)), None) => name == model_id,
+        (None, Some((_, name))) => name == candidate,
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::default_model_id;
+    use super::*;
 
     #[test]
     fn test_default_model_id_comes_from_manifest() {
         assert_eq!(default_model_id(), "parakeet-tdt-0.6b-v3");
     }
+
+    #[test]
+    fn test_recommended_params_match_with_or_without_namespace() {
+        let params = recommended_params(default_model_id()).expect("default model params");
+        assert_eq!(params.beam_size, Some(1));
+        assert!(params.expected_ram_mb.is_some());
+        assert_eq!(
+            recommended_params("nvidia/parakeet-tdt-0.6b-v3"),
+            Some(params)
+        );
+        assert_eq!(recommended_params("unknown-model"), None);
+    }
+
+    #[test]
+    fn test_model_id_matches_only_on_bare_name() {
+        assert!(model_id_matches("nvidia/parakeet", "parakeet"));
+        assert!(model_id_matches("parakeet", "nvidia/parakeet"));
+        assert!(!model_id_matches("nvidia/parakeet", "other/parakeet"));
+        assert!(!model_id_matches("nvidia/parakeet", "parakeet-v2"));
+    }
 }
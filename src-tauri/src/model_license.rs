@@ -0,0 +1,231 @@
+//! License acceptance for models whose download requires agreeing to an EULA.
+//!
+//! When `model.install` answers with `status: "license_required"`, the
+//! license is held here as the pending license and the download stops. The
+//! settings UI fetches it with `get_pending_license`; once the user accepts,
+//! the acceptance is persisted per model next to the config and the download
+//! is retried with `license_accepted: true`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::config;
+
+/// Acceptance file name inside the config directory.
+const LICENSES_FILE_NAME: &str = "model_licenses.json";
+
+static MODEL_LICENSES: Lazy<ModelLicenseStore> =
+    Lazy::new(|| ModelLicenseStore::new(config::config_dir().join(LICENSES_FILE_NAME)));
+
+/// Get the process-wide license store.
+pub fn global_licenses() -> &'static ModelLicenseStore {
+    &MODEL_LICENSES
+}
+
+/// License terms reported by the sidecar for a model download.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModelLicense {
+    pub spdx_id: String,
+    pub name: String,
+    pub url: String,
+    /// Full license text shown to the user before accepting.
+    pub text: String,
+}
+
+/// A license the user must accept before a model download can continue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PendingLicense {
+    pub model_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    #[serde(flatten)]
+    pub license: ModelLicense,
+}
+
+/// A persisted license acceptance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AcceptedLicense {
+    pub model_id: String,
+    pub spdx_id: String,
+    pub name: String,
+    pub accepted_at: DateTime<Utc>,
+}
+
+/// Accepting a license failed.
+#[derive(Debug, Error)]
+pub enum LicenseError {
+    #[error("No license is pending for model '{0}'")]
+    NotPending(String),
+
+    #[error("Failed to save license acceptance: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// JSON-backed record of accepted licenses, keyed by model id, plus the
+/// license currently blocking a download.
+#[derive(Debug)]
+pub struct ModelLicenseStore {
+    path: PathBuf,
+    /// Accepted licenses; loaded from disk on first use.
+    accepted: Mutex<Option<BTreeMap<String, AcceptedLicense>>>,
+    pending: Mutex<Option<PendingLicense>>,
+}
+
+impl ModelLicenseStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            accepted: Mutex::new(None),
+            pending: Mutex::new(None),
+        }
+    }
+
+    /// Whether the license for `model_id` has been accepted.
+    pub fn is_accepted(&self, model_id: &str) -> bool {
+        let mut guard = self.accepted.lock().unwrap_or_else(|e| e.into_inner());
+        guard
+            .get_or_insert_with(|| self.load())
+            .contains_key(model_id)
+    }
+
+    /// Hold `license` until the user accepts it, replacing any earlier one.
+    pub fn set_pending(&self, license: PendingLicense) {
+        *self.pending.lock().unwrap_or_else(|e| e.into_inner()) = Some(license);
+    }
+
+    /// The license currently blocking a download, if any.
+    pub fn pending(&self) -> Option<PendingLicense> {
+        self.pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Accept the pending license for `model_id` and persist the acceptance.
+    pub fn accept(&self, model_id: &str) -> Result<AcceptedLicense, LicenseError> {
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        let license = match pending.as_ref() {
+            Some(license) if license.model_id == model_id => license.license.clone(),
+            _ => return Err(LicenseError::NotPending(model_id.to_string())),
+        };
+
+        let accepted = AcceptedLicense {
+            model_id: model_id.to_string(),
+            spdx_id: license.spdx_id,
+            name: license.name,
+            accepted_at: Utc::now(),
+        };
+        let mut guard = self.accepted.lock().unwrap_or_else(|e| e.into_inner());
+        let entries = guard.get_or_insert_with(|| self.load());
+        let previous = entries.insert(model_id.to_string(), accepted.clone());
+        if let Err(error) = self.save(entries) {
+            match previous {
+                Some(previous) => entries.insert(model_id.to_string(), previous),
+                None => entries.remove(model_id),
+            };
+            return Err(error.into());
+        }
+
+        *pending = None;
+        Ok(accepted)
+    }
+
+    fn load(&self) -> BTreeMap<String, AcceptedLicense> {
+        let Ok(contents) = fs::read_to_string(&self.path) else {
+            return BTreeMap::new();
+        };
+        match serde_json::from_str::<Vec<AcceptedLicense>>(&contents) {
+            Ok(entries) => entries
+                .into_iter()
+                .map(|entry| (entry.model_id.clone(), entry))
+                .collect(),
+            Err(error) => {
+                log::warn!("Ignoring unreadable model license file: {}", error);
+                BTreeMap::new()
+            }
+        }
+    }
+
+    fn save(&self, entries: &BTreeMap<String, AcceptedLicense>) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let entries: Vec<&AcceptedLicense> = entries.values().collect();
+        let contents = serde_json::to_string_pretty(&entries)?;
+        let temp_path = self.path.with_extension("json.tmp");
+        fs::write(&temp_path, contents)?;
+        fs::rename(&temp_path, &self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn pending(model_id: &str) -> PendingLicense {
+        PendingLicense {
+            model_id: model_id.to_string(),
+            revision: Some("main".to_string()),
+            license: ModelLicense {
+                spdx_id: "LicenseRef-Example".to_string(),
+                name: "Example License".to_string(),
+                url: "https://example.com/license".to_string(),
+                text: "You agree to the terms.".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_accept_persists_and_clears_pending() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("licenses.json");
+        let store = ModelLicenseStore::new(path.clone());
+        store.set_pending(pending("example-model"));
+        assert!(!store.is_accepted("example-model"));
+
+        let accepted = store.accept("example-model").unwrap();
+        assert_eq!(accepted.spdx_id, "LicenseRef-Example");
+        assert!(store.is_accepted("example-model"));
+        assert!(store.pending().is_none());
+
+        let reloaded = ModelLicenseStore::new(path);
+        assert!(reloaded.is_accepted("example-model"));
+        assert!(!reloaded.is_accepted("other-model"));
+    }
+
+    #[test]
+    fn test_accept_requires_matching_pending_license() {
+        let dir = tempdir().unwrap();
+        let store = ModelLicenseStore::new(dir.path().join("licenses.json"));
+        assert!(matches!(
+            store.accept("example-model"),
+            Err(LicenseError::NotPending(_))
+        ));
+
+        store.set_pending(pending("example-model"));
+        assert!(matches!(
+            store.accept("other-model"),
+            Err(LicenseError::NotPending(_))
+        ));
+        assert!(store.pending().is_some());
+        assert!(!store.is_accepted("other-model"));
+    }
+
+    #[test]
+    fn test_pending_license_serializes_flat() {
+        let value = serde_json::to_value(pending("example-model")).unwrap();
+        assert_eq!(value["model_id"], "example-model");
+        assert_eq!(value["name"], "Example License");
+        assert_eq!(value["text"], "You agree to the terms.");
+    }
+}
@@ -0,0 +1,490 @@
+//! Per-method throttling for sidecar notifications.
+//!
+//! High-frequency notifications are filtered before they reach the
+//! notification loop's handlers, using a policy table keyed by method:
+//! - Rate limiting drops notifications that arrive faster than a minimum
+//!   interval (audio levels, where a skipped sample is harmless)
+//! - Conflation keeps only the newest notification in each interval and
+//!   delivers it when the interval closes (progress, where the last value
+//!   must not be lost). A held notification is dropped when an event that
+//!   supersedes it passes first (its model's status change, the end of its
+//!   session's transcript), so stale progress never lands after it.
+//!
+//! The low-power profile halves the meter audio level rate and lowers the
+//! rate of other audio levels further.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+use crate::ipc::NotificationEvent;
+use crate::power::PowerProfile;
+
+/// Minimum interval between meter audio level notifications (<=30Hz).
+const AUDIO_LEVEL_METER_MIN_INTERVAL: Duration = Duration::from_millis(34);
+
+/// Minimum interval between non-meter audio level notifications (<=15Hz).
+const AUDIO_LEVEL_NON_METER_MIN_INTERVAL: Duration = Duration::from_millis(67);
+
+/// Meter audio level interval under the low-power profile (<=15Hz).
+const AUDIO_LEVEL_METER_LOW_POWER_MIN_INTERVAL: Duration = Duration::from_millis(67);
+
+/// Non-meter audio level interval under the low-power profile (<=5Hz).
+const AUDIO_LEVEL_NON_METER_LOW_POWER_MIN_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Conflation window for model download progress (<=10Hz per model).
+const MODEL_PROGRESS_CONFLATE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Conflation window for partial transcripts (<=10Hz per session).
+const PARTIAL_TRANSCRIPT_CONFLATE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How notifications matching a rule are delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationPolicy {
+    /// Drop notifications arriving within `min_interval` of the last delivered one.
+    RateLimit { min_interval: Duration },
+    /// Deliver at most once per `min_interval`, latest-wins.
+    Conflate { min_interval: Duration },
+}
+
+/// One entry of the policy table.
+#[derive(Debug, Clone)]
+pub struct NotificationRule {
+    method: &'static str,
+    field_match: Option<(&'static str, &'static str)>,
+    key_field: Option<&'static str>,
+    superseded_by: &'static [(&'static str, &'static str)],
+    policy: NotificationPolicy,
+}
+
+impl NotificationRule {
+    pub const fn new(method: &'static str, policy: NotificationPolicy) -> Self {
+        Self {
+            method,
+            field_match: None,
+            key_field: None,
+            superseded_by: &[],
+            policy,
+        }
+    }
+
+    /// Only apply this rule when `params[field] == value`.
+    pub const fn when_field(mut self, field: &'static str, value: &'static str) -> Self {
+        self.field_match = Some((field, value));
+        self
+    }
+
+    /// Track throttle state separately for each distinct `params[field]` value.
+    pub const fn keyed_by(mut self, field: &'static str) -> Self {
+        self.key_field = Some(field);
+        self
+    }
+
+    /// Drop a held notification once one of `events` passes, given as
+    /// `(method, JSON pointer to the key)` and carries the same key.
+    pub const fn superseded_by(mut self, events: &'static [(&'static str, &'static str)]) -> Self {
+        self.superseded_by = events;
+        self
+    }
+
+    /// Whether `event` supersedes a held notification of this rule with
+    /// `key`.
+    fn supersedes(&self, event: &NotificationEvent, key: Option<&str>) -> bool {
+        self.superseded_by
+            .iter()
+            .filter(|(method, _)| *method == event.method)
+            .filter_map(|(_, pointer)| event.params.pointer(pointer))
+            .any(|value| key == Some(key_string(value).as_str()))
+    }
+
+    fn matches(&self, event: &NotificationEvent) -> bool {
+        if self.method != event.method {
+            return false;
+        }
+        match self.field_match {
+            Some((field, value)) => event.params.get(field).and_then(Value::as_str) == Some(value),
+            None => true,
+        }
+    }
+
+    fn key_for(&self, event: &NotificationEvent) -> Option<String> {
+        event.params.get(self.key_field?).map(key_string)
+    }
+}
+
+fn key_string(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Default policy table; the first matching rule wins.
+pub fn default_notification_rules() -> Vec<NotificationRule> {
+    notification_rules(PowerProfile::Normal)
+}
+
+/// Policy table for `profile`; the first matching rule wins.
+pub fn notification_rules(profile: PowerProfile) -> Vec<NotificationRule> {
+    let (meter_interval, non_meter_interval) = match profile {
+        PowerProfile::Normal => (
+            AUDIO_LEVEL_METER_MIN_INTERVAL,
+            AUDIO_LEVEL_NON_METER_MIN_INTERVAL,
+        ),
+        PowerProfile::LowPower => (
+            AUDIO_LEVEL_METER_LOW_POWER_MIN_INTERVAL,
+            AUDIO_LEVEL_NON_METER_LOW_POWER_MIN_INTERVAL,
+        ),
+    };
+    vec![
+        NotificationRule::new(
+            "event.audio_level",
+            NotificationPolicy::RateLimit {
+                min_interval: meter_interval,
+            },
+        )
+        .when_field("source", "meter"),
+        NotificationRule::new(
+            "event.audio_level",
+            NotificationPolicy::RateLimit {
+                min_interval: non_meter_interval,
+            },
+        ),
+        NotificationRule::new(
+            "event.model_progress",
+            NotificationPolicy::Conflate {
+                min_interval: MODEL_PROGRESS_CONFLATE_INTERVAL,
+            },
+        )
+        .keyed_by("model_id")
+        .superseded_by(&[("event.status_changed", "/model/model_id")]),
+        NotificationRule::new(
+            "event.transcription_partial",
+            NotificationPolicy::Conflate {
+                min_interval: PARTIAL_TRANSCRIPT_CONFLATE_INTERVAL,
+            },
+        )
+        .keyed_by("session_id")
+        .superseded_by(&[
+            ("event.utterance_complete", "/session_id"),
+            ("event.transcription_complete", "/session_id"),
+            ("event.transcription_error", "/session_id"),
+        ]),
+    ]
+}
+
+type ThrottleKey = (usize, Option<String>);
+
+#[derive(Debug)]
+struct PendingNotification {
+    due_at: Instant,
+    event: NotificationEvent,
+}
+
+/// Applies a notification policy table to a stream of notifications.
+#[derive(Debug)]
+pub struct NotificationThrottle {
+    rules: Vec<NotificationRule>,
+    last_delivered: HashMap<ThrottleKey, Instant>,
+    pending: HashMap<ThrottleKey, PendingNotification>,
+}
+
+impl Default for NotificationThrottle {
+    fn default() -> Self {
+        Self::new(default_notification_rules())
+    }
+}
+
+impl NotificationThrottle {
+    pub fn new(rules: Vec<NotificationRule>) -> Self {
+        Self {
+            rules,
+            last_delivered: HashMap::new(),
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Admit an incoming notification.
+    ///
+    /// Returns the notification if it should be handled now. Conflated
+    /// notifications may be held back and later returned by [`Self::take_due`].
+    pub fn admit(&mut self, event: NotificationEvent, now: Instant) -> Option<NotificationEvent> {
+        let rules = &self.rules;
+        self.pending
+            .retain(|(index, key), _| !rules[*index].supersedes(&event, key.as_deref()));
+
+        let Some(index) = self.rules.iter().position(|rule| rule.matches(&event)) else {
+            return Some(event);
+        };
+        let rule = &self.rules[index];
+        let key = (index, rule.key_for(&event));
+
+        match rule.policy {
+            NotificationPolicy::RateLimit { min_interval } => {
+                if self.within_interval(&key, now, min_interval) {
+                    return None;
+                }
+                self.last_delivered.insert(key, now);
+                Some(event)
+            }
+            NotificationPolicy::Conflate { min_interval } => {
+                if let Some(last) = self.last_delivered.get(&key).copied() {
+                    if now.duration_since(last) < min_interval {
+                        // Latest wins: replace any older notification still waiting.
+                        self.pending.insert(
+                            key,
+                            PendingNotification {
+                                due_at: last + min_interval,
+                                event,
+                            },
+                        );
+                        return None;
+                    }
+                }
+                self.pending.remove(&key);
+                self.last_delivered.insert(key, now);
+                Some(event)
+            }
+        }
+    }
+
+    /// Earliest instant at which a held-back notification becomes due.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending.values().map(|pending| pending.due_at).min()
+    }
+
+    /// Take the earliest held-back notification that is due at `now`.
+    pub fn take_due(&mut self, now: Instant) -> Option<NotificationEvent> {
+        let key = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| pending.due_at <= now)
+            .min_by_key(|(_, pending)| pending.due_at)
+            .map(|(key, _)| key.clone())?;
+        let pending = self.pending.remove(&key)?;
+        self.last_delivered.insert(key, now);
+        Some(pending.event)
+    }
+
+    fn within_interval(&self, key: &ThrottleKey, now: Instant, min_interval: Duration) -> bool {
+        self.last_delivered
+            .get(key)
+            .is_some_and(|last| now.duration_since(*last) < min_interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn notification(method: &str, params: Value) -> NotificationEvent {
+        NotificationEvent {
+            method: method.to_string(),
+            params,
+        }
+    }
+
+    fn audio_level(source: &str) -> NotificationEvent {
+        notification(
+            "event.audio_level",
+            json!({ "source": source, "rms": 0.1, "peak": 0.2 }),
+        )
+    }
+
+    fn model_progress(model_id: &str, current: u64) -> NotificationEvent {
+        notification(
+            "event.model_progress",
+            json!({ "model_id": model_id, "current": current, "total": 100 }),
+        )
+    }
+
+    #[test]
+    fn test_audio_level_meter_source_is_rate_limited_to_30hz() {
+        let mut throttle = NotificationThrottle::default();
+        let start = Instant::now();
+
+        assert!(throttle.admit(audio_level("meter"), start).is_some());
+        assert!(throttle
+            .admit(audio_level("meter"), start + Duration::from_millis(5))
+            .is_none());
+        assert!(throttle
+            .admit(audio_level("meter"), start + Duration::from_millis(40))
+            .is_some());
+        assert_eq!(throttle.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_audio_level_non_meter_sources_are_rate_limited_to_15hz() {
+        let mut throttle = NotificationThrottle::default();
+        let start = Instant::now();
+
+        assert!(throttle.admit(audio_level("recording"), start).is_some());
+        assert!(throttle
+            .admit(audio_level("recording"), start + Duration::from_millis(40))
+            .is_none());
+        assert!(throttle
+            .admit(audio_level("recording"), start + Duration::from_millis(90))
+            .is_some());
+    }
+
+    #[test]
+    fn test_low_power_profile_lowers_audio_level_rates() {
+        let mut throttle = NotificationThrottle::new(notification_rules(PowerProfile::LowPower));
+        let start = Instant::now();
+
+        assert!(throttle.admit(audio_level("meter"), start).is_some());
+        assert!(throttle
+            .admit(audio_level("meter"), start + Duration::from_millis(40))
+            .is_none());
+        assert!(throttle
+            .admit(audio_level("meter"), start + Duration::from_millis(70))
+            .is_some());
+
+        assert!(throttle.admit(audio_level("recording"), start).is_some());
+        assert!(throttle
+            .admit(audio_level("recording"), start + Duration::from_millis(90))
+            .is_none());
+        assert!(throttle
+            .admit(audio_level("recording"), start + Duration::from_millis(210))
+            .is_some());
+    }
+
+    #[test]
+    fn test_model_progress_conflates_to_latest_value() {
+        let mut throttle = NotificationThrottle::default();
+        let start = Instant::now();
+
+        assert!(throttle.admit(model_progress("m", 1), start).is_some());
+        assert!(throttle
+            .admit(model_progress("m", 2), start + Duration::from_millis(10))
+            .is_none());
+        assert!(throttle
+            .admit(model_progress("m", 3), start + Duration::from_millis(20))
+            .is_none());
+
+        let deadline = throttle.next_deadline().expect("pending progress");
+        assert_eq!(deadline, start + MODEL_PROGRESS_CONFLATE_INTERVAL);
+        assert!(throttle
+            .take_due(start + Duration::from_millis(50))
+            .is_none());
+
+        let delivered = throttle.take_due(deadline).expect("latest progress");
+        assert_eq!(delivered.params["current"], 3);
+        assert_eq!(throttle.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_model_progress_is_conflated_per_model() {
+        let mut throttle = NotificationThrottle::default();
+        let start = Instant::now();
+
+        assert!(throttle.admit(model_progress("a", 1), start).is_some());
+        assert!(throttle
+            .admit(model_progress("b", 1), start + Duration::from_millis(10))
+            .is_some());
+    }
+
+    #[test]
+    fn test_fresh_delivery_discards_older_pending_notification() {
+        let mut throttle = NotificationThrottle::default();
+        let start = Instant::now();
+
+        throttle.admit(model_progress("m", 1), start);
+        throttle.admit(model_progress("m", 2), start + Duration::from_millis(10));
+        let delivered = throttle
+            .admit(model_progress("m", 4), start + Duration::from_millis(150))
+            .expect("interval elapsed");
+
+        assert_eq!(delivered.params["current"], 4);
+        assert_eq!(throttle.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_status_change_drops_held_progress_of_its_model() {
+        let mut throttle = NotificationThrottle::default();
+        let start = Instant::now();
+
+        throttle.admit(model_progress("m", 1), start);
+        throttle.admit(model_progress("m", 99), start + Duration::from_millis(10));
+        throttle.admit(
+            model_progress("other", 1),
+            start + Duration::from_millis(10),
+        );
+        throttle.admit(
+            model_progress("other", 2),
+            start + Duration::from_millis(20),
+        );
+        let recording = notification("event.status_changed", json!({ "state": "recording" }));
+        throttle.admit(recording, start + Duration::from_millis(25));
+        let ready = notification(
+            "event.status_changed",
+            json!({ "state": "ready", "model": { "model_id": "m", "status": "ready" } }),
+        );
+        assert!(throttle
+            .admit(ready, start + Duration::from_millis(30))
+            .is_some());
+
+        let delivered = throttle
+            .take_due(start + Duration::from_secs(1))
+            .expect("other model's progress still held");
+        assert_eq!(delivered.params["model_id"], "other");
+        assert!(throttle.take_due(start + Duration::from_secs(1)).is_none());
+    }
+
+    #[test]
+    fn test_transcript_end_drops_held_partial_of_its_session() {
+        let mut throttle = NotificationThrottle::default();
+        let start = Instant::now();
+        let partial = |text: &str| {
+            notification(
+                "event.transcription_partial",
+                json!({ "session_id": "s", "text": text }),
+            )
+        };
+
+        assert!(throttle.admit(partial("hel"), start).is_some());
+        assert!(throttle
+            .admit(partial("hello"), start + Duration::from_millis(10))
+            .is_none());
+        let complete = notification(
+            "event.transcription_complete",
+            json!({ "session_id": "s", "text": "Hello." }),
+        );
+        assert!(throttle
+            .admit(complete, start + Duration::from_millis(20))
+            .is_some());
+
+        assert_eq!(throttle.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_unlisted_methods_pass_through() {
+        let mut throttle = NotificationThrottle::default();
+        let start = Instant::now();
+        let event = notification("event.status_changed", json!({ "state": "ready" }));
+
+        assert!(throttle.admit(event.clone(), start).is_some());
+        assert!(throttle.admit(event, start).is_some());
+    }
+
+    #[test]
+    fn test_custom_policy_table_replaces_defaults() {
+        let mut throttle = NotificationThrottle::new(vec![NotificationRule::new(
+            "event.status_changed",
+            NotificationPolicy::RateLimit {
+                min_interval: Duration::from_secs(1),
+            },
+        )]);
+        let start = Instant::now();
+        let status = notification("event.status_changed", json!({ "state": "ready" }));
+
+        assert!(throttle.admit(audio_level("meter"), start).is_some());
+        assert!(throttle.admit(audio_level("meter"), start).is_some());
+        assert!(throttle.admit(status.clone(), start).is_some());
+        assert!(throttle
+            .admit(status, start + Duration::from_millis(500))
+            .is_none());
+    }
+}
@@ -2,20 +2,27 @@
 //!
 //! This module provides:
 //! - Show/hide behavior for the `overlay` window based on recording state.
-//! - Positioning helpers with multi-monitor awareness.
+//! - Positioning helpers with multi-monitor awareness: the overlay shows on
+//!   the monitor of the focused app, or on the monitor named by
+//!   `ui.overlay_monitor`.
 //! - Click-through + always-on-top window configuration.
 //! - Auto-disable behavior after repeated window-operation failures.
 //! - Meter/timer throttling guards to keep overlay CPU usage within budget.
 
 #![allow(dead_code)] // Phase 2: Floating Overlay Window — not yet wired into the app
 
+use std::ops::RangeInclusive;
 use std::time::{Duration, Instant};
 
+use serde::Serialize;
+#[cfg(feature = "desktop")]
 use tauri::{AppHandle, Manager};
+#[cfg(feature = "desktop")]
 use tauri::{PhysicalPosition, Position};
 use thiserror::Error;
 
 use crate::config;
+use crate::focus::WindowBounds;
 
 pub const OVERLAY_WINDOW_LABEL: &str = "overlay";
 pub const OVERLAY_METER_MAX_HZ: u64 = 15;
@@ -27,6 +34,11 @@ const DEFAULT_FAILURE_THRESHOLD: u32 = 3;
 const DEFAULT_MARGIN_X: i32 = 24;
 const DEFAULT_MARGIN_Y: i32 = 24;
 
+/// Accepted `ui.overlay_scale` values.
+pub const OVERLAY_SCALE_RANGE: RangeInclusive<f64> = 0.5..=2.0;
+/// Accepted `ui.overlay_opacity` values; fully transparent would hide the overlay.
+pub const OVERLAY_OPACITY_RANGE: RangeInclusive<f64> = 0.2..=1.0;
+
 /// Anchor point for overlay placement within a monitor work area.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum OverlayAnchor {
@@ -72,8 +84,54 @@ impl Default for OverlayWindowSize {
     }
 }
 
+/// Overlay appearance, pushed to the overlay window with `overlay:config`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OverlayAppearance {
+    /// "system", "light" or "dark".
+    pub theme: String,
+    pub scale: f64,
+    pub opacity: f64,
+    pub show_waveform: bool,
+}
+
+impl OverlayAppearance {
+    pub fn from_config(ui: &config::UiConfig) -> Self {
+        Self {
+            theme: ui.overlay_theme.clone(),
+            scale: ui.overlay_scale,
+            opacity: ui.overlay_opacity,
+            show_waveform: ui.overlay_show_waveform,
+        }
+    }
+}
+
+/// Why the overlay appearance in `ui` is invalid, if it is.
+pub fn appearance_issue(ui: &config::UiConfig) -> Option<String> {
+    if !matches!(ui.overlay_theme.as_str(), "system" | "light" | "dark") {
+        return Some(format!(
+            "Invalid overlay theme '{}': use system, light or dark",
+            ui.overlay_theme
+        ));
+    }
+    if !OVERLAY_SCALE_RANGE.contains(&ui.overlay_scale) {
+        return Some(format!(
+            "Overlay scale must be between {} and {}",
+            OVERLAY_SCALE_RANGE.start(),
+            OVERLAY_SCALE_RANGE.end()
+        ));
+    }
+    if !OVERLAY_OPACITY_RANGE.contains(&ui.overlay_opacity) {
+        return Some(format!(
+            "Overlay opacity must be between {} and {}",
+            OVERLAY_OPACITY_RANGE.start(),
+            OVERLAY_OPACITY_RANGE.end()
+        ));
+    }
+    None
+}
+
 /// Monitor bounds (including work area) in physical pixels.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MonitorBounds {
     pub x: i32,
     pub y: i32,
@@ -83,10 +141,15 @@ pub struct MonitorBounds {
     pub work_y: i32,
     pub work_width: u32,
     pub work_height: u32,
+    /// Name the platform reports for the monitor.
+    pub name: Option<String>,
+    /// Physical pixels per logical point.
+    pub scale_factor: f64,
 }
 
+#[cfg(feature = "desktop")]
 impl MonitorBounds {
-    fn from_tauri_monitor(monitor: &tauri::Monitor) -> Self {
+    pub(crate) fn from_tauri_monitor(monitor: &tauri::Monitor) -> Self {
         let position = monitor.position();
         let size = monitor.size();
         let work_area = monitor.work_area();
@@ -99,6 +162,8 @@ impl MonitorBounds {
             work_y: work_area.position.y,
             work_width: work_area.size.width,
             work_height: work_area.size.height,
+            name: monitor.name().cloned(),
+            scale_factor: monitor.scale_factor(),
         }
     }
 }
@@ -138,6 +203,21 @@ impl OverlayConfigStore for FileOverlayConfigStore {
     }
 }
 
+/// Config store that reports the overlay enabled whatever the saved setting,
+/// for recordings where `ui.screen_share_policy` forces it visible.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ForcedOverlayConfigStore;
+
+impl OverlayConfigStore for ForcedOverlayConfigStore {
+    fn is_overlay_enabled(&self) -> Result<bool, String> {
+        Ok(true)
+    }
+
+    fn set_overlay_enabled(&self, enabled: bool) -> Result<(), String> {
+        FileOverlayConfigStore.set_overlay_enabled(enabled)
+    }
+}
+
 /// Window operations required by [`OverlayManager`].
 pub trait OverlayWindowBackend {
     fn window_exists(&self, label: &str) -> bool;
@@ -154,10 +234,12 @@ pub trait OverlayWindowBackend {
 }
 
 /// Tauri backend implementation for [`OverlayWindowBackend`].
+#[cfg(feature = "desktop")]
 pub struct TauriOverlayWindowBackend<'a> {
     app_handle: &'a AppHandle,
 }
 
+#[cfg(feature = "desktop")]
 impl<'a> TauriOverlayWindowBackend<'a> {
     pub fn new(app_handle: &'a AppHandle) -> Self {
         Self { app_handle }
@@ -170,6 +252,7 @@ impl<'a> TauriOverlayWindowBackend<'a> {
     }
 }
 
+#[cfg(feature = "desktop")]
 impl OverlayWindowBackend for TauriOverlayWindowBackend<'_> {
     fn window_exists(&self, label: &str) -> bool {
         self.app_handle.get_webview_window(label).is_some()
@@ -360,6 +443,10 @@ pub struct OverlayManager {
     position_config: OverlayPositionConfig,
     window_size: OverlayWindowSize,
     rate_limiter: OverlayRateLimiter,
+    /// Frame of the app dictation goes to; the overlay follows it to its monitor.
+    target_window: Option<WindowBounds>,
+    /// Monitor the overlay is pinned to, when connected.
+    fixed_monitor: Option<String>,
 }
 
 impl Default for OverlayManager {
@@ -379,6 +466,8 @@ impl OverlayManager {
             position_config: OverlayPositionConfig::default(),
             window_size: OverlayWindowSize::default(),
             rate_limiter: OverlayRateLimiter::default(),
+            target_window: None,
+            fixed_monitor: None,
         }
     }
 
@@ -395,6 +484,14 @@ impl OverlayManager {
         self.window_size = window_size;
     }
 
+    pub fn set_target_window(&mut self, target_window: Option<WindowBounds>) {
+        self.target_window = target_window;
+    }
+
+    pub fn set_fixed_monitor(&mut self, fixed_monitor: Option<String>) {
+        self.fixed_monitor = fixed_monitor;
+    }
+
     pub fn visible(&self) -> bool {
         self.visible
     }
@@ -539,10 +636,29 @@ impl OverlayManager {
         Ok(())
     }
 
+    /// The pinned monitor, else the focused app's monitor, else the monitor
+    /// the overlay is on, the primary monitor, or any monitor.
     fn resolve_target_monitor<W: OverlayWindowBackend>(
         &self,
         window_backend: &W,
     ) -> Result<MonitorBounds, OverlayError> {
+        if self.fixed_monitor.is_some() || self.target_window.is_some() {
+            if let Ok(monitors) = window_backend.available_monitors(&self.window_label) {
+                let fixed = self.fixed_monitor.as_deref().and_then(|name| {
+                    monitors
+                        .iter()
+                        .find(|monitor| monitor.name.as_deref() == Some(name))
+                });
+                let followed = || {
+                    self.target_window
+                        .as_ref()
+                        .and_then(|window| monitor_for_window(&monitors, window))
+                };
+                if let Some(monitor) = fixed.or_else(followed) {
+                    return Ok(monitor.clone());
+                }
+            }
+        }
         if let Ok(Some(current)) = window_backend.current_monitor(&self.window_label) {
             return Ok(current);
         }
@@ -596,6 +712,52 @@ impl OverlayManager {
     }
 }
 
+/// Monitor showing `window`: the one containing its center, else the one
+/// it overlaps most.
+pub fn monitor_for_window<'a>(
+    monitors: &'a [MonitorBounds],
+    window: &WindowBounds,
+) -> Option<&'a MonitorBounds> {
+    // Monitor frame in the window's coordinate space.
+    let frame = |monitor: &MonitorBounds| {
+        let scale = if window.logical && monitor.scale_factor > 0.0 {
+            monitor.scale_factor
+        } else {
+            1.0
+        };
+        (
+            f64::from(monitor.x) / scale,
+            f64::from(monitor.y) / scale,
+            f64::from(monitor.width) / scale,
+            f64::from(monitor.height) / scale,
+        )
+    };
+    let (left, top) = (f64::from(window.x), f64::from(window.y));
+    let (right, bottom) = (
+        left + f64::from(window.width),
+        top + f64::from(window.height),
+    );
+    let (center_x, center_y) = ((left + right) / 2.0, (top + bottom) / 2.0);
+
+    let contains_center = monitors.iter().find(|monitor| {
+        let (x, y, width, height) = frame(monitor);
+        (x..x + width).contains(&center_x) && (y..y + height).contains(&center_y)
+    });
+    contains_center.or_else(|| {
+        monitors
+            .iter()
+            .map(|monitor| {
+                let (x, y, width, height) = frame(monitor);
+                let overlap_x = (right.min(x + width) - left.max(x)).max(0.0);
+                let overlap_y = (bottom.min(y + height) - top.max(y)).max(0.0);
+                (monitor, overlap_x * overlap_y)
+            })
+            .filter(|(_, overlap)| *overlap > 0.0)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(monitor, _)| monitor)
+    })
+}
+
 pub fn compute_overlay_position(
     monitor: &MonitorBounds,
     window_size: OverlayWindowSize,
@@ -689,11 +851,13 @@ mod tests {
                 work_y: 0,
                 work_width: 1920,
                 work_height: 1080,
+                name: None,
+                scale_factor: 1.0,
             };
             Self {
                 exists: Arc::new(Mutex::new(true)),
-                current_monitor: Some(monitor),
-                primary_monitor: Some(monitor),
+                current_monitor: Some(monitor.clone()),
+                primary_monitor: Some(monitor.clone()),
                 monitors: vec![monitor],
                 fail_show: false,
                 fail_create: false,
@@ -746,11 +910,11 @@ mod tests {
         }
 
         fn current_monitor(&self, _label: &str) -> Result<Option<MonitorBounds>, String> {
-            Ok(self.current_monitor)
+            Ok(self.current_monitor.clone())
         }
 
         fn primary_monitor(&self, _label: &str) -> Result<Option<MonitorBounds>, String> {
-            Ok(self.primary_monitor)
+            Ok(self.primary_monitor.clone())
         }
 
         fn set_always_on_top(&self, _label: &str, _always_on_top: bool) -> Result<(), String> {
@@ -832,6 +996,8 @@ mod tests {
             work_y: 30,
             work_width: 2560,
             work_height: 1370,
+            name: None,
+            scale_factor: 1.0,
         };
 
         let (x, y) = compute_overlay_position(
@@ -922,6 +1088,8 @@ mod tests {
             work_y: 0,
             work_width: 1920,
             work_height: 1080,
+            name: None,
+            scale_factor: 1.0,
         };
         let size = OverlayWindowSize {
             width: 300,
@@ -982,6 +1150,8 @@ mod tests {
             work_y: 0,
             work_width: 2560,
             work_height: 1400,
+            name: None,
+            scale_factor: 1.0,
         };
         let size = OverlayWindowSize::default();
         let config = OverlayPositionConfig::default();
@@ -1004,6 +1174,8 @@ mod tests {
             work_y: 0,
             work_width: 1920,
             work_height: 1080,
+            name: None,
+            scale_factor: 1.0,
         };
         let config = MockConfigStore::new(true);
         let backend = MockWindowBackend {
@@ -1028,6 +1200,8 @@ mod tests {
             work_y: 0,
             work_width: 1920,
             work_height: 1080,
+            name: None,
+            scale_factor: 1.0,
         };
         let config = MockConfigStore::new(true);
         let backend = MockWindowBackend {
@@ -1042,6 +1216,86 @@ mod tests {
         assert!(manager.visible());
     }
 
+    fn named_monitor(name: &str, x: i32, width: u32, scale_factor: f64) -> MonitorBounds {
+        MonitorBounds {
+            x,
+            y: 0,
+            width,
+            height: 1080,
+            work_x: x,
+            work_y: 0,
+            work_width: width,
+            work_height: 1040,
+            name: Some(name.to_string()),
+            scale_factor,
+        }
+    }
+
+    fn window_at(x: i32, width: u32, logical: bool) -> WindowBounds {
+        WindowBounds {
+            x,
+            y: 100,
+            width,
+            height: 600,
+            logical,
+        }
+    }
+
+    #[test]
+    fn resolve_monitor_follows_focused_window_unless_pinned() {
+        let left = named_monitor("DP-1", 0, 1920, 1.0);
+        let right = named_monitor("HDMI-1", 1920, 2560, 1.0);
+        let backend = MockWindowBackend {
+            current_monitor: Some(left.clone()),
+            monitors: vec![left.clone(), right.clone()],
+            ..MockWindowBackend::new()
+        };
+        let mut manager = OverlayManager::new();
+        assert_eq!(manager.resolve_target_monitor(&backend).unwrap(), left);
+
+        manager.set_target_window(Some(window_at(2200, 1200, false)));
+        assert_eq!(manager.resolve_target_monitor(&backend).unwrap(), right);
+
+        manager.set_fixed_monitor(Some("DP-1".to_string()));
+        assert_eq!(manager.resolve_target_monitor(&backend).unwrap(), left);
+
+        // A disconnected pinned monitor falls back to following focus.
+        manager.set_fixed_monitor(Some("DP-2".to_string()));
+        assert_eq!(manager.resolve_target_monitor(&backend).unwrap(), right);
+    }
+
+    #[test]
+    fn monitor_for_window_uses_center_then_overlap() {
+        let monitors = [
+            named_monitor("left", 0, 1920, 1.0),
+            named_monitor("right", 1920, 1920, 1.0),
+        ];
+        let name = |window: WindowBounds| {
+            monitor_for_window(&monitors, &window).and_then(|monitor| monitor.name.clone())
+        };
+        assert_eq!(name(window_at(1800, 400, false)).as_deref(), Some("right"));
+        // Center off-screen: the monitor showing most of the window wins.
+        assert_eq!(name(window_at(-500, 600, false)).as_deref(), Some("left"));
+        assert_eq!(name(window_at(5000, 600, false)), None);
+
+        // Logical coordinates are compared against scaled monitor frames.
+        let hidpi = [
+            named_monitor("left", 0, 3840, 2.0),
+            named_monitor("right", 3840, 3840, 2.0),
+        ];
+        let hidpi_name = |window: WindowBounds| {
+            monitor_for_window(&hidpi, &window).and_then(|monitor| monitor.name.clone())
+        };
+        assert_eq!(
+            hidpi_name(window_at(2000, 100, true)).as_deref(),
+            Some("right")
+        );
+        assert_eq!(
+            hidpi_name(window_at(2000, 100, false)).as_deref(),
+            Some("left")
+        );
+    }
+
     #[test]
     fn resolve_monitor_fails_when_no_monitors_available() {
         let config = MockConfigStore::new(true);
@@ -1213,4 +1467,21 @@ mod tests {
         assert!(!manager.visible());
         assert_eq!(backend.call_count("hide"), 1);
     }
+
+    #[test]
+    fn appearance_issue_rejects_invalid_theme_scale_and_opacity() {
+        let mut ui = config::UiConfig::default();
+        assert_eq!(appearance_issue(&ui), None);
+
+        ui.overlay_theme = "neon".to_string();
+        assert!(appearance_issue(&ui).unwrap().contains("theme"));
+
+        ui = config::UiConfig::default();
+        ui.overlay_scale = 3.0;
+        assert!(appearance_issue(&ui).unwrap().contains("scale"));
+
+        ui = config::UiConfig::default();
+        ui.overlay_opacity = f64::NAN;
+        assert!(appearance_issue(&ui).unwrap().contains("opacity"));
+    }
 }
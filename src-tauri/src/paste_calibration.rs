@@ -0,0 +1,314 @@
+//! Learned per-app paste delays.
+//!
+//! `injection.paste_delay_ms` is one value for every app, so it is either
+//! slower than native apps need or too fast for busy Electron apps. With
+//! `injection.calibrate_paste_delay` on, every paste measures what the
+//! focused app needed: how long the new clipboard contents took to become
+//! readable, and how long the app took to act on the paste keystroke (read
+//! back from the field where the platform allows it, otherwise estimated
+//! from how long the keystroke took to dispatch). Once an app has enough
+//! samples, pastes into it wait the learned delay instead of the static one;
+//! an app override's `paste_delay_ms` still wins. Samples are stored next to
+//! the config and can be inspected or reset per app.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::config;
+
+/// Calibration file name inside the config directory.
+const CALIBRATION_FILE_NAME: &str = "paste_calibration.json";
+
+/// Samples kept per app; the learned delay covers the slowest of them.
+const MAX_SAMPLES: usize = 5;
+
+/// Samples needed before the learned delay replaces the static one.
+pub const MIN_SAMPLES: usize = 3;
+
+/// Bounds of a learned delay, the same as for `paste_delay_ms`.
+const MIN_DELAY_MS: u32 = 10;
+const MAX_DELAY_MS: u32 = 500;
+
+static PASTE_CALIBRATION: Lazy<PasteCalibration> =
+    Lazy::new(|| PasteCalibration::new(config::config_dir().join(CALIBRATION_FILE_NAME)));
+
+/// Get the process-wide paste calibration.
+pub fn global_calibration() -> &'static PasteCalibration {
+    &PASTE_CALIBRATION
+}
+
+/// Record a sample in `calibration` (usually [`global_calibration`]),
+/// logging (not propagating) write failures.
+pub fn record(calibration: &PasteCalibration, app_id: &str, sample: PasteSample) {
+    match calibration.record(app_id, sample) {
+        Ok(calibration) => log::debug!(
+            "Paste calibration for {}: sample={:?}, learned_delay_ms={:?}",
+            app_id,
+            sample,
+            calibration.learned_delay_ms
+        ),
+        Err(error) => log::warn!("Failed to update paste calibration: {}", error),
+    }
+}
+
+/// Timings measured around one paste.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PasteSample {
+    /// Delay the paste waited before the keystroke.
+    pub delay_ms: u32,
+    /// Time until the clipboard read back the new contents.
+    pub clipboard_settle_ms: u32,
+    /// Time the paste keystroke took to dispatch.
+    pub key_dispatch_ms: u32,
+    /// Time from the keystroke until the text appeared in the field, when
+    /// the field could be read back.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paste_ready_ms: Option<u32>,
+    /// Whether the text reached the field; absent when it could not be read back.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arrived: Option<bool>,
+}
+
+impl PasteSample {
+    /// Delay this paste shows the app needs.
+    pub fn required_delay_ms(&self) -> u32 {
+        if self.arrived == Some(false) {
+            // The delay that was used lost the paste.
+            return self.delay_ms.saturating_mul(2);
+        }
+        let input_latency = self
+            .paste_ready_ms
+            .unwrap_or_else(|| self.key_dispatch_ms.saturating_mul(2));
+        self.clipboard_settle_ms.saturating_add(input_latency)
+    }
+}
+
+/// Calibration state of one app.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppPasteCalibration {
+    /// App id, normalized like app override keys.
+    pub app_id: String,
+    /// Most recent samples, oldest first.
+    pub samples: Vec<PasteSample>,
+    /// Delay pastes into the app wait, once there are enough samples.
+    pub learned_delay_ms: Option<u32>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Delay covering the slowest of `samples`, with a quarter on top for jitter.
+fn learned_delay_ms(samples: &[PasteSample]) -> Option<u32> {
+    if samples.len() < MIN_SAMPLES {
+        return None;
+    }
+    let slowest = samples.iter().map(PasteSample::required_delay_ms).max()?;
+    Some(
+        slowest
+            .saturating_add(slowest / 4)
+            .clamp(MIN_DELAY_MS, MAX_DELAY_MS),
+    )
+}
+
+/// JSON-backed paste calibration, keyed by app id.
+#[derive(Debug)]
+pub struct PasteCalibration {
+    path: PathBuf,
+    /// Calibrated apps; loaded from disk on first use.
+    apps: Mutex<Option<BTreeMap<String, AppPasteCalibration>>>,
+}
+
+impl PasteCalibration {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            apps: Mutex::new(None),
+        }
+    }
+
+    /// Add a sample for `app_id` and return its updated calibration.
+    pub fn record(&self, app_id: &str, sample: PasteSample) -> io::Result<AppPasteCalibration> {
+        self.with_apps(|apps| {
+            let calibration =
+                apps.entry(app_id.to_string())
+                    .or_insert_with(|| AppPasteCalibration {
+                        app_id: app_id.to_string(),
+                        samples: Vec::new(),
+                        learned_delay_ms: None,
+                        updated_at: Utc::now(),
+                    });
+            calibration.samples.push(sample);
+            let excess = calibration.samples.len().saturating_sub(MAX_SAMPLES);
+            calibration.samples.drain(..excess);
+            calibration.learned_delay_ms = learned_delay_ms(&calibration.samples);
+            calibration.updated_at = Utc::now();
+            Ok(calibration.clone())
+        })
+    }
+
+    /// All calibrated apps, sorted by app id.
+    pub fn entries(&self) -> Vec<AppPasteCalibration> {
+        self.with_apps(|apps| Ok::<_, io::Error>(apps.values().cloned().collect()))
+            .unwrap_or_default()
+    }
+
+    /// Learned delays of apps with enough samples.
+    pub fn learned_delays(&self) -> HashMap<String, u32> {
+        self.entries()
+            .into_iter()
+            .filter_map(|calibration| {
+                calibration
+                    .learned_delay_ms
+                    .map(|delay| (calibration.app_id, delay))
+            })
+            .collect()
+    }
+
+    /// Forget the calibration of `app_id`, or of every app when `None`.
+    /// Returns how many apps were reset.
+    pub fn reset(&self, app_id: Option<&str>) -> io::Result<usize> {
+        self.with_apps(|apps| match app_id {
+            Some(app_id) => Ok(usize::from(apps.remove(app_id).is_some())),
+            None => {
+                let count = apps.len();
+                apps.clear();
+                Ok(count)
+            }
+        })
+    }
+
+    /// Run `f` against the loaded apps, saving afterwards if they changed.
+    fn with_apps<T>(
+        &self,
+        f: impl FnOnce(&mut BTreeMap<String, AppPasteCalibration>) -> io::Result<T>,
+    ) -> io::Result<T> {
+        let mut guard = self
+            .apps
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let apps = guard.get_or_insert_with(|| self.load());
+        let before = apps.clone();
+        let result = f(apps)?;
+        if *apps != before {
+            self.save(apps)?;
+        }
+        Ok(result)
+    }
+
+    fn load(&self) -> BTreeMap<String, AppPasteCalibration> {
+        let Ok(contents) = fs::read_to_string(&self.path) else {
+            return BTreeMap::new();
+        };
+        match serde_json::from_str::<Vec<AppPasteCalibration>>(&contents) {
+            Ok(entries) => entries
+                .into_iter()
+                .map(|entry| (entry.app_id.clone(), entry))
+                .collect(),
+            Err(error) => {
+                log::warn!("Ignoring unreadable paste calibration: {}", error);
+                BTreeMap::new()
+            }
+        }
+    }
+
+    fn save(&self, apps: &BTreeMap<String, AppPasteCalibration>) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let entries: Vec<&AppPasteCalibration> = apps.values().collect();
+        let contents = serde_json::to_string_pretty(&entries)?;
+        let temp_path = self.path.with_extension("json.tmp");
+        fs::write(&temp_path, contents)?;
+        fs::rename(&temp_path, &self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn sample(clipboard_settle_ms: u32, paste_ready_ms: Option<u32>) -> PasteSample {
+        PasteSample {
+            delay_ms: 40,
+            clipboard_settle_ms,
+            key_dispatch_ms: 3,
+            paste_ready_ms,
+            arrived: paste_ready_ms.map(|_| true),
+        }
+    }
+
+    #[test]
+    fn test_required_delay_uses_readback_or_dispatch_time() {
+        assert_eq!(sample(5, Some(20)).required_delay_ms(), 25);
+        assert_eq!(sample(5, None).required_delay_ms(), 11);
+
+        let lost = PasteSample {
+            arrived: Some(false),
+            ..sample(5, None)
+        };
+        assert_eq!(lost.required_delay_ms(), 80);
+    }
+
+    #[test]
+    fn test_delay_is_learned_after_enough_samples() {
+        let dir = tempdir().unwrap();
+        let calibration = PasteCalibration::new(dir.path().join("calibration.json"));
+
+        calibration.record("code", sample(4, Some(8))).unwrap();
+        let second = calibration.record("code", sample(6, Some(10))).unwrap();
+        assert_eq!(second.learned_delay_ms, None);
+        assert!(calibration.learned_delays().is_empty());
+
+        let third = calibration.record("code", sample(2, None)).unwrap();
+        // Slowest sample needed 16ms; a quarter is added on top.
+        assert_eq!(third.learned_delay_ms, Some(20));
+        assert_eq!(calibration.learned_delays().get("code"), Some(&20));
+
+        let fast = calibration.record("terminal", sample(0, Some(1))).unwrap();
+        assert_eq!(fast.samples.len(), 1);
+    }
+
+    #[test]
+    fn test_record_writes_to_the_given_calibration() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("calibration.json");
+        let calibration = PasteCalibration::new(path.clone());
+
+        record(&calibration, "code", sample(4, Some(8)));
+
+        let entries = PasteCalibration::new(path).entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].app_id, "code");
+        assert_eq!(entries[0].samples, vec![sample(4, Some(8))]);
+    }
+
+    #[test]
+    fn test_old_samples_roll_off_and_reset_persists() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("calibration.json");
+        let calibration = PasteCalibration::new(path.clone());
+        calibration.record("slack", sample(400, Some(400))).unwrap();
+        for _ in 0..MAX_SAMPLES {
+            calibration.record("slack", sample(20, Some(40))).unwrap();
+        }
+        calibration.record("code", sample(1, Some(1))).unwrap();
+
+        let reopened = PasteCalibration::new(path);
+        let entries = reopened.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].app_id, "slack");
+        assert_eq!(entries[1].samples.len(), MAX_SAMPLES);
+        assert_eq!(entries[1].learned_delay_ms, Some(75));
+
+        assert_eq!(reopened.reset(Some("slack")).unwrap(), 1);
+        assert_eq!(reopened.reset(Some("slack")).unwrap(), 0);
+        assert_eq!(reopened.reset(None).unwrap(), 1);
+        assert!(reopened.entries().is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+//! Queue of transcripts that could not be injected automatically.
+//!
+//! When Focus Guard or an injection error downgrades a transcript to
+//! clipboard-only, the text is easy to lose under the next clipboard write.
+//! Such transcripts are queued here so the user can insert each one into the
+//! now-correct focus from the tray or settings window; an item is removed as
+//! soon as it is injected or dismissed.
+
+use std::collections::VecDeque;
+use std::sync::RwLock;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Maximum queued transcripts; the oldest is dropped when full.
+const MAX_PENDING_INSERTS: usize = 20;
+
+/// A transcript waiting to be inserted.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PendingInsert {
+    /// History entry the transcript belongs to.
+    pub entry_id: Uuid,
+    /// Final transcript text (without injection suffix).
+    pub text: String,
+    /// Why automatic injection fell back to the clipboard.
+    pub reason: String,
+    /// When the transcript was queued.
+    pub queued_at: DateTime<Utc>,
+}
+
+impl PendingInsert {
+    pub fn new(entry_id: Uuid, text: String, reason: String) -> Self {
+        Self {
+            entry_id,
+            text,
+            reason,
+            queued_at: Utc::now(),
+        }
+    }
+}
+
+/// Canonical `pending_inserts:changed` event payload.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct PendingInsertsChangedPayload {
+    /// Transcripts left in the queue.
+    pub count: usize,
+}
+
+/// Bounded FIFO of pending inserts, shared as Tauri managed state.
+#[derive(Debug, Default)]
+pub struct PendingInsertQueue {
+    items: RwLock<VecDeque<PendingInsert>>,
+}
+
+impl PendingInsertQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a transcript, replacing any existing item for the same entry.
+    pub fn push(&self, item: PendingInsert) {
+        let mut items = self.items.write().unwrap();
+        items.retain(|existing| existing.entry_id != item.entry_id);
+        if items.len() >= MAX_PENDING_INSERTS {
+            items.pop_front();
+        }
+        items.push_back(item);
+    }
+
+    /// Look up a queued transcript.
+    pub fn get(&self, entry_id: Uuid) -> Option<PendingInsert> {
+        self.items
+            .read()
+            .unwrap()
+            .iter()
+            .find(|item| item.entry_id == entry_id)
+            .cloned()
+    }
+
+    /// Remove a queued transcript, returning it if it was present.
+    pub fn remove(&self, entry_id: Uuid) -> Option<PendingInsert> {
+        let mut items = self.items.write().unwrap();
+        let index = items.iter().position(|item| item.entry_id == entry_id)?;
+        items.remove(index)
+    }
+
+    /// All queued transcripts, oldest first.
+    pub fn all(&self) -> Vec<PendingInsert> {
+        self.items.read().unwrap().iter().cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.read().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(text: &str) -> PendingInsert {
+        PendingInsert::new(
+            Uuid::new_v4(),
+            text.to_string(),
+            "Focus changed".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_push_get_and_remove() {
+        let queue = PendingInsertQueue::new();
+        let first = item("first");
+        let second = item("second");
+        queue.push(first.clone());
+        queue.push(second.clone());
+
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.get(first.entry_id), Some(first.clone()));
+        assert_eq!(queue.remove(first.entry_id), Some(first.clone()));
+        assert_eq!(queue.remove(first.entry_id), None);
+        assert_eq!(queue.all(), vec![second]);
+    }
+
+    #[test]
+    fn test_push_replaces_item_for_same_entry() {
+        let queue = PendingInsertQueue::new();
+        let original = item("original");
+        let mut updated = original.clone();
+        updated.text = "updated".to_string();
+
+        queue.push(original);
+        queue.push(updated.clone());
+
+        assert_eq!(queue.all(), vec![updated]);
+    }
+
+    #[test]
+    fn test_queue_drops_oldest_when_full() {
+        let queue = PendingInsertQueue::new();
+        let oldest = item("oldest");
+        queue.push(oldest.clone());
+        for index in 0..MAX_PENDING_INSERTS {
+            queue.push(item(&format!("item {index}")));
+        }
+
+        assert_eq!(queue.len(), MAX_PENDING_INSERTS);
+        assert!(queue.get(oldest.entry_id).is_none());
+        assert_eq!(queue.all()[0].text, "item 0");
+    }
+}
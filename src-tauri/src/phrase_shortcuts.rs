@@ -0,0 +1,472 @@
+//! Exact-phrase shortcuts that expand into canned text.
+//!
+//! Saying a shortcut's phrase on its own ("insert my address") injects the
+//! shortcut's value instead of the transcript. Shortcuts are resolved here,
+//! after the sidecar's replacement rules, and only when the whole utterance
+//! matches, so they never fire inside ordinary dictation. A value is either
+//! literal text, which may span several lines, or the name of a secret in the
+//! OS keyring. Secret values are read at injection time and are never logged
+//! or stored in history.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::config;
+use crate::secrets::{self, SecretError, SecretStore};
+
+/// Shortcuts file name inside the config directory.
+const SHORTCUTS_FILE_NAME: &str = "phrase_shortcuts.json";
+
+/// Maximum number of shortcuts.
+pub const MAX_SHORTCUTS: usize = 200;
+
+/// Longest accepted trigger phrase.
+pub const MAX_PHRASE_CHARS: usize = 100;
+
+/// Longest accepted text value.
+pub const MAX_VALUE_CHARS: usize = 10_000;
+
+static WORD_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\w[\w']*").unwrap());
+
+static PHRASE_SHORTCUTS: Lazy<PhraseShortcuts> =
+    Lazy::new(|| PhraseShortcuts::new(config::config_dir().join(SHORTCUTS_FILE_NAME)));
+
+/// Get the process-wide shortcut list.
+pub fn global_shortcuts() -> &'static PhraseShortcuts {
+    &PHRASE_SHORTCUTS
+}
+
+/// Expand a transcript with the global shortcuts, logging (not propagating) failures.
+pub fn expand(text: &str) -> Option<Expansion> {
+    // Keep unit tests from reading the real config directory and keyring.
+    if cfg!(test) {
+        return None;
+    }
+    match global_shortcuts().expand(text, secrets::store()) {
+        Ok(expansion) => expansion,
+        Err(error) => {
+            log::warn!("Phrase shortcut not expanded: {}", error);
+            None
+        }
+    }
+}
+
+/// Shortcut management errors.
+#[derive(Debug, Error)]
+pub enum ShortcutError {
+    #[error("Shortcut phrase must contain at least one word")]
+    EmptyPhrase,
+
+    #[error("Shortcut phrase must be at most {MAX_PHRASE_CHARS} characters")]
+    PhraseTooLong,
+
+    #[error("A shortcut for \"{0}\" already exists")]
+    DuplicatePhrase(String),
+
+    #[error("Shortcut text must not be empty")]
+    EmptyValue,
+
+    #[error("Shortcut text must be at most {MAX_VALUE_CHARS} characters")]
+    ValueTooLong,
+
+    #[error("At most {MAX_SHORTCUTS} shortcuts can be saved")]
+    TooMany,
+
+    #[error("Secret '{0}' is not in the keychain")]
+    MissingSecret(String),
+
+    #[error(transparent)]
+    Secret(#[from] SecretError),
+
+    #[error("Failed to save shortcuts: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// What a shortcut inserts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ShortcutValue {
+    /// Literal text; newlines are kept.
+    Text { text: String },
+    /// A secret stored with `set_secret`, referenced by name.
+    Secret { secret_name: String },
+}
+
+/// A spoken phrase and the value it expands to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhraseShortcut {
+    pub id: Uuid,
+    /// Trigger phrase as the user entered it.
+    pub phrase: String,
+    pub value: ShortcutValue,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Text to inject in place of a transcript.
+pub struct Expansion {
+    pub shortcut_id: Uuid,
+    pub text: String,
+    /// The text came from the keyring and must not be logged or stored.
+    pub secret: bool,
+}
+
+impl fmt::Debug for Expansion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = if self.secret {
+            "<redacted>"
+        } else {
+            &self.text
+        };
+        f.debug_struct("Expansion")
+            .field("shortcut_id", &self.shortcut_id)
+            .field("text", &text)
+            .field("secret", &self.secret)
+            .finish()
+    }
+}
+
+/// Lowercase words of `text` joined by single spaces, ignoring punctuation,
+/// so "Insert my address." matches the phrase "insert my address".
+pub fn normalize_phrase(text: &str) -> String {
+    let lowercase = text.to_lowercase();
+    WORD_RE
+        .find_iter(&lowercase)
+        .map(|word| word.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// JSON-backed list of phrase shortcuts.
+#[derive(Debug)]
+pub struct PhraseShortcuts {
+    path: PathBuf,
+    /// Shortcuts in creation order; loaded from disk on first use.
+    shortcuts: Mutex<Option<Vec<PhraseShortcut>>>,
+}
+
+impl PhraseShortcuts {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            shortcuts: Mutex::new(None),
+        }
+    }
+
+    /// All shortcuts in creation order.
+    pub fn entries(&self) -> Vec<PhraseShortcut> {
+        self.with_shortcuts(|shortcuts| Ok::<_, ShortcutError>(shortcuts.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Create a shortcut, or replace the one with the same `id`.
+    pub fn save(
+        &self,
+        id: Option<Uuid>,
+        phrase: &str,
+        value: ShortcutValue,
+        enabled: bool,
+    ) -> Result<PhraseShortcut, ShortcutError> {
+        let phrase = phrase.trim();
+        let normalized = normalize_phrase(phrase);
+        if normalized.is_empty() {
+            return Err(ShortcutError::EmptyPhrase);
+        }
+        if phrase.chars().count() > MAX_PHRASE_CHARS {
+            return Err(ShortcutError::PhraseTooLong);
+        }
+        let value = validate_value(value)?;
+
+        self.with_shortcuts(|shortcuts| {
+            if shortcuts.iter().any(|existing| {
+                Some(existing.id) != id && normalize_phrase(&existing.phrase) == normalized
+            }) {
+                return Err(ShortcutError::DuplicatePhrase(phrase.to_string()));
+            }
+
+            let shortcut = PhraseShortcut {
+                id: id.unwrap_or_else(Uuid::new_v4),
+                phrase: phrase.to_string(),
+                value,
+                enabled,
+            };
+            if let Some(existing) = shortcuts
+                .iter_mut()
+                .find(|existing| existing.id == shortcut.id)
+            {
+                *existing = shortcut.clone();
+            } else if shortcuts.len() >= MAX_SHORTCUTS {
+                return Err(ShortcutError::TooMany);
+            } else {
+                shortcuts.push(shortcut.clone());
+            }
+            Ok(shortcut)
+        })
+    }
+
+    /// Delete a shortcut. Returns whether it existed.
+    pub fn remove(&self, id: Uuid) -> Result<bool, ShortcutError> {
+        self.with_shortcuts(|shortcuts| {
+            let before = shortcuts.len();
+            shortcuts.retain(|shortcut| shortcut.id != id);
+            Ok(shortcuts.len() != before)
+        })
+    }
+
+    /// The expansion for `text` if the whole of it is an enabled shortcut's phrase.
+    pub fn expand(
+        &self,
+        text: &str,
+        secrets: &SecretStore,
+    ) -> Result<Option<Expansion>, ShortcutError> {
+        let normalized = normalize_phrase(text);
+        if normalized.is_empty() {
+            return Ok(None);
+        }
+        let matched = self.with_shortcuts(|shortcuts| {
+            Ok::<_, ShortcutError>(
+                shortcuts
+                    .iter()
+                    .find(|shortcut| {
+                        shortcut.enabled && normalize_phrase(&shortcut.phrase) == normalized
+                    })
+                    .cloned(),
+            )
+        })?;
+        let Some(shortcut) = matched else {
+            return Ok(None);
+        };
+
+        let (text, secret) = match shortcut.value {
+            ShortcutValue::Text { text } => (text, false),
+            ShortcutValue::Secret { secret_name } => match secrets.resolve(&secret_name)? {
+                Some(value) => (value, true),
+                None => return Err(ShortcutError::MissingSecret(secret_name)),
+            },
+        };
+        Ok(Some(Expansion {
+            shortcut_id: shortcut.id,
+            text,
+            secret,
+        }))
+    }
+
+    /// Run `f` against the loaded shortcuts, saving afterwards if they changed.
+    fn with_shortcuts<T>(
+        &self,
+        f: impl FnOnce(&mut Vec<PhraseShortcut>) -> Result<T, ShortcutError>,
+    ) -> Result<T, ShortcutError> {
+        let mut guard = self
+            .shortcuts
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let shortcuts = guard.get_or_insert_with(|| self.load());
+        let before = shortcuts.clone();
+        let result = f(shortcuts)?;
+        if *shortcuts != before {
+            self.save_file(shortcuts)?;
+        }
+        Ok(result)
+    }
+
+    fn load(&self) -> Vec<PhraseShortcut> {
+        let Ok(contents) = fs::read_to_string(&self.path) else {
+            return Vec::new();
+        };
+        serde_json::from_str(&contents).unwrap_or_else(|error| {
+            log::warn!("Ignoring unreadable phrase shortcuts: {}", error);
+            Vec::new()
+        })
+    }
+
+    fn save_file(&self, shortcuts: &[PhraseShortcut]) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string_pretty(shortcuts)?;
+        let temp_path = self.path.with_extension("json.tmp");
+        fs::write(&temp_path, contents)?;
+        fs::rename(&temp_path, &self.path)
+    }
+}
+
+fn validate_value(value: ShortcutValue) -> Result<ShortcutValue, ShortcutError> {
+    match value {
+        ShortcutValue::Text { text } => {
+            let text = text.replace("\r\n", "\n");
+            if text.trim().is_empty() {
+                return Err(ShortcutError::EmptyValue);
+            }
+            if text.chars().count() > MAX_VALUE_CHARS {
+                return Err(ShortcutError::ValueTooLong);
+            }
+            Ok(ShortcutValue::Text { text })
+        }
+        ShortcutValue::Secret { secret_name } => {
+            let secret_name = secret_name.trim().to_string();
+            if !secrets::is_valid_secret_name(&secret_name) {
+                return Err(SecretError::InvalidName(secret_name).into());
+            }
+            Ok(ShortcutValue::Secret { secret_name })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secrets::SecretBackend;
+    use std::collections::HashMap;
+    use tempfile::tempdir;
+
+    #[derive(Default)]
+    struct MemoryBackend {
+        values: Mutex<HashMap<String, String>>,
+    }
+
+    impl SecretBackend for MemoryBackend {
+        fn get(&self, name: &str) -> Result<Option<String>, SecretError> {
+            Ok(self.values.lock().unwrap().get(name).cloned())
+        }
+
+        fn set(&self, name: &str, value: &str) -> Result<(), SecretError> {
+            self.values
+                .lock()
+                .unwrap()
+                .insert(name.to_string(), value.to_string());
+            Ok(())
+        }
+
+        fn delete(&self, name: &str) -> Result<bool, SecretError> {
+            Ok(self.values.lock().unwrap().remove(name).is_some())
+        }
+    }
+
+    fn shortcuts() -> (tempfile::TempDir, PhraseShortcuts) {
+        let dir = tempdir().unwrap();
+        let shortcuts = PhraseShortcuts::new(dir.path().join("shortcuts.json"));
+        (dir, shortcuts)
+    }
+
+    fn text(value: &str) -> ShortcutValue {
+        ShortcutValue::Text {
+            text: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_normalize_phrase_ignores_case_and_punctuation() {
+        assert_eq!(
+            normalize_phrase("  Insert my   address. "),
+            "insert my address"
+        );
+        assert_eq!(normalize_phrase("What's up?!"), "what's up");
+        assert_eq!(normalize_phrase("..."), "");
+    }
+
+    #[test]
+    fn test_expand_matches_whole_transcript_only() {
+        let (_dir, shortcuts) = shortcuts();
+        let secrets = SecretStore::new(Box::<MemoryBackend>::default());
+        let address = "221B Baker Street\nLondon";
+        shortcuts
+            .save(None, "insert my address", text(address), true)
+            .unwrap();
+
+        let expansion = shortcuts
+            .expand("Insert my address.", &secrets)
+            .unwrap()
+            .unwrap();
+        assert_eq!(expansion.text, address);
+        assert!(!expansion.secret);
+        assert!(shortcuts
+            .expand("please insert my address now", &secrets)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_secret_values_come_from_keyring_and_are_redacted() {
+        let (_dir, shortcuts) = shortcuts();
+        let secrets = SecretStore::new(Box::<MemoryBackend>::default());
+        shortcuts
+            .save(
+                None,
+                "insert my tax id",
+                ShortcutValue::Secret {
+                    secret_name: "shortcut.tax_id".to_string(),
+                },
+                true,
+            )
+            .unwrap();
+
+        assert!(matches!(
+            shortcuts.expand("insert my tax id", &secrets),
+            Err(ShortcutError::MissingSecret(_))
+        ));
+
+        secrets.set("shortcut.tax_id", "12-3456789").unwrap();
+        let expansion = shortcuts
+            .expand("insert my tax id", &secrets)
+            .unwrap()
+            .unwrap();
+        assert_eq!(expansion.text, "12-3456789");
+        assert!(expansion.secret);
+        assert!(!format!("{expansion:?}").contains("12-3456789"));
+        // The file only references the secret by name.
+        let saved = fs::read_to_string(&shortcuts.path).unwrap();
+        assert!(!saved.contains("12-3456789"));
+    }
+
+    #[test]
+    fn test_save_validates_and_updates_in_place() {
+        let (_dir, shortcuts) = shortcuts();
+        let saved = shortcuts
+            .save(None, "sign off", text("Best,\nSam"), true)
+            .unwrap();
+
+        assert!(matches!(
+            shortcuts.save(None, "Sign off!", text("Cheers"), true),
+            Err(ShortcutError::DuplicatePhrase(_))
+        ));
+        assert!(matches!(
+            shortcuts.save(None, " ? ", text("x"), true),
+            Err(ShortcutError::EmptyPhrase)
+        ));
+        assert!(matches!(
+            shortcuts.save(None, "blank", text(" \n "), true),
+            Err(ShortcutError::EmptyValue)
+        ));
+
+        shortcuts
+            .save(Some(saved.id), "sign off", text("Cheers"), false)
+            .unwrap();
+        let entries = shortcuts.entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].value, text("Cheers"));
+        assert!(!entries[0].enabled);
+
+        // Disabled shortcuts never expand.
+        let secrets = SecretStore::new(Box::<MemoryBackend>::default());
+        assert!(shortcuts.expand("sign off", &secrets).unwrap().is_none());
+
+        // Reloading from disk keeps the saved shortcuts.
+        let reloaded = PhraseShortcuts::new(shortcuts.path.clone());
+        assert_eq!(reloaded.entries(), entries);
+        assert!(reloaded.remove(saved.id).unwrap());
+        assert!(!reloaded.remove(saved.id).unwrap());
+    }
+}
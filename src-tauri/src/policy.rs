@@ -0,0 +1,282 @@
+//! Admin-provisioned policy for managed (kiosk/enterprise) installations.
+//!
+//! An administrator can place a JSON policy file at a system-wide path that
+//! users cannot write to. It pins config fields to fixed values by dotted
+//! path (e.g. `history.persistence_mode`) and can restrict which models may
+//! be selected or downloaded:
+//!
+//! ```json
+//! {
+//!   "locked": { "history.persistence_mode": "memory" },
+//!   "allowed_models": ["parakeet-tdt-0.6b-v3"]
+//! }
+//! ```
+//!
+//! Locked values are applied whenever config is loaded, `update_config`
+//! rejects changes to them, and `get_config` lists them as managed. The
+//! policy is read once at startup.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::config::AppConfig;
+
+/// Overrides the system policy path in debug builds (for testing deployments).
+const POLICY_PATH_ENV: &str = "OPENVOICY_POLICY_PATH";
+const POLICY_FILE_NAME: &str = "policy.json";
+
+/// Config path of the selected model, locked implicitly by `allowed_models`.
+const MODEL_ID_FIELD: &str = "model.model_id";
+
+static POLICY: Lazy<ManagedPolicy> = Lazy::new(|| {
+    // Keep unit tests independent of any policy installed on the machine.
+    if cfg!(test) {
+        return ManagedPolicy::default();
+    }
+    ManagedPolicy::load(&policy_path())
+});
+
+/// Rejected change to a managed setting.
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("Managed by your administrator: {}", .fields.join(", "))]
+pub struct PolicyViolation {
+    pub fields: Vec<String>,
+}
+
+/// Settings locked by the policy file.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ManagedPolicy {
+    /// Fixed values keyed by dotted config path.
+    #[serde(default)]
+    pub locked: BTreeMap<String, Value>,
+    /// Model IDs that may be selected or downloaded; None allows any.
+    #[serde(default)]
+    pub allowed_models: Option<Vec<String>>,
+}
+
+impl ManagedPolicy {
+    /// Load a policy file; a missing file means no policy.
+    pub fn load(path: &Path) -> Self {
+        match fs::read_to_string(path) {
+            Ok(contents) => match serde_json::from_str::<Self>(&contents) {
+                Ok(policy) => {
+                    log::info!(
+                        "Loaded managed policy from {:?} ({} locked fields)",
+                        path,
+                        policy.locked.len()
+                    );
+                    policy
+                }
+                Err(error) => {
+                    log::error!("Ignoring invalid managed policy {:?}: {}", path, error);
+                    Self::default()
+                }
+            },
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.locked.is_empty() && self.allowed_models.is_none()
+    }
+
+    /// Dotted paths of every managed config field.
+    pub fn managed_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.locked.keys().cloned().collect();
+        if self.allowed_models.is_some() && !self.locked.contains_key(MODEL_ID_FIELD) {
+            fields.push(MODEL_ID_FIELD.to_string());
+        }
+        fields.sort();
+        fields
+    }
+
+    /// Whether a model may be selected or downloaded.
+    pub fn is_model_allowed(&self, model_id: &str) -> bool {
+        self.allowed_models
+            .as_ref()
+            .is_none_or(|allowed| allowed.iter().any(|id| id == model_id))
+    }
+
+    /// Apply locked values to `config`.
+    ///
+    /// A disallowed model selection is replaced with the first allowed model.
+    /// Locks for fields the config does not have, or whose value does not fit
+    /// the field, are logged and skipped.
+    pub fn enforce(&self, config: &mut AppConfig) {
+        if self.is_empty() {
+            return;
+        }
+        let Ok(mut value) = serde_json::to_value(&*config) else {
+            return;
+        };
+        for (path, locked) in &self.locked {
+            match field_mut(&mut value, path) {
+                Some(field) => *field = locked.clone(),
+                None => log::warn!("Managed policy locks unknown config field '{}'", path),
+            }
+        }
+        if let Some(allowed) = &self.allowed_models {
+            let selected = field(&value, MODEL_ID_FIELD).and_then(Value::as_str);
+            if !selected.is_some_and(|id| self.is_model_allowed(id)) {
+                // Indexing creates the `model` section when it is unset.
+                value["model"]["model_id"] = allowed.first().cloned().into();
+            }
+        }
+        match serde_json::from_value::<AppConfig>(value) {
+            Ok(enforced) => *config = enforced,
+            Err(error) => log::error!("Managed policy values do not fit the config: {}", error),
+        }
+    }
+
+    /// Check that `requested` leaves every managed field at its enforced value.
+    pub fn check_update(&self, requested: &AppConfig) -> Result<(), PolicyViolation> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        let mut enforced = requested.clone();
+        self.enforce(&mut enforced);
+        let (Ok(requested), Ok(enforced)) = (
+            serde_json::to_value(requested),
+            serde_json::to_value(&enforced),
+        ) else {
+            return Ok(());
+        };
+
+        let fields: Vec<String> = self
+            .managed_fields()
+            .into_iter()
+            .filter(|path| field(&requested, path) != field(&enforced, path))
+            .collect();
+        if fields.is_empty() {
+            Ok(())
+        } else {
+            Err(PolicyViolation { fields })
+        }
+    }
+}
+
+/// The policy in effect for this process.
+pub fn current() -> &'static ManagedPolicy {
+    &POLICY
+}
+
+/// System-wide policy file location.
+///
+/// Release builds ignore `OPENVOICY_POLICY_PATH` so a user cannot point them
+/// at a policy file of their own and lift the admin's locks.
+pub fn policy_path() -> PathBuf {
+    if let Some(path) = env::var_os(POLICY_PATH_ENV).filter(|_| cfg!(debug_assertions)) {
+        return PathBuf::from(path);
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        PathBuf::from("/Library/Application Support/OpenVoicy").join(POLICY_FILE_NAME)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        env::var_os("ProgramData")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(r"C:\ProgramData"))
+            .join("OpenVoicy")
+            .join(POLICY_FILE_NAME)
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        PathBuf::from("/etc/openvoicy").join(POLICY_FILE_NAME)
+    }
+}
+
+fn field<'a>(root: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(root, |value, segment| value.get(segment))
+}
+
+fn field_mut<'a>(root: &'a mut Value, path: &str) -> Option<&'a mut Value> {
+    path.split('.')
+        .try_fold(root, |value, segment| value.get_mut(segment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn policy(value: Value) -> ManagedPolicy {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_enforce_applies_locked_values() {
+        let policy = policy(json!({
+            "locked": {
+                "history.persistence_mode": "memory",
+                "history.max_entries": 10,
+                "telemetry.enabled": false
+            }
+        }));
+        let mut config = AppConfig::default();
+        config.history.persistence_mode = "disk".to_string();
+
+        policy.enforce(&mut config);
+        assert_eq!(config.history.persistence_mode, "memory");
+        assert_eq!(config.history.max_entries, 10);
+    }
+
+    #[test]
+    fn test_check_update_rejects_changes_to_locked_fields() {
+        let policy = policy(json!({ "locked": { "history.persistence_mode": "memory" } }));
+        let mut requested = AppConfig::default();
+        policy.enforce(&mut requested);
+        assert_eq!(policy.check_update(&requested), Ok(()));
+
+        requested.history.persistence_mode = "disk".to_string();
+        // Unmanaged fields can still change.
+        requested.ui.theme = "dark".to_string();
+        assert_eq!(
+            policy.check_update(&requested),
+            Err(PolicyViolation {
+                fields: vec!["history.persistence_mode".to_string()]
+            })
+        );
+    }
+
+    #[test]
+    fn test_allowed_models_restrict_selection() {
+        let policy = policy(json!({ "allowed_models": ["model-a", "model-b"] }));
+        assert!(policy.is_model_allowed("model-b"));
+        assert!(!policy.is_model_allowed("model-c"));
+        assert_eq!(policy.managed_fields(), vec![MODEL_ID_FIELD.to_string()]);
+
+        let model_id = |config: &AppConfig| config.model.as_ref()?.model_id.clone();
+        let mut config = AppConfig::default();
+        policy.enforce(&mut config);
+        assert_eq!(model_id(&config).as_deref(), Some("model-a"));
+
+        config.model.as_mut().unwrap().model_id = Some("model-c".to_string());
+        policy.enforce(&mut config);
+        assert_eq!(model_id(&config).as_deref(), Some("model-a"));
+
+        config.model.as_mut().unwrap().model_id = Some("model-b".to_string());
+        assert_eq!(policy.check_update(&config), Ok(()));
+        config.model.as_mut().unwrap().model_id = Some("model-c".to_string());
+        assert!(policy.check_update(&config).is_err());
+    }
+
+    #[test]
+    fn test_empty_policy_manages_nothing() {
+        let policy = ManagedPolicy::default();
+        assert!(policy.managed_fields().is_empty());
+        assert!(policy.is_model_allowed("anything"));
+        assert_eq!(policy.check_update(&AppConfig::default()), Ok(()));
+    }
+}
@@ -0,0 +1,299 @@
+//! Power source detection and the low-power profile.
+//!
+//! With `power.low_power_on_battery` on, running on battery switches the app
+//! to a low-power profile: the sidecar watchdog pings less often, the model
+//! is not preloaded, audio level events are delivered at a lower rate, and
+//! the sidecar unloads its model after a few idle minutes instead of keeping
+//! it loaded until dictation is fully paused. The profile in effect is
+//! reported in diagnostics.
+//!
+//! Windows reads `GetSystemPowerStatus`, Linux reads
+//! `/sys/class/power_supply`, and macOS asks `pmset`.
+
+use std::sync::RwLock;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+use crate::config::PowerConfig;
+
+/// How often the power source is checked.
+pub const POWER_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Idle time before the sidecar model is unloaded under the low-power profile.
+const LOW_POWER_SIDECAR_IDLE_SUSPEND: Duration = Duration::from_secs(5 * 60);
+
+static POWER_STATUS: Lazy<RwLock<PowerStatus>> = Lazy::new(|| RwLock::new(PowerStatus::default()));
+
+/// Where the machine draws power from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PowerSource {
+    /// Mains power (or a machine without a battery).
+    Ac,
+    Battery,
+    /// Not detected yet, or not detectable on this platform.
+    #[default]
+    Unknown,
+}
+
+/// Resource profile the app runs with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PowerProfile {
+    #[default]
+    Normal,
+    /// Running on battery with `power.low_power_on_battery` on.
+    LowPower,
+}
+
+/// Power source and the profile in effect, as reported in diagnostics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct PowerStatus {
+    pub source: PowerSource,
+    pub profile: PowerProfile,
+}
+
+impl PowerStatus {
+    fn new(source: PowerSource, config: &PowerConfig) -> Self {
+        let profile = if config.low_power_on_battery && source == PowerSource::Battery {
+            PowerProfile::LowPower
+        } else {
+            PowerProfile::Normal
+        };
+        Self { source, profile }
+    }
+}
+
+/// The last detected power status.
+pub fn status() -> PowerStatus {
+    *POWER_STATUS.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The profile in effect.
+pub fn current_profile() -> PowerProfile {
+    status().profile
+}
+
+/// Detect the power source again and update the status. Returns the new
+/// status when the profile changed.
+pub fn refresh(config: &PowerConfig) -> Option<PowerStatus> {
+    update(PowerStatus::new(detect(), config))
+}
+
+/// Re-evaluate the profile for changed settings, keeping the last detected
+/// source. Returns the new status when the profile changed.
+pub fn apply_config(config: &PowerConfig) -> Option<PowerStatus> {
+    update(PowerStatus::new(status().source, config))
+}
+
+fn update(next: PowerStatus) -> Option<PowerStatus> {
+    let mut current = POWER_STATUS.write().unwrap_or_else(|e| e.into_inner());
+    let previous = std::mem::replace(&mut *current, next);
+    if previous.profile == next.profile {
+        return None;
+    }
+    log::info!(
+        "Power profile changed to {:?} (source: {:?})",
+        next.profile,
+        next.source
+    );
+    Some(next)
+}
+
+/// Idle time after which the sidecar model is unloaded. The normal profile
+/// keeps it loaded until dictation is fully paused.
+pub fn sidecar_idle_suspend_after(profile: PowerProfile) -> Option<Duration> {
+    match profile {
+        PowerProfile::Normal => None,
+        PowerProfile::LowPower => Some(LOW_POWER_SIDECAR_IDLE_SUSPEND),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn detect() -> PowerSource {
+    let mut status = SystemPowerStatus::default();
+    if unsafe { GetSystemPowerStatus(&mut status) } == 0 {
+        return PowerSource::Unknown;
+    }
+    match status.ac_line_status {
+        0 => PowerSource::Battery,
+        1 => PowerSource::Ac,
+        _ => PowerSource::Unknown,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect() -> PowerSource {
+    let Ok(entries) = std::fs::read_dir("/sys/class/power_supply") else {
+        return PowerSource::Unknown;
+    };
+    let read = |path: &std::path::Path, name: &str| {
+        std::fs::read_to_string(path.join(name))
+            .map(|value| value.trim().to_string())
+            .unwrap_or_default()
+    };
+    let supplies: Vec<PowerSupply> = entries
+        .flatten()
+        .map(|entry| {
+            let path = entry.path();
+            PowerSupply {
+                kind: read(&path, "type"),
+                online: read(&path, "online") == "1",
+                status: read(&path, "status"),
+            }
+        })
+        .collect();
+    source_from_supplies(&supplies)
+}
+
+#[cfg(target_os = "macos")]
+fn detect() -> PowerSource {
+    match std::process::Command::new("pmset")
+        .args(["-g", "batt"])
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            parse_pmset_batt(&String::from_utf8_lossy(&output.stdout))
+        }
+        _ => PowerSource::Unknown,
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux", target_os = "macos")))]
+fn detect() -> PowerSource {
+    PowerSource::Unknown
+}
+
+/// One entry of `/sys/class/power_supply`.
+#[cfg(any(target_os = "linux", test))]
+struct PowerSupply {
+    /// `Mains`, `USB`, `Battery`, ...
+    kind: String,
+    online: bool,
+    /// Battery charge status, e.g. `Discharging`.
+    status: String,
+}
+
+/// On battery only when no external supply is online and a battery is
+/// discharging; machines without a battery count as on mains.
+#[cfg(any(target_os = "linux", test))]
+fn source_from_supplies(supplies: &[PowerSupply]) -> PowerSource {
+    let external_online = supplies
+        .iter()
+        .any(|supply| supply.kind != "Battery" && supply.online);
+    let discharging = supplies
+        .iter()
+        .any(|supply| supply.kind == "Battery" && supply.status == "Discharging");
+    if discharging && !external_online {
+        PowerSource::Battery
+    } else {
+        PowerSource::Ac
+    }
+}
+
+/// Source from the `Now drawing from '...'` line of `pmset -g batt`.
+#[cfg(any(target_os = "macos", test))]
+fn parse_pmset_batt(output: &str) -> PowerSource {
+    let Some(line) = output.lines().find(|line| line.contains("drawing from")) else {
+        return PowerSource::Unknown;
+    };
+    if line.contains("'Battery Power'") {
+        PowerSource::Battery
+    } else if line.contains("'AC Power'") {
+        PowerSource::Ac
+    } else {
+        PowerSource::Unknown
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[repr(C)]
+#[derive(Default)]
+#[allow(dead_code)] // Filled in by the OS; only the AC line status is read.
+struct SystemPowerStatus {
+    ac_line_status: u8,
+    battery_flag: u8,
+    battery_life_percent: u8,
+    system_status_flag: u8,
+    battery_life_time: u32,
+    battery_full_life_time: u32,
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "kernel32")]
+unsafe extern "system" {
+    fn GetSystemPowerStatus(status: *mut SystemPowerStatus) -> i32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn supply(kind: &str, online: bool, status: &str) -> PowerSupply {
+        PowerSupply {
+            kind: kind.to_string(),
+            online,
+            status: status.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_low_power_profile_only_on_battery_when_enabled() {
+        let enabled = PowerConfig {
+            low_power_on_battery: true,
+        };
+        assert_eq!(
+            PowerStatus::new(PowerSource::Battery, &enabled).profile,
+            PowerProfile::LowPower
+        );
+        assert_eq!(
+            PowerStatus::new(PowerSource::Ac, &enabled).profile,
+            PowerProfile::Normal
+        );
+        assert_eq!(
+            PowerStatus::new(PowerSource::Battery, &PowerConfig::default()).profile,
+            PowerProfile::Normal
+        );
+    }
+
+    #[test]
+    fn test_source_from_supplies() {
+        let on_battery = [
+            supply("Mains", false, ""),
+            supply("Battery", false, "Discharging"),
+        ];
+        assert_eq!(source_from_supplies(&on_battery), PowerSource::Battery);
+
+        let charging = [
+            supply("Mains", true, ""),
+            supply("Battery", false, "Charging"),
+        ];
+        assert_eq!(source_from_supplies(&charging), PowerSource::Ac);
+
+        // Desktops have no battery at all.
+        assert_eq!(source_from_supplies(&[]), PowerSource::Ac);
+    }
+
+    #[test]
+    fn test_parse_pmset_batt() {
+        let battery =
+            "Now drawing from 'Battery Power'\n -InternalBattery-0 (id=1)\t80%; discharging";
+        assert_eq!(parse_pmset_batt(battery), PowerSource::Battery);
+        assert_eq!(
+            parse_pmset_batt("Now drawing from 'AC Power'\n"),
+            PowerSource::Ac
+        );
+        assert_eq!(parse_pmset_batt(""), PowerSource::Unknown);
+    }
+
+    #[test]
+    fn test_only_low_power_suspends_idle_sidecar() {
+        assert_eq!(sidecar_idle_suspend_after(PowerProfile::Normal), None);
+        assert_eq!(
+            sidecar_idle_suspend_after(PowerProfile::LowPower),
+            Some(LOW_POWER_SIDECAR_IDLE_SUSPEND)
+        );
+    }
+}
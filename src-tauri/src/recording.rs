@@ -5,24 +5,30 @@
 //! - Time-bound behaviors (max duration, too-short threshold, timeout)
 //! - Double-tap cancel support
 //! - Stale notification rejection
+//! - Ordered utterance delivery for continuous dictation
 //!
 //! # Session Management
 //!
 //! Each recording session gets a unique UUID. This ID is passed to the sidecar
 //! and used to correlate transcription results. Stale notifications (from
 //! previous sessions) are silently ignored.
+//!
+//! A continuous session also receives utterance results while it is still
+//! recording. They carry a segment index and are released in that order, so
+//! text is injected in the order it was spoken.
 
 #![allow(dead_code)] // Module under construction
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::sync::{broadcast, Mutex, RwLock};
 use uuid::Uuid;
 
-use crate::state::{AppState, AppStateManager};
+use crate::state::{AppState, AppStateManager, ErrorCategory};
 
 /// Default configuration values.
 pub mod defaults {
@@ -92,12 +98,24 @@ pub enum CancelReason {
     DoubleTap,
     /// User clicked cancel button.
     UserButton,
-    /// User pressed escape key.
+    /// User pressed the cancel hotkey (Escape by default).
     EscapeKey,
     /// Max duration auto-stop (not really a cancel, but handled similarly).
     MaxDuration,
 }
 
+impl CancelReason {
+    /// Serialized name, as sent in event payloads.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DoubleTap => "double_tap",
+            Self::UserButton => "user_button",
+            Self::EscapeKey => "escape_key",
+            Self::MaxDuration => "max_duration",
+        }
+    }
+}
+
 /// Recording controller errors.
 #[derive(Debug, Error)]
 pub enum RecordingError {
@@ -136,6 +154,21 @@ pub struct TranscriptionResult {
     pub processing_duration_ms: u64,
 }
 
+/// One utterance of a continuous session, transcribed while recording goes on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UtteranceResult {
+    /// Session ID this utterance belongs to.
+    pub session_id: SessionId,
+    /// Zero-based position of the utterance within the session.
+    pub segment_index: u32,
+    /// Transcribed text.
+    pub text: String,
+    /// Audio duration in milliseconds.
+    pub audio_duration_ms: u64,
+    /// Transcription processing time in milliseconds.
+    pub processing_duration_ms: u64,
+}
+
 /// Recording session event.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "snake_case", tag = "type")]
@@ -153,6 +186,7 @@ pub enum RecordingEvent {
     },
     /// Recording was too short.
     TooShort {
+        session_id: SessionId,
         duration_ms: u64,
         timestamp: DateTime<Utc>,
     },
@@ -162,6 +196,15 @@ pub enum RecordingEvent {
         reason: CancelReason,
         timestamp: DateTime<Utc>,
     },
+    /// An utterance of a continuous session was transcribed; recording goes on.
+    UtteranceComplete {
+        session_id: SessionId,
+        segment_index: u32,
+        text: String,
+        audio_duration_ms: u64,
+        processing_duration_ms: u64,
+        timestamp: DateTime<Utc>,
+    },
     /// Transcription completed.
     TranscriptionComplete {
         session_id: SessionId,
@@ -194,6 +237,25 @@ struct ActiveSession {
     id: SessionId,
     start_time: Instant,
     start_timestamp: DateTime<Utc>,
+    /// When the current utterance began; max duration is measured from here
+    /// so a continuous session is limited per utterance.
+    segment_start: Instant,
+    /// Next utterance index to release.
+    next_segment: u32,
+    /// Utterances that arrived ahead of an earlier one.
+    pending_segments: BTreeMap<u32, UtteranceResult>,
+}
+
+impl ActiveSession {
+    /// Remove the utterances that are next in order.
+    fn take_ready_segments(&mut self) -> Vec<UtteranceResult> {
+        let mut ready = Vec::new();
+        while let Some(result) = self.pending_segments.remove(&self.next_segment) {
+            self.next_segment += 1;
+            ready.push(result);
+        }
+        ready
+    }
 }
 
 /// Recording controller state.
@@ -282,7 +344,9 @@ impl RecordingController {
         self.state_manager
             .can_start_recording()
             .map_err(|reason| match reason {
-                crate::state::CannotRecordReason::Paused => RecordingError::Disabled,
+                crate::state::CannotRecordReason::Paused
+                | crate::state::CannotRecordReason::HotkeysPaused
+                | crate::state::CannotRecordReason::ScreenSharing => RecordingError::Disabled,
                 crate::state::CannotRecordReason::ModelLoading => RecordingError::ModelNotReady,
                 crate::state::CannotRecordReason::AlreadyRecording => {
                     RecordingError::AlreadyRecording
@@ -310,6 +374,9 @@ impl RecordingController {
                 id: session_id.clone(),
                 start_time: now,
                 start_timestamp: timestamp,
+                segment_start: now,
+                next_segment: 0,
+                pending_segments: BTreeMap::new(),
             });
         }
 
@@ -351,6 +418,7 @@ impl RecordingController {
 
             // Emit event
             let _ = self.event_sender.send(RecordingEvent::TooShort {
+                session_id,
                 duration_ms: duration.as_millis() as u64,
                 timestamp: Utc::now(),
             });
@@ -483,40 +551,63 @@ impl RecordingController {
     /// Returns the same stop result emitted by `stop()` when auto-stop fires.
     pub async fn check_max_duration(&self) -> Option<StopResult> {
         // Check if max duration exceeded
-        let (session_id, duration_ms, exceeded) = {
+        let exceeded = {
             let session = self.active_session.read().await;
             if let Some(session) = session.as_ref() {
                 let config = self.config.read().await;
-                let elapsed = session.start_time.elapsed();
-                if elapsed >= config.max_duration {
-                    (Some(session.id.clone()), elapsed.as_millis() as u64, true)
-                } else {
-                    (None, 0, false)
-                }
+                let elapsed = session.segment_start.elapsed();
+                (elapsed >= config.max_duration)
+                    .then(|| (session.id.clone(), elapsed.as_millis() as u64))
             } else {
-                (None, 0, false)
+                None
             }
         };
 
-        if exceeded {
-            if let Some(session_id) = session_id {
-                // Emit max duration event
-                let _ = self.event_sender.send(RecordingEvent::MaxDurationReached {
-                    session_id,
-                    duration_ms,
-                    timestamp: Utc::now(),
-                });
-            }
+        let (session_id, duration_ms) = exceeded?;
+        self.stop_at_max_duration(session_id, duration_ms).await
+    }
 
-            // Stop recording
-            match self.stop().await {
-                Ok(result) => return Some(result),
-                Err(err) => {
-                    log::warn!("Max duration auto-stop failed: {}", err);
-                }
+    /// Start a new segment when the current one has reached the max duration,
+    /// so the recording continues instead of stopping.
+    ///
+    /// Returns the session and the length of the segment that ended. The
+    /// caller hands that audio off for transcription, or falls back to
+    /// `stop_at_max_duration` when it cannot.
+    pub async fn split_at_max_duration(&self) -> Option<(SessionId, u64)> {
+        let mut session = self.active_session.write().await;
+        let session = session.as_mut()?;
+        let config = self.config.read().await;
+        let elapsed = session.segment_start.elapsed();
+        if elapsed < config.max_duration {
+            return None;
+        }
+        session.segment_start = Instant::now();
+        Some((session.id.clone(), elapsed.as_millis() as u64))
+    }
+
+    /// Announce that `session_id` reached the max duration and stop it.
+    pub async fn stop_at_max_duration(
+        &self,
+        session_id: SessionId,
+        duration_ms: u64,
+    ) -> Option<StopResult> {
+        if self.current_session_id().await.as_ref() != Some(&session_id) {
+            return None;
+        }
+
+        let _ = self.event_sender.send(RecordingEvent::MaxDurationReached {
+            session_id,
+            duration_ms,
+            timestamp: Utc::now(),
+        });
+
+        match self.stop().await {
+            Ok(result) => Some(result),
+            Err(err) => {
+                log::warn!("Max duration auto-stop failed: {}", err);
+                None
             }
         }
-        None
     }
 
     /// Handle transcription result from sidecar.
@@ -534,8 +625,22 @@ impl RecordingController {
             return false;
         }
 
-        // Clear session
-        *self.active_session.write().await = None;
+        // Clear session, releasing utterances still held behind a gap so
+        // nothing spoken is lost.
+        let session = self.active_session.write().await.take();
+        let leftover: Vec<UtteranceResult> = session
+            .map(|session| session.pending_segments.into_values().collect())
+            .unwrap_or_default();
+        if !leftover.is_empty() {
+            log::warn!(
+                "Releasing {} out-of-order utterances at end of session {}",
+                leftover.len(),
+                result.session_id
+            );
+        }
+        for utterance in leftover {
+            self.emit_utterance(utterance);
+        }
 
         // Transition to idle
         let _ = self.state_manager.transition(AppState::Idle);
@@ -554,6 +659,56 @@ impl RecordingController {
         true
     }
 
+    /// Handle an utterance of a continuous session.
+    ///
+    /// Utterances are released as `UtteranceComplete` events in segment
+    /// order; one that arrives early is held until the gap before it fills.
+    /// Returns false for stale sessions and duplicate segments.
+    pub async fn on_utterance_result(&self, result: UtteranceResult) -> bool {
+        let ready = {
+            let mut session = self.active_session.write().await;
+            let Some(session) = session.as_mut().filter(|s| s.id == result.session_id) else {
+                log::warn!(
+                    "Ignoring stale utterance: session={}, segment={}",
+                    result.session_id,
+                    result.segment_index
+                );
+                return false;
+            };
+            if result.segment_index < session.next_segment
+                || session.pending_segments.contains_key(&result.segment_index)
+            {
+                log::warn!(
+                    "Ignoring duplicate utterance: session={}, segment={}",
+                    result.session_id,
+                    result.segment_index
+                );
+                return false;
+            }
+            session.segment_start = Instant::now();
+            session
+                .pending_segments
+                .insert(result.segment_index, result);
+            session.take_ready_segments()
+        };
+
+        for utterance in ready {
+            self.emit_utterance(utterance);
+        }
+        true
+    }
+
+    fn emit_utterance(&self, result: UtteranceResult) {
+        let _ = self.event_sender.send(RecordingEvent::UtteranceComplete {
+            session_id: result.session_id,
+            segment_index: result.segment_index,
+            text: result.text,
+            audio_duration_ms: result.audio_duration_ms,
+            processing_duration_ms: result.processing_duration_ms,
+            timestamp: Utc::now(),
+        });
+    }
+
     /// Handle transcription error from sidecar.
     pub async fn on_transcription_error(&self, session_id: SessionId, error: String) -> bool {
         // Check session ID
@@ -571,7 +726,8 @@ impl RecordingController {
         *self.active_session.write().await = None;
 
         // Transition to error
-        self.state_manager.transition_to_error(error.clone());
+        self.state_manager
+            .transition_to_error(ErrorCategory::Transcription, error.clone());
 
         // Emit event
         let _ = self.event_sender.send(RecordingEvent::TranscriptionFailed {
@@ -599,8 +755,10 @@ impl RecordingController {
         *self.active_session.write().await = None;
 
         // Transition to error
-        self.state_manager
-            .transition_to_error("Transcription timeout - no response from sidecar".to_string());
+        self.state_manager.transition_to_error(
+            ErrorCategory::Transcription,
+            "Transcription timeout - no response from sidecar".to_string(),
+        );
 
         // Emit event
         let _ = self
@@ -812,6 +970,141 @@ mod tests {
         assert!(controller.current_session_id().await.is_none());
     }
 
+    fn utterance(session_id: &str, segment_index: u32, text: &str) -> UtteranceResult {
+        UtteranceResult {
+            session_id: session_id.to_string(),
+            segment_index,
+            text: text.to_string(),
+            audio_duration_ms: 800,
+            processing_duration_ms: 100,
+        }
+    }
+
+    fn utterance_indexes(receiver: &mut broadcast::Receiver<RecordingEvent>) -> Vec<u32> {
+        let mut indexes = Vec::new();
+        while let Ok(event) = receiver.try_recv() {
+            if let RecordingEvent::UtteranceComplete { segment_index, .. } = event {
+                indexes.push(segment_index);
+            }
+        }
+        indexes
+    }
+
+    #[tokio::test]
+    async fn test_utterances_released_in_segment_order() {
+        let (state_manager, controller) = setup();
+        controller.set_model_ready(true).await;
+        let session_id = controller.start().await.unwrap();
+        let mut receiver = controller.subscribe();
+
+        assert!(
+            controller
+                .on_utterance_result(utterance(&session_id, 1, "second"))
+                .await
+        );
+        assert!(utterance_indexes(&mut receiver).is_empty());
+
+        assert!(
+            controller
+                .on_utterance_result(utterance(&session_id, 0, "first"))
+                .await
+        );
+        assert!(
+            controller
+                .on_utterance_result(utterance(&session_id, 2, "third"))
+                .await
+        );
+        assert_eq!(utterance_indexes(&mut receiver), vec![0, 1, 2]);
+
+        // Utterances do not end the session.
+        assert_eq!(state_manager.get(), AppState::Recording);
+        assert_eq!(controller.current_session_id().await, Some(session_id));
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_and_stale_utterances_rejected() {
+        let (_, controller) = setup();
+        controller.set_model_ready(true).await;
+        let session_id = controller.start().await.unwrap();
+
+        assert!(
+            controller
+                .on_utterance_result(utterance(&session_id, 0, "once"))
+                .await
+        );
+        assert!(
+            !controller
+                .on_utterance_result(utterance(&session_id, 0, "again"))
+                .await
+        );
+        assert!(
+            !controller
+                .on_utterance_result(utterance("old-session", 1, "stale"))
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn test_final_result_releases_held_utterances_first() {
+        let (_, controller) = setup();
+        controller.set_model_ready(true).await;
+        controller
+            .set_config(RecordingConfig {
+                too_short_threshold: Duration::from_millis(0),
+                ..Default::default()
+            })
+            .await;
+        let session_id = controller.start().await.unwrap();
+        let mut receiver = controller.subscribe();
+
+        // Segment 0 never arrives; segment 1 is held behind the gap.
+        controller
+            .on_utterance_result(utterance(&session_id, 1, "held"))
+            .await;
+        controller.stop().await.unwrap();
+        controller
+            .on_transcription_result(TranscriptionResult {
+                session_id: session_id.clone(),
+                text: "rest".to_string(),
+                audio_duration_ms: 1000,
+                processing_duration_ms: 500,
+            })
+            .await;
+
+        let mut order = Vec::new();
+        while let Ok(event) = receiver.try_recv() {
+            match event {
+                RecordingEvent::UtteranceComplete { text, .. }
+                | RecordingEvent::TranscriptionComplete { text, .. } => order.push(text),
+                _ => {}
+            }
+        }
+        assert_eq!(order, vec!["held".to_string(), "rest".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_max_duration_measured_per_utterance() {
+        let (_, controller) = setup();
+        controller.set_model_ready(true).await;
+        controller
+            .set_config(RecordingConfig {
+                max_duration: Duration::from_millis(50),
+                too_short_threshold: Duration::from_millis(0),
+                ..Default::default()
+            })
+            .await;
+        let session_id = controller.start().await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        controller
+            .on_utterance_result(utterance(&session_id, 0, "first"))
+            .await;
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        // 60ms into the session, but only 20ms into the current utterance.
+        assert!(controller.check_max_duration().await.is_none());
+    }
+
     #[tokio::test]
     async fn test_model_not_ready_prevents_start() {
         let (_, controller) = setup();
@@ -970,6 +1263,31 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_split_at_max_duration_restarts_segment() {
+        let (state_manager, controller) = setup();
+        controller.set_model_ready(true).await;
+        controller
+            .set_config(RecordingConfig {
+                max_duration: Duration::from_millis(30),
+                too_short_threshold: Duration::from_millis(0),
+                ..Default::default()
+            })
+            .await;
+        let session_id = controller.start().await.unwrap();
+
+        assert!(controller.split_at_max_duration().await.is_none());
+        tokio::time::sleep(Duration::from_millis(40)).await;
+
+        let (split_session, duration_ms) = controller.split_at_max_duration().await.unwrap();
+        assert_eq!(split_session, session_id);
+        assert!(duration_ms >= 30);
+        // Still recording, and the new segment has its own limit.
+        assert_eq!(state_manager.get(), AppState::Recording);
+        assert!(controller.split_at_max_duration().await.is_none());
+        assert!(controller.check_max_duration().await.is_none());
+    }
+
     #[tokio::test]
     async fn test_set_config_clamps_max_duration_to_hard_limit() {
         let (_, controller) = setup();
@@ -984,6 +1302,18 @@ mod tests {
         assert_eq!(config.max_duration, defaults::MAX_RECORDING_HARD_LIMIT);
     }
 
+    #[test]
+    fn test_cancel_reason_as_str_matches_serialization() {
+        for reason in [
+            CancelReason::DoubleTap,
+            CancelReason::UserButton,
+            CancelReason::EscapeKey,
+            CancelReason::MaxDuration,
+        ] {
+            assert_eq!(serde_json::to_value(&reason).unwrap(), reason.as_str());
+        }
+    }
+
     #[test]
     fn test_sanitize_recording_config_keeps_valid_max_duration() {
         let config = RecordingConfig {
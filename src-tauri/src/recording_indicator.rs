@@ -0,0 +1,214 @@
+//! Always-visible recording indicator for privacy-sensitive deployments.
+//!
+//! When `ui.always_visible_recording_indicator` is enabled (typically locked
+//! on by an admin policy), a small "REC" badge is pinned to the top-right
+//! corner of every monitor for as long as recording is active, independent
+//! of the overlay setting. The badges are click-through, cannot be closed,
+//! and are re-asserted every second so a hidden window or a newly attached
+//! monitor is covered again. The tray icon flashes alongside (see `tray`).
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tauri::{AppHandle, Manager};
+
+use crate::config;
+use crate::overlay::{
+    compute_overlay_position, MonitorBounds, OverlayAnchor, OverlayPositionConfig,
+    OverlayWindowSize,
+};
+use crate::state::{AppState, AppStateManager};
+
+/// Window label prefix; one window per monitor index.
+pub const INDICATOR_LABEL_PREFIX: &str = "recording-indicator-";
+const INDICATOR_WINDOW_URL: &str = "recording-indicator.html";
+const INDICATOR_WINDOW_TITLE: &str = "Recording";
+
+/// Logical size of each indicator badge.
+const INDICATOR_WIDTH: f64 = 96.0;
+const INDICATOR_HEIGHT: f64 = 32.0;
+const INDICATOR_MARGIN: i32 = 12;
+
+/// How often visible indicators are re-asserted.
+const REASSERT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Whether indicators should be on screen.
+pub fn should_show(state: AppState, enabled: bool, indicator_required: bool) -> bool {
+    indicator_required && enabled && state == AppState::Recording
+}
+
+/// Whether a window label belongs to a recording indicator.
+pub fn is_indicator_label(label: &str) -> bool {
+    label.starts_with(INDICATOR_LABEL_PREFIX)
+}
+
+fn indicator_label(index: usize) -> String {
+    format!("{INDICATOR_LABEL_PREFIX}{index}")
+}
+
+/// Physical top-left position of the badge on `monitor`.
+fn indicator_position(monitor: &MonitorBounds, scale_factor: f64) -> (i32, i32) {
+    let size = OverlayWindowSize {
+        width: (INDICATOR_WIDTH * scale_factor).round() as u32,
+        height: (INDICATOR_HEIGHT * scale_factor).round() as u32,
+    };
+    let margin = (f64::from(INDICATOR_MARGIN) * scale_factor).round() as i32;
+    compute_overlay_position(
+        monitor,
+        size,
+        OverlayPositionConfig {
+            anchor: OverlayAnchor::TopRight,
+            margin_x: margin,
+            margin_y: margin,
+        },
+    )
+}
+
+/// Show a badge on every monitor, creating or repositioning windows as needed.
+fn show_on_all_monitors(app: &AppHandle) -> Result<(), String> {
+    let monitors = app.available_monitors().map_err(|e| e.to_string())?;
+    let mut wanted = HashSet::new();
+
+    for (index, monitor) in monitors.iter().enumerate() {
+        let label = indicator_label(index);
+        let window = match app.get_webview_window(&label) {
+            Some(window) => window,
+            None => create_indicator_window(app, &label)?,
+        };
+        let (x, y) = indicator_position(
+            &MonitorBounds::from_tauri_monitor(monitor),
+            monitor.scale_factor(),
+        );
+        window
+            .set_position(tauri::PhysicalPosition::new(x, y))
+            .map_err(|e| e.to_string())?;
+        window.set_always_on_top(true).map_err(|e| e.to_string())?;
+        if !window.is_visible().unwrap_or(false) {
+            window.show().map_err(|e| e.to_string())?;
+        }
+        wanted.insert(label);
+    }
+
+    // Drop badges for monitors that were disconnected.
+    for (label, window) in app.webview_windows() {
+        if is_indicator_label(&label) && !wanted.contains(&label) {
+            let _ = window.destroy();
+        }
+    }
+    Ok(())
+}
+
+fn create_indicator_window(app: &AppHandle, label: &str) -> Result<tauri::WebviewWindow, String> {
+    let url = tauri::WebviewUrl::App(INDICATOR_WINDOW_URL.into());
+    let window = tauri::WebviewWindowBuilder::new(app, label, url)
+        .title(INDICATOR_WINDOW_TITLE)
+        .inner_size(INDICATOR_WIDTH, INDICATOR_HEIGHT)
+        .resizable(false)
+        .closable(false)
+        .minimizable(false)
+        .maximizable(false)
+        .decorations(false)
+        .transparent(true)
+        .always_on_top(true)
+        .visible_on_all_workspaces(true)
+        .skip_taskbar(true)
+        .focused(false)
+        .visible(false)
+        .build()
+        .map_err(|e| e.to_string())?;
+    window
+        .set_ignore_cursor_events(true)
+        .map_err(|e| e.to_string())?;
+    Ok(window)
+}
+
+/// Remove every indicator window.
+fn hide_all(app: &AppHandle) {
+    for (label, window) in app.webview_windows() {
+        if is_indicator_label(&label) {
+            // `destroy` skips the close request that `on_window_event` vetoes.
+            let _ = window.destroy();
+        }
+    }
+}
+
+/// Start the loop that shows indicators while recording.
+pub fn start_indicator_loop(app_handle: AppHandle, state_manager: Arc<AppStateManager>) {
+    tauri::async_runtime::spawn(async move {
+        let mut receiver = state_manager.subscribe();
+        let mut reassert = tokio::time::interval(REASSERT_INTERVAL);
+        reassert.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut showing = false;
+
+        loop {
+            tokio::select! {
+                recv_result = receiver.recv() => {
+                    if let Err(tokio::sync::broadcast::error::RecvError::Closed) = recv_result {
+                        break;
+                    }
+                }
+                _ = reassert.tick() => {}
+            }
+
+            let required = config::load_config().ui.always_visible_recording_indicator;
+            if should_show(
+                state_manager.get(),
+                state_manager.level().allows_recording(),
+                required,
+            ) {
+                if let Err(error) = show_on_all_monitors(&app_handle) {
+                    log::warn!("Failed to show recording indicator: {}", error);
+                }
+                showing = true;
+            } else if showing {
+                hide_all(&app_handle);
+                showing = false;
+            }
+        }
+
+        hide_all(&app_handle);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(x: i32, width: u32) -> MonitorBounds {
+        MonitorBounds {
+            x,
+            y: 0,
+            width,
+            height: 1080,
+            work_x: x,
+            work_y: 0,
+            work_width: width,
+            work_height: 1040,
+            name: None,
+            scale_factor: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_should_show_only_while_recording_with_flag() {
+        assert!(should_show(AppState::Recording, true, true));
+        assert!(!should_show(AppState::Recording, true, false));
+        assert!(!should_show(AppState::Recording, false, true));
+        assert!(!should_show(AppState::Transcribing, true, true));
+        assert!(!should_show(AppState::Idle, true, true));
+    }
+
+    #[test]
+    fn test_indicator_position_is_top_right_of_each_monitor() {
+        assert_eq!(indicator_position(&monitor(0, 1920), 1.0), (1812, 12));
+        assert_eq!(indicator_position(&monitor(1920, 2560), 2.0), (4264, 24));
+    }
+
+    #[test]
+    fn test_indicator_labels() {
+        assert_eq!(indicator_label(2), "recording-indicator-2");
+        assert!(is_indicator_label(&indicator_label(0)));
+        assert!(!is_indicator_label("overlay"));
+    }
+}
@@ -0,0 +1,331 @@
+//! Release manifest and artifact downloads shared by the update channels.
+//!
+//! There is no HTTP client in the dependency tree, so manifests and artifacts
+//! are fetched with `curl`, which ships with macOS, Windows 10+, and desktop
+//! Linux distributions. Every artifact is checked against the size and
+//! SHA-256 published in its manifest, and against a minisign signature made
+//! with the release key, before it is used. Nothing is fetched in offline
+//! mode.
+
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use minisign_verify::{PublicKey, Signature};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::network::{self, NetworkPurpose, OfflineBlocked};
+
+const MANIFEST_TIMEOUT_SECS: &str = "30";
+const DOWNLOAD_TIMEOUT_SECS: &str = "1800";
+
+/// How often download progress is sampled from the partial file.
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Minisign public key release artifacts are signed with: the base64 key line
+/// of a minisign `.pub` file, embedded at build time. Builds without one
+/// refuse every update.
+const UPDATE_PUBLIC_KEY: Option<&str> = option_env!("OPENVOICY_UPDATE_PUBLIC_KEY");
+
+/// Download and verification errors.
+#[derive(Debug, Error)]
+pub enum DownloadError {
+    #[error("Failed to fetch {what}: {message}")]
+    Fetch { what: String, message: String },
+
+    #[error("Downloaded {what} failed verification: {message}")]
+    Verification { what: String, message: String },
+
+    #[error("Download I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error(transparent)]
+    Offline(#[from] OfflineBlocked),
+}
+
+/// One downloadable file listed in a release manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ReleaseArtifact {
+    pub url: String,
+    /// Lowercase hex SHA-256 of the file.
+    pub sha256: String,
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// Minisign signature of the file: the contents of its `.sig` file.
+    #[serde(default)]
+    pub signature: Option<String>,
+}
+
+/// Fetch a small document (a release manifest) into memory.
+pub fn fetch_bytes(
+    url: &str,
+    what: &str,
+    purpose: NetworkPurpose,
+) -> Result<Vec<u8>, DownloadError> {
+    network::check(purpose, url)?;
+    let output = Command::new("curl")
+        .args(["-fsSL", "--max-time", MANIFEST_TIMEOUT_SECS])
+        .arg(url)
+        .output()
+        .map_err(|e| fetch_error(what, e.to_string()))?;
+    if !output.status.success() {
+        return Err(fetch_error(
+            what,
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(output.stdout)
+}
+
+/// Download an artifact to `dest`.
+///
+/// `on_progress` receives the bytes written so far while curl runs. `dest`
+/// is removed if the download fails; callers verify it with
+/// [`verify_artifact`] before use.
+pub fn download_artifact(
+    artifact: &ReleaseArtifact,
+    dest: &Path,
+    what: &str,
+    purpose: NetworkPurpose,
+    on_progress: impl Fn(u64),
+) -> Result<(), DownloadError> {
+    network::check(purpose, &artifact.url)?;
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut child = Command::new("curl")
+        .args(["-fsSL", "--max-time", DOWNLOAD_TIMEOUT_SECS, "-o"])
+        .arg(dest)
+        .arg(&artifact.url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| fetch_error(what, e.to_string()))?;
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if let Ok(metadata) = fs::metadata(dest) {
+            on_progress(metadata.len());
+        }
+        thread::sleep(PROGRESS_POLL_INTERVAL);
+    };
+
+    if !status.success() {
+        let mut stderr = String::new();
+        if let Some(mut pipe) = child.stderr.take() {
+            let _ = pipe.read_to_string(&mut stderr);
+        }
+        let _ = fs::remove_file(dest);
+        return Err(fetch_error(what, stderr.trim().to_string()));
+    }
+    if let Ok(metadata) = fs::metadata(dest) {
+        on_progress(metadata.len());
+    }
+    Ok(())
+}
+
+/// Check a downloaded file against the artifact's size and SHA-256.
+pub fn verify_artifact(
+    path: &Path,
+    artifact: &ReleaseArtifact,
+    what: &str,
+) -> Result<(), DownloadError> {
+    let mut file = fs::File::open(path)?;
+    if let Some(expected) = artifact.size {
+        let actual = file.metadata()?.len();
+        if actual != expected {
+            return Err(verification_error(
+                what,
+                format!("expected {} bytes, got {}", expected, actual),
+            ));
+        }
+    }
+
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    let actual = format!("{:x}", hasher.finalize());
+    if !actual.eq_ignore_ascii_case(artifact.sha256.trim()) {
+        return Err(verification_error(
+            what,
+            format!(
+                "SHA-256 mismatch (expected {}, got {})",
+                artifact.sha256, actual
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Check a downloaded file against its minisign signature and the public key
+/// embedded in this build.
+pub fn verify_signature(
+    path: &Path,
+    signature: Option<&str>,
+    what: &str,
+) -> Result<(), DownloadError> {
+    verify_signature_with_key(path, signature, UPDATE_PUBLIC_KEY, what)
+}
+
+fn verify_signature_with_key(
+    path: &Path,
+    signature: Option<&str>,
+    public_key: Option<&str>,
+    what: &str,
+) -> Result<(), DownloadError> {
+    let public_key = public_key
+        .ok_or_else(|| verification_error(what, "this build has no update signing key".into()))?;
+    let public_key = PublicKey::from_base64(public_key.trim())
+        .map_err(|e| verification_error(what, format!("invalid update signing key: {e}")))?;
+    let signature = signature
+        .ok_or_else(|| verification_error(what, "the release manifest has no signature".into()))?;
+    let signature = Signature::decode(signature)
+        .map_err(|e| verification_error(what, format!("invalid signature: {e}")))?;
+    let mut verifier = public_key
+        .verify_stream(&signature)
+        .map_err(|e| verification_error(what, format!("unusable signature: {e}")))?;
+
+    let mut file = fs::File::open(path)?;
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        verifier.update(&buffer[..read]);
+    }
+    verifier
+        .finalize()
+        .map_err(|e| verification_error(what, format!("signature mismatch: {e}")))
+}
+
+fn fetch_error(what: &str, message: String) -> DownloadError {
+    DownloadError::Fetch {
+        what: what.to_string(),
+        message,
+    }
+}
+
+fn verification_error(what: &str, message: String) -> DownloadError {
+    DownloadError::Verification {
+        what: what.to_string(),
+        message,
+    }
+}
+
+/// Numeric `major.minor.patch` components, ignoring any pre-release or build suffix.
+pub fn parse_version(version: &str) -> Option<Vec<u64>> {
+    let core = version
+        .trim()
+        .trim_start_matches('v')
+        .split(['-', '+'])
+        .next()?;
+    core.split('.')
+        .map(|part| part.parse::<u64>().ok())
+        .collect::<Option<Vec<_>>>()
+        .filter(|parts| !parts.is_empty())
+}
+
+/// Whether `candidate` is strictly newer than `current`.
+pub fn is_newer(candidate: &str, current: &str) -> bool {
+    match (parse_version(candidate), parse_version(current)) {
+        (Some(mut candidate), Some(mut current)) => {
+            let len = candidate.len().max(current.len());
+            candidate.resize(len, 0);
+            current.resize(len, 0);
+            candidate > current
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_version_comparison() {
+        assert!(is_newer("0.2.0", "0.1.9"));
+        assert!(is_newer("v1.0.1", "1.0"));
+        assert!(is_newer("0.10.0", "0.9.3"));
+        assert!(!is_newer("0.1.0", "0.1.0"));
+        assert!(!is_newer("0.1.0-beta", "0.1.0"));
+        assert!(!is_newer("latest", "0.1.0"));
+    }
+
+    #[test]
+    fn test_verify_artifact_checks_size_and_hash() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("sidecar");
+        fs::write(&path, b"hello").unwrap();
+        let mut artifact = ReleaseArtifact {
+            url: String::new(),
+            sha256: "2CF24DBA5FB0A30E26E83B2AC5B9E29E1B161E5C1FA7425E73043362938B9824".to_string(),
+            size: Some(5),
+            signature: None,
+        };
+        assert!(verify_artifact(&path, &artifact, "sidecar").is_ok());
+
+        artifact.size = Some(6);
+        assert!(matches!(
+            verify_artifact(&path, &artifact, "sidecar"),
+            Err(DownloadError::Verification { .. })
+        ));
+
+        artifact.size = None;
+        artifact.sha256 = "00".repeat(32);
+        assert!(matches!(
+            verify_artifact(&path, &artifact, "sidecar"),
+            Err(DownloadError::Verification { .. })
+        ));
+    }
+
+    // Test vector from the minisign-verify documentation: a prehashed
+    // signature of the bytes "test".
+    const TEST_PUBLIC_KEY: &str = "RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3";
+    const TEST_SIGNATURE: &str = "untrusted comment: signature from minisign secret key
+RUQf6LRCGA9i559r3g7V1qNyJDApGip8MfqcadIgT9CuhV3EMhHoN1mGTkUidF/z7SrlQgXdy8ofjb7bNJJylDOocrCo8KLzZwo=
+trusted comment: timestamp:1633700835\tfile:test\tprehashed
+wLMDjy9FLAuxZ3q4NlEvkgtyhrr0gtTu6KC4KBJdITbbOeAi1zBIYo0v4iTgt8jJpIidRJnp94ABQkJAgAooBQ==";
+
+    #[test]
+    fn test_verify_signature_requires_key_and_matching_signature() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("installer");
+        fs::write(&path, b"test").unwrap();
+        let verify = |signature, public_key| {
+            verify_signature_with_key(&path, signature, public_key, "installer")
+        };
+        assert!(verify(Some(TEST_SIGNATURE), Some(TEST_PUBLIC_KEY)).is_ok());
+
+        assert!(matches!(
+            verify(Some(TEST_SIGNATURE), None),
+            Err(DownloadError::Verification { .. })
+        ));
+        assert!(matches!(
+            verify(None, Some(TEST_PUBLIC_KEY)),
+            Err(DownloadError::Verification { .. })
+        ));
+
+        fs::write(&path, b"tesT").unwrap();
+        assert!(matches!(
+            verify(Some(TEST_SIGNATURE), Some(TEST_PUBLIC_KEY)),
+            Err(DownloadError::Verification { .. })
+        ));
+    }
+}
@@ -0,0 +1,517 @@
+//! Replacement rule grouping, ordering, and validation.
+//!
+//! Rules are stored flat in `replacements`; each may name a group from
+//! `replacement_groups.groups` and carry an explicit `order`. Before rules
+//! are pushed to the sidecar they are resolved into one deterministic list:
+//! - Disabled rules and groups, and groups restricted to other profiles, are dropped
+//! - Groups run by `order`, then id; ungrouped rules act as a group with order 0
+//!   that sorts before named groups of equal order
+//! - Rules within a group run by `order`, then their position in config
+//!
+//! Temporary rules (e.g. today's project codename) live only in memory and
+//! run ahead of the persisted set. They lapse on restart, when the active
+//! profile changes, or at an optional expiry time.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use regex::{Regex, RegexBuilder};
+use serde::Serialize;
+
+use crate::config::{ReplacementGroup, ReplacementGroupsConfig, ReplacementRule};
+
+static TEMPORARY_RULES: Lazy<TemporaryRuleStore> = Lazy::new(TemporaryRuleStore::default);
+
+/// Get the process-wide temporary rule store.
+pub fn temporary_rules() -> &'static TemporaryRuleStore {
+    &TEMPORARY_RULES
+}
+
+/// Category of a rule validation problem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleIssueKind {
+    /// Another rule uses the same id.
+    DuplicateId,
+    /// The rule names a group that does not exist; it runs ungrouped.
+    UnknownGroup,
+    /// The pattern does not compile.
+    InvalidPattern,
+    /// An earlier rule matches the same pattern with a different replacement.
+    Conflict,
+    /// An earlier rule rewrites the text this rule would match, so it never applies.
+    Shadowed,
+}
+
+/// A problem found in the replacement rule set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuleIssue {
+    pub kind: RuleIssueKind,
+    pub rule_id: String,
+    /// The earlier rule involved in a conflict or shadowing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub other_rule_id: Option<String>,
+    pub message: String,
+}
+
+/// Compile a rule into the regex the replacement pipeline uses.
+pub fn compile_rule(rule: &ReplacementRule) -> Result<Regex, regex::Error> {
+    let pattern = if rule.word_boundary {
+        if rule.kind == "regex" {
+            format!(r"\b(?:{})\b", rule.pattern)
+        } else {
+            format!(r"\b{}\b", regex::escape(&rule.pattern))
+        }
+    } else if rule.kind == "regex" {
+        rule.pattern.clone()
+    } else {
+        regex::escape(&rule.pattern)
+    };
+
+    RegexBuilder::new(&pattern)
+        .case_insensitive(!rule.case_sensitive)
+        .build()
+}
+
+fn group_is_active(group: &ReplacementGroup, active_profile: Option<&str>) -> bool {
+    group.enabled
+        && (group.profiles.is_empty()
+            || active_profile
+                .is_some_and(|profile| group.profiles.iter().any(|candidate| candidate == profile)))
+}
+
+/// Application order of a rule: group order, group ID, rule order, position.
+type RuleSortKey<'a> = (i32, &'a str, i32, usize);
+
+/// Resolve the rules to push to the sidecar, in application order.
+pub fn resolve_active_rules(
+    rules: &[ReplacementRule],
+    groups: &ReplacementGroupsConfig,
+) -> Vec<ReplacementRule> {
+    let groups_by_id: HashMap<&str, &ReplacementGroup> = groups
+        .groups
+        .iter()
+        .map(|group| (group.id.as_str(), group))
+        .collect();
+    let active_profile = groups.active_profile.as_deref();
+
+    let mut active: Vec<(RuleSortKey, &ReplacementRule)> = Vec::new();
+    for (index, rule) in rules.iter().enumerate() {
+        if !rule.enabled {
+            continue;
+        }
+        let group = rule
+            .group
+            .as_deref()
+            .and_then(|group_id| groups_by_id.get(group_id));
+        let (group_order, group_id) = match group {
+            Some(group) if !group_is_active(group, active_profile) => continue,
+            Some(group) => (group.order, group.id.as_str()),
+            None => (0, ""),
+        };
+        active.push(((group_order, group_id, rule.order, index), rule));
+    }
+
+    active.sort_by_key(|(key, _)| *key);
+    active.into_iter().map(|(_, rule)| rule.clone()).collect()
+}
+
+/// Rules to push to the sidecar: current temporary rules, then the resolved
+/// persisted rules.
+pub fn rules_for_sidecar(
+    rules: &[ReplacementRule],
+    groups: &ReplacementGroupsConfig,
+) -> Vec<ReplacementRule> {
+    let mut merged: Vec<ReplacementRule> = temporary_rules()
+        .current(groups.active_profile.as_deref(), Utc::now())
+        .rules
+        .into_iter()
+        .filter(|rule| rule.enabled)
+        .collect();
+    merged.extend(resolve_active_rules(rules, groups));
+    merged
+}
+
+/// Session-scoped replacement rules, never written to config.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TemporaryRules {
+    pub rules: Vec<ReplacementRule>,
+    /// When the rules lapse; None keeps them until restart or profile change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Profile active when the rules were set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+}
+
+/// In-memory holder for the current temporary rules.
+#[derive(Debug, Default)]
+pub struct TemporaryRuleStore {
+    current: Mutex<TemporaryRules>,
+}
+
+impl TemporaryRuleStore {
+    /// Replace the temporary rules, scoping them to `profile`.
+    pub fn set(
+        &self,
+        rules: Vec<ReplacementRule>,
+        expires_at: Option<DateTime<Utc>>,
+        profile: Option<String>,
+    ) {
+        *self.lock() = TemporaryRules {
+            rules,
+            expires_at,
+            profile,
+        };
+    }
+
+    /// Current rules, clearing them first if they expired or the profile changed.
+    pub fn current(&self, profile: Option<&str>, now: DateTime<Utc>) -> TemporaryRules {
+        let mut current = self.lock();
+        let expired = current
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= now);
+        let profile_changed = current.profile.as_deref() != profile;
+        if !current.rules.is_empty() && (expired || profile_changed) {
+            let reason = if expired {
+                "expired"
+            } else {
+                "profile changed"
+            };
+            log::info!(
+                "Clearing {} temporary replacement rules ({})",
+                current.rules.len(),
+                reason
+            );
+            *current = TemporaryRules::default();
+        }
+        current.clone()
+    }
+
+    pub fn clear(&self) {
+        *self.lock() = TemporaryRules::default();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, TemporaryRules> {
+        self.current
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Detect duplicate ids, unknown groups, invalid patterns, and rules that
+/// conflict with or are shadowed by an earlier active rule.
+pub fn validate_rules(
+    rules: &[ReplacementRule],
+    groups: &ReplacementGroupsConfig,
+) -> Vec<RuleIssue> {
+    let mut issues = Vec::new();
+
+    let mut seen_ids = HashSet::new();
+    for rule in rules {
+        if !seen_ids.insert(rule.id.as_str()) {
+            issues.push(RuleIssue {
+                kind: RuleIssueKind::DuplicateId,
+                rule_id: rule.id.clone(),
+                other_rule_id: None,
+                message: format!("Rule id '{}' is used more than once", rule.id),
+            });
+        }
+    }
+
+    let group_ids: HashSet<&str> = groups.groups.iter().map(|g| g.id.as_str()).collect();
+    for rule in rules {
+        if let Some(group_id) = rule.group.as_deref() {
+            if !group_ids.contains(group_id) {
+                issues.push(RuleIssue {
+                    kind: RuleIssueKind::UnknownGroup,
+                    rule_id: rule.id.clone(),
+                    other_rule_id: None,
+                    message: format!("Rule '{}' references unknown group '{}'", rule.id, group_id),
+                });
+            }
+        }
+    }
+
+    let mut compiled: Vec<(ReplacementRule, Regex)> = Vec::new();
+    for rule in resolve_active_rules(rules, groups) {
+        match compile_rule(&rule) {
+            Ok(regex) => compiled.push((rule, regex)),
+            Err(error) => issues.push(RuleIssue {
+                kind: RuleIssueKind::InvalidPattern,
+                rule_id: rule.id.clone(),
+                other_rule_id: None,
+                message: format!("Rule '{}' has an invalid pattern: {}", rule.id, error),
+            }),
+        }
+    }
+
+    for (index, (later, later_regex)) in compiled.iter().enumerate() {
+        for (earlier, earlier_regex) in &compiled[..index] {
+            if let Some(issue) = overlap_issue(earlier, earlier_regex, later, later_regex) {
+                issues.push(issue);
+                break;
+            }
+        }
+    }
+
+    issues
+}
+
+fn same_pattern(earlier: &ReplacementRule, later: &ReplacementRule) -> bool {
+    if earlier.kind != later.kind || earlier.word_boundary != later.word_boundary {
+        return false;
+    }
+    if earlier.case_sensitive && later.case_sensitive {
+        earlier.pattern == later.pattern
+    } else {
+        earlier.pattern.to_lowercase() == later.pattern.to_lowercase()
+    }
+}
+
+fn overlap_issue(
+    earlier: &ReplacementRule,
+    earlier_regex: &Regex,
+    later: &ReplacementRule,
+    later_regex: &Regex,
+) -> Option<RuleIssue> {
+    if same_pattern(earlier, later) {
+        let (kind, message) = if earlier.replacement != later.replacement {
+            (
+                RuleIssueKind::Conflict,
+                format!(
+                    "Rule '{}' matches the same pattern as earlier rule '{}' with a different replacement",
+                    later.id, earlier.id
+                ),
+            )
+        } else {
+            (
+                RuleIssueKind::Shadowed,
+                format!(
+                    "Rule '{}' duplicates earlier rule '{}'",
+                    later.id, earlier.id
+                ),
+            )
+        };
+        return Some(RuleIssue {
+            kind,
+            rule_id: later.id.clone(),
+            other_rule_id: Some(earlier.id.clone()),
+            message,
+        });
+    }
+
+    // Only literal patterns can be checked as text; regex overlap is undecidable here.
+    if later.kind != "literal" || !earlier_regex.is_match(&later.pattern) {
+        return None;
+    }
+    let rewritten = if earlier.kind == "regex" {
+        earlier_regex.replace_all(&later.pattern, earlier.replacement.as_str())
+    } else {
+        earlier_regex.replace_all(
+            &later.pattern,
+            regex::NoExpand(earlier.replacement.as_str()),
+        )
+    };
+    if later_regex.is_match(&rewritten) {
+        return None;
+    }
+
+    Some(RuleIssue {
+        kind: RuleIssueKind::Shadowed,
+        rule_id: later.id.clone(),
+        other_rule_id: Some(earlier.id.clone()),
+        message: format!(
+            "Rule '{}' never applies because earlier rule '{}' rewrites its pattern",
+            later.id, earlier.id
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(id: &str, pattern: &str, replacement: &str) -> ReplacementRule {
+        ReplacementRule {
+            id: id.to_string(),
+            kind: "literal".to_string(),
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            enabled: true,
+            word_boundary: true,
+            case_sensitive: false,
+            description: None,
+            origin: None,
+            group: None,
+            order: 0,
+        }
+    }
+
+    fn grouped(id: &str, group: &str, order: i32) -> ReplacementRule {
+        ReplacementRule {
+            group: Some(group.to_string()),
+            order,
+            ..rule(id, id, id)
+        }
+    }
+
+    fn group(id: &str, order: i32, profiles: &[&str]) -> ReplacementGroup {
+        ReplacementGroup {
+            id: id.to_string(),
+            name: id.to_string(),
+            enabled: true,
+            order,
+            profiles: profiles.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    fn ids(rules: &[ReplacementRule]) -> Vec<&str> {
+        rules.iter().map(|rule| rule.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_resolve_orders_by_group_then_rule_order_then_position() {
+        let groups = ReplacementGroupsConfig {
+            groups: vec![group("late", 5, &[]), group("early", -1, &[])],
+            active_profile: None,
+        };
+        let rules = vec![
+            grouped("late-b", "late", 2),
+            rule("ungrouped-1", "a", "b"),
+            grouped("late-a", "late", 1),
+            grouped("early-a", "early", 0),
+            rule("ungrouped-2", "c", "d"),
+        ];
+
+        let resolved = resolve_active_rules(&rules, &groups);
+
+        assert_eq!(
+            ids(&resolved),
+            vec!["early-a", "ungrouped-1", "ungrouped-2", "late-a", "late-b"]
+        );
+    }
+
+    #[test]
+    fn test_resolve_skips_disabled_groups_and_other_profiles() {
+        let mut disabled = group("disabled", 0, &[]);
+        disabled.enabled = false;
+        let groups = ReplacementGroupsConfig {
+            groups: vec![
+                disabled,
+                group("email", 0, &["work"]),
+                group("chat", 0, &["personal"]),
+            ],
+            active_profile: Some("work".to_string()),
+        };
+        let mut off = rule("off", "x", "y");
+        off.enabled = false;
+        let rules = vec![
+            grouped("in-disabled", "disabled", 0),
+            grouped("in-email", "email", 0),
+            grouped("in-chat", "chat", 0),
+            off,
+        ];
+
+        assert_eq!(
+            ids(&resolve_active_rules(&rules, &groups)),
+            vec!["in-email"]
+        );
+
+        let no_profile = ReplacementGroupsConfig {
+            active_profile: None,
+            ..groups
+        };
+        assert!(resolve_active_rules(&rules, &no_profile).is_empty());
+    }
+
+    #[test]
+    fn test_temporary_rules_lapse_on_expiry_and_profile_change() {
+        let store = TemporaryRuleStore::default();
+        let now = Utc::now();
+
+        store.set(
+            vec![rule("codename", "project x", "Falcon")],
+            Some(now + chrono::Duration::hours(1)),
+            Some("work".to_string()),
+        );
+        assert_eq!(
+            ids(&store.current(Some("work"), now).rules),
+            vec!["codename"]
+        );
+        assert!(store
+            .current(Some("work"), now + chrono::Duration::hours(2))
+            .rules
+            .is_empty());
+
+        store.set(
+            vec![rule("codename", "project x", "Falcon")],
+            None,
+            Some("work".to_string()),
+        );
+        assert!(store.current(None, now).rules.is_empty());
+        // Once cleared, returning to the original profile does not restore them.
+        assert!(store.current(Some("work"), now).rules.is_empty());
+    }
+
+    #[test]
+    fn test_validate_reports_conflicts_and_shadowed_rules() {
+        let rules = vec![
+            rule("btw", "btw", "by the way"),
+            rule("btw-upper", "BTW", "between"),
+            rule("btw-now", "btw now", "right now"),
+            rule("dup", "asap", "as soon as possible"),
+            rule("dup-again", "ASAP", "as soon as possible"),
+            rule("unrelated", "fyi", "for your information"),
+        ];
+
+        let issues = validate_rules(&rules, &ReplacementGroupsConfig::default());
+        let summary: Vec<(RuleIssueKind, &str, Option<&str>)> = issues
+            .iter()
+            .map(|issue| {
+                (
+                    issue.kind,
+                    issue.rule_id.as_str(),
+                    issue.other_rule_id.as_deref(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                (RuleIssueKind::Conflict, "btw-upper", Some("btw")),
+                (RuleIssueKind::Shadowed, "btw-now", Some("btw")),
+                (RuleIssueKind::Shadowed, "dup-again", Some("dup")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_duplicate_ids_unknown_groups_and_invalid_patterns() {
+        let mut bad_regex = rule("bad", "(unclosed", "x");
+        bad_regex.kind = "regex".to_string();
+        let rules = vec![
+            rule("same", "one", "1"),
+            rule("same", "two", "2"),
+            grouped("orphan", "missing", 0),
+            bad_regex,
+        ];
+
+        let kinds: Vec<RuleIssueKind> = validate_rules(&rules, &ReplacementGroupsConfig::default())
+            .into_iter()
+            .map(|issue| issue.kind)
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                RuleIssueKind::DuplicateId,
+                RuleIssueKind::UnknownGroup,
+                RuleIssueKind::InvalidPattern,
+            ]
+        );
+    }
+}
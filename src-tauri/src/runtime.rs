@@ -0,0 +1,215 @@
+//! Host runtime the engine runs in.
+//!
+//! With the `desktop` feature the host is Tauri: [`AppHandle`] is
+//! `tauri::AppHandle`, events go to every window, and managed state lives in
+//! the Tauri app. Without it, [`AppHandle`] is a headless stand-in that sends
+//! events to an embedder-supplied [`AppEventBroadcaster`] and holds state
+//! registered with [`AppHandle::manage`], so the integration layer builds
+//! unchanged against either host.
+
+use serde_json::Value;
+
+/// Receives every event the engine emits, already stamped with its `seq`.
+pub trait AppEventBroadcaster: Send + Sync {
+    fn emit_all(&self, event: &str, payload: Value);
+}
+
+#[cfg(feature = "desktop")]
+pub use tauri::{AppHandle, Emitter, Manager};
+
+#[cfg(feature = "desktop")]
+impl AppEventBroadcaster for AppHandle {
+    fn emit_all(&self, event: &str, payload: Value) {
+        let _ = self.emit(event, payload);
+    }
+}
+
+/// Spawn a task on the host's async runtime.
+#[cfg(feature = "desktop")]
+pub fn spawn<F>(future: F)
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    tauri::async_runtime::spawn(future);
+}
+
+/// Spawn a task on the host's async runtime; headless hosts must call the
+/// engine from inside a Tokio runtime.
+#[cfg(not(feature = "desktop"))]
+pub fn spawn<F>(future: F)
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    tokio::spawn(future);
+}
+
+#[cfg(not(feature = "desktop"))]
+pub use headless::{AppHandle, Emitter, Manager};
+
+#[cfg(not(feature = "desktop"))]
+mod headless {
+    use std::any::{Any, TypeId};
+    use std::collections::HashMap;
+    use std::ops::Deref;
+    use std::path::PathBuf;
+    use std::sync::{Arc, RwLock};
+
+    use serde::Serialize;
+
+    use super::AppEventBroadcaster;
+
+    type ManagedState = Arc<dyn Any + Send + Sync>;
+
+    /// Handle to a headless host: an event sink plus managed state.
+    #[derive(Clone)]
+    pub struct AppHandle {
+        broadcaster: Arc<dyn AppEventBroadcaster>,
+        state: Arc<RwLock<HashMap<TypeId, ManagedState>>>,
+    }
+
+    impl AppHandle {
+        pub fn new(broadcaster: impl AppEventBroadcaster + 'static) -> Self {
+            Self {
+                broadcaster: Arc::new(broadcaster),
+                state: Arc::new(RwLock::new(HashMap::new())),
+            }
+        }
+
+        /// Register `value` as the managed state of its type. Like Tauri,
+        /// returns false and keeps the existing value if one is registered.
+        pub fn manage<T: Send + Sync + 'static>(&self, value: T) -> bool {
+            let mut state = self
+                .state
+                .write()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            if state.contains_key(&TypeId::of::<T>()) {
+                return false;
+            }
+            state.insert(TypeId::of::<T>(), Arc::new(value));
+            true
+        }
+    }
+
+    impl AppEventBroadcaster for AppHandle {
+        fn emit_all(&self, event: &str, payload: serde_json::Value) {
+            self.broadcaster.emit_all(event, payload);
+        }
+    }
+
+    /// Managed state borrowed from an [`AppHandle`].
+    pub struct State<T: Send + Sync + 'static>(Arc<T>);
+
+    impl<T: Send + Sync + 'static> State<T> {
+        pub fn inner(&self) -> &T {
+            &self.0
+        }
+    }
+
+    impl<T: Send + Sync + 'static> Deref for State<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            &self.0
+        }
+    }
+
+    /// Error from a headless host operation.
+    #[derive(Debug, thiserror::Error)]
+    pub enum Error {
+        #[error("failed to serialize event payload: {0}")]
+        Serialize(#[from] serde_json::Error),
+        #[error("{0}")]
+        Path(String),
+    }
+
+    /// Mirror of `tauri::Emitter`.
+    pub trait Emitter {
+        fn emit<S: Serialize + Clone>(&self, event: &str, payload: S) -> Result<(), Error>;
+    }
+
+    impl Emitter for AppHandle {
+        fn emit<S: Serialize + Clone>(&self, event: &str, payload: S) -> Result<(), Error> {
+            let payload = serde_json::to_value(payload)?;
+            self.broadcaster.emit_all(event, payload);
+            Ok(())
+        }
+    }
+
+    /// Mirror of the parts of `tauri::Manager` the engine uses.
+    pub trait Manager {
+        /// Managed state of type `T`; panics when none was registered, like Tauri.
+        fn state<T: Send + Sync + 'static>(&self) -> State<T>;
+        fn try_state<T: Send + Sync + 'static>(&self) -> Option<State<T>>;
+        fn path(&self) -> PathResolver;
+    }
+
+    impl Manager for AppHandle {
+        fn state<T: Send + Sync + 'static>(&self) -> State<T> {
+            self.try_state().unwrap_or_else(|| {
+                panic!(
+                    "state() called before manage() for {}",
+                    std::any::type_name::<T>()
+                )
+            })
+        }
+
+        fn try_state<T: Send + Sync + 'static>(&self) -> Option<State<T>> {
+            let state = self
+                .state
+                .read()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let value = Arc::clone(state.get(&TypeId::of::<T>())?);
+            value.downcast::<T>().ok().map(State)
+        }
+
+        fn path(&self) -> PathResolver {
+            PathResolver
+        }
+    }
+
+    /// Resolves host directories.
+    pub struct PathResolver;
+
+    impl PathResolver {
+        /// Directory of the host executable, where a bundled sidecar is
+        /// looked up.
+        pub fn resource_dir(&self) -> Result<PathBuf, Error> {
+            let exe = std::env::current_exe().map_err(|e| Error::Path(e.to_string()))?;
+            exe.parent()
+                .map(PathBuf::from)
+                .ok_or_else(|| Error::Path("executable has no parent directory".to_string()))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct Recorder(Mutex<Vec<(String, serde_json::Value)>>);
+
+        impl AppEventBroadcaster for Arc<Recorder> {
+            fn emit_all(&self, event: &str, payload: serde_json::Value) {
+                self.0.lock().unwrap().push((event.to_string(), payload));
+            }
+        }
+
+        #[test]
+        fn test_headless_handle_emits_and_manages_state() {
+            let recorder = Arc::new(Recorder::default());
+            let handle = AppHandle::new(Arc::clone(&recorder));
+
+            handle.emit("test:ping", 7).unwrap();
+            assert_eq!(
+                recorder.0.lock().unwrap()[0],
+                ("test:ping".to_string(), serde_json::json!(7))
+            );
+
+            assert!(handle.try_state::<u32>().is_none());
+            assert!(handle.manage(7u32));
+            assert!(!handle.manage(8u32));
+            assert_eq!(*handle.clone().state::<u32>(), 7);
+        }
+    }
+}
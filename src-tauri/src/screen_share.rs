@@ -0,0 +1,230 @@
+//! Detection of active screen sharing.
+//!
+//! While the screen is shared, the overlay, the recording indicators and the
+//! dictated text itself can end up in front of an audience.
+//! `ui.screen_share_policy` decides what happens when a recording starts
+//! during a share: warn, force the overlay visible so viewers know the
+//! microphone is live, or refuse to record.
+//!
+//! There is no cross-platform API that reports whether the screen is being
+//! captured. Linux asks PipeWire for running screen-cast streams, which
+//! covers every portal-based share on Wayland. macOS and Windows recognize
+//! the helper process conferencing apps run only while sharing (Zoom's
+//! `CptHost`). Sharing from a browser tab is not detected on any platform.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+use crate::config::ScreenSharePolicy;
+
+/// How long a detection result is reused; detection spawns a process.
+const DETECTION_TTL: Duration = Duration::from_secs(5);
+
+/// Helper processes that only run while an app is sharing the screen, with
+/// the app they belong to.
+#[cfg(not(target_os = "linux"))]
+const SHARING_PROCESSES: [(&str, &str); 1] = [("cpthost", "zoom")];
+
+static CACHED_SHARE: Lazy<Mutex<Option<(Instant, ScreenShareInfo)>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// Whether the screen is being shared.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct ScreenShareInfo {
+    pub active: bool,
+    /// What is capturing the screen when known ("zoom", "pipewire").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub source: Option<String>,
+}
+
+impl ScreenShareInfo {
+    fn sharing(source: &str) -> Self {
+        Self {
+            active: true,
+            source: Some(source.to_string()),
+        }
+    }
+
+    /// The policy to enforce for a recording started now, if any.
+    pub fn enforced_policy(&self, policy: ScreenSharePolicy) -> Option<ScreenSharePolicy> {
+        (self.active && policy != ScreenSharePolicy::Off).then_some(policy)
+    }
+}
+
+/// The current screen share state, re-detected at most every few seconds.
+pub fn current() -> ScreenShareInfo {
+    let mut cached = CACHED_SHARE.lock().unwrap_or_else(|e| e.into_inner());
+    let now = Instant::now();
+    if let Some((detected_at, info)) = cached.as_ref() {
+        if now.saturating_duration_since(*detected_at) < DETECTION_TTL {
+            return info.clone();
+        }
+    }
+
+    let info = detect();
+    if cached.as_ref().map(|(_, previous)| previous) != Some(&info) {
+        log::info!(
+            "Screen sharing active: {} (source: {:?})",
+            info.active,
+            info.source
+        );
+    }
+    *cached = Some((now, info.clone()));
+    info
+}
+
+/// The policy to enforce for a recording started now, skipping detection
+/// entirely when the policy is off.
+pub fn enforced_policy(policy: ScreenSharePolicy) -> Option<ScreenSharePolicy> {
+    if policy == ScreenSharePolicy::Off {
+        return None;
+    }
+    current().enforced_policy(policy)
+}
+
+/// App sharing the screen, judged by running process names.
+#[cfg(not(target_os = "linux"))]
+fn sharing_app<'a>(process_names: impl IntoIterator<Item = &'a str>) -> Option<&'static str> {
+    process_names.into_iter().find_map(|name| {
+        let name = name.rsplit(['/', '\\']).next().unwrap_or(name).trim();
+        let name = name.to_ascii_lowercase();
+        let name = name.strip_suffix(".exe").unwrap_or(&name);
+        SHARING_PROCESSES
+            .iter()
+            .find(|(process, _)| *process == name)
+            .map(|(_, app)| *app)
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn detect() -> ScreenShareInfo {
+    let Ok(output) = std::process::Command::new("ps")
+        .args(["-A", "-o", "comm="])
+        .output()
+    else {
+        return ScreenShareInfo::default();
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    sharing_app(stdout.lines())
+        .map(ScreenShareInfo::sharing)
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "windows")]
+fn detect() -> ScreenShareInfo {
+    let Ok(output) = std::process::Command::new("tasklist")
+        .args(["/FO", "CSV", "/NH"])
+        .output()
+    else {
+        return ScreenShareInfo::default();
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    sharing_app(stdout.lines().filter_map(tasklist_image_name))
+        .map(ScreenShareInfo::sharing)
+        .unwrap_or_default()
+}
+
+/// Image name from a `tasklist /FO CSV /NH` row (`"CptHost.exe","1234",...`).
+#[cfg(not(target_os = "linux"))]
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn tasklist_image_name(line: &str) -> Option<&str> {
+    line.strip_prefix('"')?.split('"').next()
+}
+
+#[cfg(target_os = "linux")]
+fn detect() -> ScreenShareInfo {
+    match std::process::Command::new("pw-dump").output() {
+        Ok(output) if output.status.success() => {
+            if has_running_screencast(&String::from_utf8_lossy(&output.stdout)) {
+                ScreenShareInfo::sharing("pipewire")
+            } else {
+                ScreenShareInfo::default()
+            }
+        }
+        _ => ScreenShareInfo::default(),
+    }
+}
+
+/// Whether `pw-dump` output contains a running video source that is not a
+/// camera. Screen casts are published by the compositor as device-less
+/// `Video/Source` nodes; webcams carry a `device.api` (v4l2, libcamera).
+#[cfg(any(target_os = "linux", test))]
+fn has_running_screencast(pw_dump: &str) -> bool {
+    let Ok(serde_json::Value::Array(objects)) = serde_json::from_str(pw_dump) else {
+        return false;
+    };
+    objects.iter().any(|object| {
+        let info = &object["info"];
+        let props = &info["props"];
+        object["type"] == "PipeWire:Interface:Node"
+            && info["state"] == "running"
+            && props["media.class"] == "Video/Source"
+            && props.get("device.api").is_none()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enforced_policy_only_while_sharing() {
+        let sharing = ScreenShareInfo::sharing("zoom");
+        assert_eq!(
+            sharing.enforced_policy(ScreenSharePolicy::DisableDictation),
+            Some(ScreenSharePolicy::DisableDictation)
+        );
+        assert_eq!(sharing.enforced_policy(ScreenSharePolicy::Off), None);
+        assert_eq!(
+            ScreenShareInfo::default().enforced_policy(ScreenSharePolicy::Warn),
+            None
+        );
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[test]
+    fn test_sharing_app_from_process_names() {
+        assert_eq!(sharing_app(["/sbin/launchd", "Finder"]), None);
+        assert_eq!(
+            sharing_app([
+                "/Applications/zoom.us.app/Contents/Frameworks/cpthost.app/Contents/MacOS/CptHost"
+            ]),
+            Some("zoom")
+        );
+        assert_eq!(
+            sharing_app(
+                ["\"CptHost.exe\",\"4242\",\"Console\",\"1\",\"9,000 K\""]
+                    .into_iter()
+                    .filter_map(tasklist_image_name)
+            ),
+            Some("zoom")
+        );
+    }
+
+    #[test]
+    fn test_has_running_screencast_ignores_cameras_and_idle_streams() {
+        let node = |state: &str, device_api: Option<&str>| {
+            let mut props = serde_json::json!({ "media.class": "Video/Source" });
+            if let Some(api) = device_api {
+                props["device.api"] = api.into();
+            }
+            serde_json::json!({
+                "type": "PipeWire:Interface:Node",
+                "info": { "state": state, "props": props },
+            })
+        };
+        let dump = |nodes: Vec<serde_json::Value>| serde_json::Value::Array(nodes).to_string();
+
+        assert!(has_running_screencast(&dump(vec![node("running", None)])));
+        assert!(!has_running_screencast(&dump(vec![
+            node("running", Some("v4l2")),
+            node("suspended", None),
+        ])));
+        assert!(!has_running_screencast("not json"));
+    }
+}
@@ -0,0 +1,216 @@
+//! API keys and other credentials stored in the OS keyring.
+//!
+//! Secrets never go into `config.json` (unknown sensitive fields are stripped
+//! on load). Provider settings instead hold the *name* of a secret, and the
+//! value is resolved from the keyring when the provider is used. The UI can
+//! store, delete, and check for a secret but never read it back.
+
+use std::sync::Mutex;
+
+use keyring::Error as KeyringError;
+use once_cell::sync::Lazy;
+use thiserror::Error;
+
+const SECRETS_KEYRING_SERVICE: &str = "openvoicy.secrets";
+
+/// Longest accepted secret name.
+const MAX_SECRET_NAME_CHARS: usize = 64;
+
+static STORE: Lazy<SecretStore> = Lazy::new(|| SecretStore::new(Box::new(KeyringBackend)));
+
+/// Secret storage errors.
+#[derive(Debug, Error)]
+pub enum SecretError {
+    #[error("Invalid secret name '{0}': use 1-64 lowercase letters, digits, '.', '_' or '-'")]
+    InvalidName(String),
+
+    #[error("Secret value must not be empty")]
+    EmptyValue,
+
+    #[error("Keychain error: {0}")]
+    Keychain(String),
+}
+
+/// Where secret values live.
+pub trait SecretBackend: Send + Sync {
+    fn get(&self, name: &str) -> Result<Option<String>, SecretError>;
+    fn set(&self, name: &str, value: &str) -> Result<(), SecretError>;
+    /// Remove a secret, returning whether it existed.
+    fn delete(&self, name: &str) -> Result<bool, SecretError>;
+}
+
+/// OS keyring backend (Keychain, Credential Manager, Secret Service).
+struct KeyringBackend;
+
+impl KeyringBackend {
+    fn entry(name: &str) -> Result<keyring::Entry, SecretError> {
+        keyring::Entry::new(SECRETS_KEYRING_SERVICE, name).map_err(|error| {
+            SecretError::Keychain(format!("failed to open keychain entry '{name}': {error}"))
+        })
+    }
+}
+
+impl SecretBackend for KeyringBackend {
+    fn get(&self, name: &str) -> Result<Option<String>, SecretError> {
+        match Self::entry(name)?.get_password() {
+            Ok(value) => Ok(Some(value)),
+            Err(KeyringError::NoEntry) => Ok(None),
+            Err(error) => Err(SecretError::Keychain(format!(
+                "failed to read secret '{name}': {error}"
+            ))),
+        }
+    }
+
+    fn set(&self, name: &str, value: &str) -> Result<(), SecretError> {
+        Self::entry(name)?.set_password(value).map_err(|error| {
+            SecretError::Keychain(format!("failed to store secret '{name}': {error}"))
+        })
+    }
+
+    fn delete(&self, name: &str) -> Result<bool, SecretError> {
+        match Self::entry(name)?.delete_password() {
+            Ok(()) => Ok(true),
+            Err(KeyringError::NoEntry) => Ok(false),
+            Err(error) => Err(SecretError::Keychain(format!(
+                "failed to delete secret '{name}': {error}"
+            ))),
+        }
+    }
+}
+
+/// Named secrets over a backend.
+pub struct SecretStore {
+    backend: Box<dyn SecretBackend>,
+    /// Serializes writes so a set racing a delete cannot interleave.
+    write_lock: Mutex<()>,
+}
+
+impl SecretStore {
+    pub fn new(backend: Box<dyn SecretBackend>) -> Self {
+        Self {
+            backend,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    pub fn set(&self, name: &str, value: &str) -> Result<(), SecretError> {
+        validate_secret_name(name)?;
+        if value.trim().is_empty() {
+            return Err(SecretError::EmptyValue);
+        }
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        self.backend.set(name, value)
+    }
+
+    pub fn delete(&self, name: &str) -> Result<bool, SecretError> {
+        validate_secret_name(name)?;
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        self.backend.delete(name)
+    }
+
+    pub fn has(&self, name: &str) -> Result<bool, SecretError> {
+        validate_secret_name(name)?;
+        Ok(self.backend.get(name)?.is_some())
+    }
+
+    /// Resolve a secret referenced by name from config.
+    pub fn resolve(&self, name: &str) -> Result<Option<String>, SecretError> {
+        validate_secret_name(name)?;
+        self.backend.get(name)
+    }
+}
+
+/// The process-wide keyring-backed store.
+pub fn store() -> &'static SecretStore {
+    &STORE
+}
+
+/// Whether `name` is a valid secret name (as referenced from config).
+pub fn is_valid_secret_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.chars().count() <= MAX_SECRET_NAME_CHARS
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '_' | '-'))
+}
+
+fn validate_secret_name(name: &str) -> Result<(), SecretError> {
+    if is_valid_secret_name(name) {
+        Ok(())
+    } else {
+        Err(SecretError::InvalidName(name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct MemoryBackend {
+        values: Mutex<HashMap<String, String>>,
+    }
+
+    impl SecretBackend for MemoryBackend {
+        fn get(&self, name: &str) -> Result<Option<String>, SecretError> {
+            Ok(self.values.lock().unwrap().get(name).cloned())
+        }
+
+        fn set(&self, name: &str, value: &str) -> Result<(), SecretError> {
+            self.values
+                .lock()
+                .unwrap()
+                .insert(name.to_string(), value.to_string());
+            Ok(())
+        }
+
+        fn delete(&self, name: &str) -> Result<bool, SecretError> {
+            Ok(self.values.lock().unwrap().remove(name).is_some())
+        }
+    }
+
+    fn memory_store() -> SecretStore {
+        SecretStore::new(Box::<MemoryBackend>::default())
+    }
+
+    #[test]
+    fn test_set_has_resolve_delete() {
+        let store = memory_store();
+        assert!(!store.has("deepl.api_key").unwrap());
+
+        store.set("deepl.api_key", "sk-123").unwrap();
+        assert!(store.has("deepl.api_key").unwrap());
+        assert_eq!(
+            store.resolve("deepl.api_key").unwrap().as_deref(),
+            Some("sk-123")
+        );
+
+        assert!(store.delete("deepl.api_key").unwrap());
+        assert!(!store.delete("deepl.api_key").unwrap());
+        assert_eq!(store.resolve("deepl.api_key").unwrap(), None);
+    }
+
+    #[test]
+    fn test_rejects_invalid_names_and_empty_values() {
+        let store = memory_store();
+        assert!(matches!(
+            store.set("Deepl Key", "x"),
+            Err(SecretError::InvalidName(_))
+        ));
+        assert!(matches!(store.has(""), Err(SecretError::InvalidName(_))));
+        assert!(matches!(
+            store.set("webhook.token", "  "),
+            Err(SecretError::EmptyValue)
+        ));
+        assert!(is_valid_secret_name("webhook-1.token"));
+        assert!(!is_valid_secret_name(&"a".repeat(65)));
+    }
+
+    #[test]
+    fn test_errors_do_not_include_values() {
+        let store = memory_store();
+        let error = store.set("bad name", "super-secret-value").unwrap_err();
+        assert!(!error.to_string().contains("super-secret-value"));
+    }
+}
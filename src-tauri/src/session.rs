@@ -0,0 +1,269 @@
+//! Detection of remote desktop and background (non-console) sessions.
+//!
+//! With RDP, fast user switching, or several people signed in to one
+//! machine, each session runs its own copy of the app. Global hotkeys must
+//! only start a dictation in the session the user is sitting at, and a paste
+//! synthesized from a background session lands nowhere. This module reports
+//! which kind of session the app runs in so hotkeys can be paused and
+//! injection can fall back to the clipboard with a reason instead of
+//! silently failing.
+//!
+//! Windows compares the process session with the active console session and
+//! checks `SM_REMOTESESSION`. Linux asks systemd-logind about the current
+//! session and recognizes xrdp and SSH from the environment. macOS only
+//! recognizes SSH.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+/// How long a detection result is reused; sessions change rarely, but
+/// detection may spawn `loginctl`.
+const DETECTION_TTL: Duration = Duration::from_secs(5);
+
+#[cfg(target_os = "windows")]
+const SM_REMOTESESSION: i32 = 0x1000;
+
+static CACHED_SESSION: Lazy<Mutex<Option<(Instant, SessionInfo)>>> = Lazy::new(|| Mutex::new(None));
+
+/// Kind of login session the app runs in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum SessionKind {
+    /// The local session attached to the physical keyboard and display.
+    Console,
+    /// An active remote desktop or SSH session.
+    Remote,
+    /// A session in the background: another user holds the console, or the
+    /// remote connection was dropped.
+    Inactive,
+}
+
+/// The session the app runs in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct SessionInfo {
+    pub kind: SessionKind,
+    /// Remote protocol when known ("rdp", "ssh").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub remote_protocol: Option<String>,
+}
+
+impl SessionInfo {
+    #[cfg(test)]
+    pub fn console() -> Self {
+        Self {
+            kind: SessionKind::Console,
+            remote_protocol: None,
+        }
+    }
+
+    /// Whether global hotkeys should be ignored in this session.
+    pub fn suppresses_hotkeys(&self) -> bool {
+        self.kind != SessionKind::Console
+    }
+
+    /// Why text cannot be pasted into the focused app, if it cannot.
+    ///
+    /// A remote session has its own focus and keyboard, so pasting works
+    /// there; a background session has no focused app to receive it.
+    pub fn injection_block_reason(&self) -> Option<String> {
+        (self.kind == SessionKind::Inactive)
+            .then(|| "Session is not active (user switched or disconnected)".to_string())
+    }
+}
+
+/// The current session, re-detected at most every few seconds.
+pub fn current() -> SessionInfo {
+    let mut cached = CACHED_SESSION.lock().unwrap_or_else(|e| e.into_inner());
+    let now = Instant::now();
+    if let Some((detected_at, info)) = cached.as_ref() {
+        if now.saturating_duration_since(*detected_at) < DETECTION_TTL {
+            return info.clone();
+        }
+    }
+
+    let info = detect();
+    if cached.as_ref().map(|(_, previous)| previous) != Some(&info) {
+        log::info!(
+            "Session detected: {:?} (protocol: {:?})",
+            info.kind,
+            info.remote_protocol
+        );
+    }
+    *cached = Some((now, info.clone()));
+    info
+}
+
+fn classify(active: bool, remote_protocol: Option<&str>) -> SessionInfo {
+    let kind = match (active, remote_protocol) {
+        (false, _) => SessionKind::Inactive,
+        (true, Some(_)) => SessionKind::Remote,
+        (true, None) => SessionKind::Console,
+    };
+    SessionInfo {
+        kind,
+        remote_protocol: remote_protocol.map(str::to_string),
+    }
+}
+
+/// Remote protocol implied by the environment, for sessions logind or the
+/// OS does not report.
+fn remote_protocol_from_env(get: impl Fn(&str) -> Option<String>) -> Option<&'static str> {
+    if get("XRDP_SESSION").is_some() {
+        Some("rdp")
+    } else if get("SSH_CONNECTION").is_some() || get("SSH_CLIENT").is_some() {
+        Some("ssh")
+    } else {
+        None
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn detect() -> SessionInfo {
+    // SAFETY: plain Win32 queries with no pointers kept past the call.
+    let (remote, active) = unsafe {
+        let mut process_session = 0u32;
+        let known = ProcessIdToSessionId(GetCurrentProcessId(), &mut process_session) != 0;
+        let console_session = WTSGetActiveConsoleSessionId();
+        (
+            GetSystemMetrics(SM_REMOTESESSION) != 0,
+            !known || process_session == console_session,
+        )
+    };
+    // An RDP session is never the console session, so only a disconnected
+    // one counts as inactive.
+    let remote_protocol = if remote {
+        Some("rdp")
+    } else {
+        remote_protocol_from_env(|key| std::env::var(key).ok())
+    };
+    classify(active || remote, remote_protocol)
+}
+
+#[cfg(target_os = "linux")]
+fn detect() -> SessionInfo {
+    let env_protocol = remote_protocol_from_env(|key| std::env::var(key).ok());
+    let session_id = std::env::var("XDG_SESSION_ID").unwrap_or_else(|_| "auto".to_string());
+    let output = std::process::Command::new("loginctl")
+        .args(["show-session", &session_id, "-p", "Remote", "-p", "Active"])
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {
+            let logind = parse_loginctl_session(&String::from_utf8_lossy(&output.stdout));
+            let remote_protocol = env_protocol.or(logind.remote.then_some("remote"));
+            classify(logind.active, remote_protocol)
+        }
+        _ => classify(true, env_protocol),
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+fn detect() -> SessionInfo {
+    classify(
+        true,
+        remote_protocol_from_env(|key| std::env::var(key).ok()),
+    )
+}
+
+/// `Remote=` and `Active=` from `loginctl show-session`.
+#[derive(Debug, PartialEq, Eq)]
+struct LogindSession {
+    remote: bool,
+    active: bool,
+}
+
+fn parse_loginctl_session(output: &str) -> LogindSession {
+    let mut session = LogindSession {
+        remote: false,
+        active: true,
+    };
+    for line in output.lines() {
+        match line.trim().split_once('=') {
+            Some(("Remote", value)) => session.remote = value == "yes",
+            Some(("Active", value)) => session.active = value == "yes",
+            _ => {}
+        }
+    }
+    session
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "user32")]
+unsafe extern "system" {
+    fn GetSystemMetrics(index: i32) -> i32;
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "kernel32")]
+unsafe extern "system" {
+    fn GetCurrentProcessId() -> u32;
+    fn ProcessIdToSessionId(process_id: u32, session_id: *mut u32) -> i32;
+    fn WTSGetActiveConsoleSessionId() -> u32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_prefers_inactive_over_remote() {
+        assert_eq!(classify(true, None), SessionInfo::console());
+        assert_eq!(classify(true, Some("rdp")).kind, SessionKind::Remote);
+        assert_eq!(classify(false, Some("rdp")).kind, SessionKind::Inactive);
+        assert_eq!(classify(false, None).kind, SessionKind::Inactive);
+    }
+
+    #[test]
+    fn test_only_background_sessions_block_injection() {
+        assert!(!SessionInfo::console().suppresses_hotkeys());
+        assert!(SessionInfo::console().injection_block_reason().is_none());
+
+        let remote = classify(true, Some("rdp"));
+        assert!(remote.suppresses_hotkeys());
+        assert!(remote.injection_block_reason().is_none());
+
+        let inactive = classify(false, None);
+        assert!(inactive.suppresses_hotkeys());
+        assert!(inactive.injection_block_reason().is_some());
+    }
+
+    #[test]
+    fn test_remote_protocol_from_env() {
+        let env = |vars: &'static [&'static str]| {
+            move |key: &str| vars.contains(&key).then(|| "1".to_string())
+        };
+        assert_eq!(remote_protocol_from_env(env(&[])), None);
+        assert_eq!(
+            remote_protocol_from_env(env(&["SSH_CONNECTION"])),
+            Some("ssh")
+        );
+        assert_eq!(
+            remote_protocol_from_env(env(&["XRDP_SESSION", "SSH_CLIENT"])),
+            Some("rdp")
+        );
+    }
+
+    #[test]
+    fn test_parse_loginctl_session() {
+        assert_eq!(
+            parse_loginctl_session("Remote=yes\nActive=no\n"),
+            LogindSession {
+                remote: true,
+                active: false
+            }
+        );
+        // Missing properties keep the local, active defaults.
+        assert_eq!(
+            parse_loginctl_session(""),
+            LogindSession {
+                remote: false,
+                active: true
+            }
+        );
+    }
+}
@@ -0,0 +1,294 @@
+//! Append-only session journal for crash diagnosis.
+//!
+//! Records dictation lifecycle milestones (started, stopped, transcribed,
+//! injected) to a small JSONL file next to the config so "my dictation
+//! disappeared" reports can be reconstructed after a crash or restart, when
+//! the in-memory log buffer is gone. Transcript text is never written; only
+//! lengths and outcomes.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config;
+
+/// Journal file name inside the config directory.
+const JOURNAL_FILE_NAME: &str = "session_journal.jsonl";
+
+/// Rotate the active journal file once it would exceed this size.
+const DEFAULT_MAX_BYTES: u64 = 256 * 1024;
+
+/// Number of rotated journal files kept alongside the active one.
+const DEFAULT_MAX_ROTATED: usize = 2;
+
+static SESSION_JOURNAL: Lazy<SessionJournal> = Lazy::new(|| {
+    SessionJournal::new(
+        config::config_dir().join(JOURNAL_FILE_NAME),
+        DEFAULT_MAX_BYTES,
+        DEFAULT_MAX_ROTATED,
+    )
+});
+
+/// Get the process-wide session journal.
+pub fn global_journal() -> &'static SessionJournal {
+    &SESSION_JOURNAL
+}
+
+/// Append to `journal` (usually [`global_journal`]), logging (not
+/// propagating) write failures.
+pub fn record(
+    journal: &SessionJournal,
+    event: JournalEvent,
+    session_id: Option<&str>,
+    detail: Option<Value>,
+) {
+    if let Err(error) = journal.append(event, session_id, detail) {
+        log::warn!("Failed to append session journal record: {}", error);
+    }
+}
+
+/// Lifecycle milestone recorded in the journal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalEvent {
+    SessionStarted,
+    SessionStopped,
+    SessionTooShort,
+    SessionCancelled,
+    Transcribed,
+    TranscriptionFailed,
+    TranscriptionTimeout,
+    /// A transient transcription error triggered one more attempt.
+    TranscriptionRetried,
+    Injected,
+    /// Unparseable sidecar output was skipped.
+    ProtocolWarning,
+}
+
+/// One line of the journal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalRecord {
+    pub seq: u64,
+    pub timestamp: DateTime<Utc>,
+    pub event: JournalEvent,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<Value>,
+}
+
+/// Size-rotated append-only JSONL journal.
+#[derive(Debug)]
+pub struct SessionJournal {
+    path: PathBuf,
+    max_bytes: u64,
+    max_rotated: usize,
+    /// Next sequence number; resolved from disk on first append.
+    next_seq: Mutex<Option<u64>>,
+}
+
+impl SessionJournal {
+    pub fn new(path: PathBuf, max_bytes: u64, max_rotated: usize) -> Self {
+        Self {
+            path,
+            max_bytes,
+            max_rotated,
+            next_seq: Mutex::new(None),
+        }
+    }
+
+    /// Append a record, rotating the journal first if it is full.
+    pub fn append(
+        &self,
+        event: JournalEvent,
+        session_id: Option<&str>,
+        detail: Option<Value>,
+    ) -> io::Result<JournalRecord> {
+        let mut next_seq = self
+            .next_seq
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let seq = match *next_seq {
+            Some(seq) => seq,
+            None => self.last_seq_on_disk().map_or(1, |last| last + 1),
+        };
+
+        let record = JournalRecord {
+            seq,
+            timestamp: Utc::now(),
+            event,
+            session_id: session_id.map(ToString::to_string),
+            detail,
+        };
+        let mut line = serde_json::to_string(&record)?;
+        line.push('\n');
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let current_len = fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+        if current_len > 0 && current_len + line.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(line.as_bytes())?;
+
+        *next_seq = Some(seq + 1);
+        Ok(record)
+    }
+
+    /// Last `count` journal lines across rotated files, oldest first.
+    pub fn tail(&self, count: usize) -> Vec<String> {
+        let mut lines = Vec::new();
+        for index in (1..=self.max_rotated).rev() {
+            lines.extend(read_lines(&self.rotated_path(index)));
+        }
+        lines.extend(read_lines(&self.path));
+
+        let skip = lines.len().saturating_sub(count);
+        lines.into_iter().skip(skip).collect()
+    }
+
+    fn rotate(&self) -> io::Result<()> {
+        if self.max_rotated == 0 {
+            return fs::remove_file(&self.path);
+        }
+        for index in (1..self.max_rotated).rev() {
+            let from = self.rotated_path(index);
+            if from.exists() {
+                fs::rename(&from, self.rotated_path(index + 1))?;
+            }
+        }
+        fs::rename(&self.path, self.rotated_path(1))
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut name = self.path.as_os_str().to_owned();
+        name.push(format!(".{index}"));
+        PathBuf::from(name)
+    }
+
+    fn last_seq_on_disk(&self) -> Option<u64> {
+        std::iter::once(self.path.clone())
+            .chain((1..=self.max_rotated).map(|index| self.rotated_path(index)))
+            .find_map(|path| {
+                read_lines(&path)
+                    .iter()
+                    .rev()
+                    .find_map(|line| serde_json::from_str::<JournalRecord>(line).ok())
+                    .map(|record| record.seq)
+            })
+    }
+}
+
+/// A journal in a fresh temp directory, which is removed when dropped.
+#[cfg(test)]
+pub fn temp_journal() -> (tempfile::TempDir, SessionJournal) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let journal = SessionJournal::new(dir.path().join(JOURNAL_FILE_NAME), 64 * 1024, 2);
+    (dir, journal)
+}
+
+fn read_lines(path: &Path) -> Vec<String> {
+    let Ok(file) = File::open(path) else {
+        return Vec::new();
+    };
+    BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter(|line| !line.trim().is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_append_writes_jsonl_with_increasing_seq() {
+        let dir = tempdir().unwrap();
+        let journal = SessionJournal::new(dir.path().join("journal.jsonl"), 64 * 1024, 2);
+
+        let first = journal
+            .append(JournalEvent::SessionStarted, Some("s1"), None)
+            .unwrap();
+        let second = journal
+            .append(
+                JournalEvent::Transcribed,
+                Some("s1"),
+                Some(json!({ "text_length": 12 })),
+            )
+            .unwrap();
+
+        assert_eq!(first.seq, 1);
+        assert_eq!(second.seq, 2);
+
+        let tail = journal.tail(10);
+        assert_eq!(tail.len(), 2);
+        let parsed: JournalRecord = serde_json::from_str(&tail[1]).unwrap();
+        assert_eq!(parsed.event, JournalEvent::Transcribed);
+        assert_eq!(parsed.session_id.as_deref(), Some("s1"));
+        assert_eq!(parsed.detail, Some(json!({ "text_length": 12 })));
+    }
+
+    #[test]
+    fn test_seq_resumes_from_existing_journal() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("journal.jsonl");
+        SessionJournal::new(path.clone(), 64 * 1024, 2)
+            .append(JournalEvent::SessionStarted, Some("s1"), None)
+            .unwrap();
+
+        let reopened = SessionJournal::new(path, 64 * 1024, 2);
+        let record = reopened
+            .append(JournalEvent::SessionStopped, Some("s1"), None)
+            .unwrap();
+
+        assert_eq!(record.seq, 2);
+    }
+
+    #[test]
+    fn test_rotation_keeps_bounded_files_and_tail_spans_them() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("journal.jsonl");
+        let journal = SessionJournal::new(path.clone(), 200, 2);
+
+        for _ in 0..20 {
+            journal
+                .append(JournalEvent::Injected, Some("session"), None)
+                .unwrap();
+        }
+
+        assert!(fs::metadata(&path).unwrap().len() <= 200);
+        assert!(journal.rotated_path(1).exists());
+        assert!(journal.rotated_path(2).exists());
+        assert!(!journal.rotated_path(3).exists());
+
+        let tail = journal.tail(5);
+        let seqs: Vec<u64> = tail
+            .iter()
+            .map(|line| serde_json::from_str::<JournalRecord>(line).unwrap().seq)
+            .collect();
+        assert_eq!(seqs, vec![16, 17, 18, 19, 20]);
+    }
+
+    #[test]
+    fn test_tail_of_missing_journal_is_empty() {
+        let dir = tempdir().unwrap();
+        let journal = SessionJournal::new(dir.path().join("missing.jsonl"), 1024, 2);
+
+        assert!(journal.tail(10).is_empty());
+    }
+}
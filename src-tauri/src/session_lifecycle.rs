@@ -0,0 +1,350 @@
+//! Canonical lifecycle of a dictation session.
+//!
+//! A session moves `created → recording → transcribing → completed`, or ends
+//! early as `failed` or `cancelled`. The backend keeps a snapshot of the most
+//! recent session, publishes it with `session:changed` on every transition,
+//! and returns it from `get_active_session`, so a reloaded window restores the
+//! exact UI state instead of inferring it from the last few events.
+//!
+//! Every transition bumps the snapshot's `revision`. A client that has already
+//! applied a revision of a session can drop any snapshot of that session that
+//! is not newer, which makes replayed events and re-fetches idempotent.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::recording::RecordingEvent;
+
+/// Reason recorded when a recording ends below the minimum duration.
+const TOO_SHORT_CANCEL_REASON: &str = "too_short";
+
+/// Error recorded when the sidecar never answered a transcription request.
+const TRANSCRIPTION_TIMEOUT_ERROR: &str = "Transcription timed out";
+
+/// Phase of a dictation session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum SessionPhase {
+    /// Session id allocated; audio capture is being started.
+    Created,
+    /// Audio is being captured.
+    Recording,
+    /// Capture stopped; waiting for the transcript.
+    Transcribing,
+    /// Transcript delivered.
+    Completed,
+    /// Capture or transcription failed.
+    Failed,
+    /// Discarded by the user, or too short to transcribe.
+    Cancelled,
+}
+
+impl SessionPhase {
+    /// Whether the session has ended; terminal snapshots never change again.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+}
+
+/// Full state of one dictation session.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct SessionSnapshot {
+    pub session_id: String,
+    pub phase: SessionPhase,
+    /// Starts at 1 and grows by one per transition of this session.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub revision: u64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// When audio capture began.
+    pub started_at: Option<DateTime<Utc>>,
+    /// Captured audio length, known once recording stopped.
+    #[cfg_attr(test, ts(type = "number | null"))]
+    pub audio_ms: Option<u64>,
+    /// Final transcript of a completed session.
+    pub text: Option<String>,
+    /// Failure message of a failed session.
+    pub error: Option<String>,
+    /// Why a cancelled session was discarded.
+    pub cancel_reason: Option<String>,
+}
+
+impl SessionSnapshot {
+    /// Snapshot of a session whose capture is about to start.
+    pub fn created(session_id: String, now: DateTime<Utc>) -> Self {
+        Self {
+            session_id,
+            phase: SessionPhase::Created,
+            revision: 1,
+            created_at: now,
+            updated_at: now,
+            started_at: None,
+            audio_ms: None,
+            text: None,
+            error: None,
+            cancel_reason: None,
+        }
+    }
+
+    /// Mark the session failed; `None` when it has already ended.
+    pub fn fail(&self, error: String, now: DateTime<Utc>) -> Option<Self> {
+        if self.phase.is_terminal() {
+            return None;
+        }
+        let mut next = self.advance(SessionPhase::Failed, now);
+        next.error = Some(error);
+        Some(next)
+    }
+
+    fn advance(&self, phase: SessionPhase, now: DateTime<Utc>) -> Self {
+        Self {
+            phase,
+            revision: self.revision + 1,
+            updated_at: now,
+            ..self.clone()
+        }
+    }
+}
+
+/// Next snapshot after a recording event, or `None` when the event does not
+/// move the session forward.
+///
+/// Events for another session are ignored, except `Started`, which begins a
+/// session that was not announced as created. Ended sessions never change.
+pub fn apply_recording_event(
+    current: Option<&SessionSnapshot>,
+    event: &RecordingEvent,
+) -> Option<SessionSnapshot> {
+    if let RecordingEvent::Started {
+        session_id,
+        timestamp,
+    } = event
+    {
+        let base = match current {
+            Some(snapshot) if snapshot.session_id == *session_id => snapshot.clone(),
+            _ => SessionSnapshot::created(session_id.clone(), *timestamp),
+        };
+        if base.phase != SessionPhase::Created {
+            return None;
+        }
+        let mut next = base.advance(SessionPhase::Recording, *timestamp);
+        next.started_at = Some(*timestamp);
+        return Some(next);
+    }
+
+    let current = current?;
+    if current.phase.is_terminal() || !event_belongs_to(event, &current.session_id) {
+        return None;
+    }
+
+    match event {
+        RecordingEvent::Stopped {
+            duration_ms,
+            timestamp,
+            ..
+        } => {
+            let mut next = current.advance(SessionPhase::Transcribing, *timestamp);
+            next.audio_ms = Some(*duration_ms);
+            Some(next)
+        }
+        RecordingEvent::TooShort {
+            duration_ms,
+            timestamp,
+            ..
+        } => {
+            let mut next = current.advance(SessionPhase::Cancelled, *timestamp);
+            next.audio_ms = Some(*duration_ms);
+            next.cancel_reason = Some(TOO_SHORT_CANCEL_REASON.to_string());
+            Some(next)
+        }
+        RecordingEvent::Cancelled {
+            reason, timestamp, ..
+        } => {
+            let mut next = current.advance(SessionPhase::Cancelled, *timestamp);
+            next.cancel_reason = Some(reason.as_str().to_string());
+            Some(next)
+        }
+        RecordingEvent::TranscriptionComplete {
+            text,
+            audio_duration_ms,
+            timestamp,
+            ..
+        } => {
+            let mut next = current.advance(SessionPhase::Completed, *timestamp);
+            next.text = Some(text.clone());
+            next.audio_ms = next.audio_ms.or(Some(*audio_duration_ms));
+            Some(next)
+        }
+        RecordingEvent::TranscriptionFailed {
+            error, timestamp, ..
+        } => current.fail(error.clone(), *timestamp),
+        RecordingEvent::TranscriptionTimeout { timestamp, .. } => {
+            current.fail(TRANSCRIPTION_TIMEOUT_ERROR.to_string(), *timestamp)
+        }
+        // Utterances of a continuous session and the max-duration notice do
+        // not change the phase; the `Stopped` that follows does.
+        RecordingEvent::Started { .. }
+        | RecordingEvent::UtteranceComplete { .. }
+        | RecordingEvent::MaxDurationReached { .. } => None,
+    }
+}
+
+fn event_belongs_to(event: &RecordingEvent, session_id: &str) -> bool {
+    let event_session_id = match event {
+        RecordingEvent::Started { session_id, .. }
+        | RecordingEvent::Stopped { session_id, .. }
+        | RecordingEvent::TooShort { session_id, .. }
+        | RecordingEvent::Cancelled { session_id, .. }
+        | RecordingEvent::UtteranceComplete { session_id, .. }
+        | RecordingEvent::TranscriptionComplete { session_id, .. }
+        | RecordingEvent::TranscriptionFailed { session_id, .. }
+        | RecordingEvent::TranscriptionTimeout { session_id, .. }
+        | RecordingEvent::MaxDurationReached { session_id, .. } => session_id,
+    };
+    event_session_id == session_id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording::CancelReason;
+
+    fn started(session_id: &str) -> RecordingEvent {
+        RecordingEvent::Started {
+            session_id: session_id.to_string(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn stopped(session_id: &str, duration_ms: u64) -> RecordingEvent {
+        RecordingEvent::Stopped {
+            session_id: session_id.to_string(),
+            duration_ms,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_full_lifecycle_bumps_revision_per_transition() {
+        let created = SessionSnapshot::created("s1".to_string(), Utc::now());
+        assert_eq!(created.phase, SessionPhase::Created);
+        assert_eq!(created.revision, 1);
+
+        let recording = apply_recording_event(Some(&created), &started("s1")).unwrap();
+        assert_eq!(recording.phase, SessionPhase::Recording);
+        assert_eq!(recording.revision, 2);
+        assert!(recording.started_at.is_some());
+        assert_eq!(recording.created_at, created.created_at);
+
+        let transcribing = apply_recording_event(Some(&recording), &stopped("s1", 1200)).unwrap();
+        assert_eq!(transcribing.phase, SessionPhase::Transcribing);
+        assert_eq!(transcribing.audio_ms, Some(1200));
+        assert_eq!(transcribing.revision, 3);
+
+        let completed = apply_recording_event(
+            Some(&transcribing),
+            &RecordingEvent::TranscriptionComplete {
+                session_id: "s1".to_string(),
+                text: "hello".to_string(),
+                audio_duration_ms: 1100,
+                processing_duration_ms: 80,
+                timestamp: Utc::now(),
+            },
+        )
+        .unwrap();
+        assert_eq!(completed.phase, SessionPhase::Completed);
+        assert_eq!(completed.text.as_deref(), Some("hello"));
+        assert_eq!(completed.audio_ms, Some(1200));
+        assert_eq!(completed.revision, 4);
+    }
+
+    #[test]
+    fn test_started_without_created_snapshot_begins_recording_session() {
+        let previous = SessionSnapshot::created("old".to_string(), Utc::now())
+            .fail("boom".to_string(), Utc::now())
+            .unwrap();
+
+        let next = apply_recording_event(Some(&previous), &started("new")).unwrap();
+        assert_eq!(next.session_id, "new");
+        assert_eq!(next.phase, SessionPhase::Recording);
+        assert_eq!(next.revision, 2);
+
+        assert_eq!(
+            apply_recording_event(None, &started("fresh"))
+                .unwrap()
+                .phase,
+            SessionPhase::Recording
+        );
+    }
+
+    #[test]
+    fn test_events_for_other_sessions_are_ignored() {
+        let recording = apply_recording_event(None, &started("s1")).expect("started snapshot");
+        assert!(apply_recording_event(Some(&recording), &stopped("s2", 500)).is_none());
+        assert!(apply_recording_event(None, &stopped("s1", 500)).is_none());
+    }
+
+    #[test]
+    fn test_terminal_sessions_never_change() {
+        let recording = apply_recording_event(None, &started("s1")).unwrap();
+        let cancelled = apply_recording_event(
+            Some(&recording),
+            &RecordingEvent::Cancelled {
+                session_id: "s1".to_string(),
+                reason: CancelReason::EscapeKey,
+                timestamp: Utc::now(),
+            },
+        )
+        .unwrap();
+        assert_eq!(cancelled.phase, SessionPhase::Cancelled);
+        assert_eq!(cancelled.cancel_reason.as_deref(), Some("escape_key"));
+
+        assert!(apply_recording_event(Some(&cancelled), &stopped("s1", 900)).is_none());
+        assert!(apply_recording_event(Some(&cancelled), &started("s1")).is_none());
+        assert!(cancelled.fail("late".to_string(), Utc::now()).is_none());
+    }
+
+    #[test]
+    fn test_too_short_and_timeout_map_to_terminal_phases() {
+        let recording = apply_recording_event(None, &started("s1")).unwrap();
+        let too_short = apply_recording_event(
+            Some(&recording),
+            &RecordingEvent::TooShort {
+                session_id: "s1".to_string(),
+                duration_ms: 90,
+                timestamp: Utc::now(),
+            },
+        )
+        .unwrap();
+        assert_eq!(too_short.phase, SessionPhase::Cancelled);
+        assert_eq!(too_short.cancel_reason.as_deref(), Some("too_short"));
+
+        let transcribing = apply_recording_event(Some(&recording), &stopped("s1", 800)).unwrap();
+        let timed_out = apply_recording_event(
+            Some(&transcribing),
+            &RecordingEvent::TranscriptionTimeout {
+                session_id: "s1".to_string(),
+                timestamp: Utc::now(),
+            },
+        )
+        .unwrap();
+        assert_eq!(timed_out.phase, SessionPhase::Failed);
+        assert!(timed_out.error.is_some());
+    }
+
+    #[test]
+    fn test_utterances_do_not_change_the_snapshot() {
+        let recording = apply_recording_event(None, &started("s1")).unwrap();
+        let utterance = RecordingEvent::UtteranceComplete {
+            session_id: "s1".to_string(),
+            segment_index: 0,
+            text: "partial".to_string(),
+            audio_duration_ms: 400,
+            processing_duration_ms: 20,
+            timestamp: Utc::now(),
+        };
+        assert!(apply_recording_event(Some(&recording), &utterance).is_none());
+    }
+}
@@ -0,0 +1,282 @@
+//! Sharing transcripts with other apps.
+//!
+//! On macOS the `NSSharingServicePicker` and on Windows the Share UI
+//! (`DataTransferManager`) are opened from a short-lived helper process
+//! (`osascript` / PowerShell) that owns the anchor window the system UI
+//! needs; the helper reports back once the sheet is up. Linux has no common
+//! share sheet, so an email draft is opened via a `mailto:` link. If neither
+//! works, or the text is too long for a URL, the transcript is copied to the
+//! clipboard instead.
+
+use std::process::{Command, Stdio};
+
+use serde::Serialize;
+use thiserror::Error;
+
+const SHARE_SUBJECT: &str = "Transcript";
+
+/// Longest `mailto:` URL handed to the OS; mail clients truncate beyond this.
+const MAX_MAILTO_URL_CHARS: usize = 2000;
+
+/// How long the helper process keeps its anchor window alive.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+const SHARE_SHEET_TIMEOUT_SECS: u32 = 120;
+
+/// How long to wait for the helper to show the sheet (PowerShell starts slowly).
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+const SHARE_HELPER_READY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(15);
+
+/// Line the helper prints once the share sheet is showing.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+const SHARE_HELPER_READY: &str = "shown";
+
+/// Sharing errors.
+#[derive(Debug, Error)]
+pub enum ShareError {
+    #[error("Could not share or copy the transcript: {0}")]
+    Unavailable(String),
+}
+
+/// How a transcript was shared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShareMethod {
+    /// The platform share sheet was opened.
+    ShareSheet,
+    /// An email draft was opened in the default mail client.
+    Email,
+    /// The text was copied to the clipboard for manual pasting.
+    Clipboard,
+}
+
+/// Share `text`, falling back from the share sheet to email to the clipboard.
+pub fn share_text(text: &str) -> Result<ShareMethod, ShareError> {
+    match open_share_sheet(text) {
+        Ok(()) => return Ok(ShareMethod::ShareSheet),
+        Err(reason) => log::debug!("Share sheet unavailable: {}", reason),
+    }
+
+    if let Some(url) = mailto_url(SHARE_SUBJECT, text) {
+        match open_url(&url) {
+            Ok(()) => return Ok(ShareMethod::Email),
+            Err(reason) => log::debug!("Email share unavailable: {}", reason),
+        }
+    }
+
+    crate::injection::set_clipboard_public(text)
+        .map(|()| ShareMethod::Clipboard)
+        .map_err(ShareError::Unavailable)
+}
+
+/// A `mailto:` URL with `subject` and `body`, or None if it would be too long.
+pub fn mailto_url(subject: &str, body: &str) -> Option<String> {
+    let url = format!(
+        "mailto:?subject={}&body={}",
+        percent_encode(subject),
+        percent_encode(body)
+    );
+    (url.len() <= MAX_MAILTO_URL_CHARS).then_some(url)
+}
+
+/// Percent-encode everything except RFC 3986 unreserved characters.
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+fn spawn_detached(command: &mut Command) -> Result<(), String> {
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+fn open_url(url: &str) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        spawn_detached(Command::new("open").arg(url))
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        spawn_detached(Command::new("rundll32").args(["url.dll,FileProtocolHandler", url]))
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        spawn_detached(Command::new("xdg-open").arg(url))
+    }
+}
+
+/// Run a share helper and wait until it reports the sheet is showing.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+fn run_share_helper(command: &mut Command) -> Result<(), String> {
+    use std::io::{BufRead, BufReader};
+    use std::sync::mpsc;
+
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| "share helper has no stdout".to_string())?;
+
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut line = String::new();
+        let _ = BufReader::new(stdout).read_line(&mut line);
+        let _ = tx.send(line);
+    });
+
+    match rx.recv_timeout(SHARE_HELPER_READY_TIMEOUT) {
+        Ok(line) if line.trim() == SHARE_HELPER_READY => {
+            // Reap the helper once the user is done with the sheet.
+            std::thread::spawn(move || {
+                let _ = child.wait();
+            });
+            Ok(())
+        }
+        result => {
+            let _ = child.kill();
+            let _ = child.wait();
+            Err(match result {
+                Ok(_) => "share helper failed".to_string(),
+                Err(_) => "share helper did not respond".to_string(),
+            })
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn open_share_sheet(text: &str) -> Result<(), String> {
+    // The picker must be anchored to a view, so the helper creates a tiny
+    // borderless window at the mouse position and runs until it times out.
+    let script = format!(
+        r#"ObjC.import('AppKit');
+const app = $.NSApplication.sharedApplication;
+app.setActivationPolicy($.NSApplicationActivationPolicyAccessory);
+const mouse = $.NSEvent.mouseLocation;
+const win = $.NSWindow.alloc.initWithContentRectStyleMaskBackingDefer(
+  $.NSMakeRect(mouse.x, mouse.y, 1, 1), $.NSWindowStyleMaskBorderless, $.NSBackingStoreBuffered, false);
+win.level = $.NSFloatingWindowLevel;
+win.makeKeyAndOrderFront(null);
+app.activateIgnoringOtherApps(true);
+const picker = $.NSSharingServicePicker.alloc.initWithItems($([{text}]));
+picker.showRelativeToRectOfViewPreferredEdge(win.contentView.bounds, win.contentView, $.NSMinYEdge);
+$.NSFileHandle.fileHandleWithStandardOutput.writeData($('{ready}\n').dataUsingEncoding($.NSUTF8StringEncoding));
+$.NSRunLoop.currentRunLoop.runUntilDate($.NSDate.dateWithTimeIntervalSinceNow({timeout}));"#,
+        text = js_string_literal(text),
+        timeout = SHARE_SHEET_TIMEOUT_SECS,
+        ready = SHARE_HELPER_READY,
+    );
+    run_share_helper(Command::new("osascript").args(["-l", "JavaScript", "-e", &script]))
+}
+
+#[cfg(target_os = "windows")]
+fn open_share_sheet(text: &str) -> Result<(), String> {
+    // DataTransferManager only shares from a window owned by the calling
+    // process, so the helper opens an invisible form and shows the Share UI
+    // for it. The text is passed through the environment to avoid quoting.
+    let script = format!(
+        r#"$ErrorActionPreference='Stop'
+Add-Type -AssemblyName System.Windows.Forms
+Add-Type -TypeDefinition @'
+using System;
+using System.Runtime.InteropServices;
+[ComImport, Guid("3A3DCD6C-3EAB-43DC-BCDE-45671CE800C8"), InterfaceType(ComInterfaceType.InterfaceIsIUnknown)]
+public interface IDataTransferManagerInterop {{
+  IntPtr GetForWindow(IntPtr appWindow, ref Guid riid);
+  void ShowShareUIForWindow(IntPtr appWindow);
+}}
+'@
+$dtmType = [Windows.ApplicationModel.DataTransfer.DataTransferManager, Windows.ApplicationModel.DataTransfer, ContentType=WindowsRuntime]
+$interop = [System.Runtime.InteropServices.WindowsRuntime.WindowsRuntimeMarshal]::GetActivationFactory($dtmType) -as [IDataTransferManagerInterop]
+$form = New-Object System.Windows.Forms.Form -Property @{{ ShowInTaskbar = $false; Opacity = 0; Width = 1; Height = 1 }}
+$form.Add_Shown({{
+  $iid = [Guid]'A5CAEE9B-8708-49D1-8D36-67D25A8DA00C'
+  $ptr = $interop.GetForWindow($form.Handle, [ref]$iid)
+  $dtm = [System.Runtime.InteropServices.Marshal]::GetObjectForIUnknown($ptr)
+  $dtm.add_DataRequested({{ param($sender, $e)
+    $e.Request.Data.Properties.Title = '{subject}'
+    $e.Request.Data.SetText($env:OPENVOICY_SHARE_TEXT)
+  }})
+  $interop.ShowShareUIForWindow($form.Handle)
+  [Console]::Out.WriteLine('{ready}')
+  [Console]::Out.Flush()
+}})
+$timer = New-Object System.Windows.Forms.Timer -Property @{{ Interval = {timeout_ms} }}
+$timer.Add_Tick({{ $form.Close() }})
+$timer.Start()
+[System.Windows.Forms.Application]::Run($form)"#,
+        subject = SHARE_SUBJECT,
+        timeout_ms = SHARE_SHEET_TIMEOUT_SECS * 1000,
+        ready = SHARE_HELPER_READY,
+    );
+    run_share_helper(
+        Command::new("powershell")
+            .args(["-NoProfile", "-NonInteractive", "-WindowStyle", "Hidden"])
+            .args(["-Command", &script])
+            .env("OPENVOICY_SHARE_TEXT", text),
+    )
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn open_share_sheet(_text: &str) -> Result<(), String> {
+    Err("no system share sheet on this platform".to_string())
+}
+
+/// Quote `value` as a JavaScript string literal.
+#[cfg(any(target_os = "macos", test))]
+fn js_string_literal(value: &str) -> String {
+    // JSON strings are valid JavaScript string literals.
+    serde_json::Value::String(value.to_string()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mailto_url_encodes_subject_and_body() {
+        assert_eq!(
+            mailto_url("Transcript", "Hello, world & more\nNext line").as_deref(),
+            Some("mailto:?subject=Transcript&body=Hello%2C%20world%20%26%20more%0ANext%20line")
+        );
+        assert_eq!(
+            mailto_url("Transcript", "café").as_deref(),
+            Some("mailto:?subject=Transcript&body=caf%C3%A9")
+        );
+    }
+
+    #[test]
+    fn test_mailto_url_rejects_long_text() {
+        assert!(mailto_url("Transcript", &"word ".repeat(1000)).is_none());
+    }
+
+    #[test]
+    fn test_js_string_literal_escapes_quotes_and_newlines() {
+        assert_eq!(js_string_literal("it's \"ok\"\n"), r#""it's \"ok\"\n""#);
+    }
+
+    #[test]
+    fn test_share_method_serializes_snake_case() {
+        assert_eq!(
+            serde_json::to_value(ShareMethod::ShareSheet).unwrap(),
+            serde_json::json!("share_sheet")
+        );
+    }
+}
@@ -5,22 +5,31 @@
 //!
 //! In release builds, the sidecar is a bundled PyInstaller binary.
 //! In debug builds, it runs via Python interpreter for faster iteration.
+//! Before each Python-mode spawn, a preflight checks the interpreter version
+//! and that the sidecar module and its dependencies import, so a broken dev
+//! environment fails once with a clear reason instead of crash-looping.
+//! Messages travel over stdio, or over a socket or named pipe the sidecar
+//! connects back to when `OPENVOICY_SIDECAR_TRANSPORT=socket` (see
+//! [`crate::ipc::transport`]).
 
 #![allow(dead_code)] // Methods will be used in future RPC client implementation
 
 use std::collections::VecDeque;
 use std::env;
-use std::io::{BufRead, BufReader, Write};
+use std::io::{BufRead, BufReader};
 use std::process::{Child, ChildStderr, ChildStdout, Command, Stdio};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::errors::{AppError, ErrorKind};
+use crate::ipc::decode::{decode_line, OutputChannel};
+use crate::ipc::transport::{self, SocketListener, Transport, TransportKind};
+use crate::network;
+use crate::runtime::{AppHandle, Emitter, Manager};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use tauri::{AppHandle, Emitter, Manager};
 
 /// Maximum number of restart attempts before giving up.
 const MAX_RESTART_ATTEMPTS: u32 = 5;
@@ -32,6 +41,23 @@ const SIDECAR_CAPTURED_LOG_MAX_LINES: usize = 1000;
 /// Event name for sidecar status changes
 const EVENT_SIDECAR_STATUS: &str = "sidecar:status";
 
+/// Oldest Python the sidecar supports (`requires-python` in sidecar/pyproject.toml).
+const MIN_PYTHON_VERSION: (u32, u32) = (3, 11);
+
+/// Third-party packages the sidecar imports at startup.
+const PYTHON_DEPENDENCIES: [&str; 3] = ["numpy", "scipy", "sounddevice"];
+
+/// Upper bound on the preflight; importing numpy and scipy cold takes a few seconds.
+const PYTHON_PREFLIGHT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Marks a spawn failure caused by the Python environment rather than a
+/// missing interpreter; see [`incompatible_environment_reason`].
+const INCOMPATIBLE_ENVIRONMENT: &str = "incompatible sidecar environment";
+
+/// How long a sidecar offered a socket may take to connect back before it
+/// is talked to over stdio; covers a cold interpreter start.
+const SOCKET_CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+
 /// Sidecar lifecycle state
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -82,7 +108,8 @@ struct SidecarInner {
     state: SidecarState,
     restart_count: u32,
     child: Option<Child>,
-    stdout_reader: Option<BufReader<ChildStdout>>,
+    /// Channel to the running sidecar.
+    transport: Option<Arc<dyn Transport>>,
     last_error: Option<String>,
     captured_logs: VecDeque<String>,
 }
@@ -111,6 +138,7 @@ pub struct SidecarManager {
     app_handle: Option<AppHandle>,
     spawn_mode: SpawnMode,
     simulation_mode: SidecarSimulationMode,
+    transport_kind: TransportKind,
 }
 
 impl SidecarManager {
@@ -134,7 +162,7 @@ impl SidecarManager {
                 state: SidecarState::NotStarted,
                 restart_count: 0,
                 child: None,
-                stdout_reader: None,
+                transport: None,
                 last_error: None,
                 captured_logs: VecDeque::new(),
             })),
@@ -142,6 +170,7 @@ impl SidecarManager {
             app_handle: None,
             spawn_mode,
             simulation_mode: Self::simulation_mode_from_env(),
+            transport_kind: TransportKind::from_env(),
         }
     }
 
@@ -162,6 +191,13 @@ impl SidecarManager {
         self.spawn_mode = SpawnMode::Bundled;
     }
 
+    /// Set the transport for the next spawn; a running sidecar keeps its own
+    /// until it restarts.
+    #[allow(dead_code)]
+    pub fn set_transport_kind(&mut self, kind: TransportKind) {
+        self.transport_kind = kind;
+    }
+
     /// Check if using bundled binary mode.
     #[allow(dead_code)]
     pub fn is_bundled_mode(&self) -> bool {
@@ -260,6 +296,21 @@ impl SidecarManager {
             self.simulation_mode
         );
 
+        let listener = match self.transport_kind {
+            TransportKind::Stdio => None,
+            TransportKind::Socket => match SocketListener::bind() {
+                Ok(listener) => Some(listener),
+                Err(e) => {
+                    log::warn!("Failed to open sidecar socket, using stdio: {}", e);
+                    None
+                }
+            },
+        };
+        let endpoint_env: Vec<(&str, String)> = listener
+            .iter()
+            .map(|listener| (transport::ENDPOINT_ENV, listener.endpoint().to_string()))
+            .collect();
+
         let mut child = if self.simulation_mode == SidecarSimulationMode::CrashOnStart {
             Self::spawn_simulated_crash_process()?
         } else {
@@ -267,9 +318,13 @@ impl SidecarManager {
                 SpawnMode::Python { path, module } => {
                     // Development mode: run via Python interpreter
                     log::info!("Using Python mode: {} -m {}", path, module);
+                    python_preflight(path, module)
+                        .map_err(|reason| format!("Failed to spawn Python sidecar: {}", reason))?;
                     Command::new(path)
                         .arg("-m")
                         .arg(module)
+                        .envs(network::sidecar_env())
+                        .envs(endpoint_env)
                         .stdin(Stdio::piped())
                         .stdout(Stdio::piped())
                         .stderr(Stdio::piped())
@@ -302,6 +357,8 @@ impl SidecarManager {
                     }
 
                     Command::new(&sidecar_path)
+                        .envs(network::sidecar_env())
+                        .envs(endpoint_env)
                         .stdin(Stdio::piped())
                         .stdout(Stdio::piped())
                         .stderr(Stdio::piped())
@@ -312,14 +369,41 @@ impl SidecarManager {
         };
 
         let pid = child.id();
-        let stdout_reader = child.stdout.take().map(BufReader::new);
         let stderr_reader = child.stderr.take();
         log::info!("Sidecar spawned with PID {}", pid);
 
+        let connected = listener.and_then(|listener| {
+            match listener.accept(SOCKET_CONNECT_TIMEOUT, || {
+                matches!(child.try_wait(), Ok(None))
+            }) {
+                Ok(transport) => Some(transport),
+                Err(e) => {
+                    log::warn!("Sidecar did not connect to its socket ({}), using stdio", e);
+                    None
+                }
+            }
+        });
+        let transport: Option<Arc<dyn Transport>> = match connected {
+            Some(transport) => {
+                // Stdout is free for whatever wraps the sidecar; keep it drained.
+                if let Some(stdout) = child.stdout.take() {
+                    Self::start_stdout_log_thread(stdout);
+                }
+                Some(Arc::from(transport))
+            }
+            None => match (child.stdin.take(), child.stdout.take()) {
+                (Some(stdin), Some(stdout)) => Some(Arc::new(transport::stdio(stdin, stdout))),
+                _ => None,
+            },
+        };
+        if let Some(transport) = &transport {
+            log::info!("Sidecar transport: {}", transport.name());
+        }
+
         {
             let mut inner = self.inner.lock().unwrap();
             inner.child = Some(child);
-            inner.stdout_reader = stdout_reader;
+            inner.transport = transport;
             inner.state = SidecarState::Running;
             inner.last_error = None;
         }
@@ -428,6 +512,12 @@ impl SidecarManager {
             .parent()
             .ok_or_else(|| "Failed to get executable directory".to_string())?;
 
+        // An installed sidecar update takes precedence over the bundled binary.
+        if let Some(update_path) = crate::sidecar_update::active_sidecar_path() {
+            log::info!("Using updated sidecar: {:?}", update_path);
+            return Ok(update_path);
+        }
+
         // Tauri externalBin artifacts are target-triple suffixed. Keep unsuffixed
         // fallback for older/local setups that still ship plain names.
         let sidecar_names = Self::get_sidecar_binary_candidates();
@@ -482,14 +572,14 @@ impl SidecarManager {
     }
 
     /// Get the sidecar binary name for the current platform.
-    fn get_sidecar_binary_name() -> String {
+    pub(crate) fn get_sidecar_binary_name() -> String {
         Self::get_sidecar_binary_candidates()
             .into_iter()
             .next()
             .unwrap()
     }
 
-    fn get_target_triple() -> String {
+    pub(crate) fn get_target_triple() -> String {
         if let Some(target) = option_env!("TARGET") {
             return target.to_string();
         }
@@ -619,6 +709,28 @@ impl SidecarManager {
         });
     }
 
+    /// Log stdout of a sidecar that talks over a socket, so output from a
+    /// wrapping debugger or profiler neither blocks it nor gets lost.
+    fn start_stdout_log_thread(stdout: ChildStdout) {
+        thread::spawn(move || {
+            let mut reader = BufReader::new(stdout);
+            let mut line = Vec::new();
+            loop {
+                line.clear();
+                match reader.read_until(b'\n', &mut line) {
+                    Ok(0) | Err(_) => break,
+                    Ok(_) => {
+                        let text = decode_line(OutputChannel::Log, &line);
+                        let text = text.trim();
+                        if !text.is_empty() {
+                            log::debug!("Sidecar stdout: {}", text);
+                        }
+                    }
+                }
+            }
+        });
+    }
+
     /// Start a thread that continuously consumes sidecar stderr.
     /// Fatal stderr patterns request process termination so monitor thread
     /// can apply the normal crash/restart path.
@@ -628,14 +740,17 @@ impl SidecarManager {
 
         thread::spawn(move || {
             let mut reader = BufReader::new(stderr);
-            let mut line = String::new();
+            let mut line = Vec::new();
 
             loop {
                 line.clear();
-                match reader.read_line(&mut line) {
+                // Python may print errors in the locale's encoding; decode
+                // lossily so a stray byte does not stop log capture.
+                match reader.read_until(b'\n', &mut line) {
                     Ok(0) => break,
                     Ok(_) => {
-                        let text = line.trim();
+                        let text = decode_line(OutputChannel::Log, &line);
+                        let text = text.trim();
                         if text.is_empty() {
                             continue;
                         }
@@ -724,7 +839,7 @@ impl SidecarManager {
 
             inner.restart_count += 1;
             inner.state = SidecarState::Restarting;
-            inner.stdout_reader = None;
+            inner.transport = None;
             (inner.restart_count, true)
         };
 
@@ -782,16 +897,15 @@ impl SidecarManager {
             let mut inner = self.inner.lock().unwrap();
             inner.state = SidecarState::ShuttingDown;
 
-            if let Some(ref mut child) = inner.child {
-                // Try graceful shutdown via stdin close
-                if let Some(ref mut stdin) = child.stdin.take() {
-                    // Send shutdown command
-                    let shutdown_cmd =
-                        r#"{"jsonrpc":"2.0","id":"shutdown","method":"system.shutdown"}"#;
-                    let _ = writeln!(stdin, "{}", shutdown_cmd);
-                    let _ = stdin.flush();
-                }
+            // Try graceful shutdown: send the command, then end the input.
+            if let Some(transport) = inner.transport.take() {
+                let shutdown_cmd =
+                    r#"{"jsonrpc":"2.0","id":"shutdown","method":"system.shutdown"}"#;
+                let _ = transport.write_line(shutdown_cmd);
+                transport.close_write();
+            }
 
+            if let Some(ref mut child) = inner.child {
                 // Wait briefly for graceful exit
                 thread::sleep(Duration::from_millis(500));
 
@@ -802,7 +916,6 @@ impl SidecarManager {
                 }
 
                 inner.child = None;
-                inner.stdout_reader = None;
             }
 
             inner.state = SidecarState::NotStarted;
@@ -833,58 +946,40 @@ impl SidecarManager {
         self.start()
     }
 
-    /// Write a line to the sidecar's stdin.
-    pub fn write_line(&self, line: &str) -> Result<(), String> {
-        let mut inner = self.inner.lock().unwrap();
-
+    /// The running sidecar's transport.
+    fn running_transport(&self) -> Result<Arc<dyn Transport>, String> {
+        let inner = self.inner.lock().unwrap();
         if inner.state != SidecarState::Running {
             return Err(format!("Sidecar not running (state: {:?})", inner.state));
         }
+        inner
+            .transport
+            .clone()
+            .ok_or_else(|| "Transport not available".to_string())
+    }
 
-        if let Some(ref mut child) = inner.child {
-            if let Some(ref mut stdin) = child.stdin.as_mut() {
-                writeln!(stdin, "{}", line).map_err(|e| format!("Write error: {}", e))?;
-                stdin.flush().map_err(|e| format!("Flush error: {}", e))?;
-                Ok(())
-            } else {
-                Err("Stdin not available".to_string())
-            }
-        } else {
-            Err("No child process".to_string())
-        }
+    /// Write a line to the sidecar.
+    pub fn write_line(&self, line: &str) -> Result<(), String> {
+        self.running_transport()?
+            .write_line(line)
+            .map_err(|e| format!("Write error: {}", e))
     }
 
-    /// Read a line from the sidecar's stdout.
-    /// Note: This blocks until a line is available.
+    /// Read a line from the sidecar.
+    /// Note: This blocks until a line is available. Lines over the 1 MiB
+    /// protocol limit are cut just past it so the RPC reader rejects them.
     pub fn read_line(&self) -> Result<String, String> {
-        let mut reader = {
-            let mut inner = self.inner.lock().unwrap();
-            if inner.state != SidecarState::Running {
-                return Err(format!("Sidecar not running (state: {:?})", inner.state));
-            }
-
-            inner.stdout_reader.take()
-        }
-        .ok_or_else(|| "Stdout not available".to_string())?;
-
-        let mut line = String::new();
-        let read_result = reader
+        let transport = self.running_transport()?;
+        let mut line = Vec::new();
+        let bytes_read = transport
             .read_line(&mut line)
-            .map_err(|e| format!("Read error: {}", e));
-
-        {
-            let mut inner = self.inner.lock().unwrap();
-            if inner.stdout_reader.is_none() {
-                inner.stdout_reader = Some(reader);
-            }
-        }
-
-        let bytes_read = read_result?;
+            .map_err(|e| format!("Read error: {}", e))?;
         if bytes_read == 0 {
-            return Err("Sidecar stdout closed".to_string());
+            return Err(format!("Sidecar {} closed", transport.name()));
         };
 
-        Ok(line.trim().to_string())
+        // Binary garbage becomes replacement characters and fails to parse.
+        Ok(decode_line(OutputChannel::Rpc, &line).trim().to_string())
     }
 
     /// Clone self for use in thread (without cloning app_handle).
@@ -895,6 +990,7 @@ impl SidecarManager {
             app_handle: self.app_handle.clone(),
             spawn_mode: self.spawn_mode.clone(),
             simulation_mode: self.simulation_mode,
+            transport_kind: self.transport_kind,
         }
     }
 
@@ -942,6 +1038,111 @@ fn sidecar_status_event_payload(status: &SidecarStatus) -> Value {
     crate::event_seq::payload_with_next_seq(payload)
 }
 
+/// Check that `path` is a supported Python that can import `module` and the
+/// sidecar's dependencies. The error names the problem and how to fix it.
+fn python_preflight(path: &str, module: &str) -> Result<(), String> {
+    let mut child = Command::new(path)
+        .arg("-c")
+        .arg(python_preflight_script(module))
+        .envs(network::sidecar_env())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| {
+            format!(
+                "Python interpreter '{}' could not be started ({}). Install Python {}.{} or newer, or point the sidecar at an existing interpreter.",
+                path, e, MIN_PYTHON_VERSION.0, MIN_PYTHON_VERSION.1
+            )
+        })?;
+
+    let deadline = Instant::now() + PYTHON_PREFLIGHT_TIMEOUT;
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break,
+            Ok(None) if Instant::now() < deadline => thread::sleep(Duration::from_millis(50)),
+            Ok(None) | Err(_) => {
+                // Leave the verdict to the real spawn rather than block startup.
+                let _ = child.kill();
+                let _ = child.wait();
+                log::warn!("Python sidecar preflight did not finish; skipping it");
+                return Ok(());
+            }
+        }
+    }
+
+    let mut output = String::new();
+    if let Some(mut stdout) = child.stdout.take() {
+        let _ = std::io::Read::read_to_string(&mut stdout, &mut output);
+    }
+    check_python_preflight_output(path, &output)
+}
+
+/// Script for `python -c`: prints the interpreter version, then a
+/// `missing <module>: <error>` line for every module that fails to import.
+fn python_preflight_script(module: &str) -> String {
+    let modules: Vec<String> = PYTHON_DEPENDENCIES
+        .iter()
+        .copied()
+        .chain(std::iter::once(module))
+        .map(|name| format!("{:?}", name))
+        .collect();
+    format!(
+        r#"import importlib, sys
+print("version %d.%d.%d" % tuple(sys.version_info[:3]))
+for name in [{}]:
+    try:
+        importlib.import_module(name)
+    except Exception as error:
+        print("missing %s: %s" % (name, (str(error).splitlines() or [type(error).__name__])[0]))
+"#,
+        modules.join(", ")
+    )
+}
+
+fn check_python_preflight_output(path: &str, output: &str) -> Result<(), String> {
+    let version = output
+        .lines()
+        .find_map(|line| line.strip_prefix("version "))
+        .ok_or_else(|| {
+            format!(
+                "{}: '{}' did not report a Python version. Point the sidecar at a Python {}.{} interpreter.",
+                INCOMPATIBLE_ENVIRONMENT, path, MIN_PYTHON_VERSION.0, MIN_PYTHON_VERSION.1
+            )
+        })?;
+    let mut parts = version
+        .split('.')
+        .map(|part| part.parse::<u32>().unwrap_or(0));
+    let found = (parts.next().unwrap_or(0), parts.next().unwrap_or(0));
+    if found < MIN_PYTHON_VERSION {
+        return Err(format!(
+            "{}: '{}' is Python {}, but the sidecar needs {}.{} or newer. Install a newer Python or point the sidecar at one.",
+            INCOMPATIBLE_ENVIRONMENT, path, version, MIN_PYTHON_VERSION.0, MIN_PYTHON_VERSION.1
+        ));
+    }
+
+    let missing: Vec<&str> = output
+        .lines()
+        .filter_map(|line| line.strip_prefix("missing "))
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    Err(format!(
+        "{}: '{}' cannot import {}. Install the sidecar with `pip install -e sidecar` from the repository root.",
+        INCOMPATIBLE_ENVIRONMENT,
+        path,
+        missing.join("; ")
+    ))
+}
+
+/// The reason in a spawn failure caused by an incompatible Python
+/// environment, or None for any other failure.
+pub fn incompatible_environment_reason(message: &str) -> Option<&str> {
+    let (_, reason) = message.split_once(INCOMPATIBLE_ENVIRONMENT)?;
+    Some(reason.trim_start_matches(':').trim())
+}
+
 impl Default for SidecarManager {
     fn default() -> Self {
         Self::new()
@@ -1109,6 +1310,43 @@ mod tests {
         assert!(!manager.is_bundled_mode());
     }
 
+    #[test]
+    fn test_python_preflight_output_checks_version_and_imports() {
+        assert_eq!(
+            check_python_preflight_output("python3", "version 3.12.1\n"),
+            Ok(())
+        );
+
+        let old = check_python_preflight_output("python3", "version 3.9.18\n").unwrap_err();
+        assert_eq!(
+            incompatible_environment_reason(&old),
+            Some(
+                "'python3' is Python 3.9.18, but the sidecar needs 3.11 or newer. Install a newer Python or point the sidecar at one."
+            )
+        );
+
+        let missing = check_python_preflight_output(
+            "python3",
+            "version 3.11.4\nmissing numpy: No module named 'numpy'\nmissing openvoicy_sidecar: No module named 'openvoicy_sidecar'\n",
+        )
+        .unwrap_err();
+        let reason = incompatible_environment_reason(&missing).unwrap();
+        assert!(reason.contains("cannot import numpy: No module named 'numpy'; openvoicy_sidecar:"));
+        assert!(reason.contains("pip install -e sidecar"));
+
+        assert!(check_python_preflight_output("python3", "").is_err());
+        assert_eq!(
+            incompatible_environment_reason("Failed to spawn: not found"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_python_preflight_script_imports_dependencies_then_module() {
+        let script = python_preflight_script("openvoicy_sidecar");
+        assert!(script.contains(r#"["numpy", "scipy", "sounddevice", "openvoicy_sidecar"]"#));
+    }
+
     #[test]
     fn test_set_bundled_mode() {
         let mut manager = SidecarManager::new();
@@ -1278,7 +1516,10 @@ mod tests {
         {
             let mut inner = manager.inner.lock().unwrap();
             inner.state = SidecarState::Running;
-            inner.stdout_reader = child.stdout.take().map(std::io::BufReader::new);
+            inner.transport = Some(Arc::new(transport::stdio(
+                child.stdin.take().expect("stdin is piped"),
+                child.stdout.take().expect("stdout is piped"),
+            )));
             inner.child = Some(child);
         }
 
@@ -1292,7 +1533,7 @@ mod tests {
             if let Some(mut child) = inner.child.take() {
                 let _ = child.wait();
             }
-            inner.stdout_reader = None;
+            inner.transport = None;
             inner.state = SidecarState::NotStarted;
         }
     }
@@ -0,0 +1,519 @@
+//! Update channel for the bundled Python sidecar.
+//!
+//! Release builds can pick up a newer sidecar build without reinstalling the
+//! app. A release manifest lists one artifact per target triple; an update is
+//! downloaded into `<config dir>/sidecar/<version>/`, verified against the
+//! manifest's SHA-256 and minisign signature, and activated by atomically
+//! rewriting `active.json`, which the spawn path consults before the bundled
+//! binary. A newly activated build stays pending until it first reaches
+//! Ready; if that first start fails, the previous build is restored.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use thiserror::Error;
+
+use crate::config;
+use crate::network::NetworkPurpose;
+use crate::release_download::{self, DownloadError, ReleaseArtifact};
+use crate::sidecar::SidecarManager;
+
+/// Sidecar update progress event name.
+pub const EVENT_SIDECAR_UPDATE_STATUS: &str = "sidecar:update_status";
+
+/// Release manifest; debug builds can point at another with
+/// `OPENVOICY_SIDECAR_UPDATE_URL`.
+const DEFAULT_MANIFEST_URL: &str =
+    "https://github.com/urgood2/translatorvoiceinputtool/releases/latest/download/sidecar-manifest.json";
+const MANIFEST_URL_ENV: &str = "OPENVOICY_SIDECAR_UPDATE_URL";
+
+/// Update directory inside the config directory.
+const UPDATE_DIR_NAME: &str = "sidecar";
+const ACTIVE_FILE_NAME: &str = "active.json";
+
+/// Version of the sidecar shipped inside the app bundle.
+const BUNDLED_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+static STORE: Lazy<SidecarUpdateStore> =
+    Lazy::new(|| SidecarUpdateStore::new(config::config_dir().join(UPDATE_DIR_NAME)));
+
+/// Sidecar update errors.
+#[derive(Debug, Error)]
+pub enum SidecarUpdateError {
+    #[error("Sidecar updates are only available in release builds")]
+    Unsupported,
+
+    #[error("Invalid sidecar release manifest: {0}")]
+    InvalidManifest(String),
+
+    #[error("No sidecar build for {0} in the release manifest")]
+    NoArtifact(String),
+
+    #[error(transparent)]
+    Download(#[from] DownloadError),
+
+    #[error("Sidecar update I/O error: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// Release manifest published alongside sidecar builds.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SidecarReleaseManifest {
+    pub version: String,
+    #[serde(default)]
+    pub notes: Option<String>,
+    /// Artifacts keyed by Rust target triple.
+    pub artifacts: BTreeMap<String, ReleaseArtifact>,
+}
+
+/// Result of `check_sidecar_update`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SidecarUpdateInfo {
+    pub current_version: String,
+    pub latest_version: String,
+    pub update_available: bool,
+    pub notes: Option<String>,
+}
+
+/// Phase reported through `sidecar:update_status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SidecarUpdatePhase {
+    Checking,
+    Downloading,
+    Verifying,
+    Installing,
+    Restarting,
+    Ready,
+    RolledBack,
+    Failed,
+}
+
+/// An installed sidecar update.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstalledSidecar {
+    pub version: String,
+    pub path: PathBuf,
+}
+
+/// Contents of `active.json`; no active entry means the bundled sidecar.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActiveSidecarState {
+    #[serde(default)]
+    pub active: Option<InstalledSidecar>,
+    /// Build to restore if the active one fails its first start.
+    #[serde(default)]
+    pub previous: Option<InstalledSidecar>,
+    /// The active build has not reached Ready yet.
+    #[serde(default)]
+    pub pending: bool,
+}
+
+/// Installed updates and the active-build pointer under one directory.
+pub struct SidecarUpdateStore {
+    root: PathBuf,
+}
+
+impl SidecarUpdateStore {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    fn state_path(&self) -> PathBuf {
+        self.root.join(ACTIVE_FILE_NAME)
+    }
+
+    pub fn load(&self) -> ActiveSidecarState {
+        match fs::read_to_string(self.state_path()) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|error| {
+                log::warn!("Ignoring unreadable sidecar update state: {}", error);
+                ActiveSidecarState::default()
+            }),
+            Err(_) => ActiveSidecarState::default(),
+        }
+    }
+
+    /// Write the state through a temp file so a crash never leaves it torn.
+    fn save(&self, state: &ActiveSidecarState) -> io::Result<()> {
+        fs::create_dir_all(&self.root)?;
+        let path = self.state_path();
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, serde_json::to_string_pretty(state)?)?;
+        fs::rename(&temp_path, &path)
+    }
+
+    /// Version of the sidecar that will be spawned.
+    pub fn current_version(&self) -> String {
+        self.load()
+            .active
+            .map(|installed| installed.version)
+            .unwrap_or_else(|| BUNDLED_VERSION.to_string())
+    }
+
+    /// Path of the active update, if one is installed and still on disk.
+    pub fn active_path(&self) -> Option<PathBuf> {
+        self.load()
+            .active
+            .map(|installed| installed.path)
+            .filter(|path| path.is_file())
+    }
+
+    /// Directory a version is installed into.
+    fn version_dir(&self, version: &str) -> PathBuf {
+        self.root.join(version)
+    }
+
+    /// Move a verified download into place and return the installed build.
+    pub fn install(
+        &self,
+        version: &str,
+        downloaded: &Path,
+        binary_name: &str,
+    ) -> Result<InstalledSidecar, SidecarUpdateError> {
+        let dir = self.version_dir(version);
+        fs::create_dir_all(&dir)?;
+        let path = dir.join(binary_name);
+        fs::rename(downloaded, &path)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+        }
+
+        Ok(InstalledSidecar {
+            version: version.to_string(),
+            path,
+        })
+    }
+
+    /// Point the spawn path at `installed`, keeping the current build for rollback.
+    pub fn activate(&self, installed: InstalledSidecar) -> io::Result<()> {
+        let mut state = self.load();
+        // A pending build never proved itself, so it is not a rollback target.
+        let previous = if state.pending {
+            state.previous.take()
+        } else {
+            state.active.take()
+        };
+        self.save(&ActiveSidecarState {
+            active: Some(installed),
+            previous,
+            pending: true,
+        })
+    }
+
+    /// Mark a pending build as good after it reached Ready.
+    ///
+    /// Returns the confirmed version, or None when nothing was pending.
+    pub fn confirm_pending(&self) -> io::Result<Option<String>> {
+        let mut state = self.load();
+        if !state.pending {
+            return Ok(None);
+        }
+        state.pending = false;
+        self.save(&state)?;
+        Ok(state.active.map(|installed| installed.version))
+    }
+
+    /// Restore the previous build after a pending one failed to start.
+    ///
+    /// Returns the version that was rolled back, or None when nothing was pending.
+    pub fn rollback_pending(&self) -> io::Result<Option<String>> {
+        let state = self.load();
+        if !state.pending {
+            return Ok(None);
+        }
+        let failed = state.active.map(|installed| installed.version);
+        self.save(&ActiveSidecarState {
+            active: state.previous,
+            previous: None,
+            pending: false,
+        })?;
+        Ok(failed)
+    }
+}
+
+/// Process-wide update store under the config directory.
+pub fn store() -> &'static SidecarUpdateStore {
+    &STORE
+}
+
+/// Spawn path of the active update, if any; the bundled binary otherwise.
+pub fn active_sidecar_path() -> Option<PathBuf> {
+    // Keep unit tests from reading the real config directory.
+    if cfg!(test) {
+        return None;
+    }
+    store().active_path()
+}
+
+/// Payload for a `sidecar:update_status` event.
+pub fn update_status_payload(
+    phase: SidecarUpdatePhase,
+    version: &str,
+    message: Option<&str>,
+) -> Value {
+    let mut payload = json!({
+        "phase": phase,
+        "version": version,
+    });
+    if let Some(message) = message {
+        payload["message"] = json!(message);
+    }
+    payload
+}
+
+/// Confirm a pending update after the sidecar reached Ready.
+pub fn confirm_pending_update() {
+    if cfg!(test) {
+        return;
+    }
+    match store().confirm_pending() {
+        Ok(Some(version)) => log::info!("Sidecar update {} confirmed", version),
+        Ok(None) => {}
+        Err(error) => log::warn!("Failed to confirm sidecar update: {}", error),
+    }
+}
+
+/// Roll back a pending update after the sidecar failed to start.
+///
+/// Returns the version that was rolled back.
+pub fn rollback_pending_update() -> Option<String> {
+    if cfg!(test) {
+        return None;
+    }
+    match store().rollback_pending() {
+        Ok(rolled_back) => rolled_back,
+        Err(error) => {
+            log::warn!("Failed to roll back sidecar update: {}", error);
+            None
+        }
+    }
+}
+
+/// URL of the release manifest.
+pub fn manifest_url() -> String {
+    env::var(MANIFEST_URL_ENV)
+        .ok()
+        .filter(|url| cfg!(debug_assertions) && !url.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_MANIFEST_URL.to_string())
+}
+
+/// Fetch and parse the release manifest.
+pub fn fetch_manifest() -> Result<SidecarReleaseManifest, SidecarUpdateError> {
+    parse_manifest(&release_download::fetch_bytes(
+        &manifest_url(),
+        "sidecar release manifest",
+        NetworkPurpose::SidecarUpdate,
+    )?)
+}
+
+fn parse_manifest(bytes: &[u8]) -> Result<SidecarReleaseManifest, SidecarUpdateError> {
+    let manifest: SidecarReleaseManifest = serde_json::from_slice(bytes)
+        .map_err(|e| SidecarUpdateError::InvalidManifest(e.to_string()))?;
+    if release_download::parse_version(&manifest.version).is_none() {
+        return Err(SidecarUpdateError::InvalidManifest(format!(
+            "unparseable version '{}'",
+            manifest.version
+        )));
+    }
+    if !is_path_safe_version(&manifest.version) {
+        return Err(SidecarUpdateError::InvalidManifest(format!(
+            "version '{}' is not usable as a directory name",
+            manifest.version
+        )));
+    }
+    Ok(manifest)
+}
+
+/// The version names the build's directory under the update dir, so it
+/// must not carry path separators or `..`.
+fn is_path_safe_version(version: &str) -> bool {
+    !version.contains("..")
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-'))
+}
+
+/// Compare the manifest against the running sidecar version.
+pub fn check_manifest(
+    manifest: &SidecarReleaseManifest,
+    current_version: &str,
+) -> SidecarUpdateInfo {
+    SidecarUpdateInfo {
+        current_version: current_version.to_string(),
+        latest_version: manifest.version.clone(),
+        update_available: release_download::is_newer(&manifest.version, current_version),
+        notes: manifest.notes.clone(),
+    }
+}
+
+/// The manifest artifact for a target triple.
+pub fn select_artifact<'a>(
+    manifest: &'a SidecarReleaseManifest,
+    target: &str,
+) -> Result<&'a ReleaseArtifact, SidecarUpdateError> {
+    manifest
+        .artifacts
+        .get(target)
+        .ok_or_else(|| SidecarUpdateError::NoArtifact(target.to_string()))
+}
+
+/// Download, verify, and activate the manifest's build for this platform.
+///
+/// `on_phase` is called as each step starts. The new build is pending until
+/// the caller restarts the sidecar and it reaches Ready.
+pub fn download_and_activate(
+    manifest: &SidecarReleaseManifest,
+    on_phase: impl Fn(SidecarUpdatePhase),
+) -> Result<InstalledSidecar, SidecarUpdateError> {
+    if cfg!(debug_assertions) {
+        return Err(SidecarUpdateError::Unsupported);
+    }
+
+    let target = SidecarManager::get_target_triple();
+    let artifact = select_artifact(manifest, &target)?;
+    let binary_name = SidecarManager::get_sidecar_binary_name();
+    let store = store();
+    let dir = store.version_dir(&manifest.version);
+    fs::create_dir_all(&dir)?;
+    let partial = dir.join(format!("{binary_name}.partial"));
+
+    on_phase(SidecarUpdatePhase::Downloading);
+    release_download::download_artifact(
+        artifact,
+        &partial,
+        "sidecar build",
+        NetworkPurpose::SidecarUpdate,
+        |_| {},
+    )?;
+
+    on_phase(SidecarUpdatePhase::Verifying);
+    let signature = artifact.signature.as_deref();
+    let verified = release_download::verify_artifact(&partial, artifact, "sidecar build")
+        .and_then(|()| release_download::verify_signature(&partial, signature, "sidecar build"));
+    if let Err(error) = verified {
+        let _ = fs::remove_file(&partial);
+        return Err(error.into());
+    }
+
+    on_phase(SidecarUpdatePhase::Installing);
+    let installed = store.install(&manifest.version, &partial, &binary_name)?;
+    store.activate(installed.clone())?;
+    log::info!(
+        "Activated sidecar {} at {:?} (pending first start)",
+        installed.version,
+        installed.path
+    );
+    Ok(installed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn manifest(version: &str) -> SidecarReleaseManifest {
+        parse_manifest(
+            format!(
+                r#"{{"version": "{version}", "artifacts": {{
+                    "x86_64-unknown-linux-gnu": {{"url": "https://example.com/sc", "sha256": "ab"}}
+                }}}}"#
+            )
+            .as_bytes(),
+        )
+        .unwrap()
+    }
+
+    fn installed(version: &str) -> InstalledSidecar {
+        InstalledSidecar {
+            version: version.to_string(),
+            path: PathBuf::from(format!("/tmp/{version}/openvoicy-sidecar")),
+        }
+    }
+
+    #[test]
+    fn test_manifest_parsing_and_artifact_selection() {
+        let manifest = manifest("0.2.0");
+        assert!(select_artifact(&manifest, "x86_64-unknown-linux-gnu").is_ok());
+        assert!(matches!(
+            select_artifact(&manifest, "aarch64-apple-darwin"),
+            Err(SidecarUpdateError::NoArtifact(_))
+        ));
+        assert!(check_manifest(&manifest, "0.1.0").update_available);
+
+        assert!(matches!(
+            parse_manifest(br#"{"version": "next", "artifacts": {}}"#),
+            Err(SidecarUpdateError::InvalidManifest(_))
+        ));
+        for version in ["1.0.0-/../../x", "1.0.0-..", "1.0.0+a\\b"] {
+            let bytes = json!({ "version": version, "artifacts": {} }).to_string();
+            assert!(
+                matches!(
+                    parse_manifest(bytes.as_bytes()),
+                    Err(SidecarUpdateError::InvalidManifest(_))
+                ),
+                "{version}"
+            );
+        }
+        assert!(parse_manifest(br#"{"version": "1.0.0-rc.1+build.5", "artifacts": {}}"#).is_ok());
+    }
+
+    #[test]
+    fn test_activate_confirm_and_rollback() {
+        let dir = tempdir().unwrap();
+        let store = SidecarUpdateStore::new(dir.path().to_path_buf());
+        assert_eq!(store.current_version(), BUNDLED_VERSION);
+
+        store.activate(installed("0.2.0")).unwrap();
+        assert_eq!(store.confirm_pending().unwrap(), Some("0.2.0".to_string()));
+        assert_eq!(store.confirm_pending().unwrap(), None);
+
+        store.activate(installed("0.3.0")).unwrap();
+        assert_eq!(store.current_version(), "0.3.0");
+        assert_eq!(store.rollback_pending().unwrap(), Some("0.3.0".to_string()));
+        assert_eq!(store.current_version(), "0.2.0");
+        assert_eq!(store.rollback_pending().unwrap(), None);
+    }
+
+    #[test]
+    fn test_failed_pending_build_is_not_a_rollback_target() {
+        let dir = tempdir().unwrap();
+        let store = SidecarUpdateStore::new(dir.path().to_path_buf());
+
+        store.activate(installed("0.2.0")).unwrap();
+        store.activate(installed("0.3.0")).unwrap();
+        store.rollback_pending().unwrap();
+        // 0.2.0 never reached Ready, so the bundled build is restored.
+        assert_eq!(store.load().active, None);
+        assert_eq!(store.current_version(), BUNDLED_VERSION);
+    }
+
+    #[test]
+    fn test_install_moves_download_into_version_dir() {
+        let dir = tempdir().unwrap();
+        let store = SidecarUpdateStore::new(dir.path().to_path_buf());
+        let download = dir.path().join("download.partial");
+        fs::write(&download, b"bin").unwrap();
+
+        let installed = store
+            .install("0.2.0", &download, "openvoicy-sidecar")
+            .unwrap();
+        assert_eq!(
+            installed.path,
+            dir.path().join("0.2.0").join("openvoicy-sidecar")
+        );
+        assert!(installed.path.is_file());
+        assert!(!download.exists());
+
+        store.activate(installed.clone()).unwrap();
+        assert_eq!(store.active_path(), Some(installed.path));
+    }
+}
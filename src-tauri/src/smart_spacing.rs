@@ -0,0 +1,167 @@
+//! Context-aware spacing for text injected next to existing text.
+//!
+//! When `injection.smart_spacing` is enabled, the text just before the caret
+//! in the focused field is read through the platform accessibility API
+//! (AX on macOS, UI Automation on Windows). A space is prepended when the
+//! dictation would otherwise run into the previous word, and the first
+//! letter is capitalized at the start of a field or sentence. When the
+//! target cannot be introspected (Linux, fields without accessibility
+//! support, permission denied), the text is injected unchanged and only
+//! the static `suffix` applies.
+
+/// How much text before the caret is read; only the tail matters.
+#[cfg(any(target_os = "macos", target_os = "windows", test))]
+const CONTEXT_CHARS: usize = 64;
+
+/// Marks the end of introspection output so trailing whitespace survives.
+#[cfg(any(target_os = "macos", target_os = "windows", test))]
+const END_MARKER: &str = "<<END>>";
+
+/// Characters after which no separating space is needed.
+const OPENING_CHARS: &[char] = &['(', '[', '{', '\u{201C}', '\u{2018}', '/'];
+
+/// Characters that attach to the preceding word.
+const ATTACHING_CHARS: &[char] = &[',', '.', '!', '?', ';', ':', ')', ']', '}', '%'];
+
+const SENTENCE_END_CHARS: &[char] = &['.', '!', '?'];
+
+/// Adjust `text` to read naturally after `before_caret`.
+pub fn apply(text: &str, before_caret: &str) -> String {
+    let Some(first) = text.chars().next() else {
+        return String::new();
+    };
+
+    let mut adjusted = String::with_capacity(text.len() + 1);
+    let needs_space = before_caret.chars().next_back().is_some_and(|last| {
+        !last.is_whitespace() && !OPENING_CHARS.contains(&last) && !first.is_whitespace()
+    }) && !ATTACHING_CHARS.contains(&first);
+    if needs_space {
+        adjusted.push(' ');
+    }
+
+    let starts_sentence = before_caret
+        .trim_end()
+        .chars()
+        .next_back()
+        .is_none_or(|last| SENTENCE_END_CHARS.contains(&last));
+    if starts_sentence && first.is_lowercase() {
+        adjusted.extend(first.to_uppercase());
+        adjusted.push_str(&text[first.len_utf8()..]);
+    } else {
+        adjusted.push_str(text);
+    }
+    adjusted
+}
+
+/// Text just before the caret in the focused field, if the platform exposes it.
+///
+/// Returns at most 64 characters. `None` means the target
+/// could not be introspected; `Some("")` means the caret is at the start.
+pub fn text_before_caret() -> Option<String> {
+    #[cfg(target_os = "macos")]
+    {
+        text_before_caret_macos()
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        text_before_caret_windows()
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        None
+    }
+}
+
+/// Extract the context from introspection output ending in [`END_MARKER`].
+#[cfg(any(target_os = "macos", target_os = "windows", test))]
+fn parse_marked_output(stdout: &str) -> Option<String> {
+    let text = stdout
+        .trim_end_matches(['\r', '\n'])
+        .strip_suffix(END_MARKER)?;
+    let skip = text.chars().count().saturating_sub(CONTEXT_CHARS);
+    Some(text.chars().skip(skip).collect())
+}
+
+#[cfg(target_os = "macos")]
+fn text_before_caret_macos() -> Option<String> {
+    use std::process::Command;
+
+    let script = format!(
+        r#"tell application "System Events"
+    set focusedElement to value of attribute "AXFocusedUIElement" of (first application process whose frontmost is true)
+    set fieldValue to value of attribute "AXValue" of focusedElement
+    set caretRange to value of attribute "AXSelectedTextRange" of focusedElement
+end tell
+set caretStart to item 1 of caretRange
+if caretStart > 1 then
+    set contextStart to caretStart - {limit}
+    if contextStart < 1 then set contextStart to 1
+    return (text contextStart thru (caretStart - 1) of fieldValue) & "{marker}"
+end if
+return "{marker}""#,
+        limit = CONTEXT_CHARS,
+        marker = END_MARKER,
+    );
+    let output = Command::new("osascript")
+        .args(["-e", &script])
+        .output()
+        .ok()
+        .filter(|result| result.status.success())?;
+    parse_marked_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(target_os = "windows")]
+fn text_before_caret_windows() -> Option<String> {
+    use std::process::Command;
+
+    let script = format!(
+        r#"$ErrorActionPreference='Stop'; Add-Type -AssemblyName UIAutomationClient, UIAutomationTypes; $e=[System.Windows.Automation.AutomationElement]::FocusedElement; $p=$e.GetCurrentPattern([System.Windows.Automation.TextPattern]::Pattern); $sel=$p.GetSelection()[0]; $r=$p.DocumentRange.Clone(); $r.MoveEndpointByRange([System.Windows.Automation.Text.TextPatternRangeEndpoint]::End, $sel, [System.Windows.Automation.Text.TextPatternRangeEndpoint]::Start); $t=$r.GetText(-1); if ($t.Length -gt {limit}) {{ $t=$t.Substring($t.Length - {limit}) }}; [Console]::Out.Write($t + '{marker}')"#,
+        limit = CONTEXT_CHARS,
+        marker = END_MARKER,
+    );
+    let output = Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", &script])
+        .output()
+        .ok()
+        .filter(|result| result.status.success())?;
+    parse_marked_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_prepends_space_after_word() {
+        assert_eq!(apply("world", "hello"), " world");
+        assert_eq!(apply("world", "hello "), "world");
+        assert_eq!(apply("world", "hello\n"), "world");
+        assert_eq!(apply("note", "see ("), "note");
+        assert_eq!(apply(", and more", "first"), ", and more");
+    }
+
+    #[test]
+    fn test_apply_capitalizes_sentence_start() {
+        assert_eq!(apply("hello there", ""), "Hello there");
+        assert_eq!(apply("next one", "Done."), " Next one");
+        assert_eq!(apply("next one", "Done? "), "Next one");
+        assert_eq!(apply("and more", "first, "), "and more");
+        assert_eq!(apply("élan", ""), "Élan");
+        assert_eq!(apply("", "anything"), "");
+    }
+
+    #[test]
+    fn test_parse_marked_output_keeps_trailing_whitespace() {
+        assert_eq!(
+            parse_marked_output("Hello world. <<END>>\n").as_deref(),
+            Some("Hello world. ")
+        );
+        assert_eq!(parse_marked_output("<<END>>").as_deref(), Some(""));
+        assert_eq!(parse_marked_output("execution error"), None);
+
+        let long = format!("{}<<END>>", "x".repeat(100));
+        assert_eq!(parse_marked_output(&long).unwrap().len(), CONTEXT_CHARS);
+    }
+}
@@ -0,0 +1,436 @@
+//! Fatal startup failures.
+//!
+//! When the app cannot start, the failure is classified, written to a crash
+//! report in `crash-reports/` under the config directory, shown in a native
+//! error dialog, and the process exits with a code per category so packaging
+//! and diagnostic tooling can tell failures apart without parsing logs.
+//!
+//! Exit codes follow `sysexits.h`:
+//!
+//! | Category                 | Code |
+//! |--------------------------|------|
+//! | config corrupt           | 78   |
+//! | webview missing          | 69   |
+//! | single-instance conflict | 75   |
+//! | anything else            | 70   |
+//!
+//! Dialogs are shown by shelling out (`osascript`, PowerShell, `zenity` or
+//! `kdialog`) because the webview may be the thing that failed.
+
+use std::fs;
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use chrono::Utc;
+use serde::Serialize;
+use serde_json::json;
+
+use crate::config;
+use crate::log_buffer;
+
+/// Crash report directory inside the config directory.
+const CRASH_REPORT_DIR_NAME: &str = "crash-reports";
+
+/// Lock file holding the pid of the running instance.
+const INSTANCE_LOCK_FILE_NAME: &str = "instance.lock";
+
+/// Log lines included in a crash report.
+const CRASH_REPORT_LOG_LINES: usize = 50;
+
+/// Category of a fatal startup failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StartupFailureKind {
+    /// The config file exists but cannot be read.
+    ConfigCorrupt,
+    /// The system webview (WebView2, WebKitGTK) is missing or failed to load.
+    WebviewMissing,
+    /// Another instance of the app is already running.
+    SingleInstanceConflict,
+    Other,
+}
+
+impl StartupFailureKind {
+    /// Process exit code for this category.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Self::ConfigCorrupt => 78,
+            Self::WebviewMissing => 69,
+            Self::SingleInstanceConflict => 75,
+            Self::Other => 70,
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::ConfigCorrupt => "Settings Cannot Be Read",
+            Self::WebviewMissing => "System Web View Missing",
+            Self::SingleInstanceConflict => "Already Running",
+            Self::Other => "Voice Input Tool Failed to Start",
+        }
+    }
+
+    fn remediation(self) -> &'static str {
+        match self {
+            Self::ConfigCorrupt => {
+                "Check the permissions of the settings file, or move it aside to start with defaults."
+            }
+            Self::WebviewMissing => {
+                "Install the system web view (Microsoft Edge WebView2 on Windows, WebKitGTK on Linux) and try again."
+            }
+            Self::SingleInstanceConflict => {
+                "Voice Input Tool is already running. Use the tray icon to open it."
+            }
+            Self::Other => "Try starting the app again. If it keeps failing, attach the crash report to a bug report.",
+        }
+    }
+}
+
+/// A fatal startup failure and what caused it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StartupFailure {
+    pub kind: StartupFailureKind,
+    pub detail: String,
+}
+
+impl StartupFailure {
+    pub fn new(kind: StartupFailureKind, detail: impl Into<String>) -> Self {
+        Self {
+            kind,
+            detail: detail.into(),
+        }
+    }
+
+    /// Classify an error returned by the Tauri runtime.
+    pub fn classify(error: &impl std::fmt::Display) -> Self {
+        let detail = error.to_string();
+        let lower = detail.to_lowercase();
+        let kind = if ["webview", "webkit", "wry"]
+            .iter()
+            .any(|needle| lower.contains(needle))
+        {
+            StartupFailureKind::WebviewMissing
+        } else {
+            StartupFailureKind::Other
+        };
+        Self::new(kind, detail)
+    }
+}
+
+/// Fail when the config file exists but cannot be read. Unparseable contents
+/// are not fatal: loading moves them aside and falls back to defaults.
+pub fn check_config_file(path: &Path) -> Result<(), StartupFailure> {
+    match fs::read(path) {
+        Ok(_) => Ok(()),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(StartupFailure::new(
+            StartupFailureKind::ConfigCorrupt,
+            format!("cannot read {}: {}", path.display(), error),
+        )),
+    }
+}
+
+/// Claim the instance lock in `dir`, failing when the pid in it belongs to
+/// another running copy of this app. A lock left by a crashed instance is
+/// taken over.
+pub fn acquire_instance_lock(dir: &Path) -> Result<(), StartupFailure> {
+    let path = dir.join(INSTANCE_LOCK_FILE_NAME);
+    let own_pid = std::process::id();
+    let lock_error = |error: io::Error| -> Result<(), StartupFailure> {
+        // Not being able to write the lock must not keep the app from starting.
+        log::warn!(
+            "Failed to write instance lock {}: {}",
+            path.display(),
+            error
+        );
+        Ok(())
+    };
+
+    if let Err(error) = fs::create_dir_all(dir) {
+        return lock_error(error);
+    }
+    match fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+    {
+        Ok(mut file) => return write!(file, "{}", own_pid).or_else(lock_error),
+        Err(error) if error.kind() != ErrorKind::AlreadyExists => return lock_error(error),
+        Err(_) => {}
+    }
+
+    let holder = fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| contents.trim().parse::<u32>().ok());
+    if let Some(pid) = holder.filter(|pid| *pid != own_pid && is_running_instance(*pid)) {
+        return Err(StartupFailure::new(
+            StartupFailureKind::SingleInstanceConflict,
+            format!("another instance is running (pid {})", pid),
+        ));
+    }
+    fs::write(&path, own_pid.to_string()).or_else(lock_error)
+}
+
+/// Whether `pid` is a live process running the same executable as this one.
+fn is_running_instance(pid: u32) -> bool {
+    let Some(exe_name) = std::env::current_exe().ok().and_then(|exe| {
+        exe.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+    }) else {
+        return false;
+    };
+
+    #[cfg(target_os = "linux")]
+    {
+        fs::read_link(format!("/proc/{}/exe", pid))
+            .ok()
+            .and_then(|exe| {
+                exe.file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+            })
+            .is_some_and(|name| name == exe_name)
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Command::new("ps")
+            .args(["-p", &pid.to_string(), "-o", "comm="])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .is_some_and(|output| {
+                String::from_utf8_lossy(&output.stdout)
+                    .trim()
+                    .ends_with(&exe_name)
+            })
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {}", pid), "/FO", "CSV", "/NH"])
+            .output()
+            .ok()
+            .is_some_and(|output| {
+                String::from_utf8_lossy(&output.stdout)
+                    .to_lowercase()
+                    .contains(&format!("\"{}\"", exe_name.to_lowercase()))
+            })
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    {
+        let _ = (pid, exe_name);
+        false
+    }
+}
+
+/// Report `failure` and exit the process with its category's exit code.
+pub fn handle(failure: StartupFailure) -> ! {
+    log::error!("Startup failed ({:?}): {}", failure.kind, failure.detail);
+    let report_path =
+        write_crash_report(&failure, &config::config_dir().join(CRASH_REPORT_DIR_NAME))
+            .or_else(|_| write_crash_report(&failure, &std::env::temp_dir()));
+    let report_path = match report_path {
+        Ok(path) => {
+            log::error!("Crash report written to {}", path.display());
+            Some(path)
+        }
+        Err(error) => {
+            log::error!("Failed to write crash report: {}", error);
+            None
+        }
+    };
+    show_error_dialog(
+        failure.kind.title(),
+        &dialog_message(&failure, report_path.as_deref()),
+    );
+    std::process::exit(failure.kind.exit_code());
+}
+
+/// Write a JSON crash report for `failure` into `dir` and return its path.
+fn write_crash_report(failure: &StartupFailure, dir: &Path) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let now = Utc::now();
+    let path = dir.join(format!("startup-{}.json", now.format("%Y%m%dT%H%M%S%.3fZ")));
+    let recent_logs: Vec<String> = log_buffer::global_buffer()
+        .entries()
+        .iter()
+        .rev()
+        .take(CRASH_REPORT_LOG_LINES)
+        .rev()
+        .map(|entry| entry.format_line())
+        .collect();
+    let report = json!({
+        "kind": failure.kind,
+        "exit_code": failure.kind.exit_code(),
+        "detail": failure.detail,
+        "app_version": env!("CARGO_PKG_VERSION"),
+        "os": std::env::consts::OS,
+        "arch": std::env::consts::ARCH,
+        "timestamp": now,
+        "recent_logs": recent_logs,
+    });
+    fs::write(&path, serde_json::to_string_pretty(&report)?)?;
+    Ok(path)
+}
+
+fn dialog_message(failure: &StartupFailure, report_path: Option<&Path>) -> String {
+    let mut message = format!("{}\n\n{}", failure.kind.remediation(), failure.detail);
+    if let Some(path) = report_path {
+        message.push_str(&format!("\n\nCrash report: {}", path.display()));
+    }
+    message
+}
+
+/// Show a blocking native error dialog; failures to show it are only logged.
+fn show_error_dialog(title: &str, message: &str) {
+    let shown = dialog_commands(title, message)
+        .into_iter()
+        .any(|(program, args)| {
+            Command::new(program)
+                .args(&args)
+                .status()
+                .is_ok_and(|status| status.success())
+        });
+    if !shown {
+        log::warn!("No native dialog available to show startup failure");
+    }
+}
+
+/// Commands that can show the dialog on this platform, in order of preference.
+fn dialog_commands(title: &str, message: &str) -> Vec<(&'static str, Vec<String>)> {
+    #[cfg(target_os = "macos")]
+    {
+        let quote = |text: &str| text.replace('\\', "\\\\").replace('"', "\\\"");
+        vec![(
+            "osascript",
+            vec![
+                "-e".to_string(),
+                format!(
+                    "display alert \"{}\" message \"{}\" as critical",
+                    quote(title),
+                    quote(message)
+                ),
+            ],
+        )]
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let quote = |text: &str| text.replace('\'', "''");
+        vec![(
+            "powershell",
+            vec![
+                "-NoProfile".to_string(),
+                "-Command".to_string(),
+                format!(
+                    "Add-Type -AssemblyName PresentationFramework; [System.Windows.MessageBox]::Show('{}', '{}', 'OK', 'Error') | Out-Null",
+                    quote(message),
+                    quote(title)
+                ),
+            ],
+        )]
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        vec![
+            (
+                "zenity",
+                vec![
+                    "--error".to_string(),
+                    "--no-markup".to_string(),
+                    format!("--title={}", title),
+                    format!("--text={}", message),
+                ],
+            ),
+            (
+                "kdialog",
+                vec![
+                    "--title".to_string(),
+                    title.to_string(),
+                    "--error".to_string(),
+                    message.to_string(),
+                ],
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_classify_and_exit_codes() {
+        let webview = StartupFailure::classify(&"failed to create webview: WebView2 not found");
+        assert_eq!(webview.kind, StartupFailureKind::WebviewMissing);
+        assert_eq!(
+            StartupFailure::classify(&"error encountered during setup hook: boom").kind,
+            StartupFailureKind::Other
+        );
+
+        let codes = [
+            StartupFailureKind::ConfigCorrupt,
+            StartupFailureKind::WebviewMissing,
+            StartupFailureKind::SingleInstanceConflict,
+            StartupFailureKind::Other,
+        ]
+        .map(StartupFailureKind::exit_code);
+        for (i, code) in codes.iter().enumerate() {
+            assert_ne!(*code, 0);
+            assert!(!codes[i + 1..].contains(code));
+        }
+    }
+
+    #[test]
+    fn test_check_config_file() {
+        let dir = tempdir().unwrap();
+        assert!(check_config_file(&dir.path().join("config.json")).is_ok());
+
+        // A directory in place of the file cannot be read.
+        let failure = check_config_file(dir.path()).unwrap_err();
+        assert_eq!(failure.kind, StartupFailureKind::ConfigCorrupt);
+    }
+
+    #[test]
+    fn test_instance_lock_takes_over_stale_lock() {
+        let dir = tempdir().unwrap();
+        acquire_instance_lock(dir.path()).unwrap();
+        let lock_path = dir.path().join(INSTANCE_LOCK_FILE_NAME);
+        assert_eq!(
+            fs::read_to_string(&lock_path).unwrap(),
+            std::process::id().to_string()
+        );
+
+        // Our own pid and a pid that is not running do not conflict.
+        acquire_instance_lock(dir.path()).unwrap();
+        fs::write(&lock_path, u32::MAX.to_string()).unwrap();
+        acquire_instance_lock(dir.path()).unwrap();
+        assert_eq!(
+            fs::read_to_string(&lock_path).unwrap(),
+            std::process::id().to_string()
+        );
+    }
+
+    #[test]
+    fn test_crash_report_contents() {
+        let dir = tempdir().unwrap();
+        let failure = StartupFailure::new(StartupFailureKind::SingleInstanceConflict, "pid 42");
+        let path = write_crash_report(&failure, dir.path()).unwrap();
+
+        let report: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(report["kind"], "single_instance_conflict");
+        assert_eq!(report["exit_code"], 75);
+        assert_eq!(report["detail"], "pid 42");
+        assert!(report["recent_logs"].is_array());
+
+        let message = dialog_message(&failure, Some(Path::new("/tmp/report.json")));
+        assert!(message.contains("already running"));
+        assert!(message.ends_with("Crash report: /tmp/report.json"));
+    }
+}
@@ -31,20 +31,24 @@
 //!              │                  ┌─────────┐  │
 //!              └─────────────────►│  Error  │◄─┘
 //!                                 └────┬────┘
-//!                                      │ retry
+//!                                      │ retry / auto-recovery
 //!                                      ▼
 //!                                    Idle
 //! ```
+//!
+//! Errors carry an [`ErrorCategory`]. Recoverable ones are revalidated in
+//! the background and left for Idle once their checks pass (see
+//! [`crate::error_recovery`]).
 
 use chrono::{DateTime, Utc};
-use serde::Serialize;
-use std::sync::atomic::{AtomicBool, Ordering};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::RwLock;
 use thiserror::Error;
 use tokio::sync::broadcast;
 
 /// Application state values.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AppState {
     /// Ready to record (default state).
@@ -60,13 +64,106 @@ pub enum AppState {
     Error,
 }
 
+/// What put the app into the Error state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCategory {
+    /// The sidecar hung, crashed or stopped responding.
+    Sidecar,
+    /// No usable audio input device.
+    AudioDevice,
+    /// The model failed to download or load.
+    Model,
+    /// A transcription failed or timed out.
+    Transcription,
+    /// Anything that needs the user to act.
+    Other,
+}
+
+impl ErrorCategory {
+    /// Whether errors of this category may clear themselves once
+    /// revalidation passes.
+    pub fn auto_recoverable(self) -> bool {
+        self != ErrorCategory::Other
+    }
+}
+
+/// The Error state currently entered, as told apart from earlier ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ErrorStamp {
+    /// Counts every `transition_to_error`.
+    pub generation: u64,
+    pub category: ErrorCategory,
+}
+
+/// How far dictation is paused.
+///
+/// `toggle_enabled` switches between `Enabled` and `FullyPaused`; the other
+/// levels are set explicitly through `set_enable_level` or the tray.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnableLevel {
+    /// Hotkeys, tray and overlay all start recordings.
+    #[default]
+    Enabled,
+    /// Hotkeys are ignored; the tray and overlay can still record.
+    HotkeysPaused,
+    /// Nothing records and the sidecar unloads its model until resumed.
+    FullyPaused,
+    /// Recording works but shows no overlay, tray recording icon or cues.
+    Stealth,
+}
+
+impl EnableLevel {
+    /// All levels, in tray menu order.
+    pub const ALL: [EnableLevel; 4] = [
+        EnableLevel::Enabled,
+        EnableLevel::HotkeysPaused,
+        EnableLevel::FullyPaused,
+        EnableLevel::Stealth,
+    ];
+
+    /// Wire name, as serialized.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EnableLevel::Enabled => "enabled",
+            EnableLevel::HotkeysPaused => "hotkeys_paused",
+            EnableLevel::FullyPaused => "fully_paused",
+            EnableLevel::Stealth => "stealth",
+        }
+    }
+
+    /// Parse a wire name.
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|level| level.as_str() == value)
+    }
+
+    /// Whether hotkey presses start recordings.
+    pub fn hotkeys_active(self) -> bool {
+        matches!(self, EnableLevel::Enabled | EnableLevel::Stealth)
+    }
+
+    /// Whether recordings can start at all (from the tray or overlay).
+    pub fn allows_recording(self) -> bool {
+        self != EnableLevel::FullyPaused
+    }
+
+    /// Whether recording shows the overlay, tray icon and audio cues.
+    pub fn shows_indicators(self) -> bool {
+        self != EnableLevel::Stealth
+    }
+}
+
 /// Event emitted when state changes.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StateEvent {
     /// Current application state.
     pub state: AppState,
     /// Whether hotkey listening is enabled (false = paused).
     pub enabled: bool,
+    /// Current pause level; `enabled` is its `hotkeys_active`.
+    #[serde(default)]
+    pub level: EnableLevel,
     /// Error detail (only set when state is Error).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub detail: Option<String>,
@@ -88,7 +185,7 @@ pub struct InvalidTransition {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum CannotRecordReason {
-    /// Hotkey listening is paused.
+    /// Dictation is fully paused.
     Paused,
     /// Model is still loading.
     ModelLoading,
@@ -98,16 +195,25 @@ pub enum CannotRecordReason {
     StillTranscribing,
     /// In error state (needs recovery).
     InErrorState,
+    /// The screen is shared and `ui.screen_share_policy` disables dictation.
+    ScreenSharing,
+    /// Hotkeys are paused; the tray and overlay can still record.
+    HotkeysPaused,
 }
 
 /// Thread-safe application state manager.
 pub struct AppStateManager {
     /// Current application state.
     state: RwLock<AppState>,
-    /// Whether hotkey listening is enabled.
-    enabled: AtomicBool,
+    /// Current pause level.
+    level: RwLock<EnableLevel>,
     /// Error detail (when in Error state).
     error_detail: RwLock<Option<String>>,
+    /// Category of the current error (when entered through
+    /// `transition_to_error`).
+    error_category: RwLock<Option<ErrorCategory>>,
+    /// Number of `transition_to_error` calls so far.
+    error_generation: AtomicU64,
     /// Broadcast sender for state events.
     event_sender: broadcast::Sender<StateEvent>,
 }
@@ -118,8 +224,10 @@ impl AppStateManager {
         let (tx, _) = broadcast::channel(16);
         Self {
             state: RwLock::new(AppState::Idle),
-            enabled: AtomicBool::new(true),
+            level: RwLock::new(EnableLevel::Enabled),
             error_detail: RwLock::new(None),
+            error_category: RwLock::new(None),
+            error_generation: AtomicU64::new(0),
             event_sender: tx,
         }
     }
@@ -131,12 +239,27 @@ impl AppStateManager {
 
     /// Check if hotkey listening is enabled.
     pub fn is_enabled(&self) -> bool {
-        self.enabled.load(Ordering::SeqCst)
+        self.level().hotkeys_active()
     }
 
-    /// Enable or disable hotkey listening (pause/resume).
+    /// Enable or fully pause dictation. Enabling keeps stealth on.
     pub fn set_enabled(&self, enabled: bool) {
-        self.enabled.store(enabled, Ordering::SeqCst);
+        let level = match (enabled, self.level()) {
+            (true, EnableLevel::Stealth) => EnableLevel::Stealth,
+            (true, _) => EnableLevel::Enabled,
+            (false, _) => EnableLevel::FullyPaused,
+        };
+        self.set_level(level);
+    }
+
+    /// Get the current pause level.
+    pub fn level(&self) -> EnableLevel {
+        *self.level.read().unwrap()
+    }
+
+    /// Set the pause level.
+    pub fn set_level(&self, level: EnableLevel) {
+        *self.level.write().unwrap() = level;
         self.emit_event();
     }
 
@@ -145,6 +268,20 @@ impl AppStateManager {
         self.error_detail.read().unwrap().clone()
     }
 
+    /// The current error, if the app is in an Error state entered through
+    /// `transition_to_error`.
+    pub fn current_error(&self) -> Option<ErrorStamp> {
+        let state = self.state.read().unwrap();
+        if *state != AppState::Error {
+            return None;
+        }
+        let category = (*self.error_category.read().unwrap())?;
+        Some(ErrorStamp {
+            generation: self.error_generation.load(Ordering::SeqCst),
+            category,
+        })
+    }
+
     /// Attempt a state transition.
     ///
     /// Returns `Ok(())` if the transition is valid, or an error if not.
@@ -152,8 +289,11 @@ impl AppStateManager {
         let mut state = self.state.write().unwrap();
         let current = *state;
 
-        // Guard recording start while paused: direct transitions must respect enabled state.
-        if current == AppState::Idle && new_state == AppState::Recording && !self.is_enabled() {
+        // Guard recording start while paused: direct transitions must respect the pause level.
+        if current == AppState::Idle
+            && new_state == AppState::Recording
+            && !self.level().allows_recording()
+        {
             return Err(InvalidTransition {
                 from: current,
                 to: new_state,
@@ -172,6 +312,7 @@ impl AppStateManager {
         // Clear error detail when transitioning away from error
         if new_state != AppState::Error {
             *self.error_detail.write().unwrap() = None;
+            *self.error_category.write().unwrap() = None;
         }
 
         drop(state);
@@ -182,17 +323,34 @@ impl AppStateManager {
     /// Transition to Error state with a detail message.
     ///
     /// This always succeeds (any state can transition to Error).
-    pub fn transition_to_error(&self, detail: String) {
+    pub fn transition_to_error(&self, category: ErrorCategory, detail: String) {
         let mut state = self.state.write().unwrap();
         *state = AppState::Error;
         *self.error_detail.write().unwrap() = Some(detail);
+        *self.error_category.write().unwrap() = Some(category);
+        self.error_generation.fetch_add(1, Ordering::SeqCst);
+        drop(state);
+        self.emit_event();
+    }
+
+    /// Return to Idle from the error `generation`, unless the app has left
+    /// it or a newer error replaced it. Returns whether it did.
+    pub fn recover_from_error(&self, generation: u64) -> bool {
+        let mut state = self.state.write().unwrap();
+        if *state != AppState::Error || self.error_generation.load(Ordering::SeqCst) != generation {
+            return false;
+        }
+        *state = AppState::Idle;
+        *self.error_detail.write().unwrap() = None;
+        *self.error_category.write().unwrap() = None;
         drop(state);
         self.emit_event();
+        true
     }
 
     /// Check if recording can start.
     pub fn can_start_recording(&self) -> Result<(), CannotRecordReason> {
-        if !self.is_enabled() {
+        if !self.level().allows_recording() {
             return Err(CannotRecordReason::Paused);
         }
 
@@ -205,6 +363,14 @@ impl AppStateManager {
         }
     }
 
+    /// Check if a hotkey press can start recording.
+    pub fn can_start_recording_from_hotkey(&self) -> Result<(), CannotRecordReason> {
+        if self.level() == EnableLevel::HotkeysPaused {
+            return Err(CannotRecordReason::HotkeysPaused);
+        }
+        self.can_start_recording()
+    }
+
     /// Subscribe to state change events.
     pub fn subscribe(&self) -> broadcast::Receiver<StateEvent> {
         self.event_sender.subscribe()
@@ -212,9 +378,11 @@ impl AppStateManager {
 
     /// Get a snapshot of the current state event.
     pub fn get_event(&self) -> StateEvent {
+        let level = self.level();
         StateEvent {
             state: self.get(),
-            enabled: self.is_enabled(),
+            enabled: level.hotkeys_active(),
+            level,
             detail: self.get_error_detail(),
             timestamp: Utc::now(),
         }
@@ -404,6 +572,60 @@ mod tests {
         assert!(manager.is_enabled());
     }
 
+    #[test]
+    fn test_enable_levels() {
+        let manager = AppStateManager::new();
+        assert_eq!(manager.level(), EnableLevel::Enabled);
+
+        // Hotkeys paused: tray and overlay still record.
+        manager.set_level(EnableLevel::HotkeysPaused);
+        assert!(!manager.is_enabled());
+        assert!(manager.can_start_recording().is_ok());
+        assert_eq!(
+            manager.can_start_recording_from_hotkey(),
+            Err(CannotRecordReason::HotkeysPaused)
+        );
+
+        // Fully paused: nothing records.
+        manager.set_level(EnableLevel::FullyPaused);
+        assert_eq!(
+            manager.can_start_recording(),
+            Err(CannotRecordReason::Paused)
+        );
+        assert_eq!(
+            manager.can_start_recording_from_hotkey(),
+            Err(CannotRecordReason::Paused)
+        );
+        assert!(manager.transition(AppState::Recording).is_err());
+
+        // Stealth records from hotkeys too, and survives re-enabling.
+        manager.set_level(EnableLevel::Stealth);
+        assert!(manager.is_enabled());
+        assert!(manager.can_start_recording_from_hotkey().is_ok());
+        manager.set_enabled(true);
+        assert_eq!(manager.level(), EnableLevel::Stealth);
+        manager.set_enabled(false);
+        assert_eq!(manager.level(), EnableLevel::FullyPaused);
+
+        manager.set_level(EnableLevel::HotkeysPaused);
+        manager.transition(AppState::Recording).unwrap();
+        let event = manager.get_event();
+        assert_eq!(event.level, EnableLevel::HotkeysPaused);
+        assert!(!event.enabled);
+    }
+
+    #[test]
+    fn test_enable_level_wire_names_round_trip() {
+        for level in EnableLevel::ALL {
+            assert_eq!(
+                serde_json::to_value(level).unwrap(),
+                Value::String(level.as_str().to_string())
+            );
+            assert_eq!(EnableLevel::parse(level.as_str()), Some(level));
+        }
+        assert_eq!(EnableLevel::parse("asleep"), None);
+    }
+
     #[test]
     fn test_can_start_recording() {
         let manager = AppStateManager::new();
@@ -467,7 +689,7 @@ mod tests {
         assert!(manager.get_error_detail().is_none());
 
         // Set error with detail
-        manager.transition_to_error("Test error message".to_string());
+        manager.transition_to_error(ErrorCategory::Other, "Test error message".to_string());
         assert_eq!(manager.get(), AppState::Error);
         assert_eq!(
             manager.get_error_detail(),
@@ -477,6 +699,30 @@ mod tests {
         // Detail cleared when leaving error state
         manager.transition(AppState::Idle).unwrap();
         assert!(manager.get_error_detail().is_none());
+        assert!(manager.current_error().is_none());
+    }
+
+    #[test]
+    fn test_recover_from_error_only_for_the_same_error() {
+        let manager = AppStateManager::new();
+
+        manager.transition_to_error(ErrorCategory::Sidecar, "hung".to_string());
+        let first = manager.current_error().unwrap();
+        assert_eq!(first.category, ErrorCategory::Sidecar);
+
+        // A newer error supersedes the one being recovered from.
+        manager.transition_to_error(ErrorCategory::AudioDevice, "no mic".to_string());
+        assert!(!manager.recover_from_error(first.generation));
+        assert_eq!(manager.get(), AppState::Error);
+
+        let second = manager.current_error().unwrap();
+        assert_eq!(second.category, ErrorCategory::AudioDevice);
+        assert!(manager.recover_from_error(second.generation));
+        assert_eq!(manager.get(), AppState::Idle);
+        assert!(manager.get_error_detail().is_none());
+
+        // Nothing to recover once the app has left the error.
+        assert!(!manager.recover_from_error(second.generation));
     }
 
     #[test]
@@ -548,7 +794,9 @@ mod tests {
             CannotRecordReason::ModelLoading,
             CannotRecordReason::AlreadyRecording,
             CannotRecordReason::StillTranscribing,
-            CannotRecordReason::InErrorState
+            CannotRecordReason::InErrorState,
+            CannotRecordReason::ScreenSharing,
+            CannotRecordReason::HotkeysPaused
         ]);
 
         assert_eq!(&actual, expected);
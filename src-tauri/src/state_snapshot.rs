@@ -0,0 +1,354 @@
+//! Support snapshots of the app's state.
+//!
+//! `export_app_state_snapshot` writes a single JSON file with the app state,
+//! capabilities, model status, redacted config, sidecar supervisor state, and
+//! the events most recently sent to the windows, for users to attach to
+//! support requests. Debug builds load a snapshot with
+//! `replay_app_state_snapshot`, which sends its events to the windows again
+//! so the UI walks through what the user saw without a sidecar, hotkeys, or
+//! the user's config behind it.
+//!
+//! Transcript text, replacement patterns, vocabulary, and endpoint URLs are
+//! reduced to their length; other strings go through the log redaction.
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use thiserror::Error;
+
+use crate::config::AppConfig;
+use crate::event_seq::add_seq_to_payload;
+use crate::integration::ModelStatus;
+use crate::runtime::AppEventBroadcaster;
+use crate::state::StateEvent;
+use crate::supervisor::SidecarState;
+
+/// Format version written to `snapshot_version`.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Maximum number of recent events kept.
+const MAX_RECENT_EVENTS: usize = 200;
+
+/// Longest pause between replayed events.
+const MAX_REPLAY_GAP: Duration = Duration::from_millis(500);
+
+/// High-rate events that would push everything else out of the buffer.
+const UNRECORDED_EVENTS: [&str; 2] = ["audio:level", "vad:preview"];
+
+/// Keys whose string values are user content.
+const USER_TEXT_KEYS: [&str; 14] = [
+    "text",
+    "raw_text",
+    "final_text",
+    "unfiltered_text",
+    "transcript",
+    "words",
+    "segments",
+    "pattern",
+    "replacement",
+    "description",
+    "terms",
+    "hotwords",
+    "url",
+    "endpoint",
+];
+
+static RECENT_EVENTS: Lazy<Mutex<VecDeque<RecordedEvent>>> =
+    Lazy::new(|| Mutex::new(VecDeque::with_capacity(MAX_RECENT_EVENTS)));
+
+/// Errors writing or reading a snapshot.
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    #[error("No directory available to save the snapshot in")]
+    MissingExportDirectory,
+    #[error("Snapshot version {0} is not supported (expected {SNAPSHOT_VERSION})")]
+    UnsupportedVersion(u32),
+    #[error("Snapshot file error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid snapshot: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// An event sent to the windows, with its payload redacted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    pub event: String,
+    pub at: DateTime<Utc>,
+    pub payload: Value,
+}
+
+/// State of the live sidecar's supervisor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SupervisorSnapshot {
+    pub state: SidecarState,
+    pub restart_count: u32,
+    pub circuit_breaker_open: bool,
+}
+
+/// Everything a snapshot file holds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppStateSnapshot {
+    pub snapshot_version: u32,
+    pub created_at: DateTime<Utc>,
+    pub app_version: String,
+    pub platform: String,
+    pub app_state: StateEvent,
+    pub capabilities: Value,
+    pub model_status: ModelStatus,
+    /// Config with user content reduced to its length.
+    pub config: Value,
+    pub supervisor: SupervisorSnapshot,
+    /// Events sent to the windows, oldest first.
+    pub recent_events: Vec<RecordedEvent>,
+}
+
+fn recent_events_buffer() -> std::sync::MutexGuard<'static, VecDeque<RecordedEvent>> {
+    RECENT_EVENTS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Record an event sent to the windows.
+pub fn record_event(event: &str, payload: &Value) {
+    if UNRECORDED_EVENTS.contains(&event) {
+        return;
+    }
+    let mut payload = payload.clone();
+    redact_value(&mut payload, false);
+    let mut events = recent_events_buffer();
+    if events.len() >= MAX_RECENT_EVENTS {
+        events.pop_front();
+    }
+    events.push_back(RecordedEvent {
+        event: event.to_string(),
+        at: Utc::now(),
+        payload,
+    });
+}
+
+/// The recorded events, oldest first.
+pub fn recent_events() -> Vec<RecordedEvent> {
+    recent_events_buffer().iter().cloned().collect()
+}
+
+/// `config` with replacement rules, vocabulary, and endpoints reduced to
+/// their length.
+pub fn redact_config(config: &AppConfig) -> Value {
+    let mut value = serde_json::to_value(config).unwrap_or(Value::Null);
+    redact_value(&mut value, false);
+    value
+}
+
+fn redact_value(value: &mut Value, user_text: bool) {
+    match value {
+        Value::String(text) if user_text => {
+            *text = format!("<{} chars>", text.chars().count());
+        }
+        Value::String(text) => {
+            *text = crate::log_buffer::redact_sensitive(text);
+        }
+        Value::Array(items) => {
+            for item in items {
+                redact_value(item, user_text);
+            }
+        }
+        Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                redact_value(field, user_text || USER_TEXT_KEYS.contains(&key.as_str()));
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+/// Write `snapshot` to the downloads folder (or documents, or home) and
+/// return the file's path.
+pub fn write_snapshot(snapshot: &AppStateSnapshot) -> Result<PathBuf, SnapshotError> {
+    let dir = dirs::download_dir()
+        .or_else(dirs::document_dir)
+        .or_else(dirs::home_dir)
+        .ok_or(SnapshotError::MissingExportDirectory)?;
+    write_snapshot_to_dir(snapshot, &dir)
+}
+
+fn write_snapshot_to_dir(
+    snapshot: &AppStateSnapshot,
+    dir: &Path,
+) -> Result<PathBuf, SnapshotError> {
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!(
+        "openvoicy-state-{}.json",
+        snapshot.created_at.format("%Y%m%d-%H%M%S")
+    ));
+    std::fs::write(&path, serde_json::to_vec_pretty(snapshot)?)?;
+    Ok(path)
+}
+
+/// Read a snapshot file.
+pub fn read_snapshot(path: &Path) -> Result<AppStateSnapshot, SnapshotError> {
+    let snapshot: AppStateSnapshot = serde_json::from_slice(&std::fs::read(path)?)?;
+    if snapshot.snapshot_version != SNAPSHOT_VERSION {
+        return Err(SnapshotError::UnsupportedVersion(snapshot.snapshot_version));
+    }
+    Ok(snapshot)
+}
+
+/// Events to replay with the pause before each: the recorded events with
+/// their original spacing (capped at [`MAX_REPLAY_GAP`]), then the final
+/// app state so the UI ends where the snapshot was taken.
+fn replay_steps(snapshot: &AppStateSnapshot) -> Vec<(Duration, String, Value)> {
+    let mut steps = Vec::with_capacity(snapshot.recent_events.len() + 1);
+    let mut previous_at = None;
+    for event in &snapshot.recent_events {
+        let gap = previous_at
+            .and_then(|previous: DateTime<Utc>| (event.at - previous).to_std().ok())
+            .unwrap_or_default()
+            .min(MAX_REPLAY_GAP);
+        previous_at = Some(event.at);
+        steps.push((gap, event.event.clone(), event.payload.clone()));
+    }
+    let final_state = serde_json::to_value(&snapshot.app_state).unwrap_or_else(|_| json!({}));
+    steps.push((Duration::ZERO, "state:changed".to_string(), final_state));
+    steps
+}
+
+/// Send the snapshot's events to `broadcaster`, restamped with `next_seq` so
+/// the frontend does not drop them as stale. Replayed events are not
+/// recorded again.
+pub async fn replay<B: AppEventBroadcaster>(
+    snapshot: &AppStateSnapshot,
+    broadcaster: &B,
+    next_seq: impl Fn() -> u64,
+) {
+    for (gap, event, payload) in replay_steps(snapshot) {
+        if !gap.is_zero() {
+            tokio::time::sleep(gap).await;
+        }
+        broadcaster.emit_all(&event, add_seq_to_payload(payload, next_seq()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{AppState, EnableLevel};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<(String, Value)>>);
+
+    impl AppEventBroadcaster for Recorder {
+        fn emit_all(&self, event: &str, payload: Value) {
+            self.0.lock().unwrap().push((event.to_string(), payload));
+        }
+    }
+
+    fn snapshot_with_events(recent_events: Vec<RecordedEvent>) -> AppStateSnapshot {
+        AppStateSnapshot {
+            snapshot_version: SNAPSHOT_VERSION,
+            created_at: Utc::now(),
+            app_version: "0.1.0".to_string(),
+            platform: "linux".to_string(),
+            app_state: StateEvent {
+                state: AppState::Error,
+                enabled: true,
+                level: EnableLevel::Enabled,
+                detail: Some("Microphone disconnected".to_string()),
+                timestamp: Utc::now(),
+            },
+            capabilities: json!({}),
+            model_status: ModelStatus::Ready,
+            config: redact_config(&AppConfig::default()),
+            supervisor: SupervisorSnapshot {
+                state: SidecarState::Ready,
+                restart_count: 2,
+                circuit_breaker_open: false,
+            },
+            recent_events,
+        }
+    }
+
+    #[test]
+    fn test_redaction_hides_user_content() {
+        let mut payload = json!({
+            "seq": 4,
+            "text": "dear diary",
+            "words": [{ "word": "dear", "start_ms": 0 }],
+            "error": "Failed to open /home/alice/model.bin",
+            "state": "idle",
+        });
+        redact_value(&mut payload, false);
+        assert_eq!(payload["text"], "<10 chars>");
+        assert_eq!(payload["words"][0]["word"], "<4 chars>");
+        assert_eq!(payload["words"][0]["start_ms"], 0);
+        assert!(!payload["error"].as_str().unwrap().contains("alice"));
+        assert_eq!(payload["state"], "idle");
+
+        let mut config = AppConfig::default();
+        config.vocabulary.terms = vec!["Kubernetes".to_string()];
+        config.sync.url = "https://dav.example.com/alice/settings".to_string();
+        let redacted = redact_config(&config);
+        assert_eq!(redacted["vocabulary"]["terms"][0], "<10 chars>");
+        assert_eq!(redacted["sync"]["url"], "<38 chars>");
+        assert_eq!(
+            redacted["hotkeys"]["primary"],
+            config.hotkeys.primary.as_str()
+        );
+    }
+
+    #[test]
+    fn test_snapshot_round_trips_through_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let snapshot = snapshot_with_events(Vec::new());
+        let path = write_snapshot_to_dir(&snapshot, dir.path()).unwrap();
+
+        let loaded = read_snapshot(&path).unwrap();
+        assert_eq!(loaded.app_state.state, AppState::Error);
+        assert_eq!(loaded.model_status, ModelStatus::Ready);
+        assert_eq!(loaded.supervisor, snapshot.supervisor);
+
+        let mut future = serde_json::to_value(&snapshot).unwrap();
+        future["snapshot_version"] = json!(SNAPSHOT_VERSION + 1);
+        std::fs::write(&path, future.to_string()).unwrap();
+        assert!(matches!(
+            read_snapshot(&path),
+            Err(SnapshotError::UnsupportedVersion(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_replay_restamps_events_and_ends_in_snapshot_state() {
+        let at = Utc::now();
+        let snapshot = snapshot_with_events(vec![
+            RecordedEvent {
+                event: "state:changed".to_string(),
+                at,
+                payload: json!({ "seq": 90, "state": "recording" }),
+            },
+            RecordedEvent {
+                event: "sidecar:status".to_string(),
+                at: at + chrono::Duration::seconds(30),
+                payload: json!({ "seq": 91, "state": "failed" }),
+            },
+        ]);
+
+        let steps = replay_steps(&snapshot);
+        assert_eq!(steps[1].0, MAX_REPLAY_GAP);
+
+        let recorder = Recorder::default();
+        let seq = AtomicU64::new(500);
+        replay(&snapshot, &recorder, || seq.fetch_add(1, Ordering::Relaxed)).await;
+
+        let emitted = recorder.0.lock().unwrap();
+        assert_eq!(emitted.len(), 3);
+        assert_eq!(emitted[0].1["seq"], 500);
+        assert_eq!(emitted[1].0, "sidecar:status");
+        assert_eq!(emitted[2].0, "state:changed");
+        assert_eq!(emitted[2].1["state"], "error");
+        assert_eq!(emitted[2].1["seq"], 502);
+    }
+}
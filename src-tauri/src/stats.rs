@@ -0,0 +1,186 @@
+//! Usage statistics derived from transcript history.
+//!
+//! Word counts and recorded audio time are aggregated per local calendar day
+//! so the tray can show today's totals and the statistics page can show the
+//! recent trend. Nothing is persisted; everything is recomputed from history.
+
+use chrono::{DateTime, Days, FixedOffset, Local, NaiveDate};
+use serde::Serialize;
+
+use crate::history::TranscriptEntry;
+
+/// Days covered by `UsageStats::days`, including today.
+pub const USAGE_STATS_DAYS: u64 = 7;
+
+/// Totals over a set of transcripts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct UsageTotals {
+    pub transcripts: u64,
+    pub words: u64,
+    pub audio_ms: u64,
+}
+
+impl UsageTotals {
+    fn add(&mut self, entry: &TranscriptEntry) {
+        self.transcripts += 1;
+        self.words += word_count(&entry.text);
+        self.audio_ms += u64::from(entry.audio_duration_ms);
+    }
+
+    /// Recorded audio rounded to whole minutes.
+    pub fn audio_minutes(&self) -> u64 {
+        (self.audio_ms + 30_000) / 60_000
+    }
+}
+
+/// Totals for one local calendar day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DailyUsage {
+    pub date: NaiveDate,
+    #[serde(flatten)]
+    pub totals: UsageTotals,
+}
+
+/// Usage summary returned by `get_usage_stats`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UsageStats {
+    pub today: UsageTotals,
+    /// The last `USAGE_STATS_DAYS` days, oldest first, including empty days.
+    pub days: Vec<DailyUsage>,
+    /// Everything still in history.
+    pub all_time: UsageTotals,
+}
+
+/// Number of whitespace-separated words in a transcript.
+pub fn word_count(text: &str) -> u64 {
+    text.split_whitespace().count() as u64
+}
+
+/// Summarize history relative to the local clock.
+pub fn usage_stats(entries: &[TranscriptEntry]) -> UsageStats {
+    usage_stats_at(entries, Local::now().fixed_offset())
+}
+
+/// Summarize history relative to `now`; entries are bucketed by their date in
+/// `now`'s offset.
+pub fn usage_stats_at(entries: &[TranscriptEntry], now: DateTime<FixedOffset>) -> UsageStats {
+    let today = now.date_naive();
+    let mut days: Vec<DailyUsage> = (0..USAGE_STATS_DAYS)
+        .rev()
+        .filter_map(|offset| today.checked_sub_days(Days::new(offset)))
+        .map(|date| DailyUsage {
+            date,
+            totals: UsageTotals::default(),
+        })
+        .collect();
+    let mut all_time = UsageTotals::default();
+
+    for entry in entries {
+        all_time.add(entry);
+        let date = entry.timestamp.with_timezone(now.offset()).date_naive();
+        if let Some(day) = days.iter_mut().find(|day| day.date == date) {
+            day.totals.add(entry);
+        }
+    }
+
+    let today = days
+        .last()
+        .filter(|day| day.date == today)
+        .map(|day| day.totals)
+        .unwrap_or_default();
+
+    UsageStats {
+        today,
+        days,
+        all_time,
+    }
+}
+
+/// Format a count with `separator` between groups of three digits.
+pub fn group_thousands(value: u64, separator: &str) -> String {
+    let digits = value.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3 * separator.len());
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            out.push_str(separator);
+        }
+        out.push(digit);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::HistoryInjectionResult;
+    use chrono::{TimeZone, Utc};
+
+    fn entry_at(text: &str, audio_ms: u32, timestamp: DateTime<Utc>) -> TranscriptEntry {
+        let mut entry = TranscriptEntry::new(
+            text.to_string(),
+            audio_ms,
+            100,
+            HistoryInjectionResult::Injected,
+        );
+        entry.timestamp = timestamp;
+        entry
+    }
+
+    #[test]
+    fn test_word_count_splits_on_whitespace() {
+        assert_eq!(word_count("  hello   world\nagain "), 3);
+        assert_eq!(word_count(""), 0);
+    }
+
+    #[test]
+    fn test_usage_stats_buckets_by_local_day() {
+        // 00:30 on March 10 at UTC+2 is still March 9 in UTC.
+        let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+        let now = offset.with_ymd_and_hms(2026, 3, 10, 9, 0, 0).unwrap();
+        let entries = vec![
+            entry_at(
+                "just after midnight",
+                90_000,
+                Utc.with_ymd_and_hms(2026, 3, 9, 22, 30, 0).unwrap(),
+            ),
+            entry_at(
+                "yesterday",
+                60_000,
+                Utc.with_ymd_and_hms(2026, 3, 9, 12, 0, 0).unwrap(),
+            ),
+            entry_at(
+                "long ago",
+                60_000,
+                Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap(),
+            ),
+        ];
+
+        let stats = usage_stats_at(&entries, now);
+
+        assert_eq!(
+            stats.today,
+            UsageTotals {
+                transcripts: 1,
+                words: 3,
+                audio_ms: 90_000,
+            }
+        );
+        assert_eq!(stats.today.audio_minutes(), 2);
+        assert_eq!(stats.days.len(), USAGE_STATS_DAYS as usize);
+        assert_eq!(
+            stats.days[0].date,
+            NaiveDate::from_ymd_opt(2026, 3, 4).unwrap()
+        );
+        assert_eq!(stats.days[5].totals.words, 1);
+        assert_eq!(stats.all_time.transcripts, 3);
+        assert_eq!(stats.all_time.words, 6);
+    }
+
+    #[test]
+    fn test_group_thousands() {
+        assert_eq!(group_thousands(0, ","), "0");
+        assert_eq!(group_thousands(999, ","), "999");
+        assert_eq!(group_thousands(3214, ","), "3,214");
+        assert_eq!(group_thousands(1_234_567, "."), "1.234.567");
+    }
+}
@@ -0,0 +1,348 @@
+//! Subtitle (SRT / WebVTT) rendering for timestamped transcripts.
+//!
+//! Converts sidecar-provided transcript segments into subtitle cues that follow
+//! common readability rules: bounded line length, at most two lines per cue,
+//! and minimum/maximum on-screen durations.
+
+use crate::history::{HistoryExportError, TranscriptEntry, TranscriptSegment};
+
+/// Maximum characters per subtitle line.
+pub const MAX_LINE_CHARS: usize = 42;
+/// Maximum lines shown in a single cue.
+pub const MAX_LINES_PER_CUE: usize = 2;
+/// Minimum time a cue stays on screen.
+pub const MIN_CUE_DURATION_MS: u64 = 1_000;
+/// Maximum time a cue stays on screen before it is split.
+pub const MAX_CUE_DURATION_MS: u64 = 7_000;
+
+/// Supported subtitle output formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubtitleFormat {
+    Srt,
+    Vtt,
+}
+
+impl SubtitleFormat {
+    pub fn parse(input: &str) -> Result<Self, HistoryExportError> {
+        match input.trim().to_ascii_lowercase().as_str() {
+            "srt" => Ok(Self::Srt),
+            "vtt" | "webvtt" => Ok(Self::Vtt),
+            value => Err(HistoryExportError::InvalidFormat {
+                value: value.to_string(),
+            }),
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Srt => "srt",
+            Self::Vtt => "vtt",
+        }
+    }
+}
+
+/// A single rendered subtitle cue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubtitleCue {
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub lines: Vec<String>,
+}
+
+/// Render an entry as subtitles in the requested format.
+///
+/// Entries without segment timestamps fall back to a single segment spanning
+/// the recorded audio duration.
+pub fn render_entry(entry: &TranscriptEntry, format: SubtitleFormat) -> String {
+    let cues = build_cues(&entry_segments(entry));
+    match format {
+        SubtitleFormat::Srt => render_srt(&cues),
+        SubtitleFormat::Vtt => render_vtt(&cues),
+    }
+}
+
+fn entry_segments(entry: &TranscriptEntry) -> Vec<TranscriptSegment> {
+    if !entry.segments.is_empty() {
+        return entry.segments.clone();
+    }
+
+    let text = if entry.final_text.is_empty() {
+        entry.text.clone()
+    } else {
+        entry.final_text.clone()
+    };
+    vec![TranscriptSegment {
+        start_ms: 0,
+        end_ms: u64::from(entry.audio_duration_ms),
+        text,
+        speaker: None,
+    }]
+}
+
+/// Build display cues from raw transcript segments.
+pub fn build_cues(segments: &[TranscriptSegment]) -> Vec<SubtitleCue> {
+    let mut ordered: Vec<&TranscriptSegment> = segments
+        .iter()
+        .filter(|segment| !segment.text.trim().is_empty())
+        .collect();
+    ordered.sort_by_key(|segment| segment.start_ms);
+
+    let mut cues = Vec::new();
+    for segment in ordered {
+        let lines = wrap_lines(&segment.text, MAX_LINE_CHARS);
+        let chunks: Vec<Vec<String>> = lines
+            .chunks(MAX_LINES_PER_CUE)
+            .map(|chunk| chunk.to_vec())
+            .collect();
+        let total_chars: usize = chunks.iter().map(|chunk| chunk_chars(chunk)).sum();
+        let span = segment.end_ms.saturating_sub(segment.start_ms);
+
+        let mut cursor = segment.start_ms;
+        let mut consumed_chars = 0usize;
+        for chunk in chunks {
+            consumed_chars += chunk_chars(&chunk);
+            let end = if total_chars == 0 {
+                segment.end_ms
+            } else {
+                segment.start_ms + span * consumed_chars as u64 / total_chars as u64
+            };
+            push_split_by_max_duration(&mut cues, cursor, end, chunk);
+            cursor = end;
+        }
+    }
+
+    enforce_min_duration(&mut cues);
+    cues
+}
+
+fn chunk_chars(lines: &[String]) -> usize {
+    lines
+        .iter()
+        .map(|line| line.chars().count())
+        .sum::<usize>()
+        .max(1)
+}
+
+fn push_split_by_max_duration(
+    cues: &mut Vec<SubtitleCue>,
+    start_ms: u64,
+    end_ms: u64,
+    lines: Vec<String>,
+) {
+    let duration = end_ms.saturating_sub(start_ms);
+    if duration <= MAX_CUE_DURATION_MS || lines.len() < 2 {
+        cues.push(SubtitleCue {
+            start_ms,
+            end_ms: end_ms.min(start_ms + MAX_CUE_DURATION_MS),
+            lines,
+        });
+        return;
+    }
+
+    // Too long for one cue: show each line on its own for half the span.
+    let midpoint = start_ms + duration / 2;
+    let mut lines = lines.into_iter();
+    let first = lines.next().into_iter().collect::<Vec<_>>();
+    let rest = lines.collect::<Vec<_>>();
+    push_split_by_max_duration(cues, start_ms, midpoint, first);
+    push_split_by_max_duration(cues, midpoint, end_ms, rest);
+}
+
+fn enforce_min_duration(cues: &mut [SubtitleCue]) {
+    for index in 0..cues.len() {
+        let next_start = cues.get(index + 1).map(|cue| cue.start_ms);
+        let cue = &mut cues[index];
+        if cue.end_ms.saturating_sub(cue.start_ms) >= MIN_CUE_DURATION_MS {
+            continue;
+        }
+        let desired_end = cue.start_ms + MIN_CUE_DURATION_MS;
+        cue.end_ms = match next_start {
+            Some(next) => desired_end.min(next.max(cue.end_ms)),
+            None => desired_end,
+        };
+    }
+}
+
+/// Greedy word wrap; words longer than `max_chars` are hard-split.
+pub fn wrap_lines(text: &str, max_chars: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        while word.len() > max_chars {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            lines.push(word.drain(..max_chars).collect());
+        }
+        let word: String = word.into_iter().collect();
+        if word.is_empty() {
+            continue;
+        }
+
+        let needed = if current.is_empty() {
+            word.chars().count()
+        } else {
+            current.chars().count() + 1 + word.chars().count()
+        };
+        if needed > max_chars && !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(&word);
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+fn format_timestamp(ms: u64, fraction_separator: char) -> String {
+    let hours = ms / 3_600_000;
+    let minutes = (ms / 60_000) % 60;
+    let seconds = (ms / 1_000) % 60;
+    let millis = ms % 1_000;
+    format!("{hours:02}:{minutes:02}:{seconds:02}{fraction_separator}{millis:03}")
+}
+
+/// Render cues as SubRip (`.srt`).
+pub fn render_srt(cues: &[SubtitleCue]) -> String {
+    let mut out = String::new();
+    for (index, cue) in cues.iter().enumerate() {
+        out.push_str(&format!("{}\n", index + 1));
+        out.push_str(&format!(
+            "{} --> {}\n",
+            format_timestamp(cue.start_ms, ','),
+            format_timestamp(cue.end_ms, ',')
+        ));
+        for line in &cue.lines {
+            out.push_str(line);
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Render cues as WebVTT (`.vtt`).
+pub fn render_vtt(cues: &[SubtitleCue]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for cue in cues {
+        out.push_str(&format!(
+            "{} --> {}\n",
+            format_timestamp(cue.start_ms, '.'),
+            format_timestamp(cue.end_ms, '.')
+        ));
+        for line in &cue.lines {
+            // "-->" is reserved in cue payloads.
+            out.push_str(&line.replace("-->", "->"));
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::HistoryInjectionResult;
+
+    fn segment(start_ms: u64, end_ms: u64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            start_ms,
+            end_ms,
+            text: text.to_string(),
+            speaker: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_format_accepts_aliases() {
+        assert_eq!(SubtitleFormat::parse("SRT").unwrap(), SubtitleFormat::Srt);
+        assert_eq!(
+            SubtitleFormat::parse("webvtt").unwrap(),
+            SubtitleFormat::Vtt
+        );
+        assert!(SubtitleFormat::parse("txt").is_err());
+    }
+
+    #[test]
+    fn test_format_timestamp_uses_format_specific_separator() {
+        assert_eq!(format_timestamp(3_723_004, ','), "01:02:03,004");
+        assert_eq!(format_timestamp(3_723_004, '.'), "01:02:03.004");
+    }
+
+    #[test]
+    fn test_wrap_lines_respects_max_length() {
+        let lines = wrap_lines(
+            "the quick brown fox jumps over the lazy dog and keeps running far away",
+            MAX_LINE_CHARS,
+        );
+        assert!(lines.len() > 1);
+        assert!(lines
+            .iter()
+            .all(|line| line.chars().count() <= MAX_LINE_CHARS));
+    }
+
+    #[test]
+    fn test_wrap_lines_hard_splits_oversized_words() {
+        let word = "a".repeat(MAX_LINE_CHARS + 5);
+        let lines = wrap_lines(&word, MAX_LINE_CHARS);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].len(), 5);
+    }
+
+    #[test]
+    fn test_build_cues_limits_lines_per_cue() {
+        let text = "word ".repeat(60);
+        let cues = build_cues(&[segment(0, 6_000, &text)]);
+        assert!(cues.len() > 1);
+        assert!(cues.iter().all(|cue| cue.lines.len() <= MAX_LINES_PER_CUE));
+        assert_eq!(cues.last().unwrap().end_ms, 6_000);
+    }
+
+    #[test]
+    fn test_build_cues_extends_short_cues_without_overlap() {
+        let cues = build_cues(&[segment(0, 200, "hi"), segment(600, 700, "there")]);
+        assert_eq!(cues[0].end_ms, 600);
+        assert_eq!(cues[1].end_ms, 600 + MIN_CUE_DURATION_MS);
+    }
+
+    #[test]
+    fn test_build_cues_caps_max_duration() {
+        let cues = build_cues(&[segment(0, 20_000, "short line")]);
+        assert_eq!(cues.len(), 1);
+        assert_eq!(cues[0].end_ms, MAX_CUE_DURATION_MS);
+    }
+
+    #[test]
+    fn test_render_srt_and_vtt() {
+        let cues = build_cues(&[segment(1_000, 2_500, "hello world")]);
+        assert_eq!(
+            render_srt(&cues),
+            "1\n00:00:01,000 --> 00:00:02,500\nhello world\n\n"
+        );
+        assert_eq!(
+            render_vtt(&cues),
+            "WEBVTT\n\n00:00:01.000 --> 00:00:02.500\nhello world\n\n"
+        );
+    }
+
+    #[test]
+    fn test_render_entry_without_segments_spans_audio_duration() {
+        let entry = TranscriptEntry::new(
+            "fallback text".to_string(),
+            3_000,
+            100,
+            HistoryInjectionResult::Injected,
+        );
+        let srt = render_entry(&entry, SubtitleFormat::Srt);
+        assert!(srt.contains("00:00:00,000 --> 00:00:03,000"));
+        assert!(srt.contains("fallback text"));
+    }
+}
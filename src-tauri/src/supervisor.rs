@@ -7,13 +7,15 @@
 
 #![allow(dead_code)] // Module is added ahead of full runtime wiring.
 
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use tauri::{AppHandle, Emitter};
 
+use crate::crash_reports::{self, CrashReport, CrashReportStore};
+use crate::runtime::{AppHandle, Emitter};
 use crate::sidecar::SidecarManager;
 
 const EVENT_SIDECAR_STATUS: &str = "sidecar:status";
@@ -21,7 +23,7 @@ const DEFAULT_CAPTURED_LOG_MAX_LINES: usize = 1000;
 const STATUS_LOG_EXCERPT_LINES: usize = 8;
 const STATUS_LOG_LINE_MAX_CHARS: usize = 180;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SidecarState {
     Starting,
@@ -43,6 +45,43 @@ impl SidecarState {
     }
 }
 
+/// Role a supervised sidecar process plays.
+///
+/// Live dictation and heavy auxiliary work run in separate processes so a
+/// long batch job never queues behind (or in front of) a recording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SidecarRole {
+    /// Recording, live ASR, and other latency-sensitive calls.
+    Live,
+    /// File/batch transcription, translation, and other long-running jobs.
+    Auxiliary,
+}
+
+/// RPC method prefixes served by the auxiliary sidecar.
+const AUXILIARY_METHOD_PREFIXES: &[&str] = &["batch.", "file.", "translate."];
+
+impl SidecarRole {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Live => "live",
+            Self::Auxiliary => "auxiliary",
+        }
+    }
+
+    /// Route an RPC method to the sidecar role that serves it.
+    pub fn for_method(method: &str) -> Self {
+        if AUXILIARY_METHOD_PREFIXES
+            .iter()
+            .any(|prefix| method.starts_with(prefix))
+        {
+            Self::Auxiliary
+        } else {
+            Self::Live
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SidecarLogStream {
     Stdout,
@@ -146,6 +185,8 @@ where
     circuit_breaker: CircuitBreakerState,
     app_handle: Option<AppHandle>,
     captured_logs: VecDeque<SidecarLogRecord>,
+    /// Where sidecar crash reports are saved.
+    crash_reports: CrashReportStore,
 }
 
 impl<C> SidecarSupervisor<C>
@@ -163,9 +204,15 @@ where
             circuit_breaker: CircuitBreakerState::default(),
             app_handle: None,
             captured_logs: VecDeque::new(),
+            crash_reports: crash_reports::global_store(),
         }
     }
 
+    pub fn with_crash_report_store(mut self, store: CrashReportStore) -> Self {
+        self.crash_reports = store;
+        self
+    }
+
     pub fn with_app_handle(mut self, app_handle: AppHandle) -> Self {
         self.app_handle = Some(app_handle);
         self
@@ -175,6 +222,12 @@ where
         self.app_handle = Some(app_handle);
     }
 
+    /// Detach the app handle so this supervisor's status changes are no
+    /// longer emitted.
+    pub fn take_app_handle(&mut self) -> Option<AppHandle> {
+        self.app_handle.take()
+    }
+
     pub fn state(&self) -> SidecarState {
         self.state
     }
@@ -273,6 +326,15 @@ where
     pub async fn handle_crash(&mut self) -> Result<(), String> {
         let now = Instant::now();
         self.capture_controller_logs();
+        let log_lines: Vec<String> = self
+            .captured_logs
+            .iter()
+            .map(|entry| entry.line.clone())
+            .collect();
+        crash_reports::record(
+            &self.crash_reports,
+            CrashReport::sidecar_crash(self.restart_count, &log_lines),
+        );
 
         // Reset backoff only if the sidecar ran healthily for a sustained period.
         if let Some(ready_at) = self.ready_since {
@@ -464,10 +526,62 @@ where
     }
 }
 
+/// Shared handle to a supervisor; each role is locked independently.
+pub type SharedSupervisor<C = SidecarManager> = Arc<tokio::sync::Mutex<SidecarSupervisor<C>>>;
+
+/// Supervisors keyed by [`SidecarRole`].
+pub struct SupervisorRegistry<C = SidecarManager>
+where
+    C: SidecarController,
+{
+    supervisors: BTreeMap<SidecarRole, SharedSupervisor<C>>,
+}
+
+impl<C> Default for SupervisorRegistry<C>
+where
+    C: SidecarController,
+{
+    fn default() -> Self {
+        Self {
+            supervisors: BTreeMap::new(),
+        }
+    }
+}
+
+impl<C> SupervisorRegistry<C>
+where
+    C: SidecarController,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a supervisor for `role`, replacing any previous one.
+    pub fn with_supervisor(mut self, role: SidecarRole, supervisor: SidecarSupervisor<C>) -> Self {
+        self.supervisors
+            .insert(role, Arc::new(tokio::sync::Mutex::new(supervisor)));
+        self
+    }
+
+    pub fn get(&self, role: SidecarRole) -> Option<SharedSupervisor<C>> {
+        self.supervisors.get(&role).cloned()
+    }
+
+    pub fn roles(&self) -> impl Iterator<Item = SidecarRole> + '_ {
+        self.supervisors.keys().copied()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::{Arc, Mutex};
+    use std::sync::Mutex;
+    use tempfile::{tempdir, TempDir};
+
+    /// Crash reports from tests go to `dir`, not the config directory.
+    fn crash_store(dir: &TempDir) -> CrashReportStore {
+        CrashReportStore::new(dir.path().to_path_buf())
+    }
 
     #[derive(Default)]
     struct FakeControllerState {
@@ -591,6 +705,7 @@ mod tests {
         println!("[SUPERVISOR_TEST] verifying restart timing progression");
         let controller = FakeController::default();
         controller.set_fail_start(true);
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller.clone(),
             SidecarSupervisorConfig {
@@ -602,7 +717,8 @@ mod tests {
                 auto_restart_enabled: true,
                 captured_log_max_lines: DEFAULT_CAPTURED_LOG_MAX_LINES,
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         let first_started = Instant::now();
         let first_err = supervisor
@@ -647,13 +763,15 @@ mod tests {
         println!("[SUPERVISOR_TEST] verifying restart_count increments");
         let controller = FakeController::default();
         controller.set_fail_start(true);
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller,
             SidecarSupervisorConfig {
                 backoff_base_ms: 0,
                 ..SidecarSupervisorConfig::default()
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         let _ = supervisor.handle_crash().await;
         assert_eq!(supervisor.restart_count(), 1);
@@ -661,6 +779,7 @@ mod tests {
         assert_eq!(supervisor.restart_count(), 2);
         let _ = supervisor.handle_crash().await;
         assert_eq!(supervisor.restart_count(), 3);
+        assert_eq!(crash_store(&crash_dir).pending().len(), 3);
     }
 
     #[test]
@@ -696,6 +815,7 @@ mod tests {
     #[tokio::test]
     async fn handle_crash_trips_circuit_breaker_after_rapid_failures() {
         let controller = FakeController::default();
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller.clone(),
             SidecarSupervisorConfig {
@@ -707,7 +827,8 @@ mod tests {
                 auto_restart_enabled: true,
                 captured_log_max_lines: DEFAULT_CAPTURED_LOG_MAX_LINES,
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         supervisor
             .handle_crash()
@@ -732,6 +853,7 @@ mod tests {
         println!("[CIRCUIT_BREAKER_TEST] verifying exact threshold trip behavior");
         let controller = FakeController::default();
         controller.set_fail_start(true);
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller.clone(),
             SidecarSupervisorConfig {
@@ -743,7 +865,8 @@ mod tests {
                 auto_restart_enabled: true,
                 captured_log_max_lines: DEFAULT_CAPTURED_LOG_MAX_LINES,
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         let _ = supervisor
             .handle_crash()
@@ -803,6 +926,7 @@ mod tests {
     async fn max_restart_count_zero_trips_immediately() {
         println!("[CIRCUIT_BREAKER_TEST] verifying max_restart_count=0 immediate trip");
         let controller = FakeController::default();
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller.clone(),
             SidecarSupervisorConfig {
@@ -814,7 +938,8 @@ mod tests {
                 auto_restart_enabled: true,
                 captured_log_max_lines: DEFAULT_CAPTURED_LOG_MAX_LINES,
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         supervisor
             .handle_crash()
@@ -834,6 +959,7 @@ mod tests {
         println!("[CIRCUIT_BREAKER_TEST] verifying tripped state blocks auto-restart");
         let controller = FakeController::default();
         controller.set_fail_start(true);
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller.clone(),
             SidecarSupervisorConfig {
@@ -845,7 +971,8 @@ mod tests {
                 auto_restart_enabled: true,
                 captured_log_max_lines: DEFAULT_CAPTURED_LOG_MAX_LINES,
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         supervisor
             .handle_crash()
@@ -869,6 +996,7 @@ mod tests {
     #[tokio::test]
     async fn manual_restart_resets_circuit_breaker() {
         let controller = FakeController::default();
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller.clone(),
             SidecarSupervisorConfig {
@@ -880,7 +1008,8 @@ mod tests {
                 auto_restart_enabled: true,
                 captured_log_max_lines: DEFAULT_CAPTURED_LOG_MAX_LINES,
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         supervisor
             .handle_crash()
@@ -903,6 +1032,7 @@ mod tests {
         println!("[CIRCUIT_BREAKER_TEST] verifying manual reset re-enables auto-restart");
         let controller = FakeController::default();
         controller.set_fail_start(true);
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller.clone(),
             SidecarSupervisorConfig {
@@ -914,7 +1044,8 @@ mod tests {
                 auto_restart_enabled: true,
                 captured_log_max_lines: DEFAULT_CAPTURED_LOG_MAX_LINES,
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         let _ = supervisor
             .handle_crash()
@@ -1034,6 +1165,7 @@ mod tests {
     #[tokio::test]
     async fn auto_restart_toggle_disables_restart_attempts() {
         let controller = FakeController::default();
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller.clone(),
             SidecarSupervisorConfig {
@@ -1041,7 +1173,8 @@ mod tests {
                 backoff_base_ms: 0,
                 ..SidecarSupervisorConfig::default()
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         supervisor
             .handle_crash()
@@ -1057,6 +1190,7 @@ mod tests {
     #[tokio::test]
     async fn successful_restart_resets_backoff_counter() {
         let controller = FakeController::default();
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller.clone(),
             SidecarSupervisorConfig {
@@ -1068,7 +1202,8 @@ mod tests {
                 auto_restart_enabled: true,
                 captured_log_max_lines: DEFAULT_CAPTURED_LOG_MAX_LINES,
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         supervisor
             .handle_crash()
@@ -1090,6 +1225,7 @@ mod tests {
         println!("[SUPERVISOR_TEST] verifying reset after successful recovery");
         let controller = FakeController::default();
         controller.set_fail_start(true);
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller.clone(),
             SidecarSupervisorConfig {
@@ -1101,7 +1237,8 @@ mod tests {
                 auto_restart_enabled: true,
                 captured_log_max_lines: DEFAULT_CAPTURED_LOG_MAX_LINES,
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         let _ = supervisor
             .handle_crash()
@@ -1125,6 +1262,7 @@ mod tests {
     #[tokio::test]
     async fn backoff_resets_after_sustained_healthy_operation() {
         let controller = FakeController::default();
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller.clone(),
             SidecarSupervisorConfig {
@@ -1136,7 +1274,8 @@ mod tests {
                 auto_restart_enabled: true,
                 captured_log_max_lines: DEFAULT_CAPTURED_LOG_MAX_LINES,
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         // First crash: restart succeeds
         supervisor
@@ -1162,6 +1301,7 @@ mod tests {
     #[tokio::test]
     async fn backoff_does_not_reset_after_quick_crash() {
         let controller = FakeController::default();
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller.clone(),
             SidecarSupervisorConfig {
@@ -1173,7 +1313,8 @@ mod tests {
                 auto_restart_enabled: true,
                 captured_log_max_lines: DEFAULT_CAPTURED_LOG_MAX_LINES,
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         // First crash: restart succeeds, restart_count = 1
         supervisor
@@ -1200,6 +1341,7 @@ mod tests {
         // Regression: 3kwp — a hung (still alive) sidecar was never killed before
         // starting its replacement, leading to duplicate processes.
         let controller = FakeController::default();
+        let crash_dir = tempdir().unwrap();
         let mut supervisor = SidecarSupervisor::new(
             controller.clone(),
             SidecarSupervisorConfig {
@@ -1211,7 +1353,8 @@ mod tests {
                 auto_restart_enabled: true,
                 captured_log_max_lines: DEFAULT_CAPTURED_LOG_MAX_LINES,
             },
-        );
+        )
+        .with_crash_report_store(crash_store(&crash_dir));
 
         supervisor
             .handle_crash()
@@ -1269,4 +1412,57 @@ mod tests {
         );
         assert!(payload.get("message").is_none());
     }
+
+    #[test]
+    fn sidecar_role_routes_auxiliary_methods() {
+        assert_eq!(
+            SidecarRole::for_method("recording.start"),
+            SidecarRole::Live
+        );
+        assert_eq!(SidecarRole::for_method("asr.initialize"), SidecarRole::Live);
+        assert_eq!(
+            SidecarRole::for_method("batch.transcribe"),
+            SidecarRole::Auxiliary
+        );
+        assert_eq!(
+            SidecarRole::for_method("translate.text"),
+            SidecarRole::Auxiliary
+        );
+        assert_eq!(SidecarRole::Auxiliary.as_str(), "auxiliary");
+    }
+
+    #[tokio::test]
+    async fn supervisor_registry_keeps_roles_independent() {
+        let live = FakeController::default();
+        let auxiliary = FakeController::default();
+        let registry = SupervisorRegistry::new()
+            .with_supervisor(
+                SidecarRole::Live,
+                SidecarSupervisor::new(live.clone(), SidecarSupervisorConfig::default()),
+            )
+            .with_supervisor(
+                SidecarRole::Auxiliary,
+                SidecarSupervisor::new(auxiliary.clone(), SidecarSupervisorConfig::default()),
+            );
+
+        assert_eq!(
+            registry.roles().collect::<Vec<_>>(),
+            vec![SidecarRole::Live, SidecarRole::Auxiliary]
+        );
+
+        let auxiliary_supervisor = registry.get(SidecarRole::Auxiliary).unwrap();
+        auxiliary_supervisor.lock().await.start().await.unwrap();
+
+        assert_eq!(auxiliary.state().start_calls, 1);
+        assert_eq!(live.state().start_calls, 0);
+        assert_eq!(
+            registry
+                .get(SidecarRole::Live)
+                .unwrap()
+                .lock()
+                .await
+                .state(),
+            SidecarState::Stopped
+        );
+    }
 }
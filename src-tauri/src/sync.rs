@@ -0,0 +1,1201 @@
+//! Optional encrypted settings sync across machines.
+//!
+//! When `sync.enabled` is set, replacement rules, rule groups (profiles),
+//! vocabulary terms and the learned spelling dictionary are kept in a single
+//! document on a user-provided WebDAV or S3 endpoint. Transcripts and
+//! history are never synced. The document is encrypted with AES-256-GCM
+//! under a key derived from a passphrase kept in the OS keyring, so the
+//! server only ever stores ciphertext. As with release downloads, requests
+//! go through `curl`; credentials are passed on stdin rather than the
+//! command line, and S3 requests are signed with curl's `aws-sigv4`.
+//!
+//! Each sync is a three-way merge per item against the item hashes recorded
+//! after the previous sync (`sync_state.json` next to the config). An item
+//! changed on only one machine takes that change; a deletion applies unless
+//! the other machine edited the item. Items edited on both machines keep
+//! this machine's version, except learned words, where the most recent
+//! correction wins. Conflicts are reported in the [`SyncReport`].
+//!
+//! Uploads are conditional on the fetched document's ETag, so a device never
+//! overwrites a document it has not merged. Servers that send no ETag are
+//! refused.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use aes_gcm::aead::Aead;
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use rand::RngCore;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::config::{self, AppConfig, SyncConfig};
+use crate::learned_dictionary::{self, LearnedWord};
+use crate::network::{self, NetworkPurpose, OfflineBlocked};
+use crate::secrets::{self, SecretError};
+
+/// Sync state file name inside the config directory.
+const SYNC_STATE_FILE_NAME: &str = "sync_state.json";
+
+const SYNC_FORMAT: &str = "openvoicy-sync";
+const SYNC_FORMAT_VERSION: u32 = 1;
+
+/// PBKDF2-HMAC-SHA256 iterations for the document key (OWASP 2023 guidance).
+const KEY_DERIVATION_ITERATIONS: u32 = 600_000;
+const KEY_BYTES: usize = 32;
+const SALT_BYTES: usize = 16;
+const NONCE_BYTES: usize = 12;
+
+const REQUEST_TIMEOUT_SECS: u32 = 30;
+
+/// Merges attempted before giving up when another device keeps replacing
+/// the document between fetch and upload.
+const STORE_ATTEMPTS: usize = 3;
+
+/// How often the background loop checks whether a sync is due.
+const SYNC_LOOP_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Serializes manual and background syncs.
+static SYNC_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// Sync errors.
+#[derive(Debug, Error)]
+pub enum SyncError {
+    #[error("Sync is not set up: {0}")]
+    NotConfigured(String),
+
+    #[error(transparent)]
+    Secret(#[from] SecretError),
+
+    #[error("Sync request failed: {0}")]
+    Transport(String),
+
+    #[error("Sync data could not be decrypted; check that every device uses the same passphrase")]
+    Decrypt,
+
+    #[error("Sync data is invalid: {0}")]
+    Format(String),
+
+    #[error("The sync server sent no ETag, so uploads could overwrite other devices' changes")]
+    NoVersionTag,
+
+    #[error("Sync I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("Failed to save synced settings: {0}")]
+    Config(#[from] config::ConfigError),
+
+    #[error(transparent)]
+    Offline(#[from] OfflineBlocked),
+}
+
+/// Settings that are synced, each merged item by item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncSection {
+    ReplacementRules,
+    ReplacementGroups,
+    Vocabulary,
+    LearnedWords,
+}
+
+impl SyncSection {
+    const ALL: [SyncSection; 4] = [
+        SyncSection::ReplacementRules,
+        SyncSection::ReplacementGroups,
+        SyncSection::Vocabulary,
+        SyncSection::LearnedWords,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            SyncSection::ReplacementRules => "replacement_rules",
+            SyncSection::ReplacementGroups => "replacement_groups",
+            SyncSection::Vocabulary => "vocabulary",
+            SyncSection::LearnedWords => "learned_words",
+        }
+    }
+
+    /// Pick the version of an item edited on both machines.
+    fn resolve_conflict(self, local: &Value, remote: &Value) -> SyncSide {
+        match self {
+            SyncSection::LearnedWords if last_corrected(remote) > last_corrected(local) => {
+                SyncSide::Remote
+            }
+            _ => SyncSide::Local,
+        }
+    }
+
+    /// Human-readable name of an item for conflict reports.
+    fn item_label(self, key: &str, value: &Value) -> String {
+        let field = match self {
+            SyncSection::ReplacementRules => "pattern",
+            SyncSection::ReplacementGroups => "name",
+            SyncSection::LearnedWords => "word",
+            SyncSection::Vocabulary => return value.as_str().unwrap_or(key).to_string(),
+        };
+        value
+            .get(field)
+            .and_then(Value::as_str)
+            .filter(|label| !label.is_empty())
+            .unwrap_or(key)
+            .to_string()
+    }
+}
+
+fn last_corrected(value: &Value) -> Option<DateTime<Utc>> {
+    value
+        .get("last_corrected")
+        .and_then(Value::as_str)
+        .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+}
+
+/// Which machine's version of a conflicting item was kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncSide {
+    Local,
+    Remote,
+}
+
+/// An item changed on both this machine and another since the last sync.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SyncConflict {
+    pub section: SyncSection,
+    pub item: String,
+    pub kept: SyncSide,
+}
+
+/// Outcome of a sync.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SyncReport {
+    pub synced_at: DateTime<Utc>,
+    /// Items added, changed or removed on this machine.
+    pub pulled: usize,
+    /// Items added, changed or removed in the remote document.
+    pub pushed: usize,
+    pub conflicts: Vec<SyncConflict>,
+}
+
+/// One synced item, keyed by a stable identifier.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SyncItem {
+    key: String,
+    value: Value,
+}
+
+/// Synced items per section, keyed by [`SyncSection::as_str`].
+type Snapshot = BTreeMap<String, Vec<SyncItem>>;
+
+/// Decrypted contents of the remote document.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SyncDocument {
+    #[serde(default)]
+    sections: Snapshot,
+    #[serde(default)]
+    updated_at: Option<DateTime<Utc>>,
+}
+
+/// The remote document as stored on the server.
+#[derive(Debug, Serialize, Deserialize)]
+struct SyncEnvelope {
+    format: String,
+    version: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// What this machine remembers about the last sync.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SyncState {
+    /// Item hashes per section as of the last sync; the merge base.
+    #[serde(default)]
+    base: BTreeMap<String, BTreeMap<String, String>>,
+    #[serde(default)]
+    last_synced_at: Option<DateTime<Utc>>,
+}
+
+impl SyncState {
+    fn load(path: &Path) -> Self {
+        let Ok(contents) = fs::read_to_string(path) else {
+            return Self::default();
+        };
+        serde_json::from_str(&contents).unwrap_or_else(|error| {
+            log::warn!("Ignoring unreadable sync state: {}", error);
+            Self::default()
+        })
+    }
+
+    fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string_pretty(self)?;
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, contents)?;
+        fs::rename(&temp_path, path)
+    }
+}
+
+/// The stored sync document as fetched.
+pub struct RemoteDocument {
+    pub body: Vec<u8>,
+    /// Server version tag; None if the server sent none.
+    pub etag: Option<String>,
+}
+
+/// Where the encrypted sync document is stored.
+pub trait SyncTransport {
+    /// Fetch the document, or None if none has been uploaded yet.
+    fn fetch(&self) -> Result<Option<RemoteDocument>, SyncError>;
+    /// Replace the document if it is still `base` (no document at all when
+    /// None). Returns false if another device replaced it since the fetch.
+    fn store(&self, body: &[u8], base: Option<&RemoteDocument>) -> Result<bool, SyncError>;
+}
+
+/// WebDAV or S3 transport backed by `curl`.
+struct CurlTransport {
+    url: String,
+    /// AWS region for SigV4 signing; None for WebDAV.
+    s3_region: Option<String>,
+    /// "user:password" or "ACCESS_KEY:SECRET_KEY".
+    credentials: Option<String>,
+}
+
+impl CurlTransport {
+    fn from_config(sync: &SyncConfig) -> Result<Self, SyncError> {
+        let url = sync.url.trim();
+        if url.is_empty() {
+            return Err(SyncError::NotConfigured("no sync URL is set".to_string()));
+        }
+        if !is_allowed_url(url) {
+            return Err(SyncError::NotConfigured(
+                "the sync URL must use https".to_string(),
+            ));
+        }
+        let credentials = secrets::store().resolve(&sync.credentials_name)?;
+        let s3_region = (sync.backend == "s3").then(|| sync.s3_region.clone());
+        if s3_region.is_some() && credentials.is_none() {
+            return Err(SyncError::NotConfigured(format!(
+                "store the S3 access key pair as secret '{}'",
+                sync.credentials_name
+            )));
+        }
+        Ok(Self {
+            url: url.to_string(),
+            s3_region,
+            credentials,
+        })
+    }
+
+    /// curl options shared by every request, in curl config file syntax.
+    fn base_config(&self) -> String {
+        let mut config = format!(
+            "url = {}\nmax-time = {}\nwrite-out = \"\\n%header{{etag}}\\n%{{http_code}}\"\n",
+            curl_config_value(&self.url),
+            REQUEST_TIMEOUT_SECS
+        );
+        if let Some(credentials) = &self.credentials {
+            config.push_str(&format!("user = {}\n", curl_config_value(credentials)));
+        }
+        if let Some(region) = &self.s3_region {
+            config.push_str(&format!(
+                "aws-sigv4 = {}\n",
+                curl_config_value(&format!("aws:amz:{region}:s3"))
+            ));
+        }
+        config
+    }
+}
+
+impl SyncTransport for CurlTransport {
+    fn fetch(&self) -> Result<Option<RemoteDocument>, SyncError> {
+        network::check(NetworkPurpose::SettingsSync, &self.url)?;
+        let response = run_curl(&self.base_config())?;
+        match response.status {
+            200 => Ok(Some(RemoteDocument {
+                body: response.body,
+                etag: response.etag,
+            })),
+            404 => Ok(None),
+            status => Err(SyncError::Transport(format!(
+                "GET {} returned HTTP {}",
+                self.url, status
+            ))),
+        }
+    }
+
+    fn store(&self, body: &[u8], base: Option<&RemoteDocument>) -> Result<bool, SyncError> {
+        network::check(NetworkPurpose::SettingsSync, &self.url)?;
+        let precondition = store_precondition(base)?;
+        let upload_path =
+            std::env::temp_dir().join(format!("openvoicy-sync-{}.json", Uuid::new_v4()));
+        fs::write(&upload_path, body)?;
+        let mut config = self.base_config();
+        config.push_str(&format!(
+            "upload-file = {}\nheader = \"Content-Type: application/json\"\n",
+            curl_config_value(&upload_path.to_string_lossy())
+        ));
+        config.push_str(&format!("header = {}\n", curl_config_value(&precondition)));
+        let result = run_curl(&config);
+        let _ = fs::remove_file(&upload_path);
+
+        match result?.status {
+            200..=299 => Ok(true),
+            412 => Ok(false),
+            status => Err(SyncError::Transport(format!(
+                "PUT {} returned HTTP {}",
+                self.url, status
+            ))),
+        }
+    }
+}
+
+/// Header that makes an upload fail unless the document is still `base`.
+/// Without one, devices syncing at once overwrite each other, so a server
+/// that sent no ETag for the fetched document is refused.
+fn store_precondition(base: Option<&RemoteDocument>) -> Result<String, SyncError> {
+    match base {
+        None => Ok("If-None-Match: *".to_string()),
+        Some(base) => base
+            .etag
+            .as_ref()
+            .map(|etag| format!("If-Match: {etag}"))
+            .ok_or(SyncError::NoVersionTag),
+    }
+}
+
+/// Whether `url` may be used as a sync or crash report endpoint: https, or
+/// http on loopback.
+pub fn is_allowed_url(url: &str) -> bool {
+    let lower = url.to_ascii_lowercase();
+    lower.starts_with("https://")
+        || ["http://localhost", "http://127.0.0.1", "http://[::1]"]
+            .iter()
+            .any(|prefix| {
+                lower.strip_prefix(prefix).is_some_and(|rest| {
+                    rest.is_empty() || rest.starts_with(':') || rest.starts_with('/')
+                })
+            })
+}
+
+/// Quote `value` for a curl config file.
+fn curl_config_value(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for ch in value.chars() {
+        match ch {
+            '\\' => quoted.push_str("\\\\"),
+            '"' => quoted.push_str("\\\""),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Response from a curl request.
+struct CurlResponse {
+    status: u16,
+    etag: Option<String>,
+    body: Vec<u8>,
+}
+
+/// Run curl with `config` on stdin.
+fn run_curl(config: &str) -> Result<CurlResponse, SyncError> {
+    let mut child = Command::new("curl")
+        .args(["-sS", "--config", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| SyncError::Transport(format!("could not run curl: {e}")))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(config.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(SyncError::Transport(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    parse_curl_output(output.stdout)
+}
+
+/// Split the body from the ETag and status lines appended by `write-out`.
+fn parse_curl_output(mut stdout: Vec<u8>) -> Result<CurlResponse, SyncError> {
+    let mut take_last_line = || {
+        let split = stdout.iter().rposition(|&byte| byte == b'\n')?;
+        let line = String::from_utf8_lossy(&stdout[split + 1..])
+            .trim()
+            .to_string();
+        stdout.truncate(split);
+        Some(line)
+    };
+    let status = take_last_line()
+        .ok_or_else(|| SyncError::Transport("curl did not report an HTTP status".to_string()))?
+        .parse::<u16>()
+        .map_err(|_| SyncError::Transport("curl reported an invalid HTTP status".to_string()))?;
+    let etag = take_last_line()
+        .ok_or_else(|| SyncError::Transport("curl did not report the ETag".to_string()))?;
+    Ok(CurlResponse {
+        status,
+        etag: Some(etag).filter(|etag| !etag.is_empty()),
+        body: stdout,
+    })
+}
+
+/// Document key derived from the passphrase and the document's salt.
+struct DocumentKey {
+    salt: Vec<u8>,
+    cipher: Aes256Gcm,
+}
+
+impl DocumentKey {
+    fn derive(passphrase: &str, salt: Vec<u8>, iterations: u32) -> Self {
+        let mut key = [0u8; KEY_BYTES];
+        pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), &salt, iterations, &mut key);
+        Self {
+            salt,
+            cipher: Aes256Gcm::new(&key.into()),
+        }
+    }
+
+    fn seal(&self, document: &SyncDocument) -> Result<Vec<u8>, SyncError> {
+        let plaintext = serde_json::to_vec(document)
+            .map_err(|e| SyncError::Format(format!("could not encode sync document: {e}")))?;
+        let mut nonce = [0u8; NONCE_BYTES];
+        rand::rngs::OsRng.fill_bytes(&mut nonce);
+        let ciphertext = self
+            .cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|_| SyncError::Format("could not encrypt sync document".to_string()))?;
+        let envelope = SyncEnvelope {
+            format: SYNC_FORMAT.to_string(),
+            version: SYNC_FORMAT_VERSION,
+            salt: BASE64_STANDARD.encode(&self.salt),
+            nonce: BASE64_STANDARD.encode(nonce),
+            ciphertext: BASE64_STANDARD.encode(ciphertext),
+        };
+        serde_json::to_vec(&envelope)
+            .map_err(|e| SyncError::Format(format!("could not encode sync document: {e}")))
+    }
+
+    fn open(&self, envelope: &SyncEnvelope) -> Result<SyncDocument, SyncError> {
+        let nonce = decode_field(&envelope.nonce, "nonce")?;
+        if nonce.len() != NONCE_BYTES {
+            return Err(SyncError::Format("nonce has the wrong length".to_string()));
+        }
+        let ciphertext = decode_field(&envelope.ciphertext, "ciphertext")?;
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| SyncError::Decrypt)?;
+        serde_json::from_slice(&plaintext)
+            .map_err(|e| SyncError::Format(format!("unreadable sync document: {e}")))
+    }
+}
+
+fn decode_field(value: &str, name: &str) -> Result<Vec<u8>, SyncError> {
+    BASE64_STANDARD
+        .decode(value)
+        .map_err(|_| SyncError::Format(format!("{name} is not valid base64")))
+}
+
+fn parse_envelope(body: &[u8]) -> Result<SyncEnvelope, SyncError> {
+    let envelope: SyncEnvelope = serde_json::from_slice(body)
+        .map_err(|e| SyncError::Format(format!("not a sync document: {e}")))?;
+    if envelope.format != SYNC_FORMAT {
+        return Err(SyncError::Format(format!(
+            "unexpected format '{}'",
+            envelope.format
+        )));
+    }
+    if envelope.version > SYNC_FORMAT_VERSION {
+        return Err(SyncError::Format(format!(
+            "document version {} is newer than this app supports; update the app",
+            envelope.version
+        )));
+    }
+    Ok(envelope)
+}
+
+fn item_hash(value: &Value) -> String {
+    format!("{:x}", Sha256::digest(value.to_string().as_bytes()))
+}
+
+fn to_items<T: Serialize>(values: &[T], key: impl Fn(&T) -> String) -> Vec<SyncItem> {
+    let mut seen = HashSet::new();
+    values
+        .iter()
+        .filter_map(|value| {
+            let key = key(value);
+            // Duplicate keys would make the merge ambiguous; keep the first.
+            if !seen.insert(key.clone()) {
+                return None;
+            }
+            serde_json::to_value(value)
+                .ok()
+                .map(|value| SyncItem { key, value })
+        })
+        .collect()
+}
+
+fn from_items<T: DeserializeOwned>(
+    snapshot: &Snapshot,
+    section: SyncSection,
+) -> Result<Vec<T>, SyncError> {
+    snapshot
+        .get(section.as_str())
+        .into_iter()
+        .flatten()
+        .map(|item| {
+            serde_json::from_value(item.value.clone())
+                .map_err(|e| SyncError::Format(format!("invalid {} item: {}", section.as_str(), e)))
+        })
+        .collect()
+}
+
+/// The synced parts of this machine's settings.
+fn local_snapshot(config: &AppConfig, learned: &[LearnedWord]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    snapshot.insert(
+        SyncSection::ReplacementRules.as_str().to_string(),
+        to_items(&config.replacements, |rule| rule.id.clone()),
+    );
+    snapshot.insert(
+        SyncSection::ReplacementGroups.as_str().to_string(),
+        to_items(&config.replacement_groups.groups, |group| group.id.clone()),
+    );
+    snapshot.insert(
+        SyncSection::Vocabulary.as_str().to_string(),
+        to_items(&config.vocabulary.terms, |term| term.to_lowercase()),
+    );
+    snapshot.insert(
+        SyncSection::LearnedWords.as_str().to_string(),
+        to_items(learned, |entry| entry.word.to_lowercase()),
+    );
+    snapshot
+}
+
+/// Write merged items into `config`; returns the merged learned words.
+fn apply_snapshot(
+    config: &mut AppConfig,
+    snapshot: &Snapshot,
+) -> Result<Vec<LearnedWord>, SyncError> {
+    config.replacements = from_items(snapshot, SyncSection::ReplacementRules)?;
+    config.replacement_groups.groups = from_items(snapshot, SyncSection::ReplacementGroups)?;
+    config.vocabulary.terms = from_items(snapshot, SyncSection::Vocabulary)?;
+    from_items(snapshot, SyncSection::LearnedWords)
+}
+
+/// Three-way merge of one section against the hashes recorded at the last sync.
+///
+/// Local order is kept; items only present remotely are appended in remote order.
+fn merge_section(
+    section: SyncSection,
+    base: &BTreeMap<String, String>,
+    local: &[SyncItem],
+    remote: &[SyncItem],
+    conflicts: &mut Vec<SyncConflict>,
+) -> Vec<SyncItem> {
+    let local_values: HashMap<&str, &Value> = local
+        .iter()
+        .map(|item| (item.key.as_str(), &item.value))
+        .collect();
+    let remote_values: HashMap<&str, &Value> = remote
+        .iter()
+        .map(|item| (item.key.as_str(), &item.value))
+        .collect();
+    let unchanged = |key: &str, value: &Value| base.get(key) == Some(&item_hash(value));
+    let mut conflict = |key: &str, value: &Value, kept: SyncSide| {
+        conflicts.push(SyncConflict {
+            section,
+            item: section.item_label(key, value),
+            kept,
+        });
+    };
+
+    let mut merged = Vec::with_capacity(local.len().max(remote.len()));
+    for item in local {
+        let key = item.key.as_str();
+        let Some(&remote_value) = remote_values.get(key) else {
+            // Missing remotely: added here, or deleted on another machine.
+            if !base.contains_key(key) {
+                merged.push(item.clone());
+            } else if !unchanged(key, &item.value) {
+                conflict(key, &item.value, SyncSide::Local);
+                merged.push(item.clone());
+            }
+            continue;
+        };
+
+        let value = if *remote_value == item.value || unchanged(key, remote_value) {
+            &item.value
+        } else if unchanged(key, &item.value) {
+            remote_value
+        } else {
+            let kept = section.resolve_conflict(&item.value, remote_value);
+            conflict(key, &item.value, kept);
+            match kept {
+                SyncSide::Local => &item.value,
+                SyncSide::Remote => remote_value,
+            }
+        };
+        merged.push(SyncItem {
+            key: item.key.clone(),
+            value: value.clone(),
+        });
+    }
+
+    for item in remote {
+        let key = item.key.as_str();
+        if local_values.contains_key(key) {
+            continue;
+        }
+        // Missing here: added on another machine, or deleted here.
+        if !base.contains_key(key) {
+            merged.push(item.clone());
+        } else if !unchanged(key, &item.value) {
+            conflict(key, &item.value, SyncSide::Remote);
+            merged.push(item.clone());
+        }
+    }
+    merged
+}
+
+/// Number of items added, changed or removed going from `before` to `after`.
+fn count_changes(before: &[SyncItem], after: &[SyncItem]) -> usize {
+    let before: HashMap<&str, &Value> = before
+        .iter()
+        .map(|item| (item.key.as_str(), &item.value))
+        .collect();
+    let after: HashMap<&str, &Value> = after
+        .iter()
+        .map(|item| (item.key.as_str(), &item.value))
+        .collect();
+    let changed = after
+        .iter()
+        .filter(|(key, value)| before.get(*key) != Some(*value))
+        .count();
+    let removed = before
+        .keys()
+        .filter(|key| !after.contains_key(*key))
+        .count();
+    changed + removed
+}
+
+/// Merge `local` with the remote document, upload the result if it changed,
+/// and hand it to `apply` if this machine's settings changed.
+///
+/// The upload only replaces the version that was merged; if another device
+/// replaced it in the meantime, the merge starts over with the new version.
+///
+/// The merge base is only advanced once `apply` succeeds, so a failed local
+/// write is retried on the next sync instead of reverting the remote side.
+fn sync_with(
+    transport: &dyn SyncTransport,
+    passphrase: &str,
+    state_path: &Path,
+    local: &Snapshot,
+    iterations: u32,
+    apply: impl FnOnce(&Snapshot) -> Result<(), SyncError>,
+) -> Result<SyncReport, SyncError> {
+    let mut state = SyncState::load(state_path);
+
+    let mut attempts = 0;
+    let (merged, conflicts, pulled, pushed, synced_at) = loop {
+        attempts += 1;
+        let fetched = transport.fetch()?;
+        let (key, remote) = match &fetched {
+            Some(fetched) => {
+                let envelope = parse_envelope(&fetched.body)?;
+                let key = DocumentKey::derive(
+                    passphrase,
+                    decode_field(&envelope.salt, "salt")?,
+                    iterations,
+                );
+                let document = key.open(&envelope)?;
+                (key, Some(document.sections))
+            }
+            None => {
+                let mut salt = vec![0u8; SALT_BYTES];
+                rand::rngs::OsRng.fill_bytes(&mut salt);
+                (DocumentKey::derive(passphrase, salt, iterations), None)
+            }
+        };
+
+        let empty = Vec::new();
+        let no_base = BTreeMap::new();
+        let mut merged = Snapshot::new();
+        let mut conflicts = Vec::new();
+        let (mut pulled, mut pushed) = (0, 0);
+        for section in SyncSection::ALL {
+            let name = section.as_str();
+            let local_items = local.get(name).unwrap_or(&empty);
+            let remote_items = remote
+                .as_ref()
+                .and_then(|remote| remote.get(name))
+                .unwrap_or(&empty);
+            let items = merge_section(
+                section,
+                state.base.get(name).unwrap_or(&no_base),
+                local_items,
+                remote_items,
+                &mut conflicts,
+            );
+            pulled += count_changes(local_items, &items);
+            pushed += count_changes(remote_items, &items);
+            merged.insert(name.to_string(), items);
+        }
+
+        let synced_at = Utc::now();
+        if pushed > 0 || remote.is_none() {
+            let document = SyncDocument {
+                sections: merged.clone(),
+                updated_at: Some(synced_at),
+            };
+            // Another device uploaded since the fetch: merge with its version.
+            if !transport.store(&key.seal(&document)?, fetched.as_ref())? {
+                if attempts < STORE_ATTEMPTS {
+                    continue;
+                }
+                return Err(SyncError::Transport(
+                    "the sync document kept changing during upload".to_string(),
+                ));
+            }
+        }
+        break (merged, conflicts, pulled, pushed, synced_at);
+    };
+
+    if pulled > 0 {
+        apply(&merged)?;
+    }
+
+    state.base = merged
+        .iter()
+        .map(|(name, items)| {
+            let hashes = items
+                .iter()
+                .map(|item| (item.key.clone(), item_hash(&item.value)))
+                .collect();
+            (name.clone(), hashes)
+        })
+        .collect();
+    state.last_synced_at = Some(synced_at);
+    state.save(state_path)?;
+
+    Ok(SyncReport {
+        synced_at,
+        pulled,
+        pushed,
+        conflicts,
+    })
+}
+
+fn state_path() -> PathBuf {
+    config::config_dir().join(SYNC_STATE_FILE_NAME)
+}
+
+/// Sync settings with the configured endpoint.
+///
+/// Returns the report and, if remote changes were applied, the saved config.
+pub fn sync_now() -> Result<(SyncReport, Option<AppConfig>), SyncError> {
+    let _guard = SYNC_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut config = config::load_config();
+    if !config.sync.enabled {
+        return Err(SyncError::NotConfigured(
+            "enable sync in settings first".to_string(),
+        ));
+    }
+    let transport = CurlTransport::from_config(&config.sync)?;
+    let passphrase = secrets::store()
+        .resolve(&config.sync.passphrase_name)?
+        .ok_or_else(|| {
+            SyncError::NotConfigured(format!(
+                "store the sync passphrase as secret '{}'",
+                config.sync.passphrase_name
+            ))
+        })?;
+
+    let dictionary = learned_dictionary::global_dictionary();
+    let local = local_snapshot(&config, &dictionary.entries());
+    let mut applied = false;
+    let report = sync_with(
+        &transport,
+        &passphrase,
+        &state_path(),
+        &local,
+        KEY_DERIVATION_ITERATIONS,
+        |merged| {
+            let learned = apply_snapshot(&mut config, merged)?;
+            config.validate_and_clamp();
+            crate::policy::current().enforce(&mut config);
+            config::save_config(&config)?;
+            dictionary.replace_all(learned)?;
+            applied = true;
+            Ok(())
+        },
+    )?;
+
+    if !report.conflicts.is_empty() {
+        log::info!(
+            "Settings sync resolved {} conflict(s)",
+            report.conflicts.len()
+        );
+    }
+    Ok((report, applied.then_some(config)))
+}
+
+/// Whether a background sync is due, given when the last one was attempted.
+fn sync_due(sync: &SyncConfig, last_attempt: Option<Instant>, now: Instant) -> bool {
+    if !sync.enabled || sync.interval_minutes == 0 {
+        return false;
+    }
+    let interval = Duration::from_secs(u64::from(sync.interval_minutes) * 60);
+    last_attempt.is_none_or(|last| now.saturating_duration_since(last) >= interval)
+}
+
+/// Start the loop that syncs in the background while sync is enabled.
+///
+/// `on_applied` receives the saved config whenever a sync changed local settings.
+pub fn start_sync_loop(on_applied: impl Fn(AppConfig) + Send + 'static) {
+    crate::runtime::spawn(async move {
+        let mut poll = tokio::time::interval(SYNC_LOOP_POLL_INTERVAL);
+        poll.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut last_attempt = None;
+
+        loop {
+            poll.tick().await;
+            let now = Instant::now();
+            if !sync_due(&config::load_config().sync, last_attempt, now) {
+                continue;
+            }
+            last_attempt = Some(now);
+
+            match tokio::task::spawn_blocking(sync_now).await {
+                Ok(Ok((report, applied))) => {
+                    log::debug!(
+                        "Background sync: {} pulled, {} pushed",
+                        report.pulled,
+                        report.pushed
+                    );
+                    if let Some(config) = applied {
+                        on_applied(config);
+                    }
+                }
+                Ok(Err(error)) => log::warn!("Background sync failed: {}", error),
+                Err(error) => log::warn!("Background sync task failed: {}", error),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use tempfile::tempdir;
+
+    /// Key derivation rounds for tests; the real count is too slow in debug builds.
+    const TEST_ITERATIONS: u32 = 2;
+
+    type StoreHook = Box<dyn FnOnce(&MemoryTransport)>;
+
+    #[derive(Default)]
+    struct MemoryTransport {
+        /// The document and its version.
+        document: RefCell<Option<(Vec<u8>, u32)>>,
+        /// Runs once before the next store, as another device syncing.
+        before_store: RefCell<Option<StoreHook>>,
+    }
+
+    impl SyncTransport for MemoryTransport {
+        fn fetch(&self) -> Result<Option<RemoteDocument>, SyncError> {
+            Ok(self
+                .document
+                .borrow()
+                .as_ref()
+                .map(|(body, version)| RemoteDocument {
+                    body: body.clone(),
+                    etag: Some(version.to_string()),
+                }))
+        }
+
+        fn store(&self, body: &[u8], base: Option<&RemoteDocument>) -> Result<bool, SyncError> {
+            let before_store = self.before_store.borrow_mut().take();
+            if let Some(before_store) = before_store {
+                before_store(self);
+            }
+            let mut document = self.document.borrow_mut();
+            let current = document.as_ref().map(|(_, version)| version.to_string());
+            if current != base.and_then(|base| base.etag.clone()) {
+                return Ok(false);
+            }
+            let version = document.as_ref().map_or(1, |(_, version)| version + 1);
+            *document = Some((body.to_vec(), version));
+            Ok(true)
+        }
+    }
+
+    fn snapshot(terms: &[&str]) -> Snapshot {
+        let mut config = AppConfig::default();
+        config.vocabulary.terms = terms.iter().map(|term| term.to_string()).collect();
+        local_snapshot(&config, &[])
+    }
+
+    fn terms(snapshot: &Snapshot) -> Vec<String> {
+        from_items(snapshot, SyncSection::Vocabulary).unwrap()
+    }
+
+    /// Sync `local` and return the merged snapshot (or `local` if nothing was pulled).
+    fn sync(
+        transport: &MemoryTransport,
+        state_path: &Path,
+        local: &Snapshot,
+    ) -> (SyncReport, Snapshot) {
+        let mut result = local.clone();
+        let report = sync_with(
+            transport,
+            "correct horse",
+            state_path,
+            local,
+            TEST_ITERATIONS,
+            |merged| {
+                result = merged.clone();
+                Ok(())
+            },
+        )
+        .unwrap();
+        (report, result)
+    }
+
+    #[test]
+    fn test_document_round_trips_and_rejects_wrong_passphrase() {
+        let key = DocumentKey::derive("correct horse", vec![7; SALT_BYTES], TEST_ITERATIONS);
+        let document = SyncDocument {
+            sections: snapshot(&["OpenVoicy"]),
+            updated_at: None,
+        };
+        let body = key.seal(&document).unwrap();
+        assert!(!String::from_utf8_lossy(&body).contains("OpenVoicy"));
+
+        let envelope = parse_envelope(&body).unwrap();
+        assert_eq!(terms(&key.open(&envelope).unwrap().sections), ["OpenVoicy"]);
+
+        let wrong = DocumentKey::derive("battery staple", vec![7; SALT_BYTES], TEST_ITERATIONS);
+        assert!(matches!(wrong.open(&envelope), Err(SyncError::Decrypt)));
+    }
+
+    #[test]
+    fn test_changes_flow_between_machines() {
+        let dir = tempdir().unwrap();
+        let (laptop, desktop) = (
+            dir.path().join("laptop.json"),
+            dir.path().join("desktop.json"),
+        );
+        let transport = MemoryTransport::default();
+
+        let (report, _) = sync(&transport, &laptop, &snapshot(&["Tauri", "Rust"]));
+        assert_eq!((report.pulled, report.pushed), (0, 2));
+
+        let (report, merged) = sync(&transport, &desktop, &snapshot(&["Parakeet"]));
+        assert_eq!(terms(&merged), ["Parakeet", "Tauri", "Rust"]);
+        assert_eq!((report.pulled, report.pushed), (2, 1));
+
+        // A deletion on one machine reaches the other.
+        let (_, merged) = sync(&transport, &desktop, &snapshot(&["Parakeet", "Tauri"]));
+        assert_eq!(terms(&merged), ["Parakeet", "Tauri"]);
+        let (report, merged) = sync(&transport, &laptop, &snapshot(&["Tauri", "Rust"]));
+        assert_eq!(terms(&merged), ["Tauri", "Parakeet"]);
+        assert!(report.conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_concurrent_upload_is_merged_instead_of_overwritten() {
+        let dir = tempdir().unwrap();
+        let (laptop, desktop) = (
+            dir.path().join("laptop.json"),
+            dir.path().join("desktop.json"),
+        );
+        let transport = MemoryTransport::default();
+        sync(&transport, &laptop, &snapshot(&["Tauri"]));
+        sync(&transport, &desktop, &snapshot(&["Tauri"]));
+
+        // The desktop uploads between the laptop's fetch and upload.
+        let desktop_state = desktop.clone();
+        *transport.before_store.borrow_mut() = Some(Box::new(move |transport| {
+            sync(transport, &desktop_state, &snapshot(&["Tauri", "Parakeet"]));
+        }));
+        let (report, merged) = sync(&transport, &laptop, &snapshot(&["Tauri", "Rust"]));
+        assert_eq!(terms(&merged), ["Tauri", "Rust", "Parakeet"]);
+        assert_eq!((report.pulled, report.pushed), (1, 1));
+
+        // Neither device's addition was lost.
+        let (_, merged) = sync(&transport, &desktop, &snapshot(&["Tauri", "Parakeet"]));
+        assert_eq!(terms(&merged), ["Tauri", "Parakeet", "Rust"]);
+    }
+
+    #[test]
+    fn test_merge_reports_conflicts_and_keeps_local_edit() {
+        let base_value = Value::String("tauri".to_string());
+        let base = BTreeMap::from([("tauri".to_string(), item_hash(&base_value))]);
+        let local = vec![SyncItem {
+            key: "tauri".to_string(),
+            value: Value::String("Tauri".to_string()),
+        }];
+        let remote = vec![SyncItem {
+            key: "tauri".to_string(),
+            value: Value::String("TAURI".to_string()),
+        }];
+
+        let mut conflicts = Vec::new();
+        let merged = merge_section(
+            SyncSection::Vocabulary,
+            &base,
+            &local,
+            &remote,
+            &mut conflicts,
+        );
+        assert_eq!(merged, local);
+        assert_eq!(
+            conflicts,
+            [SyncConflict {
+                section: SyncSection::Vocabulary,
+                item: "Tauri".to_string(),
+                kept: SyncSide::Local,
+            }]
+        );
+
+        // An edit beats a deletion on the other machine.
+        let mut conflicts = Vec::new();
+        let merged = merge_section(SyncSection::Vocabulary, &base, &[], &remote, &mut conflicts);
+        assert_eq!(merged, remote);
+        assert_eq!(conflicts[0].kept, SyncSide::Remote);
+    }
+
+    #[test]
+    fn test_learned_word_conflict_keeps_latest_correction() {
+        let word = |spelling: &str, timestamp: &str| LearnedWord {
+            word: spelling.to_string(),
+            corrections: 2,
+            active: true,
+            last_corrected: timestamp.parse().unwrap(),
+        };
+        let local = to_items(&[word("PostgreSQL", "2026-01-01T00:00:00Z")], |entry| {
+            entry.word.to_lowercase()
+        });
+        let remote = to_items(&[word("PostGreSQL", "2026-02-01T00:00:00Z")], |entry| {
+            entry.word.to_lowercase()
+        });
+
+        let mut conflicts = Vec::new();
+        let merged = merge_section(
+            SyncSection::LearnedWords,
+            &BTreeMap::new(),
+            &local,
+            &remote,
+            &mut conflicts,
+        );
+        assert_eq!(merged, remote);
+        assert_eq!(conflicts[0].kept, SyncSide::Remote);
+    }
+
+    #[test]
+    fn test_snapshot_never_includes_history() {
+        let snapshot = local_snapshot(&AppConfig::default(), &[]);
+        let sections: Vec<&str> = snapshot.keys().map(String::as_str).collect();
+        assert_eq!(
+            sections,
+            [
+                "learned_words",
+                "replacement_groups",
+                "replacement_rules",
+                "vocabulary"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sync_due_respects_interval_and_manual_mode() {
+        let now = Instant::now();
+        let mut sync = SyncConfig {
+            enabled: true,
+            ..SyncConfig::default()
+        };
+        assert!(sync_due(&sync, None, now));
+        assert!(!sync_due(&sync, Some(now), now + Duration::from_secs(60)));
+        assert!(sync_due(
+            &sync,
+            Some(now),
+            now + Duration::from_secs(u64::from(sync.interval_minutes) * 60)
+        ));
+
+        sync.interval_minutes = 0;
+        assert!(!sync_due(&sync, None, now));
+        sync.interval_minutes = 30;
+        sync.enabled = false;
+        assert!(!sync_due(&sync, None, now));
+    }
+
+    #[test]
+    fn test_curl_config_quotes_values() {
+        assert_eq!(curl_config_value(r#"a"b\c"#), r#""a\"b\\c""#);
+        assert_eq!(curl_config_value("line\nbreak"), r#""line\nbreak""#);
+    }
+
+    #[test]
+    fn test_parse_curl_output_splits_status() {
+        let response = parse_curl_output(b"{\"a\":1}\n\"v7\"\n200".to_vec()).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.etag.as_deref(), Some("\"v7\""));
+        assert_eq!(response.body, b"{\"a\":1}");
+        let response = parse_curl_output(b"\n\n404".to_vec()).unwrap();
+        assert_eq!((response.status, response.etag), (404, None));
+        assert!(parse_curl_output(b"garbage".to_vec()).is_err());
+        assert!(parse_curl_output(b"\n200".to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_store_requires_a_precondition() {
+        assert_eq!(store_precondition(None).unwrap(), "If-None-Match: *");
+        let fetched = |etag: Option<&str>| RemoteDocument {
+            body: Vec::new(),
+            etag: etag.map(ToString::to_string),
+        };
+        assert_eq!(
+            store_precondition(Some(&fetched(Some("\"v7\"")))).unwrap(),
+            "If-Match: \"v7\""
+        );
+        assert!(matches!(
+            store_precondition(Some(&fetched(None))),
+            Err(SyncError::NoVersionTag)
+        ));
+    }
+
+    #[test]
+    fn test_sync_url_requires_https_except_loopback() {
+        assert!(is_allowed_url("https://dav.example.com/openvoicy.json"));
+        assert!(is_allowed_url("http://localhost:8080/sync.json"));
+        assert!(!is_allowed_url("http://dav.example.com/openvoicy.json"));
+        assert!(!is_allowed_url("http://localhost.example.com/sync.json"));
+    }
+}
@@ -0,0 +1,195 @@
+//! Responsiveness check for the app about to receive a transcript.
+//!
+//! An app that is busy (saving a large file, stuck in a modal loop) drops
+//! synthetic keystrokes and pastes. Before injecting, the session checks
+//! whether the foreground app responds; while it does not, the transcript
+//! waits in the pending insert queue and the check is repeated for up to
+//! `injection.busy_target_wait_secs` before falling back to the clipboard.
+//!
+//! Windows reads the hung flag of the foreground window (`IsHungAppWindow`),
+//! set once the window has not processed messages for a few seconds. macOS
+//! asks System Events for the front window of the frontmost app under a short
+//! AppleEvent timeout; the Accessibility query behind it times out while the
+//! app's event loop is blocked. Other platforms report
+//! [`Responsiveness::Unknown`], which never defers injection.
+
+use std::time::{Duration, Instant};
+
+/// How often a busy target is checked again.
+const BUSY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Whether the foreground app processes input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Responsiveness {
+    #[cfg_attr(not(any(target_os = "windows", target_os = "macos")), allow(dead_code))]
+    Responsive,
+    Busy,
+    /// Not detectable on this platform, or the check itself failed.
+    Unknown,
+}
+
+/// How a wait for a busy target ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusyWaitOutcome {
+    /// The target responds (or can no longer be checked).
+    Responsive,
+    /// The target was still busy when the wait ran out.
+    Expired,
+    /// `still_wanted` turned false before the target recovered.
+    Abandoned,
+}
+
+/// Check the foreground app. The check can block for up to a second, so it
+/// runs on the blocking pool.
+pub async fn check() -> Responsiveness {
+    tokio::task::spawn_blocking(detect)
+        .await
+        .unwrap_or(Responsiveness::Unknown)
+}
+
+/// Check the foreground app until it responds, `max_wait` passes, or
+/// `still_wanted` returns false.
+pub async fn wait_until_responsive(
+    max_wait: Duration,
+    still_wanted: impl Fn() -> bool,
+) -> BusyWaitOutcome {
+    wait_with(check, max_wait, BUSY_POLL_INTERVAL, still_wanted).await
+}
+
+async fn wait_with<F, Fut>(
+    check: F,
+    max_wait: Duration,
+    poll_interval: Duration,
+    still_wanted: impl Fn() -> bool,
+) -> BusyWaitOutcome
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = Responsiveness>,
+{
+    let deadline = Instant::now() + max_wait;
+    loop {
+        if !still_wanted() {
+            return BusyWaitOutcome::Abandoned;
+        }
+        if check().await != Responsiveness::Busy {
+            return BusyWaitOutcome::Responsive;
+        }
+        if Instant::now() >= deadline {
+            return BusyWaitOutcome::Expired;
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn detect() -> Responsiveness {
+    let window = unsafe { GetForegroundWindow() };
+    if window.is_null() {
+        return Responsiveness::Unknown;
+    }
+    if unsafe { IsHungAppWindow(window) } != 0 {
+        Responsiveness::Busy
+    } else {
+        Responsiveness::Responsive
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn detect() -> Responsiveness {
+    const SCRIPT: &str = "with timeout of 1 seconds\n\
+        tell application \"System Events\" to get name of front window of \
+        (first application process whose frontmost is true)\n\
+        end timeout";
+    match std::process::Command::new("osascript")
+        .args(["-e", SCRIPT])
+        .output()
+    {
+        Ok(output) if output.status.success() => Responsiveness::Responsive,
+        Ok(output) => classify_osascript_error(&String::from_utf8_lossy(&output.stderr)),
+        Err(_) => Responsiveness::Unknown,
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn detect() -> Responsiveness {
+    Responsiveness::Unknown
+}
+
+/// Busy when the query timed out: the AppleEvent timeout (-1712), or the
+/// Accessibility call that could not complete (-25204).
+#[cfg(any(target_os = "macos", test))]
+fn classify_osascript_error(stderr: &str) -> Responsiveness {
+    if stderr.contains("(-1712)") || stderr.contains("(-25204)") {
+        Responsiveness::Busy
+    } else {
+        Responsiveness::Unknown
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "user32")]
+unsafe extern "system" {
+    fn GetForegroundWindow() -> *mut std::ffi::c_void;
+    fn IsHungAppWindow(window: *mut std::ffi::c_void) -> i32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    const POLL: Duration = Duration::from_millis(1);
+
+    #[tokio::test]
+    async fn test_wait_returns_once_target_responds() {
+        let checks = Cell::new(0);
+        let check = || {
+            checks.set(checks.get() + 1);
+            let busy = checks.get() < 3;
+            async move {
+                if busy {
+                    Responsiveness::Busy
+                } else {
+                    Responsiveness::Responsive
+                }
+            }
+        };
+        let outcome = wait_with(check, Duration::from_secs(5), POLL, || true).await;
+        assert_eq!(outcome, BusyWaitOutcome::Responsive);
+        assert_eq!(checks.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_wait_expires_or_is_abandoned() {
+        let busy = || async { Responsiveness::Busy };
+        assert_eq!(
+            wait_with(busy, Duration::from_millis(20), POLL, || true).await,
+            BusyWaitOutcome::Expired
+        );
+        assert_eq!(
+            wait_with(busy, Duration::from_secs(5), POLL, || false).await,
+            BusyWaitOutcome::Abandoned
+        );
+
+        // Unknown never holds injection back.
+        let unknown = || async { Responsiveness::Unknown };
+        assert_eq!(
+            wait_with(unknown, Duration::ZERO, POLL, || true).await,
+            BusyWaitOutcome::Responsive
+        );
+    }
+
+    #[test]
+    fn test_classify_osascript_error() {
+        assert_eq!(
+            classify_osascript_error(
+                "execution error: System Events got an error: AppleEvent timed out. (-1712)"
+            ),
+            Responsiveness::Busy
+        );
+        // An app without windows is not busy.
+        let no_window = "execution error: Can’t get window 1 of application process \"Finder\". \
+                         Invalid index. (-1719)";
+        assert_eq!(classify_osascript_error(no_window), Responsiveness::Unknown);
+    }
+}
@@ -0,0 +1,228 @@
+//! What post-processing changed in a transcript.
+//!
+//! A history entry keeps the ASR's `raw_text` and the `final_text` produced
+//! by the sidecar (normalization, macros, replacement rules) and the learned
+//! dictionary. `transcript_diff` lines the two up word by word and, for each
+//! changed span, names the replacement rule or learned spelling that turns
+//! the raw words into the final ones. Attribution re-runs today's rules on
+//! the raw span, so a span stays unattributed when the rule that made it was
+//! since edited or removed, or when several stages touched the same words.
+
+use regex::NoExpand;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::config::ReplacementRule;
+use crate::model_compare::{word_diff, DiffOp};
+use crate::replacement_rules;
+
+/// How a span of the final text relates to the raw text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffSpanKind {
+    Equal,
+    /// Words only in the final text.
+    Insert,
+    /// Words dropped from the raw text.
+    Delete,
+    /// Raw words rewritten into different final words.
+    Replace,
+}
+
+/// Post-processing stage responsible for a change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffStage {
+    Replacement,
+    LearnedDictionary,
+}
+
+/// A run of words with the same diff kind.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffSpan {
+    pub kind: DiffSpanKind,
+    /// Words in the raw text; empty for `insert`.
+    pub raw: String,
+    /// Words in the final text; empty for `delete`.
+    pub r#final: String,
+    /// Stage that explains the change, when known.
+    pub stage: Option<DiffStage>,
+    /// Replacement rule behind a `replacement` change.
+    pub rule_id: Option<String>,
+}
+
+/// Result of the `get_transcript_diff` command.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TranscriptDiff {
+    pub entry_id: Uuid,
+    pub raw_text: String,
+    pub final_text: String,
+    pub spans: Vec<DiffSpan>,
+}
+
+/// Diff `raw_text` against `final_text`, attributing changes to `rules` (in
+/// application order) or to the learned spellings applied by `learned`.
+pub fn transcript_diff(
+    entry_id: Uuid,
+    raw_text: &str,
+    final_text: &str,
+    rules: &[ReplacementRule],
+    learned: impl Fn(&str) -> String,
+) -> TranscriptDiff {
+    let mut spans: Vec<DiffSpan> = Vec::new();
+    for segment in word_diff(raw_text, final_text) {
+        let (kind, raw, r#final) = match segment.op {
+            DiffOp::Equal => (DiffSpanKind::Equal, segment.text.clone(), segment.text),
+            DiffOp::Delete => (DiffSpanKind::Delete, segment.text, String::new()),
+            DiffOp::Insert => match spans.last_mut() {
+                // A deletion followed by an insertion is one rewrite.
+                Some(last) if last.kind == DiffSpanKind::Delete => {
+                    last.kind = DiffSpanKind::Replace;
+                    last.r#final = segment.text;
+                    continue;
+                }
+                _ => (DiffSpanKind::Insert, String::new(), segment.text),
+            },
+        };
+        spans.push(DiffSpan {
+            kind,
+            raw,
+            r#final,
+            stage: None,
+            rule_id: None,
+        });
+    }
+
+    // Inserted words have no raw words a stage could have rewritten.
+    for span in spans
+        .iter_mut()
+        .filter(|span| matches!(span.kind, DiffSpanKind::Delete | DiffSpanKind::Replace))
+    {
+        if let Some(rule) = rules
+            .iter()
+            .find(|rule| apply_rule(rule, &span.raw).trim() == span.r#final)
+        {
+            span.stage = Some(DiffStage::Replacement);
+            span.rule_id = Some(rule.id.clone());
+        } else if learned(&span.raw) == span.r#final {
+            span.stage = Some(DiffStage::LearnedDictionary);
+        }
+    }
+
+    TranscriptDiff {
+        entry_id,
+        raw_text: raw_text.to_string(),
+        final_text: final_text.to_string(),
+        spans,
+    }
+}
+
+/// `text` after one rule, the way the replacement pipeline applies it.
+fn apply_rule(rule: &ReplacementRule, text: &str) -> String {
+    let Ok(compiled) = replacement_rules::compile_rule(rule) else {
+        return text.to_string();
+    };
+    if rule.kind == "regex" {
+        compiled
+            .replace_all(text, rule.replacement.as_str())
+            .into_owned()
+    } else {
+        compiled
+            .replace_all(text, NoExpand(rule.replacement.as_str()))
+            .into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(id: &str, kind: &str, pattern: &str, replacement: &str) -> ReplacementRule {
+        ReplacementRule {
+            id: id.to_string(),
+            kind: kind.to_string(),
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            enabled: true,
+            word_boundary: true,
+            case_sensitive: false,
+            description: None,
+            origin: None,
+            group: None,
+            order: 0,
+        }
+    }
+
+    fn kinds(diff: &TranscriptDiff) -> Vec<DiffSpanKind> {
+        diff.spans.iter().map(|span| span.kind).collect()
+    }
+
+    #[test]
+    fn test_unchanged_text_is_one_equal_span() {
+        let diff = transcript_diff(Uuid::nil(), "hello world", "hello world", &[], |t| {
+            t.to_string()
+        });
+        assert_eq!(kinds(&diff), vec![DiffSpanKind::Equal]);
+        assert_eq!(diff.spans[0].raw, "hello world");
+        assert_eq!(diff.spans[0].r#final, "hello world");
+    }
+
+    #[test]
+    fn test_rewrites_are_attributed_to_the_matching_rule() {
+        let rules = [
+            rule("unrelated", "literal", "foo", "bar"),
+            rule("brb", "literal", "be right back", "brb"),
+            rule("um", "regex", r"u+m+", ""),
+        ];
+        let diff = transcript_diff(
+            Uuid::nil(),
+            "um I will be right back soon",
+            "I will brb soon",
+            &rules,
+            |t| t.to_string(),
+        );
+
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                DiffSpanKind::Delete,
+                DiffSpanKind::Equal,
+                DiffSpanKind::Replace,
+                DiffSpanKind::Equal,
+            ]
+        );
+        assert_eq!(diff.spans[0].rule_id.as_deref(), Some("um"));
+        assert_eq!(diff.spans[2].raw, "be right back");
+        assert_eq!(diff.spans[2].r#final, "brb");
+        assert_eq!(diff.spans[2].stage, Some(DiffStage::Replacement));
+        assert_eq!(diff.spans[2].rule_id.as_deref(), Some("brb"));
+    }
+
+    #[test]
+    fn test_learned_spellings_and_unknown_changes() {
+        let diff = transcript_diff(
+            Uuid::nil(),
+            "ask openai about it today",
+            "ask OpenAI about it today!",
+            &[],
+            |t| t.replace("openai", "OpenAI"),
+        );
+
+        assert_eq!(diff.spans[1].kind, DiffSpanKind::Replace);
+        assert_eq!(diff.spans[1].stage, Some(DiffStage::LearnedDictionary));
+        assert!(diff.spans[1].rule_id.is_none());
+        assert_eq!(diff.spans[3].raw, "today");
+        assert_eq!(diff.spans[3].r#final, "today!");
+        assert!(diff.spans[3].stage.is_none());
+    }
+
+    #[test]
+    fn test_inserted_words_serialize_with_final_key() {
+        let diff = transcript_diff(Uuid::nil(), "hello", "hello there", &[], |t| t.to_string());
+        let json = serde_json::to_value(&diff.spans[1]).unwrap();
+        assert_eq!(json["kind"], "insert");
+        assert_eq!(json["raw"], "");
+        assert_eq!(json["final"], "there");
+        assert_eq!(json["stage"], serde_json::Value::Null);
+    }
+}
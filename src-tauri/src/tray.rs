@@ -4,10 +4,15 @@
 //! - System tray icon that changes based on app state
 //! - State-aware dynamic context menu
 //! - Tooltip showing current status
+//! - Today's word count and audio time, linking to the statistics page
+//!
+//! Labels come from the `i18n` catalog in the configured UI language; the menu
+//! is rebuilt when the language changes since it is part of the menu state.
 
 use std::sync::{Arc, RwLock as StdRwLock};
 use std::time::Duration;
 
+use chrono::{Local, NaiveDate};
 use tauri::menu::{
     CheckMenuItemBuilder, Menu, MenuEvent, MenuId, MenuItemBuilder, PredefinedMenuItem, Submenu,
 };
@@ -15,20 +20,29 @@ use tauri::tray::{TrayIcon, TrayIconBuilder, TrayIconEvent};
 use tauri::{image::Image, AppHandle, Emitter, Listener, Manager};
 use tokio::sync::RwLock;
 
+use crate::command_audit::CommandAudit;
 use crate::config::{self, HotkeyMode};
+use crate::errors::IntegrationError;
 use crate::history::TranscriptHistory;
-use crate::state::{AppState, AppStateManager};
+use crate::i18n;
+use crate::pending_inserts::PendingInsertQueue;
+use crate::state::{AppState, AppStateManager, EnableLevel};
+use crate::stats::{self, UsageTotals};
 
 /// Tray menu item IDs.
 mod menu_ids {
     pub const HEADER: &str = "header";
     pub const TOGGLE_ENABLED: &str = "toggle_enabled";
+    pub const ENABLE_LEVEL_SUBMENU: &str = "enable_level_submenu";
     pub const TOGGLE_RECORDING: &str = "toggle_recording";
     pub const CANCEL_RECORDING: &str = "cancel_recording";
     pub const MODE_STATUS: &str = "mode_status";
     pub const LANGUAGE_STATUS: &str = "language_status";
     pub const MIC_SUBMENU: &str = "mic_submenu";
+    pub const INJECTION_MODE_SUBMENU: &str = "injection_mode_submenu";
     pub const RECENT_SUBMENU: &str = "recent_submenu";
+    pub const STATS_SUMMARY: &str = "stats_summary";
+    pub const PENDING_INSERT_SUBMENU: &str = "pending_insert_submenu";
     pub const TOGGLE_OVERLAY: &str = "toggle_overlay";
     pub const MODEL_STATUS: &str = "model_status";
     pub const SIDECAR_STATUS: &str = "sidecar_status";
@@ -36,6 +50,9 @@ mod menu_ids {
 
     pub const SELECT_MIC_PREFIX: &str = "select_mic::";
     pub const COPY_RECENT_PREFIX: &str = "copy_recent::";
+    pub const INJECT_PENDING_PREFIX: &str = "inject_pending::";
+    pub const SET_INJECTION_MODE_PREFIX: &str = "set_injection_mode::";
+    pub const SET_ENABLE_LEVEL_PREFIX: &str = "set_enable_level::";
 }
 
 /// Tray icon file paths (embedded at compile time).
@@ -49,6 +66,8 @@ const ICON_DISABLED: &[u8] = include_bytes!("../icons/tray-disabled.png");
 const MAX_RECENT_TRANSCRIPTS: usize = 5;
 const MAX_RECENT_TRANSCRIPT_CHARS: usize = 50;
 const TRAY_REBUILD_POLL_INTERVAL_MS: u64 = 100;
+/// Blink period of the tray icon under `ui.always_visible_recording_indicator`.
+const TRAY_FLASH_INTERVAL_MS: u64 = 500;
 #[cfg(test)]
 const TRAY_REFLECTION_TARGET_MS: u64 = 250;
 
@@ -87,22 +106,59 @@ impl TrayDeviceCache {
     }
 }
 
+/// Today's usage totals for the tray summary. Recomputed from history when a
+/// transcript completes rather than on every menu poll.
+pub struct TrayStatsCache(StdRwLock<Option<(NaiveDate, UsageTotals)>>);
+
+impl TrayStatsCache {
+    pub fn new() -> Self {
+        Self(StdRwLock::new(None))
+    }
+
+    /// Recompute today's totals from history.
+    pub fn refresh(&self, history: &TranscriptHistory) {
+        let summary = stats::usage_stats(&history.all());
+        *self.0.write().expect("TrayStatsCache poisoned") =
+            Some((Local::now().date_naive(), summary.today));
+    }
+
+    /// Today's totals; empty once the cached day has passed.
+    pub fn today(&self) -> UsageTotals {
+        match *self.0.read().expect("TrayStatsCache poisoned") {
+            Some((date, totals)) if date == Local::now().date_naive() => totals,
+            _ => UsageTotals::default(),
+        }
+    }
+}
+
 /// Pure-state snapshot used to build a deterministic tray menu.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TrayMenuState {
-    pub enabled: bool,
+    pub enable_level: EnableLevel,
     pub recording: bool,
     pub transcribing: bool,
     pub mode: String,
     pub language: Option<String>,
     pub current_device: Option<String>,
     pub devices: Vec<TrayAudioDevice>,
+    /// Global output mode (`injection.mode`).
+    pub injection_mode: String,
     /// Recent transcripts as `(entry_id, text)` pairs for stable menu item identity.
     pub recent_transcripts: Vec<(String, String)>,
+    /// Clipboard-only transcripts awaiting insertion as `(entry_id, text)` pairs.
+    pub pending_inserts: Vec<(String, String)>,
     pub overlay_enabled: bool,
     pub model_status: String,
     pub sidecar_state: String,
     pub window_visible: bool,
+    /// Catalog language for labels (see `i18n::resolve_language`).
+    pub ui_language: String,
+    /// Primary hotkey binding shown next to the recording actions; empty hides it.
+    pub hotkey: String,
+    /// Words dictated today.
+    pub today_words: u64,
+    /// Audio recorded today, in whole minutes.
+    pub today_audio_minutes: u64,
 }
 
 /// Pure tray menu tree, independent of any Tauri runtime handles.
@@ -126,7 +182,9 @@ pub enum TrayMenuEntry {
         enabled: bool,
         items: Vec<TrayMenuEntry>,
     },
-    Quit,
+    Quit {
+        text: String,
+    },
 }
 
 type SystemTrayMenu = Menu<tauri::Wry>;
@@ -139,9 +197,15 @@ fn should_rebuild_menu(last: Option<&TrayMenuState>, current: &TrayMenuState) ->
 }
 
 /// Get the appropriate icon bytes for the given state.
-fn get_icon_for_state(state: AppState, enabled: bool) -> &'static [u8] {
-    if !enabled {
-        return ICON_DISABLED;
+fn get_icon_for_state(state: AppState, level: EnableLevel) -> &'static [u8] {
+    match (level, state) {
+        (EnableLevel::FullyPaused, _) | (EnableLevel::HotkeysPaused, AppState::Idle) => {
+            return ICON_DISABLED;
+        }
+        (EnableLevel::Stealth, AppState::Recording | AppState::Transcribing) => {
+            return ICON_IDLE;
+        }
+        _ => {}
     }
 
     match state {
@@ -153,52 +217,140 @@ fn get_icon_for_state(state: AppState, enabled: bool) -> &'static [u8] {
     }
 }
 
-/// Get the tooltip text for the given state.
-fn get_tooltip_text(state: AppState, enabled: bool) -> &'static str {
-    if !enabled {
-        return "OpenVoicy - Paused";
+/// Icon for one frame of the recording flash.
+fn get_flash_icon(lit: bool) -> &'static [u8] {
+    if lit {
+        ICON_RECORDING
+    } else {
+        ICON_IDLE
     }
+}
 
-    match state {
-        AppState::Idle => "OpenVoicy - Ready",
-        AppState::Recording => "OpenVoicy - Recording...",
-        AppState::Transcribing => "OpenVoicy - Processing...",
-        AppState::LoadingModel => "OpenVoicy - Loading model...",
-        AppState::Error => "OpenVoicy - Error",
+/// Get the tooltip text for the given state.
+fn get_tooltip_text(ui_language: &str, state: AppState, level: EnableLevel) -> &'static str {
+    match (level, state) {
+        (EnableLevel::FullyPaused, _) => return i18n::text(ui_language, "tray.tooltip.paused"),
+        (EnableLevel::HotkeysPaused, AppState::Idle) => {
+            return i18n::text(ui_language, "tray.tooltip.hotkeys_paused");
+        }
+        (EnableLevel::Stealth, AppState::Idle | AppState::Recording | AppState::Transcribing) => {
+            return i18n::text(ui_language, "tray.tooltip.stealth");
+        }
+        _ => {}
     }
+
+    let key = match state {
+        AppState::Idle => "tray.tooltip.ready",
+        AppState::Recording => "tray.tooltip.recording",
+        AppState::Transcribing => "tray.tooltip.processing",
+        AppState::LoadingModel => "tray.tooltip.loading_model",
+        AppState::Error => "tray.tooltip.error",
+    };
+    i18n::text(ui_language, key)
 }
 
-fn normalize_mode_label(mode: &str) -> &'static str {
+fn normalize_mode_label(ui_language: &str, mode: &str) -> &'static str {
     if mode.eq_ignore_ascii_case("toggle") {
-        "Toggle"
+        i18n::text(ui_language, "tray.mode.toggle")
     } else {
-        "Hold"
+        i18n::text(ui_language, "tray.mode.hold")
     }
 }
 
-fn language_label(language: Option<&str>) -> String {
+fn language_label(ui_language: &str, language: Option<&str>) -> String {
     match language {
-        Some("auto") | None => "Language: Auto".to_string(),
-        Some(value) => format!("Language: {}", value),
+        Some("auto") | None => i18n::text(ui_language, "tray.language.auto").to_string(),
+        Some(value) => i18n::format_text(ui_language, "tray.language", &[("language", value)]),
     }
 }
 
-fn window_label(window_visible: bool) -> &'static str {
+/// Output modes offered in the tray, as `(config value, label key)` pairs.
+const INJECTION_MODE_OPTIONS: [(&str, &str); 3] = [
+    ("inject", "tray.output.inject"),
+    ("clipboard_only", "tray.output.clipboard_only"),
+    ("ask", "tray.output.ask"),
+];
+
+fn injection_mode_label(ui_language: &str, mode: &str) -> &'static str {
+    let key = INJECTION_MODE_OPTIONS
+        .iter()
+        .find(|(value, _)| *value == mode)
+        .map(|(_, key)| *key)
+        .unwrap_or(INJECTION_MODE_OPTIONS[0].1);
+    i18n::text(ui_language, key)
+}
+
+fn window_label(ui_language: &str, window_visible: bool) -> &'static str {
     if window_visible {
-        "Hide Window"
+        i18n::text(ui_language, "tray.hide_window")
     } else {
-        "Show Window"
+        i18n::text(ui_language, "tray.show_window")
     }
 }
 
-fn enabled_toggle_label(enabled: bool) -> &'static str {
+fn enabled_toggle_label(ui_language: &str, enabled: bool) -> &'static str {
     if enabled {
-        "Disable OpenVoicy"
+        i18n::text(ui_language, "tray.disable")
     } else {
-        "Enable OpenVoicy"
+        i18n::text(ui_language, "tray.enable")
+    }
+}
+
+/// Pause levels offered in the tray, as `(level, label key)` pairs.
+const ENABLE_LEVEL_OPTIONS: [(EnableLevel, &str); 4] = [
+    (EnableLevel::Enabled, "tray.enable_level.enabled"),
+    (
+        EnableLevel::HotkeysPaused,
+        "tray.enable_level.hotkeys_paused",
+    ),
+    (EnableLevel::FullyPaused, "tray.enable_level.fully_paused"),
+    (EnableLevel::Stealth, "tray.enable_level.stealth"),
+];
+
+/// Start/stop label, with the primary hotkey appended when one is bound.
+fn recording_action_label(ui_language: &str, recording: bool, hotkey: &str) -> String {
+    match (recording, hotkey.is_empty()) {
+        (false, true) => i18n::text(ui_language, "tray.start_recording").to_string(),
+        (true, true) => i18n::text(ui_language, "tray.stop_recording").to_string(),
+        (false, false) => i18n::format_text(
+            ui_language,
+            "tray.start_recording_with_hotkey",
+            &[("hotkey", hotkey)],
+        ),
+        (true, false) => i18n::format_text(
+            ui_language,
+            "tray.stop_recording_with_hotkey",
+            &[("hotkey", hotkey)],
+        ),
     }
 }
 
+/// Localized model/sidecar status, falling back to the raw status value.
+fn status_label(ui_language: &str, status: &str) -> String {
+    let key = match status {
+        "ready" => "tray.status.ready",
+        "loading" => "tray.status.loading",
+        "paused" => "tray.status.paused",
+        "error" => "tray.status.error",
+        "failed" => "tray.status.failed",
+        _ => return status.to_string(),
+    };
+    i18n::text(ui_language, key).to_string()
+}
+
+/// Today's totals, e.g. "Today: 3,214 words, 26 min audio".
+fn stats_summary_label(ui_language: &str, words: u64, audio_minutes: u64) -> String {
+    let separator = i18n::text(ui_language, "number.group_separator");
+    i18n::format_text(
+        ui_language,
+        "tray.stats.today",
+        &[
+            ("words", &stats::group_thousands(words, separator)),
+            ("minutes", &stats::group_thousands(audio_minutes, separator)),
+        ],
+    )
+}
+
 fn truncate_for_menu(text: &str, max_chars: usize) -> String {
     let trimmed = text.trim();
     let chars_count = trimmed.chars().count();
@@ -221,6 +373,7 @@ fn truncate_for_menu(text: &str, max_chars: usize) -> String {
 
 /// Pure menu builder: deterministic for a given input state.
 pub fn build_tray_menu(state: &TrayMenuState) -> Vec<TrayMenuEntry> {
+    let ui = state.ui_language.as_str();
     let mut recent_items = Vec::new();
     for (entry_id, transcript) in state.recent_transcripts.iter().take(MAX_RECENT_TRANSCRIPTS) {
         recent_items.push(TrayMenuEntry::Action {
@@ -232,7 +385,7 @@ pub fn build_tray_menu(state: &TrayMenuState) -> Vec<TrayMenuEntry> {
     if recent_items.is_empty() {
         recent_items.push(TrayMenuEntry::Action {
             id: "recent_empty".to_string(),
-            text: "No recent transcripts".to_string(),
+            text: i18n::text(ui, "tray.recent.empty").to_string(),
             enabled: false,
         });
     }
@@ -241,7 +394,7 @@ pub fn build_tray_menu(state: &TrayMenuState) -> Vec<TrayMenuEntry> {
     if state.devices.is_empty() {
         mic_items.push(TrayMenuEntry::Action {
             id: "mic_empty".to_string(),
-            text: "No microphone devices".to_string(),
+            text: i18n::text(ui, "tray.mic.empty").to_string(),
             enabled: false,
         });
     } else {
@@ -255,6 +408,26 @@ pub fn build_tray_menu(state: &TrayMenuState) -> Vec<TrayMenuEntry> {
         }
     }
 
+    let injection_mode_items = INJECTION_MODE_OPTIONS
+        .iter()
+        .map(|(value, key)| TrayMenuEntry::Toggle {
+            id: format!("{}{}", menu_ids::SET_INJECTION_MODE_PREFIX, value),
+            text: i18n::text(ui, key).to_string(),
+            enabled: true,
+            checked: state.injection_mode == *value,
+        })
+        .collect();
+
+    let enable_level_items = ENABLE_LEVEL_OPTIONS
+        .iter()
+        .map(|(level, key)| TrayMenuEntry::Toggle {
+            id: format!("{}{}", menu_ids::SET_ENABLE_LEVEL_PREFIX, level.as_str()),
+            text: i18n::text(ui, key).to_string(),
+            enabled: true,
+            checked: state.enable_level == *level,
+        })
+        .collect();
+
     let mut entries = vec![
         TrayMenuEntry::Action {
             id: menu_ids::HEADER.to_string(),
@@ -264,34 +437,62 @@ pub fn build_tray_menu(state: &TrayMenuState) -> Vec<TrayMenuEntry> {
         TrayMenuEntry::Separator,
         TrayMenuEntry::Toggle {
             id: menu_ids::TOGGLE_ENABLED.to_string(),
-            text: enabled_toggle_label(state.enabled).to_string(),
+            text: enabled_toggle_label(ui, state.enable_level.hotkeys_active()).to_string(),
+            enabled: true,
+            checked: state.enable_level.hotkeys_active(),
+        },
+        TrayMenuEntry::Submenu {
+            id: menu_ids::ENABLE_LEVEL_SUBMENU.to_string(),
+            text: i18n::text(ui, "tray.enable_level").to_string(),
             enabled: true,
-            checked: state.enabled,
+            items: enable_level_items,
         },
     ];
 
     if state.transcribing {
         entries.push(TrayMenuEntry::Action {
             id: menu_ids::TOGGLE_RECORDING.to_string(),
-            text: "Processing...".to_string(),
+            text: i18n::text(ui, "tray.processing").to_string(),
             enabled: false,
         });
     } else if state.recording {
         entries.push(TrayMenuEntry::Action {
             id: menu_ids::TOGGLE_RECORDING.to_string(),
-            text: "Stop Recording".to_string(),
-            enabled: state.enabled,
+            text: recording_action_label(ui, true, &state.hotkey),
+            enabled: state.enable_level.allows_recording(),
         });
         entries.push(TrayMenuEntry::Action {
             id: menu_ids::CANCEL_RECORDING.to_string(),
-            text: "Cancel Recording".to_string(),
-            enabled: state.enabled,
+            text: i18n::text(ui, "tray.cancel_recording").to_string(),
+            enabled: state.enable_level.allows_recording(),
         });
     } else {
         entries.push(TrayMenuEntry::Action {
             id: menu_ids::TOGGLE_RECORDING.to_string(),
-            text: "Start Recording".to_string(),
-            enabled: state.enabled,
+            text: recording_action_label(ui, false, &state.hotkey),
+            enabled: state.enable_level.allows_recording(),
+        });
+    }
+
+    if !state.pending_inserts.is_empty() {
+        let pending_items = state
+            .pending_inserts
+            .iter()
+            .map(|(entry_id, transcript)| TrayMenuEntry::Action {
+                id: format!("{}{}", menu_ids::INJECT_PENDING_PREFIX, entry_id),
+                text: truncate_for_menu(transcript, MAX_RECENT_TRANSCRIPT_CHARS),
+                enabled: true,
+            })
+            .collect();
+        entries.push(TrayMenuEntry::Submenu {
+            id: menu_ids::PENDING_INSERT_SUBMENU.to_string(),
+            text: i18n::format_text(
+                ui,
+                "tray.pending_insert",
+                &[("count", &state.pending_inserts.len().to_string())],
+            ),
+            enabled: true,
+            items: pending_items,
         });
     }
 
@@ -299,56 +500,87 @@ pub fn build_tray_menu(state: &TrayMenuState) -> Vec<TrayMenuEntry> {
         TrayMenuEntry::Separator,
         TrayMenuEntry::Action {
             id: menu_ids::MODE_STATUS.to_string(),
-            text: format!("Mode: {}", normalize_mode_label(&state.mode)),
+            text: i18n::format_text(
+                ui,
+                "tray.mode",
+                &[("mode", normalize_mode_label(ui, &state.mode))],
+            ),
             enabled: false,
         },
         TrayMenuEntry::Action {
             id: menu_ids::LANGUAGE_STATUS.to_string(),
-            text: language_label(state.language.as_deref()),
+            text: language_label(ui, state.language.as_deref()),
             enabled: false,
         },
         TrayMenuEntry::Separator,
         TrayMenuEntry::Submenu {
             id: menu_ids::MIC_SUBMENU.to_string(),
             text: match state.current_device.as_deref() {
-                Some(name) if !name.is_empty() => format!("Mic: {}", name),
-                _ => "Mic: System Default".to_string(),
+                Some(name) if !name.is_empty() => {
+                    i18n::format_text(ui, "tray.mic", &[("device", name)])
+                }
+                _ => i18n::text(ui, "tray.mic.default").to_string(),
             },
             enabled: true,
             items: mic_items,
         },
+        TrayMenuEntry::Submenu {
+            id: menu_ids::INJECTION_MODE_SUBMENU.to_string(),
+            text: i18n::format_text(
+                ui,
+                "tray.output",
+                &[("mode", injection_mode_label(ui, &state.injection_mode))],
+            ),
+            enabled: true,
+            items: injection_mode_items,
+        },
         TrayMenuEntry::Separator,
         TrayMenuEntry::Submenu {
             id: menu_ids::RECENT_SUBMENU.to_string(),
-            text: "Recent".to_string(),
+            text: i18n::text(ui, "tray.recent").to_string(),
             enabled: true,
             items: recent_items,
         },
+        TrayMenuEntry::Action {
+            id: menu_ids::STATS_SUMMARY.to_string(),
+            text: stats_summary_label(ui, state.today_words, state.today_audio_minutes),
+            enabled: true,
+        },
         TrayMenuEntry::Separator,
         TrayMenuEntry::Toggle {
             id: menu_ids::TOGGLE_OVERLAY.to_string(),
-            text: "Show Overlay".to_string(),
+            text: i18n::text(ui, "tray.show_overlay").to_string(),
             enabled: true,
             checked: state.overlay_enabled,
         },
         TrayMenuEntry::Separator,
         TrayMenuEntry::Action {
             id: menu_ids::MODEL_STATUS.to_string(),
-            text: format!("Model: {}", state.model_status),
+            text: i18n::format_text(
+                ui,
+                "tray.model",
+                &[("status", &status_label(ui, &state.model_status))],
+            ),
             enabled: false,
         },
         TrayMenuEntry::Action {
             id: menu_ids::SIDECAR_STATUS.to_string(),
-            text: format!("Sidecar: {}", state.sidecar_state),
+            text: i18n::format_text(
+                ui,
+                "tray.sidecar",
+                &[("status", &status_label(ui, &state.sidecar_state))],
+            ),
             enabled: false,
         },
         TrayMenuEntry::Separator,
         TrayMenuEntry::Action {
             id: menu_ids::TOGGLE_WINDOW.to_string(),
-            text: window_label(state.window_visible).to_string(),
+            text: window_label(ui, state.window_visible).to_string(),
             enabled: true,
         },
-        TrayMenuEntry::Quit,
+        TrayMenuEntry::Quit {
+            text: i18n::text(ui, "tray.quit").to_string(),
+        },
     ]);
 
     entries
@@ -393,8 +625,8 @@ fn append_entry_to_submenu(
             }
             submenu.append(&nested_submenu)?;
         }
-        TrayMenuEntry::Quit => {
-            submenu.append(&PredefinedMenuItem::quit(app, Some("Quit"))?)?;
+        TrayMenuEntry::Quit { text } => {
+            submenu.append(&PredefinedMenuItem::quit(app, Some(text))?)?;
         }
     }
 
@@ -440,16 +672,16 @@ fn append_entry_to_menu(
             }
             menu.append(&submenu)?;
         }
-        TrayMenuEntry::Quit => {
-            menu.append(&PredefinedMenuItem::quit(app, Some("Quit"))?)?;
+        TrayMenuEntry::Quit { text } => {
+            menu.append(&PredefinedMenuItem::quit(app, Some(text))?)?;
         }
     }
 
     Ok(())
 }
 
-fn map_state_to_model_status(state: AppState, enabled: bool) -> &'static str {
-    if !enabled {
+fn map_state_to_model_status(state: AppState, level: EnableLevel) -> &'static str {
+    if !level.allows_recording() {
         return "paused";
     }
 
@@ -467,7 +699,11 @@ fn map_state_to_sidecar_status(state: AppState) -> &'static str {
     }
 }
 
-fn load_runtime_tray_menu_state(app: &AppHandle, state: AppState, enabled: bool) -> TrayMenuState {
+fn load_runtime_tray_menu_state(
+    app: &AppHandle,
+    state: AppState,
+    level: EnableLevel,
+) -> TrayMenuState {
     let current_config = config::load_config();
     let history = app.state::<TranscriptHistory>();
     let recent_transcripts = history
@@ -476,8 +712,17 @@ fn load_runtime_tray_menu_state(app: &AppHandle, state: AppState, enabled: bool)
         .map(|entry| (entry.id.to_string(), entry.text))
         .take(MAX_RECENT_TRANSCRIPTS)
         .collect::<Vec<_>>();
+    let pending_inserts = app
+        .state::<PendingInsertQueue>()
+        .all()
+        .into_iter()
+        .map(|item| (item.entry_id.to_string(), item.text))
+        .collect::<Vec<_>>();
 
-    let mode = match current_config.hotkeys.mode {
+    let today = app.state::<TrayStatsCache>().today();
+
+    let hotkeys = current_config.effective_hotkeys();
+    let mode = match hotkeys.mode {
         HotkeyMode::Hold => "hold".to_string(),
         HotkeyMode::Toggle => "toggle".to_string(),
     };
@@ -495,18 +740,24 @@ fn load_runtime_tray_menu_state(app: &AppHandle, state: AppState, enabled: bool)
         .unwrap_or(false);
 
     TrayMenuState {
-        enabled,
+        enable_level: level,
         recording: state == AppState::Recording,
         transcribing: state == AppState::Transcribing,
         mode,
         language,
         current_device,
         devices: app.state::<TrayDeviceCache>().get(),
+        injection_mode: current_config.injection.mode.clone(),
         recent_transcripts,
+        pending_inserts,
         overlay_enabled: current_config.ui.overlay_enabled,
-        model_status: map_state_to_model_status(state, enabled).to_string(),
+        model_status: map_state_to_model_status(state, level).to_string(),
         sidecar_state: map_state_to_sidecar_status(state).to_string(),
         window_visible,
+        ui_language: i18n::resolve_language(current_config.ui.locale.as_deref()).to_string(),
+        hotkey: hotkeys.primary,
+        today_words: today.words,
+        today_audio_minutes: today.audio_minutes(),
     }
 }
 
@@ -580,18 +831,22 @@ fn build_system_tray_menu(
 fn setup_tray(app: &AppHandle) -> Result<TrayIcon, tauri::Error> {
     let state_manager = app.state::<Arc<AppStateManager>>();
     let initial_state = state_manager.get();
-    let initial_enabled = state_manager.is_enabled();
-    let tray_menu_state = load_runtime_tray_menu_state(app, initial_state, initial_enabled);
+    let initial_level = state_manager.level();
+    let tray_menu_state = load_runtime_tray_menu_state(app, initial_state, initial_level);
     let menu = build_system_tray_menu(app, &tray_menu_state)?;
 
     // Load the initial icon.
-    let icon = load_png_icon(get_icon_for_state(initial_state, initial_enabled))
+    let icon = load_png_icon(get_icon_for_state(initial_state, initial_level))
         .map_err(|e| tauri::Error::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
 
     // Build the tray icon.
     let tray = TrayIconBuilder::new()
         .icon(icon)
-        .tooltip(get_tooltip_text(initial_state, initial_enabled))
+        .tooltip(get_tooltip_text(
+            &tray_menu_state.ui_language,
+            initial_state,
+            initial_level,
+        ))
         .menu(&menu)
         .show_menu_on_left_click(true)
         .on_menu_event(handle_menu_event)
@@ -618,6 +873,20 @@ fn toggle_window_visibility(app: &AppHandle) {
     }
 }
 
+/// Bring up the main window on the statistics page.
+fn open_statistics_page(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+    let _ = app.emit(
+        "app:navigate",
+        crate::event_seq::payload_with_next_seq(serde_json::json!({
+            "tab": "statistics",
+        })),
+    );
+}
+
 fn toggle_overlay_setting() -> Result<bool, String> {
     let mut cfg = config::load_config();
     cfg.ui.overlay_enabled = !cfg.ui.overlay_enabled;
@@ -650,7 +919,9 @@ fn trigger_tray_recording_action(app: &AppHandle, action: &'static str) {
             "start" => manager.start_recording().await,
             "stop" => manager.stop_recording().await,
             "cancel" => manager.cancel_recording().await,
-            _ => Err(format!("Unknown tray recording action: {action}")),
+            _ => Err(IntegrationError::InvalidInput(format!(
+                "Unknown tray recording action: {action}"
+            ))),
         };
 
         match result {
@@ -683,6 +954,51 @@ fn handle_menu_event(app: &AppHandle, event: MenuEvent) {
         return;
     }
 
+    if let Some(entry_id) = id.strip_prefix(menu_ids::INJECT_PENDING_PREFIX) {
+        let app = app.clone();
+        let entry_id = entry_id.to_string();
+        tauri::async_runtime::spawn(async move {
+            match crate::commands::inject_pending_insert(
+                CommandAudit::internal("inject_pending_insert", "tray"),
+                app,
+                entry_id.clone(),
+            )
+            .await
+            {
+                Ok(()) => log::info!("Inserted pending transcript {} from tray", entry_id),
+                Err(err) => log::warn!("Pending transcript {} not inserted: {}", entry_id, err),
+            }
+        });
+        return;
+    }
+
+    if let Some(mode) = id.strip_prefix(menu_ids::SET_INJECTION_MODE_PREFIX) {
+        match crate::commands::set_injection_mode(
+            CommandAudit::internal("set_injection_mode", "tray"),
+            app.clone(),
+            mode.to_string(),
+        ) {
+            Ok(mode) => log::info!("Injection mode set from tray: {}", mode),
+            Err(err) => log::warn!("Failed to set injection mode from tray: {}", err),
+        }
+        return;
+    }
+
+    if let Some(level) = id.strip_prefix(menu_ids::SET_ENABLE_LEVEL_PREFIX) {
+        match crate::commands::set_enable_level(
+            CommandAudit::internal("set_enable_level", "tray"),
+            app.state::<Arc<AppStateManager>>(),
+            level.to_string(),
+        ) {
+            Ok(()) => {
+                log::info!("Enable level set from tray: {}", level);
+                emit_tray_update(app, "enabled_toggled");
+            }
+            Err(err) => log::warn!("Failed to set enable level from tray: {}", err),
+        }
+        return;
+    }
+
     if let Some(device_uid) = id.strip_prefix(menu_ids::SELECT_MIC_PREFIX) {
         match select_microphone(device_uid) {
             Ok(()) => {
@@ -696,7 +1012,10 @@ fn handle_menu_event(app: &AppHandle, event: MenuEvent) {
 
     match id {
         menu_ids::TOGGLE_ENABLED => {
-            let now_enabled = crate::commands::toggle_enabled(app.state::<Arc<AppStateManager>>());
+            let now_enabled = crate::commands::toggle_enabled(
+                CommandAudit::internal("toggle_enabled", "tray"),
+                app.state::<Arc<AppStateManager>>(),
+            );
             log::info!("Enabled toggled to {}", now_enabled);
             emit_tray_update(app, "enabled_toggled");
         }
@@ -704,6 +1023,10 @@ fn handle_menu_event(app: &AppHandle, event: MenuEvent) {
             toggle_window_visibility(app);
             emit_tray_update(app, "window_visibility_changed");
         }
+        menu_ids::STATS_SUMMARY => {
+            open_statistics_page(app);
+            emit_tray_update(app, "window_visibility_changed");
+        }
         menu_ids::TOGGLE_OVERLAY => match toggle_overlay_setting() {
             Ok(now_enabled) => {
                 log::info!("Overlay toggled to {}", now_enabled);
@@ -778,25 +1101,36 @@ impl TrayManager {
     }
 
     /// Update tray icon, tooltip, and dynamic menu from current state.
-    pub fn update_state(&self, state: AppState, enabled: bool) -> Result<(), String> {
+    pub fn update_state(&self, state: AppState, level: EnableLevel) -> Result<(), String> {
         let tray = self
             .tray
             .as_ref()
             .ok_or_else(|| "Tray not initialized".to_string())?;
 
-        let icon_bytes = get_icon_for_state(state, enabled);
+        let icon_bytes = get_icon_for_state(state, level);
         let icon = load_png_icon(icon_bytes)?;
         tray.set_icon(Some(icon)).map_err(|e| e.to_string())?;
 
-        let tooltip = get_tooltip_text(state, enabled);
+        let menu_state = load_runtime_tray_menu_state(&self.app_handle, state, level);
+        let tooltip = get_tooltip_text(&menu_state.ui_language, state, level);
         tray.set_tooltip(Some(tooltip)).map_err(|e| e.to_string())?;
 
-        let state = load_runtime_tray_menu_state(&self.app_handle, state, enabled);
-        let menu = build_system_tray_menu(&self.app_handle, &state).map_err(|e| e.to_string())?;
+        let menu =
+            build_system_tray_menu(&self.app_handle, &menu_state).map_err(|e| e.to_string())?;
         tray.set_menu(Some(menu)).map_err(|e| e.to_string())?;
 
         Ok(())
     }
+
+    /// Show one frame of the recording flash; `update_state` restores the icon.
+    pub fn set_flash_frame(&self, lit: bool) -> Result<(), String> {
+        let tray = self
+            .tray
+            .as_ref()
+            .ok_or_else(|| "Tray not initialized".to_string())?;
+        let icon = load_png_icon(get_flash_icon(lit))?;
+        tray.set_icon(Some(icon)).map_err(|e| e.to_string())
+    }
 }
 
 /// Start the tray state update loop.
@@ -808,17 +1142,38 @@ pub fn start_tray_loop(
     tokio::spawn(async move {
         let mut receiver = state_manager.subscribe();
         let (rebuild_tx, mut rebuild_rx) = tokio::sync::mpsc::unbounded_channel::<()>();
-        let listener_id = app_handle.listen("tray:update", move |_| {
+        let refresh_stats = {
+            let app_handle = app_handle.clone();
+            move || {
+                app_handle
+                    .state::<TrayStatsCache>()
+                    .refresh(&app_handle.state::<TranscriptHistory>());
+            }
+        };
+        refresh_stats();
+        let listener_id = {
+            let rebuild_tx = rebuild_tx.clone();
+            let refresh_stats = refresh_stats.clone();
+            app_handle.listen("tray:update", move |_| {
+                refresh_stats();
+                let _ = rebuild_tx.send(());
+            })
+        };
+        let transcript_listener_id = app_handle.listen("transcript:complete", move |_| {
+            refresh_stats();
             let _ = rebuild_tx.send(());
         });
         let mut poll = tokio::time::interval(Duration::from_millis(TRAY_REBUILD_POLL_INTERVAL_MS));
         poll.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut flash = tokio::time::interval(Duration::from_millis(TRAY_FLASH_INTERVAL_MS));
+        flash.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut flash_lit = true;
         let mut last_state = state_manager.get();
-        let mut last_enabled = state_manager.is_enabled();
+        let mut last_level = state_manager.level();
         let mut last_menu_state = Some(load_runtime_tray_menu_state(
             &app_handle,
             last_state,
-            last_enabled,
+            last_level,
         ));
 
         log::info!("Tray update loop started");
@@ -830,10 +1185,10 @@ pub fn start_tray_loop(
                         Ok(event) => {
                             let started = std::time::Instant::now();
                             last_state = event.state;
-                            last_enabled = event.enabled;
+                            last_level = event.level;
 
                             let tray = tray_manager.read().await;
-                            if let Err(e) = tray.update_state(last_state, last_enabled) {
+                            if let Err(e) = tray.update_state(last_state, last_level) {
                                 log::warn!("Failed to update tray: {}", e);
                             } else {
                                 log::info!(
@@ -847,7 +1202,7 @@ pub fn start_tray_loop(
                             last_menu_state = Some(load_runtime_tray_menu_state(
                                 &app_handle,
                                 last_state,
-                                last_enabled,
+                                last_level,
                             ));
                         }
                         Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
@@ -858,11 +1213,11 @@ pub fn start_tray_loop(
                 }
                 _ = poll.tick() => {
                     let current_menu_state =
-                        load_runtime_tray_menu_state(&app_handle, last_state, last_enabled);
+                        load_runtime_tray_menu_state(&app_handle, last_state, last_level);
                     if should_rebuild_menu(last_menu_state.as_ref(), &current_menu_state) {
                         let started = std::time::Instant::now();
                         let tray = tray_manager.read().await;
-                        if let Err(e) = tray.update_state(last_state, last_enabled) {
+                        if let Err(e) = tray.update_state(last_state, last_level) {
                             log::warn!("Failed to refresh tray menu: {}", e);
                         } else {
                             log::info!(
@@ -873,12 +1228,24 @@ pub fn start_tray_loop(
                         }
                     }
                 }
+                _ = flash.tick(), if last_state == AppState::Recording && last_level.allows_recording() => {
+                    // Settle on the lit frame if the flag is turned off mid-recording.
+                    let flashing = config::load_config().ui.always_visible_recording_indicator;
+                    let next_lit = !flashing || !flash_lit;
+                    if next_lit != flash_lit {
+                        flash_lit = next_lit;
+                        let tray = tray_manager.read().await;
+                        if let Err(e) = tray.set_flash_frame(flash_lit) {
+                            log::warn!("Failed to flash tray icon: {}", e);
+                        }
+                    }
+                }
                 Some(_) = rebuild_rx.recv() => {
                     let current_menu_state =
-                        load_runtime_tray_menu_state(&app_handle, last_state, last_enabled);
+                        load_runtime_tray_menu_state(&app_handle, last_state, last_level);
                     let started = std::time::Instant::now();
                     let tray = tray_manager.read().await;
-                    if let Err(e) = tray.update_state(last_state, last_enabled) {
+                    if let Err(e) = tray.update_state(last_state, last_level) {
                         log::warn!("Failed to process tray:update trigger: {}", e);
                     } else {
                         log::info!(
@@ -892,6 +1259,7 @@ pub fn start_tray_loop(
         }
 
         app_handle.unlisten(listener_id);
+        app_handle.unlisten(transcript_listener_id);
         log::info!("Tray update loop ended");
     });
 }
@@ -911,7 +1279,7 @@ mod tests {
 
     fn sample_state() -> TrayMenuState {
         TrayMenuState {
-            enabled: true,
+            enable_level: EnableLevel::Enabled,
             recording: false,
             transcribing: false,
             mode: "hold".to_string(),
@@ -927,63 +1295,127 @@ mod tests {
                     name: "USB Mic".to_string(),
                 },
             ],
+            injection_mode: "inject".to_string(),
             recent_transcripts: vec![
                 ("id-1".to_string(), "first short transcript".to_string()),
                 ("id-2".to_string(), "second short transcript".to_string()),
             ],
+            pending_inserts: Vec::new(),
             overlay_enabled: true,
             model_status: "ready".to_string(),
             sidecar_state: "ready".to_string(),
             window_visible: false,
+            ui_language: "en".to_string(),
+            hotkey: String::new(),
+            today_words: 3214,
+            today_audio_minutes: 26,
         }
     }
 
     #[test]
     fn test_get_icon_for_state_disabled() {
-        let icon = get_icon_for_state(AppState::Idle, false);
+        let icon = get_icon_for_state(AppState::Idle, EnableLevel::FullyPaused);
         assert_eq!(icon, ICON_DISABLED);
     }
 
+    #[test]
+    fn test_get_icon_for_state_per_enable_level() {
+        // Hotkeys paused: paused icon at rest, real state while recording from the tray.
+        assert_eq!(
+            get_icon_for_state(AppState::Idle, EnableLevel::HotkeysPaused),
+            ICON_DISABLED
+        );
+        assert_eq!(
+            get_icon_for_state(AppState::Recording, EnableLevel::HotkeysPaused),
+            ICON_RECORDING
+        );
+        assert_eq!(
+            get_icon_for_state(AppState::Recording, EnableLevel::FullyPaused),
+            ICON_DISABLED
+        );
+        // Stealth: no recording or processing indicator.
+        assert_eq!(
+            get_icon_for_state(AppState::Recording, EnableLevel::Stealth),
+            ICON_IDLE
+        );
+        assert_eq!(
+            get_icon_for_state(AppState::Transcribing, EnableLevel::Stealth),
+            ICON_IDLE
+        );
+        assert_eq!(
+            get_icon_for_state(AppState::Error, EnableLevel::Stealth),
+            ICON_ERROR
+        );
+    }
+
     #[test]
     fn test_get_icon_for_state_enabled() {
-        let icon = get_icon_for_state(AppState::Idle, true);
+        let icon = get_icon_for_state(AppState::Idle, EnableLevel::Enabled);
         assert_eq!(icon, ICON_IDLE);
 
-        let icon = get_icon_for_state(AppState::Recording, true);
+        let icon = get_icon_for_state(AppState::Recording, EnableLevel::Enabled);
         assert_eq!(icon, ICON_RECORDING);
 
-        let icon = get_icon_for_state(AppState::Transcribing, true);
+        let icon = get_icon_for_state(AppState::Transcribing, EnableLevel::Enabled);
         assert_eq!(icon, ICON_TRANSCRIBING);
 
-        let icon = get_icon_for_state(AppState::LoadingModel, true);
+        let icon = get_icon_for_state(AppState::LoadingModel, EnableLevel::Enabled);
         assert_eq!(icon, ICON_LOADING);
 
-        let icon = get_icon_for_state(AppState::Error, true);
+        let icon = get_icon_for_state(AppState::Error, EnableLevel::Enabled);
         assert_eq!(icon, ICON_ERROR);
     }
 
+    #[test]
+    fn test_get_flash_icon_alternates_recording_and_idle() {
+        assert_eq!(get_flash_icon(true), ICON_RECORDING);
+        assert_eq!(get_flash_icon(false), ICON_IDLE);
+    }
+
     #[test]
     fn test_get_tooltip_text_disabled() {
-        let text = get_tooltip_text(AppState::Idle, false);
+        let text = get_tooltip_text("en", AppState::Idle, EnableLevel::FullyPaused);
         assert_eq!(text, "OpenVoicy - Paused");
     }
 
+    #[test]
+    fn test_get_tooltip_text_per_enable_level() {
+        assert_eq!(
+            get_tooltip_text("en", AppState::Idle, EnableLevel::HotkeysPaused),
+            "OpenVoicy - Hotkeys Paused"
+        );
+        assert_eq!(
+            get_tooltip_text("en", AppState::Recording, EnableLevel::HotkeysPaused),
+            "OpenVoicy - Recording..."
+        );
+        assert_eq!(
+            get_tooltip_text("en", AppState::Recording, EnableLevel::Stealth),
+            "OpenVoicy - Stealth"
+        );
+    }
+
     #[test]
     fn test_get_tooltip_text_enabled() {
-        assert_eq!(get_tooltip_text(AppState::Idle, true), "OpenVoicy - Ready");
         assert_eq!(
-            get_tooltip_text(AppState::Recording, true),
+            get_tooltip_text("en", AppState::Idle, EnableLevel::Enabled),
+            "OpenVoicy - Ready"
+        );
+        assert_eq!(
+            get_tooltip_text("en", AppState::Recording, EnableLevel::Enabled),
             "OpenVoicy - Recording..."
         );
         assert_eq!(
-            get_tooltip_text(AppState::Transcribing, true),
+            get_tooltip_text("en", AppState::Transcribing, EnableLevel::Enabled),
             "OpenVoicy - Processing..."
         );
         assert_eq!(
-            get_tooltip_text(AppState::LoadingModel, true),
+            get_tooltip_text("en", AppState::LoadingModel, EnableLevel::Enabled),
             "OpenVoicy - Loading model..."
         );
-        assert_eq!(get_tooltip_text(AppState::Error, true), "OpenVoicy - Error");
+        assert_eq!(
+            get_tooltip_text("en", AppState::Error, EnableLevel::Enabled),
+            "OpenVoicy - Error"
+        );
     }
 
     #[test]
@@ -1054,7 +1486,38 @@ mod tests {
             )
         }));
 
-        assert!(matches!(menu.last(), Some(TrayMenuEntry::Quit)));
+        assert!(matches!(menu.last(), Some(TrayMenuEntry::Quit { .. })));
+    }
+
+    #[test]
+    fn test_build_tray_menu_injection_mode_submenu_checks_current_mode() {
+        let mut state = sample_state();
+        state.injection_mode = "clipboard_only".to_string();
+
+        let menu = build_tray_menu(&state);
+        let items = menu
+            .iter()
+            .find_map(|entry| match entry {
+                TrayMenuEntry::Submenu {
+                    id, text, items, ..
+                } if id == menu_ids::INJECTION_MODE_SUBMENU => {
+                    assert_eq!(text, "Output: Clipboard Only");
+                    Some(items.clone())
+                }
+                _ => None,
+            })
+            .expect("injection mode submenu");
+
+        let checked: Vec<&str> = items
+            .iter()
+            .filter_map(|item| match item {
+                TrayMenuEntry::Toggle {
+                    id, checked: true, ..
+                } => Some(id.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(checked, vec!["set_injection_mode::clipboard_only"]);
     }
 
     #[test]
@@ -1161,10 +1624,78 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn test_build_tray_menu_recording_labels_include_hotkey() {
+        let mut state = sample_state();
+        state.hotkey = "Ctrl+Alt+Space".to_string();
+
+        let menu = build_tray_menu(&state);
+        assert!(menu.iter().any(|entry| {
+            matches!(
+                entry,
+                TrayMenuEntry::Action { id, text, .. }
+                    if id == menu_ids::TOGGLE_RECORDING
+                        && text == "Start Recording (Ctrl+Alt+Space)"
+            )
+        }));
+
+        state.recording = true;
+        let menu = build_tray_menu(&state);
+        assert!(menu.iter().any(|entry| {
+            matches!(
+                entry,
+                TrayMenuEntry::Action { id, text, .. }
+                    if id == menu_ids::TOGGLE_RECORDING
+                        && text == "Stop Recording (Ctrl+Alt+Space)"
+            )
+        }));
+    }
+
+    #[test]
+    fn test_build_tray_menu_uses_ui_language() {
+        let mut state = sample_state();
+        state.ui_language = "de".to_string();
+        state.hotkey = "Ctrl+Alt+Space".to_string();
+
+        let menu = build_tray_menu(&state);
+        assert!(menu.iter().any(|entry| {
+            matches!(
+                entry,
+                TrayMenuEntry::Action { id, text, .. }
+                    if id == menu_ids::TOGGLE_RECORDING
+                        && text == "Aufnahme starten (Ctrl+Alt+Space)"
+            )
+        }));
+        assert!(menu.iter().any(|entry| {
+            matches!(
+                entry,
+                TrayMenuEntry::Action { id, text, .. }
+                    if id == menu_ids::MODEL_STATUS && text == "Modell: bereit"
+            )
+        }));
+        assert!(matches!(
+            menu.last(),
+            Some(TrayMenuEntry::Quit { text }) if text == "Beenden"
+        ));
+        assert_eq!(
+            get_tooltip_text(&state.ui_language, AppState::Idle, EnableLevel::Enabled),
+            "OpenVoicy - Bereit"
+        );
+    }
+
+    #[test]
+    fn test_language_change_triggers_menu_rebuild() {
+        let previous = sample_state();
+        let mut current = previous.clone();
+        current.ui_language = "fr".to_string();
+
+        assert!(should_rebuild_menu(Some(&previous), &current));
+    }
+
     #[test]
     fn test_build_tray_menu_disables_recording_controls_when_app_disabled() {
         let mut recording_state = sample_state();
-        recording_state.enabled = false;
+        recording_state.enable_level = EnableLevel::FullyPaused;
         recording_state.recording = true;
 
         let recording_menu = build_tray_menu(&recording_state);
@@ -1184,7 +1715,7 @@ mod tests {
         }));
 
         let mut transcribing_state = sample_state();
-        transcribing_state.enabled = false;
+        transcribing_state.enable_level = EnableLevel::FullyPaused;
         transcribing_state.transcribing = true;
         let transcribing_menu = build_tray_menu(&transcribing_state);
         assert!(transcribing_menu.iter().any(|entry| {
@@ -1237,7 +1768,7 @@ mod tests {
         }));
 
         let mut disabled_state = sample_state();
-        disabled_state.enabled = false;
+        disabled_state.enable_level = EnableLevel::FullyPaused;
         let disabled_menu = build_tray_menu(&disabled_state);
         assert!(disabled_menu.iter().any(|entry| {
             matches!(
@@ -1250,6 +1781,45 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn test_build_tray_menu_enable_level_submenu_marks_current_level() {
+        let mut state = sample_state();
+        state.enable_level = EnableLevel::HotkeysPaused;
+        let menu = build_tray_menu(&state);
+        let level_items = menu
+            .iter()
+            .find_map(|entry| match entry {
+                TrayMenuEntry::Submenu { id, items, .. }
+                    if id == menu_ids::ENABLE_LEVEL_SUBMENU =>
+                {
+                    Some(items)
+                }
+                _ => None,
+            })
+            .expect("enable level submenu should exist");
+
+        let checked = level_items
+            .iter()
+            .filter_map(|entry| match entry {
+                TrayMenuEntry::Toggle {
+                    id, checked: true, ..
+                } => Some(id.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(checked, vec!["set_enable_level::hotkeys_paused"]);
+        assert_eq!(level_items.len(), EnableLevel::ALL.len());
+
+        // The tray can still record while only hotkeys are paused.
+        assert!(menu.iter().any(|entry| {
+            matches!(
+                entry,
+                TrayMenuEntry::Action { id, enabled, .. }
+                    if id == menu_ids::TOGGLE_RECORDING && *enabled
+            )
+        }));
+    }
+
     #[test]
     fn test_build_tray_menu_microphone_submenu_marks_selected_device() {
         let menu = build_tray_menu(&sample_state());
@@ -1326,6 +1896,39 @@ mod tests {
         assert_eq!(recent.len(), 5);
     }
 
+    #[test]
+    fn test_build_tray_menu_pending_insert_submenu_shows_count_only_when_queued() {
+        let mut state = sample_state();
+        let has_pending_submenu = |menu: &[TrayMenuEntry]| {
+            menu.iter().any(|entry| {
+                matches!(entry, TrayMenuEntry::Submenu { id, .. } if id == menu_ids::PENDING_INSERT_SUBMENU)
+            })
+        };
+        assert!(!has_pending_submenu(&build_tray_menu(&state)));
+
+        state.pending_inserts = vec![
+            ("pending-1".to_string(), "focus moved away".to_string()),
+            ("pending-2".to_string(), "injection failed".to_string()),
+        ];
+        let menu = build_tray_menu(&state);
+        let (text, items) = menu
+            .iter()
+            .find_map(|entry| match entry {
+                TrayMenuEntry::Submenu {
+                    id, text, items, ..
+                } if id == menu_ids::PENDING_INSERT_SUBMENU => Some((text, items)),
+                _ => None,
+            })
+            .expect("pending insert submenu should exist");
+
+        assert_eq!(text, "Pending Insert (2)");
+        assert!(matches!(
+            items.first(),
+            Some(TrayMenuEntry::Action { id, enabled: true, .. })
+                if id == &format!("{}pending-1", menu_ids::INJECT_PENDING_PREFIX)
+        ));
+    }
+
     #[test]
     fn test_build_tray_menu_recent_entry_text_truncates_to_menu_limit() {
         let mut state = sample_state();
@@ -1385,6 +1988,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_tray_menu_shows_today_stats_summary() {
+        let mut state = sample_state();
+        let menu = build_tray_menu(&state);
+        assert!(menu.iter().any(|entry| matches!(
+            entry,
+            TrayMenuEntry::Action { id, text, enabled }
+                if id == menu_ids::STATS_SUMMARY
+                    && text == "Today: 3,214 words, 26 min audio"
+                    && *enabled
+        )));
+
+        state.ui_language = "de".to_string();
+        let menu = build_tray_menu(&state);
+        assert!(menu.iter().any(|entry| matches!(
+            entry,
+            TrayMenuEntry::Action { id, text, .. }
+                if id == menu_ids::STATS_SUMMARY && text == "Heute: 3.214 Wörter, 26 Min. Audio"
+        )));
+    }
+
     #[test]
     fn test_build_tray_menu_handles_empty_devices_and_recent() {
         let mut state = sample_state();
@@ -1537,11 +2161,7 @@ mod tests {
         let previous = sample_state();
         let mut current = previous.clone();
         current.recording = true;
-        assert_menu_rebuild_within_reflection_budget(
-            Some(&previous),
-            &current,
-            "state change",
-        );
+        assert_menu_rebuild_within_reflection_budget(Some(&previous), &current, "state change");
     }
 
     #[test]
@@ -1577,7 +2197,7 @@ mod tests {
     fn test_tray_reflection_budget_enable_toggle_within_250ms() {
         let previous = sample_state();
         let mut current = previous.clone();
-        current.enabled = !previous.enabled;
+        current.enable_level = EnableLevel::FullyPaused;
         assert_menu_rebuild_within_reflection_budget(
             Some(&previous),
             &current,
@@ -1699,7 +2319,9 @@ mod tests {
                 text: "Show Window".to_string(),
                 enabled: true,
             },
-            TrayMenuEntry::Quit,
+            TrayMenuEntry::Quit {
+                text: "Quit".to_string(),
+            },
         ];
 
         println!("tray menu snapshot: {menu:#?}");
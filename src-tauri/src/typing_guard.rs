@@ -0,0 +1,270 @@
+//! Hotkey suppression while the user is typing.
+//!
+//! Fast typists can roll into the dictation hotkey (e.g. still holding a
+//! letter while pressing Ctrl+Shift+Space), which starts an unwanted
+//! recording. When `hotkeys.typing_suppression_ms` is set, a background
+//! monitor samples the platform's keyboard activity and a recording-start
+//! press is ignored if another key went down within that window. Platforms
+//! without a keyboard activity source never suppress.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+
+/// How often the background monitor samples keyboard activity.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Key-down timestamps kept; only the two most recent matter.
+const MAX_RECENT_KEY_DOWNS: usize = 8;
+
+static MONITOR: Lazy<Mutex<KeyboardMonitor>> = Lazy::new(|| {
+    Mutex::new(KeyboardMonitor {
+        sampler: KeySampler::new(),
+        activity: KeyActivity::default(),
+    })
+});
+
+static MONITOR_STARTED: Once = Once::new();
+
+static SUPPRESSED_ACTIVATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// Recent key-down times, oldest first.
+#[derive(Debug, Default)]
+pub struct KeyActivity {
+    key_downs: VecDeque<Instant>,
+}
+
+impl KeyActivity {
+    /// Record `count` key-downs observed at `at`.
+    pub fn record_key_downs(&mut self, count: u64, at: Instant) {
+        for _ in 0..count.min(MAX_RECENT_KEY_DOWNS as u64) {
+            if self.key_downs.len() >= MAX_RECENT_KEY_DOWNS {
+                self.key_downs.pop_front();
+            }
+            self.key_downs.push_back(at);
+        }
+    }
+
+    /// Whether a key other than the hotkey went down within `window` of `now`.
+    ///
+    /// The most recent key-down is the hotkey press itself, so the one before
+    /// it decides.
+    pub fn typed_within(&self, now: Instant, window: Duration) -> bool {
+        let mut recent = self.key_downs.iter().rev();
+        if recent.next().is_none() {
+            return false;
+        }
+        recent
+            .next()
+            .is_some_and(|previous| now.saturating_duration_since(*previous) < window)
+    }
+}
+
+struct KeyboardMonitor {
+    sampler: KeySampler,
+    activity: KeyActivity,
+}
+
+impl KeyboardMonitor {
+    fn poll(&mut self, now: Instant) {
+        if let Some(count) = self.sampler.sample() {
+            if count > 0 {
+                self.activity.record_key_downs(count, now);
+            }
+        }
+    }
+}
+
+/// Start the background keyboard monitor if this platform supports it.
+pub fn start_monitor() {
+    if !KeySampler::is_supported() {
+        return;
+    }
+    MONITOR_STARTED.call_once(|| {
+        let spawned = thread::Builder::new()
+            .name("typing-guard".to_string())
+            .spawn(|| loop {
+                MONITOR
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .poll(Instant::now());
+                thread::sleep(POLL_INTERVAL);
+            });
+        if let Err(error) = spawned {
+            log::warn!("Failed to start keyboard activity monitor: {}", error);
+        }
+    });
+}
+
+/// Whether a recording-start hotkey press should be ignored as typing rollover.
+///
+/// Counts the activation as suppressed when it returns true.
+pub fn should_suppress(window_ms: u32) -> bool {
+    if window_ms == 0 || !KeySampler::is_supported() {
+        return false;
+    }
+    start_monitor();
+
+    let now = Instant::now();
+    let mut monitor = MONITOR
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    // Pick up the hotkey's own key-down before the next background poll.
+    monitor.poll(now);
+    let suppress = monitor
+        .activity
+        .typed_within(now, Duration::from_millis(u64::from(window_ms)));
+    if suppress {
+        SUPPRESSED_ACTIVATIONS.fetch_add(1, Ordering::Relaxed);
+    }
+    suppress
+}
+
+/// Hotkey activations suppressed since launch.
+pub fn suppressed_activations() -> u64 {
+    SUPPRESSED_ACTIVATIONS.load(Ordering::Relaxed)
+}
+
+/// Counts key-downs since the previous sample, excluding modifier keys.
+struct KeySampler {
+    #[cfg(target_os = "macos")]
+    last_count: Option<u32>,
+    #[cfg(target_os = "windows")]
+    keys_down: [bool; 256],
+}
+
+#[cfg(target_os = "macos")]
+impl KeySampler {
+    fn new() -> Self {
+        Self { last_count: None }
+    }
+
+    fn is_supported() -> bool {
+        true
+    }
+
+    /// Uses the HID system key-down counter, which does not include modifier
+    /// (flags-changed) events.
+    fn sample(&mut self) -> Option<u64> {
+        // kCGEventSourceStateHIDSystemState, kCGEventKeyDown
+        let count = unsafe { CGEventSourceCounterForEventType(1, 10) };
+        let new_key_downs = self
+            .last_count
+            .map(|last| u64::from(count.wrapping_sub(last)))
+            .unwrap_or(0);
+        self.last_count = Some(count);
+        Some(new_key_downs)
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[link(name = "CoreGraphics", kind = "framework")]
+unsafe extern "C" {
+    fn CGEventSourceCounterForEventType(state_id: i32, event_type: u32) -> u32;
+}
+
+#[cfg(target_os = "windows")]
+impl KeySampler {
+    fn new() -> Self {
+        Self {
+            keys_down: [false; 256],
+        }
+    }
+
+    fn is_supported() -> bool {
+        true
+    }
+
+    /// Polls every virtual key and counts up-to-down transitions.
+    fn sample(&mut self) -> Option<u64> {
+        let mut new_key_downs = 0;
+        for vkey in 0x08..=0xFE_usize {
+            if is_ignored_windows_key(vkey) {
+                continue;
+            }
+            let down = unsafe { GetAsyncKeyState(vkey as i32) } as u16 & 0x8000 != 0;
+            if down && !self.keys_down[vkey] {
+                new_key_downs += 1;
+            }
+            self.keys_down[vkey] = down;
+        }
+        Some(new_key_downs)
+    }
+}
+
+/// Modifier and lock keys, which are part of hotkey chords rather than typing.
+#[cfg(target_os = "windows")]
+fn is_ignored_windows_key(vkey: usize) -> bool {
+    matches!(vkey, 0x10..=0x12 | 0x14 | 0x5B | 0x5C | 0x90 | 0x91 | 0xA0..=0xA5)
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "user32")]
+unsafe extern "system" {
+    fn GetAsyncKeyState(vkey: i32) -> i16;
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+impl KeySampler {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn is_supported() -> bool {
+        false
+    }
+
+    /// No global keyboard activity source without elevated input access.
+    fn sample(&mut self) -> Option<u64> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hotkey_press_alone_is_not_typing() {
+        let start = Instant::now();
+        let mut activity = KeyActivity::default();
+        assert!(!activity.typed_within(start, Duration::from_millis(300)));
+
+        activity.record_key_downs(1, start);
+        assert!(!activity.typed_within(start, Duration::from_millis(300)));
+    }
+
+    #[test]
+    fn test_recent_key_before_hotkey_suppresses() {
+        let start = Instant::now();
+        let mut activity = KeyActivity::default();
+        activity.record_key_downs(1, start);
+        let hotkey_at = start + Duration::from_millis(120);
+        activity.record_key_downs(1, hotkey_at);
+
+        assert!(activity.typed_within(hotkey_at, Duration::from_millis(300)));
+        assert!(!activity.typed_within(hotkey_at, Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_key_rollover_within_one_sample_suppresses() {
+        let now = Instant::now();
+        let mut activity = KeyActivity::default();
+        activity.record_key_downs(2, now);
+
+        assert!(activity.typed_within(now, Duration::from_millis(50)));
+    }
+
+    #[test]
+    fn test_only_recent_key_downs_are_kept() {
+        let now = Instant::now();
+        let mut activity = KeyActivity::default();
+        activity.record_key_downs(1_000, now);
+
+        assert_eq!(activity.key_downs.len(), MAX_RECENT_KEY_DOWNS);
+    }
+}
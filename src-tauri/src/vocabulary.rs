@@ -0,0 +1,171 @@
+//! Custom vocabulary (hotword) list handling.
+//!
+//! Users maintain a list of domain terms, names, and acronyms that is passed to
+//! the sidecar as a hotword/boost list. This module normalizes user input and
+//! enforces size limits so oversized lists never reach the ASR backend.
+
+use thiserror::Error;
+
+/// Maximum number of vocabulary terms forwarded to the sidecar.
+pub const MAX_VOCABULARY_TERMS: usize = 500;
+/// Maximum characters per vocabulary term.
+pub const MAX_VOCABULARY_TERM_CHARS: usize = 64;
+/// Largest vocabulary file imported; a full list is a few dozen kilobytes.
+pub const MAX_VOCABULARY_FILE_BYTES: u64 = 1024 * 1024;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum VocabularyError {
+    #[error("vocabulary has {count} terms; at most {max} are allowed")]
+    TooManyTerms { count: usize, max: usize },
+    #[error("vocabulary term '{term}' exceeds {max} characters")]
+    TermTooLong { term: String, max: usize },
+    #[error("vocabulary file is {size} bytes; at most {max} are allowed")]
+    FileTooLarge { size: u64, max: u64 },
+}
+
+fn normalize_term(term: &str) -> Option<String> {
+    let collapsed = term.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.is_empty() {
+        None
+    } else {
+        Some(collapsed)
+    }
+}
+
+fn dedupe_case_insensitive(terms: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    terms
+        .into_iter()
+        .filter(|term| seen.insert(term.to_lowercase()))
+        .collect()
+}
+
+/// Normalize a user-supplied term list, rejecting lists that exceed limits.
+///
+/// Whitespace is collapsed, empty entries are dropped, and duplicates are
+/// removed case-insensitively (first spelling wins).
+pub fn normalize_terms(terms: &[String]) -> Result<Vec<String>, VocabularyError> {
+    let normalized = dedupe_case_insensitive(terms.iter().filter_map(|term| normalize_term(term)));
+
+    if let Some(term) = normalized
+        .iter()
+        .find(|term| term.chars().count() > MAX_VOCABULARY_TERM_CHARS)
+    {
+        return Err(VocabularyError::TermTooLong {
+            term: term.clone(),
+            max: MAX_VOCABULARY_TERM_CHARS,
+        });
+    }
+    if normalized.len() > MAX_VOCABULARY_TERMS {
+        return Err(VocabularyError::TooManyTerms {
+            count: normalized.len(),
+            max: MAX_VOCABULARY_TERMS,
+        });
+    }
+
+    Ok(normalized)
+}
+
+/// Lenient variant used when loading config: drops invalid terms instead of failing.
+pub fn clamp_terms(terms: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> =
+        dedupe_case_insensitive(terms.iter().filter_map(|term| normalize_term(term)))
+            .into_iter()
+            .filter(|term| term.chars().count() <= MAX_VOCABULARY_TERM_CHARS)
+            .collect();
+    normalized.truncate(MAX_VOCABULARY_TERMS);
+    normalized
+}
+
+/// Reject an import file of `size` bytes before it is read into memory.
+pub fn check_file_size(size: u64) -> Result<(), VocabularyError> {
+    if size > MAX_VOCABULARY_FILE_BYTES {
+        return Err(VocabularyError::FileTooLarge {
+            size,
+            max: MAX_VOCABULARY_FILE_BYTES,
+        });
+    }
+    Ok(())
+}
+
+/// Parse an imported vocabulary file.
+///
+/// Accepts one term per line or comma-separated terms; lines starting with `#`
+/// are treated as comments.
+pub fn parse_vocabulary_file(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .flat_map(|line| line.split(','))
+        .filter_map(normalize_term)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_normalize_trims_and_dedupes_case_insensitively() {
+        let normalized =
+            normalize_terms(&terms(&["  Kubernetes ", "kubernetes", "", "gRPC  API"])).unwrap();
+        assert_eq!(normalized, terms(&["Kubernetes", "gRPC API"]));
+    }
+
+    #[test]
+    fn test_normalize_rejects_overlong_term() {
+        let long = "x".repeat(MAX_VOCABULARY_TERM_CHARS + 1);
+        assert!(matches!(
+            normalize_terms(&[long]),
+            Err(VocabularyError::TermTooLong { .. })
+        ));
+    }
+
+    #[test]
+    fn test_normalize_rejects_too_many_terms() {
+        let many: Vec<String> = (0..=MAX_VOCABULARY_TERMS)
+            .map(|index| format!("term{index}"))
+            .collect();
+        assert_eq!(
+            normalize_terms(&many),
+            Err(VocabularyError::TooManyTerms {
+                count: MAX_VOCABULARY_TERMS + 1,
+                max: MAX_VOCABULARY_TERMS,
+            })
+        );
+    }
+
+    #[test]
+    fn test_clamp_drops_invalid_terms_and_truncates() {
+        let mut input: Vec<String> = (0..MAX_VOCABULARY_TERMS + 10)
+            .map(|index| format!("term{index}"))
+            .collect();
+        input.insert(0, "y".repeat(MAX_VOCABULARY_TERM_CHARS + 1));
+        let clamped = clamp_terms(&input);
+        assert_eq!(clamped.len(), MAX_VOCABULARY_TERMS);
+        assert_eq!(clamped[0], "term0");
+    }
+
+    #[test]
+    fn test_check_file_size_rejects_oversized_files() {
+        assert_eq!(check_file_size(MAX_VOCABULARY_FILE_BYTES), Ok(()));
+        assert_eq!(
+            check_file_size(MAX_VOCABULARY_FILE_BYTES + 1),
+            Err(VocabularyError::FileTooLarge {
+                size: MAX_VOCABULARY_FILE_BYTES + 1,
+                max: MAX_VOCABULARY_FILE_BYTES,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_vocabulary_file_supports_lines_commas_and_comments() {
+        let parsed = parse_vocabulary_file("# project terms\nOpenVoicy, Tauri\n\n  Parakeet  \n");
+        assert_eq!(parsed, terms(&["OpenVoicy", "Tauri", "Parakeet"]));
+    }
+}
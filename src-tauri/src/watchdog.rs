@@ -10,9 +10,12 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use serde::Serialize;
-use tokio::sync::{broadcast, RwLock};
+use tokio::sync::{broadcast, watch, RwLock};
 use tokio::time::interval;
 
+use crate::config::SupervisorConfig;
+use crate::power::PowerProfile;
+
 /// Default interval between health checks.
 const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(10);
 
@@ -25,6 +28,10 @@ const HANG_THRESHOLD: Duration = Duration::from_secs(30);
 /// Minimum loop gap treated as likely suspend/resume.
 const RESUME_GAP_MIN_THRESHOLD: Duration = Duration::from_secs(20);
 
+/// How much longer the ping interval and hang threshold are under the
+/// low-power profile.
+const LOW_POWER_INTERVAL_FACTOR: u32 = 3;
+
 /// Watchdog health check result.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -94,7 +101,7 @@ impl Default for WatchdogState {
 }
 
 /// Watchdog configuration.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WatchdogConfig {
     /// Interval between health checks.
     pub check_interval: Duration,
@@ -117,12 +124,57 @@ impl Default for WatchdogConfig {
     }
 }
 
+impl WatchdogConfig {
+    /// Timings from the user's `supervisor` settings.
+    pub fn from_supervisor(supervisor: &SupervisorConfig) -> Self {
+        Self {
+            check_interval: Duration::from_secs(supervisor.watchdog_ping_interval_secs),
+            ping_timeout: Duration::from_secs(supervisor.watchdog_ping_timeout_secs),
+            hang_threshold: Duration::from_secs(supervisor.watchdog_hang_threshold_secs),
+            ..Self::default()
+        }
+    }
+
+    /// These timings adjusted for `profile`: the low-power profile pings less
+    /// often and waits proportionally longer before declaring a hang.
+    pub fn for_power_profile(self, profile: PowerProfile) -> Self {
+        match profile {
+            PowerProfile::Normal => self,
+            PowerProfile::LowPower => Self {
+                check_interval: self.check_interval * LOW_POWER_INTERVAL_FACTOR,
+                hang_threshold: self.hang_threshold * LOW_POWER_INTERVAL_FACTOR,
+                ..self
+            },
+        }
+    }
+}
+
+/// Effective watchdog settings, as reported in diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct WatchdogSettings {
+    pub ping_interval_ms: u64,
+    pub ping_timeout_ms: u64,
+    pub hang_threshold_ms: u64,
+    pub auto_restart_on_hang: bool,
+}
+
+impl From<&WatchdogConfig> for WatchdogSettings {
+    fn from(config: &WatchdogConfig) -> Self {
+        Self {
+            ping_interval_ms: config.check_interval.as_millis() as u64,
+            ping_timeout_ms: config.ping_timeout.as_millis() as u64,
+            hang_threshold_ms: config.hang_threshold.as_millis() as u64,
+            auto_restart_on_hang: config.auto_restart_on_hang,
+        }
+    }
+}
+
 /// Watchdog for monitoring sidecar health.
 pub struct Watchdog {
     /// Internal state.
     state: Arc<RwLock<WatchdogState>>,
-    /// Configuration.
-    config: WatchdogConfig,
+    /// Configuration, replaceable while the loop runs.
+    config_tx: watch::Sender<WatchdogConfig>,
     /// Event broadcaster.
     event_tx: broadcast::Sender<WatchdogEvent>,
     /// Shutdown flag.
@@ -141,12 +193,29 @@ impl Watchdog {
 
         Self {
             state: Arc::new(RwLock::new(WatchdogState::default())),
-            config,
+            config_tx: watch::Sender::new(config),
             event_tx,
             shutdown_flag: Arc::new(AtomicBool::new(false)),
         }
     }
 
+    /// Configuration currently in effect.
+    pub fn config(&self) -> WatchdogConfig {
+        self.config_tx.borrow().clone()
+    }
+
+    /// Replace the configuration. A running loop picks it up right away and
+    /// restarts its check interval.
+    pub fn reconfigure(&self, config: WatchdogConfig) {
+        log::info!(
+            "Watchdog reconfigured (interval: {:?}, ping timeout: {:?}, hang threshold: {:?})",
+            config.check_interval,
+            config.ping_timeout,
+            config.hang_threshold
+        );
+        self.config_tx.send_replace(config);
+    }
+
     /// Subscribe to watchdog events.
     pub fn subscribe(&self) -> broadcast::Receiver<WatchdogEvent> {
         self.event_tx.subscribe()
@@ -182,7 +251,8 @@ impl Watchdog {
         }
 
         // Try to ping with timeout
-        let ping_result = tokio::time::timeout(self.config.ping_timeout, pinger.ping()).await;
+        let config = self.config();
+        let ping_result = tokio::time::timeout(config.ping_timeout, pinger.ping()).await;
 
         let status = match ping_result {
             Ok(Ok(())) => {
@@ -193,7 +263,7 @@ impl Watchdog {
             Ok(Err(e)) => {
                 // Ping failed (RPC error)
                 log::warn!("Watchdog ping failed: {}", e);
-                if state.last_activity.elapsed() > self.config.hang_threshold {
+                if state.last_activity.elapsed() > config.hang_threshold {
                     HealthStatus::Hung
                 } else {
                     HealthStatus::Unhealthy
@@ -202,7 +272,7 @@ impl Watchdog {
             Err(_) => {
                 // Ping timed out
                 log::warn!("Watchdog ping timed out");
-                if state.last_activity.elapsed() > self.config.hang_threshold {
+                if state.last_activity.elapsed() > config.hang_threshold {
                     HealthStatus::Hung
                 } else {
                     HealthStatus::Unhealthy
@@ -257,7 +327,8 @@ impl Watchdog {
     /// The loop runs until shutdown() is called.
     pub fn start_loop<P: PingCallback + 'static>(&self, pinger: Arc<P>) {
         let state = Arc::clone(&self.state);
-        let config = self.config.clone();
+        let mut config_rx = self.config_tx.subscribe();
+        let mut config = config_rx.borrow_and_update().clone();
         let event_tx = self.event_tx.clone();
         let shutdown_flag = Arc::clone(&self.shutdown_flag);
 
@@ -270,7 +341,15 @@ impl Watchdog {
             );
 
             loop {
-                check_interval.tick().await;
+                tokio::select! {
+                    _ = check_interval.tick() => {}
+                    Ok(()) = config_rx.changed() => {
+                        config = config_rx.borrow_and_update().clone();
+                        check_interval = interval(config.check_interval);
+                        last_tick = Instant::now();
+                        continue;
+                    }
+                }
                 let now = Instant::now();
                 let loop_gap = now.saturating_duration_since(last_tick);
                 last_tick = now;
@@ -497,6 +576,46 @@ mod tests {
         assert!(config.auto_restart_on_hang);
     }
 
+    #[test]
+    fn test_watchdog_config_from_supervisor_settings() {
+        let supervisor = SupervisorConfig {
+            watchdog_ping_interval_secs: 15,
+            watchdog_ping_timeout_secs: 3,
+            watchdog_hang_threshold_secs: 60,
+            ..SupervisorConfig::default()
+        };
+        let config = WatchdogConfig::from_supervisor(&supervisor);
+        assert_eq!(config.check_interval, Duration::from_secs(15));
+        assert_eq!(config.ping_timeout, Duration::from_secs(3));
+        assert_eq!(config.hang_threshold, Duration::from_secs(60));
+        assert!(config.auto_restart_on_hang);
+
+        let settings = WatchdogSettings::from(&config);
+        assert_eq!(settings.ping_interval_ms, 15_000);
+        assert_eq!(settings.hang_threshold_ms, 60_000);
+        // Defaults match the supervisor defaults.
+        assert_eq!(
+            WatchdogSettings::from(&WatchdogConfig::from_supervisor(
+                &SupervisorConfig::default()
+            )),
+            WatchdogSettings::from(&WatchdogConfig::default())
+        );
+    }
+
+    #[test]
+    fn test_watchdog_config_low_power_profile_pings_less_often() {
+        let config = WatchdogConfig::default();
+        assert_eq!(
+            config.clone().for_power_profile(PowerProfile::Normal),
+            config
+        );
+
+        let low_power = config.clone().for_power_profile(PowerProfile::LowPower);
+        assert_eq!(low_power.check_interval, DEFAULT_CHECK_INTERVAL * 3);
+        assert_eq!(low_power.hang_threshold, HANG_THRESHOLD * 3);
+        assert_eq!(low_power.ping_timeout, config.ping_timeout);
+    }
+
     #[test]
     fn test_health_status_serialization() {
         let statuses = [
@@ -698,6 +817,43 @@ mod tests {
         watchdog.shutdown();
     }
 
+    #[tokio::test]
+    async fn test_reconfigure_applies_to_running_loop() {
+        let config = WatchdogConfig {
+            check_interval: Duration::from_secs(3600),
+            ..Default::default()
+        };
+        let watchdog = Watchdog::with_config(config);
+        let pinger = Arc::new(MockPinger::new(true));
+        let mut receiver = watchdog.subscribe();
+
+        watchdog.start_loop(Arc::clone(&pinger));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        watchdog.reconfigure(WatchdogConfig {
+            check_interval: Duration::from_millis(20),
+            ..Default::default()
+        });
+        assert_eq!(watchdog.config().check_interval, Duration::from_millis(20));
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        // One check from the first hourly tick, the rest from the new interval.
+        let mut health_checks = 0;
+        while let Ok(Ok(event)) =
+            tokio::time::timeout(Duration::from_millis(10), receiver.recv()).await
+        {
+            if matches!(event, WatchdogEvent::HealthCheck { .. }) {
+                health_checks += 1;
+            }
+        }
+        assert!(
+            health_checks >= 3,
+            "Expected the new interval to take effect, got {} checks",
+            health_checks
+        );
+
+        watchdog.shutdown();
+    }
+
     #[tokio::test]
     async fn test_watchdog_loop_detects_hung_and_requests_supervisor_recovery() {
         let config = WatchdogConfig {
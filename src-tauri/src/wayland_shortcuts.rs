@@ -0,0 +1,413 @@
+//! Global shortcuts through the XDG desktop portal on Wayland.
+//!
+//! Wayland does not let applications grab keys globally, so on Wayland
+//! sessions the hotkeys are registered with the
+//! `org.freedesktop.portal.GlobalShortcuts` portal instead of
+//! `global-hotkey`. Binding shortcuts shows a compositor approval dialog;
+//! to avoid asking again on every launch, the session token and the triggers
+//! that were bound are persisted, and shortcuts are only re-bound when the
+//! portal no longer lists them or the configured hotkeys changed.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::thread;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+use zbus::blocking::Connection;
+use zbus::zvariant::{OwnedObjectPath, OwnedValue, Value};
+
+use crate::config;
+use crate::hotkey::HotkeyAction;
+use crate::xdg_portal::{self, close_session, new_handle_token, portal_proxy, portal_request};
+
+const GLOBAL_SHORTCUTS_INTERFACE: &str = "org.freedesktop.portal.GlobalShortcuts";
+
+/// Persisted portal state file name inside the config directory.
+const STATE_FILE_NAME: &str = "wayland_shortcuts.json";
+
+/// Shortcut ids registered with the portal.
+pub const PRIMARY_SHORTCUT_ID: &str = "primary";
+pub const COPY_LAST_SHORTCUT_ID: &str = "copy_last";
+pub const DICTATE_RAW_SHORTCUT_ID: &str = "dictate_raw";
+pub const CANCEL_SHORTCUT_ID: &str = "cancel";
+
+/// A shortcut to register with the portal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortalShortcut {
+    pub id: &'static str,
+    pub description: &'static str,
+    /// Hotkey in config syntax, e.g. "Ctrl+Shift+Space".
+    pub hotkey: String,
+}
+
+/// Portal state persisted across launches.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PortalShortcutState {
+    /// Stable `session_handle_token` passed to CreateSession.
+    pub session_token: String,
+    /// Hotkeys last bound, keyed by shortcut id.
+    pub bound: BTreeMap<String, String>,
+}
+
+impl PortalShortcutState {
+    /// Load persisted state, creating a new session token when missing.
+    pub fn load(path: &Path) -> Self {
+        let mut state = fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str::<Self>(&contents).ok())
+            .unwrap_or_default();
+        if !is_valid_token(&state.session_token) {
+            state.session_token = format!("openvoicy_{}", Uuid::new_v4().simple());
+            state.bound.clear();
+        }
+        state
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+
+    /// Whether the shortcuts must be bound again, prompting the user.
+    ///
+    /// `listed` holds the shortcut ids the portal already knows for this app.
+    pub fn needs_bind(&self, shortcuts: &[PortalShortcut], listed: &[String]) -> bool {
+        shortcuts.iter().any(|shortcut| {
+            !listed.iter().any(|id| id == shortcut.id)
+                || self.bound.get(shortcut.id) != Some(&shortcut.hotkey)
+        })
+    }
+
+    fn record_bound(&mut self, shortcuts: &[PortalShortcut]) {
+        self.bound = shortcuts
+            .iter()
+            .map(|shortcut| (shortcut.id.to_string(), shortcut.hotkey.clone()))
+            .collect();
+    }
+}
+
+/// Active portal session; shortcut events are forwarded to the hotkey channel.
+pub struct PortalShortcuts {
+    connection: Connection,
+    session: OwnedObjectPath,
+}
+
+impl PortalShortcuts {
+    /// Create a portal session and start binding/listening in the background.
+    ///
+    /// Returns an error (so the caller can fall back) only if the session
+    /// cannot be created; binding may wait on the user's approval and runs on
+    /// the listener thread.
+    pub fn start(
+        shortcuts: Vec<PortalShortcut>,
+        action_tx: mpsc::Sender<HotkeyAction>,
+    ) -> Result<Self, String> {
+        let state_path = config::config_dir().join(STATE_FILE_NAME);
+        let mut state = PortalShortcutState::load(&state_path);
+
+        let connection =
+            Connection::session().map_err(|e| format!("session bus connection failed: {e}"))?;
+        let portal = portal_proxy(&connection, GLOBAL_SHORTCUTS_INTERFACE)?;
+
+        let session = xdg_portal::create_session(&connection, &portal, &state.session_token)?;
+
+        let listener_connection = connection.clone();
+        let listener_session = session.clone();
+        thread::Builder::new()
+            .name("wayland-shortcuts".to_string())
+            .spawn(move || {
+                if let Err(error) = bind_if_needed(
+                    &listener_connection,
+                    &listener_session,
+                    &shortcuts,
+                    &mut state,
+                    &state_path,
+                ) {
+                    log::warn!("GlobalShortcuts portal binding failed: {}", error);
+                    return;
+                }
+                if let Err(error) =
+                    forward_activations(&listener_connection, &listener_session, &action_tx)
+                {
+                    log::warn!("GlobalShortcuts portal listener stopped: {}", error);
+                }
+            })
+            .map_err(|e| format!("failed to start portal listener: {e}"))?;
+
+        log::info!("Registered hotkeys through the GlobalShortcuts portal");
+        Ok(Self {
+            connection,
+            session,
+        })
+    }
+
+    /// Close the portal session; the compositor keeps the bindings.
+    pub fn close(&self) {
+        if let Err(error) = close_session(&self.connection, &self.session) {
+            log::debug!("Failed to close GlobalShortcuts session: {}", error);
+        }
+    }
+}
+
+/// Portal trigger syntax for a config hotkey ("Ctrl+Shift+Space" becomes
+/// "CTRL+SHIFT+space"). Returns None for an empty binding.
+pub fn preferred_trigger(hotkey: &str) -> Option<String> {
+    let parts: Vec<&str> = hotkey
+        .split('+')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect();
+    let (key, modifiers) = parts.split_last()?;
+
+    let mut trigger: Vec<String> = modifiers
+        .iter()
+        .map(|modifier| match modifier.to_lowercase().as_str() {
+            "ctrl" | "control" => "CTRL".to_string(),
+            "alt" => "ALT".to_string(),
+            "shift" => "SHIFT".to_string(),
+            "meta" | "super" | "cmd" | "command" | "win" => "LOGO".to_string(),
+            other => other.to_uppercase(),
+        })
+        .collect();
+    let key = if key.chars().count() == 1 {
+        key.to_lowercase()
+    } else {
+        match key.to_lowercase().as_str() {
+            "space" => "space".to_string(),
+            "enter" | "return" => "Return".to_string(),
+            "esc" | "escape" => "Escape".to_string(),
+            "tab" => "Tab".to_string(),
+            "backspace" => "BackSpace".to_string(),
+            _ => key.to_string(),
+        }
+    };
+    trigger.push(key);
+    Some(trigger.join("+"))
+}
+
+/// Map a portal activation to a hotkey action.
+pub fn portal_action(shortcut_id: &str, activated: bool) -> Option<HotkeyAction> {
+    match (shortcut_id, activated) {
+        (PRIMARY_SHORTCUT_ID, true) => Some(HotkeyAction::PrimaryDown),
+        (PRIMARY_SHORTCUT_ID, false) => Some(HotkeyAction::PrimaryUp),
+        (COPY_LAST_SHORTCUT_ID, true) => Some(HotkeyAction::CopyLast),
+        (DICTATE_RAW_SHORTCUT_ID, true) => Some(HotkeyAction::DictateRawDown),
+        // Release stops a hold-mode recording exactly like the primary key.
+        (DICTATE_RAW_SHORTCUT_ID, false) => Some(HotkeyAction::PrimaryUp),
+        (CANCEL_SHORTCUT_ID, true) => Some(HotkeyAction::Cancel),
+        _ => None,
+    }
+}
+
+fn bind_if_needed(
+    connection: &Connection,
+    session: &OwnedObjectPath,
+    shortcuts: &[PortalShortcut],
+    state: &mut PortalShortcutState,
+    state_path: &Path,
+) -> Result<(), String> {
+    let portal = portal_proxy(connection, GLOBAL_SHORTCUTS_INTERFACE)?;
+
+    let handle_token = new_handle_token();
+    let list_options = HashMap::from([("handle_token", Value::from(handle_token.as_str()))]);
+    let listed = portal_request(
+        connection,
+        &portal,
+        "ListShortcuts",
+        &handle_token,
+        |proxy| proxy.call_method("ListShortcuts", &(session.as_ref(), list_options)),
+    )
+    .map(|results| listed_shortcut_ids(&results))
+    .unwrap_or_default();
+
+    if !state.needs_bind(shortcuts, &listed) {
+        log::info!("GlobalShortcuts already bound; skipping approval prompt");
+        return Ok(());
+    }
+
+    let requested: Vec<(&str, HashMap<&str, Value>)> = shortcuts
+        .iter()
+        .map(|shortcut| {
+            let mut properties =
+                HashMap::from([("description", Value::from(shortcut.description))]);
+            if let Some(trigger) = preferred_trigger(&shortcut.hotkey) {
+                properties.insert("preferred_trigger", Value::from(trigger));
+            }
+            (shortcut.id, properties)
+        })
+        .collect();
+    let handle_token = new_handle_token();
+    let bind_options = HashMap::from([("handle_token", Value::from(handle_token.as_str()))]);
+    portal_request(
+        connection,
+        &portal,
+        "BindShortcuts",
+        &handle_token,
+        |proxy| {
+            proxy.call_method(
+                "BindShortcuts",
+                &(session.as_ref(), requested, "", bind_options),
+            )
+        },
+    )?;
+
+    state.record_bound(shortcuts);
+    if let Err(error) = state.save(state_path) {
+        log::warn!("Failed to save GlobalShortcuts state: {}", error);
+    }
+    Ok(())
+}
+
+fn forward_activations(
+    connection: &Connection,
+    session: &OwnedObjectPath,
+    action_tx: &mpsc::Sender<HotkeyAction>,
+) -> Result<(), String> {
+    let portal = portal_proxy(connection, GLOBAL_SHORTCUTS_INTERFACE)?;
+    let signals = portal
+        .receive_all_signals()
+        .map_err(|e| format!("failed to subscribe to shortcut signals: {e}"))?;
+
+    for message in signals {
+        let header = message.header();
+        let activated = match header.member().map(|member| member.as_str()) {
+            Some("Activated") => true,
+            Some("Deactivated") => false,
+            _ => continue,
+        };
+        let Ok((signal_session, shortcut_id, _timestamp, _options)) = message
+            .body()
+            .deserialize::<(OwnedObjectPath, String, u64, HashMap<String, OwnedValue>)>()
+        else {
+            continue;
+        };
+        if signal_session != *session {
+            continue;
+        }
+        if let Some(action) = portal_action(&shortcut_id, activated) {
+            let _ = action_tx.try_send(action);
+        }
+    }
+    Ok(())
+}
+
+/// Shortcut ids from a ListShortcuts response (`a(sa{sv})`).
+fn listed_shortcut_ids(results: &HashMap<String, OwnedValue>) -> Vec<String> {
+    let Some(Value::Array(shortcuts)) = results.get("shortcuts").map(|value| &**value) else {
+        return Vec::new();
+    };
+    shortcuts
+        .iter()
+        .filter_map(|shortcut| match shortcut {
+            Value::Structure(fields) => match fields.fields().first() {
+                Some(Value::Str(id)) => Some(id.to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+fn is_valid_token(token: &str) -> bool {
+    !token.is_empty()
+        && token
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn shortcut(id: &'static str, hotkey: &str) -> PortalShortcut {
+        PortalShortcut {
+            id,
+            description: "test",
+            hotkey: hotkey.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_preferred_trigger_uses_portal_syntax() {
+        assert_eq!(
+            preferred_trigger("Ctrl+Shift+Space").as_deref(),
+            Some("CTRL+SHIFT+space")
+        );
+        assert_eq!(preferred_trigger("Super+V").as_deref(), Some("LOGO+v"));
+        assert_eq!(preferred_trigger("Alt+F2").as_deref(), Some("ALT+F2"));
+        assert_eq!(preferred_trigger(""), None);
+    }
+
+    #[test]
+    fn test_portal_action_maps_activation_and_release() {
+        assert!(matches!(
+            portal_action(PRIMARY_SHORTCUT_ID, true),
+            Some(HotkeyAction::PrimaryDown)
+        ));
+        assert!(matches!(
+            portal_action(PRIMARY_SHORTCUT_ID, false),
+            Some(HotkeyAction::PrimaryUp)
+        ));
+        assert!(matches!(
+            portal_action(COPY_LAST_SHORTCUT_ID, true),
+            Some(HotkeyAction::CopyLast)
+        ));
+        assert!(portal_action(COPY_LAST_SHORTCUT_ID, false).is_none());
+        assert!(matches!(
+            portal_action(CANCEL_SHORTCUT_ID, true),
+            Some(HotkeyAction::Cancel)
+        ));
+        assert!(portal_action("unknown", true).is_none());
+    }
+
+    #[test]
+    fn test_state_persists_token_and_skips_rebind() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join(STATE_FILE_NAME);
+        let shortcuts = vec![
+            shortcut(PRIMARY_SHORTCUT_ID, "Ctrl+Shift+Space"),
+            shortcut(COPY_LAST_SHORTCUT_ID, "Ctrl+Shift+V"),
+        ];
+
+        let mut state = PortalShortcutState::load(&path);
+        assert!(is_valid_token(&state.session_token));
+        assert!(state.needs_bind(&shortcuts, &[]));
+        state.record_bound(&shortcuts);
+        state.save(&path).unwrap();
+
+        let reloaded = PortalShortcutState::load(&path);
+        assert_eq!(reloaded.session_token, state.session_token);
+        let listed = vec!["primary".to_string(), "copy_last".to_string()];
+        assert!(!reloaded.needs_bind(&shortcuts, &listed));
+        // The portal forgot a shortcut, or the configured hotkey changed.
+        assert!(reloaded.needs_bind(&shortcuts, &listed[..1]));
+        let changed = vec![
+            shortcut(PRIMARY_SHORTCUT_ID, "Ctrl+Alt+Space"),
+            shortcut(COPY_LAST_SHORTCUT_ID, "Ctrl+Shift+V"),
+        ];
+        assert!(reloaded.needs_bind(&changed, &listed));
+    }
+
+    #[test]
+    fn test_invalid_persisted_token_is_replaced() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join(STATE_FILE_NAME);
+        fs::write(
+            &path,
+            r#"{"session_token":"bad-token/..","bound":{"primary":"Ctrl+Space"}}"#,
+        )
+        .unwrap();
+
+        let state = PortalShortcutState::load(&path);
+
+        assert!(is_valid_token(&state.session_token));
+        assert!(state.bound.is_empty());
+    }
+}
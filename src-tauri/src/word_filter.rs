@@ -0,0 +1,351 @@
+//! Profanity and sensitive-word filtering.
+//!
+//! Runs on the host after the sidecar returns final text and before the
+//! transcript is injected or stored in history. Words come from the user's
+//! list plus an optional built-in profanity preset, and are either masked
+//! (`f***`) or removed.
+
+use std::ops::Range;
+
+use regex::{Regex, RegexBuilder};
+
+use crate::config::{AppConfig, WordFilterConfig};
+use crate::focus::{app_override_candidates, normalize_app_id, FocusSignature};
+use crate::history::{TranscriptSegment, TranscriptWord};
+
+/// Built-in profanity list used when `word_filter.use_profanity_preset` is on.
+pub const PROFANITY_PRESET: &[&str] = &[
+    "arse",
+    "asshole",
+    "bastard",
+    "bitch",
+    "bollocks",
+    "bullshit",
+    "crap",
+    "damn",
+    "dick",
+    "fuck",
+    "fucking",
+    "motherfucker",
+    "piss",
+    "shit",
+    "wanker",
+];
+
+/// How matched words are treated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterMode {
+    /// Keep the first character and replace the rest with `*`.
+    Mask,
+    /// Drop the word entirely.
+    Remove,
+}
+
+impl FilterMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "mask" => Some(Self::Mask),
+            "remove" => Some(Self::Remove),
+            _ => None,
+        }
+    }
+}
+
+/// Whether filtering applies for the given focus target, honoring the active
+/// profile and then per-app overrides.
+pub fn filter_enabled_for_focus(config: &AppConfig, focus: Option<&FocusSignature>) -> bool {
+    let global = config.word_filter_enabled();
+    let Some(focus) = focus else {
+        return global;
+    };
+
+    for candidate in app_override_candidates(focus) {
+        for (key, app_override) in &config.injection.app_overrides {
+            let normalized_key = normalize_app_id(key).unwrap_or_else(|| key.to_lowercase());
+            if normalized_key == candidate {
+                return app_override.word_filter_enabled.unwrap_or(global);
+            }
+        }
+    }
+    global
+}
+
+fn filter_words(config: &WordFilterConfig) -> Vec<String> {
+    let mut words: Vec<String> = config
+        .words
+        .iter()
+        .map(|word| word.trim().to_string())
+        .filter(|word| !word.is_empty())
+        .collect();
+    if config.use_profanity_preset {
+        words.extend(PROFANITY_PRESET.iter().map(ToString::to_string));
+    }
+    words
+}
+
+fn build_pattern(words: &[String]) -> Option<Regex> {
+    if words.is_empty() {
+        return None;
+    }
+    let alternation = words
+        .iter()
+        .map(|word| regex::escape(word))
+        .collect::<Vec<_>>()
+        .join("|");
+    // `\b` never matches next to a term's non-word edge (e.g. "c++"), so the
+    // term is delimited by a non-word character or the ends of the text.
+    RegexBuilder::new(&format!(r"(?:^|\W)({alternation})(?:\W|$)"))
+        .case_insensitive(true)
+        .build()
+        .map_err(|error| log::warn!("Failed to build word filter pattern: {}", error))
+        .ok()
+}
+
+/// Byte ranges of the terms `pattern` finds in `text`. Each search resumes
+/// right after the previous term, so a delimiter can precede the next one.
+fn find_terms(pattern: &Regex, text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while let Some(term) = pattern
+        .captures_at(text, start)
+        .and_then(|caps| caps.get(1))
+    {
+        ranges.push(term.range());
+        start = term.end();
+    }
+    ranges
+}
+
+/// Drop `ranges` from `text`, closing only the gaps they leave behind: one
+/// space is kept between the neighbouring words, none before a line break,
+/// the end of the text, `,` or `.`, and none at the start of a line.
+fn remove_terms(text: &str, ranges: &[Range<usize>]) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = 0;
+    for range in ranges {
+        out.push_str(&text[rest..range.start]);
+        let after = &text[range.end..];
+        let gap_after = after.len() - after.trim_start_matches(' ').len();
+        let kept = out.trim_end_matches(' ').len();
+        let line_start = kept == 0 || out[..kept].ends_with('\n');
+        rest = range.end;
+        match after[gap_after..].chars().next() {
+            None | Some('\n' | ',' | '.') => {
+                out.truncate(kept);
+                rest += gap_after;
+            }
+            _ if line_start || kept < out.len() => {
+                out.truncate(kept);
+                if !line_start {
+                    out.push(' ');
+                }
+                rest += gap_after;
+            }
+            _ => {}
+        }
+    }
+    out.push_str(&text[rest..]);
+    out
+}
+
+fn mask_word(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => {
+            let mut masked = first.to_string();
+            masked.extend(chars.map(|_| '*'));
+            masked
+        }
+        None => String::new(),
+    }
+}
+
+/// Apply the configured filter to `text`.
+///
+/// Returns the filtered text, which equals the input when nothing matched.
+pub fn apply(config: &WordFilterConfig, text: &str) -> String {
+    let Some(mode) = FilterMode::parse(&config.mode) else {
+        return text.to_string();
+    };
+    let Some(pattern) = build_pattern(&filter_words(config)) else {
+        return text.to_string();
+    };
+    let ranges = find_terms(&pattern, text);
+
+    match mode {
+        FilterMode::Mask => {
+            let mut masked = String::with_capacity(text.len());
+            let mut rest = 0;
+            for range in ranges {
+                masked.push_str(&text[rest..range.start]);
+                masked.push_str(&mask_word(&text[range.clone()]));
+                rest = range.end;
+            }
+            masked.push_str(&text[rest..]);
+            masked
+        }
+        FilterMode::Remove => remove_terms(text, &ranges),
+    }
+}
+
+/// Filter segment text in place so subtitle exports match the stored transcript.
+pub fn apply_to_segments(config: &WordFilterConfig, segments: &mut [TranscriptSegment]) {
+    for segment in segments {
+        segment.text = apply(config, &segment.text);
+    }
+}
+
+/// Filter word timestamps in place; removed words are dropped.
+pub fn apply_to_words(config: &WordFilterConfig, words: &mut Vec<TranscriptWord>) {
+    for word in words.iter_mut() {
+        word.word = apply(config, &word.word);
+    }
+    words.retain(|word| !word.word.trim().is_empty());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppOverride;
+    use chrono::Utc;
+    use std::collections::HashMap;
+    use std::time::Instant;
+
+    fn filter_config(mode: &str, words: &[&str]) -> WordFilterConfig {
+        WordFilterConfig {
+            enabled: true,
+            mode: mode.to_string(),
+            words: words.iter().map(ToString::to_string).collect(),
+            use_profanity_preset: false,
+            keep_unfiltered: false,
+            profiles: HashMap::new(),
+        }
+    }
+
+    fn focus(process: &str) -> FocusSignature {
+        FocusSignature {
+            window_id: "1".to_string(),
+            process_name: process.to_string(),
+            app_name: process.to_string(),
+            captured_at: Instant::now(),
+            timestamp: Utc::now(),
+            window_bounds: None,
+            pid: None,
+        }
+    }
+
+    #[test]
+    fn test_mask_keeps_first_character_and_word_boundaries() {
+        let config = filter_config("mask", &["secret"]);
+        assert_eq!(
+            apply(&config, "The Secret plan, secretly secret."),
+            "The S***** plan, secretly s*****."
+        );
+    }
+
+    #[test]
+    fn test_remove_drops_words_and_collapses_spaces() {
+        let config = filter_config("remove", &["darn"]);
+        assert_eq!(
+            apply(&config, "well darn it works\nnow Darn."),
+            "well it works\nnow."
+        );
+    }
+
+    #[test]
+    fn test_remove_leaves_unmatched_text_untouched() {
+        let config = filter_config("remove", &["darn"]);
+        let text = "spaced  out , and darned .\n  indented";
+        assert_eq!(apply(&config, text), text);
+        assert_eq!(apply(&config, "so  darn , yes darn darn"), "so, yes");
+    }
+
+    #[test]
+    fn test_terms_with_non_word_edges_match() {
+        let config = filter_config("mask", &["c++", "@boss"]);
+        assert_eq!(
+            apply(&config, "C++ and @boss, not c++x or a@boss"),
+            "C** and @****, not c++x or a@boss"
+        );
+        let config = filter_config("mask", &["crap"]);
+        assert_eq!(apply(&config, "crap crap,crap"), "c*** c***,c***");
+    }
+
+    #[test]
+    fn test_profanity_preset_is_opt_in() {
+        let mut config = filter_config("mask", &[]);
+        assert_eq!(apply(&config, "oh crap"), "oh crap");
+        config.use_profanity_preset = true;
+        assert_eq!(apply(&config, "oh crap"), "oh c***");
+    }
+
+    #[test]
+    fn test_apply_to_words_drops_removed_words() {
+        let config = filter_config("remove", &["um"]);
+        let mut words = vec![
+            TranscriptWord {
+                word: "um".to_string(),
+                start_ms: 0,
+                end_ms: 100,
+                confidence: None,
+            },
+            TranscriptWord {
+                word: "hello".to_string(),
+                start_ms: 100,
+                end_ms: 400,
+                confidence: Some(0.9),
+            },
+        ];
+        apply_to_words(&config, &mut words);
+        assert_eq!(words.len(), 1);
+        assert_eq!(words[0].word, "hello");
+    }
+
+    #[test]
+    fn test_unknown_mode_leaves_text_unchanged() {
+        let config = filter_config("shout", &["secret"]);
+        assert_eq!(apply(&config, "secret"), "secret");
+    }
+
+    #[test]
+    fn test_app_override_can_disable_filter() {
+        let mut config = AppConfig::default();
+        config.word_filter.enabled = true;
+        config.injection.app_overrides.insert(
+            "Terminal".to_string(),
+            AppOverride {
+                paste_delay_ms: None,
+                use_clipboard_only: None,
+                word_filter_enabled: Some(false),
+            },
+        );
+
+        assert!(filter_enabled_for_focus(&config, None));
+        assert!(filter_enabled_for_focus(&config, Some(&focus("slack"))));
+        assert!(!filter_enabled_for_focus(&config, Some(&focus("terminal"))));
+    }
+
+    #[test]
+    fn test_active_profile_selects_filter_before_app_override() {
+        let mut config = AppConfig::default();
+        config.word_filter.profiles.insert("work".to_string(), true);
+        config.injection.app_overrides.insert(
+            "Terminal".to_string(),
+            AppOverride {
+                paste_delay_ms: None,
+                use_clipboard_only: None,
+                word_filter_enabled: Some(false),
+            },
+        );
+
+        assert!(!filter_enabled_for_focus(&config, Some(&focus("slack"))));
+
+        config.replacement_groups.active_profile = Some("work".to_string());
+        assert!(filter_enabled_for_focus(&config, None));
+        assert!(filter_enabled_for_focus(&config, Some(&focus("slack"))));
+        assert!(!filter_enabled_for_focus(&config, Some(&focus("terminal"))));
+
+        config.replacement_groups.active_profile = Some("home".to_string());
+        assert!(!filter_enabled_for_focus(&config, Some(&focus("slack"))));
+    }
+}
@@ -0,0 +1,260 @@
+//! Shared XDG desktop portal plumbing and the RemoteDesktop paste session.
+//!
+//! Portal methods reply with a Request object path and deliver their results
+//! later through its `Response` signal; `portal_request` hides that dance.
+//! `RemoteDesktopSession` uses the `org.freedesktop.portal.RemoteDesktop`
+//! portal to send the paste shortcut on Wayland compositors that do not offer
+//! a virtual keyboard protocol. Starting it shows a consent dialog once; the
+//! session is then kept for the lifetime of the app.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use uuid::Uuid;
+use zbus::blocking::{Connection, Proxy};
+use zbus::zvariant::{ObjectPath, OwnedObjectPath, OwnedValue, Value};
+
+const PORTAL_DESTINATION: &str = "org.freedesktop.portal.Desktop";
+const PORTAL_PATH: &str = "/org/freedesktop/portal/desktop";
+const REQUEST_INTERFACE: &str = "org.freedesktop.portal.Request";
+const SESSION_INTERFACE: &str = "org.freedesktop.portal.Session";
+const REMOTE_DESKTOP_INTERFACE: &str = "org.freedesktop.portal.RemoteDesktop";
+
+/// RemoteDesktop device type bit for keyboards.
+const DEVICE_TYPE_KEYBOARD: u32 = 1;
+
+const KEYSYM_CONTROL_L: i32 = 0xffe3;
+const KEYSYM_V: i32 = 0x0076;
+
+static REMOTE_DESKTOP: Lazy<Mutex<Option<RemoteDesktopSession>>> = Lazy::new(|| Mutex::new(None));
+
+/// Proxy for a portal interface on the desktop portal object.
+pub fn portal_proxy(connection: &Connection, interface: &str) -> Result<Proxy<'static>, String> {
+    Proxy::new(
+        connection,
+        PORTAL_DESTINATION,
+        PORTAL_PATH,
+        interface.to_string(),
+    )
+    .map_err(|e| format!("{interface} proxy creation failed: {e}"))
+}
+
+/// Call a portal method that answers through a Request object and wait for
+/// its Response.
+///
+/// The Request path is derived from `handle_token` (which the caller passes in
+/// the method options) so the Response subscription exists before the call
+/// and a fast reply cannot be missed.
+pub fn portal_request(
+    connection: &Connection,
+    portal: &Proxy<'_>,
+    method: &str,
+    handle_token: &str,
+    call: impl FnOnce(&Proxy<'_>) -> zbus::Result<zbus::Message>,
+) -> Result<HashMap<String, OwnedValue>, String> {
+    let sender = connection
+        .unique_name()
+        .ok_or_else(|| "session bus connection has no unique name".to_string())?;
+    let request = Proxy::new(
+        connection,
+        PORTAL_DESTINATION,
+        request_path(sender.as_str(), handle_token),
+        REQUEST_INTERFACE,
+    )
+    .map_err(|e| format!("request proxy creation failed: {e}"))?;
+    let mut responses = request
+        .receive_signal("Response")
+        .map_err(|e| format!("failed to wait for {method} response: {e}"))?;
+
+    call(portal).map_err(|e| format!("{method} call failed: {e}"))?;
+
+    let response = responses
+        .next()
+        .ok_or_else(|| format!("{method} request closed without a response"))?;
+    let (code, results): (u32, HashMap<String, OwnedValue>) = response
+        .body()
+        .deserialize()
+        .map_err(|e| format!("invalid {method} response: {e}"))?;
+
+    match code {
+        0 => Ok(results),
+        1 => Err(format!("{method} was cancelled by the user")),
+        _ => Err(format!("{method} failed with response code {code}")),
+    }
+}
+
+/// Create a portal session, returning its object path.
+pub fn create_session(
+    connection: &Connection,
+    portal: &Proxy<'_>,
+    session_token: &str,
+) -> Result<OwnedObjectPath, String> {
+    let handle_token = new_handle_token();
+    let options = HashMap::from([
+        ("handle_token", Value::from(handle_token.as_str())),
+        ("session_handle_token", Value::from(session_token)),
+    ]);
+    let results = portal_request(
+        connection,
+        portal,
+        "CreateSession",
+        &handle_token,
+        |proxy| proxy.call_method("CreateSession", &(options,)),
+    )?;
+    results
+        .get("session_handle")
+        .and_then(owned_value_path)
+        .ok_or_else(|| "CreateSession returned no session handle".to_string())
+}
+
+/// Close a portal session.
+pub fn close_session(connection: &Connection, session: &OwnedObjectPath) -> Result<(), String> {
+    Proxy::new(
+        connection,
+        PORTAL_DESTINATION,
+        session.as_ref(),
+        SESSION_INTERFACE,
+    )
+    .and_then(|proxy| proxy.call_method("Close", &()))
+    .map(|_| ())
+    .map_err(|e| format!("failed to close portal session: {e}"))
+}
+
+/// Request object path the portal uses for a sender and handle token.
+fn request_path(sender: &str, handle_token: &str) -> String {
+    let sender = sender.trim_start_matches(':').replace('.', "_");
+    format!("{PORTAL_PATH}/request/{sender}/{handle_token}")
+}
+
+/// Random token for a portal request or session handle.
+pub fn new_handle_token() -> String {
+    format!("openvoicy_{}", Uuid::new_v4().simple())
+}
+
+fn owned_value_path(value: &OwnedValue) -> Option<OwnedObjectPath> {
+    match &**value {
+        Value::ObjectPath(path) => Some(path.clone().into()),
+        Value::Str(path) => ObjectPath::try_from(path.as_str())
+            .ok()
+            .map(|path| path.into()),
+        _ => None,
+    }
+}
+
+/// Started RemoteDesktop session with keyboard access.
+struct RemoteDesktopSession {
+    connection: Connection,
+    session: OwnedObjectPath,
+}
+
+impl RemoteDesktopSession {
+    fn start() -> Result<Self, String> {
+        let connection =
+            Connection::session().map_err(|e| format!("session bus connection failed: {e}"))?;
+        let portal = portal_proxy(&connection, REMOTE_DESKTOP_INTERFACE)?;
+        let session = create_session(&connection, &portal, &new_handle_token())?;
+
+        let handle_token = new_handle_token();
+        let select_options = HashMap::from([
+            ("handle_token", Value::from(handle_token.as_str())),
+            ("types", Value::from(DEVICE_TYPE_KEYBOARD)),
+        ]);
+        portal_request(
+            &connection,
+            &portal,
+            "SelectDevices",
+            &handle_token,
+            |proxy| proxy.call_method("SelectDevices", &(session.as_ref(), select_options)),
+        )?;
+
+        let handle_token = new_handle_token();
+        let start_options = HashMap::from([("handle_token", Value::from(handle_token.as_str()))]);
+        let results = portal_request(&connection, &portal, "Start", &handle_token, |proxy| {
+            proxy.call_method("Start", &(session.as_ref(), "", start_options))
+        })?;
+        let devices = results
+            .get("devices")
+            .and_then(|value| u32::try_from(value).ok())
+            .unwrap_or(0);
+        if devices & DEVICE_TYPE_KEYBOARD == 0 {
+            let _ = close_session(&connection, &session);
+            return Err("RemoteDesktop session was not granted keyboard access".to_string());
+        }
+
+        log::info!("Started RemoteDesktop portal session for paste injection");
+        Ok(Self {
+            connection,
+            session,
+        })
+    }
+
+    fn send_keysyms(&self, keysyms: &[(i32, u32)]) -> Result<(), String> {
+        let portal = portal_proxy(&self.connection, REMOTE_DESKTOP_INTERFACE)?;
+        for &(keysym, state) in keysyms {
+            let options: HashMap<&str, Value> = HashMap::new();
+            portal
+                .call_method(
+                    "NotifyKeyboardKeysym",
+                    &(self.session.as_ref(), options, keysym, state),
+                )
+                .map_err(|e| format!("NotifyKeyboardKeysym failed: {e}"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Press Ctrl+V through the RemoteDesktop portal, starting the session
+/// (and its consent dialog) on first use.
+pub fn remote_desktop_paste() -> Result<(), String> {
+    let mut guard = REMOTE_DESKTOP
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if guard.is_none() {
+        *guard = Some(RemoteDesktopSession::start()?);
+    }
+    let Some(session) = guard.as_ref() else {
+        return Err("RemoteDesktop session unavailable".to_string());
+    };
+
+    let result = session.send_keysyms(&paste_keysyms());
+    if result.is_err() {
+        // The compositor may have revoked the session; start over next time.
+        if let Some(session) = guard.take() {
+            let _ = close_session(&session.connection, &session.session);
+        }
+    }
+    result
+}
+
+/// Key events (keysym, pressed) for Ctrl+V.
+fn paste_keysyms() -> [(i32, u32); 4] {
+    [
+        (KEYSYM_CONTROL_L, 1),
+        (KEYSYM_V, 1),
+        (KEYSYM_V, 0),
+        (KEYSYM_CONTROL_L, 0),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_path_escapes_sender() {
+        assert_eq!(
+            request_path(":1.42", "openvoicy_abc"),
+            "/org/freedesktop/portal/desktop/request/1_42/openvoicy_abc"
+        );
+    }
+
+    #[test]
+    fn test_paste_keysyms_release_in_reverse_order() {
+        let keys = paste_keysyms();
+        assert_eq!(keys[0], (KEYSYM_CONTROL_L, 1));
+        assert_eq!(keys[1], (KEYSYM_V, 1));
+        assert_eq!(keys[2], (KEYSYM_V, 0));
+        assert_eq!(keys[3], (KEYSYM_CONTROL_L, 0));
+    }
+}
@@ -1,6 +1,6 @@
 import { useCallback, useEffect, useMemo, useState } from 'react';
 import { useAppStore, selectAppState, selectReplacementBadgeCount } from './store';
-import { useReducedMotion, useTauriEvents, useTheme } from './hooks';
+import { useReducedMotion, useTauriEvent, useTauriEvents, useTheme } from './hooks';
 import {
   SelfCheck,
   Diagnostics,
@@ -12,11 +12,12 @@ import {
   TabPanel,
   ReplacementList,
   PresetsPanel,
+  StatisticsPanel,
 } from './components';
 import { OnboardingWizard } from './components/Onboarding';
-import type { DiagnosticsReport, ReplacementRule } from './types';
+import type { DiagnosticsReport, ReplacementRule, UsageStats } from './types';
 
-type AppTab = 'status' | 'history' | 'replacements' | 'settings';
+type AppTab = 'status' | 'history' | 'statistics' | 'replacements' | 'settings';
 
 function App() {
   // Set up Tauri event listeners
@@ -29,11 +30,16 @@ function App() {
   const replacementsBadgeCount = useAppStore(selectReplacementBadgeCount);
   const enabled = useAppStore((state) => state.enabled);
   const errorDetail = useAppStore((state) => state.errorDetail);
+  const screenSharingWarning = useAppStore(
+    (state) => state.appState === 'recording' && state.recordingStatus?.screen_sharing === true
+  );
   const isInitialized = useAppStore((state) => state.isInitialized);
   const isLoading = useAppStore((state) => state.isLoading);
   const devices = useAppStore((state) => state.devices);
   const audioLevel = useAppStore((state) => state.audioLevel);
+  const vadPreview = useAppStore((state) => state.vadPreview);
   const isMeterRunning = useAppStore((state) => state.isMeterRunning);
+  const cueStatus = useAppStore((state) => state.cueStatus);
   const history = useAppStore((state) => state.history);
   const downloadProgress = useAppStore((state) => state.downloadProgress);
   const selfCheckResult = useAppStore((state) => state.selfCheckResult);
@@ -45,20 +51,31 @@ function App() {
   const refreshDevices = useAppStore((state) => state.refreshDevices);
   const runSelfCheck = useAppStore((state) => state.runSelfCheck);
   const generateDiagnostics = useAppStore((state) => state.generateDiagnostics);
+  const exportAppStateSnapshot = useAppStore((state) => state.exportAppStateSnapshot);
+  const getUsageStats = useAppStore((state) => state.getUsageStats);
   const updateAudioConfig = useAppStore((state) => state.updateAudioConfig);
   const updateHotkeyConfig = useAppStore((state) => state.updateHotkeyConfig);
   const updateInjectionConfig = useAppStore((state) => state.updateInjectionConfig);
   const updateUiConfig = useAppStore((state) => state.updateUiConfig);
+  const updateNetworkConfig = useAppStore((state) => state.updateNetworkConfig);
+  const updateCompanionConfig = useAppStore((state) => state.updateCompanionConfig);
+  const updateCrashReportingConfig = useAppStore((state) => state.updateCrashReportingConfig);
+  const updatePowerConfig = useAppStore((state) => state.updatePowerConfig);
   const setReplacementRules = useAppStore((state) => state.setReplacementRules);
   const loadPreset = useAppStore((state) => state.loadPreset);
   const startMicTest = useAppStore((state) => state.startMicTest);
+  const setInputGain = useAppStore((state) => state.setInputGain);
   const stopMicTest = useAppStore((state) => state.stopMicTest);
   const copyTranscript = useAppStore((state) => state.copyTranscript);
+  const reinjectTranscript = useAppStore((state) => state.reinjectTranscript);
+  const getAppIcon = useAppStore((state) => state.getAppIcon);
   const clearHistory = useAppStore((state) => state.clearHistory);
 
   const [isSelfCheckLoading, setIsSelfCheckLoading] = useState(false);
   const [isDiagnosticsLoading, setIsDiagnosticsLoading] = useState(false);
   const [diagnosticsReport, setDiagnosticsReport] = useState<DiagnosticsReport | null>(null);
+  const [usageStats, setUsageStats] = useState<UsageStats | null>(null);
+  const [isUsageStatsLoading, setIsUsageStatsLoading] = useState(false);
   const [activeTab, setActiveTab] = useState<AppTab>('status');
   const [presetRulesById, setPresetRulesById] = useState<Map<string, ReplacementRule[]>>(new Map());
   const [onboardingDismissed, setOnboardingDismissed] = useState(false);
@@ -87,6 +104,17 @@ function App() {
     }
   }, [generateDiagnostics]);
 
+  const refreshUsageStats = useCallback(async () => {
+    setIsUsageStatsLoading(true);
+    try {
+      setUsageStats(await getUsageStats());
+    } catch {
+      // Logged by the store; keep showing the last stats.
+    } finally {
+      setIsUsageStatsLoading(false);
+    }
+  }, [getUsageStats]);
+
   const handleSettingsChange = useCallback(async (path: string[], value: any) => {
     const [section, key] = path;
     if (!section || !key) return;
@@ -105,13 +133,39 @@ function App() {
     }
     if (section === 'ui') {
       await updateUiConfig({ [key]: value });
+      return;
     }
-  }, [updateAudioConfig, updateHotkeyConfig, updateInjectionConfig, updateUiConfig]);
+    if (section === 'network') {
+      await updateNetworkConfig({ [key]: value });
+      return;
+    }
+    if (section === 'companion') {
+      await updateCompanionConfig({ [key]: value });
+      return;
+    }
+    if (section === 'crash_reporting') {
+      await updateCrashReportingConfig({ [key]: value });
+      return;
+    }
+    if (section === 'power') {
+      await updatePowerConfig({ [key]: value });
+    }
+  }, [
+    updateAudioConfig,
+    updateHotkeyConfig,
+    updateInjectionConfig,
+    updateUiConfig,
+    updateNetworkConfig,
+    updateCompanionConfig,
+    updateCrashReportingConfig,
+    updatePowerConfig,
+  ]);
 
   const handleTabChange = useCallback((tabId: string) => {
     if (
       tabId === 'status'
       || tabId === 'history'
+      || tabId === 'statistics'
       || tabId === 'replacements'
       || tabId === 'settings'
     ) {
@@ -119,6 +173,20 @@ function App() {
     }
   }, []);
 
+  // Tray click-through (e.g. the "Today: ..." summary).
+  useTauriEvent<{ tab?: string }>('app:navigate', (payload) => {
+    if (payload?.tab) {
+      handleTabChange(payload.tab);
+    }
+  });
+
+  // Statistics follow history; refresh while the page is open.
+  useEffect(() => {
+    if (activeTab === 'statistics') {
+      void refreshUsageStats();
+    }
+  }, [activeTab, history, refreshUsageStats]);
+
   const handleReplacementRulesChange = useCallback((rules: ReplacementRule[]) => {
     void setReplacementRules(rules).catch((error) => {
       console.error('Failed to update replacement rules from tab', error);
@@ -213,6 +281,7 @@ function App() {
   const tabs = [
     { id: 'status', label: 'Status' },
     { id: 'history', label: 'History' },
+    { id: 'statistics', label: 'Statistics' },
     { id: 'replacements', label: 'Replacements', badge: replacementsBadgeCount },
     { id: 'settings', label: 'Settings' },
   ];
@@ -233,7 +302,10 @@ function App() {
           <StatusIndicator
             state={appState}
             enabled={enabled}
-            detail={errorDetail}
+            detail={
+              errorDetail
+              ?? (screenSharingWarning ? 'Screen sharing is active; viewers may see your dictation' : undefined)
+            }
             progress={downloadProgress ?? undefined}
           />
         </div>
@@ -250,10 +322,20 @@ function App() {
               <HistoryPanel
                 entries={history.slice(0, 25)}
                 onCopy={copyTranscript}
+                onReinject={reinjectTranscript}
+                onLoadAppIcon={getAppIcon}
                 onClearAll={clearHistory}
               />
             </TabPanel>
 
+            <TabPanel id="statistics" activeTab={activeTab}>
+              <StatisticsPanel
+                stats={usageStats}
+                onRefresh={() => void refreshUsageStats()}
+                isLoading={isUsageStatsLoading}
+              />
+            </TabPanel>
+
             <TabPanel id="replacements" activeTab={activeTab}>
               {config ? (
                 <div className="flex h-full min-h-0 flex-col gap-4 overflow-y-auto pr-1">
@@ -286,11 +368,15 @@ function App() {
                       config={config}
                       devices={devices}
                       audioLevel={audioLevel}
+                      vadPreview={vadPreview}
                       isMeterRunning={isMeterRunning}
+                      cueStatus={cueStatus}
                       effectiveHotkeyMode={capabilities?.hotkey_mode}
+                      primarySelectionAvailable={capabilities?.primary_selection_available}
                       onStartMicTest={startMicTest}
                       onStopMicTest={stopMicTest}
                       onRefreshDevices={refreshDevices}
+                      onInputGainChange={setInputGain}
                       onConfigChange={handleSettingsChange}
                       isLoading={isLoading}
                     />
@@ -309,6 +395,7 @@ function App() {
                   <Diagnostics
                     report={diagnosticsReport}
                     onRefresh={refreshDiagnostics}
+                    onExportSnapshot={exportAppStateSnapshot}
                     isLoading={isDiagnosticsLoading}
                   />
                 </div>
@@ -0,0 +1,53 @@
+/**
+ * Compile-time drift check between Rust payloads and frontend types.
+ *
+ * `src/bindings/` is generated by ts-rs during `cargo test` and is not
+ * committed, so this file is excluded from the main tsconfig and checked by
+ * `scripts/check_ts_bindings.sh` instead. Each assertion fails to compile when
+ * the backend emits a shape the frontend types would not accept.
+ *
+ * Fields the backend types as plain strings but the frontend narrows to a
+ * union (error codes, model states, recording phases) are omitted here; those
+ * values are covered by the contract catalogs instead.
+ */
+
+import type { AppError as WireAppError } from './bindings/AppError';
+import type { Capabilities as WireCapabilities } from './bindings/Capabilities';
+import type { FileTranscriptionProgressPayload as WireFileTranscriptionProgress } from './bindings/FileTranscriptionProgressPayload';
+import type { ModelStatusPayload as WireModelStatus } from './bindings/ModelStatusPayload';
+import type { PendingInsertsChangedPayload as WirePendingInsertsChanged } from './bindings/PendingInsertsChangedPayload';
+import type { RecordingStatusPayload as WireRecordingStatus } from './bindings/RecordingStatusPayload';
+import type { SessionSnapshot as WireSessionSnapshot } from './bindings/SessionSnapshot';
+import type { TranscriptEntry as WireTranscriptEntry } from './bindings/TranscriptEntry';
+import type {
+  AppError,
+  Capabilities,
+  FileTranscriptionProgressEvent,
+  ModelStatus,
+  PendingInsertsChangedEvent,
+  RecordingStatusEvent,
+  SessionSnapshot,
+  TranscriptEntry,
+} from './types';
+
+type Assignable<Wire, Frontend> = [Wire] extends [Frontend] ? true : false;
+type Assert<T extends true> = T;
+
+export type AppErrorParity = Assert<
+  Assignable<Omit<WireAppError, 'code'>, Omit<AppError, 'code'>>
+>;
+export type CapabilitiesParity = Assert<Assignable<WireCapabilities, Capabilities>>;
+export type FileTranscriptionProgressParity = Assert<
+  Assignable<WireFileTranscriptionProgress, FileTranscriptionProgressEvent>
+>;
+export type ModelStatusParity = Assert<
+  Assignable<Omit<WireModelStatus, 'status' | 'kind'>, Omit<ModelStatus, 'status' | 'kind'>>
+>;
+export type PendingInsertsChangedParity = Assert<
+  Assignable<WirePendingInsertsChanged, PendingInsertsChangedEvent>
+>;
+export type RecordingStatusParity = Assert<
+  Assignable<Omit<WireRecordingStatus, 'phase'>, Omit<RecordingStatusEvent, 'phase'>>
+>;
+export type SessionSnapshotParity = Assert<Assignable<WireSessionSnapshot, SessionSnapshot>>;
+export type TranscriptEntryParity = Assert<Assignable<WireTranscriptEntry, TranscriptEntry>>;
@@ -1,9 +1,13 @@
-import { useState } from 'react';
-import type { InjectionResult, TranscriptEntry } from '../../types';
+import { useEffect, useState } from 'react';
+import type { AppIcon, InjectionResult, TranscriptEntry } from '../../types';
 
 interface HistoryEntryProps {
   entry: TranscriptEntry;
   onCopy: () => Promise<void>;
+  /** Inject into the window the transcript was dictated into; hidden when unset. */
+  onReinject?: () => Promise<void>;
+  /** Resolve the icon of the app the transcript went to; no icon when unset. */
+  onLoadAppIcon?: (appId: string) => Promise<AppIcon | null>;
 }
 
 /** Format a timestamp as relative time. */
@@ -78,10 +82,28 @@ function timingPairs(entry: TranscriptEntry): Array<{ label: string; value: numb
     .map((timing) => ({ label: timing.label, value: timing.value as number }));
 }
 
-export function HistoryEntry({ entry, onCopy }: HistoryEntryProps) {
+export function HistoryEntry({ entry, onCopy, onReinject, onLoadAppIcon }: HistoryEntryProps) {
   const [copied, setCopied] = useState(false);
   const [copyError, setCopyError] = useState<string | null>(null);
+  const [reinjecting, setReinjecting] = useState(false);
   const [showRawText, setShowRawText] = useState(false);
+  const [appIconUrl, setAppIconUrl] = useState<string | null>(null);
+  const appId = entry.injection_target?.app_id;
+
+  useEffect(() => {
+    if (!appId || !onLoadAppIcon) {
+      return;
+    }
+    let cancelled = false;
+    void onLoadAppIcon(appId).then((icon) => {
+      if (!cancelled) {
+        setAppIconUrl(icon?.data_url ?? null);
+      }
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, [appId, onLoadAppIcon]);
   const badge = getInjectionBadge(entry.injection_result);
   const hasRawFinalDiff =
     typeof entry.raw_text === 'string'
@@ -108,6 +130,21 @@ export function HistoryEntry({ entry, onCopy }: HistoryEntryProps) {
     }
   };
 
+  const handleReinject = async () => {
+    if (!onReinject) {
+      return;
+    }
+    setCopyError(null);
+    setReinjecting(true);
+    try {
+      await onReinject();
+    } catch (e) {
+      setCopyError(e instanceof Error ? e.message : 'Failed to insert transcript');
+    } finally {
+      setReinjecting(false);
+    }
+  };
+
   return (
     <div className="rounded-lg border border-gray-200 bg-white p-3 transition-shadow hover:shadow-sm dark:border-gray-700 dark:bg-gray-800">
       <div className="mb-2 flex items-center justify-between">
@@ -115,6 +152,33 @@ export function HistoryEntry({ entry, onCopy }: HistoryEntryProps) {
           <span>{formatRelativeTime(entry.timestamp)}</span>
           <span>•</span>
           <span>{formatDuration(entry.audio_duration_ms)} audio</span>
+          {entry.source === 'file' ? (
+            <>
+              <span>•</span>
+              <span
+                data-testid={`history-entry-source-${entry.id}`}
+                title={entry.source_file}
+                className="max-w-[12rem] truncate"
+              >
+                📄 {entry.source_file ?? 'Audio file'}
+              </span>
+            </>
+          ) : null}
+          {entry.app_name ? (
+            <>
+              <span>•</span>
+              <span
+                data-testid={`history-entry-target-${entry.id}`}
+                title={entry.window_title ?? entry.app_name}
+                className="flex max-w-[12rem] items-center gap-1"
+              >
+                {appIconUrl ? (
+                  <img src={appIconUrl} alt="" aria-hidden="true" className="h-4 w-4 shrink-0" />
+                ) : null}
+                <span className="truncate">{entry.app_name}</span>
+              </span>
+            </>
+          ) : null}
         </div>
 
         <div className={`flex items-center gap-1 text-sm ${badge.color}`} title={badge.tooltip}>
@@ -170,7 +234,19 @@ export function HistoryEntry({ entry, onCopy }: HistoryEntryProps) {
         </div>
       ) : null}
 
-      <div className="flex justify-end">
+      <div className="flex justify-end gap-1">
+        {onReinject ? (
+          <button
+            type="button"
+            data-testid={`history-entry-reinject-${entry.id}`}
+            onClick={() => void handleReinject()}
+            disabled={reinjecting}
+            title={entry.app_name ? `Insert into ${entry.app_name}` : undefined}
+            className="rounded px-3 py-1 text-sm text-gray-600 transition-colors hover:bg-gray-100 disabled:opacity-50 dark:text-gray-300 dark:hover:bg-gray-700"
+          >
+            Insert into original window
+          </button>
+        ) : null}
         <button
           type="button"
           onClick={() => void handleCopy()}
@@ -4,9 +4,10 @@
 
 import { describe, it, expect, vi } from 'vitest';
 import { useState } from 'react';
-import { act, fireEvent, render, screen } from '@testing-library/react';
+import { act, fireEvent, render, screen, waitFor } from '@testing-library/react';
 import { HistoryPanel } from './HistoryPanel';
 import type { TranscriptEntry } from '../../types';
+import { emitMockEvent } from '../../tests/setup';
 
 // Mock entries for testing
 const mockEntries: TranscriptEntry[] = [
@@ -487,4 +488,93 @@ describe('HistoryPanel', () => {
 
     expect(await screen.findByText('No transcripts yet')).toBeDefined();
   });
+
+  it('marks entries transcribed from a file', () => {
+    const fileEntry: TranscriptEntry = {
+      ...mockEntries[1],
+      id: 'file-1',
+      source: 'file',
+      source_file: 'interview.wav',
+    };
+    render(<HistoryPanel entries={[fileEntry, mockEntries[0]]} onCopy={vi.fn().mockResolvedValue(undefined)} />);
+
+    expect(screen.getByTestId('history-entry-source-file-1').textContent).toContain('interview.wav');
+    expect(screen.queryByTestId('history-entry-source-1')).toBeNull();
+  });
+
+  it('re-injects entries that recorded their target window', async () => {
+    const targeted: TranscriptEntry = {
+      ...mockEntries[0],
+      app_name: 'Code',
+      injection_target: { app_id: 'code', window_title_hash: 'ab12' },
+    };
+    const onReinject = vi.fn().mockRejectedValue(new Error('The original window is no longer open'));
+    render(
+      <HistoryPanel
+        entries={[targeted, mockEntries[1]]}
+        onCopy={vi.fn().mockResolvedValue(undefined)}
+        onReinject={onReinject}
+      />
+    );
+
+    expect(screen.queryByTestId('history-entry-reinject-2')).toBeNull();
+    fireEvent.click(screen.getByTestId('history-entry-reinject-1'));
+
+    expect(onReinject).toHaveBeenCalledWith('1');
+    expect((await screen.findByRole('alert')).textContent).toContain('no longer open');
+  });
+
+  it('shows the target app with its lazily loaded icon', async () => {
+    const targeted: TranscriptEntry = {
+      ...mockEntries[0],
+      app_name: 'Code',
+      window_title: 'main.rs - crate',
+      injection_target: { app_id: 'code' },
+    };
+    const onLoadAppIcon = vi
+      .fn()
+      .mockResolvedValue({ app_id: 'code', data_url: 'data:image/png;base64,AAAA' });
+    render(
+      <HistoryPanel
+        entries={[targeted, mockEntries[1]]}
+        onCopy={vi.fn().mockResolvedValue(undefined)}
+        onLoadAppIcon={onLoadAppIcon}
+      />
+    );
+
+    const target = screen.getByTestId('history-entry-target-1');
+    expect(target.textContent).toContain('Code');
+    expect(target.getAttribute('title')).toBe('main.rs - crate');
+    await waitFor(() => {
+      expect(target.querySelector('img')?.getAttribute('src')).toBe('data:image/png;base64,AAAA');
+    });
+    expect(onLoadAppIcon).toHaveBeenCalledTimes(1);
+    expect(onLoadAppIcon).toHaveBeenCalledWith('code');
+    expect(screen.queryByTestId('history-entry-target-2')).toBeNull();
+  });
+
+  it('shows progress and failures of dropped files', () => {
+    render(<HistoryPanel entries={mockEntries} onCopy={vi.fn().mockResolvedValue(undefined)} />);
+    expect(screen.queryByTestId('history-file-progress')).toBeNull();
+
+    act(() => {
+      emitMockEvent('file_transcription:progress', { seq: 1, file_name: 'a.wav', phase: 'transcribing' });
+    });
+    expect(screen.getByTestId('history-file-progress').textContent).toContain('Transcribing a.wav');
+
+    act(() => {
+      emitMockEvent('file_transcription:progress', { seq: 2, file_name: 'a.wav', phase: 'complete', entry_id: '1' });
+    });
+    expect(screen.queryByTestId('history-file-progress')).toBeNull();
+
+    act(() => {
+      emitMockEvent('file_transcription:progress', {
+        seq: 3,
+        file_name: 'song.mp3',
+        phase: 'failed',
+        error: 'song.mp3: only WAV audio files can be transcribed',
+      });
+    });
+    expect(screen.getByRole('alert').textContent).toContain('only WAV audio files');
+  });
 });
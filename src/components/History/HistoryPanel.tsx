@@ -7,10 +7,12 @@
  * - Shows injection status (injected, clipboard-only, error)
  * - Relative timestamps ("2 minutes ago")
  * - Audio duration display
+ * - Progress of audio files dropped onto the window
  */
 
 import { useEffect, useMemo, useRef, useState } from 'react';
-import type { TranscriptEntry } from '../../types';
+import { useTauriEvent } from '../../hooks/useTauriEvents';
+import type { AppIcon, FileTranscriptionProgressEvent, TranscriptEntry } from '../../types';
 import { HistoryEntry } from './HistoryEntry';
 
 export type ExportFormat = 'markdown' | 'csv';
@@ -18,6 +20,10 @@ export type ExportFormat = 'markdown' | 'csv';
 export interface HistoryPanelProps {
   entries: TranscriptEntry[];
   onCopy: (id: string) => Promise<void>;
+  /** Inject an entry into the window it was dictated into. */
+  onReinject?: (id: string) => Promise<void>;
+  /** Resolve the icon of the app an entry went to. */
+  onLoadAppIcon?: (appId: string) => Promise<AppIcon | null>;
   onClearAll?: () => Promise<void>;
   onExport?: (format: ExportFormat) => Promise<string>;
 }
@@ -30,7 +36,14 @@ function getFocusableElements(container: HTMLElement): HTMLElement[] {
   );
 }
 
-export function HistoryPanel({ entries, onCopy, onClearAll, onExport }: HistoryPanelProps) {
+export function HistoryPanel({
+  entries,
+  onCopy,
+  onReinject,
+  onLoadAppIcon,
+  onClearAll,
+  onExport,
+}: HistoryPanelProps) {
   const [searchInput, setSearchInput] = useState('');
   const [debouncedQuery, setDebouncedQuery] = useState('');
   const [showClearConfirm, setShowClearConfirm] = useState(false);
@@ -39,6 +52,7 @@ export function HistoryPanel({ entries, onCopy, onClearAll, onExport }: HistoryP
   const [isExporting, setIsExporting] = useState(false);
   const [exportResult, setExportResult] = useState<{ path: string } | null>(null);
   const [exportError, setExportError] = useState<string | null>(null);
+  const [fileProgress, setFileProgress] = useState<FileTranscriptionProgressEvent | null>(null);
   const clearAllButtonRef = useRef<HTMLButtonElement | null>(null);
   const clearDialogRef = useRef<HTMLDivElement | null>(null);
   const wasDialogOpenRef = useRef(false);
@@ -123,6 +137,10 @@ export function HistoryPanel({ entries, onCopy, onClearAll, onExport }: HistoryP
     };
   }, [showClearConfirm]);
 
+  useTauriEvent<FileTranscriptionProgressEvent>('file_transcription:progress', (payload) => {
+    setFileProgress(payload.phase === 'complete' ? null : payload);
+  });
+
   const filteredEntries = useMemo(() => {
     if (debouncedQuery.length === 0) {
       return entries;
@@ -132,11 +150,20 @@ export function HistoryPanel({ entries, onCopy, onClearAll, onExport }: HistoryP
       const text = entry.text.toLowerCase();
       const finalText = (entry.final_text ?? '').toLowerCase();
       const language = (entry.language ?? '').toLowerCase();
+      const appName = (entry.app_name ?? '').toLowerCase();
+      const windowTitle = (entry.window_title ?? '').toLowerCase();
+      const sessionName = (entry.session_name ?? '').toLowerCase();
+      const sourceFile = (entry.source_file ?? '').toLowerCase();
 
       return (
         text.includes(debouncedQuery)
         || finalText.includes(debouncedQuery)
         || language.includes(debouncedQuery)
+        || appName.includes(debouncedQuery)
+        || windowTitle.includes(debouncedQuery)
+        || sessionName.includes(debouncedQuery)
+        || sourceFile.includes(debouncedQuery)
+        || (entry.tags ?? []).some((tag) => tag.toLowerCase().includes(debouncedQuery))
       );
     });
   }, [debouncedQuery, entries]);
@@ -290,6 +317,31 @@ export function HistoryPanel({ entries, onCopy, onClearAll, onExport }: HistoryP
         </div>
       ) : null}
 
+      {fileProgress?.phase === 'failed' ? (
+        <div
+          role="alert"
+          data-testid="history-file-progress"
+          className="rounded-md border border-red-600/40 bg-red-50 p-2.5 text-xs text-red-700 dark:border-red-500/30 dark:bg-red-900/20 dark:text-red-300"
+        >
+          Could not transcribe {fileProgress.file_name}: {fileProgress.error ?? 'unknown error'}
+          <button
+            type="button"
+            onClick={() => setFileProgress(null)}
+            className="ml-2 text-red-600 underline hover:text-red-500 dark:text-red-400"
+          >
+            Dismiss
+          </button>
+        </div>
+      ) : fileProgress ? (
+        <div
+          role="status"
+          data-testid="history-file-progress"
+          className="rounded-md border border-blue-600/40 bg-blue-50 p-2.5 text-xs text-blue-700 dark:border-blue-500/30 dark:bg-blue-900/20 dark:text-blue-300"
+        >
+          {fileProgress.phase === 'queued' ? 'Queued' : 'Transcribing'} {fileProgress.file_name}…
+        </div>
+      ) : null}
+
       {entries.length === 0 ? (
         <div className="flex h-full min-h-0 items-center justify-center rounded-lg bg-gray-50 p-8 text-center text-gray-500 dark:bg-gray-800 dark:text-gray-400">
           <div>
@@ -312,6 +364,8 @@ export function HistoryPanel({ entries, onCopy, onClearAll, onExport }: HistoryP
               key={entry.id}
               entry={entry}
               onCopy={() => onCopy(entry.id)}
+              onReinject={onReinject && entry.injection_target ? () => onReinject(entry.id) : undefined}
+              onLoadAppIcon={onLoadAppIcon}
             />
           ))}
         </div>
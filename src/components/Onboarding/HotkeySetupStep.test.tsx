@@ -1,7 +1,7 @@
 /**
  * Tests for HotkeySetupStep onboarding component.
  *
- * Covers: hotkey display, key recording, mode selection,
+ * Covers: hotkey display, key recording, mode selection, practice round,
  * config update calls, error handling.
  */
 
@@ -61,12 +61,14 @@ function makeConfig(hotkeyOverrides: Partial<AppConfig['hotkeys']> = {}): AppCon
 // ── Setup ─────────────────────────────────────────────────────────
 
 const updateHotkeyConfigSpy = vi.fn().mockResolvedValue(undefined);
+const practiceHotkeySpy = vi.fn();
 
 beforeEach(() => {
   vi.clearAllMocks();
   useAppStore.setState({
     config: makeConfig(),
     updateHotkeyConfig: updateHotkeyConfigSpy,
+    practiceHotkey: practiceHotkeySpy,
   });
 });
 
@@ -273,4 +275,44 @@ describe('HotkeySetupStep', () => {
     const holdRadio = screen.getByDisplayValue('hold') as HTMLInputElement;
     expect(holdRadio.checked).toBe(true);
   });
+
+  test('practice round reports a working hotkey', async () => {
+    practiceHotkeySpy.mockResolvedValueOnce({
+      hotkey: 'Ctrl+Shift+Space',
+      mode: 'hold',
+      passed: true,
+      presses: 1,
+      releases: 1,
+      hold_ms: 900,
+      latency_ms: 14,
+    });
+    render(<HotkeySetupStep onReady={vi.fn()} />);
+
+    await act(async () => {
+      fireEvent.click(screen.getByRole('button', { name: 'Try your hotkey' }));
+    });
+
+    expect(practiceHotkeySpy).toHaveBeenCalled();
+    expect(screen.getByText(/Your hotkey works/)).toBeDefined();
+    expect(screen.getByText(/14 ms/)).toBeDefined();
+  });
+
+  test('practice round shows the hint when the hotkey does not match the mode', async () => {
+    practiceHotkeySpy.mockResolvedValueOnce({
+      hotkey: 'Ctrl+Shift+Space',
+      mode: 'hold',
+      passed: false,
+      presses: 1,
+      releases: 0,
+      hint: 'The hotkey release was not detected. Switch to toggle mode.',
+    });
+    render(<HotkeySetupStep onReady={vi.fn()} />);
+
+    await act(async () => {
+      fireEvent.click(screen.getByRole('button', { name: 'Try your hotkey' }));
+    });
+
+    expect(screen.queryByText(/Your hotkey works/)).toBeNull();
+    expect(screen.getByText(/release was not detected/)).toBeDefined();
+  });
 });
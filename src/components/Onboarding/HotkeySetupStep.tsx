@@ -2,12 +2,13 @@
  * Onboarding step for hotkey configuration.
  *
  * Shows the current recording hotkey, lets the user re-record it,
- * and explains hold vs toggle activation modes.
+ * explains hold vs toggle activation modes, and runs a practice round
+ * so the user can confirm the hotkey works before dictating.
  */
 
 import { useState, useCallback } from 'react';
 import { useAppStore } from '../../store/appStore';
-import type { HotkeyMode } from '../../types';
+import type { HotkeyMode, HotkeyPracticeReport } from '../../types';
 
 export interface HotkeySetupStepProps {
   onReady: () => void;
@@ -16,12 +17,15 @@ export interface HotkeySetupStepProps {
 export function HotkeySetupStep({ onReady: _onReady }: HotkeySetupStepProps) {
   const config = useAppStore((s) => s.config);
   const updateHotkeyConfig = useAppStore((s) => s.updateHotkeyConfig);
+  const practiceHotkey = useAppStore((s) => s.practiceHotkey);
 
   const primaryHotkey = config?.hotkeys.primary ?? 'Ctrl+Shift+Space';
   const mode = config?.hotkeys.mode ?? 'hold';
 
   const [isRecording, setIsRecording] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  const [isPracticing, setIsPracticing] = useState(false);
+  const [practice, setPractice] = useState<HotkeyPracticeReport | null>(null);
 
   const handleKeyDown = useCallback(
     async (e: React.KeyboardEvent) => {
@@ -59,6 +63,7 @@ export function HotkeySetupStep({ onReady: _onReady }: HotkeySetupStepProps) {
       const newHotkey = parts.join('+');
       setIsRecording(false);
       setError(null);
+      setPractice(null);
 
       try {
         await updateHotkeyConfig({ primary: newHotkey });
@@ -72,6 +77,7 @@ export function HotkeySetupStep({ onReady: _onReady }: HotkeySetupStepProps) {
   const handleModeChange = useCallback(
     async (newMode: HotkeyMode) => {
       setError(null);
+      setPractice(null);
       try {
         await updateHotkeyConfig({ mode: newMode });
       } catch (err) {
@@ -81,6 +87,19 @@ export function HotkeySetupStep({ onReady: _onReady }: HotkeySetupStepProps) {
     [updateHotkeyConfig],
   );
 
+  const handlePractice = useCallback(async () => {
+    setIsPracticing(true);
+    setPractice(null);
+    setError(null);
+    try {
+      setPractice(await practiceHotkey());
+    } catch (err) {
+      setError(err instanceof Error ? err.message : 'Hotkey practice failed');
+    } finally {
+      setIsPracticing(false);
+    }
+  }, [practiceHotkey]);
+
   return (
     <div>
       <h2 className="text-2xl font-bold mb-4">Hotkey Configuration</h2>
@@ -160,6 +179,38 @@ export function HotkeySetupStep({ onReady: _onReady }: HotkeySetupStepProps) {
         </div>
       </div>
 
+      {/* Practice round */}
+      <div className="mb-6 text-left">
+        <button
+          type="button"
+          onClick={() => void handlePractice()}
+          disabled={isPracticing || isRecording || !primaryHotkey}
+          className="px-3 py-1.5 text-sm text-gray-700 dark:text-gray-300 border border-gray-300 dark:border-gray-600 rounded-md
+                     hover:bg-gray-100 dark:hover:bg-gray-700 disabled:opacity-50 disabled:cursor-not-allowed"
+        >
+          Try your hotkey
+        </button>
+        <div aria-live="polite">
+          {isPracticing && (
+            <p className="mt-1 text-xs text-blue-600 dark:text-blue-400">
+              {mode === 'hold'
+                ? `Hold ${primaryHotkey} for a second, then release it.`
+                : `Press ${primaryHotkey} once, then press it again.`}{' '}
+              Nothing will be recorded.
+            </p>
+          )}
+          {practice?.passed && (
+            <p className="mt-1 text-xs text-green-600 dark:text-green-400">
+              Your hotkey works
+              {practice.latency_ms !== undefined ? ` (responded in ${practice.latency_ms} ms)` : ''}.
+            </p>
+          )}
+          {practice?.hint && (
+            <p className="mt-1 text-xs text-yellow-700 dark:text-yellow-300">{practice.hint}</p>
+          )}
+        </div>
+      </div>
+
       {/* Error display */}
       {error && (
         <div role="alert" className="p-3 bg-red-50 dark:bg-red-900/20 border border-red-200 dark:border-red-800 rounded-md">
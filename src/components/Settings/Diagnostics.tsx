@@ -7,6 +7,7 @@
  * - Bounded size (truncates if too large)
  * - One-click copy to clipboard
  * - Shows recent logs (redacted)
+ * - Saves a state snapshot file for support sessions
  */
 
 import { useState, useMemo, useCallback } from 'react';
@@ -15,6 +16,8 @@ import type { DiagnosticsReport, Capabilities, AppConfig, SelfCheckResult, LogEn
 interface DiagnosticsProps {
   report: DiagnosticsReport | null;
   onRefresh: () => Promise<void>;
+  /** Save a state snapshot; resolves to the file's path. */
+  onExportSnapshot?: () => Promise<string>;
   isLoading?: boolean;
 }
 
@@ -72,6 +75,9 @@ function formatCapabilities(caps: Capabilities): string {
   lines.push(`Hotkey Release: ${caps.hotkey_release_available ? 'available' : 'unavailable'}`);
   lines.push(`Keystroke Injection: ${caps.keystroke_injection_available ? 'available' : 'unavailable'}`);
   lines.push(`Clipboard: ${caps.clipboard_available ? 'available' : 'unavailable'}`);
+  if (caps.primary_selection_available !== undefined) {
+    lines.push(`Primary Selection: ${caps.primary_selection_available ? 'available' : 'unavailable'}`);
+  }
 
   return lines.join('\n');
 }
@@ -143,6 +149,32 @@ function generateDiagnosticsText(report: DiagnosticsReport): string {
   sections.push(formatConfig(report.config));
   sections.push('');
 
+  // Effective values after clamping, which may differ from the config file.
+  if (report.watchdog) {
+    const { watchdog } = report;
+    sections.push('--- Sidecar Watchdog ---');
+    sections.push(`Ping interval: ${watchdog.ping_interval_ms} ms`);
+    sections.push(`Ping timeout: ${watchdog.ping_timeout_ms} ms`);
+    sections.push(`Hang threshold: ${watchdog.hang_threshold_ms} ms`);
+    sections.push(`Restart on hang: ${watchdog.auto_restart_on_hang ? 'yes' : 'no'}`);
+    sections.push('');
+  }
+
+  if (report.power) {
+    sections.push('--- Power ---');
+    sections.push(`Source: ${report.power.source}`);
+    sections.push(`Profile: ${report.power.profile}`);
+    sections.push('');
+  }
+
+  if (report.sidecar_output) {
+    const { sidecar_output } = report;
+    sections.push('--- Sidecar Output Encoding ---');
+    sections.push(`Invalid UTF-8 bytes replaced (RPC): ${sidecar_output.rpc_bytes_replaced}`);
+    sections.push(`Invalid UTF-8 bytes replaced (logs): ${sidecar_output.log_bytes_replaced}`);
+    sections.push('');
+  }
+
   // Raw diagnostics from capabilities
   if (report.capabilities.diagnostics) {
     sections.push('--- Platform Diagnostics ---');
@@ -155,6 +187,13 @@ function generateDiagnosticsText(report: DiagnosticsReport): string {
   sections.push(redactPaths(formatRecentLogs(report.recent_logs)));
   sections.push('');
 
+  // Persisted session journal survives restarts; useful for lost-dictation reports.
+  if (report.session_journal && report.session_journal.length > 0) {
+    sections.push('--- Session Journal ---');
+    sections.push(report.session_journal.join('\n'));
+    sections.push('');
+  }
+
   // Reference docs for platform-specific caveats.
   sections.push('--- References ---');
   sections.push('Known limitations: docs/KNOWN_LIMITATIONS.md');
@@ -173,9 +212,11 @@ function generateDiagnosticsText(report: DiagnosticsReport): string {
   return redactPaths(text);
 }
 
-export function Diagnostics({ report, onRefresh, isLoading }: DiagnosticsProps) {
+export function Diagnostics({ report, onRefresh, onExportSnapshot, isLoading }: DiagnosticsProps) {
   const [copied, setCopied] = useState(false);
   const [refreshing, setRefreshing] = useState(false);
+  const [snapshotPath, setSnapshotPath] = useState<string | null>(null);
+  const [snapshotError, setSnapshotError] = useState<string | null>(null);
 
   const diagnosticsText = useMemo(() => {
     if (!report) return '';
@@ -209,6 +250,17 @@ export function Diagnostics({ report, onRefresh, isLoading }: DiagnosticsProps)
     }
   }, [onRefresh]);
 
+  const handleExportSnapshot = useCallback(async () => {
+    if (!onExportSnapshot) return;
+    setSnapshotError(null);
+    try {
+      setSnapshotPath(await onExportSnapshot());
+    } catch (error) {
+      setSnapshotPath(null);
+      setSnapshotError(error instanceof Error ? error.message : String(error));
+    }
+  }, [onExportSnapshot]);
+
   // Loading state
   if (!report || isLoading) {
     return (
@@ -246,6 +298,17 @@ export function Diagnostics({ report, onRefresh, isLoading }: DiagnosticsProps)
           >
             {refreshing ? 'Refreshing...' : 'Refresh'}
           </button>
+          {onExportSnapshot && (
+            <button
+              type="button"
+              onClick={handleExportSnapshot}
+              aria-label="Save state snapshot file"
+              className="px-3 py-1.5 text-sm text-gray-600 dark:text-gray-400
+                         hover:bg-gray-100 dark:hover:bg-gray-700 rounded-md transition-colors"
+            >
+              Save Snapshot
+            </button>
+          )}
           <button
             type="button"
             onClick={handleCopy}
@@ -268,6 +331,17 @@ export function Diagnostics({ report, onRefresh, isLoading }: DiagnosticsProps)
         Copy and include this when reporting bugs.
       </p>
 
+      {snapshotPath && (
+        <p className="text-sm text-green-600 dark:text-green-400" role="status">
+          State snapshot saved to {redactPaths(snapshotPath)}. Attach it to your support request.
+        </p>
+      )}
+      {snapshotError && (
+        <p className="text-sm text-red-600 dark:text-red-400" role="alert">
+          Could not save the state snapshot: {snapshotError}
+        </p>
+      )}
+
       {/* Diagnostics output */}
       <div className="relative">
         <pre
@@ -4,20 +4,25 @@
  * Features:
  * - Primary hotkey input for recording
  * - Copy-last hotkey input
+ * - Cancel hotkey input (active only while recording)
  * - Hold/Toggle mode selector
  * - Shows effective mode with reason if different
+ * - Leak test that checks the recording hotkey does not reach the focused app
  */
 
 import { useEffect, useId, useState } from 'react';
-import type { HotkeyMode, EffectiveMode, ActivationMode } from '../../types';
+import { invoke } from '@tauri-apps/api/core';
+import type { HotkeyMode, EffectiveMode, ActivationMode, HotkeyLeakReport } from '../../types';
 
 interface HotkeyConfigProps {
   primaryHotkey: string;
   copyLastHotkey: string;
+  cancelHotkey?: string;
   mode: HotkeyMode;
   effectiveMode?: EffectiveMode<ActivationMode>;
   onPrimaryChange: (hotkey: string) => Promise<void>;
   onCopyLastChange: (hotkey: string) => Promise<void>;
+  onCancelChange?: (hotkey: string) => Promise<void>;
   onModeChange: (mode: HotkeyMode) => Promise<void>;
   isLoading?: boolean;
 }
@@ -30,6 +35,24 @@ interface HotkeyInputProps {
   disabled?: boolean;
 }
 
+type KeyCombo = Pick<KeyboardEvent, 'key' | 'ctrlKey' | 'altKey' | 'shiftKey' | 'metaKey'>;
+
+/** Hotkey string for a key event, or null for a modifier-only press. */
+function hotkeyFromEvent(e: KeyCombo): string | null {
+  // Don't accept modifier-only combinations
+  if (['Control', 'Alt', 'Shift', 'Meta'].includes(e.key)) {
+    return null;
+  }
+
+  const parts: string[] = [];
+  if (e.ctrlKey) parts.push('Ctrl');
+  if (e.altKey) parts.push('Alt');
+  if (e.shiftKey) parts.push('Shift');
+  if (e.metaKey) parts.push('Meta');
+  parts.push(e.key.length === 1 ? e.key.toUpperCase() : e.key);
+  return parts.join('+');
+}
+
 function HotkeyInput({ label, description, value, onChange, disabled }: HotkeyInputProps) {
   const [isRecording, setIsRecording] = useState(false);
   const [error, setError] = useState<string | null>(null);
@@ -59,21 +82,11 @@ function HotkeyInput({ label, description, value, onChange, disabled }: HotkeyIn
     }
 
     e.preventDefault();
-    const parts: string[] = [];
-
-    if (e.ctrlKey) parts.push('Ctrl');
-    if (e.altKey) parts.push('Alt');
-    if (e.shiftKey) parts.push('Shift');
-    if (e.metaKey) parts.push('Meta');
-
-    // Don't accept modifier-only combinations
-    if (!['Control', 'Alt', 'Shift', 'Meta'].includes(e.key)) {
-      parts.push(e.key.length === 1 ? e.key.toUpperCase() : e.key);
-    } else {
+    const newHotkey = hotkeyFromEvent(e);
+    if (!newHotkey) {
       return; // Wait for a non-modifier key
     }
 
-    const newHotkey = parts.join('+');
     setPendingValue(newHotkey);
     setIsRecording(false);
     setError(null);
@@ -159,13 +172,91 @@ function HotkeyInput({ label, description, value, onChange, disabled }: HotkeyIn
   );
 }
 
+interface HotkeyLeakTestProps {
+  hotkey: string;
+  disabled?: boolean;
+}
+
+/**
+ * Asks the user to press the recording hotkey while this window is focused.
+ * A keydown reaching the page means the hotkey leaks into the focused app.
+ */
+function HotkeyLeakTest({ hotkey, disabled }: HotkeyLeakTestProps) {
+  const [isTesting, setIsTesting] = useState(false);
+  const [report, setReport] = useState<HotkeyLeakReport | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!isTesting) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      const pressed = hotkeyFromEvent(e);
+      if (pressed && pressed.toLowerCase() === hotkey.toLowerCase()) {
+        e.preventDefault();
+        void invoke('report_hotkey_keydown');
+      }
+    };
+    window.addEventListener('keydown', onKeyDown, true);
+    return () => window.removeEventListener('keydown', onKeyDown, true);
+  }, [isTesting, hotkey]);
+
+  const runTest = async () => {
+    setIsTesting(true);
+    setReport(null);
+    setError(null);
+    try {
+      setReport(await invoke<HotkeyLeakReport>('test_hotkey_leakage'));
+    } catch (e) {
+      setError(e instanceof Error ? e.message : 'Hotkey test failed');
+    } finally {
+      setIsTesting(false);
+    }
+  };
+
+  return (
+    <div>
+      <button
+        type="button"
+        onClick={() => void runTest()}
+        disabled={disabled || isTesting || !hotkey}
+        className="px-3 py-1.5 text-sm text-gray-700 dark:text-gray-300 border border-gray-300 dark:border-gray-600 rounded-md
+                   hover:bg-gray-100 dark:hover:bg-gray-700 disabled:opacity-50 disabled:cursor-not-allowed"
+      >
+        Test for key leaks
+      </button>
+      <div aria-live="polite">
+        {isTesting && (
+          <p className="mt-1 text-xs text-blue-600 dark:text-blue-400">
+            Press {hotkey} now. It will not start a recording.
+          </p>
+        )}
+        {report && !report.leaked && report.hotkey_detected && (
+          <p className="mt-1 text-xs text-green-600 dark:text-green-400">
+            No leak detected. The hotkey does not reach other apps.
+          </p>
+        )}
+        {report && (report.leaked || !report.hotkey_detected) && (
+          <p className="mt-1 text-xs text-yellow-700 dark:text-yellow-300">
+            {report.leaked ? 'The hotkey leaked into the focused app. ' : ''}
+            {report.hint}
+          </p>
+        )}
+      </div>
+      {error && (
+        <p role="alert" className="mt-1 text-xs text-red-600 dark:text-red-400">{error}</p>
+      )}
+    </div>
+  );
+}
+
 export function HotkeyConfig({
   primaryHotkey,
   copyLastHotkey,
+  cancelHotkey = 'Escape',
   mode,
   effectiveMode,
   onPrimaryChange,
   onCopyLastChange,
+  onCancelChange,
   onModeChange,
   isLoading,
 }: HotkeyConfigProps) {
@@ -196,6 +287,7 @@ export function HotkeyConfig({
         onChange={onPrimaryChange}
         disabled={isLoading}
       />
+      <HotkeyLeakTest hotkey={primaryHotkey} disabled={isLoading} />
 
       {/* Copy last hotkey */}
       <HotkeyInput
@@ -206,6 +298,17 @@ export function HotkeyConfig({
         disabled={isLoading}
       />
 
+      {/* Cancel hotkey */}
+      {onCancelChange && (
+        <HotkeyInput
+          label="Cancel Hotkey"
+          description="Discard the current recording without transcribing (only active while recording)"
+          value={cancelHotkey}
+          onChange={onCancelChange}
+          disabled={isLoading}
+        />
+      )}
+
       {/* Mode selector */}
       <div>
         <p id="hotkey-mode-label" className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2">
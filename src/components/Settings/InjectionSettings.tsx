@@ -6,14 +6,18 @@
  * - Restore clipboard toggle
  * - Suffix selector (none, space, newline)
  * - Focus Guard toggle with explanation
+ * - PRIMARY selection mode (Linux, where available)
+ * - Confirmation guards for long transcripts and recordings
  */
 
 import { useState } from 'react';
-import type { InjectionConfig } from '../../types';
+import type { InjectionConfig, PrimarySelectionMode } from '../../types';
 
 interface InjectionSettingsProps {
   config: InjectionConfig;
   onChange: (key: keyof InjectionConfig, value: any) => Promise<void>;
+  /** Whether the display server has a usable PRIMARY selection. */
+  primarySelectionAvailable?: boolean;
   isLoading?: boolean;
 }
 
@@ -24,6 +28,22 @@ const SUFFIX_OPTIONS = [
   { value: '\n', label: 'Newline', description: 'Add a newline after text' },
 ];
 
+/** Where transcripts go relative to the PRIMARY selection. */
+const PRIMARY_SELECTION_OPTIONS: { value: PrimarySelectionMode; label: string }[] = [
+  { value: 'off', label: 'Clipboard only' },
+  { value: 'also', label: 'Clipboard and primary selection' },
+  { value: 'instead', label: 'Primary selection instead of clipboard' },
+];
+
+/** Character limits above which a transcript waits for confirmation. */
+const CONFIRM_CHARS_OPTIONS = [0, 1000, 2000, 5000, 10000];
+
+/** Recording lengths (minutes) above which a transcript waits for confirmation. */
+const CONFIRM_MINUTES_OPTIONS = [0, 2, 5, 10, 30];
+
+/** How long (seconds) a transcript waits for a busy target app. */
+const BUSY_TARGET_WAIT_OPTIONS = [0, 5, 10, 30, 60];
+
 /** Tooltip component for explanations. */
 function Tooltip({ text }: { text: string }) {
   return (
@@ -36,8 +56,14 @@ function Tooltip({ text }: { text: string }) {
   );
 }
 
-export function InjectionSettings({ config, onChange, isLoading }: InjectionSettingsProps) {
+export function InjectionSettings({
+  config,
+  onChange,
+  primarySelectionAvailable = false,
+  isLoading,
+}: InjectionSettingsProps) {
   const [errors, setErrors] = useState<Record<string, string>>({});
+  const primarySelection = config.primary_selection ?? 'off';
 
   const handleChange = async (key: keyof InjectionConfig, value: any) => {
     setErrors(prev => ({ ...prev, [key]: '' }));
@@ -149,6 +175,132 @@ export function InjectionSettings({ config, onChange, isLoading }: InjectionSett
         )}
       </div>
 
+      {/* PRIMARY selection (shown while set, so it can be turned off) */}
+      {(primarySelectionAvailable || primarySelection !== 'off') && (
+        <div>
+          <label htmlFor="primary-selection" className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1">
+            Middle-Click Paste
+            <Tooltip text="Also put transcripts on the primary selection, so a middle click pastes them. With 'instead', your clipboard is left as it was." />
+          </label>
+          <select
+            id="primary-selection"
+            value={primarySelection}
+            onChange={(e) => handleChange('primary_selection', e.target.value)}
+            disabled={isLoading}
+            className="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-md
+                       bg-white dark:bg-gray-700 text-gray-900 dark:text-gray-100
+                       disabled:opacity-50 disabled:cursor-not-allowed"
+          >
+            {PRIMARY_SELECTION_OPTIONS.map((option) => (
+              <option key={option.value} value={option.value}>
+                {option.label}
+              </option>
+            ))}
+          </select>
+          {!primarySelectionAvailable && (
+            <p className="mt-1 text-xs text-amber-600 dark:text-amber-400">
+              No primary selection is available on this display server; transcripts use the clipboard.
+            </p>
+          )}
+          {errors.primary_selection && (
+            <p role="alert" className="mt-1 text-xs text-red-600 dark:text-red-400">{errors.primary_selection}</p>
+          )}
+        </div>
+      )}
+
+      {/* Smart spacing toggle */}
+      <div className="flex items-center justify-between">
+        <div>
+          <label id="smart-spacing-label" htmlFor="smart-spacing" className="font-medium text-gray-900 dark:text-gray-100">
+            Smart Spacing
+          </label>
+          <Tooltip text="Reads the text before the cursor (macOS and Windows) to add a leading space or capitalize a new sentence. Uses the suffix alone when the field can't be read." />
+          <p id="smart-spacing-description" className="text-sm text-gray-500 dark:text-gray-400">
+            Match spacing and capitalization to existing text
+          </p>
+        </div>
+        <button
+          type="button"
+          id="smart-spacing"
+          role="switch"
+          aria-checked={config.smart_spacing ?? false}
+          aria-labelledby="smart-spacing-label"
+          aria-describedby="smart-spacing-description"
+          onClick={() => handleChange('smart_spacing', !config.smart_spacing)}
+          disabled={isLoading}
+          className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors
+                     ${config.smart_spacing ? 'bg-blue-500' : 'bg-gray-300 dark:bg-gray-600'}
+                     disabled:opacity-50 disabled:cursor-not-allowed`}
+        >
+          <span
+            className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform
+                       ${config.smart_spacing ? 'translate-x-6' : 'translate-x-1'}`}
+          />
+        </button>
+      </div>
+
+      {/* Paste verification toggle */}
+      <div className="flex items-center justify-between">
+        <div>
+          <label id="verify-paste-label" htmlFor="verify-paste" className="font-medium text-gray-900 dark:text-gray-100">
+            Verify Paste
+          </label>
+          <Tooltip text="Reads the field back after pasting (macOS and Windows) and types the text with keystrokes if the paste didn't arrive. The result is shown in history." />
+          <p id="verify-paste-description" className="text-sm text-gray-500 dark:text-gray-400">
+            Retry with typing when a paste is lost
+          </p>
+        </div>
+        <button
+          type="button"
+          id="verify-paste"
+          role="switch"
+          aria-checked={config.verify_paste ?? false}
+          aria-labelledby="verify-paste-label"
+          aria-describedby="verify-paste-description"
+          onClick={() => handleChange('verify_paste', !config.verify_paste)}
+          disabled={isLoading}
+          className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors
+                     ${config.verify_paste ? 'bg-blue-500' : 'bg-gray-300 dark:bg-gray-600'}
+                     disabled:opacity-50 disabled:cursor-not-allowed`}
+        >
+          <span
+            className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform
+                       ${config.verify_paste ? 'translate-x-6' : 'translate-x-1'}`}
+          />
+        </button>
+      </div>
+
+      {/* Paste delay calibration toggle */}
+      <div className="flex items-center justify-between">
+        <div>
+          <label id="calibrate-paste-delay-label" htmlFor="calibrate-paste-delay" className="font-medium text-gray-900 dark:text-gray-100">
+            Calibrate Paste Delay
+          </label>
+          <Tooltip text="Times each paste and learns how long every app needs, so fast apps paste sooner and slow ones stay reliable. App overrides still win." />
+          <p id="calibrate-paste-delay-description" className="text-sm text-gray-500 dark:text-gray-400">
+            Learn a paste delay per app
+          </p>
+        </div>
+        <button
+          type="button"
+          id="calibrate-paste-delay"
+          role="switch"
+          aria-checked={config.calibrate_paste_delay ?? false}
+          aria-labelledby="calibrate-paste-delay-label"
+          aria-describedby="calibrate-paste-delay-description"
+          onClick={() => handleChange('calibrate_paste_delay', !config.calibrate_paste_delay)}
+          disabled={isLoading}
+          className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors
+                     ${config.calibrate_paste_delay ? 'bg-blue-500' : 'bg-gray-300 dark:bg-gray-600'}
+                     disabled:opacity-50 disabled:cursor-not-allowed`}
+        >
+          <span
+            className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform
+                       ${config.calibrate_paste_delay ? 'translate-x-6' : 'translate-x-1'}`}
+          />
+        </button>
+      </div>
+
       {/* Focus Guard toggle */}
       <div className="flex items-center justify-between">
         <div>
@@ -195,6 +347,75 @@ export function InjectionSettings({ config, onChange, isLoading }: InjectionSett
           </p>
         </div>
       )}
+
+      {/* Confirmation guards */}
+      <div className="grid grid-cols-2 gap-4">
+        <div>
+          <label htmlFor="confirm-above-chars" className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1">
+            Confirm Long Text
+            <Tooltip text="Transcripts longer than this are copied to the clipboard and wait for you to confirm before they are pasted." />
+          </label>
+          <select
+            id="confirm-above-chars"
+            value={config.confirm_above_chars ?? 0}
+            onChange={(e) => handleChange('confirm_above_chars', parseInt(e.target.value))}
+            disabled={isLoading}
+            className="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-md
+                       bg-white dark:bg-gray-700 text-gray-900 dark:text-gray-100
+                       disabled:opacity-50 disabled:cursor-not-allowed"
+          >
+            {CONFIRM_CHARS_OPTIONS.map((chars) => (
+              <option key={chars} value={chars}>
+                {chars === 0 ? 'Never' : `Over ${chars.toLocaleString()} characters`}
+              </option>
+            ))}
+          </select>
+        </div>
+        <div>
+          <label htmlFor="confirm-above-minutes" className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1">
+            Confirm Long Recordings
+            <Tooltip text="Transcripts of recordings longer than this wait for you to confirm, so a forgotten open mic doesn't paste a wall of text." />
+          </label>
+          <select
+            id="confirm-above-minutes"
+            value={config.confirm_above_minutes ?? 0}
+            onChange={(e) => handleChange('confirm_above_minutes', parseInt(e.target.value))}
+            disabled={isLoading}
+            className="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-md
+                       bg-white dark:bg-gray-700 text-gray-900 dark:text-gray-100
+                       disabled:opacity-50 disabled:cursor-not-allowed"
+          >
+            {CONFIRM_MINUTES_OPTIONS.map((minutes) => (
+              <option key={minutes} value={minutes}>
+                {minutes === 0 ? 'Never' : `Over ${minutes} minutes`}
+              </option>
+            ))}
+          </select>
+        </div>
+      </div>
+
+      {/* Busy target deferral */}
+      <div>
+        <label htmlFor="busy-target-wait" className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1">
+          Wait for Busy Apps
+          <Tooltip text="When the app you dictate into is not responding, the transcript waits in the pending inserts and is typed once the app recovers, instead of going straight to the clipboard. Detected on Windows and macOS." />
+        </label>
+        <select
+          id="busy-target-wait"
+          value={config.busy_target_wait_secs ?? 10}
+          onChange={(e) => handleChange('busy_target_wait_secs', parseInt(e.target.value))}
+          disabled={isLoading}
+          className="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-md
+                     bg-white dark:bg-gray-700 text-gray-900 dark:text-gray-100
+                     disabled:opacity-50 disabled:cursor-not-allowed"
+        >
+          {BUSY_TARGET_WAIT_OPTIONS.map((seconds) => (
+            <option key={seconds} value={seconds}>
+              {seconds === 0 ? "Don't wait" : `Up to ${seconds} seconds`}
+            </option>
+          ))}
+        </select>
+      </div>
     </div>
   );
 }
@@ -7,9 +7,11 @@
  * - No-signal detection with warning
  * - Smooth animations with decay
  * - Start/stop test controls
+ * - Live VAD speech/silence preview for the configured timings
  */
 
 import { useEffect, useState, useRef, useCallback } from 'react';
+import type { VadPreviewEvent, VadPreviewState } from '../../types';
 
 interface AudioLevel {
   rms: number;
@@ -21,6 +23,7 @@ interface MicrophoneTestProps {
   onStartTest: () => Promise<void>;
   onStopTest: () => Promise<void>;
   audioLevel: AudioLevel | null;
+  vadPreview?: VadPreviewEvent | null;
   isRunning?: boolean;
 }
 
@@ -34,6 +37,14 @@ const LEVEL_THRESHOLDS = {
   yellow: 0.8,
 };
 
+/** Label and color for each VAD preview state. */
+const VAD_PREVIEW_DISPLAY: Record<VadPreviewState, { label: string; color: string }> = {
+  waiting_for_speech: { label: 'Waiting for speech', color: 'bg-gray-400' },
+  speech: { label: 'Speech', color: 'bg-green-500' },
+  silence: { label: 'Silence', color: 'bg-yellow-500' },
+  auto_stop: { label: 'Would stop here', color: 'bg-red-500' },
+};
+
 /** Get color class based on level. */
 function getLevelColor(level: number): string {
   if (level > LEVEL_THRESHOLDS.yellow) return 'bg-red-500';
@@ -46,6 +57,7 @@ export function MicrophoneTest({
   onStartTest,
   onStopTest,
   audioLevel,
+  vadPreview = null,
   isRunning = false,
 }: MicrophoneTestProps) {
   const [displayLevel, setDisplayLevel] = useState({ rms: 0, peak: 0 });
@@ -231,6 +243,21 @@ export function MicrophoneTest({
         </div>
       </div>
 
+      {/* VAD preview */}
+      {isRunning && vadPreview && (
+        <div data-testid="vad-preview" className="flex items-center justify-between text-sm">
+          <span className="flex items-center gap-2 text-gray-700 dark:text-gray-300" aria-live="polite">
+            <span className={`inline-block h-2.5 w-2.5 rounded-full ${VAD_PREVIEW_DISPLAY[vadPreview.state].color}`} />
+            {VAD_PREVIEW_DISPLAY[vadPreview.state].label}
+          </span>
+          {vadPreview.state === 'silence' && (
+            <span className="text-xs font-mono text-gray-500 dark:text-gray-400">
+              {(vadPreview.silence_ms / 1000).toFixed(1)}s / {(vadPreview.silence_threshold_ms / 1000).toFixed(1)}s
+            </span>
+          )}
+        </div>
+      )}
+
       {/* No signal warning */}
       {isRunning && noSignal && (
         <div role="alert" className="p-3 bg-yellow-50 dark:bg-yellow-900/20 border border-yellow-200 dark:border-yellow-800 rounded-md">
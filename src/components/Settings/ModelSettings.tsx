@@ -49,6 +49,23 @@ function parseCatalogEntries(payload: unknown): ModelCatalogEntry[] {
   });
 }
 
+/** Summarize catalog-recommended parameters, e.g. "~2 GB RAM • beam 1 • 25 languages". */
+function describeRecommendedParams(entry: ModelCatalogEntry): string | null {
+  const params = entry.recommended_params;
+  const parts: string[] = [];
+  if (typeof params?.expected_ram_mb === 'number') {
+    parts.push(`~${formatBytes(params.expected_ram_mb * 1024 * 1024)} RAM`);
+  }
+  if (typeof params?.beam_size === 'number') {
+    parts.push(params.beam_size === 1 ? 'greedy decoding' : `beam ${params.beam_size}`);
+  }
+  if (typeof params?.vad_silence_ms === 'number') {
+    parts.push(`VAD stop after ${(params.vad_silence_ms / 1000).toFixed(1)}s`);
+  }
+  parts.push(`${entry.supported_languages.length} language${entry.supported_languages.length === 1 ? '' : 's'}`);
+  return parts.length > 1 ? parts.join(' • ') : null;
+}
+
 /** Format bytes to human-readable size. */
 function formatBytes(bytes: number): string {
   if (bytes === 0) return '0 B';
@@ -209,6 +226,7 @@ export function ModelSettings({
   const [isCatalogLoading, setIsCatalogLoading] = useState(false);
   const [activeModelId, setActiveModelId] = useState<string | null>(status?.model_id ?? null);
   const [selectedLanguage, setSelectedLanguage] = useState<string>('auto');
+  const [preloadOnStart, setPreloadOnStart] = useState(false);
 
   useEffect(() => {
     if (typeof status?.model_id === 'string' && status.model_id.length > 0) {
@@ -257,6 +275,7 @@ export function ModelSettings({
         } else {
           setSelectedLanguage('auto');
         }
+        setPreloadOnStart(modelConfig?.preload_on_start === true);
       } catch {
         // Best-effort load; keep defaults.
       }
@@ -323,6 +342,7 @@ export function ModelSettings({
     const nextConfig: AppConfig = {
       ...config,
       model: {
+        ...config.model,
         model_id: modelId,
         device: config.model?.device ?? null,
         preferred_device: config.model?.preferred_device ?? 'auto',
@@ -358,6 +378,7 @@ export function ModelSettings({
       const nextConfig: AppConfig = {
         ...config,
         model: {
+          ...config.model,
           model_id: activeModelId ?? config.model?.model_id ?? null,
           device: config.model?.device ?? null,
           preferred_device: config.model?.preferred_device ?? 'auto',
@@ -372,6 +393,32 @@ export function ModelSettings({
     }
   };
 
+  const handlePreloadChange = async (enabled: boolean) => {
+    setError(null);
+    setPreloadOnStart(enabled);
+    setActionInProgress('select');
+    try {
+      const config = await invoke<AppConfig>('get_config');
+      const nextConfig: AppConfig = {
+        ...config,
+        model: {
+          ...config.model,
+          model_id: config.model?.model_id ?? null,
+          device: config.model?.device ?? null,
+          preferred_device: config.model?.preferred_device ?? 'auto',
+          language: config.model?.language ?? null,
+          preload_on_start: enabled,
+        },
+      };
+      await invoke('update_config', { config: nextConfig });
+    } catch (e) {
+      setPreloadOnStart(!enabled);
+      setError(e instanceof Error ? e.message : 'Failed to update model preload');
+    } finally {
+      setActionInProgress(null);
+    }
+  };
+
   // Loading state
   if (!status) {
     return (
@@ -439,6 +486,7 @@ export function ModelSettings({
               const cardStatus =
                 status.model_id === entry.model_id ? status.status : ('missing' as ModelState);
               const cardStatusConfig = getStatusConfig(cardStatus);
+              const recommended = describeRecommendedParams(entry);
 
               return (
                 <div
@@ -454,6 +502,11 @@ export function ModelSettings({
                       <p className="text-sm font-medium text-gray-900 dark:text-gray-100">{entry.display_name}</p>
                       <p className="text-xs text-gray-500 dark:text-gray-400 font-mono break-all">{entry.model_id}</p>
                       <p className="text-xs text-gray-600 dark:text-gray-300 mt-1">{entry.family} • {formatBytes(entry.size_bytes)}</p>
+                      {recommended && (
+                        <p className="text-xs text-gray-500 dark:text-gray-400" data-testid="model-recommended-params">
+                          Recommended: {recommended}
+                        </p>
+                      )}
                     </div>
                     <div className="flex items-center gap-2">
                       <span className={`text-xs ${cardStatusConfig.color}`}>{cardStatusConfig.label}</span>
@@ -506,6 +559,25 @@ export function ModelSettings({
             </select>
           </div>
         )}
+
+        <label className="flex items-center justify-between gap-3">
+          <span>
+            <span className="block text-xs font-medium text-gray-700 dark:text-gray-300">
+              Load model at startup
+            </span>
+            <span className="block text-xs text-gray-500 dark:text-gray-400">
+              Keeps the first dictation fast at the cost of memory while idle.
+            </span>
+          </span>
+          <input
+            type="checkbox"
+            aria-label="Load model at startup"
+            checked={preloadOnStart}
+            onChange={(event) => void handlePreloadChange(event.target.checked)}
+            disabled={isLoading || actionInProgress !== null}
+            className="h-4 w-4 accent-blue-500"
+          />
+        </label>
       </div>
 
       {/* Download progress */}
@@ -13,8 +13,12 @@ import type {
   AppConfig,
   AudioDevice,
   AudioLevelEvent,
+  CueStatusEvent,
   EffectiveMode,
   ActivationMode,
+  ScreenSharePolicy,
+  VadPreset,
+  VadPreviewEvent,
 } from '../../types';
 import { MicrophoneSelect } from './MicrophoneSelect';
 import { HotkeyConfig } from './HotkeyConfig';
@@ -27,11 +31,15 @@ interface SettingsPanelProps {
   config: AppConfig;
   devices: AudioDevice[];
   audioLevel?: AudioLevelEvent | null;
+  vadPreview?: VadPreviewEvent | null;
   isMeterRunning?: boolean;
+  cueStatus?: CueStatusEvent | null;
   effectiveHotkeyMode?: EffectiveMode<ActivationMode>;
+  primarySelectionAvailable?: boolean;
   onStartMicTest?: () => Promise<void>;
   onStopMicTest?: () => Promise<void>;
   onRefreshDevices?: () => Promise<void> | void;
+  onInputGainChange?: (deviceUid: string | null, gainDb: number) => Promise<void>;
   onConfigChange: (path: string[], value: any) => Promise<void>;
   onPurgeHistory?: () => Promise<void>;
   historyCount?: number;
@@ -84,11 +92,15 @@ export function SettingsPanel({
   config,
   devices,
   audioLevel,
+  vadPreview,
   isMeterRunning = false,
+  cueStatus,
   effectiveHotkeyMode,
+  primarySelectionAvailable,
   onStartMicTest,
   onStopMicTest,
   onRefreshDevices,
+  onInputGainChange,
   onConfigChange,
   onPurgeHistory,
   historyCount = 0,
@@ -103,6 +115,45 @@ export function SettingsPanel({
     { id: 'appearance', label: 'Appearance' },
   ];
   const themeOptions = ['system', 'light', 'dark'] as const;
+  const vadPresetOptions: Array<{ value: VadPreset; label: string }> = [
+    { value: 'aggressive', label: 'Aggressive' },
+    { value: 'balanced', label: 'Balanced' },
+    { value: 'relaxed', label: 'Relaxed' },
+    { value: 'custom', label: 'Custom' },
+  ];
+  const vadPreset = config.audio.vad_preset ?? 'balanced';
+  const gainDeviceUid =
+    config.audio.device_uid ?? devices.find((device) => device.is_default)?.uid ?? null;
+  const inputGainDb = (gainDeviceUid && config.audio.input_gains?.[gainDeviceUid]) || 0;
+  const countdownOptions: Array<{ value: number; label: string }> = [
+    { value: 0, label: 'Off' },
+    { value: 1, label: '1 second' },
+    { value: 2, label: '2 seconds' },
+    { value: 3, label: '3 seconds' },
+    { value: 5, label: '5 seconds' },
+  ];
+  const liveFlushOptions: Array<{ value: number; label: string }> = [
+    { value: 0, label: 'Off' },
+    { value: 5, label: 'Every 5 seconds' },
+    { value: 10, label: 'Every 10 seconds' },
+    { value: 15, label: 'Every 15 seconds' },
+    { value: 30, label: 'Every 30 seconds' },
+  ];
+  const screenSharePolicyOptions: Array<{ value: ScreenSharePolicy; label: string }> = [
+    { value: 'off', label: 'Do nothing' },
+    { value: 'warn', label: 'Warn me' },
+    { value: 'show_overlay', label: 'Always show the overlay' },
+    { value: 'disable_dictation', label: 'Disable dictation' },
+  ];
+  const screenSharePolicy = config.ui.screen_share_policy ?? 'warn';
+  const overlayTheme = config.ui.overlay_theme ?? 'system';
+  const overlayScale = config.ui.overlay_scale ?? 1;
+  const overlayOpacity = config.ui.overlay_opacity ?? 1;
+  const overlayShowWaveform = config.ui.overlay_show_waveform ?? true;
+  const offlineMode = config.network?.offline_mode ?? false;
+  const companionEnabled = config.companion?.enabled ?? false;
+  const crashUploadEnabled = config.crash_reporting?.upload_enabled ?? false;
+  const lowPowerOnBattery = config.power?.low_power_on_battery ?? false;
   const [showPurgeConfirm, setShowPurgeConfirm] = useState(false);
   const [isPurging, setIsPurging] = useState(false);
   const [purgeError, setPurgeError] = useState<string | null>(null);
@@ -280,9 +331,198 @@ export function SettingsPanel({
               onStartTest={handleStartMicTest}
               onStopTest={handleStopMicTest}
               audioLevel={audioLevel ?? null}
+              vadPreview={vadPreview ?? null}
               isRunning={isMeterRunning}
             />
 
+            {/* Input gain of the selected microphone */}
+            {onInputGainChange && (
+              <div>
+                <label htmlFor="input-gain-db" className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1">
+                  Input gain: {inputGainDb > 0 ? '+' : ''}{inputGainDb} dB
+                </label>
+                <input
+                  id="input-gain-db"
+                  type="range"
+                  min={-20}
+                  max={30}
+                  step={1}
+                  value={inputGainDb}
+                  onChange={(e) => {
+                    void onInputGainChange(gainDeviceUid, Number(e.target.value));
+                  }}
+                  disabled={isLoading}
+                  className="w-full accent-blue-500"
+                />
+                <div className="flex justify-between text-xs text-gray-400">
+                  <span>-20 dB</span>
+                  <span>+30 dB</span>
+                </div>
+                <p className="text-xs text-gray-500 dark:text-gray-400 mt-1">
+                  Boost a quiet microphone or tame a loud one. Saved per microphone; run the microphone test to check the level.
+                </p>
+              </div>
+            )}
+
+            {/* Audio cues during meetings */}
+            <div className="space-y-4">
+              <h3 className="text-sm font-semibold uppercase tracking-wide text-gray-400">
+                Meetings
+              </h3>
+
+              <div className="flex items-center justify-between">
+                <div>
+                  <label id="in-meeting-label" htmlFor="in-meeting" className="font-medium text-gray-900 dark:text-gray-100">
+                    In a Meeting
+                  </label>
+                  <p id="in-meeting-description" className="text-sm text-gray-500 dark:text-gray-400">
+                    Mute audio cues until you turn this off
+                  </p>
+                </div>
+                <button
+                  type="button"
+                  id="in-meeting"
+                  role="switch"
+                  aria-checked={config.audio.in_meeting ?? false}
+                  aria-labelledby="in-meeting-label"
+                  aria-describedby="in-meeting-description"
+                  onClick={() => handleAudioChange('in_meeting', !config.audio.in_meeting)}
+                  disabled={isLoading}
+                  className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors
+                             ${config.audio.in_meeting ?? false ? 'bg-blue-500' : 'bg-gray-300 dark:bg-gray-600'}
+                             disabled:opacity-50 disabled:cursor-not-allowed`}
+                >
+                  <span
+                    className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform
+                               ${config.audio.in_meeting ?? false ? 'translate-x-6' : 'translate-x-1'}`}
+                  />
+                </button>
+              </div>
+
+              <div className="flex items-center justify-between">
+                <div>
+                  <label id="mute-cues-in-calls-label" htmlFor="mute-cues-in-calls" className="font-medium text-gray-900 dark:text-gray-100">
+                    Mute Cues During Calls
+                  </label>
+                  <p id="mute-cues-in-calls-description" className="text-sm text-gray-500 dark:text-gray-400">
+                    Mute audio cues while Zoom, Teams, Slack, Discord, Skype or Webex uses the microphone
+                  </p>
+                </div>
+                <button
+                  type="button"
+                  id="mute-cues-in-calls"
+                  role="switch"
+                  aria-checked={config.audio.mute_cues_in_calls ?? true}
+                  aria-labelledby="mute-cues-in-calls-label"
+                  aria-describedby="mute-cues-in-calls-description"
+                  onClick={() => handleAudioChange('mute_cues_in_calls', !(config.audio.mute_cues_in_calls ?? true))}
+                  disabled={isLoading}
+                  className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors
+                             ${config.audio.mute_cues_in_calls ?? true ? 'bg-blue-500' : 'bg-gray-300 dark:bg-gray-600'}
+                             disabled:opacity-50 disabled:cursor-not-allowed`}
+                >
+                  <span
+                    className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform
+                               ${config.audio.mute_cues_in_calls ?? true ? 'translate-x-6' : 'translate-x-1'}`}
+                  />
+                </button>
+              </div>
+
+              <div className="flex items-center justify-between">
+                <div>
+                  <label id="mute-cues-in-dnd-label" htmlFor="mute-cues-in-dnd" className="font-medium text-gray-900 dark:text-gray-100">
+                    Mute Cues in Do Not Disturb
+                  </label>
+                  <p id="mute-cues-in-dnd-description" className="text-sm text-gray-500 dark:text-gray-400">
+                    Mute audio cues while the system is in Do Not Disturb or Focus mode
+                  </p>
+                </div>
+                <button
+                  type="button"
+                  id="mute-cues-in-dnd"
+                  role="switch"
+                  aria-checked={config.audio.mute_cues_in_dnd ?? true}
+                  aria-labelledby="mute-cues-in-dnd-label"
+                  aria-describedby="mute-cues-in-dnd-description"
+                  onClick={() => handleAudioChange('mute_cues_in_dnd', !(config.audio.mute_cues_in_dnd ?? true))}
+                  disabled={isLoading}
+                  className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors
+                             ${config.audio.mute_cues_in_dnd ?? true ? 'bg-blue-500' : 'bg-gray-300 dark:bg-gray-600'}
+                             disabled:opacity-50 disabled:cursor-not-allowed`}
+                >
+                  <span
+                    className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform
+                               ${config.audio.mute_cues_in_dnd ?? true ? 'translate-x-6' : 'translate-x-1'}`}
+                  />
+                </button>
+              </div>
+
+              {cueStatus?.suppressed && (
+                <p data-testid="cue-status" className="text-xs text-amber-600 dark:text-amber-400">
+                  {cueStatus.reason === 'call'
+                    ? `Audio cues muted: ${cueStatus.app ?? 'a call'} is using the microphone`
+                    : cueStatus.reason === 'do_not_disturb'
+                      ? 'Audio cues muted while Do Not Disturb is on'
+                      : 'Audio cues muted while you are in a meeting'}
+                </p>
+              )}
+            </div>
+
+            {/* Countdown between the hotkey press and capture */}
+            <div>
+              <label htmlFor="countdown-secs" className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1">
+                Countdown before recording
+              </label>
+              <select
+                id="countdown-secs"
+                value={config.audio.countdown_secs ?? 0}
+                onChange={(e) => handleAudioChange('countdown_secs', Number(e.target.value))}
+                disabled={isLoading}
+                className="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-md
+                           bg-white dark:bg-gray-700 text-gray-900 dark:text-gray-100
+                           disabled:opacity-50 disabled:cursor-not-allowed"
+              >
+                {countdownOptions.map((option) => (
+                  <option key={option.value} value={option.value}>
+                    {option.label}
+                  </option>
+                ))}
+              </select>
+              <p className="text-xs text-gray-500 dark:text-gray-400 mt-1">
+                Count down on the overlay before the microphone opens; press the hotkey again to cancel
+              </p>
+            </div>
+
+            {/* Split long recordings instead of stopping at the limit */}
+            <div className="flex items-center justify-between">
+              <div>
+                <label id="auto-split-label" htmlFor="auto-split" className="font-medium text-gray-900 dark:text-gray-100">
+                  Split Long Recordings
+                </label>
+                <p id="auto-split-description" className="text-sm text-gray-500 dark:text-gray-400">
+                  At the maximum recording length, type what you said so far and keep recording
+                </p>
+              </div>
+              <button
+                type="button"
+                id="auto-split"
+                role="switch"
+                aria-checked={config.audio.auto_split_recordings ?? false}
+                aria-labelledby="auto-split-label"
+                aria-describedby="auto-split-description"
+                onClick={() => handleAudioChange('auto_split_recordings', !config.audio.auto_split_recordings)}
+                disabled={isLoading}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors
+                           ${config.audio.auto_split_recordings ? 'bg-blue-500' : 'bg-gray-300 dark:bg-gray-600'}
+                           disabled:opacity-50 disabled:cursor-not-allowed`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform
+                             ${config.audio.auto_split_recordings ? 'translate-x-6' : 'translate-x-1'}`}
+                />
+              </button>
+            </div>
+
             {/* VAD Auto-Stop settings */}
             <div className="space-y-4">
               <h3 className="text-sm font-semibold uppercase tracking-wide text-gray-400">
@@ -319,9 +559,91 @@ export function SettingsPanel({
                 </button>
               </div>
 
-              {/* VAD parameter sliders (only shown when enabled) */}
-              {config.audio.vad_enabled && (
+              {/* Continuous dictation toggle */}
+              <div className="flex items-center justify-between">
+                <div>
+                  <label id="continuous-dictation-label" htmlFor="continuous-dictation" className="font-medium text-gray-900 dark:text-gray-100">
+                    Continuous Dictation
+                  </label>
+                  <p id="continuous-dictation-description" className="text-sm text-gray-500 dark:text-gray-400">
+                    Keep the microphone open and type each sentence after you pause; press the hotkey to stop
+                  </p>
+                </div>
+                <button
+                  type="button"
+                  id="continuous-dictation"
+                  role="switch"
+                  aria-checked={config.audio.continuous_dictation ?? false}
+                  aria-labelledby="continuous-dictation-label"
+                  aria-describedby="continuous-dictation-description"
+                  onClick={() => handleAudioChange('continuous_dictation', !config.audio.continuous_dictation)}
+                  disabled={isLoading}
+                  className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors
+                             ${config.audio.continuous_dictation ? 'bg-blue-500' : 'bg-gray-300 dark:bg-gray-600'}
+                             disabled:opacity-50 disabled:cursor-not-allowed`}
+                >
+                  <span
+                    className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform
+                               ${config.audio.continuous_dictation ? 'translate-x-6' : 'translate-x-1'}`}
+                  />
+                </button>
+              </div>
+
+              {/* Time-boxed flush for continuous dictation */}
+              {config.audio.continuous_dictation && (
+                <div className="pl-1">
+                  <label htmlFor="live-flush-secs" className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1">
+                    Type while speaking
+                  </label>
+                  <select
+                    id="live-flush-secs"
+                    value={config.audio.live_flush_secs ?? 0}
+                    onChange={(e) => handleAudioChange('live_flush_secs', Number(e.target.value))}
+                    disabled={isLoading}
+                    className="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-md
+                               bg-white dark:bg-gray-700 text-gray-900 dark:text-gray-100
+                               disabled:opacity-50 disabled:cursor-not-allowed"
+                  >
+                    {liveFlushOptions.map((option) => (
+                      <option key={option.value} value={option.value}>
+                        {option.label}
+                      </option>
+                    ))}
+                  </select>
+                  <p className="text-xs text-gray-500 dark:text-gray-400 mt-1">
+                    Type finished sentences at the next short break instead of waiting for a pause
+                  </p>
+                </div>
+              )}
+
+              {/* VAD parameter sliders (shown when auto-stop or continuous dictation uses them) */}
+              {(config.audio.vad_enabled || config.audio.continuous_dictation) && (
                 <div className="space-y-4 pl-1">
+                  {/* Sensitivity preset; editing a slider below switches to custom */}
+                  <div>
+                    <label htmlFor="vad-preset" className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1">
+                      Sensitivity
+                    </label>
+                    <select
+                      id="vad-preset"
+                      value={vadPreset}
+                      onChange={(e) => handleAudioChange('vad_preset', e.target.value as VadPreset)}
+                      disabled={isLoading}
+                      className="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-md
+                                 bg-white dark:bg-gray-700 text-gray-900 dark:text-gray-100
+                                 disabled:opacity-50 disabled:cursor-not-allowed"
+                    >
+                      {vadPresetOptions.map((option) => (
+                        <option key={option.value} value={option.value}>
+                          {option.label}
+                        </option>
+                      ))}
+                    </select>
+                    <p className="text-xs text-gray-500 dark:text-gray-400 mt-1">
+                      Aggressive stops soonest after you pause; relaxed waits longer. Run the microphone test to preview.
+                    </p>
+                  </div>
+
                   {/* Silence duration slider */}
                   <div>
                     <label htmlFor="vad-silence-ms" className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1">
@@ -375,10 +697,12 @@ export function SettingsPanel({
           <HotkeyConfig
             primaryHotkey={config.hotkeys.primary}
             copyLastHotkey={config.hotkeys.copy_last}
+            cancelHotkey={config.hotkeys.cancel ?? 'Escape'}
             mode={config.hotkeys.mode}
             effectiveMode={effectiveHotkeyMode}
             onPrimaryChange={(value) => handleHotkeyChange('primary', value)}
             onCopyLastChange={(value) => handleHotkeyChange('copy_last', value)}
+            onCancelChange={(value) => handleHotkeyChange('cancel', value)}
             onModeChange={(value) => handleHotkeyChange('mode', value)}
             isLoading={isLoading}
           />
@@ -388,6 +712,7 @@ export function SettingsPanel({
           <InjectionSettings
             config={config.injection}
             onChange={handleInjectionChange}
+            primarySelectionAvailable={primarySelectionAvailable}
             isLoading={isLoading}
           />
         )}
@@ -427,6 +752,246 @@ export function SettingsPanel({
               Choose &ldquo;System&rdquo; to follow your OS preference.
             </p>
 
+            {/* Overlay appearance */}
+            <div className="mt-6 space-y-4 border-t border-gray-200 pt-4 dark:border-gray-700">
+              <h3 className="text-sm font-semibold uppercase tracking-wide text-gray-400">
+                Overlay
+              </h3>
+
+              <div className="flex items-center justify-between">
+                <label htmlFor="overlay-theme" className="font-medium text-gray-900 dark:text-gray-100">
+                  Overlay theme
+                </label>
+                <select
+                  id="overlay-theme"
+                  value={overlayTheme}
+                  onChange={(e) => {
+                    void onConfigChange(['ui', 'overlay_theme'], e.target.value);
+                  }}
+                  disabled={isLoading}
+                  className="rounded border border-gray-300 bg-white px-2 py-1 text-sm capitalize dark:border-gray-600 dark:bg-gray-700 dark:text-gray-100"
+                >
+                  {themeOptions.map((option) => (
+                    <option key={option} value={option}>
+                      {option}
+                    </option>
+                  ))}
+                </select>
+              </div>
+
+              <div>
+                <label htmlFor="overlay-scale" className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1">
+                  Size: {Math.round(overlayScale * 100)}%
+                </label>
+                <input
+                  id="overlay-scale"
+                  type="range"
+                  min={0.5}
+                  max={2}
+                  step={0.1}
+                  value={overlayScale}
+                  onChange={(e) => {
+                    void onConfigChange(['ui', 'overlay_scale'], Number(e.target.value));
+                  }}
+                  disabled={isLoading}
+                  className="w-full accent-blue-500"
+                />
+              </div>
+
+              <div>
+                <label htmlFor="overlay-opacity" className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1">
+                  Opacity: {Math.round(overlayOpacity * 100)}%
+                </label>
+                <input
+                  id="overlay-opacity"
+                  type="range"
+                  min={0.2}
+                  max={1}
+                  step={0.05}
+                  value={overlayOpacity}
+                  onChange={(e) => {
+                    void onConfigChange(['ui', 'overlay_opacity'], Number(e.target.value));
+                  }}
+                  disabled={isLoading}
+                  className="w-full accent-blue-500"
+                />
+              </div>
+
+              <div className="flex items-center justify-between">
+                <label id="overlay-waveform-label" htmlFor="overlay-waveform" className="font-medium text-gray-900 dark:text-gray-100">
+                  Show waveform
+                </label>
+                <button
+                  type="button"
+                  id="overlay-waveform"
+                  role="switch"
+                  aria-checked={overlayShowWaveform}
+                  aria-labelledby="overlay-waveform-label"
+                  onClick={() => {
+                    void onConfigChange(['ui', 'overlay_show_waveform'], !overlayShowWaveform);
+                  }}
+                  disabled={isLoading}
+                  className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors
+                             ${overlayShowWaveform ? 'bg-blue-500' : 'bg-gray-300 dark:bg-gray-600'}
+                             disabled:opacity-50 disabled:cursor-not-allowed`}
+                >
+                  <span
+                    className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform
+                               ${overlayShowWaveform ? 'translate-x-6' : 'translate-x-1'}`}
+                  />
+                </button>
+              </div>
+
+              <div>
+                <div className="flex items-center justify-between">
+                  <label htmlFor="screen-share-policy" className="font-medium text-gray-900 dark:text-gray-100">
+                    While screen sharing
+                  </label>
+                  <select
+                    id="screen-share-policy"
+                    value={screenSharePolicy}
+                    onChange={(e) => {
+                      void onConfigChange(['ui', 'screen_share_policy'], e.target.value as ScreenSharePolicy);
+                    }}
+                    disabled={isLoading}
+                    className="rounded border border-gray-300 bg-white px-2 py-1 text-sm dark:border-gray-600 dark:bg-gray-700 dark:text-gray-100"
+                  >
+                    {screenSharePolicyOptions.map((option) => (
+                      <option key={option.value} value={option.value}>
+                        {option.label}
+                      </option>
+                    ))}
+                  </select>
+                </div>
+                <p className="text-xs text-gray-500 dark:text-gray-400 mt-1">
+                  Applies when a recording starts during a Zoom or desktop screen share. Sharing from a browser tab is not detected.
+                </p>
+              </div>
+            </div>
+
+            {/* Network */}
+            <div className="mt-6 space-y-3 border-t border-gray-200 pt-4 dark:border-gray-700">
+              <h3 className="text-sm font-semibold uppercase tracking-wide text-gray-400">
+                Network
+              </h3>
+              <div className="flex items-center justify-between">
+                <div>
+                  <label id="offline-mode-label" htmlFor="offline-mode" className="font-medium text-gray-900 dark:text-gray-100">
+                    Offline mode
+                  </label>
+                  <p className="text-sm text-gray-500 dark:text-gray-400">
+                    Block model downloads, update checks and settings sync. Downloaded models keep working.
+                  </p>
+                </div>
+                <button
+                  type="button"
+                  id="offline-mode"
+                  role="switch"
+                  aria-checked={offlineMode}
+                  aria-labelledby="offline-mode-label"
+                  onClick={() => {
+                    void onConfigChange(['network', 'offline_mode'], !offlineMode);
+                  }}
+                  disabled={isLoading}
+                  className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors
+                             ${offlineMode ? 'bg-blue-500' : 'bg-gray-300 dark:bg-gray-600'}
+                             disabled:opacity-50 disabled:cursor-not-allowed`}
+                >
+                  <span
+                    className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform
+                               ${offlineMode ? 'translate-x-6' : 'translate-x-1'}`}
+                  />
+                </button>
+              </div>
+              <div className="flex items-center justify-between">
+                <div>
+                  <label id="companion-enabled-label" htmlFor="companion-enabled" className="font-medium text-gray-900 dark:text-gray-100">
+                    Companion devices
+                  </label>
+                  <p className="text-sm text-gray-500 dark:text-gray-400">
+                    Share recording state with widgets on your local network. Read-only; off in offline mode.
+                  </p>
+                </div>
+                <button
+                  type="button"
+                  id="companion-enabled"
+                  role="switch"
+                  aria-checked={companionEnabled}
+                  aria-labelledby="companion-enabled-label"
+                  onClick={() => {
+                    void onConfigChange(['companion', 'enabled'], !companionEnabled);
+                  }}
+                  disabled={isLoading}
+                  className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors
+                             ${companionEnabled ? 'bg-blue-500' : 'bg-gray-300 dark:bg-gray-600'}
+                             disabled:opacity-50 disabled:cursor-not-allowed`}
+                >
+                  <span
+                    className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform
+                               ${companionEnabled ? 'translate-x-6' : 'translate-x-1'}`}
+                  />
+                </button>
+              </div>
+              <div className="flex items-center justify-between">
+                <div>
+                  <label id="crash-upload-label" htmlFor="crash-upload" className="font-medium text-gray-900 dark:text-gray-100">
+                    Send crash reports
+                  </label>
+                  <p className="text-sm text-gray-500 dark:text-gray-400">
+                    Upload anonymous, redacted crash reports to the configured endpoint. Reports are always kept on this device until deleted.
+                  </p>
+                </div>
+                <button
+                  type="button"
+                  id="crash-upload"
+                  role="switch"
+                  aria-checked={crashUploadEnabled}
+                  aria-labelledby="crash-upload-label"
+                  onClick={() => {
+                    void onConfigChange(['crash_reporting', 'upload_enabled'], !crashUploadEnabled);
+                  }}
+                  disabled={isLoading}
+                  className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors
+                             ${crashUploadEnabled ? 'bg-blue-500' : 'bg-gray-300 dark:bg-gray-600'}
+                             disabled:opacity-50 disabled:cursor-not-allowed`}
+                >
+                  <span
+                    className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform
+                               ${crashUploadEnabled ? 'translate-x-6' : 'translate-x-1'}`}
+                  />
+                </button>
+              </div>
+              <div className="flex items-center justify-between">
+                <div>
+                  <label id="low-power-label" htmlFor="low-power" className="font-medium text-gray-900 dark:text-gray-100">
+                    Save energy on battery
+                  </label>
+                  <p className="text-sm text-gray-500 dark:text-gray-400">
+                    On battery, skip the model preload, check the engine less often, and unload the model after a few idle minutes. The first dictation after a break reloads it and may need a second try.
+                  </p>
+                </div>
+                <button
+                  type="button"
+                  id="low-power"
+                  role="switch"
+                  aria-checked={lowPowerOnBattery}
+                  aria-labelledby="low-power-label"
+                  onClick={() => {
+                    void onConfigChange(['power', 'low_power_on_battery'], !lowPowerOnBattery);
+                  }}
+                  disabled={isLoading}
+                  className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors
+                             ${lowPowerOnBattery ? 'bg-blue-500' : 'bg-gray-300 dark:bg-gray-600'}
+                             disabled:opacity-50 disabled:cursor-not-allowed`}
+                >
+                  <span
+                    className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform
+                               ${lowPowerOnBattery ? 'translate-x-6' : 'translate-x-1'}`}
+                  />
+                </button>
+              </div>
+            </div>
+
             {/* Data Management */}
             {onPurgeHistory ? (
               <div className="mt-6 space-y-3 border-t border-gray-200 pt-4 dark:border-gray-700">
@@ -168,6 +168,35 @@ describe('ModelSettings', () => {
     expect(await screen.findByText('Whisper Small')).toBeDefined();
   });
 
+  test('shows recommended parameters from the catalog', async () => {
+    vi.mocked(invoke).mockImplementation((cmd: string) => {
+      if (cmd === 'get_model_catalog') {
+        return Promise.resolve([
+          {
+            model_id: 'nvidia/parakeet-tdt-0.6b-v3',
+            family: 'parakeet',
+            display_name: 'Parakeet 0.6B',
+            description: 'Fast and accurate',
+            supported_languages: ['en', 'de'],
+            default_language: 'en',
+            size_bytes: 1024,
+            manifest_path: 'model/MODEL_MANIFEST.json',
+            recommended_params: { beam_size: 1, vad_silence_ms: 1200, expected_ram_mb: 2048 },
+          },
+        ] as unknown);
+      }
+      return Promise.resolve(undefined as unknown);
+    });
+
+    const status = makeStatus({ status: 'ready', model_id: 'nvidia/parakeet-tdt-0.6b-v3' });
+    render(<ModelSettings status={status} onDownload={vi.fn()} onPurgeCache={vi.fn()} />);
+
+    const recommended = await screen.findByTestId('model-recommended-params');
+    expect(recommended.textContent).toBe(
+      'Recommended: ~2 GB RAM • greedy decoding • VAD stop after 1.2s • 2 languages',
+    );
+  });
+
   test('calls onSelectModel when selecting a different model', async () => {
     vi.mocked(invoke).mockImplementation((cmd: string) => {
       if (cmd === 'get_model_catalog') {
@@ -263,4 +292,49 @@ describe('ModelSettings', () => {
     expect(screen.getByRole('option', { name: 'EN' })).toBeDefined();
     expect(screen.getByRole('option', { name: 'DE' })).toBeDefined();
   });
+
+  test('persists the load-at-startup toggle without dropping other model fields', async () => {
+    const config = {
+      schema_version: 1,
+      audio: {},
+      hotkeys: {},
+      injection: {},
+      model: {
+        model_id: 'nvidia/parakeet-tdt-0.6b-v3',
+        device: null,
+        preferred_device: 'auto',
+        language: null,
+        beam_size: 4,
+        preload_on_start: false,
+        preload_delay_ms: 2000,
+      },
+      replacements: [],
+      ui: {},
+      history: {},
+      presets: {},
+    };
+    vi.mocked(invoke).mockImplementation((cmd: string) => {
+      if (cmd === 'get_config') {
+        return Promise.resolve(config as unknown);
+      }
+      return Promise.resolve(undefined as unknown);
+    });
+
+    render(<ModelSettings status={makeStatus({})} onDownload={vi.fn()} onPurgeCache={vi.fn()} />);
+
+    const toggle = await screen.findByRole('checkbox', { name: 'Load model at startup' });
+    await act(async () => {
+      fireEvent.click(toggle);
+    });
+
+    expect(invoke).toHaveBeenCalledWith('update_config', {
+      config: expect.objectContaining({
+        model: expect.objectContaining({
+          beam_size: 4,
+          preload_on_start: true,
+          preload_delay_ms: 2000,
+        }),
+      }),
+    });
+  });
 });
@@ -0,0 +1,43 @@
+/**
+ * Tests for StatisticsPanel component.
+ */
+
+import { describe, it, expect, vi } from 'vitest';
+import { fireEvent, render, screen } from '@testing-library/react';
+import { StatisticsPanel } from './StatisticsPanel';
+import type { UsageStats } from '../../types';
+
+const mockStats: UsageStats = {
+  today: { transcripts: 12, words: 3214, audio_ms: 26 * 60_000 },
+  days: [
+    { date: '2026-03-09', transcripts: 1, words: 40, audio_ms: 60_000 },
+    { date: '2026-03-10', transcripts: 12, words: 3214, audio_ms: 26 * 60_000 },
+  ],
+  all_time: { transcripts: 13, words: 3254, audio_ms: 27 * 60_000 },
+};
+
+describe('StatisticsPanel', () => {
+  it('renders today, per-day, and all-time totals', () => {
+    render(<StatisticsPanel stats={mockStats} onRefresh={vi.fn()} />);
+
+    expect(screen.getByText('Today words').nextSibling?.textContent).toBe((3214).toLocaleString());
+    expect(screen.getByText('Today audio').nextSibling?.textContent).toBe('26 min');
+    expect(screen.getByText('All-time transcripts').nextSibling?.textContent).toBe('13');
+    expect(screen.getAllByTestId('statistics-day')).toHaveLength(2);
+  });
+
+  it('shows a placeholder until stats load', () => {
+    render(<StatisticsPanel stats={null} onRefresh={vi.fn()} isLoading />);
+
+    expect(screen.getByText('Loading statistics...')).toBeDefined();
+    expect(screen.getByRole('button', { name: 'Refreshing...' })).toBeDisabled();
+  });
+
+  it('calls onRefresh', () => {
+    const onRefresh = vi.fn();
+    render(<StatisticsPanel stats={mockStats} onRefresh={onRefresh} />);
+
+    fireEvent.click(screen.getByRole('button', { name: 'Refresh' }));
+    expect(onRefresh).toHaveBeenCalledTimes(1);
+  });
+});
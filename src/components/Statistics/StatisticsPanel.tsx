@@ -0,0 +1,107 @@
+/**
+ * Usage statistics derived from transcript history.
+ *
+ * Features:
+ * - Today's words, audio time, and transcript count
+ * - Words per day over the last week
+ * - All-time totals for everything still in history
+ */
+
+import type { UsageStats, UsageTotals } from '../../types';
+
+export interface StatisticsPanelProps {
+  stats: UsageStats | null;
+  onRefresh: () => void;
+  isLoading?: boolean;
+}
+
+function formatCount(value: number): string {
+  return value.toLocaleString();
+}
+
+function formatAudioMinutes(audioMs: number): string {
+  return `${formatCount(Math.round(audioMs / 60_000))} min`;
+}
+
+function formatDayLabel(date: string): string {
+  // Dates are local calendar days; parse without a timezone shift.
+  const [year, month, day] = date.split('-').map(Number);
+  const parsed = new Date(year, month - 1, day);
+  if (Number.isNaN(parsed.getTime())) {
+    return date;
+  }
+  return parsed.toLocaleDateString([], { weekday: 'short' });
+}
+
+function TotalsRow({ label, totals }: { label: string; totals: UsageTotals }) {
+  return (
+    <div className="grid grid-cols-3 gap-3">
+      <div className="rounded-md bg-gray-100 dark:bg-gray-700/60 p-3">
+        <p className="text-xs text-gray-600 dark:text-gray-400">{label} words</p>
+        <p className="text-2xl font-semibold text-gray-900 dark:text-white">{formatCount(totals.words)}</p>
+      </div>
+      <div className="rounded-md bg-gray-100 dark:bg-gray-700/60 p-3">
+        <p className="text-xs text-gray-600 dark:text-gray-400">{label} audio</p>
+        <p className="text-2xl font-semibold text-gray-900 dark:text-white">{formatAudioMinutes(totals.audio_ms)}</p>
+      </div>
+      <div className="rounded-md bg-gray-100 dark:bg-gray-700/60 p-3">
+        <p className="text-xs text-gray-600 dark:text-gray-400">{label} transcripts</p>
+        <p className="text-2xl font-semibold text-gray-900 dark:text-white">{formatCount(totals.transcripts)}</p>
+      </div>
+    </div>
+  );
+}
+
+export function StatisticsPanel({ stats, onRefresh, isLoading = false }: StatisticsPanelProps) {
+  const maxWords = Math.max(1, ...(stats?.days ?? []).map((day) => day.words));
+
+  return (
+    <div className="flex h-full min-h-0 flex-col gap-4 overflow-y-auto pr-1" data-testid="statistics-panel">
+      <div className="flex items-center justify-between">
+        <h2 className="text-lg font-semibold">Statistics</h2>
+        <button
+          type="button"
+          onClick={onRefresh}
+          disabled={isLoading}
+          className="rounded-md border border-gray-300 dark:border-gray-600 px-3 py-1.5 text-xs font-semibold hover:bg-gray-50 dark:hover:bg-gray-700 disabled:cursor-not-allowed disabled:opacity-60"
+        >
+          {isLoading ? 'Refreshing...' : 'Refresh'}
+        </button>
+      </div>
+
+      {!stats ? (
+        <p className="text-sm text-gray-500 dark:text-gray-400">
+          {isLoading ? 'Loading statistics...' : 'No statistics available.'}
+        </p>
+      ) : (
+        <>
+          <TotalsRow label="Today" totals={stats.today} />
+
+          <section aria-labelledby="statistics-week-title" className="rounded-lg border border-gray-200 dark:border-gray-700 p-4">
+            <h3 id="statistics-week-title" className="mb-3 text-sm font-semibold uppercase tracking-wide text-gray-600 dark:text-gray-400">
+              Last 7 days
+            </h3>
+            <ul className="space-y-2">
+              {stats.days.map((day) => (
+                <li key={day.date} className="flex items-center gap-3 text-sm" data-testid="statistics-day">
+                  <span className="w-10 text-gray-600 dark:text-gray-400">{formatDayLabel(day.date)}</span>
+                  <span className="h-2 flex-1 rounded bg-gray-100 dark:bg-gray-700" aria-hidden="true">
+                    <span
+                      className="block h-2 rounded bg-blue-500"
+                      style={{ width: `${(day.words / maxWords) * 100}%` }}
+                    />
+                  </span>
+                  <span className="w-32 text-right text-gray-700 dark:text-gray-300">
+                    {formatCount(day.words)} words, {formatAudioMinutes(day.audio_ms)}
+                  </span>
+                </li>
+              ))}
+            </ul>
+          </section>
+
+          <TotalsRow label="All-time" totals={stats.all_time} />
+        </>
+      )}
+    </div>
+  );
+}
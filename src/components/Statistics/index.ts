@@ -0,0 +1 @@
+export { StatisticsPanel } from './StatisticsPanel';
@@ -30,6 +30,17 @@ function formatProgress(progress: Progress): string {
   return String(progress.current);
 }
 
+const LOADING_STAGE_LABELS: Record<string, string> = {
+  weights: 'Loading weights',
+  compile: 'Preparing for device',
+  warmup: 'Warming up',
+};
+
+function formatLoadingStage(stage: string, percent?: number): string {
+  const label = LOADING_STAGE_LABELS[stage] ?? stage;
+  return typeof percent === 'number' ? `${label} (${percent}%)` : label;
+}
+
 function statusAppearance(status: ModelState | 'not_downloaded'): StatusAppearance {
   if (status === 'ready') {
     return {
@@ -150,6 +161,10 @@ export function ModelBadge() {
   const appearance = statusAppearance(normalizedStatus);
   const progress = modelStatus?.progress ?? downloadProgress;
   const progressLabel = progress ? formatProgress(progress) : null;
+  // A loading stage supersedes download progress left over from before loading.
+  const stageLabel = modelStatus?.stage
+    ? formatLoadingStage(modelStatus.stage, modelStatus.percent)
+    : null;
 
   const onDownload = async () => {
     setDownloadError(null);
@@ -182,7 +197,11 @@ export function ModelBadge() {
         </span>
       </div>
 
-      {appearance.showProgress && progressLabel ? (
+      {appearance.showProgress && stageLabel ? (
+        <p className="text-xs text-amber-200" data-testid="model-badge-stage" aria-live="polite">
+          {stageLabel}
+        </p>
+      ) : appearance.showProgress && progressLabel ? (
         <p className="text-xs text-amber-200" data-testid="model-badge-progress" aria-live="polite">
           Progress: {progressLabel}
         </p>
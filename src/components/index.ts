@@ -7,6 +7,7 @@ export { StatusDashboard } from './Status';
 export { TabBar } from './Layout/TabBar';
 export { TabPanel } from './Layout/TabPanel';
 export { HistoryPanel } from './History/HistoryPanel';
+export { StatisticsPanel } from './Statistics';
 export { MicrophoneSelect } from './Settings/MicrophoneSelect';
 export { HotkeyConfig } from './Settings/HotkeyConfig';
 export { InjectionSettings } from './Settings/InjectionSettings';
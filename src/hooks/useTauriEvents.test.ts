@@ -12,17 +12,20 @@ import { useTauriEvents, useTauriEvent } from './useTauriEvents';
 import { useAppStore } from '../store/appStore';
 import { emitMockEvent, waitFor as waitForCondition } from '../tests/setup';
 
-const EXPECTED_CANONICAL_LISTENER_COUNT = 9;
+const EXPECTED_CANONICAL_LISTENER_COUNT = 12;
 const CANONICAL_EVENT_NAMES = [
   'state:changed',
   'model:status',
   'model:progress',
   'audio:level',
+  'vad:preview',
   'transcript:complete',
   'transcript:error',
   'app:error',
   'sidecar:status',
   'recording:status',
+  'session:changed',
+  'cues:status',
 ] as const;
 
 type Deferred<T> = {
@@ -81,6 +84,7 @@ beforeEach(() => {
     devices: [],
     selectedDeviceUid: null,
     audioLevel: null,
+    vadPreview: null,
     isMeterRunning: false,
     history: [],
     recordingStatus: null,
@@ -112,11 +116,14 @@ describe('useTauriEvents', () => {
     expect(listen).toHaveBeenCalledWith('model:status', expect.any(Function));
     expect(listen).toHaveBeenCalledWith('model:progress', expect.any(Function));
     expect(listen).toHaveBeenCalledWith('audio:level', expect.any(Function));
+    expect(listen).toHaveBeenCalledWith('vad:preview', expect.any(Function));
     expect(listen).toHaveBeenCalledWith('transcript:complete', expect.any(Function));
     expect(listen).toHaveBeenCalledWith('transcript:error', expect.any(Function));
     expect(listen).toHaveBeenCalledWith('app:error', expect.any(Function));
     expect(listen).toHaveBeenCalledWith('sidecar:status', expect.any(Function));
     expect(listen).toHaveBeenCalledWith('recording:status', expect.any(Function));
+    expect(listen).toHaveBeenCalledWith('session:changed', expect.any(Function));
+    expect(listen).toHaveBeenCalledWith('cues:status', expect.any(Function));
 
     // Verify legacy aliases are no longer registered
     expect(listen).not.toHaveBeenCalledWith('state_changed', expect.any(Function));
@@ -171,6 +178,10 @@ describe('useTauriEvents', () => {
     store._setAudioLevel({ rms: -20, peak: -10 });
     expect(useAppStore.getState().audioLevel?.rms).toBe(-20);
 
+    // Test _setVadPreview
+    store._setVadPreview({ state: 'speech', speech_ms: 400, silence_ms: 0, silence_threshold_ms: 1200 });
+    expect(useAppStore.getState().vadPreview?.state).toBe('speech');
+
     // Test _addHistoryEntry
     store._addHistoryEntry({
       id: 'test',
@@ -471,6 +482,71 @@ describe('useTauriEvents', () => {
     unmount();
   });
 
+  test('session:changed keeps the newest revision of the session', async () => {
+    const { unmount } = renderHook(() => useTauriEvents());
+
+    await waitForCanonicalListeners();
+
+    act(() => {
+      emitMockEvent('session:changed', {
+        seq: 12,
+        session_id: 'session-7',
+        phase: 'transcribing',
+        revision: 3,
+        created_at: '2026-01-01T00:00:00Z',
+        started_at: '2026-01-01T00:00:01Z',
+        updated_at: '2026-01-01T00:00:04Z',
+        audio_ms: 3000,
+        text: null,
+        error: null,
+        cancel_reason: null,
+      });
+      emitMockEvent('session:changed', {
+        seq: 11,
+        session_id: 'session-7',
+        phase: 'recording',
+        revision: 2,
+        created_at: '2026-01-01T00:00:00Z',
+        started_at: '2026-01-01T00:00:01Z',
+        updated_at: '2026-01-01T00:00:01Z',
+        audio_ms: null,
+        text: null,
+        error: null,
+        cancel_reason: null,
+      });
+    });
+
+    expect(useAppStore.getState().activeSession).toMatchObject({
+      session_id: 'session-7',
+      phase: 'transcribing',
+      revision: 3,
+    });
+
+    unmount();
+  });
+
+  test('cues:status stores why audio cues are muted', async () => {
+    const { unmount } = renderHook(() => useTauriEvents());
+
+    await waitForCanonicalListeners();
+
+    act(() => {
+      emitMockEvent('cues:status', { seq: 4, suppressed: true, reason: 'call', app: 'zoom' });
+    });
+    expect(useAppStore.getState().cueStatus).toMatchObject({
+      suppressed: true,
+      reason: 'call',
+      app: 'zoom',
+    });
+
+    act(() => {
+      emitMockEvent('cues:status', { seq: 5, suppressed: false, reason: null, app: null });
+    });
+    expect(useAppStore.getState().cueStatus?.suppressed).toBe(false);
+
+    unmount();
+  });
+
   test('sidecar:status updates sidecar status slice', async () => {
     const { unmount } = renderHook(() => useTauriEvents());
 
@@ -11,15 +11,18 @@ import { useAppStore } from '../store/appStore';
 import { createSeqDedupeTracker, type DedupeStreamKey } from '../utils/dedupeTracker';
 import type {
   AudioLevelEvent,
+  CueStatusEvent,
   ErrorEvent,
   InjectionResult,
   Progress,
   RecordingStatusEvent,
+  SessionChangedEvent,
   SidecarStatusEvent,
   StateEventPayload,
   TranscriptEntry,
   TranscriptEventPayload,
   ModelStatusPayload,
+  VadPreviewEvent,
 } from '../types';
 
 // Event names emitted by Rust backend
@@ -33,6 +36,7 @@ const EVENTS = {
 
   // Audio events
   AUDIO_LEVEL: 'audio:level',
+  VAD_PREVIEW: 'vad:preview',
 
   // Transcript events
   TRANSCRIPT_COMPLETE: 'transcript:complete',
@@ -46,6 +50,12 @@ const EVENTS = {
 
   // Recording events
   RECORDING_STATUS: 'recording:status',
+
+  // Session lifecycle events
+  SESSION_CHANGED: 'session:changed',
+
+  // Audio cue events
+  CUES_STATUS: 'cues:status',
 } as const;
 
 const STREAM_KEYS: Record<string, DedupeStreamKey> = {
@@ -57,6 +67,7 @@ const STREAM_KEYS: Record<string, DedupeStreamKey> = {
   MODEL_PROGRESS: 'modelProgress',
   RECORDING: 'recording',
   AUDIO: 'audio',
+  VAD: 'vad',
   ERROR: 'error',
 } as const;
 
@@ -369,6 +380,15 @@ export function useTauriEvents(): void {
       );
       if (!audioLevelRegistered) return;
 
+      // Subscribe to VAD decisions (during mic test)
+      const vadPreviewRegistered = await registerListener<VadPreviewEvent>(
+        EVENTS.VAD_PREVIEW,
+        dedupeHandler(STREAM_KEYS.VAD, (payload) => {
+          store._setVadPreview(payload);
+        })
+      );
+      if (!vadPreviewRegistered) return;
+
       // Subscribe to transcript completions
       const transcriptRegistered = await registerListener<TranscriptEventPayload>(
         EVENTS.TRANSCRIPT_COMPLETE,
@@ -431,6 +451,28 @@ export function useTauriEvents(): void {
       );
       if (!recordingStatusRegistered) return;
 
+      // Subscribe to session lifecycle snapshots. The store drops revisions it
+      // has already applied, so these bypass seq dedupe.
+      const sessionRegistered = await registerListener<SessionChangedEvent>(
+        EVENTS.SESSION_CHANGED,
+        (event) => {
+          console.debug('Event: session:changed', event.payload.phase, event.payload.revision);
+          store._applySessionSnapshot(event.payload);
+        }
+      );
+      if (!sessionRegistered) return;
+
+      // Subscribe to audio cue muting. Each payload is the full status, so
+      // replays are harmless.
+      const cuesRegistered = await registerListener<CueStatusEvent>(
+        EVENTS.CUES_STATUS,
+        (event) => {
+          console.debug('Event: cues:status', event.payload.suppressed, event.payload.reason);
+          store._setCueStatus(event.payload);
+        }
+      );
+      if (!cuesRegistered) return;
+
       console.log('Tauri event listeners set up');
     };
 
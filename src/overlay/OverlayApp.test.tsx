@@ -155,10 +155,112 @@ describe('OverlayApp listener lifecycle', () => {
     expect(screen.getByText('Recording')).toBeInTheDocument();
   });
 
+  it('shows transcribing progress from overlay:transcribing until the phase ends', async () => {
+    render(<OverlayApp />);
+
+    await waitFor(() => {
+      expect(activeListenerCount('overlay:transcribing')).toBe(1);
+    });
+
+    act(() => {
+      emitEvent('recording:status', { phase: 'transcribing', audio_ms: 5000 });
+      emitEvent('overlay:transcribing', {
+        session_id: 'session-1',
+        elapsed_ms: 2600,
+        audio_ms: 5000,
+      });
+    });
+    expect(screen.getByText('Transcribing')).toBeInTheDocument();
+    expect(screen.getByTestId('transcribing-elapsed')).toHaveTextContent('2s');
+    expect(screen.getByText('00:05')).toBeInTheDocument();
+
+    act(() => {
+      emitEvent('recording:status', { phase: 'idle' });
+    });
+    expect(screen.queryByTestId('transcribing-elapsed')).toBeNull();
+  });
+
+  it('plays the cancel animation on overlay:cancelled before going idle', async () => {
+    render(<OverlayApp />);
+
+    await waitFor(() => {
+      expect(activeListenerCount('overlay:cancelled')).toBe(1);
+    });
+
+    act(() => {
+      emitEvent('recording:status', { phase: 'recording', audio_ms: 0 });
+    });
+    expect(screen.getByText('Recording')).toBeInTheDocument();
+
+    act(() => {
+      emitEvent('recording:status', { phase: 'idle' });
+      emitEvent('overlay:cancelled', { session_id: 'session-1', reason: 'escape_key' });
+    });
+    expect(screen.getByText('Cancelled')).toBeInTheDocument();
+    expect(screen.getByTestId('cancelled-mark')).toBeInTheDocument();
+
+    await waitFor(() => {
+      expect(screen.queryByText('Cancelled')).toBeNull();
+    });
+  });
+
+  it('counts down from overlay:countdown until recording starts', async () => {
+    render(<OverlayApp />);
+
+    await waitFor(() => {
+      expect(activeListenerCount('overlay:countdown')).toBe(1);
+    });
+
+    act(() => {
+      emitEvent('overlay:countdown', { seq: 1, remaining_secs: 3, cancelled: false });
+    });
+    expect(screen.getByText('Get ready')).toBeInTheDocument();
+    expect(screen.getByTestId('countdown-seconds')).toHaveTextContent('3');
+
+    act(() => {
+      emitEvent('overlay:countdown', { seq: 2, remaining_secs: 1, cancelled: false });
+    });
+    expect(screen.getByTestId('countdown-seconds')).toHaveTextContent('1');
+
+    act(() => {
+      emitEvent('overlay:countdown', { seq: 3, remaining_secs: 0, cancelled: false });
+      emitEvent('recording:status', { phase: 'recording', audio_ms: 0 });
+    });
+    expect(screen.queryByText('Get ready')).toBeNull();
+    expect(screen.getByText('Recording')).toBeInTheDocument();
+  });
+
+  it('plays the cancel animation when the countdown is cancelled', async () => {
+    render(<OverlayApp />);
+
+    await waitFor(() => {
+      expect(activeListenerCount('overlay:countdown')).toBe(1);
+    });
+
+    act(() => {
+      emitEvent('overlay:countdown', { seq: 1, remaining_secs: 2, cancelled: false });
+    });
+    act(() => {
+      emitEvent('overlay:countdown', { seq: 2, remaining_secs: 0, cancelled: true });
+    });
+    expect(screen.getByText('Cancelled')).toBeInTheDocument();
+
+    await waitFor(() => {
+      expect(screen.queryByText('Cancelled')).toBeNull();
+    });
+    expect(screen.queryByText('Get ready')).toBeNull();
+  });
+
   it('refreshes app state on re-enable so overlay does not show stale phase', async () => {
-    vi.mocked(invoke)
-      .mockResolvedValueOnce({ state: 'idle' })
-      .mockResolvedValueOnce({ state: 'recording' });
+    const appStates = ['idle', 'recording'];
+    vi.mocked(invoke).mockImplementation(async (command: string) => {
+      if (command === 'get_app_state') {
+        return { state: appStates.shift() ?? 'recording' };
+      }
+      return undefined;
+    });
+    const appStateCalls = () =>
+      vi.mocked(invoke).mock.calls.filter(([command]) => command === 'get_app_state').length;
 
     render(<OverlayApp />);
 
@@ -180,8 +282,44 @@ describe('OverlayApp listener lifecycle', () => {
     });
 
     await waitFor(() => {
-      expect(vi.mocked(invoke)).toHaveBeenCalledTimes(2);
+      expect(appStateCalls()).toBe(2);
     });
     expect(screen.getByText('Recording')).toBeInTheDocument();
   });
+
+  it('applies appearance from get_overlay_config and overlay:config', async () => {
+    vi.mocked(invoke).mockImplementation(async (command: string) => {
+      if (command === 'get_app_state') {
+        return { state: 'recording' };
+      }
+      if (command === 'get_overlay_config') {
+        return { theme: 'dark', scale: 1.5, opacity: 0.6, show_waveform: true };
+      }
+      return undefined;
+    });
+
+    const { container } = render(<OverlayApp />);
+
+    await waitFor(() => {
+      expect(activeListenerCount('overlay:config')).toBe(1);
+      expect(screen.getByText('Recording')).toBeInTheDocument();
+    });
+    const root = container.firstChild as HTMLElement;
+    expect(root.style.opacity).toBe('0.6');
+    expect((root.firstChild as HTMLElement).style.transform).toBe('scale(1.5)');
+    expect(screen.getByTestId('waveform')).toBeInTheDocument();
+
+    act(() => {
+      emitEvent('overlay:config', {
+        seq: 4,
+        theme: 'light',
+        scale: 1,
+        opacity: 1,
+        show_waveform: false,
+      });
+    });
+
+    expect(root.style.opacity).toBe('1');
+    expect(screen.queryByTestId('waveform')).toBeNull();
+  });
 });
@@ -3,7 +3,12 @@ import { listen, type UnlistenFn } from '@tauri-apps/api/event';
 import { useEffect, useMemo, useRef, useState } from 'react';
 import type {
   TauriCommandGetAppStateResult,
+  TauriCommandGetOverlayConfigResult,
   TauriEventAudioLevelPayload,
+  TauriEventOverlayCancelledPayload,
+  TauriEventOverlayConfigPayload,
+  TauriEventOverlayCountdownPayload,
+  TauriEventOverlayTranscribingPayload,
   TauriEventRecordingStatusPayload,
   TauriEventSidecarStatusPayload,
   TauriEventStateChangedPayload,
@@ -16,11 +21,49 @@ type RecordingPhase = 'idle' | 'recording' | 'transcribing';
 type SidecarState = 'starting' | 'ready' | 'failed' | 'restarting' | 'stopped' | 'unknown';
 
 const WAVEFORM_INTERVAL_MS = 67; // ~15Hz max waveform updates.
+const CANCEL_ANIMATION_MS = 600; // Matches the host's delay before hiding the overlay.
 
 type OverlayTogglePayload = {
   enabled?: boolean;
 };
 
+type OverlayAppearance = Omit<TauriEventOverlayConfigPayload, 'seq'>;
+
+const DEFAULT_APPEARANCE: OverlayAppearance = {
+  theme: 'system',
+  scale: 1,
+  opacity: 1,
+  show_waveform: true,
+};
+
+function toAppearance(value: unknown): OverlayAppearance | null {
+  if (!value || typeof value !== 'object') {
+    return null;
+  }
+  const candidate = value as Partial<OverlayAppearance>;
+  return {
+    theme:
+      candidate.theme === 'light' || candidate.theme === 'dark' || candidate.theme === 'system'
+        ? candidate.theme
+        : DEFAULT_APPEARANCE.theme,
+    scale: Number.isFinite(candidate.scale) ? Number(candidate.scale) : DEFAULT_APPEARANCE.scale,
+    opacity: Number.isFinite(candidate.opacity)
+      ? Number(candidate.opacity)
+      : DEFAULT_APPEARANCE.opacity,
+    show_waveform:
+      typeof candidate.show_waveform === 'boolean'
+        ? candidate.show_waveform
+        : DEFAULT_APPEARANCE.show_waveform,
+  };
+}
+
+function prefersDarkScheme(): boolean {
+  if (typeof window === 'undefined' || typeof window.matchMedia !== 'function') {
+    return true;
+  }
+  return window.matchMedia('(prefers-color-scheme: dark)').matches;
+}
+
 function parseStartTime(value?: string): number | null {
   if (!value) {
     return null;
@@ -56,12 +99,16 @@ export function OverlayApp() {
   const [phase, setPhase] = useState<RecordingPhase>('idle');
   const [audioMs, setAudioMs] = useState(0);
   const [startedAtMs, setStartedAtMs] = useState<number | null>(null);
+  const [transcribingElapsedMs, setTranscribingElapsedMs] = useState<number | null>(null);
   const [waveLevel, setWaveLevel] = useState(0);
   const [overlayEnabled, setOverlayEnabled] = useState(true);
   const [documentVisible, setDocumentVisible] = useState(
     () => document.visibilityState !== 'hidden',
   );
   const [sidecarState, setSidecarState] = useState<SidecarState>('unknown');
+  const [appearance, setAppearance] = useState<OverlayAppearance>(DEFAULT_APPEARANCE);
+  const [cancelAnimating, setCancelAnimating] = useState(false);
+  const [countdownSecs, setCountdownSecs] = useState<number | null>(null);
 
   const shouldProcess = overlayEnabled && documentVisible;
   const shouldProcessRef = useRef(shouldProcess);
@@ -77,6 +124,14 @@ export function OverlayApp() {
     }
   }, [shouldProcess]);
 
+  useEffect(() => {
+    if (!cancelAnimating) {
+      return;
+    }
+    const timeout = window.setTimeout(() => setCancelAnimating(false), CANCEL_ANIMATION_MS);
+    return () => window.clearTimeout(timeout);
+  }, [cancelAnimating]);
+
   useEffect(() => {
     const onVisibilityChange = () => {
       setDocumentVisible(document.visibilityState !== 'hidden');
@@ -117,6 +172,47 @@ export function OverlayApp() {
     };
   }, []);
 
+  useEffect(() => {
+    let cancelled = false;
+    let unlistenOverlayConfig: UnlistenFn | null = null;
+
+    const setup = async () => {
+      try {
+        const initial = toAppearance(
+          await invoke<TauriCommandGetOverlayConfigResult>('get_overlay_config'),
+        );
+        if (!cancelled && initial) {
+          setAppearance(initial);
+        }
+      } catch {
+        // Defaults apply until the first overlay:config event.
+      }
+
+      const unlisten = await listen<TauriEventOverlayConfigPayload>('overlay:config', (event) => {
+        const next = toAppearance(event.payload);
+        if (next) {
+          setAppearance(next);
+        }
+      });
+
+      if (cancelled) {
+        unlisten();
+        return;
+      }
+
+      unlistenOverlayConfig = unlisten;
+    };
+
+    void setup();
+
+    return () => {
+      cancelled = true;
+      if (unlistenOverlayConfig) {
+        unlistenOverlayConfig();
+      }
+    };
+  }, []);
+
   useEffect(() => {
     if (!shouldProcess) {
       return;
@@ -164,8 +260,13 @@ export function OverlayApp() {
 
         setPhase(payload.phase);
         setAudioMs(payload.audio_ms ?? 0);
+        if (payload.phase !== 'transcribing') {
+          setTranscribingElapsedMs(null);
+        }
 
         if (payload.phase === 'recording') {
+          setCancelAnimating(false);
+          setCountdownSecs(null);
           setStartedAtMs(parseStartTime(payload.started_at));
         } else {
           setStartedAtMs(null);
@@ -176,7 +277,37 @@ export function OverlayApp() {
         if (!shouldProcessRef.current) {
           return;
         }
-        setPhase(mapAppStateToPhase(payload.state));
+        const nextPhase = mapAppStateToPhase(payload.state);
+        setPhase(nextPhase);
+        if (nextPhase !== 'transcribing') {
+          setTranscribingElapsedMs(null);
+        }
+      });
+
+      await subscribe<TauriEventOverlayTranscribingPayload>('overlay:transcribing', (payload) => {
+        if (!shouldProcessRef.current) {
+          return;
+        }
+        setPhase('transcribing');
+        setAudioMs(payload.audio_ms);
+        setTranscribingElapsedMs(payload.elapsed_ms);
+      });
+
+      await subscribe<TauriEventOverlayCancelledPayload>('overlay:cancelled', () => {
+        if (!shouldProcessRef.current) {
+          return;
+        }
+        setCancelAnimating(true);
+      });
+
+      await subscribe<TauriEventOverlayCountdownPayload>('overlay:countdown', (payload) => {
+        if (!shouldProcessRef.current) {
+          return;
+        }
+        setCountdownSecs(payload.remaining_secs > 0 ? payload.remaining_secs : null);
+        if (payload.cancelled) {
+          setCancelAnimating(true);
+        }
       });
 
       await subscribe<TauriEventSidecarStatusPayload>('sidecar:status', (payload) => {
@@ -216,6 +347,14 @@ export function OverlayApp() {
     return null;
   }
 
+  const pillPhase = cancelAnimating
+    ? 'cancelled'
+    : phase === 'idle' && countdownSecs !== null
+      ? 'countdown'
+      : phase;
+  const pillTheme =
+    appearance.theme === 'system' ? (prefersDarkScheme() ? 'dark' : 'light') : appearance.theme;
+
   return (
     <div
       style={{
@@ -223,17 +362,27 @@ export function OverlayApp() {
         display: 'flex',
         height: '100vh',
         justifyContent: 'center',
+        opacity: appearance.opacity,
         padding: 16,
         pointerEvents: 'none',
         width: '100vw',
       }}
     >
-      <RecordingPill
-        phase={phase}
-        sidecarState={sidecarState}
-        timer={<SessionTimer phase={phase} audioMs={audioMs} startedAtMs={startedAtMs} />}
-        waveform={<Waveform active={phase !== 'idle'} level={waveLevel} />}
-      />
+      <div style={{ transform: `scale(${appearance.scale})`, transformOrigin: 'bottom center' }}>
+        <RecordingPill
+          phase={pillPhase}
+          sidecarState={sidecarState}
+          theme={pillTheme}
+          transcribingElapsedMs={transcribingElapsedMs}
+          countdownSecs={countdownSecs}
+          timer={<SessionTimer phase={phase} audioMs={audioMs} startedAtMs={startedAtMs} />}
+          waveform={
+            appearance.show_waveform ? (
+              <Waveform active={phase !== 'idle'} level={waveLevel} />
+            ) : null
+          }
+        />
+      </div>
     </div>
   );
 }
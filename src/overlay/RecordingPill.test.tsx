@@ -48,6 +48,37 @@ describe('Overlay RecordingPill', () => {
     expect(screen.queryByText('Transcribing')).toBeNull();
   });
 
+  it('renders a cancelled state with a dismiss animation', () => {
+    mockMatchMedia({ matches: false });
+    render(
+      <RecordingPill phase="cancelled" sidecarState="ready" timer={<span>00:00</span>} />,
+    );
+
+    expect(screen.getByText('Cancelled')).toBeInTheDocument();
+    expect(screen.getByTestId('cancelled-mark')).toBeInTheDocument();
+    expect(screen.getByTestId('overlay-cancelled').style.animation).toContain(
+      'overlay-cancel-dismiss',
+    );
+  });
+
+  it('renders the countdown seconds without timer or waveform', () => {
+    mockMatchMedia({ matches: false });
+    render(
+      <RecordingPill
+        phase="countdown"
+        sidecarState="ready"
+        countdownSecs={3}
+        timer={<span>00:00</span>}
+        waveform={<span>wf</span>}
+      />,
+    );
+
+    expect(screen.getByText('Get ready')).toBeInTheDocument();
+    expect(screen.getByTestId('countdown-seconds')).toHaveTextContent('3');
+    expect(screen.queryByText('00:00')).toBeNull();
+    expect(screen.queryByText('wf')).toBeNull();
+  });
+
   it('renders a pulsing red dot while recording', () => {
     mockMatchMedia({ matches: false });
     render(
@@ -82,6 +113,33 @@ describe('Overlay RecordingPill', () => {
     expect(spinner).toHaveStyle({ animation: 'overlay-transcribing-spin 0.95s linear infinite' });
   });
 
+  it('shows transcribing elapsed time only while transcribing', () => {
+    mockMatchMedia({ matches: false });
+    const { rerender } = render(
+      <RecordingPill
+        phase="transcribing"
+        sidecarState="ready"
+        transcribingElapsedMs={3400}
+        timer={<span>00:05</span>}
+        waveform={<span>wf</span>}
+      />,
+    );
+
+    expect(screen.getByTestId('transcribing-elapsed')).toHaveTextContent('3s');
+
+    rerender(
+      <RecordingPill
+        phase="recording"
+        sidecarState="ready"
+        transcribingElapsedMs={3400}
+        timer={<span>00:05</span>}
+        waveform={<span>wf</span>}
+      />,
+    );
+
+    expect(screen.queryByTestId('transcribing-elapsed')).toBeNull();
+  });
+
   it('disables animation when reduced motion is preferred', () => {
     mockMatchMedia({ matches: true });
     render(
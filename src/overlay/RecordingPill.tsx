@@ -1,7 +1,8 @@
 import { useEffect, useState, type ReactNode } from 'react';
 
-type RecordingPhase = 'idle' | 'recording' | 'transcribing';
+type RecordingPhase = 'idle' | 'countdown' | 'recording' | 'transcribing' | 'cancelled';
 type SidecarState = 'starting' | 'ready' | 'failed' | 'restarting' | 'stopped' | 'unknown';
+type PillTheme = 'light' | 'dark';
 
 interface Palette {
   bg: string;
@@ -11,6 +12,12 @@ interface Palette {
 }
 
 const PHASE_PALETTE: Record<Exclude<RecordingPhase, 'idle'>, Palette> = {
+  countdown: {
+    bg: 'rgba(10, 36, 64, 0.9)',
+    border: 'rgba(86, 164, 240, 0.8)',
+    dot: '#63b3ff',
+    text: '#e8f4ff',
+  },
   recording: {
     bg: 'rgba(53, 9, 9, 0.9)',
     border: 'rgba(235, 76, 76, 0.82)',
@@ -23,15 +30,54 @@ const PHASE_PALETTE: Record<Exclude<RecordingPhase, 'idle'>, Palette> = {
     dot: '#ffd659',
     text: '#fff8e1',
   },
+  cancelled: {
+    bg: 'rgba(38, 38, 42, 0.9)',
+    border: 'rgba(160, 160, 170, 0.7)',
+    dot: '#c4c4cc',
+    text: '#ececf1',
+  },
+};
+
+const LIGHT_PHASE_PALETTE: Record<Exclude<RecordingPhase, 'idle'>, Palette> = {
+  countdown: {
+    bg: 'rgba(236, 245, 255, 0.94)',
+    border: 'rgba(36, 118, 204, 0.7)',
+    dot: '#1f76cf',
+    text: '#0c2f54',
+  },
+  recording: {
+    bg: 'rgba(255, 241, 241, 0.94)',
+    border: 'rgba(214, 52, 52, 0.7)',
+    dot: '#e02f2f',
+    text: '#5a0e0e',
+  },
+  transcribing: {
+    bg: 'rgba(255, 249, 229, 0.94)',
+    border: 'rgba(196, 148, 20, 0.7)',
+    dot: '#c99400',
+    text: '#4d3805',
+  },
+  cancelled: {
+    bg: 'rgba(244, 244, 246, 0.94)',
+    border: 'rgba(120, 120, 130, 0.6)',
+    dot: '#6b6b75',
+    text: '#2b2b30',
+  },
 };
 
 function phaseLabel(phase: RecordingPhase): string {
+  if (phase === 'countdown') {
+    return 'Get ready';
+  }
   if (phase === 'recording') {
     return 'Recording';
   }
   if (phase === 'transcribing') {
     return 'Transcribing';
   }
+  if (phase === 'cancelled') {
+    return 'Cancelled';
+  }
   return 'Idle';
 }
 
@@ -82,30 +128,53 @@ function useReducedMotion(): boolean {
 export interface RecordingPillProps {
   phase: RecordingPhase;
   sidecarState: SidecarState;
+  /** Time spent transcribing so far, from `overlay:transcribing`. */
+  transcribingElapsedMs?: number | null;
+  /** Seconds left before capture starts, from `overlay:countdown`. */
+  countdownSecs?: number | null;
   timer: ReactNode;
-  waveform: ReactNode;
+  /** Omitted when the overlay waveform is turned off. */
+  waveform?: ReactNode;
+  theme?: PillTheme;
 }
 
-export function RecordingPill({ phase, sidecarState, timer, waveform }: RecordingPillProps) {
+export function RecordingPill({
+  phase,
+  sidecarState,
+  transcribingElapsedMs = null,
+  countdownSecs = null,
+  timer,
+  waveform = null,
+  theme = 'dark',
+}: RecordingPillProps) {
   const reducedMotion = useReducedMotion();
 
   if (phase === 'idle') {
     return null;
   }
+  const cancelled = phase === 'cancelled';
+  const countdown = phase === 'countdown';
 
-  const palette = PHASE_PALETTE[phase];
+  const palette = (theme === 'light' ? LIGHT_PHASE_PALETTE : PHASE_PALETTE)[phase];
   const sidecar = sidecarLabel(sidecarState);
   const sidecarWarning = sidecarState === 'failed' || sidecarState === 'stopped';
+  const transcribingSeconds =
+    phase === 'transcribing' && typeof transcribingElapsedMs === 'number'
+      ? Math.floor(Math.max(0, transcribingElapsedMs) / 1000)
+      : null;
 
   return (
     <div
+      data-testid={cancelled ? 'overlay-cancelled' : undefined}
       style={{
         alignItems: 'center',
+        animation:
+          cancelled && !reducedMotion ? 'overlay-cancel-dismiss 0.6s ease-in forwards' : 'none',
         backdropFilter: 'blur(4px)',
         background: palette.bg,
         border: `1px solid ${palette.border}`,
         borderRadius: 999,
-        boxShadow: '0 12px 24px rgba(0, 0, 0, 0.35)',
+        boxShadow: theme === 'light' ? '0 12px 24px rgba(0, 0, 0, 0.15)' : '0 12px 24px rgba(0, 0, 0, 0.35)',
         color: palette.text,
         display: 'inline-flex',
         gap: 10,
@@ -123,8 +192,38 @@ export function RecordingPill({ phase, sidecarState, timer, waveform }: Recordin
           from { transform: rotate(0deg); }
           to { transform: rotate(360deg); }
         }
+        @keyframes overlay-cancel-dismiss {
+          0% { transform: translateX(0); opacity: 1; }
+          15% { transform: translateX(-6px); }
+          30% { transform: translateX(6px); }
+          45% { transform: translateX(-3px); opacity: 1; }
+          100% { transform: translateX(0) scale(0.92); opacity: 0; }
+        }
       `}</style>
-      {phase === 'recording' ? (
+      {cancelled ? (
+        <span
+          aria-hidden="true"
+          data-testid="cancelled-mark"
+          style={{ color: palette.dot, fontSize: 14, fontWeight: 700, lineHeight: 1 }}
+        >
+          ✕
+        </span>
+      ) : countdown ? (
+        <span
+          data-testid="countdown-seconds"
+          style={{
+            color: palette.dot,
+            fontSize: 15,
+            fontVariantNumeric: 'tabular-nums',
+            fontWeight: 700,
+            lineHeight: 1,
+            minWidth: 10,
+            textAlign: 'center',
+          }}
+        >
+          {countdownSecs ?? ''}
+        </span>
+      ) : phase === 'recording' ? (
         <span
           aria-hidden="true"
           data-testid="recording-dot"
@@ -154,8 +253,16 @@ export function RecordingPill({ phase, sidecarState, timer, waveform }: Recordin
         />
       )}
       <span style={{ fontSize: 13, fontWeight: 600, minWidth: 84 }}>{phaseLabel(phase)}</span>
-      {timer}
-      <div style={{ opacity: 1 }}>{waveform}</div>
+      {transcribingSeconds !== null ? (
+        <span
+          data-testid="transcribing-elapsed"
+          style={{ fontSize: 11, fontVariantNumeric: 'tabular-nums', opacity: 0.8 }}
+        >
+          {`${transcribingSeconds}s`}
+        </span>
+      ) : null}
+      {countdown ? null : timer}
+      {waveform && !countdown ? <div style={{ opacity: 1 }}>{waveform}</div> : null}
       {sidecar ? (
         <span
           style={{
@@ -18,6 +18,7 @@ export function Waveform({ active, level }: WaveformProps) {
   return (
     <div
       aria-hidden="true"
+      data-testid="waveform"
       style={{
         alignItems: 'center',
         display: 'flex',
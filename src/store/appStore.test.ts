@@ -26,7 +26,7 @@ import {
   createMockModelStatus,
   createMockConfig,
 } from '../tests/setup';
-import type { DiagnosticsReport, TranscriptEntry } from '../types';
+import type { DiagnosticsReport, SessionSnapshot, TranscriptEntry } from '../types';
 
 // ============================================================================
 // TEST SETUP
@@ -44,9 +44,12 @@ const getInitialState = () => ({
   devices: [],
   selectedDeviceUid: null,
   audioLevel: null,
+  vadPreview: null,
   isMeterRunning: false,
   history: [],
   recordingStatus: null,
+  activeSession: null,
+  cueStatus: null,
   sidecarStatus: null,
   sidecarRecoveryNeeded: false,
   lastTranscriptError: null,
@@ -64,6 +67,22 @@ beforeEach(() => {
   useAppStore.setState(getInitialState());
 });
 
+function createSessionSnapshot(overrides: Partial<SessionSnapshot> = {}): SessionSnapshot {
+  return {
+    session_id: 'session-1',
+    phase: 'recording',
+    revision: 2,
+    created_at: '2026-01-01T00:00:00Z',
+    updated_at: '2026-01-01T00:00:01Z',
+    started_at: '2026-01-01T00:00:01Z',
+    audio_ms: null,
+    text: null,
+    error: null,
+    cancel_reason: null,
+    ...overrides,
+  };
+}
+
 // ============================================================================
 // SELECTOR TESTS
 // ============================================================================
@@ -249,6 +268,30 @@ describe('Device Actions', () => {
     expect(useAppStore.getState().selectedDeviceUid).toBeNull();
   });
 
+  test('setInputGain stores the gain under the resolved device', async () => {
+    const config = createMockConfig();
+    config.audio.input_gains = { 'old-device': 3 };
+    useAppStore.setState({ config });
+
+    setMockInvokeHandler((cmd) => {
+      if (cmd === 'set_input_gain') return { device_uid: 'default-mic', gain_db: 30 };
+      return undefined;
+    });
+
+    await useAppStore.getState().setInputGain(null, 42);
+
+    expect(invoke).toHaveBeenCalledWith('set_input_gain', { deviceUid: null, gainDb: 42 });
+    expect(useAppStore.getState().config?.audio.input_gains).toEqual({
+      'old-device': 3,
+      'default-mic': 30,
+    });
+
+    setMockInvokeHandler(() => ({ device_uid: 'old-device', gain_db: 0 }));
+    await useAppStore.getState().setInputGain('old-device', 0);
+
+    expect(useAppStore.getState().config?.audio.input_gains).toEqual({ 'default-mic': 30 });
+  });
+
   test('startMicTest enables meter and resets audio level', async () => {
     useAppStore.setState({
       isMeterRunning: false,
@@ -332,6 +375,32 @@ describe('Recording Actions', () => {
     expect(invoke).toHaveBeenCalledWith('cancel_recording');
     expect(useAppStore.getState().appState).toBe('recording');
   });
+
+  test('startRecording surfaces the structured integration error message', async () => {
+    useAppStore.setState({ appState: 'idle' });
+
+    setMockInvokeHandler((cmd) => {
+      if (cmd === 'start_recording') {
+        throw {
+          code: 'integration',
+          message: 'Failed to call recording.start RPC: Remote error: E_MIC_PERMISSION',
+          error: {
+            code: 'E_MIC_PERMISSION',
+            message: 'Microphone permission is required for voice input.',
+            recoverable: false,
+          },
+        };
+      }
+      return undefined;
+    });
+
+    await expect(useAppStore.getState().startRecording()).rejects.toBeDefined();
+
+    expect(useAppStore.getState().appState).toBe('error');
+    expect(useAppStore.getState().errorDetail).toBe(
+      'Microphone permission is required for voice input.',
+    );
+  });
 });
 
 // ============================================================================
@@ -393,6 +462,40 @@ describe('Config Actions', () => {
     expect(useAppStore.getState().isMeterRunning).toBe(true);
   });
 
+  test('updateAudioConfig applies VAD preset timings and restarts the mic test', async () => {
+    const config = createMockConfig();
+    useAppStore.setState({ config, isMeterRunning: true });
+
+    setMockInvokeHandler(() => undefined);
+
+    await useAppStore.getState().updateAudioConfig({ vad_preset: 'aggressive' });
+
+    expect(useAppStore.getState().config?.audio).toMatchObject({
+      vad_preset: 'aggressive',
+      vad_silence_ms: 600,
+      vad_min_speech_ms: 150,
+    });
+    expect(invoke).toHaveBeenNthCalledWith(1, 'stop_mic_test');
+    expect(invoke).toHaveBeenNthCalledWith(3, 'start_mic_test');
+  });
+
+  test('updateAudioConfig switches to the custom VAD preset when a timing is edited', async () => {
+    const config = createMockConfig();
+    useAppStore.setState({
+      config: { ...config, audio: { ...config.audio, vad_preset: 'relaxed' as const } },
+    });
+
+    setMockInvokeHandler(() => undefined);
+
+    await useAppStore.getState().updateAudioConfig({ vad_silence_ms: 900 });
+
+    expect(useAppStore.getState().config?.audio).toMatchObject({
+      vad_preset: 'custom',
+      vad_silence_ms: 900,
+    });
+    expect(invoke).not.toHaveBeenCalledWith('stop_mic_test');
+  });
+
   test('updateAudioConfig does nothing without config', async () => {
     await useAppStore.getState().updateAudioConfig({ sample_rate: 48000 });
 
@@ -474,6 +577,59 @@ describe('Config Actions', () => {
     expect(invoke).toHaveBeenCalledWith('update_config', expect.anything());
   });
 
+  test('updateNetworkConfig adds the network section when missing', async () => {
+    useAppStore.setState({ config: createMockConfig() });
+
+    setMockInvokeHandler(() => undefined);
+
+    await useAppStore.getState().updateNetworkConfig({ offline_mode: true });
+
+    expect(useAppStore.getState().config?.network?.offline_mode).toBe(true);
+    expect(invoke).toHaveBeenCalledWith('update_config', expect.anything());
+  });
+
+  test('updateCompanionConfig adds the companion section with the default port', async () => {
+    useAppStore.setState({ config: createMockConfig() });
+
+    setMockInvokeHandler(() => undefined);
+
+    await useAppStore.getState().updateCompanionConfig({ enabled: true });
+
+    expect(useAppStore.getState().config?.companion).toEqual({ enabled: true, port: 47830 });
+    expect(invoke).toHaveBeenCalledWith('update_config', expect.anything());
+  });
+
+  test('updateCrashReportingConfig opts in without an endpoint by default', async () => {
+    useAppStore.setState({ config: createMockConfig() });
+
+    setMockInvokeHandler(() => undefined);
+
+    await useAppStore.getState().updateCrashReportingConfig({ upload_enabled: true });
+
+    expect(useAppStore.getState().config?.crash_reporting).toEqual({
+      upload_enabled: true,
+      endpoint: '',
+    });
+  });
+
+  test('updatePowerConfig enables the low-power profile', async () => {
+    useAppStore.setState({ config: createMockConfig() });
+
+    setMockInvokeHandler(() => undefined);
+
+    await useAppStore.getState().updatePowerConfig({ low_power_on_battery: true });
+
+    expect(useAppStore.getState().config?.power).toEqual({ low_power_on_battery: true });
+    expect(invoke).toHaveBeenCalledWith('update_config', expect.anything());
+  });
+
+  test('deleteCrashReports deletes every report when no ids are given', async () => {
+    setMockInvokeHandler((cmd) => (cmd === 'delete_crash_reports' ? 2 : undefined));
+
+    expect(await useAppStore.getState().deleteCrashReports()).toBe(2);
+    expect(invoke).toHaveBeenCalledWith('delete_crash_reports', { ids: null });
+  });
+
   test('setReplacementRules updates config replacements', async () => {
     const config = createMockConfig();
     useAppStore.setState({ config });
@@ -566,6 +722,28 @@ describe('Model Actions', () => {
     expect(useAppStore.getState().modelStatus).toEqual(status);
   });
 
+  test('compareModels forwards audio source and model ids', async () => {
+    const comparison = {
+      audio_duration_ms: 4000,
+      baseline_model_id: 'parakeet-tdt-0.6b-v3',
+      results: [],
+    };
+    setMockInvokeHandler((cmd) => {
+      if (cmd === 'compare_models') return comparison;
+      return undefined;
+    });
+
+    await expect(
+      useAppStore
+        .getState()
+        .compareModels({ sessionId: 'session-1' }, ['parakeet-tdt-0.6b-v3', 'openai/whisper-small'])
+    ).resolves.toEqual(comparison);
+    expect(invoke).toHaveBeenCalledWith('compare_models', {
+      sessionId: 'session-1',
+      modelIds: ['parakeet-tdt-0.6b-v3', 'openai/whisper-small'],
+    });
+  });
+
   test('restartSidecar invokes restart command', async () => {
     setMockInvokeHandler((cmd) => {
       if (cmd === 'restart_sidecar') return undefined;
@@ -652,6 +830,14 @@ describe('History Actions', () => {
     expect(invoke).toHaveBeenCalledWith('copy_transcript', { id: 'transcript-123' });
   });
 
+  test('reinjectTranscript targets the original window of an entry', async () => {
+    setMockInvokeHandler(() => undefined);
+
+    await useAppStore.getState().reinjectTranscript('entry-1');
+
+    expect(invoke).toHaveBeenCalledWith('reinject_to_original_target', { entryId: 'entry-1' });
+  });
+
   test('copyLastTranscript invokes copy last command', async () => {
     setMockInvokeHandler(() => undefined);
 
@@ -672,6 +858,76 @@ describe('History Actions', () => {
     expect(invoke).toHaveBeenCalledWith('clear_history');
     expect(useAppStore.getState().history).toEqual([]);
   });
+
+  test('clearHistoryFiltered returns the removed count and refreshes history', async () => {
+    const remaining = [{ ...createMockTranscript({ id: '2' }), pinned: true }];
+    useAppStore.setState({
+      history: [createMockTranscript({ id: '1' }), ...remaining],
+    });
+
+    setMockInvokeHandler((cmd) => {
+      if (cmd === 'clear_history_filtered') return 1;
+      if (cmd === 'get_transcript_history') return remaining;
+      return undefined;
+    });
+
+    const filter = { older_than_days: 30, unpinned_only: true };
+    const removed = await useAppStore.getState().clearHistoryFiltered(filter);
+
+    expect(removed).toBe(1);
+    expect(invoke).toHaveBeenCalledWith('clear_history_filtered', { filter });
+    expect(useAppStore.getState().history.map((entry) => entry.id)).toEqual(['2']);
+  });
+
+  test('setTranscriptPinned updates the entry in place', async () => {
+    useAppStore.setState({ history: [createMockTranscript({ id: '1' })] });
+    setMockInvokeHandler(() => undefined);
+
+    await useAppStore.getState().setTranscriptPinned('1', true);
+
+    expect(invoke).toHaveBeenCalledWith('set_transcript_pinned', { entryId: '1', pinned: true });
+    expect(useAppStore.getState().history[0].pinned).toBe(true);
+  });
+
+  test('getTranscriptDiff returns attributed spans for an entry', async () => {
+    const diff = {
+      entry_id: 'entry-1',
+      raw_text: 'be right back',
+      final_text: 'brb',
+      spans: [
+        { kind: 'replace', raw: 'be right back', final: 'brb', stage: 'replacement', rule_id: 'rule-1' },
+      ],
+    };
+    setMockInvokeHandler((cmd) => (cmd === 'get_transcript_diff' ? diff : undefined));
+
+    await expect(useAppStore.getState().getTranscriptDiff('entry-1')).resolves.toEqual(diff);
+    expect(invoke).toHaveBeenCalledWith('get_transcript_diff', { entryId: 'entry-1' });
+  });
+
+  test('getLowConfidenceWords forwards the threshold', async () => {
+    setMockInvokeHandler((cmd) => (cmd === 'get_low_confidence_words' ? [1, 3] : undefined));
+
+    await expect(useAppStore.getState().getLowConfidenceWords('entry-1', 0.5)).resolves.toEqual([
+      1, 3,
+    ]);
+    expect(invoke).toHaveBeenCalledWith('get_low_confidence_words', {
+      entryId: 'entry-1',
+      threshold: 0.5,
+    });
+  });
+
+  test('getAppIcon resolves the icon and falls back to null on failure', async () => {
+    const icon = { app_id: 'code', data_url: 'data:image/png;base64,AAAA' };
+    setMockInvokeHandler((cmd) => (cmd === 'get_app_icon' ? icon : undefined));
+
+    await expect(useAppStore.getState().getAppIcon('code')).resolves.toEqual(icon);
+    expect(invoke).toHaveBeenCalledWith('get_app_icon', { appId: 'code' });
+
+    setMockInvokeHandler(() => {
+      throw new Error('boom');
+    });
+    await expect(useAppStore.getState().getAppIcon('code')).resolves.toBeNull();
+  });
 });
 
 // ============================================================================
@@ -700,6 +956,66 @@ describe('Enabled Toggle', () => {
     expect(invoke).toHaveBeenCalledWith('set_enabled', { enabled: false });
     expect(useAppStore.getState().enabled).toBe(false);
   });
+
+  test('setEnableLevel pauses hotkeys only', async () => {
+    setMockInvokeHandler(() => undefined);
+
+    await useAppStore.getState().setEnableLevel('hotkeys_paused');
+
+    expect(invoke).toHaveBeenCalledWith('set_enable_level', { level: 'hotkeys_paused' });
+    expect(useAppStore.getState().enableLevel).toBe('hotkeys_paused');
+    expect(useAppStore.getState().enabled).toBe(false);
+  });
+
+  test('_setAppState derives the level from legacy payloads', () => {
+    useAppStore.getState()._setAppState({ state: 'idle', enabled: false });
+    expect(useAppStore.getState().enableLevel).toBe('fully_paused');
+
+    useAppStore.getState()._setAppState({
+      state: 'idle',
+      enabled: true,
+      level: 'stealth',
+      timestamp: '2026-01-01T00:02:00.000Z',
+    });
+    expect(useAppStore.getState().enableLevel).toBe('stealth');
+  });
+});
+
+// ============================================================================
+// COMMAND PALETTE TESTS
+// ============================================================================
+
+describe('Command Palette', () => {
+  test('listActions returns backend actions', async () => {
+    const actions = [
+      {
+        id: 'recording.stop',
+        name: 'Stop recording',
+        description: 'Stop the current recording and transcribe it.',
+        args: [],
+        available: false,
+        unavailable_reason: 'not_recording',
+      },
+    ];
+    setMockInvokeHandler((cmd) => (cmd === 'list_actions' ? actions : undefined));
+
+    await expect(useAppStore.getState().listActions()).resolves.toEqual(actions);
+  });
+
+  test('invokeAction forwards id and args', async () => {
+    setMockInvokeHandler((cmd) => (cmd === 'invoke_action' ? 'ask' : undefined));
+
+    await expect(
+      useAppStore.getState().invokeAction('injection.set_mode', { mode: 'ask' })
+    ).resolves.toBe('ask');
+    expect(invoke).toHaveBeenCalledWith('invoke_action', {
+      id: 'injection.set_mode',
+      args: { mode: 'ask' },
+    });
+
+    await useAppStore.getState().invokeAction('recording.start');
+    expect(invoke).toHaveBeenCalledWith('invoke_action', { id: 'recording.start', args: null });
+  });
 });
 
 // ============================================================================
@@ -734,6 +1050,24 @@ describe('Async Action Coverage', () => {
     expect(useAppStore.getState().hotkeyStatus).toEqual(status);
   });
 
+  test('practiceHotkey returns the practice report', async () => {
+    const report = {
+      hotkey: 'Ctrl+Shift+Space',
+      mode: 'hold',
+      passed: true,
+      presses: 1,
+      releases: 1,
+      hold_ms: 820,
+      latency_ms: 12,
+    };
+    setMockInvokeHandler((cmd) => {
+      if (cmd === 'practice_hotkey') return report;
+      return undefined;
+    });
+
+    expect(await useAppStore.getState().practiceHotkey()).toEqual(report);
+  });
+
   test('loadPresets fetches available presets', async () => {
     const presets = [{ id: 'default', name: 'Default' }];
     setMockInvokeHandler((cmd) => {
@@ -822,8 +1156,45 @@ describe('Async Action Coverage', () => {
     expect(diagnostics).toEqual(report);
   });
 
+  test('getUsageStats returns usage stats from backend', async () => {
+    const totals = { transcripts: 2, words: 42, audio_ms: 90_000 };
+    const stats = {
+      today: totals,
+      days: [{ date: '2026-03-10', ...totals }],
+      all_time: totals,
+    };
+    setMockInvokeHandler((cmd) => {
+      if (cmd === 'get_usage_stats') return stats;
+      return undefined;
+    });
+
+    await expect(useAppStore.getState().getUsageStats()).resolves.toEqual(stats);
+  });
+
+  test('getHistoryStats returns history stats from backend', async () => {
+    const stats = {
+      entries: 3,
+      pinned: 1,
+      max_entries: 100,
+      persistence: 'disk_encrypted',
+      storage_bytes: 2048,
+      memory_bytes: 1900,
+      oldest_at: '2026-03-01T09:00:00Z',
+      newest_at: '2026-03-10T17:30:00Z',
+      last_pruned_at: null,
+    };
+    setMockInvokeHandler((cmd) => {
+      if (cmd === 'get_history_stats') return stats;
+      return undefined;
+    });
+
+    await expect(useAppStore.getState().getHistoryStats()).resolves.toEqual(stats);
+  });
+
   test('getRecentLogs uses default count and returns logs', async () => {
-    const logs = ['line1', 'line2'];
+    const logs = [
+      { timestamp: '2026-02-18T00:00:00Z', level: 'INFO', target: 'app::test', message: 'line1' },
+    ];
     setMockInvokeHandler((cmd, args) => {
       if (cmd === 'get_recent_logs') {
         expect(args).toEqual({ count: 100 });
@@ -835,6 +1206,58 @@ describe('Async Action Coverage', () => {
     const result = await useAppStore.getState().getRecentLogs();
     expect(result).toEqual(logs);
   });
+
+  test('getRecentLogs passes filters', async () => {
+    setMockInvokeHandler(() => []);
+
+    await useAppStore.getState().getRecentLogs(50, { level: 'warn', module: 'sidecar' });
+
+    expect(invoke).toHaveBeenCalledWith('get_recent_logs', {
+      count: 50,
+      level: 'warn',
+      module: 'sidecar',
+    });
+  });
+
+  test('getCommandAuditLog uses default count and returns entries', async () => {
+    const entries = [
+      {
+        id: 7,
+        command: 'download_model',
+        window: 'main',
+        args: 'modelId="parakeet-tdt-0.6b-v2"',
+        started_at: '2026-02-18T00:00:00Z',
+        duration_ms: 12,
+        status: 'error',
+        error: 'Download failed',
+      },
+    ];
+    setMockInvokeHandler((cmd, args) => {
+      if (cmd === 'get_command_audit_log') {
+        expect(args).toEqual({ count: 200 });
+        return entries;
+      }
+      return undefined;
+    });
+
+    const result = await useAppStore.getState().getCommandAuditLog();
+    expect(result).toEqual(entries);
+  });
+
+  test('exportAppStateSnapshot and replayAppStateSnapshot call Tauri', async () => {
+    const path = '/Users/test/Downloads/openvoicy-state-20260218-000000.json';
+    setMockInvokeHandler((cmd, args) => {
+      if (cmd === 'export_app_state_snapshot') return path;
+      if (cmd === 'replay_app_state_snapshot') {
+        expect(args).toEqual({ path });
+        return 42;
+      }
+      return undefined;
+    });
+
+    await expect(useAppStore.getState().exportAppStateSnapshot()).resolves.toBe(path);
+    await expect(useAppStore.getState().replayAppStateSnapshot(path)).resolves.toBe(42);
+  });
 });
 
 // ============================================================================
@@ -933,6 +1356,34 @@ describe('Internal Actions', () => {
     expect(useAppStore.getState().downloadProgress).toBeNull();
   });
 
+  test('_setModelStatus keeps loading stages only for the latest loading event', () => {
+    useAppStore.getState()._setModelStatus({
+      status: 'loading',
+      model_id: 'openai/whisper-small',
+      stage: 'compile',
+      percent: 40,
+    });
+    expect(useAppStore.getState().modelStatus).toEqual({
+      status: 'loading',
+      model_id: 'openai/whisper-small',
+      stage: 'compile',
+      percent: 40,
+    });
+
+    useAppStore.getState()._setModelStatus({ status: 'loading', model_id: 'openai/whisper-small' });
+    expect(useAppStore.getState().modelStatus?.stage).toBeUndefined();
+
+    useAppStore.getState()._setModelStatus({
+      status: 'ready',
+      model_id: 'openai/whisper-small',
+      stage: 'warmup',
+    });
+    expect(useAppStore.getState().modelStatus).toEqual({
+      status: 'ready',
+      model_id: 'openai/whisper-small',
+    });
+  });
+
   test('_setModelStatus ignores events for non-configured model', () => {
     // Set up: configured model A is ready
     useAppStore.setState({
@@ -1191,6 +1642,50 @@ describe('Internal Actions', () => {
     expect(useAppStore.getState().appState).toBe('recording');
   });
 
+  test('_applySessionSnapshot ignores repeated and older revisions', () => {
+    const store = useAppStore.getState();
+    store._applySessionSnapshot(createSessionSnapshot({ phase: 'transcribing', revision: 3 }));
+    store._applySessionSnapshot(createSessionSnapshot({ phase: 'recording', revision: 2 }));
+    store._applySessionSnapshot(createSessionSnapshot({ phase: 'failed', revision: 3 }));
+
+    expect(useAppStore.getState().activeSession).toMatchObject({
+      phase: 'transcribing',
+      revision: 3,
+    });
+
+    store._applySessionSnapshot(
+      createSessionSnapshot({ phase: 'completed', revision: 4, text: 'hello' })
+    );
+    expect(useAppStore.getState().activeSession).toMatchObject({
+      phase: 'completed',
+      text: 'hello',
+    });
+  });
+
+  test('_applySessionSnapshot switches to newer sessions only', () => {
+    const store = useAppStore.getState();
+    store._applySessionSnapshot(
+      createSessionSnapshot({ session_id: 'session-2', created_at: '2026-01-01T00:05:00Z' })
+    );
+    store._applySessionSnapshot(
+      createSessionSnapshot({ session_id: 'session-1', phase: 'completed', revision: 5 })
+    );
+    expect(useAppStore.getState().activeSession?.session_id).toBe('session-2');
+
+    store._applySessionSnapshot(
+      createSessionSnapshot({
+        session_id: 'session-3',
+        phase: 'created',
+        revision: 1,
+        created_at: '2026-01-01T00:10:00Z',
+      })
+    );
+    expect(useAppStore.getState().activeSession).toMatchObject({
+      session_id: 'session-3',
+      phase: 'created',
+    });
+  });
+
   test('_setSidecarStatus updates sidecar status slice', () => {
     useAppStore.getState()._setSidecarStatus({
       state: 'ready',
@@ -1319,6 +1814,25 @@ describe('Initialization', () => {
     expect(useAppStore.getState().devices).toEqual(mockDevices);
   });
 
+  test('initialize restores the active session snapshot', async () => {
+    const session = createSessionSnapshot({ phase: 'transcribing', revision: 3, audio_ms: 2400 });
+    setMockInvokeHandler((cmd) => {
+      switch (cmd) {
+        case 'get_app_state':
+          return { state: 'transcribing', enabled: true };
+        case 'get_active_session':
+          return session;
+        default:
+          return undefined;
+      }
+    });
+
+    await useAppStore.getState().initialize();
+
+    expect(useAppStore.getState().appState).toBe('transcribing');
+    expect(useAppStore.getState().activeSession).toEqual(session);
+  });
+
   test('initialize only runs once', async () => {
     useAppStore.setState({ isInitialized: true });
 
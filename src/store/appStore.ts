@@ -8,6 +8,7 @@
 import { create } from 'zustand';
 import { invoke } from '@tauri-apps/api/core';
 import type {
+  ActionInfo,
   AppState,
   AppError,
   AppConfig,
@@ -15,25 +16,49 @@ import type {
   AudioDevice,
   AudioLevelEvent,
   Capabilities,
+  EnableLevel,
+  CommandError,
+  CueStatusEvent,
   ErrorEvent,
+  HistoryClearFilter,
   HotkeyConfig,
+  HotkeyPracticeReport,
   HotkeyStatus,
   InjectionConfig,
+  InputGain,
+  NetworkConfig,
+  CompanionConfig,
+  CrashReport,
+  CrashReportingConfig,
+  PowerConfig,
   UiConfig,
+  ModelComparison,
+  ModelComparisonAudio,
   ModelStatus,
   ModelState,
   ModelStatusPayload,
   PresetInfo,
   Progress,
   RecordingStatusEvent,
+  SessionSnapshot,
   ReplacementRule,
+  ReplacementRuleIssue,
   DiagnosticsReport,
+  LogEntry,
+  LogFilter,
+  CommandAuditEntry,
   SidecarStatusEvent,
   SelfCheckResult,
   StateEventPayload,
   TranscriptErrorEvent,
+  TranscriptDiff,
   TranscriptEntry,
+  UsageStats,
+  HistoryStats,
+  AppIcon,
+  VadPreviewEvent,
 } from '../types';
+import { VAD_PRESET_TIMINGS } from '../types';
 
 // ============================================================================
 // STORE STATE INTERFACE
@@ -43,6 +68,7 @@ export interface AppStoreState {
   // Application state
   appState: AppState;
   enabled: boolean;
+  enableLevel: EnableLevel;
   errorDetail?: string;
   stateTimestamp?: string;
   errorRecoveryActions: string[];
@@ -55,11 +81,14 @@ export interface AppStoreState {
   devices: AudioDevice[];
   selectedDeviceUid: string | null;
   audioLevel: AudioLevelEvent | null;
+  vadPreview: VadPreviewEvent | null;
   isMeterRunning: boolean;
 
   // Transcript history
   history: TranscriptEntry[];
   recordingStatus: RecordingStatusEvent | null;
+  activeSession: SessionSnapshot | null;
+  cueStatus: CueStatusEvent | null;
   sidecarStatus: SidecarStatusEvent | null;
   sidecarRecoveryNeeded: boolean;
   lastTranscriptError: TranscriptErrorEvent | null;
@@ -95,6 +124,7 @@ export interface AppStoreActions {
   // Device actions
   refreshDevices: () => Promise<void>;
   selectDevice: (uid: string | null) => Promise<void>;
+  setInputGain: (deviceUid: string | null, gainDb: number) => Promise<void>;
   startMicTest: () => Promise<void>;
   stopMicTest: () => Promise<void>;
   startRecording: () => Promise<void>;
@@ -107,23 +137,35 @@ export interface AppStoreActions {
   updateHotkeyConfig: (config: Partial<HotkeyConfig>) => Promise<void>;
   updateInjectionConfig: (config: Partial<InjectionConfig>) => Promise<void>;
   updateUiConfig: (config: Partial<UiConfig>) => Promise<void>;
-  setReplacementRules: (rules: ReplacementRule[]) => Promise<void>;
+  updateNetworkConfig: (config: Partial<NetworkConfig>) => Promise<void>;
+  updateCompanionConfig: (config: Partial<CompanionConfig>) => Promise<void>;
+  updateCrashReportingConfig: (config: Partial<CrashReportingConfig>) => Promise<void>;
+  updatePowerConfig: (config: Partial<PowerConfig>) => Promise<void>;
+  setReplacementRules: (rules: ReplacementRule[]) => Promise<ReplacementRuleIssue[]>;
   resetConfig: () => Promise<void>;
 
   // Model actions
   refreshModelStatus: () => Promise<void>;
   downloadModel: () => Promise<void>;
   purgeModelCache: (modelId?: string) => Promise<void>;
+  compareModels: (audio: ModelComparisonAudio, modelIds: string[]) => Promise<ModelComparison>;
 
   // History actions
   refreshHistory: () => Promise<void>;
   copyTranscript: (id: string) => Promise<void>;
+  reinjectTranscript: (entryId: string) => Promise<void>;
   copyLastTranscript: () => Promise<void>;
   clearHistory: () => Promise<void>;
+  clearHistoryFiltered: (filter: HistoryClearFilter) => Promise<number>;
+  setTranscriptPinned: (entryId: string, pinned: boolean) => Promise<void>;
+  getTranscriptDiff: (entryId: string) => Promise<TranscriptDiff>;
+  getLowConfidenceWords: (entryId: string, threshold?: number) => Promise<number[]>;
+  getAppIcon: (appId: string) => Promise<AppIcon | null>;
 
   // Hotkey actions
   refreshHotkeyStatus: () => Promise<void>;
   setHotkey: (primary: string, copyLast: string) => Promise<void>;
+  practiceHotkey: () => Promise<HotkeyPracticeReport>;
 
   // Preset actions
   loadPresets: () => Promise<void>;
@@ -137,20 +179,35 @@ export interface AppStoreActions {
 
   // Diagnostics
   generateDiagnostics: () => Promise<DiagnosticsReport>;
-  getRecentLogs: (count?: number) => Promise<string[]>;
+  getUsageStats: () => Promise<UsageStats>;
+  getHistoryStats: () => Promise<HistoryStats>;
+  getRecentLogs: (count?: number, filter?: LogFilter) => Promise<LogEntry[]>;
+  getCommandAuditLog: (count?: number) => Promise<CommandAuditEntry[]>;
+  exportAppStateSnapshot: () => Promise<string>;
+  replayAppStateSnapshot: (path: string) => Promise<number>;
+  getPendingCrashReports: () => Promise<CrashReport[]>;
+  deleteCrashReports: (ids?: string[]) => Promise<number>;
   restartSidecar: () => Promise<void>;
 
   // Toggle enabled
   toggleEnabled: () => Promise<void>;
   setEnabled: (enabled: boolean) => Promise<void>;
+  setEnableLevel: (level: EnableLevel) => Promise<void>;
+
+  // Command palette
+  listActions: () => Promise<ActionInfo[]>;
+  invokeAction: (id: string, args?: Record<string, string>) => Promise<unknown>;
 
   // Internal actions (called by event handlers)
   _setAppState: (event: StateEventPayload) => void;
   _setModelStatus: (status: ModelStatusPayload) => void;
   _setDownloadProgress: (progress: Progress | null) => void;
   _setAudioLevel: (level: AudioLevelEvent | null) => void;
+  _setVadPreview: (preview: VadPreviewEvent | null) => void;
   _addHistoryEntry: (entry: TranscriptEntry) => void;
   _setRecordingStatus: (status: RecordingStatusEvent) => void;
+  _applySessionSnapshot: (snapshot: SessionSnapshot) => void;
+  _setCueStatus: (status: CueStatusEvent) => void;
   _setSidecarStatus: (status: SidecarStatusEvent) => void;
   _setTranscriptError: (payload: TranscriptErrorEvent) => void;
   _setError: (payload: string | ErrorEvent | TranscriptErrorEvent) => void;
@@ -169,6 +226,7 @@ export type AppStore = AppStoreState & AppStoreActions;
 const defaultState: AppStoreState = {
   appState: 'idle',
   enabled: true,
+  enableLevel: 'enabled',
   errorDetail: undefined,
   stateTimestamp: undefined,
   errorRecoveryActions: [],
@@ -177,9 +235,12 @@ const defaultState: AppStoreState = {
   devices: [],
   selectedDeviceUid: null,
   audioLevel: null,
+  vadPreview: null,
   isMeterRunning: false,
   history: [],
   recordingStatus: null,
+  activeSession: null,
+  cueStatus: null,
   sidecarStatus: null,
   sidecarRecoveryNeeded: false,
   lastTranscriptError: null,
@@ -192,6 +253,23 @@ const defaultState: AppStoreState = {
   isLoading: false,
 };
 
+/**
+ * Merge an audio config update, keeping the VAD preset and timings consistent:
+ * choosing a named preset applies its timings, and editing a timing directly
+ * switches to 'custom'.
+ */
+function withVadPresetTimings(current: AudioConfig, update: Partial<AudioConfig>): AudioConfig {
+  const merged = { ...current, ...update };
+  if (update.vad_preset !== undefined) {
+    if (update.vad_preset !== 'custom') {
+      Object.assign(merged, VAD_PRESET_TIMINGS[update.vad_preset]);
+    }
+  } else if (update.vad_silence_ms !== undefined || update.vad_min_speech_ms !== undefined) {
+    merged.vad_preset = 'custom';
+  }
+  return merged;
+}
+
 function stateDetailFromPayload(payload: StateEventPayload): string | undefined {
   if ('detail' in payload && typeof payload.detail === 'string' && payload.detail.length > 0) {
     return payload.detail;
@@ -206,6 +284,14 @@ function stateDetailFromPayload(payload: StateEventPayload): string | undefined
   return undefined;
 }
 
+/** Older backends send only `enabled`, which meant a full pause when false. */
+function enableLevelFromPayload(payload: StateEventPayload): EnableLevel {
+  if ('level' in payload && payload.level) {
+    return payload.level;
+  }
+  return payload.enabled ? 'enabled' : 'fully_paused';
+}
+
 function normalizeModelStatusPayload(
   payload: ModelStatusPayload,
   current: ModelStatus | null
@@ -265,6 +351,14 @@ function normalizeModelStatusPayload(
     next.progress = current.progress;
   }
 
+  // Stages describe the latest loading step only, so they are never carried over.
+  if (next.status === 'loading' && 'stage' in payload && typeof payload.stage === 'string') {
+    next.stage = payload.stage;
+    if (typeof payload.percent === 'number') {
+      next.percent = payload.percent;
+    }
+  }
+
   if ('error' in payload && typeof payload.error === 'string' && payload.error.length > 0) {
     next.error = payload.error;
   } else if (payload.status !== 'error' && current?.error) {
@@ -300,6 +394,21 @@ function messageFromAppError(error: AppError): string {
     : 'Unknown error';
 }
 
+function isCommandError(error: unknown): error is CommandError {
+  return typeof error === 'object'
+    && error !== null
+    && typeof (error as CommandError).code === 'string'
+    && typeof (error as CommandError).message === 'string';
+}
+
+/** Detail text for a rejected command, preferring the structured app error. */
+function commandErrorDetail(error: unknown): string {
+  if (isCommandError(error)) {
+    return error.error ? messageFromAppError(error.error) : error.message;
+  }
+  return error instanceof Error ? error.message : String(error);
+}
+
 function normalizeErrorMessage(payload: string | ErrorEvent | TranscriptErrorEvent): string {
   if (typeof payload === 'string') {
     return payload;
@@ -335,6 +444,20 @@ function deriveRecoveryActions(
   return ['Retry the operation'];
 }
 
+/**
+ * Whether `next` is already reflected by `current`: a repeated or older
+ * revision of the same session, or a session created before the current one.
+ */
+function isStaleSessionSnapshot(current: SessionSnapshot | null, next: SessionSnapshot): boolean {
+  if (!current) {
+    return false;
+  }
+  if (current.session_id === next.session_id) {
+    return next.revision <= current.revision;
+  }
+  return Date.parse(next.created_at) < Date.parse(current.created_at);
+}
+
 function appStateFromRecordingPhase(phase: string): AppState | null {
   if (phase === 'recording' || phase === 'transcribing' || phase === 'idle') {
     return phase;
@@ -378,9 +501,16 @@ export const useAppStore = create<AppStore>((set, get) => ({
       set({
         appState: stateEvent.state,
         enabled: stateEvent.enabled,
+        enableLevel: enableLevelFromPayload(stateEvent),
         errorDetail: stateDetailFromPayload(stateEvent),
       });
 
+      // Restore the session a previous window instance was showing.
+      const session = await invoke<SessionSnapshot | null>('get_active_session');
+      if (session) {
+        get()._applySessionSnapshot(session);
+      }
+
       set({ isInitialized: true });
     } catch (error) {
       console.error('Failed to initialize app store:', error);
@@ -428,10 +558,36 @@ export const useAppStore = create<AppStore>((set, get) => ({
     }
   },
 
+  setInputGain: async (deviceUid, gainDb) => {
+    try {
+      const result = await invoke<InputGain>('set_input_gain', { deviceUid, gainDb });
+
+      // Update local config
+      const config = get().config;
+      if (config && result.device_uid) {
+        const inputGains = { ...config.audio.input_gains };
+        if (result.gain_db === 0) {
+          delete inputGains[result.device_uid];
+        } else {
+          inputGains[result.device_uid] = result.gain_db;
+        }
+        set({
+          config: {
+            ...config,
+            audio: { ...config.audio, input_gains: inputGains },
+          },
+        });
+      }
+    } catch (error) {
+      console.error('Failed to set input gain:', error);
+      throw error;
+    }
+  },
+
   startMicTest: async () => {
     try {
       await invoke('start_mic_test');
-      set({ isMeterRunning: true, audioLevel: null });
+      set({ isMeterRunning: true, audioLevel: null, vadPreview: null });
     } catch (error) {
       console.error('Failed to start mic test:', error);
       throw error;
@@ -441,7 +597,7 @@ export const useAppStore = create<AppStore>((set, get) => ({
   stopMicTest: async () => {
     try {
       await invoke('stop_mic_test');
-      set({ isMeterRunning: false, audioLevel: null });
+      set({ isMeterRunning: false, audioLevel: null, vadPreview: null });
     } catch (error) {
       console.error('Failed to stop mic test:', error);
       throw error;
@@ -455,7 +611,7 @@ export const useAppStore = create<AppStore>((set, get) => ({
       console.error('Failed to start recording:', error);
       set({
         appState: 'error',
-        errorDetail: error instanceof Error ? error.message : String(error),
+        errorDetail: commandErrorDetail(error),
       });
       throw error;
     }
@@ -468,7 +624,7 @@ export const useAppStore = create<AppStore>((set, get) => ({
       console.error('Failed to stop recording:', error);
       set({
         appState: 'error',
-        errorDetail: error instanceof Error ? error.message : String(error),
+        errorDetail: commandErrorDetail(error),
       });
       throw error;
     }
@@ -481,7 +637,7 @@ export const useAppStore = create<AppStore>((set, get) => ({
       console.error('Failed to cancel recording:', error);
       set({
         appState: 'error',
-        errorDetail: error instanceof Error ? error.message : String(error),
+        errorDetail: commandErrorDetail(error),
       });
       throw error;
     }
@@ -509,20 +665,23 @@ export const useAppStore = create<AppStore>((set, get) => ({
 
     const isDeviceChange = Object.prototype.hasOwnProperty.call(audioConfig, 'device_uid')
       && audioConfig.device_uid !== config.audio.device_uid;
-    const shouldRestartMeter = isDeviceChange && get().isMeterRunning;
 
     const newConfig = {
       ...config,
-      audio: { ...config.audio, ...audioConfig },
+      audio: withVadPresetTimings(config.audio, audioConfig),
     };
+    // The mic test previews VAD timings captured when it started.
+    const isVadTimingChange = newConfig.audio.vad_silence_ms !== config.audio.vad_silence_ms
+      || newConfig.audio.vad_min_speech_ms !== config.audio.vad_min_speech_ms;
+    const shouldRestartMeter = (isDeviceChange || isVadTimingChange) && get().isMeterRunning;
 
-    let meterStoppedForDeviceSwitch = false;
+    let meterStoppedForRestart = false;
     let configPersisted = false;
 
     try {
       if (shouldRestartMeter) {
         await get().stopMicTest();
-        meterStoppedForDeviceSwitch = true;
+        meterStoppedForRestart = true;
       }
 
       await invoke('update_config', { config: newConfig });
@@ -532,12 +691,12 @@ export const useAppStore = create<AppStore>((set, get) => ({
         selectedDeviceUid: newConfig.audio.device_uid ?? null,
       });
 
-      if (meterStoppedForDeviceSwitch) {
+      if (meterStoppedForRestart) {
         await get().startMicTest();
       }
     } catch (error) {
       console.error('Failed to update audio config:', error);
-      if (meterStoppedForDeviceSwitch && !configPersisted) {
+      if (meterStoppedForRestart && !configPersisted) {
         try {
           await get().startMicTest();
         } catch (restartError) {
@@ -603,13 +762,97 @@ export const useAppStore = create<AppStore>((set, get) => ({
     }
   },
 
+  updateNetworkConfig: async (networkConfig) => {
+    const config = get().config;
+    if (!config) return;
+
+    const newConfig = {
+      ...config,
+      network: { offline_mode: false, ...config.network, ...networkConfig },
+    };
+
+    try {
+      await invoke('update_config', { config: newConfig });
+      set({ config: newConfig });
+    } catch (error) {
+      console.error('Failed to update network config:', error);
+      throw error;
+    }
+  },
+
+  updateCompanionConfig: async (companionConfig) => {
+    const config = get().config;
+    if (!config) return;
+
+    const newConfig = {
+      ...config,
+      companion: { enabled: false, port: 47830, ...config.companion, ...companionConfig },
+    };
+
+    try {
+      await invoke('update_config', { config: newConfig });
+      set({ config: newConfig });
+    } catch (error) {
+      console.error('Failed to update companion config:', error);
+      throw error;
+    }
+  },
+
+  updateCrashReportingConfig: async (crashReportingConfig) => {
+    const config = get().config;
+    if (!config) return;
+
+    const newConfig = {
+      ...config,
+      crash_reporting: {
+        upload_enabled: false,
+        endpoint: '',
+        ...config.crash_reporting,
+        ...crashReportingConfig,
+      },
+    };
+
+    try {
+      await invoke('update_config', { config: newConfig });
+      set({ config: newConfig });
+    } catch (error) {
+      console.error('Failed to update crash reporting config:', error);
+      throw error;
+    }
+  },
+
+  updatePowerConfig: async (powerConfig) => {
+    const config = get().config;
+    if (!config) return;
+
+    const newConfig = {
+      ...config,
+      power: {
+        low_power_on_battery: false,
+        ...config.power,
+        ...powerConfig,
+      },
+    };
+
+    try {
+      await invoke('update_config', { config: newConfig });
+      set({ config: newConfig });
+    } catch (error) {
+      console.error('Failed to update power config:', error);
+      throw error;
+    }
+  },
+
   setReplacementRules: async (rules) => {
     try {
-      await invoke('set_replacement_rules', { rules });
+      const issues = await invoke<ReplacementRuleIssue[] | null>('set_replacement_rules', {
+        rules,
+      });
       const config = get().config;
       if (config) {
         set({ config: { ...config, replacements: rules } });
       }
+      return issues ?? [];
     } catch (error) {
       console.error('Failed to set replacement rules:', error);
       throw error;
@@ -667,6 +910,15 @@ export const useAppStore = create<AppStore>((set, get) => ({
     }
   },
 
+  compareModels: async (audio, modelIds) => {
+    try {
+      return await invoke<ModelComparison>('compare_models', { ...audio, modelIds });
+    } catch (error) {
+      console.error('Failed to compare models:', error);
+      throw error;
+    }
+  },
+
   // --------------------------------------------------------------------------
   // HISTORY ACTIONS
   // --------------------------------------------------------------------------
@@ -689,6 +941,15 @@ export const useAppStore = create<AppStore>((set, get) => ({
     }
   },
 
+  reinjectTranscript: async (entryId) => {
+    try {
+      await invoke('reinject_to_original_target', { entryId });
+    } catch (error) {
+      console.error('Failed to re-inject transcript:', error);
+      throw new Error(commandErrorDetail(error));
+    }
+  },
+
   copyLastTranscript: async () => {
     try {
       await invoke('copy_last_transcript');
@@ -708,6 +969,64 @@ export const useAppStore = create<AppStore>((set, get) => ({
     }
   },
 
+  clearHistoryFiltered: async (filter) => {
+    try {
+      const removed = await invoke<number>('clear_history_filtered', { filter });
+      if (removed > 0) {
+        await get().refreshHistory();
+      }
+      return removed;
+    } catch (error) {
+      console.error('Failed to clear history:', error);
+      throw error;
+    }
+  },
+
+  setTranscriptPinned: async (entryId, pinned) => {
+    try {
+      await invoke('set_transcript_pinned', { entryId, pinned });
+      set((state) => ({
+        history: state.history.map((entry) =>
+          entry.id === entryId ? { ...entry, pinned } : entry
+        ),
+      }));
+    } catch (error) {
+      console.error('Failed to pin transcript:', error);
+      throw error;
+    }
+  },
+
+  getTranscriptDiff: async (entryId) => {
+    try {
+      return await invoke<TranscriptDiff>('get_transcript_diff', { entryId });
+    } catch (error) {
+      console.error('Failed to diff transcript:', error);
+      throw error;
+    }
+  },
+
+  getLowConfidenceWords: async (entryId, threshold) => {
+    try {
+      return await invoke<number[]>('get_low_confidence_words', {
+        entryId,
+        threshold: threshold ?? null,
+      });
+    } catch (error) {
+      console.error('Failed to load low-confidence words:', error);
+      throw error;
+    }
+  },
+
+  getAppIcon: async (appId) => {
+    try {
+      return await invoke<AppIcon | null>('get_app_icon', { appId });
+    } catch (error) {
+      // Icons are decorative; the entry still names the app.
+      console.error('Failed to get app icon:', error);
+      return null;
+    }
+  },
+
   // --------------------------------------------------------------------------
   // HOTKEY ACTIONS
   // --------------------------------------------------------------------------
@@ -731,6 +1050,15 @@ export const useAppStore = create<AppStore>((set, get) => ({
     }
   },
 
+  practiceHotkey: async () => {
+    try {
+      return await invoke<HotkeyPracticeReport>('practice_hotkey');
+    } catch (error) {
+      console.error('Failed to practice hotkey:', error);
+      throw error;
+    }
+  },
+
   // --------------------------------------------------------------------------
   // PRESET ACTIONS
   // --------------------------------------------------------------------------
@@ -795,9 +1123,27 @@ export const useAppStore = create<AppStore>((set, get) => ({
     }
   },
 
-  getRecentLogs: async (count = 100) => {
+  getUsageStats: async () => {
     try {
-      const logs = await invoke<string[]>('get_recent_logs', { count });
+      return await invoke<UsageStats>('get_usage_stats');
+    } catch (error) {
+      console.error('Failed to get usage stats:', error);
+      throw error;
+    }
+  },
+
+  getHistoryStats: async () => {
+    try {
+      return await invoke<HistoryStats>('get_history_stats');
+    } catch (error) {
+      console.error('Failed to get history stats:', error);
+      throw error;
+    }
+  },
+
+  getRecentLogs: async (count = 100, filter = {}) => {
+    try {
+      const logs = await invoke<LogEntry[]>('get_recent_logs', { count, ...filter });
       return logs;
     } catch (error) {
       console.error('Failed to get recent logs:', error);
@@ -805,6 +1151,51 @@ export const useAppStore = create<AppStore>((set, get) => ({
     }
   },
 
+  getCommandAuditLog: async (count = 200) => {
+    try {
+      return await invoke<CommandAuditEntry[]>('get_command_audit_log', { count });
+    } catch (error) {
+      console.error('Failed to get command audit log:', error);
+      throw error;
+    }
+  },
+
+  exportAppStateSnapshot: async () => {
+    try {
+      return await invoke<string>('export_app_state_snapshot');
+    } catch (error) {
+      console.error('Failed to export state snapshot:', error);
+      throw error;
+    }
+  },
+
+  replayAppStateSnapshot: async (path) => {
+    try {
+      return await invoke<number>('replay_app_state_snapshot', { path });
+    } catch (error) {
+      console.error('Failed to replay state snapshot:', error);
+      throw error;
+    }
+  },
+
+  getPendingCrashReports: async () => {
+    try {
+      return await invoke<CrashReport[]>('get_pending_crash_reports');
+    } catch (error) {
+      console.error('Failed to get crash reports:', error);
+      throw error;
+    }
+  },
+
+  deleteCrashReports: async (ids) => {
+    try {
+      return await invoke<number>('delete_crash_reports', { ids: ids ?? null });
+    } catch (error) {
+      console.error('Failed to delete crash reports:', error);
+      throw error;
+    }
+  },
+
   restartSidecar: async () => {
     try {
       await invoke('restart_sidecar');
@@ -838,6 +1229,38 @@ export const useAppStore = create<AppStore>((set, get) => ({
     }
   },
 
+  setEnableLevel: async (level) => {
+    try {
+      await invoke('set_enable_level', { level });
+      set({ enableLevel: level, enabled: level === 'enabled' || level === 'stealth' });
+    } catch (error) {
+      console.error('Failed to set enable level:', error);
+      throw error;
+    }
+  },
+
+  // --------------------------------------------------------------------------
+  // COMMAND PALETTE
+  // --------------------------------------------------------------------------
+
+  listActions: async () => {
+    try {
+      return await invoke<ActionInfo[]>('list_actions');
+    } catch (error) {
+      console.error('Failed to list actions:', error);
+      throw error;
+    }
+  },
+
+  invokeAction: async (id, args) => {
+    try {
+      return await invoke<unknown>('invoke_action', { id, args: args ?? null });
+    } catch (error) {
+      console.error(`Failed to invoke action ${id}:`, error);
+      throw error;
+    }
+  },
+
   // --------------------------------------------------------------------------
   // INTERNAL ACTIONS (called by event handlers)
   // --------------------------------------------------------------------------
@@ -846,6 +1269,7 @@ export const useAppStore = create<AppStore>((set, get) => ({
     set({
       appState: event.state,
       enabled: event.enabled,
+      enableLevel: enableLevelFromPayload(event),
       errorDetail: stateDetailFromPayload(event),
       stateTimestamp: 'timestamp' in event ? event.timestamp : undefined,
     });
@@ -908,6 +1332,10 @@ export const useAppStore = create<AppStore>((set, get) => ({
     set({ audioLevel: level });
   },
 
+  _setVadPreview: (preview) => {
+    set({ vadPreview: preview });
+  },
+
   _addHistoryEntry: (entry) => {
     const fallbackHistoryLimit = 100;
     const normalizedEntry = normalizeTranscriptEntry(entry);
@@ -930,6 +1358,16 @@ export const useAppStore = create<AppStore>((set, get) => ({
     });
   },
 
+  _applySessionSnapshot: (snapshot) => {
+    set((state) =>
+      isStaleSessionSnapshot(state.activeSession, snapshot) ? {} : { activeSession: snapshot }
+    );
+  },
+
+  _setCueStatus: (status) => {
+    set({ cueStatus: status });
+  },
+
   _setSidecarStatus: (status) => {
     set({
       sidecarStatus: status,
@@ -150,6 +150,12 @@ function installAppHandler(config: AppConfigLike, history: any[] = []) {
           sidecar: { status: 'ok', message: 'Connected and responsive' },
           model: { status: 'ok', message: 'Ready' },
         };
+      case 'get_usage_stats':
+        return {
+          today: { transcripts: 1, words: 3, audio_ms: 2400 },
+          days: [],
+          all_time: { transcripts: 1, words: 3, audio_ms: 2400 },
+        };
       case 'generate_diagnostics':
         return {
           version: '0.1.0',
@@ -212,6 +218,7 @@ describe('App diagnostics panels', () => {
 
     expect(screen.getByRole('tab', { name: 'Status' })).toBeDefined();
     expect(screen.getByRole('tab', { name: 'History' })).toBeDefined();
+    expect(screen.getByRole('tab', { name: 'Statistics' })).toBeDefined();
     expect(screen.getByRole('tab', { name: 'Replacements' })).toBeDefined();
     expect(screen.getByRole('tab', { name: 'Settings' })).toBeDefined();
     expect(screen.getAllByText('Ready').length).toBeGreaterThan(0);
@@ -268,6 +275,18 @@ describe('App diagnostics panels', () => {
     expect(screen.getAllByText('Sample transcript text.').length).toBeGreaterThan(0);
   });
 
+  it('loads usage stats when the statistics tab opens', async () => {
+    render(<App />);
+
+    fireEvent.click(await screen.findByRole('tab', { name: 'Statistics' }));
+
+    await waitFor(() => {
+      expect(invoke).toHaveBeenCalledWith('get_usage_stats');
+    });
+    expect(await screen.findByTestId('statistics-panel')).toBeDefined();
+    expect(screen.getByText('Today words').nextSibling?.textContent).toBe('3');
+  });
+
   it('renders skip link and main landmark for keyboard navigation', async () => {
     render(<App />);
 
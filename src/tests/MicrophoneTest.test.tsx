@@ -244,4 +244,33 @@ describe('MicrophoneTest', () => {
 
     expect(screen.queryByText('No audio detected')).toBeNull();
   });
+
+  it('shows the live VAD preview while running', () => {
+    const { rerender } = render(
+      <MicrophoneTest
+        deviceUid="test-device"
+        onStartTest={vi.fn()}
+        onStopTest={vi.fn()}
+        audioLevel={null}
+        vadPreview={{ state: 'silence', speech_ms: 900, silence_ms: 600, silence_threshold_ms: 1200 }}
+        isRunning={true}
+      />
+    );
+
+    expect(screen.getByTestId('vad-preview').textContent).toContain('Silence');
+    expect(screen.getByText('0.6s / 1.2s')).toBeDefined();
+
+    rerender(
+      <MicrophoneTest
+        deviceUid="test-device"
+        onStartTest={vi.fn()}
+        onStopTest={vi.fn()}
+        audioLevel={null}
+        vadPreview={{ state: 'auto_stop', speech_ms: 900, silence_ms: 1200, silence_threshold_ms: 1200 }}
+        isRunning={false}
+      />
+    );
+
+    expect(screen.queryByTestId('vad-preview')).toBeNull();
+  });
 });
@@ -3,7 +3,7 @@
  */
 
 import { describe, it, expect, vi, beforeEach } from 'vitest';
-import { render, screen, fireEvent, act } from '@testing-library/react';
+import { render, screen, fireEvent, act, waitFor } from '@testing-library/react';
 import { SelfCheck } from '../components/Settings/SelfCheck';
 import { Diagnostics } from '../components/Settings/Diagnostics';
 import type { SelfCheckResult, DiagnosticsReport, Capabilities, AppConfig } from '../types';
@@ -119,6 +119,20 @@ const mockDiagnosticsReport: DiagnosticsReport = {
       message: 'sample diagnostics log',
     },
   ],
+  watchdog: {
+    ping_interval_ms: 10000,
+    ping_timeout_ms: 5000,
+    hang_threshold_ms: 30000,
+    auto_restart_on_hang: true,
+  },
+  power: {
+    source: 'battery',
+    profile: 'low_power',
+  },
+  sidecar_output: {
+    rpc_bytes_replaced: 0,
+    log_bytes_replaced: 7,
+  },
 };
 
 describe('SelfCheck', () => {
@@ -279,6 +293,26 @@ describe('Diagnostics', () => {
     expect(screen.getByText('Refresh')).toBeDefined();
   });
 
+  it('saves a state snapshot and shows where', async () => {
+    const onExportSnapshot = vi
+      .fn()
+      .mockResolvedValue('/Users/test/Downloads/openvoicy-state-20260218-000000.json');
+    render(
+      <Diagnostics
+        report={mockDiagnosticsReport}
+        onRefresh={vi.fn()}
+        onExportSnapshot={onExportSnapshot}
+      />
+    );
+
+    fireEvent.click(screen.getByRole('button', { name: 'Save state snapshot file' }));
+
+    await waitFor(() => {
+      expect(screen.getByText(/State snapshot saved to \[REDACTED_PATH\]\/Downloads/)).toBeDefined();
+    });
+    expect(onExportSnapshot).toHaveBeenCalledTimes(1);
+  });
+
   it('includes version in output', () => {
     render(
       <Diagnostics report={mockDiagnosticsReport} onRefresh={vi.fn()} />
@@ -312,6 +346,31 @@ describe('Diagnostics', () => {
     expect(screen.getByText(/sample diagnostics log/)).toBeDefined();
   });
 
+  it('shows effective watchdog settings in output', () => {
+    render(
+      <Diagnostics report={mockDiagnosticsReport} onRefresh={vi.fn()} />
+    );
+
+    expect(screen.getByText(/Sidecar Watchdog/)).toBeDefined();
+    expect(screen.getByText(/Hang threshold: 30000 ms/)).toBeDefined();
+  });
+
+  it('shows the active power profile in output', () => {
+    render(
+      <Diagnostics report={mockDiagnosticsReport} onRefresh={vi.fn()} />
+    );
+
+    expect(screen.getByText(/Profile: low_power/)).toBeDefined();
+  });
+
+  it('shows replaced sidecar output bytes in output', () => {
+    render(
+      <Diagnostics report={mockDiagnosticsReport} onRefresh={vi.fn()} />
+    );
+
+    expect(screen.getByText(/Invalid UTF-8 bytes replaced \(logs\): 7/)).toBeDefined();
+  });
+
   it('references KNOWN_LIMITATIONS.md in diagnostics output', () => {
     render(
       <Diagnostics report={mockDiagnosticsReport} onRefresh={vi.fn()} />
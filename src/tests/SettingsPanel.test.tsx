@@ -9,6 +9,7 @@ import { HotkeyConfig } from '../components/Settings/HotkeyConfig';
 import { InjectionSettings } from '../components/Settings/InjectionSettings';
 import { SettingsPanel } from '../components/Settings/SettingsPanel';
 import type { AudioDevice, AppConfig, InjectionConfig } from '../types';
+import { mockInvoke } from './setup';
 
 // Mock data
 const mockDevices: AudioDevice[] = [
@@ -205,6 +206,22 @@ describe('HotkeyConfig', () => {
     expect(screen.getByText('Ctrl+Shift+C')).toBeDefined();
   });
 
+  it('renders cancel hotkey when it can be changed', () => {
+    render(
+      <HotkeyConfig
+        primaryHotkey="Ctrl+Shift+A"
+        copyLastHotkey="Ctrl+Shift+C"
+        mode="hold"
+        onPrimaryChange={vi.fn()}
+        onCopyLastChange={vi.fn()}
+        onCancelChange={vi.fn()}
+        onModeChange={vi.fn()}
+      />
+    );
+    expect(screen.getByText('Cancel Hotkey')).toBeDefined();
+    expect(screen.getByText('Escape')).toBeDefined();
+  });
+
   it('renders mode selector', () => {
     render(
       <HotkeyConfig
@@ -319,6 +336,31 @@ describe('HotkeyConfig', () => {
     expect(onPrimaryChange).not.toHaveBeenCalled();
     expect(screen.queryByText('Press keys...')).toBeNull();
   });
+
+  it('reports a leaking hotkey with a hint', async () => {
+    mockInvoke('test_hotkey_leakage', {
+      hotkey: 'Ctrl+Shift+A',
+      hotkey_detected: true,
+      leaked: true,
+      suppression: 'unsuppressed',
+      hint: 'Hotkeys are only grabbed in XWayland windows on this desktop.',
+    });
+    render(
+      <HotkeyConfig
+        primaryHotkey="Ctrl+Shift+A"
+        copyLastHotkey="Ctrl+Shift+C"
+        mode="hold"
+        onPrimaryChange={vi.fn()}
+        onCopyLastChange={vi.fn()}
+        onModeChange={vi.fn()}
+      />
+    );
+
+    fireEvent.click(screen.getByRole('button', { name: 'Test for key leaks' }));
+
+    expect(await screen.findByText(/leaked into the focused app/)).toBeDefined();
+    expect(screen.getByText(/only grabbed in XWayland windows/)).toBeDefined();
+  });
 });
 
 describe('InjectionSettings', () => {
@@ -391,6 +433,79 @@ describe('InjectionSettings', () => {
     expect(onChange).toHaveBeenCalledWith('suffix', '\n');
   });
 
+  it('calls onChange when a confirmation guard is selected', () => {
+    const onChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <InjectionSettings
+        config={mockInjectionConfig}
+        onChange={onChange}
+      />
+    );
+
+    fireEvent.change(screen.getByLabelText(/Confirm Long Text/), { target: { value: '2000' } });
+    fireEvent.change(screen.getByLabelText(/Confirm Long Recordings/), { target: { value: '5' } });
+
+    expect(onChange).toHaveBeenCalledWith('confirm_above_chars', 2000);
+    expect(onChange).toHaveBeenCalledWith('confirm_above_minutes', 5);
+  });
+
+  it('calls onChange when the busy app wait is changed', () => {
+    const onChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <InjectionSettings
+        config={mockInjectionConfig}
+        onChange={onChange}
+      />
+    );
+
+    const select = screen.getByLabelText(/Wait for Busy Apps/) as HTMLSelectElement;
+    expect(select.value).toBe('10');
+    fireEvent.change(select, { target: { value: '0' } });
+
+    expect(onChange).toHaveBeenCalledWith('busy_target_wait_secs', 0);
+  });
+
+  it('hides the primary selection option where it is unavailable', () => {
+    render(
+      <InjectionSettings
+        config={mockInjectionConfig}
+        onChange={vi.fn()}
+      />
+    );
+    expect(screen.queryByLabelText(/Middle-Click Paste/)).toBeNull();
+  });
+
+  it('calls onChange when a primary selection mode is selected', () => {
+    const onChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <InjectionSettings
+        config={mockInjectionConfig}
+        onChange={onChange}
+        primarySelectionAvailable
+      />
+    );
+
+    fireEvent.change(screen.getByLabelText(/Middle-Click Paste/), { target: { value: 'also' } });
+
+    expect(onChange).toHaveBeenCalledWith('primary_selection', 'also');
+  });
+
+  it('calls onChange when paste delay calibration toggles', () => {
+    const onChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <InjectionSettings
+        config={mockInjectionConfig}
+        onChange={onChange}
+      />
+    );
+
+    const toggle = screen.getByRole('switch', { name: /calibrate paste delay/i });
+    expect(toggle.getAttribute('aria-checked')).toBe('false');
+    fireEvent.click(toggle);
+
+    expect(onChange).toHaveBeenCalledWith('calibrate_paste_delay', true);
+  });
+
   it('calls onChange when focus guard toggles', () => {
     const onChange = vi.fn().mockResolvedValue(undefined);
     render(
@@ -497,6 +612,26 @@ describe('SettingsPanel', () => {
     expect(onConfigChange).toHaveBeenCalledWith(['audio', 'audio_cues_enabled'], false);
   });
 
+  it('adjusts the input gain of the default microphone', () => {
+    const onInputGainChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <SettingsPanel
+        config={{
+          ...mockConfig,
+          audio: { ...mockConfig.audio, device_uid: undefined, input_gains: { 'device-1': 6 } },
+        }}
+        devices={mockDevices}
+        onInputGainChange={onInputGainChange}
+        onConfigChange={vi.fn()}
+      />
+    );
+
+    const slider = screen.getByLabelText(/input gain: \+6 dB/i);
+    fireEvent.change(slider, { target: { value: '-4' } });
+
+    expect(onInputGainChange).toHaveBeenCalledWith('device-1', -4);
+  });
+
   it('renders VAD toggle in audio tab', () => {
     render(
       <SettingsPanel
@@ -558,6 +693,128 @@ describe('SettingsPanel', () => {
     expect(onConfigChange).toHaveBeenCalledWith(['audio', 'vad_enabled'], true);
   });
 
+  it('toggles continuous dictation and shows the VAD sliders it uses', () => {
+    const onConfigChange = vi.fn().mockResolvedValue(undefined);
+    const { rerender } = render(
+      <SettingsPanel
+        config={mockConfig}
+        devices={mockDevices}
+        onConfigChange={onConfigChange}
+      />
+    );
+
+    const toggle = screen.getByRole('switch', { name: /continuous dictation/i });
+    expect(toggle.getAttribute('aria-checked')).toBe('false');
+    fireEvent.click(toggle);
+    expect(onConfigChange).toHaveBeenCalledWith(['audio', 'continuous_dictation'], true);
+
+    rerender(
+      <SettingsPanel
+        config={{ ...mockConfig, audio: { ...mockConfig.audio, continuous_dictation: true } }}
+        devices={mockDevices}
+        onConfigChange={onConfigChange}
+      />
+    );
+    expect(screen.getByLabelText(/silence before stop/i)).toBeDefined();
+  });
+
+  it('updates the recording countdown via onConfigChange', () => {
+    const onConfigChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <SettingsPanel
+        config={mockConfig}
+        devices={mockDevices}
+        onConfigChange={onConfigChange}
+      />
+    );
+
+    const select = screen.getByLabelText(/countdown before recording/i) as HTMLSelectElement;
+    expect(select.value).toBe('0');
+    fireEvent.change(select, { target: { value: '3' } });
+    expect(onConfigChange).toHaveBeenCalledWith(['audio', 'countdown_secs'], 3);
+  });
+
+  it('toggles splitting long recordings via onConfigChange', () => {
+    const onConfigChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <SettingsPanel
+        config={mockConfig}
+        devices={mockDevices}
+        onConfigChange={onConfigChange}
+      />
+    );
+
+    const toggle = screen.getByRole('switch', { name: /split long recordings/i });
+    expect(toggle.getAttribute('aria-checked')).toBe('false');
+    fireEvent.click(toggle);
+    expect(onConfigChange).toHaveBeenCalledWith(['audio', 'auto_split_recordings'], true);
+  });
+
+  it('toggles meeting mode and shows why cues are muted', () => {
+    const onConfigChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <SettingsPanel
+        config={mockConfig}
+        devices={mockDevices}
+        cueStatus={{ suppressed: true, reason: 'call', app: 'zoom' }}
+        onConfigChange={onConfigChange}
+      />
+    );
+
+    fireEvent.click(screen.getByRole('switch', { name: /in a meeting/i }));
+    expect(onConfigChange).toHaveBeenCalledWith(['audio', 'in_meeting'], true);
+
+    const detection = screen.getByRole('switch', { name: /mute cues during calls/i });
+    expect(detection.getAttribute('aria-checked')).toBe('true');
+    fireEvent.click(detection);
+    expect(onConfigChange).toHaveBeenCalledWith(['audio', 'mute_cues_in_calls'], false);
+
+    expect(screen.getByTestId('cue-status').textContent).toContain('zoom is using the microphone');
+  });
+
+  it('toggles Do Not Disturb muting and explains it', () => {
+    const onConfigChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <SettingsPanel
+        config={mockConfig}
+        devices={mockDevices}
+        cueStatus={{ suppressed: true, reason: 'do_not_disturb', app: null }}
+        onConfigChange={onConfigChange}
+      />
+    );
+
+    const toggle = screen.getByRole('switch', { name: /mute cues in do not disturb/i });
+    expect(toggle.getAttribute('aria-checked')).toBe('true');
+    fireEvent.click(toggle);
+    expect(onConfigChange).toHaveBeenCalledWith(['audio', 'mute_cues_in_dnd'], false);
+
+    expect(screen.getByTestId('cue-status').textContent).toContain('Do Not Disturb is on');
+  });
+
+  it('offers type-while-speaking intervals only for continuous dictation', () => {
+    const onConfigChange = vi.fn().mockResolvedValue(undefined);
+    const { rerender } = render(
+      <SettingsPanel
+        config={mockConfig}
+        devices={mockDevices}
+        onConfigChange={onConfigChange}
+      />
+    );
+    expect(screen.queryByLabelText(/type while speaking/i)).toBeNull();
+
+    rerender(
+      <SettingsPanel
+        config={{ ...mockConfig, audio: { ...mockConfig.audio, continuous_dictation: true } }}
+        devices={mockDevices}
+        onConfigChange={onConfigChange}
+      />
+    );
+    const select = screen.getByLabelText(/type while speaking/i) as HTMLSelectElement;
+    expect(select.value).toBe('0');
+    fireEvent.change(select, { target: { value: '10' } });
+    expect(onConfigChange).toHaveBeenCalledWith(['audio', 'live_flush_secs'], 10);
+  });
+
   it('updates VAD silence_ms slider via onConfigChange', () => {
     const onConfigChange = vi.fn().mockResolvedValue(undefined);
     const vadConfig = {
@@ -598,6 +855,113 @@ describe('SettingsPanel', () => {
     expect(onConfigChange).toHaveBeenCalledWith(['audio', 'vad_min_speech_ms'], 500);
   });
 
+  it('selects a VAD sensitivity preset via onConfigChange', () => {
+    const onConfigChange = vi.fn().mockResolvedValue(undefined);
+    const vadConfig = {
+      ...mockConfig,
+      audio: { ...mockConfig.audio, vad_enabled: true },
+    };
+    render(
+      <SettingsPanel
+        config={vadConfig}
+        devices={mockDevices}
+        onConfigChange={onConfigChange}
+      />
+    );
+
+    const presetSelect = screen.getByLabelText(/sensitivity/i) as HTMLSelectElement;
+    expect(presetSelect.value).toBe('balanced');
+    fireEvent.change(presetSelect, { target: { value: 'relaxed' } });
+
+    expect(onConfigChange).toHaveBeenCalledWith(['audio', 'vad_preset'], 'relaxed');
+  });
+
+  it('toggles offline mode from the appearance tab', () => {
+    const onConfigChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <SettingsPanel
+        config={mockConfig}
+        devices={mockDevices}
+        onConfigChange={onConfigChange}
+      />
+    );
+
+    fireEvent.click(screen.getByText('Appearance'));
+    const toggle = screen.getByRole('switch', { name: /offline mode/i });
+    expect(toggle.getAttribute('aria-checked')).toBe('false');
+    fireEvent.click(toggle);
+    expect(onConfigChange).toHaveBeenCalledWith(['network', 'offline_mode'], true);
+  });
+
+  it('selects the screen share policy from the appearance tab', () => {
+    const onConfigChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <SettingsPanel
+        config={mockConfig}
+        devices={mockDevices}
+        onConfigChange={onConfigChange}
+      />
+    );
+
+    fireEvent.click(screen.getByText('Appearance'));
+    const policySelect = screen.getByLabelText(/while screen sharing/i) as HTMLSelectElement;
+    expect(policySelect.value).toBe('warn');
+    fireEvent.change(policySelect, { target: { value: 'disable_dictation' } });
+
+    expect(onConfigChange).toHaveBeenCalledWith(['ui', 'screen_share_policy'], 'disable_dictation');
+  });
+
+  it('toggles the companion status endpoint from the appearance tab', () => {
+    const onConfigChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <SettingsPanel
+        config={mockConfig}
+        devices={mockDevices}
+        onConfigChange={onConfigChange}
+      />
+    );
+
+    fireEvent.click(screen.getByText('Appearance'));
+    const toggle = screen.getByRole('switch', { name: /companion devices/i });
+    expect(toggle.getAttribute('aria-checked')).toBe('false');
+    fireEvent.click(toggle);
+    expect(onConfigChange).toHaveBeenCalledWith(['companion', 'enabled'], true);
+  });
+
+  it('keeps crash report upload off until the user opts in', () => {
+    const onConfigChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <SettingsPanel
+        config={mockConfig}
+        devices={mockDevices}
+        onConfigChange={onConfigChange}
+      />
+    );
+
+    fireEvent.click(screen.getByText('Appearance'));
+    const toggle = screen.getByRole('switch', { name: /send crash reports/i });
+    expect(toggle.getAttribute('aria-checked')).toBe('false');
+    fireEvent.click(toggle);
+    expect(onConfigChange).toHaveBeenCalledWith(['crash_reporting', 'upload_enabled'], true);
+  });
+
+  it('toggles the low-power profile on battery', () => {
+    const onConfigChange = vi.fn().mockResolvedValue(undefined);
+    render(
+      <SettingsPanel
+        config={mockConfig}
+        devices={mockDevices}
+        onConfigChange={onConfigChange}
+      />
+    );
+
+    fireEvent.click(screen.getByText('Appearance'));
+    const toggle = screen.getByRole('switch', { name: /save energy on battery/i });
+    expect(toggle.getAttribute('aria-checked')).toBe('false');
+    fireEvent.click(toggle);
+    expect(onConfigChange).toHaveBeenCalledWith(['power', 'low_power_on_battery'], true);
+  });
+
   it('shows purge button in appearance tab when onPurgeHistory is provided', () => {
     render(
       <SettingsPanel
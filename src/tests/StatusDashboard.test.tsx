@@ -172,6 +172,27 @@ describe('StatusDashboard', () => {
     expect(pulseDot).not.toBeNull();
   });
 
+  it('shows the model loading stage instead of stale download progress', async () => {
+    render(<StatusDashboard />);
+
+    act(() => {
+      useAppStore.setState({
+        modelStatus: {
+          status: 'loading',
+          model_id: 'parakeet-next',
+          progress: { current: 100, total: 100, unit: 'bytes' },
+          stage: 'weights',
+          percent: 35,
+        },
+      });
+    });
+
+    await waitFor(() => {
+      expect(screen.getByTestId('model-badge-stage').textContent).toBe('Loading weights (35%)');
+    });
+    expect(screen.queryByTestId('model-badge-progress')).toBeNull();
+  });
+
   it('shows error state badge without pulse animation', async () => {
     const { container } = render(<StatusDashboard />);
 
@@ -27,6 +27,7 @@ describe('tauri wire snapshots', () => {
       'already_recording',
       'still_transcribing',
       'in_error_state',
+      'screen_sharing',
     ];
 
     expect(snapshot.cannot_record_reason).toEqual(expected);
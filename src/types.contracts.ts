@@ -11,6 +11,19 @@
  */
 
 // TauriCommand local definitions
+export type TauriCommandDefActionInfo = {
+  args: Array<{
+  name: string;
+  required: boolean;
+  values?: Array<string>;
+}>;
+  available: boolean;
+  description: string;
+  id: string;
+  name: string;
+  unavailable_reason: string | null;
+};
+
 export type TauriCommandDefAudioDevice = {
   channels: number;
   is_default: boolean;
@@ -25,19 +38,50 @@ export type TauriCommandDefCheckItem = {
   status: "ok" | "warning" | "error";
 };
 
+export type TauriCommandDefCommandAuditEntry = {
+  args: string;
+  command: string;
+  duration_ms?: number;
+  error?: string;
+  id: number;
+  started_at: string;
+  status: "pending" | "ok" | "error";
+  window: string;
+};
+
 export type TauriCommandDefDiagnosticsReport = {
   capabilities: TauriCommandDefOpenObject;
   config: TauriCommandDefOpenObject;
   environment?: Record<string, string>;
   platform: string;
+  power: {
+  profile: "normal" | "low_power";
+  source: "ac" | "battery" | "unknown";
+};
   recent_logs: Array<TauriCommandDefLogEntry>;
   self_check: TauriCommandDefOpenObject;
+  session_journal?: Array<string>;
+  sidecar_output: {
+  log_bytes_replaced: number;
+  rpc_bytes_replaced: number;
+};
   version: string;
+  watchdog: {
+  auto_restart_on_hang: boolean;
+  hang_threshold_ms: number;
+  ping_interval_ms: number;
+  ping_timeout_ms: number;
+};
 };
 
 export type TauriCommandDefEmptyParams = {
 };
 
+export type TauriCommandDefInputGain = {
+  device_uid: string | null;
+  gain_db: number;
+};
+
 export type TauriCommandDefLogEntry = {
   level: string;
   message: string;
@@ -53,6 +97,12 @@ export type TauriCommandDefModelCatalogEntry = {
   license_spdx?: string | null;
   manifest_path: string;
   model_id: string;
+  recommended_params?: {
+  beam_size?: number;
+  expected_ram_mb?: number;
+  vad_min_speech_ms?: number;
+  vad_silence_ms?: number;
+};
   size_bytes?: number | null;
   supported_languages: Array<string>;
 };
@@ -72,6 +122,21 @@ export type TauriCommandDefModelStatus = {
 
 export type TauriCommandDefOpenObject = Record<string, unknown>;
 
+export type TauriCommandDefPhraseShortcut = {
+  enabled: boolean;
+  id: string;
+  phrase: string;
+  value: TauriCommandDefPhraseShortcutValue;
+};
+
+export type TauriCommandDefPhraseShortcutValue = {
+  kind: "text";
+  text: string;
+} | {
+  kind: "secret";
+  secret_name: string;
+};
+
 export type TauriCommandDefPresetInfo = {
   description: string;
   id: string;
@@ -79,6 +144,19 @@ export type TauriCommandDefPresetInfo = {
   rule_count: number;
 };
 
+export type TauriCommandDefSessionSnapshot = {
+  audio_ms: number | null;
+  cancel_reason: string | null;
+  created_at: string;
+  error: string | null;
+  phase: "created" | "recording" | "transcribing" | "completed" | "failed" | "cancelled";
+  revision: number;
+  session_id: string;
+  started_at: string | null;
+  text: string | null;
+  updated_at: string;
+};
+
 export type TauriCommandDefStateEvent = {
   detail?: string | null;
   enabled: boolean;
@@ -87,6 +165,26 @@ export type TauriCommandDefStateEvent = {
   [key: string]: unknown;
 };
 
+export type TauriCommandDefTranscriptDiff = {
+  entry_id: string;
+  final_text: string;
+  raw_text: string;
+  spans: Array<{
+  final: string;
+  kind: "equal" | "insert" | "delete" | "replace";
+  raw: string;
+  rule_id: string | null;
+  stage: "replacement" | "learned_dictionary" | null;
+}>;
+};
+
+export type TauriCommandDefUsageTotals = {
+  audio_ms: number;
+  transcripts: number;
+  words: number;
+  [key: string]: unknown;
+};
+
 export type TauriCommandDefVoidResult = null | Record<string, unknown>;
 
 // TauriEvent local definitions
@@ -104,8 +202,32 @@ export type TauriEventDefAppErrorPayload = {
   seq: number;
 };
 
+export type TauriEventDefAppNavigatePayload = {
+  seq: number;
+  tab: "statistics";
+};
+
+export type TauriEventDefAppRecoveryPayload = {
+  attempt: number;
+  category: "sidecar" | "audio_device" | "model" | "transcription" | "other";
+  delay_ms: number | null;
+  failed_checks: Array<"sidecar_ping" | "audio_devices" | "model_status">;
+  max_attempts: number;
+  phase: "scheduled" | "checking" | "failed" | "recovered" | "superseded" | "gave_up";
+  seq: number;
+};
+
 export type TauriEventDefAppState = "idle" | "loading_model" | "recording" | "transcribing" | "error";
 
+export type TauriEventDefAppUpdateProgressPayload = {
+  downloaded_bytes?: number;
+  message?: string;
+  phase: "downloading" | "verifying" | "staged" | "installing" | "failed";
+  seq: number;
+  total_bytes?: number;
+  version: string;
+};
+
 export type TauriEventDefAudioLevelPayload = {
   peak: number;
   rms: number;
@@ -114,6 +236,74 @@ export type TauriEventDefAudioLevelPayload = {
   source: string;
 };
 
+export type TauriEventDefCapabilitiesIssuesPayload = {
+  issues: Array<Record<string, unknown>>;
+  permissions: {
+  accessibility: TauriEventDefPermissionState;
+  input_monitoring: TauriEventDefPermissionState;
+  microphone: TauriEventDefPermissionState;
+};
+  seq: number;
+};
+
+export type TauriEventDefCuesStatusPayload = {
+  app: string | null;
+  reason: "manual" | "call" | "do_not_disturb" | null;
+  seq: number;
+  suppressed: boolean;
+};
+
+export type TauriEventDefFileTranscriptionProgressPayload = {
+  entry_id?: string;
+  error?: string;
+  file_name: string;
+  phase: "queued" | "transcribing" | "complete" | "failed";
+  seq: number;
+};
+
+export type TauriEventDefFocusChangedPayload = {
+  app_name: string;
+  is_self: boolean;
+  seq: number;
+  window_id: string;
+  window_title: string | null;
+};
+
+export type TauriEventDefInjectionConfirmRequestedPayload = {
+  audio_duration_ms?: number;
+  entry_id: string;
+  guard?: "text_length" | "audio_duration";
+  seq: number;
+  text_length: number;
+};
+
+export type TauriEventDefInjectionDeferredPayload = {
+  entry_id: string;
+  max_wait_ms: number;
+  phase: "waiting" | "resumed" | "expired" | "dismissed";
+  seq: number;
+  session_id: string;
+  waited_ms: number;
+};
+
+export type TauriEventDefInjectionModeChangedPayload = {
+  mode: "inject" | "clipboard_only" | "ask";
+  seq: number;
+};
+
+export type TauriEventDefInjectionProgressPayload = {
+  chars_injected: number;
+  chunk_count: number;
+  chunks_done: number;
+  seq: number;
+  total_chars: number;
+};
+
+export type TauriEventDefInjectionQueueChangedPayload = {
+  depth: number;
+  seq: number;
+};
+
 export type TauriEventDefInjectionResult = {
   status: "injected";
   [key: string]: unknown;
@@ -143,10 +333,13 @@ export type TauriEventDefModelProgressPayload = {
 export type TauriEventDefModelStatusPayload = {
   cache_path?: string | null;
   error?: string | null;
+  kind?: "sidecar" | "embedded";
   model_id: string;
+  percent?: number;
   progress?: TauriEventDefModelStatusProgress | null;
   revision?: string | null;
   seq: number;
+  stage?: string;
   status: "missing" | "downloading" | "loading" | "verifying" | "ready" | "error" | "unknown";
 };
 
@@ -156,19 +349,68 @@ export type TauriEventDefModelStatusProgress = {
   unit: string;
 };
 
+export type TauriEventDefOverlayCancelledPayload = {
+  reason: "double_tap" | "user_button" | "escape_key" | "max_duration";
+  seq: number;
+  session_id: string;
+};
+
+export type TauriEventDefOverlayConfigPayload = {
+  opacity: number;
+  scale: number;
+  seq: number;
+  show_waveform: boolean;
+  theme: "system" | "light" | "dark";
+};
+
+export type TauriEventDefOverlayCountdownPayload = {
+  cancelled: boolean;
+  remaining_secs: number;
+  seq: number;
+};
+
 export type TauriEventDefOverlayTogglePayload = {
   enabled: boolean;
   seq: number;
 };
 
+export type TauriEventDefOverlayTranscribingPayload = {
+  audio_ms: number;
+  elapsed_ms: number;
+  seq: number;
+  session_id: string;
+};
+
+export type TauriEventDefPendingInsertsChangedPayload = {
+  count: number;
+  seq: number;
+};
+
+export type TauriEventDefPermissionState = "granted" | "denied" | "not_determined" | "not_applicable";
+
 export type TauriEventDefRecordingStatusPayload = {
   audio_ms?: number;
   phase: "idle" | "recording" | "transcribing";
+  screen_sharing?: boolean;
   seq: number;
   session_id?: string;
   started_at?: string;
 };
 
+export type TauriEventDefSessionChangedPayload = {
+  audio_ms: number | null;
+  cancel_reason: string | null;
+  created_at: string;
+  error: string | null;
+  phase: "created" | "recording" | "transcribing" | "completed" | "failed" | "cancelled";
+  revision: number;
+  seq: number;
+  session_id: string;
+  started_at: string | null;
+  text: string | null;
+  updated_at: string;
+};
+
 export type TauriEventDefSidecarStatusPayload = {
   message?: string;
   restart_count: number;
@@ -176,9 +418,17 @@ export type TauriEventDefSidecarStatusPayload = {
   state: "starting" | "ready" | "failed" | "restarting" | "stopped";
 };
 
+export type TauriEventDefSidecarUpdateStatusPayload = {
+  message?: string;
+  phase: "checking" | "downloading" | "verifying" | "installing" | "restarting" | "ready" | "rolled_back" | "failed";
+  seq: number;
+  version: string;
+};
+
 export type TauriEventDefStateChangedPayload = {
   detail?: string | null;
   enabled: boolean;
+  level?: "enabled" | "hotkeys_paused" | "fully_paused" | "stealth";
   seq: number;
   state: TauriEventDefAppState;
   timestamp: string;
@@ -190,18 +440,45 @@ export type TauriEventDefTranscriptCompletePayload = {
 };
 
 export type TauriEventDefTranscriptEntry = {
+  app_name?: string;
   audio_duration_ms: number;
+  auto_punctuation?: boolean;
   confidence?: number;
   final_text?: string;
   id: string;
   injection_result: TauriEventDefInjectionResult;
+  injection_target?: {
+  app_id: string;
+  window_title_hash?: string;
+};
   language?: string;
+  paste_verification?: "verified" | "retried_with_keystrokes" | "failed" | "unverifiable";
+  pinned?: boolean;
   raw_text?: string;
+  segments?: Array<{
+  end_ms: number;
+  speaker?: string;
+  start_ms: number;
+  text: string;
+}>;
   session_id?: string;
+  session_name?: string;
+  source?: "microphone" | "file";
+  source_file?: string;
+  tags?: Array<string>;
   text: string;
   timestamp: string;
   timings?: TauriEventDefTranscriptTimings;
   transcription_duration_ms: number;
+  transcription_retries?: number;
+  unfiltered_text?: string;
+  window_title?: string;
+  words?: Array<{
+  confidence?: number;
+  end_ms: number;
+  start_ms: number;
+  word: string;
+}>;
   [key: string]: unknown;
 };
 
@@ -222,6 +499,14 @@ export type TauriEventDefTranscriptTimings = {
   transcribe_ms?: number;
 };
 
+export type TauriEventDefVadPreviewPayload = {
+  seq: number;
+  silence_ms: number;
+  silence_threshold_ms: number;
+  speech_ms: number;
+  state: "waiting_for_speech" | "speech" | "silence" | "auto_stop";
+};
+
 // SidecarRpc local definitions
 export type SidecarRpcDefReplacementRule = {
   case_sensitive: boolean;
@@ -237,15 +522,90 @@ export type SidecarRpcDefReplacementRule = {
 };
 
 // Tauri command params/results
+export type TauriCommandAcceptModelLicenseParams = {
+  modelId: string;
+};
+export type TauriCommandAcceptModelLicenseResult = TauriCommandDefVoidResult;
+
+export type TauriCommandApplySidecarUpdateParams = TauriCommandDefEmptyParams;
+export type TauriCommandApplySidecarUpdateResult = string;
+
 export type TauriCommandCanStartRecordingParams = TauriCommandDefEmptyParams;
 export type TauriCommandCanStartRecordingResult = TauriCommandDefVoidResult;
 
+export type TauriCommandCancelInjectionParams = TauriCommandDefEmptyParams;
+export type TauriCommandCancelInjectionResult = boolean;
+
 export type TauriCommandCancelRecordingParams = TauriCommandDefEmptyParams;
 export type TauriCommandCancelRecordingResult = TauriCommandDefOpenObject;
 
+export type TauriCommandCheckAppUpdateParams = TauriCommandDefEmptyParams;
+export type TauriCommandCheckAppUpdateResult = {
+  current_version: string;
+  in_rollout: boolean;
+  latest_version: string;
+  notes: string | null;
+  rollout_percentage: number;
+  staged_version: string | null;
+  update_available: boolean;
+};
+
+export type TauriCommandCheckSidecarUpdateParams = TauriCommandDefEmptyParams;
+export type TauriCommandCheckSidecarUpdateResult = {
+  current_version: string;
+  latest_version: string;
+  notes: string | null;
+  update_available: boolean;
+};
+
 export type TauriCommandClearHistoryParams = TauriCommandDefEmptyParams;
 export type TauriCommandClearHistoryResult = TauriCommandDefVoidResult;
 
+export type TauriCommandClearHistoryFilteredParams = {
+  filter: {
+  app_name?: string | null;
+  older_than_days?: number | null;
+  tag?: string | null;
+  unpinned_only?: boolean;
+};
+};
+export type TauriCommandClearHistoryFilteredResult = number;
+
+export type TauriCommandClearTemporaryReplacementRulesParams = TauriCommandDefEmptyParams;
+export type TauriCommandClearTemporaryReplacementRulesResult = TauriCommandDefVoidResult;
+
+export type TauriCommandCompareModelsParams = {
+  audioPath?: string | null;
+  modelIds: Array<string>;
+  sessionId?: string | null;
+};
+export type TauriCommandCompareModelsResult = {
+  audio_duration_ms: number;
+  baseline_model_id: string | null;
+  results: Array<{
+  changed_words: number;
+  confidence: number | null;
+  diff: Array<{
+  op: "equal" | "insert" | "delete";
+  text: string;
+}>;
+  error: {
+  code: string;
+  message: string;
+} | null;
+  load_ms: number | null;
+  model_id: string;
+  real_time_factor: number | null;
+  text: string | null;
+  transcribe_ms: number | null;
+}>;
+};
+
+export type TauriCommandConfirmInjectionParams = {
+  entry_id: string;
+};
+export type TauriCommandConfirmInjectionResult = TauriCommandDefVoidResult;
+
 export type TauriCommandCopyLastTranscriptParams = TauriCommandDefEmptyParams;
 export type TauriCommandCopyLastTranscriptResult = string | null;
 
@@ -254,6 +614,29 @@ export type TauriCommandCopyTranscriptParams = {
 };
 export type TauriCommandCopyTranscriptResult = TauriCommandDefVoidResult;
 
+export type TauriCommandDeleteCrashReportsParams = {
+  ids?: Array<string> | null;
+};
+export type TauriCommandDeleteCrashReportsResult = number;
+
+export type TauriCommandDeletePhraseShortcutParams = {
+  id: string;
+};
+export type TauriCommandDeletePhraseShortcutResult = boolean;
+
+export type TauriCommandDeleteSecretParams = {
+  name: string;
+};
+export type TauriCommandDeleteSecretResult = boolean;
+
+export type TauriCommandDismissPendingInsertParams = {
+  entry_id: string;
+};
+export type TauriCommandDismissPendingInsertResult = boolean;
+
+export type TauriCommandDownloadAppUpdateParams = TauriCommandDefEmptyParams;
+export type TauriCommandDownloadAppUpdateResult = string;
+
 export type TauriCommandDownloadModelParams = {
   force?: boolean | null;
   modelId?: string | null;
@@ -261,15 +644,41 @@ export type TauriCommandDownloadModelParams = {
 };
 export type TauriCommandDownloadModelResult = TauriCommandDefVoidResult;
 
+export type TauriCommandEditTranscriptParams = {
+  entry_id: string;
+  text: string;
+};
+export type TauriCommandEditTranscriptResult = TauriCommandDefVoidResult;
+
+export type TauriCommandExportAppStateSnapshotParams = TauriCommandDefEmptyParams;
+export type TauriCommandExportAppStateSnapshotResult = string;
+
 export type TauriCommandExportHistoryParams = {
   format?: "json" | "csv";
   path?: string;
 };
 export type TauriCommandExportHistoryResult = TauriCommandDefOpenObject;
 
+export type TauriCommandExportSubtitlesParams = {
+  entry_id: string;
+  format: "srt" | "vtt";
+};
+export type TauriCommandExportSubtitlesResult = string;
+
 export type TauriCommandGenerateDiagnosticsParams = TauriCommandDefEmptyParams;
 export type TauriCommandGenerateDiagnosticsResult = TauriCommandDefDiagnosticsReport;
 
+export type TauriCommandGetActiveSessionParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetActiveSessionResult = TauriCommandDefSessionSnapshot | null;
+
+export type TauriCommandGetAppIconParams = {
+  app_id: string;
+};
+export type TauriCommandGetAppIconResult = {
+  app_id: string;
+  data_url: string;
+} | null;
+
 export type TauriCommandGetAppStateParams = TauriCommandDefEmptyParams;
 export type TauriCommandGetAppStateResult = TauriCommandDefStateEvent;
 
@@ -282,17 +691,70 @@ export type TauriCommandGetCapabilitiesResult = TauriCommandDefOpenObject;
 export type TauriCommandGetCapabilityIssuesParams = TauriCommandDefEmptyParams;
 export type TauriCommandGetCapabilityIssuesResult = Array<TauriCommandDefOpenObject>;
 
+export type TauriCommandGetCommandAuditLogParams = {
+  count: number;
+};
+export type TauriCommandGetCommandAuditLogResult = Array<TauriCommandDefCommandAuditEntry>;
+
 export type TauriCommandGetConfigParams = TauriCommandDefEmptyParams;
 export type TauriCommandGetConfigResult = TauriCommandDefOpenObject;
 
+export type TauriCommandGetCurrentFocusInfoParams = {
+};
+export type TauriCommandGetCurrentFocusInfoResult = {
+  app_name: string;
+  is_self: boolean;
+  window_id: string;
+  window_title: string | null;
+};
+
+export type TauriCommandGetEnableLevelParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetEnableLevelResult = "enabled" | "hotkeys_paused" | "fully_paused" | "stealth";
+
+export type TauriCommandGetHistoryStatsParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetHistoryStatsResult = {
+  entries: number;
+  last_pruned_at: string | null;
+  max_entries: number;
+  memory_bytes: number;
+  newest_at: string | null;
+  oldest_at: string | null;
+  persistence: "memory_only" | "disk_plaintext" | "disk_encrypted";
+  pinned: number;
+  storage_bytes: number;
+};
+
 export type TauriCommandGetHotkeyStatusParams = TauriCommandDefEmptyParams;
 export type TauriCommandGetHotkeyStatusResult = {
+  conflicts: Array<string>;
   copy_last: string;
   mode: string;
   primary: string;
+  profile: string | null;
   registered: boolean;
+  suppressed_while_typing: number;
 };
 
+export type TauriCommandGetInputGainParams = {
+  deviceUid?: string | null;
+  device_uid?: string | null;
+};
+export type TauriCommandGetInputGainResult = TauriCommandDefInputGain;
+
+export type TauriCommandGetLearnedDictionaryParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetLearnedDictionaryResult = Array<{
+  active: boolean;
+  corrections: number;
+  last_corrected: string;
+  word: string;
+}>;
+
+export type TauriCommandGetLowConfidenceWordsParams = {
+  entry_id: string;
+  threshold?: number | null;
+};
+export type TauriCommandGetLowConfidenceWordsResult = Array<number>;
+
 export type TauriCommandGetModelCatalogParams = TauriCommandDefEmptyParams;
 export type TauriCommandGetModelCatalogResult = Array<TauriCommandDefModelCatalogEntry>;
 
@@ -302,20 +764,137 @@ export type TauriCommandGetModelStatusParams = {
 };
 export type TauriCommandGetModelStatusResult = TauriCommandDefModelStatus;
 
+export type TauriCommandGetOverlayConfigParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetOverlayConfigResult = {
+  opacity: number;
+  scale: number;
+  show_waveform: boolean;
+  theme: "system" | "light" | "dark";
+};
+
+export type TauriCommandGetPasteCalibrationParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetPasteCalibrationResult = Array<{
+  app_id: string;
+  learned_delay_ms: number | null;
+  samples: Array<{
+  arrived?: boolean;
+  clipboard_settle_ms: number;
+  delay_ms: number;
+  key_dispatch_ms: number;
+  paste_ready_ms?: number;
+}>;
+  updated_at: string;
+}>;
+
+export type TauriCommandGetPendingCrashReportsParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetPendingCrashReportsResult = Array<TauriCommandDefOpenObject>;
+
+export type TauriCommandGetPendingInsertsParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetPendingInsertsResult = Array<{
+  entry_id: string;
+  queued_at: string;
+  reason: string;
+  text: string;
+}>;
+
+export type TauriCommandGetPendingLicenseParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetPendingLicenseResult = {
+  model_id: string;
+  name: string;
+  revision?: string;
+  spdx_id: string;
+  text: string;
+  url: string;
+} | null;
+
+export type TauriCommandGetPhraseShortcutsParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetPhraseShortcutsResult = Array<TauriCommandDefPhraseShortcut>;
+
 export type TauriCommandGetRecentLogsParams = {
   count: number;
+  level?: "error" | "warn" | "info" | "debug" | "trace";
+  module?: string;
+  since?: string;
 };
 export type TauriCommandGetRecentLogsResult = Array<TauriCommandDefLogEntry>;
 
 export type TauriCommandGetReplacementRulesParams = TauriCommandDefEmptyParams;
 export type TauriCommandGetReplacementRulesResult = Array<TauriCommandDefOpenObject>;
 
+export type TauriCommandGetSessionLabelParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetSessionLabelResult = {
+  name?: string;
+  tags: Array<string>;
+};
+
+export type TauriCommandGetTagSuggestionsParams = {
+  limit?: number | null;
+  prefix?: string | null;
+};
+export type TauriCommandGetTagSuggestionsResult = Array<string>;
+
+export type TauriCommandGetTemporaryReplacementRulesParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetTemporaryReplacementRulesResult = {
+  expires_at?: string;
+  profile?: string;
+  rules: Array<TauriCommandDefOpenObject>;
+};
+
+export type TauriCommandGetTranscriptDiffParams = {
+  entry_id: string;
+};
+export type TauriCommandGetTranscriptDiffResult = TauriCommandDefTranscriptDiff;
+
 export type TauriCommandGetTranscriptHistoryParams = TauriCommandDefEmptyParams;
 export type TauriCommandGetTranscriptHistoryResult = Array<TauriCommandDefOpenObject>;
 
+export type TauriCommandGetUnfilteredTranscriptParams = {
+  entry_id: string;
+};
+export type TauriCommandGetUnfilteredTranscriptResult = string | null;
+
+export type TauriCommandGetUsageStatsParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetUsageStatsResult = {
+  all_time: TauriCommandDefUsageTotals;
+  days: Array<TauriCommandDefUsageTotals & {
+  date: string;
+}>;
+  today: TauriCommandDefUsageTotals;
+};
+
+export type TauriCommandGetVocabularyParams = TauriCommandDefEmptyParams;
+export type TauriCommandGetVocabularyResult = Array<string>;
+
+export type TauriCommandHasSecretParams = {
+  name: string;
+};
+export type TauriCommandHasSecretResult = boolean;
+
+export type TauriCommandImportVocabularyParams = {
+  path: string;
+};
+export type TauriCommandImportVocabularyResult = Array<string>;
+
+export type TauriCommandInjectPendingInsertParams = {
+  entry_id: string;
+};
+export type TauriCommandInjectPendingInsertResult = TauriCommandDefVoidResult;
+
+export type TauriCommandInstallAppUpdateParams = TauriCommandDefEmptyParams;
+export type TauriCommandInstallAppUpdateResult = TauriCommandDefVoidResult;
+
+export type TauriCommandInvokeActionParams = {
+  args?: Record<string, string> | null;
+  id: string;
+};
+export type TauriCommandInvokeActionResult = unknown;
+
 export type TauriCommandIsEnabledParams = TauriCommandDefEmptyParams;
 export type TauriCommandIsEnabledResult = boolean;
 
+export type TauriCommandListActionsParams = TauriCommandDefEmptyParams;
+export type TauriCommandListActionsResult = Array<TauriCommandDefActionInfo>;
+
 export type TauriCommandListAudioDevicesParams = TauriCommandDefEmptyParams;
 export type TauriCommandListAudioDevicesResult = Array<TauriCommandDefAudioDevice>;
 
@@ -325,6 +904,18 @@ export type TauriCommandLoadPresetParams = {
 };
 export type TauriCommandLoadPresetResult = Array<TauriCommandDefOpenObject>;
 
+export type TauriCommandPracticeHotkeyParams = TauriCommandDefEmptyParams;
+export type TauriCommandPracticeHotkeyResult = {
+  hint?: string;
+  hold_ms?: number;
+  hotkey: string;
+  latency_ms?: number;
+  mode: "hold" | "toggle";
+  passed: boolean;
+  presses: number;
+  releases: number;
+};
+
 export type TauriCommandPreviewReplacementParams = {
   input: string;
   rules: Array<TauriCommandDefOpenObject>;
@@ -341,9 +932,32 @@ export type TauriCommandPurgeModelCacheParams = {
 };
 export type TauriCommandPurgeModelCacheResult = TauriCommandDefVoidResult;
 
+export type TauriCommandReinjectToOriginalTargetParams = {
+  entry_id: string;
+};
+export type TauriCommandReinjectToOriginalTargetResult = TauriCommandDefVoidResult;
+
+export type TauriCommandRemoveLearnedWordParams = {
+  word: string;
+};
+export type TauriCommandRemoveLearnedWordResult = boolean;
+
+export type TauriCommandReplayAppStateSnapshotParams = {
+  path: string;
+};
+export type TauriCommandReplayAppStateSnapshotResult = number;
+
+export type TauriCommandReportHotkeyKeydownParams = TauriCommandDefEmptyParams;
+export type TauriCommandReportHotkeyKeydownResult = TauriCommandDefVoidResult;
+
 export type TauriCommandResetConfigToDefaultsParams = TauriCommandDefEmptyParams;
 export type TauriCommandResetConfigToDefaultsResult = TauriCommandDefOpenObject;
 
+export type TauriCommandResetPasteCalibrationParams = {
+  app_id?: string | null;
+};
+export type TauriCommandResetPasteCalibrationResult = number;
+
 export type TauriCommandRestartSidecarParams = TauriCommandDefEmptyParams;
 export type TauriCommandRestartSidecarResult = TauriCommandDefOpenObject;
 
@@ -356,12 +970,31 @@ export type TauriCommandRunSelfCheckResult = {
   sidecar: TauriCommandDefCheckItem;
 };
 
+export type TauriCommandSavePhraseShortcutParams = {
+  enabled?: boolean | null;
+  id?: string | null;
+  phrase: string;
+  value: TauriCommandDefPhraseShortcutValue;
+};
+export type TauriCommandSavePhraseShortcutResult = TauriCommandDefPhraseShortcut;
+
+export type TauriCommandSearchTranscriptHistoryParams = {
+  query?: string | null;
+  tags?: Array<string> | null;
+};
+export type TauriCommandSearchTranscriptHistoryResult = Array<TauriCommandDefOpenObject>;
+
 export type TauriCommandSetAudioDeviceParams = {
   deviceUid?: string | null;
   device_uid?: string | null;
 };
 export type TauriCommandSetAudioDeviceResult = string;
 
+export type TauriCommandSetEnableLevelParams = {
+  level: "enabled" | "hotkeys_paused" | "fully_paused" | "stealth";
+};
+export type TauriCommandSetEnableLevelResult = TauriCommandDefVoidResult;
+
 export type TauriCommandSetEnabledParams = {
   enabled: boolean;
 };
@@ -374,10 +1007,74 @@ export type TauriCommandSetHotkeyParams = {
 };
 export type TauriCommandSetHotkeyResult = TauriCommandDefVoidResult;
 
+export type TauriCommandSetInjectionModeParams = {
+  mode: "inject" | "clipboard_only" | "ask";
+};
+export type TauriCommandSetInjectionModeResult = "inject" | "clipboard_only" | "ask";
+
+export type TauriCommandSetInputGainParams = {
+  deviceUid?: string | null;
+  device_uid?: string | null;
+  gainDb: number;
+};
+export type TauriCommandSetInputGainResult = TauriCommandDefInputGain;
+
 export type TauriCommandSetReplacementRulesParams = {
   rules: Array<TauriCommandDefOpenObject>;
 };
-export type TauriCommandSetReplacementRulesResult = TauriCommandDefVoidResult;
+export type TauriCommandSetReplacementRulesResult = Array<{
+  kind: "duplicate_id" | "unknown_group" | "invalid_pattern" | "conflict" | "shadowed";
+  message: string;
+  other_rule_id?: string;
+  rule_id: string;
+}>;
+
+export type TauriCommandSetSecretParams = {
+  name: string;
+  value: string;
+};
+export type TauriCommandSetSecretResult = TauriCommandDefVoidResult;
+
+export type TauriCommandSetSessionLabelParams = {
+  name?: string | null;
+  tags: Array<string>;
+};
+export type TauriCommandSetSessionLabelResult = {
+  name?: string;
+  tags: Array<string>;
+};
+
+export type TauriCommandSetTemporaryReplacementRulesParams = {
+  expires_at?: string | null;
+  rules: Array<TauriCommandDefOpenObject>;
+};
+export type TauriCommandSetTemporaryReplacementRulesResult = TauriCommandDefVoidResult;
+
+export type TauriCommandSetTranscriptLabelParams = {
+  entryId: string;
+  name?: string | null;
+  tags: Array<string>;
+};
+export type TauriCommandSetTranscriptLabelResult = {
+  name?: string;
+  tags: Array<string>;
+};
+
+export type TauriCommandSetTranscriptPinnedParams = {
+  entryId: string;
+  pinned: boolean;
+};
+export type TauriCommandSetTranscriptPinnedResult = TauriCommandDefVoidResult;
+
+export type TauriCommandSetVocabularyParams = {
+  terms: Array<string>;
+};
+export type TauriCommandSetVocabularyResult = Array<string>;
+
+export type TauriCommandShareHistoryEntryParams = {
+  entry_id: string;
+};
+export type TauriCommandShareHistoryEntryResult = "share_sheet" | "email" | "clipboard";
 
 export type TauriCommandStartMicTestParams = TauriCommandDefEmptyParams;
 export type TauriCommandStartMicTestResult = TauriCommandDefVoidResult;
@@ -391,131 +1088,350 @@ export type TauriCommandStopMicTestResult = TauriCommandDefVoidResult;
 export type TauriCommandStopRecordingParams = TauriCommandDefEmptyParams;
 export type TauriCommandStopRecordingResult = TauriCommandDefOpenObject;
 
+export type TauriCommandSyncNowParams = TauriCommandDefEmptyParams;
+export type TauriCommandSyncNowResult = {
+  conflicts: Array<{
+  item: string;
+  kept: "local" | "remote";
+  section: "replacement_rules" | "replacement_groups" | "vocabulary" | "learned_words";
+}>;
+  pulled: number;
+  pushed: number;
+  synced_at: string;
+};
+
+export type TauriCommandTestHotkeyLeakageParams = TauriCommandDefEmptyParams;
+export type TauriCommandTestHotkeyLeakageResult = {
+  hint?: string;
+  hotkey: string;
+  hotkey_detected: boolean;
+  leaked: boolean;
+  suppression: "os_grab" | "os_grab_with_modifier_mask" | "portal" | "unsuppressed";
+};
+
 export type TauriCommandToggleEnabledParams = TauriCommandDefEmptyParams;
 export type TauriCommandToggleEnabledResult = boolean;
 
+export type TauriCommandTranscribeFileParams = {
+  path: string;
+};
+export type TauriCommandTranscribeFileResult = TauriCommandDefOpenObject;
+
 export type TauriCommandUpdateConfigParams = {
   config: TauriCommandDefOpenObject;
 };
 export type TauriCommandUpdateConfigResult = TauriCommandDefVoidResult;
 
-export type TauriCommandName = "can_start_recording" | "cancel_recording" | "clear_history" | "copy_last_transcript" | "copy_transcript" | "download_model" | "export_history" | "generate_diagnostics" | "get_app_state" | "get_available_presets" | "get_capabilities" | "get_capability_issues" | "get_config" | "get_hotkey_status" | "get_model_catalog" | "get_model_status" | "get_recent_logs" | "get_replacement_rules" | "get_transcript_history" | "is_enabled" | "list_audio_devices" | "load_preset" | "preview_replacement" | "purge_model_cache" | "reset_config_to_defaults" | "restart_sidecar" | "run_self_check" | "set_audio_device" | "set_enabled" | "set_hotkey" | "set_replacement_rules" | "start_mic_test" | "start_recording" | "stop_mic_test" | "stop_recording" | "toggle_enabled" | "update_config";
+export type TauriCommandName = "accept_model_license" | "apply_sidecar_update" | "can_start_recording" | "cancel_injection" | "cancel_recording" | "check_app_update" | "check_sidecar_update" | "clear_history" | "clear_history_filtered" | "clear_temporary_replacement_rules" | "compare_models" | "confirm_injection" | "copy_last_transcript" | "copy_transcript" | "delete_crash_reports" | "delete_phrase_shortcut" | "delete_secret" | "dismiss_pending_insert" | "download_app_update" | "download_model" | "edit_transcript" | "export_app_state_snapshot" | "export_history" | "export_subtitles" | "generate_diagnostics" | "get_active_session" | "get_app_icon" | "get_app_state" | "get_available_presets" | "get_capabilities" | "get_capability_issues" | "get_command_audit_log" | "get_config" | "get_current_focus_info" | "get_enable_level" | "get_history_stats" | "get_hotkey_status" | "get_input_gain" | "get_learned_dictionary" | "get_low_confidence_words" | "get_model_catalog" | "get_model_status" | "get_overlay_config" | "get_paste_calibration" | "get_pending_crash_reports" | "get_pending_inserts" | "get_pending_license" | "get_phrase_shortcuts" | "get_recent_logs" | "get_replacement_rules" | "get_session_label" | "get_tag_suggestions" | "get_temporary_replacement_rules" | "get_transcript_diff" | "get_transcript_history" | "get_unfiltered_transcript" | "get_usage_stats" | "get_vocabulary" | "has_secret" | "import_vocabulary" | "inject_pending_insert" | "install_app_update" | "invoke_action" | "is_enabled" | "list_actions" | "list_audio_devices" | "load_preset" | "practice_hotkey" | "preview_replacement" | "purge_model_cache" | "reinject_to_original_target" | "remove_learned_word" | "replay_app_state_snapshot" | "report_hotkey_keydown" | "reset_config_to_defaults" | "reset_paste_calibration" | "restart_sidecar" | "run_self_check" | "save_phrase_shortcut" | "search_transcript_history" | "set_audio_device" | "set_enable_level" | "set_enabled" | "set_hotkey" | "set_injection_mode" | "set_input_gain" | "set_replacement_rules" | "set_secret" | "set_session_label" | "set_temporary_replacement_rules" | "set_transcript_label" | "set_transcript_pinned" | "set_vocabulary" | "share_history_entry" | "start_mic_test" | "start_recording" | "stop_mic_test" | "stop_recording" | "sync_now" | "test_hotkey_leakage" | "toggle_enabled" | "transcribe_file" | "update_config";
 export interface TauriCommandParamsMap {
+  "accept_model_license": TauriCommandAcceptModelLicenseParams;
+  "apply_sidecar_update": TauriCommandApplySidecarUpdateParams;
   "can_start_recording": TauriCommandCanStartRecordingParams;
+  "cancel_injection": TauriCommandCancelInjectionParams;
   "cancel_recording": TauriCommandCancelRecordingParams;
+  "check_app_update": TauriCommandCheckAppUpdateParams;
+  "check_sidecar_update": TauriCommandCheckSidecarUpdateParams;
   "clear_history": TauriCommandClearHistoryParams;
+  "clear_history_filtered": TauriCommandClearHistoryFilteredParams;
+  "clear_temporary_replacement_rules": TauriCommandClearTemporaryReplacementRulesParams;
+  "compare_models": TauriCommandCompareModelsParams;
+  "confirm_injection": TauriCommandConfirmInjectionParams;
   "copy_last_transcript": TauriCommandCopyLastTranscriptParams;
   "copy_transcript": TauriCommandCopyTranscriptParams;
+  "delete_crash_reports": TauriCommandDeleteCrashReportsParams;
+  "delete_phrase_shortcut": TauriCommandDeletePhraseShortcutParams;
+  "delete_secret": TauriCommandDeleteSecretParams;
+  "dismiss_pending_insert": TauriCommandDismissPendingInsertParams;
+  "download_app_update": TauriCommandDownloadAppUpdateParams;
   "download_model": TauriCommandDownloadModelParams;
+  "edit_transcript": TauriCommandEditTranscriptParams;
+  "export_app_state_snapshot": TauriCommandExportAppStateSnapshotParams;
   "export_history": TauriCommandExportHistoryParams;
+  "export_subtitles": TauriCommandExportSubtitlesParams;
   "generate_diagnostics": TauriCommandGenerateDiagnosticsParams;
+  "get_active_session": TauriCommandGetActiveSessionParams;
+  "get_app_icon": TauriCommandGetAppIconParams;
   "get_app_state": TauriCommandGetAppStateParams;
   "get_available_presets": TauriCommandGetAvailablePresetsParams;
   "get_capabilities": TauriCommandGetCapabilitiesParams;
   "get_capability_issues": TauriCommandGetCapabilityIssuesParams;
+  "get_command_audit_log": TauriCommandGetCommandAuditLogParams;
   "get_config": TauriCommandGetConfigParams;
+  "get_current_focus_info": TauriCommandGetCurrentFocusInfoParams;
+  "get_enable_level": TauriCommandGetEnableLevelParams;
+  "get_history_stats": TauriCommandGetHistoryStatsParams;
   "get_hotkey_status": TauriCommandGetHotkeyStatusParams;
+  "get_input_gain": TauriCommandGetInputGainParams;
+  "get_learned_dictionary": TauriCommandGetLearnedDictionaryParams;
+  "get_low_confidence_words": TauriCommandGetLowConfidenceWordsParams;
   "get_model_catalog": TauriCommandGetModelCatalogParams;
   "get_model_status": TauriCommandGetModelStatusParams;
+  "get_overlay_config": TauriCommandGetOverlayConfigParams;
+  "get_paste_calibration": TauriCommandGetPasteCalibrationParams;
+  "get_pending_crash_reports": TauriCommandGetPendingCrashReportsParams;
+  "get_pending_inserts": TauriCommandGetPendingInsertsParams;
+  "get_pending_license": TauriCommandGetPendingLicenseParams;
+  "get_phrase_shortcuts": TauriCommandGetPhraseShortcutsParams;
   "get_recent_logs": TauriCommandGetRecentLogsParams;
   "get_replacement_rules": TauriCommandGetReplacementRulesParams;
+  "get_session_label": TauriCommandGetSessionLabelParams;
+  "get_tag_suggestions": TauriCommandGetTagSuggestionsParams;
+  "get_temporary_replacement_rules": TauriCommandGetTemporaryReplacementRulesParams;
+  "get_transcript_diff": TauriCommandGetTranscriptDiffParams;
   "get_transcript_history": TauriCommandGetTranscriptHistoryParams;
+  "get_unfiltered_transcript": TauriCommandGetUnfilteredTranscriptParams;
+  "get_usage_stats": TauriCommandGetUsageStatsParams;
+  "get_vocabulary": TauriCommandGetVocabularyParams;
+  "has_secret": TauriCommandHasSecretParams;
+  "import_vocabulary": TauriCommandImportVocabularyParams;
+  "inject_pending_insert": TauriCommandInjectPendingInsertParams;
+  "install_app_update": TauriCommandInstallAppUpdateParams;
+  "invoke_action": TauriCommandInvokeActionParams;
   "is_enabled": TauriCommandIsEnabledParams;
+  "list_actions": TauriCommandListActionsParams;
   "list_audio_devices": TauriCommandListAudioDevicesParams;
   "load_preset": TauriCommandLoadPresetParams;
+  "practice_hotkey": TauriCommandPracticeHotkeyParams;
   "preview_replacement": TauriCommandPreviewReplacementParams;
   "purge_model_cache": TauriCommandPurgeModelCacheParams;
+  "reinject_to_original_target": TauriCommandReinjectToOriginalTargetParams;
+  "remove_learned_word": TauriCommandRemoveLearnedWordParams;
+  "replay_app_state_snapshot": TauriCommandReplayAppStateSnapshotParams;
+  "report_hotkey_keydown": TauriCommandReportHotkeyKeydownParams;
   "reset_config_to_defaults": TauriCommandResetConfigToDefaultsParams;
+  "reset_paste_calibration": TauriCommandResetPasteCalibrationParams;
   "restart_sidecar": TauriCommandRestartSidecarParams;
   "run_self_check": TauriCommandRunSelfCheckParams;
+  "save_phrase_shortcut": TauriCommandSavePhraseShortcutParams;
+  "search_transcript_history": TauriCommandSearchTranscriptHistoryParams;
   "set_audio_device": TauriCommandSetAudioDeviceParams;
+  "set_enable_level": TauriCommandSetEnableLevelParams;
   "set_enabled": TauriCommandSetEnabledParams;
   "set_hotkey": TauriCommandSetHotkeyParams;
+  "set_injection_mode": TauriCommandSetInjectionModeParams;
+  "set_input_gain": TauriCommandSetInputGainParams;
   "set_replacement_rules": TauriCommandSetReplacementRulesParams;
+  "set_secret": TauriCommandSetSecretParams;
+  "set_session_label": TauriCommandSetSessionLabelParams;
+  "set_temporary_replacement_rules": TauriCommandSetTemporaryReplacementRulesParams;
+  "set_transcript_label": TauriCommandSetTranscriptLabelParams;
+  "set_transcript_pinned": TauriCommandSetTranscriptPinnedParams;
+  "set_vocabulary": TauriCommandSetVocabularyParams;
+  "share_history_entry": TauriCommandShareHistoryEntryParams;
   "start_mic_test": TauriCommandStartMicTestParams;
   "start_recording": TauriCommandStartRecordingParams;
   "stop_mic_test": TauriCommandStopMicTestParams;
   "stop_recording": TauriCommandStopRecordingParams;
+  "sync_now": TauriCommandSyncNowParams;
+  "test_hotkey_leakage": TauriCommandTestHotkeyLeakageParams;
   "toggle_enabled": TauriCommandToggleEnabledParams;
+  "transcribe_file": TauriCommandTranscribeFileParams;
   "update_config": TauriCommandUpdateConfigParams;
 }
 export interface TauriCommandResultMap {
+  "accept_model_license": TauriCommandAcceptModelLicenseResult;
+  "apply_sidecar_update": TauriCommandApplySidecarUpdateResult;
   "can_start_recording": TauriCommandCanStartRecordingResult;
+  "cancel_injection": TauriCommandCancelInjectionResult;
   "cancel_recording": TauriCommandCancelRecordingResult;
+  "check_app_update": TauriCommandCheckAppUpdateResult;
+  "check_sidecar_update": TauriCommandCheckSidecarUpdateResult;
   "clear_history": TauriCommandClearHistoryResult;
+  "clear_history_filtered": TauriCommandClearHistoryFilteredResult;
+  "clear_temporary_replacement_rules": TauriCommandClearTemporaryReplacementRulesResult;
+  "compare_models": TauriCommandCompareModelsResult;
+  "confirm_injection": TauriCommandConfirmInjectionResult;
   "copy_last_transcript": TauriCommandCopyLastTranscriptResult;
   "copy_transcript": TauriCommandCopyTranscriptResult;
+  "delete_crash_reports": TauriCommandDeleteCrashReportsResult;
+  "delete_phrase_shortcut": TauriCommandDeletePhraseShortcutResult;
+  "delete_secret": TauriCommandDeleteSecretResult;
+  "dismiss_pending_insert": TauriCommandDismissPendingInsertResult;
+  "download_app_update": TauriCommandDownloadAppUpdateResult;
   "download_model": TauriCommandDownloadModelResult;
+  "edit_transcript": TauriCommandEditTranscriptResult;
+  "export_app_state_snapshot": TauriCommandExportAppStateSnapshotResult;
   "export_history": TauriCommandExportHistoryResult;
+  "export_subtitles": TauriCommandExportSubtitlesResult;
   "generate_diagnostics": TauriCommandGenerateDiagnosticsResult;
+  "get_active_session": TauriCommandGetActiveSessionResult;
+  "get_app_icon": TauriCommandGetAppIconResult;
   "get_app_state": TauriCommandGetAppStateResult;
   "get_available_presets": TauriCommandGetAvailablePresetsResult;
   "get_capabilities": TauriCommandGetCapabilitiesResult;
   "get_capability_issues": TauriCommandGetCapabilityIssuesResult;
+  "get_command_audit_log": TauriCommandGetCommandAuditLogResult;
   "get_config": TauriCommandGetConfigResult;
+  "get_current_focus_info": TauriCommandGetCurrentFocusInfoResult;
+  "get_enable_level": TauriCommandGetEnableLevelResult;
+  "get_history_stats": TauriCommandGetHistoryStatsResult;
   "get_hotkey_status": TauriCommandGetHotkeyStatusResult;
+  "get_input_gain": TauriCommandGetInputGainResult;
+  "get_learned_dictionary": TauriCommandGetLearnedDictionaryResult;
+  "get_low_confidence_words": TauriCommandGetLowConfidenceWordsResult;
   "get_model_catalog": TauriCommandGetModelCatalogResult;
   "get_model_status": TauriCommandGetModelStatusResult;
+  "get_overlay_config": TauriCommandGetOverlayConfigResult;
+  "get_paste_calibration": TauriCommandGetPasteCalibrationResult;
+  "get_pending_crash_reports": TauriCommandGetPendingCrashReportsResult;
+  "get_pending_inserts": TauriCommandGetPendingInsertsResult;
+  "get_pending_license": TauriCommandGetPendingLicenseResult;
+  "get_phrase_shortcuts": TauriCommandGetPhraseShortcutsResult;
   "get_recent_logs": TauriCommandGetRecentLogsResult;
   "get_replacement_rules": TauriCommandGetReplacementRulesResult;
+  "get_session_label": TauriCommandGetSessionLabelResult;
+  "get_tag_suggestions": TauriCommandGetTagSuggestionsResult;
+  "get_temporary_replacement_rules": TauriCommandGetTemporaryReplacementRulesResult;
+  "get_transcript_diff": TauriCommandGetTranscriptDiffResult;
   "get_transcript_history": TauriCommandGetTranscriptHistoryResult;
+  "get_unfiltered_transcript": TauriCommandGetUnfilteredTranscriptResult;
+  "get_usage_stats": TauriCommandGetUsageStatsResult;
+  "get_vocabulary": TauriCommandGetVocabularyResult;
+  "has_secret": TauriCommandHasSecretResult;
+  "import_vocabulary": TauriCommandImportVocabularyResult;
+  "inject_pending_insert": TauriCommandInjectPendingInsertResult;
+  "install_app_update": TauriCommandInstallAppUpdateResult;
+  "invoke_action": TauriCommandInvokeActionResult;
   "is_enabled": TauriCommandIsEnabledResult;
+  "list_actions": TauriCommandListActionsResult;
   "list_audio_devices": TauriCommandListAudioDevicesResult;
   "load_preset": TauriCommandLoadPresetResult;
+  "practice_hotkey": TauriCommandPracticeHotkeyResult;
   "preview_replacement": TauriCommandPreviewReplacementResult;
   "purge_model_cache": TauriCommandPurgeModelCacheResult;
+  "reinject_to_original_target": TauriCommandReinjectToOriginalTargetResult;
+  "remove_learned_word": TauriCommandRemoveLearnedWordResult;
+  "replay_app_state_snapshot": TauriCommandReplayAppStateSnapshotResult;
+  "report_hotkey_keydown": TauriCommandReportHotkeyKeydownResult;
   "reset_config_to_defaults": TauriCommandResetConfigToDefaultsResult;
+  "reset_paste_calibration": TauriCommandResetPasteCalibrationResult;
   "restart_sidecar": TauriCommandRestartSidecarResult;
   "run_self_check": TauriCommandRunSelfCheckResult;
+  "save_phrase_shortcut": TauriCommandSavePhraseShortcutResult;
+  "search_transcript_history": TauriCommandSearchTranscriptHistoryResult;
   "set_audio_device": TauriCommandSetAudioDeviceResult;
+  "set_enable_level": TauriCommandSetEnableLevelResult;
   "set_enabled": TauriCommandSetEnabledResult;
   "set_hotkey": TauriCommandSetHotkeyResult;
+  "set_injection_mode": TauriCommandSetInjectionModeResult;
+  "set_input_gain": TauriCommandSetInputGainResult;
   "set_replacement_rules": TauriCommandSetReplacementRulesResult;
+  "set_secret": TauriCommandSetSecretResult;
+  "set_session_label": TauriCommandSetSessionLabelResult;
+  "set_temporary_replacement_rules": TauriCommandSetTemporaryReplacementRulesResult;
+  "set_transcript_label": TauriCommandSetTranscriptLabelResult;
+  "set_transcript_pinned": TauriCommandSetTranscriptPinnedResult;
+  "set_vocabulary": TauriCommandSetVocabularyResult;
+  "share_history_entry": TauriCommandShareHistoryEntryResult;
   "start_mic_test": TauriCommandStartMicTestResult;
   "start_recording": TauriCommandStartRecordingResult;
   "stop_mic_test": TauriCommandStopMicTestResult;
   "stop_recording": TauriCommandStopRecordingResult;
+  "sync_now": TauriCommandSyncNowResult;
+  "test_hotkey_leakage": TauriCommandTestHotkeyLeakageResult;
   "toggle_enabled": TauriCommandToggleEnabledResult;
+  "transcribe_file": TauriCommandTranscribeFileResult;
   "update_config": TauriCommandUpdateConfigResult;
 }
 
 // Tauri event payloads
 export type TauriEventAppErrorPayload = TauriEventDefAppErrorPayload;
 
+export type TauriEventAppNavigatePayload = TauriEventDefAppNavigatePayload;
+
+export type TauriEventAppRecoveryPayload = TauriEventDefAppRecoveryPayload;
+
+export type TauriEventAppUpdateProgressPayload = TauriEventDefAppUpdateProgressPayload;
+
 export type TauriEventAudioLevelPayload = TauriEventDefAudioLevelPayload;
 
+export type TauriEventCapabilitiesIssuesPayload = TauriEventDefCapabilitiesIssuesPayload;
+
+export type TauriEventCuesStatusPayload = TauriEventDefCuesStatusPayload;
+
+export type TauriEventFileTranscriptionProgressPayload = TauriEventDefFileTranscriptionProgressPayload;
+
+export type TauriEventFocusChangedPayload = TauriEventDefFocusChangedPayload;
+
+export type TauriEventInjectionConfirmRequestedPayload = TauriEventDefInjectionConfirmRequestedPayload;
+
+export type TauriEventInjectionDeferredPayload = TauriEventDefInjectionDeferredPayload;
+
+export type TauriEventInjectionModeChangedPayload = TauriEventDefInjectionModeChangedPayload;
+
+export type TauriEventInjectionProgressPayload = TauriEventDefInjectionProgressPayload;
+
+export type TauriEventInjectionQueueChangedPayload = TauriEventDefInjectionQueueChangedPayload;
+
 export type TauriEventModelProgressPayload = TauriEventDefModelProgressPayload;
 
 export type TauriEventModelStatusPayload = TauriEventDefModelStatusPayload;
 
+export type TauriEventOverlayCancelledPayload = TauriEventDefOverlayCancelledPayload;
+
+export type TauriEventOverlayConfigPayload = TauriEventDefOverlayConfigPayload;
+
+export type TauriEventOverlayCountdownPayload = TauriEventDefOverlayCountdownPayload;
+
 export type TauriEventOverlayTogglePayload = TauriEventDefOverlayTogglePayload;
 
+export type TauriEventOverlayTranscribingPayload = TauriEventDefOverlayTranscribingPayload;
+
+export type TauriEventPendingInsertsChangedPayload = TauriEventDefPendingInsertsChangedPayload;
+
 export type TauriEventRecordingStatusPayload = TauriEventDefRecordingStatusPayload;
 
+export type TauriEventSessionChangedPayload = TauriEventDefSessionChangedPayload;
+
 export type TauriEventSidecarStatusPayload = TauriEventDefSidecarStatusPayload;
 
+export type TauriEventSidecarUpdateStatusPayload = TauriEventDefSidecarUpdateStatusPayload;
+
 export type TauriEventStateChangedPayload = TauriEventDefStateChangedPayload;
 
 export type TauriEventTranscriptCompletePayload = TauriEventDefTranscriptCompletePayload;
 
 export type TauriEventTranscriptErrorPayload = TauriEventDefTranscriptErrorPayload;
 
-export type TauriEventName = "app:error" | "audio:level" | "model:progress" | "model:status" | "overlay:toggle" | "recording:status" | "sidecar:status" | "state:changed" | "transcript:complete" | "transcript:error";
+export type TauriEventVadPreviewPayload = TauriEventDefVadPreviewPayload;
+
+export type TauriEventName = "app:error" | "app:navigate" | "app:recovery" | "app:update_progress" | "audio:level" | "capabilities:issues" | "cues:status" | "file_transcription:progress" | "focus:changed" | "injection:confirm_requested" | "injection:deferred" | "injection:mode_changed" | "injection:progress" | "injection:queue_changed" | "model:progress" | "model:status" | "overlay:cancelled" | "overlay:config" | "overlay:countdown" | "overlay:toggle" | "overlay:transcribing" | "pending_inserts:changed" | "recording:status" | "session:changed" | "sidecar:status" | "sidecar:update_status" | "state:changed" | "transcript:complete" | "transcript:error" | "vad:preview";
 export interface TauriEventPayloadMap {
   "app:error": TauriEventAppErrorPayload;
+  "app:navigate": TauriEventAppNavigatePayload;
+  "app:recovery": TauriEventAppRecoveryPayload;
+  "app:update_progress": TauriEventAppUpdateProgressPayload;
   "audio:level": TauriEventAudioLevelPayload;
+  "capabilities:issues": TauriEventCapabilitiesIssuesPayload;
+  "cues:status": TauriEventCuesStatusPayload;
+  "file_transcription:progress": TauriEventFileTranscriptionProgressPayload;
+  "focus:changed": TauriEventFocusChangedPayload;
+  "injection:confirm_requested": TauriEventInjectionConfirmRequestedPayload;
+  "injection:deferred": TauriEventInjectionDeferredPayload;
+  "injection:mode_changed": TauriEventInjectionModeChangedPayload;
+  "injection:progress": TauriEventInjectionProgressPayload;
+  "injection:queue_changed": TauriEventInjectionQueueChangedPayload;
   "model:progress": TauriEventModelProgressPayload;
   "model:status": TauriEventModelStatusPayload;
+  "overlay:cancelled": TauriEventOverlayCancelledPayload;
+  "overlay:config": TauriEventOverlayConfigPayload;
+  "overlay:countdown": TauriEventOverlayCountdownPayload;
   "overlay:toggle": TauriEventOverlayTogglePayload;
+  "overlay:transcribing": TauriEventOverlayTranscribingPayload;
+  "pending_inserts:changed": TauriEventPendingInsertsChangedPayload;
   "recording:status": TauriEventRecordingStatusPayload;
+  "session:changed": TauriEventSessionChangedPayload;
   "sidecar:status": TauriEventSidecarStatusPayload;
+  "sidecar:update_status": TauriEventSidecarUpdateStatusPayload;
   "state:changed": TauriEventStateChangedPayload;
   "transcript:complete": TauriEventTranscriptCompletePayload;
   "transcript:error": TauriEventTranscriptErrorPayload;
+  "vad:preview": TauriEventVadPreviewPayload;
 }
 
 // Sidecar RPC method params/results
 export type SidecarRpcMethodAsrInitializeParams = {
+  beam_size?: number | null;
   device_pref?: "auto" | "cuda" | "cpu";
   language?: string | null;
   model_id?: string;
@@ -551,6 +1467,23 @@ export type SidecarRpcMethodAsrTranscribeResult = {
   [key: string]: unknown;
 };
 
+export type SidecarRpcMethodAsrUnloadParams = {
+};
+export type SidecarRpcMethodAsrUnloadResult = {
+  ready: boolean;
+  state: string;
+  [key: string]: unknown;
+};
+
+export type SidecarRpcMethodAudioGetGainParams = {
+  device_uid?: string | null;
+};
+export type SidecarRpcMethodAudioGetGainResult = {
+  device_uid: string | null;
+  gain_db: number;
+  [key: string]: unknown;
+};
+
 export type SidecarRpcMethodAudioListDevicesParams = {
 };
 export type SidecarRpcMethodAudioListDevicesResult = {
@@ -560,11 +1493,16 @@ export type SidecarRpcMethodAudioListDevicesResult = {
 
 export type SidecarRpcMethodAudioMeterStartParams = {
   device_uid?: string | null;
+  input_gains?: Record<string, number>;
   interval_ms?: number;
+  vad_min_speech_ms?: number;
+  vad_preview?: boolean;
+  vad_silence_ms?: number;
 };
 export type SidecarRpcMethodAudioMeterStartResult = {
   interval_ms: number;
   running: boolean;
+  vad_preview?: boolean;
   [key: string]: unknown;
 };
 
@@ -591,17 +1529,89 @@ export type SidecarRpcMethodAudioSetDeviceResult = {
   [key: string]: unknown;
 };
 
+export type SidecarRpcMethodAudioSetGainParams = {
+  device_uid?: string | null;
+  gain_db: number;
+};
+export type SidecarRpcMethodAudioSetGainResult = {
+  device_uid: string;
+  gain_db: number;
+  [key: string]: unknown;
+};
+
+export type SidecarRpcMethodBatchCompareModelsParams = {
+  audio_path: string;
+  device_pref?: string;
+  language?: string | null;
+  model_ids: Array<string>;
+};
+export type SidecarRpcMethodBatchCompareModelsResult = {
+  audio_duration_ms: number;
+  results: Array<{
+  confidence?: number;
+  error?: {
+  code: string;
+  message: string;
+  [key: string]: unknown;
+};
+  load_ms?: number;
+  model_id: string;
+  text?: string;
+  transcribe_ms?: number;
+  [key: string]: unknown;
+}>;
+  [key: string]: unknown;
+};
+
+export type SidecarRpcMethodFileTranscribeParams = {
+  audio_path: string;
+  beam_size?: number | null;
+  device_pref?: string;
+  diarize?: boolean;
+  language?: string | null;
+  model_id: string;
+};
+export type SidecarRpcMethodFileTranscribeResult = {
+  confidence?: number;
+  duration_ms?: number;
+  language?: string;
+  segments?: Array<{
+  end_ms: number;
+  speaker?: string;
+  start_ms: number;
+  text: string;
+  [key: string]: unknown;
+}>;
+  text: string;
+  words?: Array<{
+  confidence?: number;
+  end_ms: number;
+  start_ms: number;
+  word: string;
+  [key: string]: unknown;
+}>;
+  [key: string]: unknown;
+};
+
 export type SidecarRpcMethodModelDownloadParams = {
+  license_accepted?: boolean;
   model_id?: string;
   [key: string]: unknown;
 };
 export type SidecarRpcMethodModelDownloadResult = {
   cache_path?: string;
   error_message?: string;
+  license?: {
+  name: string;
+  spdx_id?: string;
+  text: string;
+  url?: string;
+  [key: string]: unknown;
+};
   model_id?: string;
   progress?: Record<string, unknown>;
   revision?: string;
-  status: "missing" | "downloading" | "verifying" | "ready" | "error";
+  status: "missing" | "downloading" | "verifying" | "ready" | "error" | "license_required";
   [key: string]: unknown;
 };
 
@@ -618,16 +1628,24 @@ export type SidecarRpcMethodModelGetStatusResult = {
 };
 
 export type SidecarRpcMethodModelInstallParams = {
+  license_accepted?: boolean;
   model_id?: string;
   [key: string]: unknown;
 };
 export type SidecarRpcMethodModelInstallResult = {
   cache_path?: string;
   error_message?: string;
+  license?: {
+  name: string;
+  spdx_id?: string;
+  text: string;
+  url?: string;
+  [key: string]: unknown;
+};
   model_id?: string;
   progress?: Record<string, unknown>;
   revision?: string;
-  status: "missing" | "downloading" | "verifying" | "ready" | "error";
+  status: "missing" | "downloading" | "verifying" | "ready" | "error" | "license_required";
   [key: string]: unknown;
 };
 
@@ -648,8 +1666,35 @@ export type SidecarRpcMethodRecordingCancelResult = {
   [key: string]: unknown;
 };
 
+export type SidecarRpcMethodRecordingExportAudioParams = {
+  audio_path: string;
+  session_id: string;
+};
+export type SidecarRpcMethodRecordingExportAudioResult = {
+  audio_path: string;
+  duration_ms: number;
+  session_id: string;
+  [key: string]: unknown;
+};
+
+export type SidecarRpcMethodRecordingSplitParams = {
+  session_id: string;
+};
+export type SidecarRpcMethodRecordingSplitResult = {
+  audio_duration_ms: number;
+  segment_index: number;
+  session_id: string;
+  [key: string]: unknown;
+};
+
 export type SidecarRpcMethodRecordingStartParams = {
+  continuous?: boolean;
   device_uid?: string | null;
+  diarize?: boolean;
+  flush_interval_ms?: number;
+  hotwords?: Array<string>;
+  input_gains?: Record<string, number>;
+  punctuate?: boolean;
   session_id?: string;
   [key: string]: unknown;
 };
@@ -779,23 +1824,63 @@ export type SidecarRpcMethodSystemShutdownResult = {
   [key: string]: unknown;
 };
 
-export type SidecarRpcMethodName = "asr.initialize" | "asr.status" | "asr.transcribe" | "audio.list_devices" | "audio.meter_start" | "audio.meter_status" | "audio.meter_stop" | "audio.set_device" | "model.download" | "model.get_status" | "model.install" | "model.purge_cache" | "recording.cancel" | "recording.start" | "recording.status" | "recording.stop" | "replacements.get_preset_rules" | "replacements.get_presets" | "replacements.get_rules" | "replacements.preview" | "replacements.set_rules" | "status.get" | "system.info" | "system.ping" | "system.shutdown";
+export type SidecarRpcMethodTranscriptionGetResultParams = {
+  session_id: string;
+};
+export type SidecarRpcMethodTranscriptionGetResultResult = {
+  error?: {
+  kind: string;
+  message: string;
+  [key: string]: unknown;
+};
+  result?: {
+  confidence?: number;
+  duration_ms: number;
+  final_text?: string;
+  language?: string;
+  raw_text?: string;
+  session_id: string;
+  text: string;
+  [key: string]: unknown;
+};
+  session_id: string;
+  state: "pending" | "completed" | "error" | "cancelled" | "unknown";
+  [key: string]: unknown;
+};
+
+export type SidecarRpcMethodTranscriptionRetryParams = {
+  session_id: string;
+};
+export type SidecarRpcMethodTranscriptionRetryResult = {
+  retrying: boolean;
+  session_id: string;
+  [key: string]: unknown;
+};
+
+export type SidecarRpcMethodName = "asr.initialize" | "asr.status" | "asr.transcribe" | "asr.unload" | "audio.get_gain" | "audio.list_devices" | "audio.meter_start" | "audio.meter_status" | "audio.meter_stop" | "audio.set_device" | "audio.set_gain" | "batch.compare_models" | "file.transcribe" | "model.download" | "model.get_status" | "model.install" | "model.purge_cache" | "recording.cancel" | "recording.export_audio" | "recording.split" | "recording.start" | "recording.status" | "recording.stop" | "replacements.get_preset_rules" | "replacements.get_presets" | "replacements.get_rules" | "replacements.preview" | "replacements.set_rules" | "status.get" | "system.info" | "system.ping" | "system.shutdown" | "transcription.get_result" | "transcription.retry";
 export type SidecarRpcRequiredMethodName = "asr.initialize" | "audio.list_devices" | "audio.meter_start" | "audio.meter_stop" | "audio.set_device" | "model.get_status" | "model.purge_cache" | "recording.cancel" | "recording.start" | "recording.stop" | "replacements.get_presets" | "replacements.get_rules" | "replacements.set_rules" | "status.get" | "system.info" | "system.ping" | "system.shutdown";
-export type SidecarRpcOptionalMethodName = "asr.status" | "asr.transcribe" | "audio.meter_status" | "model.download" | "model.install" | "recording.status" | "replacements.get_preset_rules" | "replacements.preview";
+export type SidecarRpcOptionalMethodName = "asr.status" | "asr.transcribe" | "asr.unload" | "audio.get_gain" | "audio.meter_status" | "audio.set_gain" | "batch.compare_models" | "file.transcribe" | "model.download" | "model.install" | "recording.export_audio" | "recording.split" | "recording.status" | "replacements.get_preset_rules" | "replacements.preview" | "transcription.get_result" | "transcription.retry";
 export interface SidecarRpcMethodParamsMap {
   "asr.initialize": SidecarRpcMethodAsrInitializeParams;
   "asr.status": SidecarRpcMethodAsrStatusParams;
   "asr.transcribe": SidecarRpcMethodAsrTranscribeParams;
+  "asr.unload": SidecarRpcMethodAsrUnloadParams;
+  "audio.get_gain": SidecarRpcMethodAudioGetGainParams;
   "audio.list_devices": SidecarRpcMethodAudioListDevicesParams;
   "audio.meter_start": SidecarRpcMethodAudioMeterStartParams;
   "audio.meter_status": SidecarRpcMethodAudioMeterStatusParams;
   "audio.meter_stop": SidecarRpcMethodAudioMeterStopParams;
   "audio.set_device": SidecarRpcMethodAudioSetDeviceParams;
+  "audio.set_gain": SidecarRpcMethodAudioSetGainParams;
+  "batch.compare_models": SidecarRpcMethodBatchCompareModelsParams;
+  "file.transcribe": SidecarRpcMethodFileTranscribeParams;
   "model.download": SidecarRpcMethodModelDownloadParams;
   "model.get_status": SidecarRpcMethodModelGetStatusParams;
   "model.install": SidecarRpcMethodModelInstallParams;
   "model.purge_cache": SidecarRpcMethodModelPurgeCacheParams;
   "recording.cancel": SidecarRpcMethodRecordingCancelParams;
+  "recording.export_audio": SidecarRpcMethodRecordingExportAudioParams;
+  "recording.split": SidecarRpcMethodRecordingSplitParams;
   "recording.start": SidecarRpcMethodRecordingStartParams;
   "recording.status": SidecarRpcMethodRecordingStatusParams;
   "recording.stop": SidecarRpcMethodRecordingStopParams;
@@ -808,21 +1893,30 @@ export interface SidecarRpcMethodParamsMap {
   "system.info": SidecarRpcMethodSystemInfoParams;
   "system.ping": SidecarRpcMethodSystemPingParams;
   "system.shutdown": SidecarRpcMethodSystemShutdownParams;
+  "transcription.get_result": SidecarRpcMethodTranscriptionGetResultParams;
+  "transcription.retry": SidecarRpcMethodTranscriptionRetryParams;
 }
 export interface SidecarRpcMethodResultMap {
   "asr.initialize": SidecarRpcMethodAsrInitializeResult;
   "asr.status": SidecarRpcMethodAsrStatusResult;
   "asr.transcribe": SidecarRpcMethodAsrTranscribeResult;
+  "asr.unload": SidecarRpcMethodAsrUnloadResult;
+  "audio.get_gain": SidecarRpcMethodAudioGetGainResult;
   "audio.list_devices": SidecarRpcMethodAudioListDevicesResult;
   "audio.meter_start": SidecarRpcMethodAudioMeterStartResult;
   "audio.meter_status": SidecarRpcMethodAudioMeterStatusResult;
   "audio.meter_stop": SidecarRpcMethodAudioMeterStopResult;
   "audio.set_device": SidecarRpcMethodAudioSetDeviceResult;
+  "audio.set_gain": SidecarRpcMethodAudioSetGainResult;
+  "batch.compare_models": SidecarRpcMethodBatchCompareModelsResult;
+  "file.transcribe": SidecarRpcMethodFileTranscribeResult;
   "model.download": SidecarRpcMethodModelDownloadResult;
   "model.get_status": SidecarRpcMethodModelGetStatusResult;
   "model.install": SidecarRpcMethodModelInstallResult;
   "model.purge_cache": SidecarRpcMethodModelPurgeCacheResult;
   "recording.cancel": SidecarRpcMethodRecordingCancelResult;
+  "recording.export_audio": SidecarRpcMethodRecordingExportAudioResult;
+  "recording.split": SidecarRpcMethodRecordingSplitResult;
   "recording.start": SidecarRpcMethodRecordingStartResult;
   "recording.status": SidecarRpcMethodRecordingStatusResult;
   "recording.stop": SidecarRpcMethodRecordingStopResult;
@@ -835,6 +1929,8 @@ export interface SidecarRpcMethodResultMap {
   "system.info": SidecarRpcMethodSystemInfoResult;
   "system.ping": SidecarRpcMethodSystemPingResult;
   "system.shutdown": SidecarRpcMethodSystemShutdownResult;
+  "transcription.get_result": SidecarRpcMethodTranscriptionGetResultResult;
+  "transcription.retry": SidecarRpcMethodTranscriptionRetryResult;
 }
 
 // Sidecar RPC notification params
@@ -860,7 +1956,9 @@ export type SidecarRpcNotificationEventModelProgressParams = {
 export type SidecarRpcNotificationEventStatusChangedParams = {
   detail?: string;
   model?: Record<string, unknown>;
+  percent?: number;
   progress?: Record<string, unknown>;
+  stage?: string;
   state: string;
   [key: string]: unknown;
 };
@@ -868,8 +1966,22 @@ export type SidecarRpcNotificationEventStatusChangedParams = {
 export type SidecarRpcNotificationEventTranscriptionCompleteParams = {
   confidence?: number;
   duration_ms: number;
+  segments?: Array<{
+  end_ms: number;
+  speaker?: string;
+  start_ms: number;
+  text: string;
+  [key: string]: unknown;
+}>;
   session_id: string;
   text: string;
+  words?: Array<{
+  confidence?: number;
+  end_ms: number;
+  start_ms: number;
+  word: string;
+  [key: string]: unknown;
+}>;
   [key: string]: unknown;
 };
 
@@ -880,80 +1992,196 @@ export type SidecarRpcNotificationEventTranscriptionErrorParams = {
   [key: string]: unknown;
 };
 
-export type SidecarRpcNotificationName = "event.audio_level" | "event.model_progress" | "event.status_changed" | "event.transcription_complete" | "event.transcription_error";
+export type SidecarRpcNotificationEventUtteranceCompleteParams = {
+  audio_duration_ms?: number;
+  confidence?: number;
+  duration_ms: number;
+  language?: string;
+  raw_text?: string;
+  segment_index: number;
+  session_id: string;
+  text: string;
+  [key: string]: unknown;
+};
+
+export type SidecarRpcNotificationEventVadPreviewParams = {
+  silence_ms: number;
+  silence_threshold_ms: number;
+  source: "meter";
+  speech_ms: number;
+  state: "waiting_for_speech" | "speech" | "silence" | "auto_stop";
+  [key: string]: unknown;
+};
+
+export type SidecarRpcNotificationName = "event.audio_level" | "event.model_progress" | "event.status_changed" | "event.transcription_complete" | "event.transcription_error" | "event.utterance_complete" | "event.vad_preview";
 export interface SidecarRpcNotificationParamsMap {
   "event.audio_level": SidecarRpcNotificationEventAudioLevelParams;
   "event.model_progress": SidecarRpcNotificationEventModelProgressParams;
   "event.status_changed": SidecarRpcNotificationEventStatusChangedParams;
   "event.transcription_complete": SidecarRpcNotificationEventTranscriptionCompleteParams;
   "event.transcription_error": SidecarRpcNotificationEventTranscriptionErrorParams;
+  "event.utterance_complete": SidecarRpcNotificationEventUtteranceCompleteParams;
+  "event.vad_preview": SidecarRpcNotificationEventVadPreviewParams;
 }
 
 // Command name constants
+export const COMMAND_ACCEPT_MODEL_LICENSE = "accept_model_license" as const;
+export const COMMAND_APPLY_SIDECAR_UPDATE = "apply_sidecar_update" as const;
 export const COMMAND_CAN_START_RECORDING = "can_start_recording" as const;
+export const COMMAND_CANCEL_INJECTION = "cancel_injection" as const;
 export const COMMAND_CANCEL_RECORDING = "cancel_recording" as const;
+export const COMMAND_CHECK_APP_UPDATE = "check_app_update" as const;
+export const COMMAND_CHECK_SIDECAR_UPDATE = "check_sidecar_update" as const;
 export const COMMAND_CLEAR_HISTORY = "clear_history" as const;
+export const COMMAND_CLEAR_HISTORY_FILTERED = "clear_history_filtered" as const;
+export const COMMAND_CLEAR_TEMPORARY_REPLACEMENT_RULES = "clear_temporary_replacement_rules" as const;
+export const COMMAND_COMPARE_MODELS = "compare_models" as const;
+export const COMMAND_CONFIRM_INJECTION = "confirm_injection" as const;
 export const COMMAND_COPY_LAST_TRANSCRIPT = "copy_last_transcript" as const;
 export const COMMAND_COPY_TRANSCRIPT = "copy_transcript" as const;
+export const COMMAND_DELETE_CRASH_REPORTS = "delete_crash_reports" as const;
+export const COMMAND_DELETE_PHRASE_SHORTCUT = "delete_phrase_shortcut" as const;
+export const COMMAND_DELETE_SECRET = "delete_secret" as const;
+export const COMMAND_DISMISS_PENDING_INSERT = "dismiss_pending_insert" as const;
+export const COMMAND_DOWNLOAD_APP_UPDATE = "download_app_update" as const;
 export const COMMAND_DOWNLOAD_MODEL = "download_model" as const;
+export const COMMAND_EDIT_TRANSCRIPT = "edit_transcript" as const;
+export const COMMAND_EXPORT_APP_STATE_SNAPSHOT = "export_app_state_snapshot" as const;
 export const COMMAND_EXPORT_HISTORY = "export_history" as const;
+export const COMMAND_EXPORT_SUBTITLES = "export_subtitles" as const;
 export const COMMAND_GENERATE_DIAGNOSTICS = "generate_diagnostics" as const;
+export const COMMAND_GET_ACTIVE_SESSION = "get_active_session" as const;
+export const COMMAND_GET_APP_ICON = "get_app_icon" as const;
 export const COMMAND_GET_APP_STATE = "get_app_state" as const;
 export const COMMAND_GET_AVAILABLE_PRESETS = "get_available_presets" as const;
 export const COMMAND_GET_CAPABILITIES = "get_capabilities" as const;
 export const COMMAND_GET_CAPABILITY_ISSUES = "get_capability_issues" as const;
+export const COMMAND_GET_COMMAND_AUDIT_LOG = "get_command_audit_log" as const;
 export const COMMAND_GET_CONFIG = "get_config" as const;
+export const COMMAND_GET_CURRENT_FOCUS_INFO = "get_current_focus_info" as const;
+export const COMMAND_GET_ENABLE_LEVEL = "get_enable_level" as const;
+export const COMMAND_GET_HISTORY_STATS = "get_history_stats" as const;
 export const COMMAND_GET_HOTKEY_STATUS = "get_hotkey_status" as const;
+export const COMMAND_GET_INPUT_GAIN = "get_input_gain" as const;
+export const COMMAND_GET_LEARNED_DICTIONARY = "get_learned_dictionary" as const;
+export const COMMAND_GET_LOW_CONFIDENCE_WORDS = "get_low_confidence_words" as const;
 export const COMMAND_GET_MODEL_CATALOG = "get_model_catalog" as const;
 export const COMMAND_GET_MODEL_STATUS = "get_model_status" as const;
+export const COMMAND_GET_OVERLAY_CONFIG = "get_overlay_config" as const;
+export const COMMAND_GET_PASTE_CALIBRATION = "get_paste_calibration" as const;
+export const COMMAND_GET_PENDING_CRASH_REPORTS = "get_pending_crash_reports" as const;
+export const COMMAND_GET_PENDING_INSERTS = "get_pending_inserts" as const;
+export const COMMAND_GET_PENDING_LICENSE = "get_pending_license" as const;
+export const COMMAND_GET_PHRASE_SHORTCUTS = "get_phrase_shortcuts" as const;
 export const COMMAND_GET_RECENT_LOGS = "get_recent_logs" as const;
 export const COMMAND_GET_REPLACEMENT_RULES = "get_replacement_rules" as const;
+export const COMMAND_GET_SESSION_LABEL = "get_session_label" as const;
+export const COMMAND_GET_TAG_SUGGESTIONS = "get_tag_suggestions" as const;
+export const COMMAND_GET_TEMPORARY_REPLACEMENT_RULES = "get_temporary_replacement_rules" as const;
+export const COMMAND_GET_TRANSCRIPT_DIFF = "get_transcript_diff" as const;
 export const COMMAND_GET_TRANSCRIPT_HISTORY = "get_transcript_history" as const;
+export const COMMAND_GET_UNFILTERED_TRANSCRIPT = "get_unfiltered_transcript" as const;
+export const COMMAND_GET_USAGE_STATS = "get_usage_stats" as const;
+export const COMMAND_GET_VOCABULARY = "get_vocabulary" as const;
+export const COMMAND_HAS_SECRET = "has_secret" as const;
+export const COMMAND_IMPORT_VOCABULARY = "import_vocabulary" as const;
+export const COMMAND_INJECT_PENDING_INSERT = "inject_pending_insert" as const;
+export const COMMAND_INSTALL_APP_UPDATE = "install_app_update" as const;
+export const COMMAND_INVOKE_ACTION = "invoke_action" as const;
 export const COMMAND_IS_ENABLED = "is_enabled" as const;
+export const COMMAND_LIST_ACTIONS = "list_actions" as const;
 export const COMMAND_LIST_AUDIO_DEVICES = "list_audio_devices" as const;
 export const COMMAND_LOAD_PRESET = "load_preset" as const;
+export const COMMAND_PRACTICE_HOTKEY = "practice_hotkey" as const;
 export const COMMAND_PREVIEW_REPLACEMENT = "preview_replacement" as const;
 export const COMMAND_PURGE_MODEL_CACHE = "purge_model_cache" as const;
+export const COMMAND_REINJECT_TO_ORIGINAL_TARGET = "reinject_to_original_target" as const;
+export const COMMAND_REMOVE_LEARNED_WORD = "remove_learned_word" as const;
+export const COMMAND_REPLAY_APP_STATE_SNAPSHOT = "replay_app_state_snapshot" as const;
+export const COMMAND_REPORT_HOTKEY_KEYDOWN = "report_hotkey_keydown" as const;
 export const COMMAND_RESET_CONFIG_TO_DEFAULTS = "reset_config_to_defaults" as const;
+export const COMMAND_RESET_PASTE_CALIBRATION = "reset_paste_calibration" as const;
 export const COMMAND_RESTART_SIDECAR = "restart_sidecar" as const;
 export const COMMAND_RUN_SELF_CHECK = "run_self_check" as const;
+export const COMMAND_SAVE_PHRASE_SHORTCUT = "save_phrase_shortcut" as const;
+export const COMMAND_SEARCH_TRANSCRIPT_HISTORY = "search_transcript_history" as const;
 export const COMMAND_SET_AUDIO_DEVICE = "set_audio_device" as const;
+export const COMMAND_SET_ENABLE_LEVEL = "set_enable_level" as const;
 export const COMMAND_SET_ENABLED = "set_enabled" as const;
 export const COMMAND_SET_HOTKEY = "set_hotkey" as const;
+export const COMMAND_SET_INJECTION_MODE = "set_injection_mode" as const;
+export const COMMAND_SET_INPUT_GAIN = "set_input_gain" as const;
 export const COMMAND_SET_REPLACEMENT_RULES = "set_replacement_rules" as const;
+export const COMMAND_SET_SECRET = "set_secret" as const;
+export const COMMAND_SET_SESSION_LABEL = "set_session_label" as const;
+export const COMMAND_SET_TEMPORARY_REPLACEMENT_RULES = "set_temporary_replacement_rules" as const;
+export const COMMAND_SET_TRANSCRIPT_LABEL = "set_transcript_label" as const;
+export const COMMAND_SET_TRANSCRIPT_PINNED = "set_transcript_pinned" as const;
+export const COMMAND_SET_VOCABULARY = "set_vocabulary" as const;
+export const COMMAND_SHARE_HISTORY_ENTRY = "share_history_entry" as const;
 export const COMMAND_START_MIC_TEST = "start_mic_test" as const;
 export const COMMAND_START_RECORDING = "start_recording" as const;
 export const COMMAND_STOP_MIC_TEST = "stop_mic_test" as const;
 export const COMMAND_STOP_RECORDING = "stop_recording" as const;
+export const COMMAND_SYNC_NOW = "sync_now" as const;
+export const COMMAND_TEST_HOTKEY_LEAKAGE = "test_hotkey_leakage" as const;
 export const COMMAND_TOGGLE_ENABLED = "toggle_enabled" as const;
+export const COMMAND_TRANSCRIBE_FILE = "transcribe_file" as const;
 export const COMMAND_UPDATE_CONFIG = "update_config" as const;
 
 // Event name constants
 export const EVENT_APP_ERROR = "app:error" as const;
+export const EVENT_APP_NAVIGATE = "app:navigate" as const;
+export const EVENT_APP_RECOVERY = "app:recovery" as const;
+export const EVENT_APP_UPDATE_PROGRESS = "app:update_progress" as const;
 export const EVENT_AUDIO_LEVEL = "audio:level" as const;
+export const EVENT_CAPABILITIES_ISSUES = "capabilities:issues" as const;
+export const EVENT_CUES_STATUS = "cues:status" as const;
+export const EVENT_FILE_TRANSCRIPTION_PROGRESS = "file_transcription:progress" as const;
+export const EVENT_FOCUS_CHANGED = "focus:changed" as const;
+export const EVENT_INJECTION_CONFIRM_REQUESTED = "injection:confirm_requested" as const;
+export const EVENT_INJECTION_DEFERRED = "injection:deferred" as const;
+export const EVENT_INJECTION_MODE_CHANGED = "injection:mode_changed" as const;
+export const EVENT_INJECTION_PROGRESS = "injection:progress" as const;
+export const EVENT_INJECTION_QUEUE_CHANGED = "injection:queue_changed" as const;
 export const EVENT_MODEL_PROGRESS = "model:progress" as const;
 export const EVENT_MODEL_STATUS = "model:status" as const;
+export const EVENT_OVERLAY_CANCELLED = "overlay:cancelled" as const;
+export const EVENT_OVERLAY_CONFIG = "overlay:config" as const;
+export const EVENT_OVERLAY_COUNTDOWN = "overlay:countdown" as const;
 export const EVENT_OVERLAY_TOGGLE = "overlay:toggle" as const;
+export const EVENT_OVERLAY_TRANSCRIBING = "overlay:transcribing" as const;
+export const EVENT_PENDING_INSERTS_CHANGED = "pending_inserts:changed" as const;
 export const EVENT_RECORDING_STATUS = "recording:status" as const;
+export const EVENT_SESSION_CHANGED = "session:changed" as const;
 export const EVENT_SIDECAR_STATUS = "sidecar:status" as const;
+export const EVENT_SIDECAR_UPDATE_STATUS = "sidecar:update_status" as const;
 export const EVENT_STATE_CHANGED = "state:changed" as const;
 export const EVENT_TRANSCRIPT_COMPLETE = "transcript:complete" as const;
 export const EVENT_TRANSCRIPT_ERROR = "transcript:error" as const;
+export const EVENT_VAD_PREVIEW = "vad:preview" as const;
 
 // Sidecar RPC method name constants
 export const RPC_METHOD_ASR_INITIALIZE = "asr.initialize" as const;
 export const RPC_METHOD_ASR_STATUS = "asr.status" as const;
 export const RPC_METHOD_ASR_TRANSCRIBE = "asr.transcribe" as const;
+export const RPC_METHOD_ASR_UNLOAD = "asr.unload" as const;
+export const RPC_METHOD_AUDIO_GET_GAIN = "audio.get_gain" as const;
 export const RPC_METHOD_AUDIO_LIST_DEVICES = "audio.list_devices" as const;
 export const RPC_METHOD_AUDIO_METER_START = "audio.meter_start" as const;
 export const RPC_METHOD_AUDIO_METER_STATUS = "audio.meter_status" as const;
 export const RPC_METHOD_AUDIO_METER_STOP = "audio.meter_stop" as const;
 export const RPC_METHOD_AUDIO_SET_DEVICE = "audio.set_device" as const;
+export const RPC_METHOD_AUDIO_SET_GAIN = "audio.set_gain" as const;
+export const RPC_METHOD_BATCH_COMPARE_MODELS = "batch.compare_models" as const;
+export const RPC_METHOD_FILE_TRANSCRIBE = "file.transcribe" as const;
 export const RPC_METHOD_MODEL_DOWNLOAD = "model.download" as const;
 export const RPC_METHOD_MODEL_GET_STATUS = "model.get_status" as const;
 export const RPC_METHOD_MODEL_INSTALL = "model.install" as const;
 export const RPC_METHOD_MODEL_PURGE_CACHE = "model.purge_cache" as const;
 export const RPC_METHOD_RECORDING_CANCEL = "recording.cancel" as const;
+export const RPC_METHOD_RECORDING_EXPORT_AUDIO = "recording.export_audio" as const;
+export const RPC_METHOD_RECORDING_SPLIT = "recording.split" as const;
 export const RPC_METHOD_RECORDING_START = "recording.start" as const;
 export const RPC_METHOD_RECORDING_STATUS = "recording.status" as const;
 export const RPC_METHOD_RECORDING_STOP = "recording.stop" as const;
@@ -966,3 +2194,5 @@ export const RPC_METHOD_STATUS_GET = "status.get" as const;
 export const RPC_METHOD_SYSTEM_INFO = "system.info" as const;
 export const RPC_METHOD_SYSTEM_PING = "system.ping" as const;
 export const RPC_METHOD_SYSTEM_SHUTDOWN = "system.shutdown" as const;
+export const RPC_METHOD_TRANSCRIPTION_GET_RESULT = "transcription.get_result" as const;
+export const RPC_METHOD_TRANSCRIPTION_RETRY = "transcription.retry" as const;
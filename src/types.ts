@@ -19,10 +19,14 @@ export type AppState =
   | 'transcribing'
   | 'error';
 
+/** How far dictation is paused. */
+export type EnableLevel = 'enabled' | 'hotkeys_paused' | 'fully_paused' | 'stealth';
+
 /** Application state info returned by get_app_state. */
 export interface StateEvent {
   state: AppState;
   enabled: boolean;
+  level?: EnableLevel;
   detail?: string;
   timestamp: string;
 }
@@ -46,7 +50,9 @@ export type CannotRecordReason =
   | 'model_loading'
   | 'already_recording'
   | 'still_transcribing'
-  | 'in_error_state';
+  | 'in_error_state'
+  | 'screen_sharing'
+  | 'hotkeys_paused';
 
 // ============================================================================
 // CAPABILITY TYPES
@@ -61,10 +67,10 @@ export type DisplayServer =
   | { type: 'unknown' };
 
 /** Hotkey activation mode. */
-export type ActivationMode = 'hold' | 'toggle';
+export type ActivationMode = 'push_to_talk' | 'toggle';
 
 /** Text injection method. */
-export type InjectionMethod = 'clipboard_paste' | 'clipboard_only';
+export type InjectionMethod = 'clipboard_paste' | 'clipboard_only' | 'ask';
 
 /** Effective mode with reason. */
 export interface EffectiveMode<T> {
@@ -74,12 +80,13 @@ export interface EffectiveMode<T> {
 }
 
 /** Permission state. */
-export type PermissionState = 'granted' | 'denied' | 'unknown' | 'not_required';
+export type PermissionState = 'granted' | 'denied' | 'not_determined' | 'not_applicable';
 
 /** Permission status. */
 export interface PermissionStatus {
   microphone: PermissionState;
   accessibility?: PermissionState;
+  input_monitoring?: PermissionState;
 }
 
 /** Platform capabilities. */
@@ -89,12 +96,74 @@ export interface Capabilities {
   hotkey_release_available: boolean;
   keystroke_injection_available: boolean;
   clipboard_available: boolean;
+  /** Whether transcripts can go to the PRIMARY selection (Linux middle-click paste). */
+  primary_selection_available?: boolean;
   hotkey_mode: EffectiveMode<ActivationMode>;
   injection_method: EffectiveMode<InjectionMethod>;
   permissions: PermissionStatus;
+  strategies?: PlatformStrategies;
+  session?: SessionInfo;
+  screen_share?: ScreenShareInfo;
+  dnd?: DndInfo;
+  network?: NetworkStatus;
   diagnostics: string;
 }
 
+/** Login session the app runs in; hotkeys pause outside the console session. */
+export type SessionKind = 'console' | 'remote' | 'inactive';
+
+export interface SessionInfo {
+  kind: SessionKind;
+  remote_protocol?: string;
+}
+
+/** Whether the screen is being shared (see `ui.screen_share_policy`). */
+export interface ScreenShareInfo {
+  active: boolean;
+  source?: string;
+}
+
+/** Whether the OS is in Do-Not-Disturb / Focus mode (see `audio.mute_cues_in_dnd`). */
+export interface DndInfo {
+  active: boolean;
+  /** False where the state cannot be read, e.g. macOS without Full Disk Access. */
+  detectable: boolean;
+  source?: string;
+}
+
+/** Why the app wanted to reach the network. */
+export type NetworkPurpose =
+  | 'model_download'
+  | 'app_update'
+  | 'sidecar_update'
+  | 'settings_sync'
+  | 'companion_status'
+  | 'crash_report'
+  | 'translation'
+  | 'webhook';
+
+/** A network request refused by offline mode. */
+export interface BlockedRequest {
+  purpose: NetworkPurpose;
+  target: string;
+  at: string;
+}
+
+/** Offline mode state and the requests it refused. */
+export interface NetworkStatus {
+  offline_mode: boolean;
+  blocked_count: number;
+  recent_blocked: BlockedRequest[];
+}
+
+/** Focus and paste strategies selected for a Linux session. */
+export interface PlatformStrategies {
+  focus: Exclude<FocusStrategySetting, 'auto'>;
+  paste: Exclude<PasteStrategySetting, 'auto'>;
+  focus_overridden: boolean;
+  paste_overridden: boolean;
+}
+
 /** Capability issue for user attention. */
 export interface CapabilityIssue {
   id: string;
@@ -102,6 +171,7 @@ export interface CapabilityIssue {
   title: string;
   description: string;
   fix_instructions?: string;
+  deep_link?: string;
 }
 
 // ============================================================================
@@ -111,27 +181,67 @@ export interface CapabilityIssue {
 /** Hotkey mode setting. */
 export type HotkeyMode = 'hold' | 'toggle';
 
+/** Named VAD timing preset; anything but 'custom' overrides the VAD timings. */
+export type VadPreset = 'aggressive' | 'balanced' | 'relaxed' | 'custom';
+
+/** VAD timings for each named preset (mirrors `VadPreset::timings` in config.rs). */
+export const VAD_PRESET_TIMINGS: Record<
+  Exclude<VadPreset, 'custom'>,
+  { vad_silence_ms: number; vad_min_speech_ms: number }
+> = {
+  aggressive: { vad_silence_ms: 600, vad_min_speech_ms: 150 },
+  balanced: { vad_silence_ms: 1200, vad_min_speech_ms: 250 },
+  relaxed: { vad_silence_ms: 2500, vad_min_speech_ms: 400 },
+};
+
 /** Audio configuration. */
 export interface AudioConfig {
   device_uid?: string;
   audio_cues_enabled: boolean;
   trim_silence: boolean;
   vad_enabled: boolean;
+  vad_preset?: VadPreset;
   vad_silence_ms: number;
   vad_min_speech_ms: number;
+  diarization_enabled?: boolean;
+  auto_punctuation?: boolean;
+  continuous_dictation?: boolean;
+  live_flush_secs?: number;
+  countdown_secs?: number;
+  mute_cues_in_calls?: boolean;
+  mute_cues_in_dnd?: boolean;
+  in_meeting?: boolean;
+  auto_split_recordings?: boolean;
+  /** Input gain in dB per device UID (-20..30); missing devices use 0. */
+  input_gains?: Record<string, number>;
 }
 
 /** Hotkey configuration. */
 export interface HotkeyConfig {
   primary: string;
   copy_last: string;
+  dictate_raw?: string;
+  cancel?: string;
   mode: HotkeyMode;
+  idle_auto_disable_minutes?: number;
+  typing_suppression_ms?: number;
+  profiles?: Record<string, ProfileHotkeys>;
+}
+
+/** Per-profile hotkey overrides; unset fields keep the base binding. */
+export interface ProfileHotkeys {
+  primary?: string;
+  copy_last?: string;
+  dictate_raw?: string;
+  cancel?: string;
+  mode?: HotkeyMode;
 }
 
 /** Injection configuration. */
 export interface AppOverride {
   paste_delay_ms?: number;
   use_clipboard_only?: boolean;
+  word_filter_enabled?: boolean;
 }
 
 /** Injection configuration. */
@@ -141,16 +251,51 @@ export interface InjectionConfig {
   suffix: string;
   focus_guard_enabled: boolean;
   app_overrides?: Record<string, AppOverride>;
+  mode?: InjectionMode;
+  chunk_threshold_chars?: number;
+  chunk_size_chars?: number;
+  focus_strategy?: FocusStrategySetting;
+  paste_strategy?: PasteStrategySetting;
+  smart_spacing?: boolean;
+  verify_paste?: boolean;
+  calibrate_paste_delay?: boolean;
+  confirm_above_chars?: number;
+  confirm_above_minutes?: number;
+  primary_selection?: PrimarySelectionMode;
+  /** Seconds a transcript waits for a busy target app; 0 falls back to the clipboard right away. */
+  busy_target_wait_secs?: number;
 }
 
+/** Linux focus capture strategy ('auto' selects from the display server). */
+export type FocusStrategySetting = 'auto' | 'xdotool' | 'sway' | 'hyprland' | 'unavailable';
+
+/** Linux paste keystroke strategy ('auto' selects from the display server). */
+export type PasteStrategySetting = 'auto' | 'xdotool' | 'wtype' | 'portal' | 'clipboard_only';
+
+/** Whether transcripts also go to the Linux PRIMARY selection (middle-click paste). */
+export type PrimarySelectionMode = 'off' | 'also' | 'instead';
+
+/** Global output mode for finished transcripts. */
+export type InjectionMode = 'inject' | 'clipboard_only' | 'ask';
+
 /** Model configuration. */
 export interface ModelConfig {
   model_id: string | null;
   device: 'auto' | 'cpu' | 'cuda' | 'mps' | null;
   preferred_device: 'auto' | 'cpu' | 'gpu';
   language: string | null;
+  /** Decoder beam width override; null applies the catalog recommendation. */
+  beam_size?: number | null;
+  /** Load the model as soon as the sidecar is ready instead of on first use. */
+  preload_on_start?: boolean;
+  /** Delay before the startup preload (0-60000 ms). */
+  preload_delay_ms?: number;
+  asr_backend?: AsrBackend;
 }
 
+/** Transcription engine; `auto` falls back to the embedded engine when the sidecar keeps failing. */
+export type AsrBackend = 'auto' | 'sidecar' | 'embedded';
+
 /** UI configuration. */
 export interface UiConfig {
   show_on_startup: boolean;
@@ -159,10 +304,29 @@ export interface UiConfig {
   theme: 'system' | 'light' | 'dark';
   onboarding_completed: boolean;
   overlay_enabled: boolean;
+  overlay_theme?: 'system' | 'light' | 'dark';
+  /** Overlay size multiplier (0.5-2.0). */
+  overlay_scale?: number;
+  /** Overlay opacity (0.2-1.0). */
+  overlay_opacity?: number;
+  overlay_show_waveform?: boolean;
+  /** Monitor the overlay is pinned to; null follows the focused app. */
+  overlay_monitor?: string | null;
   locale: string | null;
   reduce_motion: boolean;
+  /** Speak state announcements for screen-reader users (off by default). */
+  announcements_enabled?: boolean;
+  announce_recording?: boolean;
+  announce_transcription?: boolean;
+  announce_status?: boolean;
+  announce_errors?: boolean;
+  always_visible_recording_indicator?: boolean;
+  screen_share_policy?: ScreenSharePolicy;
 }
 
+/** Response to a recording started while the screen is shared. */
+export type ScreenSharePolicy = 'off' | 'warn' | 'show_overlay' | 'disable_dictation';
+
 /** Text replacement rule kind. */
 export type ReplacementKind = 'literal' | 'regex';
 
@@ -180,6 +344,38 @@ export interface ReplacementRule {
   case_sensitive: boolean;
   description?: string;
   origin?: ReplacementOrigin;
+  group?: string;
+  order?: number;
+}
+
+/** Named group of replacement rules. */
+export interface ReplacementGroup {
+  id: string;
+  name?: string;
+  enabled?: boolean;
+  order?: number;
+  profiles?: string[];
+}
+
+/** Replacement groups and the active rule profile. */
+export interface ReplacementGroupsConfig {
+  groups: ReplacementGroup[];
+  active_profile?: string | null;
+}
+
+/** Session-scoped replacement rules (get_temporary_replacement_rules). */
+export interface TemporaryReplacementRules {
+  rules: ReplacementRule[];
+  expires_at?: string;
+  profile?: string;
+}
+
+/** Problem reported by set_replacement_rules. */
+export interface ReplacementRuleIssue {
+  kind: 'duplicate_id' | 'unknown_group' | 'invalid_pattern' | 'conflict' | 'shadowed';
+  rule_id: string;
+  other_rule_id?: string;
+  message: string;
 }
 
 /** Presets configuration. */
@@ -187,11 +383,86 @@ export interface PresetsConfig {
   enabled_presets: string[];
 }
 
+/** Custom vocabulary configuration. */
+export interface VocabularyConfig {
+  terms: string[];
+  learn_capitalization?: boolean;
+}
+
+/** Word filter configuration. */
+export interface WordFilterConfig {
+  enabled: boolean;
+  mode: 'mask' | 'remove';
+  use_profanity_preset: boolean;
+  words: string[];
+  keep_unfiltered: boolean;
+  /** Per-profile enablement keyed by replacement_groups.active_profile. */
+  profiles: Record<string, boolean>;
+}
+
+/** Encrypted settings sync configuration. */
+export interface SyncConfig {
+  enabled: boolean;
+  backend: 'webdav' | 's3';
+  url: string;
+  s3_region: string;
+  credentials_name: string;
+  passphrase_name: string;
+  interval_minutes: number;
+}
+
+/** Network access configuration. */
+export interface NetworkConfig {
+  offline_mode: boolean;
+}
+
+/** LAN status endpoint for companion devices. */
+export interface CompanionConfig {
+  enabled: boolean;
+  port: number;
+}
+
+/** Opt-in crash report upload; reports are always kept locally. */
+export interface CrashReportingConfig {
+  upload_enabled: boolean;
+  endpoint: string;
+}
+
+/** Energy saving on battery power. */
+export interface PowerConfig {
+  low_power_on_battery: boolean;
+}
+
+/** Settings synced by `sync_now`. */
+export type SyncSection = 'replacement_rules' | 'replacement_groups' | 'vocabulary' | 'learned_words';
+
+/** An item edited on this machine and another since the last sync. */
+export interface SyncConflict {
+  section: SyncSection;
+  item: string;
+  kept: 'local' | 'remote';
+}
+
+/** Result of `sync_now`. */
+export interface SyncReport {
+  synced_at: string;
+  pulled: number;
+  pushed: number;
+  conflicts: SyncConflict[];
+}
+
 /** Transcript history configuration. */
 export interface HistoryConfig {
   persistence_mode: 'memory' | 'disk';
   max_entries: number;
   encrypt_at_rest: boolean;
+  capture_window_context?: boolean;
+  sanitize_window_title?: boolean;
+  window_title_max_chars?: number;
+  /** Delete unpinned entries older than this many days; 0 keeps them. */
+  retention_days?: number;
+  /** Prune the oldest unpinned entries above this persisted size in MiB; 0 = no limit. */
+  max_storage_mb?: number;
 }
 
 /** Complete application configuration. */
@@ -202,9 +473,23 @@ export interface AppConfig {
   injection: InjectionConfig;
   model: ModelConfig | null;
   replacements: ReplacementRule[];
+  replacement_groups?: ReplacementGroupsConfig;
   ui: UiConfig;
   history: HistoryConfig;
   presets: PresetsConfig;
+  vocabulary?: VocabularyConfig;
+  word_filter?: WordFilterConfig;
+  sync?: SyncConfig;
+  network?: NetworkConfig;
+  companion?: CompanionConfig;
+  crash_reporting?: CrashReportingConfig;
+  power?: PowerConfig;
+}
+
+/** Configuration returned by get_config. */
+export interface ManagedAppConfig extends AppConfig {
+  /** Dotted paths of settings locked by an administrator policy; update_config rejects changes. */
+  managed_fields: string[];
 }
 
 // ============================================================================
@@ -220,6 +505,13 @@ export interface AudioDevice {
   channels: number;
 }
 
+/** Input gain of a device, as returned by `get_input_gain`/`set_input_gain`. */
+export interface InputGain {
+  /** Resolved device UID; null when no input device exists. */
+  device_uid: string | null;
+  gain_db: number;
+}
+
 // ============================================================================
 // MODEL TYPES
 // ============================================================================
@@ -237,7 +529,7 @@ export type ModelState =
 /** Download/verification progress. */
 export interface Progress {
   current: number;
-  total?: number;
+  total?: number | null;
   unit: string;
 }
 
@@ -250,8 +542,17 @@ export interface ModelStatus {
   cache_path?: string;
   progress?: Progress;
   error?: string;
+  /** Engine serving the model; absent means the sidecar. */
+  kind?: ModelKind;
+  /** Initialization step while `status` is `loading`: `weights`, `compile`, or `warmup`. */
+  stage?: string;
+  /** Completion of `stage` (0-100), when the sidecar can measure it. */
+  percent?: number;
 }
 
+/** ASR engine behind `model:status`: the sidecar or the embedded fallback. */
+export type ModelKind = 'sidecar' | 'embedded';
+
 /** Legacy model status payload shape emitted by older backend paths. */
 export interface LegacyModelStatus {
   seq?: number;
@@ -267,6 +568,14 @@ export const SUPPORTED_MODEL_FAMILIES = ['parakeet', 'whisper'] as const;
 export type ModelFamily = (typeof SUPPORTED_MODEL_FAMILIES)[number];
 
 /** Catalog entry for a single model (matches shared/model/MODEL_CATALOG.json). */
+/** Catalog-recommended parameters for a model. */
+export interface RecommendedModelParams {
+  beam_size?: number;
+  vad_silence_ms?: number;
+  vad_min_speech_ms?: number;
+  expected_ram_mb?: number;
+}
+
 export interface ModelCatalogEntry {
   model_id: string;
   family: ModelFamily;
@@ -277,6 +586,39 @@ export interface ModelCatalogEntry {
   size_bytes: number;
   license_spdx?: string;
   manifest_path: string;
+  recommended_params?: RecommendedModelParams;
+}
+
+/** Audio to compare models on: a recent session or a WAV file. */
+export type ModelComparisonAudio = { sessionId: string } | { audioPath: string };
+
+/** One word run in a model's diff against the comparison baseline. */
+export interface ModelDiffSegment {
+  op: 'equal' | 'insert' | 'delete';
+  text: string;
+}
+
+/** One model's output in a `compare_models` result. */
+export interface ModelComparisonResult {
+  model_id: string;
+  text: string | null;
+  confidence: number | null;
+  load_ms: number | null;
+  transcribe_ms: number | null;
+  /** Inference time divided by audio length; below 1 is faster than real time. */
+  real_time_factor: number | null;
+  error: { code: string; message: string } | null;
+  /** Differences from the baseline text; empty for the baseline itself. */
+  diff: ModelDiffSegment[];
+  changed_words: number;
+}
+
+/** Side-by-side transcription of the same audio with several models. */
+export interface ModelComparison {
+  audio_duration_ms: number;
+  /** First model, in request order, that produced text. */
+  baseline_model_id: string | null;
+  results: ModelComparisonResult[];
 }
 
 // ============================================================================
@@ -298,6 +640,22 @@ export interface TranscriptTimings {
   total_ms?: number;
 }
 
+/** Segment timestamp reported by the sidecar. */
+export interface TranscriptSegment {
+  start_ms: number;
+  end_ms: number;
+  text: string;
+  speaker?: string;
+}
+
+/** Word timestamp and confidence reported by the sidecar. */
+export interface TranscriptWord {
+  word: string;
+  start_ms: number;
+  end_ms: number;
+  confidence?: number;
+}
+
 /** Transcript history entry. */
 export interface TranscriptEntry {
   id: string;
@@ -312,8 +670,67 @@ export interface TranscriptEntry {
   confidence?: number;
   injection_result: InjectionResult;
   timings?: TranscriptTimings;
+  segments?: TranscriptSegment[];
+  words?: TranscriptWord[];
+  unfiltered_text?: string;
+  auto_punctuation?: boolean;
+  app_name?: string;
+  window_title?: string;
+  /** Window the transcript was dictated into (`reinject_to_original_target`). */
+  injection_target?: InjectionTarget;
+  session_name?: string;
+  tags?: string[];
+  paste_verification?: PasteVerification;
+  /** Transcription attempts repeated after transient sidecar errors. */
+  transcription_retries?: number;
+  /** Where the audio came from; absent for microphone dictation. */
+  source?: TranscriptSource;
+  /** Name of the transcribed file, for `source: 'file'`. */
+  source_file?: string;
+  /** Pinned entries survive `unpinned_only` clears and retention. */
+  pinned?: boolean;
+}
+
+/** Window a transcript was dictated into; the title is stored only as a hash. */
+export interface InjectionTarget {
+  app_id: string;
+  window_title_hash?: string;
 }
 
+/** Icon of the app a transcript went to (get_app_icon). */
+export interface AppIcon {
+  app_id: string;
+  /** `data:` URL of a PNG or SVG image. */
+  data_url: string;
+}
+
+/** Origin of a transcript's audio. */
+export type TranscriptSource = 'microphone' | 'file';
+
+/** Whether pasted text was confirmed in the target field (`injection.verify_paste`). */
+export type PasteVerification =
+  | 'verified'
+  | 'retried_with_keystrokes'
+  | 'failed'
+  | 'unverifiable';
+
+/** Which entries `clear_history_filtered` removes; every set criterion must match. */
+export interface HistoryClearFilter {
+  older_than_days?: number | null;
+  app_name?: string | null;
+  tag?: string | null;
+  unpinned_only?: boolean;
+}
+
+/** Name and tags assigned to a dictation session. */
+export interface SessionLabel {
+  name?: string;
+  tags: string[];
+}
+
+/** How `share_history_entry` delivered a transcript. */
+export type ShareMethod = 'share_sheet' | 'email' | 'clipboard';
+
 // ============================================================================
 // HOTKEY TYPES
 // ============================================================================
@@ -324,6 +741,37 @@ export interface HotkeyStatus {
   copy_last: string;
   mode: string;
   registered: boolean;
+  suppressed_while_typing: number;
+  profile: string | null;
+  conflicts: string[];
+}
+
+/** How the hotkey chord is kept from reaching the focused app. */
+export type HotkeySuppression =
+  | 'os_grab'
+  | 'os_grab_with_modifier_mask'
+  | 'portal'
+  | 'unsuppressed';
+
+/** Result of `test_hotkey_leakage`. */
+export interface HotkeyLeakReport {
+  hotkey: string;
+  hotkey_detected: boolean;
+  leaked: boolean;
+  suppression: HotkeySuppression;
+  hint?: string;
+}
+
+/** Result of `practice_hotkey`. */
+export interface HotkeyPracticeReport {
+  hotkey: string;
+  mode: HotkeyMode;
+  passed: boolean;
+  presses: number;
+  releases: number;
+  hold_ms?: number;
+  latency_ms?: number;
+  hint?: string;
 }
 
 // ============================================================================
@@ -373,6 +821,98 @@ export interface LogEntry {
   message: string;
 }
 
+/** Likely cause of a sidecar crash. */
+export type SidecarCrashCategory =
+  | 'out_of_memory'
+  | 'missing_dependency'
+  | 'native_crash'
+  | 'python_exception'
+  | 'unknown';
+
+/** Redacted crash report kept locally (get_pending_crash_reports). */
+export interface CrashReport {
+  id: string;
+  kind: 'panic' | 'sidecar_crash';
+  created_at: string;
+  app_version: string;
+  os: string;
+  arch: string;
+  message: string;
+  location?: string;
+  sidecar_category?: SidecarCrashCategory;
+  recent_logs: string[];
+  upload_attempts: number;
+  next_upload_at?: string;
+}
+
+/** Log severity accepted by `get_recent_logs`. */
+export type LogLevel = 'error' | 'warn' | 'info' | 'debug' | 'trace';
+
+/** Optional `get_recent_logs` filters; unset fields match everything. */
+export interface LogFilter {
+  /** Most verbose level to include ('warn' keeps warnings and errors). */
+  level?: LogLevel;
+  /** Case-insensitive substring of the logger target. */
+  module?: string;
+  /** RFC 3339 timestamp; only entries recorded after it are returned. */
+  since?: string;
+}
+
+/** One invoked command in the `get_command_audit_log` buffer. */
+export interface CommandAuditEntry {
+  id: number;
+  command: string;
+  /** Label of the window that invoked the command ('tray' for tray menu calls). */
+  window: string;
+  /** Argument summary; free text is reduced to its length and credentials are redacted. */
+  args: string;
+  started_at: string;
+  /** Unset while the command is still running. */
+  duration_ms?: number;
+  status: 'pending' | 'ok' | 'error';
+  /** Redacted message of the error returned to the frontend. */
+  error?: string;
+}
+
+/** Transcript, word, and audio totals over a period. */
+export interface UsageTotals {
+  transcripts: number;
+  words: number;
+  audio_ms: number;
+}
+
+/** Totals for one local calendar day (`YYYY-MM-DD`). */
+export interface DailyUsage extends UsageTotals {
+  date: string;
+}
+
+/** Where transcript history is stored. */
+export type HistoryPersistencePolicy = 'memory_only' | 'disk_plaintext' | 'disk_encrypted';
+
+/** Entry counts and storage footprint of transcript history (get_history_stats). */
+export interface HistoryStats {
+  entries: number;
+  pinned: number;
+  max_entries: number;
+  persistence: HistoryPersistencePolicy;
+  /** Size of the history file; 0 for memory-only history. */
+  storage_bytes: number;
+  /** Serialized size of the entries held in memory. */
+  memory_bytes: number;
+  oldest_at: string | null;
+  newest_at: string | null;
+  /** When background pruning by age and size last ran. */
+  last_pruned_at: string | null;
+}
+
+/** Usage statistics derived from transcript history (`get_usage_stats`). */
+export interface UsageStats {
+  today: UsageTotals;
+  /** The last 7 days, oldest first, including empty days. */
+  days: DailyUsage[];
+  all_time: UsageTotals;
+}
+
 /** Diagnostics report. */
 export interface DiagnosticsReport {
   version: string;
@@ -381,6 +921,36 @@ export interface DiagnosticsReport {
   config: AppConfig;
   self_check: SelfCheckResult;
   recent_logs: LogEntry[];
+  /** Tail of the persisted session journal (JSONL lines, oldest first). */
+  session_journal?: string[];
+  /** Sidecar watchdog timings in effect. */
+  watchdog: WatchdogSettings;
+  /** Power source and the profile in effect. */
+  power: PowerStatus;
+  /** Invalid UTF-8 bytes replaced in sidecar output this session. */
+  sidecar_output: SidecarOutputStats;
+}
+
+/** Power source and the resource profile in effect. */
+export interface PowerStatus {
+  source: 'ac' | 'battery' | 'unknown';
+  profile: 'normal' | 'low_power';
+}
+
+/** Bytes of sidecar output that were not valid UTF-8 and got replaced. */
+export interface SidecarOutputStats {
+  /** Replaced in JSON-RPC lines. */
+  rpc_bytes_replaced: number;
+  /** Replaced in captured stdout/stderr logging. */
+  log_bytes_replaced: number;
+}
+
+/** Effective sidecar watchdog settings (`supervisor.watchdog_*` config). */
+export interface WatchdogSettings {
+  ping_interval_ms: number;
+  ping_timeout_ms: number;
+  hang_threshold_ms: number;
+  auto_restart_on_hang: boolean;
 }
 
 // ============================================================================
@@ -421,11 +991,11 @@ export interface AppError {
 
 /** Command error codes. */
 export type CommandErrorCode =
-  | 'E_SIDECAR_IPC'
   | 'config'
-  | 'audio'
-  | 'model'
+  | 'integration'
+  | 'policy'
   | 'clipboard'
+  | 'action_unavailable'
   | 'hotkey'
   | 'not_implemented'
   | 'internal';
@@ -434,6 +1004,60 @@ export type CommandErrorCode =
 export interface CommandError {
   code: CommandErrorCode;
   message: string;
+  /** Dialog title for 'integration' failures. */
+  title?: string;
+  /** Standardized error for 'integration' failures (sidecar, model, recording). */
+  error?: AppError;
+  /** Managed settings a 'policy' failure tried to change. */
+  fields?: string[];
+  /** Why an 'action_unavailable' action cannot run, as in `ActionInfo`. */
+  reason?: string;
+}
+
+/** Argument of a backend action; values are always strings. */
+export interface ActionArg {
+  name: string;
+  required: boolean;
+  /** Accepted values; absent when any string is accepted. */
+  values?: string[];
+}
+
+/** How a span of a transcript's final text relates to its raw text. */
+export type TranscriptDiffSpanKind = 'equal' | 'insert' | 'delete' | 'replace';
+
+/** Post-processing stage that explains a transcript change. */
+export type TranscriptDiffStage = 'replacement' | 'learned_dictionary';
+
+/** A run of words with the same diff kind. */
+export interface TranscriptDiffSpan {
+  kind: TranscriptDiffSpanKind;
+  /** Words in the raw text; empty for 'insert'. */
+  raw: string;
+  /** Words in the final text; empty for 'delete'. */
+  final: string;
+  stage: TranscriptDiffStage | null;
+  /** Replacement rule behind a 'replacement' change. */
+  rule_id: string | null;
+}
+
+/** Raw-to-final diff of a transcript (get_transcript_diff). */
+export interface TranscriptDiff {
+  entry_id: string;
+  raw_text: string;
+  final_text: string;
+  spans: TranscriptDiffSpan[];
+}
+
+/** User-invokable backend action, for the command palette. */
+export interface ActionInfo {
+  /** Stable id such as "recording.start". */
+  id: string;
+  name: string;
+  description: string;
+  args: ActionArg[];
+  available: boolean;
+  /** Stable code such as "not_recording" when unavailable. */
+  unavailable_reason: string | null;
 }
 
 // ============================================================================
@@ -446,6 +1070,18 @@ export interface AudioLevelEvent {
   peak: number;
 }
 
+/** VAD decision for the mic test stream. */
+export type VadPreviewState = 'waiting_for_speech' | 'speech' | 'silence' | 'auto_stop';
+
+/** VAD preview event during mic test (vad:preview). */
+export interface VadPreviewEvent {
+  seq?: number;
+  state: VadPreviewState;
+  speech_ms: number;
+  silence_ms: number;
+  silence_threshold_ms: number;
+}
+
 /** Model download progress event. */
 export interface ModelProgressEvent {
   current: number;
@@ -510,6 +1146,8 @@ export interface RecordingStatusEvent {
   session_id?: string;
   started_at?: string;
   audio_ms?: number;
+  /** Recording started while the screen was shared (`warn` policy). */
+  screen_sharing?: boolean;
 }
 
 /** Canonical sidecar status event payload. */
@@ -520,6 +1158,70 @@ export interface SidecarStatusEvent {
   message?: string;
 }
 
+/** Sidecar self-update step reported by sidecar:update_status events. */
+export type SidecarUpdatePhase =
+  | 'checking'
+  | 'downloading'
+  | 'verifying'
+  | 'installing'
+  | 'restarting'
+  | 'ready'
+  | 'rolled_back'
+  | 'failed';
+
+/** Sidecar update progress event payload. */
+export interface SidecarUpdateStatusEvent {
+  seq?: number;
+  phase: SidecarUpdatePhase;
+  version: string;
+  message?: string;
+}
+
+/** Step of a dropped audio file reported by file_transcription:progress events. */
+export type FileTranscriptionPhase = 'queued' | 'transcribing' | 'complete' | 'failed';
+
+/** File transcription progress event payload. */
+export interface FileTranscriptionProgressEvent {
+  seq?: number;
+  file_name: string;
+  phase: FileTranscriptionPhase;
+  /** History entry created for the file, on `complete`. */
+  entry_id?: string;
+  error?: string;
+}
+
+/** Result of check_sidecar_update. */
+export interface SidecarUpdateInfo {
+  current_version: string;
+  latest_version: string;
+  update_available: boolean;
+  notes: string | null;
+}
+
+/** App self-update step reported by app:update_progress events. */
+export type AppUpdatePhase = 'downloading' | 'verifying' | 'staged' | 'installing' | 'failed';
+
+/** App update progress event payload. */
+export interface AppUpdateProgressEvent {
+  seq?: number;
+  phase: AppUpdatePhase;
+  version: string;
+  downloaded_bytes?: number;
+  total_bytes?: number;
+  message?: string;
+}
+
+/** Result of check_app_update. */
+export interface AppUpdateInfo {
+  current_version: string;
+  latest_version: string;
+  update_available: boolean;
+  in_rollout: boolean;
+  rollout_percentage: number;
+  notes: string | null;
+  staged_version: string | null;
+}
+
 /** Canonical transcript error payload emitted by transcript:error events. */
 export interface TranscriptErrorEvent {
   seq?: number;
@@ -529,3 +1231,156 @@ export interface TranscriptErrorEvent {
   recoverable?: boolean;
   app_error?: AppError;
 }
+
+/** Would-be injection target returned by get_current_focus_info. */
+export interface FocusInfo {
+  app_name: string;
+  window_title: string | null;
+  window_id: string;
+  is_self: boolean;
+}
+
+/** Chunked injection progress for long transcripts (injection:progress). */
+export interface InjectionProgressEvent {
+  seq?: number;
+  chunks_done: number;
+  chunk_count: number;
+  chars_injected: number;
+  total_chars: number;
+}
+
+/** Transcripts waiting for or holding the injection turn (injection:queue_changed). */
+export interface InjectionQueueChangedEvent {
+  seq?: number;
+  depth: number;
+}
+
+/** Injection held back for a target app that is not responding (injection:deferred). */
+export interface InjectionDeferredEvent {
+  seq?: number;
+  entry_id: string;
+  session_id: string;
+  phase: 'waiting' | 'resumed' | 'expired' | 'dismissed';
+  waited_ms: number;
+  max_wait_ms: number;
+}
+
+/** Transcript that fell back to clipboard-only and awaits insertion. */
+export interface PendingInsert {
+  entry_id: string;
+  text: string;
+  reason: string;
+  queued_at: string;
+}
+
+/** Pending insert queue size changed (pending_inserts:changed). */
+export interface PendingInsertsChangedEvent {
+  seq?: number;
+  count: number;
+}
+
+/** Lifecycle phase of a dictation session. */
+export type SessionPhase =
+  | 'created'
+  | 'recording'
+  | 'transcribing'
+  | 'completed'
+  | 'failed'
+  | 'cancelled';
+
+/** Most recent dictation session, from get_active_session or session:changed. */
+export interface SessionSnapshot {
+  session_id: string;
+  phase: SessionPhase;
+  /** Grows by one per transition; a lower revision of the same session is stale. */
+  revision: number;
+  created_at: string;
+  updated_at: string;
+  started_at: string | null;
+  audio_ms: number | null;
+  text: string | null;
+  error: string | null;
+  cancel_reason: string | null;
+}
+
+/** Why audio cues are muted: the manual toggle, a detected call, or OS Do-Not-Disturb. */
+export type CueSuppressionReason = 'manual' | 'call' | 'do_not_disturb';
+
+/** Whether audio cues are muted for a meeting (cues:status). */
+export interface CueStatusEvent {
+  seq?: number;
+  suppressed: boolean;
+  reason: CueSuppressionReason | null;
+  /** Conferencing app holding the microphone, for `call`. */
+  app: string | null;
+}
+
+/** Session lifecycle transition (session:changed). */
+export interface SessionChangedEvent extends SessionSnapshot {
+  seq?: number;
+}
+
+/** What put the app into the Error state. */
+export type ErrorCategory = 'sidecar' | 'audio_device' | 'model' | 'transcription' | 'other';
+
+/** A revalidation run before automatically leaving the Error state. */
+export type RecoveryCheck = 'sidecar_ping' | 'audio_devices' | 'model_status';
+
+/** One step of automatic recovery from the Error state (app:recovery). */
+export interface AppRecoveryEvent {
+  seq?: number;
+  phase: 'scheduled' | 'checking' | 'failed' | 'recovered' | 'superseded' | 'gave_up';
+  category: ErrorCategory;
+  /** 1-based attempt the step belongs to. */
+  attempt: number;
+  max_attempts: number;
+  /** Wait before the attempt, for `scheduled`. */
+  delay_ms: number | null;
+  /** Checks that did not pass, for `failed`. */
+  failed_checks: RecoveryCheck[];
+}
+
+/** License that must be accepted before a model download can continue. */
+export interface PendingLicense {
+  model_id: string;
+  revision?: string;
+  spdx_id: string;
+  name: string;
+  url: string;
+  /** Full license text to show before accepting. */
+  text: string;
+}
+
+/** A word whose capitalization was learned from user corrections. */
+export interface LearnedWord {
+  word: string;
+  corrections: number;
+  active: boolean;
+  last_corrected: string;
+}
+
+/** What a phrase shortcut inserts: literal text, or a keyring secret by name. */
+export type PhraseShortcutValue =
+  | { kind: 'text'; text: string }
+  | { kind: 'secret'; secret_name: string };
+
+/** Exact-phrase shortcut (get_phrase_shortcuts / save_phrase_shortcut). */
+export interface PhraseShortcut {
+  id: string;
+  /** Spoken trigger; matches only when it is the whole utterance. */
+  phrase: string;
+  value: PhraseShortcutValue;
+  enabled: boolean;
+}
+
+/** Debounced focus:changed event payload for the overlay focus preview. */
+export interface FocusChangedEvent extends FocusInfo {
+  seq?: number;
+}
+
+/** Permission pre-flight result (capabilities:issues), sent when permissions change. */
+export interface CapabilitiesIssuesEvent {
+  seq?: number;
+  permissions: PermissionStatus;
+  issues: CapabilityIssue[];
+}
@@ -7,6 +7,7 @@ export type DedupeStreamKey =
   | 'modelProgress'
   | 'recording'
   | 'audio'
+  | 'vad'
   | 'error';
 
 export interface SeqDedupeTracker {
@@ -31,6 +31,7 @@ export default defineConfig({
       input: {
         main: resolve(__dirname, 'index.html'),
         overlay: resolve(__dirname, 'overlay.html'),
+        recordingIndicator: resolve(__dirname, 'recording-indicator.html'),
       },
     },
   },